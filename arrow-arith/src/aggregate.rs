@@ -28,6 +28,18 @@ use std::cmp::{self, Ordering};
 use std::ops::{BitAnd, BitOr, BitXor};
 use types::ByteViewType;
 
+mod decimal;
+mod grouped;
+mod histogram;
+mod hyperloglog;
+mod tdigest;
+
+pub use decimal::*;
+pub use grouped::*;
+pub use histogram::*;
+pub use hyperloglog::*;
+pub use tdigest::*;
+
 /// An accumulator for primitive numeric values.
 trait NumericAccumulator<T: ArrowNativeTypeOp>: Copy + Default {
     /// Accumulate a non-null value.
@@ -815,6 +827,10 @@ where
 
 /// Returns the minimum value in the array, according to the natural order.
 /// For floating point arrays any NaN values are considered to be greater than any other non-null value
+///
+/// Interval values are compared using their total order, comparing each field in turn,
+/// e.g. months, then days, then nanoseconds for [`IntervalMonthDayNano`](arrow_buffer::IntervalMonthDayNano).
+/// This does not account for the relative length of months and days
 pub fn min<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> Option<T::Native>
 where
     T::Native: PartialOrd,
@@ -824,6 +840,10 @@ where
 
 /// Returns the maximum value in the array, according to the natural order.
 /// For floating point arrays any NaN values are considered to be greater than any other non-null value
+///
+/// Interval values are compared using their total order, comparing each field in turn,
+/// e.g. months, then days, then nanoseconds for [`IntervalMonthDayNano`](arrow_buffer::IntervalMonthDayNano).
+/// This does not account for the relative length of months and days
 pub fn max<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> Option<T::Native>
 where
     T::Native: PartialOrd,
@@ -831,6 +851,56 @@ where
     aggregate::<T::Native, T, MaxAccumulator<T::Native>>(array)
 }
 
+/// Applies `op` to each chunk of `array`, and then to the non-null results
+fn aggregate_chunked<T, F>(array: &ChunkedArray, op: F) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    F: Fn(&PrimitiveArray<T>) -> Option<T::Native>,
+{
+    let partials = array
+        .chunks()
+        .iter()
+        .map(|c| op(c.as_primitive::<T>()))
+        .collect::<PrimitiveArray<T>>();
+    op(&partials)
+}
+
+/// Returns the sum of values in the [`ChunkedArray`], see [`sum`]
+///
+/// # Panics
+///
+/// Panics if the chunks of `array` are not of type `T`
+pub fn sum_chunked<T: ArrowNumericType>(array: &ChunkedArray) -> Option<T::Native>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    aggregate_chunked::<T, _>(array, sum)
+}
+
+/// Returns the minimum value in the [`ChunkedArray`], see [`min`]
+///
+/// # Panics
+///
+/// Panics if the chunks of `array` are not of type `T`
+pub fn min_chunked<T: ArrowNumericType>(array: &ChunkedArray) -> Option<T::Native>
+where
+    T::Native: PartialOrd,
+{
+    aggregate_chunked::<T, _>(array, min)
+}
+
+/// Returns the maximum value in the [`ChunkedArray`], see [`max`]
+///
+/// # Panics
+///
+/// Panics if the chunks of `array` are not of type `T`
+pub fn max_chunked<T: ArrowNumericType>(array: &ChunkedArray) -> Option<T::Native>
+where
+    T::Native: PartialOrd,
+{
+    aggregate_chunked::<T, _>(array, max)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1240,6 +1310,50 @@ mod tests {
         assert!(max(&a).unwrap().is_nan());
     }
 
+    #[test]
+    fn test_interval_min_max() {
+        let a = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, 0, 0)),
+            None,
+            Some(IntervalMonthDayNano::new(0, 45, 0)),
+            Some(IntervalMonthDayNano::new(1, -1, 10)),
+            Some(IntervalMonthDayNano::new(0, 45, -1)),
+        ]);
+        // Compared by months, then days, then nanoseconds
+        assert_eq!(min(&a), Some(IntervalMonthDayNano::new(0, 45, -1)));
+        assert_eq!(max(&a), Some(IntervalMonthDayNano::new(1, 0, 0)));
+        assert_eq!(min(&a.slice(1, 1)), None);
+
+        let a = IntervalDayTimeArray::from(vec![
+            IntervalDayTime::new(1, -5),
+            IntervalDayTime::new(-1, 500),
+            IntervalDayTime::new(1, 3),
+        ]);
+        assert_eq!(min(&a), Some(IntervalDayTime::new(-1, 500)));
+        assert_eq!(max(&a), Some(IntervalDayTime::new(1, 3)));
+
+        let a = IntervalYearMonthArray::from(vec![Some(5), None, Some(-3)]);
+        assert_eq!(min(&a), Some(-3));
+        assert_eq!(max(&a), Some(5));
+
+        let values = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(0, 1, 0),
+            IntervalMonthDayNano::new(2, 0, 0),
+            IntervalMonthDayNano::new(0, 0, 7),
+        ]);
+        let keys = Int8Array::from(vec![Some(0), None, Some(2)]);
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let dict = dict.downcast_dict::<IntervalMonthDayNanoArray>().unwrap();
+        assert_eq!(
+            min_array::<IntervalMonthDayNanoType, _>(dict),
+            Some(IntervalMonthDayNano::new(0, 0, 7))
+        );
+        assert_eq!(
+            max_array::<IntervalMonthDayNanoType, _>(dict),
+            Some(IntervalMonthDayNano::new(0, 1, 0))
+        );
+    }
+
     macro_rules! test_binary {
         ($NAME:ident, $ARRAY:expr, $EXPECTED_MIN:expr, $EXPECTED_MAX: expr) => {
             #[test]
@@ -1701,4 +1815,30 @@ mod tests {
         sum_checked(&a).expect_err("overflow should be detected");
         sum_array_checked::<Int32Type, _>(&a).expect_err("overflow should be detected");
     }
+
+    #[test]
+    fn test_aggregate_chunked() {
+        let chunks: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![Some(1.5), None, Some(-2.0)])),
+            Arc::new(Float64Array::from(vec![None, None])),
+            Arc::new(Float64Array::from(Vec::<f64>::new())),
+            Arc::new(Float64Array::from(vec![f64::NAN, 4.0])),
+        ];
+        let array = ChunkedArray::try_new(DataType::Float64, chunks).unwrap();
+        assert!(sum_chunked::<Float64Type>(&array).unwrap().is_nan());
+        assert_eq!(min_chunked::<Float64Type>(&array), Some(-2.0));
+        assert!(max_chunked::<Float64Type>(&array).unwrap().is_nan());
+
+        let sliced = array.slice(0, 5);
+        assert_eq!(sum_chunked::<Float64Type>(&sliced), Some(-0.5));
+        assert_eq!(max_chunked::<Float64Type>(&sliced), Some(1.5));
+
+        let nulls = array.slice(3, 2);
+        assert_eq!(sum_chunked::<Float64Type>(&nulls), None);
+        assert_eq!(min_chunked::<Float64Type>(&nulls), None);
+
+        let empty = ChunkedArray::new_empty(DataType::Int32);
+        assert_eq!(sum_chunked::<Int32Type>(&empty), None);
+        assert_eq!(max_chunked::<Int32Type>(&empty), None);
+    }
 }
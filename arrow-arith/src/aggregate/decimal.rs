@@ -0,0 +1,456 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Overflow aware sum and average of decimal arrays
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::i256;
+use arrow_schema::{
+    ArrowError, DataType, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION,
+    DECIMAL256_MAX_SCALE,
+};
+use std::sync::Arc;
+
+/// Determines the behaviour of [`sum_decimal`] and [`avg_decimal`] when the result
+/// cannot be represented by the output type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecimalOverflowMode {
+    /// Return an [`ArrowError::ArithmeticOverflow`]
+    #[default]
+    Error,
+    /// Return the largest, or smallest, value representable by the output type
+    Saturate,
+    /// Return a [`Decimal256Array`] with precision [`DECIMAL256_MAX_PRECISION`] if the
+    /// input is a [`Decimal128Array`], otherwise return an [`ArrowError::ArithmeticOverflow`]
+    Promote,
+}
+
+/// The total of the valid values in a decimal array
+#[derive(Debug, Clone, Copy)]
+enum Total {
+    /// The exact total
+    Value(i256),
+    /// The total overflowed an [`i256`]
+    Overflow { negative: bool },
+}
+
+fn total<T: DecimalType>(
+    array: &PrimitiveArray<T>,
+    to_i256: impl Fn(T::Native) -> i256,
+) -> Option<(Total, usize)> {
+    let mut sum = i256::ZERO;
+    let mut count = 0;
+    for value in array.iter().flatten() {
+        let value = to_i256(value);
+        count += 1;
+        match sum.checked_add(value) {
+            Some(s) => sum = s,
+            None => {
+                let negative = value.is_negative();
+                return Some((Total::Overflow { negative }, count));
+            }
+        }
+    }
+    (count != 0).then_some((Total::Value(sum), count))
+}
+
+/// The precision, scale, and total of a decimal array
+struct DecimalTotal {
+    is_decimal128: bool,
+    precision: u8,
+    scale: i8,
+    total: Option<(Total, usize)>,
+}
+
+impl DecimalTotal {
+    fn try_new(array: &dyn Array, name: &str) -> Result<Self, ArrowError> {
+        let (is_decimal128, precision, scale, total) = match array.data_type() {
+            DataType::Decimal128(p, s) => {
+                let array = array.as_primitive::<Decimal128Type>();
+                (true, *p, *s, total(array, i256::from_i128))
+            }
+            DataType::Decimal256(p, s) => {
+                let array = array.as_primitive::<Decimal256Type>();
+                (false, *p, *s, total(array, |v| v))
+            }
+            d => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{name} not supported for {d}"
+                )))
+            }
+        };
+        Ok(Self {
+            is_decimal128,
+            precision,
+            scale,
+            total,
+        })
+    }
+
+    fn max_precision(&self) -> u8 {
+        match self.is_decimal128 {
+            true => DECIMAL128_MAX_PRECISION,
+            false => DECIMAL256_MAX_PRECISION,
+        }
+    }
+
+    fn max_scale(&self) -> i8 {
+        match self.is_decimal128 {
+            true => DECIMAL128_MAX_SCALE,
+            false => DECIMAL256_MAX_SCALE,
+        }
+    }
+
+    /// Returns a [`Scalar`] containing `value` with the given output `precision` and `scale`,
+    /// handling values that exceed `precision` according to `mode`
+    fn finish(
+        &self,
+        name: &str,
+        value: Option<Total>,
+        precision: u8,
+        scale: i8,
+        mode: DecimalOverflowMode,
+    ) -> Result<Scalar<ArrayRef>, ArrowError> {
+        let max = max_for_precision(precision);
+        let (value, negative) = match value {
+            None => return Ok(decimal_scalar(None, self.is_decimal128, precision, scale)),
+            Some(Total::Value(v)) if v <= max && v >= max.wrapping_neg() => {
+                return Ok(decimal_scalar(
+                    Some(v),
+                    self.is_decimal128,
+                    precision,
+                    scale,
+                ))
+            }
+            Some(Total::Value(v)) => (Some(v), v.is_negative()),
+            Some(Total::Overflow { negative }) => (None, negative),
+        };
+
+        match mode {
+            DecimalOverflowMode::Saturate => {
+                let v = if negative { max.wrapping_neg() } else { max };
+                Ok(decimal_scalar(
+                    Some(v),
+                    self.is_decimal128,
+                    precision,
+                    scale,
+                ))
+            }
+            DecimalOverflowMode::Promote if self.is_decimal128 => {
+                // A sum of Decimal128 values cannot overflow an i256
+                let precision = DECIMAL256_MAX_PRECISION;
+                let max = max_for_precision(precision);
+                match value {
+                    Some(v) if v <= max && v >= max.wrapping_neg() => {
+                        Ok(decimal_scalar(Some(v), false, precision, scale))
+                    }
+                    _ => Err(overflow_error(name, precision, scale, false)),
+                }
+            }
+            _ => Err(overflow_error(name, precision, scale, self.is_decimal128)),
+        }
+    }
+}
+
+/// Returns the largest value with `precision` digits
+fn max_for_precision(precision: u8) -> i256 {
+    i256::from_i128(10).wrapping_pow(precision as u32) - i256::ONE
+}
+
+fn overflow_error(name: &str, precision: u8, scale: i8, is_decimal128: bool) -> ArrowError {
+    let data_type = match is_decimal128 {
+        true => DataType::Decimal128(precision, scale),
+        false => DataType::Decimal256(precision, scale),
+    };
+    ArrowError::ArithmeticOverflow(format!(
+        "Overflow happened on {name}, result exceeds {data_type}"
+    ))
+}
+
+fn decimal_scalar(
+    value: Option<i256>,
+    is_decimal128: bool,
+    precision: u8,
+    scale: i8,
+) -> Scalar<ArrayRef> {
+    let array: ArrayRef = match is_decimal128 {
+        true => Arc::new(
+            Decimal128Array::from(vec![value.map(|v| v.as_i128())])
+                .with_data_type(DataType::Decimal128(precision, scale)),
+        ),
+        false => Arc::new(
+            Decimal256Array::from(vec![value])
+                .with_data_type(DataType::Decimal256(precision, scale)),
+        ),
+    };
+    Scalar::new(array)
+}
+
+/// Returns the sum of the non-null values in a [`Decimal128Array`] or [`Decimal256Array`]
+///
+/// For an input with precision `p` and scale `s`, the result has the same decimal type
+/// with precision `p + 10`, capped at the maximum precision of the type, and scale `s`.
+/// Returns a null [`Scalar`] if the array is empty or only contains null values.
+///
+/// If the sum cannot be represented by the output type, `mode` determines
+/// whether to error, saturate, or promote to a [`Decimal256Array`]
+///
+/// ```
+/// # use arrow_array::{Array, Decimal128Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{Decimal128Type, Decimal256Type};
+/// # use arrow_arith::aggregate::{sum_decimal, DecimalOverflowMode};
+/// # use arrow_buffer::i256;
+/// # use arrow_schema::DataType;
+/// let array = Decimal128Array::from(vec![i128::MAX / 10; 20])
+///     .with_precision_and_scale(38, 2)
+///     .unwrap();
+///
+/// assert!(sum_decimal(&array, DecimalOverflowMode::Error).is_err());
+///
+/// let sum = sum_decimal(&array, DecimalOverflowMode::Saturate).unwrap().into_inner();
+/// assert_eq!(sum.data_type(), &DataType::Decimal128(38, 2));
+/// assert_eq!(sum.as_primitive::<Decimal128Type>().value(0), 10_i128.pow(38) - 1);
+///
+/// let sum = sum_decimal(&array, DecimalOverflowMode::Promote).unwrap().into_inner();
+/// assert_eq!(sum.data_type(), &DataType::Decimal256(76, 2));
+/// let expected = i256::from_i128(i128::MAX / 10) * i256::from_i128(20);
+/// assert_eq!(sum.as_primitive::<Decimal256Type>().value(0), expected);
+/// ```
+pub fn sum_decimal(
+    array: &dyn Array,
+    mode: DecimalOverflowMode,
+) -> Result<Scalar<ArrayRef>, ArrowError> {
+    let total = DecimalTotal::try_new(array, "sum_decimal")?;
+    let precision = total
+        .precision
+        .saturating_add(10)
+        .min(total.max_precision());
+    let value = total.total.map(|(t, _)| t);
+    total.finish("sum_decimal", value, precision, total.scale, mode)
+}
+
+/// Returns the average of the non-null values in a [`Decimal128Array`] or [`Decimal256Array`]
+///
+/// For an input with precision `p` and scale `s`, the result has the same decimal type
+/// with precision `p + 4` and scale `s + 4`, capped at the maximum precision and scale
+/// of the type. The average is truncated towards zero. Returns a null [`Scalar`] if the
+/// array is empty or only contains null values.
+///
+/// If the average cannot be represented by the output type, `mode` determines
+/// whether to error, saturate, or promote to a [`Decimal256Array`]
+///
+/// ```
+/// # use arrow_array::{Array, Decimal128Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Decimal128Type;
+/// # use arrow_arith::aggregate::{avg_decimal, DecimalOverflowMode};
+/// # use arrow_schema::DataType;
+/// let array = Decimal128Array::from(vec![Some(100), None, Some(200), Some(200)])
+///     .with_precision_and_scale(10, 2)
+///     .unwrap();
+///
+/// let avg = avg_decimal(&array, DecimalOverflowMode::Error).unwrap().into_inner();
+/// assert_eq!(avg.data_type(), &DataType::Decimal128(14, 6));
+/// assert_eq!(avg.as_primitive::<Decimal128Type>().value(0), 1_666_666);
+/// ```
+pub fn avg_decimal(
+    array: &dyn Array,
+    mode: DecimalOverflowMode,
+) -> Result<Scalar<ArrayRef>, ArrowError> {
+    let total = DecimalTotal::try_new(array, "avg_decimal")?;
+    let precision = total.precision.saturating_add(4).min(total.max_precision());
+    let scale = total.scale.saturating_add(4).min(total.max_scale());
+    let mul = i256::from_i128(10).wrapping_pow((scale - total.scale) as u32);
+
+    let value = total.total.map(|(t, count)| match t {
+        Total::Value(v) => match v.checked_mul(mul) {
+            Some(v) => Total::Value(v / i256::from_i128(count as i128)),
+            None => Total::Overflow {
+                negative: v.is_negative(),
+            },
+        },
+        overflow => overflow,
+    });
+    total.finish("avg_decimal", value, precision, scale, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal128(values: Vec<Option<i128>>, precision: u8, scale: i8) -> Decimal128Array {
+        Decimal128Array::from(values)
+            .with_precision_and_scale(precision, scale)
+            .unwrap()
+    }
+
+    fn decimal256(values: Vec<Option<i256>>, precision: u8, scale: i8) -> Decimal256Array {
+        Decimal256Array::from(values)
+            .with_precision_and_scale(precision, scale)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sum_decimal() {
+        let array = decimal128(vec![Some(123), None, Some(-23)], 5, 2);
+        for mode in [
+            DecimalOverflowMode::Error,
+            DecimalOverflowMode::Saturate,
+            DecimalOverflowMode::Promote,
+        ] {
+            let sum = sum_decimal(&array, mode).unwrap().into_inner();
+            assert_eq!(sum.as_ref(), &decimal128(vec![Some(100)], 15, 2));
+        }
+
+        // Empty and all null arrays
+        let array = decimal128(vec![None, None], 5, 2);
+        let sum = sum_decimal(&array, DecimalOverflowMode::Error)
+            .unwrap()
+            .into_inner();
+        assert_eq!(sum.as_ref(), &decimal128(vec![None], 15, 2));
+        let sum = sum_decimal(&array.slice(0, 0), DecimalOverflowMode::Error)
+            .unwrap()
+            .into_inner();
+        assert_eq!(sum.as_ref(), &decimal128(vec![None], 15, 2));
+
+        let array = decimal256(
+            vec![Some(i256::from_i128(5)), Some(i256::MINUS_ONE)],
+            70,
+            -1,
+        );
+        let sum = sum_decimal(&array, DecimalOverflowMode::Error)
+            .unwrap()
+            .into_inner();
+        assert_eq!(
+            sum.as_ref(),
+            &decimal256(vec![Some(i256::from_i128(4))], 76, -1)
+        );
+    }
+
+    #[test]
+    fn test_sum_decimal128_overflow() {
+        let max = 10_i128.pow(38) - 1;
+        let array = decimal128(vec![Some(-max), Some(-max), None], 38, 0);
+
+        let err = sum_decimal(&array, DecimalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on sum_decimal, result exceeds Decimal128(38, 0)"
+        );
+
+        let sum = sum_decimal(&array, DecimalOverflowMode::Saturate)
+            .unwrap()
+            .into_inner();
+        assert_eq!(sum.as_ref(), &decimal128(vec![Some(-max)], 38, 0));
+
+        let sum = sum_decimal(&array, DecimalOverflowMode::Promote)
+            .unwrap()
+            .into_inner();
+        let expected = i256::from_i128(-max) * i256::from_i128(2);
+        assert_eq!(sum.as_ref(), &decimal256(vec![Some(expected)], 76, 0));
+
+        // Does not promote if the result fits
+        let sum = sum_decimal(&array.slice(0, 1), DecimalOverflowMode::Promote)
+            .unwrap()
+            .into_inner();
+        assert_eq!(sum.as_ref(), &decimal128(vec![Some(-max)], 38, 0));
+    }
+
+    #[test]
+    fn test_sum_decimal256_overflow() {
+        let max = max_for_precision(76);
+        let array = decimal256(vec![Some(max); 10], 76, 0);
+        for mode in [DecimalOverflowMode::Error, DecimalOverflowMode::Promote] {
+            let err = sum_decimal(&array, mode).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Arithmetic overflow: Overflow happened on sum_decimal, result exceeds Decimal256(76, 0)"
+            );
+        }
+
+        // Overflows i256
+        let sum = sum_decimal(&array, DecimalOverflowMode::Saturate)
+            .unwrap()
+            .into_inner();
+        assert_eq!(sum.as_ref(), &decimal256(vec![Some(max)], 76, 0));
+    }
+
+    #[test]
+    fn test_avg_decimal() {
+        let array = decimal128(vec![Some(1), Some(2), None, Some(-6)], 10, 0);
+        let avg = avg_decimal(&array, DecimalOverflowMode::Error)
+            .unwrap()
+            .into_inner();
+        // Truncated towards zero
+        assert_eq!(avg.as_ref(), &decimal128(vec![Some(-10_000)], 14, 4));
+
+        let array = decimal128(vec![Some(2), Some(1)], 10, 0);
+        let avg = avg_decimal(&array, DecimalOverflowMode::Error)
+            .unwrap()
+            .into_inner();
+        assert_eq!(avg.as_ref(), &decimal128(vec![Some(15_000)], 14, 4));
+
+        // Scale is capped at the maximum
+        let array = decimal128(vec![Some(1), Some(2)], 38, 36);
+        let avg = avg_decimal(&array, DecimalOverflowMode::Error)
+            .unwrap()
+            .into_inner();
+        assert_eq!(avg.as_ref(), &decimal128(vec![Some(150)], 38, 38));
+
+        let array = decimal128(vec![None], 10, 0);
+        let avg = avg_decimal(&array, DecimalOverflowMode::Error)
+            .unwrap()
+            .into_inner();
+        assert_eq!(avg.as_ref(), &decimal128(vec![None], 14, 4));
+    }
+
+    #[test]
+    fn test_avg_decimal_overflow() {
+        let max = 10_i128.pow(38) - 1;
+        let array = decimal128(vec![Some(max), Some(max)], 38, 0);
+
+        let err = avg_decimal(&array, DecimalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on avg_decimal, result exceeds Decimal128(38, 4)"
+        );
+
+        let avg = avg_decimal(&array, DecimalOverflowMode::Saturate)
+            .unwrap()
+            .into_inner();
+        assert_eq!(avg.as_ref(), &decimal128(vec![Some(max)], 38, 4));
+
+        let avg = avg_decimal(&array, DecimalOverflowMode::Promote)
+            .unwrap()
+            .into_inner();
+        let expected = i256::from_i128(max) * i256::from_i128(10_000);
+        assert_eq!(avg.as_ref(), &decimal256(vec![Some(expected)], 76, 4));
+    }
+
+    #[test]
+    fn test_decimal_unsupported() {
+        let array = Int32Array::from(vec![1]);
+        let err = sum_decimal(&array, DecimalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: sum_decimal not supported for Int32"
+        );
+    }
+}
@@ -0,0 +1,383 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Grouped aggregations, accumulating values into per-group state
+//!
+//! Each row of the input is assigned to a group by a `UInt32Array` of group ids,
+//! as might be computed by a hash table in a `GROUP BY`. State is stored in a
+//! dense buffer indexed by group id, that grows as new group ids are observed,
+//! and can be updated with any number of batches before calling `finish`
+
+use arrow_array::types::Int64Type;
+use arrow_array::*;
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
+use arrow_schema::ArrowError;
+
+/// Returns the group ids of `group_ids`, validating they are non-null and
+/// of the same length as `values`
+fn group_ids<'a>(group_ids: &'a UInt32Array, values: &dyn Array) -> Result<&'a [u32], ArrowError> {
+    if group_ids.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Group ids of length {} do not match values of length {}",
+            group_ids.len(),
+            values.len()
+        )));
+    }
+    if group_ids.null_count() != 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Group ids must not contain nulls".to_string(),
+        ));
+    }
+    Ok(group_ids.values())
+}
+
+/// Returns the number of groups required to store the state of `group_ids`
+fn required_groups(group_ids: &[u32]) -> usize {
+    group_ids.iter().max().map(|x| *x as usize + 1).unwrap_or(0)
+}
+
+/// Calls `f` with the row index and group id of each non-null row in `values`
+fn for_each_valid(nulls: Option<&NullBuffer>, group_ids: &[u32], mut f: impl FnMut(usize, usize)) {
+    match nulls.filter(|n| n.null_count() != 0) {
+        Some(n) => n
+            .valid_indices()
+            .for_each(|idx| f(idx, group_ids[idx] as usize)),
+        None => group_ids
+            .iter()
+            .enumerate()
+            .for_each(|(idx, g)| f(idx, *g as usize)),
+    }
+}
+
+/// Builds the [`NullBuffer`] of groups that have observed at least one value
+fn finish_nulls(seen: &mut BooleanBufferBuilder) -> Option<NullBuffer> {
+    Some(NullBuffer::new(seen.finish())).filter(|n| n.null_count() != 0)
+}
+
+/// Per-group state for [`sum_grouped`]
+#[derive(Debug)]
+pub struct GroupedSum<T: ArrowNumericType> {
+    sums: Vec<T::Native>,
+    seen: BooleanBufferBuilder,
+}
+
+impl<T: ArrowNumericType> Default for GroupedSum<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ArrowNumericType> GroupedSum<T> {
+    /// Creates a new, empty [`GroupedSum`]
+    pub fn new() -> Self {
+        Self {
+            sums: vec![],
+            seen: BooleanBufferBuilder::new(0),
+        }
+    }
+
+    /// Returns the number of groups in this state
+    pub fn num_groups(&self) -> usize {
+        self.sums.len()
+    }
+
+    /// Grows the state to contain at least `num_groups` groups
+    pub fn resize(&mut self, num_groups: usize) {
+        if num_groups > self.sums.len() {
+            self.seen.append_n(num_groups - self.sums.len(), false);
+            self.sums.resize(num_groups, T::default_value());
+        }
+    }
+
+    /// Returns the sum of each group, resetting the state
+    ///
+    /// Groups without any non-null values are null
+    pub fn finish(&mut self) -> PrimitiveArray<T> {
+        let nulls = finish_nulls(&mut self.seen);
+        let sums = std::mem::take(&mut self.sums);
+        PrimitiveArray::new(sums.into(), nulls)
+    }
+}
+
+/// Adds the non-null `values` to the sum of the group identified by the
+/// corresponding index of `group_ids`
+///
+/// This doesn't detect overflow, once overflowing the result will wrap around
+///
+/// Returns an error if `group_ids` contains nulls or has a different length to `values`
+///
+/// ```
+/// # use arrow_array::{Int32Array, UInt32Array};
+/// # use arrow_arith::aggregate::{sum_grouped, GroupedSum};
+/// let mut state = GroupedSum::new();
+/// let values = Int32Array::from(vec![Some(1), Some(2), None, Some(4)]);
+/// let groups = UInt32Array::from(vec![0, 1, 2, 0]);
+/// sum_grouped(&mut state, &values, &groups).unwrap();
+/// assert_eq!(state.finish(), Int32Array::from(vec![Some(5), Some(2), None]));
+/// ```
+pub fn sum_grouped<T: ArrowNumericType>(
+    state: &mut GroupedSum<T>,
+    values: &PrimitiveArray<T>,
+    group_ids: &UInt32Array,
+) -> Result<(), ArrowError> {
+    let groups = self::group_ids(group_ids, values)?;
+    state.resize(required_groups(groups));
+
+    let data = values.values();
+    for_each_valid(values.nulls(), groups, |idx, g| {
+        state.sums[g] = state.sums[g].add_wrapping(data[idx]);
+        state.seen.set_bit(g, true);
+    });
+    Ok(())
+}
+
+/// Per-group state for [`min_max_grouped`]
+#[derive(Debug)]
+pub struct GroupedMinMax<T: ArrowNumericType> {
+    min: Vec<T::Native>,
+    max: Vec<T::Native>,
+    seen: BooleanBufferBuilder,
+}
+
+impl<T: ArrowNumericType> Default for GroupedMinMax<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ArrowNumericType> GroupedMinMax<T> {
+    /// Creates a new, empty [`GroupedMinMax`]
+    pub fn new() -> Self {
+        Self {
+            min: vec![],
+            max: vec![],
+            seen: BooleanBufferBuilder::new(0),
+        }
+    }
+
+    /// Returns the number of groups in this state
+    pub fn num_groups(&self) -> usize {
+        self.min.len()
+    }
+
+    /// Grows the state to contain at least `num_groups` groups
+    pub fn resize(&mut self, num_groups: usize) {
+        if num_groups > self.min.len() {
+            self.seen.append_n(num_groups - self.min.len(), false);
+            self.min.resize(num_groups, T::default_value());
+            self.max.resize(num_groups, T::default_value());
+        }
+    }
+
+    /// Returns the minimum and maximum of each group, resetting the state
+    ///
+    /// Groups without any non-null values are null
+    pub fn finish(&mut self) -> (PrimitiveArray<T>, PrimitiveArray<T>) {
+        let nulls = finish_nulls(&mut self.seen);
+        let min = std::mem::take(&mut self.min);
+        let max = std::mem::take(&mut self.max);
+        (
+            PrimitiveArray::new(min.into(), nulls.clone()),
+            PrimitiveArray::new(max.into(), nulls),
+        )
+    }
+}
+
+/// Updates the minimum and maximum of the group identified by the corresponding
+/// index of `group_ids` with the non-null `values`
+///
+/// For floating point arrays any NaN values are considered to be greater than any
+/// other non-null value
+///
+/// Returns an error if `group_ids` contains nulls or has a different length to `values`
+///
+/// ```
+/// # use arrow_array::{Int32Array, UInt32Array};
+/// # use arrow_arith::aggregate::{min_max_grouped, GroupedMinMax};
+/// let mut state = GroupedMinMax::new();
+/// let values = Int32Array::from(vec![Some(3), Some(2), None, Some(1)]);
+/// let groups = UInt32Array::from(vec![0, 1, 2, 0]);
+/// min_max_grouped(&mut state, &values, &groups).unwrap();
+/// let (min, max) = state.finish();
+/// assert_eq!(min, Int32Array::from(vec![Some(1), Some(2), None]));
+/// assert_eq!(max, Int32Array::from(vec![Some(3), Some(2), None]));
+/// ```
+pub fn min_max_grouped<T: ArrowNumericType>(
+    state: &mut GroupedMinMax<T>,
+    values: &PrimitiveArray<T>,
+    group_ids: &UInt32Array,
+) -> Result<(), ArrowError> {
+    let groups = self::group_ids(group_ids, values)?;
+    state.resize(required_groups(groups));
+
+    let data = values.values();
+    for_each_valid(values.nulls(), groups, |idx, g| {
+        let value = data[idx];
+        if !state.seen.get_bit(g) {
+            state.min[g] = value;
+            state.max[g] = value;
+            state.seen.set_bit(g, true);
+        } else {
+            if value.is_lt(state.min[g]) {
+                state.min[g] = value;
+            }
+            if value.is_gt(state.max[g]) {
+                state.max[g] = value;
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Per-group state for [`count_grouped`]
+#[derive(Debug, Default)]
+pub struct GroupedCount {
+    counts: Vec<i64>,
+}
+
+impl GroupedCount {
+    /// Creates a new, empty [`GroupedCount`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of groups in this state
+    pub fn num_groups(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Grows the state to contain at least `num_groups` groups
+    pub fn resize(&mut self, num_groups: usize) {
+        if num_groups > self.counts.len() {
+            self.counts.resize(num_groups, 0);
+        }
+    }
+
+    /// Returns the number of non-null values in each group, resetting the state
+    pub fn finish(&mut self) -> PrimitiveArray<Int64Type> {
+        std::mem::take(&mut self.counts).into()
+    }
+}
+
+/// Counts the non-null `values` of the group identified by the corresponding
+/// index of `group_ids`
+///
+/// Unlike [`sum_grouped`] and [`min_max_grouped`], `values` may be of any type
+///
+/// Returns an error if `group_ids` contains nulls or has a different length to `values`
+///
+/// ```
+/// # use arrow_array::{Int64Array, StringArray, UInt32Array};
+/// # use arrow_arith::aggregate::{count_grouped, GroupedCount};
+/// let mut state = GroupedCount::new();
+/// let values = StringArray::from(vec![Some("a"), None, Some("c"), Some("d")]);
+/// let groups = UInt32Array::from(vec![0, 1, 0, 2]);
+/// count_grouped(&mut state, &values, &groups).unwrap();
+/// assert_eq!(state.finish(), Int64Array::from(vec![2, 0, 1]));
+/// ```
+pub fn count_grouped(
+    state: &mut GroupedCount,
+    values: &dyn Array,
+    group_ids: &UInt32Array,
+) -> Result<(), ArrowError> {
+    let groups = self::group_ids(group_ids, values)?;
+    state.resize(required_groups(groups));
+
+    let nulls = values.logical_nulls();
+    for_each_valid(nulls.as_ref(), groups, |_, g| state.counts[g] += 1);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::*;
+
+    #[test]
+    fn test_sum_grouped() {
+        let mut state = GroupedSum::<Int64Type>::new();
+        let values = Int64Array::from(vec![Some(1), None, Some(3), Some(i64::MAX)]);
+        let groups = UInt32Array::from(vec![1, 0, 1, 3]);
+        sum_grouped(&mut state, &values, &groups).unwrap();
+        assert_eq!(state.num_groups(), 4);
+
+        let values = Int64Array::from(vec![10, 20, 1]);
+        let groups = UInt32Array::from(vec![4, 1, 3]);
+        sum_grouped(&mut state, &values, &groups).unwrap();
+
+        let expected = Int64Array::from(vec![None, Some(24), None, Some(i64::MIN), Some(10)]);
+        assert_eq!(state.finish(), expected);
+
+        // State is reset after finish
+        assert_eq!(state.num_groups(), 0);
+        let values = Int64Array::from(vec![5]);
+        sum_grouped(&mut state, &values, &UInt32Array::from(vec![0])).unwrap();
+        assert_eq!(state.finish(), Int64Array::from(vec![5]));
+    }
+
+    #[test]
+    fn test_min_max_grouped() {
+        let mut state = GroupedMinMax::<Float64Type>::new();
+        let values = Float64Array::from(vec![Some(1.), Some(f64::NAN), None, Some(-2.), Some(0.)]);
+        let groups = UInt32Array::from(vec![0, 0, 1, 0, 2]);
+        min_max_grouped(&mut state, &values, &groups).unwrap();
+        state.resize(4);
+
+        let (min, max) = state.finish();
+        assert_eq!(
+            min,
+            Float64Array::from(vec![Some(-2.), None, Some(0.), None])
+        );
+        assert_eq!(max.len(), 4);
+        assert!(max.value(0).is_nan());
+        assert_eq!(max.nulls(), min.nulls());
+        assert_eq!(max.value(2), 0.);
+    }
+
+    #[test]
+    fn test_count_grouped() {
+        let mut state = GroupedCount::new();
+        let keys = Int32Array::from(vec![Some(0), None, Some(1), Some(0)]);
+        let values = DictionaryArray::new(
+            keys,
+            std::sync::Arc::new(Int32Array::from(vec![None, Some(1)])),
+        );
+        let groups = UInt32Array::from(vec![1, 1, 1, 0]);
+        count_grouped(&mut state, &values, &groups).unwrap();
+        assert_eq!(state.finish(), Int64Array::from(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_grouped_invalid() {
+        let mut state = GroupedCount::new();
+        let values = Int32Array::from(vec![1, 2]);
+
+        let groups = UInt32Array::from(vec![0]);
+        let err = count_grouped(&mut state, &values, &groups).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Group ids of length 1 do not match values of length 2"
+        );
+
+        let groups = UInt32Array::from(vec![Some(0), None]);
+        let err = count_grouped(&mut state, &values, &groups).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Group ids must not contain nulls"
+        );
+    }
+}
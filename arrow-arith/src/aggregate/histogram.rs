@@ -0,0 +1,295 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Histograms of numeric and temporal values
+
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+
+/// Counts the values of `array` falling into each of the bins defined by `bin_edges`
+///
+/// `bin_edges` must contain at least two non-null values, sorted in ascending order,
+/// with `n + 1` edges defining `n` bins. Each bin contains the values greater than or
+/// equal to its lower edge and less than its upper edge, except the last bin, which also
+/// contains values equal to its upper edge.
+///
+/// Returns a [`UInt64Array`] with the number of values in each bin. Null values, and
+/// values outside the range of `bin_edges`, are not counted.
+///
+/// Supports numeric and temporal arrays, other than intervals, with `bin_edges` of the
+/// same type as `array`. Floating point values are ordered as by [`f64::total_cmp`], and
+/// so NaN values fall outside bins with finite or infinite edges.
+///
+/// ```
+/// # use arrow_array::{Float64Array, UInt64Array};
+/// # use arrow_arith::aggregate::histogram;
+/// let array = Float64Array::from(vec![Some(0.5), Some(1.0), None, Some(2.5), Some(3.0), Some(4.0)]);
+/// let bin_edges = Float64Array::from(vec![0.0, 1.0, 2.0, 3.0]);
+/// let counts = histogram(&array, &bin_edges).unwrap();
+/// assert_eq!(counts, UInt64Array::from(vec![1, 1, 2]));
+/// ```
+pub fn histogram(array: &dyn Array, bin_edges: &dyn Array) -> Result<UInt64Array, ArrowError> {
+    check_types("histogram", array, bin_edges)?;
+    downcast_primitive_array!(
+        array => histogram_impl(array, bin_edges),
+        _ => unreachable!()
+    )
+}
+
+fn histogram_impl<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    bin_edges: &dyn Array,
+) -> Result<UInt64Array, ArrowError> {
+    let edges = check_edges::<T>("histogram", bin_edges)?;
+    let mut counts = vec![0; edges.len() - 1];
+    array
+        .iter()
+        .flatten()
+        .filter_map(|v| bin_index(edges, v))
+        .for_each(|idx| counts[idx] += 1);
+    Ok(counts.into())
+}
+
+/// Returns the index of the bin defined by `bin_edges` containing each value of `array`
+///
+/// Bins are defined as in [`histogram`]. Returns a [`UInt32Array`] of the same length as
+/// `array`, which is null for null values, and values outside the range of `bin_edges`.
+///
+/// ```
+/// # use arrow_array::{Int32Array, UInt32Array};
+/// # use arrow_arith::aggregate::bucketize;
+/// let array = Int32Array::from(vec![Some(5), Some(-1), Some(10), None, Some(25), Some(30)]);
+/// let bin_edges = Int32Array::from(vec![0, 10, 20, 30]);
+/// let bins = bucketize(&array, &bin_edges).unwrap();
+/// assert_eq!(bins, UInt32Array::from(vec![Some(0), None, Some(1), None, Some(2), Some(2)]));
+/// ```
+pub fn bucketize(array: &dyn Array, bin_edges: &dyn Array) -> Result<UInt32Array, ArrowError> {
+    check_types("bucketize", array, bin_edges)?;
+    if bin_edges.len() > u32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "bucketize supports at most {} bin edges, got {}",
+            u32::MAX,
+            bin_edges.len()
+        )));
+    }
+    downcast_primitive_array!(
+        array => bucketize_impl(array, bin_edges),
+        _ => unreachable!()
+    )
+}
+
+fn bucketize_impl<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    bin_edges: &dyn Array,
+) -> Result<UInt32Array, ArrowError> {
+    let edges = check_edges::<T>("bucketize", bin_edges)?;
+    let bins = array
+        .iter()
+        .map(|v| v.and_then(|v| bin_index(edges, v)).map(|idx| idx as u32))
+        .collect();
+    Ok(bins)
+}
+
+fn check_types(name: &str, array: &dyn Array, bin_edges: &dyn Array) -> Result<(), ArrowError> {
+    let data_type = array.data_type();
+    let supported = data_type.is_numeric() || data_type.is_temporal();
+    if !supported || matches!(data_type, DataType::Interval(_)) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} not supported for {data_type}"
+        )));
+    }
+    if bin_edges.data_type() != data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} bin edges must have the same type as the array, got {} vs {data_type}",
+            bin_edges.data_type()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the values of `edges`, checking they define at least one bin
+fn check_edges<'a, T: ArrowPrimitiveType>(
+    name: &str,
+    edges: &'a dyn Array,
+) -> Result<&'a [T::Native], ArrowError> {
+    let edges = edges.as_primitive::<T>();
+    if edges.len() < 2 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} requires at least two bin edges, got {}",
+            edges.len()
+        )));
+    }
+    if edges.null_count() != 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} bin edges must not contain nulls"
+        )));
+    }
+    let values = edges.values().as_ref();
+    if values.windows(2).any(|w| w[1].is_lt(w[0])) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} bin edges must be sorted in ascending order"
+        )));
+    }
+    Ok(values)
+}
+
+/// Returns the index of the bin defined by `edges` containing `v`
+fn bin_index<N: ArrowNativeTypeOp>(edges: &[N], v: N) -> Option<usize> {
+    let last = edges.len() - 1;
+    if v.is_lt(edges[0]) || v.is_gt(edges[last]) {
+        return None;
+    }
+    let idx = edges.partition_point(|e| e.is_le(v)) - 1;
+    Some(idx.min(last - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_histogram() {
+        let array = Int32Array::from(vec![
+            Some(-5),
+            Some(0),
+            Some(9),
+            None,
+            Some(10),
+            Some(19),
+            Some(20),
+            Some(21),
+        ]);
+        let bin_edges = Int32Array::from(vec![0, 10, 20]);
+        let counts = histogram(&array, &bin_edges).unwrap();
+        assert_eq!(counts, UInt64Array::from(vec![2, 3]));
+        let bins = bucketize(&array, &bin_edges).unwrap();
+        let expected = UInt32Array::from(vec![
+            None,
+            Some(0),
+            Some(0),
+            None,
+            Some(1),
+            Some(1),
+            Some(1),
+            None,
+        ]);
+        assert_eq!(bins, expected);
+
+        // Repeated edges define empty bins
+        let bin_edges = Int32Array::from(vec![0, 10, 10, 20]);
+        let counts = histogram(&array, &bin_edges).unwrap();
+        assert_eq!(counts, UInt64Array::from(vec![2, 0, 3]));
+
+        let array = Int32Array::from(Vec::<i32>::new());
+        let counts = histogram(&array, &bin_edges).unwrap();
+        assert_eq!(counts, UInt64Array::from(vec![0, 0, 0]));
+    }
+
+    #[test]
+    fn test_histogram_float() {
+        let array = Float64Array::from(vec![
+            f64::NEG_INFINITY,
+            -1.0,
+            f64::NAN,
+            -0.0,
+            0.0,
+            0.5,
+            f64::INFINITY,
+        ]);
+        let bin_edges = Float64Array::from(vec![f64::NEG_INFINITY, 0.0, f64::INFINITY]);
+        let counts = histogram(&array, &bin_edges).unwrap();
+        assert_eq!(counts, UInt64Array::from(vec![3, 3]));
+
+        let bin_edges = Float64Array::from(vec![-1.0, 0.0, 1.0]);
+        let bins = bucketize(&array, &bin_edges).unwrap();
+        let expected =
+            UInt32Array::from(vec![None, Some(0), None, Some(0), Some(1), Some(1), None]);
+        assert_eq!(bins, expected);
+    }
+
+    #[test]
+    fn test_histogram_temporal() {
+        let array = TimestampSecondArray::from(vec![0, 3_599, 3_600, 86_400]).with_timezone("UTC");
+        let bin_edges =
+            TimestampSecondArray::from(vec![0, 3_600, 7_200, 86_400]).with_timezone("UTC");
+        let counts = histogram(&array, &bin_edges).unwrap();
+        assert_eq!(counts, UInt64Array::from(vec![2, 1, 1]));
+
+        let array = Date32Array::from(vec![Some(1), None, Some(40)]).slice(1, 2);
+        let bin_edges = Date32Array::from(vec![0, 31, 59]);
+        let bins = bucketize(&array, &bin_edges).unwrap();
+        assert_eq!(bins, UInt32Array::from(vec![None, Some(1)]));
+
+        let array = Decimal128Array::from(vec![150, 250])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let bin_edges = Decimal128Array::from(vec![100, 200, 300])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let counts = histogram(&array, &bin_edges).unwrap();
+        assert_eq!(counts, UInt64Array::from(vec![1, 1]));
+    }
+
+    #[test]
+    fn test_histogram_invalid() {
+        let array = Int32Array::from(vec![1]);
+
+        let bin_edges = Int32Array::from(vec![1]);
+        let err = histogram(&array, &bin_edges).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: histogram requires at least two bin edges, got 1"
+        );
+
+        let bin_edges = Int32Array::from(vec![Some(1), None]);
+        let err = bucketize(&array, &bin_edges).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: bucketize bin edges must not contain nulls"
+        );
+
+        let bin_edges = Int32Array::from(vec![1, 3, 2]);
+        let err = histogram(&array, &bin_edges).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: histogram bin edges must be sorted in ascending order"
+        );
+
+        let bin_edges = Int64Array::from(vec![1, 2]);
+        let err = histogram(&array, &bin_edges).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: histogram bin edges must have the same type as the array, got Int64 vs Int32"
+        );
+
+        let array = IntervalYearMonthArray::from(vec![1]);
+        let err = histogram(&array, &array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: histogram not supported for Interval(YearMonth)"
+        );
+
+        let array: DictionaryArray<Int32Type> = vec!["a"].into_iter().collect();
+        let err = bucketize(&array, &array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: bucketize not supported for Dictionary(Int32, Utf8)"
+        );
+    }
+}
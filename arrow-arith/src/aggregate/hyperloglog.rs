@@ -0,0 +1,397 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Approximate distinct counting using HyperLogLog
+
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, ToByteSlice};
+use arrow_schema::{ArrowError, DataType};
+use std::hash::Hasher;
+use twox_hash::XxHash64;
+
+/// The seed of the hash function, this must not change as sketches
+/// may be persisted and merged with sketches created elsewhere
+const SEED: u64 = 0;
+
+/// Returns the [XXH64] hash of `bytes`
+///
+/// [XXH64]: https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = XxHash64::with_seed(SEED);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// A [HyperLogLog] sketch for estimating the number of distinct values
+///
+/// Sketches can be updated with arrays of any supported type, and merged with
+/// other sketches of the same precision, allowing partial aggregates computed
+/// for multiple batches or partitions to be combined
+///
+/// Each value is hashed using [XXH64] with a seed of `0`, over the following bytes:
+///
+/// * Primitive values: the little-endian bytes of the value, as stored in an Arrow array
+/// * Booleans: a single byte, `1` for `true` and `0` for `false`
+/// * Strings: the UTF-8 bytes of the string
+/// * Binary values: the bytes of the value
+///
+/// As this does not depend on the platform or process, the [registers](Self::registers)
+/// of a sketch can be persisted and later merged with sketches created elsewhere, and
+/// [`Self::insert_hash`] can be used to add values hashed in the same way by other systems.
+///
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use arrow_arith::aggregate::HyperLogLog;
+/// let mut a = HyperLogLog::new();
+/// a.update(&Int32Array::from_iter_values(0..1000)).unwrap();
+///
+/// let mut b = HyperLogLog::new();
+/// b.update(&Int32Array::from_iter_values(500..1500)).unwrap();
+///
+/// a.merge(&b).unwrap();
+/// let estimate = a.estimate();
+/// assert!((1450..1550).contains(&estimate));
+/// ```
+///
+/// [HyperLogLog]: https://en.wikipedia.org/wiki/HyperLogLog
+/// [XXH64]: https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    /// The default precision, giving a standard error of approximately 0.8%
+    pub const DEFAULT_PRECISION: u8 = 14;
+
+    /// The minimum supported precision
+    pub const MIN_PRECISION: u8 = 4;
+
+    /// The maximum supported precision
+    pub const MAX_PRECISION: u8 = 18;
+
+    /// Creates an empty sketch with [`Self::DEFAULT_PRECISION`]
+    pub fn new() -> Self {
+        Self {
+            precision: Self::DEFAULT_PRECISION,
+            registers: vec![0; 1 << Self::DEFAULT_PRECISION],
+        }
+    }
+
+    /// Creates an empty sketch with `2^precision` registers
+    ///
+    /// Higher precisions give more accurate estimates at the cost of more memory,
+    /// the standard error is approximately `1.04 / sqrt(2^precision)`
+    pub fn try_new(precision: u8) -> Result<Self, ArrowError> {
+        Self::check_precision(precision)?;
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// Creates a sketch from the registers of another sketch, as returned by [`Self::registers`]
+    pub fn try_from_registers(registers: Vec<u8>) -> Result<Self, ArrowError> {
+        let precision = registers.len().trailing_zeros() as u8;
+        if !registers.len().is_power_of_two() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "HyperLogLog register count must be a power of two, got {}",
+                registers.len()
+            )));
+        }
+        Self::check_precision(precision)?;
+        let max = 64 - precision + 1;
+        if let Some(r) = registers.iter().find(|r| **r > max) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "HyperLogLog register value {r} exceeds maximum of {max}"
+            )));
+        }
+        Ok(Self {
+            precision,
+            registers,
+        })
+    }
+
+    fn check_precision(precision: u8) -> Result<(), ArrowError> {
+        if !(Self::MIN_PRECISION..=Self::MAX_PRECISION).contains(&precision) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "HyperLogLog precision must be between {} and {}, got {precision}",
+                Self::MIN_PRECISION,
+                Self::MAX_PRECISION
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the precision of this sketch
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Returns the registers of this sketch, which can be used to persist it
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+
+    /// Adds the non-null values of `array` to this sketch
+    ///
+    /// The values of dictionary arrays are added, as opposed to the keys
+    pub fn update(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        for_each_hash(array, &mut |_, hash| self.insert_hash(hash))
+    }
+
+    /// Adds a 64-bit hash of a value to this sketch
+    ///
+    /// To be merged with sketches updated using [`Self::update`], the hash must be
+    /// computed as described on [`HyperLogLog`]
+    pub fn insert_hash(&mut self, hash: u64) {
+        let idx = (hash >> (64 - self.precision)) as usize;
+        // The number of leading zeros of the remaining bits, plus one
+        let rank = ((hash << self.precision) | (1 << (self.precision - 1))).leading_zeros() + 1;
+        let register = &mut self.registers[idx];
+        *register = (*register).max(rank as u8);
+    }
+
+    /// Merges `other` into this sketch, such that this sketch estimates the
+    /// number of distinct values added to either sketch
+    ///
+    /// Returns an error if the sketches have different precisions
+    pub fn merge(&mut self, other: &Self) -> Result<(), ArrowError> {
+        if self.precision != other.precision {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot merge HyperLogLog sketches with precision {} and {}",
+                self.precision, other.precision
+            )));
+        }
+        self.registers
+            .iter_mut()
+            .zip(&other.registers)
+            .for_each(|(a, b)| *a = (*a).max(*b));
+        Ok(())
+    }
+
+    /// Returns the estimated number of distinct values added to this sketch
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1. + 1.079 / m),
+        };
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            // Use linear counting for small cardinalities
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+}
+
+/// Calls `f` with the index and hash of each non-null value of `array`
+fn for_each_hash(array: &dyn Array, f: &mut dyn FnMut(usize, u64)) -> Result<(), ArrowError> {
+    let nulls = array.logical_nulls();
+    let is_valid = |idx: usize| nulls.as_ref().map(|n| n.is_valid(idx)).unwrap_or(true);
+
+    macro_rules! hash {
+        ($array:expr, |$a:ident, $idx:ident| $value:expr) => {{
+            let $a = $array;
+            for $idx in (0..$a.len()).filter(|i| is_valid(*i)) {
+                f($idx, hash_bytes($value))
+            }
+        }};
+    }
+
+    downcast_primitive_array!(
+        array => hash!(array, |a, i| a.value(i).to_byte_slice()),
+        DataType::Null => {}
+        DataType::Boolean => hash!(array.as_boolean(), |a, i| &[a.value(i) as u8]),
+        DataType::Utf8 => hash!(array.as_string::<i32>(), |a, i| a.value(i).as_bytes()),
+        DataType::LargeUtf8 => hash!(array.as_string::<i64>(), |a, i| a.value(i).as_bytes()),
+        DataType::Utf8View => hash!(array.as_string_view(), |a, i| a.value(i).as_bytes()),
+        DataType::Binary => hash!(array.as_binary::<i32>(), |a, i| a.value(i)),
+        DataType::LargeBinary => hash!(array.as_binary::<i64>(), |a, i| a.value(i)),
+        DataType::BinaryView => hash!(array.as_binary_view(), |a, i| a.value(i)),
+        DataType::FixedSizeBinary(_) => {
+            hash!(array.as_fixed_size_binary(), |a, i| a.value(i))
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array!(
+            array => {
+                // Hash each value once, and then look up the hash of each key
+                let mut hashes = vec![None; array.values().len()];
+                for_each_hash(array.values().as_ref(), &mut |idx, hash| hashes[idx] = Some(hash))?;
+                for (idx, key) in array.keys().iter().enumerate() {
+                    if let Some(hash) = key.and_then(|k| hashes[k.as_usize()]) {
+                        f(idx, hash)
+                    }
+                }
+            }
+            _ => unreachable!()
+        ),
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "HyperLogLog not supported for {d}"
+            )))
+        }
+    );
+    Ok(())
+}
+
+/// Returns the approximate number of distinct non-null values in `array`
+///
+/// This uses a [`HyperLogLog`] sketch with [`HyperLogLog::DEFAULT_PRECISION`]
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_arith::aggregate::approx_distinct;
+/// let array = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+/// assert_eq!(approx_distinct(&array).unwrap(), 2);
+/// ```
+pub fn approx_distinct(array: &dyn Array) -> Result<u64, ArrowError> {
+    let mut sketch = HyperLogLog::new();
+    sketch.update(array)?;
+    Ok(sketch.estimate())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    fn assert_within(estimate: u64, expected: u64, tolerance: f64) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(
+            error <= tolerance,
+            "estimate {estimate} not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_approx_distinct() {
+        let array = Int64Array::from_iter_values((0..100_000).map(|x| x % 50_000));
+        assert_within(approx_distinct(&array).unwrap(), 50_000, 0.02);
+
+        let array = Float32Array::from(vec![Some(1.), None, Some(2.), Some(1.), None]);
+        assert_eq!(approx_distinct(&array).unwrap(), 2);
+
+        let strings: Vec<_> = (0..10_000).map(|x| format!("value{}", x % 1000)).collect();
+        let array = StringArray::from_iter_values(&strings);
+        assert_within(approx_distinct(&array).unwrap(), 1000, 0.02);
+        let array = StringViewArray::from_iter_values(&strings);
+        assert_within(approx_distinct(&array).unwrap(), 1000, 0.02);
+
+        let array = BooleanArray::from(vec![true, false, true]);
+        assert_eq!(approx_distinct(&array).unwrap(), 2);
+
+        let array = NullArray::new(10);
+        assert_eq!(approx_distinct(&array).unwrap(), 0);
+
+        let array = ListArray::new_null(Arc::new(Field::new_list_field(DataType::Int32, true)), 1);
+        let err = approx_distinct(&array).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("HyperLogLog not supported for List"));
+    }
+
+    #[test]
+    fn test_approx_distinct_dictionary() {
+        let values = StringArray::from(vec!["a", "b", "c", "unused"]);
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(0), Some(2)]);
+        let dictionary = DictionaryArray::new(keys, Arc::new(values));
+        assert_eq!(approx_distinct(&dictionary).unwrap(), 3);
+
+        let mut a = HyperLogLog::new();
+        a.update(&dictionary).unwrap();
+        let mut b = HyperLogLog::new();
+        b.update(&StringArray::from(vec!["c", "a", "b"])).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_stable_hash() {
+        // Known XXH64 hashes with a seed of 0
+        assert_eq!(hash_bytes(b""), 0xef46db3751d8e999);
+        assert_eq!(hash_bytes(b"abc"), 0x44bc2cf5ad770999);
+
+        let mut a = HyperLogLog::new();
+        a.update(&StringArray::from(vec!["abc"])).unwrap();
+        let mut b = HyperLogLog::new();
+        b.insert_hash(0x44bc2cf5ad770999);
+        assert_eq!(a, b);
+
+        let mut a = HyperLogLog::new();
+        a.update(&Int32Array::from(vec![7])).unwrap();
+        let mut b = HyperLogLog::new();
+        b.update(&BinaryArray::from(vec![7i32.to_le_bytes().as_slice()]))
+            .unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = HyperLogLog::try_new(12).unwrap();
+        a.update(&UInt32Array::from_iter_values(0..20_000)).unwrap();
+        let mut b = HyperLogLog::try_new(12).unwrap();
+        b.update(&UInt32Array::from_iter_values(10_000..30_000))
+            .unwrap();
+        a.merge(&b).unwrap();
+        assert_within(a.estimate(), 30_000, 0.05);
+
+        let restored = HyperLogLog::try_from_registers(a.registers().to_vec()).unwrap();
+        assert_eq!(restored, a);
+        assert_eq!(restored.precision(), 12);
+
+        let err = a.merge(&HyperLogLog::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot merge HyperLogLog sketches with precision 12 and 14"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let err = HyperLogLog::try_new(2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: HyperLogLog precision must be between 4 and 18, got 2"
+        );
+
+        let err = HyperLogLog::try_from_registers(vec![0; 100]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: HyperLogLog register count must be a power of two, got 100"
+        );
+
+        let err = HyperLogLog::try_from_registers(vec![100; 16]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: HyperLogLog register value 100 exceeds maximum of 61"
+        );
+    }
+}
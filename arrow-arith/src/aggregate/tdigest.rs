@@ -0,0 +1,494 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Approximate quantiles using a merging t-digest
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use num::ToPrimitive;
+use std::f64::consts::PI;
+
+/// A cluster of values within a [`TDigest`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    /// Creates a new centroid with the provided `mean` and `weight`
+    pub fn new(mean: f64, weight: f64) -> Self {
+        Self { mean, weight }
+    }
+
+    /// The mean of the values in this centroid
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The number of values in this centroid
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    fn merge(&mut self, other: &Self) {
+        let weight = self.weight + other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / weight;
+        self.weight = weight;
+    }
+}
+
+/// A [t-digest] sketch for estimating quantiles of numeric values
+///
+/// Sketches can be updated with arrays of any numeric type, including decimals,
+/// and merged with other sketches, allowing partial aggregates computed for
+/// multiple batches or partitions to be combined. Null and NaN values are ignored
+///
+/// ```
+/// # use arrow_array::{Float64Array, Int32Array};
+/// # use arrow_arith::aggregate::TDigest;
+/// let mut a = TDigest::new();
+/// a.update(&Int32Array::from_iter_values(0..500)).unwrap();
+///
+/// let mut b = TDigest::new();
+/// b.update(&Float64Array::from_iter_values((500..1000).map(|x| x as f64))).unwrap();
+///
+/// a.merge(&b);
+/// let median = a.quantile(0.5).unwrap().unwrap();
+/// assert!((490. ..510.).contains(&median));
+/// ```
+///
+/// [t-digest]: https://arxiv.org/abs/1902.04023
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    compression: usize,
+    centroids: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TDigest {
+    /// The default compression, bounding the sketch to at most a few hundred centroids
+    pub const DEFAULT_COMPRESSION: usize = 100;
+
+    /// The number of values buffered by [`Self::update`] before being merged into
+    /// the centroids, as a multiple of the compression
+    const BUFFER_FACTOR: usize = 5;
+
+    /// Creates an empty sketch with [`Self::DEFAULT_COMPRESSION`]
+    pub fn new() -> Self {
+        Self::new_with_compression(Self::DEFAULT_COMPRESSION)
+    }
+
+    /// Creates an empty sketch with the provided `compression`
+    ///
+    /// Higher compressions give more accurate estimates at the cost of more memory,
+    /// the number of centroids retained is proportional to `compression`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `compression` is zero
+    pub fn new_with_compression(compression: usize) -> Self {
+        assert!(compression > 0, "compression must be greater than zero");
+        Self {
+            compression,
+            centroids: vec![],
+            count: 0.,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Creates a sketch from the [`Centroid`] of another sketch, as returned by [`Self::centroids`]
+    ///
+    /// Returns an error if any centroid has a non-finite mean or non-positive weight
+    pub fn try_from_centroids(
+        compression: usize,
+        centroids: Vec<Centroid>,
+        min: f64,
+        max: f64,
+    ) -> Result<Self, ArrowError> {
+        if compression == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "TDigest compression must be greater than zero".to_string(),
+            ));
+        }
+        if let Some(c) = centroids
+            .iter()
+            .find(|c| !c.mean.is_finite() || c.weight.is_nan() || c.weight <= 0.)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid TDigest centroid with mean {} and weight {}",
+                c.mean, c.weight
+            )));
+        }
+        let mut digest = Self::new_with_compression(compression);
+        if !centroids.is_empty() {
+            digest.min = min;
+            digest.max = max;
+            digest.compress(centroids);
+        }
+        Ok(digest)
+    }
+
+    /// Returns the compression of this sketch
+    pub fn compression(&self) -> usize {
+        self.compression
+    }
+
+    /// Returns the centroids of this sketch, ordered by mean
+    pub fn centroids(&self) -> &[Centroid] {
+        &self.centroids
+    }
+
+    /// Returns the number of values added to this sketch
+    pub fn count(&self) -> u64 {
+        self.count as u64
+    }
+
+    /// Returns the minimum value added to this sketch, if any
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0.).then_some(self.min)
+    }
+
+    /// Returns the maximum value added to this sketch, if any
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0.).then_some(self.max)
+    }
+
+    /// Adds the non-null values of `array` to this sketch
+    ///
+    /// Values are buffered and merged into the centroids in fixed size chunks,
+    /// so the memory used does not depend on the length of `array`
+    ///
+    /// Returns an error if `array` is not of a numeric type
+    pub fn update(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        let capacity = self.compression.saturating_mul(Self::BUFFER_FACTOR);
+        let mut buffer = Vec::with_capacity(capacity.min(array.len()));
+        for_each_value(array, &mut |v| {
+            buffer.push(v);
+            if buffer.len() == capacity {
+                self.flush(&mut buffer);
+            }
+        })?;
+        self.flush(&mut buffer);
+        Ok(())
+    }
+
+    /// Merges the buffered `values` into the centroids of this sketch, clearing `values`
+    fn flush(&mut self, values: &mut Vec<f64>) {
+        if values.is_empty() {
+            return;
+        }
+        let mut centroids = Vec::with_capacity(self.centroids.len() + values.len());
+        centroids.extend_from_slice(&self.centroids);
+        for v in values.drain(..) {
+            self.min = self.min.min(v);
+            self.max = self.max.max(v);
+            centroids.push(Centroid::new(v, 1.));
+        }
+        self.compress(centroids);
+    }
+
+    /// Merges `other` into this sketch
+    pub fn merge(&mut self, other: &Self) {
+        if other.centroids.is_empty() {
+            return;
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        let mut centroids = Vec::with_capacity(self.centroids.len() + other.centroids.len());
+        centroids.extend_from_slice(&self.centroids);
+        centroids.extend_from_slice(&other.centroids);
+        self.compress(centroids);
+    }
+
+    /// Returns the estimated value at quantile `q`, or `None` if the sketch is empty
+    ///
+    /// Returns an error if `q` is not within `[0, 1]`
+    pub fn quantile(&self, q: f64) -> Result<Option<f64>, ArrowError> {
+        if !(0. ..=1.).contains(&q) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Quantile must be within [0, 1], got {q}"
+            )));
+        }
+        Ok(self.estimate(q))
+    }
+
+    fn estimate(&self, q: f64) -> Option<f64> {
+        let (first, last) = (self.centroids.first()?, self.centroids.last()?);
+        if self.centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        let target = q * self.count;
+        // Values between min and the center of the first centroid
+        if target < first.weight / 2. {
+            let fraction = target / (first.weight / 2.);
+            return Some(self.min + (first.mean - self.min) * fraction);
+        }
+        // Values between the center of the last centroid and max
+        if target > self.count - last.weight / 2. {
+            let fraction = (self.count - target) / (last.weight / 2.);
+            return Some(self.max - (self.max - last.mean) * fraction);
+        }
+
+        // Interpolate between the centers of adjacent centroids
+        let mut center = first.weight / 2.;
+        for w in self.centroids.windows(2) {
+            let next = center + (w[0].weight + w[1].weight) / 2.;
+            if target <= next {
+                let fraction = (target - center) / (next - center);
+                let value = w[0].mean + (w[1].mean - w[0].mean) * fraction;
+                return Some(value.clamp(self.min, self.max));
+            }
+            center = next;
+        }
+        Some(last.mean)
+    }
+
+    /// Sorts and merges `centroids` so that each centroid spans at most one unit of
+    /// the `k1` scale function, and stores the result in `self`
+    fn compress(&mut self, mut centroids: Vec<Centroid>) {
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let delta = self.compression as f64;
+
+        // k1(q) = delta / (2 * PI) * asin(2q - 1), with inverse used to find the
+        // largest quantile the current centroid may extend to
+        let k = |q: f64| delta / (2. * PI) * (2. * q - 1.).asin();
+        let k_inv = |k: f64| ((k * 2. * PI / delta).clamp(-PI / 2., PI / 2.).sin() + 1.) / 2.;
+
+        let mut iter = centroids.into_iter();
+        let Some(mut current) = iter.next() else {
+            return;
+        };
+        let mut out = Vec::with_capacity(self.compression);
+        let mut so_far = 0.;
+        let mut limit = k_inv(k(0.) + 1.);
+        for next in iter {
+            if (so_far + current.weight + next.weight) / total <= limit {
+                current.merge(&next);
+            } else {
+                so_far += current.weight;
+                limit = k_inv(k(so_far / total) + 1.);
+                out.push(current);
+                current = next;
+            }
+        }
+        out.push(current);
+
+        self.centroids = out;
+        self.count = total;
+    }
+}
+
+/// Calls `f` with each non-null, non-NaN value of `array` as `f64`
+fn for_each_value(array: &dyn Array, f: &mut dyn FnMut(f64)) -> Result<(), ArrowError> {
+    fn values<T: ArrowPrimitiveType>(
+        array: &dyn Array,
+        to_f64: impl Fn(T::Native) -> Option<f64>,
+        f: &mut dyn FnMut(f64),
+    ) {
+        let array = array.as_primitive::<T>();
+        array
+            .iter()
+            .flatten()
+            .filter_map(to_f64)
+            .filter(|v| !v.is_nan())
+            .for_each(f)
+    }
+
+    match array.data_type() {
+        DataType::Int8 => values::<Int8Type>(array, |v| v.to_f64(), f),
+        DataType::Int16 => values::<Int16Type>(array, |v| v.to_f64(), f),
+        DataType::Int32 => values::<Int32Type>(array, |v| v.to_f64(), f),
+        DataType::Int64 => values::<Int64Type>(array, |v| v.to_f64(), f),
+        DataType::UInt8 => values::<UInt8Type>(array, |v| v.to_f64(), f),
+        DataType::UInt16 => values::<UInt16Type>(array, |v| v.to_f64(), f),
+        DataType::UInt32 => values::<UInt32Type>(array, |v| v.to_f64(), f),
+        DataType::UInt64 => values::<UInt64Type>(array, |v| v.to_f64(), f),
+        DataType::Float16 => values::<Float16Type>(array, |v| Some(v.to_f64()), f),
+        DataType::Float32 => values::<Float32Type>(array, |v| v.to_f64(), f),
+        DataType::Float64 => values::<Float64Type>(array, Some, f),
+        DataType::Decimal128(_, scale) => {
+            let div = 10_f64.powi(*scale as i32);
+            values::<Decimal128Type>(array, |v| Some(v as f64 / div), f)
+        }
+        DataType::Decimal256(_, scale) => {
+            let div = 10_f64.powi(*scale as i32);
+            values::<Decimal256Type>(array, |v| v.to_f64().map(|v| v / div), f)
+        }
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "TDigest not supported for {d}"
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Returns the approximate value at quantile `q` of the non-null values in `array`,
+/// or `None` if there are no such values
+///
+/// This uses a [`TDigest`] sketch with [`TDigest::DEFAULT_COMPRESSION`]
+///
+/// Returns an error if `q` is not within `[0, 1]` or `array` is not of a numeric type
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_arith::aggregate::approx_quantile;
+/// let array = Int32Array::from(vec![Some(5), None, Some(1), Some(3)]);
+/// assert_eq!(approx_quantile(&array, 0.5).unwrap(), Some(3.));
+/// ```
+pub fn approx_quantile(array: &dyn Array, q: f64) -> Result<Option<f64>, ArrowError> {
+    let mut digest = TDigest::new();
+    digest.quantile(q)?;
+    digest.update(array)?;
+    digest.quantile(q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+
+    fn assert_within(estimate: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (estimate - expected).abs() <= tolerance,
+            "estimate {estimate} not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_approx_quantile() {
+        let array = Int64Array::from_iter_values(0..100_001);
+        assert_eq!(approx_quantile(&array, 0.).unwrap(), Some(0.));
+        assert_eq!(approx_quantile(&array, 1.).unwrap(), Some(100_000.));
+        for q in [0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99] {
+            let estimate = approx_quantile(&array, q).unwrap().unwrap();
+            assert_within(estimate, q * 100_000., 500.);
+        }
+
+        let array = Float64Array::from(vec![Some(2.), None, Some(f64::NAN), Some(1.)]);
+        assert_eq!(approx_quantile(&array, 0.).unwrap(), Some(1.));
+        assert_eq!(approx_quantile(&array, 1.).unwrap(), Some(2.));
+
+        let array = UInt8Array::from(vec![None, None]);
+        assert_eq!(approx_quantile(&array, 0.5).unwrap(), None);
+
+        let err = approx_quantile(&array, 1.5).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Quantile must be within [0, 1], got 1.5"
+        );
+
+        let err = approx_quantile(&StringArray::from(vec!["a"]), 0.5).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: TDigest not supported for Utf8"
+        );
+    }
+
+    #[test]
+    fn test_approx_quantile_decimal() {
+        let array = Decimal128Array::from(vec![Some(150), None, Some(250), Some(350)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        assert_eq!(approx_quantile(&array, 0.5).unwrap(), Some(2.5));
+
+        let array = Decimal256Array::from(vec![i256::from(-150), i256::from(250)])
+            .with_precision_and_scale(10, 1)
+            .unwrap();
+        assert_eq!(approx_quantile(&array, 0.).unwrap(), Some(-15.));
+        assert_eq!(approx_quantile(&array, 1.).unwrap(), Some(25.));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut digests: Vec<_> = (0..10)
+            .map(|i| {
+                let mut digest = TDigest::new();
+                let values =
+                    Float32Array::from_iter_values((i..50_000).step_by(10).map(|x| x as f32));
+                digest.update(&values).unwrap();
+                digest
+            })
+            .collect();
+
+        let mut merged = digests.pop().unwrap();
+        digests.iter().for_each(|d| merged.merge(d));
+        assert_eq!(merged.count(), 50_000);
+        assert_eq!(merged.min(), Some(0.));
+        assert_eq!(merged.max(), Some(49_999.));
+        assert!(merged.centroids().len() <= 2 * TDigest::DEFAULT_COMPRESSION);
+        assert_within(merged.quantile(0.5).unwrap().unwrap(), 25_000., 250.);
+        assert_within(merged.quantile(0.99).unwrap().unwrap(), 49_500., 100.);
+
+        let restored =
+            TDigest::try_from_centroids(100, merged.centroids().to_vec(), 0., 49_999.).unwrap();
+        assert_eq!(restored, merged);
+
+        let err =
+            TDigest::try_from_centroids(100, vec![Centroid::new(1., 0.)], 1., 1.).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid TDigest centroid with mean 1 and weight 0"
+        );
+
+        let mut empty = TDigest::new();
+        empty.merge(&TDigest::new());
+        assert_eq!(empty.quantile(0.5).unwrap(), None);
+        assert_eq!(empty.min(), None);
+    }
+
+    #[test]
+    fn test_update_bounded() {
+        let mut digest = TDigest::new_with_compression(20);
+        digest
+            .update(&Int32Array::from_iter_values(0..100_000))
+            .unwrap();
+        assert_eq!(digest.count(), 100_000);
+        assert!(digest.centroids().len() <= 2 * 20);
+        assert_within(digest.quantile(0.5).unwrap().unwrap(), 50_000., 2_500.);
+
+        // Updating in many small batches gives comparable estimates
+        let mut batched = TDigest::new_with_compression(20);
+        for chunk in (0..100_000).collect::<Vec<_>>().chunks(7) {
+            batched
+                .update(&Int32Array::from_iter_values(chunk.iter().copied()))
+                .unwrap();
+        }
+        assert_eq!(batched.count(), 100_000);
+        assert!(batched.centroids().len() <= 2 * 20);
+        assert_within(batched.quantile(0.5).unwrap().unwrap(), 50_000., 2_500.);
+
+        for q in [-0.1, 1.1, f64::NAN] {
+            let err = digest.quantile(q).unwrap_err();
+            assert!(err.to_string().contains("Quantile must be within [0, 1]"));
+        }
+    }
+}
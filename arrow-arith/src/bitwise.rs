@@ -18,10 +18,12 @@
 //! Module contains bitwise operations on arrays
 
 use crate::arity::{binary, unary};
+use arrow_array::types::UInt32Type;
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
 use arrow_schema::ArrowError;
 use num::traits::{WrappingShl, WrappingShr};
+use num::PrimInt;
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
 /// The helper function for bitwise operation with two array
@@ -118,6 +120,18 @@ where
     Ok(unary(array, |value| !value))
 }
 
+/// Count the number of set bits (popcount) of each value in an array. If array value is null
+/// then the result is also null.
+///
+/// Negative values are counted using their two's complement representation
+pub fn bit_count<T>(array: &PrimitiveArray<T>) -> Result<UInt32Array, ArrowError>
+where
+    T: ArrowNumericType,
+    T::Native: PrimInt,
+{
+    Ok(array.unary::<_, UInt32Type>(|value| value.count_ones()))
+}
+
 /// Perform `left & !right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 pub fn bitwise_and_not<T>(
@@ -314,6 +328,21 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn test_bit_count() {
+        let array = UInt64Array::from(vec![Some(0), Some(7), None, Some(u64::MAX)]);
+        let expected = UInt32Array::from(vec![Some(0), Some(3), None, Some(64)]);
+        assert_eq!(bit_count(&array).unwrap(), expected);
+
+        let array = Int8Array::from(vec![Some(-1), Some(5), None, Some(i8::MIN)]);
+        let expected = UInt32Array::from(vec![Some(8), Some(2), None, Some(1)]);
+        assert_eq!(bit_count(&array).unwrap(), expected);
+
+        let array = Int32Array::from(vec![1, 2, 3, 4, 5, -1]).slice(2, 4);
+        let expected = UInt32Array::from(vec![2, 1, 2, 32]);
+        assert_eq!(bit_count(&array).unwrap(), expected);
+    }
+
     #[test]
     fn test_bitwise_and_not_array() {
         // unsigned value
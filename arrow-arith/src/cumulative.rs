@@ -0,0 +1,402 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines cumulative aggregation kernels, such as [`cumsum`], returning the running
+//! aggregate of the values of an array up to and including each row
+
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+
+/// Determines how cumulative kernels, such as [`cumsum`], handle null values
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NullHandling {
+    /// Null values are ignored, returning null for the row of each null value, and the
+    /// running aggregate of the valid values for all other rows
+    #[default]
+    Skip,
+    /// Null values are propagated, returning null for the row of the first null value
+    /// and all subsequent rows
+    Propagate,
+}
+
+/// Returns the running sum of the values of `array`, returning an error on overflow
+///
+/// Supports integer, floating point and decimal arrays. Decimal arrays return a decimal
+/// array with the maximum precision for the type, and the same scale as `array`,
+/// returning an error if the sum exceeds this precision.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_arith::cumulative::{cumsum, NullHandling};
+/// let array = Int32Array::from(vec![Some(1), Some(2), None, Some(3)]);
+///
+/// let result = cumsum(&array, NullHandling::Skip).unwrap();
+/// let expected = Int32Array::from(vec![Some(1), Some(3), None, Some(6)]);
+/// assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+///
+/// let result = cumsum(&array, NullHandling::Propagate).unwrap();
+/// let expected = Int32Array::from(vec![Some(1), Some(3), None, None]);
+/// assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+/// ```
+pub fn cumsum(array: &dyn Array, nulls: NullHandling) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Sum, array, nulls)
+}
+
+/// Returns the running sum of the values of `array`, wrapping on overflow for
+/// [`DataType::is_integer`]
+///
+/// See [`cumsum`] for more details
+pub fn cumsum_wrapping(array: &dyn Array, nulls: NullHandling) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::SumWrapping, array, nulls)
+}
+
+/// Returns the running product of the values of `array`, returning an error on overflow
+///
+/// Supports integer and floating point arrays
+pub fn cumprod(array: &dyn Array, nulls: NullHandling) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Prod, array, nulls)
+}
+
+/// Returns the running product of the values of `array`, wrapping on overflow for
+/// [`DataType::is_integer`]
+///
+/// Supports integer and floating point arrays
+pub fn cumprod_wrapping(array: &dyn Array, nulls: NullHandling) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::ProdWrapping, array, nulls)
+}
+
+/// Returns the running minimum of the values of `array`
+///
+/// Supports integer, floating point and decimal arrays. For floating point arrays any
+/// NaN values are considered to be greater than any other non-null value.
+pub fn cummin(array: &dyn Array, nulls: NullHandling) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Min, array, nulls)
+}
+
+/// Returns the running maximum of the values of `array`
+///
+/// Supports integer, floating point and decimal arrays. For floating point arrays any
+/// NaN values are considered to be greater than any other non-null value.
+///
+/// ```
+/// # use arrow_array::Float64Array;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Float64Type;
+/// # use arrow_arith::cumulative::{cummax, NullHandling};
+/// let array = Float64Array::from(vec![Some(1.5), None, Some(0.5), Some(2.0)]);
+/// let result = cummax(&array, NullHandling::Skip).unwrap();
+/// let expected = Float64Array::from(vec![Some(1.5), None, Some(1.5), Some(2.0)]);
+/// assert_eq!(result.as_primitive::<Float64Type>(), &expected);
+/// ```
+pub fn cummax(array: &dyn Array, nulls: NullHandling) -> Result<ArrayRef, ArrowError> {
+    cumulative_op(Op::Max, array, nulls)
+}
+
+/// An enumeration of cumulative aggregations
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Sum,
+    SumWrapping,
+    Prod,
+    ProdWrapping,
+    Min,
+    Max,
+}
+
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Sum => write!(f, "cumsum"),
+            Op::SumWrapping => write!(f, "cumsum_wrapping"),
+            Op::Prod => write!(f, "cumprod"),
+            Op::ProdWrapping => write!(f, "cumprod_wrapping"),
+            Op::Min => write!(f, "cummin"),
+            Op::Max => write!(f, "cummax"),
+        }
+    }
+}
+
+/// Dispatch the given `op` to the appropriate specialized kernel
+fn cumulative_op(op: Op, array: &dyn Array, nulls: NullHandling) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Decimal128(_, _) => decimal_op::<Decimal128Type>(op, array, nulls),
+        DataType::Decimal256(_, _) => decimal_op::<Decimal256Type>(op, array, nulls),
+        t if t.is_integer() || t.is_floating() => downcast_primitive_array!(
+            array => Ok(Arc::new(primitive_op(op, array, nulls)?)),
+            _ => unreachable!()
+        ),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "{op} not supported for {t}"
+        ))),
+    }
+}
+
+/// Perform a cumulative aggregation on decimals
+fn decimal_op<T: DecimalType>(
+    op: Op,
+    array: &dyn Array,
+    nulls: NullHandling,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    let result = match op {
+        Op::Sum | Op::SumWrapping => {
+            let result = primitive_op(op, array, nulls)?
+                .with_precision_and_scale(T::MAX_PRECISION, array.scale())?;
+            if matches!(op, Op::Sum) {
+                result.validate_decimal_precision(T::MAX_PRECISION)?;
+            }
+            result
+        }
+        Op::Min | Op::Max => {
+            primitive_op(op, array, nulls)?.with_data_type(array.data_type().clone())
+        }
+        Op::Prod | Op::ProdWrapping => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{op} not supported for {}",
+                array.data_type()
+            )))
+        }
+    };
+    Ok(Arc::new(result))
+}
+
+fn primitive_op<T: ArrowPrimitiveType>(
+    op: Op,
+    array: &PrimitiveArray<T>,
+    nulls: NullHandling,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    match op {
+        Op::Sum => accumulate(array, nulls, |a, b| a.add_checked(b)),
+        Op::SumWrapping => accumulate(array, nulls, |a, b| Ok(a.add_wrapping(b))),
+        Op::Prod => accumulate(array, nulls, |a, b| a.mul_checked(b)),
+        Op::ProdWrapping => accumulate(array, nulls, |a, b| Ok(a.mul_wrapping(b))),
+        Op::Min => accumulate(array, nulls, |a, b| Ok(if b.is_lt(a) { b } else { a })),
+        Op::Max => accumulate(array, nulls, |a, b| Ok(if b.is_gt(a) { b } else { a })),
+    }
+}
+
+/// Returns the running aggregate of `array`, combining the aggregate of the preceding
+/// valid values with each value using `f`
+fn accumulate<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    nulls: NullHandling,
+    f: impl Fn(T::Native, T::Native) -> Result<T::Native, ArrowError>,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    let mut acc: Option<T::Native> = None;
+    let mut seen_null = false;
+    array
+        .iter()
+        .map(|v| {
+            seen_null |= v.is_none();
+            match (v, nulls) {
+                (None, _) => Ok(None),
+                (Some(_), NullHandling::Propagate) if seen_null => Ok(None),
+                (Some(v), _) => {
+                    let next = match acc {
+                        Some(acc) => f(acc, v)?,
+                        None => v,
+                    };
+                    acc = Some(next);
+                    Ok(Some(next))
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+
+    #[test]
+    fn test_cumulative() {
+        let array = Int32Array::from(vec![Some(3), None, Some(-2), Some(5), None, Some(1)]);
+
+        type Kernel = fn(&dyn Array, NullHandling) -> Result<ArrayRef, ArrowError>;
+        let cases: [(Kernel, _, _); 6] = [
+            (
+                cumsum,
+                vec![Some(3), None, Some(1), Some(6), None, Some(7)],
+                vec![Some(3), None, None, None, None, None],
+            ),
+            (
+                cumsum_wrapping,
+                vec![Some(3), None, Some(1), Some(6), None, Some(7)],
+                vec![Some(3), None, None, None, None, None],
+            ),
+            (
+                cumprod,
+                vec![Some(3), None, Some(-6), Some(-30), None, Some(-30)],
+                vec![Some(3), None, None, None, None, None],
+            ),
+            (
+                cumprod_wrapping,
+                vec![Some(3), None, Some(-6), Some(-30), None, Some(-30)],
+                vec![Some(3), None, None, None, None, None],
+            ),
+            (
+                cummin,
+                vec![Some(3), None, Some(-2), Some(-2), None, Some(-2)],
+                vec![Some(3), None, None, None, None, None],
+            ),
+            (
+                cummax,
+                vec![Some(3), None, Some(3), Some(5), None, Some(5)],
+                vec![Some(3), None, None, None, None, None],
+            ),
+        ];
+        for (kernel, skip, propagate) in cases {
+            let result = kernel(&array, NullHandling::Skip).unwrap();
+            assert_eq!(result.as_primitive::<Int32Type>(), &Int32Array::from(skip));
+            let result = kernel(&array, NullHandling::Propagate).unwrap();
+            assert_eq!(
+                result.as_primitive::<Int32Type>(),
+                &Int32Array::from(propagate)
+            );
+        }
+
+        // Leading nulls
+        let array = UInt8Array::from(vec![None, Some(2), Some(3)]);
+        let result = cumsum(&array, NullHandling::Skip).unwrap();
+        let expected = UInt8Array::from(vec![None, Some(2), Some(5)]);
+        assert_eq!(result.as_primitive::<UInt8Type>(), &expected);
+        let result = cumsum(&array, NullHandling::Propagate).unwrap();
+        assert_eq!(result.as_primitive::<UInt8Type>(), &UInt8Array::new_null(3));
+
+        let array = Int64Array::from(Vec::<i64>::new());
+        let result = cumprod(&array, NullHandling::Skip).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_cumulative_overflow() {
+        let array = Int8Array::from(vec![100, 27, 1]);
+        let err = cumsum(&array, NullHandling::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 127 + 1"
+        );
+        let result = cumsum_wrapping(&array, NullHandling::Skip).unwrap();
+        let expected = Int8Array::from(vec![100, 127, -128]);
+        assert_eq!(result.as_primitive::<Int8Type>(), &expected);
+
+        let array = UInt8Array::from(vec![16, 16, 2]);
+        let err = cumprod(&array, NullHandling::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arithmetic overflow: Overflow happened on: 16 * 16"
+        );
+        let result = cumprod_wrapping(&array, NullHandling::Skip).unwrap();
+        let expected = UInt8Array::from(vec![16, 0, 0]);
+        assert_eq!(result.as_primitive::<UInt8Type>(), &expected);
+
+        // Values after a null are not aggregated when propagating nulls
+        let array = Int8Array::from(vec![Some(100), None, Some(100)]);
+        let result = cumsum(&array, NullHandling::Propagate).unwrap();
+        let expected = Int8Array::from(vec![Some(100), None, None]);
+        assert_eq!(result.as_primitive::<Int8Type>(), &expected);
+    }
+
+    #[test]
+    fn test_cumulative_float() {
+        let array = Float64Array::from(vec![Some(1.5), Some(f64::NAN), None, Some(-1.0)]);
+
+        let result = cumsum(&array, NullHandling::Skip).unwrap();
+        let result = result.as_primitive::<Float64Type>();
+        assert_eq!(result.value(0), 1.5);
+        assert!(result.value(1).is_nan());
+        assert!(result.is_null(2));
+        assert!(result.value(3).is_nan());
+
+        let result = cummax(&array, NullHandling::Skip).unwrap();
+        assert!(result.as_primitive::<Float64Type>().value(3).is_nan());
+
+        let result = cummin(&array, NullHandling::Skip).unwrap();
+        let expected = Float64Array::from(vec![Some(1.5), Some(1.5), None, Some(-1.0)]);
+        assert_eq!(result.as_primitive::<Float64Type>(), &expected);
+
+        let array = Float32Array::from(vec![f32::MAX, f32::MAX, 0.5]);
+        let result = cumprod(&array, NullHandling::Skip).unwrap();
+        let expected = Float32Array::from(vec![f32::MAX, f32::INFINITY, f32::INFINITY]);
+        assert_eq!(result.as_primitive::<Float32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_cumulative_decimal() {
+        let array = Decimal128Array::from(vec![Some(150), None, Some(-25), Some(300)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+
+        let result = cumsum(&array, NullHandling::Skip).unwrap();
+        let expected = Decimal128Array::from(vec![Some(150), None, Some(125), Some(425)])
+            .with_precision_and_scale(38, 2)
+            .unwrap();
+        assert_eq!(result.as_primitive::<Decimal128Type>(), &expected);
+
+        let result = cummin(&array, NullHandling::Propagate).unwrap();
+        let expected = Decimal128Array::from(vec![Some(150), None, None, None])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        assert_eq!(result.as_primitive::<Decimal128Type>(), &expected);
+
+        let array = Decimal256Array::from(vec![i256::MAX, i256::ONE])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        let result = cummax(&array, NullHandling::Skip).unwrap();
+        let expected = Decimal256Array::from(vec![i256::MAX, i256::MAX])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        assert_eq!(result.as_primitive::<Decimal256Type>(), &expected);
+
+        // Exceeds the maximum precision without overflowing an i128
+        let max = 10_i128.pow(38) - 1;
+        let array = Decimal128Array::from(vec![max, 1])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let err = cumsum(&array, NullHandling::Skip).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("too large to store in a Decimal128 of precision 38"));
+        let result = cumsum_wrapping(&array, NullHandling::Skip).unwrap();
+        assert_eq!(result.as_primitive::<Decimal128Type>().value(1), max + 1);
+    }
+
+    #[test]
+    fn test_cumulative_unsupported() {
+        let array = Decimal128Array::from(vec![1]);
+        let err = cumprod(&array, NullHandling::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: cumprod not supported for Decimal128(38, 10)"
+        );
+
+        let array = Date32Array::from(vec![1]);
+        let err = cummax(&array, NullHandling::Skip).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: cummax not supported for Date32"
+        );
+    }
+}
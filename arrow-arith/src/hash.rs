@@ -0,0 +1,526 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels computing per-row hashes of arrays, for use in hash joins,
+//! hash aggregation and repartitioning
+//!
+//! Hashes are deterministic for a given `seed` within a build of this crate,
+//! but are not stable across versions or platforms and must not be persisted
+
+use ahash::RandomState;
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, OffsetBuffer, ToByteSlice};
+use arrow_schema::{ArrowError, DataType, UnionMode};
+
+/// Returns the hash of each row of `array`, using the provided `seed`
+///
+/// Values that compare equal produce the same hash, in particular:
+///
+/// * Null values hash to a constant that depends only on `seed`
+/// * Dictionary arrays hash their values, not their keys, and so hash the same
+///   as the equivalent non-dictionary array
+/// * `Utf8`, `LargeUtf8` and `Utf8View` produce the same hash for the same string,
+///   likewise for the binary types
+/// * Nested types combine the hashes of their children, using [`combine_hashes`]
+///
+/// ```
+/// # use arrow_array::{DictionaryArray, StringArray, types::Int8Type};
+/// # use arrow_arith::hash::hash;
+/// let strings = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+/// let hashes = hash(&strings, 0).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(3));
+/// assert_ne!(hashes.value(0), hashes.value(2));
+///
+/// let dictionary: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+/// assert_eq!(hash(&dictionary, 0).unwrap().value(0), hashes.value(0));
+/// ```
+pub fn hash(array: &dyn Array, seed: u64) -> Result<UInt64Array, ArrowError> {
+    let state = random_state(seed);
+    let mut hashes = vec![0; array.len()];
+    hash_array(&state, array, &mut hashes)?;
+    Ok(hashes.into())
+}
+
+/// Returns the hash of each row of `columns`, using the provided `seed`
+///
+/// The hashes of each column, as computed by [`hash`], are combined in order using
+/// [`combine_hashes`]
+///
+/// Returns an error if `columns` is empty or the columns have different lengths
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_arith::hash::hash_columns;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 1]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "x", "x"]));
+/// let hashes = hash_columns(&[a, b], 0).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_ne!(hashes.value(0), hashes.value(1));
+/// ```
+pub fn hash_columns(columns: &[ArrayRef], seed: u64) -> Result<UInt64Array, ArrowError> {
+    let (first, rest) = columns.split_first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("hash_columns requires at least one column".to_string())
+    })?;
+    if let Some(c) = rest.iter().find(|c| c.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "hash_columns requires columns of the same length, got {} and {}",
+            first.len(),
+            c.len()
+        )));
+    }
+
+    let state = random_state(seed);
+    let mut hashes = vec![0; first.len()];
+    hash_array(&state, first.as_ref(), &mut hashes)?;
+    let mut column_hashes = vec![0; first.len()];
+    for column in rest {
+        hash_array(&state, column.as_ref(), &mut column_hashes)?;
+        combine_all(&mut hashes, &column_hashes);
+    }
+    Ok(hashes.into())
+}
+
+/// Combines two hashes into a single hash, the result depends on the order of the arguments
+#[inline]
+pub fn combine_hashes(l: u64, r: u64) -> u64 {
+    let hash = (17 * 37u64).wrapping_add(l);
+    hash.wrapping_mul(37).wrapping_add(r)
+}
+
+fn combine_all(hashes: &mut [u64], other: &[u64]) {
+    hashes
+        .iter_mut()
+        .zip(other)
+        .for_each(|(l, r)| *l = combine_hashes(*l, *r));
+}
+
+fn random_state(seed: u64) -> RandomState {
+    RandomState::with_seeds(
+        seed,
+        0x243f_6a88_85a3_08d3,
+        0x1319_8a2e_0370_7344,
+        0xa409_3822_299f_31d0,
+    )
+}
+
+/// Writes the hash of each row of `array` to `out`
+fn hash_array(state: &RandomState, array: &dyn Array, out: &mut [u64]) -> Result<(), ArrowError> {
+    macro_rules! hash {
+        ($array:expr, |$a:ident, $idx:ident| $value:expr) => {{
+            let $a = $array;
+            for ($idx, h) in out.iter_mut().enumerate() {
+                *h = state.hash_one($value)
+            }
+        }};
+    }
+
+    let null_hash = state.hash_one(());
+    match array.data_type() {
+        // Types whose hashes are derived from children that handle their own nulls
+        DataType::Null => {
+            out.fill(null_hash);
+            return Ok(());
+        }
+        DataType::Dictionary(_, _) => return hash_dictionary(state, array, out),
+        DataType::RunEndEncoded(_, _) => return hash_run_end_encoded(state, array, out),
+        DataType::Union(_, _) => return hash_union(state, array, out),
+        _ => {}
+    }
+
+    downcast_primitive_array!(
+        array => hash!(array, |a, i| a.value(i).to_byte_slice()),
+        DataType::Boolean => hash!(array.as_boolean(), |a, i| a.value(i)),
+        DataType::Utf8 => hash!(array.as_string::<i32>(), |a, i| a.value(i)),
+        DataType::LargeUtf8 => hash!(array.as_string::<i64>(), |a, i| a.value(i)),
+        DataType::Utf8View => hash!(array.as_string_view(), |a, i| a.value(i)),
+        DataType::Binary => hash!(array.as_binary::<i32>(), |a, i| a.value(i)),
+        DataType::LargeBinary => hash!(array.as_binary::<i64>(), |a, i| a.value(i)),
+        DataType::BinaryView => hash!(array.as_binary_view(), |a, i| a.value(i)),
+        DataType::FixedSizeBinary(_) => hash!(array.as_fixed_size_binary(), |a, i| a.value(i)),
+        DataType::List(_) => {
+            let list = array.as_list::<i32>();
+            hash_list(state, list.offsets(), list.values().as_ref(), out)?
+        }
+        DataType::LargeList(_) => {
+            let list = array.as_list::<i64>();
+            hash_list(state, list.offsets(), list.values().as_ref(), out)?
+        }
+        DataType::FixedSizeList(_, size) => {
+            let values = array.as_fixed_size_list().values();
+            let size = *size as usize;
+            let mut child = vec![0; values.len()];
+            hash_array(state, values.as_ref(), &mut child)?;
+            for (idx, h) in out.iter_mut().enumerate() {
+                let range = &child[idx * size..(idx + 1) * size];
+                *h = range.iter().fold(state.hash_one(size), |l, r| combine_hashes(l, *r));
+            }
+        }
+        DataType::Map(_, _) => {
+            let map = array.as_map();
+            hash_list(state, map.offsets(), map.entries(), out)?
+        }
+        DataType::Struct(_) => {
+            let array = array.as_struct();
+            out.fill(state.hash_one(array.num_columns()));
+            let mut child = vec![0; array.len()];
+            for column in array.columns() {
+                hash_array(state, column.as_ref(), &mut child)?;
+                combine_all(out, &child);
+            }
+        }
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hashing not supported for {d}"
+            )))
+        }
+    );
+
+    if let Some(nulls) = array.nulls().filter(|n| n.null_count() != 0) {
+        nulls.iter().zip(out).for_each(|(valid, h)| {
+            if !valid {
+                *h = null_hash
+            }
+        });
+    }
+    Ok(())
+}
+
+fn hash_list<O: OffsetSizeTrait>(
+    state: &RandomState,
+    offsets: &OffsetBuffer<O>,
+    values: &dyn Array,
+    out: &mut [u64],
+) -> Result<(), ArrowError> {
+    // Only hash the values referenced by offsets
+    let start = offsets[0].as_usize();
+    let end = offsets[offsets.len() - 1].as_usize();
+    let values = values.slice(start, end - start);
+    let mut child = vec![0; values.len()];
+    hash_array(state, values.as_ref(), &mut child)?;
+
+    for (h, w) in out.iter_mut().zip(offsets.windows(2)) {
+        let range = &child[w[0].as_usize() - start..w[1].as_usize() - start];
+        *h = range
+            .iter()
+            .fold(state.hash_one(range.len()), |l, r| combine_hashes(l, *r));
+    }
+    Ok(())
+}
+
+fn hash_dictionary(
+    state: &RandomState,
+    array: &dyn Array,
+    out: &mut [u64],
+) -> Result<(), ArrowError> {
+    let null_hash = state.hash_one(());
+    downcast_dictionary_array!(
+        array => {
+            // Hash each value once, and then look up the hash of each key
+            let values = array.values();
+            let mut value_hashes = vec![0; values.len()];
+            hash_array(state, values.as_ref(), &mut value_hashes)?;
+            for (h, key) in out.iter_mut().zip(array.keys()) {
+                *h = match key {
+                    Some(k) => value_hashes[k.as_usize()],
+                    None => null_hash,
+                }
+            }
+            Ok(())
+        }
+        _ => unreachable!()
+    )
+}
+
+fn hash_run_end_encoded(
+    state: &RandomState,
+    array: &dyn Array,
+    out: &mut [u64],
+) -> Result<(), ArrowError> {
+    downcast_run_array!(
+        array => {
+            let run_ends = array.run_ends();
+            if run_ends.is_empty() {
+                return Ok(());
+            }
+            let start = run_ends.get_start_physical_index();
+            let end = run_ends.get_end_physical_index();
+            let values = array.values().slice(start, end + 1 - start);
+            let mut value_hashes = vec![0; values.len()];
+            hash_array(state, values.as_ref(), &mut value_hashes)?;
+
+            let offset = run_ends.offset();
+            let mut idx = 0;
+            for (physical, run_end) in run_ends.values()[start..=end].iter().enumerate() {
+                let run_end = (run_end.as_usize() - offset).min(out.len());
+                out[idx..run_end].fill(value_hashes[physical]);
+                idx = run_end;
+            }
+            Ok(())
+        }
+        _ => unreachable!()
+    )
+}
+
+fn hash_union(state: &RandomState, array: &dyn Array, out: &mut [u64]) -> Result<(), ArrowError> {
+    let DataType::Union(fields, mode) = array.data_type() else {
+        unreachable!()
+    };
+    let array = array.as_union();
+
+    let mut children = vec![vec![]; 128];
+    for (type_id, _) in fields.iter() {
+        let child = array.child(type_id);
+        let hashes = &mut children[type_id as usize];
+        hashes.resize(child.len(), 0);
+        hash_array(state, child.as_ref(), hashes)?;
+    }
+
+    for (idx, (h, type_id)) in out.iter_mut().zip(array.type_ids()).enumerate() {
+        let child_idx = match mode {
+            UnionMode::Sparse => idx,
+            UnionMode::Dense => array.value_offset(idx),
+        };
+        let type_hash = state.hash_one(type_id);
+        *h = combine_hashes(type_hash, children[*type_id as usize][child_idx]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{ListBuilder, StringBuilder};
+    use arrow_array::types::Int16Type;
+    use arrow_buffer::ScalarBuffer;
+    use arrow_schema::{Field, Fields, UnionFields};
+    use std::sync::Arc;
+
+    fn hash_values(array: &dyn Array) -> Vec<u64> {
+        hash(array, 0).unwrap().values().to_vec()
+    }
+
+    #[test]
+    fn test_hash_primitive() {
+        let array = Int32Array::from(vec![Some(1), None, Some(2), Some(1), None]);
+        let hashes = hash_values(&array);
+        assert_eq!(hashes[0], hashes[3]);
+        assert_eq!(hashes[1], hashes[4]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+
+        // Seed changes the hash
+        let seeded = hash(&array, 1).unwrap();
+        assert_ne!(seeded.values()[0], hashes[0]);
+
+        // Slicing doesn't change the hash
+        let sliced = hash(&array.slice(2, 2), 0).unwrap();
+        assert_eq!(sliced.values(), &hashes[2..4]);
+        assert_eq!(sliced.null_count(), 0);
+    }
+
+    #[test]
+    fn test_hash_strings() {
+        let values = vec![
+            Some("hello"),
+            None,
+            Some("a longer string that is not inlined"),
+        ];
+        let expected = hash_values(&StringArray::from(values.clone()));
+        assert_eq!(
+            hash_values(&LargeStringArray::from(values.clone())),
+            expected
+        );
+        assert_eq!(
+            hash_values(&StringViewArray::from(values.clone())),
+            expected
+        );
+
+        let dictionary: DictionaryArray<Int16Type> = values.into_iter().collect();
+        assert_eq!(hash_values(&dictionary), expected);
+
+        let binary = vec![Some(b"hello".as_slice()), None, Some(b"world".as_slice())];
+        let expected = hash_values(&BinaryArray::from(binary.clone()));
+        assert_eq!(
+            hash_values(&LargeBinaryArray::from(binary.clone())),
+            expected
+        );
+        assert_eq!(hash_values(&BinaryViewArray::from(binary)), expected);
+    }
+
+    #[test]
+    fn test_hash_dictionary_values() {
+        // Different keys referencing equal values hash the same
+        let values = StringArray::from(vec![Some("a"), Some("b"), Some("a"), None]);
+        let keys = Int32Array::from(vec![Some(0), Some(2), Some(1), None, Some(3)]);
+        let dictionary = DictionaryArray::new(keys, Arc::new(values));
+        let hashes = hash_values(&dictionary);
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_eq!(hashes[3], hashes[4]);
+    }
+
+    #[test]
+    fn test_hash_run_end_encoded() {
+        let run_ends = Int32Array::from(vec![2, 3, 5]);
+        let values = Int64Array::from(vec![Some(1), None, Some(2)]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let expected = hash_values(&Int64Array::from(vec![
+            Some(1),
+            Some(1),
+            None,
+            Some(2),
+            Some(2),
+        ]));
+        assert_eq!(hash_values(&array), expected);
+        assert_eq!(hash_values(&array.slice(1, 3)), &expected[1..4]);
+    }
+
+    #[test]
+    fn test_hash_list() {
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), Some("b")]);
+        builder.append_null();
+        builder.append_value([Some("a"), Some("b")]);
+        builder.append_value([Some("a")]);
+        builder.append_value([Some("b"), Some("a")]);
+        builder.append_value([None::<&str>]);
+        builder.append_value([] as [Option<&str>; 0]);
+        let list = builder.finish();
+
+        let hashes = hash_values(&list);
+        assert_eq!(hashes[0], hashes[2]);
+        for i in [1, 3, 4, 5, 6] {
+            assert_ne!(hashes[0], hashes[i]);
+        }
+        assert_ne!(hashes[1], hashes[5]);
+        assert_ne!(hashes[1], hashes[6]);
+        assert_eq!(hash_values(&list.slice(2, 3)), &hashes[2..5]);
+
+        let large = to_large_list(&list);
+        assert_eq!(hash_values(&large), hashes);
+    }
+
+    fn to_large_list(list: &ListArray) -> LargeListArray {
+        let offsets = list.offsets().iter().map(|x| *x as i64).collect::<Vec<_>>();
+        LargeListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8, true)),
+            OffsetBuffer::new(offsets.into()),
+            list.values().clone(),
+            list.nulls().cloned(),
+        )
+    }
+
+    #[test]
+    fn test_hash_fixed_size_list() {
+        let values = Int32Array::from(vec![1, 2, 3, 4, 1, 2]);
+        let field = Arc::new(Field::new_list_field(DataType::Int32, false));
+        let list = FixedSizeListArray::new(field, 2, Arc::new(values), None);
+        let hashes = hash_values(&list);
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        assert_eq!(hash_values(&list.slice(1, 2)), &hashes[1..]);
+    }
+
+    #[test]
+    fn test_hash_struct() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 2, 1]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "x", "x", "y"]));
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let nulls = vec![true, true, true, false].into();
+        let array = StructArray::new(fields, vec![a.clone(), b.clone()], Some(nulls));
+
+        let hashes = hash_values(&array);
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_eq!(hashes[3], hash_values(&Int32Array::new_null(1))[0]);
+
+        // Differs from the combined hash of the columns
+        let columns = hash_columns(&[a, b], 0).unwrap();
+        assert_ne!(columns.values()[0], hashes[0]);
+    }
+
+    #[test]
+    fn test_hash_union() {
+        let fields = UnionFields::new(
+            [0, 1],
+            [
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Int32, true),
+            ],
+        );
+        let type_ids = ScalarBuffer::from(vec![0_i8, 1, 0, 1]);
+        let children: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(1), None])),
+            Arc::new(Int32Array::from(vec![None, Some(1), None, Some(2)])),
+        ];
+        let sparse = UnionArray::try_new(fields.clone(), type_ids.clone(), None, children).unwrap();
+        let hashes = hash_values(&sparse);
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[3]);
+
+        let offsets = ScalarBuffer::from(vec![0, 0, 1, 1]);
+        let children: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![1, 1])),
+            Arc::new(Int32Array::from(vec![1, 2])),
+        ];
+        let dense = UnionArray::try_new(fields, type_ids, Some(offsets), children).unwrap();
+        assert_eq!(hash_values(&dense), hashes);
+    }
+
+    #[test]
+    fn test_hash_columns() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 1, 1]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("x"),
+            Some("x"),
+            Some("x"),
+            None,
+        ]));
+        let hashes = hash_columns(&[a.clone(), b.clone()], 42).unwrap();
+        assert_eq!(hashes.values()[0], hashes.values()[2]);
+        assert_ne!(hashes.values()[0], hashes.values()[1]);
+        assert_ne!(hashes.values()[0], hashes.values()[3]);
+
+        let expected = combine_hashes(
+            hash(&a, 42).unwrap().value(0),
+            hash(&b, 42).unwrap().value(0),
+        );
+        assert_eq!(hashes.value(0), expected);
+
+        // Order of columns matters
+        let reversed = hash_columns(&[b, a.clone()], 42).unwrap();
+        assert_ne!(reversed.value(0), hashes.value(0));
+
+        let err = hash_columns(&[], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: hash_columns requires at least one column"
+        );
+
+        let err = hash_columns(&[a.clone(), a.slice(0, 1)], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: hash_columns requires columns of the same length, got 4 and 1"
+        );
+    }
+}
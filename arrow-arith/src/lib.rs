@@ -24,5 +24,8 @@ pub mod arithmetic;
 pub mod arity;
 pub mod bitwise;
 pub mod boolean;
+pub mod cumulative;
+pub mod hash;
+pub mod math;
 pub mod numeric;
 pub mod temporal;
@@ -0,0 +1,772 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines elementwise math kernels on floating point arrays, such as [`sqrt`]
+//!
+//! These kernels support [`DataType::Float16`], [`DataType::Float32`] and
+//! [`DataType::Float64`], and return an array of the same type. Nulls are propagated,
+//! and inputs outside the domain of a function, such as the square root of a negative
+//! number, follow the IEEE 754 rules and return NaN
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType};
+use num::{Float, NumCast, Zero};
+
+use crate::arity::binary;
+
+macro_rules! float_unary {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        pub fn $name(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+            match array.data_type() {
+                DataType::Float16 => Ok(Arc::new(
+                    array.as_primitive::<Float16Type>().unary::<_, Float16Type>(|x| x.$name()),
+                )),
+                DataType::Float32 => Ok(Arc::new(
+                    array.as_primitive::<Float32Type>().unary::<_, Float32Type>(|x| x.$name()),
+                )),
+                DataType::Float64 => Ok(Arc::new(
+                    array.as_primitive::<Float64Type>().unary::<_, Float64Type>(|x| x.$name()),
+                )),
+                d => Err(ArrowError::InvalidArgumentError(format!(
+                    "{} not supported for {d}",
+                    stringify!($name)
+                ))),
+            }
+        }
+    };
+}
+
+float_unary!(
+    /// Computes `e^x` for each element of `array`
+    exp
+);
+
+float_unary!(
+    /// Computes the natural logarithm of each element of `array`
+    ln
+);
+
+float_unary!(
+    /// Computes the base 2 logarithm of each element of `array`
+    log2
+);
+
+float_unary!(
+    /// Computes the base 10 logarithm of each element of `array`
+    log10
+);
+
+float_unary!(
+    /// Computes the square root of each element of `array`
+    sqrt
+);
+
+float_unary!(
+    /// Computes the cube root of each element of `array`
+    cbrt
+);
+
+float_unary!(
+    /// Computes the sine of each element of `array`, in radians
+    sin
+);
+
+float_unary!(
+    /// Computes the cosine of each element of `array`, in radians
+    cos
+);
+
+float_unary!(
+    /// Computes the tangent of each element of `array`, in radians
+    tan
+);
+
+float_unary!(
+    /// Computes the arcsine of each element of `array`, in radians
+    asin
+);
+
+float_unary!(
+    /// Computes the arccosine of each element of `array`, in radians
+    acos
+);
+
+float_unary!(
+    /// Computes the arctangent of each element of `array`, in radians
+    atan
+);
+
+/// Computes the four quadrant arctangent of `y` and `x`, in radians
+///
+/// Either input may be a [`Scalar`], and both must have the same floating point type
+pub fn atan2(y: &dyn Datum, x: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (y, y_scalar) = y.get();
+    let (x, x_scalar) = x.get();
+    match (y.data_type(), x.data_type()) {
+        (DataType::Float16, DataType::Float16) => {
+            atan2_impl::<Float16Type>(y, y_scalar, x, x_scalar)
+        }
+        (DataType::Float32, DataType::Float32) => {
+            atan2_impl::<Float32Type>(y, y_scalar, x, x_scalar)
+        }
+        (DataType::Float64, DataType::Float64) => {
+            atan2_impl::<Float64Type>(y, y_scalar, x, x_scalar)
+        }
+        (y_t, x_t) => Err(ArrowError::InvalidArgumentError(format!(
+            "atan2 not supported for {y_t} and {x_t}"
+        ))),
+    }
+}
+
+fn atan2_impl<T>(
+    y: &dyn Array,
+    y_scalar: bool,
+    x: &dyn Array,
+    x_scalar: bool,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    let y = y.as_primitive::<T>();
+    let x = x.as_primitive::<T>();
+    let array: PrimitiveArray<T> = match (y_scalar, x_scalar) {
+        (true, true) | (false, false) => binary(y, x, |y, x| y.atan2(x))?,
+        (true, false) => match (y.null_count() == 0).then(|| y.value(0)) {
+            None => PrimitiveArray::new_null(x.len()),
+            Some(y) => x.unary(|x| y.atan2(x)),
+        },
+        (false, true) => match (x.null_count() == 0).then(|| x.value(0)) {
+            None => PrimitiveArray::new_null(y.len()),
+            Some(x) => y.unary(|y| y.atan2(x)),
+        },
+    };
+    Ok(Arc::new(array))
+}
+
+/// The rounding behaviour of [`round`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with ties rounded away from zero
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, with ties rounded to the nearest even value
+    HalfEven,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+}
+
+/// Rounds each element of `array` to `ndigits` decimal places according to `mode`
+///
+/// A negative `ndigits` rounds to the left of the decimal point, e.g. `-2` rounds
+/// to the nearest hundred.
+///
+/// Supports floating point and decimal arrays:
+///
+/// * Floating point arrays return an array of the same type. As floating point numbers
+///   cannot exactly represent most decimal fractions, the result is the closest
+///   representable value to the rounded result
+/// * Decimal arrays with a scale greater than `ndigits` return a decimal array of the same
+///   type with scale `ndigits`, or zero if `ndigits` is negative, and a precision that
+///   allows for the carry of rounding up. Returns an error if the result cannot be
+///   represented by the output type. Other decimal arrays are returned unchanged
+///
+/// ```
+/// # use arrow_array::{Decimal128Array, Float64Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{Decimal128Type, Float64Type};
+/// # use arrow_arith::math::{round, RoundingMode};
+/// let array = Float64Array::from(vec![2.5, -2.5, 1234.5]);
+/// let r = round(&array, 0, RoundingMode::HalfUp).unwrap();
+/// assert_eq!(r.as_primitive::<Float64Type>().values(), &[3.0, -3.0, 1235.0]);
+/// let r = round(&array, 0, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(r.as_primitive::<Float64Type>().values(), &[2.0, -2.0, 1234.0]);
+/// let r = round(&array, -2, RoundingMode::HalfUp).unwrap();
+/// assert_eq!(r.as_primitive::<Float64Type>().values(), &[0.0, -0.0, 1200.0]);
+///
+/// // 1.255 and -1.255 with precision 5 and scale 3
+/// let array = Decimal128Array::from(vec![1255, -1255])
+///     .with_precision_and_scale(5, 3)
+///     .unwrap();
+/// let r = round(&array, 2, RoundingMode::HalfUp).unwrap();
+/// let r = r.as_primitive::<Decimal128Type>();
+/// assert_eq!(r.values(), &[126, -126]);
+/// assert_eq!((r.precision(), r.scale()), (5, 2));
+/// ```
+pub fn round(array: &dyn Array, ndigits: i32, mode: RoundingMode) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Float16 => Ok(Arc::new(round_float::<Float16Type>(
+            array.as_primitive(),
+            ndigits,
+            mode,
+        ))),
+        DataType::Float32 => Ok(Arc::new(round_float::<Float32Type>(
+            array.as_primitive(),
+            ndigits,
+            mode,
+        ))),
+        DataType::Float64 => Ok(Arc::new(round_float::<Float64Type>(
+            array.as_primitive(),
+            ndigits,
+            mode,
+        ))),
+        DataType::Decimal128(p, s) => round_decimal::<Decimal128Type>(array, *p, *s, ndigits, mode),
+        DataType::Decimal256(p, s) => round_decimal::<Decimal256Type>(array, *p, *s, ndigits, mode),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "round not supported for {d}"
+        ))),
+    }
+}
+
+/// Rounds each element of `array` towards negative infinity to `ndigits` decimal places
+///
+/// See [`round`] for details
+pub fn floor(array: &dyn Array, ndigits: i32) -> Result<ArrayRef, ArrowError> {
+    round(array, ndigits, RoundingMode::Floor)
+}
+
+/// Rounds each element of `array` towards positive infinity to `ndigits` decimal places
+///
+/// See [`round`] for details
+pub fn ceil(array: &dyn Array, ndigits: i32) -> Result<ArrayRef, ArrowError> {
+    round(array, ndigits, RoundingMode::Ceil)
+}
+
+fn round_float<T>(array: &PrimitiveArray<T>, ndigits: i32, mode: RoundingMode) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    let two: T::Native = NumCast::from(2).unwrap();
+    let half: T::Native = NumCast::from(0.5).unwrap();
+    let round = move |x: T::Native| match mode {
+        RoundingMode::HalfUp => x.round(),
+        RoundingMode::HalfEven => {
+            let r = x.round();
+            match (r - x).abs() == half {
+                true => (x / two).round() * two,
+                false => r,
+            }
+        }
+        RoundingMode::Floor => x.floor(),
+        RoundingMode::Ceil => x.ceil(),
+    };
+
+    let ten: T::Native = NumCast::from(10).unwrap();
+    let factor = ten.powi(ndigits.saturating_abs());
+    match (ndigits >= 0, factor.is_finite()) {
+        (true, true) => array.unary(|x| round(x * factor) / factor),
+        // No representable digits beyond this precision
+        (true, false) => array.clone(),
+        (false, true) => array.unary(|x| round(x / factor) * factor),
+        // Any finite value rounds to zero or an infinity
+        (false, false) => array.unary(|x| match mode {
+            _ if x.is_zero() || !x.is_finite() => x,
+            RoundingMode::Floor if x.is_sign_negative() => T::Native::neg_infinity(),
+            RoundingMode::Ceil if x.is_sign_positive() => T::Native::infinity(),
+            _ => x * T::Native::zero(),
+        }),
+    }
+}
+
+fn round_decimal<T>(
+    array: &dyn Array,
+    precision: u8,
+    scale: i8,
+    ndigits: i32,
+    mode: RoundingMode,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let array = array.as_primitive::<T>();
+    let scale_i32 = scale as i32;
+    if ndigits >= scale_i32 {
+        return Ok(Arc::new(array.clone()));
+    }
+    let out_scale = ndigits.max(0).min(scale_i32);
+    let out_precision =
+        (precision as i32 - scale_i32 + out_scale + 1).clamp(1, T::MAX_PRECISION as i32);
+
+    let ten = T::Native::usize_as(10);
+    // If the factor overflows, all values have a magnitude less than half of it
+    let factor = ten.pow_checked((scale_i32 - ndigits) as u32).ok();
+    let multiplier = ten.pow_checked((out_scale - ndigits) as u32);
+    let zero = T::Native::ZERO;
+    let one = T::Native::ONE;
+
+    let rounded = array.try_unary::<_, T, _>(|v| {
+        let (q, r) = match factor {
+            Some(f) => (v.div_wrapping(f), v.mod_wrapping(f)),
+            None => (zero, v),
+        };
+        let sign = match r.compare(zero) {
+            Ordering::Less => one.neg_wrapping(),
+            Ordering::Equal => return round_decimal_value::<T>(q, &multiplier, out_precision),
+            Ordering::Greater => one,
+        };
+        let increment = match (mode, factor) {
+            (RoundingMode::Floor, _) => sign.is_lt(zero),
+            (RoundingMode::Ceil, _) => sign.is_gt(zero),
+            (_, None) => false,
+            (RoundingMode::HalfUp, Some(f)) => {
+                let r = r.mul_wrapping(sign);
+                r.compare(f.sub_wrapping(r)) != Ordering::Less
+            }
+            (RoundingMode::HalfEven, Some(f)) => {
+                let r = r.mul_wrapping(sign);
+                match r.compare(f.sub_wrapping(r)) {
+                    Ordering::Less => false,
+                    Ordering::Equal => !q.mod_wrapping(T::Native::usize_as(2)).is_zero(),
+                    Ordering::Greater => true,
+                }
+            }
+        };
+        let q = match increment {
+            true => q.add_checked(sign)?,
+            false => q,
+        };
+        round_decimal_value::<T>(q, &multiplier, out_precision)
+    })?;
+
+    Ok(Arc::new(rounded.with_precision_and_scale(
+        out_precision as u8,
+        out_scale as i8,
+    )?))
+}
+
+/// Scales the rounded value `q` by `multiplier` and validates it fits within `precision`
+fn round_decimal_value<T>(
+    q: T::Native,
+    multiplier: &Result<T::Native, ArrowError>,
+    precision: i32,
+) -> Result<T::Native, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    if q.is_zero() {
+        return Ok(q);
+    }
+    let v = match multiplier {
+        Ok(m) => q.mul_checked(*m)?,
+        Err(_) => {
+            return Err(ArrowError::ArithmeticOverflow(format!(
+                "Overflow happened on round, result exceeds {}",
+                T::PREFIX
+            )))
+        }
+    };
+    T::validate_decimal_precision(v, precision as u8)?;
+    Ok(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+    use half::f16;
+    use std::f64::consts::{E, FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn assert_close(actual: &ArrayRef, expected: &[Option<f64>]) {
+        let actual = actual.as_primitive::<Float64Type>();
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            match (a, e) {
+                (Some(a), Some(e)) if e.is_nan() => assert!(a.is_nan(), "{a} != {e}"),
+                (Some(a), Some(e)) => assert!(a == *e || (a - e).abs() < 1e-12, "{a} != {e}"),
+                (a, e) => assert_eq!(a, *e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unary_math() {
+        let a = Float64Array::from(vec![Some(1.0), None, Some(-8.0), Some(100.0)]);
+        assert_close(
+            &exp(&a).unwrap(),
+            &[Some(E), None, Some((-8.0_f64).exp()), Some(100.0_f64.exp())],
+        );
+        assert_close(
+            &ln(&a).unwrap(),
+            &[Some(0.0), None, Some(f64::NAN), Some(100.0_f64.ln())],
+        );
+        assert_close(
+            &log2(&a).unwrap(),
+            &[Some(0.0), None, Some(f64::NAN), Some(100.0_f64.log2())],
+        );
+        assert_close(
+            &log10(&a).unwrap(),
+            &[Some(0.0), None, Some(f64::NAN), Some(2.0)],
+        );
+        assert_close(
+            &sqrt(&a).unwrap(),
+            &[Some(1.0), None, Some(f64::NAN), Some(10.0)],
+        );
+        assert_close(
+            &cbrt(&a).unwrap(),
+            &[Some(1.0), None, Some(-2.0), Some(100.0_f64.cbrt())],
+        );
+
+        let a = Float64Array::from(vec![Some(0.0), None, Some(1.0), Some(-1.0)]);
+        assert_close(
+            &sin(&a).unwrap(),
+            &[Some(0.0), None, Some(1.0_f64.sin()), Some(-(1.0_f64.sin()))],
+        );
+        assert_close(
+            &cos(&a).unwrap(),
+            &[Some(1.0), None, Some(1.0_f64.cos()), Some(1.0_f64.cos())],
+        );
+        assert_close(
+            &tan(&a).unwrap(),
+            &[Some(0.0), None, Some(1.0_f64.tan()), Some(-(1.0_f64.tan()))],
+        );
+        assert_close(
+            &asin(&a).unwrap(),
+            &[Some(0.0), None, Some(FRAC_PI_2), Some(-FRAC_PI_2)],
+        );
+        assert_close(
+            &acos(&a).unwrap(),
+            &[Some(FRAC_PI_2), None, Some(0.0), Some(PI)],
+        );
+        assert_close(
+            &atan(&a).unwrap(),
+            &[Some(0.0), None, Some(FRAC_PI_4), Some(-FRAC_PI_4)],
+        );
+    }
+
+    #[test]
+    fn test_unary_math_types() {
+        let a = Float32Array::from(vec![Some(4.0), None, Some(0.25)]);
+        let r = sqrt(&a).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float32Type>(),
+            &Float32Array::from(vec![Some(2.0), None, Some(0.5)])
+        );
+
+        let a = Float16Array::from(vec![Some(f16::from_f32(4.0)), None]);
+        let r = sqrt(&a).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float16Type>(),
+            &Float16Array::from(vec![Some(f16::from_f32(2.0)), None])
+        );
+
+        let a = Float64Array::from(vec![4.0, 9.0, 16.0]).slice(1, 2);
+        let r = sqrt(&a).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float64Type>(),
+            &Float64Array::from(vec![3.0, 4.0])
+        );
+
+        let a = Int32Array::from(vec![1]);
+        let err = sqrt(&a).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: sqrt not supported for Int32"
+        );
+    }
+
+    #[test]
+    fn test_atan2() {
+        let y = Float64Array::from(vec![Some(1.0), Some(1.0), None, Some(-1.0)]);
+        let x = Float64Array::from(vec![Some(1.0), Some(-1.0), Some(1.0), None]);
+        assert_close(
+            &atan2(&y, &x).unwrap(),
+            &[Some(FRAC_PI_4), Some(3.0 * FRAC_PI_4), None, None],
+        );
+
+        let one = Float64Array::new_scalar(1.0);
+        assert_close(
+            &atan2(&one, &x).unwrap(),
+            &[
+                Some(FRAC_PI_4),
+                Some(3.0 * FRAC_PI_4),
+                Some(FRAC_PI_4),
+                None,
+            ],
+        );
+        assert_close(
+            &atan2(&y, &one).unwrap(),
+            &[Some(FRAC_PI_4), Some(FRAC_PI_4), None, Some(-FRAC_PI_4)],
+        );
+        assert_close(&atan2(&one, &one).unwrap(), &[Some(FRAC_PI_4)]);
+
+        let null = Scalar::new(Float64Array::new_null(1));
+        assert_close(&atan2(&null, &x).unwrap(), &[None, None, None, None]);
+
+        let x = Float32Array::from(vec![1.0]);
+        let err = atan2(&y, &x).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: atan2 not supported for Float64 and Float32"
+        );
+    }
+
+    #[test]
+    fn test_round_float() {
+        let a = Float64Array::from(vec![
+            Some(1.25),
+            None,
+            Some(-1.35),
+            Some(0.5),
+            Some(1.5),
+            Some(-2.5),
+            Some(1234.5678),
+        ]);
+        let cases = [
+            (
+                RoundingMode::HalfUp,
+                0,
+                vec![
+                    Some(1.0),
+                    None,
+                    Some(-1.0),
+                    Some(1.0),
+                    Some(2.0),
+                    Some(-3.0),
+                    Some(1235.0),
+                ],
+            ),
+            (
+                RoundingMode::HalfEven,
+                0,
+                vec![
+                    Some(1.0),
+                    None,
+                    Some(-1.0),
+                    Some(0.0),
+                    Some(2.0),
+                    Some(-2.0),
+                    Some(1235.0),
+                ],
+            ),
+            (
+                RoundingMode::Floor,
+                0,
+                vec![
+                    Some(1.0),
+                    None,
+                    Some(-2.0),
+                    Some(0.0),
+                    Some(1.0),
+                    Some(-3.0),
+                    Some(1234.0),
+                ],
+            ),
+            (
+                RoundingMode::Ceil,
+                0,
+                vec![
+                    Some(2.0),
+                    None,
+                    Some(-1.0),
+                    Some(1.0),
+                    Some(2.0),
+                    Some(-2.0),
+                    Some(1235.0),
+                ],
+            ),
+            (
+                RoundingMode::HalfUp,
+                1,
+                vec![
+                    Some(1.3),
+                    None,
+                    Some(-1.4),
+                    Some(0.5),
+                    Some(1.5),
+                    Some(-2.5),
+                    Some(1234.6),
+                ],
+            ),
+            (
+                RoundingMode::HalfEven,
+                1,
+                vec![
+                    Some(1.2),
+                    None,
+                    Some(-1.4),
+                    Some(0.5),
+                    Some(1.5),
+                    Some(-2.5),
+                    Some(1234.6),
+                ],
+            ),
+            (
+                RoundingMode::HalfUp,
+                -2,
+                vec![
+                    Some(0.0),
+                    None,
+                    Some(-0.0),
+                    Some(0.0),
+                    Some(0.0),
+                    Some(-0.0),
+                    Some(1200.0),
+                ],
+            ),
+            (
+                RoundingMode::Ceil,
+                -3,
+                vec![
+                    Some(1000.0),
+                    None,
+                    Some(-0.0),
+                    Some(1000.0),
+                    Some(1000.0),
+                    Some(-0.0),
+                    Some(2000.0),
+                ],
+            ),
+        ];
+        for (mode, ndigits, expected) in cases {
+            let r = round(&a, ndigits, mode).unwrap();
+            assert_close(&r, &expected);
+        }
+
+        // Out of range digits
+        let a = Float64Array::from(vec![1.5, -1.5, 0.0]);
+        let r = round(&a, 400, RoundingMode::HalfUp).unwrap();
+        assert_close(&r, &[Some(1.5), Some(-1.5), Some(0.0)]);
+        let r = round(&a, -400, RoundingMode::HalfUp).unwrap();
+        assert_close(&r, &[Some(0.0), Some(-0.0), Some(0.0)]);
+        let r = floor(&a, -400).unwrap();
+        assert_close(&r, &[Some(0.0), Some(f64::NEG_INFINITY), Some(0.0)]);
+        let r = ceil(&a, -400).unwrap();
+        assert_close(&r, &[Some(f64::INFINITY), Some(-0.0), Some(0.0)]);
+
+        let a = Float32Array::from(vec![Some(2.5), None, Some(-0.25)]);
+        let r = round(&a, 0, RoundingMode::HalfEven).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float32Type>(),
+            &Float32Array::from(vec![Some(2.0), None, Some(-0.0)])
+        );
+    }
+
+    #[test]
+    fn test_round_decimal() {
+        let a = Decimal128Array::from(vec![Some(1250), None, Some(-1350), Some(-1250), Some(9999)])
+            .with_precision_and_scale(4, 3)
+            .unwrap();
+        let cases = [
+            (
+                RoundingMode::HalfUp,
+                1,
+                vec![Some(13), None, Some(-14), Some(-13), Some(100)],
+            ),
+            (
+                RoundingMode::HalfEven,
+                1,
+                vec![Some(12), None, Some(-14), Some(-12), Some(100)],
+            ),
+            (
+                RoundingMode::Floor,
+                1,
+                vec![Some(12), None, Some(-14), Some(-13), Some(99)],
+            ),
+            (
+                RoundingMode::Ceil,
+                1,
+                vec![Some(13), None, Some(-13), Some(-12), Some(100)],
+            ),
+            (
+                RoundingMode::HalfUp,
+                0,
+                vec![Some(1), None, Some(-1), Some(-1), Some(10)],
+            ),
+            (
+                RoundingMode::Ceil,
+                -1,
+                vec![Some(10), None, Some(0), Some(0), Some(10)],
+            ),
+            (
+                RoundingMode::Floor,
+                -1,
+                vec![Some(0), None, Some(-10), Some(-10), Some(0)],
+            ),
+            (
+                RoundingMode::Floor,
+                -40,
+                vec![Some(0), None, None, None, Some(0)],
+            ),
+        ];
+        for (mode, ndigits, expected) in cases {
+            let scale = ndigits.max(0) as i8;
+            let precision = (4 - 3 + scale as i32 + 1) as u8;
+            if ndigits == -40 {
+                // Rounding negative values down overflows
+                let err = round(&a, ndigits, mode).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "Arithmetic overflow: Overflow happened on round, result exceeds Decimal128"
+                );
+                continue;
+            }
+            let r = round(&a, ndigits, mode).unwrap();
+            let expected = Decimal128Array::from(expected)
+                .with_precision_and_scale(precision, scale)
+                .unwrap();
+            assert_eq!(
+                r.as_primitive::<Decimal128Type>(),
+                &expected,
+                "{mode:?} {ndigits}"
+            );
+        }
+
+        // Digits greater than or equal to the scale are unchanged
+        let r = round(&a, 3, RoundingMode::HalfUp).unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &a);
+
+        // Negative scale
+        let a = Decimal256Array::from(vec![i256::from_i128(15), i256::from_i128(-25)])
+            .with_precision_and_scale(10, -2)
+            .unwrap();
+        let r = round(&a, -3, RoundingMode::HalfEven).unwrap();
+        let expected = Decimal256Array::from(vec![i256::from_i128(20), i256::from_i128(-20)])
+            .with_precision_and_scale(11, -2)
+            .unwrap();
+        assert_eq!(r.as_primitive::<Decimal256Type>(), &expected);
+
+        // Precision overflow
+        let a = Decimal128Array::from(vec![10_i128.pow(38) - 1])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let err = round(&a, -1, RoundingMode::HalfUp).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("is too large to store in a Decimal128 of precision 38"),
+            "{err}"
+        );
+
+        let a = Int32Array::from(vec![1]);
+        let err = round(&a, 0, RoundingMode::HalfUp).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: round not supported for Int32"
+        );
+    }
+}
@@ -27,6 +27,7 @@ use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, IntervalDayTime, IntervalMonthDayNano};
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
+use chrono::{DateTime, Datelike, Months};
 
 use crate::arity::{binary, try_binary};
 
@@ -167,6 +168,70 @@ pub fn neg_wrapping(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     }
 }
 
+/// Returns the elapsed time `lhs - rhs` between two timestamps as a duration in `unit`
+///
+/// Unlike [`sub`], `lhs` and `rhs` may have different units and timezones, with each
+/// timestamp interpreted as an instant in UTC. Results are truncated towards zero to
+/// a whole number of `unit`, and an error is returned if they overflow.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{DurationMillisecondArray, TimestampMillisecondArray, TimestampSecondArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::DurationMillisecondType;
+/// # use arrow_arith::numeric::timestamp_diff;
+/// # use arrow_schema::TimeUnit;
+/// let lhs = TimestampMillisecondArray::from(vec![10_500, 500]).with_timezone("+01:00");
+/// let rhs = TimestampSecondArray::from(vec![1, 2]);
+/// let result = timestamp_diff(&lhs, &rhs, TimeUnit::Millisecond).unwrap();
+/// let expected = DurationMillisecondArray::from(vec![9_500, -1_500]);
+/// assert_eq!(result.as_primitive::<DurationMillisecondType>(), &expected);
+/// ```
+pub fn timestamp_diff(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    unit: TimeUnit,
+) -> Result<ArrayRef, ArrowError> {
+    timestamp_diff_impl(lhs, rhs, unit)
+}
+
+/// Returns the calendar interval `lhs - rhs` between two timestamps, decomposed into
+/// months, days and nanoseconds
+///
+/// Similar to the Postgres [age] function, this is the largest whole number of months
+/// that can be added to `rhs` without passing `lhs`, followed by the whole days and
+/// nanoseconds remaining, such that adding the result to `rhs` returns `lhs`. If `lhs`
+/// is before `rhs`, the result is the negation of `rhs - lhs`.
+///
+/// `lhs` and `rhs` may have different units and timezones, with the calculation
+/// performed on the UTC representation of each timestamp, in which every day is
+/// 24 hours long.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{IntervalMonthDayNanoArray, TimestampSecondArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::IntervalMonthDayNanoType;
+/// # use arrow_arith::numeric::timestamp_diff_interval;
+/// # use arrow_buffer::IntervalMonthDayNano;
+/// // 2024-03-15T12:00:00Z and 2023-01-31T00:00:00Z
+/// let lhs = TimestampSecondArray::from(vec![1710504000]);
+/// let rhs = TimestampSecondArray::from(vec![1675123200]);
+/// let result = timestamp_diff_interval(&lhs, &rhs).unwrap();
+/// // 13 months (to 2024-02-29), 15 days and 12 hours
+/// let expected = IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNano::new(
+///     13,
+///     15,
+///     12 * 60 * 60 * 1_000_000_000,
+/// )]);
+/// assert_eq!(result.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+/// ```
+///
+/// [age]: https://www.postgresql.org/docs/current/functions-datetime.html
+pub fn timestamp_diff_interval(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    timestamp_diff_interval_impl(lhs, rhs)
+}
+
 /// An enumeration of arithmetic operations
 ///
 /// This allows sharing the type dispatch logic across the various kernels
@@ -844,6 +909,113 @@ fn decimal_op<T: DecimalType>(
     Ok(Arc::new(array))
 }
 
+/// Returns the number of nanoseconds in `unit`
+fn unit_nanos(unit: TimeUnit) -> i128 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Returns the values of the timestamp array `a`, along with the number of nanoseconds
+/// in its unit, returning an error if it is not a timestamp
+fn timestamp_values(name: &str, a: &dyn Array) -> Result<(Int64Array, i128), ArrowError> {
+    let values = match a.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            a.as_primitive::<TimestampSecondType>().reinterpret_cast()
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => a
+            .as_primitive::<TimestampMillisecondType>()
+            .reinterpret_cast(),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => a
+            .as_primitive::<TimestampMicrosecondType>()
+            .reinterpret_cast(),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => a
+            .as_primitive::<TimestampNanosecondType>()
+            .reinterpret_cast(),
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{name} not supported for {t}"
+            )))
+        }
+    };
+    let DataType::Timestamp(unit, _) = a.data_type() else {
+        unreachable!()
+    };
+    Ok((values, unit_nanos(*unit)))
+}
+
+fn timestamp_diff_impl(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    unit: TimeUnit,
+) -> Result<ArrayRef, ArrowError> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+    let (l, l_nanos) = &timestamp_values("timestamp_diff", l)?;
+    let (r, r_nanos) = &timestamp_values("timestamp_diff", r)?;
+    let nanos = unit_nanos(unit);
+
+    let array: Int64Array = try_op!(l, l_s, r, r_s, {
+        let delta = (l as i128 * l_nanos - r as i128 * r_nanos) / nanos;
+        i64::try_from(delta).map_err(|_| {
+            ArrowError::ArithmeticOverflow(format!("Overflow happened on: {l:?} - {r:?}"))
+        })
+    });
+    Ok(match unit {
+        TimeUnit::Second => Arc::new(array.reinterpret_cast::<DurationSecondType>()),
+        TimeUnit::Millisecond => Arc::new(array.reinterpret_cast::<DurationMillisecondType>()),
+        TimeUnit::Microsecond => Arc::new(array.reinterpret_cast::<DurationMicrosecondType>()),
+        TimeUnit::Nanosecond => Arc::new(array.reinterpret_cast::<DurationNanosecondType>()),
+    })
+}
+
+fn timestamp_diff_interval_impl(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+    let (l, l_nanos) = &timestamp_values("timestamp_diff_interval", l)?;
+    let (r, r_nanos) = &timestamp_values("timestamp_diff_interval", r)?;
+
+    let array: PrimitiveArray<IntervalMonthDayNanoType> = try_op!(l, l_s, r, r_s, {
+        calendar_interval(l as i128 * l_nanos, r as i128 * r_nanos)
+            .ok_or_else(|| ArrowError::ComputeError("Timestamp out of range".to_string()))
+    });
+    Ok(Arc::new(array))
+}
+
+/// Returns the calendar interval `end - start` between two instants, measured in
+/// nanoseconds since the UNIX epoch
+fn calendar_interval(end: i128, start: i128) -> Option<IntervalMonthDayNano> {
+    const NANOS_PER_SECOND: i128 = 1_000_000_000;
+    const NANOS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000_000;
+
+    if end < start {
+        return calendar_interval(start, end)?.checked_neg();
+    }
+    let to_datetime = |v: i128| {
+        let secs = i64::try_from(v.div_euclid(NANOS_PER_SECOND)).ok()?;
+        let nanos = v.rem_euclid(NANOS_PER_SECOND) as u32;
+        DateTime::from_timestamp(secs, nanos).map(|d| d.naive_utc())
+    };
+    let (end, start) = (to_datetime(end)?, to_datetime(start)?);
+
+    // Adding months clamps to the end of the month, and so may overshoot by at most one
+    let mut months = (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    let mut shifted = start.checked_add_months(Months::new(months as u32))?;
+    if shifted > end {
+        months -= 1;
+        shifted = start.checked_add_months(Months::new(months as u32))?;
+    }
+    let remaining = (end - shifted).num_nanoseconds()?;
+    Some(IntervalMonthDayNano::new(
+        months,
+        (remaining / NANOS_PER_DAY) as i32,
+        remaining % NANOS_PER_DAY,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1520,4 +1692,99 @@ mod tests {
             "Arithmetic overflow: Overflow happened on: 9223372036854775807 - -1"
         );
     }
+
+    #[test]
+    fn test_timestamp_diff() {
+        let a = TimestampNanosecondArray::from(vec![Some(1_500), Some(-1_500), None, Some(0)]);
+        let b = Scalar::new(TimestampMicrosecondArray::from(vec![0]).with_timezone("+05:00"));
+        let result = timestamp_diff(&a, &b, TimeUnit::Microsecond).unwrap();
+        let expected = DurationMicrosecondArray::from(vec![Some(1), Some(-1), None, Some(0)]);
+        assert_eq!(result.as_primitive::<DurationMicrosecondType>(), &expected);
+
+        let result = timestamp_diff(&b, &a, TimeUnit::Nanosecond).unwrap();
+        let expected =
+            DurationNanosecondArray::from(vec![Some(-1_500), Some(1_500), None, Some(0)]);
+        assert_eq!(result.as_primitive::<DurationNanosecondType>(), &expected);
+
+        // Timezones do not affect the elapsed time between instants
+        let a = TimestampSecondArray::from(vec![86_400, 3_600]).with_timezone("-05:00");
+        let b = TimestampMillisecondArray::from(vec![0, 7_200_500]).with_timezone("+01:00");
+        let result = timestamp_diff(&a, &b, TimeUnit::Second).unwrap();
+        let expected = DurationSecondArray::from(vec![86_400, -3_600]);
+        assert_eq!(result.as_primitive::<DurationSecondType>(), &expected);
+
+        let result = timestamp_diff(&a, &b, TimeUnit::Millisecond).unwrap();
+        let expected = DurationMillisecondArray::from(vec![86_400_000, -3_600_500]);
+        assert_eq!(result.as_primitive::<DurationMillisecondType>(), &expected);
+
+        let a = TimestampSecondArray::from(vec![i64::MAX]);
+        let b = TimestampSecondArray::from(vec![i64::MIN]);
+        let result = timestamp_diff(&a, &b, TimeUnit::Second);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Arithmetic overflow: Overflow happened on: 9223372036854775807 - -9223372036854775808"
+        );
+
+        let b = Date64Array::from(vec![0]);
+        let result = timestamp_diff(&a, &b, TimeUnit::Second);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid argument error: timestamp_diff not supported for Date64"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_diff_interval() {
+        let ts = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().timestamp_millis();
+        let a = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-03-15T12:00:00Z")),
+            Some(ts("2024-02-29T00:00:00Z")),
+            Some(ts("2024-03-30T23:59:59.999Z")),
+            Some(ts("2023-01-31T00:00:00Z")),
+            Some(ts("2024-01-31T06:00:00+05:00")),
+            None,
+        ]);
+        let b = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-03-15T12:00:00Z")),
+            Some(ts("2024-01-31T00:00:00Z")),
+            Some(ts("2024-02-29T00:00:00Z")),
+            Some(ts("2024-03-15T12:00:00Z")),
+            Some(ts("2023-12-31T23:00:00-02:00")),
+            Some(0),
+        ]);
+        let result = timestamp_diff_interval(&a, &b).unwrap();
+        let hour = 60 * 60 * 1_000_000_000;
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(0, 0, 0)),
+            Some(IntervalMonthDayNano::new(1, 0, 0)),
+            Some(IntervalMonthDayNano::new(1, 1, 24 * hour - 1_000_000)),
+            Some(IntervalMonthDayNano::new(-13, -15, -12 * hour)),
+            Some(IntervalMonthDayNano::new(0, 30, 0)),
+            None,
+        ]);
+        assert_eq!(result.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+
+        // Adding the result to the second argument returns the first
+        let sum = add(&b, &result).unwrap();
+        assert_eq!(sum.as_primitive::<TimestampMillisecondType>(), &a);
+
+        let a = Scalar::new(TimestampSecondArray::from(vec![
+            ts("2000-01-01T00:00:00Z") / 1000,
+        ]));
+        let b = TimestampNanosecondArray::from(vec![1]).with_timezone("+01:00");
+        let result = timestamp_diff_interval(&a, &b).unwrap();
+        let expected = IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNano::new(
+            359,
+            30,
+            24 * hour - 1,
+        )]);
+        assert_eq!(result.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+
+        let a = TimestampSecondArray::from(vec![i64::MAX]);
+        let result = timestamp_diff_interval(&a, &b);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Compute error: Timestamp out of range"
+        );
+    }
 }
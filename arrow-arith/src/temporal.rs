@@ -21,17 +21,21 @@ use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
 use cast::as_primitive_array;
-use chrono::{Datelike, TimeZone, Timelike, Utc};
+use chrono::{
+    DateTime, Datelike, Days, Duration, FixedOffset, LocalResult, Months, NaiveDate, NaiveDateTime,
+    Offset, TimeZone, Timelike, Utc,
+};
 
 use arrow_array::temporal_conversions::{
-    date32_to_datetime, date64_to_datetime, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
-    timestamp_s_to_datetime, timestamp_us_to_datetime, MICROSECONDS, MICROSECONDS_IN_DAY,
-    MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS, NANOSECONDS_IN_DAY, SECONDS_IN_DAY,
+    as_datetime, as_datetime_with_timezone, date32_to_datetime, date64_to_datetime,
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_s_to_datetime,
+    timestamp_us_to_datetime, MICROSECONDS, MICROSECONDS_IN_DAY, MILLISECONDS, MILLISECONDS_IN_DAY,
+    NANOSECONDS, NANOSECONDS_IN_DAY, SECONDS_IN_DAY,
 };
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_buffer::ArrowNativeType;
+use arrow_buffer::{ArrowNativeType, IntervalMonthDayNano};
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
 
 /// Valid parts to extract from date/time/timestamp arrays.
@@ -665,6 +669,505 @@ impl<T: Datelike> ChronoDateExt for T {
     }
 }
 
+/// Truncates each timestamp in `array` to the start of the [`DatePart`] containing it,
+/// such as the start of its hour or day
+///
+/// Supports truncation to [`DatePart::Year`], [`DatePart::Quarter`], [`DatePart::Month`],
+/// [`DatePart::Week`], [`DatePart::Day`], [`DatePart::Hour`], [`DatePart::Minute`],
+/// [`DatePart::Second`], [`DatePart::Millisecond`], [`DatePart::Microsecond`] and
+/// [`DatePart::Nanosecond`]. Weeks start on Monday, as defined by ISO 8601.
+///
+/// Timestamps with a timezone are truncated in local time, returning the instant at which
+/// the local day, week, etc. starts, so for example truncating to [`DatePart::Day`]
+/// returns local midnight even across daylight saving transitions. If the truncated local
+/// time is ambiguous the offset of the input timestamp is preferred, and if it does not
+/// exist, the offset in effect before the transition is used.
+///
+/// Returns an array of the same type as `array`, or a dictionary with this function
+/// applied onto its values if `array` is a dictionary of timestamps.
+///
+/// # Example
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampSecondType;
+/// # use arrow_arith::temporal::{date_trunc, DatePart};
+/// // 2024-03-15T10:30:45 and 2024-03-15T23:59:59
+/// let array = TimestampSecondArray::from(vec![1710498645, 1710547199])
+///     .with_timezone("+05:00");
+/// let result = date_trunc(&array, DatePart::Day).unwrap();
+/// // 2024-03-15T00:00:00+05:00 and 2024-03-16T00:00:00+05:00
+/// let expected = TimestampSecondArray::from(vec![1710442800, 1710529200])
+///     .with_timezone("+05:00");
+/// assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+/// ```
+pub fn date_trunc(array: &dyn Array, part: DatePart) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            date_trunc_impl::<TimestampSecondType>(array.as_primitive(), part)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            date_trunc_impl::<TimestampMillisecondType>(array.as_primitive(), part)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            date_trunc_impl::<TimestampMicrosecondType>(array.as_primitive(), part)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            date_trunc_impl::<TimestampNanosecondType>(array.as_primitive(), part)
+        }
+        DataType::Dictionary(_, _) => {
+            let array = array.as_any_dictionary();
+            let values = date_trunc(array.values(), part)?;
+            Ok(array.with_values(values))
+        }
+        t => return_compute_error_with!("date_trunc does not support", t),
+    }
+}
+
+fn date_trunc_impl<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    part: DatePart,
+) -> Result<ArrayRef, ArrowError> {
+    let per_second = units_per_second(T::UNIT);
+    let divisor = match part {
+        DatePart::Second => per_second,
+        DatePart::Millisecond => per_second / 1_000,
+        DatePart::Microsecond => per_second / 1_000_000,
+        DatePart::Nanosecond => per_second / 1_000_000_000,
+        DatePart::Minute
+        | DatePart::Hour
+        | DatePart::Day
+        | DatePart::Week
+        | DatePart::Month
+        | DatePart::Quarter
+        | DatePart::Year => {
+            let tz = get_tz(array.data_type())?;
+            let result = array.try_unary::<_, T, _>(|v| {
+                map_local_time::<T>(v, tz, |local| trunc_local_time(local, part))
+                    .ok_or_else(|| out_of_range("date_trunc", v))
+            })?;
+            return Ok(Arc::new(result.with_data_type(array.data_type().clone())));
+        }
+        _ => return_compute_error_with!(format!("{part} does not support"), "date_trunc"),
+    };
+
+    // Timezone offsets are a whole number of seconds, and so units of a second or less
+    // can be truncated without converting to local time
+    let divisor = divisor.max(1);
+    let result = array.unary::<_, T>(|v| v - v.rem_euclid(divisor));
+    Ok(Arc::new(result.with_data_type(array.data_type().clone())))
+}
+
+/// Assigns each timestamp in `array` to a bin of width `stride` aligned with `origin`,
+/// returning the start of the bin
+///
+/// Modelled after the Postgres [date_bin], each timestamp `t` is mapped to
+/// `origin + floor((t - origin) / stride) * stride`. `origin` is a timestamp in the same
+/// unit and timezone as `array`.
+///
+/// Timestamps with a timezone are binned in local time, with `origin` and the returned
+/// bins interpreted as local times as in [`date_trunc`], so for example a `stride` of one
+/// day bins timestamps by local day across daylight saving transitions.
+///
+/// Returns an error if `stride` contains months, as these are not of a fixed length, or
+/// if `stride` is not positive.
+///
+/// Returns an array of the same type as `array`, or a dictionary with this function
+/// applied onto its values if `array` is a dictionary of timestamps.
+///
+/// # Example
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampSecondType;
+/// # use arrow_buffer::IntervalMonthDayNano;
+/// # use arrow_arith::temporal::date_bin;
+/// // 15 minute bins, offset from the hour by 5 minutes
+/// let stride = IntervalMonthDayNano::new(0, 0, 15 * 60 * 1_000_000_000);
+/// let array = TimestampSecondArray::from(vec![0, 299, 300, 1199, 1200]);
+/// let result = date_bin(&array, stride, 300).unwrap();
+/// let expected = TimestampSecondArray::from(vec![-600, -600, 300, 300, 1200]);
+/// assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+/// ```
+///
+/// [date_bin]: https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-BIN
+pub fn date_bin(
+    array: &dyn Array,
+    stride: IntervalMonthDayNano,
+    origin: i64,
+) -> Result<ArrayRef, ArrowError> {
+    if stride.months != 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "date_bin does not support strides containing months".to_string(),
+        ));
+    }
+    let stride_nanos =
+        stride.days as i128 * NANOSECONDS_IN_DAY as i128 + stride.nanoseconds as i128;
+    if stride_nanos <= 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "date_bin stride must be greater than zero".to_string(),
+        ));
+    }
+
+    match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            date_bin_impl::<TimestampSecondType>(array.as_primitive(), stride_nanos, origin)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            date_bin_impl::<TimestampMillisecondType>(array.as_primitive(), stride_nanos, origin)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            date_bin_impl::<TimestampMicrosecondType>(array.as_primitive(), stride_nanos, origin)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            date_bin_impl::<TimestampNanosecondType>(array.as_primitive(), stride_nanos, origin)
+        }
+        DataType::Dictionary(_, _) => {
+            let array = array.as_any_dictionary();
+            let values = date_bin(array.values(), stride, origin)?;
+            Ok(array.with_values(values))
+        }
+        t => return_compute_error_with!("date_bin does not support", t),
+    }
+}
+
+fn date_bin_impl<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    stride_nanos: i128,
+    origin: i64,
+) -> Result<ArrayRef, ArrowError> {
+    let tz = get_tz(array.data_type())?;
+    let origin_nanos = match tz {
+        Some(tz) => as_datetime_with_timezone::<T>(origin, tz).map(|d| d.naive_local()),
+        None => as_datetime::<T>(origin),
+    }
+    .map(|origin| local_nanos(&origin))
+    .ok_or_else(|| out_of_range("date_bin", origin))?;
+
+    let result = array.try_unary::<_, T, _>(|v| {
+        map_local_time::<T>(v, tz, |local| {
+            let delta = local_nanos(&local) - origin_nanos;
+            let bin = origin_nanos + delta.div_euclid(stride_nanos) * stride_nanos;
+            let secs = i64::try_from(bin.div_euclid(NANOSECONDS as i128)).ok()?;
+            let nanos = bin.rem_euclid(NANOSECONDS as i128) as u32;
+            DateTime::from_timestamp(secs, nanos).map(|d| d.naive_utc())
+        })
+        .ok_or_else(|| out_of_range("date_bin", v))
+    })?;
+    Ok(Arc::new(result.with_data_type(array.data_type().clone())))
+}
+
+fn units_per_second(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
+}
+
+fn out_of_range(name: &str, v: i64) -> ArrowError {
+    ArrowError::ComputeError(format!("{name} result out of range for timestamp {v}"))
+}
+
+/// Returns the number of nanoseconds between the UNIX epoch and `local`, ignoring
+/// timezones
+fn local_nanos(local: &NaiveDateTime) -> i128 {
+    let utc = local.and_utc();
+    utc.timestamp() as i128 * NANOSECONDS as i128 + utc.timestamp_subsec_nanos() as i128
+}
+
+/// Maps the timestamp `v` by applying `f` to its local time in `tz`, returning `None`
+/// if the timestamp or result is out of range
+fn map_local_time<T: ArrowTimestampType>(
+    v: i64,
+    tz: Option<Tz>,
+    f: impl Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+) -> Option<i64> {
+    match tz {
+        None => T::make_value(f(as_datetime::<T>(v)?)?),
+        Some(tz) => {
+            let dt = as_datetime_with_timezone::<T>(v, tz)?;
+            let local = f(dt.naive_local())?;
+            let resolved = resolve_local_time(&tz, &local, dt.offset().fix())?;
+            T::make_value(resolved.naive_utc())
+        }
+    }
+}
+
+/// Returns the instant of `local` in `tz`, preferring `offset` if `local` is ambiguous,
+/// and using the offset in effect before the transition if `local` does not exist
+fn resolve_local_time(tz: &Tz, local: &NaiveDateTime, offset: FixedOffset) -> Option<DateTime<Tz>> {
+    match tz.from_local_datetime(local) {
+        LocalResult::Single(dt) => Some(dt),
+        LocalResult::Ambiguous(earliest, latest) => match latest.offset().fix() == offset {
+            true => Some(latest),
+            false => Some(earliest),
+        },
+        LocalResult::None => {
+            // Transitions are far more than a day apart
+            let before = tz.offset_from_utc_datetime(&local.checked_sub_days(Days::new(1))?);
+            let utc = local
+                .checked_sub_signed(Duration::seconds(before.fix().local_minus_utc() as i64))?;
+            Some(tz.from_utc_datetime(&utc))
+        }
+    }
+}
+
+fn trunc_local_time(local: NaiveDateTime, part: DatePart) -> Option<NaiveDateTime> {
+    let date = local.date();
+    let date = match part {
+        DatePart::Minute => return local.date().and_hms_opt(local.hour(), local.minute(), 0),
+        DatePart::Hour => return local.date().and_hms_opt(local.hour(), 0, 0),
+        DatePart::Day => date,
+        DatePart::Week => {
+            let days = date.weekday().num_days_from_monday();
+            date.checked_sub_days(Days::new(days as u64))?
+        }
+        DatePart::Month => date.with_day(1)?,
+        DatePart::Quarter => NaiveDate::from_ymd_opt(date.year(), date.quarter0() * 3 + 1, 1)?,
+        DatePart::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1)?,
+        _ => unreachable!("unsupported date_trunc part {part}"),
+    };
+    date.and_hms_opt(0, 0, 0)
+}
+
+/// Determines the behaviour of [`timestamp_add_interval`] and [`date_add_interval`] when
+/// the result cannot be represented by the output type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IntervalOverflowMode {
+    /// Return an [`ArrowError::ComputeError`]
+    #[default]
+    Error,
+    /// Return null
+    Null,
+}
+
+/// Adds each interval in `intervals` to the corresponding timestamp in `timestamps`
+///
+/// Modelled after Postgres, the components of each interval are applied in turn:
+///
+/// * Months are added to the local date, clamping the day to the end of the month if
+///   necessary, so that for example adding one month to January 31st returns the last
+///   day of February
+/// * Days are added to the local date, preserving the local time of day
+/// * Nanoseconds are added as elapsed time, and truncated to the unit of `timestamps`
+///
+/// Timestamps with a timezone are shifted in local time, and so adding one day returns
+/// the same local time on the next day, even if this is 23 or 25 hours later due to a
+/// daylight saving transition. If the shifted local time is ambiguous, the offset of the
+/// input timestamp is preferred, and if it does not exist, the offset in effect before
+/// the transition is used, moving it forward by the length of the gap.
+///
+/// This differs from [`add`](crate::numeric::add), which returns an error for local
+/// times that are ambiguous or do not exist.
+///
+/// `timestamps` may be a timestamp array of any unit and timezone, and `intervals` an
+/// interval array of any unit. Either may be a scalar, and the result has the same type
+/// as `timestamps`. Results that are out of range are handled according to `mode`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{IntervalMonthDayNanoArray, TimestampSecondArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampSecondType;
+/// # use arrow_arith::temporal::{timestamp_add_interval, IntervalOverflowMode};
+/// # use arrow_buffer::IntervalMonthDayNano;
+/// # #[cfg(feature = "chrono-tz")]
+/// # {
+/// // 2024-03-09T12:00:00-05:00 and 2024-01-31T12:00:00-05:00 in New York
+/// let timestamps = TimestampSecondArray::from(vec![1710003600, 1706720400])
+///     .with_timezone("America/New_York");
+/// let intervals = IntervalMonthDayNanoArray::from(vec![
+///     IntervalMonthDayNano::new(0, 1, 0),
+///     IntervalMonthDayNano::new(1, 0, 0),
+/// ]);
+/// let result = timestamp_add_interval(&timestamps, &intervals, IntervalOverflowMode::Error)
+///     .unwrap();
+/// // 2024-03-10T12:00:00-04:00, 23 hours later, and 2024-02-29T12:00:00-05:00
+/// let expected = TimestampSecondArray::from(vec![1710086400, 1709226000])
+///     .with_timezone("America/New_York");
+/// assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+/// # }
+/// ```
+pub fn timestamp_add_interval(
+    timestamps: &dyn Datum,
+    intervals: &dyn Datum,
+    mode: IntervalOverflowMode,
+) -> Result<ArrayRef, ArrowError> {
+    const NAME: &str = "timestamp_add_interval";
+    let (l, l_s) = timestamps.get();
+    let (r, r_s) = intervals.get();
+    let r = &as_month_day_nano(NAME, r)?;
+
+    macro_rules! helper {
+        ($t:ty) => {{
+            let l = l.as_primitive::<$t>();
+            let tz = get_tz(l.data_type())?;
+            let op = |v, interval| add_interval::<$t>(v, interval, tz);
+            let err = |v| out_of_range(NAME, v);
+            let result = interval_op(NAME, l, l_s, r, r_s, mode, op, err)?;
+            Ok(Arc::new(result.with_data_type(l.data_type().clone())))
+        }};
+    }
+
+    match l.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => helper!(TimestampSecondType),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => helper!(TimestampMillisecondType),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => helper!(TimestampMicrosecondType),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => helper!(TimestampNanosecondType),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "{NAME} not supported for {t}"
+        ))),
+    }
+}
+
+/// Adds each interval in `intervals` to the corresponding date in `dates`
+///
+/// Months and days are added as in [`timestamp_add_interval`]. The nanoseconds of each
+/// interval are added as elapsed time, and for [`Date32Array`] truncated towards zero to
+/// a whole number of days.
+///
+/// `dates` may be a [`Date32Array`] or [`Date64Array`], and `intervals` an interval array
+/// of any unit. Either may be a scalar, and the result has the same type as `dates`.
+/// Results that are out of range are handled according to `mode`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Date32Array, IntervalYearMonthArray, Scalar};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Date32Type;
+/// # use arrow_arith::temporal::{date_add_interval, IntervalOverflowMode};
+/// // 2024-01-31 and 2024-02-29
+/// let dates = Date32Array::from(vec![19753, 19782]);
+/// let intervals = Scalar::new(IntervalYearMonthArray::from(vec![13]));
+/// let result = date_add_interval(&dates, &intervals, IntervalOverflowMode::Error).unwrap();
+/// // 2025-02-28 and 2025-03-29
+/// let expected = Date32Array::from(vec![20147, 20176]);
+/// assert_eq!(result.as_primitive::<Date32Type>(), &expected);
+/// ```
+pub fn date_add_interval(
+    dates: &dyn Datum,
+    intervals: &dyn Datum,
+    mode: IntervalOverflowMode,
+) -> Result<ArrayRef, ArrowError> {
+    const NAME: &str = "date_add_interval";
+    let (l, l_s) = dates.get();
+    let (r, r_s) = intervals.get();
+    let r = &as_month_day_nano(NAME, r)?;
+    let err = |v: i64| ArrowError::ComputeError(format!("{NAME} result out of range for date {v}"));
+
+    match l.data_type() {
+        DataType::Date32 => {
+            let l = l.as_primitive::<Date32Type>();
+            let err = |v: i32| err(v as i64);
+            let result = interval_op(NAME, l, l_s, r, r_s, mode, add_interval_date32, err)?;
+            Ok(Arc::new(result))
+        }
+        DataType::Date64 => {
+            let l = l.as_primitive::<Date64Type>();
+            let op = |v, interval| add_interval::<TimestampMillisecondType>(v, interval, None);
+            let result = interval_op(NAME, l, l_s, r, r_s, mode, op, err)?;
+            Ok(Arc::new(result))
+        }
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "{NAME} not supported for {t}"
+        ))),
+    }
+}
+
+/// Converts an interval array of any unit to an [`IntervalMonthDayNanoArray`]
+fn as_month_day_nano(name: &str, a: &dyn Array) -> Result<IntervalMonthDayNanoArray, ArrowError> {
+    match a.data_type() {
+        DataType::Interval(IntervalUnit::MonthDayNano) => Ok(a.as_primitive().clone()),
+        DataType::Interval(IntervalUnit::YearMonth) => Ok(a
+            .as_primitive::<IntervalYearMonthType>()
+            .unary(|months| IntervalMonthDayNano::new(months, 0, 0))),
+        DataType::Interval(IntervalUnit::DayTime) => Ok(a
+            .as_primitive::<IntervalDayTimeType>()
+            .unary(|d| IntervalMonthDayNano::new(0, d.days, d.milliseconds as i64 * 1_000_000))),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "{name} not supported for {t}"
+        ))),
+    }
+}
+
+/// Applies `op` to each pair of values in `l` and `r`, either of which may be a scalar,
+/// handling values for which `op` returns `None` according to `mode`
+#[allow(clippy::too_many_arguments)]
+fn interval_op<T: ArrowPrimitiveType>(
+    name: &str,
+    l: &PrimitiveArray<T>,
+    l_s: bool,
+    r: &IntervalMonthDayNanoArray,
+    r_s: bool,
+    mode: IntervalOverflowMode,
+    op: impl Fn(T::Native, IntervalMonthDayNano) -> Option<T::Native>,
+    err: impl Fn(T::Native) -> ArrowError,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    let len = match (l_s, r_s) {
+        (false, false) if l.len() != r.len() => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot perform {name} on arrays of different length, got {} vs {}",
+                l.len(),
+                r.len()
+            )))
+        }
+        (true, false) => r.len(),
+        _ => l.len(),
+    };
+
+    (0..len)
+        .map(|idx| {
+            let (l_idx, r_idx) = (if l_s { 0 } else { idx }, if r_s { 0 } else { idx });
+            if l.is_null(l_idx) || r.is_null(r_idx) {
+                return Ok(None);
+            }
+            let v = l.value(l_idx);
+            match (op(v, r.value(r_idx)), mode) {
+                (Some(result), _) => Ok(Some(result)),
+                (None, IntervalOverflowMode::Null) => Ok(None),
+                (None, IntervalOverflowMode::Error) => Err(err(v)),
+            }
+        })
+        .collect()
+}
+
+/// Adds `interval` to the timestamp `v`, shifting months and days in the local time of
+/// `tz`, returning `None` if the result is out of range
+fn add_interval<T: ArrowTimestampType>(
+    v: i64,
+    interval: IntervalMonthDayNano,
+    tz: Option<Tz>,
+) -> Option<i64> {
+    let shifted = match (interval.months, interval.days) {
+        (0, 0) => v,
+        (months, days) => map_local_time::<T>(v, tz, |local| add_months_days(local, months, days))?,
+    };
+    let nanos = Duration::nanoseconds(interval.nanoseconds);
+    T::make_value(as_datetime::<T>(shifted)?.checked_add_signed(nanos)?)
+}
+
+fn add_interval_date32(v: i32, interval: IntervalMonthDayNano) -> Option<i32> {
+    let days = interval.nanoseconds / NANOSECONDS_IN_DAY;
+    let days = i32::try_from(days).ok()?.checked_add(interval.days)?;
+    let date = date32_to_datetime(v)?;
+    let date = add_months_days(date, interval.months, days)?;
+    let epoch = NaiveDateTime::UNIX_EPOCH;
+    i32::try_from((date - epoch).num_days()).ok()
+}
+
+fn add_months_days(local: NaiveDateTime, months: i32, days: i32) -> Option<NaiveDateTime> {
+    let local = match months >= 0 {
+        true => local.checked_add_months(Months::new(months as u32))?,
+        false => local.checked_sub_months(Months::new(months.unsigned_abs()))?,
+    };
+    match days >= 0 {
+        true => local.checked_add_days(Days::new(days as u64)),
+        false => local.checked_sub_days(Days::new(days.unsigned_abs() as u64)),
+    }
+}
+
 /// Extracts the hours of a given array as an array of integers within
 /// the range of [0, 23]. If the given array isn't temporal primitive or dictionary array,
 /// an `Err` will be returned.
@@ -2072,4 +2575,447 @@ mod tests {
         ensure_returns_error(&DurationMicrosecondArray::from(vec![0]));
         ensure_returns_error(&DurationNanosecondArray::from(vec![0]));
     }
+
+    fn ts(s: &str) -> i64 {
+        DateTime::parse_from_rfc3339(s).unwrap().timestamp_millis()
+    }
+
+    #[test]
+    fn test_date_trunc() {
+        // Wednesday
+        let array = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-05-15T13:45:30.123Z")),
+            None,
+            Some(ts("1969-12-31T23:59:59.500Z")),
+        ]);
+        let cases = [
+            (
+                DatePart::Year,
+                "2024-01-01T00:00:00Z",
+                "1969-01-01T00:00:00Z",
+            ),
+            (
+                DatePart::Quarter,
+                "2024-04-01T00:00:00Z",
+                "1969-10-01T00:00:00Z",
+            ),
+            (
+                DatePart::Month,
+                "2024-05-01T00:00:00Z",
+                "1969-12-01T00:00:00Z",
+            ),
+            (
+                DatePart::Week,
+                "2024-05-13T00:00:00Z",
+                "1969-12-29T00:00:00Z",
+            ),
+            (
+                DatePart::Day,
+                "2024-05-15T00:00:00Z",
+                "1969-12-31T00:00:00Z",
+            ),
+            (
+                DatePart::Hour,
+                "2024-05-15T13:00:00Z",
+                "1969-12-31T23:00:00Z",
+            ),
+            (
+                DatePart::Minute,
+                "2024-05-15T13:45:00Z",
+                "1969-12-31T23:59:00Z",
+            ),
+            (
+                DatePart::Second,
+                "2024-05-15T13:45:30Z",
+                "1969-12-31T23:59:59Z",
+            ),
+            (
+                DatePart::Millisecond,
+                "2024-05-15T13:45:30.123Z",
+                "1969-12-31T23:59:59.500Z",
+            ),
+            (
+                DatePart::Microsecond,
+                "2024-05-15T13:45:30.123Z",
+                "1969-12-31T23:59:59.500Z",
+            ),
+        ];
+        for (part, a, b) in cases {
+            let result = date_trunc(&array, part).unwrap();
+            let expected = TimestampMillisecondArray::from(vec![Some(ts(a)), None, Some(ts(b))]);
+            assert_eq!(
+                result.as_primitive::<TimestampMillisecondType>(),
+                &expected,
+                "{part}"
+            );
+        }
+
+        let array = TimestampNanosecondArray::from(vec![1_123_456_789, -1]);
+        let result = date_trunc(&array, DatePart::Microsecond).unwrap();
+        let expected = TimestampNanosecondArray::from(vec![1_123_456_000, -1_000]);
+        assert_eq!(result.as_primitive::<TimestampNanosecondType>(), &expected);
+
+        let array = TimestampSecondArray::from(vec![59, 61]);
+        let result = date_trunc(&array, DatePart::Millisecond).unwrap();
+        assert_eq!(result.as_primitive::<TimestampSecondType>(), &array);
+    }
+
+    #[test]
+    fn test_date_trunc_fixed_offset() {
+        let array = TimestampSecondArray::from(vec![ts("2024-05-15T10:45:30+05:30") / 1000])
+            .with_timezone("+05:30");
+        let cases = [
+            (DatePart::Hour, "2024-05-15T10:00:00+05:30"),
+            (DatePart::Day, "2024-05-15T00:00:00+05:30"),
+            (DatePart::Month, "2024-05-01T00:00:00+05:30"),
+        ];
+        for (part, expected) in cases {
+            let result = date_trunc(&array, part).unwrap();
+            let expected =
+                TimestampSecondArray::from(vec![ts(expected) / 1000]).with_timezone("+05:30");
+            assert_eq!(
+                result.as_primitive::<TimestampSecondType>(),
+                &expected,
+                "{part}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn test_date_trunc_dst() {
+        let tz = "America/New_York";
+        let array = TimestampMillisecondArray::from(vec![
+            // After the spring forward transition at 02:00
+            ts("2024-03-10T12:00:00-04:00"),
+            // Both occurrences of 01:30 on the fall back transition at 02:00
+            ts("2024-11-03T01:30:00-04:00"),
+            ts("2024-11-03T01:30:00-05:00"),
+        ])
+        .with_timezone(tz);
+
+        let result = date_trunc(&array, DatePart::Day).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            ts("2024-03-10T00:00:00-05:00"),
+            ts("2024-11-03T00:00:00-04:00"),
+            ts("2024-11-03T00:00:00-04:00"),
+        ])
+        .with_timezone(tz);
+        assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+
+        let result = date_trunc(&array, DatePart::Hour).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            ts("2024-03-10T12:00:00-04:00"),
+            ts("2024-11-03T01:00:00-04:00"),
+            ts("2024-11-03T01:00:00-05:00"),
+        ])
+        .with_timezone(tz);
+        assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+
+        // Midnight was skipped when daylight saving time started in Sao Paulo
+        let tz = "America/Sao_Paulo";
+        let array = TimestampMillisecondArray::from(vec![ts("2018-11-04T12:00:00-02:00")])
+            .with_timezone(tz);
+        let result = date_trunc(&array, DatePart::Day).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![ts("2018-11-04T01:00:00-02:00")])
+            .with_timezone(tz);
+        assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+    }
+
+    #[test]
+    fn test_date_bin() {
+        let minute = 60 * 1_000_000_000;
+        let stride = IntervalMonthDayNano::new(0, 0, 15 * minute);
+        let array = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-05-15T13:44:59.999Z")),
+            Some(ts("2024-05-15T13:50:00Z")),
+            None,
+            Some(ts("1969-12-31T23:59:00Z")),
+        ]);
+        let origin = ts("2024-01-01T00:05:00Z");
+        let result = date_bin(&array, stride, origin).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-05-15T13:35:00Z")),
+            Some(ts("2024-05-15T13:50:00Z")),
+            None,
+            Some(ts("1969-12-31T23:50:00Z")),
+        ]);
+        assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+
+        let stride = IntervalMonthDayNano::new(0, 7, 0);
+        let origin = ts("2024-01-01T00:00:00Z") * 1_000;
+        let array = TimestampMicrosecondArray::from(vec![ts("2024-05-15T13:44:59Z") * 1_000]);
+        let result = date_bin(&array, stride, origin).unwrap();
+        let expected = TimestampMicrosecondArray::from(vec![ts("2024-05-13T00:00:00Z") * 1_000]);
+        assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn test_date_bin_dst() {
+        let tz = "America/New_York";
+        let stride = IntervalMonthDayNano::new(0, 1, 0);
+        let origin = ts("2024-03-01T00:00:00-05:00") / 1000;
+        let array = TimestampSecondArray::from(vec![
+            ts("2024-03-09T12:00:00-05:00") / 1000,
+            ts("2024-03-10T12:00:00-04:00") / 1000,
+            ts("2024-03-11T00:30:00-04:00") / 1000,
+        ])
+        .with_timezone(tz);
+        let result = date_bin(&array, stride, origin).unwrap();
+        let expected = TimestampSecondArray::from(vec![
+            ts("2024-03-09T00:00:00-05:00") / 1000,
+            ts("2024-03-10T00:00:00-05:00") / 1000,
+            ts("2024-03-11T00:00:00-04:00") / 1000,
+        ])
+        .with_timezone(tz);
+        assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+    }
+
+    #[test]
+    fn test_date_trunc_bin_dictionary() {
+        let values = TimestampSecondArray::from(vec![3_661, 90_061]).with_timezone("+01:00");
+        let keys = Int8Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+
+        let result = date_trunc(&array, DatePart::Day).unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        assert_eq!(result.keys(), array.keys());
+        let expected = TimestampSecondArray::from(vec![-3_600, 82_800]).with_timezone("+01:00");
+        assert_eq!(
+            result.values().as_primitive::<TimestampSecondType>(),
+            &expected
+        );
+
+        let stride = IntervalMonthDayNano::new(0, 0, 3_600 * 1_000_000_000);
+        let result = date_bin(&array, stride, 0).unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        let expected = TimestampSecondArray::from(vec![3_600, 90_000]).with_timezone("+01:00");
+        assert_eq!(
+            result.values().as_primitive::<TimestampSecondType>(),
+            &expected
+        );
+    }
+
+    #[test]
+    fn test_date_trunc_bin_invalid() {
+        let array = TimestampSecondArray::from(vec![0]);
+        let err = date_trunc(&array, DatePart::DayOfYear).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: DayOfYear does not support: \"date_trunc\""
+        );
+
+        let err = date_trunc(&Date32Array::from(vec![0]), DatePart::Day).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_trunc does not support: Date32"
+        );
+
+        let stride = IntervalMonthDayNano::new(1, 0, 0);
+        let err = date_bin(&array, stride, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: date_bin does not support strides containing months"
+        );
+
+        let stride = IntervalMonthDayNano::new(0, -1, 1);
+        let err = date_bin(&array, stride, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: date_bin stride must be greater than zero"
+        );
+
+        let array = TimestampSecondArray::from(vec![i64::MAX]);
+        let err = date_trunc(&array, DatePart::Day).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compute error: date_trunc result out of range for timestamp {}",
+                i64::MAX
+            )
+        );
+    }
+
+    #[test]
+    fn test_timestamp_add_interval() {
+        let array = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-01-31T10:00:00.500Z")),
+            Some(ts("2024-03-31T00:00:00Z")),
+            None,
+            Some(ts("1969-12-31T23:59:59Z")),
+        ]);
+        let intervals = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, 1, 1_999_999)),
+            Some(IntervalMonthDayNano::new(-1, 0, -1)),
+            Some(IntervalMonthDayNano::new(1, 0, 0)),
+            None,
+        ]);
+        let result =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Error).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-03-01T10:00:00.501Z")),
+            Some(ts("2024-02-28T23:59:59.999Z")),
+            None,
+            None,
+        ]);
+        assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+
+        // Other interval units, with scalars
+        let intervals = Scalar::new(IntervalYearMonthArray::from(vec![-12]));
+        let result =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Error).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            Some(ts("2023-01-31T10:00:00.500Z")),
+            Some(ts("2023-03-31T00:00:00Z")),
+            None,
+            Some(ts("1968-12-31T23:59:59Z")),
+        ]);
+        assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+
+        let array = Scalar::new(TimestampSecondArray::from(vec![0]).with_timezone("+05:30"));
+        let intervals = IntervalDayTimeArray::from(vec![
+            IntervalDayTime::new(1, 1_500),
+            IntervalDayTime::new(-1, -500),
+        ]);
+        let result =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Error).unwrap();
+        let expected = TimestampSecondArray::from(vec![86_401, -86_401]).with_timezone("+05:30");
+        assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn test_timestamp_add_interval_dst() {
+        let tz = "America/New_York";
+        let hour = 60 * 60 * 1_000_000_000;
+        let array = TimestampMillisecondArray::from(vec![
+            ts("2024-03-09T12:00:00-05:00"),
+            ts("2024-03-09T12:00:00-05:00"),
+            // Shifted into the gap when clocks go forward at 02:00
+            ts("2024-03-09T02:30:00-05:00"),
+            // Both occurrences of 01:30 when clocks go back at 02:00
+            ts("2024-11-02T01:30:00-04:00"),
+            ts("2024-11-04T01:30:00-05:00"),
+            ts("2024-10-03T01:30:00-04:00"),
+        ])
+        .with_timezone(tz);
+        let intervals = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(0, 1, 0),
+            IntervalMonthDayNano::new(0, 0, 24 * hour),
+            IntervalMonthDayNano::new(0, 1, 0),
+            IntervalMonthDayNano::new(0, 1, 0),
+            IntervalMonthDayNano::new(0, -1, 0),
+            IntervalMonthDayNano::new(1, 0, hour),
+        ]);
+        let result =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Error).unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            ts("2024-03-10T12:00:00-04:00"),
+            ts("2024-03-10T13:00:00-04:00"),
+            ts("2024-03-10T03:30:00-04:00"),
+            ts("2024-11-03T01:30:00-04:00"),
+            ts("2024-11-03T01:30:00-05:00"),
+            ts("2024-11-03T01:30:00-05:00"),
+        ])
+        .with_timezone(tz);
+        assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+    }
+
+    #[test]
+    fn test_date_add_interval() {
+        let date = |y, m, d| Date32Type::from_naive_date(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        let dates = Date32Array::from(vec![Some(date(2024, 1, 31)), Some(date(2024, 3, 1)), None]);
+        let day = NANOSECONDS_IN_DAY;
+        let intervals = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(1, 1, 2 * day - 1),
+            IntervalMonthDayNano::new(0, 0, -day - 1),
+            IntervalMonthDayNano::new(0, 1, 0),
+        ]);
+        let result = date_add_interval(&dates, &intervals, IntervalOverflowMode::Error).unwrap();
+        let expected =
+            Date32Array::from(vec![Some(date(2024, 3, 2)), Some(date(2024, 2, 29)), None]);
+        assert_eq!(result.as_primitive::<Date32Type>(), &expected);
+
+        let dates = Date64Array::from(vec![ts("2024-01-31T00:00:00Z"), ts("2024-05-31T00:00:00Z")]);
+        let intervals = Scalar::new(IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(-3, 0, 1_000_000),
+        ]));
+        let result = date_add_interval(&dates, &intervals, IntervalOverflowMode::Error).unwrap();
+        let expected = Date64Array::from(vec![
+            ts("2023-10-31T00:00:00.001Z"),
+            ts("2024-02-29T00:00:00.001Z"),
+        ]);
+        assert_eq!(result.as_primitive::<Date64Type>(), &expected);
+    }
+
+    #[test]
+    fn test_add_interval_overflow() {
+        let array = TimestampNanosecondArray::from(vec![Some(0), Some(i64::MAX - 1), None]);
+        let intervals = Scalar::new(IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(0, 0, 1),
+        ]));
+        let result =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Null).unwrap();
+        let expected = TimestampNanosecondArray::from(vec![Some(1), Some(i64::MAX), None]);
+        assert_eq!(result.as_primitive::<TimestampNanosecondType>(), &expected);
+
+        let intervals = Scalar::new(IntervalYearMonthArray::from(vec![1]));
+        let result =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Null).unwrap();
+        let expected =
+            TimestampNanosecondArray::from(vec![Some(2_678_400_000_000_000), None, None]);
+        assert_eq!(result.as_primitive::<TimestampNanosecondType>(), &expected);
+
+        let err =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compute error: timestamp_add_interval result out of range for timestamp {}",
+                i64::MAX - 1
+            )
+        );
+
+        let dates = Date32Array::from(vec![0, i32::MAX]);
+        let result = date_add_interval(&dates, &intervals, IntervalOverflowMode::Null).unwrap();
+        assert_eq!(
+            result.as_primitive::<Date32Type>(),
+            &Date32Array::from(vec![Some(31), None])
+        );
+        let err = date_add_interval(&dates, &intervals, IntervalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compute error: date_add_interval result out of range for date {}",
+                i32::MAX
+            )
+        );
+    }
+
+    #[test]
+    fn test_add_interval_invalid() {
+        let array = TimestampSecondArray::from(vec![0, 1]);
+        let intervals = IntervalYearMonthArray::from(vec![1]);
+        let err =
+            timestamp_add_interval(&array, &intervals, IntervalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot perform timestamp_add_interval on arrays of different length, got 2 vs 1"
+        );
+
+        let err = date_add_interval(&array, &intervals, IntervalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: date_add_interval not supported for Timestamp(Second, None)"
+        );
+
+        let durations = DurationSecondArray::from(vec![1, 1]);
+        let err =
+            timestamp_add_interval(&array, &durations, IntervalOverflowMode::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: timestamp_add_interval not supported for Duration(Second)"
+        );
+    }
 }
@@ -475,6 +475,60 @@ impl<T: ByteViewType + ?Sized> GenericByteViewArray<T> {
         builder.finish()
     }
 
+    /// Returns the number of bytes of the data buffers referenced by the non-null
+    /// values of this array
+    ///
+    /// Values of at most 12 bytes are stored inline in their view and do not reference
+    /// the data buffers. Compare with [`Self::total_data_buffer_bytes`] to find how much
+    /// of the data buffers is no longer referenced, e.g. after filtering or slicing
+    pub fn total_buffer_bytes_used(&self) -> usize {
+        let used = |view: &u128| match *view as u32 {
+            len if len > 12 => len as usize,
+            _ => 0,
+        };
+        match &self.nulls {
+            Some(nulls) => self
+                .views
+                .iter()
+                .zip(nulls.iter())
+                .filter(|(_, valid)| *valid)
+                .map(|(view, _)| used(view))
+                .sum(),
+            None => self.views.iter().map(used).sum(),
+        }
+    }
+
+    /// Returns the total size in bytes of the data buffers of this array
+    pub fn total_data_buffer_bytes(&self) -> usize {
+        self.buffers.iter().map(|b| b.len()).sum()
+    }
+
+    /// Returns a compacted version of this array, see [`Self::gc`], if the fraction of
+    /// its data buffer bytes not referenced by its values exceeds `threshold`, otherwise
+    /// returns a clone of this array
+    ///
+    /// A `threshold` of `0.0` compacts any array with unreferenced data, and a
+    /// `threshold` of `1.0` never compacts
+    ///
+    /// ```
+    /// # use arrow_array::StringViewArray;
+    /// let array = StringViewArray::from(vec!["a string longer than 12 bytes", "another long string"]);
+    /// let sliced = array.slice(0, 1);
+    /// assert_eq!(sliced.total_buffer_bytes_used(), 29);
+    /// assert_eq!(sliced.total_data_buffer_bytes(), 48);
+    ///
+    /// assert_eq!(sliced.compact(0.5).total_data_buffer_bytes(), 48);
+    /// assert_eq!(sliced.compact(0.25).total_data_buffer_bytes(), 29);
+    /// ```
+    pub fn compact(&self, threshold: f64) -> Self {
+        let total = self.total_data_buffer_bytes();
+        let wasted = total.saturating_sub(self.total_buffer_bytes_used());
+        match total > 0 && wasted as f64 / total as f64 > threshold {
+            true => self.gc(),
+            false => self.clone(),
+        }
+    }
+
     /// Compare two [`GenericByteViewArray`] at index `left_idx` and `right_idx`
     ///
     /// Comparing two ByteView types are non-trivial.
@@ -843,7 +897,7 @@ impl From<Vec<Option<String>>> for StringViewArray {
 mod tests {
     use crate::builder::{BinaryViewBuilder, StringViewBuilder};
     use crate::{Array, BinaryViewArray, StringViewArray};
-    use arrow_buffer::{Buffer, ScalarBuffer};
+    use arrow_buffer::{Buffer, NullBuffer, ScalarBuffer};
     use arrow_data::ByteView;
 
     #[test]
@@ -1034,6 +1088,34 @@ mod tests {
         check_gc(&array.slice(3, 1));
     }
 
+    #[test]
+    fn test_compact() {
+        let long = "longer than 12 bytes";
+        let array = StringViewArray::from(vec![Some(long), Some("short"), None, Some(long)]);
+        assert_eq!(array.total_data_buffer_bytes(), 40);
+        assert_eq!(array.total_buffer_bytes_used(), 40);
+        assert_eq!(array.compact(0.0).total_data_buffer_bytes(), 40);
+
+        let sliced = array.slice(1, 2);
+        assert_eq!(sliced.total_buffer_bytes_used(), 0);
+        let compacted = sliced.compact(0.5);
+        assert_eq!(compacted.total_data_buffer_bytes(), 0);
+        assert_eq!(compacted, sliced);
+
+        // Null values do not reference the data buffers
+        let (views, buffers, _) = array.clone().into_parts();
+        let nulls = NullBuffer::from(vec![false, true, true, true]);
+        let array = StringViewArray::new(views, buffers, Some(nulls));
+        assert_eq!(array.total_buffer_bytes_used(), 20);
+        assert_eq!(array.compact(0.6).total_data_buffer_bytes(), 40);
+        let compacted = array.compact(0.4);
+        assert_eq!(compacted.total_data_buffer_bytes(), 20);
+        assert_eq!(compacted, array);
+
+        let empty = StringViewArray::from(Vec::<&str>::new());
+        assert_eq!(empty.compact(0.0).total_data_buffer_bytes(), 0);
+    }
+
     #[test]
     fn test_eq() {
         let test_data = [
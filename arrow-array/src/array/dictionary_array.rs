@@ -26,6 +26,7 @@ use crate::{
 use arrow_buffer::bit_util::set_bit;
 use arrow_buffer::buffer::NullBuffer;
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, BooleanBufferBuilder};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType};
 use std::any::Any;
@@ -572,6 +573,158 @@ impl<K: ArrowDictionaryKeyType> DictionaryArray<K> {
         }
         builder.finish()
     }
+
+    /// Returns a [`DictionaryArray`] without the values not referenced by any key,
+    /// remapping the keys accordingly, along with [`DictionaryStats`] of the savings
+    ///
+    /// Values are kept in their existing order, and so [`Self::is_ordered`] is preserved
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, DictionaryArray, Int32Array, StringArray};
+    /// # use arrow_array::types::Int32Type;
+    /// let values = StringArray::from(vec!["a", "b", "c", "d"]);
+    /// let keys = Int32Array::from(vec![Some(3), None, Some(1), Some(3)]);
+    /// let dictionary = DictionaryArray::<Int32Type>::new(keys, Arc::new(values));
+    ///
+    /// let (gc, stats) = dictionary.gc();
+    /// assert_eq!(gc.values().as_ref(), &StringArray::from(vec!["b", "d"]));
+    /// assert_eq!(gc.keys(), &Int32Array::from(vec![Some(1), None, Some(0), Some(1)]));
+    /// assert_eq!((stats.values_before, stats.values_after), (4, 2));
+    /// ```
+    pub fn gc(&self) -> (Self, DictionaryStats) {
+        let occupancy = self.occupancy();
+        let values_len = self.values.len();
+        if occupancy.count_set_bits() == values_len {
+            return (self.clone(), DictionaryStats::new(self, self, values_len));
+        }
+
+        let zero = K::Native::usize_as(0);
+        let mut mapping = vec![zero; values_len];
+        let data = self.values.to_data();
+        let mut mutable = MutableArrayData::new(vec![&data], false, occupancy.count_set_bits());
+        let mut next = 0;
+        for (start, end) in occupancy.set_slices() {
+            mutable.extend(0, start, end);
+            for m in &mut mapping[start..end] {
+                *m = K::Native::usize_as(next);
+                next += 1;
+            }
+        }
+        let values = make_array(mutable.freeze());
+
+        // Keys in null slots may be out of bounds
+        let keys = self
+            .keys
+            .unary(|k| mapping.get(k.as_usize()).copied().unwrap_or(zero));
+
+        // Safety: all valid keys were remapped to the index of their value
+        let mut gc = unsafe { Self::new_unchecked(keys, values) };
+        gc.is_ordered = self.is_ordered;
+        let stats = DictionaryStats::new(self, &gc, gc.values.len());
+        (gc, stats)
+    }
+
+    /// Returns this [`DictionaryArray`] with the narrowest key type of the same signedness
+    /// able to index its values, along with [`DictionaryStats`] of the savings
+    ///
+    /// Returns a copy of this array if its key type is already the narrowest possible.
+    /// Combine with [`Self::gc`] to first remove unreferenced values
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, DictionaryArray, Int32Array, StringArray};
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_schema::DataType;
+    /// let values = StringArray::from(vec!["a", "b"]);
+    /// let keys = Int32Array::from_iter_values((0..1024).map(|x| x % 2));
+    /// let dictionary = DictionaryArray::<Int32Type>::new(keys, Arc::new(values));
+    ///
+    /// let (minimized, stats) = dictionary.minimize_key_type();
+    /// let expected = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+    /// assert_eq!(minimized.data_type(), &expected);
+    /// assert_eq!(stats.memory_saved(), 1024 * 3);
+    /// ```
+    pub fn minimize_key_type(&self) -> (ArrayRef, DictionaryStats) {
+        let len = self.values.len();
+        let array: ArrayRef = match K::DATA_TYPE {
+            DataType::Int16 | DataType::Int32 | DataType::Int64 => match len {
+                l if l <= 1 << 7 => Arc::new(self.with_key_type::<Int8Type>()),
+                l if l <= 1 << 15 && K::DATA_TYPE != DataType::Int16 => {
+                    Arc::new(self.with_key_type::<Int16Type>())
+                }
+                l if l <= 1 << 31 && K::DATA_TYPE == DataType::Int64 => {
+                    Arc::new(self.with_key_type::<Int32Type>())
+                }
+                _ => Arc::new(self.clone()),
+            },
+            DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => match len {
+                l if l <= 1 << 8 => Arc::new(self.with_key_type::<UInt8Type>()),
+                l if l <= 1 << 16 && K::DATA_TYPE != DataType::UInt16 => {
+                    Arc::new(self.with_key_type::<UInt16Type>())
+                }
+                l if l <= 1 << 32 && K::DATA_TYPE == DataType::UInt64 => {
+                    Arc::new(self.with_key_type::<UInt32Type>())
+                }
+                _ => Arc::new(self.clone()),
+            },
+            _ => Arc::new(self.clone()),
+        };
+        let stats = DictionaryStats::new(self, &array, len);
+        (array, stats)
+    }
+
+    /// Converts the keys of this [`DictionaryArray`] to `T`, which must be able to
+    /// index all its values
+    fn with_key_type<T: ArrowDictionaryKeyType>(&self) -> DictionaryArray<T> {
+        // Keys in null slots may not be representable
+        let keys = self
+            .keys
+            .unary(|k| T::Native::from_usize(k.as_usize()).unwrap_or_default());
+        // Safety: all valid keys are unchanged
+        let mut array = unsafe { DictionaryArray::new_unchecked(keys, self.values.clone()) };
+        array.is_ordered = self.is_ordered;
+        array
+    }
+}
+
+/// Statistics of the space saved by [`DictionaryArray::gc`] or
+/// [`DictionaryArray::minimize_key_type`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictionaryStats {
+    /// The number of dictionary values before the operation
+    pub values_before: usize,
+    /// The number of dictionary values after the operation
+    pub values_after: usize,
+    /// The [memory size](Array::get_array_memory_size) before the operation
+    pub memory_before: usize,
+    /// The [memory size](Array::get_array_memory_size) after the operation
+    pub memory_after: usize,
+}
+
+impl DictionaryStats {
+    fn new<K: ArrowDictionaryKeyType>(
+        before: &DictionaryArray<K>,
+        after: &dyn Array,
+        values_after: usize,
+    ) -> Self {
+        Self {
+            values_before: before.values.len(),
+            values_after,
+            memory_before: before.get_array_memory_size(),
+            memory_after: after.get_array_memory_size(),
+        }
+    }
+
+    /// Returns the number of dictionary values removed
+    pub fn values_removed(&self) -> usize {
+        self.values_before - self.values_after
+    }
+
+    /// Returns the number of bytes of memory saved, or `0` if none were saved
+    pub fn memory_saved(&self) -> usize {
+        self.memory_before.saturating_sub(self.memory_after)
+    }
 }
 
 /// Constructs a `DictionaryArray` from an array data reference.
@@ -1045,7 +1198,10 @@ impl<K: ArrowDictionaryKeyType> AnyDictionaryArray for DictionaryArray<K> {
 mod tests {
     use super::*;
     use crate::cast::as_dictionary_array;
-    use crate::{Int16Array, Int32Array, Int8Array, RunArray};
+    use crate::{
+        Int16Array, Int32Array, Int64Array, Int8Array, RunArray, UInt16Array, UInt32Array,
+        UInt64Array,
+    };
     use arrow_buffer::{Buffer, ToByteSlice};
 
     #[test]
@@ -1454,6 +1610,99 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gc() {
+        let values = Int32Array::from(vec![Some(0), Some(1), None, Some(3), Some(4), Some(5)]);
+        let keys = Int32Array::new(
+            vec![4, 700, 2, 4, 5, 0].into(),
+            Some(NullBuffer::from(vec![true, false, true, true, true, true])),
+        );
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let (gc, stats) = dict.gc();
+        let expected_values = Int32Array::from(vec![Some(0), None, Some(4), Some(5)]);
+        assert_eq!(gc.values().as_ref(), &expected_values as &dyn Array);
+        let expected_keys = Int32Array::new(
+            vec![2, 0, 1, 2, 3, 0].into(),
+            Some(NullBuffer::from(vec![true, false, true, true, true, true])),
+        );
+        assert_eq!(gc.keys(), &expected_keys);
+        assert_eq!(stats.values_removed(), 2);
+        assert_eq!(stats.values_after, 4);
+
+        // Logical values are unchanged
+        let before: Vec<_> = dict
+            .downcast_dict::<Int32Array>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let after: Vec<_> = gc
+            .downcast_dict::<Int32Array>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(before, after);
+
+        // No unreferenced values
+        let (again, stats) = gc.gc();
+        assert_eq!(again, gc);
+        assert_eq!(stats.values_removed(), 0);
+        assert_eq!(stats.memory_saved(), 0);
+
+        // All keys null
+        let keys = Int8Array::new_null(3);
+        let dict = DictionaryArray::new(keys, Arc::new(StringArray::from(vec!["a", "b"])));
+        let (gc, stats) = dict.gc();
+        assert_eq!(gc.values().len(), 0);
+        assert_eq!(gc.null_count(), 3);
+        assert_eq!(stats.values_removed(), 2);
+    }
+
+    #[test]
+    fn test_minimize_key_type() {
+        let dict_type = |k: DataType| DataType::Dictionary(Box::new(k), Box::new(DataType::Int32));
+
+        let values = Arc::new(Int32Array::from_iter_values(0..128));
+        let keys = Int64Array::new(
+            vec![127, -5, 0].into(),
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        let dict = DictionaryArray::new(keys, values.clone());
+        let (minimized, stats) = dict.minimize_key_type();
+        assert_eq!(minimized.data_type(), &dict_type(DataType::Int8));
+        let minimized = minimized.as_dictionary::<Int8Type>();
+        assert_eq!(
+            minimized.keys(),
+            &Int8Array::from(vec![Some(127), None, Some(0)])
+        );
+        assert_eq!(stats.values_removed(), 0);
+
+        let keys = Int64Array::from_iter_values((0..1024).map(|x| x % 128));
+        let dict = DictionaryArray::new(keys, values.clone());
+        let (_, stats) = dict.minimize_key_type();
+        assert_eq!(stats.memory_saved(), 1024 * 7);
+
+        let values = Arc::new(Int32Array::from_iter_values(0..129));
+        let dict = DictionaryArray::new(Int64Array::from(vec![128]), values.clone());
+        let (minimized, _) = dict.minimize_key_type();
+        assert_eq!(minimized.data_type(), &dict_type(DataType::Int16));
+
+        let dict = DictionaryArray::new(Int16Array::from(vec![128]), values.clone());
+        let (minimized, stats) = dict.minimize_key_type();
+        assert_eq!(minimized.data_type(), &dict_type(DataType::Int16));
+        assert_eq!(stats.memory_saved(), 0);
+
+        let dict = DictionaryArray::new(UInt32Array::from(vec![128]), values.clone());
+        let (minimized, _) = dict.minimize_key_type();
+        assert_eq!(minimized.data_type(), &dict_type(DataType::UInt8));
+
+        let values = Arc::new(Int32Array::from_iter_values(0..257));
+        let dict = DictionaryArray::new(UInt64Array::from(vec![256]), values);
+        let (minimized, _) = dict.minimize_key_type();
+        assert_eq!(minimized.data_type(), &dict_type(DataType::UInt16));
+        let minimized = minimized.as_dictionary::<UInt16Type>();
+        assert_eq!(minimized.keys(), &UInt16Array::from(vec![256]));
+    }
+
     #[test]
     fn test_iterator_nulls() {
         let keys = Int32Array::new(
@@ -25,7 +25,7 @@ use arrow_schema::{ArrowError, DataType, Field};
 use crate::{
     builder::StringRunBuilder,
     make_array,
-    run_iterator::RunArrayIter,
+    run_iterator::{RunArrayIter, RunsIter},
     types::{Int16Type, Int32Type, Int64Type, RunEndIndexType},
     Array, ArrayAccessor, ArrayRef, PrimitiveArray,
 };
@@ -558,6 +558,26 @@ impl<'a, R: RunEndIndexType, V> TypedRunArray<'a, R, V> {
     pub fn run_array(&self) -> &'a RunArray<R> {
         self.run_array
     }
+
+    /// Returns an iterator over the runs of this [`TypedRunArray`], yielding the range of
+    /// logical indices of each run and its value, without a binary search per element
+    ///
+    /// ```
+    /// # use arrow_array::{Int32RunArray, StringArray};
+    /// let array: Int32RunArray = vec!["a", "a", "b", "c", "c", "c"].into_iter().collect();
+    /// let array = array.slice(1, 4);
+    /// let typed = array.downcast::<StringArray>().unwrap();
+    /// let runs: Vec<_> = typed.runs().collect();
+    /// assert_eq!(runs, vec![(0..1, Some("a")), (1..2, Some("b")), (2..4, Some("c"))]);
+    /// ```
+    pub fn runs(&self) -> RunsIter<'a, R, V>
+    where
+        V: Sync + Send,
+        &'a V: ArrayAccessor,
+        <&'a V as ArrayAccessor>::Item: Default,
+    {
+        RunsIter::new(*self)
+    }
 }
 
 impl<R: RunEndIndexType, V: Sync> Array for TypedRunArray<'_, R, V> {
@@ -146,7 +146,9 @@ impl StructArray {
 
             if !f.is_nullable() {
                 if let Some(a) = a.logical_nulls() {
-                    if !nulls.as_ref().map(|n| n.contains(&a)).unwrap_or_default() {
+                    if a.null_count() > 0
+                        && !nulls.as_ref().map(|n| n.contains(&a)).unwrap_or_default()
+                    {
                         return Err(ArrowError::InvalidArgumentError(format!(
                             "Found unmasked nulls for non-nullable StructArray field {:?}",
                             f.name()
@@ -743,6 +745,16 @@ mod tests {
         )]));
     }
 
+    #[test]
+    fn test_struct_array_non_nullable_child_without_nulls() {
+        // A null buffer containing no nulls is valid for a non-nullable field
+        let child = Int32Array::new(vec![42, 28, 19].into(), Some(NullBuffer::new_valid(3)));
+        let fields = Fields::from(vec![Field::new("c", DataType::Int32, false)]);
+        let array = StructArray::try_new(fields, vec![Arc::new(child)], None).unwrap();
+        assert_eq!(array.null_count(), 0);
+        assert_eq!(array.len(), 3);
+    }
+
     #[test]
     fn test_struct_array_fmt_debug() {
         let arr: StructArray = StructArray::new(
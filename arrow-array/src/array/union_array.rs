@@ -38,7 +38,7 @@ use std::sync::Arc;
 /// [specification](https://arrow.apache.org/docs/format/Columnar.html#union-layout).
 ///
 /// [UnionBuilder](crate::builder::UnionBuilder) can be used to
-/// create [UnionArray]'s of primitive types. `UnionArray`'s of nested
+/// create [UnionArray]'s of primitive and byte array types. `UnionArray`'s of nested
 /// types are also supported but not via `UnionBuilder`, see the tests
 /// for examples.
 ///
@@ -1008,7 +1008,9 @@ mod tests {
     use crate::array::Int8Type;
     use crate::builder::UnionBuilder;
     use crate::cast::AsArray;
-    use crate::types::{Float32Type, Float64Type, Int32Type, Int64Type};
+    use crate::types::{
+        BinaryType, Float32Type, Float64Type, Int32Type, Int64Type, LargeUtf8Type, Utf8Type,
+    };
     use crate::{Float64Array, Int32Array, Int64Array, StringArray};
     use crate::{Int8Array, RecordBatch};
     use arrow_buffer::Buffer;
@@ -1529,6 +1531,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_builder_mixed_with_bytes() {
+        for mut builder in [UnionBuilder::new_dense(), UnionBuilder::new_sparse()] {
+            builder.append::<Int32Type>("a", 1).unwrap();
+            builder.append_bytes::<Utf8Type>("b", "hello").unwrap();
+            builder
+                .append_bytes::<BinaryType>("c", b"\x00\x01")
+                .unwrap();
+            builder.append_bytes_null::<Utf8Type>("b").unwrap();
+            builder.append_null_type_id(0).unwrap();
+            builder.append_bytes::<Utf8Type>("b", "world").unwrap();
+            assert_eq!(builder.len(), 6);
+            assert_eq!(builder.type_id("c"), Some(2));
+            assert_eq!(builder.type_id("d"), None);
+
+            let err = builder.append_null_type_id(3).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid argument error: No field with type id 3"
+            );
+            let err = builder.append_bytes::<LargeUtf8Type>("b", "x").unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid argument error: Attempt to write col \"b\" with type LargeUtf8 doesn't match existing type Utf8"
+            );
+            // A failed append leaves the builder unchanged
+            builder.append::<Int32Type>("a", 2).unwrap();
+
+            let union = builder.build().unwrap();
+            assert_eq!(union.len(), 7);
+            assert_eq!(union.type_ids(), &[0, 1, 2, 1, 0, 1, 0]);
+            assert_eq!(union.child(1).data_type(), &DataType::Utf8);
+            assert_eq!(union.child(2).data_type(), &DataType::Binary);
+
+            assert_eq!(union.value(1).as_string::<i32>().value(0), "hello");
+            assert_eq!(union.value(2).as_binary::<i32>().value(0), b"\x00\x01");
+            assert_eq!(union.value(5).as_string::<i32>().value(0), "world");
+            assert_eq!(union.value(6).as_primitive::<Int32Type>().value(0), 2);
+            let nulls = union.logical_nulls().unwrap();
+            assert_eq!(
+                (0..7).map(|i| nulls.is_null(i)).collect::<Vec<_>>(),
+                [false, false, false, true, true, false, false]
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_too_many_fields() {
+        let mut builder = UnionBuilder::new_dense();
+        for i in 0..128 {
+            builder.append::<Int32Type>(&i.to_string(), i).unwrap();
+        }
+        let err = builder.append::<Int32Type>("128", 128).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot add field \"128\", a union can have at most 128 fields"
+        );
+        assert_eq!(builder.build().unwrap().len(), 128);
+    }
+
     #[test]
     fn test_union_array_validity() {
         let mut builder = UnionBuilder::new_sparse();
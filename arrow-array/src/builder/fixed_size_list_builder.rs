@@ -17,8 +17,8 @@
 
 use crate::builder::ArrayBuilder;
 use crate::{ArrayRef, FixedSizeListArray};
-use arrow_buffer::NullBufferBuilder;
-use arrow_schema::{Field, FieldRef};
+use arrow_buffer::{NullBuffer, NullBufferBuilder};
+use arrow_schema::{ArrowError, Field, FieldRef};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -166,47 +166,78 @@ where
     }
 
     /// Builds the [`FixedSizeListBuilder`] and reset this builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`FixedSizeListArray`] is not valid, see [`Self::try_finish`]
     pub fn finish(&mut self) -> FixedSizeListArray {
+        self.try_finish().unwrap()
+    }
+
+    /// Builds the [`FixedSizeListBuilder`] and reset this builder, returning an error if
+    /// the number of values appended to the child builder is not [`Self::value_length`]
+    /// times the number of slots, or the values do not match the field set by
+    /// [`Self::with_field`]
+    ///
+    /// The builder is reset even if an error is returned
+    ///
+    /// ```
+    /// # use arrow_array::builder::{FixedSizeListBuilder, Int32Builder};
+    /// let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+    /// builder.values().append_slice(&[1, 2, 3]);
+    /// builder.append(true);
+    /// let err = builder.try_finish().unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Invalid argument error: Length of the child array (3) must be the multiple of the value length (2) and the array length (1)."
+    /// );
+    /// ```
+    pub fn try_finish(&mut self) -> Result<FixedSizeListArray, ArrowError> {
         let len = self.len();
         let values = self.values_builder.finish();
         let nulls = self.null_buffer_builder.finish();
-
-        assert_eq!(
-            values.len(), len * self.list_len as usize,
-            "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
-            values.len(),
-            self.list_len,
-            len,
-        );
-
-        let field = self
-            .field
-            .clone()
-            .unwrap_or_else(|| Arc::new(Field::new_list_field(values.data_type().clone(), true)));
-
-        FixedSizeListArray::new(field, self.list_len, values, nulls)
+        self.build(len, values, nulls)
     }
 
     /// Builds the [`FixedSizeListBuilder`] without resetting the builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`FixedSizeListArray`] is not valid, see [`Self::try_finish`]
     pub fn finish_cloned(&self) -> FixedSizeListArray {
         let len = self.len();
         let values = self.values_builder.finish_cloned();
         let nulls = self.null_buffer_builder.finish_cloned();
+        self.build(len, values, nulls).unwrap()
+    }
 
-        assert_eq!(
-            values.len(), len * self.list_len as usize,
-            "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
-            values.len(),
-            self.list_len,
-            len,
-        );
+    fn build(
+        &self,
+        len: usize,
+        values: ArrayRef,
+        nulls: Option<NullBuffer>,
+    ) -> Result<FixedSizeListArray, ArrowError> {
+        if values.len() != len * self.list_len as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
+                values.len(),
+                self.list_len,
+                len,
+            )));
+        }
 
         let field = self
             .field
             .clone()
             .unwrap_or_else(|| Arc::new(Field::new_list_field(values.data_type().clone(), true)));
 
-        FixedSizeListArray::new(field, self.list_len, values, nulls)
+        // The length of a FixedSizeListArray of size 0 is that of its null buffer
+        let nulls = match self.list_len {
+            0 => nulls.or_else(|| Some(NullBuffer::new_valid(len))),
+            _ => nulls,
+        };
+
+        FixedSizeListArray::try_new(field, self.list_len, values, nulls)
     }
 
     /// Returns the current null buffer as a slice
@@ -220,7 +251,7 @@ mod tests {
     use super::*;
     use arrow_schema::DataType;
 
-    use crate::builder::Int32Builder;
+    use crate::builder::{Int32Builder, StructBuilder};
     use crate::Array;
     use crate::Int32Array;
 
@@ -489,4 +520,87 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_try_finish() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        builder.values().append_slice(&[1, 2, 3]);
+        builder.append(true);
+        let err = builder.try_finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Length of the child array (3) must be the multiple of the value length (2) and the array length (1)."
+        );
+
+        // The builder is reset after an error
+        builder.values().append_slice(&[1, 2]);
+        builder.append(true);
+        let array = builder.try_finish().unwrap();
+        assert_eq!(array.len(), 1);
+
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2).with_field(Field::new(
+            "item",
+            DataType::Int64,
+            true,
+        ));
+        builder.values().append_slice(&[1, 2]);
+        builder.append(true);
+        let err = builder.try_finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: FixedSizeListArray expected data type Int64 got Int32 for \"item\""
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_empty_lists() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 0);
+        builder.append(true);
+        builder.append(true);
+        let array = builder.finish();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.null_count(), 0);
+
+        builder.append(true);
+        builder.append(false);
+        builder.append(true);
+        let array = builder.finish_cloned();
+        assert_eq!(array.len(), 3);
+        assert!(array.is_null(1));
+    }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_nested() {
+        let mut builder = FixedSizeListBuilder::new(
+            FixedSizeListBuilder::new(
+                StructBuilder::from_fields(vec![Field::new("a", DataType::Int32, true)], 0),
+                1,
+            ),
+            2,
+        );
+        for i in 0..4 {
+            let inner = builder.values();
+            inner
+                .values()
+                .field_builder::<Int32Builder>(0)
+                .unwrap()
+                .append_value(i);
+            inner.values().append(true);
+            inner.append(true);
+            if i % 2 == 1 {
+                builder.append(true);
+            }
+        }
+        let array = builder.finish();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.values().len(), 4);
+        assert_eq!(
+            array.value_type(),
+            DataType::new_fixed_size_list(
+                DataType::Struct(vec![Field::new("a", DataType::Int32, true)].into()),
+                1,
+                true
+            )
+        );
+    }
 }
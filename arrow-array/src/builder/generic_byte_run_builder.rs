@@ -170,27 +170,56 @@ where
 
     /// Appends value to the logical array encoded by the RunArray.
     pub fn append_value(&mut self, input_value: impl AsRef<V::Native>) {
-        let value: &[u8] = input_value.as_ref().as_ref();
-        if !self.has_current_value {
-            self.append_run_end();
-            self.current_value.extend_from_slice(value);
-            self.has_current_value = true;
-        } else if self.current_value.as_slice() != value {
-            self.append_run_end();
-            self.current_value.clear();
-            self.current_value.extend_from_slice(value);
-        }
-        self.current_run_end_index += 1;
+        self.append_run(Some(input_value), 1)
     }
 
     /// Appends null to the logical array encoded by the RunArray.
     pub fn append_null(&mut self) {
-        if self.has_current_value {
-            self.append_run_end();
-            self.current_value.clear();
-            self.has_current_value = false;
+        self.append_run(None::<&V::Native>, 1)
+    }
+
+    /// Appends `run_length` repetitions of an optional value to the logical array
+    /// encoded by the RunArray, without comparing each repetition.
+    ///
+    /// A run equal to the preceding value extends it, and an empty run is ignored.
+    ///
+    /// ```
+    /// # use arrow_array::builder::StringRunBuilder;
+    /// # use arrow_array::types::Int16Type;
+    /// let mut builder = StringRunBuilder::<Int16Type>::new();
+    /// builder.append_run(Some("abc"), 3);
+    /// builder.append_run(None::<&str>, 2);
+    /// builder.extend([(Some("def"), 1), (Some("def"), 4)]);
+    /// let array = builder.finish();
+    ///
+    /// assert_eq!(array.run_ends().values(), &[3, 5, 10]);
+    /// ```
+    pub fn append_run(&mut self, input_value: Option<impl AsRef<V::Native>>, run_length: usize) {
+        if run_length == 0 {
+            return;
+        }
+        match input_value {
+            Some(input_value) => {
+                let value: &[u8] = input_value.as_ref().as_ref();
+                if !self.has_current_value {
+                    self.append_run_end();
+                    self.current_value.extend_from_slice(value);
+                    self.has_current_value = true;
+                } else if self.current_value.as_slice() != value {
+                    self.append_run_end();
+                    self.current_value.clear();
+                    self.current_value.extend_from_slice(value);
+                }
+            }
+            None => {
+                if self.has_current_value {
+                    self.append_run_end();
+                    self.current_value.clear();
+                    self.has_current_value = false;
+                }
+            }
         }
-        self.current_run_end_index += 1;
+        self.current_run_end_index += run_length;
     }
 
     /// Creates the RunArray and resets the builder.
@@ -299,6 +328,19 @@ where
     }
 }
 
+impl<R, V, S> Extend<(Option<S>, usize)> for GenericByteRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: ByteArrayType,
+    S: AsRef<V::Native>,
+{
+    fn extend<T: IntoIterator<Item = (Option<S>, usize)>>(&mut self, iter: T) {
+        for (value, run_length) in iter {
+            self.append_run(value, run_length);
+        }
+    }
+}
+
 /// Builder for [`RunArray`] of [`StringArray`](crate::array::StringArray)
 ///
 /// ```
@@ -380,6 +422,7 @@ mod tests {
     use crate::types::{Int16Type, Int32Type};
     use crate::GenericByteArray;
     use crate::Int16RunArray;
+    use crate::StringArray;
 
     fn test_bytes_run_builder<T>(values: Vec<&T::Native>)
     where
@@ -514,4 +557,30 @@ mod tests {
         assert_eq!(str_array.value(2), "b");
         assert_eq!(str_array.value(3), "cupcakes");
     }
+
+    #[test]
+    fn test_append_run() {
+        let mut builder = StringRunBuilder::<Int32Type>::new();
+        builder.append_null();
+        builder.append_run(None::<&str>, 2);
+        builder.append_run(Some("a"), 0);
+        builder.append_run(Some("a"), 2);
+        builder.extend([(Some("a"), 1), (Some("b"), 3), (None, 1)]);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.run_ends().values(), &[3, 6, 9, 10]);
+
+        let typed = array.downcast::<StringArray>().unwrap();
+        let runs: Vec<_> = typed.runs().collect();
+        assert_eq!(
+            runs,
+            vec![
+                (0..3, None),
+                (3..6, Some("a")),
+                (6..9, Some("b")),
+                (9..10, None)
+            ]
+        );
+    }
 }
@@ -28,7 +28,7 @@ use hashbrown::HashTable;
 use crate::builder::ArrayBuilder;
 use crate::types::bytes::ByteArrayNativeType;
 use crate::types::{BinaryViewType, ByteViewType, StringViewType};
-use crate::{ArrayRef, GenericByteViewArray};
+use crate::{Array, ArrayRef, GenericByteViewArray};
 
 const STARTING_BLOCK_SIZE: u32 = 8 * 1024; // 8KiB
 const MAX_BLOCK_SIZE: u32 = 2 * 1024 * 1024; // 2MiB
@@ -77,7 +77,9 @@ impl BlockSizeGrowthStrategy {
 /// Some use-cases may wish to reuse an existing allocation containing string data, for example,
 /// when parsing data from a parquet data page. In such a case entire blocks can be appended
 /// using [`GenericByteViewBuilder::append_block`] and then views into this block appended
-/// using [`GenericByteViewBuilder::try_append_view`]
+/// using [`GenericByteViewBuilder::try_append_view`]. The values of an existing
+/// [`GenericByteViewArray`] can likewise be appended without copying their data using
+/// [`GenericByteViewBuilder::append_array`]
 pub struct GenericByteViewBuilder<T: ByteViewType + ?Sized> {
     views_builder: BufferBuilder<u128>,
     null_buffer_builder: NullBufferBuilder,
@@ -234,6 +236,70 @@ impl<T: ByteViewType + ?Sized> GenericByteViewBuilder<T> {
         Ok(())
     }
 
+    /// Appends all values of `array` without copying their data
+    ///
+    /// The data buffers of `array` are appended as blocks, see [`Self::append_block`], and
+    /// only the buffer indices of its views are rewritten. This retains the whole data
+    /// buffers of `array`, even if its views only reference a small portion of them, see
+    /// [`GenericByteViewArray::compact`]. The appended values are not deduplicated, see
+    /// [`Self::with_deduplicate_strings`]
+    ///
+    /// Note: this will first flush any in-progress block if `array` has data buffers
+    ///
+    /// ```
+    /// # use arrow_array::builder::StringViewBuilder;
+    /// # use arrow_array::{Array, StringViewArray};
+    /// let a = StringViewArray::from(vec![Some("a string longer than 12 bytes"), None]);
+    /// let b = StringViewArray::from(vec!["short", "another long string"]);
+    ///
+    /// let mut builder = StringViewBuilder::new();
+    /// builder.append_value("first");
+    /// builder.append_array(&a);
+    /// builder.append_array(&b);
+    /// let array = builder.finish();
+    ///
+    /// let expected = [
+    ///     Some("first"),
+    ///     Some("a string longer than 12 bytes"),
+    ///     None,
+    ///     Some("short"),
+    ///     Some("another long string"),
+    /// ];
+    /// assert_eq!(array, StringViewArray::from_iter(expected));
+    /// // The data buffers of `a` and `b` are shared
+    /// assert_eq!(array.data_buffers()[1].as_ptr(), b.data_buffers()[0].as_ptr());
+    /// ```
+    pub fn append_array(&mut self, array: &GenericByteViewArray<T>) {
+        let offset = match array.data_buffers() {
+            [] => 0,
+            buffers => {
+                self.flush_in_progress();
+                let offset = self.completed.len() as u32;
+                buffers.iter().for_each(|b| self.push_completed(b.clone()));
+                offset
+            }
+        };
+
+        let views = array.views().iter().map(|v| match *v as u32 {
+            len if len > 12 => {
+                let mut view = ByteView::from(*v);
+                view.buffer_index += offset;
+                view.into()
+            }
+            _ => *v,
+        });
+        match array.nulls() {
+            Some(nulls) => views.zip(nulls.iter()).for_each(|(view, valid)| {
+                self.views_builder.append(if valid { view } else { 0 });
+                self.null_buffer_builder.append(valid);
+            }),
+            None => {
+                views.for_each(|view| self.views_builder.append(view));
+                self.null_buffer_builder.append_n_non_nulls(array.len());
+            }
+        }
+    }
+
     /// Flushes the in progress block if any
     #[inline]
     fn flush_in_progress(&mut self) {
@@ -558,7 +624,7 @@ mod tests {
     use core::str;
 
     use super::*;
-    use crate::Array;
+    use crate::StringViewArray;
 
     #[test]
     fn test_string_view_deduplicate() {
@@ -610,6 +676,48 @@ mod tests {
         let _array = builder.finish();
     }
 
+    #[test]
+    fn test_append_array() {
+        let long = "longer than 12 bytes";
+        let a = {
+            let mut builder = StringViewBuilder::new().with_fixed_block_size(32);
+            builder.append_value(long);
+            builder.append_null();
+            builder.append_value("a longer value in a second block");
+            builder.finish()
+        };
+        assert_eq!(a.data_buffers().len(), 2);
+        let sliced = a.slice(1, 2);
+
+        let mut builder = StringViewBuilder::new();
+        builder.append_value("in progress value");
+        builder.append_array(&sliced);
+        builder.append_array(&StringViewArray::from(vec![Some("tiny"), None]));
+        builder.append_value(long);
+        builder.append_array(&a);
+        let array = builder.finish();
+
+        let expected = StringViewArray::from(vec![
+            Some("in progress value"),
+            None,
+            Some("a longer value in a second block"),
+            Some("tiny"),
+            None,
+            Some(long),
+            Some(long),
+            None,
+            Some("a longer value in a second block"),
+        ]);
+        assert_eq!(array, expected);
+        assert_eq!(array.null_count(), 3);
+
+        // The in-progress block is flushed before adopting the buffers of `sliced` and `a`
+        assert_eq!(array.data_buffers().len(), 6);
+        for (i, buffer) in [(1, &a.data_buffers()[0]), (4, &a.data_buffers()[0])] {
+            assert_eq!(array.data_buffers()[i].as_ptr(), buffer.as_ptr());
+        }
+    }
+
     #[test]
     fn test_string_view() {
         let b1 = Buffer::from(b"world\xFFbananas\xF0\x9F\x98\x81");
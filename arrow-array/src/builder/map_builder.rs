@@ -16,12 +16,16 @@
 // under the License.
 
 use crate::builder::{ArrayBuilder, BufferBuilder};
+use crate::cast::AsArray;
+use crate::{downcast_primitive_array, make_array, ArrowNativeTypeOp};
 use crate::{Array, ArrayRef, MapArray, StructArray};
 use arrow_buffer::Buffer;
 use arrow_buffer::{NullBuffer, NullBufferBuilder};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, Field, FieldRef};
 use std::any::Any;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 /// Builder for [`MapArray`]
@@ -62,6 +66,23 @@ pub struct MapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
     key_builder: K,
     value_builder: V,
     value_field: Option<FieldRef>,
+    sort_keys: bool,
+    keys_sorted: bool,
+    duplicate_keys: DuplicateKeys,
+}
+
+/// How a [`MapBuilder`] handles duplicate keys within a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateKeys {
+    /// Keep all entries, including those with duplicate keys
+    #[default]
+    Allow,
+    /// Keep the first appended entry of each key
+    KeepFirst,
+    /// Keep the last appended entry of each key
+    KeepLast,
+    /// Fail to finish the [`MapArray`] if a map has duplicate keys
+    Error,
 }
 
 /// The [`Field`] names for a [`MapArray`]
@@ -108,6 +129,9 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             key_builder,
             value_builder,
             value_field: None,
+            sort_keys: false,
+            keys_sorted: false,
+            duplicate_keys: DuplicateKeys::Allow,
         }
     }
 
@@ -124,6 +148,39 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
         }
     }
 
+    /// Sort the entries of each map by key when finishing the [`MapArray`]
+    ///
+    /// Entries with equal keys keep the order they were appended in. This sets the
+    /// `keys_sorted` flag of the [`DataType::Map`], see [`Self::with_keys_sorted`]
+    ///
+    /// Note: sorting is supported for keys of primitive, boolean, string and binary types,
+    /// [`Self::try_finish`] returns an error for other key types
+    pub fn with_sort_keys(self, sort_keys: bool) -> Self {
+        Self { sort_keys, ..self }
+    }
+
+    /// Declare that the entries of each map are appended sorted by key
+    ///
+    /// This sets the `keys_sorted` flag of the [`DataType::Map`], and
+    /// [`Self::try_finish`] returns an error if the keys of a map are not sorted
+    pub fn with_keys_sorted(self, keys_sorted: bool) -> Self {
+        Self {
+            keys_sorted,
+            ..self
+        }
+    }
+
+    /// Set how duplicate keys within a map are handled, by default they are allowed
+    ///
+    /// Note: finding duplicate keys is supported for the same key types as
+    /// [`Self::with_sort_keys`]
+    pub fn with_duplicate_keys(self, duplicate_keys: DuplicateKeys) -> Self {
+        Self {
+            duplicate_keys,
+            ..self
+        }
+    }
+
     /// Returns the key array builder of the map
     pub fn keys(&mut self) -> &mut K {
         &mut self.key_builder
@@ -157,7 +214,41 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
     }
 
     /// Builds the [`MapArray`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are not valid for the options of this builder, see
+    /// [`Self::try_finish`]
     pub fn finish(&mut self) -> MapArray {
+        self.try_finish().unwrap()
+    }
+
+    /// Builds the [`MapArray`], returning an error if the keys of a map are not sorted
+    /// as declared by [`Self::with_keys_sorted`], or are duplicated and
+    /// [`DuplicateKeys::Error`] is set
+    ///
+    /// The builder is reset even if an error is returned
+    ///
+    /// ```
+    /// # use arrow_array::builder::{DuplicateKeys, Int32Builder, MapBuilder, StringBuilder};
+    /// # use arrow_array::{Int32Array, StringArray};
+    /// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+    ///     .with_sort_keys(true)
+    ///     .with_duplicate_keys(DuplicateKeys::KeepLast);
+    ///
+    /// // Construct `[{"b": 1, "a": 2, "b": 3}]`
+    /// for (key, value) in [("b", 1), ("a", 2), ("b", 3)] {
+    ///     builder.keys().append_value(key);
+    ///     builder.values().append_value(value);
+    /// }
+    /// builder.append(true).unwrap();
+    ///
+    /// // Sorted and deduplicated to `[{"a": 2, "b": 3}]`
+    /// let array = builder.try_finish().unwrap();
+    /// assert_eq!(array.keys().as_ref(), &StringArray::from(vec!["a", "b"]));
+    /// assert_eq!(array.values().as_ref(), &Int32Array::from(vec![2, 3]));
+    /// ```
+    pub fn try_finish(&mut self) -> Result<MapArray, ArrowError> {
         let len = self.len();
         // Build the keys
         let keys_arr = self.key_builder.finish();
@@ -170,6 +261,11 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
     }
 
     /// Builds the [`MapArray`] without resetting the builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are not valid for the options of this builder, see
+    /// [`Self::try_finish`]
     pub fn finish_cloned(&self) -> MapArray {
         let len = self.len();
         // Build the keys
@@ -178,6 +274,7 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
         let offset_buffer = Buffer::from_slice_ref(self.offsets_builder.as_slice());
         let nulls = self.null_buffer_builder.finish_cloned();
         self.finish_helper(keys_arr, values_arr, offset_buffer, nulls, len)
+            .unwrap()
     }
 
     fn finish_helper(
@@ -187,13 +284,20 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
         offset_buffer: Buffer,
         nulls: Option<NullBuffer>,
         len: usize,
-    ) -> MapArray {
+    ) -> Result<MapArray, ArrowError> {
         assert!(
             keys_arr.null_count() == 0,
             "Keys array must have no null values, found {} null value(s)",
             keys_arr.null_count()
         );
 
+        let (keys_arr, values_arr, offset_buffer) =
+            match self.sort_keys || self.keys_sorted || self.duplicate_keys != DuplicateKeys::Allow
+            {
+                true => self.normalize_entries(keys_arr, values_arr, offset_buffer)?,
+                false => (keys_arr, values_arr, offset_buffer),
+            };
+
         let keys_field = Arc::new(Field::new(
             self.field_names.key.as_str(),
             keys_arr.data_type().clone(),
@@ -216,7 +320,8 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             struct_array.data_type().clone(),
             false, // always non-nullable
         ));
-        let array_data = ArrayData::builder(DataType::Map(map_field, false)) // TODO: support sorted keys
+        let keys_sorted = self.sort_keys || self.keys_sorted;
+        let array_data = ArrayData::builder(DataType::Map(map_field, keys_sorted))
             .len(len)
             .add_buffer(offset_buffer)
             .add_child_data(struct_array.into_data())
@@ -224,7 +329,88 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
 
         let array_data = unsafe { array_data.build_unchecked() };
 
-        MapArray::from(array_data)
+        Ok(MapArray::from(array_data))
+    }
+
+    /// Sorts, validates and deduplicates the entries of each map according to the
+    /// options of this builder
+    fn normalize_entries(
+        &self,
+        keys: ArrayRef,
+        values: ArrayRef,
+        offsets: Buffer,
+    ) -> Result<(ArrayRef, ArrayRef, Buffer), ArrowError> {
+        let cmp = key_comparator(keys.as_ref())?;
+        let offsets_slice: &[i32] = offsets.typed_data();
+
+        let mut indices = Vec::with_capacity(keys.len());
+        let mut new_offsets = BufferBuilder::<i32>::new(offsets_slice.len());
+        new_offsets.append(0);
+        let mut entries = vec![];
+        let mut sorted = vec![];
+        for (i, w) in offsets_slice.windows(2).enumerate() {
+            entries.clear();
+            entries.extend(w[0] as usize..w[1] as usize);
+
+            let check_sorted = self.keys_sorted && !self.sort_keys;
+            if check_sorted && entries.windows(2).any(|e| cmp(e[0], e[1]).is_gt()) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Map at index {i} has unsorted keys"
+                )));
+            }
+
+            // A stable sort keeps entries with equal keys in the order they were appended
+            sorted.clear();
+            sorted.extend_from_slice(&entries);
+            if self.sort_keys || self.duplicate_keys != DuplicateKeys::Allow {
+                sorted.sort_by(|a, b| cmp(*a, *b));
+            }
+            if self.duplicate_keys != DuplicateKeys::Allow {
+                let mut keep = vec![true; entries.len()];
+                let mut start = 0;
+                while start < sorted.len() {
+                    let end = (start + 1..sorted.len())
+                        .find(|j| cmp(sorted[start], sorted[*j]).is_ne())
+                        .unwrap_or(sorted.len());
+                    let run = &sorted[start..end];
+                    start = end;
+                    if run.len() == 1 {
+                        continue;
+                    }
+                    let kept = match self.duplicate_keys {
+                        DuplicateKeys::KeepFirst => run[0],
+                        DuplicateKeys::KeepLast => run[run.len() - 1],
+                        _ => {
+                            return Err(ArrowError::InvalidArgumentError(format!(
+                                "Map at index {i} has duplicate keys"
+                            )))
+                        }
+                    };
+                    for idx in run.iter().filter(|idx| **idx != kept) {
+                        keep[*idx - w[0] as usize] = false;
+                    }
+                }
+                let base = w[0] as usize;
+                sorted.retain(|idx| keep[*idx - base]);
+                entries.retain(|idx| keep[*idx - base]);
+            }
+
+            match self.sort_keys {
+                true => indices.extend_from_slice(&sorted),
+                false => indices.extend_from_slice(&entries),
+            }
+            new_offsets.append(indices.len() as i32);
+        }
+
+        drop(cmp);
+        if indices.iter().copied().eq(0..keys.len()) {
+            return Ok((keys, values, offsets));
+        }
+        Ok((
+            take_entries(keys.as_ref(), &indices),
+            take_entries(values.as_ref(), &indices),
+            new_offsets.finish(),
+        ))
     }
 
     /// Returns the current null buffer as a slice
@@ -233,6 +419,62 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
     }
 }
 
+/// Returns a comparator of the values at two indices of `keys`
+fn key_comparator(
+    keys: &dyn Array,
+) -> Result<Box<dyn Fn(usize, usize) -> Ordering + '_>, ArrowError> {
+    Ok(downcast_primitive_array! {
+        keys => Box::new(move |i, j| keys.value(i).compare(keys.value(j))),
+        DataType::Boolean => {
+            let keys = keys.as_boolean();
+            Box::new(move |i, j| keys.value(i).cmp(&keys.value(j)))
+        }
+        DataType::Utf8 => {
+            let keys = keys.as_string::<i32>();
+            Box::new(move |i, j| keys.value(i).cmp(keys.value(j)))
+        }
+        DataType::LargeUtf8 => {
+            let keys = keys.as_string::<i64>();
+            Box::new(move |i, j| keys.value(i).cmp(keys.value(j)))
+        }
+        DataType::Utf8View => {
+            let keys = keys.as_string_view();
+            Box::new(move |i, j| keys.value(i).cmp(keys.value(j)))
+        }
+        DataType::Binary => {
+            let keys = keys.as_binary::<i32>();
+            Box::new(move |i, j| keys.value(i).cmp(keys.value(j)))
+        }
+        DataType::LargeBinary => {
+            let keys = keys.as_binary::<i64>();
+            Box::new(move |i, j| keys.value(i).cmp(keys.value(j)))
+        }
+        DataType::BinaryView => {
+            let keys = keys.as_binary_view();
+            Box::new(move |i, j| keys.value(i).cmp(keys.value(j)))
+        }
+        DataType::FixedSizeBinary(_) => {
+            let keys = keys.as_fixed_size_binary();
+            Box::new(move |i, j| keys.value(i).cmp(keys.value(j)))
+        }
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "MapBuilder cannot compare keys of type {d}"
+            )))
+        }
+    })
+}
+
+/// Returns the values of `array` at `indices`
+fn take_entries(array: &dyn Array, indices: &[usize]) -> ArrayRef {
+    let data = array.to_data();
+    let mut mutable = MutableArrayData::new(vec![&data], false, indices.len());
+    for i in indices {
+        mutable.extend(0, *i, *i + 1);
+    }
+    make_array(mutable.freeze())
+}
+
 impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
     fn len(&self) -> usize {
         self.null_buffer_builder.len()
@@ -262,7 +504,8 @@ impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
 
 #[cfg(test)]
 mod tests {
-    use crate::builder::{make_builder, Int32Builder, StringBuilder};
+    use crate::builder::{make_builder, Float64Builder, Int32Builder, ListBuilder, StringBuilder};
+    use crate::types::Float64Type;
     use crate::{Int32Array, StringArray};
 
     use super::*;
@@ -377,4 +620,145 @@ mod tests {
             )
         );
     }
+
+    fn append_map(builder: &mut MapBuilder<StringBuilder, Int32Builder>, entries: &[(&str, i32)]) {
+        for (key, value) in entries {
+            builder.keys().append_value(key);
+            builder.values().append_value(*value);
+        }
+        builder.append(true).unwrap();
+    }
+
+    #[test]
+    fn test_map_builder_sort_keys() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new()).with_sort_keys(true);
+        append_map(&mut builder, &[("c", 1), ("a", 2), ("b", 3), ("a", 4)]);
+        builder.append(false).unwrap();
+        append_map(&mut builder, &[("z", 5)]);
+
+        let map = builder.try_finish().unwrap();
+        assert!(matches!(map.data_type(), DataType::Map(_, true)));
+        assert_eq!(map.value_offsets(), &[0, 4, 4, 5]);
+        assert_eq!(
+            map.keys().as_ref(),
+            &StringArray::from(vec!["a", "a", "b", "c", "z"])
+        );
+        assert_eq!(
+            map.values().as_ref(),
+            &Int32Array::from(vec![2, 4, 3, 1, 5])
+        );
+        assert!(map.is_null(1));
+    }
+
+    #[test]
+    fn test_map_builder_duplicate_keys() {
+        let entries = [("b", 1), ("a", 2), ("b", 3), ("c", 4), ("a", 5)];
+
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+            .with_duplicate_keys(DuplicateKeys::KeepFirst);
+        append_map(&mut builder, &entries);
+        append_map(&mut builder, &[("a", 6)]);
+        let map = builder.try_finish().unwrap();
+        assert!(matches!(map.data_type(), DataType::Map(_, false)));
+        assert_eq!(map.value_offsets(), &[0, 3, 4]);
+        assert_eq!(
+            map.keys().as_ref(),
+            &StringArray::from(vec!["b", "a", "c", "a"])
+        );
+        assert_eq!(map.values().as_ref(), &Int32Array::from(vec![1, 2, 4, 6]));
+
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+            .with_duplicate_keys(DuplicateKeys::KeepLast);
+        append_map(&mut builder, &entries);
+        let map = builder.try_finish().unwrap();
+        assert_eq!(map.keys().as_ref(), &StringArray::from(vec!["b", "c", "a"]));
+        assert_eq!(map.values().as_ref(), &Int32Array::from(vec![3, 4, 5]));
+
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+            .with_sort_keys(true)
+            .with_duplicate_keys(DuplicateKeys::KeepLast);
+        append_map(&mut builder, &entries);
+        let map = builder.try_finish().unwrap();
+        assert_eq!(map.keys().as_ref(), &StringArray::from(vec!["a", "b", "c"]));
+        assert_eq!(map.values().as_ref(), &Int32Array::from(vec![5, 3, 4]));
+
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+            .with_duplicate_keys(DuplicateKeys::Error);
+        append_map(&mut builder, &[("a", 1), ("b", 2)]);
+        append_map(&mut builder, &entries);
+        let err = builder.try_finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Map at index 1 has duplicate keys"
+        );
+
+        // The builder is reset after an error
+        append_map(&mut builder, &[("a", 1), ("b", 2)]);
+        let map = builder.try_finish().unwrap();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.keys().len(), 2);
+    }
+
+    #[test]
+    fn test_map_builder_keys_sorted() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new()).with_keys_sorted(true);
+        append_map(&mut builder, &[("a", 1), ("b", 2)]);
+        append_map(&mut builder, &[("a", 3), ("a", 4)]);
+        let map = builder.try_finish().unwrap();
+        assert!(matches!(map.data_type(), DataType::Map(_, true)));
+        assert_eq!(map.keys().len(), 4);
+
+        append_map(&mut builder, &[("a", 1)]);
+        append_map(&mut builder, &[("b", 2), ("a", 3)]);
+        let err = builder.try_finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Map at index 1 has unsorted keys"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Map at index 0 has unsorted keys")]
+    fn test_map_builder_keys_sorted_finish_panics() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new()).with_keys_sorted(true);
+        append_map(&mut builder, &[("b", 2), ("a", 3)]);
+        builder.finish();
+    }
+
+    #[test]
+    fn test_map_builder_sort_primitive_keys() {
+        let mut builder = MapBuilder::new(None, Float64Builder::new(), Int32Builder::new())
+            .with_sort_keys(true)
+            .with_duplicate_keys(DuplicateKeys::KeepFirst);
+        for (key, value) in [(2.5, 1), (-0.0, 2), (f64::NAN, 3), (0.0, 4), (2.5, 5)] {
+            builder.keys().append_value(key);
+            builder.values().append_value(value);
+        }
+        builder.append(true).unwrap();
+        let map = builder.finish();
+        let keys = map.keys().as_primitive::<Float64Type>();
+        assert_eq!(keys.len(), 4);
+        assert_eq!(&keys.values()[..3], &[-0.0, 0.0, 2.5]);
+        assert!(keys.value(3).is_nan());
+        assert_eq!(map.values().as_ref(), &Int32Array::from(vec![2, 4, 1, 3]));
+    }
+
+    #[test]
+    fn test_map_builder_unsupported_key_type() {
+        let key_builder = ListBuilder::new(Int32Builder::new());
+        let mut builder =
+            MapBuilder::new(None, key_builder, Int32Builder::new()).with_sort_keys(true);
+        builder.keys().values().append_value(1);
+        builder.keys().append(true);
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        let err = builder.try_finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: MapBuilder cannot compare keys of type List(Field { name: \"item\", data_type: Int32, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} })"
+        );
+    }
 }
@@ -22,6 +22,7 @@ use crate::{types::RunEndIndexType, ArrayRef, ArrowPrimitiveType, RunArray};
 use super::{ArrayBuilder, PrimitiveBuilder};
 
 use arrow_buffer::ArrowNativeType;
+use arrow_schema::DataType;
 
 /// Builder for [`RunArray`] of [`PrimitiveArray`](crate::array::PrimitiveArray)
 ///
@@ -110,6 +111,33 @@ where
     }
 }
 
+impl<R, V> PrimitiveRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: ArrowPrimitiveType,
+{
+    /// By default [`PrimitiveRunBuilder`] uses [`ArrowPrimitiveType::DATA_TYPE`] as the
+    /// data type of the values of the generated array.
+    ///
+    /// This method allows overriding the data type, to allow specifying timezones
+    /// for [`DataType::Timestamp`] or precision and scale for [`DataType::Decimal128`] and [`DataType::Decimal256`]
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `data_type` is not [PrimitiveArray::is_compatible]
+    ///
+    /// [`DataType::Timestamp`]: arrow_schema::DataType::Timestamp
+    /// [`DataType::Decimal128`]: arrow_schema::DataType::Decimal128
+    /// [`DataType::Decimal256`]: arrow_schema::DataType::Decimal256
+    /// [PrimitiveArray::is_compatible]: crate::PrimitiveArray::is_compatible
+    pub fn with_data_type(self, data_type: DataType) -> Self {
+        Self {
+            values_builder: self.values_builder.with_data_type(data_type),
+            ..self
+        }
+    }
+}
+
 impl<R, V> ArrayBuilder for PrimitiveRunBuilder<R, V>
 where
     R: RunEndIndexType,
@@ -154,17 +182,38 @@ where
 {
     /// Appends optional value to the logical array encoded by the RunArray.
     pub fn append_option(&mut self, value: Option<V::Native>) {
-        if self.current_run_end_index == 0 {
-            self.current_run_end_index = 1;
-            self.current_value = value;
+        self.append_run(value, 1)
+    }
+
+    /// Appends `run_length` repetitions of an optional value to the logical array
+    /// encoded by the RunArray, without comparing each repetition.
+    ///
+    /// A run equal to the preceding value extends it, and an empty run is ignored.
+    ///
+    /// ```
+    /// # use arrow_array::builder::PrimitiveRunBuilder;
+    /// # use arrow_array::types::{Int16Type, UInt32Type};
+    /// let mut builder = PrimitiveRunBuilder::<Int16Type, UInt32Type>::new();
+    /// builder.append_run(Some(1234), 3);
+    /// builder.append_run(None, 0);
+    /// builder.append_run(None, 2);
+    /// builder.extend([(Some(5678), 1), (Some(5678), 4)]);
+    /// let array = builder.finish();
+    ///
+    /// assert_eq!(array.run_ends().values(), &[3, 5, 10]);
+    /// ```
+    pub fn append_run(&mut self, value: Option<V::Native>, run_length: usize) {
+        if run_length == 0 {
             return;
         }
-        if self.current_value != value {
+        if self.current_run_end_index == 0 {
+            self.current_value = value;
+        } else if self.current_value != value {
             self.append_run_end();
             self.current_value = value;
         }
 
-        self.current_run_end_index += 1;
+        self.current_run_end_index += run_length;
     }
 
     /// Appends value to the logical array encoded by the run-ends array.
@@ -257,12 +306,24 @@ where
     }
 }
 
+impl<R, V> Extend<(Option<V::Native>, usize)> for PrimitiveRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: ArrowPrimitiveType,
+{
+    fn extend<T: IntoIterator<Item = (Option<V::Native>, usize)>>(&mut self, iter: T) {
+        for (value, run_length) in iter {
+            self.append_run(value, run_length);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::builder::PrimitiveRunBuilder;
     use crate::cast::AsArray;
     use crate::types::{Int16Type, UInt32Type};
-    use crate::{Array, UInt32Array};
+    use crate::{Array, Int16Array, UInt32Array};
 
     #[test]
     fn test_primitive_ree_array_builder() {
@@ -310,4 +371,36 @@ mod tests {
             &[1, 2, 5, 4, 6, 2]
         );
     }
+
+    #[test]
+    fn test_append_run() {
+        let mut builder = PrimitiveRunBuilder::<Int16Type, Int16Type>::new();
+        builder.append_value(1);
+        builder.append_run(Some(1), 2);
+        builder.append_run(Some(2), 0);
+        builder.append_run(None, 3);
+        builder.append_null();
+        builder.extend([(Some(3), 2), (Some(4), 1), (Some(4), 1)]);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 11);
+        assert_eq!(array.logical_null_count(), 4);
+        assert_eq!(array.run_ends().values(), &[3, 7, 9, 11]);
+        let values = array.values().as_primitive::<Int16Type>();
+        assert_eq!(
+            values,
+            &Int16Array::from(vec![Some(1), None, Some(3), Some(4)])
+        );
+
+        let runs: Vec<_> = array.downcast::<Int16Array>().unwrap().runs().collect();
+        assert_eq!(
+            runs,
+            vec![
+                (0..3, Some(1)),
+                (3..7, None),
+                (7..9, Some(3)),
+                (9..11, Some(4))
+            ]
+        );
+    }
 }
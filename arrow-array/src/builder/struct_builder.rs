@@ -16,10 +16,12 @@
 // under the License.
 
 use crate::builder::*;
-use crate::types::Int32Type;
-use crate::StructArray;
+use crate::cast::AsArray;
+use crate::types::*;
+use crate::{downcast_integer, downcast_primitive, downcast_run_end_index};
+use crate::{Array, OffsetSizeTrait, StructArray};
 use arrow_buffer::NullBufferBuilder;
-use arrow_schema::{DataType, Fields, IntervalUnit, SchemaBuilder, TimeUnit};
+use arrow_schema::{ArrowError, DataType, Fields, IntervalUnit, SchemaBuilder, TimeUnit};
 use std::sync::Arc;
 
 /// Builder for [`StructArray`]
@@ -168,6 +170,14 @@ impl ArrayBuilder for StructBuilder {
 ///
 /// See comments on [StructBuilder] for retrieving collection builders built by
 /// make_builder.
+///
+/// Nested types are supported recursively, with dictionaries and run-end encoded
+/// arrays of primitive, string and binary values.
+///
+/// # Panics
+///
+/// Panics if `datatype`, or any type nested within it, is not supported, such as
+/// [`DataType::Union`]
 pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilder> {
     use crate::builder::*;
     match datatype {
@@ -189,14 +199,22 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
         DataType::FixedSizeBinary(len) => {
             Box::new(FixedSizeBinaryBuilder::with_capacity(capacity, *len))
         }
+        DataType::Decimal32(p, s) => Box::new(
+            Decimal32Builder::with_capacity(capacity).with_data_type(DataType::Decimal32(*p, *s)),
+        ),
+        DataType::Decimal64(p, s) => Box::new(
+            Decimal64Builder::with_capacity(capacity).with_data_type(DataType::Decimal64(*p, *s)),
+        ),
         DataType::Decimal128(p, s) => Box::new(
             Decimal128Builder::with_capacity(capacity).with_data_type(DataType::Decimal128(*p, *s)),
         ),
         DataType::Decimal256(p, s) => Box::new(
             Decimal256Builder::with_capacity(capacity).with_data_type(DataType::Decimal256(*p, *s)),
         ),
+        DataType::BinaryView => Box::new(BinaryViewBuilder::with_capacity(capacity)),
         DataType::Utf8 => Box::new(StringBuilder::with_capacity(capacity, 1024)),
         DataType::LargeUtf8 => Box::new(LargeStringBuilder::with_capacity(capacity, 1024)),
+        DataType::Utf8View => Box::new(StringViewBuilder::with_capacity(capacity)),
         DataType::Date32 => Box::new(Date32Builder::with_capacity(capacity)),
         DataType::Date64 => Box::new(Date64Builder::with_capacity(capacity)),
         DataType::Time32(TimeUnit::Second) => {
@@ -268,7 +286,7 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                     .with_field(field.clone()),
             )
         }
-        DataType::Map(field, _) => match field.data_type() {
+        DataType::Map(field, keys_sorted) => match field.data_type() {
             DataType::Struct(fields) => {
                 let map_field_names = MapFieldNames {
                     key: fields[0].name().clone(),
@@ -284,39 +302,301 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                         value_builder,
                         capacity,
                     )
-                    .with_values_field(fields[1].clone()),
+                    .with_values_field(fields[1].clone())
+                    .with_keys_sorted(*keys_sorted),
                 )
             }
             t => panic!("The field of Map data type {t:?} should has a child Struct field"),
         },
         DataType::Struct(fields) => Box::new(StructBuilder::from_fields(fields.clone(), capacity)),
-        DataType::Dictionary(key_type, value_type) if **key_type == DataType::Int32 => {
-            match &**value_type {
-                DataType::Utf8 => {
-                    let dict_builder: StringDictionaryBuilder<Int32Type> =
-                        StringDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                    Box::new(dict_builder)
-                }
-                DataType::LargeUtf8 => {
-                    let dict_builder: LargeStringDictionaryBuilder<Int32Type> =
-                        LargeStringDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                    Box::new(dict_builder)
+        DataType::Dictionary(key_type, value_type) => {
+            macro_rules! dictionary_builder_helper {
+                ($k:ty, $value_type:expr, $capacity:expr) => {
+                    make_dictionary_builder::<$k>($value_type, $capacity)
+                };
+            }
+            downcast_integer! {
+                key_type.as_ref() => (dictionary_builder_helper, value_type, capacity),
+                _ => panic!("Data type {datatype:?} is not currently supported"),
+            }
+        }
+        DataType::RunEndEncoded(run_ends, values) => {
+            macro_rules! run_builder_helper {
+                ($r:ty, $value_type:expr, $capacity:expr) => {
+                    make_run_builder::<$r>($value_type, $capacity)
+                };
+            }
+            let value_type = values.data_type();
+            downcast_run_end_index! {
+                run_ends.data_type() => (run_builder_helper, value_type, capacity),
+                _ => panic!("Data type {datatype:?} is not currently supported"),
+            }
+        }
+        t => panic!("Data type {t:?} is not currently supported"),
+    }
+}
+
+/// Returns a builder for a [`DataType::Dictionary`] with keys of type `K`
+fn make_dictionary_builder<K: ArrowDictionaryKeyType>(
+    value_type: &DataType,
+    capacity: usize,
+) -> Box<dyn ArrayBuilder> {
+    macro_rules! primitive_dictionary_helper {
+        ($v:ty, $value_type:expr, $capacity:expr) => {
+            Box::new(
+                PrimitiveDictionaryBuilder::<K, $v>::new_from_empty_builders(
+                    PrimitiveBuilder::with_capacity($capacity),
+                    PrimitiveBuilder::with_capacity(256).with_data_type($value_type.clone()),
+                ),
+            )
+        };
+    }
+    downcast_primitive! {
+        value_type => (primitive_dictionary_helper, value_type, capacity),
+        DataType::Utf8 => Box::new(StringDictionaryBuilder::<K>::with_capacity(capacity, 256, 1024)),
+        DataType::LargeUtf8 => Box::new(LargeStringDictionaryBuilder::<K>::with_capacity(
+            capacity, 256, 1024,
+        )),
+        DataType::Binary => Box::new(BinaryDictionaryBuilder::<K>::with_capacity(capacity, 256, 1024)),
+        DataType::LargeBinary => Box::new(LargeBinaryDictionaryBuilder::<K>::with_capacity(
+            capacity, 256, 1024,
+        )),
+        t => panic!("Unsupported dictionary value type {t:?} is not currently supported"),
+    }
+}
+
+/// Returns a builder for a [`DataType::RunEndEncoded`] with run ends of type `R`
+fn make_run_builder<R: RunEndIndexType>(
+    value_type: &DataType,
+    capacity: usize,
+) -> Box<dyn ArrayBuilder> {
+    macro_rules! primitive_run_helper {
+        ($v:ty, $value_type:expr, $capacity:expr) => {
+            Box::new(
+                PrimitiveRunBuilder::<R, $v>::with_capacity($capacity)
+                    .with_data_type($value_type.clone()),
+            )
+        };
+    }
+    downcast_primitive! {
+        value_type => (primitive_run_helper, value_type, capacity),
+        DataType::Utf8 => Box::new(GenericByteRunBuilder::<R, Utf8Type>::with_capacity(capacity, 1024)),
+        DataType::LargeUtf8 => Box::new(GenericByteRunBuilder::<R, LargeUtf8Type>::with_capacity(
+            capacity, 1024,
+        )),
+        DataType::Binary => Box::new(GenericByteRunBuilder::<R, BinaryType>::with_capacity(
+            capacity, 1024,
+        )),
+        DataType::LargeBinary => Box::new(
+            GenericByteRunBuilder::<R, LargeBinaryType>::with_capacity(capacity, 1024),
+        ),
+        t => panic!("Unsupported run-end encoded value type {t:?} is not currently supported"),
+    }
+}
+
+/// Returns `builder` as a `T`, or an error if it is not a builder for `data_type` of type `T`
+fn downcast_builder<'a, T: ArrayBuilder>(
+    builder: &'a mut dyn ArrayBuilder,
+    data_type: &DataType,
+) -> Result<&'a mut T, ArrowError> {
+    builder.as_any_mut().downcast_mut::<T>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Cannot append a value of type {data_type} to a builder of a different type"
+        ))
+    })
+}
+
+/// Appends the value at `index` of `array` to `builder`, which must be of the type
+/// returned by [`make_builder`] for the data type of `array`
+fn append_array_value(
+    builder: &mut dyn ArrayBuilder,
+    array: &dyn Array,
+    index: usize,
+) -> Result<(), ArrowError> {
+    let data_type = array.data_type();
+    let is_valid = array.is_valid(index);
+
+    macro_rules! primitive_helper {
+        ($t:ty, $builder:expr, $array:expr, $index:expr) => {{
+            let builder = downcast_builder::<PrimitiveBuilder<$t>>($builder, data_type)?;
+            match is_valid {
+                true => builder.append_value($array.as_primitive::<$t>().value($index)),
+                false => builder.append_null(),
+            }
+        }};
+    }
+
+    downcast_primitive! {
+        data_type => (primitive_helper, builder, array, index),
+        DataType::Null => downcast_builder::<NullBuilder>(builder, data_type)?.append_null(),
+        DataType::Boolean => {
+            let builder = downcast_builder::<BooleanBuilder>(builder, data_type)?;
+            builder.append_option(is_valid.then(|| array.as_boolean().value(index)))
+        }
+        DataType::Utf8 => append_bytes_value::<Utf8Type>(builder, array, index)?,
+        DataType::LargeUtf8 => append_bytes_value::<LargeUtf8Type>(builder, array, index)?,
+        DataType::Binary => append_bytes_value::<BinaryType>(builder, array, index)?,
+        DataType::LargeBinary => append_bytes_value::<LargeBinaryType>(builder, array, index)?,
+        DataType::Utf8View => append_byte_view_value::<StringViewType>(builder, array, index)?,
+        DataType::BinaryView => append_byte_view_value::<BinaryViewType>(builder, array, index)?,
+        DataType::FixedSizeBinary(_) => {
+            let builder = downcast_builder::<FixedSizeBinaryBuilder>(builder, data_type)?;
+            match is_valid {
+                true => builder.append_value(array.as_fixed_size_binary().value(index))?,
+                false => builder.append_null(),
+            }
+        }
+        DataType::List(_) => append_list_value::<i32>(builder, array, index)?,
+        DataType::LargeList(_) => append_list_value::<i64>(builder, array, index)?,
+        DataType::FixedSizeList(_, size) => {
+            let builder =
+                downcast_builder::<FixedSizeListBuilder<Box<dyn ArrayBuilder>>>(builder, data_type)?;
+            // The values of a null slot are appended too, as they must still be present
+            let list = array.as_fixed_size_list();
+            let offset = list.value_offset(index) as usize;
+            for i in offset..offset + *size as usize {
+                append_array_value(builder.values(), list.values().as_ref(), i)?;
+            }
+            builder.append(is_valid)
+        }
+        DataType::Map(_, _) => {
+            let builder = downcast_builder::<MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>>(
+                builder, data_type,
+            )?;
+            if is_valid {
+                let map = array.as_map();
+                let offsets = map.value_offsets();
+                for i in offsets[index] as usize..offsets[index + 1] as usize {
+                    append_array_value(builder.keys(), map.keys().as_ref(), i)?;
+                    append_array_value(builder.values(), map.values().as_ref(), i)?;
                 }
-                DataType::Binary => {
-                    let dict_builder: BinaryDictionaryBuilder<Int32Type> =
-                        BinaryDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                    Box::new(dict_builder)
+            }
+            builder.append(is_valid)?
+        }
+        DataType::Struct(_) => {
+            let builder = downcast_builder::<StructBuilder>(builder, data_type)?;
+            // The values of a null slot are appended too, as they must still be present
+            let columns = array.as_struct().columns();
+            for (field_builder, column) in builder.field_builders.iter_mut().zip(columns) {
+                append_array_value(field_builder.as_mut(), column.as_ref(), index)?;
+            }
+            builder.append(is_valid)
+        }
+        DataType::Dictionary(key_type, _) => {
+            macro_rules! dictionary_helper {
+                ($k:ty, $builder:expr, $array:expr, $index:expr) => {
+                    append_dictionary_value::<$k>($builder, $array, $index)?
+                };
+            }
+            downcast_integer! {
+                key_type.as_ref() => (dictionary_helper, builder, array, index),
+                _ => unreachable!("Dictionary keys are integers"),
+            }
+        }
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Appending values of type {d} to a StructBuilder is not supported"
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn append_bytes_value<T: ByteArrayType>(
+    builder: &mut dyn ArrayBuilder,
+    array: &dyn Array,
+    index: usize,
+) -> Result<(), ArrowError> {
+    let builder = downcast_builder::<GenericByteBuilder<T>>(builder, array.data_type())?;
+    match array.is_valid(index) {
+        true => builder.append_value(array.as_bytes::<T>().value(index)),
+        false => builder.append_null(),
+    }
+    Ok(())
+}
+
+fn append_byte_view_value<T: ByteViewType>(
+    builder: &mut dyn ArrayBuilder,
+    array: &dyn Array,
+    index: usize,
+) -> Result<(), ArrowError> {
+    let builder = downcast_builder::<GenericByteViewBuilder<T>>(builder, array.data_type())?;
+    match array.is_valid(index) {
+        true => builder.append_value(array.as_byte_view::<T>().value(index)),
+        false => builder.append_null(),
+    }
+    Ok(())
+}
+
+fn append_list_value<O: OffsetSizeTrait>(
+    builder: &mut dyn ArrayBuilder,
+    array: &dyn Array,
+    index: usize,
+) -> Result<(), ArrowError> {
+    let builder = downcast_builder::<GenericListBuilder<O, Box<dyn ArrayBuilder>>>(
+        builder,
+        array.data_type(),
+    )?;
+    if array.is_valid(index) {
+        let list = array.as_list::<O>();
+        let offsets = list.value_offsets();
+        for i in offsets[index].as_usize()..offsets[index + 1].as_usize() {
+            append_array_value(builder.values(), list.values().as_ref(), i)?;
+        }
+    }
+    builder.append(array.is_valid(index));
+    Ok(())
+}
+
+fn append_dictionary_value<K: ArrowDictionaryKeyType>(
+    builder: &mut dyn ArrayBuilder,
+    array: &dyn Array,
+    index: usize,
+) -> Result<(), ArrowError> {
+    let data_type = array.data_type();
+    let array = array.as_dictionary::<K>();
+    let values = array.values();
+    // A null key or a null value are both appended as a null
+    let key = array.key(index).filter(|key| values.is_valid(*key));
+
+    macro_rules! primitive_dictionary_helper {
+        ($v:ty, $builder:expr, $values:expr, $key:expr) => {{
+            let builder =
+                downcast_builder::<PrimitiveDictionaryBuilder<K, $v>>($builder, data_type)?;
+            match $key {
+                Some(key) => {
+                    builder.append($values.as_primitive::<$v>().value(key))?;
                 }
-                DataType::LargeBinary => {
-                    let dict_builder: LargeBinaryDictionaryBuilder<Int32Type> =
-                        LargeBinaryDictionaryBuilder::with_capacity(capacity, 256, 1024);
-                    Box::new(dict_builder)
+                None => builder.append_null(),
+            }
+        }};
+    }
+    macro_rules! bytes_dictionary_helper {
+        ($v:ty) => {{
+            let builder =
+                downcast_builder::<GenericByteDictionaryBuilder<K, $v>>(builder, data_type)?;
+            match key {
+                Some(key) => {
+                    builder.append(values.as_bytes::<$v>().value(key))?;
                 }
-                t => panic!("Unsupported dictionary value type {t:?} is not currently supported"),
+                None => builder.append_null(),
             }
+        }};
+    }
+
+    downcast_primitive! {
+        values.data_type() => (primitive_dictionary_helper, builder, values, key),
+        DataType::Utf8 => bytes_dictionary_helper!(Utf8Type),
+        DataType::LargeUtf8 => bytes_dictionary_helper!(LargeUtf8Type),
+        DataType::Binary => bytes_dictionary_helper!(BinaryType),
+        DataType::LargeBinary => bytes_dictionary_helper!(LargeBinaryType),
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Appending values of type {d} to a StructBuilder is not supported"
+            )))
         }
-        t => panic!("Data type {t:?} is not currently supported"),
     }
+    Ok(())
 }
 
 impl StructBuilder {
@@ -346,11 +626,107 @@ impl StructBuilder {
         self.field_builders[i].as_any_mut().downcast_mut::<T>()
     }
 
+    /// Returns a mutable reference to the child field builder of the field named `name`.
+    /// Result will be `None` if there is no field named `name`, or the input type `T`
+    /// provided doesn't match the actual field builder's type.
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, StringBuilder, StructBuilder};
+    /// # use arrow_schema::{DataType, Field};
+    /// let fields = vec![
+    ///     Field::new("id", DataType::Int32, false),
+    ///     Field::new("name", DataType::Utf8, true),
+    /// ];
+    /// let mut builder = StructBuilder::from_fields(fields, 0);
+    /// builder.field_builder_by_name::<StringBuilder>("name").unwrap().append_value("a");
+    /// builder.field_builder_by_name::<Int32Builder>("id").unwrap().append_value(1);
+    /// builder.append(true);
+    ///
+    /// assert!(builder.field_builder_by_name::<Int32Builder>("age").is_none());
+    /// assert!(builder.field_builder_by_name::<StringBuilder>("id").is_none());
+    /// ```
+    pub fn field_builder_by_name<T: ArrayBuilder>(&mut self, name: &str) -> Option<&mut T> {
+        let (idx, _) = self.fields.find(name)?;
+        self.field_builder(idx)
+    }
+
+    /// Returns an iterator over the name and builder of each child field
+    pub fn field_builders_mut(&mut self) -> impl Iterator<Item = (&str, &mut dyn ArrayBuilder)> {
+        self.fields
+            .iter()
+            .zip(self.field_builders.iter_mut())
+            .map(|(field, builder)| (field.name().as_str(), builder.as_mut()))
+    }
+
     /// Returns the number of fields for the struct this builder is building.
     pub fn num_fields(&self) -> usize {
         self.field_builders.len()
     }
 
+    /// Appends a valid struct value, appending the value at `row` of each of `columns`
+    /// to the builder of the corresponding field
+    ///
+    /// This supports the child field builders created by [`make_builder`] and
+    /// [`StructBuilder::from_fields`], for all types other than unions and run-end encoded
+    /// types. Returns an error if the number or types of `columns` do not match the
+    /// fields of this builder, or a child field builder is of an unexpected type, in which
+    /// case the child field builders may have been partially appended to.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::builder::StructBuilder;
+    /// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+    /// # use arrow_schema::{DataType, Field};
+    /// let fields = vec![
+    ///     Field::new("id", DataType::Int32, false),
+    ///     Field::new("name", DataType::Utf8, true),
+    /// ];
+    /// let mut builder = StructBuilder::from_fields(fields, 0);
+    ///
+    /// let columns: Vec<ArrayRef> = vec![
+    ///     Arc::new(Int32Array::from(vec![1, 2])),
+    ///     Arc::new(StringArray::from(vec![Some("a"), None])),
+    /// ];
+    /// builder.append_row(&columns, 1).unwrap();
+    /// builder.append_row(&columns, 0).unwrap();
+    ///
+    /// let array = builder.finish();
+    /// assert_eq!(array.column(0).as_ref(), &Int32Array::from(vec![2, 1]));
+    /// assert_eq!(array.column(1).as_ref(), &StringArray::from(vec![None, Some("a")]));
+    /// ```
+    pub fn append_row(&mut self, columns: &[ArrayRef], row: usize) -> Result<(), ArrowError> {
+        if columns.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "StructBuilder expected {} columns got {}",
+                self.fields.len(),
+                columns.len()
+            )));
+        }
+        for (field, column) in self.fields.iter().zip(columns) {
+            if field.data_type() != column.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "StructBuilder expected data type {} got {} for field \"{}\"",
+                    field.data_type(),
+                    column.data_type(),
+                    field.name()
+                )));
+            }
+            if row >= column.len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Row {row} out of bounds for column of length {} for field \"{}\"",
+                    column.len(),
+                    field.name()
+                )));
+            }
+        }
+
+        for (builder, column) in self.field_builders.iter_mut().zip(columns) {
+            append_array_value(builder.as_mut(), column.as_ref(), row)?;
+        }
+        self.append(true);
+        Ok(())
+    }
+
     /// Appends an element (either null or non-null) to the struct. The actual elements
     /// should be appended for each child sub-array in a consistent way.
     #[inline]
@@ -436,6 +812,11 @@ mod tests {
     use arrow_schema::Field;
 
     use crate::array::Array;
+    use crate::cast::AsArray;
+    use crate::{
+        make_array, DictionaryArray, Float64Array, Int32Array, StringArray, StringViewArray,
+    };
+    use arrow_data::transform::MutableArrayData;
 
     #[test]
     fn test_struct_array_builder() {
@@ -714,13 +1095,13 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Data type Dictionary(Int16, Utf8) is not currently supported")]
+    #[should_panic(expected = "Data type Dictionary(Float32, Utf8) is not currently supported")]
     fn test_struct_array_builder_from_schema_unsupported_type() {
         let fields = vec![
             Field::new("f1", DataType::Int16, false),
             Field::new(
                 "f2",
-                DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8)),
+                DataType::Dictionary(Box::new(DataType::Float32), Box::new(DataType::Utf8)),
                 false,
             ),
         ];
@@ -729,17 +1110,308 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Unsupported dictionary value type Int32 is not currently supported")]
+    #[should_panic(
+        expected = "Unsupported dictionary value type Boolean is not currently supported"
+    )]
     fn test_struct_array_builder_from_dict_with_unsupported_value_type() {
         let fields = vec![Field::new(
             "f1",
-            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Int32)),
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Boolean)),
             false,
         )];
 
         let _ = StructBuilder::from_fields(fields, 5);
     }
 
+    #[test]
+    fn test_struct_array_builder_field_builder_by_name() {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let mut builder = StructBuilder::from_fields(fields, 2);
+        builder
+            .field_builder_by_name::<StringBuilder>("b")
+            .unwrap()
+            .append_value("x");
+        builder
+            .field_builder_by_name::<Int32Builder>("a")
+            .unwrap()
+            .append_value(1);
+        builder.append(true);
+        assert!(builder.field_builder_by_name::<Int32Builder>("c").is_none());
+        assert!(builder.field_builder_by_name::<Int32Builder>("b").is_none());
+
+        let names: Vec<_> = builder
+            .field_builders_mut()
+            .map(|(name, field_builder)| {
+                assert_eq!(field_builder.len(), 1);
+                name.to_string()
+            })
+            .collect();
+        assert_eq!(names, vec!["a", "b"]);
+
+        let array = builder.finish();
+        assert_eq!(array.column(0).as_primitive::<Int32Type>().value(0), 1);
+        assert_eq!(array.column(1).as_string::<i32>().value(0), "x");
+    }
+
+    #[test]
+    fn test_struct_array_builder_append_row() {
+        let inner = Fields::from(vec![
+            Field::new("x", DataType::Float64, true),
+            Field::new("y", DataType::Utf8View, true),
+        ]);
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::new_list(DataType::Utf8, true), true),
+            Field::new("c", DataType::Struct(inner.clone()), true),
+            Field::new(
+                "d",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+            ),
+            Field::new(
+                "e",
+                DataType::new_fixed_size_list(DataType::Boolean, 2, true),
+                true,
+            ),
+        ]);
+
+        let mut list = ListBuilder::new(StringBuilder::new());
+        list.append_value([Some("p"), None]);
+        list.append_null();
+        list.append_value([Some("q")]);
+        let mut fixed = FixedSizeListBuilder::new(BooleanBuilder::new(), 2);
+        for (values, is_valid) in [
+            ([true, false], true),
+            ([false, false], false),
+            ([true, true], true),
+        ] {
+            fixed.values().append_slice(&values);
+            fixed.append(is_valid);
+        }
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(list.finish()),
+            Arc::new(StructArray::new(
+                inner.clone(),
+                vec![
+                    Arc::new(Float64Array::from(vec![1.5, 2.5, 3.5])),
+                    Arc::new(StringViewArray::from(vec![
+                        Some("a string longer than 12 bytes"),
+                        Some("b"),
+                        None,
+                    ])),
+                ],
+                Some(vec![true, false, true].into()),
+            )),
+            Arc::new(DictionaryArray::<Int8Type>::from_iter([
+                Some("u"),
+                Some("v"),
+                None,
+            ])),
+            Arc::new(fixed.finish()),
+        ];
+
+        let mut builder = StructBuilder::from_fields(fields.clone(), 3);
+        let rows = [2, 0, 1];
+        for row in rows {
+            builder.append_row(&columns, row).unwrap();
+        }
+        let array = builder.finish();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 0);
+
+        for (actual, column) in array.columns().iter().zip(&columns) {
+            let data = column.to_data();
+            let mut expected = MutableArrayData::new(vec![&data], false, rows.len());
+            for row in rows {
+                expected.extend(0, row, row + 1);
+            }
+            assert_eq!(actual.as_ref(), &make_array(expected.freeze()));
+        }
+    }
+
+    #[test]
+    fn test_struct_array_builder_append_row_errors() {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let mut builder = StructBuilder::from_fields(fields, 2);
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x"]));
+
+        let err = builder.append_row(std::slice::from_ref(&a), 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: StructBuilder expected 2 columns got 1"
+        );
+        let err = builder.append_row(&[b.clone(), a.clone()], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: StructBuilder expected data type Int32 got Utf8 for field \"a\""
+        );
+        let err = builder.append_row(&[a.clone(), b.clone()], 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Row 1 out of bounds for column of length 1 for field \"a\""
+        );
+        assert_eq!(builder.len(), 0);
+
+        // A field builder not created by make_builder
+        let fields = vec![Field::new("a", DataType::Int32, true)];
+        let mut builder = StructBuilder::new(fields, vec![Box::new(Int64Builder::new())]);
+        let err = builder.append_row(&[a], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append a value of type Int32 to a builder of a different type"
+        );
+    }
+
+    #[test]
+    fn test_make_builder_nested_types() {
+        let struct_type = DataType::Struct(Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8View, true),
+        ]));
+        let list_type = DataType::new_list(DataType::Decimal128(10, 2), true);
+        let map_type = |keys_sorted, value_type| {
+            let entries = Fields::from(vec![
+                Field::new("keys", DataType::Utf8, false),
+                Field::new("values", value_type, true),
+            ]);
+            DataType::Map(
+                Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+                keys_sorted,
+            )
+        };
+        let ree_type = |value_type| {
+            DataType::RunEndEncoded(
+                Arc::new(Field::new("run_ends", DataType::Int16, false)),
+                Arc::new(Field::new("values", value_type, true)),
+            )
+        };
+        let dictionary_type =
+            |key_type, value_type| DataType::Dictionary(Box::new(key_type), Box::new(value_type));
+
+        let data_types = [
+            DataType::Utf8View,
+            DataType::BinaryView,
+            DataType::new_fixed_size_list(struct_type.clone(), 2, true),
+            DataType::new_fixed_size_list(list_type.clone(), 3, false),
+            DataType::new_fixed_size_list(DataType::Int32, 0, true),
+            DataType::new_large_list(map_type(false, list_type.clone()), true),
+            map_type(true, struct_type.clone()),
+            map_type(
+                false,
+                DataType::new_fixed_size_list(DataType::Utf8, 2, true),
+            ),
+            dictionary_type(DataType::Int8, DataType::Utf8),
+            dictionary_type(DataType::UInt64, DataType::LargeBinary),
+            dictionary_type(DataType::Int16, DataType::Decimal128(5, 1)),
+            dictionary_type(
+                DataType::Int32,
+                DataType::Timestamp(TimeUnit::Second, Some("+01:00".into())),
+            ),
+            ree_type(DataType::Utf8),
+            ree_type(DataType::Float64),
+            ree_type(DataType::Timestamp(
+                TimeUnit::Nanosecond,
+                Some("UTC".into()),
+            )),
+            DataType::Struct(Fields::from(vec![
+                Field::new("s", struct_type, false),
+                Field::new("r", ree_type(DataType::Binary), true),
+                Field::new(
+                    "d",
+                    dictionary_type(DataType::UInt8, DataType::Float16),
+                    true,
+                ),
+            ])),
+        ];
+
+        for data_type in data_types {
+            let mut builder = make_builder(&data_type, 3);
+            assert_eq!(builder.finish().data_type(), &data_type);
+            assert_eq!(builder.finish_cloned().data_type(), &data_type);
+        }
+    }
+
+    #[test]
+    fn test_make_builder_fixed_size_list_of_struct() {
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let data_type = DataType::new_fixed_size_list(DataType::Struct(fields), 2, true);
+        let mut builder = make_builder(&data_type, 2);
+        let builder = builder
+            .as_any_mut()
+            .downcast_mut::<FixedSizeListBuilder<Box<dyn ArrayBuilder>>>()
+            .unwrap();
+
+        for (a, b) in [(1, Some("x")), (2, None)] {
+            let values = builder
+                .values()
+                .as_any_mut()
+                .downcast_mut::<StructBuilder>()
+                .unwrap();
+            values
+                .field_builder::<Int32Builder>(0)
+                .unwrap()
+                .append_value(a);
+            values
+                .field_builder::<StringBuilder>(1)
+                .unwrap()
+                .append_option(b);
+            values.append(true);
+        }
+        builder.append(true);
+
+        let array = builder.finish();
+        assert_eq!(array.data_type(), &data_type);
+        assert_eq!(array.len(), 1);
+        let value = array.value(0);
+        let value = value.as_struct();
+        assert_eq!(
+            value.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 2]
+        );
+        assert!(value.column(1).is_null(1));
+    }
+
+    #[test]
+    fn test_make_builder_run_end_encoded() {
+        let data_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        let mut builder = make_builder(&data_type, 4);
+        let builder = builder
+            .as_any_mut()
+            .downcast_mut::<StringRunBuilder<Int32Type>>()
+            .unwrap();
+        builder.extend([Some("a"), Some("a"), None, Some("b")]);
+        let array = builder.finish();
+        assert_eq!(array.run_ends().values(), &[2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported run-end encoded value type List(")]
+    fn test_make_builder_unsupported_run_end_encoded_value_type() {
+        let data_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new(
+                "values",
+                DataType::new_list(DataType::Int32, true),
+                true,
+            )),
+        );
+        make_builder(&data_type, 4);
+    }
+
     #[test]
     fn test_struct_array_builder_field_builder_type_mismatch() {
         let int_builder = Int32Builder::with_capacity(10);
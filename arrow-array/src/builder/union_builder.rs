@@ -16,83 +16,65 @@
 // under the License.
 
 use crate::builder::buffer_builder::{Int32BufferBuilder, Int8BufferBuilder};
-use crate::builder::BufferBuilder;
-use crate::{make_array, ArrowPrimitiveType, UnionArray};
-use arrow_buffer::NullBufferBuilder;
-use arrow_buffer::{ArrowNativeType, Buffer};
-use arrow_data::ArrayDataBuilder;
+use crate::builder::{ArrayBuilder, GenericByteBuilder, PrimitiveBuilder};
+use crate::types::ByteArrayType;
+use crate::{ArrowPrimitiveType, UnionArray};
 use arrow_schema::{ArrowError, DataType, Field};
-use std::any::Any;
 use std::collections::BTreeMap;
+use std::fmt::Debug;
 use std::sync::Arc;
 
-/// `FieldData` is a helper struct to track the state of the fields in the `UnionBuilder`.
-#[derive(Debug)]
-struct FieldData {
-    /// The type id for this field
-    type_id: i8,
-    /// The Arrow data type represented in the `values_buffer`, which is untyped
-    data_type: DataType,
-    /// A buffer containing the values for this field in raw bytes
-    values_buffer: Box<dyn FieldDataValues>,
-    ///  The number of array slots represented by the buffer
-    slots: usize,
-    /// A builder for the null bitmap
-    null_buffer_builder: NullBufferBuilder,
-}
-
-/// A type-erased [`BufferBuilder`] used by [`FieldData`]
-trait FieldDataValues: std::fmt::Debug {
-    fn as_mut_any(&mut self) -> &mut dyn Any;
-
+/// A type-erased builder of a child of a [`UnionBuilder`]
+trait ChildBuilder: ArrayBuilder {
     fn append_null(&mut self);
-
-    fn finish(&mut self) -> Buffer;
 }
 
-impl<T: ArrowNativeType> FieldDataValues for BufferBuilder<T> {
-    fn as_mut_any(&mut self) -> &mut dyn Any {
-        self
+impl<T: ArrowPrimitiveType> ChildBuilder for PrimitiveBuilder<T> {
+    fn append_null(&mut self) {
+        self.append_null()
     }
+}
 
+impl<T: ByteArrayType> ChildBuilder for GenericByteBuilder<T> {
     fn append_null(&mut self) {
-        self.advance(1)
+        self.append_null()
     }
+}
 
-    fn finish(&mut self) -> Buffer {
-        self.finish()
-    }
+/// `FieldData` is a helper struct to track the state of the fields in the `UnionBuilder`.
+struct FieldData {
+    /// The type id for this field
+    type_id: i8,
+    /// The Arrow data type of this field
+    data_type: DataType,
+    /// The builder of the values of this field
+    builder: Box<dyn ChildBuilder>,
 }
 
-impl FieldData {
-    /// Creates a new `FieldData`.
-    fn new<T: ArrowPrimitiveType>(type_id: i8, data_type: DataType, capacity: usize) -> Self {
-        Self {
-            type_id,
-            data_type,
-            slots: 0,
-            values_buffer: Box::new(BufferBuilder::<T::Native>::new(capacity)),
-            null_buffer_builder: NullBufferBuilder::new(capacity),
-        }
+impl Debug for FieldData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldData")
+            .field("type_id", &self.type_id)
+            .field("data_type", &self.data_type)
+            .field("len", &self.builder.len())
+            .finish()
     }
+}
 
-    /// Appends a single value to this `FieldData`'s `values_buffer`.
-    fn append_value<T: ArrowPrimitiveType>(&mut self, v: T::Native) {
-        self.values_buffer
-            .as_mut_any()
-            .downcast_mut::<BufferBuilder<T::Native>>()
-            .expect("Tried to append unexpected type")
-            .append(v);
-
-        self.null_buffer_builder.append(true);
-        self.slots += 1;
+impl FieldData {
+    /// Appends a single value to this `FieldData`'s builder.
+    fn append_value<B: ChildBuilder>(&mut self, append: impl FnOnce(&mut B)) {
+        let builder = self
+            .builder
+            .as_any_mut()
+            .downcast_mut::<B>()
+            .expect("Tried to append unexpected type");
+        append(builder)
     }
 
     /// Appends a null to this `FieldData`.
     fn append_null(&mut self) {
-        self.values_buffer.append_null();
-        self.null_buffer_builder.append(false);
-        self.slots += 1;
+        self.builder.append_null()
     }
 }
 
@@ -184,6 +166,23 @@ impl UnionBuilder {
         }
     }
 
+    /// Returns the number of slots in this builder
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this builder has no slots
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the type id of the field (child) named `type_name`, if any
+    ///
+    /// Type ids are assigned in the order fields are first appended to, starting at 0
+    pub fn type_id(&self, type_name: &str) -> Option<i8> {
+        self.fields.get(type_name).map(|f| f.type_id)
+    }
+
     /// Appends a null to this builder, encoding the null in the array
     /// of the `type_name` child / field.
     ///
@@ -199,6 +198,39 @@ impl UnionBuilder {
         self.append_option::<T>(type_name, None)
     }
 
+    /// Appends a null to this builder, encoding the null in the array of the existing
+    /// child / field with `type_id`
+    ///
+    /// Unlike [`Self::append_null`], this does not need the type of the child, but
+    /// returns an error if no child with `type_id` has been appended to yet
+    ///
+    /// ```
+    /// # use arrow_array::builder::UnionBuilder;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_array::Array;
+    /// let mut builder = UnionBuilder::new_sparse();
+    /// builder.append::<Int32Type>("a", 1).unwrap();
+    /// let type_id = builder.type_id("a").unwrap();
+    /// builder.append_null_type_id(type_id).unwrap();
+    /// assert!(builder.append_null_type_id(1).is_err());
+    ///
+    /// let union = builder.build().unwrap();
+    /// assert_eq!(union.logical_nulls().unwrap().null_count(), 1);
+    /// ```
+    pub fn append_null_type_id(&mut self, type_id: i8) -> Result<(), ArrowError> {
+        let type_name = self
+            .fields
+            .iter()
+            .find(|(_, f)| f.type_id == type_id)
+            .map(|(name, _)| name.clone())
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!("No field with type id {type_id}"))
+            })?;
+        let field_data = self.fields.remove(&type_name).unwrap();
+        self.append_to(type_name, field_data, FieldData::append_null);
+        Ok(())
+    }
+
     /// Appends a value to this builder.
     #[inline]
     pub fn append<T: ArrowPrimitiveType>(
@@ -209,51 +241,127 @@ impl UnionBuilder {
         self.append_option::<T>(type_name, Some(v))
     }
 
+    /// Appends a byte array value, such as a string, to this builder
+    ///
+    /// ```
+    /// # use arrow_array::builder::UnionBuilder;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::{Int32Type, Utf8Type};
+    /// # use arrow_array::Array;
+    /// let mut builder = UnionBuilder::new_dense();
+    /// builder.append::<Int32Type>("int", 1).unwrap();
+    /// builder.append_bytes::<Utf8Type>("str", "hello").unwrap();
+    /// builder.append_bytes_null::<Utf8Type>("str").unwrap();
+    /// let union = builder.build().unwrap();
+    ///
+    /// assert_eq!(union.type_id(1), 1);
+    /// assert_eq!(union.value(1).as_string::<i32>().value(0), "hello");
+    /// assert!(union.value(2).is_null(0));
+    /// ```
+    pub fn append_bytes<T: ByteArrayType>(
+        &mut self,
+        type_name: &str,
+        v: impl AsRef<T::Native>,
+    ) -> Result<(), ArrowError> {
+        let field_data = self.field_data(type_name, &T::DATA_TYPE, |capacity| {
+            GenericByteBuilder::<T>::with_capacity(capacity, 1024)
+        })?;
+        self.append_to(type_name.to_string(), field_data, |f| {
+            f.append_value(|b: &mut GenericByteBuilder<T>| b.append_value(v))
+        });
+        Ok(())
+    }
+
+    /// Appends a null to this builder, encoding the null in the byte array of the
+    /// `type_name` child / field, see [`Self::append_null`]
+    pub fn append_bytes_null<T: ByteArrayType>(
+        &mut self,
+        type_name: &str,
+    ) -> Result<(), ArrowError> {
+        let field_data = self.field_data(type_name, &T::DATA_TYPE, |capacity| {
+            GenericByteBuilder::<T>::with_capacity(capacity, 1024)
+        })?;
+        self.append_to(type_name.to_string(), field_data, FieldData::append_null);
+        Ok(())
+    }
+
     fn append_option<T: ArrowPrimitiveType>(
         &mut self,
         type_name: &str,
         v: Option<T::Native>,
     ) -> Result<(), ArrowError> {
-        let type_name = type_name.to_string();
+        let field_data = self.field_data(
+            type_name,
+            &T::DATA_TYPE,
+            PrimitiveBuilder::<T>::with_capacity,
+        )?;
+        self.append_to(type_name.to_string(), field_data, |f| match v {
+            Some(v) => f.append_value(|b: &mut PrimitiveBuilder<T>| b.append_value(v)),
+            None => f.append_null(),
+        });
+        Ok(())
+    }
 
-        let mut field_data = match self.fields.remove(&type_name) {
-            Some(data) => {
-                if data.data_type != T::DATA_TYPE {
-                    return Err(ArrowError::InvalidArgumentError(format!(
-                        "Attempt to write col \"{}\" with type {} doesn't match existing type {}",
-                        type_name,
-                        T::DATA_TYPE,
-                        data.data_type
-                    )));
-                }
-                data
+    /// Removes the `FieldData` of `type_name` from `self.fields`, or creates it with
+    /// `new_builder` if it does not exist yet
+    ///
+    /// Returns an error if the existing field is not of `data_type`, or if a new field
+    /// would exceed the maximum number of fields
+    fn field_data<B: ChildBuilder>(
+        &mut self,
+        type_name: &str,
+        data_type: &DataType,
+        new_builder: impl FnOnce(usize) -> B,
+    ) -> Result<FieldData, ArrowError> {
+        if let Some(data) = self.fields.remove(type_name) {
+            if &data.data_type != data_type {
+                let err = ArrowError::InvalidArgumentError(format!(
+                    "Attempt to write col \"{}\" with type {} doesn't match existing type {}",
+                    type_name, data_type, data.data_type
+                ));
+                self.fields.insert(type_name.to_string(), data);
+                return Err(err);
             }
-            None => match self.value_offset_builder {
-                Some(_) => FieldData::new::<T>(
-                    self.fields.len() as i8,
-                    T::DATA_TYPE,
-                    self.initial_capacity,
-                ),
-                // In the case of a sparse union, we should pass the maximum of the currently length and the capacity.
-                None => {
-                    let mut fd = FieldData::new::<T>(
-                        self.fields.len() as i8,
-                        T::DATA_TYPE,
-                        self.len.max(self.initial_capacity),
-                    );
-                    for _ in 0..self.len {
-                        fd.append_null();
-                    }
-                    fd
-                }
-            },
+            return Ok(data);
+        }
+
+        let type_id = i8::try_from(self.fields.len()).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot add field \"{type_name}\", a union can have at most 128 fields"
+            ))
+        })?;
+        // In the case of a sparse union, we should pass the maximum of the currently length and the capacity.
+        let capacity = match self.value_offset_builder {
+            Some(_) => self.initial_capacity,
+            None => self.len.max(self.initial_capacity),
         };
+        let mut field_data = FieldData {
+            type_id,
+            data_type: data_type.clone(),
+            builder: Box::new(new_builder(capacity)),
+        };
+        if self.value_offset_builder.is_none() {
+            for _ in 0..self.len {
+                field_data.append_null();
+            }
+        }
+        Ok(field_data)
+    }
+
+    /// Appends a slot of `field_data` using `append`, and returns `field_data` to
+    /// `self.fields`
+    fn append_to(
+        &mut self,
+        type_name: String,
+        mut field_data: FieldData,
+        append: impl FnOnce(&mut FieldData),
+    ) {
         self.type_id_builder.append(field_data.type_id);
 
         match &mut self.value_offset_builder {
             // Dense Union
             Some(offset_builder) => {
-                offset_builder.append(field_data.slots as i32);
+                offset_builder.append(field_data.builder.len() as i32);
             }
             // Sparse Union
             None => {
@@ -264,14 +372,10 @@ impl UnionBuilder {
             }
         }
 
-        match v {
-            Some(v) => field_data.append_value::<T>(v),
-            None => field_data.append_null(),
-        }
+        append(&mut field_data);
 
         self.fields.insert(type_name, field_data);
         self.len += 1;
-        Ok(())
     }
 
     /// Builds this builder creating a new `UnionArray`.
@@ -286,19 +390,10 @@ impl UnionBuilder {
                     FieldData {
                         type_id,
                         data_type,
-                        mut values_buffer,
-                        slots,
-                        mut null_buffer_builder,
+                        mut builder,
                     },
                 )| {
-                    let array_ref = make_array(unsafe {
-                        ArrayDataBuilder::new(data_type.clone())
-                            .add_buffer(values_buffer.finish())
-                            .len(slots)
-                            .nulls(null_buffer_builder.finish())
-                            .build_unchecked()
-                    });
-                    children.push(array_ref);
+                    children.push(builder.finish());
                     (type_id, Arc::new(Field::new(name, data_type, false)))
                 },
             )
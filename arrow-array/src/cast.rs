@@ -282,6 +282,7 @@ macro_rules! downcast_temporal_array {
 /// assert_eq!(primitive_size(&DataType::Int32), 4);
 /// assert_eq!(primitive_size(&DataType::Int64), 8);
 /// assert_eq!(primitive_size(&DataType::Float16), 2);
+/// assert_eq!(primitive_size(&DataType::Decimal32(9, 2)), 4);
 /// assert_eq!(primitive_size(&DataType::Decimal128(38, 10)), 16);
 /// assert_eq!(primitive_size(&DataType::Decimal256(76, 20)), 32);
 /// ```
@@ -301,6 +302,12 @@ macro_rules! downcast_primitive {
             $crate::repeat_pat!($crate::cast::__private::DataType::Float64, $($data_type),+) => {
                 $m!($crate::types::Float64Type $(, $args)*)
             }
+            $crate::repeat_pat!($crate::cast::__private::DataType::Decimal32(_, _), $($data_type),+) => {
+                $m!($crate::types::Decimal32Type $(, $args)*)
+            }
+            $crate::repeat_pat!($crate::cast::__private::DataType::Decimal64(_, _), $($data_type),+) => {
+                $m!($crate::types::Decimal64Type $(, $args)*)
+            }
             $crate::repeat_pat!($crate::cast::__private::DataType::Decimal128(_, _), $($data_type),+) => {
                 $m!($crate::types::Decimal128Type $(, $args)*)
             }
@@ -832,6 +839,14 @@ pub trait AsArray: private::Sealed {
         self.as_list_opt().expect("list array")
     }
 
+    /// Downcast this to a [`GenericListViewArray`] returning `None` if not possible
+    fn as_list_view_opt<O: OffsetSizeTrait>(&self) -> Option<&GenericListViewArray<O>>;
+
+    /// Downcast this to a [`GenericListViewArray`] panicking if not possible
+    fn as_list_view<O: OffsetSizeTrait>(&self) -> &GenericListViewArray<O> {
+        self.as_list_view_opt().expect("list view array")
+    }
+
     /// Downcast this to a [`FixedSizeBinaryArray`] returning `None` if not possible
     fn as_fixed_size_binary_opt(&self) -> Option<&FixedSizeBinaryArray>;
 
@@ -905,6 +920,10 @@ impl AsArray for dyn Array + '_ {
         self.as_any().downcast_ref()
     }
 
+    fn as_list_view_opt<O: OffsetSizeTrait>(&self) -> Option<&GenericListViewArray<O>> {
+        self.as_any().downcast_ref()
+    }
+
     fn as_fixed_size_binary_opt(&self) -> Option<&FixedSizeBinaryArray> {
         self.as_any().downcast_ref()
     }
@@ -960,6 +979,10 @@ impl AsArray for ArrayRef {
         self.as_ref().as_list_opt()
     }
 
+    fn as_list_view_opt<O: OffsetSizeTrait>(&self) -> Option<&GenericListViewArray<O>> {
+        self.as_ref().as_list_view_opt()
+    }
+
     fn as_fixed_size_binary_opt(&self) -> Option<&FixedSizeBinaryArray> {
         self.as_ref().as_fixed_size_binary_opt()
     }
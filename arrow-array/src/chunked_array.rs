@@ -0,0 +1,277 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A logical array made up of a sequence of [`ArrayRef`] of the same [`DataType`]
+
+use crate::{Array, ArrayRef};
+use arrow_schema::{ArrowError, DataType};
+
+/// A logical array made up of a sequence of arrays, its chunks, of the same [`DataType`]
+///
+/// This allows data that arrives in pieces, such as a column read from a sequence of
+/// [`RecordBatch`](crate::RecordBatch), to be treated as a single array without
+/// first concatenating it into a contiguous array. Kernels that support chunked
+/// input, such as `filter_chunked` and `take_chunked` in `arrow-select`, operate on
+/// each chunk in turn.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, ArrayRef, ChunkedArray, Int32Array};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![Some(4), None]));
+/// let chunked = ChunkedArray::try_new(a.data_type().clone(), vec![a, b]).unwrap();
+/// assert_eq!(chunked.len(), 5);
+/// assert_eq!(chunked.num_chunks(), 2);
+/// assert_eq!(chunked.null_count(), 1);
+/// assert_eq!(chunked.chunk_index(3), (1, 0));
+///
+/// let sliced = chunked.slice(2, 2);
+/// assert_eq!(sliced.num_chunks(), 2);
+/// assert_eq!(sliced.chunk(1).as_ref(), &Int32Array::from(vec![4]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkedArray {
+    data_type: DataType,
+    chunks: Vec<ArrayRef>,
+    /// The logical index of the start of each chunk, followed by the length
+    offsets: Vec<usize>,
+}
+
+impl ChunkedArray {
+    /// Creates a [`ChunkedArray`] of `data_type` from `chunks`
+    ///
+    /// Returns an error if any of the chunks is not of `data_type`
+    pub fn try_new(data_type: DataType, chunks: Vec<ArrayRef>) -> Result<Self, ArrowError> {
+        if let Some(c) = chunks.iter().find(|c| c.data_type() != &data_type) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "ChunkedArray of {data_type} cannot contain a chunk of {}",
+                c.data_type()
+            )));
+        }
+        Ok(Self::new_unchecked(data_type, chunks))
+    }
+
+    /// Creates a [`ChunkedArray`] of `data_type` without any chunks
+    pub fn new_empty(data_type: DataType) -> Self {
+        Self::new_unchecked(data_type, vec![])
+    }
+
+    fn new_unchecked(data_type: DataType, chunks: Vec<ArrayRef>) -> Self {
+        let mut offsets = Vec::with_capacity(chunks.len() + 1);
+        offsets.push(0);
+        let mut len = 0;
+        for c in &chunks {
+            len += c.len();
+            offsets.push(len);
+        }
+        Self {
+            data_type,
+            chunks,
+            offsets,
+        }
+    }
+
+    /// Returns the [`DataType`] of this array
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the total number of values in this array
+    pub fn len(&self) -> usize {
+        *self.offsets.last().unwrap()
+    }
+
+    /// Returns `true` if this array does not contain any values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total number of null values in this array
+    pub fn null_count(&self) -> usize {
+        self.chunks.iter().map(|c| c.null_count()).sum()
+    }
+
+    /// Returns the number of chunks of this array
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns the chunk at `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn chunk(&self, index: usize) -> &ArrayRef {
+        &self.chunks[index]
+    }
+
+    /// Returns the chunks of this array
+    pub fn chunks(&self) -> &[ArrayRef] {
+        &self.chunks
+    }
+
+    /// Returns the chunks of this array, consuming self
+    pub fn into_chunks(self) -> Vec<ArrayRef> {
+        self.chunks
+    }
+
+    /// Returns the logical index of the first value of each chunk, followed by the
+    /// total length of this array
+    pub fn chunk_offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the index of the chunk containing the value at the logical `index`, and
+    /// the index of the value within that chunk
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn chunk_index(&self, index: usize) -> (usize, usize) {
+        assert!(
+            index < self.len(),
+            "Trying to access an element at index {} from a ChunkedArray of length {}",
+            index,
+            self.len()
+        );
+        // Find the last chunk starting at or before index, skipping empty chunks
+        let chunk = self.offsets.partition_point(|o| *o <= index) - 1;
+        (chunk, index - self.offsets[chunk])
+    }
+
+    /// Returns a zero-copy slice of this array, containing the sliced portion of each
+    /// chunk it overlaps
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` with `length` is greater than the length of this array
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset.saturating_add(length) <= self.len(),
+            "the length + offset of the sliced ChunkedArray cannot exceed the existing length"
+        );
+        let end = offset + length;
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(self.offsets.windows(2))
+            .filter(|(_, o)| o[0] < o[1] && o[0] < end && o[1] > offset)
+            .map(|(c, o)| {
+                let start = offset.saturating_sub(o[0]);
+                let stop = end.min(o[1]) - o[0];
+                c.slice(start, stop - start)
+            })
+            .collect();
+        Self::new_unchecked(self.data_type.clone(), chunks)
+    }
+
+    /// Returns the total number of bytes of memory occupied by the chunks of this array
+    ///
+    /// See [`Array::get_array_memory_size`]
+    pub fn get_array_memory_size(&self) -> usize {
+        self.chunks.iter().map(|c| c.get_array_memory_size()).sum()
+    }
+}
+
+impl From<ArrayRef> for ChunkedArray {
+    fn from(array: ArrayRef) -> Self {
+        Self::new_unchecked(array.data_type().clone(), vec![array])
+    }
+}
+
+impl PartialEq for ChunkedArray {
+    /// Two [`ChunkedArray`] are equal if they contain the same values, regardless
+    /// of how these are split into chunks
+    fn eq(&self, other: &Self) -> bool {
+        if self.data_type != other.data_type || self.len() != other.len() {
+            return false;
+        }
+        let mut lhs = self.chunks.iter().filter(|c| !c.is_empty());
+        let mut rhs = other.chunks.iter().filter(|c| !c.is_empty());
+        let (mut l, mut r) = (lhs.next().cloned(), rhs.next().cloned());
+        while let (Some(a), Some(b)) = (&l, &r) {
+            let len = a.len().min(b.len());
+            if a.slice(0, len).to_data() != b.slice(0, len).to_data() {
+                return false;
+            }
+            l = match a.len() == len {
+                true => lhs.next().cloned(),
+                false => Some(a.slice(len, a.len() - len)),
+            };
+            r = match b.len() == len {
+                true => rhs.next().cloned(),
+                false => Some(b.slice(len, b.len() - len)),
+            };
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Int32Array, StringArray};
+    use std::sync::Arc;
+
+    fn chunked(chunks: &[&[i32]]) -> ChunkedArray {
+        let chunks = chunks
+            .iter()
+            .map(|c| Arc::new(Int32Array::from(c.to_vec())) as ArrayRef)
+            .collect();
+        ChunkedArray::try_new(DataType::Int32, chunks).unwrap()
+    }
+
+    #[test]
+    fn test_chunked_array() {
+        let array = chunked(&[&[1, 2], &[], &[3, 4, 5], &[6]]);
+        assert_eq!(array.len(), 6);
+        assert_eq!(array.num_chunks(), 4);
+        assert_eq!(array.chunk_offsets(), &[0, 2, 2, 5, 6]);
+        assert_eq!(array.chunk_index(0), (0, 0));
+        assert_eq!(array.chunk_index(2), (2, 0));
+        assert_eq!(array.chunk_index(4), (2, 2));
+        assert_eq!(array.chunk_index(5), (3, 0));
+
+        let sliced = array.slice(1, 4);
+        assert_eq!(sliced.len(), 4);
+        assert_eq!(sliced, chunked(&[&[2, 3, 4, 5]]));
+        assert_eq!(sliced.num_chunks(), 2);
+        assert_eq!(array.slice(6, 0).num_chunks(), 0);
+        assert_eq!(array.slice(2, 0).num_chunks(), 0);
+
+        assert_eq!(array, chunked(&[&[1], &[2, 3, 4], &[5, 6]]));
+        assert_ne!(array, chunked(&[&[1], &[2, 3, 4], &[5, 7]]));
+        assert_ne!(array, chunked(&[&[1, 2, 3, 4, 5]]));
+
+        let empty = ChunkedArray::new_empty(DataType::Int32);
+        assert!(empty.is_empty());
+        assert_eq!(empty, chunked(&[&[], &[]]));
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec!["a"]));
+        let err = ChunkedArray::try_new(DataType::Int32, vec![strings]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: ChunkedArray of Int32 cannot contain a chunk of Utf8"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at index 6 from a ChunkedArray of length 6")]
+    fn test_chunk_index_out_of_bounds() {
+        chunked(&[&[1, 2], &[3, 4, 5, 6]]).chunk_index(6);
+    }
+}
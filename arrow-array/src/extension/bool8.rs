@@ -0,0 +1,177 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::cast::AsArray;
+use crate::extension::{invalid_storage, ExtensionArray};
+use crate::types::Int8Type;
+use crate::{Array, ArrayRef, BooleanArray, Int8Array};
+use arrow_buffer::{BooleanBuffer, ScalarBuffer};
+use arrow_schema::extension::Bool8;
+use arrow_schema::{ArrowError, Field};
+
+/// An array of the [`Bool8`] extension type, stored as an [`Int8Array`]
+///
+/// ```
+/// # use arrow_array::extension::{Bool8Array, ExtensionArray};
+/// # use arrow_array::{BooleanArray, Int8Array};
+/// let array = Bool8Array::new(Int8Array::from(vec![Some(0), Some(-3), None]));
+/// assert!(!array.value(0));
+/// assert!(array.value(1));
+/// assert_eq!(array.to_boolean(), BooleanArray::from(vec![Some(false), Some(true), None]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bool8Array {
+    values: Int8Array,
+}
+
+impl Bool8Array {
+    /// Creates a [`Bool8Array`] from `values`, where `0` is `false` and any other value
+    /// is `true`
+    pub fn new(values: Int8Array) -> Self {
+        Self { values }
+    }
+
+    /// Returns the storage array of this array
+    pub fn values(&self) -> &Int8Array {
+        &self.values
+    }
+
+    /// Returns the storage array of this array, consuming self
+    pub fn into_inner(self) -> Int8Array {
+        self.values
+    }
+
+    /// Returns the boolean at `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn value(&self, index: usize) -> bool {
+        self.values.value(index) != 0
+    }
+
+    /// Returns an iterator over the booleans of this array
+    pub fn iter(&self) -> impl Iterator<Item = Option<bool>> + '_ {
+        self.values.iter().map(|v| v.map(|v| v != 0))
+    }
+
+    /// Returns a zero-copy slice of this array
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        Self::new(self.values.slice(offset, length))
+    }
+
+    /// Returns the booleans of this array as a [`BooleanArray`]
+    pub fn to_boolean(&self) -> BooleanArray {
+        let values = self.values.values();
+        let values = BooleanBuffer::collect_bool(values.len(), |i| values[i] != 0);
+        BooleanArray::new(values, self.values.nulls().cloned())
+    }
+}
+
+impl ExtensionArray for Bool8Array {
+    type Type = Bool8;
+
+    fn extension_type(&self) -> &Self::Type {
+        &Bool8
+    }
+
+    fn storage(&self) -> &dyn Array {
+        &self.values
+    }
+
+    fn to_array_ref(&self) -> ArrayRef {
+        Arc::new(self.values.clone())
+    }
+
+    fn try_from_field(field: &Field, array: &dyn Array) -> Result<Self, ArrowError> {
+        field.try_extension_type::<Bool8>()?;
+        let values = array
+            .as_primitive_opt::<Int8Type>()
+            .ok_or_else(|| invalid_storage::<Bool8>(array))?;
+        Ok(Self::new(values.clone()))
+    }
+}
+
+impl From<&BooleanArray> for Bool8Array {
+    fn from(array: &BooleanArray) -> Self {
+        let values: ScalarBuffer<i8> = array.values().iter().map(i8::from).collect();
+        Self::new(Int8Array::new(values, array.nulls().cloned()))
+    }
+}
+
+impl From<BooleanArray> for Bool8Array {
+    fn from(array: BooleanArray) -> Self {
+        Self::from(&array)
+    }
+}
+
+impl From<Bool8Array> for Int8Array {
+    fn from(array: Bool8Array) -> Self {
+        array.values
+    }
+}
+
+impl FromIterator<Option<bool>> for Bool8Array {
+    fn from_iter<T: IntoIterator<Item = Option<bool>>>(iter: T) -> Self {
+        Self::new(iter.into_iter().map(|v| v.map(i8::from)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::DataType;
+
+    #[test]
+    fn test_bool8_array() {
+        let array: Bool8Array = vec![Some(true), None, Some(false)].into_iter().collect();
+        assert_eq!(
+            array.values(),
+            &Int8Array::from(vec![Some(1), None, Some(0)])
+        );
+        assert_eq!(
+            array.iter().collect::<Vec<_>>(),
+            vec![Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            array.slice(2, 1).to_boolean(),
+            BooleanArray::from(vec![false])
+        );
+
+        let boolean = BooleanArray::from(vec![Some(false), None, Some(true)]);
+        let array = Bool8Array::from(&boolean);
+        assert_eq!(
+            array.values(),
+            &Int8Array::from(vec![Some(0), None, Some(1)])
+        );
+        assert_eq!(array.to_boolean(), boolean);
+
+        let field = array.field("b");
+        assert_eq!(field.data_type(), &DataType::Int8);
+        assert_eq!(field.extension_type_name(), Some("arrow.bool8"));
+        let array = Bool8Array::try_from_field(&field, &array.to_array_ref()).unwrap();
+        assert_eq!(array.null_count(), 1);
+
+        let err = Bool8Array::try_from_field(&field, &boolean).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.bool8 extension type not supported for Boolean"
+        );
+    }
+}
@@ -0,0 +1,404 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::cast::AsArray;
+use crate::extension::{invalid_storage, ExtensionArray};
+use crate::types::ArrowPrimitiveType;
+use crate::{Array, ArrayRef, FixedSizeListArray, PrimitiveArray};
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_schema::extension::{ExtensionType, FixedShapeTensor, FixedShapeTensorMetadata};
+use arrow_schema::{ArrowError, Field};
+
+/// An array of the [`FixedShapeTensor`] extension type, stored as a
+/// [`FixedSizeListArray`] of the tensor elements in row-major order
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::extension::{ExtensionArray, FixedShapeTensorArray};
+/// # use arrow_array::{Array, FixedSizeListArray, Float32Array};
+/// # use arrow_array::types::Float32Type;
+/// # use arrow_schema::extension::{ExtensionType, FixedShapeTensor, FixedShapeTensorMetadata};
+/// # use arrow_schema::{DataType, Field};
+/// let item = Arc::new(Field::new("item", DataType::Float32, false));
+/// let elements = Float32Array::from((0..12).map(|v| v as f32).collect::<Vec<_>>());
+/// let values = FixedSizeListArray::new(item, 6, Arc::new(elements), None);
+///
+/// let metadata = FixedShapeTensorMetadata::try_new(vec![2, 3], None, None).unwrap();
+/// let tensor = FixedShapeTensor::try_new(values.data_type(), metadata).unwrap();
+/// let array = FixedShapeTensorArray::try_new(tensor, values).unwrap();
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(array.extension_type().shape(), &[2, 3]);
+/// assert_eq!(array.value(1).as_ref(), &Float32Array::from(vec![6., 7., 8., 9., 10., 11.]));
+///
+/// let row = array.row::<Float32Type>(1);
+/// assert_eq!(row.shape(), &[2, 3]);
+/// assert_eq!(row[[1, 0]], 9.);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedShapeTensorArray {
+    extension_type: FixedShapeTensor,
+    values: FixedSizeListArray,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+impl FixedShapeTensorArray {
+    /// Creates a [`FixedShapeTensorArray`] of `extension_type` from `values`
+    ///
+    /// Returns an error if the data type of `values` does not match the element type
+    /// and shape of `extension_type`
+    pub fn try_new(
+        extension_type: FixedShapeTensor,
+        values: FixedSizeListArray,
+    ) -> Result<Self, ArrowError> {
+        extension_type.supports_data_type(values.data_type())?;
+        Ok(Self {
+            shape: extension_type.logical_shape(),
+            strides: extension_type.strides(),
+            extension_type,
+            values,
+        })
+    }
+
+    /// Creates a [`FixedShapeTensorArray`] of tensors with `metadata` from `values`, the
+    /// elements of each tensor in turn in row-major order
+    ///
+    /// Returns an error if the length of `values` is not a multiple of the number of
+    /// elements of each tensor, or if `nulls` does not have a slot for each tensor
+    ///
+    /// ```
+    /// # use arrow_array::extension::{ExtensionArray, FixedShapeTensorArray};
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_buffer::ScalarBuffer;
+    /// # use arrow_schema::extension::FixedShapeTensorMetadata;
+    /// let metadata = FixedShapeTensorMetadata::try_new(vec![2, 2], None, None).unwrap();
+    /// let values = ScalarBuffer::from(vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let array =
+    ///     FixedShapeTensorArray::try_from_contiguous::<Int32Type>(metadata, values, None)
+    ///         .unwrap();
+    /// assert_eq!(array.len(), 2);
+    /// assert_eq!(array.row::<Int32Type>(1).values(), &[5, 6, 7, 8]);
+    /// assert_eq!(array.slice(1, 1).to_contiguous::<Int32Type>(), &[5, 6, 7, 8]);
+    /// ```
+    pub fn try_from_contiguous<T: ArrowPrimitiveType>(
+        metadata: FixedShapeTensorMetadata,
+        values: ScalarBuffer<T::Native>,
+        nulls: Option<NullBuffer>,
+    ) -> Result<Self, ArrowError> {
+        let size = metadata.list_size();
+        if size != 0 && values.len() % size != 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{} extension type expects a multiple of {size} values, got {}",
+                FixedShapeTensor::NAME,
+                values.len()
+            )));
+        }
+        let size = i32::try_from(size).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "{} extension type tensors of {size} elements are too large",
+                FixedShapeTensor::NAME
+            ))
+        })?;
+
+        let item = Arc::new(Field::new_list_field(T::DATA_TYPE, false));
+        let elements = Arc::new(PrimitiveArray::<T>::new(values, None));
+        let values = FixedSizeListArray::try_new(item, size, elements, nulls)?;
+        let extension_type = FixedShapeTensor::try_new(values.data_type(), metadata)?;
+        Self::try_new(extension_type, values)
+    }
+
+    /// Returns the storage array of this array
+    pub fn values(&self) -> &FixedSizeListArray {
+        &self.values
+    }
+
+    /// Returns the storage array of this array, consuming self
+    pub fn into_inner(self) -> FixedSizeListArray {
+        self.values
+    }
+
+    /// Returns the elements of the tensor at `index` in row-major order
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn value(&self, index: usize) -> ArrayRef {
+        self.values.value(index)
+    }
+
+    /// Returns the tensor at `index` as a [`TensorView`] of its elements of type `T`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if the tensor elements are not of type `T`
+    pub fn row<T: ArrowPrimitiveType>(&self, index: usize) -> TensorView<'_, T::Native> {
+        assert!(
+            index < self.len(),
+            "Trying to access an element at index {} from a FixedShapeTensorArray of length {}",
+            index,
+            self.len()
+        );
+        let size = self.extension_type.list_size();
+        let start = index * size;
+        TensorView {
+            values: &self.elements::<T>()[start..start + size],
+            shape: &self.shape,
+            strides: &self.strides,
+        }
+    }
+
+    /// Returns an iterator over the tensors of this array as [`TensorView`], yielding
+    /// `None` for null tensors
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tensor elements are not of type `T`
+    pub fn iter<T: ArrowPrimitiveType>(
+        &self,
+    ) -> impl Iterator<Item = Option<TensorView<'_, T::Native>>> + '_ {
+        (0..self.len()).map(|i| (!self.is_null(i)).then(|| self.row::<T>(i)))
+    }
+
+    /// Returns the elements of all the tensors of this array, in turn and in row-major
+    /// order, as a contiguous buffer
+    ///
+    /// This is zero-copy, and is the inverse of [`Self::try_from_contiguous`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tensor elements are not of type `T`
+    pub fn to_contiguous<T: ArrowPrimitiveType>(&self) -> ScalarBuffer<T::Native> {
+        let len = self.len() * self.extension_type.list_size();
+        self.elements::<T>().slice(0, len)
+    }
+
+    /// Returns a zero-copy slice of this array
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        Self {
+            extension_type: self.extension_type.clone(),
+            values: self.values.slice(offset, length),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+        }
+    }
+
+    fn elements<T: ArrowPrimitiveType>(&self) -> &ScalarBuffer<T::Native> {
+        match self.values.values().as_primitive_opt::<T>() {
+            Some(values) => values.values(),
+            None => panic!(
+                "FixedShapeTensorArray of {} elements cannot be accessed as {}",
+                self.extension_type.value_type(),
+                T::DATA_TYPE
+            ),
+        }
+    }
+}
+
+/// A view of a single tensor of a [`FixedShapeTensorArray`]
+///
+/// Elements are indexed by their position in the logical shape of the tensor, taking
+/// into account any permutation of its dimensions
+#[derive(Debug, Clone, Copy)]
+pub struct TensorView<'a, T> {
+    values: &'a [T],
+    shape: &'a [usize],
+    strides: &'a [usize],
+}
+
+impl<'a, T> TensorView<'a, T> {
+    /// Returns the elements of this tensor in the row-major order of its storage
+    pub fn values(&self) -> &'a [T] {
+        self.values
+    }
+
+    /// Returns the logical shape of this tensor
+    pub fn shape(&self) -> &'a [usize] {
+        self.shape
+    }
+
+    /// Returns the strides of the logical dimensions of this tensor, in elements
+    pub fn strides(&self) -> &'a [usize] {
+        self.strides
+    }
+
+    /// Returns the number of dimensions of this tensor
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Returns the element at `index`, or `None` if `index` is out of bounds
+    pub fn get(&self, index: &[usize]) -> Option<&'a T> {
+        if index.len() != self.shape.len() || index.iter().zip(self.shape).any(|(i, s)| i >= s) {
+            return None;
+        }
+        let offset: usize = index.iter().zip(self.strides).map(|(i, s)| i * s).sum();
+        self.values.get(offset)
+    }
+}
+
+impl<T, const N: usize> std::ops::Index<[usize; N]> for TensorView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        match self.get(&index) {
+            Some(v) => v,
+            None => panic!(
+                "index {index:?} is out of bounds for tensor of shape {:?}",
+                self.shape
+            ),
+        }
+    }
+}
+
+impl ExtensionArray for FixedShapeTensorArray {
+    type Type = FixedShapeTensor;
+
+    fn extension_type(&self) -> &Self::Type {
+        &self.extension_type
+    }
+
+    fn storage(&self) -> &dyn Array {
+        &self.values
+    }
+
+    fn to_array_ref(&self) -> ArrayRef {
+        Arc::new(self.values.clone())
+    }
+
+    fn try_from_field(field: &Field, array: &dyn Array) -> Result<Self, ArrowError> {
+        let extension_type = field.try_extension_type::<FixedShapeTensor>()?;
+        let values = array
+            .as_fixed_size_list_opt()
+            .ok_or_else(|| invalid_storage::<FixedShapeTensor>(array))?;
+        Self::try_new(extension_type, values.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Float32Type, Int64Type};
+    use crate::Int64Array;
+    use arrow_schema::DataType;
+
+    #[test]
+    fn test_fixed_shape_tensor_array() {
+        let item = Arc::new(Field::new("item", DataType::Int64, false));
+        let values =
+            FixedSizeListArray::new(item, 4, Arc::new(Int64Array::from_iter_values(0..12)), None);
+
+        let metadata = FixedShapeTensorMetadata::try_new(
+            vec![2, 2],
+            Some(vec!["x".to_string(), "y".to_string()]),
+            None,
+        )
+        .unwrap();
+        let tensor = FixedShapeTensor::try_new(values.data_type(), metadata).unwrap();
+        let array = FixedShapeTensorArray::try_new(tensor, values.clone()).unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.row::<Int64Type>(2).values(), &[8, 9, 10, 11]);
+        assert_eq!(
+            array.slice(1, 2).value(1).as_ref(),
+            &Int64Array::from(vec![8, 9, 10, 11])
+        );
+
+        let field = array.field("t");
+        assert_eq!(
+            field.extension_type_metadata(),
+            Some(r#"{"shape":[2,2],"dim_names":["x","y"]}"#)
+        );
+        let array = FixedShapeTensorArray::try_from_field(&field, &array.to_array_ref()).unwrap();
+        assert_eq!(array.extension_type().dim_names().unwrap(), &["x", "y"]);
+
+        let metadata = FixedShapeTensorMetadata::try_new(vec![2, 3], None, None).unwrap();
+        let data_type =
+            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int64, false)), 6);
+        let tensor = FixedShapeTensor::try_new(&data_type, metadata).unwrap();
+        let err = FixedShapeTensorArray::try_new(tensor, values).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.fixed_shape_tensor extension type not supported for FixedSizeList(Field { name: \"item\", data_type: Int64, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: {} }, 4)"
+        );
+    }
+
+    #[test]
+    fn test_fixed_shape_tensor_row() {
+        // Physical shape [2, 3] transposed to a logical shape of [3, 2]
+        let metadata =
+            FixedShapeTensorMetadata::try_new(vec![2, 3], None, Some(vec![1, 0])).unwrap();
+        let values = ScalarBuffer::from_iter((0..18).map(|x| x as f32));
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let array = FixedShapeTensorArray::try_from_contiguous::<Float32Type>(
+            metadata,
+            values,
+            Some(nulls),
+        )
+        .unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 1);
+
+        let row = array.row::<Float32Type>(2);
+        assert_eq!(row.values(), &[12., 13., 14., 15., 16., 17.]);
+        assert_eq!(row.shape(), &[3, 2]);
+        assert_eq!(row.strides(), &[1, 3]);
+        assert_eq!(row.ndim(), 2);
+        assert_eq!(row[[0, 0]], 12.);
+        assert_eq!(row[[0, 1]], 15.);
+        assert_eq!(row[[2, 1]], 17.);
+        assert_eq!(row.get(&[3, 0]), None);
+        assert_eq!(row.get(&[0]), None);
+
+        let rows: Vec<_> = array
+            .iter::<Float32Type>()
+            .map(|r| r.map(|r| r[[1, 0]]))
+            .collect();
+        assert_eq!(rows, vec![Some(1.), None, Some(13.)]);
+
+        let sliced = array.slice(1, 2);
+        assert_eq!(sliced.row::<Float32Type>(1).values(), row.values());
+        let contiguous = sliced.to_contiguous::<Float32Type>();
+        assert_eq!(contiguous.len(), 12);
+        assert_eq!(contiguous[0], 6.);
+
+        let metadata = FixedShapeTensorMetadata::try_new(vec![2, 2], None, None).unwrap();
+        let err = FixedShapeTensorArray::try_from_contiguous::<Float32Type>(
+            metadata,
+            ScalarBuffer::from(vec![1., 2., 3.]),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.fixed_shape_tensor extension type expects a multiple of 4 values, got 3"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "FixedShapeTensorArray of Float32 elements cannot be accessed as Int64"
+    )]
+    fn test_fixed_shape_tensor_row_type() {
+        let metadata = FixedShapeTensorMetadata::try_new(vec![2], None, None).unwrap();
+        let values = ScalarBuffer::from(vec![1_f32, 2.]);
+        let array =
+            FixedShapeTensorArray::try_from_contiguous::<Float32Type>(metadata, values, None)
+                .unwrap();
+        array.row::<Int64Type>(0);
+    }
+}
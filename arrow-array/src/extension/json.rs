@@ -0,0 +1,168 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::cast::AsArray;
+use crate::extension::ExtensionArray;
+use crate::{Array, ArrayRef, LargeStringArray, StringArray, StringViewArray};
+use arrow_schema::extension::{ExtensionType, Json};
+use arrow_schema::{ArrowError, DataType, Field};
+
+/// An array of the [`Json`] extension type, stored as a [`StringArray`],
+/// [`LargeStringArray`] or [`StringViewArray`]
+///
+/// The values are not parsed, and are not validated to be JSON
+///
+/// ```
+/// # use arrow_array::extension::{ExtensionArray, JsonArray};
+/// # use arrow_array::StringArray;
+/// let array = JsonArray::from(StringArray::from(vec![Some(r#"{"a": 1}"#), None]));
+/// assert_eq!(array.value(0), r#"{"a": 1}"#);
+/// assert!(array.is_null(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonArray {
+    extension_type: Json,
+    values: ArrayRef,
+}
+
+impl JsonArray {
+    /// Creates a [`JsonArray`] from `values`
+    ///
+    /// Returns an error if `values` is not a [`StringArray`], [`LargeStringArray`] or
+    /// [`StringViewArray`]
+    pub fn try_new(values: ArrayRef) -> Result<Self, ArrowError> {
+        let extension_type = Json::default();
+        extension_type.supports_data_type(values.data_type())?;
+        Ok(Self {
+            extension_type,
+            values,
+        })
+    }
+
+    /// Returns the storage array of this array
+    pub fn values(&self) -> &ArrayRef {
+        &self.values
+    }
+
+    /// Returns the storage array of this array, consuming self
+    pub fn into_inner(self) -> ArrayRef {
+        self.values
+    }
+
+    /// Returns the JSON document at `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn value(&self, index: usize) -> &str {
+        match self.values.data_type() {
+            DataType::Utf8 => self.values.as_string::<i32>().value(index),
+            DataType::LargeUtf8 => self.values.as_string::<i64>().value(index),
+            DataType::Utf8View => self.values.as_string_view().value(index),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns an iterator over the JSON documents of this array
+    pub fn iter(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        (0..self.values.len()).map(|i| (!self.values.is_null(i)).then(|| self.value(i)))
+    }
+
+    /// Returns a zero-copy slice of this array
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        Self {
+            extension_type: self.extension_type.clone(),
+            values: self.values.slice(offset, length),
+        }
+    }
+}
+
+impl ExtensionArray for JsonArray {
+    type Type = Json;
+
+    fn extension_type(&self) -> &Self::Type {
+        &self.extension_type
+    }
+
+    fn storage(&self) -> &dyn Array {
+        self.values.as_ref()
+    }
+
+    fn to_array_ref(&self) -> ArrayRef {
+        Arc::clone(&self.values)
+    }
+
+    fn try_from_field(field: &Field, array: &dyn Array) -> Result<Self, ArrowError> {
+        let extension_type = field.try_extension_type::<Json>()?;
+        extension_type.supports_data_type(array.data_type())?;
+        Ok(Self {
+            extension_type,
+            values: array.slice(0, array.len()),
+        })
+    }
+}
+
+impl From<StringArray> for JsonArray {
+    fn from(values: StringArray) -> Self {
+        Self::try_new(Arc::new(values)).unwrap()
+    }
+}
+
+impl From<LargeStringArray> for JsonArray {
+    fn from(values: LargeStringArray) -> Self {
+        Self::try_new(Arc::new(values)).unwrap()
+    }
+}
+
+impl From<StringViewArray> for JsonArray {
+    fn from(values: StringViewArray) -> Self {
+        Self::try_new(Arc::new(values)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Int32Array;
+
+    #[test]
+    fn test_json_array() {
+        let documents = vec![Some("[1, 2]"), None, Some("null")];
+        let arrays = [
+            JsonArray::from(StringArray::from(documents.clone())),
+            JsonArray::from(LargeStringArray::from(documents.clone())),
+            JsonArray::from(StringViewArray::from(documents.clone())),
+        ];
+        for array in arrays {
+            assert_eq!(array.iter().collect::<Vec<_>>(), documents);
+            assert_eq!(array.slice(2, 1).value(0), "null");
+
+            let field = array.field("j");
+            assert_eq!(field.extension_type_name(), Some("arrow.json"));
+            let array = JsonArray::try_from_field(&field, &array.to_array_ref()).unwrap();
+            assert_eq!(array.value(0), "[1, 2]");
+        }
+
+        let err = JsonArray::try_new(Arc::new(Int32Array::from(vec![1]))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.json extension type not supported for Int32"
+        );
+    }
+}
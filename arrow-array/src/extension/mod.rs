@@ -0,0 +1,110 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Typed arrays of [extension types](arrow_schema::extension)
+//!
+//! An extension array wraps the array of its storage type, validating that the storage
+//! type is supported by the extension type, and provides typed accessors to its values.
+//!
+//! ```
+//! # use arrow_array::extension::{ExtensionArray, UuidArray};
+//! # use arrow_array::RecordBatch;
+//! # use arrow_schema::extension::Uuid;
+//! # use arrow_schema::Schema;
+//! # use std::sync::Arc;
+//! let array: UuidArray = vec![Some([1; 16]), None].into_iter().collect();
+//! let field = array.field("id");
+//! assert_eq!(field.extension_type_name(), Some("arrow.uuid"));
+//!
+//! let schema = Arc::new(Schema::new(vec![field]));
+//! let batch = RecordBatch::try_new(schema, vec![array.to_array_ref()]).unwrap();
+//!
+//! let column = UuidArray::try_from_field(batch.schema().field(0), batch.column(0)).unwrap();
+//! assert_eq!(column.value(0), [1; 16]);
+//! assert!(column.is_null(1));
+//! ```
+
+mod bool8;
+pub use bool8::Bool8Array;
+mod fixed_shape_tensor;
+pub use fixed_shape_tensor::{FixedShapeTensorArray, TensorView};
+mod json;
+pub use json::JsonArray;
+mod uuid;
+pub use uuid::UuidArray;
+
+use crate::{Array, ArrayRef};
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{ArrowError, Field};
+
+/// An array of an [`ExtensionType`], backed by an array of its storage type
+pub trait ExtensionArray: Sized {
+    /// The extension type of this array
+    type Type: ExtensionType + Clone;
+
+    /// Returns the extension type of this array
+    fn extension_type(&self) -> &Self::Type;
+
+    /// Returns the storage array of this array
+    fn storage(&self) -> &dyn Array;
+
+    /// Returns the storage array of this array as an [`ArrayRef`]
+    fn to_array_ref(&self) -> ArrayRef;
+
+    /// Creates an array of this type from `array`, the storage of the extension type of
+    /// `field`
+    ///
+    /// Returns an error if `field` does not have the extension type [`Self::Type`], or if
+    /// `array` is not a valid storage array for it
+    fn try_from_field(field: &Field, array: &dyn Array) -> Result<Self, ArrowError>;
+
+    /// Returns a nullable [`Field`] named `name` for this array, with its extension type
+    fn field(&self, name: &str) -> Field {
+        Field::new(name, self.storage().data_type().clone(), true)
+            .try_with_extension_type(self.extension_type().clone())
+            .expect("supported storage type")
+    }
+
+    /// Returns the number of values in this array
+    fn len(&self) -> usize {
+        self.storage().len()
+    }
+
+    /// Returns `true` if this array is empty
+    fn is_empty(&self) -> bool {
+        self.storage().is_empty()
+    }
+
+    /// Returns `true` if the value at `index` is null
+    fn is_null(&self, index: usize) -> bool {
+        self.storage().is_null(index)
+    }
+
+    /// Returns the number of null values in this array
+    fn null_count(&self) -> usize {
+        self.storage().null_count()
+    }
+}
+
+/// Returns an error for an `array` that is not a valid storage array for `E`
+fn invalid_storage<E: ExtensionType>(array: &dyn Array) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "{} extension type not supported for {}",
+        E::NAME,
+        array.data_type()
+    ))
+}
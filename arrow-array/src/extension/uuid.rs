@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::cast::AsArray;
+use crate::extension::{invalid_storage, ExtensionArray};
+use crate::{Array, ArrayRef, FixedSizeBinaryArray};
+use arrow_schema::extension::{ExtensionType, Uuid};
+use arrow_schema::{ArrowError, Field};
+
+/// An array of the [`Uuid`] extension type, stored as a [`FixedSizeBinaryArray`]
+///
+/// ```
+/// # use arrow_array::extension::{ExtensionArray, UuidArray};
+/// let array: UuidArray = vec![Some([0xAB; 16]), None].into_iter().collect();
+/// assert_eq!(array.value(0), [0xAB; 16]);
+/// assert_eq!(array.iter().collect::<Vec<_>>(), vec![Some([0xAB; 16]), None]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UuidArray {
+    values: FixedSizeBinaryArray,
+}
+
+impl UuidArray {
+    /// Creates a [`UuidArray`] from `values`
+    ///
+    /// Returns an error if the values of `values` are not 16 bytes long
+    pub fn try_new(values: FixedSizeBinaryArray) -> Result<Self, ArrowError> {
+        Uuid.supports_data_type(values.data_type())?;
+        Ok(Self { values })
+    }
+
+    /// Returns the storage array of this array
+    pub fn values(&self) -> &FixedSizeBinaryArray {
+        &self.values
+    }
+
+    /// Returns the storage array of this array, consuming self
+    pub fn into_inner(self) -> FixedSizeBinaryArray {
+        self.values
+    }
+
+    /// Returns the UUID at `index` as big-endian bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn value(&self, index: usize) -> [u8; 16] {
+        self.values.value(index).try_into().unwrap()
+    }
+
+    /// Returns an iterator over the UUIDs of this array
+    pub fn iter(&self) -> impl Iterator<Item = Option<[u8; 16]>> + '_ {
+        (0..self.values.len()).map(|i| (!self.values.is_null(i)).then(|| self.value(i)))
+    }
+
+    /// Returns a zero-copy slice of this array
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        Self {
+            values: self.values.slice(offset, length),
+        }
+    }
+}
+
+impl ExtensionArray for UuidArray {
+    type Type = Uuid;
+
+    fn extension_type(&self) -> &Self::Type {
+        &Uuid
+    }
+
+    fn storage(&self) -> &dyn Array {
+        &self.values
+    }
+
+    fn to_array_ref(&self) -> ArrayRef {
+        Arc::new(self.values.clone())
+    }
+
+    fn try_from_field(field: &Field, array: &dyn Array) -> Result<Self, ArrowError> {
+        field.try_extension_type::<Uuid>()?;
+        let values = array
+            .as_fixed_size_binary_opt()
+            .ok_or_else(|| invalid_storage::<Uuid>(array))?;
+        Self::try_new(values.clone())
+    }
+}
+
+impl FromIterator<Option<[u8; 16]>> for UuidArray {
+    fn from_iter<T: IntoIterator<Item = Option<[u8; 16]>>>(iter: T) -> Self {
+        let values = FixedSizeBinaryArray::try_from_sparse_iter_with_size(iter.into_iter(), 16)
+            .expect("UUIDs are 16 bytes");
+        Self { values }
+    }
+}
+
+impl From<UuidArray> for FixedSizeBinaryArray {
+    fn from(array: UuidArray) -> Self {
+        array.values
+    }
+}
+
+impl TryFrom<FixedSizeBinaryArray> for UuidArray {
+    type Error = ArrowError;
+
+    fn try_from(values: FixedSizeBinaryArray) -> Result<Self, Self::Error> {
+        Self::try_new(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Int8Array;
+    use arrow_schema::DataType;
+
+    #[test]
+    fn test_uuid_array() {
+        let array: UuidArray = vec![Some([1; 16]), None, Some([3; 16])]
+            .into_iter()
+            .collect();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.value(2), [3; 16]);
+        assert_eq!(
+            array.slice(1, 2).iter().collect::<Vec<_>>(),
+            vec![None, Some([3; 16])]
+        );
+
+        let field = array.field("id");
+        assert_eq!(field.data_type(), &DataType::FixedSizeBinary(16));
+        assert_eq!(field.extension_type_name(), Some("arrow.uuid"));
+        let array = UuidArray::try_from_field(&field, &array.to_array_ref()).unwrap();
+        assert_eq!(array.value(0), [1; 16]);
+
+        let err = UuidArray::try_new(FixedSizeBinaryArray::from(vec![&[1_u8; 8]])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.uuid extension type not supported for FixedSizeBinary(8)"
+        );
+
+        let err = UuidArray::try_from_field(&field, &Int8Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.uuid extension type not supported for Int8"
+        );
+
+        let field = Field::new("id", DataType::FixedSizeBinary(16), true);
+        let err = UuidArray::try_from_field(&field, &array.to_array_ref()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field \"id\" does not have an extension type, expected arrow.uuid"
+        );
+    }
+}
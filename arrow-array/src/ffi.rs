@@ -636,6 +636,33 @@ mod tests_to_then_from_ffi {
         // (drop/release)
         Ok(())
     }
+
+    #[test]
+    #[cfg(not(feature = "force_validate"))]
+    fn test_decimal32_decimal64_round_trip() -> Result<()> {
+        use crate::types::{Decimal32Type, Decimal64Type};
+
+        let original_array = Decimal32Array::from(vec![Some(12345), Some(-12345), None])
+            .with_precision_and_scale(6, 2)
+            .unwrap();
+        let (array, schema) = to_ffi(&original_array.to_data())?;
+        let data = unsafe { from_ffi(array, &schema) }?;
+        assert_eq!(
+            make_array(data).as_primitive::<Decimal32Type>(),
+            &original_array
+        );
+
+        let original_array = Decimal64Array::from(vec![Some(123456789012), None])
+            .with_precision_and_scale(18, -3)
+            .unwrap();
+        let (array, schema) = to_ffi(&original_array.to_data())?;
+        let data = unsafe { from_ffi(array, &schema) }?;
+        assert_eq!(
+            make_array(data).as_primitive::<Decimal64Type>(),
+            &original_array
+        );
+        Ok(())
+    }
     // case with nulls is tested in the docs, through the example on this module.
 
     #[test]
@@ -0,0 +1,781 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains declarations to bind to the [C Device Data Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html).
+//!
+//! The C Device Data Interface extends the [C Data Interface](crate::ffi) and
+//! [C Stream Interface](crate::ffi_stream) with the device on which the buffers of an
+//! array reside, along with an optional event that consumers must wait on before
+//! accessing them.
+//!
+//! Arrays resident on a non-CPU device, such as CUDA, can be passed through
+//! [`FFI_ArrowDeviceArray`] without being accessed, with the buffer pointers treated as
+//! opaque. Only arrays whose memory is accessible from the host, see
+//! [`DeviceType::is_host_accessible`], can be imported as [`ArrayData`].
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Array, Int32Array, make_array};
+//! # use arrow_array::ffi_device::{from_ffi_device, to_ffi_device, DeviceType};
+//! let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+//!
+//! // Export the array, which resides in CPU memory
+//! let (device_array, schema) = to_ffi_device(&array.to_data()).unwrap();
+//! assert_eq!(device_array.device_type().unwrap(), DeviceType::Cpu);
+//!
+//! // Import it back
+//! let data = unsafe { from_ffi_device(device_array, &schema) }.unwrap();
+//! assert_eq!(make_array(data).as_ref(), &array as &dyn Array);
+//! ```
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr::addr_of;
+use std::sync::Arc;
+
+use arrow_data::ffi::FFI_ArrowArray;
+use arrow_data::ArrayData;
+use arrow_schema::ffi::FFI_ArrowSchema;
+use arrow_schema::{ArrowError, DataType, Schema, SchemaRef};
+
+use crate::array::StructArray;
+use crate::ffi::{from_ffi, from_ffi_and_data_type};
+use crate::ffi_stream::FFI_ArrowArrayStream;
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+type Result<T> = std::result::Result<T, ArrowError>;
+
+const EINVAL: i32 = 22;
+
+/// The type of device on which the buffers of an array reside
+///
+/// See <https://arrow.apache.org/docs/format/CDeviceDataInterface.html#c.ArrowDeviceType>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceType {
+    /// CPU device, same as using the C Data Interface
+    Cpu,
+    /// CUDA GPU device
+    Cuda,
+    /// Pinned CUDA CPU memory allocated by `cudaMallocHost`
+    CudaHost,
+    /// OpenCL device
+    OpenCl,
+    /// Vulkan buffer for next-gen graphics
+    Vulkan,
+    /// Metal for Apple GPU
+    Metal,
+    /// Verilog simulator buffer
+    Vpi,
+    /// ROCm GPUs for AMD GPUs
+    Rocm,
+    /// Pinned ROCm CPU memory allocated by `hipMallocHost`
+    RocmHost,
+    /// Reserved for extension devices
+    ExtDev,
+    /// CUDA managed/unified memory allocated by `cudaMallocManaged`
+    CudaManaged,
+    /// Unified shared memory allocated on an Intel oneAPI non-partitioned device
+    OneApi,
+    /// GPU support for next-gen WebGPU standard
+    WebGpu,
+    /// Qualcomm Hexagon DSP
+    Hexagon,
+}
+
+impl DeviceType {
+    /// Returns true if memory on this device can be directly accessed from the CPU
+    pub fn is_host_accessible(&self) -> bool {
+        matches!(
+            self,
+            Self::Cpu | Self::CudaHost | Self::RocmHost | Self::CudaManaged
+        )
+    }
+}
+
+impl From<DeviceType> for i32 {
+    fn from(value: DeviceType) -> Self {
+        match value {
+            DeviceType::Cpu => 1,
+            DeviceType::Cuda => 2,
+            DeviceType::CudaHost => 3,
+            DeviceType::OpenCl => 4,
+            DeviceType::Vulkan => 7,
+            DeviceType::Metal => 8,
+            DeviceType::Vpi => 9,
+            DeviceType::Rocm => 10,
+            DeviceType::RocmHost => 11,
+            DeviceType::ExtDev => 12,
+            DeviceType::CudaManaged => 13,
+            DeviceType::OneApi => 14,
+            DeviceType::WebGpu => 15,
+            DeviceType::Hexagon => 16,
+        }
+    }
+}
+
+impl TryFrom<i32> for DeviceType {
+    type Error = ArrowError;
+
+    fn try_from(value: i32) -> Result<Self> {
+        Ok(match value {
+            1 => Self::Cpu,
+            2 => Self::Cuda,
+            3 => Self::CudaHost,
+            4 => Self::OpenCl,
+            7 => Self::Vulkan,
+            8 => Self::Metal,
+            9 => Self::Vpi,
+            10 => Self::Rocm,
+            11 => Self::RocmHost,
+            12 => Self::ExtDev,
+            13 => Self::CudaManaged,
+            14 => Self::OneApi,
+            15 => Self::WebGpu,
+            16 => Self::Hexagon,
+            _ => {
+                return Err(ArrowError::CDataInterface(format!(
+                    "Unknown device type: {value}"
+                )))
+            }
+        })
+    }
+}
+
+/// ABI-compatible struct for `ArrowDeviceArray` from C Device Data Interface
+/// See <https://arrow.apache.org/docs/format/CDeviceDataInterface.html#structure-definitions>
+///
+/// Dropping this releases the wrapped [`FFI_ArrowArray`]
+#[repr(C)]
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub struct FFI_ArrowDeviceArray {
+    /// The array, whose buffers reside on the device
+    pub array: FFI_ArrowArray,
+    /// The identifier of the device, or -1 if not applicable
+    pub device_id: i64,
+    /// The [`DeviceType`] of the device, as its C representation
+    pub device_type: i32,
+    /// An event that consumers must wait on before accessing the buffers,
+    /// or null if the data is ready. The concrete type depends on the device
+    pub sync_event: *mut c_void,
+    /// Reserved for future use, must be zero
+    pub reserved: [i64; 3],
+}
+
+unsafe impl Send for FFI_ArrowDeviceArray {}
+
+impl FFI_ArrowDeviceArray {
+    /// Creates a new [`FFI_ArrowDeviceArray`] for `array`, whose buffers reside on
+    /// the given device
+    ///
+    /// `sync_event` should be null, or point to an event of the type appropriate for
+    /// `device_type`, such as a `cudaEvent_t` for [`DeviceType::Cuda`], that remains valid
+    /// until `array` is released
+    pub fn new(
+        array: FFI_ArrowArray,
+        device_type: DeviceType,
+        device_id: i64,
+        sync_event: *mut c_void,
+    ) -> Self {
+        Self {
+            array,
+            device_id,
+            device_type: device_type.into(),
+            sync_event,
+            reserved: [0; 3],
+        }
+    }
+
+    /// Creates a new [`FFI_ArrowDeviceArray`] for `array`, whose buffers reside in CPU memory
+    pub fn new_cpu(array: FFI_ArrowArray) -> Self {
+        Self::new(array, DeviceType::Cpu, -1, std::ptr::null_mut())
+    }
+
+    /// Creates a new empty [`FFI_ArrowDeviceArray`]. Used to import from the C Device Data Interface.
+    pub fn empty() -> Self {
+        Self::new_cpu(FFI_ArrowArray::empty())
+    }
+
+    /// Takes ownership of the pointed to [`FFI_ArrowDeviceArray`]
+    ///
+    /// This acts to [move] the data out of `array`, setting the release callback to NULL
+    ///
+    /// # Safety
+    ///
+    /// * `array` must be [valid] for reads and writes
+    /// * `array` must be properly aligned
+    /// * `array` must point to a properly initialized value of [`FFI_ArrowDeviceArray`]
+    ///
+    /// [move]: https://arrow.apache.org/docs/format/CDataInterface.html#moving-an-array
+    /// [valid]: https://doc.rust-lang.org/std/ptr/index.html#safety
+    pub unsafe fn from_raw(array: *mut FFI_ArrowDeviceArray) -> Self {
+        std::ptr::replace(array, Self::empty())
+    }
+
+    /// Returns the [`DeviceType`] of this array
+    pub fn device_type(&self) -> Result<DeviceType> {
+        self.device_type.try_into()
+    }
+
+    /// Returns the identifier of the device of this array, or -1 if not applicable
+    pub fn device_id(&self) -> i64 {
+        self.device_id
+    }
+
+    /// Returns the event to wait on before accessing the buffers of this array, if any
+    pub fn sync_event(&self) -> Option<*mut c_void> {
+        (!self.sync_event.is_null()).then_some(self.sync_event)
+    }
+
+    /// Returns true if the wrapped array has already been released
+    pub fn is_released(&self) -> bool {
+        self.array.is_released()
+    }
+}
+
+/// Export to the C Device Data Interface, as an array resident in CPU memory
+pub fn to_ffi_device(data: &ArrayData) -> Result<(FFI_ArrowDeviceArray, FFI_ArrowSchema)> {
+    let array = FFI_ArrowArray::new(data);
+    let schema = FFI_ArrowSchema::try_from(data.data_type())?;
+    Ok((FFI_ArrowDeviceArray::new_cpu(array), schema))
+}
+
+/// Import [ArrayData] from the C Device Data Interface
+///
+/// Returns an error if the array is not [host accessible](DeviceType::is_host_accessible),
+/// or has a sync event, see [`from_ffi_device_with_sync`]
+///
+/// # Safety
+///
+/// This function assumes that the incoming data agrees with the C Device Data Interface.
+pub unsafe fn from_ffi_device(
+    array: FFI_ArrowDeviceArray,
+    schema: &FFI_ArrowSchema,
+) -> Result<ArrayData> {
+    from_ffi_device_with_sync(array, schema, no_sync)
+}
+
+/// Import [ArrayData] from the C Device Data Interface, calling `sync` with the device
+/// and event of the array, if it has one, before accessing its buffers
+///
+/// `sync` must block until the event has completed, for example by calling
+/// `cudaEventSynchronize` for a CUDA event
+///
+/// Returns an error if the array is not [host accessible](DeviceType::is_host_accessible)
+///
+/// # Safety
+///
+/// This function assumes that the incoming data agrees with the C Device Data Interface.
+pub unsafe fn from_ffi_device_with_sync<F>(
+    array: FFI_ArrowDeviceArray,
+    schema: &FFI_ArrowSchema,
+    sync: F,
+) -> Result<ArrayData>
+where
+    F: FnOnce(DeviceType, i64, *mut c_void) -> Result<()>,
+{
+    let array = prepare_import(array, sync)?;
+    from_ffi(array, schema)
+}
+
+/// Checks that `array` can be accessed from the host, waiting on its sync event
+/// if any, and returns the wrapped [`FFI_ArrowArray`]
+pub(crate) fn prepare_import<F>(array: FFI_ArrowDeviceArray, sync: F) -> Result<FFI_ArrowArray>
+where
+    F: FnOnce(DeviceType, i64, *mut c_void) -> Result<()>,
+{
+    let device_type = array.device_type()?;
+    if !device_type.is_host_accessible() {
+        return Err(ArrowError::CDataInterface(format!(
+            "Cannot import array resident on {device_type:?} device, as it is not accessible from the host"
+        )));
+    }
+    if let Some(event) = array.sync_event() {
+        sync(device_type, array.device_id, event)?;
+    }
+    Ok(array.array)
+}
+
+/// The default sync hook, which errors as it cannot wait on any event
+pub(crate) fn no_sync(device_type: DeviceType, _: i64, _: *mut c_void) -> Result<()> {
+    Err(ArrowError::CDataInterface(format!(
+        "Cannot wait on sync event for {device_type:?} device without a sync hook"
+    )))
+}
+
+/// ABI-compatible struct for `ArrowDeviceArrayStream` from C Device Data Interface
+/// See <https://arrow.apache.org/docs/format/CDeviceDataInterface.html#structure-definitions>
+#[repr(C)]
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub struct FFI_ArrowDeviceArrayStream {
+    /// The [`DeviceType`] of all arrays in the stream, as its C representation
+    pub device_type: i32,
+    /// C function to get schema from the stream
+    pub get_schema:
+        Option<unsafe extern "C" fn(arg1: *mut Self, out: *mut FFI_ArrowSchema) -> c_int>,
+    /// C function to get next array from the stream
+    pub get_next:
+        Option<unsafe extern "C" fn(arg1: *mut Self, out: *mut FFI_ArrowDeviceArray) -> c_int>,
+    /// C function to get the error from last operation on the stream
+    pub get_last_error: Option<unsafe extern "C" fn(arg1: *mut Self) -> *const c_char>,
+    /// C function to release the stream
+    pub release: Option<unsafe extern "C" fn(arg1: *mut Self)>,
+    /// Private data used by the stream
+    pub private_data: *mut c_void,
+}
+
+unsafe impl Send for FFI_ArrowDeviceArrayStream {}
+
+// callback used to drop [FFI_ArrowDeviceArrayStream] when it is exported.
+unsafe extern "C" fn release_device_stream(stream: *mut FFI_ArrowDeviceArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+
+    stream.get_schema = None;
+    stream.get_next = None;
+    stream.get_last_error = None;
+
+    let private_data = Box::from_raw(stream.private_data as *mut FFI_ArrowArrayStream);
+    drop(private_data);
+
+    stream.release = None;
+}
+
+/// Returns the [`FFI_ArrowArrayStream`] wrapped by an exported `stream`
+unsafe fn inner_stream(stream: *mut FFI_ArrowDeviceArrayStream) -> *mut FFI_ArrowArrayStream {
+    (*stream).private_data as *mut FFI_ArrowArrayStream
+}
+
+// The callback used to get array schema
+unsafe extern "C" fn get_device_schema(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+    schema: *mut FFI_ArrowSchema,
+) -> c_int {
+    let inner = inner_stream(stream);
+    match (*inner).get_schema {
+        Some(get_schema) => get_schema(inner, schema),
+        None => EINVAL,
+    }
+}
+
+// The callback used to get next array
+unsafe extern "C" fn get_device_next(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+    out: *mut FFI_ArrowDeviceArray,
+) -> c_int {
+    let inner = inner_stream(stream);
+    let get_next = match (*inner).get_next {
+        Some(get_next) => get_next,
+        None => return EINVAL,
+    };
+
+    let mut array = FFI_ArrowArray::empty();
+    let ret_code = get_next(inner, &mut array);
+    if ret_code == 0 {
+        let array = FFI_ArrowDeviceArray::new_cpu(array);
+        std::ptr::copy(addr_of!(array), out, 1);
+        std::mem::forget(array);
+    }
+    ret_code
+}
+
+// The callback used to get the error from last operation on the `FFI_ArrowDeviceArrayStream`
+unsafe extern "C" fn get_device_last_error(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+) -> *const c_char {
+    let inner = inner_stream(stream);
+    match (*inner).get_last_error {
+        Some(get_last_error) => get_last_error(inner),
+        None => std::ptr::null(),
+    }
+}
+
+impl Drop for FFI_ArrowDeviceArrayStream {
+    fn drop(&mut self) {
+        match self.release {
+            None => (),
+            Some(release) => unsafe { release(self) },
+        };
+    }
+}
+
+impl FFI_ArrowDeviceArrayStream {
+    /// Creates a new [`FFI_ArrowDeviceArrayStream`] of arrays resident in CPU memory
+    pub fn new(batch_reader: Box<dyn RecordBatchReader + Send>) -> Self {
+        Self::from_stream(FFI_ArrowArrayStream::new(batch_reader))
+    }
+
+    /// Creates a new [`FFI_ArrowDeviceArrayStream`] of arrays resident in CPU memory,
+    /// from an [`FFI_ArrowArrayStream`]
+    pub fn from_stream(stream: FFI_ArrowArrayStream) -> Self {
+        Self {
+            device_type: DeviceType::Cpu.into(),
+            get_schema: Some(get_device_schema),
+            get_next: Some(get_device_next),
+            get_last_error: Some(get_device_last_error),
+            release: Some(release_device_stream),
+            private_data: Box::into_raw(Box::new(stream)) as *mut c_void,
+        }
+    }
+
+    /// Takes ownership of the pointed to [`FFI_ArrowDeviceArrayStream`]
+    ///
+    /// This acts to [move] the data out of `raw_stream`, setting the release callback to NULL
+    ///
+    /// # Safety
+    ///
+    /// * `raw_stream` must be [valid] for reads and writes
+    /// * `raw_stream` must be properly aligned
+    /// * `raw_stream` must point to a properly initialized value of [`FFI_ArrowDeviceArrayStream`]
+    ///
+    /// [move]: https://arrow.apache.org/docs/format/CDataInterface.html#moving-an-array
+    /// [valid]: https://doc.rust-lang.org/std/ptr/index.html#safety
+    pub unsafe fn from_raw(raw_stream: *mut FFI_ArrowDeviceArrayStream) -> Self {
+        std::ptr::replace(raw_stream, Self::empty())
+    }
+
+    /// Creates a new empty [FFI_ArrowDeviceArrayStream]. Used to import from the C Device Data Interface.
+    pub fn empty() -> Self {
+        Self {
+            device_type: DeviceType::Cpu.into(),
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: std::ptr::null_mut(),
+        }
+    }
+
+    /// Returns the [`DeviceType`] of the arrays in this stream
+    pub fn device_type(&self) -> Result<DeviceType> {
+        self.device_type.try_into()
+    }
+}
+
+/// A hook called with the device and event of each array read by an
+/// [`ArrowDeviceArrayStreamReader`], that must block until the event has completed
+pub type SyncHook = Box<dyn Fn(DeviceType, i64, *mut c_void) -> Result<()> + Send>;
+
+/// A `RecordBatchReader` which imports Arrays from `FFI_ArrowDeviceArrayStream`.
+///
+/// Only streams of arrays that are [host accessible](DeviceType::is_host_accessible)
+/// can be read
+pub struct ArrowDeviceArrayStreamReader {
+    stream: FFI_ArrowDeviceArrayStream,
+    schema: SchemaRef,
+    sync: Option<SyncHook>,
+}
+
+impl std::fmt::Debug for ArrowDeviceArrayStreamReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrowDeviceArrayStreamReader")
+            .field("stream", &self.stream)
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+impl ArrowDeviceArrayStreamReader {
+    /// Creates a new `ArrowDeviceArrayStreamReader` from a `FFI_ArrowDeviceArrayStream`.
+    /// This is used to import from the C Device Data Interface.
+    pub fn try_new(mut stream: FFI_ArrowDeviceArrayStream) -> Result<Self> {
+        if stream.release.is_none() {
+            return Err(ArrowError::CDataInterface(
+                "input stream is already released".to_string(),
+            ));
+        }
+
+        let device_type = stream.device_type()?;
+        if !device_type.is_host_accessible() {
+            return Err(ArrowError::CDataInterface(format!(
+                "Cannot import stream of arrays resident on {device_type:?} device, as it is not accessible from the host"
+            )));
+        }
+
+        let mut schema = FFI_ArrowSchema::empty();
+        let ret_code = unsafe { stream.get_schema.unwrap()(&mut stream, &mut schema) };
+        if ret_code != 0 {
+            return Err(ArrowError::CDataInterface(format!(
+                "Cannot get schema from input stream. Error code: {ret_code:?}"
+            )));
+        }
+        let schema = Arc::new(Schema::try_from(&schema)?);
+
+        Ok(Self {
+            stream,
+            schema,
+            sync: None,
+        })
+    }
+
+    /// Creates a new `ArrowDeviceArrayStreamReader` from a raw pointer of `FFI_ArrowDeviceArrayStream`.
+    ///
+    /// # Safety
+    ///
+    /// See [`FFI_ArrowDeviceArrayStream::from_raw`]
+    pub unsafe fn from_raw(raw_stream: *mut FFI_ArrowDeviceArrayStream) -> Result<Self> {
+        Self::try_new(FFI_ArrowDeviceArrayStream::from_raw(raw_stream))
+    }
+
+    /// Sets the hook used to wait on the sync event of each array, if any
+    ///
+    /// Without a hook, reading an array with a sync event returns an error
+    pub fn with_sync_hook(mut self, sync: SyncHook) -> Self {
+        self.sync = Some(sync);
+        self
+    }
+
+    /// Get the last error from `ArrowDeviceArrayStreamReader`
+    fn get_stream_last_error(&mut self) -> Option<String> {
+        let get_last_error = self.stream.get_last_error?;
+
+        let error_str = unsafe { get_last_error(&mut self.stream) };
+        if error_str.is_null() {
+            return None;
+        }
+
+        let error_str = unsafe { CStr::from_ptr(error_str) };
+        Some(error_str.to_string_lossy().to_string())
+    }
+}
+
+impl Iterator for ArrowDeviceArrayStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut array = FFI_ArrowDeviceArray::empty();
+
+        let ret_code = unsafe { self.stream.get_next.unwrap()(&mut self.stream, &mut array) };
+
+        if ret_code == 0 {
+            // The end of stream has been reached
+            if array.is_released() {
+                return None;
+            }
+
+            let array = match &self.sync {
+                Some(sync) => prepare_import(array, sync),
+                None => prepare_import(array, no_sync),
+            };
+            let result = array.and_then(|array| unsafe {
+                from_ffi_and_data_type(array, DataType::Struct(self.schema.fields().clone()))
+            });
+            Some(result.map(|data| RecordBatch::from(StructArray::from(data))))
+        } else {
+            let last_error = self.get_stream_last_error();
+            let err = ArrowError::CDataInterface(last_error.unwrap_or_default());
+            Some(Err(err))
+        }
+    }
+}
+
+impl RecordBatchReader for ArrowDeviceArrayStreamReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use arrow_schema::Field;
+
+    use crate::array::{Array, Int32Array, StringArray};
+    use crate::{make_array, RecordBatchIterator};
+
+    #[test]
+    fn test_device_type() {
+        for i in 0..20 {
+            match DeviceType::try_from(i) {
+                Ok(device_type) => assert_eq!(i32::from(device_type), i),
+                Err(e) => assert_eq!(
+                    e.to_string(),
+                    format!("C Data interface error: Unknown device type: {i}")
+                ),
+            }
+        }
+        assert!(DeviceType::Cpu.is_host_accessible());
+        assert!(DeviceType::CudaManaged.is_host_accessible());
+        assert!(!DeviceType::Cuda.is_host_accessible());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let array = StringArray::from(vec![Some("a"), None, Some("ccc")]);
+        let (device_array, schema) = to_ffi_device(&array.to_data()).unwrap();
+        assert_eq!(device_array.device_type().unwrap(), DeviceType::Cpu);
+        assert_eq!(device_array.device_id(), -1);
+        assert!(device_array.sync_event().is_none());
+
+        let data = unsafe { from_ffi_device(device_array, &schema) }.unwrap();
+        assert_eq!(make_array(data).as_ref(), &array as &dyn Array);
+    }
+
+    #[test]
+    fn test_from_raw() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let (device_array, schema) = to_ffi_device(&array.to_data()).unwrap();
+
+        let raw = Box::into_raw(Box::new(device_array));
+        let device_array = unsafe { FFI_ArrowDeviceArray::from_raw(raw) };
+        let released = unsafe { Box::from_raw(raw) };
+        assert!(released.is_released());
+
+        let data = unsafe { from_ffi_device(device_array, &schema) }.unwrap();
+        assert_eq!(make_array(data).as_ref(), &array as &dyn Array);
+    }
+
+    #[test]
+    fn test_device_not_accessible() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let (array, schema) = crate::ffi::to_ffi(&array.to_data()).unwrap();
+        let device_array =
+            FFI_ArrowDeviceArray::new(array, DeviceType::Cuda, 0, std::ptr::null_mut());
+        assert_eq!(device_array.array.len(), 3);
+
+        let err = unsafe { from_ffi_device(device_array, &schema) }.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Cannot import array resident on Cuda device, as it is not accessible from the host"
+        );
+    }
+
+    #[test]
+    fn test_sync_event() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let mut event = 42_u32;
+        let event_ptr = &mut event as *mut u32 as *mut c_void;
+
+        let (ffi_array, schema) = crate::ffi::to_ffi(&array.to_data()).unwrap();
+        let device_array = FFI_ArrowDeviceArray::new(ffi_array, DeviceType::CudaHost, 1, event_ptr);
+        let err = unsafe { from_ffi_device(device_array, &schema) }.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Cannot wait on sync event for CudaHost device without a sync hook"
+        );
+
+        let (ffi_array, schema) = crate::ffi::to_ffi(&array.to_data()).unwrap();
+        let device_array = FFI_ArrowDeviceArray::new(ffi_array, DeviceType::CudaHost, 1, event_ptr);
+        let mut waited = None;
+        let data = unsafe {
+            from_ffi_device_with_sync(device_array, &schema, |device_type, device_id, event| {
+                waited = Some((device_type, device_id, *(event as *const u32)));
+                Ok(())
+            })
+        }
+        .unwrap();
+        assert_eq!(waited, Some((DeviceType::CudaHost, 1, 42)));
+        assert_eq!(make_array(data).as_ref(), &array as &dyn Array);
+    }
+
+    fn test_batches() -> (SchemaRef, Vec<RecordBatch>) {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batches = (0..3)
+            .map(|i| {
+                let array = Int32Array::from(vec![Some(i), None]);
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+            })
+            .collect();
+        (schema, batches)
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        let (schema, batches) = test_batches();
+        let reader = RecordBatchIterator::new(batches.clone().into_iter().map(Ok), schema.clone());
+
+        let stream = FFI_ArrowDeviceArrayStream::new(Box::new(reader));
+        assert_eq!(stream.device_type().unwrap(), DeviceType::Cpu);
+
+        let reader = ArrowDeviceArrayStreamReader::try_new(stream).unwrap();
+        assert_eq!(reader.schema(), schema);
+        let read: Vec<_> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let (schema, _) = test_batches();
+        let batches = vec![Err(ArrowError::ComputeError("oops".to_string()))];
+        let reader = RecordBatchIterator::new(batches, schema);
+
+        let stream = FFI_ArrowDeviceArrayStream::new(Box::new(reader));
+        let mut reader = ArrowDeviceArrayStreamReader::try_new(stream).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Compute error: oops"
+        );
+
+        let (schema, batches) = test_batches();
+        let reader = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+        let mut stream = FFI_ArrowDeviceArrayStream::new(Box::new(reader));
+        stream.device_type = DeviceType::Cuda.into();
+        let err = ArrowDeviceArrayStreamReader::try_new(stream).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Cannot import stream of arrays resident on Cuda device, as it is not accessible from the host"
+        );
+    }
+
+    /// A stream whose arrays all carry a sync event
+    unsafe extern "C" fn get_next_with_event(
+        stream: *mut FFI_ArrowDeviceArrayStream,
+        out: *mut FFI_ArrowDeviceArray,
+    ) -> c_int {
+        let ret_code = get_device_next(stream, out);
+        if ret_code == 0 && !(*out).is_released() {
+            (*out).device_type = DeviceType::CudaManaged.into();
+            (*out).device_id = 3;
+            (*out).sync_event = 1 as *mut c_void;
+        }
+        ret_code
+    }
+
+    #[test]
+    fn test_stream_sync_hook() {
+        let (schema, batches) = test_batches();
+        let reader = RecordBatchIterator::new(batches.clone().into_iter().map(Ok), schema.clone());
+        let mut stream = FFI_ArrowDeviceArrayStream::new(Box::new(reader));
+        stream.device_type = DeviceType::CudaManaged.into();
+        stream.get_next = Some(get_next_with_event);
+
+        let mut reader = ArrowDeviceArrayStreamReader::try_new(stream).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Cannot wait on sync event for CudaManaged device without a sync hook"
+        );
+
+        let waits = Arc::new(AtomicUsize::new(0));
+        let captured = waits.clone();
+        let reader = reader.with_sync_hook(Box::new(move |device_type, device_id, _| {
+            assert_eq!((device_type, device_id), (DeviceType::CudaManaged, 3));
+            captured.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }));
+        let read: Vec<_> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(read, batches[1..]);
+        assert_eq!(waits.load(Ordering::Relaxed), 2);
+    }
+}
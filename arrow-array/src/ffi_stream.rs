@@ -71,6 +71,12 @@ use crate::array::StructArray;
 use crate::ffi::from_ffi_and_data_type;
 use crate::record_batch::{RecordBatch, RecordBatchReader};
 
+#[cfg(feature = "ffi_async")]
+mod async_stream;
+
+#[cfg(feature = "ffi_async")]
+pub use async_stream::*;
+
 type Result<T> = std::result::Result<T, ArrowError>;
 
 const ENOMEM: i32 = 12;
@@ -0,0 +1,838 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bindings to the [Async C Stream Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html#async-device-stream-interface)
+//!
+//! Unlike the [C Stream Interface](super), in which the consumer pulls arrays by
+//! calling `get_next`, the producer pushes data to a handler supplied by the consumer,
+//! as it is requested, and so neither side blocks a thread waiting on the other.
+//!
+//! An async [`Stream`] of [`RecordBatch`] can be exported to a handler with
+//! [`export_async_stream`], and an [`ArrowAsyncDeviceStreamReader`] provides a handler
+//! that can be passed to a producer, whose data is then read as a [`Stream`].
+
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use futures::{ready, Stream, StreamExt};
+
+use arrow_schema::ffi::FFI_ArrowSchema;
+use arrow_schema::{ArrowError, DataType, Schema, SchemaRef};
+
+use super::{get_error_code, Result, EINVAL};
+use crate::array::{Array, StructArray};
+use crate::ffi::from_ffi_and_data_type;
+use crate::ffi_device::{no_sync, prepare_import, DeviceType, FFI_ArrowDeviceArray, SyncHook};
+use crate::record_batch::RecordBatch;
+
+const ECANCELED: i32 = 125;
+
+/// ABI-compatible struct for `ArrowAsyncTask` from the Async C Stream Interface
+///
+/// Consumers must call `extract_data` exactly once for each task, which also
+/// performs any cleanup of the task
+#[repr(C)]
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub struct FFI_ArrowAsyncTask {
+    /// C function to move the data of the task into the provided array
+    pub extract_data:
+        Option<unsafe extern "C" fn(arg1: *mut Self, out: *mut FFI_ArrowDeviceArray) -> c_int>,
+    /// Private data used by the task
+    pub private_data: *mut c_void,
+}
+
+unsafe impl Send for FFI_ArrowAsyncTask {}
+
+/// ABI-compatible struct for `ArrowAsyncProducer` from the Async C Stream Interface
+///
+/// The producer is owned by the producing side of the stream, and remains valid until
+/// it calls the `release` callback of the [`FFI_ArrowAsyncDeviceStreamHandler`]
+#[repr(C)]
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub struct FFI_ArrowAsyncProducer {
+    /// The [`DeviceType`] of all arrays produced, as its C representation
+    pub device_type: i32,
+    /// C function to request that the producer push `n` more tasks
+    pub request: Option<unsafe extern "C" fn(arg1: *mut Self, n: i64)>,
+    /// C function to signal that the producer should stop producing tasks
+    pub cancel: Option<unsafe extern "C" fn(arg1: *mut Self)>,
+    /// Optional metadata describing the stream, in the format of `ArrowSchema::metadata`
+    pub additional_metadata: *const c_char,
+    /// Private data used by the producer
+    pub private_data: *mut c_void,
+}
+
+/// ABI-compatible struct for `ArrowAsyncDeviceStreamHandler` from the Async C Stream Interface
+/// See <https://arrow.apache.org/docs/format/CDeviceDataInterface.html#async-device-stream-interface>
+#[repr(C)]
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub struct FFI_ArrowAsyncDeviceStreamHandler {
+    /// C function called with the schema of the stream, before any tasks
+    pub on_schema:
+        Option<unsafe extern "C" fn(arg1: *mut Self, stream_schema: *mut FFI_ArrowSchema) -> c_int>,
+    /// C function called with each task of the stream, and with a null task at the end of the stream
+    pub on_next_task: Option<
+        unsafe extern "C" fn(
+            arg1: *mut Self,
+            task: *mut FFI_ArrowAsyncTask,
+            metadata: *const c_char,
+        ) -> c_int,
+    >,
+    /// C function called if the stream encounters an error
+    pub on_error: Option<
+        unsafe extern "C" fn(
+            arg1: *mut Self,
+            code: c_int,
+            message: *const c_char,
+            metadata: *const c_char,
+        ),
+    >,
+    /// C function called by the producer once it will make no further calls to the handler
+    pub release: Option<unsafe extern "C" fn(arg1: *mut Self)>,
+    /// The producer of the stream, set by the producer before calling `on_schema`
+    pub producer: *mut FFI_ArrowAsyncProducer,
+    /// Private data used by the handler
+    pub private_data: *mut c_void,
+}
+
+unsafe impl Send for FFI_ArrowAsyncDeviceStreamHandler {}
+
+impl Drop for FFI_ArrowAsyncDeviceStreamHandler {
+    fn drop(&mut self) {
+        match self.release {
+            None => (),
+            Some(release) => unsafe { release(self) },
+        };
+    }
+}
+
+impl FFI_ArrowAsyncDeviceStreamHandler {
+    /// Takes ownership of the pointed to [`FFI_ArrowAsyncDeviceStreamHandler`]
+    ///
+    /// This acts to [move] the data out of `raw_handler`, setting the release callback to NULL
+    ///
+    /// # Safety
+    ///
+    /// * `raw_handler` must be [valid] for reads and writes
+    /// * `raw_handler` must be properly aligned
+    /// * `raw_handler` must point to a properly initialized value of [`FFI_ArrowAsyncDeviceStreamHandler`]
+    ///
+    /// [move]: https://arrow.apache.org/docs/format/CDataInterface.html#moving-an-array
+    /// [valid]: https://doc.rust-lang.org/std/ptr/index.html#safety
+    pub unsafe fn from_raw(raw_handler: *mut FFI_ArrowAsyncDeviceStreamHandler) -> Self {
+        std::ptr::replace(raw_handler, Self::empty())
+    }
+
+    /// Creates a new empty [`FFI_ArrowAsyncDeviceStreamHandler`]
+    pub fn empty() -> Self {
+        Self {
+            on_schema: None,
+            on_next_task: None,
+            on_error: None,
+            release: None,
+            producer: std::ptr::null_mut(),
+            private_data: std::ptr::null_mut(),
+        }
+    }
+}
+
+/// The state of an exported stream, shared with the [`FFI_ArrowAsyncProducer`] callbacks
+#[derive(Debug, Default)]
+struct ProducerState {
+    /// The number of tasks requested by the consumer and not yet produced
+    requested: i64,
+    /// Whether the consumer has cancelled the stream
+    cancelled: bool,
+    /// Set if `request` was called with a non-positive count
+    invalid_request: Option<i64>,
+    /// The waker of the export future, if waiting on the consumer
+    waker: Option<Waker>,
+}
+
+impl ProducerState {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake()
+        }
+    }
+}
+
+unsafe fn producer_state<'a>(producer: *mut FFI_ArrowAsyncProducer) -> &'a Mutex<ProducerState> {
+    &*((*producer).private_data as *const Mutex<ProducerState>)
+}
+
+// The callback used to request more tasks from an exported stream
+unsafe extern "C" fn producer_request(producer: *mut FFI_ArrowAsyncProducer, n: i64) {
+    let mut state = producer_state(producer).lock().unwrap();
+    match n > 0 {
+        true => state.requested = state.requested.saturating_add(n),
+        false => state.invalid_request = Some(n),
+    }
+    state.wake();
+}
+
+// The callback used to cancel an exported stream
+unsafe extern "C" fn producer_cancel(producer: *mut FFI_ArrowAsyncProducer) {
+    let mut state = producer_state(producer).lock().unwrap();
+    state.cancelled = true;
+    state.wake();
+}
+
+/// Calls the `on_error` callback of `handler` with `err`
+unsafe fn report_error(handler: &mut FFI_ArrowAsyncDeviceStreamHandler, err: &ArrowError) {
+    if let Some(on_error) = handler.on_error {
+        let message = CString::new(err.to_string()).expect("Error string has a null byte in it.");
+        on_error(
+            handler,
+            get_error_code(err),
+            message.as_ptr(),
+            std::ptr::null(),
+        );
+    }
+}
+
+// The callback used to extract the data of an exported task
+unsafe extern "C" fn extract_task_data(
+    task: *mut FFI_ArrowAsyncTask,
+    out: *mut FFI_ArrowDeviceArray,
+) -> c_int {
+    let task = &mut *task;
+    if task.private_data.is_null() {
+        return EINVAL;
+    }
+    let array = Box::from_raw(task.private_data as *mut FFI_ArrowDeviceArray);
+    task.private_data = std::ptr::null_mut();
+    std::ptr::write_unaligned(out, *array);
+    0
+}
+
+/// Exports `stream` to the async C stream `handler`, returning once the stream has
+/// been fully consumed, the consumer has cancelled it, or an error was reported
+///
+/// Batches are only polled from `stream` once requested by the consumer, and are
+/// exported as arrays resident in CPU memory. Any error from `stream` is reported to
+/// the `on_error` callback of `handler`, which is released before returning
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_array::ffi_stream::{export_async_stream, ArrowAsyncDeviceStreamReader};
+/// # use futures::StreamExt;
+/// # futures::executor::block_on(async {
+/// let batch = RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2])) as _)]).unwrap();
+/// let schema = batch.schema();
+/// let stream = futures::stream::iter(vec![Ok(batch.clone())]);
+///
+/// // The handler would usually be provided by another runtime
+/// let (reader, handler) = ArrowAsyncDeviceStreamReader::new(2);
+/// let export = export_async_stream(schema, stream, handler);
+///
+/// let (_, read) = futures::join!(export, reader.collect::<Vec<_>>());
+/// assert_eq!(read.len(), 1);
+/// assert_eq!(read[0].as_ref().unwrap(), &batch);
+/// # })
+/// ```
+pub async fn export_async_stream<S>(
+    schema: SchemaRef,
+    stream: S,
+    handler: FFI_ArrowAsyncDeviceStreamHandler,
+) where
+    S: Stream<Item = Result<RecordBatch>>,
+{
+    let state = Box::new(Mutex::new(ProducerState::default()));
+    let mut producer = Box::new(FFI_ArrowAsyncProducer {
+        device_type: DeviceType::Cpu.into(),
+        request: Some(producer_request),
+        cancel: Some(producer_cancel),
+        additional_metadata: std::ptr::null(),
+        private_data: &*state as *const Mutex<ProducerState> as *mut c_void,
+    });
+    let mut handler = Box::new(handler);
+    handler.producer = &mut *producer;
+
+    export_to_handler(schema, stream, &mut handler, &state).await;
+
+    // Release the handler before the producer it references
+    drop(handler);
+    drop(producer);
+}
+
+async fn export_to_handler<S>(
+    schema: SchemaRef,
+    stream: S,
+    handler: &mut FFI_ArrowAsyncDeviceStreamHandler,
+    state: &Mutex<ProducerState>,
+) where
+    S: Stream<Item = Result<RecordBatch>>,
+{
+    let (on_schema, on_next_task) = match (handler.on_schema, handler.on_next_task) {
+        (Some(on_schema), Some(on_next_task)) => (on_schema, on_next_task),
+        _ => return,
+    };
+
+    let mut ffi_schema = match FFI_ArrowSchema::try_from(schema.as_ref()) {
+        Ok(schema) => schema,
+        Err(err) => return unsafe { report_error(handler, &err) },
+    };
+    // The handler takes ownership of the schema
+    if unsafe { on_schema(handler, &mut ffi_schema) } != 0 {
+        return;
+    }
+
+    futures::pin_mut!(stream);
+    loop {
+        let requested = futures::future::poll_fn(|cx| {
+            let mut state = state.lock().unwrap();
+            if state.cancelled {
+                return Poll::Ready(Ok(false));
+            }
+            if let Some(n) = state.invalid_request {
+                return Poll::Ready(Err(ArrowError::InvalidArgumentError(format!(
+                    "Requested invalid number of tasks: {n}"
+                ))));
+            }
+            if state.requested > 0 {
+                state.requested -= 1;
+                return Poll::Ready(Ok(true));
+            }
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        })
+        .await;
+
+        match requested {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(err) => return unsafe { report_error(handler, &err) },
+        }
+
+        let batch = match stream.next().await {
+            Some(Ok(batch)) => batch,
+            Some(Err(err)) => return unsafe { report_error(handler, &err) },
+            None => {
+                unsafe { on_next_task(handler, std::ptr::null_mut(), std::ptr::null()) };
+                return;
+            }
+        };
+
+        let array = StructArray::from(batch).into_data();
+        let array = FFI_ArrowDeviceArray::new_cpu(crate::ffi::FFI_ArrowArray::new(&array));
+        let mut task = FFI_ArrowAsyncTask {
+            extract_data: Some(extract_task_data),
+            private_data: Box::into_raw(Box::new(array)) as *mut c_void,
+        };
+        if unsafe { on_next_task(handler, &mut task, std::ptr::null()) } != 0 {
+            return;
+        }
+    }
+}
+
+/// The state of an imported stream, shared with the [`FFI_ArrowAsyncDeviceStreamHandler`] callbacks
+#[derive(Debug, Default)]
+struct ConsumerState {
+    /// The schema of the stream, once received
+    schema: Option<SchemaRef>,
+    /// Tasks received but not yet read
+    tasks: VecDeque<FFI_ArrowAsyncTask>,
+    /// An error reported by the producer
+    error: Option<ArrowError>,
+    /// Whether the producer will make no further calls to `on_next_task`
+    finished: bool,
+    /// Whether the reader has been dropped
+    dropped: bool,
+    /// The waker of the reader, if waiting on the producer
+    waker: Option<Waker>,
+}
+
+impl ConsumerState {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake()
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SharedConsumer {
+    state: Mutex<ConsumerState>,
+    /// The producer of the stream, or null if not yet known or released
+    ///
+    /// This is held while calling into the producer, to prevent it being released concurrently
+    producer: Mutex<*mut FFI_ArrowAsyncProducer>,
+}
+
+// The producer pointer is only accessed whilst holding the lock
+unsafe impl Send for SharedConsumer {}
+unsafe impl Sync for SharedConsumer {}
+
+unsafe fn shared_consumer<'a>(
+    handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+) -> &'a SharedConsumer {
+    &*((*handler).private_data as *const SharedConsumer)
+}
+
+/// Calls `extract_data` on `task` and releases the returned data
+unsafe fn discard_task(mut task: FFI_ArrowAsyncTask) {
+    if let Some(extract_data) = task.extract_data {
+        let mut array = FFI_ArrowDeviceArray::empty();
+        extract_data(&mut task, &mut array);
+    }
+}
+
+// The callback used to receive the schema of an imported stream
+unsafe extern "C" fn handler_on_schema(
+    handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+    schema: *mut FFI_ArrowSchema,
+) -> c_int {
+    let shared = shared_consumer(handler);
+    *shared.producer.lock().unwrap() = (*handler).producer;
+
+    let schema = FFI_ArrowSchema::from_raw(schema);
+    let mut state = shared.state.lock().unwrap();
+    let ret_code = match Schema::try_from(&schema) {
+        Ok(schema) => {
+            state.schema = Some(Arc::new(schema));
+            0
+        }
+        Err(err) => {
+            let ret_code = get_error_code(&err);
+            state.error = Some(err);
+            state.finished = true;
+            ret_code
+        }
+    };
+    state.wake();
+    ret_code
+}
+
+// The callback used to receive the next task of an imported stream
+unsafe extern "C" fn handler_on_next_task(
+    handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+    task: *mut FFI_ArrowAsyncTask,
+    _metadata: *const c_char,
+) -> c_int {
+    let shared = shared_consumer(handler);
+    let mut state = shared.state.lock().unwrap();
+    if task.is_null() {
+        state.finished = true;
+        state.wake();
+        return 0;
+    }
+
+    let task = std::ptr::replace(
+        task,
+        FFI_ArrowAsyncTask {
+            extract_data: None,
+            private_data: std::ptr::null_mut(),
+        },
+    );
+    if state.dropped {
+        drop(state);
+        discard_task(task);
+        return ECANCELED;
+    }
+    state.tasks.push_back(task);
+    state.wake();
+    0
+}
+
+// The callback used to receive an error from an imported stream
+unsafe extern "C" fn handler_on_error(
+    handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+    code: c_int,
+    message: *const c_char,
+    _metadata: *const c_char,
+) {
+    let message = match message.is_null() {
+        true => format!("Error code: {code}"),
+        false => std::ffi::CStr::from_ptr(message)
+            .to_string_lossy()
+            .to_string(),
+    };
+
+    let shared = shared_consumer(handler);
+    let mut state = shared.state.lock().unwrap();
+    state.error = Some(ArrowError::CDataInterface(message));
+    state.finished = true;
+    state.wake();
+}
+
+// The callback used to release the handler of an imported stream
+unsafe extern "C" fn handler_release(handler: *mut FFI_ArrowAsyncDeviceStreamHandler) {
+    if handler.is_null() {
+        return;
+    }
+    let handler = &mut *handler;
+    let shared = Arc::from_raw(handler.private_data as *const SharedConsumer);
+    *shared.producer.lock().unwrap() = std::ptr::null_mut();
+
+    let mut state = shared.state.lock().unwrap();
+    if !state.finished {
+        state.error = Some(ArrowError::CDataInterface(
+            "Stream was released before completion".to_string(),
+        ));
+        state.finished = true;
+    }
+    state.wake();
+    drop(state);
+
+    handler.on_schema = None;
+    handler.on_next_task = None;
+    handler.on_error = None;
+    handler.producer = std::ptr::null_mut();
+    handler.release = None;
+}
+
+/// A [`Stream`] of [`RecordBatch`] imported from the Async C Stream Interface
+///
+/// Created along with an [`FFI_ArrowAsyncDeviceStreamHandler`], that should be passed
+/// to the producer of the stream. Only arrays that are
+/// [host accessible](DeviceType::is_host_accessible) can be read.
+///
+/// Dropping the reader before the end of the stream cancels it
+pub struct ArrowAsyncDeviceStreamReader {
+    shared: Arc<SharedConsumer>,
+    /// The number of tasks to keep requested from the producer
+    queue_size: usize,
+    /// Whether the initial request has been made
+    requested: bool,
+    /// Whether the end of the stream, or an error, has been returned
+    done: bool,
+    sync: Option<SyncHook>,
+}
+
+impl std::fmt::Debug for ArrowAsyncDeviceStreamReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrowAsyncDeviceStreamReader")
+            .field("queue_size", &self.queue_size)
+            .field("requested", &self.requested)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl ArrowAsyncDeviceStreamReader {
+    /// Creates a new [`ArrowAsyncDeviceStreamReader`] along with the handler to pass to the producer
+    ///
+    /// The reader will keep up to `queue_size` tasks requested from the producer
+    ///
+    /// # Panics
+    ///
+    /// Panics if `queue_size` is 0
+    pub fn new(queue_size: usize) -> (Self, FFI_ArrowAsyncDeviceStreamHandler) {
+        assert_ne!(queue_size, 0, "queue_size must be greater than 0");
+        let shared = Arc::new(SharedConsumer {
+            state: Mutex::default(),
+            producer: Mutex::new(std::ptr::null_mut()),
+        });
+
+        let handler = FFI_ArrowAsyncDeviceStreamHandler {
+            on_schema: Some(handler_on_schema),
+            on_next_task: Some(handler_on_next_task),
+            on_error: Some(handler_on_error),
+            release: Some(handler_release),
+            producer: std::ptr::null_mut(),
+            private_data: Arc::into_raw(shared.clone()) as *mut c_void,
+        };
+
+        let reader = Self {
+            shared,
+            queue_size,
+            requested: false,
+            done: false,
+            sync: None,
+        };
+        (reader, handler)
+    }
+
+    /// Sets the hook used to wait on the sync event of each array, if any
+    ///
+    /// Without a hook, reading an array with a sync event returns an error
+    pub fn with_sync_hook(mut self, sync: SyncHook) -> Self {
+        self.sync = Some(sync);
+        self
+    }
+
+    /// Returns the schema of the stream, waiting for the producer to provide it
+    pub async fn schema(&mut self) -> Result<SchemaRef> {
+        futures::future::poll_fn(|cx| self.poll_schema(cx)).await
+    }
+
+    /// Polls for the schema of the stream
+    pub fn poll_schema(&mut self, cx: &mut Context<'_>) -> Poll<Result<SchemaRef>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if let Some(schema) = &state.schema {
+            return Poll::Ready(Ok(schema.clone()));
+        }
+        if let Some(err) = state.error.take() {
+            return Poll::Ready(Err(err));
+        }
+        if state.finished {
+            return Poll::Ready(Err(ArrowError::CDataInterface(
+                "Stream ended without a schema".to_string(),
+            )));
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Requests `n` more tasks from the producer, if not yet released
+    fn request(&self, n: usize) {
+        let producer = self.shared.producer.lock().unwrap();
+        if producer.is_null() {
+            return;
+        }
+        unsafe {
+            if let Some(request) = (**producer).request {
+                request(*producer, n as i64)
+            }
+        }
+    }
+
+    /// Extracts the data of `task` as a [`RecordBatch`]
+    fn read_task(&self, mut task: FFI_ArrowAsyncTask, schema: &SchemaRef) -> Result<RecordBatch> {
+        let extract_data = task.extract_data.ok_or_else(|| {
+            ArrowError::CDataInterface("Task is missing extract_data callback".to_string())
+        })?;
+
+        let mut array = FFI_ArrowDeviceArray::empty();
+        let ret_code = unsafe { extract_data(&mut task, &mut array) };
+        if ret_code != 0 {
+            return Err(ArrowError::CDataInterface(format!(
+                "Cannot extract data from task. Error code: {ret_code:?}"
+            )));
+        }
+
+        let array = match &self.sync {
+            Some(sync) => prepare_import(array, sync)?,
+            None => prepare_import(array, no_sync)?,
+        };
+        let data_type = DataType::Struct(schema.fields().clone());
+        let data = unsafe { from_ffi_and_data_type(array, data_type) }?;
+        let array = StructArray::from(data);
+        RecordBatch::try_new(schema.clone(), array.columns().to_vec())
+    }
+}
+
+impl Stream for ArrowAsyncDeviceStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let schema = match ready!(this.poll_schema(cx)) {
+            Ok(schema) => schema,
+            Err(err) => {
+                this.done = true;
+                return Poll::Ready(Some(Err(err)));
+            }
+        };
+
+        if !this.requested {
+            this.requested = true;
+            this.request(this.queue_size);
+        }
+
+        let mut state = this.shared.state.lock().unwrap();
+        if let Some(task) = state.tasks.pop_front() {
+            let finished = state.finished;
+            drop(state);
+            if !finished {
+                this.request(1);
+            }
+            return Poll::Ready(Some(this.read_task(task, &schema)));
+        }
+        if let Some(err) = state.error.take() {
+            this.done = true;
+            return Poll::Ready(Some(Err(err)));
+        }
+        if state.finished {
+            this.done = true;
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for ArrowAsyncDeviceStreamReader {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.dropped = true;
+        let finished = state.finished;
+        let tasks = std::mem::take(&mut state.tasks);
+        drop(state);
+
+        for task in tasks {
+            unsafe { discard_task(task) }
+        }
+
+        if !finished {
+            let producer = self.shared.producer.lock().unwrap();
+            if !producer.is_null() {
+                unsafe {
+                    if let Some(cancel) = (**producer).cancel {
+                        cancel(*producer)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_schema::Field;
+    use futures::executor::block_on;
+
+    use crate::array::Int32Array;
+
+    fn test_batches() -> (SchemaRef, Vec<RecordBatch>) {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batches = (0..5)
+            .map(|i| {
+                let array = Int32Array::from(vec![Some(i), None]);
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+            })
+            .collect();
+        (schema, batches)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (schema, batches) = test_batches();
+        for queue_size in [1, 2, 10] {
+            let (mut reader, handler) = ArrowAsyncDeviceStreamReader::new(queue_size);
+            let stream = futures::stream::iter(batches.clone().into_iter().map(Ok));
+            let export = export_async_stream(schema.clone(), stream, handler);
+
+            let read = async {
+                assert_eq!(reader.schema().await.unwrap(), schema);
+                reader.collect::<Vec<_>>().await
+            };
+            let (_, read) = block_on(async { futures::join!(export, read) });
+            let read: Vec<_> = read.into_iter().collect::<Result<_>>().unwrap();
+            assert_eq!(read, batches);
+        }
+    }
+
+    #[test]
+    fn test_backpressure() {
+        let (schema, batches) = test_batches();
+        let polled = Arc::new(Mutex::new(0));
+        let counter = polled.clone();
+        let stream = futures::stream::iter(batches.into_iter().map(Ok)).inspect(move |_| {
+            *counter.lock().unwrap() += 1;
+        });
+
+        let (mut reader, handler) = ArrowAsyncDeviceStreamReader::new(2);
+        let export = export_async_stream(schema, stream, handler);
+        futures::pin_mut!(export);
+
+        block_on(async {
+            // Nothing is produced until requested
+            assert!(futures::poll!(export.as_mut()).is_pending());
+            assert_eq!(*polled.lock().unwrap(), 0);
+
+            // Reading the first batch requests the queue size, and then one more
+            let read = futures::poll!(reader.next());
+            assert!(read.is_pending());
+            assert!(futures::poll!(export.as_mut()).is_pending());
+            assert_eq!(*polled.lock().unwrap(), 2);
+
+            let read = futures::poll!(reader.next());
+            assert!(matches!(read, Poll::Ready(Some(Ok(_)))));
+            assert!(futures::poll!(export.as_mut()).is_pending());
+            assert_eq!(*polled.lock().unwrap(), 3);
+        });
+    }
+
+    #[test]
+    fn test_error() {
+        let (schema, batches) = test_batches();
+        let stream = futures::stream::iter(vec![
+            Ok(batches[0].clone()),
+            Err(ArrowError::ComputeError("oops".to_string())),
+        ]);
+
+        let (reader, handler) = ArrowAsyncDeviceStreamReader::new(4);
+        let export = export_async_stream(schema, stream, handler);
+        let (_, read) = block_on(async { futures::join!(export, reader.collect::<Vec<_>>()) });
+
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0].as_ref().unwrap(), &batches[0]);
+        assert_eq!(
+            read[1].as_ref().unwrap_err().to_string(),
+            "C Data interface error: Compute error: oops"
+        );
+    }
+
+    #[test]
+    fn test_cancel() {
+        let (schema, batches) = test_batches();
+        let stream = futures::stream::iter(batches.clone().into_iter().map(Ok));
+
+        let (mut reader, handler) = ArrowAsyncDeviceStreamReader::new(1);
+        let export = export_async_stream(schema, stream, handler);
+        futures::pin_mut!(export);
+
+        block_on(async {
+            assert!(futures::poll!(export.as_mut()).is_pending());
+            assert!(futures::poll!(reader.next()).is_pending());
+            assert!(futures::poll!(export.as_mut()).is_pending());
+            let read = futures::poll!(reader.next());
+            match read {
+                Poll::Ready(Some(Ok(batch))) => assert_eq!(batch, batches[0]),
+                _ => panic!("expected batch"),
+            }
+
+            // Dropping the reader cancels the export, which releases the handler
+            drop(reader);
+            export.await;
+        });
+    }
+
+    #[test]
+    fn test_released_without_schema() {
+        let (mut reader, handler) = ArrowAsyncDeviceStreamReader::new(1);
+        drop(handler);
+
+        let err = block_on(reader.schema()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Stream was released before completion"
+        );
+        let read = block_on(reader.collect::<Vec<_>>());
+        assert_eq!(read.len(), 1);
+        assert_eq!(
+            read[0].as_ref().unwrap_err().to_string(),
+            "C Data interface error: Stream ended without a schema"
+        );
+    }
+}
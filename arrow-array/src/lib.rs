@@ -161,10 +161,12 @@
 //!     array.as_primitive::<Float32Type>().values()
 //! }
 //! ```
-//! # Alternatives to ChunkedArray Support
+//! # ChunkedArray and its Alternatives
 //!
-//! The Rust implementation does not provide the ChunkedArray abstraction implemented by the Python
-//! and C++ Arrow implementations. The recommended alternative is to use one of the following:
+//! [`ChunkedArray`] provides a simple, eager, version of the ChunkedArray abstraction
+//! implemented by the Python and C++ Arrow implementations, a sequence of arrays of the same
+//! type, supported by chunk-aware kernels such as `filter_chunked` and `take_chunked`.
+//! For most other use-cases the recommended alternative is to use one of the following:
 //! - `Vec<ArrayRef>` a simple, eager version of a `ChunkedArray`
 //! - `impl Iterator<Item=ArrayRef>` a lazy version of a `ChunkedArray`
 //! - `impl Stream<Item=ArrayRef>` a lazy async version of a `ChunkedArray`
@@ -232,6 +234,9 @@ pub use record_batch::{
     RecordBatch, RecordBatchIterator, RecordBatchOptions, RecordBatchReader, RecordBatchWriter,
 };
 
+mod chunked_array;
+pub use chunked_array::ChunkedArray;
+
 mod arithmetic;
 pub use arithmetic::ArrowNativeTypeOp;
 
@@ -244,11 +249,16 @@ pub use scalar::*;
 pub mod builder;
 pub mod cast;
 mod delta;
+pub mod extension;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 #[cfg(feature = "ffi")]
+pub mod ffi_device;
+#[cfg(feature = "ffi")]
 pub mod ffi_stream;
 pub mod iterator;
+pub mod memory;
+pub mod record;
 pub mod run_iterator;
 pub mod temporal_conversions;
 pub mod timezone;
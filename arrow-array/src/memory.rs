@@ -0,0 +1,398 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Memory usage introspection for arrays and [`RecordBatch`]
+//!
+//! [`Array::get_array_memory_size`] sums the capacity of every buffer reachable from
+//! an array, counting a buffer once for every array that references it, and reports
+//! the full allocation even when only a small slice of it is used. The types in this
+//! module instead distinguish between:
+//!
+//! * **retained** bytes: the size of the distinct allocations kept alive, with each
+//!   allocation counted once regardless of how many arrays reference it
+//! * **logical** bytes: the bytes actually referenced by the (possibly sliced) arrays
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Array, ArrayRef, Int32Array};
+//! # use arrow_array::memory::MemoryTracker;
+//! let array = Int32Array::from_iter_values(0..1024);
+//! let slice: ArrayRef = Arc::new(array.slice(0, 256));
+//!
+//! let mut tracker = MemoryTracker::new();
+//! tracker.add_array(&array);
+//! let usage = tracker.add_array(slice.as_ref());
+//!
+//! // The slice keeps no additional memory alive, but references 1 KiB of it
+//! assert_eq!(usage.retained_bytes, 0);
+//! assert_eq!(usage.logical_bytes, 1024);
+//! assert_eq!(tracker.usage().retained_bytes, 4096);
+//! ```
+
+use std::collections::HashSet;
+use std::ops::{Add, AddAssign};
+
+use arrow_buffer::{bit_util, ArrowNativeType, Buffer};
+use arrow_data::ArrayData;
+use arrow_schema::{DataType, UnionMode};
+
+use crate::{Array, RecordBatch};
+
+/// The memory used by one or more arrays, see the [module docs](self)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The size of the distinct allocations kept alive
+    pub retained_bytes: usize,
+    /// The number of bytes referenced by the arrays
+    pub logical_bytes: usize,
+}
+
+impl Add for MemoryUsage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            retained_bytes: self.retained_bytes + rhs.retained_bytes,
+            logical_bytes: self.logical_bytes + rhs.logical_bytes,
+        }
+    }
+}
+
+impl AddAssign for MemoryUsage {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl MemoryUsage {
+    /// Returns the memory used by `array`, counting each allocation it references once
+    pub fn of(array: &dyn Array) -> Self {
+        MemoryTracker::new().add_array(array)
+    }
+}
+
+/// The memory used by a [`RecordBatch`], as returned by [`RecordBatch::memory_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordBatchMemoryReport {
+    /// The memory used by the whole batch
+    pub total: MemoryUsage,
+    /// The name and memory used by each column, in schema order
+    ///
+    /// An allocation shared by several columns is attributed to the retained bytes
+    /// of the first column referencing it, so the retained bytes of the columns sum
+    /// to those of [`Self::total`]
+    pub columns: Vec<(String, MemoryUsage)>,
+}
+
+/// Accumulates the memory used by a set of arrays, counting each allocation once
+///
+/// This is useful for accounting the memory of a cache holding many arrays or
+/// batches, which may share buffers with one another.
+#[derive(Debug, Default)]
+pub struct MemoryTracker {
+    seen: HashSet<usize>,
+    usage: MemoryUsage,
+}
+
+impl MemoryTracker {
+    /// Create a new, empty [`MemoryTracker`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the memory used by everything added to this tracker so far
+    pub fn usage(&self) -> MemoryUsage {
+        self.usage
+    }
+
+    /// Adds `array` to this tracker, returning the memory it uses
+    ///
+    /// The returned retained bytes only include allocations not already referenced
+    /// by a previously added array, whereas the logical bytes are always those
+    /// referenced by `array`.
+    pub fn add_array(&mut self, array: &dyn Array) -> MemoryUsage {
+        let data = array.to_data();
+        let usage = MemoryUsage {
+            retained_bytes: self.retain(&data),
+            logical_bytes: logical_size(&data),
+        };
+        self.usage += usage;
+        usage
+    }
+
+    /// Adds the columns of `batch` to this tracker, returning the memory they use
+    ///
+    /// See [`Self::add_array`] for how shared allocations are attributed
+    pub fn add_batch(&mut self, batch: &RecordBatch) -> RecordBatchMemoryReport {
+        let columns: Vec<_> = batch
+            .schema_ref()
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, column)| (field.name().clone(), self.add_array(column.as_ref())))
+            .collect();
+
+        let total = columns
+            .iter()
+            .fold(MemoryUsage::default(), |acc, (_, usage)| acc + *usage);
+        RecordBatchMemoryReport { total, columns }
+    }
+
+    /// Returns the size of the allocations referenced by `data` not yet seen
+    fn retain(&mut self, data: &ArrayData) -> usize {
+        let buffers = data.buffers().iter();
+        let nulls = data.nulls().map(|n| n.buffer());
+        let retained = buffers
+            .chain(nulls)
+            .map(|buffer| self.retain_buffer(buffer))
+            .sum::<usize>();
+
+        retained
+            + data
+                .child_data()
+                .iter()
+                .map(|child| self.retain(child))
+                .sum::<usize>()
+    }
+
+    fn retain_buffer(&mut self, buffer: &Buffer) -> usize {
+        // Slices of a buffer share the pointer to the start of their allocation
+        match self.seen.insert(buffer.data_ptr().as_ptr() as usize) {
+            true => buffer.capacity(),
+            false => 0,
+        }
+    }
+}
+
+/// Returns the number of bytes referenced by the logical slice described by `data`
+fn logical_size(data: &ArrayData) -> usize {
+    let len = data.len();
+    let nulls = data.nulls().map(|_| bit_util::ceil(len, 8)).unwrap_or(0);
+
+    let values = match data.data_type() {
+        DataType::Null => 0,
+        DataType::Boolean => bit_util::ceil(len, 8),
+        DataType::FixedSizeBinary(size) => len * *size as usize,
+        DataType::Utf8 | DataType::Binary => variable_size::<i32>(data),
+        DataType::LargeUtf8 | DataType::LargeBinary => variable_size::<i64>(data),
+        DataType::Utf8View | DataType::BinaryView => {
+            let inline = len * std::mem::size_of::<u128>();
+            let external = data.buffer::<u128>(0)[..len]
+                .iter()
+                .map(|view| *view as u32 as usize)
+                .filter(|length| *length > 12)
+                .sum::<usize>();
+            inline + external
+        }
+        DataType::List(_) | DataType::Map(_, _) => list_size::<i32>(data),
+        DataType::LargeList(_) => list_size::<i64>(data),
+        DataType::ListView(_) => list_view_size::<i32>(data),
+        DataType::LargeListView(_) => list_view_size::<i64>(data),
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            let child = data.child_data()[0].slice(data.offset() * size, len * size);
+            logical_size(&child)
+        }
+        DataType::Struct(_) => data
+            .child_data()
+            .iter()
+            .map(|child| logical_size(&child.slice(data.offset(), len)))
+            .sum(),
+        DataType::Union(_, mode) => {
+            let offsets = match mode {
+                UnionMode::Dense => len * std::mem::size_of::<i32>(),
+                UnionMode::Sparse => 0,
+            };
+            let children: usize = match mode {
+                UnionMode::Dense => data.child_data().iter().map(logical_size).sum(),
+                UnionMode::Sparse => data
+                    .child_data()
+                    .iter()
+                    .map(|child| logical_size(&child.slice(data.offset(), len)))
+                    .sum(),
+            };
+            len + offsets + children
+        }
+        DataType::Dictionary(key, _) => {
+            // The values may be referenced by any key, so are counted in full
+            let keys = len * key.primitive_width().unwrap();
+            keys + logical_size(&data.child_data()[0])
+        }
+        // Run ends and values are not sliced, so are counted in full
+        DataType::RunEndEncoded(_, _) => data.child_data().iter().map(logical_size).sum(),
+        dt => len * dt.primitive_width().unwrap(),
+    };
+    nulls + values
+}
+
+/// Returns the size of the offsets and referenced values of a variable width array
+fn variable_size<O: ArrowNativeType>(data: &ArrayData) -> usize {
+    if data.is_empty() {
+        return 0;
+    }
+    let offsets = &data.buffer::<O>(0)[..=data.len()];
+    let values = offsets[data.len()].as_usize() - offsets[0].as_usize();
+    std::mem::size_of_val(offsets) + values
+}
+
+/// Returns the size of the offsets and referenced child range of a list array
+fn list_size<O: ArrowNativeType>(data: &ArrayData) -> usize {
+    if data.is_empty() {
+        return 0;
+    }
+    let offsets = &data.buffer::<O>(0)[..=data.len()];
+    let start = offsets[0].as_usize();
+    let end = offsets[data.len()].as_usize();
+    let child = data.child_data()[0].slice(start, end - start);
+    std::mem::size_of_val(offsets) + logical_size(&child)
+}
+
+/// Returns the size of the offsets, sizes and referenced child range of a list view
+/// array, counting the span covering all views as referenced
+fn list_view_size<O: ArrowNativeType>(data: &ArrayData) -> usize {
+    let offsets = &data.buffer::<O>(0)[..data.len()];
+    let sizes = &data.buffer::<O>(1)[..data.len()];
+    let (start, end) = offsets
+        .iter()
+        .zip(sizes)
+        .filter(|(_, size)| size.as_usize() != 0)
+        .map(|(offset, size)| (offset.as_usize(), offset.as_usize() + size.as_usize()))
+        .fold(None, |acc, (s, e)| match acc {
+            None => Some((s, e)),
+            Some((a, b)) => Some((a.min(s), b.max(e))),
+        })
+        .unwrap_or((0, 0));
+    let child = data.child_data()[0].slice(start, end - start);
+    2 * data.len() * std::mem::size_of::<O>() + logical_size(&child)
+}
+
+impl RecordBatch {
+    /// Returns the memory used by this batch, with a breakdown by column
+    ///
+    /// Unlike [`Self::get_array_memory_size`], allocations shared between columns,
+    /// or between several slices of the same array, are counted once, and the
+    /// number of bytes referenced by this batch is reported separately from the size
+    /// of the allocations it keeps alive. See the [module docs](crate::memory).
+    pub fn memory_report(&self) -> RecordBatchMemoryReport {
+        MemoryTracker::new().add_batch(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{ListBuilder, StringViewBuilder};
+    use crate::{ArrayRef, Int32Array, ListArray, StringArray, StringViewArray};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_primitive() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let usage = MemoryUsage::of(&array);
+        assert_eq!(usage.logical_bytes, 4 * 4 + 1);
+        assert_eq!(
+            usage.retained_bytes,
+            array.values().inner().capacity() + array.nulls().unwrap().buffer().capacity()
+        );
+
+        let slice = array.slice(1, 2);
+        let sliced = MemoryUsage::of(&slice);
+        assert_eq!(sliced.logical_bytes, 2 * 4 + 1);
+        assert_eq!(sliced.retained_bytes, usage.retained_bytes);
+    }
+
+    #[test]
+    fn test_string() {
+        let array = StringArray::from(vec!["hello", "arrow", "world"]);
+        let slice = array.slice(1, 1);
+        let usage = MemoryUsage::of(&slice);
+        assert_eq!(usage.logical_bytes, 2 * 4 + 5);
+        assert_eq!(usage.retained_bytes, MemoryUsage::of(&array).retained_bytes);
+    }
+
+    #[test]
+    fn test_string_view() {
+        let mut builder = StringViewBuilder::new();
+        builder.append_value("short");
+        builder.append_value("a string longer than twelve bytes");
+        builder.append_value("another long string value");
+        let array = builder.finish();
+        assert_eq!(MemoryUsage::of(&array).logical_bytes, 3 * 16 + 33 + 25);
+
+        let slice = array.slice(0, 1);
+        let usage = MemoryUsage::of(&slice);
+        assert_eq!(usage.logical_bytes, 16);
+        assert_eq!(usage.retained_bytes, MemoryUsage::of(&array).retained_bytes);
+
+        let array = StringViewArray::from_iter_values(["tiny"]);
+        assert_eq!(MemoryUsage::of(&array).logical_bytes, 16);
+    }
+
+    #[test]
+    fn test_list() {
+        let mut builder = ListBuilder::new(crate::builder::Int32Builder::new());
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        builder.append_value([Some(4)]);
+        builder.append_value([Some(5), Some(6)]);
+        let array: ListArray = builder.finish();
+
+        let slice = array.slice(1, 2);
+        let usage = MemoryUsage::of(&slice);
+        // 3 offsets and the 3 values of the last two lists
+        assert_eq!(usage.logical_bytes, 3 * 4 + 3 * 4);
+        assert_eq!(usage.retained_bytes, MemoryUsage::of(&array).retained_bytes);
+    }
+
+    #[test]
+    fn test_shared_buffers() {
+        let array: ArrayRef = Arc::new(Int32Array::from_iter_values(0..200));
+        let a = array.slice(0, 100);
+        let batch =
+            RecordBatch::try_from_iter([("a", a.clone()), ("b", array.slice(100, 100)), ("c", a)])
+                .unwrap();
+
+        let report = batch.memory_report();
+        let retained = MemoryUsage::of(array.as_ref()).retained_bytes;
+        assert_eq!(report.total.retained_bytes, retained);
+        assert_eq!(report.total.logical_bytes, 3 * 400);
+        assert!(batch.get_array_memory_size() > 3 * retained);
+
+        let columns: Vec<_> = report
+            .columns
+            .iter()
+            .map(|(name, usage)| (name.as_str(), usage.retained_bytes, usage.logical_bytes))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![("a", retained, 400), ("b", 0, 400), ("c", 0, 400)]
+        );
+    }
+
+    #[test]
+    fn test_tracker_across_batches() {
+        let array: ArrayRef = Arc::new(Int32Array::from_iter_values(0..100));
+        let a = RecordBatch::try_from_iter([("a", array.clone())]).unwrap();
+        let b = RecordBatch::try_from_iter([("b", array.slice(0, 50))]).unwrap();
+
+        let mut tracker = MemoryTracker::new();
+        let first = tracker.add_batch(&a);
+        let second = tracker.add_batch(&b);
+        assert_eq!(second.total.retained_bytes, 0);
+        assert_eq!(second.total.logical_bytes, 200);
+        assert_eq!(tracker.usage(), first.total + second.total);
+    }
+}
@@ -0,0 +1,749 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion between Rust structs and [`RecordBatch`]
+//!
+//! [`ArrowField`] maps a Rust type to an Arrow [`DataType`], and is implemented for
+//! primitives, [`String`], [`Option`], [`Vec`] (as a list), the chrono date and time
+//! types, and [`Decimal128`] / [`Decimal256`].
+//!
+//! [`ArrowRecord`] is implemented for structs whose fields all implement [`ArrowField`],
+//! allowing a slice of them to be converted to a [`RecordBatch`] and back. With the
+//! `derive` feature enabled, both traits can be derived with `#[derive(ArrowRecord)]`.
+//!
+//! ```ignore
+//! use arrow::array::record::{ArrowRecord, RecordIterator};
+//!
+//! #[derive(Debug, PartialEq, ArrowRecord)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//!     label: Option<String>,
+//! }
+//!
+//! let points = vec![
+//!     Point { x: 1.0, y: 2.0, label: Some("a".to_string()) },
+//!     Point { x: 3.0, y: 4.0, label: None },
+//! ];
+//!
+//! let batch = RecordBatch::try_from(points.as_slice()).unwrap();
+//! let decoded: Vec<Point> = Vec::try_from(&batch).unwrap();
+//! assert_eq!(points, decoded);
+//! ```
+
+use std::sync::Arc;
+
+use arrow_buffer::{i256, ArrowNativeType, NullBuffer, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType, Field, Fields, Schema, TimeUnit};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+
+use crate::cast::AsArray;
+use crate::temporal_conversions::{time64ns_to_time, timestamp_us_to_datetime};
+use crate::types::*;
+use crate::{
+    Array, ArrayRef, BooleanArray, ListArray, PrimitiveArray, RecordBatch, RecordBatchOptions,
+    StringArray, StructArray,
+};
+
+#[cfg(feature = "derive")]
+pub use arrow_derive::ArrowRecord;
+
+/// Items referenced by the code generated by `#[derive(ArrowRecord)]`
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::{Array, ArrayRef, StructArray};
+    pub use arrow_schema::{ArrowError, DataType, Fields};
+}
+
+/// A Rust type that can be converted to and from an Arrow array
+pub trait ArrowField: Sized {
+    /// Returns the [`DataType`] used to represent this type
+    fn data_type() -> DataType;
+
+    /// Returns true if values of this type may be null
+    fn is_nullable() -> bool {
+        false
+    }
+
+    /// Converts `values` to an array, where `None` is written as a null
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a;
+
+    /// Reads the values of `array`, returning `None` for null slots
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError>;
+}
+
+/// A Rust struct that can be converted to and from a [`RecordBatch`]
+///
+/// Each field of the struct becomes a column of the [`RecordBatch`]. Columns are
+/// looked up by name when decoding, and so may appear in any order.
+pub trait ArrowRecord: ArrowField {
+    /// Returns the fields of this record
+    fn fields() -> Fields;
+
+    /// Returns the [`Schema`] of the [`RecordBatch`] produced for this record
+    fn schema() -> Schema {
+        Schema::new(Self::fields())
+    }
+
+    /// Converts `records` to a [`RecordBatch`]
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch, ArrowError> {
+        let array = Self::to_array(records.iter().map(Some))?;
+        let (fields, columns, _) = array.as_struct().clone().into_parts();
+        let options = RecordBatchOptions::new().with_row_count(Some(records.len()));
+        RecordBatch::try_new_with_options(Arc::new(Schema::new(fields)), columns, &options)
+    }
+
+    /// Reads the rows of `batch` as records
+    fn from_record_batch(batch: &RecordBatch) -> Result<Vec<Self>, ArrowError> {
+        let array = StructArray::from(batch.clone());
+        Self::from_array(&array)?
+            .into_iter()
+            .map(|record| required(Some(record), "record"))
+            .collect()
+    }
+}
+
+impl<T: ArrowRecord> TryFrom<&[T]> for RecordBatch {
+    type Error = ArrowError;
+
+    fn try_from(records: &[T]) -> Result<Self, Self::Error> {
+        T::to_record_batch(records)
+    }
+}
+
+impl<T: ArrowRecord> TryFrom<&RecordBatch> for Vec<T> {
+    type Error = ArrowError;
+
+    fn try_from(batch: &RecordBatch) -> Result<Self, Self::Error> {
+        T::from_record_batch(batch)
+    }
+}
+
+/// An iterator of [`ArrowRecord`] decoded from an iterator of [`RecordBatch`],
+/// such as a [`RecordBatchReader`](crate::RecordBatchReader)
+#[derive(Debug)]
+pub struct RecordIterator<T, I> {
+    batches: I,
+    current: std::vec::IntoIter<T>,
+}
+
+impl<T, I> RecordIterator<T, I>
+where
+    T: ArrowRecord,
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    /// Create a new [`RecordIterator`] decoding the records of `batches`
+    pub fn new(batches: I) -> Self {
+        Self {
+            batches,
+            current: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<T, I> Iterator for RecordIterator<T, I>
+where
+    T: ArrowRecord,
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    type Item = Result<T, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.current.next() {
+                return Some(Ok(record));
+            }
+            match self.batches.next()? {
+                Ok(batch) => match T::from_record_batch(&batch) {
+                    Ok(records) => self.current = records.into_iter(),
+                    Err(e) => return Some(Err(e)),
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Returns a [`Field`] named `name` for values of type `T`
+pub fn field<T: ArrowField>(name: &str) -> Field {
+    Field::new(name, T::data_type(), T::is_nullable())
+}
+
+/// Returns the value of a non-nullable field, or an error if it was null
+pub fn required<T>(value: Option<Option<T>>, name: &str) -> Result<T, ArrowError> {
+    value.flatten().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Found null value for non-nullable {name}"))
+    })
+}
+
+/// Creates a [`StructArray`] of `fields` from `columns`, where rows for which
+/// `valid` is false are null
+pub fn to_struct_array(
+    fields: Fields,
+    columns: Vec<ArrayRef>,
+    valid: impl Iterator<Item = bool>,
+) -> Result<ArrayRef, ArrowError> {
+    let nulls = NullBuffer::from_iter(valid);
+    let len = nulls.len();
+    let nulls = (nulls.null_count() > 0).then_some(nulls);
+    let array = match fields.is_empty() {
+        true => StructArray::new_empty_fields(len, nulls),
+        false => StructArray::try_new(fields, columns, nulls)?,
+    };
+    Ok(Arc::new(array))
+}
+
+/// Returns the column named `name` of the [`StructArray`] `array`
+pub fn struct_column<'a>(array: &'a StructArray, name: &str) -> Result<&'a dyn Array, ArrowError> {
+    array
+        .column_by_name(name)
+        .map(|c| c.as_ref())
+        .ok_or_else(|| ArrowError::SchemaError(format!("Column {name} not found")))
+}
+
+/// Downcasts `array` to `A`, returning an error naming the expected `data_type`
+pub fn downcast_array<'a, A: Array + 'static>(
+    array: &'a dyn Array,
+    data_type: &DataType,
+) -> Result<&'a A, ArrowError> {
+    array.as_any().downcast_ref::<A>().ok_or_else(|| {
+        ArrowError::CastError(format!(
+            "Expected array of {data_type}, got {}",
+            array.data_type()
+        ))
+    })
+}
+
+/// Implements [`ArrowField`] for a native type stored in a [`PrimitiveArray`]
+macro_rules! primitive_field {
+    ($native:ty, $t:ty) => {
+        impl ArrowField for $native {
+            fn data_type() -> DataType {
+                <$t as ArrowPrimitiveType>::DATA_TYPE
+            }
+
+            fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+            where
+                I: Iterator<Item = Option<&'a Self>>,
+                Self: 'a,
+            {
+                let array: PrimitiveArray<$t> = values.map(|v| v.copied()).collect();
+                Ok(Arc::new(array))
+            }
+
+            fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+                let array = downcast_array::<PrimitiveArray<$t>>(array, &Self::data_type())?;
+                Ok(array.iter().collect())
+            }
+        }
+    };
+}
+
+primitive_field!(i8, Int8Type);
+primitive_field!(i16, Int16Type);
+primitive_field!(i32, Int32Type);
+primitive_field!(i64, Int64Type);
+primitive_field!(u8, UInt8Type);
+primitive_field!(u16, UInt16Type);
+primitive_field!(u32, UInt32Type);
+primitive_field!(u64, UInt64Type);
+primitive_field!(f32, Float32Type);
+primitive_field!(f64, Float64Type);
+
+impl ArrowField for bool {
+    fn data_type() -> DataType {
+        DataType::Boolean
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        let array: BooleanArray = values.map(|v| v.copied()).collect();
+        Ok(Arc::new(array))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        let array = downcast_array::<BooleanArray>(array, &Self::data_type())?;
+        Ok(array.iter().collect())
+    }
+}
+
+impl ArrowField for String {
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        let array: StringArray = values.map(|v| v.map(String::as_str)).collect();
+        Ok(Arc::new(array))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        let array = downcast_array::<StringArray>(array, &Self::data_type())?;
+        Ok(array.iter().map(|v| v.map(str::to_string)).collect())
+    }
+}
+
+impl<T: ArrowField> ArrowField for Option<T> {
+    fn data_type() -> DataType {
+        T::data_type()
+    }
+
+    fn is_nullable() -> bool {
+        true
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        T::to_array(values.map(|v| v.and_then(Option::as_ref)))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        Ok(T::from_array(array)?.into_iter().map(Some).collect())
+    }
+}
+
+/// Encodes a [`Vec`] as a [`DataType::List`] of its elements
+impl<T: ArrowField> ArrowField for Vec<T> {
+    fn data_type() -> DataType {
+        let field = Field::new_list_field(T::data_type(), T::is_nullable());
+        DataType::List(Arc::new(field))
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        let values: Vec<_> = values.collect();
+        let offsets = OffsetBuffer::from_lengths(values.iter().map(|v| v.map_or(0, Vec::len)));
+        let nulls = NullBuffer::from_iter(values.iter().map(Option::is_some));
+        let nulls = (nulls.null_count() > 0).then_some(nulls);
+        let items = T::to_array(values.iter().flatten().flat_map(|v| v.iter().map(Some)))?;
+        let field = Arc::new(Field::new_list_field(T::data_type(), T::is_nullable()));
+        Ok(Arc::new(ListArray::try_new(field, offsets, items, nulls)?))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        let array = downcast_array::<ListArray>(array, &Self::data_type())?;
+        let mut items = T::from_array(array.values().as_ref())?.into_iter();
+
+        let mut position = 0;
+        let mut values = Vec::with_capacity(array.len());
+        for (idx, w) in array.value_offsets().windows(2).enumerate() {
+            let (start, end) = (w[0].as_usize(), w[1].as_usize());
+            if start > position {
+                items.nth(start - position - 1);
+            }
+            position = end;
+
+            let list = items.by_ref().take(end - start);
+            if array.is_null(idx) {
+                list.for_each(drop);
+                values.push(None);
+                continue;
+            }
+            let list = list
+                .map(|item| required(Some(item), "list element"))
+                .collect::<Result<_, _>>()?;
+            values.push(Some(list));
+        }
+        Ok(values)
+    }
+}
+
+/// Encodes a [`NaiveDate`] as a [`DataType::Date32`]
+impl ArrowField for NaiveDate {
+    fn data_type() -> DataType {
+        DataType::Date32
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        let values = values.map(|v| v.map(|d| Date32Type::from_naive_date(*d)));
+        Ok(Arc::new(values.collect::<PrimitiveArray<Date32Type>>()))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        let array = downcast_array::<PrimitiveArray<Date32Type>>(array, &Self::data_type())?;
+        Ok(array
+            .iter()
+            .map(|v| v.map(Date32Type::to_naive_date))
+            .collect())
+    }
+}
+
+/// Encodes a [`NaiveTime`] as a [`DataType::Time64`] of nanoseconds
+impl ArrowField for NaiveTime {
+    fn data_type() -> DataType {
+        DataType::Time64(TimeUnit::Nanosecond)
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        let values = values.map(|v| {
+            v.map(|t| t.num_seconds_from_midnight() as i64 * 1_000_000_000 + t.nanosecond() as i64)
+        });
+        Ok(Arc::new(
+            values.collect::<PrimitiveArray<Time64NanosecondType>>(),
+        ))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        let array =
+            downcast_array::<PrimitiveArray<Time64NanosecondType>>(array, &Self::data_type())?;
+        array
+            .iter()
+            .map(|v| v.map(|v| out_of_range(time64ns_to_time(v), v)).transpose())
+            .collect()
+    }
+}
+
+/// Encodes a [`NaiveDateTime`] as a [`DataType::Timestamp`] of microseconds without a timezone
+impl ArrowField for NaiveDateTime {
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Microsecond, None)
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        let values = values.map(|v| v.map(|d| d.and_utc().timestamp_micros()));
+        Ok(Arc::new(
+            values.collect::<PrimitiveArray<TimestampMicrosecondType>>(),
+        ))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        let array =
+            downcast_array::<PrimitiveArray<TimestampMicrosecondType>>(array, &Self::data_type())?;
+        array
+            .iter()
+            .map(|v| {
+                v.map(|v| out_of_range(timestamp_us_to_datetime(v), v))
+                    .transpose()
+            })
+            .collect()
+    }
+}
+
+/// Encodes a [`DateTime<Utc>`] as a [`DataType::Timestamp`] of microseconds in UTC
+impl ArrowField for DateTime<Utc> {
+    fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()))
+    }
+
+    fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+    where
+        I: Iterator<Item = Option<&'a Self>>,
+        Self: 'a,
+    {
+        let values = values.map(|v| v.map(|d| d.timestamp_micros()));
+        let array = values.collect::<PrimitiveArray<TimestampMicrosecondType>>();
+        Ok(Arc::new(array.with_timezone_utc()))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+        let array =
+            downcast_array::<PrimitiveArray<TimestampMicrosecondType>>(array, &Self::data_type())?;
+        array
+            .iter()
+            .map(|v| {
+                v.map(|v| out_of_range(timestamp_us_to_datetime(v), v).map(|d| d.and_utc()))
+                    .transpose()
+            })
+            .collect()
+    }
+}
+
+fn out_of_range<T>(value: Option<T>, raw: i64) -> Result<T, ArrowError> {
+    value.ok_or_else(|| ArrowError::ComputeError(format!("Value {raw} out of range")))
+}
+
+/// A [`DataType::Decimal128`] value with the given `PRECISION` and `SCALE`
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal128<const PRECISION: u8, const SCALE: i8>(pub i128);
+
+/// A [`DataType::Decimal256`] value with the given `PRECISION` and `SCALE`
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal256<const PRECISION: u8, const SCALE: i8>(pub i256);
+
+/// Implements [`ArrowField`] for a decimal wrapper type
+macro_rules! decimal_field {
+    ($wrapper:ident, $t:ty, $data_type:ident) => {
+        impl<const PRECISION: u8, const SCALE: i8> ArrowField for $wrapper<PRECISION, SCALE> {
+            fn data_type() -> DataType {
+                DataType::$data_type(PRECISION, SCALE)
+            }
+
+            fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+            where
+                I: Iterator<Item = Option<&'a Self>>,
+                Self: 'a,
+            {
+                let array: PrimitiveArray<$t> = values.map(|v| v.map(|d| d.0)).collect();
+                Ok(Arc::new(array.with_precision_and_scale(PRECISION, SCALE)?))
+            }
+
+            fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+                let data_type = Self::data_type();
+                let array = downcast_array::<PrimitiveArray<$t>>(array, &data_type)?;
+                if array.data_type() != &data_type {
+                    return Err(ArrowError::CastError(format!(
+                        "Expected array of {data_type}, got {}",
+                        array.data_type()
+                    )));
+                }
+                Ok(array.iter().map(|v| v.map($wrapper)).collect())
+            }
+        }
+    };
+}
+
+decimal_field!(Decimal128, Decimal128Type, Decimal128);
+decimal_field!(Decimal256, Decimal256Type, Decimal256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn round_trip<T: ArrowField + PartialEq + std::fmt::Debug>(values: Vec<Option<T>>) {
+        let array = T::to_array(values.iter().map(Option::as_ref)).unwrap();
+        assert_eq!(array.data_type(), &T::data_type());
+        assert_eq!(array.len(), values.len());
+        assert_eq!(T::from_array(array.as_ref()).unwrap(), values);
+    }
+
+    #[test]
+    fn test_primitive_round_trip() {
+        round_trip(vec![Some(1_i8), None, Some(-3)]);
+        round_trip(vec![Some(1_u64), Some(u64::MAX), None]);
+        round_trip(vec![Some(1.5_f64), None]);
+        round_trip(vec![Some(true), None, Some(false)]);
+        round_trip(vec![Some("a".to_string()), None, Some(String::new())]);
+    }
+
+    #[test]
+    fn test_option_round_trip() {
+        assert!(<Option<i32>>::is_nullable());
+        assert!(!i32::is_nullable());
+        round_trip(vec![Some(Some(1_i32)), Some(None), Some(Some(3))]);
+    }
+
+    #[test]
+    fn test_list_round_trip() {
+        round_trip(vec![
+            Some(vec![1_i32, 2]),
+            None,
+            Some(vec![]),
+            Some(vec![3]),
+        ]);
+        round_trip(vec![
+            Some(vec![Some("a".to_string()), None]),
+            Some(vec![None]),
+            None,
+        ]);
+        round_trip(vec![Some(vec![vec![1_u8], vec![]]), Some(vec![vec![2, 3]])]);
+
+        let expected = DataType::List(Arc::new(Field::new_list_field(DataType::Int32, true)));
+        assert_eq!(<Vec<Option<i32>>>::data_type(), expected);
+    }
+
+    #[test]
+    fn test_sliced_list() {
+        let values = [vec![1_i32], vec![2, 3], vec![4, 5, 6], vec![7]];
+        let array = <Vec<i32>>::to_array(values.iter().map(Some)).unwrap();
+        let sliced = array.slice(1, 2);
+        let decoded = <Vec<i32>>::from_array(sliced.as_ref()).unwrap();
+        assert_eq!(decoded, vec![Some(vec![2, 3]), Some(vec![4, 5, 6])]);
+    }
+
+    #[test]
+    fn test_null_list_element() {
+        let values = [Some(vec![Some(1_i32), None])];
+        let array = <Vec<Option<i32>>>::to_array(values.iter().map(Option::as_ref)).unwrap();
+        let err = <Vec<i32>>::from_array(array.as_ref()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Found null value for non-nullable list element"
+        );
+    }
+
+    #[test]
+    fn test_temporal_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        round_trip(vec![Some(date), None]);
+
+        let time = NaiveTime::from_hms_nano_opt(13, 14, 15, 123_456_789).unwrap();
+        round_trip(vec![None, Some(time)]);
+
+        let datetime = date.and_hms_micro_opt(1, 2, 3, 456_789).unwrap();
+        round_trip(vec![Some(datetime), None]);
+
+        let utc = Utc.from_utc_datetime(&datetime);
+        round_trip(vec![Some(utc)]);
+        assert_eq!(
+            <DateTime<Utc>>::data_type(),
+            DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()))
+        );
+    }
+
+    #[test]
+    fn test_decimal_round_trip() {
+        round_trip(vec![Some(Decimal128::<10, 2>(12345)), None]);
+        round_trip(vec![Some(Decimal256::<40, -2>(i256::from_i128(-7)))]);
+        assert_eq!(
+            <Decimal128<10, 2>>::data_type(),
+            DataType::Decimal128(10, 2)
+        );
+
+        let array = <Decimal128<10, 2>>::to_array([Decimal128(1)].iter().map(Some)).unwrap();
+        let err = <Decimal128<10, 3>>::from_array(array.as_ref()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Expected array of Decimal128(10, 3), got Decimal128(10, 2)"
+        );
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let array = i32::to_array([1].iter().map(Some)).unwrap();
+        let err = String::from_array(array.as_ref()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Expected array of Utf8, got Int32"
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        label: Option<String>,
+    }
+
+    // Equivalent to the implementation generated by `#[derive(ArrowRecord)]`
+    impl ArrowField for Point {
+        fn data_type() -> DataType {
+            DataType::Struct(Self::fields())
+        }
+
+        fn to_array<'a, I>(values: I) -> Result<ArrayRef, ArrowError>
+        where
+            I: Iterator<Item = Option<&'a Self>>,
+        {
+            let values: Vec<_> = values.collect();
+            let columns = vec![
+                i32::to_array(values.iter().map(|v| v.map(|v| &v.x)))?,
+                <Option<String>>::to_array(values.iter().map(|v| v.map(|v| &v.label)))?,
+            ];
+            to_struct_array(Self::fields(), columns, values.iter().map(Option::is_some))
+        }
+
+        fn from_array(array: &dyn Array) -> Result<Vec<Option<Self>>, ArrowError> {
+            let array = downcast_array::<StructArray>(array, &Self::data_type())?;
+            let mut x = i32::from_array(struct_column(array, "x")?)?.into_iter();
+            let mut label =
+                <Option<String>>::from_array(struct_column(array, "label")?)?.into_iter();
+            (0..array.len())
+                .map(|idx| {
+                    let (x, label) = (x.next(), label.next());
+                    if array.is_null(idx) {
+                        return Ok(None);
+                    }
+                    Ok(Some(Self {
+                        x: required(x, "x")?,
+                        label: required(label, "label")?,
+                    }))
+                })
+                .collect()
+        }
+    }
+
+    impl ArrowRecord for Point {
+        fn fields() -> Fields {
+            Fields::from(vec![field::<i32>("x"), field::<Option<String>>("label")])
+        }
+    }
+
+    #[test]
+    fn test_record_batch_round_trip() {
+        let points = vec![
+            Point {
+                x: 1,
+                label: Some("a".to_string()),
+            },
+            Point { x: 2, label: None },
+        ];
+
+        let batch = RecordBatch::try_from(points.as_slice()).unwrap();
+        assert_eq!(batch.schema().as_ref(), &Point::schema());
+        assert_eq!(batch.num_rows(), 2);
+
+        let decoded: Vec<Point> = Vec::try_from(&batch).unwrap();
+        assert_eq!(decoded, points);
+
+        // Columns are matched by name
+        let reordered = batch.project(&[1, 0]).unwrap();
+        assert_eq!(Point::from_record_batch(&reordered).unwrap(), points);
+
+        let missing = batch.project(&[1]).unwrap();
+        let err = Point::from_record_batch(&missing).unwrap_err();
+        assert_eq!(err.to_string(), "Schema error: Column x not found");
+
+        let batches = vec![Ok(batch.clone()), Ok(batch.slice(1, 1)), Ok(batch)];
+        let records = RecordIterator::<Point, _>::new(batches.into_iter())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[2], points[1]);
+    }
+
+    #[test]
+    fn test_nested_struct() {
+        let values = vec![
+            Some(Point { x: 1, label: None }),
+            None,
+            Some(Point {
+                x: 3,
+                label: Some("c".to_string()),
+            }),
+        ];
+        round_trip(values);
+        round_trip(vec![Some(vec![Point { x: 4, label: None }]), Some(vec![])]);
+    }
+}
@@ -18,8 +18,10 @@
 //! A two-dimensional batch of column-oriented data with a defined
 //! [schema](arrow_schema::Schema).
 
-use crate::{new_empty_array, Array, ArrayRef, StructArray};
-use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaBuilder, SchemaRef};
+use crate::cast::AsArray;
+use crate::{make_array, new_empty_array, Array, ArrayRef, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaBuilder, SchemaRef};
 use std::ops::Index;
 use std::sync::Arc;
 
@@ -64,7 +66,7 @@ pub trait RecordBatchWriter {
 /// Support for limited data types is available. The macro will return a compile error if an unsupported data type is used.
 /// Presently supported data types are:
 /// - `Boolean`, `Null`
-/// - `Decimal128`, `Decimal256`
+/// - `Decimal32`, `Decimal64`, `Decimal128`, `Decimal256`
 /// - `Float16`, `Float32`, `Float64`
 /// - `Int8`, `Int16`, `Int32`, `Int64`
 /// - `UInt8`, `UInt16`, `UInt32`, `UInt64`
@@ -106,6 +108,8 @@ macro_rules! create_array {
     (@from DurationMillisecond) => { $crate::DurationMillisecondArray };
     (@from DurationMicrosecond) => { $crate::DurationMicrosecondArray };
     (@from DurationNanosecond) => { $crate::DurationNanosecondArray };
+    (@from Decimal32) => { $crate::Decimal32Array };
+    (@from Decimal64) => { $crate::Decimal64Array };
     (@from Decimal128) => { $crate::Decimal128Array };
     (@from Decimal256) => { $crate::Decimal256Array };
     (@from TimestampSecond) => { $crate::TimestampSecondArray };
@@ -394,6 +398,190 @@ impl RecordBatch {
         )
     }
 
+    /// Projects the schema onto the columns with the specified names, in the order given
+    ///
+    /// Returns an error if there is no column with one of the names
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+    /// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+    ///
+    /// let projected = batch.project_by_name(&["b", "a"]).unwrap();
+    /// assert_eq!(projected.schema().field(0).name(), "b");
+    /// assert_eq!(projected.schema().field(1).name(), "a");
+    /// assert!(batch.project_by_name(&["c"]).is_err());
+    /// ```
+    pub fn project_by_name(&self, names: &[&str]) -> Result<RecordBatch, ArrowError> {
+        let indices = names
+            .iter()
+            .map(|name| self.schema.index_of(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.project(&indices)
+    }
+
+    /// Returns a new [`RecordBatch`] with the columns renamed to `names`, one for each column
+    ///
+    /// The data types, nullability and metadata of the fields, and the metadata of the
+    /// schema, are preserved. Returns an error if the number of names does not match the
+    /// number of columns
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+    /// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+    ///
+    /// let renamed = batch.rename_columns(&["id", "name"]).unwrap();
+    /// assert_eq!(renamed.schema().field(0).name(), "id");
+    /// assert_eq!(renamed.schema().field(1).name(), "name");
+    /// ```
+    pub fn rename_columns(&self, names: &[&str]) -> Result<RecordBatch, ArrowError> {
+        if names.len() != self.columns.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "number of names({}) must match number of columns({})",
+                names.len(),
+                self.columns.len()
+            )));
+        }
+        let fields: Vec<_> = self
+            .schema
+            .fields()
+            .iter()
+            .zip(names)
+            .map(|(field, name)| field.as_ref().clone().with_name(*name))
+            .collect();
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            columns: self.columns.clone(),
+            row_count: self.row_count,
+        })
+    }
+
+    /// Returns a new [`RecordBatch`] with the column at `index` replaced by `array`,
+    /// described by `field`
+    ///
+    /// Returns an error if `index` is out of bounds, or `array` does not match `field` or
+    /// the number of rows of this [`RecordBatch`]
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, Int64Array, RecordBatch};
+    /// # use arrow_schema::{DataType, Field};
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+    ///
+    /// let field = Field::new("a", DataType::Int64, false);
+    /// let batch = batch.set_column(0, field, Arc::new(Int64Array::from(vec![1, 2]))).unwrap();
+    /// assert_eq!(batch.schema().field(0).data_type(), &DataType::Int64);
+    ///
+    /// let field = Field::new("a", DataType::Int64, false);
+    /// let err = batch.set_column(0, field, Arc::new(Int64Array::from(vec![1])));
+    /// assert!(err.is_err());
+    /// ```
+    pub fn set_column(
+        &self,
+        index: usize,
+        field: impl Into<FieldRef>,
+        array: ArrayRef,
+    ) -> Result<RecordBatch, ArrowError> {
+        if index >= self.columns.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "column index {index} out of bounds, number of columns {}",
+                self.columns.len()
+            )));
+        }
+        let mut builder = SchemaBuilder::from(self.schema.as_ref());
+        *builder.field_mut(index) = field.into();
+        let mut columns = self.columns.clone();
+        columns[index] = array;
+
+        RecordBatch::try_new_with_options(
+            Arc::new(builder.finish()),
+            columns,
+            &RecordBatchOptions {
+                match_field_names: true,
+                row_count: Some(self.row_count),
+            },
+        )
+    }
+
+    /// Returns a new [`RecordBatch`] without the columns with the specified names
+    ///
+    /// Returns an error if there is no column with one of the names
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+    /// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+    ///
+    /// let dropped = batch.drop_columns(&["a"]).unwrap();
+    /// assert_eq!(dropped.num_columns(), 1);
+    /// assert_eq!(dropped.schema().field(0).name(), "b");
+    /// ```
+    pub fn drop_columns(&self, names: &[&str]) -> Result<RecordBatch, ArrowError> {
+        let mut drop = vec![false; self.columns.len()];
+        for name in names {
+            drop[self.schema.index_of(name)?] = true;
+        }
+        let indices: Vec<_> = (0..self.columns.len()).filter(|i| !drop[*i]).collect();
+        self.project(&indices)
+    }
+
+    /// Returns a new [`RecordBatch`] with [`DataType::Struct`] columns flattened into
+    /// top-level columns, see [`Schema::normalize`]
+    ///
+    /// The nulls of a flattened struct are merged into those of each of its children.
+    /// Returns an error if a nullable struct with nulls is flattened into a union or
+    /// run-end encoded column, as these cannot store a null buffer
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Float64Array, Int32Array, RecordBatch, StructArray};
+    /// # use arrow_schema::{DataType, Field};
+    /// let point = StructArray::from(vec![
+    ///     (
+    ///         Arc::new(Field::new("x", DataType::Float64, false)),
+    ///         Arc::new(Float64Array::from(vec![1.0, 2.0])) as ArrayRef,
+    ///     ),
+    ///     (
+    ///         Arc::new(Field::new("y", DataType::Float64, false)),
+    ///         Arc::new(Float64Array::from(vec![3.0, 4.0])) as ArrayRef,
+    ///     ),
+    /// ]);
+    /// let id: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let batch = RecordBatch::try_from_iter([("id", id), ("point", Arc::new(point) as _)]).unwrap();
+    ///
+    /// let normalized = batch.normalize(".", None).unwrap();
+    /// let names: Vec<_> = normalized.schema().fields().iter().map(|f| f.name().clone()).collect();
+    /// assert_eq!(names, vec!["id", "point.x", "point.y"]);
+    /// ```
+    ///
+    /// [`Schema::normalize`]: arrow_schema::Schema::normalize
+    pub fn normalize(&self, separator: &str, max_depth: Option<usize>) -> Result<Self, ArrowError> {
+        let schema = self.schema.normalize(separator, max_depth);
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for column in &self.columns {
+            normalize_column(column, None, max_depth, &mut columns)?;
+        }
+
+        RecordBatch::try_new_with_options(
+            Arc::new(schema),
+            columns,
+            &RecordBatchOptions {
+                match_field_names: true,
+                row_count: Some(self.row_count),
+            },
+        )
+    }
+
     /// Returns the number of columns in the record batch.
     ///
     /// # Example
@@ -622,6 +810,41 @@ impl RecordBatch {
     }
 }
 
+/// Appends `array` to `columns`, flattening it into its children if it is a struct and
+/// `max_depth` allows, with the nulls of its parents given by `parent_nulls`
+fn normalize_column(
+    array: &ArrayRef,
+    parent_nulls: Option<&NullBuffer>,
+    max_depth: Option<usize>,
+    columns: &mut Vec<ArrayRef>,
+) -> Result<(), ArrowError> {
+    let nulls = match parent_nulls {
+        Some(parent_nulls) => NullBuffer::union(Some(parent_nulls), array.nulls())
+            .filter(|n| n.null_count() > 0 || array.nulls().is_some()),
+        None => array.nulls().cloned(),
+    };
+    match array.data_type() {
+        DataType::Struct(fields) if !fields.is_empty() && max_depth != Some(0) => {
+            for column in array.as_struct().columns() {
+                let max_depth = max_depth.map(|d| d - 1);
+                normalize_column(column, nulls.as_ref(), max_depth, columns)?;
+            }
+        }
+        _ if nulls.as_ref() == array.nulls() => columns.push(array.clone()),
+        DataType::Null => columns.push(array.clone()),
+        d @ (DataType::Union(_, _) | DataType::RunEndEncoded(_, _)) => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Cannot normalize a struct with nulls into a column of type {d}"
+            )))
+        }
+        _ => {
+            let data = array.to_data().into_builder().nulls(nulls).build()?;
+            columns.push(make_array(data));
+        }
+    }
+    Ok(())
+}
+
 /// Options that control the behaviour used when creating a [`RecordBatch`].
 #[derive(Debug)]
 #[non_exhaustive]
@@ -1213,6 +1436,183 @@ mod tests {
         assert_eq!(expected, record_batch.project(&[0, 2]).unwrap());
     }
 
+    fn column_batch() -> RecordBatch {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let c: ArrayRef = Arc::new(StringArray::from(vec!["d", "e", "f"]));
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false)
+                .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+            Field::new("c", DataType::Utf8, false),
+        ])
+        .with_metadata(HashMap::from([("foo".to_string(), "bar".to_string())]));
+        RecordBatch::try_new(Arc::new(schema), vec![a, b, c]).unwrap()
+    }
+
+    #[test]
+    fn project_by_name() {
+        let batch = column_batch();
+        let projected = batch.project_by_name(&["c", "a"]).unwrap();
+        assert_eq!(projected, batch.project(&[2, 0]).unwrap());
+        assert_eq!(projected.schema().metadata(), batch.schema().metadata());
+
+        let err = batch.project_by_name(&["a", "d"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Unable to get field named \"d\". Valid fields: [\"a\", \"b\", \"c\"]"
+        );
+    }
+
+    #[test]
+    fn rename_columns() {
+        let batch = column_batch();
+        let renamed = batch.rename_columns(&["x", "y", "z"]).unwrap();
+        let schema = renamed.schema();
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, vec!["x", "y", "z"]);
+        assert_eq!(schema.metadata(), batch.schema().metadata());
+        assert_eq!(
+            schema.field(1).metadata(),
+            batch.schema().field(1).metadata()
+        );
+        assert!(!schema.field(1).is_nullable());
+        assert_eq!(renamed.columns(), batch.columns());
+        assert_eq!(renamed.num_rows(), 3);
+
+        let err = batch.rename_columns(&["x"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: number of names(1) must match number of columns(3)"
+        );
+    }
+
+    #[test]
+    fn set_column() {
+        let batch = column_batch();
+        let d: ArrayRef = Arc::new(Int64Array::from(vec![4, 5, 6]));
+        let field = Field::new("d", DataType::Int64, false);
+        let updated = batch.set_column(1, field.clone(), d.clone()).unwrap();
+        assert_eq!(updated.schema().field(1), &field);
+        assert_eq!(updated.column(1), &d);
+        assert_eq!(updated.column(0), batch.column(0));
+        assert_eq!(updated.schema().metadata(), batch.schema().metadata());
+
+        let err = batch.set_column(3, field.clone(), d.clone()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: column index 3 out of bounds, number of columns 3"
+        );
+
+        let short: ArrayRef = Arc::new(Int64Array::from(vec![4, 5]));
+        let err = batch.set_column(1, field.clone(), short).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all columns in a record batch must have the specified row count"
+        );
+
+        let err = batch
+            .set_column(1, Field::new("d", DataType::Int32, false), d)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: column types must match schema types, expected Int32 but found Int64 at column index 1"
+        );
+
+        let nulls: ArrayRef = Arc::new(Int64Array::from(vec![Some(4), None, Some(6)]));
+        let err = batch.set_column(0, field, nulls).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Column 'd' is declared as non-nullable but contains null values"
+        );
+    }
+
+    #[test]
+    fn drop_columns() {
+        let batch = column_batch();
+        let dropped = batch.drop_columns(&["b", "a", "b"]).unwrap();
+        assert_eq!(dropped, batch.project(&[2]).unwrap());
+
+        let dropped = batch.drop_columns(&["a", "b", "c"]).unwrap();
+        assert_eq!(dropped.num_columns(), 0);
+        assert_eq!(dropped.num_rows(), 3);
+
+        assert!(batch.drop_columns(&["d"]).is_err());
+    }
+
+    #[test]
+    fn normalize() {
+        let inner = StructArray::from(vec![
+            (
+                Arc::new(Field::new("c", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("d", DataType::Utf8, true)),
+                Arc::new(StringArray::from(vec![
+                    Some("w"),
+                    None,
+                    Some("y"),
+                    Some("z"),
+                ])) as ArrayRef,
+            ),
+        ]);
+        let inner_field = Field::new("b", inner.data_type().clone(), true);
+        let (fields, columns, _) = inner.into_parts();
+        let inner = StructArray::new(fields, columns, Some(vec![true, true, false, true].into()));
+
+        let outer = StructArray::new(
+            vec![
+                inner_field.clone(),
+                Field::new("e", DataType::Boolean, true),
+            ]
+            .into(),
+            vec![
+                Arc::new(inner),
+                Arc::new(BooleanArray::from(vec![true, false, true, false])),
+            ],
+            Some(vec![true, true, true, false].into()),
+        );
+        let id: ArrayRef = Arc::new(Int64Array::from(vec![10, 20, 30, 40]));
+        let batch = RecordBatch::try_from_iter([("id", id.clone()), ("a", Arc::new(outer) as _)])
+            .unwrap()
+            .slice(1, 3);
+
+        let normalized = batch.normalize(".", None).unwrap();
+        let expected = RecordBatch::try_from_iter_with_nullable([
+            ("id", id.slice(1, 3), false),
+            (
+                "a.b.c",
+                Arc::new(Int32Array::from(vec![Some(2), None, None])) as _,
+                true,
+            ),
+            (
+                "a.b.d",
+                Arc::new(StringArray::from(vec![None::<&str>, None, None])) as _,
+                true,
+            ),
+            (
+                "a.e",
+                Arc::new(BooleanArray::from(vec![Some(false), Some(true), None])) as _,
+                true,
+            ),
+        ])
+        .unwrap();
+        assert_eq!(normalized, expected);
+
+        let normalized = batch.normalize("_", Some(1)).unwrap();
+        let schema = normalized.schema();
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, vec!["id", "a_b", "a_e"]);
+        let b = normalized.column(1).as_struct();
+        assert_eq!(
+            b.nulls().unwrap().iter().collect::<Vec<_>>(),
+            vec![true, false, false]
+        );
+
+        assert_eq!(batch.normalize(".", Some(0)).unwrap(), batch);
+    }
+
     #[test]
     fn project_empty() {
         let c: ArrayRef = Arc::new(StringArray::from(vec!["d", "e", "f"]));
@@ -19,6 +19,7 @@
 
 use crate::{array::ArrayAccessor, types::RunEndIndexType, Array, TypedRunArray};
 use arrow_buffer::ArrowNativeType;
+use std::ops::Range;
 
 /// The [`RunArrayIter`] provides an idiomatic way to iterate over the run array.
 /// It returns Some(T) if there is a value or None if the value is null.
@@ -170,6 +171,95 @@ where
 {
 }
 
+/// An iterator over the runs of a [`TypedRunArray`], returned by [`TypedRunArray::runs`]
+///
+/// Each run is returned as the range of logical indices it covers, and its value or
+/// `None` if the run is null. Unlike [`RunArrayIter`], this does not expand the runs,
+/// and so takes time proportional to the number of runs rather than the logical length
+#[derive(Debug)]
+pub struct RunsIter<'a, R, V>
+where
+    R: RunEndIndexType,
+    V: Sync + Send,
+    &'a V: ArrayAccessor,
+    <&'a V as ArrayAccessor>::Item: Default,
+{
+    array: TypedRunArray<'a, R, V>,
+    current_logical: usize,
+    current_physical: usize,
+    end_physical: usize,
+}
+
+impl<'a, R, V> RunsIter<'a, R, V>
+where
+    R: RunEndIndexType,
+    V: Sync + Send,
+    &'a V: ArrayAccessor,
+    <&'a V as ArrayAccessor>::Item: Default,
+{
+    /// create a new iterator
+    pub fn new(array: TypedRunArray<'a, R, V>) -> Self {
+        let run_array = array.run_array();
+        let end_physical = match run_array.is_empty() {
+            true => 0,
+            false => run_array.get_end_physical_index() + 1,
+        };
+        Self {
+            array,
+            current_logical: 0,
+            current_physical: run_array.get_start_physical_index(),
+            end_physical,
+        }
+    }
+}
+
+impl<'a, R, V> Iterator for RunsIter<'a, R, V>
+where
+    R: RunEndIndexType,
+    V: Sync + Send,
+    &'a V: ArrayAccessor,
+    <&'a V as ArrayAccessor>::Item: Default,
+{
+    type Item = (Range<usize>, Option<<&'a V as ArrayAccessor>::Item>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_physical == self.end_physical {
+            return None;
+        }
+        let physical = self.current_physical;
+        let run_end = self.array.run_ends().values()[physical].as_usize() - self.array.offset();
+        let start = self.current_logical;
+        let end = run_end.min(self.array.len());
+        self.current_logical = end;
+        self.current_physical += 1;
+
+        let values = self.array.values();
+        let value = match values.is_null(physical) {
+            true => None,
+            // Safety:
+            // The physical index is bounded by the end physical index of the run array,
+            // which is less than the length of the values array
+            false => Some(unsafe { values.value_unchecked(physical) }),
+        };
+        Some((start..end, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end_physical - self.current_physical;
+        (len, Some(len))
+    }
+}
+
+impl<'a, R, V> ExactSizeIterator for RunsIter<'a, R, V>
+where
+    R: RunEndIndexType,
+    V: Sync + Send,
+    &'a V: ArrayAccessor,
+    <&'a V as ArrayAccessor>::Item: Default,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use rand::{seq::SliceRandom, thread_rng, Rng};
@@ -381,4 +471,34 @@ mod tests {
             assert_eq!(expected, actual);
         }
     }
+
+    #[test]
+    fn test_runs_iterator() {
+        let input_array = build_input_array(40);
+        let mut builder = PrimitiveRunBuilder::<Int16Type, Int32Type>::new();
+        builder.extend(input_array.iter().copied());
+        let run_array = builder.finish();
+
+        for offset in 0..input_array.len() {
+            for len in 0..=input_array.len() - offset {
+                let sliced = run_array.slice(offset, len);
+                let typed = sliced.downcast::<Int32Array>().unwrap();
+                let runs = typed.runs();
+                assert_eq!(runs.len(), typed.runs().count());
+
+                let mut expected_start = 0;
+                let mut expanded = vec![];
+                for (range, value) in runs {
+                    assert_eq!(range.start, expected_start);
+                    assert!(!range.is_empty());
+                    expected_start = range.end;
+                    expanded.extend(range.map(|_| value));
+                }
+                assert_eq!(expanded, &input_array[offset..offset + len]);
+            }
+        }
+
+        let empty = run_array.slice(3, 0);
+        assert_eq!(empty.downcast::<Int32Array>().unwrap().runs().count(), 0);
+    }
 }
@@ -15,7 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::Array;
+use crate::types::*;
+use crate::{
+    new_null_array, Array, ArrayRef, ArrowPrimitiveType, BinaryArray, BooleanArray,
+    GenericByteArray, PrimitiveArray, StringArray,
+};
+use arrow_schema::DataType;
+use half::f16;
 
 /// A possibly [`Scalar`] [`Array`]
 ///
@@ -121,6 +127,10 @@ impl Datum for &dyn Array {
 /// // create a scalar from an ArrayRef (for dynamic typed Arrays)
 /// let array: ArrayRef = get_array();
 /// let scalar = Scalar::new(array);
+///
+/// // Create a scalar from a Rust value
+/// let scalar = Scalar::from(42_i32);
+/// assert_eq!(scalar.value(), Some(42));
 /// ```
 ///
 /// [compute]: https://docs.rs/arrow/latest/arrow/compute/index.html
@@ -143,6 +153,162 @@ impl<T: Array> Scalar<T> {
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Returns the inner array
+    #[inline]
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns true if the value of this [`Scalar`] is null
+    pub fn is_null(&self) -> bool {
+        self.0.is_null(0)
+    }
+}
+
+impl Scalar<ArrayRef> {
+    /// Create a null [`Scalar`] of `data_type`
+    ///
+    /// ```
+    /// # use arrow_array::Scalar;
+    /// # use arrow_schema::DataType;
+    /// let scalar = Scalar::null(&DataType::Utf8);
+    /// assert!(scalar.is_null());
+    /// assert_eq!(scalar.inner().data_type(), &DataType::Utf8);
+    /// ```
+    pub fn null(data_type: &DataType) -> Self {
+        Self(new_null_array(data_type, 1))
+    }
+
+    /// Downcasts this [`Scalar`] to a [`Scalar`] of the concrete array type `A`,
+    /// returning `None` if the inner array is not of type `A`
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, Scalar};
+    /// let scalar = Scalar::new(Arc::new(Int32Array::from(vec![42])) as ArrayRef);
+    /// let typed = scalar.downcast::<Int32Array>().unwrap();
+    /// assert_eq!(typed.value(), Some(42));
+    /// ```
+    pub fn downcast<A: Array + Clone + 'static>(&self) -> Option<Scalar<A>> {
+        let array = self.0.as_any().downcast_ref::<A>()?;
+        Some(Scalar(array.clone()))
+    }
+}
+
+impl<T: ArrowPrimitiveType> Scalar<PrimitiveArray<T>> {
+    /// Returns the value of this [`Scalar`], or `None` if it is null
+    pub fn value(&self) -> Option<T::Native> {
+        self.0.is_valid(0).then(|| self.0.value(0))
+    }
+}
+
+impl Scalar<BooleanArray> {
+    /// Returns the value of this [`Scalar`], or `None` if it is null
+    pub fn value(&self) -> Option<bool> {
+        self.0.is_valid(0).then(|| self.0.value(0))
+    }
+}
+
+impl<T: ByteArrayType> Scalar<GenericByteArray<T>> {
+    /// Returns the value of this [`Scalar`], or `None` if it is null
+    pub fn value(&self) -> Option<&T::Native> {
+        self.0.is_valid(0).then(|| self.0.value(0))
+    }
+}
+
+impl Scalar<StringArray> {
+    /// Create a [`Scalar`] of [`DataType::Utf8`] from `value`
+    pub fn utf8(value: impl AsRef<str>) -> Self {
+        StringArray::new_scalar(value)
+    }
+}
+
+impl Scalar<BinaryArray> {
+    /// Create a [`Scalar`] of [`DataType::Binary`] from `value`
+    pub fn binary(value: impl AsRef<[u8]>) -> Self {
+        BinaryArray::new_scalar(value)
+    }
+}
+
+macro_rules! scalar_from_native {
+    ($($native:ty => $t:ty),*) => {
+        $(
+            impl From<$native> for Scalar<PrimitiveArray<$t>> {
+                fn from(value: $native) -> Self {
+                    PrimitiveArray::new_scalar(value)
+                }
+            }
+
+            impl From<Option<$native>> for Scalar<PrimitiveArray<$t>> {
+                fn from(value: Option<$native>) -> Self {
+                    Self(PrimitiveArray::from(vec![value]))
+                }
+            }
+        )*
+    };
+}
+
+scalar_from_native!(
+    i8 => Int8Type,
+    i16 => Int16Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+    u8 => UInt8Type,
+    u16 => UInt16Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+    f16 => Float16Type,
+    f32 => Float32Type,
+    f64 => Float64Type
+);
+
+impl From<bool> for Scalar<BooleanArray> {
+    fn from(value: bool) -> Self {
+        BooleanArray::new_scalar(value)
+    }
+}
+
+impl From<Option<bool>> for Scalar<BooleanArray> {
+    fn from(value: Option<bool>) -> Self {
+        Self(BooleanArray::from(vec![value]))
+    }
+}
+
+impl From<&str> for Scalar<StringArray> {
+    fn from(value: &str) -> Self {
+        Self::utf8(value)
+    }
+}
+
+impl From<String> for Scalar<StringArray> {
+    fn from(value: String) -> Self {
+        Self::utf8(value)
+    }
+}
+
+impl From<Option<&str>> for Scalar<StringArray> {
+    fn from(value: Option<&str>) -> Self {
+        Self(StringArray::from(vec![value]))
+    }
+}
+
+impl From<&[u8]> for Scalar<BinaryArray> {
+    fn from(value: &[u8]) -> Self {
+        Self::binary(value)
+    }
+}
+
+impl From<Vec<u8>> for Scalar<BinaryArray> {
+    fn from(value: Vec<u8>) -> Self {
+        Self::binary(value)
+    }
+}
+
+impl From<Option<&[u8]>> for Scalar<BinaryArray> {
+    fn from(value: Option<&[u8]>) -> Self {
+        Self(BinaryArray::from(vec![value]))
+    }
 }
 
 impl<T: Array> Datum for Scalar<T> {
@@ -150,3 +316,59 @@ impl<T: Array> Datum for Scalar<T> {
         (&self.0, true)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Int32Array, Int64Array};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_scalar_from_native() {
+        let scalar = Scalar::from(42_i64);
+        assert_eq!(scalar.inner().data_type(), &DataType::Int64);
+        assert_eq!(scalar.value(), Some(42));
+        assert!(!scalar.is_null());
+
+        let scalar = Scalar::from(f16::from_f32(1.5));
+        assert_eq!(scalar.inner().data_type(), &DataType::Float16);
+        assert_eq!(scalar.value(), Some(f16::from_f32(1.5)));
+
+        let scalar = Scalar::from(None::<u8>);
+        assert_eq!(scalar.inner().data_type(), &DataType::UInt8);
+        assert_eq!(scalar.value(), None);
+        assert!(scalar.is_null());
+
+        let scalar = Scalar::from(true);
+        assert_eq!(scalar.value(), Some(true));
+        let scalar = Scalar::from(None::<bool>);
+        assert_eq!(scalar.value(), None);
+
+        let scalar = Scalar::utf8("x");
+        assert_eq!(scalar.inner().data_type(), &DataType::Utf8);
+        assert_eq!(scalar.value(), Some("x"));
+        assert_eq!(Scalar::from("x".to_string()).value(), Some("x"));
+        assert_eq!(Scalar::from(None::<&str>).value(), None);
+
+        let scalar = Scalar::from(b"ab".as_slice());
+        assert_eq!(scalar.inner().data_type(), &DataType::Binary);
+        assert_eq!(scalar.value(), Some(b"ab".as_slice()));
+        assert_eq!(Scalar::binary([1_u8]).value(), Some([1_u8].as_slice()));
+        assert_eq!(Scalar::from(vec![2_u8]).value(), Some([2_u8].as_slice()));
+        assert_eq!(Scalar::from(None::<&[u8]>).value(), None);
+    }
+
+    #[test]
+    fn test_scalar_null_and_downcast() {
+        let scalar = Scalar::null(&DataType::Int32);
+        assert!(scalar.is_null());
+        assert_eq!(scalar.downcast::<Int32Array>().unwrap().value(), None);
+        assert!(scalar.downcast::<Int64Array>().is_none());
+
+        let scalar = Scalar::new(Arc::new(StringArray::from(vec!["a"])) as ArrayRef);
+        let (array, is_scalar) = scalar.get();
+        assert!(is_scalar);
+        assert_eq!(array.len(), 1);
+        assert_eq!(scalar.downcast::<StringArray>().unwrap().value(), Some("a"));
+    }
+}
@@ -17,7 +17,8 @@
 
 use crate::schema::{Attributes, ComplexType, PrimitiveType, Record, Schema, TypeName};
 use arrow_schema::{
-    ArrowError, DataType, Field, FieldRef, IntervalUnit, SchemaBuilder, SchemaRef, TimeUnit,
+    ArrowError, DataType, Field, FieldRef, Fields, IntervalUnit, SchemaBuilder, SchemaRef,
+    TimeUnit, UnionFields, UnionMode, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
 };
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -48,7 +49,9 @@ impl AvroDataType {
     /// Returns an arrow [`Field`] with the given name
     pub fn field_with_name(&self, name: &str) -> Field {
         let d = self.codec.data_type();
-        Field::new(name, d, self.nullability.is_some()).with_metadata(self.metadata.clone())
+        // The null type, for example the null variant of a union, is always nullable
+        let nullable = self.nullability.is_some() || matches!(self.codec, Codec::Null);
+        Field::new(name, d, nullable).with_metadata(self.metadata.clone())
     }
 
     pub fn codec(&self) -> &Codec {
@@ -124,13 +127,23 @@ pub enum Codec {
     /// TimestampMicros(is_utc)
     TimestampMicros(bool),
     Fixed(i32),
+    /// Decimal(precision, scale, fixed size), encoded as fixed or, if the
+    /// size is `None`, bytes
+    Decimal(usize, usize, Option<usize>),
+    /// A UUID encoded as a string
+    Uuid,
+    /// An enumeration with the given symbols
+    Enum(Arc<[String]>),
     List(Arc<AvroDataType>),
+    Map(Arc<AvroDataType>),
     Struct(Arc<[AvroField]>),
+    /// A union of the given variants, named after their type
+    Union(Arc<[AvroField]>),
     Interval,
 }
 
 impl Codec {
-    fn data_type(&self) -> DataType {
+    pub(crate) fn data_type(&self) -> DataType {
         match self {
             Self::Null => DataType::Null,
             Self::Boolean => DataType::Boolean,
@@ -151,14 +164,39 @@ impl Codec {
             }
             Self::Interval => DataType::Interval(IntervalUnit::MonthDayNano),
             Self::Fixed(size) => DataType::FixedSizeBinary(*size),
+            Self::Decimal(precision, scale, _) => {
+                let (precision, scale) = (*precision as u8, *scale as i8);
+                match precision <= DECIMAL128_MAX_PRECISION {
+                    true => DataType::Decimal128(precision, scale),
+                    false => DataType::Decimal256(precision, scale),
+                }
+            }
+            Self::Uuid => DataType::FixedSizeBinary(16),
+            Self::Enum(_) => {
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+            }
             Self::List(f) => {
                 DataType::List(Arc::new(f.field_with_name(Field::LIST_FIELD_DEFAULT_NAME)))
             }
+            Self::Map(value) => DataType::Map(Arc::new(map_entries_field(value)), false),
             Self::Struct(f) => DataType::Struct(f.iter().map(|x| x.field()).collect()),
+            Self::Union(f) => DataType::Union(
+                UnionFields::new(0..f.len() as i8, f.iter().map(|x| x.field())),
+                UnionMode::Dense,
+            ),
         }
     }
 }
 
+/// Returns the entries [`Field`] of the arrow map for an Avro map with the given values
+pub(crate) fn map_entries_field(value: &AvroDataType) -> Field {
+    let entries = Fields::from(vec![
+        Field::new("key", DataType::Utf8, false),
+        value.field_with_name("value"),
+    ]);
+    Field::new("entries", DataType::Struct(entries), false)
+}
+
 impl From<PrimitiveType> for Codec {
     fn from(value: PrimitiveType) -> Self {
         match value {
@@ -233,9 +271,29 @@ fn make_data_type<'a>(
                     field.nullability = Some(Nullability::NullSecond);
                     Ok(field)
                 }
-                _ => Err(ArrowError::NotYetImplemented(format!(
-                    "Union of {f:?} not currently supported"
-                ))),
+                _ => {
+                    if f.len() > i8::MAX as usize {
+                        return Err(ArrowError::ParseError(format!(
+                            "Union with {} variants exceeds the maximum of {}",
+                            f.len(),
+                            i8::MAX
+                        )));
+                    }
+                    let fields = f
+                        .iter()
+                        .map(|variant| {
+                            Ok(AvroField {
+                                name: variant_name(variant)?.to_string(),
+                                data_type: make_data_type(variant, namespace, resolver)?,
+                            })
+                        })
+                        .collect::<Result<_, ArrowError>>()?;
+                    Ok(AvroDataType {
+                        nullability: None,
+                        metadata: Default::default(),
+                        codec: Codec::Union(fields),
+                    })
+                }
             }
         }
         Schema::Complex(c) => match c {
@@ -273,20 +331,43 @@ fn make_data_type<'a>(
                     ArrowError::ParseError(format!("Overflow converting size to i32: {e}"))
                 })?;
 
-                let field = AvroDataType {
+                let mut field = AvroDataType {
                     nullability: None,
                     metadata: f.attributes.field_metadata(),
                     codec: Codec::Fixed(size),
                 };
+                match f.attributes.logical_type {
+                    Some("decimal") => {
+                        let (precision, scale) = decimal_precision_scale(&f.attributes)?;
+                        field.codec = Codec::Decimal(precision, scale, Some(f.size));
+                    }
+                    Some("duration") if f.size == 12 => field.codec = Codec::Interval,
+                    Some(logical) => {
+                        field.metadata.insert("logicalType".into(), logical.into());
+                    }
+                    None => {}
+                }
                 resolver.register(f.name, namespace, field.clone());
                 Ok(field)
             }
-            ComplexType::Enum(e) => Err(ArrowError::NotYetImplemented(format!(
-                "Enum of {e:?} not currently supported"
-            ))),
-            ComplexType::Map(m) => Err(ArrowError::NotYetImplemented(format!(
-                "Map of {m:?} not currently supported"
-            ))),
+            ComplexType::Enum(e) => {
+                let symbols = e.symbols.iter().map(|s| s.to_string()).collect();
+                let field = AvroDataType {
+                    nullability: None,
+                    metadata: e.attributes.field_metadata(),
+                    codec: Codec::Enum(symbols),
+                };
+                resolver.register(e.name, namespace, field.clone());
+                Ok(field)
+            }
+            ComplexType::Map(m) => {
+                let values = make_data_type(m.values.as_ref(), namespace, resolver)?;
+                Ok(AvroDataType {
+                    nullability: None,
+                    metadata: m.attributes.field_metadata(),
+                    codec: Codec::Map(Arc::new(values)),
+                })
+            }
         },
         Schema::Type(t) => {
             let mut field =
@@ -294,10 +375,19 @@ fn make_data_type<'a>(
 
             // https://avro.apache.org/docs/1.11.1/specification/#logical-types
             match (t.attributes.logical_type, &mut field.codec) {
-                (Some("decimal"), c @ Codec::Fixed(_)) => {
-                    return Err(ArrowError::NotYetImplemented(
-                        "Decimals are not currently supported".to_string(),
-                    ))
+                (Some("decimal"), c @ Codec::Fixed(_)) | (Some("decimal"), c @ Codec::Binary) => {
+                    let size = match c {
+                        Codec::Fixed(size) => Some(*size as usize),
+                        _ => None,
+                    };
+                    let (precision, scale) = decimal_precision_scale(&t.attributes)?;
+                    *c = Codec::Decimal(precision, scale, size);
+                }
+                (Some("uuid"), c @ Codec::Utf8) => {
+                    *c = Codec::Uuid;
+                    field
+                        .metadata
+                        .insert("ARROW:extension:name".into(), "arrow.uuid".into());
                 }
                 (Some("date"), c @ Codec::Int32) => *c = Codec::Date32,
                 (Some("time-millis"), c @ Codec::Int32) => *c = Codec::TimeMillis,
@@ -327,3 +417,64 @@ fn make_data_type<'a>(
         }
     }
 }
+
+/// Returns the name of the arrow field for the union variant `schema`
+///
+/// Named types use their name, and other types the name of their type
+fn variant_name<'a>(schema: &Schema<'a>) -> Result<&'a str, ArrowError> {
+    Ok(match schema {
+        Schema::TypeName(TypeName::Primitive(p)) => primitive_name(*p),
+        Schema::TypeName(TypeName::Ref(name)) => name,
+        Schema::Type(t) => match &t.r#type {
+            TypeName::Primitive(p) => primitive_name(*p),
+            TypeName::Ref(name) => name,
+        },
+        Schema::Complex(ComplexType::Record(r)) => r.name,
+        Schema::Complex(ComplexType::Enum(e)) => e.name,
+        Schema::Complex(ComplexType::Fixed(f)) => f.name,
+        Schema::Complex(ComplexType::Array(_)) => "array",
+        Schema::Complex(ComplexType::Map(_)) => "map",
+        Schema::Union(_) => {
+            return Err(ArrowError::ParseError(
+                "Unions may not immediately contain other unions".to_string(),
+            ))
+        }
+    })
+}
+
+fn primitive_name(p: PrimitiveType) -> &'static str {
+    match p {
+        PrimitiveType::Null => "null",
+        PrimitiveType::Boolean => "boolean",
+        PrimitiveType::Int => "int",
+        PrimitiveType::Long => "long",
+        PrimitiveType::Float => "float",
+        PrimitiveType::Double => "double",
+        PrimitiveType::Bytes => "bytes",
+        PrimitiveType::String => "string",
+    }
+}
+
+/// Returns the precision and scale of a decimal logical type
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#decimal>
+fn decimal_precision_scale(attributes: &Attributes<'_>) -> Result<(usize, usize), ArrowError> {
+    let get = |name: &str| -> Result<Option<usize>, ArrowError> {
+        match attributes.additional.get(name) {
+            None => Ok(None),
+            Some(v) => v
+                .as_u64()
+                .map(|v| Some(v as usize))
+                .ok_or_else(|| ArrowError::ParseError(format!("Invalid decimal {name}: {v}"))),
+        }
+    };
+    let precision = get("precision")?
+        .ok_or_else(|| ArrowError::ParseError("Decimal requires a precision".to_string()))?;
+    let scale = get("scale")?.unwrap_or(0);
+    if precision == 0 || precision > DECIMAL256_MAX_PRECISION as usize || scale > precision {
+        return Err(ArrowError::ParseError(format!(
+            "Unsupported decimal precision {precision} and scale {scale}"
+        )));
+    }
+    Ok((precision, scale))
+}
@@ -17,19 +17,73 @@
 
 use arrow_schema::ArrowError;
 use std::io;
-use std::io::Read;
+use std::io::{Read, Write};
 
 /// The metadata key used for storing the JSON encoded [`CompressionCodec`]
 pub const CODEC_METADATA_KEY: &str = "avro.codec";
 
+/// The compression codec used to compress the blocks of an Avro [Object Container File]
+///
+/// [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum CompressionCodec {
+    /// The `deflate` codec, requires the `deflate` feature
     Deflate,
+    /// The `snappy` codec, requires the `snappy` feature
     Snappy,
+    /// The `zstandard` codec, requires the `zstd` feature
     ZStandard,
 }
 
 impl CompressionCodec {
+    /// Returns the name of this codec as stored in [`CODEC_METADATA_KEY`]
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            CompressionCodec::Deflate => "deflate",
+            CompressionCodec::Snappy => "snappy",
+            CompressionCodec::ZStandard => "zstandard",
+        }
+    }
+
+    pub(crate) fn compress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        match self {
+            #[cfg(feature = "deflate")]
+            CompressionCodec::Deflate => {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(block)?;
+                Ok(encoder.finish()?)
+            }
+            #[cfg(not(feature = "deflate"))]
+            CompressionCodec::Deflate => Err(ArrowError::ParseError(
+                "Deflate codec requires deflate feature".to_string(),
+            )),
+            #[cfg(feature = "snappy")]
+            CompressionCodec::Snappy => {
+                let mut encoder = snap::raw::Encoder::new();
+                let mut out = encoder
+                    .compress_vec(block)
+                    .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+
+                // Followed by the 4-byte, big-endian CRC32 checksum of the uncompressed data
+                let checksum = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(block);
+                out.extend_from_slice(&checksum.to_be_bytes());
+                Ok(out)
+            }
+            #[cfg(not(feature = "snappy"))]
+            CompressionCodec::Snappy => Err(ArrowError::ParseError(
+                "Snappy codec requires snappy feature".to_string(),
+            )),
+
+            #[cfg(feature = "zstd")]
+            CompressionCodec::ZStandard => Ok(zstd::encode_all(block, 0)?),
+            #[cfg(not(feature = "zstd"))]
+            CompressionCodec::ZStandard => Err(ArrowError::ParseError(
+                "ZStandard codec requires zstd feature".to_string(),
+            )),
+        }
+    }
+
     pub(crate) fn decompress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
         match self {
             #[cfg(feature = "deflate")]
@@ -45,6 +99,11 @@ impl CompressionCodec {
             )),
             #[cfg(feature = "snappy")]
             CompressionCodec::Snappy => {
+                if block.len() < 4 {
+                    return Err(ArrowError::ParseError(
+                        "Snappy block too short to contain CRC".to_string(),
+                    ));
+                }
                 // Each compressed block is followed by the 4-byte, big-endian CRC32
                 // checksum of the uncompressed data in the block.
                 let crc = &block[block.len() - 4..];
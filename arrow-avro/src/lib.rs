@@ -24,7 +24,11 @@
 #![allow(unused)] // Temporary
 
 pub mod reader;
+
 mod schema;
+pub mod writer;
+
+pub mod message;
 
 mod compression;
 
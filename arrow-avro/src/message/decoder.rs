@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::codec::AvroField;
+use crate::message::{SchemaKey, SchemaResolver};
+use crate::reader::record::RecordDecoder;
+use crate::schema::Schema;
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Decodes Avro messages in the single-object encoding or Confluent wire format
+/// into [`RecordBatch`]
+///
+/// Messages are buffered until [`MessageDecoder::flush`] is called, and the
+/// writer schema of each message is resolved using a [`SchemaResolver`]. As each
+/// [`RecordBatch`] has a single schema, a message with a different writer schema
+/// to the buffered messages is not decoded until the buffered messages are flushed
+///
+/// All errors should be considered fatal, and decoding aborted
+#[derive(Debug)]
+pub struct MessageDecoder {
+    resolver: Arc<dyn SchemaResolver>,
+    batch_size: usize,
+    /// The decoder for each resolved writer schema
+    decoders: HashMap<SchemaKey, RecordDecoder>,
+    /// The writer schema of the buffered messages
+    active: Option<SchemaKey>,
+    /// The number of buffered messages
+    num_rows: usize,
+}
+
+impl MessageDecoder {
+    /// Create a new [`MessageDecoder`] resolving writer schemas with `resolver`
+    pub fn new(resolver: Arc<dyn SchemaResolver>) -> Self {
+        Self {
+            resolver,
+            batch_size: 1024,
+            decoders: HashMap::new(),
+            active: None,
+            num_rows: 0,
+        }
+    }
+
+    /// Set the maximum number of messages buffered before [`Self::decode`] stops
+    /// decoding, defaults to 1024
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Returns the Arrow schema of the buffered messages, if any
+    pub fn schema(&self) -> Option<SchemaRef> {
+        let decoder = self.decoders.get(self.active.as_ref()?)?;
+        Some(decoder.schema().clone())
+    }
+
+    /// Returns the number of buffered messages
+    pub fn len(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns true if there are no buffered messages
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+
+    /// Decode one or more complete, consecutive messages from `buf`, returning
+    /// the number of bytes read
+    ///
+    /// Decoding stops once `batch_size` messages are buffered, or before a message
+    /// with a different writer schema to the buffered messages. The caller should
+    /// then call [`Self::flush`] and continue decoding the remaining bytes
+    pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
+        let mut offset = 0;
+        while offset < buf.len() && self.num_rows < self.batch_size {
+            let (key, prefix) = SchemaKey::from_prefix(&buf[offset..])?;
+            if self.num_rows != 0 && self.active != Some(key) {
+                break;
+            }
+
+            let decoder = self.decoder(key)?;
+            let read = decoder.decode(&buf[offset + prefix..], 1)?;
+            offset += prefix + read;
+            self.active = Some(key);
+            self.num_rows += 1;
+        }
+        Ok(offset)
+    }
+
+    /// Flush the buffered messages to a [`RecordBatch`], returning `None` if there are none
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        if self.num_rows == 0 {
+            return Ok(None);
+        }
+        self.num_rows = 0;
+        let key = self.active.take().unwrap();
+        self.decoders.get_mut(&key).unwrap().flush().map(Some)
+    }
+
+    /// Returns the [`RecordDecoder`] for `key`, resolving its schema if necessary
+    fn decoder(&mut self, key: SchemaKey) -> Result<&mut RecordDecoder, ArrowError> {
+        if !self.decoders.contains_key(&key) {
+            let json = self.resolver.resolve(key)?.ok_or_else(|| {
+                ArrowError::SchemaError(format!("Unknown Avro writer schema {key:?}"))
+            })?;
+            let schema: Schema<'_> = serde_json::from_str(&json).map_err(|e| {
+                ArrowError::ParseError(format!("Failed to parse Avro schema JSON: {e}"))
+            })?;
+            let root = AvroField::try_from(&schema)?;
+            let decoder = RecordDecoder::try_new(root.data_type())?;
+            self.decoders.insert(key, decoder);
+        }
+        Ok(self.decoders.get_mut(&key).unwrap())
+    }
+}
@@ -0,0 +1,95 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::message::{fingerprint, SchemaKey, WireFormat};
+use crate::writer::encoder::FieldEncoder;
+use crate::writer::schema::to_avro_schema;
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+
+/// Encodes the rows of [`RecordBatch`] as Avro messages in the single-object
+/// encoding or Confluent wire format
+#[derive(Debug, Clone)]
+pub struct MessageEncoder {
+    schema: SchemaRef,
+    avro_schema: String,
+    key: SchemaKey,
+}
+
+impl MessageEncoder {
+    /// Create a new [`MessageEncoder`] for [`RecordBatch`] with the given `schema`
+    pub fn try_new(schema: SchemaRef, format: WireFormat) -> Result<Self, ArrowError> {
+        let avro_schema = to_avro_schema(&schema)?.to_string();
+        let key = match format {
+            WireFormat::SingleObject => SchemaKey::Fingerprint(fingerprint(&avro_schema)?),
+            WireFormat::Confluent { schema_id } => SchemaKey::Id(schema_id),
+        };
+        Ok(Self {
+            schema,
+            avro_schema,
+            key,
+        })
+    }
+
+    /// Returns the JSON representation of the Avro writer schema
+    ///
+    /// When using the Confluent wire format, this is the schema that should be
+    /// registered in the schema registry
+    pub fn avro_schema(&self) -> &str {
+        &self.avro_schema
+    }
+
+    /// Returns the [`SchemaKey`] written in the prefix of each message
+    pub fn schema_key(&self) -> SchemaKey {
+        self.key
+    }
+
+    /// Encode each row of `batch` as a separate message
+    pub fn encode(&self, batch: &RecordBatch) -> Result<Vec<Vec<u8>>, ArrowError> {
+        if batch.schema().fields().len() != self.schema.fields().len() {
+            return Err(ArrowError::SchemaError(
+                "RecordBatch schema does not match the schema of the Avro encoder".to_string(),
+            ));
+        }
+
+        let mut encoders = self
+            .schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, array)| {
+                if field.data_type() != array.data_type() {
+                    return Err(ArrowError::SchemaError(
+                        "RecordBatch schema does not match the schema of the Avro encoder"
+                            .to_string(),
+                    ));
+                }
+                FieldEncoder::try_new(field, array.as_ref())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        (0..batch.num_rows())
+            .map(|idx| {
+                let mut out = Vec::with_capacity(32);
+                self.key.write_prefix(&mut out);
+                for encoder in &mut encoders {
+                    encoder.encode(idx, &mut out)?;
+                }
+                Ok(out)
+            })
+            .collect()
+    }
+}
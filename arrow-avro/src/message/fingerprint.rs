@@ -0,0 +1,243 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Avro [Parsing Canonical Form] and schema fingerprints
+//!
+//! [Parsing Canonical Form]: https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas
+
+use arrow_schema::ArrowError;
+use serde_json::Value;
+
+/// Returns the [Parsing Canonical Form] of the JSON Avro schema `schema`
+///
+/// [Parsing Canonical Form]: https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas
+pub fn canonical_form(schema: &str) -> Result<String, ArrowError> {
+    let schema: Value = serde_json::from_str(schema)
+        .map_err(|e| ArrowError::ParseError(format!("Failed to parse Avro schema JSON: {e}")))?;
+    let mut out = String::with_capacity(64);
+    write_canonical(&schema, "", &mut out)?;
+    Ok(out)
+}
+
+/// Returns the 64-bit [Rabin fingerprint] of the [Parsing Canonical Form] of `schema`
+///
+/// This is the fingerprint used by the Avro [single-object encoding]
+///
+/// [Rabin fingerprint]: https://avro.apache.org/docs/1.11.1/specification/#schema-fingerprints
+/// [Parsing Canonical Form]: https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas
+/// [single-object encoding]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+pub fn fingerprint(schema: &str) -> Result<u64, ArrowError> {
+    Ok(rabin(canonical_form(schema)?.as_bytes()))
+}
+
+const PRIMITIVES: [&str; 8] = [
+    "null", "boolean", "int", "long", "float", "double", "bytes", "string",
+];
+
+fn invalid(schema: &Value) -> ArrowError {
+    ArrowError::ParseError(format!("Invalid Avro schema: {schema}"))
+}
+
+/// Returns the full name of `name` within `namespace`
+fn full_name(name: &str, namespace: &str) -> String {
+    match name.contains('.') || namespace.is_empty() {
+        true => name.to_string(),
+        false => format!("{namespace}.{name}"),
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push_str(&Value::from(s).to_string())
+}
+
+/// Writes the canonical form of `schema`, defined within `namespace`, to `out`
+fn write_canonical(schema: &Value, namespace: &str, out: &mut String) -> Result<(), ArrowError> {
+    match schema {
+        Value::String(name) if PRIMITIVES.contains(&name.as_str()) => write_string(name, out),
+        Value::String(name) => write_string(&full_name(name, namespace), out),
+        Value::Array(variants) => {
+            out.push('[');
+            for (idx, variant) in variants.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                write_canonical(variant, namespace, out)?;
+            }
+            out.push(']');
+        }
+        Value::Object(object) => {
+            let type_name = match object.get("type") {
+                Some(Value::String(t)) => t.as_str(),
+                // A type definition such as `{"type": {"type": "array", ...}}`
+                Some(t) => return write_canonical(t, namespace, out),
+                None => return Err(invalid(schema)),
+            };
+            match type_name {
+                "record" | "error" | "enum" | "fixed" => {
+                    let name = object
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| invalid(schema))?;
+                    let namespace = match object.get("namespace").and_then(Value::as_str) {
+                        Some(ns) if !name.contains('.') => ns,
+                        _ => namespace,
+                    };
+                    let name = full_name(name, namespace);
+                    // The namespace of any types defined within this type
+                    let inner = name.rsplit_once('.').map(|(ns, _)| ns).unwrap_or("");
+
+                    out.push_str("{\"name\":");
+                    write_string(&name, out);
+                    out.push_str(",\"type\":");
+                    match type_name {
+                        "enum" => {
+                            out.push_str("\"enum\",\"symbols\":");
+                            let symbols = object.get("symbols").ok_or_else(|| invalid(schema))?;
+                            out.push_str(&symbols.to_string());
+                        }
+                        "fixed" => {
+                            out.push_str("\"fixed\",\"size\":");
+                            let size = object
+                                .get("size")
+                                .and_then(Value::as_u64)
+                                .ok_or_else(|| invalid(schema))?;
+                            out.push_str(&size.to_string());
+                        }
+                        _ => {
+                            out.push_str("\"record\",\"fields\":[");
+                            let fields = object
+                                .get("fields")
+                                .and_then(Value::as_array)
+                                .ok_or_else(|| invalid(schema))?;
+                            for (idx, field) in fields.iter().enumerate() {
+                                if idx != 0 {
+                                    out.push(',');
+                                }
+                                let name = field.get("name").and_then(Value::as_str);
+                                let field_type = field.get("type");
+                                let (name, field_type) = match (name, field_type) {
+                                    (Some(n), Some(t)) => (n, t),
+                                    _ => return Err(invalid(field)),
+                                };
+                                out.push_str("{\"name\":");
+                                write_string(name, out);
+                                out.push_str(",\"type\":");
+                                write_canonical(field_type, inner, out)?;
+                                out.push('}');
+                            }
+                            out.push(']');
+                        }
+                    }
+                    out.push('}');
+                }
+                "array" => {
+                    let items = object.get("items").ok_or_else(|| invalid(schema))?;
+                    out.push_str("{\"type\":\"array\",\"items\":");
+                    write_canonical(items, namespace, out)?;
+                    out.push('}');
+                }
+                "map" => {
+                    let values = object.get("values").ok_or_else(|| invalid(schema))?;
+                    out.push_str("{\"type\":\"map\",\"values\":");
+                    write_canonical(values, namespace, out)?;
+                    out.push('}');
+                }
+                // A primitive or a reference, with attributes such as logicalType
+                t => write_canonical(&Value::from(t), namespace, out)?,
+            }
+        }
+        _ => return Err(invalid(schema)),
+    }
+    Ok(())
+}
+
+/// The empty value of the CRC-64-AVRO fingerprint
+const EMPTY: u64 = 0xc15d213aa4d7a795;
+
+/// The lookup table of the CRC-64-AVRO fingerprint
+const TABLE: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut fp = i as u64;
+        let mut j = 0;
+        while j < 8 {
+            fp = (fp >> 1) ^ (EMPTY & (fp & 1).wrapping_neg());
+            j += 1;
+        }
+        table[i] = fp;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC-64-AVRO Rabin fingerprint of `data`
+fn rabin(data: &[u8]) -> u64 {
+    data.iter().fold(EMPTY, |fp, b| {
+        (fp >> 8) ^ TABLE[((fp ^ *b as u64) & 0xff) as usize]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitive_fingerprints() {
+        // From share/test/data/schema-tests.txt in the Apache Avro repository
+        let cases = [
+            ("\"null\"", 7195948357588979594_u64 as i64),
+            ("{\"type\": \"int\"}", 8247732601305521295),
+            ("\"long\"", -3434872931120570953),
+            ("\"string\"", -8142146995180207161),
+        ];
+        for (schema, expected) in cases {
+            assert_eq!(fingerprint(schema).unwrap() as i64, expected, "{schema}");
+        }
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        let schema = r#"{
+            "type": "record",
+            "name": "Test",
+            "namespace": "org.example",
+            "doc": "a test",
+            "fields": [
+                {"name": "a", "type": {"type": "long", "logicalType": "timestamp-millis"}, "default": 0},
+                {"name": "b", "type": ["null", {"type": "enum", "name": "E", "symbols": ["X", "Y"]}]},
+                {"name": "c", "type": {"type": "fixed", "name": "other.F", "size": 4}},
+                {"name": "d", "type": {"type": "array", "items": "E"}},
+                {"name": "e", "type": {"type": "map", "values": "other.F"}}
+            ]
+        }"#;
+        let expected = concat!(
+            r#"{"name":"org.example.Test","type":"record","fields":["#,
+            r#"{"name":"a","type":"long"},"#,
+            r#"{"name":"b","type":["null",{"name":"org.example.E","type":"enum","symbols":["X","Y"]}]},"#,
+            r#"{"name":"c","type":{"name":"other.F","type":"fixed","size":4}},"#,
+            r#"{"name":"d","type":{"type":"array","items":"org.example.E"}},"#,
+            r#"{"name":"e","type":{"type":"map","values":"other.F"}}]}"#
+        );
+        assert_eq!(canonical_form(schema).unwrap(), expected);
+
+        let err = canonical_form(r#"{"type": "record"}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Parser error: Invalid Avro schema: {"type":"record"}"#
+        );
+    }
+}
@@ -0,0 +1,315 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Avro [single-object encoding] and the Confluent schema registry [wire format]
+//!
+//! Unlike an Object Container File, each message in these formats contains a
+//! single record, prefixed by a key identifying its writer schema. This is
+//! commonly used for the messages of streaming systems such as Apache Kafka
+//!
+//! * The single-object encoding prefixes each record with the bytes `C3 01`, followed
+//!   by the 8-byte little-endian [`fingerprint`] of the writer schema
+//! * The Confluent wire format prefixes each record with the byte `00`, followed by
+//!   the 4-byte big-endian id of the writer schema in the schema registry
+//!
+//! The writer schemas are looked up using a [`SchemaResolver`], such as [`SchemaStore`]
+//!
+//! # Example
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int64Array, RecordBatch};
+//! # use arrow_avro::message::{MessageDecoder, MessageEncoder, SchemaStore, WireFormat};
+//! let batch = RecordBatch::try_from_iter([
+//!     ("id", Arc::new(Int64Array::from(vec![1, 2, 3])) as _),
+//! ])
+//! .unwrap();
+//!
+//! // Encode each row as a separate message
+//! let encoder = MessageEncoder::try_new(batch.schema(), WireFormat::SingleObject).unwrap();
+//! let messages = encoder.encode(&batch).unwrap();
+//! assert_eq!(messages.len(), 3);
+//!
+//! // Register the writer schema, and decode the messages
+//! let mut store = SchemaStore::new();
+//! store.register(encoder.avro_schema()).unwrap();
+//!
+//! let mut decoder = MessageDecoder::new(Arc::new(store));
+//! for message in &messages {
+//!     decoder.decode(message).unwrap();
+//! }
+//! let decoded = decoder.flush().unwrap().unwrap();
+//! assert_eq!(decoded.column(0).as_ref(), batch.column(0).as_ref());
+//! ```
+//!
+//! [single-object encoding]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+//! [wire format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+
+use arrow_schema::ArrowError;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+mod decoder;
+mod encoder;
+mod fingerprint;
+
+pub use decoder::MessageDecoder;
+pub use encoder::MessageEncoder;
+pub use fingerprint::{canonical_form, fingerprint};
+
+/// The magic bytes of the single-object encoding
+const SINGLE_OBJECT_MAGIC: [u8; 2] = [0xC3, 0x01];
+
+/// The magic byte of the Confluent wire format
+const CONFLUENT_MAGIC: u8 = 0x00;
+
+/// Identifies the writer schema of a message
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SchemaKey {
+    /// The [`fingerprint`] of the schema, as used by the single-object encoding
+    Fingerprint(u64),
+    /// The schema registry id of the schema, as used by the Confluent wire format
+    Id(u32),
+}
+
+/// The framing of encoded messages
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WireFormat {
+    /// The Avro single-object encoding
+    SingleObject,
+    /// The Confluent wire format, with the given schema registry id
+    Confluent {
+        /// The id of the writer schema in the schema registry
+        schema_id: u32,
+    },
+}
+
+impl SchemaKey {
+    /// Parses the prefix of `message`, returning the [`SchemaKey`] and the length of the prefix
+    pub fn from_prefix(message: &[u8]) -> Result<(Self, usize), ArrowError> {
+        let truncated = || ArrowError::ParseError("Truncated Avro message prefix".to_string());
+        match message {
+            [0xC3, 0x01, rest @ ..] => {
+                let fingerprint = rest.get(..8).ok_or_else(truncated)?;
+                let fingerprint = u64::from_le_bytes(fingerprint.try_into().unwrap());
+                Ok((Self::Fingerprint(fingerprint), 10))
+            }
+            [CONFLUENT_MAGIC, rest @ ..] => {
+                let id = rest.get(..4).ok_or_else(truncated)?;
+                Ok((Self::Id(u32::from_be_bytes(id.try_into().unwrap())), 5))
+            }
+            [] | [0xC3] => Err(truncated()),
+            [b, ..] => Err(ArrowError::ParseError(format!(
+                "Unrecognized Avro message prefix byte 0x{b:02X}"
+            ))),
+        }
+    }
+
+    /// Writes the message prefix for this [`SchemaKey`] to `out`
+    pub fn write_prefix(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Fingerprint(fingerprint) => {
+                out.extend_from_slice(&SINGLE_OBJECT_MAGIC);
+                out.extend_from_slice(&fingerprint.to_le_bytes());
+            }
+            Self::Id(id) => {
+                out.push(CONFLUENT_MAGIC);
+                out.extend_from_slice(&id.to_be_bytes());
+            }
+        }
+    }
+}
+
+/// Resolves the JSON writer schema of a message from its [`SchemaKey`]
+///
+/// Implementations may, for example, fetch schemas from a schema registry.
+/// [`MessageDecoder`] only resolves each [`SchemaKey`] once
+pub trait SchemaResolver: Debug + Send + Sync {
+    /// Returns the JSON Avro schema identified by `key`, or `None` if it is unknown
+    fn resolve(&self, key: SchemaKey) -> Result<Option<String>, ArrowError>;
+}
+
+/// An in-memory [`SchemaResolver`]
+#[derive(Debug, Default, Clone)]
+pub struct SchemaStore {
+    schemas: HashMap<SchemaKey, String>,
+}
+
+impl SchemaStore {
+    /// Create an empty [`SchemaStore`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `schema` by its [`fingerprint`], returning the fingerprint
+    pub fn register(&mut self, schema: &str) -> Result<u64, ArrowError> {
+        let fingerprint = fingerprint(schema)?;
+        self.schemas
+            .insert(SchemaKey::Fingerprint(fingerprint), schema.to_string());
+        Ok(fingerprint)
+    }
+
+    /// Register `schema` with the schema registry id `id`
+    pub fn register_with_id(&mut self, id: u32, schema: &str) -> Result<(), ArrowError> {
+        // Validate the schema
+        canonical_form(schema)?;
+        self.schemas.insert(SchemaKey::Id(id), schema.to_string());
+        Ok(())
+    }
+}
+
+impl SchemaResolver for SchemaStore {
+    fn resolve(&self, key: SchemaKey) -> Result<Option<String>, ArrowError> {
+        Ok(self.schemas.get(&key).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    use std::sync::Arc;
+
+    fn batch() -> RecordBatch {
+        RecordBatch::try_from_iter_with_nullable([
+            (
+                "id",
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+                false,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])) as ArrayRef,
+                true,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_prefix() {
+        let cases = [
+            (SchemaKey::Fingerprint(0x0102030405060708), 10),
+            (SchemaKey::Id(42), 5),
+        ];
+        for (key, len) in cases {
+            let mut out = Vec::new();
+            key.write_prefix(&mut out);
+            out.push(0xFF);
+            assert_eq!(SchemaKey::from_prefix(&out).unwrap(), (key, len));
+        }
+
+        let mut out = Vec::new();
+        SchemaKey::Id(42).write_prefix(&mut out);
+        assert_eq!(out, [0, 0, 0, 0, 42]);
+
+        for message in [&[][..], &[0xC3], &[0xC3, 0x01, 1, 2], &[0, 1]] {
+            let err = SchemaKey::from_prefix(message).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Parser error: Truncated Avro message prefix"
+            );
+        }
+        let err = SchemaKey::from_prefix(&[0x4F, 0x62]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Unrecognized Avro message prefix byte 0x4F"
+        );
+    }
+
+    #[test]
+    fn test_single_object_roundtrip() {
+        let batch = batch();
+        let encoder = MessageEncoder::try_new(batch.schema(), WireFormat::SingleObject).unwrap();
+        let messages = encoder.encode(&batch).unwrap();
+        assert_eq!(messages.len(), 3);
+
+        let mut store = SchemaStore::new();
+        let fingerprint = store.register(encoder.avro_schema()).unwrap();
+        assert_eq!(encoder.schema_key(), SchemaKey::Fingerprint(fingerprint));
+        assert_eq!(&messages[0][..2], &[0xC3, 0x01]);
+        assert_eq!(&messages[0][2..10], &fingerprint.to_le_bytes());
+
+        // Decode concatenated messages
+        let mut decoder = MessageDecoder::new(Arc::new(store));
+        let buf = messages.concat();
+        assert_eq!(decoder.decode(&buf).unwrap(), buf.len());
+        assert_eq!(decoder.len(), 3);
+        assert_eq!(decoder.schema().unwrap(), batch.schema());
+        assert_eq!(decoder.flush().unwrap().unwrap(), batch);
+        assert!(decoder.flush().unwrap().is_none());
+        assert!(decoder.schema().is_none());
+    }
+
+    #[test]
+    fn test_confluent_batch_size() {
+        let batch = batch();
+        let format = WireFormat::Confluent { schema_id: 7 };
+        let encoder = MessageEncoder::try_new(batch.schema(), format).unwrap();
+        let messages = encoder.encode(&batch).unwrap();
+        assert_eq!(&messages[0][..5], &[0, 0, 0, 0, 7]);
+
+        let mut store = SchemaStore::new();
+        store.register_with_id(7, encoder.avro_schema()).unwrap();
+        let mut decoder = MessageDecoder::new(Arc::new(store)).with_batch_size(2);
+
+        let buf = messages.concat();
+        let read = decoder.decode(&buf).unwrap();
+        assert_eq!(read, messages[0].len() + messages[1].len());
+        assert_eq!(decoder.decode(&buf[read..]).unwrap(), 0);
+        assert_eq!(decoder.flush().unwrap().unwrap(), batch.slice(0, 2));
+        assert_eq!(decoder.decode(&buf[read..]).unwrap(), buf.len() - read);
+        assert_eq!(decoder.flush().unwrap().unwrap(), batch.slice(2, 1));
+    }
+
+    #[test]
+    fn test_schema_change() {
+        let a = batch();
+        let b = RecordBatch::try_from_iter([(
+            "other",
+            Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef,
+        )])
+        .unwrap();
+
+        let mut store = SchemaStore::new();
+        let mut buf = Vec::new();
+        for (batch, id) in [(&a, 1), (&b, 2)] {
+            let format = WireFormat::Confluent { schema_id: id };
+            let encoder = MessageEncoder::try_new(batch.schema(), format).unwrap();
+            store.register_with_id(id, encoder.avro_schema()).unwrap();
+            buf.extend(encoder.encode(batch).unwrap().concat());
+        }
+
+        let mut decoder = MessageDecoder::new(Arc::new(store));
+        let read = decoder.decode(&buf).unwrap();
+        assert_eq!(decoder.flush().unwrap().unwrap(), a);
+        assert_eq!(decoder.decode(&buf[read..]).unwrap(), buf.len() - read);
+        assert_eq!(decoder.flush().unwrap().unwrap(), b);
+    }
+
+    #[test]
+    fn test_unknown_schema() {
+        let mut decoder = MessageDecoder::new(Arc::new(SchemaStore::new()));
+        let err = decoder.decode(&[0, 0, 0, 0, 3, 2]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Unknown Avro writer schema Id(3)"
+        );
+
+        let err = SchemaStore::new().register("{").unwrap_err();
+        assert!(err.to_string().contains("Failed to parse Avro schema JSON"));
+    }
+}
@@ -95,6 +95,18 @@ impl<'a> AvroCursor<'a> {
         Ok(ret)
     }
 
+    /// Read `n` bytes, for example the value of a `fixed` type
+    pub(crate) fn get_fixed(&mut self, n: usize) -> Result<&'a [u8], ArrowError> {
+        if self.buf.len() < n {
+            return Err(ArrowError::ParseError(
+                "Unexpected EOF reading fixed".to_string(),
+            ));
+        }
+        let ret = &self.buf[..n];
+        self.buf = &self.buf[n..];
+        Ok(ret)
+    }
+
     #[inline]
     pub(crate) fn get_float(&mut self) -> Result<f32, ArrowError> {
         if (self.buf.len() < 4) {
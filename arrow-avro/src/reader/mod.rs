@@ -16,10 +16,37 @@
 // under the License.
 
 //! Read Avro data to Arrow
+//!
+//! # Example
+//!
+//! Decoding an Avro [Object Container File] into [`RecordBatch`]es, only reading
+//! the fields named `id` and `name`
+//!
+//! ```no_run
+//! # use std::fs::File;
+//! # use std::io::BufReader;
+//! # use arrow_avro::reader::ReaderBuilder;
+//! let file = BufReader::new(File::open("test/data/users.avro").unwrap());
+//! let reader = ReaderBuilder::new()
+//!     .with_batch_size(1024)
+//!     .with_projection(vec!["id".to_string(), "name".to_string()])
+//!     .build(file)
+//!     .unwrap();
+//!
+//! for batch in reader {
+//!     println!("Read {} rows", batch.unwrap().num_rows());
+//! }
+//! ```
+//!
+//! [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
 
+use crate::codec::AvroField;
+use crate::compression::CompressionCodec;
 use crate::reader::block::{Block, BlockDecoder};
 use crate::reader::header::{Header, HeaderDecoder};
-use arrow_schema::ArrowError;
+use crate::reader::record::RecordDecoder;
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
 use std::io::BufRead;
 
 mod header;
@@ -27,7 +54,7 @@ mod header;
 mod block;
 
 mod cursor;
-mod record;
+pub(crate) mod record;
 mod vlq;
 
 /// Read a [`Header`] from the provided [`BufRead`]
@@ -51,69 +78,697 @@ fn read_header<R: BufRead>(mut reader: R) -> Result<Header, ArrowError> {
         .ok_or_else(|| ArrowError::ParseError("Unexpected EOF".to_string()))
 }
 
-/// Return an iterator of [`Block`] from the provided [`BufRead`]
-fn read_blocks<R: BufRead>(mut reader: R) -> impl Iterator<Item = Result<Block, ArrowError>> {
-    let mut decoder = BlockDecoder::default();
+/// A builder for [`Reader`]
+#[derive(Debug, Clone)]
+pub struct ReaderBuilder {
+    batch_size: usize,
+    projection: Option<Vec<String>>,
+}
+
+impl Default for ReaderBuilder {
+    fn default() -> Self {
+        Self {
+            batch_size: 1024,
+            projection: None,
+        }
+    }
+}
+
+impl ReaderBuilder {
+    /// Create a new [`ReaderBuilder`] with the default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of rows in each [`RecordBatch`], defaults to 1024
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Only decode the top-level fields with the given names, in the given order
+    ///
+    /// Fields not named in `projection` are skipped over without being decoded
+    pub fn with_projection(self, projection: Vec<String>) -> Self {
+        Self {
+            projection: Some(projection),
+            ..self
+        }
+    }
+
+    /// Create a [`Reader`] reading from `reader`, reading the file header
+    /// and validating the projection against the writer schema
+    pub fn build<R: BufRead>(self, mut reader: R) -> Result<Reader<R>, ArrowError> {
+        if self.batch_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "Batch size must be greater than 0".to_string(),
+            ));
+        }
+
+        let header = read_header(&mut reader)?;
+        let compression = header.compression()?;
+        let schema = header.schema()?.ok_or_else(|| {
+            ArrowError::ParseError("No Avro schema present in file header".to_string())
+        })?;
+        let root = AvroField::try_from(&schema)?;
+        let decoder =
+            RecordDecoder::try_new_with_projection(root.data_type(), self.projection.as_deref())?;
+
+        Ok(Reader {
+            reader,
+            header,
+            compression,
+            decoder,
+            block_decoder: BlockDecoder::default(),
+            block: None,
+            batch_size: self.batch_size,
+            finished: false,
+        })
+    }
+}
 
-    let mut try_next = move || {
+/// A block of records being decoded by a [`Reader`]
+#[derive(Debug)]
+struct CurrentBlock {
+    /// The uncompressed data of the block
+    data: Vec<u8>,
+    /// The offset into `data` of the next record to decode
+    offset: usize,
+    /// The number of records remaining in `data`
+    remaining: usize,
+}
+
+/// Reads [`RecordBatch`] from an Avro [Object Container File]
+///
+/// Created with [`ReaderBuilder`]
+///
+/// [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+#[derive(Debug)]
+pub struct Reader<R> {
+    reader: R,
+    header: Header,
+    compression: Option<CompressionCodec>,
+    decoder: RecordDecoder,
+    block_decoder: BlockDecoder,
+    block: Option<CurrentBlock>,
+    batch_size: usize,
+    finished: bool,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Returns the Arrow schema of the [`RecordBatch`] produced by this reader
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema().clone()
+    }
+
+    /// Read the next [`Block`] from the underlying reader, if any
+    fn read_block(&mut self) -> Result<Option<Block>, ArrowError> {
+        let mut consumed = 0;
         loop {
-            let buf = reader.fill_buf()?;
+            let buf = self.reader.fill_buf()?;
             if buf.is_empty() {
                 break;
             }
             let read = buf.len();
-            let decoded = decoder.decode(buf)?;
-            reader.consume(decoded);
+            let decoded = self.block_decoder.decode(buf)?;
+            self.reader.consume(decoded);
+            consumed += decoded;
             if decoded != read {
                 break;
             }
         }
-        Ok(decoder.flush())
-    };
-    std::iter::from_fn(move || try_next().transpose())
+
+        match self.block_decoder.flush() {
+            Some(block) => Ok(Some(block)),
+            None if consumed == 0 => Ok(None),
+            None => Err(ArrowError::ParseError(
+                "Unexpected EOF while reading Avro block".to_string(),
+            )),
+        }
+    }
+
+    /// Read and decompress the next block, returning `false` if at the end of the file
+    fn next_block(&mut self) -> Result<bool, ArrowError> {
+        let block = match self.read_block()? {
+            Some(block) => block,
+            None => return Ok(false),
+        };
+        if block.sync != self.header.sync() {
+            return Err(ArrowError::ParseError(
+                "Avro block sync marker does not match file header".to_string(),
+            ));
+        }
+        let data = match self.compression {
+            Some(c) => c.decompress(&block.data)?,
+            None => block.data,
+        };
+        self.block = Some(CurrentBlock {
+            data,
+            offset: 0,
+            remaining: block.count,
+        });
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let mut rows = 0;
+        while rows < self.batch_size {
+            let block = match self.block.as_mut() {
+                Some(block) if block.remaining > 0 => block,
+                _ => match self.next_block()? {
+                    true => continue,
+                    false => break,
+                },
+            };
+
+            let to_read = block.remaining.min(self.batch_size - rows);
+            block.offset += self.decoder.decode(&block.data[block.offset..], to_read)?;
+            block.remaining -= to_read;
+            rows += to_read;
+
+            if block.remaining == 0 && block.offset != block.data.len() {
+                return Err(ArrowError::ParseError(format!(
+                    "Avro block contained {} trailing bytes",
+                    block.data.len() - block.offset
+                )));
+            }
+        }
+
+        match rows {
+            0 => Ok(None),
+            _ => self.decoder.flush().map(Some),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.read().transpose();
+        // Stop after the end of the file or the first error
+        self.finished = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+impl<R: BufRead> RecordBatchReader for Reader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.decoder.schema().clone()
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::codec::AvroField;
-    use crate::compression::CompressionCodec;
-    use crate::reader::record::RecordDecoder;
-    use crate::reader::{read_blocks, read_header};
+    use crate::reader::ReaderBuilder;
     use crate::test_util::arrow_test_data;
+    use arrow_array::types::Int32Type;
     use arrow_array::*;
+    use arrow_buffer::{i256, IntervalMonthDayNano};
+    use arrow_schema::{DataType, Field, UnionFields};
     use std::fs::File;
-    use std::io::BufReader;
+    use std::io::{BufReader, Cursor, Write};
     use std::sync::Arc;
 
-    fn read_file(file: &str, batch_size: usize) -> RecordBatch {
+    fn read_file(file: &str, batch_size: usize, expected: &RecordBatch) {
         let file = File::open(file).unwrap();
-        let mut reader = BufReader::new(file);
-        let header = read_header(&mut reader).unwrap();
-        let compression = header.compression().unwrap();
-        let schema = header.schema().unwrap().unwrap();
-        let root = AvroField::try_from(&schema).unwrap();
-        let mut decoder = RecordDecoder::try_new(root.data_type()).unwrap();
-
-        for result in read_blocks(reader) {
-            let block = result.unwrap();
-            assert_eq!(block.sync, header.sync());
-            if let Some(c) = compression {
-                let decompressed = c.decompress(&block.data).unwrap();
-
-                let mut offset = 0;
-                let mut remaining = block.count;
-                while remaining > 0 {
-                    let to_read = remaining.max(batch_size);
-                    offset += decoder
-                        .decode(&decompressed[offset..], block.count)
-                        .unwrap();
-
-                    remaining -= to_read;
+        let reader = ReaderBuilder::new()
+            .with_batch_size(batch_size)
+            .build(BufReader::new(file))
+            .unwrap();
+        assert_eq!(reader.schema(), expected.schema());
+
+        let mut offset = 0;
+        for batch in reader {
+            let batch = batch.unwrap();
+            assert!(batch.num_rows() <= batch_size);
+            assert_eq!(batch, expected.slice(offset, batch.num_rows()));
+            offset += batch.num_rows();
+        }
+        assert_eq!(offset, expected.num_rows());
+    }
+
+    const SYNC: [u8; 16] = *b"0123456789abcdef";
+
+    fn put_long(out: &mut Vec<u8>, v: i64) {
+        let mut v = ((v << 1) ^ (v >> 63)) as u64;
+        while v >= 0x80 {
+            out.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+    }
+
+    fn put_bytes(out: &mut Vec<u8>, b: &[u8]) {
+        put_long(out, b.len() as i64);
+        out.extend_from_slice(b);
+    }
+
+    fn compress(codec: &str, data: &[u8]) -> Vec<u8> {
+        match codec {
+            "null" => data.to_vec(),
+            #[cfg(feature = "deflate")]
+            "deflate" => {
+                let mut e = flate2::write::DeflateEncoder::new(Vec::new(), Default::default());
+                e.write_all(data).unwrap();
+                e.finish().unwrap()
+            }
+            #[cfg(feature = "snappy")]
+            "snappy" => {
+                let mut out = snap::raw::Encoder::new().compress_vec(data).unwrap();
+                let crc = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(data);
+                out.extend_from_slice(&crc.to_be_bytes());
+                out
+            }
+            #[cfg(feature = "zstd")]
+            "zstandard" => zstd::encode_all(data, 0).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Writes an object container file with the given schema, codec and
+    /// blocks of `(count, encoded records)`
+    fn write_file(schema: &str, codec: &str, blocks: &[(usize, Vec<u8>)]) -> Vec<u8> {
+        let mut out = b"Obj\x01".to_vec();
+        put_long(&mut out, 2);
+        put_bytes(&mut out, b"avro.schema");
+        put_bytes(&mut out, schema.as_bytes());
+        put_bytes(&mut out, b"avro.codec");
+        put_bytes(&mut out, codec.as_bytes());
+        put_long(&mut out, 0);
+        out.extend_from_slice(&SYNC);
+
+        for (count, data) in blocks {
+            let data = compress(codec, data);
+            put_long(&mut out, *count as i64);
+            put_bytes(&mut out, &data);
+            out.extend_from_slice(&SYNC);
+        }
+        out
+    }
+
+    fn read_all(builder: ReaderBuilder, file: Vec<u8>) -> Vec<RecordBatch> {
+        builder
+            .build(Cursor::new(file))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// A record of `(id: int, name: string, score: ["null", "double"])`
+    const SIMPLE_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "simple",
+        "fields": [
+            {"name": "id", "type": "int"},
+            {"name": "name", "type": "string"},
+            {"name": "score", "type": ["null", "double"]}
+        ]
+    }"#;
+
+    fn simple_records(range: std::ops::Range<i64>) -> Vec<u8> {
+        let mut out = Vec::new();
+        for i in range {
+            put_long(&mut out, i);
+            put_bytes(&mut out, format!("name{i}").as_bytes());
+            match i % 2 {
+                0 => put_long(&mut out, 0),
+                _ => {
+                    put_long(&mut out, 1);
+                    out.extend_from_slice(&(i as f64).to_le_bytes());
                 }
-                assert_eq!(offset, decompressed.len());
             }
         }
-        decoder.flush().unwrap()
+        out
+    }
+
+    fn simple_batch(range: std::ops::Range<i64>) -> RecordBatch {
+        RecordBatch::try_from_iter_with_nullable([
+            (
+                "id",
+                Arc::new(Int32Array::from_iter_values(
+                    range.clone().map(|i| i as i32),
+                )) as _,
+                false,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from_iter_values(
+                    range.clone().map(|i| format!("name{i}")),
+                )) as _,
+                false,
+            ),
+            (
+                "score",
+                Arc::new(Float64Array::from_iter(
+                    range.map(|i| (i % 2 == 1).then_some(i as f64)),
+                )) as _,
+                true,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg(all(feature = "deflate", feature = "snappy", feature = "zstd"))]
+    fn test_read_compressed() {
+        for codec in ["null", "deflate", "snappy", "zstandard"] {
+            let blocks = [(3, simple_records(0..3)), (2, simple_records(3..5))];
+            let file = write_file(SIMPLE_SCHEMA, codec, &blocks);
+            let batches = read_all(ReaderBuilder::new(), file);
+            assert_eq!(batches, vec![simple_batch(0..5)], "{codec}");
+        }
+    }
+
+    #[test]
+    fn test_batch_size() {
+        let blocks = [
+            (3, simple_records(0..3)),
+            (0, vec![]),
+            (4, simple_records(3..7)),
+        ];
+        let file = write_file(SIMPLE_SCHEMA, "deflate", &blocks);
+
+        let batches = read_all(ReaderBuilder::new().with_batch_size(2), file.clone());
+        let expected: Vec<_> = [0..2, 2..4, 4..6, 6..7]
+            .into_iter()
+            .map(simple_batch)
+            .collect();
+        assert_eq!(batches, expected);
+
+        let batches = read_all(ReaderBuilder::new().with_batch_size(5), file);
+        assert_eq!(batches, vec![simple_batch(0..5), simple_batch(5..7)]);
+    }
+
+    #[test]
+    fn test_projection() {
+        let file = write_file(SIMPLE_SCHEMA, "null", &[(4, simple_records(0..4))]);
+        let projection = vec!["score".to_string(), "id".to_string()];
+        let builder = ReaderBuilder::new().with_projection(projection);
+        let batches = read_all(builder, file.clone());
+
+        let expected = simple_batch(0..4);
+        let expected = expected.project(&[2, 0]).unwrap();
+        assert_eq!(batches, vec![expected]);
+
+        let projection = vec!["name".to_string(), "missing".to_string()];
+        let err = ReaderBuilder::new()
+            .with_projection(projection)
+            .build(Cursor::new(file))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Projected field \"missing\" not found in Avro schema"
+        );
+    }
+
+    #[test]
+    fn test_projection_skips_complex() {
+        let schema = r#"{
+            "type": "record",
+            "name": "skip",
+            "fields": [
+                {"name": "tags", "type": {"type": "array", "items": "string"}},
+                {"name": "attrs", "type": {"type": "map", "values": ["null", "long"]}},
+                {"name": "nested", "type": {"type": "record", "name": "nested", "fields": [
+                    {"name": "a", "type": {"type": "fixed", "name": "f", "size": 3}},
+                    {"name": "b", "type": ["int", "string", "float"]}
+                ]}},
+                {"name": "id", "type": "long"}
+            ]
+        }"#;
+
+        let mut data = Vec::new();
+        for i in 0..3_i64 {
+            // tags: one block, then a block with a negative count and byte size
+            put_long(&mut data, 1);
+            put_bytes(&mut data, b"x");
+            let mut block = Vec::new();
+            put_bytes(&mut block, b"yz");
+            put_long(&mut data, -1);
+            put_long(&mut data, block.len() as i64);
+            data.extend_from_slice(&block);
+            put_long(&mut data, 0);
+            // attrs
+            put_long(&mut data, 2);
+            put_bytes(&mut data, b"k1");
+            put_long(&mut data, 0);
+            put_bytes(&mut data, b"k2");
+            put_long(&mut data, 1);
+            put_long(&mut data, i);
+            put_long(&mut data, 0);
+            // nested
+            data.extend_from_slice(b"abc");
+            put_long(&mut data, i % 3);
+            match i % 3 {
+                0 => put_long(&mut data, 5),
+                1 => put_bytes(&mut data, b"five"),
+                _ => data.extend_from_slice(&5_f32.to_le_bytes()),
+            }
+            // id
+            put_long(&mut data, i * 10);
+        }
+
+        let file = write_file(schema, "null", &[(3, data)]);
+        let builder = ReaderBuilder::new().with_projection(vec!["id".to_string()]);
+        let batches = read_all(builder, file.clone());
+        let expected = RecordBatch::try_from_iter_with_nullable([(
+            "id",
+            Arc::new(Int64Array::from(vec![0, 10, 20])) as _,
+            false,
+        )])
+        .unwrap();
+        assert_eq!(batches, vec![expected]);
+
+        // Decoding all the fields consumes the same bytes
+        let batches = read_all(ReaderBuilder::new(), file);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 3);
+
+        let tags = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        let values = tags.value(2);
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(values, &StringArray::from(vec!["x", "yz"]));
+
+        let attrs = batch.column(1).as_any().downcast_ref::<MapArray>().unwrap();
+        let entries = attrs.value(1);
+        let keys = entries.column(0).as_any().downcast_ref::<StringArray>();
+        assert_eq!(keys.unwrap(), &StringArray::from(vec!["k1", "k2"]));
+        let values = entries.column(1).as_any().downcast_ref::<Int64Array>();
+        assert_eq!(values.unwrap(), &Int64Array::from(vec![None, Some(1)]));
+
+        let nested = batch.column(2).as_any().downcast_ref::<StructArray>();
+        let union = nested.unwrap().column(1);
+        let union = union.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(union.type_ids(), &[0, 1, 2]);
+        assert_eq!(union.offsets().unwrap(), &[0, 0, 0]);
+        let strings = union.child(1).as_any().downcast_ref::<StringArray>();
+        assert_eq!(strings.unwrap().value(0), "five");
+    }
+
+    #[test]
+    fn test_complex_types() {
+        let schema = r#"{
+            "type": "record",
+            "name": "complex",
+            "fields": [
+                {"name": "suit", "type": {"type": "enum", "name": "suit", "symbols": ["SPADES", "HEARTS"]}},
+                {"name": "value", "type": ["long", "string", "null"]},
+                {"name": "point", "type": ["null", {"type": "record", "name": "point", "fields": [
+                    {"name": "x", "type": "float"},
+                    {"name": "y", "type": "float"}
+                ]}]},
+                {"name": "counts", "type": {"type": "map", "values": "int"}}
+            ]
+        }"#;
+
+        let mut data = Vec::new();
+        // Row 0
+        put_long(&mut data, 1);
+        put_long(&mut data, 0);
+        put_long(&mut data, 42);
+        put_long(&mut data, 1);
+        data.extend_from_slice(&1_f32.to_le_bytes());
+        data.extend_from_slice(&2_f32.to_le_bytes());
+        put_long(&mut data, 1);
+        put_bytes(&mut data, b"a");
+        put_long(&mut data, 7);
+        put_long(&mut data, 0);
+        // Row 1
+        put_long(&mut data, 0);
+        put_long(&mut data, 1);
+        put_bytes(&mut data, b"hello");
+        put_long(&mut data, 0);
+        put_long(&mut data, 0);
+        // Row 2
+        put_long(&mut data, 1);
+        put_long(&mut data, 2);
+        put_long(&mut data, 0);
+        put_long(&mut data, 0);
+
+        let file = write_file(schema, "null", &[(3, data)]);
+        let batches = read_all(ReaderBuilder::new(), file);
+        let batch = &batches[0];
+
+        let suit = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>();
+        let suit = suit.unwrap();
+        assert_eq!(suit.keys(), &Int32Array::from(vec![1, 0, 1]));
+        let symbols = suit.values().as_any().downcast_ref::<StringArray>();
+        assert_eq!(
+            symbols.unwrap(),
+            &StringArray::from(vec!["SPADES", "HEARTS"])
+        );
+
+        let fields = UnionFields::new(
+            [0, 1, 2],
+            [
+                Field::new("long", DataType::Int64, false),
+                Field::new("string", DataType::Utf8, false),
+                Field::new("null", DataType::Null, true),
+            ],
+        );
+        let expected = UnionArray::try_new(
+            fields,
+            vec![0, 1, 2].into(),
+            Some(vec![0, 0, 0].into()),
+            vec![
+                Arc::new(Int64Array::from(vec![42])),
+                Arc::new(StringArray::from(vec!["hello"])),
+                Arc::new(NullArray::new(1)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(batch.column(1).as_ref(), &expected as &dyn Array);
+
+        let point = batch.column(2).as_any().downcast_ref::<StructArray>();
+        let point = point.unwrap();
+        assert_eq!(point.null_count(), 2);
+        assert!(point.is_valid(0));
+        let x = point.column(0).as_any().downcast_ref::<Float32Array>();
+        assert_eq!(x.unwrap().value(0), 1.);
+
+        let counts = batch.column(3).as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(counts.value_offsets(), &[0, 1, 1, 1]);
+        assert_eq!(counts.null_count(), 0);
+    }
+
+    #[test]
+    fn test_logical_types() {
+        let schema = r#"{
+            "type": "record",
+            "name": "logical",
+            "fields": [
+                {"name": "dec_bytes", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+                {"name": "dec_fixed", "type": {"type": "fixed", "name": "dec", "size": 20, "logicalType": "decimal", "precision": 40, "scale": 3}},
+                {"name": "uuid", "type": {"type": "string", "logicalType": "uuid"}},
+                {"name": "duration", "type": {"type": "fixed", "name": "duration", "size": 12, "logicalType": "duration"}},
+                {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-millis"}}
+            ]
+        }"#;
+
+        let mut data = Vec::new();
+        for (dec, uuid) in [
+            (-12345_i128, "123e4567-e89b-12d3-a456-426614174000"),
+            (678, "00112233445566778899aabbccddeeff"),
+        ] {
+            let bytes = dec.to_be_bytes();
+            let skip = bytes.len() - 3;
+            put_bytes(&mut data, &bytes[skip..]);
+            let fixed = i256::from_i128(dec).to_be_bytes();
+            data.extend_from_slice(&fixed[12..]);
+            put_bytes(&mut data, uuid.as_bytes());
+            for v in [1_u32, 2, 3] {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+            put_long(&mut data, 1_000);
+        }
+
+        let file = write_file(schema, "null", &[(2, data)]);
+        let batches = read_all(ReaderBuilder::new(), file);
+        let batch = &batches[0];
+
+        let dec = Decimal128Array::from(vec![-12345, 678])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        assert_eq!(batch.column(0).as_ref(), &dec as &dyn Array);
+
+        let dec = Decimal256Array::from(vec![i256::from_i128(-12345), i256::from_i128(678)])
+            .with_precision_and_scale(40, 3)
+            .unwrap();
+        assert_eq!(batch.column(1).as_ref(), &dec as &dyn Array);
+
+        let uuid = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>();
+        let uuid = uuid.unwrap();
+        assert_eq!(
+            uuid.value(0),
+            &[
+                0x12, 0x3e, 0x45, 0x67, 0xe8, 0x9b, 0x12, 0xd3, 0xa4, 0x56, 0x42, 0x66, 0x14, 0x17,
+                0x40, 0x00
+            ]
+        );
+        let field = batch.schema().field(2).clone();
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "arrow.uuid"
+        );
+
+        let duration = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<IntervalMonthDayNanoArray>();
+        assert_eq!(
+            duration.unwrap().value(1),
+            IntervalMonthDayNano::new(1, 2, 3_000_000)
+        );
+
+        let ts = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>();
+        assert_eq!(ts.unwrap().value(0), 1_000);
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        let file = write_file(SIMPLE_SCHEMA, "null", &[(2, simple_records(0..2))]);
+
+        // Truncated block
+        let truncated = file[..file.len() - 4].to_vec();
+        let mut reader = ReaderBuilder::new().build(Cursor::new(truncated)).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Unexpected EOF while reading Avro block"
+        );
+        assert!(reader.next().is_none());
+
+        // Mismatched sync marker
+        let mut bad_sync = file.clone();
+        *bad_sync.last_mut().unwrap() = b'X';
+        let mut reader = ReaderBuilder::new().build(Cursor::new(bad_sync)).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Avro block sync marker does not match file header"
+        );
+
+        // Block count does not match encoded records
+        let file = write_file(SIMPLE_SCHEMA, "null", &[(1, simple_records(0..2))]);
+        let mut reader = ReaderBuilder::new().build(Cursor::new(file)).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("trailing bytes"), "{err}");
     }
 
     #[test]
@@ -211,8 +866,8 @@ mod test {
         for file in files {
             let file = arrow_test_data(file);
 
-            assert_eq!(read_file(&file, 8), expected);
-            assert_eq!(read_file(&file, 3), expected);
+            read_file(&file, 8, &expected);
+            read_file(&file, 3, &expected);
         }
     }
 }
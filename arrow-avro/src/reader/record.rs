@@ -14,39 +14,105 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
-
-use crate::codec::{AvroDataType, Codec, Nullability};
-use crate::reader::block::{Block, BlockDecoder};
+use crate::codec::{map_entries_field, AvroDataType, Codec, Nullability};
 use crate::reader::cursor::AvroCursor;
-use crate::reader::header::Header;
-use crate::schema::*;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::*;
 use arrow_schema::{
     ArrowError, DataType, Field as ArrowField, FieldRef, Fields, Schema as ArrowSchema, SchemaRef,
+    UnionFields,
 };
-use std::collections::HashMap;
-use std::io::Read;
 use std::sync::Arc;
 
 /// Decodes avro encoded data into [`RecordBatch`]
+#[derive(Debug)]
 pub struct RecordDecoder {
     schema: SchemaRef,
-    fields: Vec<Decoder>,
+    /// The decoders of the fields of the records, in the order they are encoded
+    fields: Vec<FieldDecoder>,
+    /// The index amongst the decoded fields of each column of `schema`
+    projection: Vec<usize>,
+    /// The number of records decoded since the last flush
+    num_rows: usize,
+}
+
+/// Decodes or skips a field of a record
+#[derive(Debug)]
+enum FieldDecoder {
+    Decode(Decoder),
+    Skip(Skipper),
 }
 
 impl RecordDecoder {
     pub fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
-        match Decoder::try_new(data_type)? {
-            Decoder::Record(fields, encodings) => Ok(Self {
-                schema: Arc::new(ArrowSchema::new(fields)),
-                fields: encodings,
-            }),
-            encoding => Err(ArrowError::ParseError(format!(
-                "Expected record got {encoding:?}"
-            ))),
+        Self::try_new_with_projection(data_type, None)
+    }
+
+    /// Create a [`RecordDecoder`] that only decodes the fields named in
+    /// `projection`, in that order, skipping over the other fields
+    pub fn try_new_with_projection(
+        data_type: &AvroDataType,
+        projection: Option<&[String]>,
+    ) -> Result<Self, ArrowError> {
+        let fields = match data_type.codec() {
+            Codec::Struct(fields) => fields,
+            codec => {
+                return Err(ArrowError::ParseError(format!(
+                    "Expected record got {codec:?}"
+                )))
+            }
+        };
+
+        // The index in `fields` of each column
+        let columns = match projection {
+            None => (0..fields.len()).collect(),
+            Some(projection) => {
+                let mut columns = Vec::with_capacity(projection.len());
+                for name in projection {
+                    let idx = fields
+                        .iter()
+                        .position(|f| f.name() == name)
+                        .ok_or_else(|| {
+                            ArrowError::SchemaError(format!(
+                                "Projected field \"{name}\" not found in Avro schema"
+                            ))
+                        })?;
+                    if columns.contains(&idx) {
+                        return Err(ArrowError::SchemaError(format!(
+                            "Field \"{name}\" projected more than once"
+                        )));
+                    }
+                    columns.push(idx);
+                }
+                columns
+            }
+        };
+
+        let mut decoded = vec![None; fields.len()];
+        let mut decoders = Vec::with_capacity(fields.len());
+        for (idx, field) in fields.iter().enumerate() {
+            match columns.contains(&idx) {
+                true => {
+                    decoded[idx] = Some(decoders.len() - skipped(&decoders));
+                    decoders.push(FieldDecoder::Decode(Decoder::try_new(field.data_type())?));
+                }
+                false => decoders.push(FieldDecoder::Skip(Skipper::new(field.data_type()))),
+            }
         }
+
+        let schema = ArrowSchema::new(
+            columns
+                .iter()
+                .map(|idx| fields[*idx].field())
+                .collect::<Vec<_>>(),
+        );
+        Ok(Self {
+            schema: Arc::new(schema),
+            fields: decoders,
+            projection: columns.iter().map(|idx| decoded[*idx].unwrap()).collect(),
+            num_rows: 0,
+        })
     }
 
     pub fn schema(&self) -> &SchemaRef {
@@ -58,9 +124,13 @@ impl RecordDecoder {
         let mut cursor = AvroCursor::new(buf);
         for _ in 0..count {
             for field in &mut self.fields {
-                field.decode(&mut cursor)?;
+                match field {
+                    FieldDecoder::Decode(decoder) => decoder.decode(&mut cursor)?,
+                    FieldDecoder::Skip(skipper) => skipper.skip(&mut cursor)?,
+                }
             }
         }
+        self.num_rows += count;
         Ok(cursor.position())
     }
 
@@ -69,13 +139,27 @@ impl RecordDecoder {
         let arrays = self
             .fields
             .iter_mut()
-            .map(|x| x.flush(None))
+            .filter_map(|field| match field {
+                FieldDecoder::Decode(decoder) => Some(decoder.flush(None)),
+                FieldDecoder::Skip(_) => None,
+            })
             .collect::<Result<Vec<_>, _>>()?;
+        let columns = self.projection.iter().map(|idx| arrays[*idx].clone());
 
-        RecordBatch::try_new(self.schema.clone(), arrays)
+        let options = RecordBatchOptions::new().with_row_count(Some(self.num_rows));
+        self.num_rows = 0;
+        RecordBatch::try_new_with_options(self.schema.clone(), columns.collect(), &options)
     }
 }
 
+/// Returns the number of skipped fields in `decoders`
+fn skipped(decoders: &[FieldDecoder]) -> usize {
+    decoders
+        .iter()
+        .filter(|d| matches!(d, FieldDecoder::Skip(_)))
+        .count()
+}
+
 #[derive(Debug)]
 enum Decoder {
     Null(usize),
@@ -91,15 +175,38 @@ enum Decoder {
     TimestampMicros(bool, Vec<i64>),
     Binary(OffsetBufferBuilder<i32>, Vec<u8>),
     String(OffsetBufferBuilder<i32>, Vec<u8>),
+    Fixed(i32, Vec<u8>),
+    Interval(Vec<IntervalMonthDayNano>),
+    /// Decimal128(precision, scale, fixed size, values)
+    Decimal128(u8, i8, Option<usize>, Vec<i128>),
+    /// Decimal256(precision, scale, fixed size, values)
+    Decimal256(u8, i8, Option<usize>, Vec<i256>),
+    Uuid(Vec<u8>),
+    /// Enum(symbols, keys)
+    Enum(ArrayRef, Vec<i32>),
     List(FieldRef, OffsetBufferBuilder<i32>, Box<Decoder>),
+    /// Map(entries field, offsets, key offsets, key values, values)
+    Map(
+        FieldRef,
+        OffsetBufferBuilder<i32>,
+        OffsetBufferBuilder<i32>,
+        Vec<u8>,
+        Box<Decoder>,
+    ),
     Record(Fields, Vec<Decoder>),
+    Union {
+        fields: UnionFields,
+        type_ids: Vec<i8>,
+        offsets: Vec<i32>,
+        /// The number of values of each variant
+        lengths: Vec<i32>,
+        variants: Vec<Decoder>,
+    },
     Nullable(Nullability, NullBufferBuilder, Box<Decoder>),
 }
 
 impl Decoder {
     fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
-        let nyi = |s: &str| Err(ArrowError::NotYetImplemented(s.to_string()));
-
         let decoder = match data_type.codec() {
             Codec::Null => Self::Null(0),
             Codec::Boolean => Self::Boolean(BooleanBufferBuilder::new(DEFAULT_CAPACITY)),
@@ -124,8 +231,22 @@ impl Decoder {
             Codec::TimestampMicros(is_utc) => {
                 Self::TimestampMicros(*is_utc, Vec::with_capacity(DEFAULT_CAPACITY))
             }
-            Codec::Fixed(_) => return nyi("decoding fixed"),
-            Codec::Interval => return nyi("decoding interval"),
+            Codec::Fixed(size) => Self::Fixed(*size, Vec::with_capacity(DEFAULT_CAPACITY)),
+            Codec::Interval => Self::Interval(Vec::with_capacity(DEFAULT_CAPACITY)),
+            Codec::Decimal(precision, scale, size) => match data_type.codec().data_type() {
+                DataType::Decimal128(p, s) => {
+                    Self::Decimal128(p, s, *size, Vec::with_capacity(DEFAULT_CAPACITY))
+                }
+                DataType::Decimal256(p, s) => {
+                    Self::Decimal256(p, s, *size, Vec::with_capacity(DEFAULT_CAPACITY))
+                }
+                d => unreachable!("unexpected decimal type {d}"),
+            },
+            Codec::Uuid => Self::Uuid(Vec::with_capacity(DEFAULT_CAPACITY)),
+            Codec::Enum(symbols) => Self::Enum(
+                Arc::new(StringArray::from_iter_values(symbols.iter())),
+                Vec::with_capacity(DEFAULT_CAPACITY),
+            ),
             Codec::List(item) => {
                 let decoder = Self::try_new(item)?;
                 Self::List(
@@ -134,6 +255,13 @@ impl Decoder {
                     Box::new(decoder),
                 )
             }
+            Codec::Map(values) => Self::Map(
+                Arc::new(map_entries_field(values)),
+                OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                Vec::with_capacity(DEFAULT_CAPACITY),
+                Box::new(Self::try_new(values)?),
+            ),
             Codec::Struct(fields) => {
                 let mut arrow_fields = Vec::with_capacity(fields.len());
                 let mut encodings = Vec::with_capacity(fields.len());
@@ -144,6 +272,22 @@ impl Decoder {
                 }
                 Self::Record(arrow_fields.into(), encodings)
             }
+            Codec::Union(variants) => {
+                let fields = match data_type.codec().data_type() {
+                    DataType::Union(fields, _) => fields,
+                    d => unreachable!("unexpected union type {d}"),
+                };
+                Self::Union {
+                    fields,
+                    type_ids: Vec::with_capacity(DEFAULT_CAPACITY),
+                    offsets: Vec::with_capacity(DEFAULT_CAPACITY),
+                    lengths: vec![0; variants.len()],
+                    variants: variants
+                        .iter()
+                        .map(|v| Self::try_new(v.data_type()))
+                        .collect::<Result<_, _>>()?,
+                }
+            }
         };
 
         Ok(match data_type.nullability() {
@@ -161,7 +305,7 @@ impl Decoder {
         match self {
             Self::Null(count) => *count += 1,
             Self::Boolean(b) => b.append(false),
-            Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v) => v.push(0),
+            Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v) | Self::Enum(_, v) => v.push(0),
             Self::Int64(v)
             | Self::TimeMicros(v)
             | Self::TimestampMillis(_, v)
@@ -169,12 +313,38 @@ impl Decoder {
             Self::Float32(v) => v.push(0.),
             Self::Float64(v) => v.push(0.),
             Self::Binary(offsets, _) | Self::String(offsets, _) => offsets.push_length(0),
+            Self::Fixed(size, v) => v.resize(v.len() + *size as usize, 0),
+            Self::Interval(v) => v.push(IntervalMonthDayNano::ZERO),
+            Self::Decimal128(_, _, _, v) => v.push(0),
+            Self::Decimal256(_, _, _, v) => v.push(i256::ZERO),
+            Self::Uuid(v) => v.resize(v.len() + 16, 0),
             Self::List(_, offsets, e) => {
                 offsets.push_length(0);
-                e.append_null();
             }
+            Self::Map(_, offsets, _, _, _) => offsets.push_length(0),
             Self::Record(_, e) => e.iter_mut().for_each(|e| e.append_null()),
-            Self::Nullable(_, _, _) => unreachable!("Nulls cannot be nested"),
+            Self::Union {
+                type_ids,
+                offsets,
+                lengths,
+                variants,
+                ..
+            } => {
+                // Use the null variant if any
+                let idx = variants
+                    .iter()
+                    .position(|v| matches!(v, Self::Null(_)))
+                    .unwrap_or(0);
+                type_ids.push(idx as i8);
+                offsets.push(lengths[idx]);
+                lengths[idx] += 1;
+                variants[idx].append_null();
+            }
+            Self::Nullable(_, nulls, e) => {
+                // A nullable child of a null record
+                nulls.append_null();
+                e.append_null();
+            }
         }
     }
 
@@ -183,9 +353,10 @@ impl Decoder {
         match self {
             Self::Null(x) => *x += 1,
             Self::Boolean(values) => values.append(buf.get_bool()?),
-            Self::Int32(values) | Self::Date32(values) | Self::TimeMillis(values) => {
-                values.push(buf.get_int()?)
-            }
+            Self::Int32(values)
+            | Self::Date32(values)
+            | Self::TimeMillis(values)
+            | Self::Enum(_, values) => values.push(buf.get_int()?),
             Self::Int64(values)
             | Self::TimeMicros(values)
             | Self::TimestampMillis(_, values)
@@ -197,16 +368,58 @@ impl Decoder {
                 offsets.push_length(data.len());
                 values.extend_from_slice(data);
             }
-            Self::List(_, _, _) => {
-                return Err(ArrowError::NotYetImplemented(
-                    "Decoding ListArray".to_string(),
-                ))
+            Self::Fixed(size, values) => values.extend_from_slice(buf.get_fixed(*size as usize)?),
+            Self::Interval(values) => {
+                // Months, days and milliseconds as little-endian unsigned integers
+                let b = buf.get_fixed(12)?;
+                let get = |i: usize| u32::from_le_bytes(b[i..i + 4].try_into().unwrap());
+                let nanos = get(8) as i64 * 1_000_000;
+                values.push(IntervalMonthDayNano::new(
+                    get(0) as i32,
+                    get(4) as i32,
+                    nanos,
+                ));
+            }
+            Self::Decimal128(_, _, size, values) => {
+                let bytes = decimal_bytes(buf, *size)?;
+                values.push(i128::from_be_bytes(sign_extend(bytes)?));
+            }
+            Self::Decimal256(_, _, size, values) => {
+                let bytes = decimal_bytes(buf, *size)?;
+                values.push(i256::from_be_bytes(sign_extend(bytes)?));
+            }
+            Self::Uuid(values) => values.extend_from_slice(&parse_uuid(buf.get_bytes()?)?),
+            Self::List(_, offsets, e) => {
+                let len = read_blocks(buf, |buf| e.decode(buf))?;
+                offsets.push_length(len);
+            }
+            Self::Map(_, offsets, key_offsets, key_values, values) => {
+                let len = read_blocks(buf, |buf| {
+                    let key = buf.get_bytes()?;
+                    key_offsets.push_length(key.len());
+                    key_values.extend_from_slice(key);
+                    values.decode(buf)
+                })?;
+                offsets.push_length(len);
             }
             Self::Record(_, encodings) => {
                 for encoding in encodings {
                     encoding.decode(buf)?;
                 }
             }
+            Self::Union {
+                type_ids,
+                offsets,
+                lengths,
+                variants,
+                ..
+            } => {
+                let idx = union_index(buf, variants.len())?;
+                type_ids.push(idx as i8);
+                offsets.push(lengths[idx]);
+                lengths[idx] += 1;
+                variants[idx].decode(buf)?;
+            }
             Self::Nullable(nullability, nulls, e) => {
                 let is_valid = buf.get_bool()? == matches!(nullability, Nullability::NullFirst);
                 nulls.append(is_valid);
@@ -253,13 +466,60 @@ impl Decoder {
             Self::String(offsets, values) => {
                 let offsets = flush_offsets(offsets);
                 let values = flush_values(values).into();
-                Arc::new(StringArray::new(offsets, values, nulls))
+                Arc::new(StringArray::try_new(offsets, values, nulls)?)
+            }
+            Self::Fixed(size, values) => {
+                let values = flush_values(values).into();
+                Arc::new(FixedSizeBinaryArray::try_new(*size, values, nulls)?)
+            }
+            Self::Interval(values) => {
+                Arc::new(flush_primitive::<IntervalMonthDayNanoType>(values, nulls))
+            }
+            Self::Decimal128(precision, scale, _, values) => Arc::new(
+                flush_primitive::<Decimal128Type>(values, nulls)
+                    .with_precision_and_scale(*precision, *scale)?,
+            ),
+            Self::Decimal256(precision, scale, _, values) => Arc::new(
+                flush_primitive::<Decimal256Type>(values, nulls)
+                    .with_precision_and_scale(*precision, *scale)?,
+            ),
+            Self::Uuid(values) => {
+                let values = flush_values(values).into();
+                Arc::new(FixedSizeBinaryArray::try_new(16, values, nulls)?)
+            }
+            Self::Enum(symbols, keys) => {
+                let keys = flush_primitive::<Int32Type>(keys, nulls);
+                Arc::new(DictionaryArray::try_new(keys, symbols.clone())?)
             }
             Self::List(field, offsets, values) => {
                 let values = values.flush(None)?;
                 let offsets = flush_offsets(offsets);
                 Arc::new(ListArray::new(field.clone(), offsets, values, nulls))
             }
+            Self::Map(field, offsets, key_offsets, key_values, values) => {
+                let fields = match field.data_type() {
+                    DataType::Struct(fields) => fields.clone(),
+                    d => unreachable!("unexpected map entries type {d}"),
+                };
+                let keys = StringArray::try_new(
+                    flush_offsets(key_offsets),
+                    flush_values(key_values).into(),
+                    None,
+                )?;
+                let entries = StructArray::try_new(
+                    fields,
+                    vec![Arc::new(keys) as ArrayRef, values.flush(None)?],
+                    None,
+                )?;
+                let offsets = flush_offsets(offsets);
+                Arc::new(MapArray::try_new(
+                    field.clone(),
+                    offsets,
+                    entries,
+                    nulls,
+                    false,
+                )?)
+            }
             Self::Record(fields, encodings) => {
                 let arrays = encodings
                     .iter_mut()
@@ -267,7 +527,240 @@ impl Decoder {
                     .collect::<Result<Vec<_>, _>>()?;
                 Arc::new(StructArray::new(fields.clone(), arrays, nulls))
             }
+            Self::Union {
+                fields,
+                type_ids,
+                offsets,
+                lengths,
+                variants,
+            } => {
+                lengths.iter_mut().for_each(|l| *l = 0);
+                let children = variants
+                    .iter_mut()
+                    .map(|x| x.flush(None))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Arc::new(UnionArray::try_new(
+                    fields.clone(),
+                    flush_values(type_ids).into(),
+                    Some(flush_values(offsets).into()),
+                    children,
+                )?)
+            }
+        })
+    }
+}
+
+/// Skips over the values of a field that is not projected, without decoding them
+#[derive(Debug)]
+enum Skipper {
+    Null,
+    Boolean,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    Bytes,
+    Fixed(usize),
+    List(Box<Skipper>),
+    Map(Box<Skipper>),
+    Record(Vec<Skipper>),
+    Union(Vec<Skipper>),
+    Nullable(Nullability, Box<Skipper>),
+}
+
+impl Skipper {
+    fn new(data_type: &AvroDataType) -> Self {
+        let skipper = match data_type.codec() {
+            Codec::Null => Self::Null,
+            Codec::Boolean => Self::Boolean,
+            Codec::Int32 | Codec::Date32 | Codec::TimeMillis | Codec::Enum(_) => Self::Int32,
+            Codec::Int64
+            | Codec::TimeMicros
+            | Codec::TimestampMillis(_)
+            | Codec::TimestampMicros(_) => Self::Int64,
+            Codec::Float32 => Self::Float32,
+            Codec::Float64 => Self::Float64,
+            Codec::Binary | Codec::Utf8 | Codec::Uuid | Codec::Decimal(_, _, None) => Self::Bytes,
+            Codec::Fixed(size) => Self::Fixed(*size as usize),
+            Codec::Decimal(_, _, Some(size)) => Self::Fixed(*size),
+            Codec::Interval => Self::Fixed(12),
+            Codec::List(item) => Self::List(Box::new(Self::new(item))),
+            Codec::Map(values) => Self::Map(Box::new(Self::new(values))),
+            Codec::Struct(fields) => {
+                Self::Record(fields.iter().map(|f| Self::new(f.data_type())).collect())
+            }
+            Codec::Union(variants) => {
+                Self::Union(variants.iter().map(|v| Self::new(v.data_type())).collect())
+            }
+        };
+
+        match data_type.nullability() {
+            Some(nullability) => Self::Nullable(nullability, Box::new(skipper)),
+            None => skipper,
+        }
+    }
+
+    /// Skip a single value in `buf`
+    fn skip(&self, buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+        match self {
+            Self::Null => {}
+            Self::Boolean => {
+                buf.get_bool()?;
+            }
+            Self::Int32 => {
+                buf.get_int()?;
+            }
+            Self::Int64 => {
+                buf.get_long()?;
+            }
+            Self::Float32 => {
+                buf.get_fixed(4)?;
+            }
+            Self::Float64 => {
+                buf.get_fixed(8)?;
+            }
+            Self::Bytes => {
+                buf.get_bytes()?;
+            }
+            Self::Fixed(size) => {
+                buf.get_fixed(*size)?;
+            }
+            Self::List(item) => skip_blocks(buf, |buf| item.skip(buf))?,
+            Self::Map(values) => skip_blocks(buf, |buf| {
+                buf.get_bytes()?;
+                values.skip(buf)
+            })?,
+            Self::Record(fields) => {
+                for field in fields {
+                    field.skip(buf)?;
+                }
+            }
+            Self::Union(variants) => {
+                let idx = union_index(buf, variants.len())?;
+                variants[idx].skip(buf)?;
+            }
+            Self::Nullable(nullability, skipper) => {
+                let is_valid = buf.get_bool()? == matches!(nullability, Nullability::NullFirst);
+                if is_valid {
+                    skipper.skip(buf)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the items of an array or map with `decode_item`, returning the number of items
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#arrays-1>
+fn read_blocks(
+    buf: &mut AvroCursor<'_>,
+    mut decode_item: impl FnMut(&mut AvroCursor<'_>) -> Result<(), ArrowError>,
+) -> Result<usize, ArrowError> {
+    let mut total = 0;
+    loop {
+        let count = match buf.get_long()? {
+            0 => return Ok(total),
+            count if count < 0 => {
+                // A negative count is followed by the size of the block in bytes
+                buf.get_long()?;
+                count.unsigned_abs() as usize
+            }
+            count => count as usize,
+        };
+        for _ in 0..count {
+            decode_item(buf)?;
+        }
+        total += count;
+    }
+}
+
+/// Skips the items of an array or map with `skip_item`, using the block sizes if present
+fn skip_blocks(
+    buf: &mut AvroCursor<'_>,
+    mut skip_item: impl FnMut(&mut AvroCursor<'_>) -> Result<(), ArrowError>,
+) -> Result<(), ArrowError> {
+    loop {
+        match buf.get_long()? {
+            0 => return Ok(()),
+            count if count < 0 => {
+                let size = buf.get_long()?.try_into().map_err(|_| {
+                    ArrowError::ParseError("Block size cannot be negative".to_string())
+                })?;
+                buf.get_fixed(size)?;
+            }
+            count => {
+                for _ in 0..count {
+                    skip_item(buf)?;
+                }
+            }
+        }
+    }
+}
+
+/// Reads the index of the variant of a union with `num_variants` variants
+fn union_index(buf: &mut AvroCursor<'_>, num_variants: usize) -> Result<usize, ArrowError> {
+    let idx = buf.get_long()?;
+    usize::try_from(idx)
+        .ok()
+        .filter(|idx| *idx < num_variants)
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Union variant {idx} out of range for union with {num_variants} variants"
+            ))
         })
+}
+
+/// Reads the big-endian two's complement bytes of a decimal, stored as `fixed`
+/// of the given size, or as `bytes` if the size is `None`
+fn decimal_bytes<'a>(
+    buf: &mut AvroCursor<'a>,
+    size: Option<usize>,
+) -> Result<&'a [u8], ArrowError> {
+    match size {
+        Some(size) => buf.get_fixed(size),
+        None => buf.get_bytes(),
+    }
+}
+
+/// Sign extends the big-endian two's complement integer `bytes` to `N` bytes
+fn sign_extend<const N: usize>(bytes: &[u8]) -> Result<[u8; N], ArrowError> {
+    let negative = bytes.first().map(|b| b & 0x80 != 0).unwrap_or(false);
+    let fill = if negative { 0xFF } else { 0 };
+    let (head, tail) = bytes.split_at(bytes.len().saturating_sub(N));
+
+    // Any bytes beyond N must only extend the sign of the remaining bytes
+    let tail_negative = tail.first().map(|b| b & 0x80 != 0).unwrap_or(false);
+    if head.iter().any(|b| *b != fill) || (!head.is_empty() && tail_negative != negative) {
+        return Err(ArrowError::ParseError(format!(
+            "Decimal value of {} bytes overflows {N} bytes",
+            bytes.len()
+        )));
+    }
+
+    let mut out = [fill; N];
+    out[N - tail.len()..].copy_from_slice(tail);
+    Ok(out)
+}
+
+/// Parses the string representation of a UUID, such as
+/// `"123e4567-e89b-12d3-a456-426614174000"`, into its 16 bytes
+fn parse_uuid(s: &[u8]) -> Result<[u8; 16], ArrowError> {
+    let err = || ArrowError::ParseError(format!("Invalid UUID {}", String::from_utf8_lossy(s)));
+    let mut digits = s
+        .iter()
+        .filter(|c| **c != b'-')
+        .map(|c| (*c as char).to_digit(16));
+
+    let mut out = [0; 16];
+    for byte in out.iter_mut() {
+        let high = digits.next().flatten().ok_or_else(err)?;
+        let low = digits.next().flatten().ok_or_else(err)?;
+        *byte = (high << 4 | low) as u8;
+    }
+    match digits.next() {
+        Some(_) => Err(err()),
+        None => Ok(out),
     }
 }
 
@@ -290,3 +783,40 @@ fn flush_primitive<T: ArrowPrimitiveType>(
 }
 
 const DEFAULT_CAPACITY: usize = 1024;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_extend() {
+        assert_eq!(sign_extend::<4>(&[0x01, 0x02]).unwrap(), [0, 0, 1, 2]);
+        assert_eq!(sign_extend::<4>(&[0x81]).unwrap(), [0xFF, 0xFF, 0xFF, 0x81]);
+        assert_eq!(sign_extend::<4>(&[]).unwrap(), [0; 4]);
+
+        // Redundant leading sign bytes are permitted
+        assert_eq!(sign_extend::<2>(&[0xFF, 0xFF, 0x81]).unwrap(), [0xFF, 0x81]);
+        assert_eq!(sign_extend::<2>(&[0, 0, 0x01, 0x02]).unwrap(), [1, 2]);
+
+        sign_extend::<2>(&[0, 0x81, 0]).unwrap_err();
+        sign_extend::<2>(&[0xFF, 0x01, 0]).unwrap_err();
+        sign_extend::<2>(&[0x01, 0, 0]).unwrap_err();
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let expected = [
+            0x12, 0x3e, 0x45, 0x67, 0xe8, 0x9b, 0x12, 0xd3, 0xa4, 0x56, 0x42, 0x66, 0x14, 0x17,
+            0x40, 0x00,
+        ];
+        let parsed = parse_uuid(b"123e4567-e89b-12d3-a456-426614174000").unwrap();
+        assert_eq!(parsed, expected);
+        let parsed = parse_uuid(b"123E4567E89B12D3A456426614174000").unwrap();
+        assert_eq!(parsed, expected);
+
+        let err = parse_uuid(b"123e4567").unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Invalid UUID 123e4567");
+        parse_uuid(b"123e4567-e89b-12d3-a456-4266141740000").unwrap_err();
+        parse_uuid(b"123e4567-e89b-12d3-a456-42661417400g").unwrap_err();
+    }
+}
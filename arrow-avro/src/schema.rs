@@ -144,8 +144,8 @@ pub struct Field<'a> {
     pub doc: Option<&'a str>,
     #[serde(borrow)]
     pub r#type: Schema<'a>,
-    #[serde(borrow, default)]
-    pub default: Option<&'a str>,
+    #[serde(default)]
+    pub default: Option<serde_json::Value>,
 }
 
 /// An enumeration
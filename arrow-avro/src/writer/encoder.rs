@@ -0,0 +1,552 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Encoding of Arrow arrays to Avro binary data
+
+use crate::writer::schema::{is_nullable, is_uuid};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ToByteSlice};
+use arrow_schema::{ArrowError, DataType, Field, IntervalUnit, TimeUnit};
+
+/// A trait to encode array values as Avro binary data
+///
+/// Nullability is handled by [`FieldEncoder`]
+pub(crate) trait Encoder {
+    /// Encode the non-null value at index `idx` to `out`
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError>;
+}
+
+/// Encodes the values of `array`, with the Avro schema derived from `field`
+pub(crate) struct FieldEncoder<'a> {
+    encoder: Box<dyn Encoder + 'a>,
+    nulls: Option<NullBuffer>,
+    /// Whether the values are encoded as a union with `"null"`
+    nullable: bool,
+}
+
+impl<'a> FieldEncoder<'a> {
+    /// Create a [`FieldEncoder`] for `array`, which must have the data type of `field`
+    pub(crate) fn try_new(field: &Field, array: &'a dyn Array) -> Result<Self, ArrowError> {
+        Self::try_new_with_nullable(field, array, is_nullable(field))
+    }
+
+    fn try_new_with_nullable(
+        field: &Field,
+        array: &'a dyn Array,
+        nullable: bool,
+    ) -> Result<Self, ArrowError> {
+        let (encoder, nulls) = make_encoder(field, array)?;
+        Ok(Self {
+            encoder,
+            nulls,
+            nullable,
+        })
+    }
+
+    pub(crate) fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let is_null = self.nulls.as_ref().map(|n| n.is_null(idx)).unwrap_or(false);
+        match (self.nullable, is_null) {
+            (true, true) => put_long(out, 0),
+            (true, false) => {
+                put_long(out, 1);
+                self.encoder.encode(idx, out)?
+            }
+            (false, false) => self.encoder.encode(idx, out)?,
+            (false, true) => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Found null value in non-nullable Avro field".to_string(),
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+fn make_encoder<'a>(
+    field: &Field,
+    array: &'a dyn Array,
+) -> Result<(Box<dyn Encoder + 'a>, Option<NullBuffer>), ArrowError> {
+    macro_rules! long_helper {
+        ($t:ty) => {{
+            let array = array.as_primitive::<$t>();
+            (Box::new(LongEncoder(array)) as _, array.nulls().cloned())
+        }};
+    }
+
+    Ok(match array.data_type() {
+        DataType::Null => (Box::new(NullEncoder) as _, None),
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            (Box::new(BooleanEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Int8 => long_helper!(Int8Type),
+        DataType::Int16 => long_helper!(Int16Type),
+        DataType::Int32 => long_helper!(Int32Type),
+        DataType::Int64 => long_helper!(Int64Type),
+        DataType::UInt8 => long_helper!(UInt8Type),
+        DataType::UInt16 => long_helper!(UInt16Type),
+        DataType::UInt32 => long_helper!(UInt32Type),
+        DataType::UInt64 => long_helper!(UInt64Type),
+        DataType::Date32 => long_helper!(Date32Type),
+        DataType::Time32(TimeUnit::Millisecond) => long_helper!(Time32MillisecondType),
+        DataType::Time64(TimeUnit::Microsecond) => long_helper!(Time64MicrosecondType),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            long_helper!(TimestampMillisecondType)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            long_helper!(TimestampMicrosecondType)
+        }
+        DataType::Float32 => {
+            let array = array.as_primitive::<Float32Type>();
+            (Box::new(FloatEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Float64 => {
+            let array = array.as_primitive::<Float64Type>();
+            (Box::new(FloatEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Binary => {
+            let array = array.as_binary::<i32>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::LargeBinary => {
+            let array = array.as_binary::<i64>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_string::<i64>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::FixedSizeBinary(_) if is_uuid(field) => {
+            let array = array.as_fixed_size_binary();
+            (Box::new(UuidEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_fixed_size_binary();
+            (Box::new(FixedEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Decimal128(_, _) => {
+            let array = array.as_primitive::<Decimal128Type>();
+            (
+                Box::new(Decimal128Encoder(array)) as _,
+                array.nulls().cloned(),
+            )
+        }
+        DataType::Decimal256(_, _) => {
+            let array = array.as_primitive::<Decimal256Type>();
+            (
+                Box::new(Decimal256Encoder(array)) as _,
+                array.nulls().cloned(),
+            )
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array.as_primitive::<IntervalMonthDayNanoType>();
+            (
+                Box::new(DurationEncoder(array)) as _,
+                array.nulls().cloned(),
+            )
+        }
+        DataType::List(item) => {
+            let array = array.as_list::<i32>();
+            let encoder = ListEncoder::try_new(item, array.offsets(), array.values())?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::LargeList(item) => {
+            let array = array.as_list::<i64>();
+            let encoder = ListEncoder::try_new(item, array.offsets(), array.values())?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_map();
+            (
+                Box::new(MapEncoder::try_new(array)?) as _,
+                array.nulls().cloned(),
+            )
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let encoders = fields
+                .iter()
+                .zip(array.columns())
+                .map(|(field, array)| FieldEncoder::try_new(field, array.as_ref()))
+                .collect::<Result<_, _>>()?;
+            (
+                Box::new(StructEncoder(encoders)) as _,
+                array.nulls().cloned(),
+            )
+        }
+        DataType::Union(fields, _) => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let encoders = fields
+                .iter()
+                .map(|(type_id, field)| {
+                    let child = array.child(type_id).as_ref();
+                    let encoder = FieldEncoder::try_new_with_nullable(field, child, false)?;
+                    Ok((type_id, encoder))
+                })
+                .collect::<Result<_, ArrowError>>()?;
+            (Box::new(UnionEncoder { array, encoders }) as _, None)
+        }
+        DataType::Dictionary(_, value) => {
+            let value_field = Field::new(field.name(), value.as_ref().clone(), true)
+                .with_metadata(field.metadata().clone());
+            let encoder: Box<dyn Encoder + 'a> = downcast_dictionary_array! {
+                array => Box::new(DictionaryEncoder {
+                    keys: array.keys().values(),
+                    values: FieldEncoder::try_new_with_nullable(&value_field, array.values().as_ref(), false)?,
+                }),
+                _ => unreachable!()
+            };
+            (encoder, array.logical_nulls())
+        }
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {d} to Avro is not yet supported"
+            )))
+        }
+    })
+}
+
+/// Writes `v` as a zig-zag encoded variable length integer
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#binary-encoding>
+pub(crate) fn put_long(out: &mut Vec<u8>, v: i64) {
+    let mut v = ((v << 1) ^ (v >> 63)) as u64;
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Writes `b` as Avro `bytes`, i.e. prefixed by its length
+pub(crate) fn put_bytes(out: &mut Vec<u8>, b: &[u8]) {
+    put_long(out, b.len() as i64);
+    out.extend_from_slice(b);
+}
+
+struct NullEncoder;
+
+impl Encoder for NullEncoder {
+    fn encode(&mut self, _idx: usize, _out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        Ok(())
+    }
+}
+
+struct BooleanEncoder<'a>(&'a BooleanArray);
+
+impl<'a> Encoder for BooleanEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        out.push(self.0.value(idx) as u8);
+        Ok(())
+    }
+}
+
+/// Encodes integers as Avro `int` or `long`, which share the same binary encoding
+struct LongEncoder<'a, T: ArrowPrimitiveType>(&'a PrimitiveArray<T>);
+
+impl<'a, T: ArrowPrimitiveType> Encoder for LongEncoder<'a, T> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let value = self.0.value(idx);
+        let value = value.to_i64().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!("Value {value:?} overflows Avro long"))
+        })?;
+        put_long(out, value);
+        Ok(())
+    }
+}
+
+struct FloatEncoder<'a, T: ArrowPrimitiveType>(&'a PrimitiveArray<T>);
+
+impl<'a, T: ArrowPrimitiveType> Encoder for FloatEncoder<'a, T> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        // Avro floats are little-endian, as is the Arrow in-memory format
+        let values = self.0.values();
+        out.extend_from_slice(values[idx..idx + 1].to_byte_slice());
+        Ok(())
+    }
+}
+
+struct BytesEncoder<'a, T: ByteArrayType>(&'a GenericByteArray<T>);
+
+impl<'a, T: ByteArrayType> Encoder for BytesEncoder<'a, T> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        put_bytes(out, self.0.value(idx).as_ref());
+        Ok(())
+    }
+}
+
+struct FixedEncoder<'a>(&'a FixedSizeBinaryArray);
+
+impl<'a> Encoder for FixedEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        out.extend_from_slice(self.0.value(idx));
+        Ok(())
+    }
+}
+
+/// Encodes a 16 byte UUID as its hyphenated string representation
+struct UuidEncoder<'a>(&'a FixedSizeBinaryArray);
+
+impl<'a> Encoder for UuidEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        put_long(out, 36);
+        for (i, b) in self.0.value(idx).iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                out.push(b'-');
+            }
+            out.push(HEX[(b >> 4) as usize]);
+            out.push(HEX[(b & 0xF) as usize]);
+        }
+        Ok(())
+    }
+}
+
+/// Writes the big-endian two's complement `bytes` of a decimal as Avro `bytes`,
+/// omitting redundant leading sign bytes
+fn put_decimal(out: &mut Vec<u8>, bytes: &[u8]) {
+    let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
+    let mut start = 0;
+    while start + 1 < bytes.len()
+        && bytes[start] == fill
+        && (bytes[start + 1] & 0x80) == (fill & 0x80)
+    {
+        start += 1;
+    }
+    put_bytes(out, &bytes[start..]);
+}
+
+struct Decimal128Encoder<'a>(&'a Decimal128Array);
+
+impl<'a> Encoder for Decimal128Encoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        put_decimal(out, &self.0.value(idx).to_be_bytes());
+        Ok(())
+    }
+}
+
+struct Decimal256Encoder<'a>(&'a Decimal256Array);
+
+impl<'a> Encoder for Decimal256Encoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        put_decimal(out, &self.0.value(idx).to_be_bytes());
+        Ok(())
+    }
+}
+
+/// Encodes [`IntervalMonthDayNano`] as an Avro `duration` of months, days and milliseconds
+///
+/// [`IntervalMonthDayNano`]: arrow_buffer::IntervalMonthDayNano
+struct DurationEncoder<'a>(&'a IntervalMonthDayNanoArray);
+
+impl<'a> Encoder for DurationEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let v = self.0.value(idx);
+        let millis = (v.nanoseconds % 1_000_000 == 0)
+            .then_some(v.nanoseconds / 1_000_000)
+            .and_then(|m| u32::try_from(m).ok());
+        let (months, days, millis) = match (u32::try_from(v.months), u32::try_from(v.days), millis)
+        {
+            (Ok(months), Ok(days), Some(millis)) => (months, days, millis),
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Interval {v:?} cannot be represented as an Avro duration"
+                )))
+            }
+        };
+        out.extend_from_slice(&months.to_le_bytes());
+        out.extend_from_slice(&days.to_le_bytes());
+        out.extend_from_slice(&millis.to_le_bytes());
+        Ok(())
+    }
+}
+
+/// Writes the `len` items of an Avro array or map as a single block, calling `encode`
+/// for each item
+fn put_block(
+    out: &mut Vec<u8>,
+    len: usize,
+    mut encode: impl FnMut(usize, &mut Vec<u8>) -> Result<(), ArrowError>,
+) -> Result<(), ArrowError> {
+    if len > 0 {
+        put_long(out, len as i64);
+        for i in 0..len {
+            encode(i, out)?;
+        }
+    }
+    put_long(out, 0);
+    Ok(())
+}
+
+struct ListEncoder<'a, O: OffsetSizeTrait> {
+    offsets: &'a OffsetBuffer<O>,
+    encoder: FieldEncoder<'a>,
+}
+
+impl<'a, O: OffsetSizeTrait> ListEncoder<'a, O> {
+    fn try_new(
+        item: &Field,
+        offsets: &'a OffsetBuffer<O>,
+        values: &'a ArrayRef,
+    ) -> Result<Self, ArrowError> {
+        let encoder = FieldEncoder::try_new(item, values.as_ref())?;
+        Ok(Self { offsets, encoder })
+    }
+}
+
+impl<'a, O: OffsetSizeTrait> Encoder for ListEncoder<'a, O> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let start = self.offsets[idx].as_usize();
+        let end = self.offsets[idx + 1].as_usize();
+        put_block(out, end - start, |i, out| {
+            self.encoder.encode(start + i, out)
+        })
+    }
+}
+
+struct MapEncoder<'a> {
+    offsets: &'a OffsetBuffer<i32>,
+    keys: &'a StringArray,
+    values: FieldEncoder<'a>,
+}
+
+impl<'a> MapEncoder<'a> {
+    fn try_new(array: &'a MapArray) -> Result<Self, ArrowError> {
+        let keys = array.keys().as_string_opt::<i32>().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Avro map keys must be strings, got {}",
+                array.keys().data_type()
+            ))
+        })?;
+        if keys.null_count() != 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "Avro map keys cannot be null".to_string(),
+            ));
+        }
+        let value_field = match array.entries().fields().get(1) {
+            Some(f) => f,
+            None => unreachable!("map entries must have two fields"),
+        };
+        let values = FieldEncoder::try_new(value_field, array.values().as_ref())?;
+        Ok(Self {
+            offsets: array.offsets(),
+            keys,
+            values,
+        })
+    }
+}
+
+impl<'a> Encoder for MapEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let start = self.offsets[idx].as_usize();
+        let end = self.offsets[idx + 1].as_usize();
+        put_block(out, end - start, |i, out| {
+            put_bytes(out, self.keys.value(start + i).as_bytes());
+            self.values.encode(start + i, out)
+        })
+    }
+}
+
+struct StructEncoder<'a>(Vec<FieldEncoder<'a>>);
+
+impl<'a> Encoder for StructEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        for encoder in &mut self.0 {
+            encoder.encode(idx, out)?;
+        }
+        Ok(())
+    }
+}
+
+/// Encodes a [`UnionArray`] as an Avro union, whose variants are in the order of the union fields
+struct UnionEncoder<'a> {
+    array: &'a UnionArray,
+    encoders: Vec<(i8, FieldEncoder<'a>)>,
+}
+
+impl<'a> Encoder for UnionEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        let type_id = self.array.type_id(idx);
+        let variant = self
+            .encoders
+            .iter()
+            .position(|(id, _)| *id == type_id)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!("Unknown union type id {type_id}"))
+            })?;
+        put_long(out, variant as i64);
+        let offset = self.array.value_offset(idx);
+        self.encoders[variant].1.encode(offset, out)
+    }
+}
+
+struct DictionaryEncoder<'a, K: ArrowNativeType> {
+    keys: &'a [K],
+    values: FieldEncoder<'a>,
+}
+
+impl<'a, K: ArrowNativeType> Encoder for DictionaryEncoder<'a, K> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        self.values.encode(self.keys[idx].as_usize(), out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_put_long() {
+        let cases: [(i64, &[u8]); 6] = [
+            (0, &[0]),
+            (-1, &[1]),
+            (1, &[2]),
+            (-64, &[0x7F]),
+            (64, &[0x80, 0x01]),
+            (
+                i64::MIN,
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ),
+        ];
+        for (v, expected) in cases {
+            let mut out = Vec::new();
+            put_long(&mut out, v);
+            assert_eq!(out, expected, "{v}");
+        }
+    }
+
+    #[test]
+    fn test_put_decimal() {
+        let cases: [(i128, &[u8]); 5] = [
+            (0, &[0]),
+            (-1, &[0xFF]),
+            (127, &[0x7F]),
+            (128, &[0x00, 0x80]),
+            (-129, &[0xFF, 0x7F]),
+        ];
+        for (v, expected) in cases {
+            let mut out = Vec::new();
+            put_decimal(&mut out, &v.to_be_bytes());
+            assert_eq!(&out[1..], expected, "{v}");
+        }
+    }
+}
@@ -0,0 +1,651 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Write Arrow data to Avro
+//!
+//! # Example
+//!
+//! Writing [`RecordBatch`]es to a deflate compressed Avro [Object Container File]
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int32Array, RecordBatch, StringArray};
+//! # use arrow_avro::writer::{CompressionCodec, WriterBuilder};
+//! let batch = RecordBatch::try_from_iter([
+//!     ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+//!     ("name", Arc::new(StringArray::from(vec!["a", "b", "c"])) as _),
+//! ])
+//! .unwrap();
+//!
+//! let mut writer = WriterBuilder::new(batch.schema())
+//!     .with_compression(Some(CompressionCodec::Deflate))
+//!     .build(Vec::new())
+//!     .unwrap();
+//! writer.write(&batch).unwrap();
+//! let file: Vec<u8> = writer.into_inner().unwrap();
+//! assert_eq!(&file[..4], b"Obj\x01");
+//! ```
+//!
+//! [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+
+use crate::compression::CODEC_METADATA_KEY;
+use crate::schema::SCHEMA_METADATA_KEY;
+use crate::writer::encoder::{put_bytes, put_long, FieldEncoder};
+use crate::writer::schema::to_avro_schema;
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+
+pub use crate::compression::CompressionCodec;
+
+pub(crate) mod encoder;
+pub(crate) mod schema;
+
+/// The magic bytes at the start of an Avro Object Container File
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+/// A builder for [`Writer`]
+#[derive(Debug, Clone)]
+pub struct WriterBuilder {
+    schema: SchemaRef,
+    compression: Option<CompressionCodec>,
+    block_size: usize,
+    sync_marker: Option<[u8; 16]>,
+}
+
+impl WriterBuilder {
+    /// Create a new [`WriterBuilder`] for [`RecordBatch`] with the given `schema`
+    pub fn new(schema: SchemaRef) -> Self {
+        Self {
+            schema,
+            compression: None,
+            block_size: 64 * 1024,
+            sync_marker: None,
+        }
+    }
+
+    /// Set the [`CompressionCodec`] used to compress each block, defaults to `None`
+    pub fn with_compression(self, compression: Option<CompressionCodec>) -> Self {
+        Self {
+            compression,
+            ..self
+        }
+    }
+
+    /// Set the approximate size in bytes of each block before compression, defaults to 64 KiB
+    ///
+    /// A block is written once the encoded rows buffered by the [`Writer`] exceed this size
+    pub fn with_block_size(self, block_size: usize) -> Self {
+        Self { block_size, ..self }
+    }
+
+    /// Set the sync marker written after the header and each block, defaults to a random value
+    pub fn with_sync_marker(self, sync_marker: [u8; 16]) -> Self {
+        Self {
+            sync_marker: Some(sync_marker),
+            ..self
+        }
+    }
+
+    /// Create a [`Writer`] writing to `writer`, writing the file header
+    pub fn build<W: Write>(self, mut writer: W) -> Result<Writer<W>, ArrowError> {
+        let avro_schema = to_avro_schema(&self.schema)?.to_string();
+        let sync_marker = self.sync_marker.unwrap_or_else(random_sync_marker);
+        let codec = self.compression.map(|c| c.name()).unwrap_or("null");
+
+        let mut header = MAGIC.to_vec();
+        put_long(&mut header, 2);
+        put_bytes(&mut header, SCHEMA_METADATA_KEY.as_bytes());
+        put_bytes(&mut header, avro_schema.as_bytes());
+        put_bytes(&mut header, CODEC_METADATA_KEY.as_bytes());
+        put_bytes(&mut header, codec.as_bytes());
+        put_long(&mut header, 0);
+        header.extend_from_slice(&sync_marker);
+        writer.write_all(&header)?;
+
+        Ok(Writer {
+            writer,
+            schema: self.schema,
+            avro_schema,
+            compression: self.compression,
+            block_size: self.block_size,
+            sync_marker,
+            buffer: Vec::with_capacity(self.block_size),
+            num_rows: 0,
+        })
+    }
+}
+
+/// Returns a random sync marker
+fn random_sync_marker() -> [u8; 16] {
+    let mut out = [0; 16];
+    for (i, chunk) in out.chunks_exact_mut(8).enumerate() {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(i);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    out
+}
+
+/// Writes [`RecordBatch`] to an Avro [Object Container File]
+///
+/// Created with [`WriterBuilder`]. Rows are buffered into blocks, and so
+/// [`Writer::finish`] or [`Writer::into_inner`] must be called to write any
+/// remaining rows once all batches have been written
+///
+/// The Avro schema is derived from the Arrow schema:
+///
+/// * Nullable fields are written as a union of `"null"` and their type
+/// * Signed and unsigned integers are written as `int` or `long`
+/// * [`DataType::FixedSizeBinary`] is written as `fixed`, or as a `uuid` string
+///   if it has the `arrow.uuid` extension type
+/// * Decimals are written as `bytes` with the `decimal` logical type
+/// * [`DataType::Interval`] of [`IntervalUnit::MonthDayNano`] is written as a `duration`
+/// * Lists, maps with string keys and structs are written as `array`, `map` and `record`
+/// * [`DataType::Union`] is written as a union of its variants
+/// * [`DataType::Dictionary`] is written as its value type
+///
+/// [Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+/// [`DataType::FixedSizeBinary`]: arrow_schema::DataType::FixedSizeBinary
+/// [`DataType::Interval`]: arrow_schema::DataType::Interval
+/// [`IntervalUnit::MonthDayNano`]: arrow_schema::IntervalUnit::MonthDayNano
+/// [`DataType::Union`]: arrow_schema::DataType::Union
+/// [`DataType::Dictionary`]: arrow_schema::DataType::Dictionary
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    schema: SchemaRef,
+    avro_schema: String,
+    compression: Option<CompressionCodec>,
+    block_size: usize,
+    sync_marker: [u8; 16],
+    /// The encoded rows of the current block
+    buffer: Vec<u8>,
+    /// The number of rows in `buffer`
+    num_rows: usize,
+}
+
+impl<W: Write> Writer<W> {
+    /// Returns the Arrow schema of this writer
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the JSON representation of the Avro schema of the written data
+    pub fn avro_schema(&self) -> &str {
+        &self.avro_schema
+    }
+
+    /// Write a [`RecordBatch`], writing a block whenever the buffered rows exceed the block size
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        let schema = batch.schema();
+        if schema.fields().len() != self.schema.fields().len()
+            || schema
+                .fields()
+                .iter()
+                .zip(self.schema.fields())
+                .any(|(a, b)| a.data_type() != b.data_type())
+        {
+            return Err(ArrowError::SchemaError(
+                "RecordBatch schema does not match the schema of the Avro writer".to_string(),
+            ));
+        }
+
+        let mut encoders = self
+            .schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, array)| FieldEncoder::try_new(field, array.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for idx in 0..batch.num_rows() {
+            let start = self.buffer.len();
+            for encoder in &mut encoders {
+                if let Err(e) = encoder.encode(idx, &mut self.buffer) {
+                    // Discard the partially encoded row
+                    self.buffer.truncate(start);
+                    return Err(e);
+                }
+            }
+            self.num_rows += 1;
+            if self.buffer.len() >= self.block_size {
+                self.write_block()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the buffered rows, if any, as a block
+    fn write_block(&mut self) -> Result<(), ArrowError> {
+        if self.num_rows == 0 {
+            return Ok(());
+        }
+        let mut block = Vec::with_capacity(self.buffer.len() + 32);
+        put_long(&mut block, self.num_rows as i64);
+        match self.compression {
+            Some(c) => put_bytes(&mut block, &c.compress(&self.buffer)?),
+            None => put_bytes(&mut block, &self.buffer),
+        }
+        block.extend_from_slice(&self.sync_marker);
+        self.writer.write_all(&block)?;
+
+        self.buffer.clear();
+        self.num_rows = 0;
+        Ok(())
+    }
+
+    /// Write any buffered rows as a block and flush the underlying writer
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        self.write_block()?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Write any buffered rows, completing the file
+    pub fn finish(&mut self) -> Result<(), ArrowError> {
+        self.flush()
+    }
+
+    /// Finish the file and return the underlying writer
+    pub fn into_inner(mut self) -> Result<W, ArrowError> {
+        self.finish()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use arrow_array::builder::{Int64Builder, MapBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_array::*;
+    use arrow_buffer::{i256, IntervalMonthDayNano, OffsetBuffer};
+    use arrow_schema::{DataType, Field, Fields, IntervalUnit, Schema, TimeUnit, UnionFields};
+    use std::collections::HashMap;
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    fn write(batches: &[RecordBatch], builder: WriterBuilder) -> Vec<u8> {
+        let mut writer = builder.build(Vec::new()).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    fn read(file: Vec<u8>) -> Vec<RecordBatch> {
+        ReaderBuilder::new()
+            .build(Cursor::new(file))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn primitive_batch() -> RecordBatch {
+        RecordBatch::try_from_iter_with_nullable([
+            (
+                "int",
+                Arc::new(Int32Array::from(vec![1, -2, i32::MAX])) as _,
+                false,
+            ),
+            (
+                "long",
+                Arc::new(Int64Array::from(vec![Some(i64::MIN), None, Some(3)])) as _,
+                true,
+            ),
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as _,
+                false,
+            ),
+            (
+                "float",
+                Arc::new(Float32Array::from(vec![1.5, f32::NAN, -0.])) as _,
+                false,
+            ),
+            (
+                "double",
+                Arc::new(Float64Array::from(vec![None, Some(2.5), None])) as _,
+                true,
+            ),
+            (
+                "string",
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("ccc")])) as _,
+                true,
+            ),
+            (
+                "bytes",
+                Arc::new(BinaryArray::from_iter_values([b"", b"x" as &[u8], b"yz"])) as _,
+                false,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip_primitives() {
+        let batch = primitive_batch();
+        let file = write(
+            std::slice::from_ref(&batch),
+            WriterBuilder::new(batch.schema()),
+        );
+        let read = read(file);
+        assert_eq!(read.len(), 1);
+        // Compare via formatting as NaN != NaN
+        assert_eq!(format!("{:?}", read[0]), format!("{batch:?}"));
+    }
+
+    #[test]
+    fn test_roundtrip_compression() {
+        let batch = primitive_batch();
+        for codec in [
+            CompressionCodec::Deflate,
+            CompressionCodec::Snappy,
+            CompressionCodec::ZStandard,
+        ] {
+            let builder = WriterBuilder::new(batch.schema()).with_compression(Some(codec));
+            let file = write(std::slice::from_ref(&batch), builder);
+            let read = read(file);
+            assert_eq!(format!("{read:?}"), format!("{:?}", [&batch]), "{codec:?}");
+        }
+    }
+
+    #[test]
+    fn test_block_size() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int64Array::from_iter_values(0..100)) as _,
+        )])
+        .unwrap();
+        let sync = *b"0123456789abcdef";
+        let builder = WriterBuilder::new(batch.schema())
+            .with_block_size(10)
+            .with_sync_marker(sync);
+        let file = write(&[batch.clone(), batch.clone()], builder);
+
+        // Values 0..64 are encoded as a single byte, the remainder as two bytes,
+        // with a block written once at least 10 bytes are buffered
+        let blocks = file.windows(16).filter(|w| *w == sync).count() - 1;
+        assert_eq!(blocks, 28);
+
+        let read = read(file);
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].slice(0, 100), batch);
+        assert_eq!(read[0].slice(100, 100), batch);
+    }
+
+    #[test]
+    fn test_roundtrip_logical_types() {
+        let uuid = Field::new("uuid", DataType::FixedSizeBinary(16), false).with_metadata(
+            HashMap::from([("ARROW:extension:name".to_string(), "arrow.uuid".to_string())]),
+        );
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("date", DataType::Date32, false),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into())),
+                false,
+            ),
+            Field::new(
+                "local_ts",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                true,
+            ),
+            Field::new("dec", DataType::Decimal128(20, 3), true),
+            Field::new("dec256", DataType::Decimal256(50, 0), false),
+            uuid,
+            Field::new("fixed", DataType::FixedSizeBinary(3), false),
+            Field::new(
+                "duration",
+                DataType::Interval(IntervalUnit::MonthDayNano),
+                false,
+            ),
+        ]));
+
+        let dec = Decimal128Array::from(vec![Some(-1), None, Some(i64::MAX as i128 * 1000)])
+            .with_precision_and_scale(20, 3)
+            .unwrap();
+        let dec256 = Decimal256Array::from(vec![
+            i256::MAX.wrapping_div(i256::from_i128(100)),
+            i256::from_i128(-128),
+            i256::from_i128(127),
+        ])
+        .with_precision_and_scale(50, 0)
+        .unwrap();
+        let uuids = FixedSizeBinaryArray::try_from_iter((0..3_u8).map(|i| [i; 16])).unwrap();
+        let fixed = FixedSizeBinaryArray::try_from_iter((0..3_u8).map(|i| [i; 3])).unwrap();
+        let durations = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(1, 2, 3_000_000),
+            IntervalMonthDayNano::new(0, 0, 0),
+            IntervalMonthDayNano::new(12, 31, 86_400_000_000_000),
+        ]);
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Date32Array::from(vec![0, 19000, -1])),
+                Arc::new(
+                    TimestampMicrosecondArray::from(vec![0, 1_000_000, -1]).with_timezone("+00:00"),
+                ),
+                Arc::new(TimestampMillisecondArray::from(vec![
+                    Some(1),
+                    None,
+                    Some(2),
+                ])),
+                Arc::new(dec),
+                Arc::new(dec256),
+                Arc::new(uuids),
+                Arc::new(fixed),
+                Arc::new(durations),
+            ],
+        )
+        .unwrap();
+
+        let file = write(std::slice::from_ref(&batch), WriterBuilder::new(schema));
+        let read = read(file);
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].columns(), batch.columns());
+    }
+
+    #[test]
+    fn test_roundtrip_nested() {
+        let item = Arc::new(Field::new("item", DataType::Int32, true));
+        let list = ListArray::new(
+            item.clone(),
+            OffsetBuffer::new(vec![0, 2, 2, 3].into()),
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            Some(vec![true, false, true].into()),
+        );
+
+        let struct_fields = Fields::from(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("label", DataType::Utf8, true),
+        ]);
+        let point = StructArray::new(
+            struct_fields.clone(),
+            vec![
+                Arc::new(Float64Array::from(vec![1., 2., 3.])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+            Some(vec![true, true, false].into()),
+        );
+
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        map.keys().append_value("k");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+        map.append(true).unwrap();
+        map.keys().append_value("k1");
+        map.values().append_null();
+        map.keys().append_value("k2");
+        map.values().append_value(2);
+        map.append(true).unwrap();
+        let map = map.finish();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("list", DataType::List(item), true),
+            Field::new("point", DataType::Struct(struct_fields), true),
+            Field::new("map", map.data_type().clone(), false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(list), Arc::new(point), Arc::new(map)],
+        )
+        .unwrap();
+
+        let file = write(std::slice::from_ref(&batch), WriterBuilder::new(schema));
+        let read = read(file);
+        assert_eq!(read.len(), 1);
+        let read = &read[0];
+
+        assert_eq!(read.column(0), batch.column(0));
+        // Child values of null struct slots are not written
+        let expected = batch.column(1).as_any().downcast_ref::<StructArray>();
+        let actual = read.column(1).as_any().downcast_ref::<StructArray>();
+        assert_eq!(actual.unwrap().slice(0, 2), expected.unwrap().slice(0, 2));
+        assert!(actual.unwrap().is_null(2));
+
+        let actual = read.column(2).as_any().downcast_ref::<MapArray>().unwrap();
+        let expected = batch.column(2).as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(actual.value_offsets(), expected.value_offsets());
+        assert_eq!(actual.keys(), expected.keys());
+        assert_eq!(actual.values(), expected.values());
+    }
+
+    #[test]
+    fn test_roundtrip_union_dictionary() {
+        let fields = UnionFields::new(
+            [0, 1, 2],
+            [
+                Field::new("long", DataType::Int64, false),
+                Field::new("string", DataType::Utf8, false),
+                Field::new("null", DataType::Null, true),
+            ],
+        );
+        let union = UnionArray::try_new(
+            fields.clone(),
+            vec![1, 0, 2, 0].into(),
+            Some(vec![0, 0, 0, 1].into()),
+            vec![
+                Arc::new(Int64Array::from(vec![5, 6])),
+                Arc::new(StringArray::from(vec!["five"])),
+                Arc::new(NullArray::new(1)),
+            ],
+        )
+        .unwrap();
+        let dictionary: DictionaryArray<Int32Type> = vec![Some("a"), Some("b"), None, Some("a")]
+            .into_iter()
+            .collect();
+
+        let batch = RecordBatch::try_from_iter([
+            ("union", Arc::new(union) as ArrayRef),
+            ("dict", Arc::new(dictionary) as ArrayRef),
+        ])
+        .unwrap();
+        let file = write(
+            std::slice::from_ref(&batch),
+            WriterBuilder::new(batch.schema()),
+        );
+        let read = read(file);
+        assert_eq!(read[0].column(0), batch.column(0));
+
+        let expected = StringArray::from(vec![Some("a"), Some("b"), None, Some("a")]);
+        assert_eq!(read[0].column(1).as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_avro_schema() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new(
+                "nested",
+                DataType::Struct(
+                    vec![Field::new("fixed", DataType::FixedSizeBinary(2), true)].into(),
+                ),
+                true,
+            ),
+            Field::new("fixed", DataType::FixedSizeBinary(4), false),
+        ]));
+        let writer = WriterBuilder::new(schema).build(Vec::new()).unwrap();
+        let expected = serde_json::json!({
+            "type": "record",
+            "name": "topLevelRecord",
+            "fields": [
+                {"name": "id", "type": "long"},
+                {"name": "nested", "type": ["null", {
+                    "type": "record",
+                    "name": "nested",
+                    "fields": [
+                        {"name": "fixed", "type": ["null", {"type": "fixed", "name": "fixed", "size": 2}]}
+                    ]
+                }]},
+                {"name": "fixed", "type": {"type": "fixed", "name": "fixed_1", "size": 4}}
+            ]
+        });
+        let actual: serde_json::Value = serde_json::from_str(writer.avro_schema()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_errors() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a b", DataType::Int32, false)]));
+        let err = WriterBuilder::new(schema).build(Vec::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Field name \"a b\" is not a valid Avro name"
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Float16, false)]));
+        let err = WriterBuilder::new(schema).build(Vec::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Writing Float16 to Avro is not yet supported"
+        );
+
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(UInt64Array::from(vec![1, u64::MAX])) as _,
+        )])
+        .unwrap();
+        let mut writer = WriterBuilder::new(batch.schema())
+            .build(Vec::new())
+            .unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Value 18446744073709551615 overflows Avro long"
+        );
+        // The first row is still written
+        let read = read(writer.into_inner().unwrap());
+        let expected = Int64Array::from(vec![1]);
+        assert_eq!(read[0].column(0).as_ref(), &expected as &dyn Array);
+
+        let other =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1])) as _)]).unwrap();
+        let mut writer = WriterBuilder::new(batch.schema())
+            .build(Vec::new())
+            .unwrap();
+        let err = writer.write(&other).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: RecordBatch schema does not match the schema of the Avro writer"
+        );
+    }
+}
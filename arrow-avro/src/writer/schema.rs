@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Conversion of an Arrow [`Schema`] to an Avro schema
+
+use arrow_schema::{ArrowError, DataType, Field, Fields, IntervalUnit, Schema, TimeUnit};
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+/// The name of the top-level Avro record
+pub(crate) const RECORD_NAME: &str = "topLevelRecord";
+
+/// Returns true if `field` is encoded as an Avro union of `"null"` and its type
+///
+/// [`DataType::Null`] is always encoded as `"null"`, and as unions may not
+/// immediately contain other unions, the nullability of [`DataType::Union`]
+/// is instead expressed by its variants
+pub(crate) fn is_nullable(field: &Field) -> bool {
+    field.is_nullable() && !matches!(field.data_type(), DataType::Null | DataType::Union(_, _))
+}
+
+/// Returns true if `field` is a [`DataType::FixedSizeBinary`] with the `arrow.uuid` extension type
+pub(crate) fn is_uuid(field: &Field) -> bool {
+    matches!(field.data_type(), DataType::FixedSizeBinary(16))
+        && field
+            .metadata()
+            .get("ARROW:extension:name")
+            .map(|x| x == "arrow.uuid")
+            .unwrap_or(false)
+}
+
+/// Converts an Arrow [`Schema`] to the JSON representation of an Avro record schema
+pub(crate) fn to_avro_schema(schema: &Schema) -> Result<Value, ArrowError> {
+    let mut converter = SchemaConverter::default();
+    converter.record(RECORD_NAME, schema.fields())
+}
+
+/// Converts Arrow types to Avro schemas, ensuring that the names of named Avro types are unique
+#[derive(Debug, Default)]
+struct SchemaConverter {
+    names: HashSet<String>,
+}
+
+impl SchemaConverter {
+    /// Returns a unique Avro name derived from `name`
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut out: String = name
+            .chars()
+            .map(|c| match c.is_ascii_alphanumeric() {
+                true => c,
+                false => '_',
+            })
+            .collect();
+        if !out.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            out.insert(0, '_');
+        }
+
+        let mut candidate = out.clone();
+        let mut suffix = 1;
+        while !self.names.insert(candidate.clone()) {
+            candidate = format!("{out}_{suffix}");
+            suffix += 1;
+        }
+        candidate
+    }
+
+    fn record(&mut self, name: &str, fields: &Fields) -> Result<Value, ArrowError> {
+        let name = self.unique_name(name);
+        let mut seen = HashSet::with_capacity(fields.len());
+        let fields = fields
+            .iter()
+            .map(|field| {
+                if !is_valid_name(field.name()) {
+                    return Err(ArrowError::SchemaError(format!(
+                        "Field name \"{}\" is not a valid Avro name",
+                        field.name()
+                    )));
+                }
+                if !seen.insert(field.name().as_str()) {
+                    return Err(ArrowError::SchemaError(format!(
+                        "Duplicate field name \"{}\" in Avro record",
+                        field.name()
+                    )));
+                }
+                let data_type = self.field(field, is_nullable(field))?;
+                Ok(json!({"name": field.name(), "type": data_type}))
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        Ok(json!({"type": "record", "name": name, "fields": fields}))
+    }
+
+    /// Returns the Avro schema of `field`, wrapped in a union with `"null"` if `nullable`
+    fn field(&mut self, field: &Field, nullable: bool) -> Result<Value, ArrowError> {
+        let schema = self.data_type(field)?;
+        Ok(match nullable {
+            true => json!(["null", schema]),
+            false => schema,
+        })
+    }
+
+    fn data_type(&mut self, field: &Field) -> Result<Value, ArrowError> {
+        let logical = |t: &str, logical: &str| json!({"type": t, "logicalType": logical});
+        Ok(match field.data_type() {
+            DataType::Null => json!("null"),
+            DataType::Boolean => json!("boolean"),
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::UInt8
+            | DataType::UInt16 => json!("int"),
+            DataType::Int64 | DataType::UInt32 | DataType::UInt64 => json!("long"),
+            DataType::Float32 => json!("float"),
+            DataType::Float64 => json!("double"),
+            DataType::Binary | DataType::LargeBinary => json!("bytes"),
+            DataType::Utf8 | DataType::LargeUtf8 => json!("string"),
+            DataType::FixedSizeBinary(_) if is_uuid(field) => logical("string", "uuid"),
+            DataType::FixedSizeBinary(size) => {
+                let name = self.unique_name(field.name());
+                json!({"type": "fixed", "name": name, "size": size})
+            }
+            DataType::Date32 => logical("int", "date"),
+            DataType::Time32(TimeUnit::Millisecond) => logical("int", "time-millis"),
+            DataType::Time64(TimeUnit::Microsecond) => logical("long", "time-micros"),
+            DataType::Timestamp(unit @ (TimeUnit::Millisecond | TimeUnit::Microsecond), tz) => {
+                let unit = match unit {
+                    TimeUnit::Millisecond => "millis",
+                    _ => "micros",
+                };
+                match tz {
+                    Some(_) => logical("long", &format!("timestamp-{unit}")),
+                    None => logical("long", &format!("local-timestamp-{unit}")),
+                }
+            }
+            DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+                if *scale < 0 {
+                    return Err(ArrowError::SchemaError(format!(
+                        "Avro decimal does not support negative scale {scale}"
+                    )));
+                }
+                json!({
+                    "type": "bytes",
+                    "logicalType": "decimal",
+                    "precision": precision,
+                    "scale": scale
+                })
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                let name = self.unique_name(field.name());
+                json!({"type": "fixed", "name": name, "size": 12, "logicalType": "duration"})
+            }
+            DataType::List(item) | DataType::LargeList(item) => {
+                json!({"type": "array", "items": self.field(item, is_nullable(item))?})
+            }
+            DataType::Map(entries, _) => {
+                let (key, value) = match entries.data_type() {
+                    DataType::Struct(f) if f.len() == 2 => (&f[0], &f[1]),
+                    d => unreachable!("invalid map entries type {d}"),
+                };
+                if key.data_type() != &DataType::Utf8 {
+                    return Err(ArrowError::SchemaError(format!(
+                        "Avro map keys must be strings, got {}",
+                        key.data_type()
+                    )));
+                }
+                json!({"type": "map", "values": self.field(value, is_nullable(value))?})
+            }
+            DataType::Struct(fields) => self.record(field.name(), fields)?,
+            DataType::Union(fields, _) => {
+                let variants = fields
+                    .iter()
+                    .map(|(_, f)| self.field(f, false))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::Array(variants)
+            }
+            DataType::Dictionary(_, value) => {
+                let value = Field::new(field.name(), value.as_ref().clone(), false);
+                self.data_type(&value)?
+            }
+            d => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Writing {d} to Avro is not yet supported"
+                )))
+            }
+        })
+    }
+}
+
+/// Returns true if `name` is a valid Avro name
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#names>
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -56,6 +56,54 @@ impl Debug for Deallocation {
     }
 }
 
+/// Records the allocation of `size` bytes with the installed [`MemoryPool`], if any
+///
+/// [`MemoryPool`]: crate::pool::MemoryPool
+#[inline(always)]
+pub(crate) fn pool_grow(size: usize) {
+    #[cfg(feature = "pool")]
+    if let Some(pool) = crate::pool::memory_pool().filter(|_| size != 0) {
+        pool.grow(size)
+    }
+    #[cfg(not(feature = "pool"))]
+    let _ = size;
+}
+
+/// Reserves `size` bytes with the installed [`MemoryPool`], if any, returning an error
+/// if this would exceed its limit
+///
+/// [`MemoryPool`]: crate::pool::MemoryPool
+#[cfg(feature = "pool")]
+#[inline]
+pub(crate) fn pool_try_grow(size: usize) -> Result<(), crate::pool::MemoryLimitError> {
+    match crate::pool::memory_pool().filter(|_| size != 0) {
+        Some(pool) => pool.try_grow(size),
+        None => Ok(()),
+    }
+}
+
+/// Records the release of `size` bytes with the installed [`MemoryPool`], if any
+///
+/// [`MemoryPool`]: crate::pool::MemoryPool
+#[inline(always)]
+pub(crate) fn pool_shrink(size: usize) {
+    #[cfg(feature = "pool")]
+    if let Some(pool) = crate::pool::memory_pool().filter(|_| size != 0) {
+        pool.shrink(size)
+    }
+    #[cfg(not(feature = "pool"))]
+    let _ = size;
+}
+
+/// Records a change in the size of an allocation from `old` to `new` bytes
+#[inline(always)]
+pub(crate) fn pool_resize(old: usize, new: usize) {
+    match new.checked_sub(old) {
+        Some(grow) => pool_grow(grow),
+        None => pool_shrink(old - new),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::alloc::Deallocation;
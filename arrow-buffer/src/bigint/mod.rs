@@ -512,6 +512,104 @@ impl i256 {
         self.div_rem(other).map(|(_, v)| v).ok()
     }
 
+    /// Performs wrapping division, returning both the quotient and the remainder
+    ///
+    /// `i256::MIN / -1` wraps around to `(i256::MIN, 0)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero
+    #[inline]
+    pub fn wrapping_div_rem(self, other: Self) -> (Self, Self) {
+        match self.div_rem(other) {
+            Ok(v) => v,
+            Err(DivRemError::DivideByZero) => panic!("attempt to divide by zero"),
+            Err(_) => (Self::MIN, Self::ZERO),
+        }
+    }
+
+    /// Performs checked division, returning both the quotient and the remainder
+    #[inline]
+    pub fn checked_div_rem(self, other: Self) -> Option<(Self, Self)> {
+        self.div_rem(other).ok()
+    }
+
+    /// Returns the square root of this [`i256`] rounded down, or `None` if it is negative
+    pub fn checked_isqrt(self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        if self <= Self::ONE {
+            return Some(self);
+        }
+
+        let leading_zeros = match self.high {
+            0 => 128 + self.low.leading_zeros(),
+            high => high.leading_zeros(),
+        };
+        // 2^ceil(bits / 2) is an upper bound of the square root, from which
+        // Newton's method decreases monotonically to the result
+        let bits = 256 - leading_zeros;
+        let mut x = Self::ONE << ((bits + 1) / 2) as u8;
+        loop {
+            let y = x.wrapping_add(self.wrapping_div(x)) >> 1;
+            if y >= x {
+                return Some(x);
+            }
+            x = y;
+        }
+    }
+
+    /// Returns the square root of this [`i256`] rounded down
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`i256`] is negative
+    pub fn isqrt(self) -> Self {
+        self.checked_isqrt()
+            .expect("argument of integer square root cannot be negative")
+    }
+
+    /// Parses an [`i256`] from a string of digits in the given `radix`, with an
+    /// optional `+` or `-` sign, as [`i128::from_str_radix`]
+    ///
+    /// ```
+    /// # use arrow_buffer::i256;
+    /// assert_eq!(i256::from_str_radix("-ff", 16).unwrap(), i256::from_i128(-255));
+    /// assert_eq!(i256::from_str_radix(&"7".repeat(64), 16).unwrap() >> 252, i256::from_i128(7));
+    /// assert!(i256::from_str_radix(&"8".repeat(64), 16).is_err());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseI256Error> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]` - found {radix}"
+        );
+        let (negative, digits) = match src.as_bytes().first() {
+            Some(b'-') => (true, &src[1..]),
+            Some(b'+') => (false, &src[1..]),
+            _ => (false, src),
+        };
+        if digits.is_empty() {
+            return Err(ParseI256Error {});
+        }
+
+        let base = Self::from_i128(radix as i128);
+        digits.chars().try_fold(Self::ZERO, |acc, c| {
+            let digit = Self::from_i128(c.to_digit(radix).ok_or(ParseI256Error {})? as i128);
+            let acc = acc.checked_mul(base).ok_or(ParseI256Error {})?;
+            // Accumulate negative values directly, as i256::MIN has no positive counterpart
+            match negative {
+                true => acc.checked_sub(digit),
+                false => acc.checked_add(digit),
+            }
+            .ok_or(ParseI256Error {})
+        })
+    }
+
     /// Performs checked exponentiation
     #[inline]
     pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
@@ -984,6 +1082,34 @@ mod tests {
             assert!(il.checked_rem(ir).is_none());
         }
 
+        // Division with remainder
+        if ir != i256::ZERO {
+            let (div, rem) = il.wrapping_div_rem(ir);
+            assert_eq!(div, il.wrapping_div(ir), "{il} / {ir}");
+            assert_eq!(rem, il.wrapping_rem(ir), "{il} % {ir}");
+
+            let checked = il.checked_div_rem(ir);
+            assert_eq!(checked, il.checked_div(ir).zip(il.checked_rem(ir)));
+        } else {
+            assert!(il.checked_div_rem(ir).is_none());
+        }
+
+        // Square root
+        match bl.is_negative() {
+            true => assert!(il.checked_isqrt().is_none()),
+            false => {
+                let expected = bl.sqrt();
+                assert_eq!(il.isqrt().to_string(), expected.to_string(), "sqrt({il})");
+                assert_eq!(il.checked_isqrt(), Some(il.isqrt()));
+            }
+        }
+
+        // Radix parsing
+        for radix in [2, 8, 10, 16, 36] {
+            let formatted = bl.to_str_radix(radix);
+            assert_eq!(i256::from_str_radix(&formatted, radix).unwrap(), il);
+        }
+
         // Exponentiation
         for exp in vec![0, 1, 2, 3, 8, 100].into_iter() {
             let actual = il.wrapping_pow(exp);
@@ -1067,6 +1193,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_str_radix() {
+        let cases = [
+            ("0", 10, Some(i256::ZERO)),
+            ("+101", 2, Some(i256::from_i128(5))),
+            ("-zz", 36, Some(i256::from_i128(-1295))),
+            ("FF", 16, Some(i256::from_i128(255))),
+            ("", 10, None),
+            ("-", 10, None),
+            ("+-1", 10, None),
+            ("12a", 10, None),
+            ("2", 2, None),
+            (" 1", 10, None),
+        ];
+        for (s, radix, expected) in cases {
+            assert_eq!(i256::from_str_radix(s, radix).ok(), expected, "{s}");
+        }
+
+        // Bounds
+        let max = format!("7{}", "f".repeat(63));
+        assert_eq!(i256::from_str_radix(&max, 16).unwrap(), i256::MAX);
+        let min = format!("-8{}", "0".repeat(63));
+        assert_eq!(i256::from_str_radix(&min, 16).unwrap(), i256::MIN);
+        let overflow = format!("8{}", "0".repeat(63));
+        assert!(i256::from_str_radix(&overflow, 16).is_err());
+        let overflow = format!("-8{}1", "0".repeat(62));
+        assert!(i256::from_str_radix(&overflow, 16).is_err());
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(i256::from_i128(24).isqrt(), i256::from_i128(4));
+        assert_eq!(i256::from_i128(25).isqrt(), i256::from_i128(5));
+        for v in [
+            i256::MAX,
+            i256::from_parts(0, 1),
+            i256::from_i128(i128::MAX),
+        ] {
+            let root = v.isqrt();
+            assert!(root.checked_mul(root).unwrap() <= v);
+            let next = root + i256::ONE;
+            assert!(next.checked_mul(next).map(|n| n > v).unwrap_or(true));
+        }
+        assert!(i256::MINUS_ONE.checked_isqrt().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn test_div_rem_zero() {
+        i256::ONE.wrapping_div_rem(i256::ZERO);
+    }
+
     #[test]
     fn test_signed_ops() {
         // signum
@@ -201,6 +201,12 @@ impl BooleanBuffer {
     }
 
     /// Returns a [`BitSliceIterator`] yielding contiguous ranges of set bits
+    ///
+    /// ```
+    /// # use arrow_buffer::BooleanBuffer;
+    /// let buffer = BooleanBuffer::from(vec![true, true, false, false, true]);
+    /// assert_eq!(buffer.set_slices().collect::<Vec<_>>(), vec![(0, 2), (4, 5)]);
+    /// ```
     pub fn set_slices(&self) -> BitSliceIterator<'_> {
         BitSliceIterator::new(self.values(), self.offset, self.len)
     }
@@ -424,4 +430,19 @@ mod tests {
         assert_eq!(buf.values().len(), 1);
         assert!(buf.value(0));
     }
+
+    #[test]
+    fn test_boolean_set_slices() {
+        let v: Vec<bool> = (0..200).map(|i| i % 70 < 65 && i != 3).collect();
+        let buf = BooleanBuffer::from(v.as_slice());
+        let expected = vec![(0, 3), (4, 65), (70, 135), (140, 200)];
+        assert_eq!(buf.set_slices().collect::<Vec<_>>(), expected);
+
+        let sliced = buf.slice(2, 130);
+        let expected = vec![(0, 1), (2, 63), (68, 130)];
+        assert_eq!(sliced.set_slices().collect::<Vec<_>>(), expected);
+
+        assert_eq!(BooleanBuffer::new_unset(10).set_slices().count(), 0);
+        assert_eq!(BooleanBuffer::new_set(0).set_slices().count(), 0);
+    }
 }
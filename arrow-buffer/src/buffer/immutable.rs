@@ -20,7 +20,7 @@ use std::fmt::Debug;
 use std::ptr::NonNull;
 use std::sync::Arc;
 
-use crate::alloc::{Allocation, Deallocation, ALIGNMENT};
+use crate::alloc::{pool_grow, pool_shrink, Allocation, Deallocation, ALIGNMENT};
 use crate::util::bit_chunk_iterator::{BitChunks, UnalignedBitChunk};
 use crate::BufferBuilder;
 use crate::{bit_util, bytes::Bytes, native::ArrowNativeType};
@@ -116,6 +116,7 @@ impl Buffer {
     pub unsafe fn from_raw_parts(ptr: NonNull<u8>, len: usize, capacity: usize) -> Self {
         assert!(len <= capacity);
         let layout = Layout::from_size_align(capacity, ALIGNMENT).unwrap();
+        pool_grow(layout.size());
         Buffer::build_with_arguments(ptr, len, Deallocation::Standard(layout))
     }
 
@@ -365,6 +366,7 @@ impl Buffer {
         Arc::try_unwrap(self.data)
             .map(|bytes| unsafe {
                 let ptr = bytes.ptr().as_ptr() as _;
+                pool_shrink(bytes.capacity());
                 std::mem::forget(bytes);
                 // Safety
                 // Verified that bytes layout matches that of Vec
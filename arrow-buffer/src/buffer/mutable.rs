@@ -19,7 +19,9 @@ use std::alloc::{handle_alloc_error, Layout};
 use std::mem;
 use std::ptr::NonNull;
 
-use crate::alloc::{Deallocation, ALIGNMENT};
+use crate::alloc::{pool_grow, pool_resize, pool_shrink, Deallocation, ALIGNMENT};
+#[cfg(feature = "pool")]
+use crate::{alloc::pool_try_grow, pool::MemoryLimitError};
 use crate::{
     bytes::Bytes,
     native::{ArrowNativeType, ToByteSlice},
@@ -76,9 +78,34 @@ impl MutableBuffer {
     /// then `isize::MAX`, then this function will panic.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
+        let layout = Self::capacity_layout(capacity);
+        pool_grow(layout.size());
+        Self::allocate(layout)
+    }
+
+    /// Allocate a new [MutableBuffer] with initial capacity to be at least `capacity`,
+    /// returning an error if this would exceed the limit of the installed [`MemoryPool`]
+    ///
+    /// # Panics
+    ///
+    /// See [`MutableBuffer::with_capacity`]
+    ///
+    /// [`MemoryPool`]: crate::pool::MemoryPool
+    #[cfg(feature = "pool")]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, MemoryLimitError> {
+        let layout = Self::capacity_layout(capacity);
+        pool_try_grow(layout.size())?;
+        Ok(Self::allocate(layout))
+    }
+
+    fn capacity_layout(capacity: usize) -> Layout {
         let capacity = bit_util::round_upto_multiple_of_64(capacity);
-        let layout = Layout::from_size_align(capacity, ALIGNMENT)
-            .expect("failed to create layout for MutableBuffer");
+        Layout::from_size_align(capacity, ALIGNMENT)
+            .expect("failed to create layout for MutableBuffer")
+    }
+
+    /// Allocates `layout` without recording it with the memory pool
+    fn allocate(layout: Layout) -> Self {
         let data = match layout.size() {
             0 => dangling_ptr(),
             _ => {
@@ -107,6 +134,7 @@ impl MutableBuffer {
     /// ```
     pub fn from_len_zeroed(len: usize) -> Self {
         let layout = Layout::from_size_align(len, ALIGNMENT).unwrap();
+        pool_grow(layout.size());
         let data = match layout.size() {
             0 => dangling_ptr(),
             _ => {
@@ -197,9 +225,33 @@ impl MutableBuffer {
         }
     }
 
+    /// Ensures that this buffer has at least `self.len + additional` bytes, see
+    /// [`MutableBuffer::reserve`], returning an error if this would exceed the limit of
+    /// the installed [`MemoryPool`]
+    ///
+    /// [`MemoryPool`]: crate::pool::MemoryPool
+    #[cfg(feature = "pool")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), MemoryLimitError> {
+        let required_cap = self.len + additional;
+        if required_cap > self.layout.size() {
+            let new_capacity = bit_util::round_upto_multiple_of_64(required_cap);
+            let new_capacity = std::cmp::max(new_capacity, self.layout.size() * 2);
+            let new_layout = Layout::from_size_align(new_capacity, self.layout.align()).unwrap();
+            pool_try_grow(new_layout.size() - self.layout.size())?;
+            self.realloc(new_layout)
+        }
+        Ok(())
+    }
+
     #[cold]
     fn reallocate(&mut self, capacity: usize) {
         let new_layout = Layout::from_size_align(capacity, self.layout.align()).unwrap();
+        pool_resize(self.layout.size(), new_layout.size());
+        self.realloc(new_layout)
+    }
+
+    /// Reallocates this buffer to `new_layout` without recording it with the memory pool
+    fn realloc(&mut self, new_layout: Layout) {
         if new_layout.size() == 0 {
             if self.layout.size() != 0 {
                 // Safety: data was allocated with layout
@@ -213,7 +265,7 @@ impl MutableBuffer {
             // Safety: new_layout is not empty
             0 => unsafe { std::alloc::alloc(new_layout) },
             // Safety: verified new layout is valid and not empty
-            _ => unsafe { std::alloc::realloc(self.as_mut_ptr(), self.layout, capacity) },
+            _ => unsafe { std::alloc::realloc(self.as_mut_ptr(), self.layout, new_layout.size()) },
         };
         self.data = NonNull::new(data).unwrap_or_else(|| handle_alloc_error(new_layout));
         self.layout = new_layout;
@@ -504,6 +556,7 @@ impl<T: ArrowNativeType> From<Vec<T>> for MutableBuffer {
         // Vec guaranteed to have a valid layout matching that of `Layout::array`
         // This is based on `RawVec::current_memory`
         let layout = unsafe { Layout::array::<T>(value.capacity()).unwrap_unchecked() };
+        pool_grow(layout.size());
         mem::forget(value);
         Self { data, len, layout }
     }
@@ -680,6 +733,7 @@ impl Drop for MutableBuffer {
         if self.layout.size() != 0 {
             // Safety: data was allocated with standard allocator with given layout
             unsafe { std::alloc::dealloc(self.data.as_ptr() as _, self.layout) };
+            pool_shrink(self.layout.size());
         }
     }
 }
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::bit_iterator::{BitIndexIterator, BitIterator, BitSliceIterator};
-use crate::buffer::BooleanBuffer;
+use crate::buffer::{bitwise_bin_op_helper, BooleanBuffer};
 use crate::{Buffer, MutableBuffer};
 
 /// A [`BooleanBuffer`] used to encode validity for arrow arrays
@@ -79,6 +79,58 @@ impl NullBuffer {
         }
     }
 
+    /// Computes the intersection of the nulls in two optional [`NullBuffer`]
+    ///
+    /// The result is NULL only where both of the input values are NULL, as is the
+    /// case for operations such as `coalesce` that only yield NULL if all their
+    /// inputs are NULL
+    ///
+    /// ```
+    /// # use arrow_buffer::NullBuffer;
+    /// let lhs = NullBuffer::from(vec![true, false, false, true]);
+    /// let rhs = NullBuffer::from(vec![true, true, false, false]);
+    /// let nulls = NullBuffer::intersection(Some(&lhs), Some(&rhs)).unwrap();
+    /// assert_eq!(nulls.iter().collect::<Vec<_>>(), vec![true, true, false, true]);
+    /// ```
+    pub fn intersection(lhs: Option<&NullBuffer>, rhs: Option<&NullBuffer>) -> Option<NullBuffer> {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(Self::new(lhs.inner() | rhs.inner())),
+            _ => None,
+        }
+    }
+
+    /// Computes the nulls in `lhs` that are not also null in `rhs`
+    ///
+    /// The result is NULL where the value of `lhs` is NULL and the value of `rhs`
+    /// is not, for example to find the slots an operation introduced NULLs in
+    ///
+    /// ```
+    /// # use arrow_buffer::NullBuffer;
+    /// let lhs = NullBuffer::from(vec![true, false, false, true]);
+    /// let rhs = NullBuffer::from(vec![true, true, false, false]);
+    /// let nulls = NullBuffer::difference(Some(&lhs), Some(&rhs)).unwrap();
+    /// assert_eq!(nulls.iter().collect::<Vec<_>>(), vec![true, false, true, true]);
+    /// ```
+    pub fn difference(lhs: Option<&NullBuffer>, rhs: Option<&NullBuffer>) -> Option<NullBuffer> {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => {
+                let (l, r) = (lhs.inner(), rhs.inner());
+                assert_eq!(l.len(), r.len());
+                let buffer = bitwise_bin_op_helper(
+                    l.inner(),
+                    l.offset(),
+                    r.inner(),
+                    r.offset(),
+                    l.len(),
+                    |a, b| a | !b,
+                );
+                Some(Self::new(BooleanBuffer::new(buffer, 0, l.len())))
+            }
+            (Some(lhs), None) => Some(lhs.clone()),
+            (None, _) => None,
+        }
+    }
+
     /// Returns true if all nulls in `other` also exist in self
     pub fn contains(&self, other: &NullBuffer) -> bool {
         if other.null_count == 0 {
@@ -269,4 +321,50 @@ mod tests {
             std::mem::size_of::<Option<NullBuffer>>()
         );
     }
+
+    #[test]
+    fn test_set_algebra() {
+        let a = NullBuffer::from(vec![true, true, false, false, true, false, true, true]);
+        let b = NullBuffer::from(vec![true, false, true, false, false, true, true, true]);
+
+        let check = |nulls: Option<NullBuffer>, expected: &[bool]| {
+            assert_eq!(nulls.unwrap().iter().collect::<Vec<_>>(), expected);
+        };
+
+        check(
+            NullBuffer::union(Some(&a), Some(&b)),
+            &[true, false, false, false, false, false, true, true],
+        );
+        check(
+            NullBuffer::intersection(Some(&a), Some(&b)),
+            &[true, true, true, false, true, true, true, true],
+        );
+        check(
+            NullBuffer::difference(Some(&a), Some(&b)),
+            &[true, true, false, true, true, false, true, true],
+        );
+        check(
+            NullBuffer::difference(Some(&b), Some(&a)),
+            &[true, false, true, true, false, true, true, true],
+        );
+
+        // Sliced inputs with different offsets
+        let (a, b) = (a.slice(1, 5), b.slice(3, 5));
+        check(
+            NullBuffer::intersection(Some(&a), Some(&b)),
+            &[true, false, true, true, true],
+        );
+        check(
+            NullBuffer::difference(Some(&a), Some(&b)),
+            &[true, true, false, true, false],
+        );
+        let nulls = NullBuffer::difference(Some(&a), Some(&b)).unwrap();
+        assert_eq!(nulls.null_count(), 2);
+
+        // Absent buffers have no nulls
+        assert_eq!(NullBuffer::intersection(Some(&a), None), None);
+        assert_eq!(NullBuffer::intersection(None, Some(&b)), None);
+        assert_eq!(NullBuffer::difference(Some(&a), None), Some(a.clone()));
+        assert_eq!(NullBuffer::difference(None, Some(&b)), None);
+    }
 }
@@ -23,7 +23,7 @@ use core::slice;
 use std::ptr::NonNull;
 use std::{fmt::Debug, fmt::Formatter};
 
-use crate::alloc::Deallocation;
+use crate::alloc::{pool_resize, pool_shrink, Deallocation};
 use crate::buffer::dangling_ptr;
 
 /// A continuous, fixed-size, immutable memory region that knows how to de-allocate itself.
@@ -131,6 +131,7 @@ impl Bytes {
                     self.ptr = ptr;
                     self.len = new_len;
                     self.deallocation = Deallocation::Standard(new_layout);
+                    pool_resize(old_layout.size(), new_len);
                     return Ok(());
                 }
             }
@@ -156,7 +157,10 @@ impl Drop for Bytes {
         match &self.deallocation {
             Deallocation::Standard(layout) => match layout.size() {
                 0 => {} // Nothing to do
-                _ => unsafe { std::alloc::dealloc(self.ptr.as_ptr(), *layout) },
+                size => {
+                    unsafe { std::alloc::dealloc(self.ptr.as_ptr(), *layout) };
+                    pool_shrink(size)
+                }
             },
             // The automatic drop implementation will free the memory once the reference count reaches zero
             Deallocation::Custom(_allocation, _size) => (),
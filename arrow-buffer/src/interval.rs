@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::arith::derive_arith;
+use std::cmp::Ordering;
 use std::ops::Neg;
 
 /// Value of an IntervalMonthDayNano array
@@ -65,6 +66,9 @@ use std::ops::Neg;
 /// `IntervalMonthDayNano` of `1 month` will compare as **greater** than a
 /// `IntervalMonthDayNano` of `100 days` because the binary representation of `1 month`
 /// is larger than the binary representation of 100 days.
+///
+/// [`IntervalMonthDayNano::cmp_normalized`] instead compares intervals by their
+/// approximate length, treating a month as 30 days and a day as 24 hours.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[repr(C)]
 pub struct IntervalMonthDayNano {
@@ -102,6 +106,38 @@ impl IntervalMonthDayNano {
         }
     }
 
+    /// Returns the approximate length of this interval in nanoseconds, treating a
+    /// month as 30 days and a day as 24 hours
+    #[inline]
+    pub const fn normalized_nanoseconds(self) -> i128 {
+        const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+        (self.months as i128 * 30 + self.days as i128) * NANOS_PER_DAY + self.nanoseconds as i128
+    }
+
+    /// Compares this interval with `other` by their [normalized length], ordering
+    /// intervals of the same normalized length by their binary representation
+    ///
+    /// Unlike [`Ord`], this orders `1 month` before `100 days`. It is a total order
+    /// consistent with [`Eq`], with `1 month` ordered after `30 days`.
+    ///
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use arrow_buffer::IntervalMonthDayNano;
+    /// let month = IntervalMonthDayNano::new(1, 0, 0);
+    /// assert_eq!(month.cmp(&IntervalMonthDayNano::new(0, 100, 0)), Ordering::Greater);
+    /// assert_eq!(month.cmp_normalized(&IntervalMonthDayNano::new(0, 100, 0)), Ordering::Less);
+    /// assert_eq!(month.cmp_normalized(&IntervalMonthDayNano::new(0, 30, 0)), Ordering::Greater);
+    /// assert_eq!(month.cmp_normalized(&IntervalMonthDayNano::new(0, 30, 1)), Ordering::Less);
+    /// ```
+    ///
+    /// [normalized length]: Self::normalized_nanoseconds
+    #[inline]
+    pub fn cmp_normalized(&self, other: &Self) -> Ordering {
+        self.normalized_nanoseconds()
+            .cmp(&other.normalized_nanoseconds())
+            .then_with(|| self.cmp(other))
+    }
+
     /// Computes the absolute value
     #[inline]
     pub fn wrapping_abs(self) -> Self {
@@ -345,6 +381,9 @@ derive_arith!(
 /// Values of `IntervalDayTime` are compared using their binary representation,
 /// which can lead to surprising results. Please see the description of ordering on
 /// [`IntervalMonthDayNano`] for more details
+///
+/// [`IntervalDayTime::cmp_normalized`] instead compares intervals by their length,
+/// treating a day as 24 hours.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[repr(C)]
 pub struct IntervalDayTime {
@@ -376,6 +415,34 @@ impl IntervalDayTime {
         Self { days, milliseconds }
     }
 
+    /// Returns the approximate length of this interval in milliseconds, treating a
+    /// day as 24 hours
+    #[inline]
+    pub const fn normalized_milliseconds(self) -> i64 {
+        self.days as i64 * 86_400_000 + self.milliseconds as i64
+    }
+
+    /// Compares this interval with `other` by their [normalized length], ordering
+    /// intervals of the same normalized length by their binary representation
+    ///
+    /// See [`IntervalMonthDayNano::cmp_normalized`]
+    ///
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use arrow_buffer::IntervalDayTime;
+    /// let day = IntervalDayTime::new(1, 0);
+    /// assert_eq!(day.cmp(&IntervalDayTime::new(0, 90_000_000)), Ordering::Greater);
+    /// assert_eq!(day.cmp_normalized(&IntervalDayTime::new(0, 90_000_000)), Ordering::Less);
+    /// ```
+    ///
+    /// [normalized length]: Self::normalized_milliseconds
+    #[inline]
+    pub fn cmp_normalized(&self, other: &Self) -> Ordering {
+        self.normalized_milliseconds()
+            .cmp(&other.normalized_milliseconds())
+            .then_with(|| self.cmp(other))
+    }
+
     /// Computes the absolute value
     #[inline]
     pub fn wrapping_abs(self) -> Self {
@@ -23,6 +23,8 @@
 
 pub mod alloc;
 pub mod buffer;
+#[cfg(feature = "pool")]
+pub mod pool;
 pub use buffer::*;
 
 pub mod builder;
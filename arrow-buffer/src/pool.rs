@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tracking of the memory used by Arrow buffers
+//!
+//! A [`MemoryPool`] installed with [`set_memory_pool`] is notified whenever a
+//! [`MutableBuffer`] or [`Buffer`] allocates, reallocates or frees memory with the
+//! standard allocator, including memory adopted from a [`Vec`], allowing applications
+//! to track the memory used by Arrow. Memory from a custom
+//! [`Allocation`](crate::alloc::Allocation), such as an FFI import or a memory mapped
+//! file, is not tracked.
+//!
+//! This module requires the `pool` feature. Without it, no pool can be installed and
+//! buffers perform no accounting.
+//!
+//! Allocations are always recorded with [`MemoryPool::grow`], which cannot fail. A
+//! limit is only enforced by the fallible [`MutableBuffer::try_with_capacity`] and
+//! [`MutableBuffer::try_reserve`], which reserve memory with [`MemoryPool::try_grow`]
+//! and return a [`MemoryLimitError`] instead of allocating beyond it.
+//!
+//! ```
+//! # use arrow_buffer::MutableBuffer;
+//! # use arrow_buffer::pool::{memory_pool, set_memory_pool, MemoryPool, TrackingMemoryPool};
+//! static POOL: TrackingMemoryPool = TrackingMemoryPool::new();
+//! set_memory_pool(&POOL).unwrap();
+//!
+//! let buffer = MutableBuffer::new(1024);
+//! assert!(memory_pool().unwrap().used() >= 1024);
+//! ```
+//!
+//! [`MutableBuffer`]: crate::MutableBuffer
+//! [`MutableBuffer::try_with_capacity`]: crate::MutableBuffer::try_with_capacity
+//! [`MutableBuffer::try_reserve`]: crate::MutableBuffer::try_reserve
+//! [`Buffer`]: crate::Buffer
+
+use std::fmt::{Debug, Display, Formatter};
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+/// A pool notified of the memory allocated and freed by Arrow buffers
+///
+/// See the [module docs](self) for more information
+pub trait MemoryPool: Send + Sync + Debug {
+    /// Records the allocation of `size` bytes
+    ///
+    /// The memory has already been, or is about to be, allocated and so this must
+    /// record it even if doing so exceeds any limit of this pool
+    fn grow(&self, size: usize);
+
+    /// Reserves `size` bytes ahead of a fallible allocation, returning an error instead
+    /// of recording them if this would exceed the limit of this pool
+    ///
+    /// The default implementation records the allocation with [`Self::grow`]
+    fn try_grow(&self, size: usize) -> Result<(), MemoryLimitError> {
+        self.grow(size);
+        Ok(())
+    }
+
+    /// Records that `size` bytes, previously recorded by [`Self::grow`], were freed
+    fn shrink(&self, size: usize);
+
+    /// Returns the number of bytes currently allocated
+    fn used(&self) -> usize;
+}
+
+/// The error returned by [`MemoryPool::try_grow`] when an allocation would exceed the
+/// memory limit of a pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryLimitError {
+    /// The number of bytes requested
+    pub size: usize,
+    /// The number of bytes in use at the time of the request
+    pub used: usize,
+    /// The maximum number of bytes that can be allocated
+    pub limit: usize,
+}
+
+impl Display for MemoryLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to allocate {} bytes, {} of the {} byte memory limit are in use",
+            self.size, self.used, self.limit
+        )
+    }
+}
+
+impl std::error::Error for MemoryLimitError {}
+
+/// A [`MemoryPool`] counting the bytes allocated, optionally up to a limit
+///
+/// The limit is only enforced by [`MemoryPool::try_grow`]
+#[derive(Debug)]
+pub struct TrackingMemoryPool {
+    used: AtomicUsize,
+    limit: usize,
+}
+
+impl TrackingMemoryPool {
+    /// Creates a new [`TrackingMemoryPool`] without a limit
+    pub const fn new() -> Self {
+        Self::with_limit(usize::MAX)
+    }
+
+    /// Creates a new [`TrackingMemoryPool`] that rejects any fallible allocation that
+    /// would take the number of bytes allocated above `limit`
+    pub const fn with_limit(limit: usize) -> Self {
+        Self {
+            used: AtomicUsize::new(0),
+            limit,
+        }
+    }
+
+    /// Returns the maximum number of bytes that can be allocated
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl Default for TrackingMemoryPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryPool for TrackingMemoryPool {
+    fn grow(&self, size: usize) {
+        let _ = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_add(size))
+            });
+    }
+
+    fn try_grow(&self, size: usize) -> Result<(), MemoryLimitError> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(size).filter(|x| *x <= self.limit)
+            })
+            .map(|_| ())
+            .map_err(|used| MemoryLimitError {
+                size,
+                used,
+                limit: self.limit,
+            })
+    }
+
+    fn shrink(&self, size: usize) {
+        // Memory allocated before this pool was installed is not recorded
+        let _ = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(size))
+            });
+    }
+
+    fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+}
+
+static POOL: AtomicPtr<&'static dyn MemoryPool> = AtomicPtr::new(null_mut());
+
+/// Installs `pool` as the [`MemoryPool`] of this process
+///
+/// A pool can only be installed once, returning `Err(pool)` if a pool is already
+/// installed. Memory allocated before the pool is installed is not recorded, and
+/// so the pool should be installed before creating any buffers.
+pub fn set_memory_pool(pool: &'static dyn MemoryPool) -> Result<(), &'static dyn MemoryPool> {
+    let ptr = Box::into_raw(Box::new(pool));
+    match POOL.compare_exchange(null_mut(), ptr, Ordering::AcqRel, Ordering::Acquire) {
+        Ok(_) => Ok(()),
+        Err(_) => {
+            // Safety: `ptr` was created above and not installed
+            drop(unsafe { Box::from_raw(ptr) });
+            Err(pool)
+        }
+    }
+}
+
+/// Returns the [`MemoryPool`] installed by [`set_memory_pool`], if any
+#[inline]
+pub fn memory_pool() -> Option<&'static dyn MemoryPool> {
+    let ptr = POOL.load(Ordering::Acquire);
+    // Safety: a non-null `ptr` was installed by `set_memory_pool` and is never freed
+    (!ptr.is_null()).then(|| unsafe { *ptr })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracking_pool() {
+        let pool = TrackingMemoryPool::with_limit(100);
+        assert_eq!(pool.limit(), 100);
+        pool.grow(60);
+        pool.grow(40);
+        assert_eq!(pool.used(), 100);
+        pool.shrink(30);
+        assert_eq!(pool.used(), 70);
+        pool.shrink(100);
+        assert_eq!(pool.used(), 0);
+
+        let pool = TrackingMemoryPool::new();
+        pool.grow(usize::MAX);
+        assert_eq!(pool.used(), usize::MAX);
+        pool.try_grow(1).unwrap_err();
+    }
+
+    #[test]
+    fn test_tracking_pool_limit() {
+        let pool = TrackingMemoryPool::with_limit(100);
+        pool.try_grow(60).unwrap();
+        let err = pool.try_grow(50).unwrap_err();
+        assert_eq!(
+            err,
+            MemoryLimitError {
+                size: 50,
+                used: 60,
+                limit: 100
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Failed to allocate 50 bytes, 60 of the 100 byte memory limit are in use"
+        );
+        assert_eq!(pool.used(), 60);
+
+        // Infallible allocations are always recorded
+        pool.grow(50);
+        assert_eq!(pool.used(), 110);
+        pool.try_grow(1).unwrap_err();
+    }
+}
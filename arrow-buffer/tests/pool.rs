@@ -0,0 +1,94 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Installing a pool affects the whole process, and so this is the only test in this binary
+
+#![cfg(feature = "pool")]
+
+use arrow_buffer::pool::{memory_pool, set_memory_pool, MemoryPool, TrackingMemoryPool};
+use arrow_buffer::{Buffer, MutableBuffer};
+
+static POOL: TrackingMemoryPool = TrackingMemoryPool::with_limit(1 << 20);
+
+#[test]
+fn test_memory_pool() {
+    set_memory_pool(&POOL).unwrap();
+    assert!(set_memory_pool(&POOL).is_err());
+    assert_eq!(memory_pool().unwrap().used(), 0);
+
+    let mut buffer = MutableBuffer::new(100);
+    assert_eq!(POOL.used(), 128);
+    buffer.extend_from_slice(&[0_u8; 200]);
+    assert_eq!(POOL.used(), 256);
+    buffer.shrink_to_fit();
+    assert_eq!(POOL.used(), 256);
+    buffer.truncate(10);
+    buffer.shrink_to_fit();
+    assert_eq!(POOL.used(), 64);
+
+    // Ownership moves between buffers without further accounting
+    let buffer: Buffer = buffer.into();
+    let cloned = buffer.clone();
+    assert_eq!(POOL.used(), 64);
+    drop(buffer);
+    assert_eq!(POOL.used(), 64);
+    let mut buffer = cloned.into_mutable().unwrap();
+    assert_eq!(POOL.used(), 64);
+    buffer.push(1_u8);
+    drop(buffer);
+    assert_eq!(POOL.used(), 0);
+
+    // Memory adopted from and released to a Vec
+    let buffer = Buffer::from_vec(vec![0_u32; 8]);
+    assert_eq!(POOL.used(), 32);
+    let vec = buffer.into_vec::<u32>().unwrap();
+    assert_eq!(POOL.used(), 0);
+    let buffer = MutableBuffer::from(vec);
+    assert_eq!(POOL.used(), 32);
+    drop(buffer);
+
+    let zeroed = MutableBuffer::from_len_zeroed(1000);
+    assert_eq!(POOL.used(), 1000);
+    drop(zeroed);
+    assert_eq!(POOL.used(), 0);
+
+    // Fallible allocations above the limit fail
+    let err = MutableBuffer::try_with_capacity(2 << 20).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to allocate 2097152 bytes, 0 of the 1048576 byte memory limit are in use"
+    );
+    assert_eq!(POOL.used(), 0);
+
+    let mut buffer = MutableBuffer::try_with_capacity(1000).unwrap();
+    assert_eq!(POOL.used(), 1024);
+    buffer.try_reserve(2000).unwrap();
+    assert_eq!(POOL.used(), 2048);
+    let err = buffer.try_reserve(2 << 20).unwrap_err();
+    assert_eq!(err.used, 2048);
+    assert_eq!(buffer.capacity(), 2048);
+    assert_eq!(POOL.used(), 2048);
+    drop(buffer);
+    assert_eq!(POOL.used(), 0);
+
+    // Infallible allocations are recorded regardless of the limit
+    let buffer = MutableBuffer::new(2 << 20);
+    assert_eq!(POOL.used(), 2 << 20);
+    MutableBuffer::try_with_capacity(1).unwrap_err();
+    drop(buffer);
+    assert_eq!(POOL.used(), 0);
+}
@@ -0,0 +1,308 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::cast::*;
+use arrow_select::concat::concat_batches;
+
+/// Returns true if [`MergeMode::Promote`] promotes `from` to `to`
+fn is_promotion(from: &DataType, to: &DataType) -> bool {
+    let mut merged = Field::new("", to.clone(), true);
+    let from = Field::new("", from.clone(), true);
+    merged
+        .try_merge_with_mode(&from, MergeMode::Promote)
+        .is_ok()
+        && merged.data_type() == to
+}
+
+/// Aligns the columns of `batch` to `schema`, returning a [`RecordBatch`] with `schema`
+///
+/// * Columns are matched by name, and reordered to match `schema`
+/// * Columns of `batch` not present in `schema` are dropped
+/// * Fields of `schema` missing from `batch` are filled with nulls, returning an
+///   error if such a field is not nullable
+/// * Columns with a different data type are cast to the data type of `schema`, provided
+///   it is the result of promoting the column's data type by [`MergeMode::Promote`],
+///   e.g. `Int32` to `Int64` or `Utf8` to `LargeUtf8`
+///
+/// Nested struct columns are cast with [`StructCastMode::ByName`], so their children
+/// are aligned in the same way, regardless of [`CastOptions::struct_cast_mode`]
+pub fn align_batch(
+    batch: &RecordBatch,
+    schema: &SchemaRef,
+    cast_options: &CastOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let cast_options = cast_options
+        .clone()
+        .with_struct_cast_mode(StructCastMode::ByName);
+    let source = batch.schema_ref();
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| match source.column_with_name(field.name()) {
+            Some((idx, _)) => {
+                let column = batch.column(idx);
+                let (from, to) = (column.data_type(), field.data_type());
+                if from == to {
+                    Ok(column.clone())
+                } else if is_promotion(from, to) {
+                    cast_with_options(column, to, &cast_options)
+                } else {
+                    Err(ArrowError::CastError(format!(
+                        "Cannot align column {} of type {from} to {to}",
+                        field.name()
+                    )))
+                }
+            }
+            None if field.is_nullable() => Ok(new_null_array(field.data_type(), batch.num_rows())),
+            None => Err(ArrowError::SchemaError(format!(
+                "Column {} is missing and is not nullable",
+                field.name()
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(schema.clone(), columns, &options)
+}
+
+/// Concatenates `batches` into a single [`RecordBatch`] with `schema`, aligning the
+/// columns of each batch to `schema` as described by [`align_batch`]
+///
+/// Unlike [`concat_batches`] the batches need not have the same schema, allowing, for
+/// example, the results of scanning files written with evolving schemas to be combined.
+/// A suitable `schema` can be determined with [`Schema::try_merge_with_mode`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, Int64Array, RecordBatch, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_cast::{concat_batches_aligned, CastOptions};
+/// # use arrow_schema::{MergeMode, Schema};
+/// let a = RecordBatch::try_from_iter_with_nullable([
+///     ("id", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef, false),
+///     ("name", Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef, true),
+/// ])
+/// .unwrap();
+/// let b = RecordBatch::try_from_iter([
+///     ("id", Arc::new(Int64Array::from(vec![3])) as ArrayRef),
+/// ])
+/// .unwrap();
+///
+/// let schemas = [a.schema().as_ref().clone(), b.schema().as_ref().clone()];
+/// let schema = Arc::new(Schema::try_merge_with_mode(schemas, MergeMode::Promote).unwrap());
+///
+/// let batch = concat_batches_aligned(&schema, [&a, &b], &CastOptions::default()).unwrap();
+/// assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[1, 2, 3]);
+/// assert_eq!(batch.column(1).as_string::<i32>().iter().collect::<Vec<_>>(), [Some("a"), Some("b"), None]);
+/// ```
+pub fn concat_batches_aligned<'a>(
+    schema: &SchemaRef,
+    input_batches: impl IntoIterator<Item = &'a RecordBatch>,
+    cast_options: &CastOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let batches = input_batches
+        .into_iter()
+        .map(|batch| align_batch(batch, schema, cast_options))
+        .collect::<Result<Vec<_>, _>>()?;
+    concat_batches(schema, &batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_buffer::NullBuffer;
+
+    fn options() -> CastOptions<'static> {
+        CastOptions::default()
+    }
+
+    #[test]
+    fn test_align_batch() {
+        let batch = RecordBatch::try_from_iter([
+            ("extra", Arc::new(Int8Array::from(vec![1, 2])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::LargeUtf8, false),
+            Field::new("c", DataType::Float64, true),
+        ]));
+        let aligned = align_batch(&batch, &schema, &options()).unwrap();
+        assert_eq!(aligned.schema(), schema);
+        assert_eq!(
+            aligned.column(0).as_primitive::<Int64Type>().values(),
+            &[1, 2]
+        );
+        assert_eq!(
+            aligned.column(1).as_string::<i64>(),
+            &LargeStringArray::from(vec!["x", "y"])
+        );
+        assert_eq!(aligned.column(2).null_count(), 2);
+
+        // Columns with the target type are not copied
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let aligned = align_batch(&batch, &schema, &options()).unwrap();
+        assert!(Arc::ptr_eq(aligned.column(0), batch.column(2)));
+
+        // Schemas without any columns retain the number of rows
+        let schema = Arc::new(Schema::empty());
+        let aligned = align_batch(&batch, &schema, &options()).unwrap();
+        assert_eq!(aligned.num_rows(), 2);
+    }
+
+    #[test]
+    fn test_align_batch_errors() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+            (
+                "b",
+                Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("c", DataType::Int64, false)]));
+        let err = align_batch(&batch, &schema, &options()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Column c is missing and is not nullable"
+        );
+
+        // Narrowing is not a promotion
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let err = align_batch(&batch, &schema, &options()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot align column a of type Int64 to Int32"
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, false)]));
+        let err = align_batch(&batch, &schema, &options()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot align column a of type Int64 to Utf8"
+        );
+
+        // Null values cannot be written to a non-nullable field
+        let schema = Arc::new(Schema::new(vec![Field::new("b", DataType::Int64, false)]));
+        let err = align_batch(&batch, &schema, &options()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Column 'b' is declared as non-nullable but contains null values"
+        );
+    }
+
+    #[test]
+    fn test_align_nested_struct() {
+        let fields = Fields::from(vec![
+            Field::new("y", DataType::Int32, true),
+            Field::new("x", DataType::Int32, true),
+        ]);
+        let nested = StructArray::new(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(Int32Array::from(vec![3, 4])),
+            ],
+            Some(NullBuffer::from(vec![true, false])),
+        );
+        let batch = RecordBatch::try_from_iter([("s", Arc::new(nested) as ArrayRef)]).unwrap();
+
+        let target = Fields::from(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("y", DataType::Int32, true),
+            Field::new("z", DataType::Utf8, true),
+        ]);
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(target),
+            true,
+        )]));
+
+        let aligned = align_batch(&batch, &schema, &options()).unwrap();
+        let s = aligned.column(0).as_struct();
+        assert_eq!(s.null_count(), 1);
+        assert_eq!(s.column(0).as_primitive::<Int64Type>().value(0), 3);
+        assert_eq!(s.column(1).as_primitive::<Int32Type>().value(0), 1);
+        assert!(s.column(2).is_null(0));
+    }
+
+    #[test]
+    fn test_concat_batches_aligned() {
+        let a = RecordBatch::try_from_iter([
+            ("id", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            (
+                "value",
+                Arc::new(Float32Array::from(vec![0.5, 1.5])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let b = RecordBatch::try_from_iter([
+            ("value", Arc::new(Int64Array::from(vec![3])) as ArrayRef),
+            ("id", Arc::new(UInt32Array::from(vec![3])) as ArrayRef),
+            ("name", Arc::new(StringArray::from(vec!["c"])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let schemas = [a.schema().as_ref().clone(), b.schema().as_ref().clone()];
+        let schema = Schema::try_merge_with_mode(schemas, MergeMode::Promote).unwrap();
+        let schema = Arc::new(schema.with_metadata(Default::default()));
+        assert_eq!(
+            schema.as_ref(),
+            &Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("value", DataType::Float64, false),
+                Field::new("name", DataType::Utf8, false),
+            ])
+        );
+
+        // Name is missing from a, so must be nullable
+        let err = concat_batches_aligned(&schema, [&a, &b], &options()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Column name is missing and is not nullable"
+        );
+
+        let mut fields = schema.fields().to_vec();
+        fields[2] = Arc::new(Field::new("name", DataType::Utf8, true));
+        let schema = Arc::new(Schema::new(fields));
+        let batch = concat_batches_aligned(&schema, [&a, &b, &a], &options()).unwrap();
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>().values(),
+            &[1, 2, 3, 1, 2]
+        );
+        assert_eq!(
+            batch.column(1).as_primitive::<Float64Type>().values(),
+            &[0.5, 1.5, 3.0, 0.5, 1.5]
+        );
+        assert_eq!(
+            batch.column(2).as_string::<i32>(),
+            &StringArray::from(vec![None, None, Some("c"), None, None])
+        );
+
+        let empty = concat_batches_aligned(&schema, [], &options()).unwrap();
+        assert_eq!(empty.num_rows(), 0);
+        assert_eq!(empty.schema(), schema);
+    }
+}
@@ -27,6 +27,34 @@ pub(crate) trait DecimalCast: Sized {
     fn from_decimal<T: DecimalCast>(n: T) -> Option<Self>;
 }
 
+impl DecimalCast for i32 {
+    fn to_i128(self) -> Option<i128> {
+        Some(self as i128)
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(i256::from_i128(self as i128))
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i128().and_then(|x| x.try_into().ok())
+    }
+}
+
+impl DecimalCast for i64 {
+    fn to_i128(self) -> Option<i128> {
+        Some(self as i128)
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(i256::from_i128(self as i128))
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i128().and_then(|x| x.try_into().ok())
+    }
+}
+
 impl DecimalCast for i128 {
     fn to_i128(self) -> Option<i128> {
         Some(self)
@@ -223,6 +251,138 @@ where
     )?))
 }
 
+/// Casts a decimal array to the decimal type `to_type` of a different width
+pub(crate) fn cast_decimal_to_decimal_of_type<I>(
+    array: &PrimitiveArray<I>,
+    input_scale: i8,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    I: DecimalType,
+    I::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    match to_type {
+        DataType::Decimal32(p, s) => {
+            cast_decimal_to_decimal::<I, Decimal32Type>(array, input_scale, *p, *s, cast_options)
+        }
+        DataType::Decimal64(p, s) => {
+            cast_decimal_to_decimal::<I, Decimal64Type>(array, input_scale, *p, *s, cast_options)
+        }
+        DataType::Decimal128(p, s) => {
+            cast_decimal_to_decimal::<I, Decimal128Type>(array, input_scale, *p, *s, cast_options)
+        }
+        DataType::Decimal256(p, s) => {
+            cast_decimal_to_decimal::<I, Decimal256Type>(array, input_scale, *p, *s, cast_options)
+        }
+        _ => unreachable!("{to_type} is not a decimal type"),
+    }
+}
+
+/// Casts a [`Decimal32Array`] or [`Decimal64Array`] to a non-decimal type
+pub(crate) fn cast_from_narrow_decimal<D>(
+    array: &dyn Array,
+    base: D::Native,
+    scale: i8,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    D: DecimalType,
+    D::Native: ArrowNativeTypeOp + ToPrimitive + AsPrimitive<f64>,
+{
+    let div = 10_f64.powi(scale as i32);
+    match to_type {
+        DataType::UInt8 => {
+            cast_decimal_to_integer::<D, UInt8Type>(array, base, scale, cast_options)
+        }
+        DataType::UInt16 => {
+            cast_decimal_to_integer::<D, UInt16Type>(array, base, scale, cast_options)
+        }
+        DataType::UInt32 => {
+            cast_decimal_to_integer::<D, UInt32Type>(array, base, scale, cast_options)
+        }
+        DataType::UInt64 => {
+            cast_decimal_to_integer::<D, UInt64Type>(array, base, scale, cast_options)
+        }
+        DataType::Int8 => cast_decimal_to_integer::<D, Int8Type>(array, base, scale, cast_options),
+        DataType::Int16 => {
+            cast_decimal_to_integer::<D, Int16Type>(array, base, scale, cast_options)
+        }
+        DataType::Int32 => {
+            cast_decimal_to_integer::<D, Int32Type>(array, base, scale, cast_options)
+        }
+        DataType::Int64 => {
+            cast_decimal_to_integer::<D, Int64Type>(array, base, scale, cast_options)
+        }
+        DataType::Float32 => {
+            cast_decimal_to_float::<D, Float32Type, _>(array, |x| (x.as_() / div) as f32)
+        }
+        DataType::Float64 => cast_decimal_to_float::<D, Float64Type, _>(array, |x| x.as_() / div),
+        DataType::Utf8View => value_to_string_view(array, cast_options),
+        DataType::Utf8 => value_to_string::<i32>(array, cast_options),
+        DataType::LargeUtf8 => value_to_string::<i64>(array, cast_options),
+        DataType::Null => Ok(new_null_array(to_type, array.len())),
+        _ => Err(ArrowError::CastError(format!(
+            "Casting from {:?} to {to_type:?} not supported",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Casts an array to a [`Decimal32Array`] or [`Decimal64Array`]
+///
+/// Numeric arrays are first cast to [`Decimal128Array`] with the same precision and scale,
+/// which is then narrowed without loss, as the values are within the precision
+pub(crate) fn cast_to_narrow_decimal<D>(
+    array: &dyn Array,
+    precision: u8,
+    scale: i8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    D: DecimalType,
+    D::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let from_type = array.data_type();
+    match from_type {
+        DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64 => {
+            let decimal = DataType::Decimal128(precision, scale);
+            let array = cast_with_options(array, &decimal, cast_options)?;
+            cast_decimal_to_decimal::<Decimal128Type, D>(
+                array.as_primitive(),
+                scale,
+                precision,
+                scale,
+                cast_options,
+            )
+        }
+        DataType::Utf8View | DataType::Utf8 => {
+            cast_string_to_decimal::<D, i32>(array, precision, scale, cast_options)
+        }
+        DataType::LargeUtf8 => {
+            cast_string_to_decimal::<D, i64>(array, precision, scale, cast_options)
+        }
+        DataType::Null => Ok(new_null_array(
+            &D::TYPE_CONSTRUCTOR(precision, scale),
+            array.len(),
+        )),
+        _ => Err(ArrowError::CastError(format!(
+            "Casting from {from_type:?} to {:?} not supported",
+            D::TYPE_CONSTRUCTOR(precision, scale)
+        ))),
+    }
+}
+
 /// Parses given string to specified decimal native (i128/i256) based on given
 /// scale. Returns an `Err` if it cannot parse given string.
 pub(crate) fn parse_string_to_decimal_native<T: DecimalType>(
@@ -39,21 +39,43 @@ pub(crate) fn dictionary_cast<K: ArrowDictionaryKeyType>(
                     )
                 })?;
 
+            // Keys and values are cast independently, avoiding materializing the
+            // flattened array unless necessary
             let keys_array: ArrayRef =
                 Arc::new(PrimitiveArray::<K>::from(dict_array.keys().to_data()));
             let values_array = dict_array.values();
-            let cast_keys = cast_with_options(&keys_array, to_index_type, cast_options)?;
-            let cast_values = cast_with_options(values_array, to_value_type, cast_options)?;
+            let cast_values = match cast_with_options(values_array, to_value_type, cast_options) {
+                Ok(values) => values,
+                // Values not referenced by any key may fail to cast, so retry
+                // casting only the referenced values
+                Err(e) => {
+                    return repack_dictionary::<K>(array, to_type, cast_options).map_err(|_| e)
+                }
+            };
+            let cast_keys = match keys_array.data_type() == to_index_type.as_ref() {
+                true => keys_array.clone(),
+                false => {
+                    let options = CastOptions {
+                        safe: true,
+                        ..cast_options.clone()
+                    };
+                    cast_with_options(&keys_array, to_index_type, &options)?
+                }
+            };
 
             // Failure to cast keys (because they don't fit in the
             // target type) results in NULL values;
             if cast_keys.null_count() > keys_array.null_count() {
-                return Err(ArrowError::ComputeError(format!(
-                    "Could not convert {} dictionary indexes from {:?} to {:?}",
-                    cast_keys.null_count() - keys_array.null_count(),
-                    keys_array.data_type(),
-                    to_index_type
-                )));
+                // The keys may only be out of range because of unreferenced values,
+                // in which case re-encoding the referenced values will succeed
+                return repack_dictionary::<K>(array, to_type, cast_options).map_err(|_| {
+                    ArrowError::ComputeError(format!(
+                        "Could not convert {} dictionary indexes from {:?} to {:?}",
+                        cast_keys.null_count() - keys_array.null_count(),
+                        keys_array.data_type(),
+                        to_index_type
+                    ))
+                });
             }
 
             let data = cast_keys.into_data();
@@ -174,6 +196,18 @@ where
     take(cast_dict_values.as_ref(), dict_array.keys(), None)
 }
 
+/// Unpack a dictionary where the keys are of type <K> and re-encode it as `to_type`,
+/// retaining only the referenced values
+fn repack_dictionary<K: ArrowDictionaryKeyType>(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let dict_array = array.as_dictionary::<K>();
+    let values = take(dict_array.values().as_ref(), dict_array.keys(), None)?;
+    cast_with_options(values.as_ref(), to_type, cast_options)
+}
+
 /// Pack a data type into a dictionary array passing the values through a primitive array
 pub(crate) fn pack_array_to_dictionary_via_primitive<K: ArrowDictionaryKeyType>(
     array: &dyn Array,
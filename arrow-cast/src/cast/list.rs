@@ -25,7 +25,7 @@ pub(crate) fn cast_values_to_list<O: OffsetSizeTrait>(
 ) -> Result<ArrayRef, ArrowError> {
     let values = cast_with_options(array, to.data_type(), cast_options)?;
     let offsets = OffsetBuffer::from_lengths(std::iter::repeat(1).take(values.len()));
-    let list = GenericListArray::<O>::new(to.clone(), offsets, values, None);
+    let list = GenericListArray::<O>::try_new(to.clone(), offsets, values, None)?;
     Ok(Arc::new(list))
 }
 
@@ -37,7 +37,7 @@ pub(crate) fn cast_values_to_fixed_size_list(
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let values = cast_with_options(array, to.data_type(), cast_options)?;
-    let list = FixedSizeListArray::new(to.clone(), size, values, None);
+    let list = FixedSizeListArray::try_new(to.clone(), size, values, None)?;
     Ok(Arc::new(list))
 }
 
@@ -180,3 +180,111 @@ pub(crate) fn cast_list<I: OffsetSizeTrait, O: OffsetSizeTrait>(
         nulls,
     )))
 }
+
+/// Cast a List/LargeList array to a ListView/LargeListView array, along with the inner datatype
+pub(crate) fn cast_list_to_list_view<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    field: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list::<I>();
+    let values = list.values();
+
+    if !O::IS_LARGE && values.len() > i32::MAX as usize {
+        return Err(ArrowError::ComputeError(
+            "LargeList too large to cast to ListView".into(),
+        ));
+    }
+
+    let values = cast_with_options(values, field.data_type(), cast_options)?;
+    let offsets = list.offsets();
+    let sizes = offsets
+        .windows(2)
+        .map(|w| O::usize_as((w[1] - w[0]).as_usize()));
+    let sizes = ScalarBuffer::from_iter(sizes);
+    let offsets = offsets[..list.len()]
+        .iter()
+        .map(|x| O::usize_as(x.as_usize()));
+    let offsets = ScalarBuffer::from_iter(offsets);
+
+    Ok(Arc::new(GenericListViewArray::<O>::try_new(
+        field.clone(),
+        offsets,
+        sizes,
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+/// Cast the container type of a ListView/LargeListView array along with the inner datatype
+pub(crate) fn cast_list_view<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    field: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list_view::<I>();
+    let values = list.values();
+
+    if !O::IS_LARGE && values.len() > i32::MAX as usize {
+        return Err(ArrowError::ComputeError(
+            "LargeListView too large to cast to ListView".into(),
+        ));
+    }
+
+    let values = cast_with_options(values, field.data_type(), cast_options)?;
+    let offsets = list.offsets().iter().map(|x| O::usize_as(x.as_usize()));
+    let sizes = list.sizes().iter().map(|x| O::usize_as(x.as_usize()));
+
+    Ok(Arc::new(GenericListViewArray::<O>::try_new(
+        field.clone(),
+        ScalarBuffer::from_iter(offsets),
+        ScalarBuffer::from_iter(sizes),
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+/// Cast a ListView/LargeListView array to a List/LargeList array, along with the inner datatype
+///
+/// As the views of a ListView may overlap or be out of order, the referenced values
+/// are copied into a new contiguous values array
+pub(crate) fn cast_list_view_to_list<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    field: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list_view::<I>();
+    let values = list.values().to_data();
+
+    let mut total = 0_usize;
+    let mut mutable = MutableArrayData::new(vec![&values], false, values.len());
+    for i in 0..list.len() {
+        if list.is_valid(i) {
+            let offset = list.value_offset(i).as_usize();
+            let size = list.value_size(i).as_usize();
+            mutable.extend(0, offset, offset + size);
+            total += size;
+        }
+    }
+
+    if !O::IS_LARGE && total > i32::MAX as usize {
+        return Err(ArrowError::ComputeError(
+            "LargeListView too large to cast to List".into(),
+        ));
+    }
+
+    let values = make_array(mutable.freeze());
+    let values = cast_with_options(&values, field.data_type(), cast_options)?;
+    let sizes = (0..list.len()).map(|i| match list.is_valid(i) {
+        true => list.value_size(i).as_usize(),
+        false => 0,
+    });
+    let offsets = OffsetBuffer::<O>::from_lengths(sizes);
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field.clone(),
+        offsets,
+        values,
+        list.nulls().cloned(),
+    )?))
+}
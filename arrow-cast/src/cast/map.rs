@@ -72,3 +72,144 @@ pub(crate) fn value_field(entries_field: &FieldRef) -> Option<FieldRef> {
         None
     }
 }
+
+/// Helper function that casts a map to a (generic) list of its key/value entries.
+pub(crate) fn cast_map_to_list<O: OffsetSizeTrait>(
+    from: &MapArray,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let entries = cast_with_options(from.entries(), to.data_type(), cast_options)?;
+    let offsets: Vec<_> = from
+        .offsets()
+        .iter()
+        .map(|x| O::usize_as(x.as_usize()))
+        .collect();
+
+    // Safety: valid offsets of a MapArray
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        to.clone(),
+        offsets,
+        entries,
+        from.nulls().cloned(),
+    )?))
+}
+
+/// Helper function that casts a (generic) list of key/value structs to a map.
+///
+/// If `to_ordered` is true, returns an error if the keys of any map are not sorted
+pub(crate) fn cast_list_to_map<O: OffsetSizeTrait>(
+    from: &GenericListArray<O>,
+    entries_field: &FieldRef,
+    cast_options: &CastOptions,
+    to_ordered: bool,
+) -> Result<ArrayRef, ArrowError> {
+    if O::IS_LARGE && from.values().len() > i32::MAX as usize {
+        return Err(ArrowError::CastError(
+            "LargeList too large to cast to Map".into(),
+        ));
+    }
+
+    let entries = cast_with_options(from.values(), entries_field.data_type(), cast_options)?;
+    let (offsets, entries) = match entries.nulls().filter(|n| n.null_count() != 0) {
+        None => {
+            let offsets: Vec<_> = from.offsets().iter().map(|x| x.as_usize() as i32).collect();
+            (offsets, entries)
+        }
+        Some(entry_nulls) => {
+            // Only entries referenced by valid list slots must be non-null, drop all others
+            let mut offsets = Vec::with_capacity(from.len() + 1);
+            offsets.push(0_i32);
+            let mut indices = Vec::new();
+            for (idx, w) in from.offsets().windows(2).enumerate() {
+                if from.is_valid(idx) {
+                    let (start, end) = (w[0].as_usize(), w[1].as_usize());
+                    if (start..end).any(|i| entry_nulls.is_null(i)) {
+                        return Err(ArrowError::CastError(
+                            "Cannot cast list containing null entries to map".to_string(),
+                        ));
+                    }
+                    indices.extend((start..end).map(|i| i as u32));
+                }
+                offsets.push(indices.len() as i32);
+            }
+            let entries = take(entries.as_ref(), &UInt32Array::from(indices), None)?;
+            (offsets, entries)
+        }
+    };
+
+    // Safety: valid offsets and checked for overflow
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
+
+    let entries = entries.as_struct().clone();
+    if to_ordered {
+        validate_keys_sorted(&offsets, entries.column(0).as_ref())?;
+    }
+
+    Ok(Arc::new(MapArray::try_new(
+        entries_field.clone(),
+        offsets,
+        entries,
+        from.nulls().cloned(),
+        to_ordered,
+    )?))
+}
+
+/// Returns an error if the keys within each map delimited by `offsets` are not sorted
+fn validate_keys_sorted(offsets: &OffsetBuffer<i32>, keys: &dyn Array) -> Result<(), ArrowError> {
+    fn check(
+        offsets: &OffsetBuffer<i32>,
+        cmp: impl Fn(usize, usize) -> std::cmp::Ordering,
+    ) -> Result<(), ArrowError> {
+        for w in offsets.windows(2) {
+            let (start, end) = (w[0].as_usize(), w[1].as_usize());
+            if (start + 1..end).any(|i| cmp(i - 1, i).is_gt()) {
+                return Err(ArrowError::CastError(
+                    "Cannot cast to map with sorted keys: keys are not sorted".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    downcast_primitive_array!(
+        keys => check(offsets, |a, b| keys.value(a).compare(keys.value(b))),
+        DataType::Utf8 => {
+            let keys = keys.as_string::<i32>();
+            check(offsets, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::LargeUtf8 => {
+            let keys = keys.as_string::<i64>();
+            check(offsets, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::Utf8View => {
+            let keys = keys.as_string_view();
+            check(offsets, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::Binary => {
+            let keys = keys.as_binary::<i32>();
+            check(offsets, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::LargeBinary => {
+            let keys = keys.as_binary::<i64>();
+            check(offsets, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::BinaryView => {
+            let keys = keys.as_binary_view();
+            check(offsets, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::FixedSizeBinary(_) => {
+            let keys = keys.as_fixed_size_binary();
+            check(offsets, |a, b| keys.value(a).cmp(keys.value(b)))
+        }
+        DataType::Boolean => {
+            let keys = keys.as_boolean();
+            check(offsets, |a, b| keys.value(a).cmp(&keys.value(b)))
+        }
+        d => Err(ArrowError::CastError(format!(
+            "Cannot validate sortedness of map keys of type {d}"
+        )))
+    )
+}
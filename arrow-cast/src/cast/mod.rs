@@ -37,16 +37,20 @@
 //! assert_eq!(7.0, c.value(2));
 //! ```
 
+mod batch;
 mod decimal;
 mod dictionary;
 mod list;
 mod map;
 mod string;
+mod union;
+pub use crate::cast::batch::*;
 use crate::cast::decimal::*;
 use crate::cast::dictionary::*;
 use crate::cast::list::*;
 use crate::cast::map::*;
 use crate::cast::string::*;
+use crate::cast::union::*;
 
 use arrow_buffer::IntervalMonthDayNano;
 use arrow_data::ByteView;
@@ -60,7 +64,7 @@ use crate::parse::{
     string_to_datetime, Parser,
 };
 use arrow_array::{builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *};
-use arrow_buffer::{i256, ArrowNativeType, OffsetBuffer};
+use arrow_buffer::{i256, ArrowNativeType, OffsetBuffer, ScalarBuffer};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::*;
@@ -75,6 +79,28 @@ pub struct CastOptions<'a> {
     pub safe: bool,
     /// Formatting options when casting from temporal types to string
     pub format_options: FormatOptions<'a>,
+    /// Tokens recognized when casting from string to boolean, see [`Self::with_boolean_tokens`]
+    boolean_tokens: Option<BooleanTokens<'a>>,
+    /// How child fields are matched when casting structs, see [`Self::with_struct_cast_mode`]
+    struct_cast_mode: StructCastMode,
+}
+
+/// How child fields are matched when casting between [`DataType::Struct`] types
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum StructCastMode {
+    /// Fields are matched by position, the source and target types must have
+    /// the same number of fields
+    #[default]
+    ByPosition,
+    /// Fields are matched by name, allowing fields to be reordered
+    ///
+    /// Source fields not present in the target type are dropped, and target fields not
+    /// present in the source type are filled with nulls, returning an error if such a
+    /// field is not nullable
+    ByName,
+    /// As [`StructCastMode::ByName`] but returns an error if any target field is not
+    /// present in the source type
+    ByNameStrict,
 }
 
 impl Default for CastOptions<'_> {
@@ -82,14 +108,126 @@ impl Default for CastOptions<'_> {
         Self {
             safe: true,
             format_options: FormatOptions::default(),
+            boolean_tokens: None,
+            struct_cast_mode: StructCastMode::ByPosition,
+        }
+    }
+}
+
+impl<'a> CastOptions<'a> {
+    /// Overrides the tokens recognized when casting from string to [`DataType::Boolean`]
+    ///
+    /// Defaults to the tokens listed on [`BooleanTokens`]
+    pub fn with_boolean_tokens(mut self, tokens: BooleanTokens<'a>) -> Self {
+        self.boolean_tokens = Some(tokens);
+        self
+    }
+
+    /// Returns the tokens recognized when casting from string to [`DataType::Boolean`],
+    /// if overridden by [`Self::with_boolean_tokens`]
+    pub fn boolean_tokens(&self) -> Option<&BooleanTokens<'a>> {
+        self.boolean_tokens.as_ref()
+    }
+
+    /// Overrides how child fields are matched when casting between [`DataType::Struct`] types
+    ///
+    /// Defaults to [`StructCastMode::ByPosition`]
+    pub fn with_struct_cast_mode(mut self, struct_cast_mode: StructCastMode) -> Self {
+        self.struct_cast_mode = struct_cast_mode;
+        self
+    }
+
+    /// Returns how child fields are matched when casting between [`DataType::Struct`] types
+    pub fn struct_cast_mode(&self) -> StructCastMode {
+        self.struct_cast_mode
+    }
+}
+
+/// The set of string values recognized as `true` and `false` when casting
+/// from string to [`DataType::Boolean`]
+///
+/// Leading and trailing whitespace is ignored when matching a value against the tokens.
+///
+/// When not overridden using [`CastOptions::with_boolean_tokens`], the following tokens are
+/// matched case-insensitively:
+///
+/// * `true`: `t`, `tr`, `tru`, `true`, `y`, `ye`, `yes`, `on`, `1`
+/// * `false`: `f`, `fa`, `fal`, `fals`, `false`, `n`, `no`, `of`, `off`, `0`
+///
+/// ```
+/// # use arrow_array::{Array, BooleanArray, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_cast::{cast_with_options, BooleanTokens, CastOptions};
+/// # use arrow_schema::DataType;
+/// let array = StringArray::from(vec!["ja", "NEIN", "yes"]);
+/// let options = CastOptions::default().with_boolean_tokens(BooleanTokens::new(&["ja"], &["nein"]));
+/// let b = cast_with_options(&array, &DataType::Boolean, &options).unwrap();
+/// assert_eq!(b.as_boolean(), &BooleanArray::from(vec![Some(true), Some(false), None]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BooleanTokens<'a> {
+    /// Values cast to `true`
+    true_tokens: &'a [&'a str],
+    /// Values cast to `false`
+    false_tokens: &'a [&'a str],
+    /// If `true` values must match a token exactly
+    case_sensitive: bool,
+}
+
+impl<'a> BooleanTokens<'a> {
+    /// Create a new set of tokens that are matched case-insensitively
+    pub const fn new(true_tokens: &'a [&'a str], false_tokens: &'a [&'a str]) -> Self {
+        Self {
+            true_tokens,
+            false_tokens,
+            case_sensitive: false,
+        }
+    }
+
+    /// If set to `true` values must match a token exactly, otherwise
+    /// values are compared ignoring case
+    ///
+    /// Defaults to `false`
+    pub const fn with_case_sensitive(self, case_sensitive: bool) -> Self {
+        Self {
+            case_sensitive,
+            ..self
         }
     }
+
+    /// Returns the values cast to `true`
+    pub fn true_tokens(&self) -> &'a [&'a str] {
+        self.true_tokens
+    }
+
+    /// Returns the values cast to `false`
+    pub fn false_tokens(&self) -> &'a [&'a str] {
+        self.false_tokens
+    }
+
+    /// Returns `true` if values must match a token exactly
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
 }
 
 /// Return true if a value of type `from_type` can be cast into a value of `to_type`.
 ///
 /// See [`cast_with_options`] for more information
 pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
+    can_cast_types_with_options(from_type, to_type, &CastOptions::default())
+}
+
+/// Return true if a value of type `from_type` can be cast into a value of `to_type`
+/// using [`cast_with_options`] with the provided [`CastOptions`]
+///
+/// Unlike [`can_cast_types`] this takes into account options that determine whether
+/// a cast is supported, such as [`CastOptions::struct_cast_mode`]
+pub fn can_cast_types_with_options(
+    from_type: &DataType,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> bool {
     use self::DataType::*;
     use self::IntervalUnit::*;
     use self::TimeUnit::*;
@@ -134,66 +272,97 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         ) => true,
         // Dictionary/List conditions should be put in front of others
         (Dictionary(_, from_value_type), Dictionary(_, to_value_type)) => {
-            can_cast_types(from_value_type, to_value_type)
-        }
-        (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
-        (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
+            can_cast_types_with_options(from_value_type, to_value_type, cast_options)
+        }
+        (Dictionary(_, value_type), _) => can_cast_types_with_options(value_type, to_type, cast_options),
+        (_, Dictionary(_, value_type)) => can_cast_types_with_options(from_type, value_type, cast_options),
+        (Union(_, _), Union(_, _)) => false,
+        // Extracting a variant introduces nulls, which cannot be stored in non-nullable list items
+        (Union(_, _), List(f) | LargeList(f) | FixedSizeList(f, _)) if !f.is_nullable() => false,
+        (Union(fields, _), _) => fields
+            .iter()
+            .any(|(_, f)| can_cast_types_with_options(f.data_type(), to_type, cast_options)),
+        (_, Union(fields, _)) => fields
+            .iter()
+            .any(|(_, f)| can_cast_types_with_options(from_type, f.data_type(), cast_options)),
         (List(list_from) | LargeList(list_from), List(list_to) | LargeList(list_to)) => {
-            can_cast_types(list_from.data_type(), list_to.data_type())
+            can_cast_types_with_options(list_from.data_type(), list_to.data_type(), cast_options)
         }
         (List(list_from) | LargeList(list_from), Utf8 | LargeUtf8) => {
-            can_cast_types(list_from.data_type(), to_type)
+            can_cast_types_with_options(list_from.data_type(), to_type, cast_options)
         }
         (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
-            can_cast_types(list_from.data_type(), list_to.data_type())
+            can_cast_types_with_options(list_from.data_type(), list_to.data_type(), cast_options)
+        }
+        (List(list_from) | LargeList(list_from), Map(entries, _)) => {
+            can_cast_types_with_options(list_from.data_type(), entries.data_type(), cast_options)
+        }
+        (Map(entries, _), List(list_to) | LargeList(list_to)) => {
+            can_cast_types_with_options(entries.data_type(), list_to.data_type(), cast_options)
+        }
+        (
+            List(list_from) | LargeList(list_from) | ListView(list_from) | LargeListView(list_from),
+            ListView(list_to) | LargeListView(list_to),
+        ) => can_cast_types_with_options(list_from.data_type(), list_to.data_type(), cast_options),
+        (ListView(list_from) | LargeListView(list_from), List(list_to) | LargeList(list_to)) => {
+            can_cast_types_with_options(list_from.data_type(), list_to.data_type(), cast_options)
         }
         (List(_), _) => false,
         (FixedSizeList(list_from,_), List(list_to)) |
         (FixedSizeList(list_from,_), LargeList(list_to)) => {
-            can_cast_types(list_from.data_type(), list_to.data_type())
+            can_cast_types_with_options(list_from.data_type(), list_to.data_type(), cast_options)
         }
         (FixedSizeList(inner, size), FixedSizeList(inner_to, size_to)) if size == size_to => {
-            can_cast_types(inner.data_type(), inner_to.data_type())
+            can_cast_types_with_options(inner.data_type(), inner_to.data_type(), cast_options)
         }
-        (_, List(list_to)) => can_cast_types(from_type, list_to.data_type()),
-        (_, LargeList(list_to)) => can_cast_types(from_type, list_to.data_type()),
+        (_, List(list_to)) => can_cast_types_with_options(from_type, list_to.data_type(), cast_options),
+        (_, LargeList(list_to)) => can_cast_types_with_options(from_type, list_to.data_type(), cast_options),
         (_, FixedSizeList(list_to,size)) if *size == 1 => {
-            can_cast_types(from_type, list_to.data_type())},
+            can_cast_types_with_options(from_type, list_to.data_type(), cast_options)},
         (FixedSizeList(list_from,size), _) if *size == 1 => {
-            can_cast_types(list_from.data_type(), to_type)},
+            can_cast_types_with_options(list_from.data_type(), to_type, cast_options)},
         (Map(from_entries,ordered_from), Map(to_entries, ordered_to)) if ordered_from == ordered_to =>
             match (key_field(from_entries), key_field(to_entries), value_field(from_entries), value_field(to_entries)) {
                 (Some(from_key), Some(to_key), Some(from_value), Some(to_value)) =>
-                    can_cast_types(from_key.data_type(), to_key.data_type()) && can_cast_types(from_value.data_type(), to_value.data_type()),
+                    can_cast_types_with_options(from_key.data_type(), to_key.data_type(), cast_options) && can_cast_types_with_options(from_value.data_type(), to_value.data_type(), cast_options),
                 _ => false
             },
         // cast one decimal type to another decimal type
-        (Decimal128(_, _), Decimal128(_, _)) => true,
-        (Decimal256(_, _), Decimal256(_, _)) => true,
-        (Decimal128(_, _), Decimal256(_, _)) => true,
-        (Decimal256(_, _), Decimal128(_, _)) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _),
+         Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) => true,
         // unsigned integer to decimal
+        (UInt8 | UInt16 | UInt32 | UInt64, Decimal32(_, _) | Decimal64(_, _)) |
         (UInt8 | UInt16 | UInt32 | UInt64, Decimal128(_, _)) |
         (UInt8 | UInt16 | UInt32 | UInt64, Decimal256(_, _)) |
         // signed numeric to decimal
+        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal32(_, _) | Decimal64(_, _)) |
         (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal128(_, _)) |
         (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal256(_, _)) |
         // decimal to unsigned numeric
-        (Decimal128(_, _) | Decimal256(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
         // decimal to signed numeric
-        (Decimal128(_, _) | Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) => true,
         // decimal to string
-        (Decimal128(_, _) | Decimal256(_, _), Utf8View | Utf8 | LargeUtf8) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), Utf8View | Utf8 | LargeUtf8) => true,
         // string to decimal
-        (Utf8View | Utf8 | LargeUtf8, Decimal128(_, _) | Decimal256(_, _)) => true,
-        (Struct(from_fields), Struct(to_fields)) => {
-            from_fields.len() == to_fields.len() &&
-                from_fields.iter().zip(to_fields.iter()).all(|(f1, f2)| {
-                    // Assume that nullability between two structs are compatible, if not,
-                    // cast kernel will return error.
-                    can_cast_types(f1.data_type(), f2.data_type())
-                })
-        }
+        (Utf8View | Utf8 | LargeUtf8, Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) => true,
+        (Struct(from_fields), Struct(to_fields)) => match cast_options.struct_cast_mode() {
+            StructCastMode::ByPosition => {
+                from_fields.len() == to_fields.len() &&
+                    from_fields.iter().zip(to_fields.iter()).all(|(f1, f2)| {
+                        // Assume that nullability between two structs are compatible, if not,
+                        // cast kernel will return error.
+                        can_cast_types_with_options(f1.data_type(), f2.data_type(), cast_options)
+                    })
+            }
+            mode => to_fields.iter().all(|to_field| match from_fields.find(to_field.name()) {
+                Some((_, from_field)) => {
+                    can_cast_types_with_options(from_field.data_type(), to_field.data_type(), cast_options)
+                }
+                // Missing fields are filled with nulls
+                None => mode == StructCastMode::ByName && to_field.is_nullable(),
+            }),
+        },
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (_, Boolean) => {
@@ -307,6 +476,38 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowErro
     cast_with_options(array, to_type, &CastOptions::default())
 }
 
+/// Cast each chunk of `array` to the provided data type, returning a new [`ChunkedArray`]
+/// with type `to_type` and the same chunks, if possible.
+///
+/// See [`cast_with_options`] for more information
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, ChunkedArray, Int32Array, Int64Array};
+/// # use arrow_cast::{cast_chunked, CastOptions};
+/// # use arrow_schema::DataType;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![Some(3), None]));
+/// let chunked = ChunkedArray::try_new(DataType::Int32, vec![a, b]).unwrap();
+///
+/// let cast = cast_chunked(&chunked, &DataType::Int64, &CastOptions::default()).unwrap();
+/// assert_eq!(cast.data_type(), &DataType::Int64);
+/// assert_eq!(cast.num_chunks(), 2);
+/// assert_eq!(cast.chunk(1).as_ref(), &Int64Array::from(vec![Some(3), None]));
+/// ```
+pub fn cast_chunked(
+    array: &ChunkedArray,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ChunkedArray, ArrowError> {
+    let chunks = array
+        .chunks()
+        .iter()
+        .map(|c| cast_with_options(c.as_ref(), to_type, cast_options))
+        .collect::<Result<_, _>>()?;
+    ChunkedArray::try_new(to_type.clone(), chunks)
+}
+
 fn cast_integer_to_decimal<
     T: ArrowPrimitiveType,
     D: DecimalType + ArrowPrimitiveType<Native = M>,
@@ -597,6 +798,15 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 /// * `List` to `FixedSizeList`: the underlying data type is cast. If safe is true and a list element
 ///   has the wrong length it will be replaced with NULL, otherwise an error will be returned
 /// * Primitive to `List`: a list array with 1 value per slot is created
+/// * `Union` to other types: the first variant of the target type, or else the first variant
+///   that can be cast to the target type, is extracted. Slots containing other variants are null
+/// * Other types to `Union`: a union containing a single variant of the source type, or else
+///   the first variant the source type can be cast to, is created
+/// * `Map` to `List`: a list of the key/value entries of each map is created
+/// * `List` to `Map`: each list of key/value structs is converted to a map. If the target map
+///   has sorted keys, an error is returned if the keys of any map are not sorted
+/// * `Struct` to `Struct`: child fields are matched according to [`CastOptions::struct_cast_mode`]
+///   and each child is cast to the target field type
 /// * `Date32` and `Date64`: precision lost when going to higher interval
 /// * `Time32 and `Time64`: precision lost when going to higher interval
 /// * `Timestamp` and `Date{32|64}`: precision lost when going to higher interval
@@ -765,6 +975,11 @@ pub fn cast_with_options(
                 "Casting from type {from_type:?} to dictionary type {to_type:?} not supported",
             ))),
         },
+        (Union(_, _), Union(_, _)) => Err(ArrowError::CastError(format!(
+            "Casting from {from_type:?} to {to_type:?} not supported",
+        ))),
+        (Union(_, _), _) => cast_from_union(array.as_union(), to_type, cast_options),
+        (_, Union(fields, mode)) => cast_to_union(array, fields, *mode, cast_options),
         (List(_), List(to)) => cast_list_values::<i32>(array, to, cast_options),
         (LargeList(_), LargeList(to)) => cast_list_values::<i64>(array, to, cast_options),
         (List(_), LargeList(list_to)) => cast_list::<i32, i64>(array, list_to, cast_options),
@@ -777,6 +992,32 @@ pub fn cast_with_options(
             let array = array.as_list::<i64>();
             cast_list_to_fixed_size_list::<i64>(array, field, *size, cast_options)
         }
+        (List(_), Map(entries, ordered)) => {
+            cast_list_to_map(array.as_list::<i32>(), entries, cast_options, *ordered)
+        }
+        (LargeList(_), Map(entries, ordered)) => {
+            cast_list_to_map(array.as_list::<i64>(), entries, cast_options, *ordered)
+        }
+        (Map(_, _), List(to)) => cast_map_to_list::<i32>(array.as_map(), to, cast_options),
+        (Map(_, _), LargeList(to)) => cast_map_to_list::<i64>(array.as_map(), to, cast_options),
+        (List(_), ListView(to)) => cast_list_to_list_view::<i32, i32>(array, to, cast_options),
+        (List(_), LargeListView(to)) => cast_list_to_list_view::<i32, i64>(array, to, cast_options),
+        (LargeList(_), ListView(to)) => cast_list_to_list_view::<i64, i32>(array, to, cast_options),
+        (LargeList(_), LargeListView(to)) => {
+            cast_list_to_list_view::<i64, i64>(array, to, cast_options)
+        }
+        (ListView(_), ListView(to)) => cast_list_view::<i32, i32>(array, to, cast_options),
+        (ListView(_), LargeListView(to)) => cast_list_view::<i32, i64>(array, to, cast_options),
+        (LargeListView(_), ListView(to)) => cast_list_view::<i64, i32>(array, to, cast_options),
+        (LargeListView(_), LargeListView(to)) => {
+            cast_list_view::<i64, i64>(array, to, cast_options)
+        }
+        (ListView(_), List(to)) => cast_list_view_to_list::<i32, i32>(array, to, cast_options),
+        (ListView(_), LargeList(to)) => cast_list_view_to_list::<i32, i64>(array, to, cast_options),
+        (LargeListView(_), List(to)) => cast_list_view_to_list::<i64, i32>(array, to, cast_options),
+        (LargeListView(_), LargeList(to)) => {
+            cast_list_view_to_list::<i64, i64>(array, to, cast_options)
+        }
         (List(_) | LargeList(_), _) => match to_type {
             Utf8 => value_to_string::<i32>(array, cast_options),
             LargeUtf8 => value_to_string::<i64>(array, cast_options),
@@ -830,6 +1071,56 @@ pub fn cast_with_options(
         (Map(_, ordered1), Map(_, ordered2)) if ordered1 == ordered2 => {
             cast_map_values(array.as_map(), to_type, cast_options, ordered1.to_owned())
         }
+        (Decimal32(_, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal_same_type::<Decimal32Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(_, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal_same_type::<Decimal64Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal32(_, s1), Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) => {
+            cast_decimal_to_decimal_of_type::<Decimal32Type>(
+                array.as_primitive(),
+                *s1,
+                to_type,
+                cast_options,
+            )
+        }
+        (Decimal64(_, s1), Decimal32(_, _) | Decimal128(_, _) | Decimal256(_, _)) => {
+            cast_decimal_to_decimal_of_type::<Decimal64Type>(
+                array.as_primitive(),
+                *s1,
+                to_type,
+                cast_options,
+            )
+        }
+        (Decimal128(_, s1), Decimal32(_, _) | Decimal64(_, _)) => {
+            cast_decimal_to_decimal_of_type::<Decimal128Type>(
+                array.as_primitive(),
+                *s1,
+                to_type,
+                cast_options,
+            )
+        }
+        (Decimal256(_, s1), Decimal32(_, _) | Decimal64(_, _)) => {
+            cast_decimal_to_decimal_of_type::<Decimal256Type>(
+                array.as_primitive(),
+                *s1,
+                to_type,
+                cast_options,
+            )
+        }
         (Decimal128(_, s1), Decimal128(p2, s2)) => {
             cast_decimal_to_decimal_same_type::<Decimal128Type>(
                 array.as_primitive(),
@@ -866,6 +1157,12 @@ pub fn cast_with_options(
                 cast_options,
             )
         }
+        (Decimal32(_, scale), _) if !to_type.is_temporal() => {
+            cast_from_narrow_decimal::<Decimal32Type>(array, 10_i32, *scale, to_type, cast_options)
+        }
+        (Decimal64(_, scale), _) if !to_type.is_temporal() => {
+            cast_from_narrow_decimal::<Decimal64Type>(array, 10_i64, *scale, to_type, cast_options)
+        }
         (Decimal128(_, scale), _) if !to_type.is_temporal() => {
             // cast decimal to other type
             match to_type {
@@ -998,6 +1295,12 @@ pub fn cast_with_options(
                 ))),
             }
         }
+        (_, Decimal32(precision, scale)) if !from_type.is_temporal() => {
+            cast_to_narrow_decimal::<Decimal32Type>(array, *precision, *scale, cast_options)
+        }
+        (_, Decimal64(precision, scale)) if !from_type.is_temporal() => {
+            cast_to_narrow_decimal::<Decimal64Type>(array, *precision, *scale, cast_options)
+        }
         (_, Decimal128(precision, scale)) if !from_type.is_temporal() => {
             // cast data to decimal
             match from_type {
@@ -1176,17 +1479,23 @@ pub fn cast_with_options(
                 ))),
             }
         }
-        (Struct(_), Struct(to_fields)) => {
-            let array = array.as_struct();
-            let fields = array
-                .columns()
-                .iter()
-                .zip(to_fields.iter())
-                .map(|(l, field)| cast_with_options(l, field.data_type(), cast_options))
-                .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
-            let array = StructArray::try_new(to_fields.clone(), fields, array.nulls().cloned())?;
-            Ok(Arc::new(array) as ArrayRef)
-        }
+        (Struct(_), Struct(to_fields)) => match cast_options.struct_cast_mode() {
+            StructCastMode::ByPosition => {
+                let array = array.as_struct();
+                let fields = array
+                    .columns()
+                    .iter()
+                    .zip(to_fields.iter())
+                    .map(|(l, field)| cast_with_options(l, field.data_type(), cast_options))
+                    .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
+                let array =
+                    StructArray::try_new(to_fields.clone(), fields, array.nulls().cloned())?;
+                Ok(Arc::new(array) as ArrayRef)
+            }
+            StructCastMode::ByName | StructCastMode::ByNameStrict => {
+                cast_struct_by_name(array.as_struct(), to_fields, cast_options)
+            }
+        },
         (Struct(_), _) => Err(ArrowError::CastError(
             "Cannot cast from struct to other types except struct".to_string(),
         )),
@@ -2190,6 +2499,35 @@ pub fn cast_with_options(
     }
 }
 
+/// Cast a [`StructArray`] to `to_fields`, matching child fields by name
+fn cast_struct_by_name(
+    array: &StructArray,
+    to_fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let columns = to_fields
+        .iter()
+        .map(|to_field| match array.column_by_name(to_field.name()) {
+            Some(column) => cast_with_options(column, to_field.data_type(), cast_options),
+            None if cast_options.struct_cast_mode() == StructCastMode::ByNameStrict => {
+                Err(ArrowError::CastError(format!(
+                    "Cannot cast struct: field '{}' not found in source type {}",
+                    to_field.name(),
+                    array.data_type()
+                )))
+            }
+            None if !to_field.is_nullable() => Err(ArrowError::CastError(format!(
+                "Cannot cast struct: non-nullable field '{}' not found in source type {}",
+                to_field.name(),
+                array.data_type()
+            ))),
+            None => Ok(new_null_array(to_field.data_type(), array.len())),
+        })
+        .collect::<Result<Vec<ArrayRef>, ArrowError>>()?;
+    let array = StructArray::try_new(to_fields.clone(), columns, array.nulls().cloned())?;
+    Ok(Arc::new(array))
+}
+
 /// Get the time unit as a multiple of a second
 const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
     match unit {
@@ -2499,6 +2837,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::display::DecimalFormat;
     use arrow_buffer::{Buffer, IntervalDayTime, NullBuffer};
     use chrono::NaiveDate;
     use half::f16;
@@ -2518,6 +2857,7 @@ mod tests {
             let cast_option = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let result = cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
             assert_eq!($OUTPUT_TYPE, result.data_type());
@@ -2749,6 +3089,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
@@ -2769,6 +3110,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
@@ -2808,6 +3150,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
@@ -2827,6 +3170,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
@@ -2883,6 +3227,113 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_decimal32_decimal64() {
+        let array = Decimal32Array::from(vec![Some(123456), Some(-99999), None])
+            .with_precision_and_scale(7, 3)
+            .unwrap();
+
+        let output_type = DataType::Decimal64(12, 5);
+        assert!(can_cast_types(array.data_type(), &output_type));
+        generate_cast_test_case!(
+            &array,
+            Decimal64Array,
+            &output_type,
+            vec![Some(12345600_i64), Some(-9999900_i64), None]
+        );
+
+        let output_type = DataType::Decimal128(10, 2);
+        generate_cast_test_case!(
+            &array,
+            Decimal128Array,
+            &output_type,
+            vec![Some(12346_i128), Some(-10000_i128), None]
+        );
+
+        let output_type = DataType::Decimal256(20, 3);
+        generate_cast_test_case!(
+            &array,
+            Decimal256Array,
+            &output_type,
+            vec![
+                Some(i256::from_i128(123456)),
+                Some(i256::from_i128(-99999)),
+                None
+            ]
+        );
+
+        let decimal128 =
+            create_decimal_array(vec![Some(12345), Some(12345678901), None], 38, 2).unwrap();
+        let output_type = DataType::Decimal32(9, 2);
+        let casted = cast(&decimal128, &output_type).unwrap();
+        assert_eq!(
+            casted.as_primitive::<Decimal32Type>(),
+            &Decimal32Array::from(vec![Some(12345), None, None])
+                .with_precision_and_scale(9, 2)
+                .unwrap()
+        );
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&decimal128, &output_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to Decimal32(9, 2). Overflowing on 12345678901"
+        );
+
+        generate_cast_test_case!(
+            &array,
+            Int32Array,
+            &DataType::Int32,
+            vec![Some(123_i32), Some(-99_i32), None]
+        );
+        generate_cast_test_case!(
+            &array,
+            Float64Array,
+            &DataType::Float64,
+            vec![Some(123.456_f64), Some(-99.999_f64), None]
+        );
+        let strings = cast(&array, &DataType::Utf8).unwrap();
+        assert_eq!(
+            strings.as_string::<i32>(),
+            &StringArray::from(vec![Some("123.456"), Some("-99.999"), None])
+        );
+
+        let ints = Int64Array::from(vec![Some(12), Some(-3), Some(1_000_000_000), None]);
+        let output_type = DataType::Decimal64(10, 2);
+        assert!(can_cast_types(ints.data_type(), &output_type));
+        let casted = cast(&ints, &output_type).unwrap();
+        assert_eq!(
+            casted.as_primitive::<Decimal64Type>(),
+            &Decimal64Array::from(vec![Some(1200), Some(-300), None, None])
+                .with_precision_and_scale(10, 2)
+                .unwrap()
+        );
+
+        let floats = Float32Array::from(vec![Some(1.25), Some(-0.5), None]);
+        generate_cast_test_case!(
+            &floats,
+            Decimal32Array,
+            &DataType::Decimal32(5, 1),
+            vec![Some(13_i32), Some(-5_i32), None]
+        );
+
+        let strings = StringArray::from(vec![Some("1.5"), Some("-12.345"), Some("abc"), None]);
+        let casted = cast(&strings, &DataType::Decimal32(6, 2)).unwrap();
+        assert_eq!(
+            casted.as_primitive::<Decimal32Type>(),
+            &Decimal32Array::from(vec![Some(150), Some(-1235), None, None])
+                .with_precision_and_scale(6, 2)
+                .unwrap()
+        );
+
+        let nulls = NullArray::new(2);
+        let casted = cast(&nulls, &DataType::Decimal64(10, 2)).unwrap();
+        assert_eq!(casted.data_type(), &DataType::Decimal64(10, 2));
+        assert_eq!(casted.null_count(), 2);
+    }
+
     #[test]
     fn test_cast_decimal_to_numeric() {
         let value_array: Vec<Option<i128>> = vec![Some(125), Some(225), Some(325), None, Some(525)];
@@ -2979,6 +3430,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -2992,6 +3444,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3006,6 +3459,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3019,6 +3473,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3182,6 +3637,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3195,6 +3651,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3612,6 +4069,7 @@ mod tests {
         let cast_option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
@@ -3825,6 +4283,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match result {
@@ -3864,6 +4323,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match casted {
@@ -3876,6 +4336,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_utf8_to_bool_with_tokens() {
+        let strings = StringArray::from(vec![
+            Some("Ja"),
+            Some("nein"),
+            Some(" JA "),
+            Some("true"),
+            Some("Über"),
+            None,
+        ]);
+        let tokens = BooleanTokens::new(&["ja", "über"], &["nein"]);
+        let options = CastOptions::default().with_boolean_tokens(tokens.clone());
+        let casted = cast_with_options(&strings, &DataType::Boolean, &options).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+            Some(true),
+            None,
+        ]);
+        assert_eq!(*as_boolean_array(&casted), expected);
+
+        let options = CastOptions::default().with_boolean_tokens(tokens.with_case_sensitive(true));
+        let strings = StringViewArray::from(vec!["ja", "JA", "nein", "Nein"]);
+        let casted = cast_with_options(&strings, &DataType::Boolean, &options).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false), None]);
+        assert_eq!(*as_boolean_array(&casted), expected);
+
+        let options = CastOptions {
+            safe: false,
+            format_options: FormatOptions::default(),
+            ..Default::default()
+        }
+        .with_boolean_tokens(BooleanTokens::new(&["1"], &["0"]));
+        let strings = LargeStringArray::from(vec!["1", "0", "yes"]);
+        let err = cast_with_options(&strings, &DataType::Boolean, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast value 'yes' to value of Boolean type"
+        );
+
+        // Replacing the format options must not discard the boolean tokens
+        let options =
+            CastOptions::default().with_boolean_tokens(BooleanTokens::new(&["ja"], &["nein"]));
+        let options = CastOptions {
+            format_options: FormatOptions::new().with_null("null"),
+            ..options
+        };
+        let strings = StringArray::from(vec!["ja", "nein"]);
+        let casted = cast_with_options(&strings, &DataType::Boolean, &options).unwrap();
+        let expected = BooleanArray::from(vec![true, false]);
+        assert_eq!(*as_boolean_array(&casted), expected);
+    }
+
     #[test]
     fn test_cast_bool_to_i32() {
         let array = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -4207,6 +4722,7 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let res = cast_with_options(&str, &DataType::Int16, &options).expect("should cast to i16");
         let expected =
@@ -4280,6 +4796,7 @@ mod tests {
                 let options = CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    ..Default::default()
                 };
                 let err = cast_with_options(array, &to_type, &options).unwrap_err();
                 assert_eq!(
@@ -4326,6 +4843,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4355,6 +4873,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let result = cast_with_options(&array, &to_type, &options).unwrap();
             let c = result.as_primitive::<Date32Type>();
@@ -4405,6 +4924,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
@@ -4447,6 +4967,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
@@ -4481,6 +5002,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
@@ -4515,6 +5037,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
@@ -4549,6 +5072,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4565,6 +5089,7 @@ mod tests {
             let options = CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
 
             let target_interval_array = cast_with_options(
@@ -4692,6 +5217,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let arrow_err = cast_with_options(
                 &string_array.clone(),
@@ -4801,6 +5327,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -4811,6 +5338,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -4949,6 +5477,7 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let b = cast_with_options(&array, &DataType::Date64, &options);
         assert!(b.is_err());
@@ -5309,6 +5838,7 @@ mod tests {
             format_options: FormatOptions::default()
                 .with_timestamp_format(Some(ts_format))
                 .with_timestamp_tz_format(Some(ts_format)),
+            ..Default::default()
         };
 
         // "2018-12-25T00:00:02.001", "1997-05-19T00:00:03.005", None
@@ -7001,6 +7531,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_dict_to_dict_unreferenced_values() {
+        use DataType::*;
+
+        // 200 distinct values, of which only 3 are referenced
+        let values = StringArray::from_iter_values((0..200).map(|i| format!("val{i}")));
+        let keys = Int32Array::from(vec![Some(199), None, Some(150), Some(199), Some(3)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+
+        let cast_type = Dictionary(Box::new(Int8), Box::new(Utf8));
+        let casted = cast(&array, &cast_type).unwrap();
+        assert_eq!(casted.data_type(), &cast_type);
+        assert_eq!(
+            array_to_strings(&casted),
+            vec!["val199", "null", "val150", "val199", "val3"]
+        );
+        assert_eq!(casted.as_dictionary::<Int8Type>().values().len(), 3);
+
+        // unreferenced values that cannot be cast are ignored
+        let values = StringArray::from(vec!["1", "not a number", "3"]);
+        let keys = Int8Array::from(vec![2, 0, 2]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let cast_type = Dictionary(Box::new(Int16), Box::new(Int32));
+        let casted = cast_with_options(&array, &cast_type, &options).unwrap();
+        assert_eq!(array_to_strings(&casted), vec!["3", "1", "3"]);
+    }
+
+    #[test]
+    fn test_cast_dict_keys_only() {
+        use DataType::*;
+
+        let values = StringArray::from(vec!["a", "b", "c"]);
+        let keys = Int32Array::from(vec![Some(2), None, Some(0)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+
+        let cast_type = Dictionary(Box::new(UInt8), Box::new(Utf8));
+        let casted = cast(&array, &cast_type).unwrap();
+        let casted = casted.as_dictionary::<UInt8Type>();
+        assert_eq!(
+            casted.keys(),
+            &UInt8Array::from(vec![Some(2), None, Some(0)])
+        );
+        // values are not copied
+        assert_eq!(
+            casted.values().as_string::<i32>().values().as_ptr(),
+            array.values().as_string::<i32>().values().as_ptr()
+        );
+    }
+
     #[test]
     fn test_cast_primitive_dict() {
         // FROM a dictionary with of INT32 values
@@ -7542,6 +8125,94 @@ mod tests {
         assert_eq!(&expected.value(2), &actual.value(2));
     }
 
+    #[test]
+    fn test_cast_chunked() {
+        let a: ArrayRef = Arc::new(StringArray::from(vec!["1", "2"]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![Some("3"), None, Some("x")]));
+        let chunked = ChunkedArray::try_new(DataType::Utf8, vec![a, b]).unwrap();
+
+        let options = CastOptions::default();
+        let cast = cast_chunked(&chunked, &DataType::Int32, &options).unwrap();
+        assert_eq!(cast.num_chunks(), 2);
+        assert_eq!(cast.chunk(0).as_ref(), &Int32Array::from(vec![1, 2]));
+        assert_eq!(
+            cast.chunk(1).as_ref(),
+            &Int32Array::from(vec![Some(3), None, None])
+        );
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_chunked(&chunked, &DataType::Int32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'x' to value of Int32 type"
+        );
+
+        let empty = ChunkedArray::new_empty(DataType::Utf8);
+        let cast = cast_chunked(&empty, &DataType::Int32, &options).unwrap();
+        assert_eq!(cast.data_type(), &DataType::Int32);
+        assert!(cast.is_empty());
+    }
+
+    #[test]
+    fn test_cast_list_view_containers() {
+        // Views are out of order and overlapping
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5, 6]));
+        let offsets = ScalarBuffer::from(vec![3, 0, 1, 2]);
+        let sizes = ScalarBuffer::from(vec![3, 2, 4, 1]);
+        let nulls = NullBuffer::from(vec![true, true, true, false]);
+        let array = ListViewArray::new(field, offsets, sizes, values, Some(nulls));
+
+        let expected = vec![
+            Some(vec![Some(4), Some(5), Some(6)]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(2), Some(3), Some(4), Some(5)]),
+            None,
+        ];
+
+        // list-view to list
+        let to_field = Arc::new(Field::new_list_field(DataType::Int64, true));
+        let list = cast(&array, &DataType::List(to_field.clone())).unwrap();
+        let list_expected = ListArray::from_iter_primitive::<Int64Type, _, _>(expected.clone());
+        assert_eq!(list.as_list::<i32>(), &list_expected);
+
+        // list-view to large-list
+        let large = cast(&array, &DataType::LargeList(to_field.clone())).unwrap();
+        let large_expected = LargeListArray::from_iter_primitive::<Int64Type, _, _>(expected);
+        assert_eq!(large.as_list::<i64>(), &large_expected);
+
+        // list-view to large-list-view
+        let large_view = cast(&array, &DataType::LargeListView(to_field.clone())).unwrap();
+        let large_view = large_view.as_list_view::<i64>();
+        assert_eq!(large_view.value_offsets(), &[3, 0, 1, 2]);
+        assert_eq!(large_view.value_sizes(), &[3, 2, 4, 1]);
+        assert_eq!(large_view.nulls(), array.nulls());
+        assert_eq!(large_view.values().data_type(), &DataType::Int64);
+
+        // large-list-view to list-view
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let view = cast(large_view, &DataType::ListView(field.clone())).unwrap();
+        assert_eq!(view.as_list_view::<i32>(), &array);
+
+        // list to list-view and back
+        let list = cast(&list_expected, &DataType::List(field.clone())).unwrap();
+        for to_type in [
+            DataType::ListView(field.clone()),
+            DataType::LargeListView(field.clone()),
+        ] {
+            assert!(can_cast_types(list.data_type(), &to_type));
+            let view = cast(&list, &to_type).unwrap();
+            assert_eq!(view.data_type(), &to_type);
+            let actual = cast(&view, list.data_type()).unwrap();
+            assert_eq!(actual.as_ref(), list.as_ref());
+        }
+
+        assert!(!can_cast_types(&DataType::ListView(field), &DataType::Utf8));
+    }
+
     #[test]
     fn test_cast_list_to_fsl() {
         // There four noteworthy cases we should handle:
@@ -8057,6 +8728,161 @@ mod tests {
         assert_eq!(&values_string, &vec!["44", "22"]);
     }
 
+    #[test]
+    fn test_cast_map_to_list_and_back() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("c");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        let array = builder.finish();
+
+        let entries = DataType::Struct(
+            vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int64, true),
+            ]
+            .into(),
+        );
+        let list_type = DataType::LargeList(Arc::new(Field::new("entries", entries, true)));
+        assert!(can_cast_types(array.data_type(), &list_type));
+
+        let list = cast(&array, &list_type).unwrap();
+        let list = list.as_list::<i64>();
+        assert_eq!(list.value_offsets(), &[0, 2, 2, 3]);
+        assert!(list.is_null(1));
+        let values = list.values().as_struct();
+        assert_eq!(
+            values.column(0).as_string::<i32>(),
+            &StringArray::from(vec!["a", "b", "c"])
+        );
+        assert_eq!(
+            values.column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(1), Some(2), None])
+        );
+
+        let map_type = DataType::Map(
+            Arc::new(Field::new(
+                "entries",
+                DataType::Struct(
+                    vec![
+                        Field::new("keys", DataType::Utf8, false),
+                        Field::new("values", DataType::Int32, true),
+                    ]
+                    .into(),
+                ),
+                false,
+            )),
+            true,
+        );
+        assert!(can_cast_types(&list_type, &map_type));
+        let map = cast(list, &map_type).unwrap();
+        assert_eq!(map.data_type(), &map_type);
+        let map = map.as_map();
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 3]);
+        assert!(map.is_null(1));
+        assert_eq!(
+            map.keys().as_string::<i32>(),
+            array.keys().as_string::<i32>()
+        );
+        assert_eq!(
+            map.values().as_primitive::<Int32Type>(),
+            array.values().as_primitive::<Int32Type>()
+        );
+    }
+
+    #[test]
+    fn test_cast_list_to_map_null_entries() {
+        let entries = StructArray::new(
+            vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]
+            .into(),
+            vec![
+                Arc::new(StringArray::from(vec!["a", "", "b", "", "c"])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(2), None, None])) as ArrayRef,
+            ],
+            Some(NullBuffer::from(vec![true, false, true, false, true])),
+        );
+        let entries_field = Arc::new(Field::new("entries", entries.data_type().clone(), false));
+        let item = Arc::new(Field::new("item", entries.data_type().clone(), true));
+        let map_type = DataType::Map(entries_field, false);
+
+        // Null entries are only referenced by the null list slot or not at all
+        let list = ListArray::new(
+            item.clone(),
+            OffsetBuffer::new(vec![0, 1, 2, 3, 3, 5].into()),
+            Arc::new(entries.clone()),
+            Some(NullBuffer::from(vec![true, false, true, true, false])),
+        );
+        let map = cast(&list, &map_type).unwrap();
+        let map = map.as_map();
+        assert_eq!(map.value_offsets(), &[0, 1, 1, 2, 2, 2]);
+        assert_eq!(map.nulls(), list.nulls());
+        assert_eq!(
+            map.keys().as_string::<i32>(),
+            &StringArray::from(vec!["a", "b"])
+        );
+        assert_eq!(
+            map.values().as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, 2])
+        );
+
+        // A valid list slot references a null entry
+        let list = ListArray::new(
+            item,
+            OffsetBuffer::new(vec![0, 2, 3].into()),
+            Arc::new(entries.slice(0, 3)),
+            None,
+        );
+        let err = cast(&list, &map_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast list containing null entries to map"
+        );
+    }
+
+    #[test]
+    fn test_cast_list_to_map_unsorted_keys() {
+        let entries = StructArray::from(vec![
+            (
+                Arc::new(Field::new("key", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![1, 3, 2])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("value", DataType::Utf8, true)),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+            ),
+        ]);
+        let entries_field = Arc::new(Field::new("entries", entries.data_type().clone(), false));
+        let list = ListArray::new(
+            Arc::new(Field::new("item", entries.data_type().clone(), true)),
+            OffsetBuffer::new(vec![0, 3].into()),
+            Arc::new(entries),
+            None,
+        );
+
+        let unsorted = DataType::Map(entries_field.clone(), false);
+        let map = cast(&list, &unsorted).unwrap();
+        assert_eq!(
+            map.as_map().keys().as_primitive::<Int32Type>().values(),
+            &[1, 3, 2]
+        );
+
+        let sorted = DataType::Map(entries_field, true);
+        let err = cast(&list, &sorted).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to map with sorted keys: keys are not sorted"
+        );
+    }
+
     #[test]
     fn test_utf8_cast_offsets() {
         // test if offset of the array is taken into account during cast
@@ -8196,6 +9022,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8207,6 +9034,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -8222,6 +9050,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8233,6 +9062,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -8248,6 +9078,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8259,6 +9090,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8279,6 +9111,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8290,6 +9123,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8310,6 +9144,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8321,6 +9156,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8341,6 +9177,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8352,6 +9189,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8816,6 +9654,7 @@ mod tests {
         let option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
@@ -8858,6 +9697,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8869,6 +9709,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal128 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -8941,6 +9782,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8952,6 +9794,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal256 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -8998,6 +9841,7 @@ mod tests {
         let cast_options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         let result = cast_string_to_timestamp::<i32, TimestampNanosecondType>(
@@ -9126,6 +9970,7 @@ mod tests {
                 &CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -9177,6 +10022,7 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
         let a = array.as_string::<i32>();
@@ -9299,6 +10145,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_decimal_to_string_with_format_options() {
+        let array = create_decimal_array(vec![Some(123000), Some(-4500), None], 20, 12).unwrap();
+        let options = CastOptions {
+            safe: false,
+            format_options: FormatOptions::new()
+                .with_decimal_format(DecimalFormat::Scientific {
+                    min_exponent: -6,
+                    max_exponent: 20,
+                })
+                .with_decimal_trim_zeros(true),
+            ..Default::default()
+        };
+
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_string::<i32>();
+        assert_eq!("1.23e-7", c.value(0));
+        assert_eq!("-4.5e-9", c.value(1));
+        assert!(c.is_null(2));
+
+        let b = cast_with_options(&array, &DataType::Utf8View, &options).unwrap();
+        let c = b.as_string_view();
+        assert_eq!("1.23e-7", c.value(0));
+        assert_eq!("-4.5e-9", c.value(1));
+        assert!(c.is_null(2));
+    }
+
     #[test]
     fn test_cast_numeric_to_decimal128_precision_overflow() {
         let array = Int64Array::from(vec![1234567]);
@@ -9309,6 +10182,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -9320,6 +10194,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal128 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9335,6 +10210,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -9346,6 +10222,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal256 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9393,6 +10270,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -9426,6 +10304,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -9459,6 +10338,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -9485,6 +10365,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -9515,6 +10396,7 @@ mod tests {
         let fallible = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let v = IntervalMonthDayNano::new(0, 0, 1234567);
 
@@ -9682,6 +10564,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -9743,6 +10626,8 @@ mod tests {
     const CAST_OPTIONS: CastOptions<'static> = CastOptions {
         safe: true,
         format_options: FormatOptions::new(),
+        boolean_tokens: None,
+        struct_cast_mode: StructCastMode::ByPosition,
     };
 
     #[test]
@@ -9756,6 +10641,7 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default().with_null("null"),
+            ..Default::default()
         };
         let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
             Some(vec![Some(0), Some(1), Some(2)]),
@@ -9926,4 +10812,260 @@ mod tests {
             "Cast non-nullable to non-nullable struct field returning null should fail",
         );
     }
+
+    #[test]
+    fn test_cast_struct_to_struct_by_name() {
+        let struct_array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, true)),
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("c", DataType::Boolean, false)),
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ]);
+
+        // reorder, subset, cast and fill missing fields with nulls
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("b", DataType::Utf8View, true),
+                Field::new("a", DataType::Int64, false),
+                Field::new("d", DataType::Float64, true),
+            ]
+            .into(),
+        );
+        let options = CastOptions::default().with_struct_cast_mode(StructCastMode::ByName);
+        assert!(can_cast_types_with_options(
+            struct_array.data_type(),
+            &to_type,
+            &options
+        ));
+        let casted = cast_with_options(&struct_array, &to_type, &options).unwrap();
+        let casted = casted.as_struct();
+        assert_eq!(casted.data_type(), &to_type);
+        assert_eq!(
+            casted.column(0).as_string_view(),
+            &StringViewArray::from(vec![Some("x"), None, Some("z")])
+        );
+        assert_eq!(
+            casted.column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 2, 3])
+        );
+        assert_eq!(casted.column(2).null_count(), 3);
+
+        // a subset of fields can only be cast by name
+        let to_type = DataType::Struct(vec![Field::new("c", DataType::Utf8, false)].into());
+        assert!(!can_cast_types(struct_array.data_type(), &to_type));
+        assert!(can_cast_types_with_options(
+            struct_array.data_type(),
+            &to_type,
+            &options
+        ));
+        let casted = cast_with_options(&struct_array, &to_type, &options).unwrap();
+        assert_eq!(
+            casted.as_struct().column(0).as_string::<i32>(),
+            &StringArray::from(vec!["true", "false", "true"])
+        );
+
+        // missing non-nullable field
+        let to_type = DataType::Struct(vec![Field::new("d", DataType::Float64, false)].into());
+        assert!(!can_cast_types_with_options(
+            struct_array.data_type(),
+            &to_type,
+            &options
+        ));
+        let err = cast_with_options(&struct_array, &to_type, &options).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("non-nullable field 'd' not found in source type"));
+
+        // strict mode errors on missing fields
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("c", DataType::Boolean, false),
+                Field::new("d", DataType::Float64, true),
+            ]
+            .into(),
+        );
+        let options = CastOptions::default().with_struct_cast_mode(StructCastMode::ByNameStrict);
+        assert!(!can_cast_types_with_options(
+            struct_array.data_type(),
+            &to_type,
+            &options
+        ));
+        let err = cast_with_options(&struct_array, &to_type, &options).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("field 'd' not found in source type"));
+    }
+
+    #[test]
+    fn test_cast_nested_struct_by_name() {
+        let inner = StructArray::from(vec![
+            (
+                Arc::new(Field::new("x", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("y", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef,
+            ),
+        ]);
+        let inner_type = inner.data_type().clone();
+        let struct_array = StructArray::from(vec![(
+            Arc::new(Field::new("inner", inner_type, false)),
+            Arc::new(inner) as ArrayRef,
+        )]);
+
+        let to_inner = DataType::Struct(vec![Field::new("y", DataType::Utf8, false)].into());
+        let to_type = DataType::Struct(vec![Field::new("inner", to_inner, false)].into());
+        let options = CastOptions::default().with_struct_cast_mode(StructCastMode::ByName);
+        let casted = cast_with_options(&struct_array, &to_type, &options).unwrap();
+        let inner = casted.as_struct().column(0).as_struct();
+        assert_eq!(
+            inner.column(0).as_string::<i32>(),
+            &StringArray::from(vec!["3", "4"])
+        );
+    }
+
+    #[test]
+    fn test_cast_struct_mode_kept_with_format_options() {
+        let options = CastOptions::default().with_struct_cast_mode(StructCastMode::ByName);
+        let options = CastOptions {
+            format_options: FormatOptions::new().with_null("null"),
+            ..options
+        };
+        assert_eq!(options.struct_cast_mode(), StructCastMode::ByName);
+
+        let struct_array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Int32, false)),
+                Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef,
+            ),
+        ]);
+        let to_type = DataType::Struct(
+            vec![
+                Field::new("b", DataType::Int64, false),
+                Field::new("a", DataType::Int64, false),
+            ]
+            .into(),
+        );
+        let casted = cast_with_options(&struct_array, &to_type, &options).unwrap();
+        let casted = casted.as_struct();
+        assert_eq!(
+            casted.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![3, 4])
+        );
+        assert_eq!(
+            casted.column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 2])
+        );
+    }
+
+    fn union_fields() -> UnionFields {
+        UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("int", DataType::Int32, true),
+                Field::new("str", DataType::Utf8, true),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_cast_from_sparse_union() {
+        let ints = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        let strs = StringArray::from(vec![None, Some("b"), None, Some("d")]);
+        let array = UnionArray::try_new(
+            union_fields(),
+            vec![0, 1, 0, 1].into(),
+            None,
+            vec![Arc::new(ints), Arc::new(strs)],
+        )
+        .unwrap();
+
+        assert!(can_cast_types(array.data_type(), &DataType::Int64));
+        let casted = cast(&array, &DataType::Int64).unwrap();
+        assert_eq!(
+            casted.as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(1), None, Some(3), None])
+        );
+
+        let casted = cast(&array, &DataType::Utf8).unwrap();
+        assert_eq!(
+            casted.as_string::<i32>(),
+            &StringArray::from(vec![None, Some("b"), None, Some("d")])
+        );
+    }
+
+    #[test]
+    fn test_cast_from_dense_union() {
+        let ints = Int32Array::from(vec![1, 3]);
+        let strs = StringArray::from(vec!["b", "d"]);
+        let array = UnionArray::try_new(
+            union_fields(),
+            vec![0, 1, 0, 1].into(),
+            Some(vec![0, 0, 1, 1].into()),
+            vec![Arc::new(ints), Arc::new(strs)],
+        )
+        .unwrap();
+
+        let casted = cast(&array.slice(1, 3), &DataType::Int32).unwrap();
+        assert_eq!(
+            casted.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![None, Some(3), None])
+        );
+
+        // no Utf8View variant, falls back to the first castable variant
+        let casted = cast(&array, &DataType::Utf8View).unwrap();
+        assert_eq!(
+            casted.as_string_view(),
+            &StringViewArray::from(vec![Some("1"), None, Some("3"), None])
+        );
+
+        assert!(!can_cast_types(
+            array.data_type(),
+            &DataType::Struct(Fields::empty())
+        ));
+        let other = DataType::Union(union_fields(), UnionMode::Sparse);
+        assert!(!can_cast_types(array.data_type(), &other));
+        assert!(cast(&array, &other).is_err());
+    }
+
+    #[test]
+    fn test_cast_to_union() {
+        let array = StringArray::from(vec![Some("a"), None, Some("c")]);
+        for mode in [UnionMode::Sparse, UnionMode::Dense] {
+            let to_type = DataType::Union(union_fields(), mode);
+            assert!(can_cast_types(array.data_type(), &to_type));
+            let casted = cast(&array, &to_type).unwrap();
+            assert_eq!(casted.data_type(), &to_type);
+            let union = casted.as_union();
+            assert_eq!(union.type_ids(), &[1, 1, 1]);
+            assert_eq!(union.child(1).as_string::<i32>(), &array);
+
+            let back = cast(&casted, &DataType::Utf8).unwrap();
+            assert_eq!(back.as_string::<i32>(), &array);
+        }
+
+        // falls back to the first castable variant
+        let array = Int8Array::from(vec![1, 2]);
+        let to_type = DataType::Union(union_fields(), UnionMode::Dense);
+        let casted = cast(&array, &to_type).unwrap();
+        let union = casted.as_union();
+        assert_eq!(union.type_ids(), &[0, 0]);
+        assert_eq!(
+            union.child(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, 2])
+        );
+    }
 }
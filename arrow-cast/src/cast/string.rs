@@ -376,18 +376,53 @@ fn cast_string_to_boolean<'a, StrArray>(
 where
     StrArray: StringArrayType<'a>,
 {
+    // Lowercase custom tokens once rather than for every value
+    let lowercase_tokens = cast_options
+        .boolean_tokens()
+        .filter(|tokens| !tokens.case_sensitive())
+        .map(|tokens| {
+            let lower = |t: &[&str]| t.iter().map(|t| t.to_lowercase()).collect::<Vec<_>>();
+            (lower(tokens.true_tokens()), lower(tokens.false_tokens()))
+        });
+
+    let parse = |value: &str| -> Option<bool> {
+        let value = value.trim();
+        let (is_true, is_false) = match (cast_options.boolean_tokens(), &lowercase_tokens) {
+            (None, _) => {
+                return match value.to_ascii_lowercase().as_str() {
+                    "t" | "tr" | "tru" | "true" | "y" | "ye" | "yes" | "on" | "1" => Some(true),
+                    "f" | "fa" | "fal" | "fals" | "false" | "n" | "no" | "of" | "off" | "0" => {
+                        Some(false)
+                    }
+                    _ => None,
+                }
+            }
+            (Some(tokens), None) => (
+                tokens.true_tokens().contains(&value),
+                tokens.false_tokens().contains(&value),
+            ),
+            (Some(_), Some((true_tokens, false_tokens))) => {
+                let value = value.to_lowercase();
+                (true_tokens.contains(&value), false_tokens.contains(&value))
+            }
+        };
+        match (is_true, is_false) {
+            (true, _) => Some(true),
+            (false, true) => Some(false),
+            (false, false) => None,
+        }
+    };
+
     let output_array = array
         .iter()
         .map(|value| match value {
-            Some(value) => match value.to_ascii_lowercase().trim() {
-                "t" | "tr" | "tru" | "true" | "y" | "ye" | "yes" | "on" | "1" => Ok(Some(true)),
-                "f" | "fa" | "fal" | "fals" | "false" | "n" | "no" | "of" | "off" | "0" => {
-                    Ok(Some(false))
-                }
-                invalid_value => match cast_options.safe {
+            Some(value) => match parse(value) {
+                Some(b) => Ok(Some(b)),
+                None => match cast_options.safe {
                     true => Ok(None),
                     false => Err(ArrowError::CastError(format!(
-                        "Cannot cast value '{invalid_value}' to value of Boolean type",
+                        "Cannot cast value '{}' to value of Boolean type",
+                        value.trim()
                     ))),
                 },
             },
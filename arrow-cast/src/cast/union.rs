@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::cast::*;
+use arrow_buffer::ScalarBuffer;
+
+/// Returns the variant of `fields` to use when casting to or from `data_type`
+///
+/// Prefers a variant of exactly `data_type`, falling back to the first variant
+/// that `can_cast` accepts
+fn union_variant<'a>(
+    fields: &'a UnionFields,
+    data_type: &DataType,
+    can_cast: impl Fn(&DataType) -> bool,
+) -> Option<(i8, &'a FieldRef)> {
+    fields
+        .iter()
+        .find(|(_, f)| f.data_type() == data_type)
+        .or_else(|| fields.iter().find(|(_, f)| can_cast(f.data_type())))
+}
+
+/// Helper function that extracts a single variant of a [`UnionArray`] and casts it to `to_type`.
+///
+/// Slots containing other variants are null
+pub(crate) fn cast_from_union(
+    array: &UnionArray,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let DataType::Union(fields, _) = array.data_type() else {
+        unreachable!()
+    };
+    let (type_id, _) =
+        union_variant(fields, to_type, |f| can_cast_types(f, to_type)).ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Casting from {:?} to {to_type:?} not supported: no matching union variant",
+                array.data_type()
+            ))
+        })?;
+
+    let type_ids = array.type_ids().iter();
+    let indices: Int32Array = match array.offsets() {
+        Some(offsets) => type_ids
+            .zip(offsets.iter())
+            .map(|(t, o)| (*t == type_id).then_some(*o))
+            .collect(),
+        None => type_ids
+            .enumerate()
+            .map(|(i, t)| (*t == type_id).then_some(i as i32))
+            .collect(),
+    };
+
+    // Take before casting, as unselected slots may contain values that fail to cast
+    let values = take(array.child(type_id).as_ref(), &indices, None)?;
+    cast_with_options(&values, to_type, cast_options)
+}
+
+/// Helper function that wraps an array into a [`UnionArray`] containing a single variant.
+pub(crate) fn cast_to_union(
+    array: &dyn Array,
+    fields: &UnionFields,
+    mode: UnionMode,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let from_type = array.data_type();
+    let (type_id, field) = union_variant(fields, from_type, |f| can_cast_types(from_type, f))
+        .ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Casting from {from_type:?} to {:?} not supported: no matching union variant",
+                DataType::Union(fields.clone(), mode)
+            ))
+        })?;
+
+    let values = cast_with_options(array, field.data_type(), cast_options)?;
+    let len = values.len();
+    let children = fields
+        .iter()
+        .map(|(id, f)| match mode {
+            _ if id == type_id => values.clone(),
+            UnionMode::Sparse => new_null_array(f.data_type(), len),
+            UnionMode::Dense => new_empty_array(f.data_type()),
+        })
+        .collect();
+    let offsets = match mode {
+        UnionMode::Sparse => None,
+        UnionMode::Dense => Some((0..len as i32).collect::<ScalarBuffer<i32>>()),
+    };
+
+    Ok(Arc::new(UnionArray::try_new(
+        fields.clone(),
+        vec![type_id; len].into(),
+        offsets,
+        children,
+    )?))
+}
@@ -23,8 +23,10 @@
 //! record batch pretty printing.
 //!
 //! [`pretty`]: crate::pretty
-use std::fmt::{Display, Formatter, Write};
+use std::fmt::{Debug, Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
 use std::ops::Range;
+use std::sync::Arc;
 
 use arrow_array::cast::*;
 use arrow_array::temporal_conversions::*;
@@ -48,6 +50,110 @@ pub enum DurationFormat {
     Pretty,
 }
 
+/// Format for displaying decimals
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecimalFormat {
+    /// Fixed-point notation with `scale` fractional digits - `0.000000000123000`
+    Fixed,
+    /// Scientific notation if the decimal exponent of a value is less than `min_exponent`
+    /// or greater than `max_exponent`, and fixed-point notation otherwise - `1.23000e-10`
+    ///
+    /// The exponent of a value is the power of ten of its most significant digit, i.e.
+    /// `0` for `1.5`, `2` for `123.0` and `-3` for `0.001`. Zero is always formatted
+    /// using fixed-point notation
+    Scientific {
+        /// Values with an exponent below this are formatted in scientific notation
+        min_exponent: i32,
+        /// Values with an exponent above this are formatted in scientific notation
+        max_exponent: i32,
+    },
+}
+
+/// A function that formats the non-null value at an index of an array
+pub type ValueFormatterFn =
+    dyn Fn(&dyn Array, usize, &mut dyn Write) -> std::fmt::Result + Send + Sync;
+
+/// A set of formatting overrides for specific [`DataType`]s
+///
+/// Arrays of a registered type, including those nested within other types, are
+/// formatted by calling the registered function for each non-null value
+///
+/// ```
+/// # use std::fmt::Write;
+/// # use arrow_array::{cast::AsArray, BinaryArray};
+/// # use arrow_cast::display::{ArrayFormatter, FormatOptions, TypeFormatters};
+/// # use arrow_schema::DataType;
+/// let formatters = TypeFormatters::new().with_formatter(DataType::Binary, |array, idx, f| {
+///     array.as_binary::<i32>().value(idx).iter().try_for_each(|b| write!(f, "{b:02x}"))
+/// });
+/// let options = FormatOptions::new()
+///     .with_null("NULL")
+///     .with_type_formatters(Some(&formatters));
+///
+/// let array = BinaryArray::from(vec![Some(b"\x01\xab".as_slice()), None]);
+/// let formatter = ArrayFormatter::try_new(&array, &options).unwrap();
+/// assert_eq!(formatter.value(0).to_string(), "01ab");
+/// assert_eq!(formatter.value(1).to_string(), "NULL");
+/// ```
+#[derive(Clone, Default)]
+pub struct TypeFormatters {
+    formatters: Vec<(DataType, Arc<ValueFormatterFn>)>,
+}
+
+impl TypeFormatters {
+    /// Creates an empty set of formatting overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Formats values of `data_type` with `formatter`, replacing any formatter
+    /// previously registered for `data_type`
+    pub fn with_formatter<F>(mut self, data_type: DataType, formatter: F) -> Self
+    where
+        F: Fn(&dyn Array, usize, &mut dyn Write) -> std::fmt::Result + Send + Sync + 'static,
+    {
+        self.formatters.retain(|(d, _)| d != &data_type);
+        self.formatters.push((data_type, Arc::new(formatter)));
+        self
+    }
+
+    /// Returns the formatter registered for `data_type`, if any
+    pub fn get(&self, data_type: &DataType) -> Option<&ValueFormatterFn> {
+        self.formatters
+            .iter()
+            .find(|(d, _)| d == data_type)
+            .map(|(_, f)| f.as_ref())
+    }
+}
+
+impl Debug for TypeFormatters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.formatters.iter().map(|(d, _)| d))
+            .finish()
+    }
+}
+
+impl PartialEq for TypeFormatters {
+    fn eq(&self, other: &Self) -> bool {
+        self.formatters.len() == other.formatters.len()
+            && self
+                .formatters
+                .iter()
+                .zip(&other.formatters)
+                .all(|((a, f_a), (b, f_b))| a == b && Arc::ptr_eq(f_a, f_b))
+    }
+}
+
+impl Eq for TypeFormatters {}
+
+impl Hash for TypeFormatters {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.formatters.iter().for_each(|(d, _)| d.hash(state))
+    }
+}
+
 /// Options for formatting arrays
 ///
 /// By default nulls are formatted as `""` and temporal types formatted
@@ -72,6 +178,16 @@ pub struct FormatOptions<'a> {
     time_format: TimeFormat<'a>,
     /// Duration format
     duration_format: DurationFormat,
+    /// Decimal format
+    decimal_format: DecimalFormat,
+    /// If set to `true` trailing fractional zeros are removed from decimals
+    decimal_trim_zeros: bool,
+    /// Formatting overrides for specific data types
+    type_formatters: Option<&'a TypeFormatters>,
+    /// Maximum depth of nested values to display
+    max_nested_depth: Option<usize>,
+    /// Maximum number of list and map elements to display
+    max_list_items: Option<usize>,
 }
 
 impl Default for FormatOptions<'_> {
@@ -92,6 +208,11 @@ impl<'a> FormatOptions<'a> {
             timestamp_tz_format: None,
             time_format: None,
             duration_format: DurationFormat::ISO8601,
+            decimal_format: DecimalFormat::Fixed,
+            decimal_trim_zeros: false,
+            type_formatters: None,
+            max_nested_depth: None,
+            max_list_items: None,
         }
     }
 
@@ -158,6 +279,71 @@ impl<'a> FormatOptions<'a> {
             ..self
         }
     }
+
+    /// Overrides the format used for decimal columns, such as [`DataType::Decimal128`]
+    ///
+    /// Defaults to [`DecimalFormat::Fixed`]
+    pub const fn with_decimal_format(self, decimal_format: DecimalFormat) -> Self {
+        Self {
+            decimal_format,
+            ..self
+        }
+    }
+
+    /// If set to `true` trailing zeros in the fractional part of decimals are removed,
+    /// e.g. `1.2300` is formatted as `1.23` and `5.000` as `5`
+    ///
+    /// Defaults to `false`
+    pub const fn with_decimal_trim_zeros(self, decimal_trim_zeros: bool) -> Self {
+        Self {
+            decimal_trim_zeros,
+            ..self
+        }
+    }
+
+    /// Overrides the formatting of values of specific data types, see [`TypeFormatters`]
+    ///
+    /// Defaults to `None`
+    pub const fn with_type_formatters(self, type_formatters: Option<&'a TypeFormatters>) -> Self {
+        Self {
+            type_formatters,
+            ..self
+        }
+    }
+
+    /// Limits the depth to which nested values, such as lists, structs and maps,
+    /// are displayed. Values nested deeper than this are displayed as `[…]` or `{…}`
+    ///
+    /// For example a depth of `1` displays `{a: [1, 2]}` as `{a: […]}`
+    ///
+    /// Defaults to `None`, displaying values of any depth
+    pub const fn with_max_nested_depth(self, max_nested_depth: Option<usize>) -> Self {
+        Self {
+            max_nested_depth,
+            ..self
+        }
+    }
+
+    /// Limits the number of elements displayed for each list or map value,
+    /// with any remaining elements replaced by `…`
+    ///
+    /// For example a limit of `2` displays `[1, 2, 3]` as `[1, 2, …]`
+    ///
+    /// Defaults to `None`, displaying all elements
+    pub const fn with_max_list_items(self, max_list_items: Option<usize>) -> Self {
+        Self {
+            max_list_items,
+            ..self
+        }
+    }
+
+    /// Returns the options used to format the children of a nested value
+    fn nested(&self) -> Self {
+        Self {
+            max_nested_depth: self.max_nested_depth.map(|d| d.saturating_sub(1)),
+            ..self.clone()
+        }
+    }
 }
 
 /// Implements [`Display`] for a specific array value
@@ -281,6 +467,32 @@ fn make_formatter<'a>(
     array: &'a dyn Array,
     options: &FormatOptions<'a>,
 ) -> Result<Box<dyn DisplayIndex + 'a>, ArrowError> {
+    let type_formatter = options
+        .type_formatters
+        .and_then(|t| t.get(array.data_type()));
+    if let Some(format) = type_formatter {
+        return Ok(Box::new(TypeFormat {
+            array,
+            format,
+            null: options.null,
+        }));
+    }
+
+    if options.max_nested_depth == Some(0) {
+        let placeholder = match array.data_type() {
+            DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => "[…]",
+            DataType::Struct(_) | DataType::Map(_, _) => "{…}",
+            _ => "",
+        };
+        if !placeholder.is_empty() {
+            return Ok(Box::new(ElidedFormat {
+                array,
+                placeholder,
+                null: options.null,
+            }));
+        }
+    }
+
     downcast_primitive_array! {
         array => array_format(array, options),
         DataType::Null => array_format(as_null_array(array), options),
@@ -395,6 +607,44 @@ impl<'a, F: DisplayIndexState<'a> + Array> DisplayIndex for ArrayFormat<'a, F> {
     }
 }
 
+/// Formats the non-null values of a nested array nested beyond
+/// [`FormatOptions::with_max_nested_depth`] as a placeholder
+struct ElidedFormat<'a> {
+    array: &'a dyn Array,
+    placeholder: &'static str,
+    null: &'a str,
+}
+
+impl DisplayIndex for ElidedFormat<'_> {
+    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+        match self.array.is_null(idx) {
+            true => f.write_str(self.null)?,
+            false => f.write_str(self.placeholder)?,
+        }
+        Ok(())
+    }
+}
+
+/// Formats an array using a function registered in [`TypeFormatters`]
+struct TypeFormat<'a> {
+    array: &'a dyn Array,
+    format: &'a ValueFormatterFn,
+    null: &'a str,
+}
+
+impl DisplayIndex for TypeFormat<'_> {
+    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+        if self.array.is_null(idx) {
+            if !self.null.is_empty() {
+                f.write_str(self.null)?
+            }
+            return Ok(());
+        }
+        (self.format)(self.array, idx, f)?;
+        Ok(())
+    }
+}
+
 impl DisplayIndex for &BooleanArray {
     fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
         write!(f, "{}", self.value(idx))?;
@@ -460,21 +710,98 @@ impl DisplayIndex for &PrimitiveArray<Float16Type> {
 macro_rules! decimal_display {
     ($($t:ty),+) => {
         $(impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t> {
-            type State = (u8, i8);
+            type State = (u8, i8, DecimalFormat, bool);
 
-            fn prepare(&self, _options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-                Ok((self.precision(), self.scale()))
+            fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+                Ok((
+                    self.precision(),
+                    self.scale(),
+                    options.decimal_format,
+                    options.decimal_trim_zeros,
+                ))
             }
 
             fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
-                write!(f, "{}", <$t>::format_decimal(self.values()[idx], s.0, s.1))?;
+                let formatted = <$t>::format_decimal(self.values()[idx], s.0, s.1);
+                match (s.2, s.3) {
+                    (DecimalFormat::Fixed, false) => f.write_str(&formatted)?,
+                    (format, trim_zeros) => write_decimal(f, &formatted, format, trim_zeros)?,
+                }
                 Ok(())
             }
         })+
     };
 }
 
-decimal_display!(Decimal128Type, Decimal256Type);
+/// Writes the fixed-point decimal string `fixed` according to `format` and `trim_zeros`
+fn write_decimal(
+    f: &mut dyn Write,
+    fixed: &str,
+    format: DecimalFormat,
+    trim_zeros: bool,
+) -> FormatResult {
+    let (sign, unsigned) = match fixed.strip_prefix('-') {
+        Some(stripped) => ("-", stripped),
+        None => ("", fixed),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+    if let DecimalFormat::Scientific {
+        min_exponent,
+        max_exponent,
+    } = format
+    {
+        let whole_trimmed = whole.trim_start_matches('0');
+        let (exponent, digits) = match whole_trimmed.is_empty() {
+            false => (whole_trimmed.len() as i32 - 1, None),
+            true => {
+                let significant = fraction.trim_start_matches('0');
+                let leading = (fraction.len() - significant.len()) as i32;
+                (-leading - 1, Some(significant))
+            }
+        };
+
+        let is_zero = whole_trimmed.is_empty() && digits.map(|d| d.is_empty()).unwrap_or(true);
+        if !is_zero && (exponent < min_exponent || exponent > max_exponent) {
+            f.write_str(sign)?;
+            let mut rest = String::new();
+            let first = match digits {
+                None => {
+                    rest.push_str(&whole_trimmed[1..]);
+                    rest.push_str(fraction);
+                    &whole_trimmed[..1]
+                }
+                Some(d) => {
+                    rest.push_str(&d[1..]);
+                    &d[..1]
+                }
+            };
+            f.write_str(first)?;
+            let rest = match trim_zeros {
+                true => rest.trim_end_matches('0'),
+                false => rest.as_str(),
+            };
+            if !rest.is_empty() {
+                write!(f, ".{rest}")?;
+            }
+            write!(f, "e{exponent}")?;
+            return Ok(());
+        }
+    }
+
+    let fraction = match trim_zeros {
+        true => fraction.trim_end_matches('0'),
+        false => fraction,
+    };
+    f.write_str(sign)?;
+    f.write_str(whole)?;
+    if !fraction.is_empty() {
+        write!(f, ".{fraction}")?;
+    }
+    Ok(())
+}
+
+decimal_display!(Decimal32Type, Decimal64Type, Decimal128Type, Decimal256Type);
 
 fn write_timestamp(
     f: &mut dyn Write,
@@ -869,51 +1196,72 @@ impl<'a, K: RunEndIndexType> DisplayIndexState<'a> for &'a RunArray<K> {
     }
 }
 
+/// Writes the elements of `range` separated by `, `, writing at most
+/// `max_items` elements followed by `…` if there are more
+fn write_elements(
+    f: &mut dyn Write,
+    range: Range<usize>,
+    max_items: Option<usize>,
+    mut write_element: impl FnMut(usize, &mut dyn Write) -> FormatResult,
+) -> FormatResult {
+    let len = range.len();
+    let shown = max_items.map_or(len, |m| m.min(len));
+    for (i, idx) in range.take(shown).enumerate() {
+        if i != 0 {
+            write!(f, ", ")?;
+        }
+        write_element(idx, f)?;
+    }
+    if shown < len {
+        match shown {
+            0 => f.write_char('…')?,
+            _ => write!(f, ", …")?,
+        }
+    }
+    Ok(())
+}
+
 fn write_list(
     f: &mut dyn Write,
-    mut range: Range<usize>,
+    range: Range<usize>,
+    max_items: Option<usize>,
     values: &dyn DisplayIndex,
 ) -> FormatResult {
     f.write_char('[')?;
-    if let Some(idx) = range.next() {
-        values.write(idx, f)?;
-    }
-    for idx in range {
-        write!(f, ", ")?;
-        values.write(idx, f)?;
-    }
+    write_elements(f, range, max_items, |idx, f| values.write(idx, f))?;
     f.write_char(']')?;
     Ok(())
 }
 
 impl<'a, O: OffsetSizeTrait> DisplayIndexState<'a> for &'a GenericListArray<O> {
-    type State = Box<dyn DisplayIndex + 'a>;
+    type State = (Option<usize>, Box<dyn DisplayIndex + 'a>);
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-        make_formatter(self.values().as_ref(), options)
+        let values = make_formatter(self.values().as_ref(), &options.nested())?;
+        Ok((options.max_list_items, values))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let offsets = self.value_offsets();
         let end = offsets[idx + 1].as_usize();
         let start = offsets[idx].as_usize();
-        write_list(f, start..end, s.as_ref())
+        write_list(f, start..end, s.0, s.1.as_ref())
     }
 }
 
 impl<'a> DisplayIndexState<'a> for &'a FixedSizeListArray {
-    type State = (usize, Box<dyn DisplayIndex + 'a>);
+    type State = (usize, Option<usize>, Box<dyn DisplayIndex + 'a>);
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-        let values = make_formatter(self.values().as_ref(), options)?;
+        let values = make_formatter(self.values().as_ref(), &options.nested())?;
         let length = self.value_length();
-        Ok((length as usize, values))
+        Ok((length as usize, options.max_list_items, values))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let start = idx * s.0;
         let end = start + s.0;
-        write_list(f, start..end, s.1.as_ref())
+        write_list(f, start..end, s.1, s.2.as_ref())
     }
 }
 
@@ -933,7 +1281,7 @@ impl<'a> DisplayIndexState<'a> for &'a StructArray {
             .iter()
             .zip(fields)
             .map(|(a, f)| {
-                let format = make_formatter(a.as_ref(), options)?;
+                let format = make_formatter(a.as_ref(), &options.nested())?;
                 Ok((f.name().as_str(), format))
             })
             .collect()
@@ -956,34 +1304,30 @@ impl<'a> DisplayIndexState<'a> for &'a StructArray {
 }
 
 impl<'a> DisplayIndexState<'a> for &'a MapArray {
-    type State = (Box<dyn DisplayIndex + 'a>, Box<dyn DisplayIndex + 'a>);
+    type State = (
+        Box<dyn DisplayIndex + 'a>,
+        Box<dyn DisplayIndex + 'a>,
+        Option<usize>,
+    );
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-        let keys = make_formatter(self.keys().as_ref(), options)?;
-        let values = make_formatter(self.values().as_ref(), options)?;
-        Ok((keys, values))
+        let nested = options.nested();
+        let keys = make_formatter(self.keys().as_ref(), &nested)?;
+        let values = make_formatter(self.values().as_ref(), &nested)?;
+        Ok((keys, values, options.max_list_items))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let offsets = self.value_offsets();
         let end = offsets[idx + 1].as_usize();
         let start = offsets[idx].as_usize();
-        let mut iter = start..end;
 
         f.write_char('{')?;
-        if let Some(idx) = iter.next() {
-            s.0.write(idx, f)?;
-            write!(f, ": ")?;
-            s.1.write(idx, f)?;
-        }
-
-        for idx in iter {
-            write!(f, ", ")?;
+        write_elements(f, start..end, s.2, |idx, f| {
             s.0.write(idx, f)?;
             write!(f, ": ")?;
-            s.1.write(idx, f)?;
-        }
-
+            s.1.write(idx, f)
+        })?;
         f.write_char('}')?;
         Ok(())
     }
@@ -1057,6 +1401,7 @@ pub fn lexical_to_string<N: lexical_core::ToLexical>(n: N) -> String {
 #[cfg(test)]
 mod tests {
     use arrow_array::builder::StringRunBuilder;
+    use arrow_buffer::i256;
 
     use super::*;
 
@@ -1195,6 +1540,58 @@ mod tests {
         assert_eq!(pretty[5], "-45 days -14 hours -2 mins -34 secs");
     }
 
+    #[test]
+    fn test_decimal_format() {
+        let array = Decimal128Array::from(vec![123000, -1230000000000000, 0, 5000000000000000])
+            .with_precision_and_scale(38, 15)
+            .unwrap();
+
+        let fixed = format_array(&array, &FormatOptions::new());
+        assert_eq!(
+            fixed,
+            &[
+                "0.000000000123000",
+                "-1.230000000000000",
+                "0.000000000000000",
+                "5.000000000000000"
+            ]
+        );
+
+        let trimmed = format_array(&array, &FormatOptions::new().with_decimal_trim_zeros(true));
+        assert_eq!(trimmed, &["0.000000000123", "-1.23", "0", "5"]);
+
+        let scientific = DecimalFormat::Scientific {
+            min_exponent: -6,
+            max_exponent: 20,
+        };
+        let options = FormatOptions::new().with_decimal_format(scientific);
+        let formatted = format_array(&array, &options);
+        assert_eq!(
+            formatted,
+            &[
+                "1.23000e-10",
+                "-1.230000000000000",
+                "0.000000000000000",
+                "5.000000000000000"
+            ]
+        );
+
+        let formatted = format_array(&array, &options.with_decimal_trim_zeros(true));
+        assert_eq!(formatted, &["1.23e-10", "-1.23", "0", "5"]);
+
+        let array = Decimal256Array::from(vec![i256::from_i128(-12345), i256::from_i128(7)])
+            .with_precision_and_scale(20, -3)
+            .unwrap();
+        let options = FormatOptions::new()
+            .with_decimal_format(DecimalFormat::Scientific {
+                min_exponent: -6,
+                max_exponent: 5,
+            })
+            .with_decimal_trim_zeros(true);
+        let formatted = format_array(&array, &options);
+        assert_eq!(formatted, &["-1.2345e7", "7000"]);
+    }
+
     #[test]
     fn test_null() {
         let array = NullArray::new(2);
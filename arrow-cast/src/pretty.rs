@@ -42,7 +42,46 @@ pub fn pretty_format_batches_with_options(
     results: &[RecordBatch],
     options: &FormatOptions,
 ) -> Result<impl Display, ArrowError> {
-    create_table(results, options)
+    create_table(results, options, &TableOptions::default())
+}
+
+/// Create a visual representation of record batches, with the layout of the
+/// table controlled by [`TableOptions`]
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_cast::display::FormatOptions;
+/// # use arrow_cast::pretty::{pretty_format_batches_with_table_options, TableOptions};
+/// let batch = RecordBatch::try_from_iter([
+///     ("id", Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef),
+///     ("name", Arc::new(StringArray::from(vec!["alpha", "beta", "gamma", "delta"])) as ArrayRef),
+/// ])
+/// .unwrap();
+///
+/// let table = TableOptions::new()
+///     .with_max_column_width(Some(4))
+///     .with_max_rows(Some(2));
+/// let options = FormatOptions::default();
+/// let output = pretty_format_batches_with_table_options(&[batch], &options, &table).unwrap();
+///
+/// assert_eq!(
+///     output.to_string(),
+///     "+----+------+\n\
+///      | id | name |\n\
+///      +----+------+\n\
+///      | 1  | alp… |\n\
+///      | …  | …    |\n\
+///      | 4  | del… |\n\
+///      +----+------+"
+/// );
+/// ```
+pub fn pretty_format_batches_with_table_options(
+    results: &[RecordBatch],
+    options: &FormatOptions,
+    table_options: &TableOptions,
+) -> Result<impl Display, ArrowError> {
+    create_table(results, options, table_options)
 }
 
 /// Create a visual representation of columns
@@ -75,8 +114,106 @@ pub fn print_columns(col_name: &str, results: &[ArrayRef]) -> Result<(), ArrowEr
     Ok(())
 }
 
+/// Options controlling the layout of tables created by
+/// [`pretty_format_batches_with_table_options`]
+///
+/// Elided rows, columns and cell contents are replaced by `…`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TableOptions {
+    max_column_width: Option<usize>,
+    max_table_width: Option<usize>,
+    max_rows: Option<usize>,
+}
+
+impl TableOptions {
+    /// Creates a new set of table options, with no limits
+    pub const fn new() -> Self {
+        Self {
+            max_column_width: None,
+            max_table_width: None,
+            max_rows: None,
+        }
+    }
+
+    /// Limits the number of characters displayed in each cell, including headers,
+    /// truncating longer values with a trailing `…`
+    ///
+    /// Defaults to `None`
+    pub const fn with_max_column_width(self, max_column_width: Option<usize>) -> Self {
+        Self {
+            max_column_width,
+            ..self
+        }
+    }
+
+    /// Limits the total width of the table in characters, including borders,
+    /// replacing columns from the middle of the table with a single `…` column
+    ///
+    /// The first column is always displayed, even if it exceeds this width
+    ///
+    /// Defaults to `None`
+    pub const fn with_max_table_width(self, max_table_width: Option<usize>) -> Self {
+        Self {
+            max_table_width,
+            ..self
+        }
+    }
+
+    /// Limits the number of rows displayed, displaying the first and last rows
+    /// separated by a row of `…`
+    ///
+    /// Defaults to `None`
+    pub const fn with_max_rows(self, max_rows: Option<usize>) -> Self {
+        Self { max_rows, ..self }
+    }
+}
+
+/// Truncates `value` to at most `max_width` characters, ending with `…` if truncated
+fn truncate(value: String, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(max) if value.chars().count() > max => {
+            let mut truncated: String = value.chars().take(max.saturating_sub(1)).collect();
+            truncated.push('…');
+            truncated
+        }
+        _ => value,
+    }
+}
+
+/// Returns the range of leading and the start of trailing columns to display, such that
+/// the table, including a `…` column for the elided columns, fits within `max_width`
+fn visible_columns(widths: &[usize], max_width: usize) -> (usize, usize) {
+    // Each column is padded by a space on either side and followed by a border,
+    // and the table starts with a border
+    let total = 1 + widths.iter().map(|w| w + 3).sum::<usize>();
+    if total <= max_width {
+        return (widths.len(), widths.len());
+    }
+
+    let (mut left, mut right) = (0, widths.len());
+    let mut used = 1 + 4;
+    let mut take_left = true;
+    while left < right {
+        let idx = if take_left { left } else { right - 1 };
+        if used + widths[idx] + 3 > max_width && left > 0 {
+            break;
+        }
+        used += widths[idx] + 3;
+        match take_left {
+            true => left += 1,
+            false => right -= 1,
+        }
+        take_left = !take_left;
+    }
+    (left, right)
+}
+
 /// Convert a series of record batches into a table
-fn create_table(results: &[RecordBatch], options: &FormatOptions) -> Result<Table, ArrowError> {
+fn create_table(
+    results: &[RecordBatch],
+    options: &FormatOptions,
+    table_options: &TableOptions,
+) -> Result<Table, ArrowError> {
     let mut table = Table::new();
     table.load_preset("||--+-++|    ++++++");
 
@@ -85,13 +222,23 @@ fn create_table(results: &[RecordBatch], options: &FormatOptions) -> Result<Tabl
     }
 
     let schema = results[0].schema();
+    let max_width = table_options.max_column_width;
 
-    let mut header = Vec::new();
-    for field in schema.fields() {
-        header.push(Cell::new(field.name()));
-    }
-    table.set_header(header);
+    let header: Vec<_> = schema
+        .fields()
+        .iter()
+        .map(|f| truncate(f.name().to_string(), max_width))
+        .collect();
+
+    // Determine the rows to display, eliding rows from the middle if necessary
+    let num_rows: usize = results.iter().map(|b| b.num_rows()).sum();
+    let (head, tail) = match table_options.max_rows {
+        Some(max) if num_rows > max => (max - max / 2, max / 2),
+        _ => (num_rows, 0),
+    };
 
+    let mut rows = Vec::with_capacity(head + tail);
+    let mut offset = 0;
     for batch in results {
         let formatters = batch
             .columns()
@@ -100,12 +247,47 @@ fn create_table(results: &[RecordBatch], options: &FormatOptions) -> Result<Tabl
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
         for row in 0..batch.num_rows() {
-            let mut cells = Vec::new();
-            for formatter in &formatters {
-                cells.push(Cell::new(formatter.value(row)));
+            let global = offset + row;
+            if global >= head && global < num_rows - tail {
+                continue;
             }
-            table.add_row(cells);
+            let cells = formatters.iter();
+            rows.push(
+                cells
+                    .map(|f| truncate(f.value(row).to_string(), max_width))
+                    .collect(),
+            );
         }
+        offset += batch.num_rows();
+    }
+    if head + tail < num_rows {
+        rows.insert(head, vec!["…".to_string(); header.len()]);
+    }
+
+    // Determine the columns to display, eliding columns from the middle if necessary
+    let (left, right) = match table_options.max_table_width {
+        Some(max) => {
+            let widths: Vec<_> = (0..header.len())
+                .map(|col| {
+                    let cells = rows.iter().map(|r| r[col].chars().count());
+                    cells.fold(header[col].chars().count(), usize::max)
+                })
+                .collect();
+            visible_columns(&widths, max)
+        }
+        None => (header.len(), header.len()),
+    };
+    let select = |values: Vec<String>| -> Vec<Cell> {
+        let mut cells: Vec<_> = values.into_iter().map(Cell::new).collect();
+        if left < right {
+            cells.splice(left..right, [Cell::new("…")]);
+        }
+        cells
+    };
+
+    table.set_header(select(header));
+    for row in rows {
+        table.add_row(select(row));
     }
 
     Ok(table)
@@ -145,12 +327,13 @@ mod tests {
     use half::f16;
 
     use arrow_array::builder::*;
+    use arrow_array::cast::AsArray;
     use arrow_array::types::*;
     use arrow_array::*;
-    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, ScalarBuffer};
+    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, OffsetBuffer, ScalarBuffer};
     use arrow_schema::*;
 
-    use crate::display::array_value_to_string;
+    use crate::display::{array_value_to_string, TypeFormatters};
 
     use super::*;
 
@@ -1093,4 +1276,248 @@ mod tests {
         let actual: Vec<&str> = batch.lines().collect();
         assert_eq!(expected, actual, "Actual result:\n{batch}");
     }
+
+    #[test]
+    fn test_format_options_type_formatters() {
+        let formatters = TypeFormatters::new().with_formatter(DataType::Binary, |array, idx, f| {
+            let value = array.as_binary::<i32>().value(idx);
+            value.iter().try_for_each(|b| write!(f, "{b:02x}"))
+        });
+        let options = FormatOptions::default()
+            .with_null("<null>")
+            .with_type_formatters(Some(&formatters));
+
+        let binary = BinaryArray::from(vec![Some(b"\x0a\x0b".as_slice()), None]);
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Binary, true)),
+            OffsetBuffer::from_lengths([2, 0]),
+            Arc::new(binary.clone()),
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([
+            ("binary", Arc::new(binary) as _),
+            ("list", Arc::new(list) as _),
+        ])
+        .unwrap();
+
+        let table = pretty_format_batches_with_options(&[batch], &options)
+            .unwrap()
+            .to_string();
+
+        let expected = vec![
+            "+--------+----------------+",
+            "| binary | list           |",
+            "+--------+----------------+",
+            "| 0a0b   | [0a0b, <null>] |",
+            "| <null> | []             |",
+            "+--------+----------------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+    }
+
+    fn wide_batches() -> Vec<RecordBatch> {
+        let batch = |start: i32| {
+            let ids = Int32Array::from_iter_values(start..start + 3);
+            let names = StringArray::from_iter_values(
+                (start..start + 3).map(|i| format!("name with a long suffix {i}")),
+            );
+            RecordBatch::try_from_iter([
+                ("id", Arc::new(ids.clone()) as ArrayRef),
+                ("name", Arc::new(names) as ArrayRef),
+                ("c", Arc::new(ids.clone()) as ArrayRef),
+                ("d", Arc::new(ids.clone()) as ArrayRef),
+                ("last_column", Arc::new(ids) as ArrayRef),
+            ])
+            .unwrap()
+        };
+        vec![batch(0), batch(3)]
+    }
+
+    #[test]
+    fn test_table_options_max_column_width() {
+        let options = FormatOptions::default();
+        let table_options = TableOptions::new().with_max_column_width(Some(8));
+        let table = pretty_format_batches_with_table_options(
+            &wide_batches()[..1],
+            &options,
+            &table_options,
+        )
+        .unwrap()
+        .to_string();
+
+        let expected = vec![
+            "+----+----------+---+---+----------+",
+            "| id | name     | c | d | last_co… |",
+            "+----+----------+---+---+----------+",
+            "| 0  | name wi… | 0 | 0 | 0        |",
+            "| 1  | name wi… | 1 | 1 | 1        |",
+            "| 2  | name wi… | 2 | 2 | 2        |",
+            "+----+----------+---+---+----------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+    }
+
+    #[test]
+    fn test_table_options_max_rows() {
+        let options = FormatOptions::default();
+        let batches = wide_batches();
+        let columns: Vec<_> = batches.iter().map(|b| b.project(&[0]).unwrap()).collect();
+
+        let table_options = TableOptions::new().with_max_rows(Some(3));
+        let table = pretty_format_batches_with_table_options(&columns, &options, &table_options)
+            .unwrap()
+            .to_string();
+
+        // Rows are taken from both the first and last batch
+        let expected = vec![
+            "+----+", "| id |", "+----+", "| 0  |", "| 1  |", "| …  |", "| 5  |", "+----+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        // No rows are elided if the limit is not exceeded
+        let table_options = TableOptions::new().with_max_rows(Some(6));
+        let table = pretty_format_batches_with_table_options(&columns, &options, &table_options)
+            .unwrap()
+            .to_string();
+        assert_eq!(table.lines().count(), 10, "Actual result:\n{table}");
+
+        let table_options = TableOptions::new().with_max_rows(Some(0));
+        let table = pretty_format_batches_with_table_options(&columns, &options, &table_options)
+            .unwrap()
+            .to_string();
+        let expected = vec!["+----+", "| id |", "+----+", "| …  |", "+----+"];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+    }
+
+    #[test]
+    fn test_table_options_max_table_width() {
+        let options = FormatOptions::default();
+        let table_options = TableOptions::new()
+            .with_max_table_width(Some(30))
+            .with_max_rows(Some(2));
+        let table =
+            pretty_format_batches_with_table_options(&wide_batches(), &options, &table_options)
+                .unwrap()
+                .to_string();
+
+        let expected = vec![
+            "+----+---+-------------+",
+            "| id | … | last_column |",
+            "+----+---+-------------+",
+            "| 0  | … | 0           |",
+            "| …  | … | …           |",
+            "| 5  | … | 5           |",
+            "+----+---+-------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+        assert!(actual.iter().all(|l| l.chars().count() <= 30));
+
+        // The first column is always displayed
+        let table_options = TableOptions::new()
+            .with_max_table_width(Some(1))
+            .with_max_rows(Some(1));
+        let table =
+            pretty_format_batches_with_table_options(&wide_batches(), &options, &table_options)
+                .unwrap()
+                .to_string();
+        let expected = vec![
+            "+----+---+",
+            "| id | … |",
+            "+----+---+",
+            "| 0  | … |",
+            "| …  | … |",
+            "+----+---+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        // The table is unchanged if it fits
+        let table_options = TableOptions::new().with_max_table_width(Some(1000));
+        let table =
+            pretty_format_batches_with_table_options(&wide_batches(), &options, &table_options)
+                .unwrap()
+                .to_string();
+        let expected = pretty_format_batches(&wide_batches()).unwrap().to_string();
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn test_format_options_nested() {
+        let inner = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+        ]);
+        let map = {
+            let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+            for i in 0..3 {
+                for (key, value) in ["a", "b", "c"].iter().zip(0..i + 1) {
+                    builder.keys().append_value(key);
+                    builder.values().append_value(value);
+                }
+                builder.append(true).unwrap();
+            }
+            builder.finish()
+        };
+        let fields = Fields::from(vec![
+            Field::new("list", inner.data_type().clone(), true),
+            Field::new("map", map.data_type().clone(), true),
+        ]);
+        let nested = StructArray::new(
+            fields,
+            vec![Arc::new(inner), Arc::new(map)],
+            Some(vec![true, true, false].into()),
+        );
+        let batch = RecordBatch::try_from_iter([("nested", Arc::new(nested) as _)]).unwrap();
+
+        let format = |options: &FormatOptions| {
+            pretty_format_batches_with_options(std::slice::from_ref(&batch), options)
+                .unwrap()
+                .to_string()
+        };
+
+        let table = format(&FormatOptions::default().with_max_list_items(Some(2)));
+        let expected = vec![
+            "+--------------------------------+",
+            "| nested                         |",
+            "+--------------------------------+",
+            "| {list: [1, 2, …], map: {a: 0}} |",
+            "| {list: , map: {a: 0, b: 1}}    |",
+            "|                                |",
+            "+--------------------------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        let table = format(&FormatOptions::default().with_max_list_items(Some(0)));
+        assert!(table.contains("{list: […], map: {…}}"), "{table}");
+        assert!(table.contains("{list: , map: {…}}"), "{table}");
+
+        let table = format(
+            &FormatOptions::default()
+                .with_max_nested_depth(Some(1))
+                .with_null("null"),
+        );
+        let expected = vec![
+            "+------------------------+",
+            "| nested                 |",
+            "+------------------------+",
+            "| {list: […], map: {…}}  |",
+            "| {list: null, map: {…}} |",
+            "| null                   |",
+            "+------------------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        let table = format(&FormatOptions::default().with_max_nested_depth(Some(0)));
+        assert!(table.contains("| {…}    |"), "{table}");
+    }
 }
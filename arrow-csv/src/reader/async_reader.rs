@@ -0,0 +1,247 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::{self, Debug};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::{ready, Stream};
+use tokio::io::AsyncBufRead;
+
+use crate::reader::Decoder;
+
+/// Asynchronous CSV reader, yielding [`RecordBatch`] from an [`AsyncBufRead`]
+///
+/// This is the asynchronous counterpart of [`BufReader`](crate::reader::BufReader),
+/// feeding the chunks returned by the underlying reader into a [`Decoder`]. Records
+/// are not required to align with these chunks, allowing use with arbitrary byte
+/// streams such as those fetched from object storage.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_csv::ReaderBuilder;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use futures::TryStreamExt;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("a", DataType::Int64, false),
+///     Field::new("b", DataType::Utf8, false),
+/// ]));
+/// let data = "a,b\n1,foo\n2,bar\n";
+///
+/// let reader = ReaderBuilder::new(schema)
+///     .with_header(true)
+///     .build_async(data.as_bytes())
+///     .unwrap();
+///
+/// let batches: Vec<_> = reader.try_collect().await.unwrap();
+/// assert_eq!(batches.len(), 1);
+/// assert_eq!(batches[0].column(0).as_primitive::<Int64Type>().values(), &[1, 2]);
+/// # }
+/// ```
+pub struct AsyncReader<R> {
+    /// The object to read from
+    reader: R,
+    /// Decodes the bytes read from `reader`
+    decoder: Decoder,
+    /// Whether the end of `reader` has been reached, or an error returned
+    finished: bool,
+}
+
+impl<R> Debug for AsyncReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncReader")
+            .field("decoder", &self.decoder)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncReader<R> {
+    /// Create a new [`AsyncReader`] decoding the contents of `reader` with `decoder`
+    ///
+    /// See [`ReaderBuilder::build_async`](crate::ReaderBuilder::build_async)
+    pub fn new(reader: R, decoder: Decoder) -> Self {
+        Self {
+            reader,
+            decoder,
+            finished: false,
+        }
+    }
+
+    /// Returns the schema of the reader, useful for getting the schema without reading
+    /// record batches
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.projected_schema()
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Consumes the [`AsyncReader`], returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn poll_read(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<RecordBatch>, ArrowError>> {
+        loop {
+            let buf = ready!(Pin::new(&mut self.reader).poll_fill_buf(cx))?;
+            let decoded = self.decoder.decode(buf)?;
+            Pin::new(&mut self.reader).consume(decoded);
+            // Yield if decoded no bytes or the decoder is full
+            //
+            // The capacity check avoids waiting on data that isn't
+            // needed to flush the next batch
+            if decoded == 0 || self.decoder.capacity() == 0 {
+                break;
+            }
+        }
+
+        Poll::Ready(self.decoder.flush())
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for AsyncReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        let result = ready!(self.poll_read(cx)).transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.finished = true;
+        }
+        Poll::Ready(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReaderBuilder;
+    use arrow_array::cast::AsArray;
+    use arrow_schema::{DataType, Field, Schema};
+    use futures::TryStreamExt;
+    use std::fs::File;
+    use std::io::Read;
+    use std::sync::Arc;
+    use tokio::io::BufReader;
+
+    fn uk_cities_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("city", DataType::Utf8, false),
+            Field::new("lat", DataType::Float64, false),
+            Field::new("lng", DataType::Float64, false),
+        ]))
+    }
+
+    #[tokio::test]
+    async fn test_async_reader() {
+        let mut data = vec![];
+        File::open("test/data/uk_cities.csv")
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+
+        let expected: Vec<_> = ReaderBuilder::new(uk_cities_schema())
+            .with_batch_size(7)
+            .build(data.as_slice())
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        // Small buffer capacities split records across reads
+        for capacity in [1, 3, 16, 1024] {
+            let reader = BufReader::with_capacity(capacity, data.as_slice());
+            let reader = ReaderBuilder::new(uk_cities_schema())
+                .with_batch_size(7)
+                .build_async(reader)
+                .unwrap();
+            let batches: Vec<_> = reader.try_collect().await.unwrap();
+            assert_eq!(batches, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_quoted_newlines() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let data = "a,b\n\"hello\nworld\",1\n\"foo, bar\",2\n";
+
+        let reader = BufReader::with_capacity(2, data.as_bytes());
+        let mut reader = ReaderBuilder::new(schema.clone())
+            .with_header(true)
+            .with_projection(vec![1])
+            .build_async(reader)
+            .unwrap();
+        assert_eq!(reader.schema().fields().len(), 1);
+
+        let batch = reader.try_next().await.unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema(), reader.schema());
+        assert!(reader.try_next().await.unwrap().is_none());
+
+        let reader = ReaderBuilder::new(schema)
+            .with_header(true)
+            .build_async(BufReader::with_capacity(2, data.as_bytes()))
+            .unwrap();
+        let batches: Vec<_> = reader.try_collect().await.unwrap();
+        let a = batches[0].column(0).as_string::<i32>();
+        assert_eq!(a.value(0), "hello\nworld");
+        assert_eq!(a.value(1), "foo, bar");
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_error() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let data = "1\n2\nfoo\n4\n";
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_batch_size(2)
+            .build_async(data.as_bytes())
+            .unwrap();
+
+        let batch = reader.try_next().await.unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+
+        let err = reader.try_next().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error while parsing value foo for column 0 at line 2"
+        );
+        // The stream is terminated after an error
+        assert!(reader.try_next().await.unwrap().is_none());
+    }
+}
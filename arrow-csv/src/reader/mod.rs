@@ -122,25 +122,37 @@
 //! }
 //! ```
 //!
+//! With the `async` feature enabled, `AsyncReader` provides a ready-made `Stream`
+//! of [`RecordBatch`] for any `tokio::io::AsyncBufRead`
 
+#[cfg(feature = "async")]
+mod async_reader;
+mod multi_byte;
 mod records;
 
+#[cfg(feature = "async")]
+pub use async_reader::AsyncReader;
+
 use arrow_array::builder::{NullBuilder, PrimitiveBuilder};
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_cast::parse::{parse_decimal, string_to_datetime, Parser};
 use arrow_schema::*;
-use chrono::{TimeZone, Utc};
+use arrow_select::take::take;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
 use csv::StringRecord;
 use lazy_static::lazy_static;
 use regex::{Regex, RegexSet};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{self, Debug};
 use std::fs::File;
 use std::io::{BufRead, BufReader as StdBufReader, Read};
 use std::sync::Arc;
 
 use crate::map_csv_error;
-use crate::reader::records::{RecordDecoder, StringRecords};
+use crate::reader::multi_byte::{MultiByteReader, MultiByteRecords};
+use crate::reader::records::{RawRecords, RecordDecoder, RecordReader, StringRecords};
 use arrow_array::timezone::Tz;
 
 lazy_static! {
@@ -162,6 +174,20 @@ lazy_static! {
 struct NullRegex(Option<Regex>);
 
 impl NullRegex {
+    /// Create a [`NullRegex`] matching any of `values` exactly
+    fn from_values<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let alternatives: Vec<_> = values
+            .into_iter()
+            .map(|v| regex::escape(v.as_ref()))
+            .collect();
+        let regex = Regex::new(&format!("^(?:{})$", alternatives.join("|"))).unwrap();
+        Self(Some(regex))
+    }
+
     /// Returns true if the value should be considered as `NULL` according to
     /// the provided regular expression.
     #[inline]
@@ -225,19 +251,53 @@ impl InferredDataType {
             1 << 8 // Utf8
         }
     }
+
+    /// Updates the [`InferredDataType`] with the given string, recognising temporal
+    /// values using the chrono format strings `formats` instead of the default patterns
+    fn update_formatted(&mut self, string: &str, formats: &[String]) {
+        for format in formats {
+            let datetime = DateTime::parse_from_str(string, format)
+                .map(|d| d.naive_utc())
+                .or_else(|_| NaiveDateTime::parse_from_str(string, format));
+            if let Ok(datetime) = datetime {
+                // Infer the precision from that of the value
+                self.packed |= match datetime.nanosecond() {
+                    0 => 1 << 4,
+                    n if n % 1_000_000 == 0 => 1 << 5,
+                    n if n % 1_000 == 0 => 1 << 6,
+                    _ => 1 << 7,
+                };
+                return;
+            }
+            if NaiveDate::parse_from_str(string, format).is_ok() {
+                self.packed |= 1 << 3;
+                return;
+            }
+        }
+
+        match REGEX_SET.matches(string).into_iter().next() {
+            Some(m) if m >= 3 => self.packed |= 1 << 8, // Utf8
+            _ => self.update(string),
+        }
+    }
 }
 
 /// The format specification for the CSV file
 #[derive(Debug, Clone, Default)]
 pub struct Format {
     header: bool,
-    delimiter: Option<u8>,
+    delimiter: Option<Vec<u8>>,
     escape: Option<u8>,
     quote: Option<u8>,
     terminator: Option<u8>,
     comment: Option<u8>,
     null_regex: NullRegex,
     truncated_rows: bool,
+    column_formats: HashMap<String, ColumnFormat>,
+    timestamp_formats: Vec<String>,
+    column_types: HashMap<String, DataType>,
+    infer_sample_interval: Option<usize>,
+    infer_max_bytes: Option<usize>,
 }
 
 impl Format {
@@ -251,6 +311,20 @@ impl Format {
 
     /// Specify a custom delimiter character, defaults to comma `','`
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = Some(vec![delimiter]);
+        self
+    }
+
+    /// Specify a custom, possibly multi-byte, delimiter, e.g. `b"||"`
+    ///
+    /// The delimiter should not contain the quote, escape or terminator characters
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty
+    pub fn with_delimiter_bytes(mut self, delimiter: impl Into<Vec<u8>>) -> Self {
+        let delimiter = delimiter.into();
+        assert!(!delimiter.is_empty(), "delimiter must not be empty");
         self.delimiter = Some(delimiter);
         self
     }
@@ -268,6 +342,8 @@ impl Format {
     }
 
     /// Specify a custom terminator character, defaults to CRLF
+    ///
+    /// Records may be separated by any single byte, for example `b'\0'`
     pub fn with_terminator(mut self, terminator: u8) -> Self {
         self.terminator = Some(terminator);
         self
@@ -287,6 +363,63 @@ impl Format {
         self
     }
 
+    /// Treat values equal to any of `values` as null, in place of the null regex
+    ///
+    /// Empty strings are only treated as null if `values` contains `""`
+    pub fn with_null_values<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.null_regex = NullRegex::from_values(values);
+        self
+    }
+
+    /// Parse temporal values using the first of the [chrono format strings] `formats`
+    /// that matches, in place of the default RFC3339-like formats
+    ///
+    /// Schema inference infers values matching a format with a time as a timestamp, and
+    /// those with only a date as [`DataType::Date32`]. When reading, applies to timestamp,
+    /// date and time columns without a [`ColumnFormat::with_timestamp_format`]
+    ///
+    /// [chrono format strings]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+    pub fn with_timestamp_formats<I, S>(mut self, formats: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.timestamp_formats = formats.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Use `data_type` for the column named `column` when inferring a schema,
+    /// instead of inferring it from the data
+    pub fn with_column_type(mut self, column: impl Into<String>, data_type: DataType) -> Self {
+        self.column_types.insert(column.into(), data_type);
+        self
+    }
+
+    /// Only infer the schema from every `interval`th record, defaults to `1`
+    ///
+    /// Records that are not sampled still count towards the `max_records` of
+    /// [`Self::infer_schema`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is `0`
+    pub fn with_infer_sample_interval(mut self, interval: usize) -> Self {
+        assert!(interval > 0, "sample interval must be greater than 0");
+        self.infer_sample_interval = Some(interval);
+        self
+    }
+
+    /// Stop inferring the schema at the first record starting after `max_bytes` bytes
+    /// of input, defaults to `None`
+    pub fn with_infer_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.infer_max_bytes = Some(max_bytes);
+        self
+    }
+
     /// Whether to allow truncated rows when parsing.
     ///
     /// By default this is set to `false` and will error if the CSV rows have different lengths.
@@ -298,6 +431,15 @@ impl Format {
         self
     }
 
+    /// Override the parsing of the column named `column` with `format`
+    ///
+    /// Columns without a [`ColumnFormat`] are parsed using the settings of this [`Format`],
+    /// and formats for columns not present in the schema are ignored
+    pub fn with_column_format(mut self, column: impl Into<String>, format: ColumnFormat) -> Self {
+        self.column_formats.insert(column.into(), format);
+        self
+    }
+
     /// Infer schema of CSV records from the provided `reader`
     ///
     /// If `max_records` is `None`, all records will be read, otherwise up to `max_records`
@@ -309,40 +451,104 @@ impl Format {
         reader: R,
         max_records: Option<usize>,
     ) -> Result<(Schema, usize), ArrowError> {
+        if let Some(tokenizer) = self.build_multi_byte_parser() {
+            let mut records = MultiByteRecords::new(StdBufReader::new(reader), tokenizer);
+            let first = records.next().transpose()?.unwrap_or_default();
+            let headers = self.header_names(&first);
+            return match self.header {
+                true => self.infer_schema_from_records(headers, records, max_records),
+                false => {
+                    let records = std::iter::once(Ok(first)).chain(records);
+                    self.infer_schema_from_records(headers, records, max_records)
+                }
+            };
+        }
+
         let mut csv_reader = self.build_reader(reader);
+        let headers = self.header_names(csv_reader.headers().map_err(map_csv_error)?);
+        let records = csv_reader.into_records().map(|r| r.map_err(map_csv_error));
+        self.infer_schema_from_records(headers, records, max_records)
+    }
 
-        // get or create header names
-        // when has_header is false, creates default column names with column_ prefix
-        let headers: Vec<String> = if self.header {
-            let headers = &csv_reader.headers().map_err(map_csv_error)?.clone();
-            headers.iter().map(|s| s.to_string()).collect()
-        } else {
-            let first_record_count = &csv_reader.headers().map_err(map_csv_error)?.len();
-            (0..*first_record_count)
+    /// Returns the column names given the first record of a file
+    ///
+    /// When `has_header` is false, creates default column names with column_ prefix
+    fn header_names(&self, first: &StringRecord) -> Vec<String> {
+        match self.header {
+            true => first.iter().map(|s| s.to_string()).collect(),
+            false => (0..first.len())
                 .map(|i| format!("column_{}", i + 1))
-                .collect()
-        };
+                .collect(),
+        }
+    }
 
+    fn infer_schema_from_records(
+        &self,
+        headers: Vec<String>,
+        mut records: impl Iterator<Item = Result<StringRecord, ArrowError>>,
+        max_records: Option<usize>,
+    ) -> Result<(Schema, usize), ArrowError> {
         let header_length = headers.len();
         // keep track of inferred field types
         let mut column_types: Vec<InferredDataType> = vec![Default::default(); header_length];
+        let column_formats: Vec<_> = headers.iter().map(|h| self.column_formats.get(h)).collect();
+        let timestamp_formats: Vec<_> = column_formats
+            .iter()
+            .map(|f| match f {
+                Some(f) if !f.timestamp_formats.is_empty() => f.timestamp_formats.as_slice(),
+                _ => self.timestamp_formats.as_slice(),
+            })
+            .collect();
+        // columns with an explicit type are not inferred
+        let inferred: Vec<_> = headers
+            .iter()
+            .map(|h| !self.column_types.contains_key(h))
+            .collect();
 
         let mut records_count = 0;
 
-        let mut record = StringRecord::new();
         let max_records = max_records.unwrap_or(usize::MAX);
+        let sample_interval = self.infer_sample_interval.unwrap_or(1);
         while records_count < max_records {
-            if !csv_reader.read_record(&mut record).map_err(map_csv_error)? {
-                break;
+            let record = match records.next() {
+                Some(record) => record?,
+                None => break,
+            };
+            if let (Some(max_bytes), Some(position)) = (self.infer_max_bytes, record.position()) {
+                if position.byte() > max_bytes as u64 {
+                    break;
+                }
             }
             records_count += 1;
+            if (records_count - 1) % sample_interval != 0 {
+                continue;
+            }
 
             // Note since we may be looking at a sample of the data, we make the safe assumption that
             // they could be nullable
             for (i, column_type) in column_types.iter_mut().enumerate().take(header_length) {
+                if !inferred[i] {
+                    continue;
+                }
                 if let Some(string) = record.get(i) {
-                    if !self.null_regex.is_null(string) {
-                        column_type.update(string)
+                    let formats = timestamp_formats[i];
+                    let mut update = |s: &str| match formats.is_empty() {
+                        true => column_type.update(s),
+                        false => column_type.update_formatted(s, formats),
+                    };
+                    match column_formats[i] {
+                        Some(format) => {
+                            let null_regex = format.null_regex.as_ref().unwrap_or(&self.null_regex);
+                            if null_regex.is_null(string) {
+                                continue;
+                            }
+                            match format.parse_bool(string) {
+                                Some(b) => update(if b { "true" } else { "false" }),
+                                None => update(&format.normalize_number(string)),
+                            }
+                        }
+                        None if !self.null_regex.is_null(string) => update(string),
+                        None => {}
                     }
                 }
             }
@@ -352,7 +558,13 @@ impl Format {
         let fields: Fields = column_types
             .iter()
             .zip(&headers)
-            .map(|(inferred, field_name)| Field::new(field_name, inferred.get(), true))
+            .map(|(inferred, field_name)| {
+                let data_type = match self.column_types.get(field_name) {
+                    Some(data_type) => data_type.clone(),
+                    None => inferred.get(),
+                };
+                Field::new(field_name, data_type, true)
+            })
             .collect();
 
         Ok((Schema::new(fields), records_count))
@@ -364,8 +576,8 @@ impl Format {
         builder.has_headers(self.header);
         builder.flexible(self.truncated_rows);
 
-        if let Some(c) = self.delimiter {
-            builder.delimiter(c);
+        if let Some(c) = &self.delimiter {
+            builder.delimiter(c[0]);
         }
         builder.escape(self.escape);
         if let Some(c) = self.quote {
@@ -380,14 +592,32 @@ impl Format {
         builder.from_reader(reader)
     }
 
-    /// Build a [`csv_core::Reader`] for this [`Format`]
-    fn build_parser(&self) -> csv_core::Reader {
+    /// Build a [`MultiByteReader`] for this [`Format`] if it has a multi-byte delimiter
+    fn build_multi_byte_parser(&self) -> Option<MultiByteReader> {
+        match &self.delimiter {
+            Some(d) if d.len() > 1 => Some(MultiByteReader::new(
+                d.clone(),
+                self.quote.unwrap_or(b'"'),
+                self.escape,
+                self.comment,
+                self.terminator,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Build a [`RecordReader`] for this [`Format`]
+    fn build_parser(&self) -> RecordReader {
+        if let Some(parser) = self.build_multi_byte_parser() {
+            return parser.into();
+        }
+
         let mut builder = csv_core::ReaderBuilder::new();
         builder.escape(self.escape);
         builder.comment(self.comment);
 
-        if let Some(c) = self.delimiter {
-            builder.delimiter(c);
+        if let Some(c) = &self.delimiter {
+            builder.delimiter(c[0]);
         }
         if let Some(c) = self.quote {
             builder.quote(c);
@@ -395,7 +625,256 @@ impl Format {
         if let Some(t) = self.terminator {
             builder.terminator(csv_core::Terminator::Any(t));
         }
-        builder.build()
+        builder.build().into()
+    }
+}
+
+/// Parsing options for an individual CSV column, overriding those of the [`Format`]
+///
+/// Useful for reading files exported by spreadsheet applications, where different
+/// columns may use different conventions.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::Array;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Float64Type;
+/// # use arrow_csv::reader::{ColumnFormat, Format};
+/// # use arrow_csv::ReaderBuilder;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("price", DataType::Float64, true),
+///     Field::new("available", DataType::Boolean, true),
+/// ]));
+/// let data = "1.234,5;Ja\nN/A;Nein\n";
+///
+/// let price = ColumnFormat::new()
+///     .with_null_values(["N/A"])
+///     .with_thousands_separator('.')
+///     .with_decimal_separator(',');
+/// let available = ColumnFormat::new()
+///     .with_true_values(["Ja"])
+///     .with_false_values(["Nein"]);
+///
+/// let format = Format::default()
+///     .with_delimiter(b';')
+///     .with_column_format("price", price)
+///     .with_column_format("available", available);
+///
+/// let mut reader = ReaderBuilder::new(schema)
+///     .with_format(format)
+///     .build(data.as_bytes())
+///     .unwrap();
+/// let batch = reader.next().unwrap().unwrap();
+///
+/// let price = batch.column(0).as_primitive::<Float64Type>();
+/// assert_eq!(price.value(0), 1234.5);
+/// assert!(price.is_null(1));
+///
+/// let available = batch.column(1).as_boolean();
+/// assert!(available.value(0));
+/// assert!(!available.value(1));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ColumnFormat {
+    null_regex: Option<NullRegex>,
+    true_values: Option<Vec<String>>,
+    false_values: Option<Vec<String>>,
+    thousands_separator: Option<char>,
+    decimal_separator: Option<char>,
+    timestamp_formats: Vec<String>,
+}
+
+impl ColumnFormat {
+    /// Create a new [`ColumnFormat`] with no overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat values equal to any of `values` as null, in place of the null regex
+    /// of the [`Format`]
+    pub fn with_null_values<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.null_regex = Some(NullRegex::from_values(values));
+        self
+    }
+
+    /// Parse values matching any of `values`, ignoring ASCII case, as `true`,
+    /// defaults to `"true"`
+    pub fn with_true_values<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.true_values = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Parse values matching any of `values`, ignoring ASCII case, as `false`,
+    /// defaults to `"false"`
+    pub fn with_false_values<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.false_values = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Ignore `separator` when parsing numeric and decimal values, e.g. `1,000,000`
+    pub fn with_thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Use `separator` as the decimal point when parsing numeric and decimal values,
+    /// defaults to `'.'`
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = Some(separator);
+        self
+    }
+
+    /// Parse temporal values using the [chrono format string] `format`
+    ///
+    /// Applies to timestamp, date and time columns. Timestamps without a timezone
+    /// in `format` are interpreted in the timezone of the column, or UTC if none
+    ///
+    /// [chrono format string]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+    pub fn with_timestamp_format(mut self, format: impl Into<String>) -> Self {
+        self.timestamp_formats = vec![format.into()];
+        self
+    }
+
+    fn parse_bool(&self, s: &str) -> Option<bool> {
+        let matches = |values: &Option<Vec<String>>, default: &str| match values {
+            Some(values) => values.iter().any(|v| v.eq_ignore_ascii_case(s)),
+            None => s.eq_ignore_ascii_case(default),
+        };
+        if matches(&self.false_values, "false") {
+            Some(false)
+        } else if matches(&self.true_values, "true") {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// Rewrite a numeric value using the default separators
+    fn normalize_number<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        if self.thousands_separator.is_none() && self.decimal_separator.is_none() {
+            return Cow::Borrowed(s);
+        }
+        s.chars()
+            .filter(|c| Some(*c) != self.thousands_separator)
+            .map(|c| match Some(c) == self.decimal_separator {
+                true => '.',
+                false => c,
+            })
+            .collect()
+    }
+
+    fn parse_primitive<T: ArrowPrimitiveType + Parser>(&self, s: &str) -> Option<T::Native> {
+        match self.timestamp_formats.as_slice() {
+            _ if T::DATA_TYPE.is_numeric() => T::parse(&self.normalize_number(s)),
+            [] => T::parse(s),
+            formats if T::DATA_TYPE.is_temporal() => {
+                formats.iter().find_map(|f| T::parse_formatted(s, f))
+            }
+            _ => T::parse(s),
+        }
+    }
+}
+
+/// Parses `s` using the chrono format string `format`, interpreting values
+/// without a timezone in `timezone`
+fn parse_formatted_datetime<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    format: &str,
+) -> Result<DateTime<T>, ArrowError> {
+    if let Ok(date) = DateTime::parse_from_str(s, format) {
+        return Ok(date.with_timezone(timezone));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(s, format)
+        .or_else(|e| match NaiveDate::parse_from_str(s, format) {
+            Ok(date) => Ok(date.and_hms_opt(0, 0, 0).unwrap()),
+            Err(_) => Err(e),
+        })
+        .map_err(|e| ArrowError::ParseError(format!("{e} using format \"{format}\"")))?;
+
+    timezone
+        .from_local_datetime(&naive)
+        .single()
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "{naive} is ambiguous or does not exist in timezone"
+            ))
+        })
+}
+
+/// How a [`Decoder`] handles malformed rows
+///
+/// A row is malformed if it has an incorrect number of fields, or contains a value
+/// that cannot be parsed as the data type of its column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorMode {
+    /// Return an error for the first malformed row
+    #[default]
+    Fail,
+    /// Skip malformed rows
+    SkipRow,
+    /// Replace unparsable values with null, skipping rows with an incorrect number of fields
+    ///
+    /// Substituting null into a non-nullable column returns an error
+    NullCell,
+}
+
+/// A malformed row skipped or repaired by a [`Decoder`] configured with an [`ErrorMode`]
+/// other than [`ErrorMode::Fail`]
+#[derive(Debug)]
+pub struct MalformedRow {
+    line: usize,
+    content: String,
+    error: ArrowError,
+}
+
+impl MalformedRow {
+    /// The one-based number of the record within the input, including any header
+    ///
+    /// Records that span multiple lines, due to quoted newlines, count as one
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The raw content of the row, without its terminator
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// The error that caused this row to be skipped or repaired
+    ///
+    /// If a row contains multiple unparsable values, this is the error for the first column
+    pub fn error(&self) -> &ArrowError {
+        &self.error
+    }
+
+    /// Consumes this row, returning its error
+    pub fn into_error(self) -> ArrowError {
+        self.error
+    }
+}
+
+/// Receives the [`MalformedRow`]s of a [`Decoder`]
+#[derive(Clone)]
+struct ErrorSink(Arc<dyn Fn(MalformedRow) + Send + Sync>);
+
+impl Debug for ErrorSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErrorSink")
     }
 }
 
@@ -414,7 +893,7 @@ pub fn infer_schema_from_files(
     let mut schemas = vec![];
     let mut records_to_read = max_read_records.unwrap_or(usize::MAX);
     let format = Format {
-        delimiter: Some(delimiter),
+        delimiter: Some(vec![delimiter]),
         header: has_header,
         ..Default::default()
     };
@@ -465,14 +944,7 @@ impl<R: Read> Reader<R> {
     /// Returns the schema of the reader, useful for getting the schema without reading
     /// record batches
     pub fn schema(&self) -> SchemaRef {
-        match &self.decoder.projection {
-            Some(projection) => {
-                let fields = self.decoder.schema.fields();
-                let projected = projection.iter().map(|i| fields[*i].clone());
-                Arc::new(Schema::new(projected.collect::<Fields>()))
-            }
-            None => self.decoder.schema.clone(),
-        }
+        self.decoder.projected_schema()
     }
 }
 
@@ -574,6 +1046,18 @@ pub struct Decoder {
 
     /// Check if the string matches this pattern for `NULL`.
     null_regex: NullRegex,
+
+    /// The [`ColumnFormat`] of each field in `schema`, if any
+    column_formats: Vec<Option<ColumnFormat>>,
+
+    /// How to handle malformed rows
+    error_mode: ErrorMode,
+
+    /// Receives the malformed rows skipped or repaired according to `error_mode`
+    error_sink: Option<ErrorSink>,
+
+    /// The number of malformed rows skipped since the last call to [`Self::flush`]
+    num_skipped: usize,
 }
 
 impl Decoder {
@@ -596,8 +1080,13 @@ impl Decoder {
             return Ok(bytes);
         }
 
-        let to_read = self.batch_size.min(self.end - self.line_number) - self.record_decoder.len();
+        let remaining = self.end - self.line_number - self.num_skipped;
+        let to_read = self.batch_size.min(remaining) - self.record_decoder.len();
         let (_, bytes) = self.record_decoder.decode(buf, to_read)?;
+        for row in self.record_decoder.take_malformed() {
+            self.num_skipped += 1;
+            self.report(row);
+        }
         Ok(bytes)
     }
 
@@ -609,26 +1098,62 @@ impl Decoder {
     /// Returns `Ok(None)` if no buffered data
     pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         if self.record_decoder.is_empty() {
+            self.line_number += std::mem::take(&mut self.num_skipped);
             return Ok(None);
         }
 
+        let raw = self.record_decoder.take_raw();
         let rows = self.record_decoder.flush()?;
-        let batch = parse(
-            &rows,
-            self.schema.fields(),
-            Some(self.schema.metadata.clone()),
-            self.projection.as_ref(),
-            self.line_number,
-            &self.null_regex,
-        )?;
-        self.line_number += rows.len();
+        let batch = match self.error_mode {
+            ErrorMode::Fail => parse(
+                &rows,
+                self.schema.fields(),
+                Some(self.schema.metadata.clone()),
+                self.projection.as_ref(),
+                self.line_number,
+                &self.null_regex,
+                &self.column_formats,
+            )?,
+            mode => parse_lenient(
+                &rows,
+                &raw,
+                mode,
+                self.error_sink.as_ref(),
+                self.schema.fields(),
+                Some(self.schema.metadata.clone()),
+                self.projection.as_ref(),
+                self.line_number,
+                &self.null_regex,
+                &self.column_formats,
+            )?,
+        };
+        self.line_number += rows.len() + std::mem::take(&mut self.num_skipped);
         Ok(Some(batch))
     }
 
+    /// Passes `row` to the error sink, if any
+    fn report(&self, row: MalformedRow) {
+        if let Some(sink) = &self.error_sink {
+            (sink.0)(row)
+        }
+    }
+
     /// Returns the number of records that can be read before requiring a call to [`Self::flush`]
     pub fn capacity(&self) -> usize {
         self.batch_size - self.record_decoder.len()
     }
+
+    /// Returns the schema of the decoded batches, with any projection applied
+    fn projected_schema(&self) -> SchemaRef {
+        match &self.projection {
+            Some(projection) => {
+                let fields = self.schema.fields();
+                let projected = projection.iter().map(|i| fields[*i].clone());
+                Arc::new(Schema::new(projected.collect::<Fields>()))
+            }
+            None => self.schema.clone(),
+        }
+    }
 }
 
 /// Parses a slice of [`StringRecords`] into a [RecordBatch]
@@ -639,6 +1164,7 @@ fn parse(
     projection: Option<&Vec<usize>>,
     line_number: usize,
     null_regex: &NullRegex,
+    column_formats: &[Option<ColumnFormat>],
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
@@ -650,8 +1176,32 @@ fn parse(
         .map(|i| {
             let i = *i;
             let field = &fields[i];
+            let column_format = column_formats.get(i).and_then(|f| f.as_ref());
+            let null_regex = column_format
+                .and_then(|f| f.null_regex.as_ref())
+                .unwrap_or(null_regex);
             match field.data_type() {
-                DataType::Boolean => build_boolean_array(line_number, rows, i, null_regex),
+                DataType::Boolean => {
+                    build_boolean_array(line_number, rows, i, null_regex, column_format)
+                }
+                DataType::Decimal32(precision, scale) => build_decimal_array::<Decimal32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    *precision,
+                    *scale,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Decimal64(precision, scale) => build_decimal_array::<Decimal64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    *precision,
+                    *scale,
+                    null_regex,
+                    column_format,
+                ),
                 DataType::Decimal128(precision, scale) => build_decimal_array::<Decimal128Type>(
                     line_number,
                     rows,
@@ -659,6 +1209,7 @@ fn parse(
                     *precision,
                     *scale,
                     null_regex,
+                    column_format,
                 ),
                 DataType::Decimal256(precision, scale) => build_decimal_array::<Decimal256Type>(
                     line_number,
@@ -667,54 +1218,125 @@ fn parse(
                     *precision,
                     *scale,
                     null_regex,
+                    column_format,
+                ),
+                DataType::Int8 => build_primitive_array::<Int8Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Int16 => build_primitive_array::<Int16Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Int32 => build_primitive_array::<Int32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Int64 => build_primitive_array::<Int64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::UInt8 => build_primitive_array::<UInt8Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::UInt16 => build_primitive_array::<UInt16Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::UInt32 => build_primitive_array::<UInt32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::UInt64 => build_primitive_array::<UInt64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Float32 => build_primitive_array::<Float32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Float64 => build_primitive_array::<Float64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Date32 => build_primitive_array::<Date32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Date64 => build_primitive_array::<Date64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
+                ),
+                DataType::Time32(TimeUnit::Second) => build_primitive_array::<Time32SecondType>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    column_format,
                 ),
-                DataType::Int8 => {
-                    build_primitive_array::<Int8Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Int16 => {
-                    build_primitive_array::<Int16Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Int32 => {
-                    build_primitive_array::<Int32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Int64 => {
-                    build_primitive_array::<Int64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt8 => {
-                    build_primitive_array::<UInt8Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt16 => {
-                    build_primitive_array::<UInt16Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt32 => {
-                    build_primitive_array::<UInt32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::UInt64 => {
-                    build_primitive_array::<UInt64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Float32 => {
-                    build_primitive_array::<Float32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Float64 => {
-                    build_primitive_array::<Float64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Date32 => {
-                    build_primitive_array::<Date32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Date64 => {
-                    build_primitive_array::<Date64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Time32(TimeUnit::Second) => {
-                    build_primitive_array::<Time32SecondType>(line_number, rows, i, null_regex)
-                }
                 DataType::Time32(TimeUnit::Millisecond) => {
-                    build_primitive_array::<Time32MillisecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time32MillisecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        column_format,
+                    )
                 }
                 DataType::Time64(TimeUnit::Microsecond) => {
-                    build_primitive_array::<Time64MicrosecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time64MicrosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        column_format,
+                    )
                 }
                 DataType::Time64(TimeUnit::Nanosecond) => {
-                    build_primitive_array::<Time64NanosecondType>(line_number, rows, i, null_regex)
+                    build_primitive_array::<Time64NanosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        column_format,
+                    )
                 }
                 DataType::Timestamp(TimeUnit::Second, tz) => {
                     build_timestamp_array::<TimestampSecondType>(
@@ -723,6 +1345,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        column_format,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Millisecond, tz) => {
@@ -732,6 +1355,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        column_format,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Microsecond, tz) => {
@@ -741,6 +1365,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        column_format,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
@@ -750,6 +1375,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        column_format,
                     )
                 }
                 DataType::Null => Ok(Arc::new({
@@ -871,6 +1497,128 @@ fn parse(
     })
 }
 
+/// Parses a slice of [`StringRecords`] into a [RecordBatch], handling rows containing
+/// unparsable values according to `mode` and passing them to `sink`
+#[allow(clippy::too_many_arguments)]
+fn parse_lenient(
+    rows: &StringRecords<'_>,
+    raw: &RawRecords,
+    mode: ErrorMode,
+    sink: Option<&ErrorSink>,
+    fields: &Fields,
+    metadata: Option<std::collections::HashMap<String, String>>,
+    projection: Option<&Vec<usize>>,
+    line_number: usize,
+    null_regex: &NullRegex,
+    column_formats: &[Option<ColumnFormat>],
+) -> Result<RecordBatch, ArrowError> {
+    let projection: Vec<usize> = match projection {
+        Some(v) => v.clone(),
+        None => fields.iter().enumerate().map(|(i, _)| i).collect(),
+    };
+
+    // Parses the column `i` of `rows`
+    let parse_column = |rows: &StringRecords<'_>, i: usize, line_number: usize| {
+        let projection = vec![i];
+        parse(
+            rows,
+            fields,
+            None,
+            Some(&projection),
+            line_number,
+            null_regex,
+            column_formats,
+        )
+        .map(|batch| batch.column(0).clone())
+    };
+
+    // The first error of each row containing an unparsable value
+    let mut errors = BTreeMap::new();
+    let mut arrays = Vec::with_capacity(projection.len());
+    for &i in &projection {
+        let error = match parse_column(rows, i, line_number) {
+            Ok(array) => {
+                arrays.push(array);
+                continue;
+            }
+            Err(e) => e,
+        };
+
+        // Parse each row individually to find the unparsable values
+        let mut valid = vec![true; rows.len()];
+        for (row, valid) in valid.iter_mut().enumerate() {
+            if let Err(e) = parse_column(&rows.select(vec![row]), i, raw.line(row)) {
+                errors.entry(row).or_insert(e);
+                *valid = false;
+            }
+        }
+        if valid.iter().all(|v| *v) {
+            return Err(error);
+        }
+
+        if mode == ErrorMode::NullCell {
+            let selection = (0..rows.len()).filter(|x| valid[*x]).collect();
+            let array = parse_column(&rows.select(selection), i, line_number)?;
+            let mut next = 0;
+            let indices: UInt32Array = valid
+                .iter()
+                .map(|valid| {
+                    valid.then(|| {
+                        let index = next;
+                        next += 1;
+                        index
+                    })
+                })
+                .collect();
+            arrays.push(take(array.as_ref(), &indices, None)?);
+        }
+    }
+
+    let skipped: Vec<usize> = errors.keys().copied().collect();
+    for (row, error) in errors {
+        if let Some(sink) = sink {
+            (sink.0)(MalformedRow {
+                line: raw.line(row),
+                content: raw.content(row),
+                error,
+            });
+        }
+    }
+
+    match mode {
+        ErrorMode::SkipRow if !skipped.is_empty() => {
+            let selection = (0..rows.len())
+                .filter(|x| skipped.binary_search(x).is_err())
+                .collect();
+            let projection = Some(&projection);
+            let rows = rows.select(selection);
+            parse(
+                &rows,
+                fields,
+                metadata,
+                projection,
+                line_number,
+                null_regex,
+                column_formats,
+            )
+        }
+        _ => {
+            let projected_fields: Fields = projection.iter().map(|i| fields[*i].clone()).collect();
+            let projected_schema = Arc::new(match metadata {
+                None => Schema::new(projected_fields),
+                Some(metadata) => Schema::new_with_metadata(projected_fields, metadata),
+            });
+            RecordBatch::try_new_with_options(
+                projected_schema,
+                arrays,
+                &RecordBatchOptions::new()
+                    .with_match_field_names(true)
+                    .with_row_count(Some(rows.len())),
+            )
+        }
+    }
+}
+
 fn parse_bool(string: &str) -> Option<bool> {
     if string.eq_ignore_ascii_case("false") {
         Some(false)
@@ -889,6 +1637,7 @@ fn build_decimal_array<T: DecimalType>(
     precision: u8,
     scale: i8,
     null_regex: &NullRegex,
+    format: Option<&ColumnFormat>,
 ) -> Result<ArrayRef, ArrowError> {
     let mut decimal_builder = PrimitiveBuilder::<T>::with_capacity(rows.len());
     for row in rows.iter() {
@@ -897,7 +1646,11 @@ fn build_decimal_array<T: DecimalType>(
             // append null
             decimal_builder.append_null();
         } else {
-            let decimal_value: Result<T::Native, _> = parse_decimal::<T>(s, precision, scale);
+            let s = match format {
+                Some(format) => format.normalize_number(s),
+                None => Cow::Borrowed(s),
+            };
+            let decimal_value: Result<T::Native, _> = parse_decimal::<T>(&s, precision, scale);
             match decimal_value {
                 Ok(v) => {
                     decimal_builder.append_value(v);
@@ -921,6 +1674,7 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
     rows: &StringRecords<'_>,
     col_idx: usize,
     null_regex: &NullRegex,
+    format: Option<&ColumnFormat>,
 ) -> Result<ArrayRef, ArrowError> {
     rows.iter()
         .enumerate()
@@ -930,7 +1684,11 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
                 return Ok(None);
             }
 
-            match T::parse(s) {
+            let parsed = match format {
+                Some(format) => format.parse_primitive::<T>(s),
+                None => T::parse(s),
+            };
+            match parsed {
                 Some(e) => Ok(Some(e)),
                 None => Err(ArrowError::ParseError(format!(
                     // TODO: we should surface the underlying error here.
@@ -951,14 +1709,32 @@ fn build_timestamp_array<T: ArrowTimestampType>(
     col_idx: usize,
     timezone: Option<&str>,
     null_regex: &NullRegex,
+    format: Option<&ColumnFormat>,
 ) -> Result<ArrayRef, ArrowError> {
+    let formats = format
+        .map(|f| f.timestamp_formats.as_slice())
+        .unwrap_or_default();
     Ok(Arc::new(match timezone {
         Some(timezone) => {
             let tz: Tz = timezone.parse()?;
-            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &tz, null_regex)?
-                .with_timezone(timezone)
+            build_timestamp_array_impl::<T, _>(
+                line_number,
+                rows,
+                col_idx,
+                &tz,
+                null_regex,
+                formats,
+            )?
+            .with_timezone(timezone)
         }
-        None => build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &Utc, null_regex)?,
+        None => build_timestamp_array_impl::<T, _>(
+            line_number,
+            rows,
+            col_idx,
+            &Utc,
+            null_regex,
+            formats,
+        )?,
     }))
 }
 
@@ -968,6 +1744,7 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
     col_idx: usize,
     timezone: &Tz,
     null_regex: &NullRegex,
+    formats: &[String],
 ) -> Result<PrimitiveArray<T>, ArrowError> {
     rows.iter()
         .enumerate()
@@ -977,7 +1754,15 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
                 return Ok(None);
             }
 
-            let date = string_to_datetime(timezone, s)
+            let date = match formats.split_last() {
+                None => string_to_datetime(timezone, s),
+                Some((last, formats)) => formats
+                    .iter()
+                    .find_map(|f| parse_formatted_datetime(timezone, s, f).ok())
+                    .map(Ok)
+                    .unwrap_or_else(|| parse_formatted_datetime(timezone, s, last)),
+            };
+            let date = date
                 .and_then(|date| match T::UNIT {
                     TimeUnit::Second => Ok(date.timestamp()),
                     TimeUnit::Millisecond => Ok(date.timestamp_millis()),
@@ -1007,6 +1792,7 @@ fn build_boolean_array(
     rows: &StringRecords<'_>,
     col_idx: usize,
     null_regex: &NullRegex,
+    format: Option<&ColumnFormat>,
 ) -> Result<ArrayRef, ArrowError> {
     rows.iter()
         .enumerate()
@@ -1015,7 +1801,10 @@ fn build_boolean_array(
             if null_regex.is_null(s) {
                 return Ok(None);
             }
-            let parsed = parse_bool(s);
+            let parsed = match format {
+                Some(format) => format.parse_bool(s),
+                None => parse_bool(s),
+            };
             match parsed {
                 Some(e) => Ok(Some(e)),
                 None => Err(ArrowError::ParseError(format!(
@@ -1046,6 +1835,10 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// How to handle malformed rows
+    error_mode: ErrorMode,
+    /// Receives the malformed rows skipped or repaired according to `error_mode`
+    error_sink: Option<ErrorSink>,
 }
 
 impl ReaderBuilder {
@@ -1077,6 +1870,8 @@ impl ReaderBuilder {
             batch_size: 1024,
             bounds: None,
             projection: None,
+            error_mode: ErrorMode::Fail,
+            error_sink: None,
         }
     }
 
@@ -1094,7 +1889,15 @@ impl ReaderBuilder {
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.format.delimiter = Some(delimiter);
+        self.format.delimiter = Some(vec![delimiter]);
+        self
+    }
+
+    /// Set the CSV file's column delimiter as a, possibly multi-byte, sequence of bytes
+    ///
+    /// See [`Format::with_delimiter_bytes`]
+    pub fn with_delimiter_bytes(mut self, delimiter: impl Into<Vec<u8>>) -> Self {
+        self.format = self.format.with_delimiter_bytes(delimiter);
         self
     }
 
@@ -1158,6 +1961,68 @@ impl ReaderBuilder {
         self
     }
 
+    /// Override the parsing of the column named `column` with `format`
+    ///
+    /// See [`Format::with_column_format`]
+    pub fn with_column_format(mut self, column: impl Into<String>, format: ColumnFormat) -> Self {
+        self.format.column_formats.insert(column.into(), format);
+        self
+    }
+
+    /// Set how to handle malformed rows, defaults to [`ErrorMode::Fail`]
+    ///
+    /// Rows with invalid UTF-8 always return an error
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use arrow_array::Array;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_csv::reader::{ErrorMode, ReaderBuilder};
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new("a", DataType::Int32, true),
+    ///     Field::new("b", DataType::Utf8, true),
+    /// ]));
+    /// let csv = "1,x\nfoo,y\n3\n4,z\n";
+    ///
+    /// let malformed = Arc::new(Mutex::new(vec![]));
+    /// let sink = malformed.clone();
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_error_mode(ErrorMode::NullCell)
+    ///     .with_error_sink(move |row| sink.lock().unwrap().push(row))
+    ///     .build(csv.as_bytes())
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let a = batch.column(0).as_primitive::<Int32Type>();
+    /// assert_eq!(a.len(), 3);
+    /// assert!(a.is_null(1));
+    ///
+    /// let malformed = malformed.lock().unwrap();
+    /// assert_eq!(malformed[0].line(), 3);
+    /// assert_eq!(malformed[0].content(), "3");
+    /// assert_eq!(malformed[1].line(), 2);
+    /// assert_eq!(malformed[1].content(), "foo,y");
+    /// ```
+    pub fn with_error_mode(mut self, mode: ErrorMode) -> Self {
+        self.error_mode = mode;
+        self
+    }
+
+    /// Provide a callback to receive the [`MalformedRow`]s skipped or repaired
+    /// according to [`Self::with_error_mode`]
+    ///
+    /// Rows with an incorrect number of fields are received as they are decoded, and
+    /// rows with unparsable values once the [`RecordBatch`] containing them is flushed
+    pub fn with_error_sink<F>(mut self, sink: F) -> Self
+    where
+        F: Fn(MalformedRow) + Send + Sync + 'static,
+    {
+        self.error_sink = Some(ErrorSink(Arc::new(sink)));
+        self
+    }
+
     /// Create a new `Reader` from a non-buffered reader
     ///
     /// If `R: BufRead` consider using [`Self::build_buffered`] to avoid unnecessary additional
@@ -1174,6 +2039,15 @@ impl ReaderBuilder {
         })
     }
 
+    /// Create a new [`AsyncReader`] from an asynchronous buffered reader
+    #[cfg(feature = "async")]
+    pub fn build_async<R>(self, reader: R) -> Result<AsyncReader<R>, ArrowError>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+    {
+        Ok(AsyncReader::new(reader, self.build_decoder()))
+    }
+
     /// Builds a decoder that can be used to decode CSV from an arbitrary byte stream
     pub fn build_decoder(self) -> Decoder {
         let delimiter = self.format.build_parser();
@@ -1181,7 +2055,8 @@ impl ReaderBuilder {
             delimiter,
             self.schema.fields().len(),
             self.format.truncated_rows,
-        );
+        )
+        .with_skip_malformed(self.error_mode != ErrorMode::Fail);
 
         let header = self.format.header as usize;
 
@@ -1190,6 +2065,25 @@ impl ReaderBuilder {
             None => (header, usize::MAX),
         };
 
+        let mut column_formats = self.format.column_formats;
+        let timestamp_formats = self.format.timestamp_formats;
+        let column_formats = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let format = column_formats.remove(f.name());
+                if timestamp_formats.is_empty() || !f.data_type().is_temporal() {
+                    return format;
+                }
+                let mut format = format.unwrap_or_default();
+                if format.timestamp_formats.is_empty() {
+                    format.timestamp_formats = timestamp_formats.clone();
+                }
+                Some(format)
+            })
+            .collect();
+
         Decoder {
             schema: self.schema,
             to_skip: start,
@@ -1199,6 +2093,10 @@ impl ReaderBuilder {
             projection: self.projection,
             batch_size: self.batch_size,
             null_regex: self.format.null_regex,
+            column_formats,
+            error_mode: self.error_mode,
+            error_sink: self.error_sink,
+            num_skipped: 0,
         }
     }
 }
@@ -1211,6 +2109,7 @@ mod tests {
     use tempfile::NamedTempFile;
 
     use arrow_array::cast::AsArray;
+    use arrow_select::concat::concat_batches;
 
     #[test]
     fn test_csv() {
@@ -2707,4 +3606,469 @@ mod tests {
         assert_eq!(c2.value(1), "something_cannot_be_inlined");
         assert_eq!(c2.value(2), "bar");
     }
+
+    #[test]
+    fn test_column_format() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Decimal128(10, 2), true),
+            Field::new("d", DataType::Boolean, true),
+            Field::new("e", DataType::Utf8, true),
+            Field::new("f", DataType::Utf8, true),
+        ]));
+        let csv = "a;b;c;d;e;f\n\
+                   1.000;1.234,5;-12,35;Y;NA;-\n\
+                   -;NA;1.000,1;n;x;NA\n";
+
+        let numbers = ColumnFormat::new()
+            .with_null_values(["-", "NA"])
+            .with_thousands_separator('.')
+            .with_decimal_separator(',');
+        let booleans = ColumnFormat::new()
+            .with_true_values(["y", "yes"])
+            .with_false_values(["n", "no"]);
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_header(true)
+            .with_delimiter(b';')
+            .with_null_regex(Regex::new("^-$").unwrap())
+            .with_column_format("a", numbers.clone())
+            .with_column_format("b", numbers.clone())
+            .with_column_format("c", numbers)
+            .with_column_format("d", booleans)
+            .with_column_format("e", ColumnFormat::new().with_null_values(["NA"]))
+            .with_column_format("missing", ColumnFormat::new())
+            .build(csv.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(a.value(0), 1000);
+        assert!(a.is_null(1));
+
+        let b = batch.column(1).as_primitive::<Float64Type>();
+        assert_eq!(b.value(0), 1234.5);
+        assert!(b.is_null(1));
+
+        let c = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(c.value(0), -1235);
+        assert_eq!(c.value(1), 100010);
+
+        let d = batch.column(3).as_boolean();
+        assert!(d.value(0));
+        assert!(!d.value(1));
+
+        // Per-column null values replace the null regex
+        let e = batch.column(4).as_string::<i32>();
+        assert!(e.is_null(0));
+        assert_eq!(e.value(1), "x");
+
+        // Columns without a column format use the null regex
+        let f = batch.column(5).as_string::<i32>();
+        assert!(f.is_null(0));
+        assert_eq!(f.value(1), "NA");
+
+        // Values not matching the boolean tokens are an error
+        let schema = Arc::new(Schema::new(vec![Field::new("d", DataType::Boolean, true)]));
+        let mut reader = ReaderBuilder::new(schema)
+            .with_column_format("d", ColumnFormat::new().with_true_values(["y"]))
+            .build("y\ntrue\nfalse\n".as_bytes())
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error while parsing value true for column 0 at line 1"
+        );
+    }
+
+    #[test]
+    fn test_column_format_temporal() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(
+                "a",
+                DataType::Timestamp(TimeUnit::Second, Some("+02:00".into())),
+                true,
+            ),
+            Field::new("b", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+            Field::new("c", DataType::Date32, true),
+            Field::new("d", DataType::Timestamp(TimeUnit::Second, None), true),
+        ]));
+        let csv = "01/02/2024 10:30,01/02/2024 10:30 +0100,01/02/2024,2024-02-01T10:30:00\n";
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_column_format(
+                "a",
+                ColumnFormat::new().with_timestamp_format("%d/%m/%Y %H:%M"),
+            )
+            .with_column_format(
+                "b",
+                ColumnFormat::new().with_timestamp_format("%d/%m/%Y %H:%M %z"),
+            )
+            .with_column_format("c", ColumnFormat::new().with_timestamp_format("%d/%m/%Y"))
+            .build(csv.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        // 2024-02-01T10:30:00+02:00
+        let a = batch.column(0).as_primitive::<TimestampSecondType>();
+        assert_eq!(a.value(0), 1706776200);
+        // 2024-02-01T10:30:00+01:00
+        let b = batch.column(1).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(b.value(0), 1706779800000);
+        let c = batch.column(2).as_primitive::<Date32Type>();
+        assert_eq!(c.value(0), 19754);
+        let d = batch.column(3).as_primitive::<TimestampSecondType>();
+        assert_eq!(d.value(0), 1706783400);
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Timestamp(TimeUnit::Second, None),
+            true,
+        )]));
+        let mut reader = ReaderBuilder::new(schema)
+            .with_column_format("a", ColumnFormat::new().with_timestamp_format("%d/%m/%Y"))
+            .build("2024-02-01\n".as_bytes())
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error parsing column 0 at line 0: Parser error: input contains invalid characters using format \"%d/%m/%Y\""
+        );
+    }
+
+    #[test]
+    fn test_column_format_inference() {
+        let csv = "a,b,c\nY,NA,1\nN,2,2\n";
+        let format = Format::default()
+            .with_header(true)
+            .with_column_format(
+                "a",
+                ColumnFormat::new()
+                    .with_true_values(["y"])
+                    .with_false_values(["n"]),
+            )
+            .with_column_format("b", ColumnFormat::new().with_null_values(["NA"]))
+            .with_column_format("c", ColumnFormat::new().with_decimal_separator(','));
+
+        let (schema, _) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Boolean);
+        assert_eq!(schema.field(1).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(2).data_type(), &DataType::Int64);
+    }
+
+    #[test]
+    fn test_multi_byte_delimiter() {
+        let csv = "name||value||note\nfoo||1||\"a||b\"\n|bar||2|||c|\n";
+        let format = Format::default()
+            .with_header(true)
+            .with_delimiter_bytes("||");
+
+        let (schema, n) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(n, 2);
+        let expected = Schema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("value", DataType::Int64, true),
+            Field::new("note", DataType::Utf8, true),
+        ]);
+        assert_eq!(schema, expected);
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, false),
+            Field::new("note", DataType::Utf8, false),
+        ]));
+        let reader = StdBufReader::with_capacity(3, csv.as_bytes());
+        let batches = ReaderBuilder::new(schema)
+            .with_format(format)
+            .with_batch_size(1)
+            .build_buffered(reader)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 2);
+
+        let name = batches[0].column(0).as_string::<i32>();
+        let note = batches[0].column(2).as_string::<i32>();
+        assert_eq!(name.value(0), "foo");
+        assert_eq!(note.value(0), "a||b");
+
+        // The delimiter is matched from the left
+        let name = batches[1].column(0).as_string::<i32>();
+        let value = batches[1].column(1).as_primitive::<Int32Type>();
+        let note = batches[1].column(2).as_string::<i32>();
+        assert_eq!(name.value(0), "|bar");
+        assert_eq!(value.value(0), 2);
+        assert_eq!(note.value(0), "|c|");
+
+        let csv = "1\t|2\n3\t|4\t|5\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let err = ReaderBuilder::new(schema)
+            .with_delimiter_bytes("\t|")
+            .build(csv.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Csv error: incorrect number of fields for line 2, expected 2 got 3"
+        );
+    }
+
+    #[test]
+    fn test_nul_terminator() {
+        let csv = "a,b\0x\ny,1\0\0z,2\0";
+        let format = Format::default().with_header(true).with_terminator(b'\0');
+
+        let (schema, n) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(schema.field(1).data_type(), &DataType::Int64);
+
+        for format in [format.clone(), format.with_delimiter_bytes(",")] {
+            let mut reader = ReaderBuilder::new(Arc::new(schema.clone()))
+                .with_format(format)
+                .build(csv.as_bytes())
+                .unwrap();
+            let batch = reader.next().unwrap().unwrap();
+            let a = batch.column(0).as_string::<i32>();
+            assert_eq!(a.value(0), "x\ny");
+            assert_eq!(a.value(1), "z");
+        }
+
+        // Multi-byte delimiters with a custom terminator
+        let csv = "a::b\0x\ny::1\0z::2";
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_header(true)
+            .with_terminator(b'\0')
+            .with_delimiter_bytes("::")
+            .build(csv.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let b = batch.column(1).as_primitive::<Int64Type>();
+        assert_eq!(b.values(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_error_mode() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Boolean, true),
+            Field::new("c", DataType::Utf8, true),
+        ]));
+        let csv = "a,b,c\n\
+            1,true,x\n\
+            2,maybe,y\n\
+            3,false\n\
+            4,true,\"z\nw\"\n\
+            five,nope,v\n\
+            6,true,u,extra\n\
+            7,false,t\n";
+
+        let read = |mode: ErrorMode, bounds: Option<(usize, usize)>| {
+            let malformed = Arc::new(std::sync::Mutex::new(vec![]));
+            let sink = malformed.clone();
+            let mut builder = ReaderBuilder::new(schema.clone())
+                .with_header(true)
+                .with_batch_size(2)
+                .with_error_mode(mode)
+                .with_error_sink(move |row| sink.lock().unwrap().push(row));
+            if let Some((start, end)) = bounds {
+                builder = builder.with_bounds(start, end);
+            }
+            let batches = builder
+                .build(csv.as_bytes())
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+
+            let mut malformed = std::mem::take(&mut *malformed.lock().unwrap());
+            malformed.sort_unstable_by_key(|row| row.line());
+            let malformed: Vec<_> = malformed
+                .iter()
+                .map(|row| (row.line(), row.content().to_string()))
+                .collect();
+            (batch, malformed)
+        };
+
+        let (batch, malformed) = read(ErrorMode::SkipRow, None);
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.values(), &[1, 4, 7]);
+        let c = batch.column(2).as_string::<i32>();
+        assert_eq!(c.value(1), "z\nw");
+        let expected = vec![
+            (3, "2,maybe,y".to_string()),
+            (4, "3,false".to_string()),
+            (6, "five,nope,v".to_string()),
+            (7, "6,true,u,extra".to_string()),
+        ];
+        assert_eq!(malformed, expected);
+
+        let (batch, malformed) = read(ErrorMode::NullCell, None);
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(4), None, Some(7)]
+        );
+        let b = batch.column(1).as_boolean();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![Some(true), None, Some(true), None, Some(false)]
+        );
+        assert_eq!(malformed, expected);
+
+        // Skipped rows count towards the bounds
+        let (batch, malformed) = read(ErrorMode::SkipRow, Some((0, 4)));
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.values(), &[1, 4]);
+        assert_eq!(malformed, expected[..2]);
+
+        let err = ReaderBuilder::new(schema)
+            .with_header(true)
+            .build(csv.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Csv error: incorrect number of fields for line 4, expected 3 got 2"
+        );
+    }
+
+    #[test]
+    fn test_error_mode_reports_first_error() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let csv = "x,1\n2,y\n3,\n";
+
+        let malformed = Arc::new(std::sync::Mutex::new(vec![]));
+        let sink = malformed.clone();
+        let mut reader = ReaderBuilder::new(schema)
+            .with_error_mode(ErrorMode::SkipRow)
+            .with_error_sink(move |row| sink.lock().unwrap().push(row))
+            .build(csv.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 0);
+
+        let malformed = malformed.lock().unwrap();
+        let errors: Vec<_> = malformed.iter().map(|r| r.error().to_string()).collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Parser error: Error while parsing value x for column 0 at line 1",
+                "Parser error: Error while parsing value y for column 1 at line 2",
+                "Invalid argument error: Column 'b' is declared as non-nullable but contains null values",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_infer_null_values() {
+        let csv = "a,b\n1,NA\n,2\n";
+        let format = Format::default().with_header(true);
+
+        let (schema, _) = format
+            .clone()
+            .with_null_values(["NA"])
+            .infer_schema(csv.as_bytes(), None)
+            .unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(1).data_type(), &DataType::Int64);
+
+        let format = format.with_null_values(["NA", ""]);
+        let (schema, _) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Int64);
+
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_format(format)
+            .build(csv.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let b = batch.column(1).as_primitive::<Int64Type>();
+        assert!(b.is_null(0));
+        assert_eq!(b.value(1), 2);
+    }
+
+    #[test]
+    fn test_infer_timestamp_formats() {
+        let csv = "a,b,c\n\
+            02/01/2024,02/01/2024 10:30,2024-01-02\n\
+            03/01/2024,03/01/2024 11:00:00.250,2024-01-03\n";
+        let format = Format::default().with_header(true).with_timestamp_formats([
+            "%d/%m/%Y %H:%M:%S%.f",
+            "%d/%m/%Y %H:%M",
+            "%d/%m/%Y",
+        ]);
+
+        let (schema, _) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Date32);
+        assert_eq!(
+            schema.field(1).data_type(),
+            &DataType::Timestamp(TimeUnit::Millisecond, None)
+        );
+        // The default formats are not used
+        assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+
+        let mut reader = ReaderBuilder::new(Arc::new(schema))
+            .with_format(format)
+            .build(csv.as_bytes())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch.column(0).as_primitive::<Date32Type>();
+        assert_eq!(a.values(), &[19724, 19725]);
+        let b = batch.column(1).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(b.values(), &[1704191400000, 1704279600250]);
+    }
+
+    #[test]
+    fn test_infer_column_type() {
+        let csv = "a,b\n1,2\n3,4\n";
+        let format = Format::default()
+            .with_header(true)
+            .with_column_type("a", DataType::Utf8)
+            .with_column_type("b", DataType::Int8);
+        let (schema, n) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(1).data_type(), &DataType::Int8);
+    }
+
+    #[test]
+    fn test_infer_sampling() {
+        let csv = "a\n1\nx\n2\ny\n";
+        let format = Format::default().with_header(true);
+
+        let (schema, n) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+
+        let format = format.with_infer_sample_interval(2);
+        let (schema, n) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+
+        let (_, n) = format.infer_schema(csv.as_bytes(), Some(3)).unwrap();
+        assert_eq!(n, 3);
+
+        // Records start at bytes 2, 4 and 6
+        let csv = "a\n1\n2\nfoo\n";
+        for delimiter in [",", "::"] {
+            let format = Format::default()
+                .with_header(true)
+                .with_delimiter_bytes(delimiter)
+                .with_infer_max_bytes(5);
+            let (schema, n) = format.infer_schema(csv.as_bytes(), None).unwrap();
+            assert_eq!(n, 2);
+            assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        }
+    }
 }
@@ -0,0 +1,455 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for CSV data with multi-byte field delimiters, such as `||`
+
+use std::io::BufRead;
+
+use arrow_schema::ArrowError;
+use csv::{Position, StringRecord};
+use csv_core::ReadRecordResult;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum State {
+    /// At the start of a record
+    StartRecord,
+    /// At the start of a field
+    StartField,
+    /// Within an unquoted field
+    InField,
+    /// Within a quoted field
+    InQuotedField,
+    /// Following an escape character within a quoted field
+    EscapeInQuotedField,
+    /// Following a quote within a quoted field
+    QuoteInQuotedField,
+    /// Within a comment line
+    InComment,
+}
+
+/// A push-based CSV tokenizer supporting multi-byte field delimiters
+///
+/// This provides the same interface and behaviour as [`csv_core::Reader::read_record`],
+/// which only supports single-byte delimiters. The delimiter should not contain the
+/// quote, escape or terminator characters.
+#[derive(Debug, Clone)]
+pub struct MultiByteReader {
+    delimiter: Vec<u8>,
+    /// For each prefix of `delimiter`, the length of its longest proper prefix that is
+    /// also a suffix, allowing overlapping partial matches to be resumed
+    fallback: Vec<usize>,
+    quote: u8,
+    escape: Option<u8>,
+    comment: Option<u8>,
+    /// The record terminator, `None` for CRLF
+    terminator: Option<u8>,
+    state: State,
+    /// The number of bytes of `delimiter` matched at the end of the current field
+    matched: usize,
+    /// The number of bytes written for the current record
+    record_len: usize,
+}
+
+impl MultiByteReader {
+    /// Create a new [`MultiByteReader`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty
+    pub fn new(
+        delimiter: Vec<u8>,
+        quote: u8,
+        escape: Option<u8>,
+        comment: Option<u8>,
+        terminator: Option<u8>,
+    ) -> Self {
+        assert!(!delimiter.is_empty(), "delimiter must not be empty");
+
+        let mut fallback = vec![0; delimiter.len()];
+        let mut k = 0;
+        for i in 1..delimiter.len() {
+            while k > 0 && delimiter[i] != delimiter[k] {
+                k = fallback[k - 1];
+            }
+            if delimiter[i] == delimiter[k] {
+                k += 1;
+            }
+            fallback[i] = k;
+        }
+
+        Self {
+            delimiter,
+            fallback,
+            quote,
+            escape,
+            comment,
+            terminator,
+            state: State::StartRecord,
+            matched: 0,
+            record_len: 0,
+        }
+    }
+
+    fn is_terminator(&self, b: u8) -> bool {
+        match self.terminator {
+            Some(t) => b == t,
+            None => b == b'\r' || b == b'\n',
+        }
+    }
+
+    /// Reads a record from `input`, writing its unescaped field data to `output`
+    /// and the end offset of each field, relative to the start of the record, to `ends`
+    ///
+    /// Returns the result along with the number of bytes read from `input`, written to
+    /// `output`, and the number of offsets written to `ends`. An empty `input` signals
+    /// the end of the data
+    pub fn read_record(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+        ends: &mut [usize],
+    ) -> (ReadRecordResult, usize, usize, usize) {
+        let (mut nin, mut nout, mut nend) = (0, 0, 0);
+
+        if input.is_empty() {
+            if matches!(self.state, State::StartRecord | State::InComment) {
+                self.state = State::StartRecord;
+                return (ReadRecordResult::End, 0, 0, 0);
+            }
+            // Any partially matched delimiter is field data
+            let matched = self.matched;
+            if output.len() < matched {
+                return (ReadRecordResult::OutputFull, 0, 0, 0);
+            }
+            if ends.is_empty() {
+                return (ReadRecordResult::OutputEndsFull, 0, 0, 0);
+            }
+            output[..matched].copy_from_slice(&self.delimiter[..matched]);
+            self.record_len += matched;
+            ends[0] = self.record_len;
+            self.end_record();
+            return (ReadRecordResult::Record, 0, matched, 1);
+        }
+
+        while nin < input.len() {
+            let b = input[nin];
+            match self.state {
+                State::StartRecord => {
+                    if self.is_terminator(b) {
+                        // Skip empty lines
+                    } else if Some(b) == self.comment {
+                        self.state = State::InComment;
+                    } else {
+                        self.state = State::StartField;
+                        continue;
+                    }
+                }
+                State::InComment => {
+                    if self.is_terminator(b) {
+                        self.state = State::StartRecord;
+                    }
+                }
+                State::StartField | State::InField => {
+                    // Writing `b` may first require writing a partially matched delimiter
+                    if output.len() - nout <= self.matched {
+                        return (ReadRecordResult::OutputFull, nin, nout, nend);
+                    }
+
+                    while self.matched > 0 && self.delimiter[self.matched] != b {
+                        // The start of the partial match was field data
+                        let fallback = self.fallback[self.matched - 1];
+                        let data = &self.delimiter[..self.matched - fallback];
+                        output[nout..nout + data.len()].copy_from_slice(data);
+                        nout += data.len();
+                        self.record_len += data.len();
+                        self.matched = fallback;
+                        self.state = State::InField;
+                    }
+
+                    if self.delimiter[self.matched] == b {
+                        self.matched += 1;
+                        if self.matched == self.delimiter.len() {
+                            if nend == ends.len() {
+                                self.matched -= 1;
+                                return (ReadRecordResult::OutputEndsFull, nin, nout, nend);
+                            }
+                            ends[nend] = self.record_len;
+                            nend += 1;
+                            self.matched = 0;
+                            self.state = State::StartField;
+                        }
+                    } else if self.state == State::StartField && b == self.quote {
+                        self.state = State::InQuotedField;
+                    } else if self.is_terminator(b) {
+                        if nend == ends.len() {
+                            return (ReadRecordResult::OutputEndsFull, nin, nout, nend);
+                        }
+                        ends[nend] = self.record_len;
+                        self.end_record();
+                        return (ReadRecordResult::Record, nin + 1, nout, nend + 1);
+                    } else {
+                        output[nout] = b;
+                        nout += 1;
+                        self.record_len += 1;
+                        self.state = State::InField;
+                    }
+                }
+                State::InQuotedField => {
+                    if Some(b) == self.escape && b != self.quote {
+                        self.state = State::EscapeInQuotedField;
+                    } else if b == self.quote {
+                        self.state = State::QuoteInQuotedField;
+                    } else {
+                        if nout == output.len() {
+                            return (ReadRecordResult::OutputFull, nin, nout, nend);
+                        }
+                        output[nout] = b;
+                        nout += 1;
+                        self.record_len += 1;
+                    }
+                }
+                State::EscapeInQuotedField => {
+                    if nout == output.len() {
+                        return (ReadRecordResult::OutputFull, nin, nout, nend);
+                    }
+                    output[nout] = b;
+                    nout += 1;
+                    self.record_len += 1;
+                    self.state = State::InQuotedField;
+                }
+                State::QuoteInQuotedField => {
+                    if b == self.quote {
+                        // An escaped quote
+                        if nout == output.len() {
+                            return (ReadRecordResult::OutputFull, nin, nout, nend);
+                        }
+                        output[nout] = b;
+                        nout += 1;
+                        self.record_len += 1;
+                        self.state = State::InQuotedField;
+                    } else {
+                        // The end of the quoted section, process `b` as unquoted data
+                        self.state = State::InField;
+                        continue;
+                    }
+                }
+            }
+            nin += 1;
+        }
+        (ReadRecordResult::InputEmpty, nin, nout, nend)
+    }
+
+    fn end_record(&mut self) {
+        self.state = State::StartRecord;
+        self.matched = 0;
+        self.record_len = 0;
+    }
+}
+
+/// An iterator of [`StringRecord`] read from a [`BufRead`] using a [`MultiByteReader`]
+#[derive(Debug)]
+pub struct MultiByteRecords<R> {
+    reader: R,
+    tokenizer: MultiByteReader,
+    /// The number of the next record to read, used for error reporting
+    line_number: usize,
+    /// The number of bytes consumed from `reader`
+    position: u64,
+    data: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl<R: BufRead> MultiByteRecords<R> {
+    /// Create a new [`MultiByteRecords`] reading from `reader`
+    pub fn new(reader: R, tokenizer: MultiByteReader) -> Self {
+        Self {
+            reader,
+            tokenizer,
+            line_number: 1,
+            position: 0,
+            data: vec![0; 1024],
+            ends: vec![0; 16],
+        }
+    }
+
+    fn read(&mut self) -> Result<Option<StringRecord>, ArrowError> {
+        let (mut data_len, mut ends_len) = (0, 0);
+        let record_start = self.position;
+        loop {
+            let input = self.reader.fill_buf()?;
+            let (result, nin, nout, nend) = self.tokenizer.read_record(
+                input,
+                &mut self.data[data_len..],
+                &mut self.ends[ends_len..],
+            );
+            self.reader.consume(nin);
+            self.position += nin as u64;
+            data_len += nout;
+            ends_len += nend;
+
+            match result {
+                ReadRecordResult::InputEmpty => {}
+                ReadRecordResult::OutputFull => self.data.resize(self.data.len() * 2, 0),
+                ReadRecordResult::OutputEndsFull => self.ends.resize(self.ends.len() * 2, 0),
+                ReadRecordResult::End => return Ok(None),
+                ReadRecordResult::Record => break,
+            }
+        }
+
+        let mut record = StringRecord::with_capacity(data_len, ends_len);
+        let mut start = 0;
+        for (idx, end) in self.ends[..ends_len].iter().enumerate() {
+            let field = std::str::from_utf8(&self.data[start..*end]).map_err(|_| {
+                ArrowError::CsvError(format!(
+                    "Encountered invalid UTF-8 data for line {} and field {}",
+                    self.line_number,
+                    idx + 1
+                ))
+            })?;
+            record.push_field(field);
+            start = *end;
+        }
+        let mut position = Position::new();
+        position
+            .set_byte(record_start)
+            .set_record(self.line_number as u64 - 1);
+        record.set_position(Some(position));
+        self.line_number += 1;
+        Ok(Some(record))
+    }
+}
+
+impl<R: BufRead> Iterator for MultiByteRecords<R> {
+    type Item = Result<StringRecord, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Splits `input` into records, feeding it `chunk_size` bytes at a time
+    fn tokenize(mut reader: MultiByteReader, input: &[u8], chunk_size: usize) -> Vec<Vec<String>> {
+        let mut records = vec![];
+        let mut output = [0; 1024];
+        let mut ends = [0; 16];
+        let (mut nout, mut nend) = (0, 0);
+
+        let chunks = input.chunks(chunk_size).chain(std::iter::once(&[][..]));
+        for mut chunk in chunks {
+            loop {
+                let (result, nin, o, e) =
+                    reader.read_record(chunk, &mut output[nout..], &mut ends[nend..]);
+                chunk = &chunk[nin..];
+                nout += o;
+                nend += e;
+                match result {
+                    ReadRecordResult::Record => {
+                        let mut start = 0;
+                        let fields = ends[..nend].iter().map(|end| {
+                            let field = std::str::from_utf8(&output[start..*end]).unwrap();
+                            start = *end;
+                            field.to_string()
+                        });
+                        records.push(fields.collect());
+                        nout = 0;
+                        nend = 0;
+                    }
+                    ReadRecordResult::InputEmpty | ReadRecordResult::End => break,
+                    r => panic!("unexpected {r:?}"),
+                }
+                if chunk.is_empty() && nin != 0 {
+                    break;
+                }
+            }
+        }
+        records
+    }
+
+    fn check(reader: MultiByteReader, input: &str, expected: &[&[&str]]) {
+        for chunk_size in [1, 2, 3, 7, input.len().max(1)] {
+            let records = tokenize(reader.clone(), input.as_bytes(), chunk_size);
+            assert_eq!(records, expected, "chunk size {chunk_size}");
+        }
+    }
+
+    fn reader(delimiter: &str) -> MultiByteReader {
+        MultiByteReader::new(delimiter.into(), b'"', None, None, None)
+    }
+
+    #[test]
+    fn test_delimiter() {
+        check(
+            reader("||"),
+            "a||b||c\n1||||3\r\n\n|x|||y|\n",
+            &[&["a", "b", "c"], &["1", "", "3"], &["|x", "|y|"]],
+        );
+        check(reader("\t|"), "a\t|b\t\t|c", &[&["a", "b\t", "c"]]);
+        // Partial matches overlapping the delimiter
+        check(reader("aab"), "xaaab1aab2\n", &[&["xa", "1", "2"]]);
+        check(reader("abab"), "ababab1abab\n", &[&["", "ab1", ""]]);
+        // A trailing partial delimiter is field data
+        check(reader("||"), "a||b|", &[&["a", "b|"]]);
+        check(reader("||"), "a||", &[&["a", ""]]);
+    }
+
+    #[test]
+    fn test_quotes() {
+        check(
+            reader("||"),
+            "\"a||b\"||\"c\"\"d\"||e\"f\n\"x\ny\"||\"\"\n",
+            &[&["a||b", "c\"d", "e\"f"], &["x\ny", ""]],
+        );
+
+        let reader = MultiByteReader::new("||".into(), b'\'', Some(b'\\'), None, None);
+        check(reader, "'a\\'b'||'c\\\\'\n", &[&["a'b", "c\\"]]);
+    }
+
+    #[test]
+    fn test_terminator_and_comment() {
+        let reader = MultiByteReader::new("::".into(), b'"', None, Some(b'#'), Some(b'\0'));
+        check(
+            reader,
+            "#a::b\0x::y\nz\0\0#c\0w::v",
+            &[&["x", "y\nz"], &["w", "v"]],
+        );
+    }
+
+    #[test]
+    fn test_output_full() {
+        let mut reader = reader("||");
+        let mut output = [0; 2];
+        let mut ends = [0; 1];
+
+        let (result, nin, nout, nend) = reader.read_record(b"abc||d\n", &mut output, &mut ends);
+        assert_eq!(
+            (result, nin, nout, nend),
+            (ReadRecordResult::OutputFull, 2, 2, 0)
+        );
+
+        let mut output = [0; 8];
+        let (result, nin, nout, nend) = reader.read_record(b"c||d\n", &mut output, &mut ends);
+        assert_eq!(
+            (result, nin, nout, nend),
+            (ReadRecordResult::OutputEndsFull, 4, 2, 1)
+        );
+        assert_eq!(ends[0], 3);
+    }
+}
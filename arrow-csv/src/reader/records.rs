@@ -18,16 +18,54 @@
 use arrow_schema::ArrowError;
 use csv_core::{ReadRecordResult, Reader};
 
+use crate::reader::multi_byte::MultiByteReader;
+use crate::reader::MalformedRow;
+
 /// The estimated length of a field in bytes
 const AVERAGE_FIELD_SIZE: usize = 8;
 
 /// The minimum amount of data in a single read
 const MIN_CAPACITY: usize = 1024;
 
+/// Splits CSV data into records and fields
+#[derive(Debug)]
+pub enum RecordReader {
+    /// A [`Reader`] for single-byte delimiters
+    Core(Box<Reader>),
+    /// A [`MultiByteReader`] for multi-byte delimiters
+    MultiByte(MultiByteReader),
+}
+
+impl RecordReader {
+    fn read_record(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+        ends: &mut [usize],
+    ) -> (ReadRecordResult, usize, usize, usize) {
+        match self {
+            Self::Core(r) => r.read_record(input, output, ends),
+            Self::MultiByte(r) => r.read_record(input, output, ends),
+        }
+    }
+}
+
+impl From<Reader> for RecordReader {
+    fn from(value: Reader) -> Self {
+        Self::Core(Box::new(value))
+    }
+}
+
+impl From<MultiByteReader> for RecordReader {
+    fn from(value: MultiByteReader) -> Self {
+        Self::MultiByte(value)
+    }
+}
+
 /// [`RecordDecoder`] provides a push-based interface to decoder [`StringRecords`]
 #[derive(Debug)]
 pub struct RecordDecoder {
-    delimiter: Reader,
+    delimiter: RecordReader,
 
     /// The expected number of fields per row
     num_columns: usize,
@@ -62,12 +100,33 @@ pub struct RecordDecoder {
     /// Default value is false
     /// When enabled fills in missing columns with null
     truncated_rows: bool,
+
+    /// Whether rows with an incorrect number of fields are skipped instead of returning an error
+    ///
+    /// When enabled, the raw input and line number of each buffered row is also retained
+    skip_malformed: bool,
+
+    /// The value of `offsets_len` at the start of the current record
+    record_offsets_start: usize,
+
+    /// The value of `data_len` at the start of the current record
+    record_data_start: usize,
+
+    /// The raw input of the buffered rows, followed by that of the current record
+    raw: RawRecords,
+
+    /// Rows skipped since the last call to [`Self::take_malformed`]
+    malformed: Vec<MalformedRow>,
 }
 
 impl RecordDecoder {
-    pub fn new(delimiter: Reader, num_columns: usize, truncated_rows: bool) -> Self {
+    pub fn new(
+        delimiter: impl Into<RecordReader>,
+        num_columns: usize,
+        truncated_rows: bool,
+    ) -> Self {
         Self {
-            delimiter,
+            delimiter: delimiter.into(),
             num_columns,
             line_number: 1,
             offsets: vec![],
@@ -77,9 +136,23 @@ impl RecordDecoder {
             data: vec![],
             num_rows: 0,
             truncated_rows,
+            skip_malformed: false,
+            record_offsets_start: 1,
+            record_data_start: 0,
+            raw: RawRecords::default(),
+            malformed: vec![],
         }
     }
 
+    /// Skip rows with an incorrect number of fields instead of returning an error
+    ///
+    /// Skipped rows still count towards the number of records read by [`Self::decode`],
+    /// and can be retrieved with [`Self::take_malformed`]
+    pub fn with_skip_malformed(mut self, skip: bool) -> Self {
+        self.skip_malformed = skip;
+        self
+    }
+
     /// Decodes records from `input` returning the number of records and bytes read
     ///
     /// Note: this expects to be called with an empty `input` to signal EOF
@@ -114,6 +187,11 @@ impl RecordDecoder {
                         &mut self.offsets[self.offsets_len..],
                     );
 
+                if self.skip_malformed {
+                    let consumed = &input[input_offset..input_offset + bytes_read];
+                    self.raw.data.extend_from_slice(consumed);
+                }
+
                 self.current_field += end_positions;
                 self.offsets_len += end_positions;
                 input_offset += bytes_read;
@@ -126,6 +204,11 @@ impl RecordDecoder {
                     }
                     // Need to allocate more capacity
                     ReadRecordResult::OutputFull => break,
+                    ReadRecordResult::OutputEndsFull if self.skip_malformed => {
+                        // Make room for the remaining fields, the record is skipped once complete
+                        let len = self.offsets.len();
+                        self.offsets.resize(len + self.num_columns, 0);
+                    }
                     ReadRecordResult::OutputEndsFull => {
                         return Err(ArrowError::CsvError(format!(
                             "incorrect number of fields for line {}, expected {} got more than {}",
@@ -133,6 +216,7 @@ impl RecordDecoder {
                         )));
                     }
                     ReadRecordResult::Record => {
+                        let mut skipped = false;
                         if self.current_field != self.num_columns {
                             if self.truncated_rows && self.current_field < self.num_columns {
                                 // If the number of fields is less than expected, pad with nulls
@@ -142,16 +226,29 @@ impl RecordDecoder {
                                     .fill(fill_value);
                                 self.offsets_len += fill_count;
                             } else {
-                                return Err(ArrowError::CsvError(format!(
+                                let error = ArrowError::CsvError(format!(
                                     "incorrect number of fields for line {}, expected {} got {}",
                                     self.line_number, self.num_columns, self.current_field
-                                )));
+                                ));
+                                if !self.skip_malformed {
+                                    return Err(error);
+                                }
+                                self.skip_record(error);
+                                skipped = true;
                             }
                         }
+                        if self.skip_malformed && !skipped {
+                            self.raw.offsets.push(self.raw.data.len());
+                            self.raw.lines.push(self.line_number);
+                        }
                         read += 1;
                         self.current_field = 0;
                         self.line_number += 1;
-                        self.num_rows += 1;
+                        if !skipped {
+                            self.num_rows += 1;
+                        }
+                        self.record_offsets_start = self.offsets_len;
+                        self.record_data_start = self.data_len;
 
                         if read == to_read {
                             // Read sufficient rows
@@ -170,6 +267,31 @@ impl RecordDecoder {
         }
     }
 
+    /// Discards the current record, recording it as a [`MalformedRow`]
+    fn skip_record(&mut self, error: ArrowError) {
+        let start = self.raw.offsets.last().copied().unwrap_or_default();
+        let content = raw_content(&self.raw.data[start..]);
+        self.raw.data.truncate(start);
+        self.offsets_len = self.record_offsets_start;
+        self.data_len = self.record_data_start;
+        self.malformed.push(MalformedRow {
+            line: self.line_number,
+            content,
+            error,
+        });
+    }
+
+    /// Returns the rows skipped since the last call to this method
+    pub fn take_malformed(&mut self) -> Vec<MalformedRow> {
+        std::mem::take(&mut self.malformed)
+    }
+
+    /// Returns the [`RawRecords`] of the buffered rows, which must be called
+    /// before [`Self::flush`] if `skip_malformed` is enabled
+    pub fn take_raw(&mut self) -> RawRecords {
+        self.raw.take_records()
+    }
+
     /// Returns the current number of buffered records
     pub fn len(&self) -> usize {
         self.num_rows
@@ -186,6 +308,10 @@ impl RecordDecoder {
         self.offsets_len = 1;
         self.data_len = 0;
         self.num_rows = 0;
+        self.record_offsets_start = 1;
+        self.record_data_start = 0;
+        self.raw.take_records();
+        self.malformed.clear();
     }
 
     /// Flushes the current contents of the reader
@@ -235,16 +361,66 @@ impl RecordDecoder {
         self.offsets_len = 1;
         self.data_len = 0;
         self.num_rows = 0;
+        self.record_offsets_start = 1;
+        self.record_data_start = 0;
 
         Ok(StringRecords {
             num_rows,
             num_columns: self.num_columns,
             offsets,
             data,
+            selection: None,
         })
     }
 }
 
+/// The raw input and line numbers of records decoded by a [`RecordDecoder`]
+/// with `skip_malformed` enabled
+#[derive(Debug, Default)]
+pub struct RawRecords {
+    /// The raw input of each record
+    data: Vec<u8>,
+    /// The end offset into `data` of each record
+    offsets: Vec<usize>,
+    /// The line number of each record
+    lines: Vec<usize>,
+}
+
+impl RawRecords {
+    /// Takes the complete records, retaining the input of any partially decoded record
+    fn take_records(&mut self) -> RawRecords {
+        let end = self.offsets.last().copied().unwrap_or_default();
+        let partial = self.data.split_off(end);
+        std::mem::replace(
+            self,
+            RawRecords {
+                data: partial,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Returns the line number of the record at `index`
+    pub fn line(&self, index: usize) -> usize {
+        self.lines[index]
+    }
+
+    /// Returns the raw input of the record at `index`
+    pub fn content(&self, index: usize) -> String {
+        let start = match index {
+            0 => 0,
+            _ => self.offsets[index - 1],
+        };
+        raw_content(&self.data[start..self.offsets[index]])
+    }
+}
+
+/// Converts the raw input of a record to a [`String`], without its terminator
+fn raw_content(raw: &[u8]) -> String {
+    let s = String::from_utf8_lossy(raw);
+    s.trim_end_matches(['\r', '\n']).to_string()
+}
+
 /// A collection of parsed, UTF-8 CSV records
 #[derive(Debug)]
 pub struct StringRecords<'a> {
@@ -252,10 +428,16 @@ pub struct StringRecords<'a> {
     num_rows: usize,
     offsets: &'a [usize],
     data: &'a str,
+    /// The indices of the rows in this collection, if a subset
+    selection: Option<Vec<usize>>,
 }
 
 impl<'a> StringRecords<'a> {
     fn get(&self, index: usize) -> StringRecord<'a> {
+        let index = match &self.selection {
+            Some(selection) => selection[index],
+            None => index,
+        };
         let field_idx = index * self.num_columns;
         StringRecord {
             data: self.data,
@@ -264,11 +446,29 @@ impl<'a> StringRecords<'a> {
     }
 
     pub fn len(&self) -> usize {
-        self.num_rows
+        match &self.selection {
+            Some(selection) => selection.len(),
+            None => self.num_rows,
+        }
     }
 
     pub fn iter(&self) -> impl Iterator<Item = StringRecord<'a>> + '_ {
-        (0..self.num_rows).map(|x| self.get(x))
+        (0..self.len()).map(|x| self.get(x))
+    }
+
+    /// Returns the subset of these records at the given `indices`
+    pub fn select(&self, indices: Vec<usize>) -> StringRecords<'a> {
+        let selection = match &self.selection {
+            Some(selection) => indices.into_iter().map(|x| selection[x]).collect(),
+            None => indices,
+        };
+        StringRecords {
+            num_columns: self.num_columns,
+            num_rows: self.num_rows,
+            offsets: self.offsets,
+            data: self.data,
+            selection: Some(selection),
+        }
     }
 }
 
@@ -384,4 +584,45 @@ mod tests {
         assert_eq!(read, 5);
         assert_eq!(bytes, csv.len());
     }
+
+    #[test]
+    fn test_skip_malformed() {
+        let csv = "a,b\nc\nd,e,f,g,h\n\"i\nj\",k\n";
+        let mut decoder = RecordDecoder::new(Reader::new(), 2, false).with_skip_malformed(true);
+
+        // Read a single record at a time, so more fields than reserved are encountered
+        let mut input = csv.as_bytes();
+        let mut read = 0;
+        loop {
+            let (records, bytes) = decoder.decode(input, 1).unwrap();
+            read += records;
+            input = &input[bytes..];
+            if bytes == 0 {
+                break;
+            }
+        }
+        assert_eq!(read, 4);
+        assert_eq!(decoder.len(), 2);
+
+        let malformed = decoder.take_malformed();
+        assert_eq!(malformed.len(), 2);
+        assert_eq!(malformed[0].line(), 2);
+        assert_eq!(malformed[0].content(), "c");
+        assert_eq!(
+            malformed[0].error().to_string(),
+            "Csv error: incorrect number of fields for line 2, expected 2 got 1"
+        );
+        assert_eq!(malformed[1].line(), 3);
+        assert_eq!(malformed[1].content(), "d,e,f,g,h");
+
+        let raw = decoder.take_raw();
+        assert_eq!(raw.line(0), 1);
+        assert_eq!(raw.content(0), "a,b");
+        assert_eq!(raw.line(1), 4);
+        assert_eq!(raw.content(1), "\"i\nj\",k");
+
+        let records = decoder.flush().unwrap();
+        let actual: Vec<_> = records.iter().map(|r| (r.get(0), r.get(1))).collect();
+        assert_eq!(actual, vec![("a", "b"), ("i\nj", "k")]);
+    }
 }
@@ -76,7 +76,7 @@ const DEFAULT_NULL_VALUE: &str = "";
 #[derive(Debug)]
 pub struct Writer<W: Write> {
     /// The object to write to
-    writer: csv::Writer<W>,
+    writer: RecordWriter<W>,
     /// Whether file should be written with headers, defaults to `true`
     has_headers: bool,
     /// The date format for date arrays, defaults to RFC3339
@@ -93,6 +93,8 @@ pub struct Writer<W: Write> {
     beginning: bool,
     /// The value to represent null entries, defaults to [`DEFAULT_NULL_VALUE`]
     null_value: Option<String>,
+    /// Formatting overrides for specific data types
+    type_formatters: Option<TypeFormatters>,
 }
 
 impl<W: Write> Writer<W> {
@@ -113,9 +115,7 @@ impl<W: Write> Writer<W> {
                     .fields()
                     .iter()
                     .for_each(|field| headers.push(field.name().to_string()));
-                self.writer
-                    .write_record(&headers[..])
-                    .map_err(map_csv_error)?;
+                self.writer.write_byte_record(&ByteRecord::from(headers))?;
             }
             self.beginning = false;
         }
@@ -126,7 +126,8 @@ impl<W: Write> Writer<W> {
             .with_datetime_format(self.datetime_format.as_deref())
             .with_timestamp_format(self.timestamp_format.as_deref())
             .with_timestamp_tz_format(self.timestamp_tz_format.as_deref())
-            .with_time_format(self.time_format.as_deref());
+            .with_time_format(self.time_format.as_deref())
+            .with_type_formatters(self.type_formatters.as_ref());
 
         let converters = batch
             .columns()
@@ -160,9 +161,7 @@ impl<W: Write> Writer<W> {
                 byte_record.push_field(buffer.as_bytes());
             }
 
-            self.writer
-                .write_byte_record(&byte_record)
-                .map_err(map_csv_error)?;
+            self.writer.write_byte_record(&byte_record)?;
         }
         self.writer.flush()?;
 
@@ -171,8 +170,164 @@ impl<W: Write> Writer<W> {
 
     /// Unwraps this `Writer<W>`, returning the underlying writer.
     pub fn into_inner(self) -> W {
-        // Safe to call `unwrap` since `write` always flushes the writer.
-        self.writer.into_inner().unwrap()
+        self.writer.into_inner()
+    }
+}
+
+/// The quoting style to use when writing CSV fields
+///
+/// Quotes embedded within a quoted field are escaped according to
+/// [`WriterBuilder::with_double_quote`] and [`WriterBuilder::with_escape`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+    /// Only quote fields that contain a quote, delimiter or record terminator
+    #[default]
+    Necessary,
+    /// Quote every field, including the header
+    Always,
+    /// Quote every field that cannot be parsed as an integer or a float
+    NonNumeric,
+    /// Never quote fields, even if this produces invalid CSV
+    Never,
+}
+
+impl From<QuoteStyle> for csv::QuoteStyle {
+    fn from(value: QuoteStyle) -> Self {
+        match value {
+            QuoteStyle::Necessary => Self::Necessary,
+            QuoteStyle::Always => Self::Always,
+            QuoteStyle::NonNumeric => Self::NonNumeric,
+            QuoteStyle::Never => Self::Never,
+        }
+    }
+}
+
+/// Writes [`ByteRecord`] to an underlying [`Write`]
+#[derive(Debug)]
+enum RecordWriter<W: Write> {
+    /// A [`csv::Writer`] for single-byte delimiters
+    Csv(Box<csv::Writer<W>>),
+    /// A [`MultiByteWriter`] for multi-byte delimiters
+    MultiByte(MultiByteWriter<W>),
+}
+
+impl<W: Write> RecordWriter<W> {
+    fn write_byte_record(&mut self, record: &ByteRecord) -> Result<(), ArrowError> {
+        match self {
+            Self::Csv(w) => w.write_byte_record(record).map_err(map_csv_error),
+            Self::MultiByte(w) => w.write_byte_record(record),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Csv(w) => w.flush(),
+            Self::MultiByte(w) => w.flush(),
+        }
+    }
+
+    fn into_inner(self) -> W {
+        match self {
+            // Safe to call `unwrap` since `write` always flushes the writer.
+            Self::Csv(w) => w.into_inner().unwrap(),
+            Self::MultiByte(w) => w.writer,
+        }
+    }
+}
+
+/// Writes CSV records with a multi-byte delimiter, which [`csv::Writer`] does not support
+///
+/// Fields are quoted if they contain the quote, a terminator, or the first byte of the
+/// delimiter, as any occurrence of the delimiter within the field would start with it
+#[derive(Debug)]
+struct MultiByteWriter<W> {
+    writer: W,
+    delimiter: Vec<u8>,
+    terminator: u8,
+    crlf: bool,
+    quote_style: QuoteStyle,
+    quote: u8,
+    escape: u8,
+    double_quote: bool,
+    /// Buffered output not yet written to `writer`
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> MultiByteWriter<W> {
+    /// The size of `buffer` after which it is written to `writer`
+    const BUFFER_SIZE: usize = 8 * 1024;
+
+    fn write_byte_record(&mut self, record: &ByteRecord) -> Result<(), ArrowError> {
+        for (idx, field) in record.iter().enumerate() {
+            if idx != 0 {
+                self.buffer.extend_from_slice(&self.delimiter);
+            }
+            if self.should_quote(field, record.len()) {
+                self.write_quoted(field);
+            } else {
+                self.buffer.extend_from_slice(field);
+            }
+        }
+        match self.crlf {
+            true => self.buffer.extend_from_slice(b"\r\n"),
+            false => self.buffer.push(self.terminator),
+        }
+
+        if self.buffer.len() >= Self::BUFFER_SIZE {
+            self.writer.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+
+    fn should_quote(&self, field: &[u8], num_fields: usize) -> bool {
+        match self.quote_style {
+            QuoteStyle::Always => true,
+            QuoteStyle::Never => false,
+            QuoteStyle::NonNumeric if is_non_numeric(field) => true,
+            // Distinguish a single empty field from an empty line
+            _ if field.is_empty() => num_fields == 1,
+            _ => field.iter().any(|b| self.requires_quotes(*b)),
+        }
+    }
+
+    fn requires_quotes(&self, b: u8) -> bool {
+        let is_terminator = match (self.crlf, self.terminator) {
+            (true, _) | (false, b'\r' | b'\n') => b == b'\r' || b == b'\n',
+            (false, t) => b == t,
+        };
+        is_terminator
+            || b == self.quote
+            || b == self.delimiter[0]
+            || (!self.double_quote && b == self.escape)
+    }
+
+    fn write_quoted(&mut self, field: &[u8]) {
+        self.buffer.push(self.quote);
+        for b in field {
+            if *b == self.quote {
+                match self.double_quote {
+                    true => self.buffer.push(self.quote),
+                    false => self.buffer.push(self.escape),
+                }
+            }
+            self.buffer.push(*b);
+        }
+        self.buffer.push(self.quote);
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.write_all(&self.buffer)?;
+        self.buffer.clear();
+        self.writer.flush()
+    }
+}
+
+/// Returns `true` if `field` cannot be parsed as an integer or a float
+fn is_non_numeric(field: &[u8]) -> bool {
+    match std::str::from_utf8(field) {
+        Ok(s) => s.parse::<f64>().is_err() && s.parse::<i128>().is_err(),
+        Err(_) => true,
     }
 }
 
@@ -190,7 +345,13 @@ impl<W: Write> RecordBatchWriter for Writer<W> {
 #[derive(Clone, Debug)]
 pub struct WriterBuilder {
     /// Optional column delimiter. Defaults to `b','`
-    delimiter: u8,
+    delimiter: Vec<u8>,
+    /// Optional record terminator. Defaults to `b'\n'`
+    terminator: u8,
+    /// Whether to terminate records with `\r\n`. Defaults to `false`
+    crlf: bool,
+    /// The quoting style. Defaults to [`QuoteStyle::Necessary`]
+    quote_style: QuoteStyle,
     /// Whether to write column names as file headers. Defaults to `true`
     has_header: bool,
     /// Optional quote character. Defaults to `b'"'`
@@ -211,12 +372,17 @@ pub struct WriterBuilder {
     time_format: Option<String>,
     /// Optional value to represent null
     null_value: Option<String>,
+    /// Optional formatting overrides for specific data types
+    type_formatters: Option<TypeFormatters>,
 }
 
 impl Default for WriterBuilder {
     fn default() -> Self {
         WriterBuilder {
-            delimiter: b',',
+            delimiter: vec![b','],
+            terminator: b'\n',
+            crlf: false,
+            quote_style: QuoteStyle::Necessary,
             has_header: true,
             quote: b'"',
             escape: b'\\',
@@ -227,6 +393,7 @@ impl Default for WriterBuilder {
             timestamp_tz_format: None,
             time_format: None,
             null_value: None,
+            type_formatters: None,
         }
     }
 }
@@ -269,13 +436,67 @@ impl WriterBuilder {
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = delimiter;
+        self.delimiter = vec![delimiter];
         self
     }
 
     /// Get the CSV file's column delimiter as a byte character
+    ///
+    /// Returns the first byte of a multi-byte delimiter, see [`Self::delimiter_bytes`]
     pub fn delimiter(&self) -> u8 {
-        self.delimiter
+        self.delimiter[0]
+    }
+
+    /// Set the CSV file's column delimiter as a, possibly multi-byte, sequence of bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty
+    pub fn with_delimiter_bytes(mut self, delimiter: impl Into<Vec<u8>>) -> Self {
+        let delimiter = delimiter.into();
+        assert!(!delimiter.is_empty(), "delimiter must not be empty");
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Get the CSV file's column delimiter as a sequence of bytes
+    pub fn delimiter_bytes(&self) -> &[u8] {
+        &self.delimiter
+    }
+
+    /// Set the CSV file's record terminator as a byte character, e.g. `b'\0'`
+    pub fn with_terminator(mut self, terminator: u8) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Get the CSV file's record terminator as a byte character
+    pub fn terminator(&self) -> u8 {
+        self.terminator
+    }
+
+    /// Set whether to terminate records with `\r\n`, as required by RFC 4180
+    ///
+    /// When enabled, this takes precedence over [`Self::with_terminator`]
+    pub fn with_crlf(mut self, crlf: bool) -> Self {
+        self.crlf = crlf;
+        self
+    }
+
+    /// Returns `true` if records are terminated with `\r\n`
+    pub fn crlf(&self) -> bool {
+        self.crlf
+    }
+
+    /// Set the [`QuoteStyle`] used to decide which fields to quote
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Get the [`QuoteStyle`] used to decide which fields to quote
+    pub fn quote_style(&self) -> QuoteStyle {
+        self.quote_style
     }
 
     /// Set the CSV file's quote character as a byte character
@@ -389,15 +610,48 @@ impl WriterBuilder {
         self.null_value.as_deref().unwrap_or(DEFAULT_NULL_VALUE)
     }
 
+    /// Set formatting overrides for values of specific data types
+    pub fn with_type_formatters(mut self, type_formatters: TypeFormatters) -> Self {
+        self.type_formatters = Some(type_formatters);
+        self
+    }
+
+    /// Get the formatting overrides for values of specific data types, if any
+    pub fn type_formatters(&self) -> Option<&TypeFormatters> {
+        self.type_formatters.as_ref()
+    }
+
     /// Create a new `Writer`
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
-        let mut builder = csv::WriterBuilder::new();
-        let writer = builder
-            .delimiter(self.delimiter)
-            .quote(self.quote)
-            .double_quote(self.double_quote)
-            .escape(self.escape)
-            .from_writer(writer);
+        let writer = match self.delimiter.as_slice() {
+            [delimiter] => {
+                let terminator = match self.crlf {
+                    true => csv::Terminator::CRLF,
+                    false => csv::Terminator::Any(self.terminator),
+                };
+                let mut builder = csv::WriterBuilder::new();
+                let writer = builder
+                    .delimiter(*delimiter)
+                    .terminator(terminator)
+                    .quote_style(self.quote_style.into())
+                    .quote(self.quote)
+                    .double_quote(self.double_quote)
+                    .escape(self.escape)
+                    .from_writer(writer);
+                RecordWriter::Csv(Box::new(writer))
+            }
+            _ => RecordWriter::MultiByte(MultiByteWriter {
+                writer,
+                delimiter: self.delimiter,
+                terminator: self.terminator,
+                crlf: self.crlf,
+                quote_style: self.quote_style,
+                quote: self.quote,
+                escape: self.escape,
+                double_quote: self.double_quote,
+                buffer: Vec::with_capacity(MultiByteWriter::<W>::BUFFER_SIZE),
+            }),
+        };
         Writer {
             writer,
             beginning: true,
@@ -408,6 +662,7 @@ impl WriterBuilder {
             timestamp_format: self.timestamp_format,
             timestamp_tz_format: self.timestamp_tz_format,
             null_value: self.null_value,
+            type_formatters: self.type_formatters,
         }
     }
 }
@@ -421,6 +676,7 @@ mod tests {
         BinaryBuilder, Decimal128Builder, Decimal256Builder, FixedSizeBinaryBuilder,
         LargeBinaryBuilder,
     };
+    use arrow_array::cast::AsArray;
     use arrow_array::types::*;
     use arrow_buffer::i256;
     use core::str;
@@ -794,6 +1050,46 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555,23:46:03,foo
         );
     }
 
+    #[test]
+    fn test_write_csv_type_formatters() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Binary, true),
+            Field::new("c2", DataType::Date32, true),
+            Field::new("c3", DataType::Int32, true),
+        ]);
+        let c1 = BinaryArray::from(vec![Some(b"\x01\xff".as_slice()), None]);
+        let c2 = Date32Array::from(vec![None, Some(3)]);
+        let c3 = Int32Array::from(vec![Some(1), None]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(c1), Arc::new(c2), Arc::new(c3)],
+        )
+        .unwrap();
+
+        let formatters = TypeFormatters::new()
+            .with_formatter(DataType::Binary, |array, idx, f| {
+                let value = array.as_binary::<i32>().value(idx);
+                value.iter().try_for_each(|b| write!(f, "{b:02x}"))
+            })
+            .with_formatter(DataType::Date32, |array, idx, f| {
+                let value = array.as_primitive::<Date32Type>().value(idx);
+                write!(f, "day {value}")
+            });
+
+        let mut buf = Vec::new();
+        let mut writer = WriterBuilder::new()
+            .with_null("N/A".to_string())
+            .with_type_formatters(formatters)
+            .build(&mut buf);
+        writer.write(&batch).unwrap();
+        drop(writer);
+
+        assert_eq!(
+            "c1,c2,c3\n01ff,N/A,1\nN/A,day 3,N/A\n",
+            String::from_utf8(buf).unwrap()
+        );
+    }
+
     #[test]
     fn test_write_csv_binary() {
         let fixed_size = 8;
@@ -844,4 +1140,144 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555,23:46:03,foo
             String::from_utf8(buf).unwrap()
         );
     }
+
+    #[test]
+    fn test_write_csv_multi_byte_delimiter() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let a = StringArray::from(vec![Some("x|y"), Some("say \"hi\""), None, Some("a\nb")]);
+        let b = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)]).unwrap();
+
+        let mut writer = WriterBuilder::new()
+            .with_delimiter_bytes("||")
+            .build(Vec::new());
+        writer.write(&batch).unwrap();
+        let buf = writer.into_inner();
+        assert_eq!(
+            str::from_utf8(&buf).unwrap(),
+            "a||b\n\"x|y\"||1\n\"say \"\"hi\"\"\"||\n||3\n\"a\nb\"||4\n"
+        );
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_header(true)
+            .with_delimiter_bytes("||")
+            .build(buf.as_slice())
+            .unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn test_write_csv_terminator() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let a = StringArray::from(vec!["x\ny", "z\0"]);
+        let b = Int32Array::from(vec![1, 2]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)]).unwrap();
+
+        for delimiter in [",", "\t|"] {
+            let mut writer = WriterBuilder::new()
+                .with_delimiter_bytes(delimiter)
+                .with_terminator(b'\0')
+                .build(Vec::new());
+            writer.write(&batch).unwrap();
+            let buf = writer.into_inner();
+            let expected = format!("a{delimiter}b\0x\ny{delimiter}1\0\"z\0\"{delimiter}2\0");
+            assert_eq!(str::from_utf8(&buf).unwrap(), expected);
+
+            let mut reader = ReaderBuilder::new(schema.clone())
+                .with_header(true)
+                .with_delimiter_bytes(delimiter)
+                .with_terminator(b'\0')
+                .build(buf.as_slice())
+                .unwrap();
+            assert_eq!(reader.next().unwrap().unwrap(), batch);
+        }
+    }
+
+    #[test]
+    fn test_write_csv_quote_style() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Float64, true),
+        ]));
+        let a = StringArray::from(vec![Some("x,y"), Some("say \"hi\""), None]);
+        let b = Float64Array::from(vec![Some(1.5), None, Some(-3.0)]);
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(a), Arc::new(b)]).unwrap();
+
+        let cases = [
+            (
+                QuoteStyle::Necessary,
+                "a,b\n\"x,y\",1.5\n\"say \"\"hi\"\"\",\n,-3.0\n",
+            ),
+            (
+                QuoteStyle::Always,
+                "\"a\",\"b\"\n\"x,y\",\"1.5\"\n\"say \"\"hi\"\"\",\"\"\n\"\",\"-3.0\"\n",
+            ),
+            (
+                QuoteStyle::NonNumeric,
+                "\"a\",\"b\"\n\"x,y\",1.5\n\"say \"\"hi\"\"\",\"\"\n\"\",-3.0\n",
+            ),
+            (QuoteStyle::Never, "a,b\nx,y,1.5\nsay \"hi\",\n,-3.0\n"),
+        ];
+
+        for (style, expected) in cases {
+            for delimiter in [",", ",,"] {
+                let mut writer = WriterBuilder::new()
+                    .with_delimiter_bytes(delimiter)
+                    .with_quote_style(style)
+                    .build(Vec::new());
+                writer.write(&batch).unwrap();
+                let buf = writer.into_inner();
+                let expected = expected.replace(',', delimiter).replace("x,,y", "x,y");
+                assert_eq!(str::from_utf8(&buf).unwrap(), expected, "{style:?}");
+            }
+        }
+
+        let mut writer = WriterBuilder::new()
+            .with_quote_style(QuoteStyle::Always)
+            .with_double_quote(false)
+            .build(Vec::new());
+        writer.write(&batch).unwrap();
+        let buf = writer.into_inner();
+        assert_eq!(
+            str::from_utf8(&buf).unwrap(),
+            "\"a\",\"b\"\n\"x,y\",\"1.5\"\n\"say \\\"hi\\\"\",\"\"\n\"\",\"-3.0\"\n"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_crlf() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let a = StringArray::from(vec!["x\ry", "z"]);
+        let b = Int32Array::from(vec![1, 2]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)]).unwrap();
+
+        for delimiter in [",", "::"] {
+            let mut writer = WriterBuilder::new()
+                .with_delimiter_bytes(delimiter)
+                .with_terminator(b'\0')
+                .with_crlf(true)
+                .build(Vec::new());
+            writer.write(&batch).unwrap();
+            let buf = writer.into_inner();
+            let expected = format!("a{delimiter}b\r\n\"x\ry\"{delimiter}1\r\nz{delimiter}2\r\n");
+            assert_eq!(str::from_utf8(&buf).unwrap(), expected);
+
+            let mut reader = ReaderBuilder::new(schema.clone())
+                .with_header(true)
+                .with_delimiter_bytes(delimiter)
+                .build(buf.as_slice())
+                .unwrap();
+            assert_eq!(reader.next().unwrap().unwrap(), batch);
+        }
+    }
 }
@@ -139,7 +139,10 @@ pub(crate) fn new_buffers(data_type: &DataType, capacity: usize) -> [MutableBuff
         DataType::FixedSizeList(_, _) | DataType::Struct(_) | DataType::RunEndEncoded(_, _) => {
             [empty_buffer, MutableBuffer::new(0)]
         }
-        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => [
+        DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => [
             MutableBuffer::new(capacity * mem::size_of::<u8>()),
             empty_buffer,
         ],
@@ -944,7 +947,7 @@ impl ArrayData {
     ) -> Result<(), ArrowError> {
         let offsets: &[T] = self.typed_buffer(0, self.len)?;
         let sizes: &[T] = self.typed_buffer(1, self.len)?;
-        for i in 0..values_length {
+        for i in 0..self.len {
             let size = sizes[i].to_usize().ok_or_else(|| {
                 ArrowError::InvalidArgumentError(format!(
                     "Error converting size[{}] ({}) to usize for {}",
@@ -1130,10 +1133,47 @@ impl ArrayData {
             )));
         }
 
-        values_data.validate()?;
+        values_data.validate().map_err(|e| self.child_error(i, e))?;
         Ok(values_data)
     }
 
+    /// Returns the name of `child_data[i]` as used in validation errors, the name of
+    /// its field if any, otherwise its index
+    fn child_name(&self, i: usize) -> String {
+        let field = match &self.data_type {
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::ListView(f)
+            | DataType::LargeListView(f)
+            | DataType::FixedSizeList(f, _)
+            | DataType::Map(f, _) => Some(f),
+            DataType::Struct(fields) => fields.get(i),
+            DataType::Union(fields, _) => fields.iter().nth(i).map(|(_, f)| f),
+            DataType::RunEndEncoded(run_ends, values) => [run_ends, values].get(i).copied(),
+            _ => None,
+        };
+        match field {
+            Some(f) => format!("'{}'", f.name()),
+            None => format!("#{i}"),
+        }
+    }
+
+    /// Adds the path of `child_data[i]` to the validation error `e` of that child
+    ///
+    /// Errors of nested children are reported as `Invalid child 'a'.'b': {error}`
+    fn child_error(&self, i: usize, e: ArrowError) -> ArrowError {
+        let name = self.child_name(i);
+        match e {
+            ArrowError::InvalidArgumentError(msg) => match msg.strip_prefix("Invalid child ") {
+                Some(path) => {
+                    ArrowError::InvalidArgumentError(format!("Invalid child {name}.{path}"))
+                }
+                None => ArrowError::InvalidArgumentError(format!("Invalid child {name}: {msg}")),
+            },
+            e => e,
+        }
+    }
+
     /// Validate that the data contained within this [`ArrayData`] is valid
     ///
     /// 1. Null count is correct
@@ -1161,21 +1201,74 @@ impl ArrayData {
     ///
     /// This is equivalent to calling [`Self::validate_data`] on this [`ArrayData`]
     /// and all its children recursively
+    ///
+    /// Errors in children are prefixed with the path of the child, for example
+    /// `Invalid child 'a'.'item': Offset invariant failure: offset at position 17 out of bounds: 20 > 10`
     pub fn validate_full(&self) -> Result<(), ArrowError> {
         self.validate_data()?;
-        // validate all children recursively
+        self.validate_children(Self::validate_full)
+    }
+
+    /// Validates this [`ArrayData`] and all its children to the given [`ValidationLevel`]
+    ///
+    /// ```
+    /// # use arrow_buffer::Buffer;
+    /// # use arrow_data::{ArrayData, ValidationLevel};
+    /// # use arrow_schema::DataType;
+    /// # // Invalid data cannot be constructed with force_validate
+    /// # #[cfg(not(feature = "force_validate"))]
+    /// # {
+    /// // Offsets [0, 2] into the invalid UTF-8 values [0xFF, 0xFF]
+    /// let data = unsafe {
+    ///     ArrayData::new_unchecked(
+    ///         DataType::Utf8,
+    ///         1,
+    ///         None,
+    ///         None,
+    ///         0,
+    ///         vec![Buffer::from_slice_ref([0_i32, 2]), Buffer::from_slice_ref([0xFF_u8, 0xFF])],
+    ///         vec![],
+    ///     )
+    /// };
+    /// data.validate_with_level(ValidationLevel::Offsets).unwrap();
+    /// assert!(data.validate_with_level(ValidationLevel::Full).is_err());
+    /// # }
+    /// ```
+    pub fn validate_with_level(&self, level: ValidationLevel) -> Result<(), ArrowError> {
+        match level {
+            ValidationLevel::Structure => self.validate(),
+            ValidationLevel::Offsets => self.validate_offsets_recursive(),
+            ValidationLevel::Full => self.validate_full(),
+        }
+    }
+
+    /// Validates this [`ArrayData`] and all its children to [`ValidationLevel::Offsets`]
+    fn validate_offsets_recursive(&self) -> Result<(), ArrowError> {
+        self.validate()?;
+        match &self.data_type {
+            DataType::Utf8 => self.validate_offsets_full::<i32>(self.buffers[1].len())?,
+            DataType::LargeUtf8 => self.validate_offsets_full::<i64>(self.buffers[1].len())?,
+            DataType::Utf8View => {
+                let views = self.typed_buffer::<u128>(0, self.len)?;
+                validate_binary_view(views, &self.buffers[1..])?
+            }
+            _ => self.validate_values()?,
+        }
+        self.validate_children(Self::validate_offsets_recursive)
+    }
+
+    /// Calls `validate` on each child of this [`ArrayData`], adding the path of the
+    /// child to any error
+    fn validate_children(
+        &self,
+        validate: fn(&ArrayData) -> Result<(), ArrowError>,
+    ) -> Result<(), ArrowError> {
         self.child_data
             .iter()
             .enumerate()
             .try_for_each(|(i, child_data)| {
-                child_data.validate_full().map_err(|e| {
-                    ArrowError::InvalidArgumentError(format!(
-                        "{} child #{} invalid: {}",
-                        self.data_type, i, e
-                    ))
-                })
-            })?;
-        Ok(())
+                validate(child_data).map_err(|e| self.child_error(i, e))
+            })
     }
 
     /// Validates the values stored within this [`ArrayData`] are valid
@@ -1582,6 +1675,8 @@ pub fn layout(data_type: &DataType) -> DataTypeLayout {
             DataTypeLayout::new_fixed_width::<IntervalMonthDayNano>()
         }
         DataType::Duration(_) => DataTypeLayout::new_fixed_width::<i64>(),
+        DataType::Decimal32(_, _) => DataTypeLayout::new_fixed_width::<i32>(),
+        DataType::Decimal64(_, _) => DataTypeLayout::new_fixed_width::<i64>(),
         DataType::Decimal128(_, _) => DataTypeLayout::new_fixed_width::<i128>(),
         DataType::Decimal256(_, _) => DataTypeLayout::new_fixed_width::<i256>(),
         DataType::FixedSizeBinary(size) => {
@@ -1637,6 +1732,24 @@ pub fn layout(data_type: &DataType) -> DataTypeLayout {
     }
 }
 
+/// How much of an [`ArrayData`] is checked by [`ArrayData::validate_with_level`]
+///
+/// Each level includes the checks of the levels before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationLevel {
+    /// Checks the structure of the data, such as buffer sizes and alignment and the
+    /// number and types of children, without inspecting the buffer contents, as
+    /// [`ArrayData::validate`]
+    Structure,
+    /// Additionally checks that all offsets, dictionary keys, views and run ends are
+    /// within bounds, so that the values can be accessed safely, without validating UTF-8
+    Offsets,
+    /// Additionally checks that string data is valid UTF-8, that null counts are
+    /// correct and that non-nullable children contain no nulls, as
+    /// [`ArrayData::validate_full`]
+    Full,
+}
+
 /// Layout specification for a data type
 #[derive(Debug, PartialEq, Eq)]
 // Note: Follows structure from C++: https://github.com/apache/arrow/blob/master/cpp/src/arrow/type.h#L91
@@ -2269,7 +2382,7 @@ mod tests {
 
         assert_eq!(
             err.to_string(),
-            "Invalid argument error: Misaligned buffers[0] in array of type Int32, offset from expected alignment of 4 by 1"
+            "Invalid argument error: Invalid child 'a': Misaligned buffers[0] in array of type Int32, offset from expected alignment of 4 by 1"
         );
 
         data.align_buffers();
@@ -15,7 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines maximum and minimum values for `decimal256` and `decimal128` types for varying precisions.
+//! Defines maximum and minimum values for `decimal256`, `decimal128`, `decimal64` and
+//! `decimal32` types for varying precisions.
 //!
 //! Also provides functions to validate if a given decimal value is within the valid range of the decimal type.
 
@@ -24,7 +25,8 @@ use arrow_schema::ArrowError;
 
 pub use arrow_schema::{
     DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
-    DECIMAL_DEFAULT_SCALE,
+    DECIMAL32_DEFAULT_SCALE, DECIMAL32_MAX_PRECISION, DECIMAL32_MAX_SCALE, DECIMAL64_DEFAULT_SCALE,
+    DECIMAL64_MAX_PRECISION, DECIMAL64_MAX_SCALE, DECIMAL_DEFAULT_SCALE,
 };
 
 /// MAX decimal256 value of little-endian format for each precision.
@@ -833,6 +835,142 @@ pub(crate) const MIN_DECIMAL_FOR_EACH_PRECISION_ONE_BASED: [i128; 39] = [
     -99999999999999999999999999999999999999,
 ];
 
+/// `MAX_DECIMAL32_FOR_EACH_PRECISION[p]` holds the maximum `i32` value that can
+/// be stored in a [arrow_schema::DataType::Decimal32] value of precision `p`.
+/// The first element is unused and is inserted so that we can look up using
+/// precision as the index without the need to subtract 1 first.
+pub const MAX_DECIMAL32_FOR_EACH_PRECISION: [i32; 10] = [
+    0, // unused first element
+    9, 99, 999, 9999, 99999, 999999, 9999999, 99999999, 999999999,
+];
+
+/// `MIN_DECIMAL32_FOR_EACH_PRECISION[p]` holds the minimum `i32` value that can
+/// be stored in a [arrow_schema::DataType::Decimal32] value of precision `p`.
+/// The first element is unused and is inserted so that we can look up using
+/// precision as the index without the need to subtract 1 first.
+pub const MIN_DECIMAL32_FOR_EACH_PRECISION: [i32; 10] = [
+    0, // unused first element
+    -9, -99, -999, -9999, -99999, -999999, -9999999, -99999999, -999999999,
+];
+
+/// `MAX_DECIMAL64_FOR_EACH_PRECISION[p]` holds the maximum `i64` value that can
+/// be stored in a [arrow_schema::DataType::Decimal64] value of precision `p`.
+/// The first element is unused and is inserted so that we can look up using
+/// precision as the index without the need to subtract 1 first.
+pub const MAX_DECIMAL64_FOR_EACH_PRECISION: [i64; 19] = [
+    0, // unused first element
+    9,
+    99,
+    999,
+    9999,
+    99999,
+    999999,
+    9999999,
+    99999999,
+    999999999,
+    9999999999,
+    99999999999,
+    999999999999,
+    9999999999999,
+    99999999999999,
+    999999999999999,
+    9999999999999999,
+    99999999999999999,
+    999999999999999999,
+];
+
+/// `MIN_DECIMAL64_FOR_EACH_PRECISION[p]` holds the minimum `i64` value that can
+/// be stored in a [arrow_schema::DataType::Decimal64] value of precision `p`.
+/// The first element is unused and is inserted so that we can look up using
+/// precision as the index without the need to subtract 1 first.
+pub const MIN_DECIMAL64_FOR_EACH_PRECISION: [i64; 19] = [
+    0, // unused first element
+    -9,
+    -99,
+    -999,
+    -9999,
+    -99999,
+    -999999,
+    -9999999,
+    -99999999,
+    -999999999,
+    -9999999999,
+    -99999999999,
+    -999999999999,
+    -9999999999999,
+    -99999999999999,
+    -999999999999999,
+    -9999999999999999,
+    -99999999999999999,
+    -999999999999999999,
+];
+
+/// Validates that the specified `i32` value can be properly
+/// interpreted as a Decimal32 number with precision `precision`
+#[inline]
+pub fn validate_decimal32_precision(value: i32, precision: u8) -> Result<(), ArrowError> {
+    if precision > DECIMAL32_MAX_PRECISION {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Max precision of a Decimal32 is {DECIMAL32_MAX_PRECISION}, but got {precision}",
+        )));
+    }
+    if value > MAX_DECIMAL32_FOR_EACH_PRECISION[precision as usize] {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too large to store in a Decimal32 of precision {precision}. Max is {}",
+            MAX_DECIMAL32_FOR_EACH_PRECISION[precision as usize]
+        )))
+    } else if value < MIN_DECIMAL32_FOR_EACH_PRECISION[precision as usize] {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too small to store in a Decimal32 of precision {precision}. Min is {}",
+            MIN_DECIMAL32_FOR_EACH_PRECISION[precision as usize]
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Determines whether the specified `i32` value can be properly
+/// interpreted as a Decimal32 number with precision `precision`
+#[inline]
+pub fn is_validate_decimal32_precision(value: i32, precision: u8) -> bool {
+    precision <= DECIMAL32_MAX_PRECISION
+        && value >= MIN_DECIMAL32_FOR_EACH_PRECISION[precision as usize]
+        && value <= MAX_DECIMAL32_FOR_EACH_PRECISION[precision as usize]
+}
+
+/// Validates that the specified `i64` value can be properly
+/// interpreted as a Decimal64 number with precision `precision`
+#[inline]
+pub fn validate_decimal64_precision(value: i64, precision: u8) -> Result<(), ArrowError> {
+    if precision > DECIMAL64_MAX_PRECISION {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Max precision of a Decimal64 is {DECIMAL64_MAX_PRECISION}, but got {precision}",
+        )));
+    }
+    if value > MAX_DECIMAL64_FOR_EACH_PRECISION[precision as usize] {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too large to store in a Decimal64 of precision {precision}. Max is {}",
+            MAX_DECIMAL64_FOR_EACH_PRECISION[precision as usize]
+        )))
+    } else if value < MIN_DECIMAL64_FOR_EACH_PRECISION[precision as usize] {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too small to store in a Decimal64 of precision {precision}. Min is {}",
+            MIN_DECIMAL64_FOR_EACH_PRECISION[precision as usize]
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Determines whether the specified `i64` value can be properly
+/// interpreted as a Decimal64 number with precision `precision`
+#[inline]
+pub fn is_validate_decimal64_precision(value: i64, precision: u8) -> bool {
+    precision <= DECIMAL64_MAX_PRECISION
+        && value >= MIN_DECIMAL64_FOR_EACH_PRECISION[precision as usize]
+        && value <= MAX_DECIMAL64_FOR_EACH_PRECISION[precision as usize]
+}
+
 /// Validates that the specified `i128` value can be properly
 /// interpreted as a Decimal number with precision `precision`
 #[inline]
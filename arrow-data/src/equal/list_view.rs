@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::data::ArrayData;
+use arrow_buffer::ArrowNativeType;
+
+use super::equal_range;
+
+pub(super) fn list_view_equal<T: ArrowNativeType>(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    lhs_start: usize,
+    rhs_start: usize,
+    len: usize,
+) -> bool {
+    let lhs_offsets = lhs.buffer::<T>(0);
+    let lhs_sizes = lhs.buffer::<T>(1);
+    let rhs_offsets = rhs.buffer::<T>(0);
+    let rhs_sizes = rhs.buffer::<T>(1);
+
+    let lhs_values = &lhs.child_data()[0];
+    let rhs_values = &rhs.child_data()[0];
+
+    // Only checking one null mask here because by the time the control flow reaches
+    // this point, the equality of the two masks would have already been verified.
+    let lhs_nulls = lhs.nulls();
+
+    // As views may overlap or be out of order, compare each view in turn
+    (0..len).all(|i| {
+        let lhs_pos = lhs_start + i;
+        let rhs_pos = rhs_start + i;
+
+        if lhs_nulls.map(|n| n.is_null(lhs_pos)).unwrap_or_default() {
+            return true;
+        }
+
+        let size = lhs_sizes[lhs_pos].as_usize();
+        size == rhs_sizes[rhs_pos].as_usize()
+            && equal_range(
+                lhs_values,
+                rhs_values,
+                lhs_offsets[lhs_pos].as_usize(),
+                rhs_offsets[rhs_pos].as_usize(),
+                size,
+            )
+    })
+}
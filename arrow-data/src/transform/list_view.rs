@@ -0,0 +1,49 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::{_MutableArrayData, Extend};
+use crate::ArrayData;
+use arrow_buffer::ArrowNativeType;
+use num::Integer;
+
+/// Copies the values of each list view into the child data, so that the
+/// views of the output never overlap, even if those of the input do
+pub(super) fn build_extend<T: ArrowNativeType + Integer>(array: &ArrayData) -> Extend<'_> {
+    let offsets = array.buffer::<T>(0);
+    let sizes = array.buffer::<T>(1);
+    Box::new(
+        move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
+            for i in start..start + len {
+                let offset = offsets[i].as_usize();
+                let size = sizes[i].as_usize();
+                let child = &mut mutable.child_data[0];
+                let new_offset = T::from_usize(child.len()).expect("list view offset overflow");
+                child.extend(index, offset, offset + size);
+                mutable.buffer1.push(new_offset);
+                mutable.buffer2.push(sizes[i]);
+            }
+        },
+    )
+}
+
+pub(super) fn extend_nulls<T: ArrowNativeType>(mutable: &mut _MutableArrayData, len: usize) {
+    let offset = T::from_usize(mutable.child_data[0].len()).expect("list view offset overflow");
+    (0..len).for_each(|_| {
+        mutable.buffer1.push(offset);
+        mutable.buffer2.push(T::usize_as(0));
+    })
+}
@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Derive macros for converting Rust structs to and from Arrow `RecordBatch`
+//!
+//! This crate is not intended to be used directly, instead enable the `derive`
+//! feature of the `arrow` crate and use `arrow::array::record::ArrowRecord`
+
+#![warn(missing_docs)]
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, LitStr, Path};
+
+/// Derive `ArrowField` and `ArrowRecord` for a struct with named fields
+///
+/// Each field of the struct becomes a column, named after the field, whose type is
+/// given by the `ArrowField` implementation of the field's type. Fields that are
+/// themselves structs deriving `ArrowRecord` are encoded as `DataType::Struct`.
+///
+/// The generated code refers to `::arrow::array::record`. Crates depending on
+/// `arrow-array` directly can override this with `#[arrow(crate = "arrow_array")]`.
+///
+/// ```ignore
+/// use arrow::array::record::ArrowRecord;
+/// use arrow::record_batch::RecordBatch;
+///
+/// #[derive(ArrowRecord)]
+/// struct Measurement {
+///     sensor: String,
+///     timestamp: chrono::NaiveDateTime,
+///     readings: Vec<Option<f64>>,
+/// }
+///
+/// fn to_batch(measurements: &[Measurement]) -> RecordBatch {
+///     RecordBatch::try_from(measurements).unwrap()
+/// }
+/// ```
+#[proc_macro_derive(ArrowRecord, attributes(arrow))]
+pub fn arrow_record(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_arrow_record(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn derive_arrow_record(input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate_path(&input)?;
+    let record = quote!(#krate::record);
+    let private = quote!(#record::__private);
+
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "ArrowRecord can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let names: Vec<_> = fields.iter().map(|f| f.ident.clone().unwrap()).collect();
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let columns: Vec<_> = names.iter().map(|n| n.to_string()).collect();
+    let iters: Vec<_> = names.iter().map(|n| format_ident!("__{}", n)).collect();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #record::ArrowField for #ident #ty_generics #where_clause {
+            fn data_type() -> #private::DataType {
+                #private::DataType::Struct(<Self as #record::ArrowRecord>::fields())
+            }
+
+            fn to_array<'__a, __I>(
+                __values: __I,
+            ) -> ::std::result::Result<#private::ArrayRef, #private::ArrowError>
+            where
+                __I: ::std::iter::Iterator<Item = ::std::option::Option<&'__a Self>>,
+                Self: '__a,
+            {
+                let __values: ::std::vec::Vec<_> = __values.collect();
+                let __columns = ::std::vec![#(
+                    <#types as #record::ArrowField>::to_array(
+                        __values.iter().map(|__v| __v.map(|__v| &__v.#names)),
+                    )?
+                ),*];
+                #record::to_struct_array(
+                    <Self as #record::ArrowRecord>::fields(),
+                    __columns,
+                    __values.iter().map(::std::option::Option::is_some),
+                )
+            }
+
+            fn from_array(
+                __array: &dyn #private::Array,
+            ) -> ::std::result::Result<
+                ::std::vec::Vec<::std::option::Option<Self>>,
+                #private::ArrowError,
+            > {
+                let __array = #record::downcast_array::<#private::StructArray>(
+                    __array,
+                    &<Self as #record::ArrowField>::data_type(),
+                )?;
+                #(
+                    let mut #iters = <#types as #record::ArrowField>::from_array(
+                        #record::struct_column(__array, #columns)?,
+                    )?
+                    .into_iter();
+                )*
+                (0..#private::Array::len(__array))
+                    .map(|__idx| {
+                        #(let #names = #iters.next();)*
+                        if #private::Array::is_null(__array, __idx) {
+                            return ::std::result::Result::Ok(::std::option::Option::None);
+                        }
+                        ::std::result::Result::Ok(::std::option::Option::Some(Self {
+                            #(#names: #record::required(#names, #columns)?),*
+                        }))
+                    })
+                    .collect()
+            }
+        }
+
+        impl #impl_generics #record::ArrowRecord for #ident #ty_generics #where_clause {
+            fn fields() -> #private::Fields {
+                #private::Fields::from(::std::vec![
+                    #(#record::field::<#types>(#columns)),*
+                ])
+            }
+        }
+    })
+}
+
+/// Returns the path to the `arrow-array` crate from `#[arrow(crate = "...")]`
+fn crate_path(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let mut path = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("arrow")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                let value: LitStr = meta.value()?.parse()?;
+                path = Some(value.parse::<Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported arrow attribute"))
+            }
+        })?;
+    }
+    Ok(match path {
+        Some(path) => quote!(#path),
+        None => quote!(::arrow::array),
+    })
+}
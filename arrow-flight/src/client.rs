@@ -17,25 +17,166 @@
 
 use crate::{
     decode::FlightRecordBatchStream,
+    encode::FlightDataEncoderBuilder,
     flight_service_client::FlightServiceClient,
     gen::{CancelFlightInfoRequest, CancelFlightInfoResult, RenewFlightEndpointRequest},
     trailers::extract_lazy_trailers,
-    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
-    HandshakeRequest, PollInfo, PutResult, Ticket,
+    Action, ActionType, CloseSessionRequest, CloseSessionResult, Criteria, Empty, FlightData,
+    FlightDescriptor, FlightEndpoint, FlightInfo, GetSessionOptionsRequest,
+    GetSessionOptionsResult, HandshakeRequest, PollInfo, PutResult, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket,
 };
-use arrow_schema::Schema;
+use arrow_array::RecordBatch;
+use arrow_schema::{Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{
-    future::ready,
+    future::{ready, Either},
     stream::{self, BoxStream},
-    Stream, StreamExt, TryStreamExt,
+    Future, Stream, StreamExt, TryStreamExt,
 };
 use prost::Message;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tonic::{metadata::MetadataMap, transport::Channel};
 
 use crate::error::{FlightError, Result};
 use crate::streams::{FallibleRequestStream, FallibleTonicResponseStream};
 
+/// Hooks invoked by a [`FlightClient`] around each gRPC call it makes
+///
+/// Interceptors can be used, for example, to refresh expired bearer tokens,
+/// inject tracing headers or observe the headers returned by the server.
+/// They are registered with [`FlightClient::add_interceptor`] and are
+/// invoked in registration order.
+///
+/// The `method` passed to each hook is the name of the gRPC method being
+/// called, for example `"Handshake"`, `"GetFlightInfo"` or `"DoGet"`.
+///
+/// # Example:
+/// ```
+/// # use arrow_flight::client::FlightClientInterceptor;
+/// # use arrow_flight::error::{FlightError, Result};
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use tonic::metadata::MetadataMap;
+/// /// Adds a `x-request-id` header to every request
+/// #[derive(Debug, Default)]
+/// struct RequestId {
+///     next: AtomicUsize,
+/// }
+///
+/// impl FlightClientInterceptor for RequestId {
+///     fn on_request(&self, _method: &str, metadata: &mut MetadataMap) -> Result<()> {
+///         let id = self.next.fetch_add(1, Ordering::Relaxed);
+///         let value = id
+///             .to_string()
+///             .parse()
+///             .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+///         metadata.insert("x-request-id", value);
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait FlightClientInterceptor: std::fmt::Debug + Send + Sync {
+    /// Invoked before each request is sent with the gRPC metadata of the
+    /// request, which may be modified
+    ///
+    /// Returning an error aborts the call without contacting the server
+    fn on_request(&self, _method: &str, _metadata: &mut MetadataMap) -> Result<()> {
+        Ok(())
+    }
+
+    /// Invoked with the headers of each response, or with the metadata
+    /// of the [`tonic::Status`] if the call failed
+    fn on_response(&self, _method: &str, _metadata: &MetadataMap) {}
+}
+
+/// A [`FlightClientInterceptor`] that stores the cookies set by the server
+/// with `set-cookie` headers, and sends them in the `cookie` header of each
+/// subsequent request
+///
+/// Servers typically use a cookie, such as `arrow_flight_session_id`, to
+/// track the session configured with [`FlightClient::set_session_options`].
+/// Cookies set with `Max-Age=0` or an empty value are removed.
+///
+/// # Example:
+/// ```no_run
+/// # async fn run() {
+/// # use std::sync::Arc;
+/// # use arrow_flight::{FlightClient, SetSessionOptionsRequest};
+/// # use arrow_flight::client::CookieInterceptor;
+/// # let channel: tonic::transport::Channel = unimplemented!();
+/// let mut client = FlightClient::new(channel);
+/// client.add_interceptor(Arc::new(CookieInterceptor::new()));
+///
+/// // The session cookie returned by the server is sent with subsequent calls
+/// let request = SetSessionOptionsRequest::new([("catalog", "sales".into())]);
+/// client.set_session_options(request).await.expect("error setting options");
+/// let options = client.get_session_options().await.expect("error getting options");
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct CookieInterceptor {
+    cookies: Mutex<BTreeMap<String, String>>,
+}
+
+impl CookieInterceptor {
+    /// Create a new [`CookieInterceptor`] without any cookies
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the value of the cookie `name`, if set by the server
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        self.cookies.lock().unwrap().get(name).cloned()
+    }
+
+    /// Remove all cookies, for example to start a new session
+    pub fn clear(&self) {
+        self.cookies.lock().unwrap().clear()
+    }
+}
+
+impl FlightClientInterceptor for CookieInterceptor {
+    fn on_request(&self, _method: &str, metadata: &mut MetadataMap) -> Result<()> {
+        let cookies = self.cookies.lock().unwrap();
+        if cookies.is_empty() {
+            return Ok(());
+        }
+        let cookie = cookies
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let cookie = cookie
+            .parse()
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        metadata.insert("cookie", cookie);
+        Ok(())
+    }
+
+    fn on_response(&self, _method: &str, metadata: &MetadataMap) {
+        let mut cookies = self.cookies.lock().unwrap();
+        for set_cookie in metadata.get_all("set-cookie") {
+            let set_cookie = match set_cookie.to_str() {
+                Ok(set_cookie) => set_cookie,
+                Err(_) => continue,
+            };
+            let mut parts = set_cookie.split(';').map(str::trim);
+            let (name, value) = match parts.next().and_then(|p| p.split_once('=')) {
+                Some((name, value)) => (name.trim(), value.trim()),
+                None => continue,
+            };
+            let expired = parts.any(|attr| attr.eq_ignore_ascii_case("max-age=0"));
+            if expired || value.is_empty() {
+                cookies.remove(name);
+            } else {
+                cookies.insert(name.to_string(), value.to_string());
+            }
+        }
+    }
+}
+
 /// A "Mid level" [Apache Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) client.
 ///
 /// [`FlightClient`] is intended as a convenience for interactions
@@ -71,6 +212,9 @@ pub struct FlightClient {
     /// Optional grpc header metadata to include with each request
     metadata: MetadataMap,
 
+    /// Interceptors invoked around each call
+    interceptors: Vec<Arc<dyn FlightClientInterceptor>>,
+
     /// The inner client
     inner: FlightServiceClient<Channel>,
 }
@@ -85,6 +229,7 @@ impl FlightClient {
     pub fn new_from_inner(inner: FlightServiceClient<Channel>) -> Self {
         Self {
             metadata: MetadataMap::new(),
+            interceptors: vec![],
             inner,
         }
     }
@@ -118,6 +263,17 @@ impl FlightClient {
         Ok(())
     }
 
+    /// Register an [`FlightClientInterceptor`] to be invoked around
+    /// all subsequent calls, after any previously registered interceptors
+    pub fn add_interceptor(&mut self, interceptor: Arc<dyn FlightClientInterceptor>) {
+        self.interceptors.push(interceptor);
+    }
+
+    /// Return the [`FlightClientInterceptor`]s invoked around each call
+    pub fn interceptors(&self) -> &[Arc<dyn FlightClientInterceptor>] {
+        &self.interceptors
+    }
+
     /// Return a reference to the underlying tonic
     /// [`FlightServiceClient`]
     pub fn inner(&self) -> &FlightServiceClient<Channel> {
@@ -149,9 +305,10 @@ impl FlightClient {
         };
 
         // apply headers, etc
-        let request = self.make_request(stream::once(ready(request)));
+        let request = self.make_request("Handshake", stream::once(ready(request)))?;
 
-        let mut response_stream = self.inner.handshake(request).await?.into_inner();
+        let response = self.inner.handshake(request).await;
+        let mut response_stream = self.intercept_response("Handshake", response)?.into_inner();
 
         if let Some(response) = response_stream.next().await.transpose()? {
             // check if there is another response
@@ -169,7 +326,7 @@ impl FlightClient {
 
     /// Make a `DoGet` call to the server with the provided ticket,
     /// returning a [`FlightRecordBatchStream`] for reading
-    /// [`RecordBatch`](arrow_array::RecordBatch)es.
+    /// [`RecordBatch`]es.
     ///
     /// # Note
     ///
@@ -204,9 +361,10 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn do_get(&mut self, ticket: Ticket) -> Result<FlightRecordBatchStream> {
-        let request = self.make_request(ticket);
+        let request = self.make_request("DoGet", ticket)?;
 
-        let (md, response_stream, _ext) = self.inner.do_get(request).await?.into_parts();
+        let response = self.inner.do_get(request).await;
+        let (md, response_stream, _ext) = self.intercept_response("DoGet", response)?.into_parts();
         let (response_stream, trailers) = extract_lazy_trailers(response_stream);
 
         Ok(FlightRecordBatchStream::new_from_flight_data(
@@ -252,9 +410,12 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn get_flight_info(&mut self, descriptor: FlightDescriptor) -> Result<FlightInfo> {
-        let request = self.make_request(descriptor);
+        let request = self.make_request("GetFlightInfo", descriptor)?;
 
-        let response = self.inner.get_flight_info(request).await?.into_inner();
+        let response = self.inner.get_flight_info(request).await;
+        let response = self
+            .intercept_response("GetFlightInfo", response)?
+            .into_inner();
         Ok(response)
     }
 
@@ -310,12 +471,102 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn poll_flight_info(&mut self, descriptor: FlightDescriptor) -> Result<PollInfo> {
-        let request = self.make_request(descriptor);
+        let request = self.make_request("PollFlightInfo", descriptor)?;
 
-        let response = self.inner.poll_flight_info(request).await?.into_inner();
+        let response = self.inner.poll_flight_info(request).await;
+        let response = self
+            .intercept_response("PollFlightInfo", response)?
+            .into_inner();
         Ok(response)
     }
 
+    /// Make `PollFlightInfo` calls to the server until the query described
+    /// by the provided [`FlightDescriptor`] completes, and return the
+    /// complete [`FlightInfo`].
+    ///
+    /// While the query is running, this waits `poll_interval` after each
+    /// [`PollInfo`] and then polls again with the retry descriptor provided
+    /// by the server. An error is returned if the `expiration_time` of the
+    /// retry descriptor passes before the query completes.
+    ///
+    /// If `cancel` completes while waiting between polls, the query is
+    /// cancelled with [`Self::cancel_flight_info`], provided the server
+    /// returned a partial [`FlightInfo`] to cancel, and a
+    /// [`tonic::Code::Cancelled`] error is returned. Use
+    /// [`futures::future::pending`] to poll without cancellation.
+    ///
+    /// Waiting requires a tokio runtime with the time driver enabled.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use std::time::Duration;
+    /// # use arrow_flight::FlightClient;
+    /// # use arrow_flight::FlightDescriptor;
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// // Send a 'CMD' request to the server, giving up after a minute
+    /// let request = FlightDescriptor::new_cmd(b"MOAR DATA".to_vec());
+    /// let timeout = tokio::time::sleep(Duration::from_secs(60));
+    /// let flight_info = client
+    ///   .poll_until_complete(request, Duration::from_millis(100), timeout)
+    ///   .await
+    ///   .expect("error polling");
+    ///
+    /// // retrieve the first endpoint from the returned flight info
+    /// let ticket = flight_info
+    ///   .endpoint[0]
+    ///   // Extract the ticket
+    ///   .ticket
+    ///   .clone()
+    ///   .expect("expected ticket");
+    /// # }
+    /// ```
+    pub async fn poll_until_complete(
+        &mut self,
+        descriptor: FlightDescriptor,
+        poll_interval: Duration,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<FlightInfo> {
+        let mut cancel = Box::pin(cancel);
+        let mut descriptor = descriptor;
+        loop {
+            let poll_info = self.poll_flight_info(descriptor).await?;
+            descriptor = match poll_info.flight_descriptor {
+                Some(descriptor) => descriptor,
+                None => {
+                    return poll_info.info.ok_or_else(|| {
+                        FlightError::protocol("Received no FlightInfo for completed PollFlightInfo")
+                    })
+                }
+            };
+
+            if let Some(expiration_time) = &poll_info.expiration_time {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default();
+                let now = (now.as_secs() as i64, now.subsec_nanos() as i32);
+                if now >= (expiration_time.seconds, expiration_time.nanos) {
+                    return Err(FlightError::protocol(
+                        "PollFlightInfo retry descriptor expired before the query completed",
+                    ));
+                }
+            }
+
+            let sleep = Box::pin(tokio::time::sleep(poll_interval));
+            if let Either::Right(_) = futures::future::select(sleep, cancel.as_mut()).await {
+                if let Some(info) = poll_info.info {
+                    self.cancel_flight_info(CancelFlightInfoRequest::new(info))
+                        .await?;
+                }
+                return Err(FlightError::Tonic(tonic::Status::cancelled(
+                    "PollFlightInfo cancelled before the query completed",
+                )));
+            }
+        }
+    }
+
     /// Make a `DoPut` call to the server with the provided
     /// [`Stream`] of [`FlightData`] and returning a
     /// stream of [`PutResult`].
@@ -368,8 +619,9 @@ impl FlightClient {
         let request = Box::pin(request); // Pin to heap
         let request_stream = FallibleRequestStream::new(sender, request);
 
-        let request = self.make_request(request_stream);
-        let response_stream = self.inner.do_put(request).await?.into_inner();
+        let request = self.make_request("DoPut", request_stream)?;
+        let response = self.inner.do_put(request).await;
+        let response_stream = self.intercept_response("DoPut", response)?.into_inner();
 
         // Forwards errors from the error oneshot with priority over responses from server
         let response_stream = Box::pin(response_stream);
@@ -422,8 +674,11 @@ impl FlightClient {
         // Intercepts client errors and sends them to the oneshot channel above
         let request_stream = FallibleRequestStream::new(sender, request);
 
-        let request = self.make_request(request_stream);
-        let response_stream = self.inner.do_exchange(request).await?.into_inner();
+        let request = self.make_request("DoExchange", request_stream)?;
+        let response = self.inner.do_exchange(request).await;
+        let response_stream = self
+            .intercept_response("DoExchange", response)?
+            .into_inner();
 
         let response_stream = Box::pin(response_stream);
         let error_stream = FallibleTonicResponseStream::new(receiver, response_stream);
@@ -432,6 +687,82 @@ impl FlightClient {
         Ok(FlightRecordBatchStream::new_from_flight_data(error_stream))
     }
 
+    /// Make a `DoExchange` call to the server for the exchange described
+    /// by `descriptor`, sending `batches` with the provided `schema` and
+    /// returning the [`RecordBatch`]es sent back by the server.
+    ///
+    /// The schema is sent to the server even if `batches` is empty. Use
+    /// [`Self::do_exchange_batches_with_encoder`] to control how the batches
+    /// are encoded, for example to send dictionaries or `app_metadata`.
+    ///
+    /// As for [`Self::do_exchange`], an error in `batches` is not sent to the
+    /// server. Instead it terminates the exchange and is returned by the
+    /// response stream. Use [`FlightRecordBatchStream::into_inner`] to access
+    /// the `app_metadata` of the [`FlightData`] received from the server.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use futures::TryStreamExt;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::UInt64Array;
+    /// # use arrow_array::RecordBatch;
+    /// # use arrow_flight::{FlightClient, FlightDescriptor};
+    /// # let batch = RecordBatch::try_from_iter(vec![
+    /// #  ("col2", Arc::new(UInt64Array::from_iter([10, 23, 33])) as _)
+    /// # ]).unwrap();
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let descriptor = FlightDescriptor::new_cmd(b"MOAR DATA".to_vec());
+    /// let schema = batch.schema();
+    /// let batches = futures::stream::iter(vec![Ok(batch)]);
+    ///
+    /// // send the batches and collect the batches sent back
+    /// let response: Vec<RecordBatch> = client
+    ///   .do_exchange_batches(descriptor, schema, batches)
+    ///   .await
+    ///   .unwrap()
+    ///   .try_collect() // use TryStreamExt to collect stream
+    ///   .await
+    ///   .expect("error calling do_exchange");
+    /// # }
+    /// ```
+    pub async fn do_exchange_batches<S>(
+        &mut self,
+        descriptor: FlightDescriptor,
+        schema: SchemaRef,
+        batches: S,
+    ) -> Result<FlightRecordBatchStream>
+    where
+        S: Stream<Item = Result<RecordBatch>> + Send + 'static,
+    {
+        let encoder = FlightDataEncoderBuilder::new()
+            .with_schema(schema)
+            .with_flight_descriptor(Some(descriptor));
+        self.do_exchange_batches_with_encoder(encoder, batches)
+            .await
+    }
+
+    /// Make a `DoExchange` call to the server, sending `batches` encoded
+    /// by `encoder` and returning the [`RecordBatch`]es sent back by the
+    /// server.
+    ///
+    /// The [`FlightDescriptor`] describing the exchange should be set with
+    /// [`FlightDataEncoderBuilder::with_flight_descriptor`].
+    ///
+    /// See [`Self::do_exchange_batches`] for more details.
+    pub async fn do_exchange_batches_with_encoder<S>(
+        &mut self,
+        encoder: FlightDataEncoderBuilder,
+        batches: S,
+    ) -> Result<FlightRecordBatchStream>
+    where
+        S: Stream<Item = Result<RecordBatch>> + Send + 'static,
+    {
+        self.do_exchange(encoder.build(batches)).await
+    }
+
     /// Make a `ListFlights` call to the server with the provided
     /// criteria and returning a [`Stream`] of [`FlightInfo`].
     ///
@@ -463,12 +794,11 @@ impl FlightClient {
             expression: expression.into(),
         };
 
-        let request = self.make_request(request);
+        let request = self.make_request("ListFlights", request)?;
 
+        let response = self.inner.list_flights(request).await;
         let response = self
-            .inner
-            .list_flights(request)
-            .await?
+            .intercept_response("ListFlights", response)?
             .into_inner()
             .map_err(FlightError::Tonic);
 
@@ -497,9 +827,10 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn get_schema(&mut self, flight_descriptor: FlightDescriptor) -> Result<Schema> {
-        let request = self.make_request(flight_descriptor);
+        let request = self.make_request("GetSchema", flight_descriptor)?;
 
-        let schema_result = self.inner.get_schema(request).await?.into_inner();
+        let response = self.inner.get_schema(request).await;
+        let schema_result = self.intercept_response("GetSchema", response)?.into_inner();
 
         // attempt decode from IPC
         let schema: Schema = schema_result.try_into()?;
@@ -530,12 +861,11 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn list_actions(&mut self) -> Result<BoxStream<'static, Result<ActionType>>> {
-        let request = self.make_request(Empty {});
+        let request = self.make_request("ListActions", Empty {})?;
 
+        let response = self.inner.list_actions(request).await;
         let action_stream = self
-            .inner
-            .list_actions(request)
-            .await?
+            .intercept_response("ListActions", response)?
             .into_inner()
             .map_err(FlightError::Tonic);
 
@@ -568,12 +898,11 @@ impl FlightClient {
     /// # }
     /// ```
     pub async fn do_action(&mut self, action: Action) -> Result<BoxStream<'static, Result<Bytes>>> {
-        let request = self.make_request(action);
+        let request = self.make_request("DoAction", action)?;
 
+        let response = self.inner.do_action(request).await;
         let result_stream = self
-            .inner
-            .do_action(request)
-            .await?
+            .intercept_response("DoAction", response)?
             .into_inner()
             .map_err(FlightError::Tonic)
             .map(|r| {
@@ -617,12 +946,7 @@ impl FlightClient {
         request: CancelFlightInfoRequest,
     ) -> Result<CancelFlightInfoResult> {
         let action = Action::new("CancelFlightInfo", request.encode_to_vec());
-        let response = self.do_action(action).await?.try_next().await?;
-        let response = response.ok_or(FlightError::protocol(
-            "Received no response for cancel_flight_info call",
-        ))?;
-        CancelFlightInfoResult::decode(response)
-            .map_err(|e| FlightError::DecodeError(e.to_string()))
+        self.do_single_action(action, "cancel_flight_info").await
     }
 
     /// Make a `RenewFlightEndpoint` call to the server and return
@@ -656,18 +980,99 @@ impl FlightClient {
         request: RenewFlightEndpointRequest,
     ) -> Result<FlightEndpoint> {
         let action = Action::new("RenewFlightEndpoint", request.encode_to_vec());
+        self.do_single_action(action, "renew_flight_endpoint").await
+    }
+
+    /// Make a `SetSessionOptions` call to the server, setting options of
+    /// the current session, or of a new session, and return the errors of
+    /// any options that could not be set.
+    ///
+    /// The session is typically tracked with a cookie, see [`CookieInterceptor`].
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use arrow_flight::{FlightClient, SetSessionOptionsRequest};
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let request = SetSessionOptionsRequest::new([
+    ///     ("catalog", "sales".into()),
+    ///     ("timezone", "UTC".into()),
+    /// ]);
+    /// let result = client
+    ///   .set_session_options(request)
+    ///   .await
+    ///   .expect("error setting session options");
+    /// assert!(result.errors.is_empty());
+    /// # }
+    /// ```
+    pub async fn set_session_options(
+        &mut self,
+        request: SetSessionOptionsRequest,
+    ) -> Result<SetSessionOptionsResult> {
+        let action = Action::new("SetSessionOptions", request.encode_to_vec());
+        self.do_single_action(action, "set_session_options").await
+    }
+
+    /// Make a `GetSessionOptions` call to the server and return the options
+    /// of the current session.
+    ///
+    /// The session is typically tracked with a cookie, see [`CookieInterceptor`].
+    pub async fn get_session_options(&mut self) -> Result<GetSessionOptionsResult> {
+        let request = GetSessionOptionsRequest {};
+        let action = Action::new("GetSessionOptions", request.encode_to_vec());
+        self.do_single_action(action, "get_session_options").await
+    }
+
+    /// Make a `CloseSession` call to the server, closing the current session.
+    ///
+    /// The session is typically tracked with a cookie, see [`CookieInterceptor`].
+    pub async fn close_session(&mut self) -> Result<CloseSessionResult> {
+        let request = CloseSessionRequest {};
+        let action = Action::new("CloseSession", request.encode_to_vec());
+        self.do_single_action(action, "close_session").await
+    }
+
+    /// Make a `DoAction` call expecting a single result, and decode it as `T`
+    async fn do_single_action<T: Message + Default>(
+        &mut self,
+        action: Action,
+        name: &str,
+    ) -> Result<T> {
         let response = self.do_action(action).await?.try_next().await?;
-        let response = response.ok_or(FlightError::protocol(
-            "Received no response for renew_flight_endpoint call",
-        ))?;
-        FlightEndpoint::decode(response).map_err(|e| FlightError::DecodeError(e.to_string()))
+        let response = response.ok_or_else(|| {
+            FlightError::protocol(format!("Received no response for {name} call"))
+        })?;
+        T::decode(response).map_err(|e| FlightError::DecodeError(e.to_string()))
     }
 
-    /// return a Request, adding any configured metadata
-    fn make_request<T>(&self, t: T) -> tonic::Request<T> {
+    /// return a Request for `method`, adding any configured metadata
+    /// and invoking the configured interceptors
+    fn make_request<T>(&self, method: &str, t: T) -> Result<tonic::Request<T>> {
         // Pass along metadata
         let mut request = tonic::Request::new(t);
         *request.metadata_mut() = self.metadata.clone();
-        request
+        for interceptor in &self.interceptors {
+            interceptor.on_request(method, request.metadata_mut())?;
+        }
+        Ok(request)
+    }
+
+    /// Pass the headers of the response to `method`, or the metadata of
+    /// its error, to the configured interceptors
+    fn intercept_response<T>(
+        &self,
+        method: &str,
+        response: std::result::Result<tonic::Response<T>, tonic::Status>,
+    ) -> Result<tonic::Response<T>> {
+        let metadata = match &response {
+            Ok(response) => response.metadata(),
+            Err(status) => status.metadata(),
+        };
+        for interceptor in &self.interceptors {
+            interceptor.on_response(method, metadata);
+        }
+        Ok(response?)
     }
 }
@@ -15,13 +15,26 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::{trailers::LazyTrailers, utils::flight_data_to_arrow_batch, FlightData};
+use crate::{
+    encode::encoded_size,
+    metrics::{FlightMetrics, StreamMetrics},
+    trailers::LazyTrailers,
+    utils::flight_data_to_arrow_batch,
+    FlightData,
+};
 use arrow_array::{ArrayRef, RecordBatch};
 use arrow_buffer::Buffer;
 use arrow_schema::{Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{ready, stream::BoxStream, Stream, StreamExt};
-use std::{collections::HashMap, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    pin::Pin,
+    sync::Arc,
+    task::Poll,
+    time::Instant,
+};
 use tonic::metadata::MetadataMap;
 
 use crate::error::{FlightError, Result};
@@ -112,6 +125,49 @@ impl FlightRecordBatchStream {
         }
     }
 
+    /// Report the messages received, the decoded bytes, dictionary resets
+    /// and the time taken to decode each [`RecordBatch`] to `metrics`
+    ///
+    /// See [`FlightDataDecoder::with_metrics`]
+    pub fn with_metrics(self, metrics: Arc<dyn FlightMetrics>) -> Self {
+        Self {
+            inner: self.inner.with_metrics(metrics),
+            ..self
+        }
+    }
+
+    /// Return an error for any [`FlightData`] message larger than
+    /// `max_message_size` bytes, instead of decoding it
+    ///
+    /// See [`FlightDataDecoder::with_max_message_size`]
+    pub fn with_max_message_size(self, max_message_size: usize) -> Self {
+        Self {
+            inner: self.inner.with_max_message_size(max_message_size),
+            ..self
+        }
+    }
+
+    /// Read ahead up to `max_buffered_bytes` of [`FlightData`] messages that
+    /// have not been decoded yet
+    ///
+    /// See [`FlightDataDecoder::with_max_buffered_bytes`]
+    pub fn with_max_buffered_bytes(self, max_buffered_bytes: usize) -> Self {
+        Self {
+            inner: self.inner.with_max_buffered_bytes(max_buffered_bytes),
+            ..self
+        }
+    }
+
+    /// Decode up to `max_buffered_batches` messages ahead of the consumer
+    ///
+    /// See [`FlightDataDecoder::with_max_buffered_batches`]
+    pub fn with_max_buffered_batches(self, max_buffered_batches: usize) -> Self {
+        Self {
+            inner: self.inner.with_max_buffered_batches(max_buffered_batches),
+            ..self
+        }
+    }
+
     /// Record response headers.
     pub fn with_headers(self, headers: MetadataMap) -> Self {
         Self { headers, ..self }
@@ -212,7 +268,8 @@ impl futures::Stream for FlightRecordBatchStream {
 ///   the decoded schema is returned.
 ///
 /// - **Dictionary Batch:** A new dictionary for a given column is registered. An existing
-///   dictionary for the same column will be overwritten. This
+///   dictionary for the same column will be overwritten, unless this is a delta
+///   dictionary batch, whose values are appended to the existing dictionary. This
 ///   message is NOT visible.
 ///
 /// - **Record Batch:** Record batch is created based on the current
@@ -227,13 +284,48 @@ impl futures::Stream for FlightRecordBatchStream {
 /// 1. Using this low level stream it is possible to receive a steam
 ///    of RecordBatches in FlightData that have different schemas by
 ///    handling multiple schema messages separately.
+///
+/// # Memory Usage
+///
+/// By default, the decoder reads and decodes a single message at a time,
+/// when it is polled. To keep the server sending while the consumer is
+/// busy, it can read ahead the messages already received, up to
+/// [`Self::with_max_buffered_bytes`], and decode them ahead of the
+/// consumer, up to [`Self::with_max_buffered_batches`]. Once either limit
+/// is reached, the decoder stops reading from the underlying stream, so a
+/// slow consumer applies backpressure to the server rather than growing
+/// the buffers.
+///
+/// The size of individual messages can be limited with
+/// [`Self::with_max_message_size`]. Note this limit is checked once a
+/// message has been received: to avoid receiving large messages at all,
+/// also configure the gRPC client, for example with
+/// [`FlightServiceClient::max_decoding_message_size`].
+///
+/// [`FlightServiceClient::max_decoding_message_size`]: crate::flight_service_client::FlightServiceClient::max_decoding_message_size
 pub struct FlightDataDecoder {
     /// Underlying data stream
     response: BoxStream<'static, Result<FlightData>>,
     /// Decoding state
     state: Option<FlightStreamState>,
     /// Seen the end of the inner stream?
+    response_done: bool,
+    /// Returned the end of this stream?
     done: bool,
+    /// Optional metrics to report the received messages to
+    metrics: Option<StreamMetrics>,
+    /// Messages read from the inner stream but not decoded yet
+    buffered: VecDeque<Result<FlightData>>,
+    /// The size in bytes of the messages in `buffered`
+    buffered_bytes: usize,
+    /// Messages decoded but not returned yet
+    decoded: VecDeque<Result<DecodedFlightData>>,
+    /// The maximum size of a single message
+    max_message_size: Option<usize>,
+    /// The maximum size of `buffered` before reading stops
+    max_buffered_bytes: usize,
+    /// The maximum length of `decoded` before decoding stops
+    max_buffered_batches: usize,
 }
 
 impl Debug for FlightDataDecoder {
@@ -241,7 +333,13 @@ impl Debug for FlightDataDecoder {
         f.debug_struct("FlightDataDecoder")
             .field("response", &"<stream>")
             .field("state", &self.state)
+            .field("response_done", &self.response_done)
             .field("done", &self.done)
+            .field("metrics", &self.metrics)
+            .field("buffered_bytes", &self.buffered_bytes)
+            .field("max_message_size", &self.max_message_size)
+            .field("max_buffered_bytes", &self.max_buffered_bytes)
+            .field("max_buffered_batches", &self.max_buffered_batches)
             .finish()
     }
 }
@@ -255,10 +353,51 @@ impl FlightDataDecoder {
         Self {
             state: None,
             response: response.boxed(),
+            response_done: false,
             done: false,
+            metrics: None,
+            buffered: VecDeque::new(),
+            buffered_bytes: 0,
+            decoded: VecDeque::new(),
+            max_message_size: None,
+            max_buffered_bytes: 0,
+            max_buffered_batches: 1,
         }
     }
 
+    /// Report the messages received, the decoded bytes, dictionary resets
+    /// and the time taken to decode each [`RecordBatch`] to `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<dyn FlightMetrics>) -> Self {
+        self.metrics = Some(StreamMetrics::new(metrics));
+        self
+    }
+
+    /// Return an error for any [`FlightData`] message whose header, body
+    /// and application metadata are larger than `max_message_size` bytes,
+    /// instead of decoding it
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = Some(max_message_size);
+        self
+    }
+
+    /// Read ahead the [`FlightData`] messages available from the underlying
+    /// stream, until the messages not yet decoded reach `max_buffered_bytes`.
+    ///
+    /// A single message is always read, regardless of its size. Defaults
+    /// to 0, which disables reading ahead.
+    pub fn with_max_buffered_bytes(mut self, max_buffered_bytes: usize) -> Self {
+        self.max_buffered_bytes = max_buffered_bytes;
+        self
+    }
+
+    /// Decode up to `max_buffered_batches` messages read ahead, before
+    /// they are requested by the consumer. Values lower than 1 are treated
+    /// as 1, the default.
+    pub fn with_max_buffered_batches(mut self, max_buffered_batches: usize) -> Self {
+        self.max_buffered_batches = max_buffered_batches.max(1);
+        self
+    }
+
     /// Returns the current schema for this stream
     pub fn schema(&self) -> Option<&SchemaRef> {
         self.state.as_ref().map(|state| &state.schema)
@@ -344,6 +483,79 @@ impl FlightDataDecoder {
     }
 }
 
+impl FlightDataDecoder {
+    /// Reads the messages available from the underlying stream into
+    /// `self.buffered`, until `self.max_buffered_bytes` is reached.
+    ///
+    /// Returns true if the underlying stream is pending
+    fn read_ahead(&mut self, cx: &mut std::task::Context<'_>) -> bool {
+        while !self.response_done
+            && (self.buffered.is_empty() || self.buffered_bytes < self.max_buffered_bytes)
+        {
+            let data = match self.response.poll_next_unpin(cx) {
+                Poll::Pending => return true,
+                Poll::Ready(None) => {
+                    self.response_done = true;
+                    break;
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    self.buffered.push_back(Err(e));
+                    continue;
+                }
+                Poll::Ready(Some(Ok(data))) => data,
+            };
+
+            if let Some(metrics) = self.metrics.as_mut() {
+                metrics.record_received(&data);
+            }
+            let size = encoded_size(&data);
+            match self.max_message_size {
+                Some(max) if size > max => {
+                    self.buffered.push_back(Err(FlightError::protocol(format!(
+                        "Received FlightData message of {size} bytes, \
+                         which exceeds the maximum message size of {max} bytes"
+                    ))));
+                }
+                _ => {
+                    self.buffered_bytes += size;
+                    self.buffered.push_back(Ok(data));
+                }
+            }
+        }
+        false
+    }
+
+    /// Decodes the messages in `self.buffered` into `self.decoded`, until
+    /// `self.max_buffered_batches` is reached
+    fn decode_ahead(&mut self) {
+        while self.decoded.len() < self.max_buffered_batches {
+            let data = match self.buffered.pop_front() {
+                Some(Ok(data)) => data,
+                Some(Err(e)) => {
+                    self.decoded.push_back(Err(e));
+                    continue;
+                }
+                None => break,
+            };
+            self.buffered_bytes -= encoded_size(&data);
+
+            let start = Instant::now();
+            match self.extract_message(data) {
+                Ok(Some(extracted)) => {
+                    if let (Some(metrics), DecodedPayload::RecordBatch(_)) =
+                        (&self.metrics, &extracted.payload)
+                    {
+                        metrics.metrics().batch_decoded(start.elapsed());
+                    }
+                    self.decoded.push_back(Ok(extracted))
+                }
+                Ok(None) => {} // Updated the state, no decoded message
+                Err(e) => self.decoded.push_back(Err(e)),
+            }
+        }
+    }
+}
+
 impl futures::Stream for FlightDataDecoder {
     type Item = Result<DecodedFlightData>;
     /// Returns the result of decoding the next [`FlightData`] message
@@ -357,22 +569,21 @@ impl futures::Stream for FlightDataDecoder {
             return Poll::Ready(None);
         }
         loop {
-            let res = ready!(self.response.poll_next_unpin(cx));
+            let pending = self.read_ahead(cx);
+            self.decode_ahead();
 
-            return Poll::Ready(match res {
-                None => {
-                    self.done = true;
-                    None // inner is exhausted
-                }
-                Some(data) => Some(match data {
-                    Err(e) => Err(e),
-                    Ok(data) => match self.extract_message(data) {
-                        Ok(Some(extracted)) => Ok(extracted),
-                        Ok(None) => continue, // Need next input message
-                        Err(e) => Err(e),
-                    },
-                }),
-            });
+            if let Some(decoded) = self.decoded.pop_front() {
+                return Poll::Ready(Some(decoded));
+            }
+            // All the buffered messages were decoded without output, for
+            // example dictionaries, so more messages are needed
+            if pending {
+                return Poll::Pending;
+            }
+            if self.response_done {
+                self.done = true;
+                return Poll::Ready(None); // inner is exhausted
+            }
         }
     }
 }
@@ -15,12 +15,17 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::{collections::VecDeque, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
+use std::{collections::VecDeque, fmt::Debug, pin::Pin, sync::Arc, task::Poll, time::Instant};
 
-use crate::{error::Result, FlightData, FlightDescriptor, SchemaAsIpc};
+use crate::{
+    error::{FlightError, Result},
+    metrics::{FlightMetrics, StreamMetrics},
+    FlightData, FlightDescriptor, SchemaAsIpc,
+};
 
 use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchOptions, UnionArray};
-use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+use arrow_ipc::writer::{CompressionOptions, DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+use arrow_ipc::CompressionType;
 
 use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef, UnionMode};
 use bytes::Bytes;
@@ -44,6 +49,8 @@ use futures::{ready, stream::BoxStream, Stream, StreamExt};
 ///    When [`DictionaryHandling`] is [`DictionaryHandling::Resend`], Dictionary [`FlightData`] is sent with every
 ///    [`RecordBatch`] that contains a [`DictionaryArray`](arrow_array::array::DictionaryArray).
 ///    See <https://github.com/apache/arrow-rs/issues/3389>.
+///    When [`DictionaryHandling`] is [`DictionaryHandling::Delta`], only the values appended
+///    to a dictionary since it was last sent are sent.
 ///
 /// [`DictionaryArray`]: arrow_array::array::DictionaryArray
 ///
@@ -141,7 +148,7 @@ use futures::{ready, stream::BoxStream, Stream, StreamExt};
 ///
 /// [`FlightService::do_get`]: crate::flight_service_server::FlightService::do_get
 /// [`FlightError`]: crate::error::FlightError
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FlightDataEncoderBuilder {
     /// The maximum approximate target message size in bytes
     /// (see details on [`Self::with_max_flight_data_size`]).
@@ -157,6 +164,11 @@ pub struct FlightDataEncoderBuilder {
     /// Deterimines how `DictionaryArray`s are encoded for transport.
     /// See [`DictionaryHandling`] for more information.
     dictionary_handling: DictionaryHandling,
+    /// Determines how messages exceeding `max_flight_data_size` are handled.
+    /// See [`OversizedRowHandling`] for more information.
+    oversized_row_handling: OversizedRowHandling,
+    /// Optional metrics to report the encoded messages to
+    metrics: Option<Arc<dyn FlightMetrics>>,
 }
 
 /// Default target size for encoded [`FlightData`].
@@ -174,6 +186,8 @@ impl Default for FlightDataEncoderBuilder {
             schema: None,
             descriptor: None,
             dictionary_handling: DictionaryHandling::Hydrate,
+            oversized_row_handling: OversizedRowHandling::Allow,
+            metrics: None,
         }
     }
 }
@@ -193,7 +207,8 @@ impl FlightDataEncoderBuilder {
     /// limit the size individual messages sent via gRPC.
     ///
     /// The size is approximate because of the additional encoding
-    /// overhead on top of the underlying data buffers themselves.
+    /// overhead on top of the underlying data buffers themselves,
+    /// unless [`OversizedRowHandling::Error`] is used.
     pub fn with_max_flight_data_size(mut self, max_flight_data_size: usize) -> Self {
         self.max_flight_data_size = max_flight_data_size;
         self
@@ -205,6 +220,15 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Set [`OversizedRowHandling`] for encoder
+    pub fn with_oversized_row_handling(
+        mut self,
+        oversized_row_handling: OversizedRowHandling,
+    ) -> Self {
+        self.oversized_row_handling = oversized_row_handling;
+        self
+    }
+
     /// Specify application specific metadata included in the
     /// [`FlightData::app_metadata`] field of the the first Schema
     /// message
@@ -214,11 +238,36 @@ impl FlightDataEncoderBuilder {
     }
 
     /// Set the [`IpcWriteOptions`] used to encode the [`RecordBatch`]es for transport.
+    ///
+    /// Note this replaces any compression configured with [`Self::try_with_compression`]
+    /// and [`Self::with_compression_options`].
     pub fn with_options(mut self, options: IpcWriteOptions) -> Self {
         self.options = options;
         self
     }
 
+    /// Compress the IPC buffers of the encoded [`FlightData`] bodies with the
+    /// given codec, or disable compression with `None` (the default).
+    ///
+    /// Compressed data is decompressed transparently by
+    /// [`FlightRecordBatchStream`](crate::decode::FlightRecordBatchStream), and
+    /// can be more effective than gRPC-level compression, which is often unavailable.
+    ///
+    /// Requires the `ipc_compression` feature, otherwise the encoder returns an
+    /// error when encoding a [`RecordBatch`]. Returns an error if the configured
+    /// [`IpcWriteOptions`] do not support compression.
+    pub fn try_with_compression(mut self, compression: Option<CompressionType>) -> Result<Self> {
+        self.options = self.options.try_with_compression(compression)?;
+        Ok(self)
+    }
+
+    /// Set the parameters, such as the zstd compression level, of the codec
+    /// configured with [`Self::try_with_compression`]
+    pub fn with_compression_options(mut self, compression_options: CompressionOptions) -> Self {
+        self.options = self.options.with_compression_options(compression_options);
+        self
+    }
+
     /// Specify a schema for the RecordBatches being sent. If a schema
     /// is not specified, an encoded Schema message will be sent when
     /// the first [`RecordBatch`], if any, is encoded. Some clients
@@ -234,6 +283,13 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Report the messages sent, the encoded bytes, dictionary resets and
+    /// the time taken to encode each [`RecordBatch`] to `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<dyn FlightMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Takes a [`Stream`] of [`Result<RecordBatch>`] and returns a [`Stream`]
     /// of [`FlightData`], consuming self.
     ///
@@ -249,6 +305,8 @@ impl FlightDataEncoderBuilder {
             schema,
             descriptor,
             dictionary_handling,
+            oversized_row_handling,
+            metrics,
         } = self;
 
         FlightDataEncoder::new(
@@ -259,6 +317,8 @@ impl FlightDataEncoderBuilder {
             app_metadata,
             descriptor,
             dictionary_handling,
+            oversized_row_handling,
+            metrics,
         )
     }
 }
@@ -287,9 +347,15 @@ pub struct FlightDataEncoder {
     /// Deterimines how `DictionaryArray`s are encoded for transport.
     /// See [`DictionaryHandling`] for more information.
     dictionary_handling: DictionaryHandling,
+    /// Determines how messages exceeding `max_flight_data_size` are handled.
+    /// See [`OversizedRowHandling`] for more information.
+    oversized_row_handling: OversizedRowHandling,
+    /// Optional metrics to report the encoded messages to
+    metrics: Option<StreamMetrics>,
 }
 
 impl FlightDataEncoder {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         inner: BoxStream<'static, Result<RecordBatch>>,
         schema: Option<SchemaRef>,
@@ -298,20 +364,21 @@ impl FlightDataEncoder {
         app_metadata: Bytes,
         descriptor: Option<FlightDescriptor>,
         dictionary_handling: DictionaryHandling,
+        oversized_row_handling: OversizedRowHandling,
+        metrics: Option<Arc<dyn FlightMetrics>>,
     ) -> Self {
         let mut encoder = Self {
             inner,
             schema: None,
             max_flight_data_size,
-            encoder: FlightIpcEncoder::new(
-                options,
-                dictionary_handling != DictionaryHandling::Resend,
-            ),
+            encoder: FlightIpcEncoder::new(options, dictionary_handling),
             app_metadata: Some(app_metadata),
             queue: VecDeque::new(),
             done: false,
             descriptor,
             dictionary_handling,
+            oversized_row_handling,
+            metrics: metrics.map(StreamMetrics::new),
         };
 
         // If schema is known up front, enqueue it immediately
@@ -348,7 +415,7 @@ impl FlightDataEncoder {
     fn encode_schema(&mut self, schema: &SchemaRef) -> SchemaRef {
         // The first message is the schema message, and all
         // batches have the same schema
-        let send_dictionaries = self.dictionary_handling == DictionaryHandling::Resend;
+        let send_dictionaries = self.dictionary_handling != DictionaryHandling::Hydrate;
         let schema = Arc::new(prepare_schema_for_flight(
             schema,
             &mut self.encoder.dictionary_tracker,
@@ -375,17 +442,60 @@ impl FlightDataEncoder {
         };
 
         let batch = match self.dictionary_handling {
-            DictionaryHandling::Resend => batch,
+            DictionaryHandling::Resend | DictionaryHandling::Delta => batch,
             DictionaryHandling::Hydrate => hydrate_dictionaries(&batch, schema)?,
         };
 
+        let mut offset = 0;
         for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size) {
-            let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
+            let num_rows = batch.num_rows();
+            self.encode_split_batch(batch, offset)?;
+            offset += num_rows;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes `batch`, the slice starting at row `offset` of the batch
+    /// being encoded, into `FlightData` messages in self.queue, splitting
+    /// it further if required by [`OversizedRowHandling::Error`]
+    fn encode_split_batch(&mut self, batch: RecordBatch, offset: usize) -> Result<()> {
+        let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
 
-            self.queue_messages(flight_dictionaries);
-            self.queue_message(flight_batch);
+        if self.oversized_row_handling == OversizedRowHandling::Error {
+            let max = self.max_flight_data_size;
+            if let Some(size) = flight_dictionaries
+                .iter()
+                .map(encoded_size)
+                .find(|size| *size > max)
+            {
+                return Err(FlightError::protocol(format!(
+                    "Dictionary batch encoded as a FlightData message of {size} bytes \
+                     exceeds the maximum size of {max} bytes"
+                )));
+            }
+
+            let size = encoded_size(&flight_batch);
+            if size > max {
+                let num_rows = batch.num_rows();
+                if num_rows <= 1 {
+                    return Err(FlightError::protocol(format!(
+                        "Row {offset} of RecordBatch encoded as a FlightData message of \
+                         {size} bytes exceeds the maximum size of {max} bytes"
+                    )));
+                }
+
+                // The dictionaries have been recorded as sent, so they must be sent
+                // before the batch is split in two and encoded again
+                self.queue_messages(flight_dictionaries);
+                let mid = num_rows / 2;
+                self.encode_split_batch(batch.slice(0, mid), offset)?;
+                return self.encode_split_batch(batch.slice(mid, num_rows - mid), offset + mid);
+            }
         }
 
+        self.queue_messages(flight_dictionaries);
+        self.queue_message(flight_batch);
         Ok(())
     }
 }
@@ -404,6 +514,9 @@ impl Stream for FlightDataEncoder {
 
             // Any messages queued to send?
             if let Some(data) = self.queue.pop_front() {
+                if let Some(metrics) = self.metrics.as_mut() {
+                    metrics.record_sent(&data);
+                }
                 return Poll::Ready(Some(Ok(data)));
             }
 
@@ -426,11 +539,15 @@ impl Stream for FlightDataEncoder {
                 }
                 Some(Ok(batch)) => {
                     // had data, encode into the queue
+                    let start = Instant::now();
                     if let Err(e) = self.encode_batch(batch) {
                         self.done = true;
                         self.queue.clear();
                         return Poll::Ready(Some(Err(e)));
                     }
+                    if let Some(metrics) = &self.metrics {
+                        metrics.metrics().batch_encoded(start.elapsed());
+                    }
                 }
             }
         }
@@ -458,14 +575,16 @@ impl Stream for FlightDataEncoder {
 /// Note that since `dict_id` defined in the `Schema` is used as a key to associate dictionary values to their arrays it is required that each
 /// `DictionaryArray` in a `RecordBatch` have a unique `dict_id`.
 ///
-/// The current implementation does not support "delta" dictionaries so a new dictionary batch will be sent each time the encoder sees a
-/// dictionary which is not pointer-equal to the previously observed dictionary for a given `dict_id`.
+/// With `DictionaryHandling::Resend` a new dictionary batch will be sent each time the encoder sees a dictionary which is not
+/// pointer-equal to the previously observed dictionary for a given `dict_id`. With `DictionaryHandling::Delta`, if the previously
+/// sent dictionary values are a prefix of the new dictionary values, as is the case for dictionaries built incrementally by a
+/// dictionary builder that is not reset between batches, only the appended values are sent in a "delta" dictionary batch.
 ///
 /// For clients which may not support `DictionaryEncoding`, the `DictionaryHandling::Hydrate` method will bypass the process defined above
 /// and "hydrate" any `DictionaryArray` in the batch to their underlying value type (e.g. `TypedDictionaryArray<'_, UInt32Type, Utf8Type>` will
 /// be sent as a `StringArray`). With this method all data will be sent in ``MessageHeader::RecordBatch` messages and the batch schema
 /// will be adjusted so that all dictionary encoded fields are changed to fields of the dictionary value type.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DictionaryHandling {
     /// Expands to the underlying type (default). This likely sends more data
     /// over the network but requires less memory (dictionaries are not tracked)
@@ -485,6 +604,36 @@ pub enum DictionaryHandling {
     /// This requires identifying the different dictionaries in use and assigning
     //  them unique IDs
     Resend,
+    /// As [`Self::Resend`], except that if the values of a dictionary start with
+    /// the values previously sent for it, only the appended values are sent as a
+    /// delta dictionary batch. This drastically reduces the data sent for
+    /// dictionaries that grow across batches.
+    Delta,
+}
+
+/// Defines how a [`FlightDataEncoder`] handles [`RecordBatch`]es whose encoded
+/// [`FlightData`] exceeds the size set with [`FlightDataEncoderBuilder::with_max_flight_data_size`]
+///
+/// [`RecordBatch`]es are split into smaller batches based on the size of their
+/// buffers, but as this size is only an estimate of the encoded size, and as a single
+/// row may contain large values, such as a large `Binary` or `List` value, the
+/// encoded messages may still exceed the maximum size, which gRPC may reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OversizedRowHandling {
+    /// Send the messages regardless of their size (default)
+    Allow,
+    /// Split any [`RecordBatch`] encoded as a message exceeding the maximum size
+    /// into smaller batches, returning an error if a single row or a dictionary
+    /// cannot be encoded within the maximum size. This guarantees that no message
+    /// exceeding the maximum size is sent, at the cost of encoding some batches
+    /// more than once.
+    Error,
+}
+
+/// Returns the size of the encoded data of `data`, as compared against the
+/// maximum size of [`OversizedRowHandling::Error`] and reported to [`FlightMetrics`]
+pub(crate) fn encoded_size(data: &FlightData) -> usize {
+    data.data_header.len() + data.data_body.len() + data.app_metadata.len()
 }
 
 fn prepare_field_for_flight(
@@ -649,8 +798,15 @@ struct FlightIpcEncoder {
 }
 
 impl FlightIpcEncoder {
-    fn new(options: IpcWriteOptions, error_on_replacement: bool) -> Self {
+    fn new(options: IpcWriteOptions, dictionary_handling: DictionaryHandling) -> Self {
         let preserve_dict_id = options.preserve_dict_id();
+        let error_on_replacement = dictionary_handling == DictionaryHandling::Hydrate;
+        let options = match dictionary_handling {
+            DictionaryHandling::Delta => {
+                options.with_dictionary_handling(arrow_ipc::writer::DictionaryHandling::Delta)
+            }
+            _ => options,
+        };
         Self {
             options,
             data_gen: IpcDataGenerator::default(),
@@ -725,7 +881,7 @@ fn hydrate_dictionary(array: &ArrayRef, data_type: &DataType) -> Result<ArrayRef
 
 #[cfg(test)]
 mod tests {
-    use crate::decode::{DecodedPayload, FlightDataDecoder};
+    use crate::decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream};
     use arrow_array::builder::{
         GenericByteDictionaryBuilder, ListBuilder, StringDictionaryBuilder, StructBuilder,
     };
@@ -736,6 +892,7 @@ mod tests {
     use arrow_ipc::MetadataVersion;
     use arrow_schema::{UnionFields, UnionMode};
     use builder::{GenericStringBuilder, MapBuilder};
+    use futures::TryStreamExt;
     use std::collections::HashMap;
 
     use super::*;
@@ -906,6 +1063,62 @@ mod tests {
         verify_flight_round_trip(vec![batch1, batch2]).await;
     }
 
+    #[tokio::test]
+    async fn test_dictionary_delta() {
+        let mut builder = StringDictionaryBuilder::<UInt16Type>::new();
+        builder.extend([Some("a"), Some("b"), Some("a")]);
+        let arr1 = builder.finish_cloned();
+        builder.extend([Some("c"), Some("a"), None]);
+        let arr2 = builder.finish_cloned();
+        builder.extend([Some("b")]);
+        let arr3 = builder.finish_cloned();
+        // not an extension of the previous dictionary, so sent in full
+        let arr4: DictionaryArray<UInt16Type> = vec!["d", "e"].into_iter().collect();
+
+        let schema = Arc::new(Schema::new(vec![Field::new_dictionary(
+            "dict",
+            DataType::UInt16,
+            DataType::Utf8,
+            true,
+        )]));
+        let batches: Vec<_> = [
+            Arc::new(arr1) as ArrayRef,
+            Arc::new(arr2),
+            Arc::new(arr3),
+            Arc::new(arr4),
+        ]
+        .into_iter()
+        .map(|a| RecordBatch::try_new(schema.clone(), vec![a]).unwrap())
+        .collect();
+
+        let flight_data: Vec<_> = FlightDataEncoderBuilder::default()
+            .with_dictionary_handling(DictionaryHandling::Delta)
+            .build(futures::stream::iter(batches.clone().into_iter().map(Ok)))
+            .try_collect()
+            .await
+            .unwrap();
+
+        // only the appended values are sent as delta dictionaries
+        let dictionaries: Vec<_> = flight_data
+            .iter()
+            .filter_map(|data| {
+                let message = arrow_ipc::root_as_message(&data.data_header).unwrap();
+                let dictionary = message.header_as_dictionary_batch()?;
+                let num_values = dictionary.data().unwrap().length();
+                Some((dictionary.isDelta(), num_values))
+            })
+            .collect();
+        assert_eq!(dictionaries, vec![(false, 2), (true, 1), (false, 2)]);
+
+        let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(
+            futures::stream::iter(flight_data).map(Ok),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(decoded, batches);
+    }
+
     #[tokio::test]
     async fn test_dictionary_list_hydration() {
         let mut builder = ListBuilder::new(StringDictionaryBuilder::<UInt16Type>::new());
@@ -1574,6 +1787,57 @@ mod tests {
         assert!(got.metadata().contains_key("some_key"));
     }
 
+    #[tokio::test]
+    #[cfg(feature = "ipc_compression")]
+    async fn test_compression_round_trip() {
+        let array = StringArray::from_iter_values((0..1000).map(|i| format!("value {}", i % 10)));
+        let batch = RecordBatch::try_from_iter(vec![("s", Arc::new(array) as ArrayRef)]).unwrap();
+
+        let encode = |builder: FlightDataEncoderBuilder| {
+            builder
+                .build(futures::stream::iter(vec![Ok(batch.clone())]))
+                .try_collect::<Vec<_>>()
+        };
+        let uncompressed = encode(FlightDataEncoderBuilder::new()).await.unwrap();
+        let body_size = |data: &[FlightData]| data.iter().map(|d| d.data_body.len()).sum::<usize>();
+
+        for (compression, options) in [
+            (CompressionType::LZ4_FRAME, CompressionOptions::new()),
+            (
+                CompressionType::ZSTD,
+                CompressionOptions::new().with_zstd_level(19).unwrap(),
+            ),
+        ] {
+            let builder = FlightDataEncoderBuilder::new()
+                .try_with_compression(Some(compression))
+                .unwrap()
+                .with_compression_options(options);
+            let compressed = encode(builder).await.unwrap();
+            assert!(body_size(&compressed) < body_size(&uncompressed));
+
+            let decoded: Vec<_> = FlightRecordBatchStream::new_from_flight_data(
+                futures::stream::iter(compressed).map(Ok),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+            assert_eq!(decoded, vec![batch.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_try_with_compression_error() {
+        let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V4).unwrap();
+        let err = FlightDataEncoderBuilder::new()
+            .with_options(options)
+            .try_with_compression(Some(CompressionType::ZSTD))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow error: Invalid argument error: Compression only supported in metadata v5 and above"
+        );
+    }
+
     #[test]
     fn test_encode_no_column_batch() {
         let batch = RecordBatch::try_new_with_options(
@@ -1749,6 +2013,101 @@ mod tests {
         verify_encoded_split(batch, 5800).await;
     }
 
+    #[tokio::test]
+    async fn test_oversized_row_handling_error() {
+        let array = StringArray::from_iter_values(vec![
+            "*".to_string(),
+            "*".repeat(1000),
+            "*".repeat(2000),
+            "*".repeat(4000),
+        ]);
+        let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as _)]).unwrap();
+
+        let encode = |max_flight_data_size: usize| {
+            FlightDataEncoderBuilder::new()
+                .with_max_flight_data_size(max_flight_data_size)
+                .with_oversized_row_handling(OversizedRowHandling::Error)
+                .build(futures::stream::iter([Ok(batch.clone())]))
+                .try_collect::<Vec<_>>()
+        };
+
+        // Without enforcing the limit, the largest row exceeds it
+        let data: Vec<_> = FlightDataEncoderBuilder::new()
+            .with_max_flight_data_size(5000)
+            .build(futures::stream::iter([Ok(batch.clone())]))
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(data.iter().any(|d| encoded_size(d) > 5000));
+
+        // Batches are split until every message is within the limit
+        let data = encode(5000).await.unwrap();
+        assert!(data.iter().all(|d| encoded_size(d) <= 5000));
+        let decoded: Vec<_> =
+            FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(data).map(Ok))
+                .try_collect()
+                .await
+                .unwrap();
+        assert_decoded_slices(&batch, &decoded);
+
+        // The last row cannot be sent within the limit
+        let err = encode(3000).await.unwrap_err().to_string();
+        assert!(
+            err.starts_with(
+                "Protocol error: Row 3 of RecordBatch encoded as a FlightData message of"
+            ),
+            "{err}"
+        );
+        assert!(
+            err.ends_with("exceeds the maximum size of 3000 bytes"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oversized_row_handling_dictionary() {
+        let values = StringArray::from_iter_values(["*".repeat(2000), "*".repeat(2000)]);
+        let keys = UInt16Array::from_iter_values((0..3000).map(|i| i % 2));
+        let array = DictionaryArray::new(keys, Arc::new(values));
+        let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as _)]).unwrap();
+
+        let encode = |max_flight_data_size: usize| {
+            FlightDataEncoderBuilder::new()
+                .with_max_flight_data_size(max_flight_data_size)
+                .with_dictionary_handling(DictionaryHandling::Resend)
+                .with_oversized_row_handling(OversizedRowHandling::Error)
+                .build(futures::stream::iter([Ok(batch.clone())]))
+                .try_collect::<Vec<_>>()
+        };
+
+        // The dictionary is sent once, before the split batches
+        let data = encode(4500).await.unwrap();
+        assert!(data.iter().all(|d| encoded_size(d) <= 4500));
+        let headers = data
+            .iter()
+            .map(|d| {
+                arrow_ipc::root_as_message(&d.data_header)
+                    .unwrap()
+                    .header_type()
+            })
+            .collect::<Vec<_>>();
+        let count = |t| headers.iter().filter(|h| **h == t).count();
+        assert_eq!(count(arrow_ipc::MessageHeader::DictionaryBatch), 1);
+        assert!(count(arrow_ipc::MessageHeader::RecordBatch) > 1);
+        let decoded: Vec<_> =
+            FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(data).map(Ok))
+                .try_collect()
+                .await
+                .unwrap();
+        assert_decoded_slices(&batch, &decoded);
+
+        let err = encode(3000).await.unwrap_err().to_string();
+        assert!(
+            err.starts_with("Protocol error: Dictionary batch encoded as a FlightData message of"),
+            "{err}"
+        );
+    }
+
     #[tokio::test]
     async fn flight_data_size_string_dictionary() {
         // Small dictionary (only 2 distinct values ==> 2 entries in dictionary)
@@ -1848,6 +2207,16 @@ mod tests {
     /// account for encoding overhead such as IPC headers and padding.
     ///
     ///
+    /// Asserts that the `decoded` batches are consecutive slices of `batch`
+    fn assert_decoded_slices(batch: &RecordBatch, decoded: &[RecordBatch]) {
+        let mut offset = 0;
+        for decoded in decoded {
+            assert_eq!(*decoded, batch.slice(offset, decoded.num_rows()));
+            offset += decoded.num_rows();
+        }
+        assert_eq!(offset, batch.num_rows());
+    }
+
     async fn verify_encoded_split(batch: RecordBatch, allowed_overage: usize) {
         let num_rows = batch.num_rows();
 
@@ -64,6 +64,24 @@ pub mod flight_descriptor {
     pub use gen::flight_descriptor::DescriptorType;
 }
 
+/// The possible values of a [`SessionOptionValue`].
+pub mod session_option_value {
+    use super::gen;
+    pub use gen::session_option_value::{OptionValue, StringListValue};
+}
+
+/// The errors of a [`SetSessionOptionsResult`].
+pub mod set_session_options_result {
+    use super::gen;
+    pub use gen::set_session_options_result::{Error, ErrorValue};
+}
+
+/// The status of a [`CloseSessionResult`].
+pub mod close_session_result {
+    use super::gen;
+    pub use gen::close_session_result::Status;
+}
+
 /// Low Level [tonic] [`FlightServiceClient`](gen::flight_service_client::FlightServiceClient).
 pub mod flight_service_client {
     use super::gen;
@@ -93,18 +111,30 @@ pub mod encode;
 /// Common error types
 pub mod error;
 
+/// Metrics reported by the Flight encoder and decoder.
+/// See [`FlightMetrics`](metrics::FlightMetrics).
+pub mod metrics;
+
+/// Resumable `DoPut` uploads.
+/// See [`ResumablePut`](put::ResumablePut).
+pub mod put;
+
 pub use gen::Action;
 pub use gen::ActionType;
 pub use gen::BasicAuth;
 pub use gen::CancelFlightInfoRequest;
 pub use gen::CancelFlightInfoResult;
 pub use gen::CancelStatus;
+pub use gen::CloseSessionRequest;
+pub use gen::CloseSessionResult;
 pub use gen::Criteria;
 pub use gen::Empty;
 pub use gen::FlightData;
 pub use gen::FlightDescriptor;
 pub use gen::FlightEndpoint;
 pub use gen::FlightInfo;
+pub use gen::GetSessionOptionsRequest;
+pub use gen::GetSessionOptionsResult;
 pub use gen::HandshakeRequest;
 pub use gen::HandshakeResponse;
 pub use gen::Location;
@@ -113,6 +143,9 @@ pub use gen::PutResult;
 pub use gen::RenewFlightEndpointRequest;
 pub use gen::Result;
 pub use gen::SchemaResult;
+pub use gen::SessionOptionValue;
+pub use gen::SetSessionOptionsRequest;
+pub use gen::SetSessionOptionsResult;
 pub use gen::Ticket;
 
 /// Helper to extract HTTP/gRPC trailers from a tonic stream.
@@ -723,6 +756,128 @@ impl RenewFlightEndpointRequest {
     }
 }
 
+impl SessionOptionValue {
+    /// Create a valueless [`SessionOptionValue`], which clears or resets the
+    /// session option when set
+    pub fn empty() -> Self {
+        Self { option_value: None }
+    }
+}
+
+impl From<String> for SessionOptionValue {
+    fn from(value: String) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::StringValue(value)),
+        }
+    }
+}
+
+impl From<&str> for SessionOptionValue {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl From<bool> for SessionOptionValue {
+    fn from(value: bool) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::BoolValue(value)),
+        }
+    }
+}
+
+impl From<i64> for SessionOptionValue {
+    fn from(value: i64) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::Int64Value(value)),
+        }
+    }
+}
+
+impl From<f64> for SessionOptionValue {
+    fn from(value: f64) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::DoubleValue(value)),
+        }
+    }
+}
+
+impl From<Vec<String>> for SessionOptionValue {
+    fn from(values: Vec<String>) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::StringListValue(
+                session_option_value::StringListValue { values },
+            )),
+        }
+    }
+}
+
+impl SetSessionOptionsRequest {
+    /// Create a new [`SetSessionOptionsRequest`] from pairs of option names
+    /// and values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use arrow_flight::SetSessionOptionsRequest;
+    /// let request = SetSessionOptionsRequest::new([
+    ///     ("catalog", "sales".into()),
+    ///     ("timezone", "UTC".into()),
+    /// ]);
+    /// ```
+    pub fn new<K: Into<String>>(
+        session_options: impl IntoIterator<Item = (K, SessionOptionValue)>,
+    ) -> Self {
+        Self {
+            session_options: session_options
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        }
+    }
+}
+
+impl SetSessionOptionsResult {
+    /// Record that setting the session option `name` failed with `error`
+    pub fn with_error(
+        mut self,
+        name: impl Into<String>,
+        error: set_session_options_result::ErrorValue,
+    ) -> Self {
+        self.errors.insert(
+            name.into(),
+            set_session_options_result::Error {
+                value: error as i32,
+            },
+        );
+        self
+    }
+}
+
+impl GetSessionOptionsResult {
+    /// Create a new [`GetSessionOptionsResult`] from pairs of option names
+    /// and values
+    pub fn new<K: Into<String>>(
+        session_options: impl IntoIterator<Item = (K, SessionOptionValue)>,
+    ) -> Self {
+        Self {
+            session_options: session_options
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        }
+    }
+}
+
+impl CloseSessionResult {
+    /// Create a new [`CloseSessionResult`] from the provided [`close_session_result::Status`].
+    pub fn new(status: close_session_result::Status) -> Self {
+        Self {
+            status: status as i32,
+        }
+    }
+}
+
 impl Action {
     /// Create a new Action with type and body
     pub fn new(action_type: impl Into<String>, body: impl Into<Bytes>) -> Self {
@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::{collections::HashSet, fmt::Debug, sync::Arc, time::Duration};
+
+use arrow_ipc::MessageHeader;
+
+use crate::{encode::encoded_size, FlightData};
+
+/// Receives metrics from a [`FlightDataEncoder`] or a [`FlightDataDecoder`],
+/// for example to export the throughput of individual streams.
+///
+/// All methods have a default implementation that ignores the metric, so
+/// implementations only need to override the ones they are interested in.
+///
+/// Register an implementation with [`FlightDataEncoderBuilder::with_metrics`],
+/// [`FlightDataDecoder::with_metrics`] or [`FlightRecordBatchStream::with_metrics`].
+///
+/// # Example
+/// ```
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// use arrow_flight::metrics::FlightMetrics;
+///
+/// /// Counts the bytes sent by a stream
+/// #[derive(Debug, Default)]
+/// struct BytesSent(AtomicUsize);
+///
+/// impl FlightMetrics for BytesSent {
+///     fn message_sent(&self, bytes: usize) {
+///         self.0.fetch_add(bytes, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+///
+/// [`FlightDataEncoder`]: crate::encode::FlightDataEncoder
+/// [`FlightDataEncoderBuilder::with_metrics`]: crate::encode::FlightDataEncoderBuilder::with_metrics
+/// [`FlightDataDecoder`]: crate::decode::FlightDataDecoder
+/// [`FlightDataDecoder::with_metrics`]: crate::decode::FlightDataDecoder::with_metrics
+/// [`FlightRecordBatchStream::with_metrics`]: crate::decode::FlightRecordBatchStream::with_metrics
+pub trait FlightMetrics: Debug + Send + Sync {
+    /// Called for each [`FlightData`] message produced by an encoder, with
+    /// the size in bytes of its header, body and application metadata
+    fn message_sent(&self, _bytes: usize) {}
+
+    /// Called for each [`FlightData`] message received by a decoder, with
+    /// the size in bytes of its header, body and application metadata
+    fn message_received(&self, _bytes: usize) {}
+
+    /// Called when a dictionary batch replaces, rather than extends, a
+    /// dictionary previously sent or received for the same dictionary id
+    fn dictionary_reset(&self) {}
+
+    /// Called after an input `RecordBatch` has been encoded into one or
+    /// more [`FlightData`] messages, with the time taken to encode it
+    fn batch_encoded(&self, _elapsed: Duration) {}
+
+    /// Called after a `RecordBatch` message has been decoded, with the time
+    /// taken to decode it
+    fn batch_decoded(&self, _elapsed: Duration) {}
+}
+
+/// Reports the messages of a single stream to a [`FlightMetrics`]
+#[derive(Debug)]
+pub(crate) struct StreamMetrics {
+    metrics: Arc<dyn FlightMetrics>,
+    /// The ids of the dictionaries seen since the last schema message
+    dictionary_ids: HashSet<i64>,
+}
+
+impl StreamMetrics {
+    pub(crate) fn new(metrics: Arc<dyn FlightMetrics>) -> Self {
+        Self {
+            metrics,
+            dictionary_ids: HashSet::new(),
+        }
+    }
+
+    /// The [`FlightMetrics`] to report to
+    pub(crate) fn metrics(&self) -> &dyn FlightMetrics {
+        self.metrics.as_ref()
+    }
+
+    /// Record a message produced by an encoder
+    pub(crate) fn record_sent(&mut self, data: &FlightData) {
+        self.metrics.message_sent(encoded_size(data));
+        self.record_dictionary(data);
+    }
+
+    /// Record a message received by a decoder
+    pub(crate) fn record_received(&mut self, data: &FlightData) {
+        self.metrics.message_received(encoded_size(data));
+        self.record_dictionary(data);
+    }
+
+    /// Reports a dictionary reset if `data` is a non-delta dictionary batch
+    /// for a dictionary id that was already seen
+    fn record_dictionary(&mut self, data: &FlightData) {
+        // Invalid messages are reported by the decoder
+        let message = match arrow_ipc::root_as_message(&data.data_header) {
+            Ok(message) => message,
+            Err(_) => return,
+        };
+        match message.header_type() {
+            MessageHeader::Schema => self.dictionary_ids.clear(),
+            MessageHeader::DictionaryBatch => {
+                if let Some(batch) = message.header_as_dictionary_batch() {
+                    if !self.dictionary_ids.insert(batch.id()) && !batch.isDelta() {
+                        self.metrics.dictionary_reset();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
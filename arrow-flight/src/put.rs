@@ -0,0 +1,365 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use arrow_array::RecordBatch;
+use arrow_ipc::MessageHeader;
+use arrow_schema::SchemaRef;
+use bytes::Bytes;
+use futures::{StreamExt, TryStreamExt};
+use tonic::Code;
+
+use crate::{
+    encode::FlightDataEncoderBuilder,
+    error::{FlightError, Result},
+    FlightClient, FlightData, FlightDescriptor, PutResult,
+};
+
+/// Encodes `sequence` as the `app_metadata` of a [`FlightData`] or
+/// [`PutResult`], as an 8 byte big-endian integer
+pub fn encode_sequence_number(sequence: u64) -> Bytes {
+    Bytes::copy_from_slice(&sequence.to_be_bytes())
+}
+
+/// Decodes a sequence number written by [`encode_sequence_number`], returning
+/// `None` if `app_metadata` is not 8 bytes long
+pub fn decode_sequence_number(app_metadata: &[u8]) -> Option<u64> {
+    app_metadata.try_into().ok().map(u64::from_be_bytes)
+}
+
+/// Decides whether, and after how long, a failed call is retried
+pub trait RetryPolicy: Debug + Send + Sync {
+    /// Return the delay before retry `attempt`, starting at 1, after the
+    /// call failed with `error`, or `None` to return the error
+    fn retry_delay(&self, attempt: usize, error: &FlightError) -> Option<Duration>;
+}
+
+/// A [`RetryPolicy`] that never retries
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRetry;
+
+impl RetryPolicy for NoRetry {
+    fn retry_delay(&self, _attempt: usize, _error: &FlightError) -> Option<Duration> {
+        None
+    }
+}
+
+/// A [`RetryPolicy`] that retries [transient](is_transient) errors, doubling
+/// the delay after each attempt up to a maximum
+#[derive(Debug, Clone)]
+pub struct ExponentialBackoff {
+    max_retries: usize,
+    initial_delay: Duration,
+    max_delay: Duration,
+}
+
+impl ExponentialBackoff {
+    /// Create a new [`ExponentialBackoff`] making at most `max_retries`
+    /// retries, with an initial delay of 100ms and a maximum delay of 10s
+    pub fn new(max_retries: usize) -> Self {
+        Self {
+            max_retries,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Set the maximum delay between retries
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn retry_delay(&self, attempt: usize, error: &FlightError) -> Option<Duration> {
+        if attempt == 0 || attempt > self.max_retries || !is_transient(error) {
+            return None;
+        }
+        let factor = 1_u32 << (attempt - 1).min(31);
+        Some(
+            self.initial_delay
+                .saturating_mul(factor)
+                .min(self.max_delay),
+        )
+    }
+}
+
+/// Returns true if `error` is a gRPC error that may succeed when retried,
+/// such as [`Code::Unavailable`] when the server can not be reached
+pub fn is_transient(error: &FlightError) -> bool {
+    match error {
+        FlightError::Tonic(status) => matches!(
+            status.code(),
+            Code::Unavailable | Code::Aborted | Code::DeadlineExceeded | Code::ResourceExhausted
+        ),
+        _ => false,
+    }
+}
+
+/// A `DoPut` upload of [`RecordBatch`]es that can be resumed from the last
+/// batch acknowledged by the server.
+///
+/// The batches are numbered from 0 in the order they are provided, and every
+/// `RecordBatch` message encoding batch `n` carries `n` as its sequence
+/// number in [`FlightData::app_metadata`], encoded with
+/// [`encode_sequence_number`]. The server acknowledges the batches it has
+/// durably processed by sending a [`PutResult`] whose `app_metadata` holds
+/// the sequence number of the last such batch. A stream that completes
+/// successfully acknowledges all the batches sent.
+///
+/// If the upload fails, for example because the connection was lost, it is
+/// resumed with a new `DoPut` call that only sends the batches not yet
+/// acknowledged, which are kept in memory until then. Each call starts a new
+/// stream with the schema and the [`FlightDescriptor`], so the server must
+/// use the sequence numbers to ignore any batch it already processed.
+///
+/// # Example:
+/// ```no_run
+/// # async fn run() {
+/// # use std::sync::Arc;
+/// # use arrow_array::{RecordBatch, UInt64Array};
+/// # use arrow_flight::{FlightClient, FlightDescriptor};
+/// # use arrow_flight::put::{ExponentialBackoff, ResumablePut};
+/// # let batch = RecordBatch::try_from_iter(vec![
+/// #  ("col2", Arc::new(UInt64Array::from_iter([10, 23, 33])) as _)
+/// # ]).unwrap();
+/// # let channel: tonic::transport::Channel = unimplemented!();
+/// let mut client = FlightClient::new(channel);
+///
+/// let descriptor = FlightDescriptor::new_path(vec!["uploads".to_string()]);
+/// let mut put = ResumablePut::new(descriptor, batch.schema(), vec![batch]);
+///
+/// // Retry transient errors up to 5 times, resuming after the last
+/// // acknowledged batch
+/// put.run(&mut client, &ExponentialBackoff::new(5))
+///   .await
+///   .expect("error uploading");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ResumablePut {
+    descriptor: FlightDescriptor,
+    schema: SchemaRef,
+    encoder: FlightDataEncoderBuilder,
+    /// The batches not yet acknowledged, with their sequence numbers
+    pending: VecDeque<(u64, RecordBatch)>,
+    /// The sequence number of the last acknowledged batch
+    last_acknowledged: Option<u64>,
+    /// The [`PutResult`]s received from the server
+    results: Vec<PutResult>,
+    /// Whether a `DoPut` call completed successfully
+    complete: bool,
+}
+
+impl ResumablePut {
+    /// Create a new [`ResumablePut`] uploading `batches` of `schema` to the
+    /// flight identified by `descriptor`
+    pub fn new(
+        descriptor: FlightDescriptor,
+        schema: SchemaRef,
+        batches: impl IntoIterator<Item = RecordBatch>,
+    ) -> Self {
+        Self {
+            descriptor,
+            schema,
+            encoder: FlightDataEncoderBuilder::new(),
+            pending: (0..).zip(batches).collect(),
+            last_acknowledged: None,
+            results: vec![],
+            complete: false,
+        }
+    }
+
+    /// Use `encoder` to encode the batches, for example to set the maximum
+    /// message size. Its schema and flight descriptor are overridden.
+    pub fn with_encoder(mut self, encoder: FlightDataEncoderBuilder) -> Self {
+        self.encoder = encoder;
+        self
+    }
+
+    /// Return the sequence number of the last batch acknowledged by the server
+    pub fn last_acknowledged(&self) -> Option<u64> {
+        self.last_acknowledged
+    }
+
+    /// Return the number of batches not yet acknowledged by the server
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Return true if a `DoPut` call completed successfully
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Return the [`PutResult`]s received from the server by all the calls
+    pub fn results(&self) -> &[PutResult] {
+        &self.results
+    }
+
+    /// Make a single `DoPut` call with `client`, sending the batches not yet
+    /// acknowledged.
+    ///
+    /// If the call fails, the acknowledgements received are kept, and the
+    /// upload can be resumed by calling this method again, possibly with a
+    /// client connected to another endpoint.
+    pub async fn send(&mut self, client: &mut FlightClient) -> Result<()> {
+        if self.complete {
+            return Ok(());
+        }
+
+        // The encoder only reads the next batch once all the messages of the
+        // previous batch have been sent, so the sequence number of the batch
+        // being encoded can be shared with the output stream
+        let sequence = Arc::new(AtomicU64::new(0));
+        let input_sequence = Arc::clone(&sequence);
+        let input = futures::stream::iter(self.pending.clone()).map(move |(n, batch)| {
+            input_sequence.store(n, Ordering::SeqCst);
+            Ok(batch)
+        });
+        let flight_data = self
+            .encoder
+            .clone()
+            .with_schema(Arc::clone(&self.schema))
+            .with_flight_descriptor(Some(self.descriptor.clone()))
+            .build(input)
+            .map_ok(move |data| tag_record_batch(data, sequence.load(Ordering::SeqCst)));
+
+        let mut results = client.do_put(flight_data).await?;
+        while let Some(result) = results.try_next().await? {
+            if let Some(n) = decode_sequence_number(&result.app_metadata) {
+                self.acknowledge(n);
+            }
+            self.results.push(result);
+        }
+
+        if let Some((n, _)) = self.pending.back() {
+            self.acknowledge(*n);
+        }
+        self.complete = true;
+        Ok(())
+    }
+
+    /// Complete the upload with `client`, resuming it after any error that
+    /// `retry_policy` decides to retry.
+    ///
+    /// The attempts are counted from the last call that made progress, so
+    /// long uploads are not abandoned because of errors spread over time.
+    pub async fn run(
+        &mut self,
+        client: &mut FlightClient,
+        retry_policy: &dyn RetryPolicy,
+    ) -> Result<()> {
+        let mut attempt = 0;
+        loop {
+            let last_acknowledged = self.last_acknowledged;
+            let error = match self.send(client).await {
+                Ok(()) => return Ok(()),
+                Err(e) => e,
+            };
+
+            if self.last_acknowledged != last_acknowledged {
+                attempt = 0;
+            }
+            attempt += 1;
+            match retry_policy.retry_delay(attempt, &error) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            }
+        }
+    }
+
+    /// Record the acknowledgement of all the batches up to sequence number `n`
+    fn acknowledge(&mut self, n: u64) {
+        while matches!(self.pending.front(), Some((pending, _)) if *pending <= n) {
+            self.pending.pop_front();
+        }
+        self.last_acknowledged = self.last_acknowledged.max(Some(n));
+    }
+}
+
+/// Set the `app_metadata` of `data` to `sequence` if it is a `RecordBatch` message
+fn tag_record_batch(mut data: FlightData, sequence: u64) -> FlightData {
+    let is_record_batch = arrow_ipc::root_as_message(&data.data_header)
+        .map(|message| message.header_type() == MessageHeader::RecordBatch)
+        .unwrap_or(false);
+    if is_record_batch {
+        data.app_metadata = encode_sequence_number(sequence);
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_number() {
+        let encoded = encode_sequence_number(258);
+        assert_eq!(encoded.as_ref(), &[0, 0, 0, 0, 0, 0, 1, 2]);
+        assert_eq!(decode_sequence_number(&encoded), Some(258));
+        assert_eq!(decode_sequence_number(b""), None);
+        assert_eq!(decode_sequence_number(b"123456789"), None);
+    }
+
+    #[test]
+    fn test_exponential_backoff() {
+        let policy = ExponentialBackoff::new(4)
+            .with_initial_delay(Duration::from_millis(10))
+            .with_max_delay(Duration::from_millis(50));
+        let error = FlightError::Tonic(tonic::Status::unavailable("connection lost"));
+
+        let delays: Vec<_> = (1..=5).map(|n| policy.retry_delay(n, &error)).collect();
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_millis(10)),
+                Some(Duration::from_millis(20)),
+                Some(Duration::from_millis(40)),
+                Some(Duration::from_millis(50)),
+                None,
+            ]
+        );
+
+        // errors that are not transient are not retried
+        let error = FlightError::Tonic(tonic::Status::invalid_argument("bad schema"));
+        assert_eq!(policy.retry_delay(1, &error), None);
+        let error = FlightError::protocol("unexpected message");
+        assert_eq!(policy.retry_delay(1, &error), None);
+
+        assert_eq!(
+            NoRetry.retry_delay(1, &FlightError::protocol("error")),
+            None
+        );
+    }
+}
@@ -21,6 +21,7 @@ use arrow_array::{RecordBatch, StringArray};
 use arrow_schema::{DataType, Field, Schema, SchemaRef};
 use once_cell::sync::Lazy;
 
+use super::{check_schema, required_string};
 use crate::error::Result;
 use crate::sql::CommandGetCatalogs;
 
@@ -86,6 +87,37 @@ impl GetCatalogsBuilder {
     }
 }
 
+/// Reads the catalog names from a [`CommandGetCatalogs`] response
+///
+/// This is the inverse of [`GetCatalogsBuilder`]
+#[derive(Debug, Clone)]
+pub struct CatalogsReader {
+    batch: RecordBatch,
+    row: usize,
+}
+
+impl CatalogsReader {
+    /// Create a new [`CatalogsReader`], returning an error if `batch` does
+    /// not have the schema of a [`CommandGetCatalogs`] response
+    pub fn try_new(batch: RecordBatch) -> Result<Self> {
+        check_schema(&batch, &get_catalogs_schema())?;
+        Ok(Self { batch, row: 0 })
+    }
+}
+
+impl Iterator for CatalogsReader {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.batch.num_rows() {
+            return None;
+        }
+        let row = self.row;
+        self.row += 1;
+        Some(required_string(&self.batch, 0, row))
+    }
+}
+
 fn get_catalogs_schema() -> SchemaRef {
     Arc::clone(&GET_CATALOG_SCHEMA)
 }
@@ -98,3 +130,61 @@ static GET_CATALOG_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
         false,
     )]))
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::ArrayRef;
+
+    #[test]
+    fn test_catalogs_reader() {
+        let mut builder = GetCatalogsBuilder::new();
+        builder.append("a_catalog");
+        builder.append("b_catalog");
+        let batch = builder.build().unwrap();
+
+        let catalogs = CatalogsReader::try_new(batch)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(catalogs, vec!["a_catalog", "b_catalog"]);
+    }
+
+    #[test]
+    fn test_catalogs_reader_invalid_schema() {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "catalog",
+            Arc::new(StringArray::from(vec!["a_catalog"])) as ArrayRef,
+        )])
+        .unwrap();
+
+        let err = CatalogsReader::try_new(batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Decode error: Expected FlightSQL metadata columns [catalog_name: Utf8] but got [catalog: Utf8]"
+        );
+    }
+
+    #[test]
+    fn test_catalogs_reader_null() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            true,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(StringArray::from(vec![Some("a_catalog"), None])) as ArrayRef],
+        )
+        .unwrap();
+
+        let mut reader = CatalogsReader::try_new(batch).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), "a_catalog");
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Decode error: Unexpected null in non-nullable column catalog_name at row 1"
+        );
+        assert!(reader.next().is_none());
+    }
+}
@@ -29,7 +29,7 @@ use arrow_select::{filter::filter_record_batch, take::take};
 use arrow_string::like::like;
 use once_cell::sync::Lazy;
 
-use super::lexsort_to_indices;
+use super::{check_schema, lexsort_to_indices, optional_string};
 use crate::error::*;
 use crate::sql::CommandGetDbSchemas;
 
@@ -170,6 +170,49 @@ impl GetDbSchemasBuilder {
     }
 }
 
+/// A database schema returned from a [`CommandGetDbSchemas`] query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbSchemaInfo {
+    /// The catalog of the schema, or an empty string if none
+    pub catalog_name: String,
+    /// The name of the schema, or an empty string if none
+    pub db_schema_name: String,
+}
+
+/// Reads [`DbSchemaInfo`]s from a [`CommandGetDbSchemas`] response
+///
+/// This is the inverse of [`GetDbSchemasBuilder`]
+#[derive(Debug, Clone)]
+pub struct DbSchemasReader {
+    batch: RecordBatch,
+    row: usize,
+}
+
+impl DbSchemasReader {
+    /// Create a new [`DbSchemasReader`], returning an error if `batch` does
+    /// not have the schema of a [`CommandGetDbSchemas`] response
+    pub fn try_new(batch: RecordBatch) -> Result<Self> {
+        check_schema(&batch, &get_db_schemas_schema())?;
+        Ok(Self { batch, row: 0 })
+    }
+}
+
+impl Iterator for DbSchemasReader {
+    type Item = Result<DbSchemaInfo>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.batch.num_rows() {
+            return None;
+        }
+        let row = self.row;
+        self.row += 1;
+        Some(Ok(DbSchemaInfo {
+            catalog_name: optional_string(&self.batch, 0, row).unwrap_or_default(),
+            db_schema_name: optional_string(&self.batch, 1, row).unwrap_or_default(),
+        }))
+    }
+}
+
 fn get_db_schemas_schema() -> SchemaRef {
     Arc::clone(&GET_DB_SCHEMAS_SCHEMA)
 }
@@ -283,4 +326,39 @@ mod tests {
 
         assert_eq!(schema_batch, ref_filtered);
     }
+
+    #[test]
+    fn test_db_schemas_reader() {
+        let mut builder = GetDbSchemasBuilder::new(None::<String>, None::<String>);
+        builder.append("a_catalog", "b_schema");
+        builder.append("", "a_schema");
+        let batch = builder.build().unwrap();
+
+        let schemas = DbSchemasReader::try_new(batch)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            schemas,
+            vec![
+                DbSchemaInfo {
+                    catalog_name: "".into(),
+                    db_schema_name: "a_schema".into(),
+                },
+                DbSchemaInfo {
+                    catalog_name: "a_catalog".into(),
+                    db_schema_name: "b_schema".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_db_schemas_reader_invalid_schema() {
+        let err = DbSchemasReader::try_new(get_ref_batch().project(&[1]).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Decode error: Expected FlightSQL metadata columns [catalog_name: Utf8, db_schema_name: Utf8] but got [db_schema_name: Utf8]"
+        );
+    }
 }
@@ -0,0 +1,618 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for foreign key metadata requests.
+//!
+//! - [`ForeignKey`] - a typed struct describing one column of a foreign key relationship.
+//! - [`GetForeignKeysBuilder`] - a builder for constructing [`CommandGetExportedKeys`],
+//!   [`CommandGetImportedKeys`] and [`CommandGetCrossReference`] responses.
+//!
+//! [`CommandGetExportedKeys`]: crate::sql::CommandGetExportedKeys
+//! [`CommandGetImportedKeys`]: crate::sql::CommandGetImportedKeys
+//! [`CommandGetCrossReference`]: crate::sql::CommandGetCrossReference
+
+use std::sync::Arc;
+
+use arrow_arith::boolean::and;
+use arrow_array::builder::{Int32Builder, StringBuilder, UInt8Builder};
+use arrow_array::types::{Int32Type, UInt8Type};
+use arrow_array::{ArrayRef, BooleanArray, RecordBatch, StringArray};
+use arrow_ord::cmp::eq;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow_select::{filter::filter_record_batch, take::take};
+use once_cell::sync::Lazy;
+
+use super::{
+    check_schema, lexsort_to_indices, optional_string, required_primitive, required_string,
+};
+use crate::error::*;
+use crate::sql::{
+    CommandGetCrossReference, CommandGetExportedKeys, CommandGetImportedKeys, UpdateDeleteRules,
+};
+
+/// Data structure describing one column of a foreign key relationship
+/// between a primary key table and a foreign key table.
+///
+/// In case a catalog or schema should be considered as empty, use an empty string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKey {
+    /// The catalog of the primary key table
+    pub pk_catalog_name: String,
+    /// The schema of the primary key table
+    pub pk_db_schema_name: String,
+    /// The name of the primary key table
+    pub pk_table_name: String,
+    /// The primary key column referenced by the foreign key
+    pub pk_column_name: String,
+    /// The catalog of the foreign key table
+    pub fk_catalog_name: String,
+    /// The schema of the foreign key table
+    pub fk_db_schema_name: String,
+    /// The name of the foreign key table
+    pub fk_table_name: String,
+    /// The foreign key column
+    pub fk_column_name: String,
+    /// The position of the column in the key, starting from 1
+    pub key_sequence: i32,
+    /// The name of the foreign key, if any
+    pub fk_key_name: Option<String>,
+    /// The name of the primary key, if any
+    pub pk_key_name: Option<String>,
+    /// The action taken when the primary key is updated
+    pub update_rule: UpdateDeleteRules,
+    /// The action taken when the primary key is deleted
+    pub delete_rule: UpdateDeleteRules,
+}
+
+/// Restricts the rows returned to a single table
+#[derive(Debug, Clone)]
+struct TableFilter {
+    catalog: Option<String>,
+    db_schema: Option<String>,
+    table: String,
+}
+
+impl TableFilter {
+    /// Returns the rows of `batch` whose catalog, schema and table columns,
+    /// starting at column `offset`, match this filter
+    fn evaluate(&self, batch: &RecordBatch, offset: usize) -> Result<BooleanArray> {
+        let table = StringArray::new_scalar(self.table.as_str());
+        let mut filter = eq(batch.column(offset + 2), &table)?;
+        if let Some(catalog) = &self.catalog {
+            let scalar = StringArray::new_scalar(catalog.as_str());
+            filter = and(&filter, &eq(batch.column(offset), &scalar)?)?;
+        }
+        if let Some(db_schema) = &self.db_schema {
+            let scalar = StringArray::new_scalar(db_schema.as_str());
+            filter = and(&filter, &eq(batch.column(offset + 1), &scalar)?)?;
+        }
+        Ok(filter)
+    }
+}
+
+/// A builder for [`CommandGetExportedKeys`], [`CommandGetImportedKeys`]
+/// and [`CommandGetCrossReference`] responses.
+///
+/// All three commands share the same result schema:
+///
+/// * pk_catalog_name: utf8,
+/// * pk_db_schema_name: utf8,
+/// * pk_table_name: utf8 not null,
+/// * pk_column_name: utf8 not null,
+/// * fk_catalog_name: utf8,
+/// * fk_db_schema_name: utf8,
+/// * fk_table_name: utf8 not null,
+/// * fk_column_name: utf8 not null,
+/// * key_sequence: int32 not null,
+/// * fk_key_name: utf8,
+/// * pk_key_name: utf8,
+/// * update_rule: uint8 not null,
+/// * delete_rule: uint8 not null
+pub struct GetForeignKeysBuilder {
+    // Restricts the primary key table, if any
+    pk_filter: Option<TableFilter>,
+    // Restricts the foreign key table, if any
+    fk_filter: Option<TableFilter>,
+    // Whether to order the results by the foreign key table rather
+    // than by the primary key table
+    order_by_fk: bool,
+    keys: Vec<ForeignKey>,
+}
+
+impl CommandGetExportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetExportedKeys> for GetForeignKeysBuilder {
+    fn from(value: CommandGetExportedKeys) -> Self {
+        Self::new_exported(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl CommandGetImportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetImportedKeys> for GetForeignKeysBuilder {
+    fn from(value: CommandGetImportedKeys) -> Self {
+        Self::new_imported(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl CommandGetCrossReference {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetCrossReference> for GetForeignKeysBuilder {
+    fn from(value: CommandGetCrossReference) -> Self {
+        Self::new_cross_reference(
+            value.pk_catalog,
+            value.pk_db_schema,
+            value.pk_table,
+            value.fk_catalog,
+            value.fk_db_schema,
+            value.fk_table,
+        )
+    }
+}
+
+impl GetForeignKeysBuilder {
+    /// Create a builder for the foreign keys that reference the primary key
+    /// of the given table, i.e. a [`CommandGetExportedKeys`] response
+    ///
+    /// Results are ordered by fk_catalog_name, fk_db_schema_name,
+    /// fk_table_name, fk_key_name, then key_sequence.
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`: Specifies the catalog of the table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema of the table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the primary key table.
+    pub fn new_exported(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            pk_filter: Some(TableFilter {
+                catalog: catalog.map(|v| v.into()),
+                db_schema: db_schema.map(|v| v.into()),
+                table: table.into(),
+            }),
+            fk_filter: None,
+            order_by_fk: true,
+            keys: Vec::new(),
+        }
+    }
+
+    /// Create a builder for the foreign keys of the given table,
+    /// i.e. a [`CommandGetImportedKeys`] response
+    ///
+    /// Results are ordered by pk_catalog_name, pk_db_schema_name,
+    /// pk_table_name, pk_key_name, then key_sequence.
+    ///
+    /// See [`Self::new_exported`] for a description of the parameters,
+    /// where `table` specifies the foreign key table.
+    pub fn new_imported(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            pk_filter: None,
+            fk_filter: Some(TableFilter {
+                catalog: catalog.map(|v| v.into()),
+                db_schema: db_schema.map(|v| v.into()),
+                table: table.into(),
+            }),
+            order_by_fk: false,
+            keys: Vec::new(),
+        }
+    }
+
+    /// Create a builder for the foreign keys of the foreign key table that
+    /// reference the primary key table, i.e. a [`CommandGetCrossReference`] response
+    ///
+    /// Results are ordered by pk_catalog_name, pk_db_schema_name,
+    /// pk_table_name, pk_key_name, then key_sequence.
+    pub fn new_cross_reference(
+        pk_catalog: Option<impl Into<String>>,
+        pk_db_schema: Option<impl Into<String>>,
+        pk_table: impl Into<String>,
+        fk_catalog: Option<impl Into<String>>,
+        fk_db_schema: Option<impl Into<String>>,
+        fk_table: impl Into<String>,
+    ) -> Self {
+        Self {
+            pk_filter: Some(TableFilter {
+                catalog: pk_catalog.map(|v| v.into()),
+                db_schema: pk_db_schema.map(|v| v.into()),
+                table: pk_table.into(),
+            }),
+            fk_filter: Some(TableFilter {
+                catalog: fk_catalog.map(|v| v.into()),
+                db_schema: fk_db_schema.map(|v| v.into()),
+                table: fk_table.into(),
+            }),
+            order_by_fk: false,
+            keys: Vec::new(),
+        }
+    }
+
+    /// Append a row describing one column of a foreign key
+    pub fn append(&mut self, key: ForeignKey) {
+        self.keys.push(key);
+    }
+
+    /// builds a `RecordBatch` with the correct schema for the response
+    pub fn build(self) -> Result<RecordBatch> {
+        let schema = self.schema();
+        let Self {
+            pk_filter,
+            fk_filter,
+            order_by_fk,
+            keys,
+        } = self;
+
+        let mut pk_catalog_name = StringBuilder::new();
+        let mut pk_db_schema_name = StringBuilder::new();
+        let mut pk_table_name = StringBuilder::new();
+        let mut pk_column_name = StringBuilder::new();
+        let mut fk_catalog_name = StringBuilder::new();
+        let mut fk_db_schema_name = StringBuilder::new();
+        let mut fk_table_name = StringBuilder::new();
+        let mut fk_column_name = StringBuilder::new();
+        let mut key_sequence = Int32Builder::new();
+        let mut fk_key_name = StringBuilder::new();
+        let mut pk_key_name = StringBuilder::new();
+        let mut update_rule = UInt8Builder::new();
+        let mut delete_rule = UInt8Builder::new();
+
+        for key in keys {
+            pk_catalog_name.append_value(key.pk_catalog_name);
+            pk_db_schema_name.append_value(key.pk_db_schema_name);
+            pk_table_name.append_value(key.pk_table_name);
+            pk_column_name.append_value(key.pk_column_name);
+            fk_catalog_name.append_value(key.fk_catalog_name);
+            fk_db_schema_name.append_value(key.fk_db_schema_name);
+            fk_table_name.append_value(key.fk_table_name);
+            fk_column_name.append_value(key.fk_column_name);
+            key_sequence.append_value(key.key_sequence);
+            fk_key_name.append_option(key.fk_key_name);
+            pk_key_name.append_option(key.pk_key_name);
+            update_rule.append_value(key.update_rule as u8);
+            delete_rule.append_value(key.delete_rule as u8);
+        }
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(pk_catalog_name.finish()) as ArrayRef,
+                Arc::new(pk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(pk_table_name.finish()) as ArrayRef,
+                Arc::new(pk_column_name.finish()) as ArrayRef,
+                Arc::new(fk_catalog_name.finish()) as ArrayRef,
+                Arc::new(fk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(fk_table_name.finish()) as ArrayRef,
+                Arc::new(fk_column_name.finish()) as ArrayRef,
+                Arc::new(key_sequence.finish()) as ArrayRef,
+                Arc::new(fk_key_name.finish()) as ArrayRef,
+                Arc::new(pk_key_name.finish()) as ArrayRef,
+                Arc::new(update_rule.finish()) as ArrayRef,
+                Arc::new(delete_rule.finish()) as ArrayRef,
+            ],
+        )?;
+
+        let filter = match (pk_filter, fk_filter) {
+            (Some(pk), Some(fk)) => Some(and(&pk.evaluate(&batch, 0)?, &fk.evaluate(&batch, 4)?)?),
+            (Some(pk), None) => Some(pk.evaluate(&batch, 0)?),
+            (None, Some(fk)) => Some(fk.evaluate(&batch, 4)?),
+            (None, None) => None,
+        };
+        let filtered_batch = match filter {
+            Some(filter) => filter_record_batch(&batch, &filter)?,
+            None => batch,
+        };
+
+        // Order filtered results by catalog, schema, table and key name of
+        // either the foreign key or the primary key table, then key_sequence
+        let sort_cols = match order_by_fk {
+            true => filtered_batch.project(&[4, 5, 6, 9, 8])?,
+            false => filtered_batch.project(&[0, 1, 2, 10, 8])?,
+        };
+        let indices = lexsort_to_indices(sort_cols.columns());
+        let columns = filtered_batch
+            .columns()
+            .iter()
+            .map(|c| take(c, &indices, None))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(RecordBatch::try_new(filtered_batch.schema(), columns)?)
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetExportedKeys`], [`CommandGetImportedKeys`]
+    /// and [`CommandGetCrossReference`]
+    pub fn schema(&self) -> SchemaRef {
+        get_foreign_keys_schema()
+    }
+}
+
+/// Reads [`ForeignKey`]s from a [`CommandGetExportedKeys`],
+/// [`CommandGetImportedKeys`] or [`CommandGetCrossReference`] response
+///
+/// This is the inverse of [`GetForeignKeysBuilder`]
+#[derive(Debug, Clone)]
+pub struct ForeignKeysReader {
+    batch: RecordBatch,
+    row: usize,
+}
+
+impl ForeignKeysReader {
+    /// Create a new [`ForeignKeysReader`], returning an error if `batch` does
+    /// not have the schema of a foreign key response
+    pub fn try_new(batch: RecordBatch) -> Result<Self> {
+        check_schema(&batch, &get_foreign_keys_schema())?;
+        Ok(Self { batch, row: 0 })
+    }
+
+    fn read_row(&self, row: usize) -> Result<ForeignKey> {
+        let batch = &self.batch;
+        Ok(ForeignKey {
+            pk_catalog_name: optional_string(batch, 0, row).unwrap_or_default(),
+            pk_db_schema_name: optional_string(batch, 1, row).unwrap_or_default(),
+            pk_table_name: required_string(batch, 2, row)?,
+            pk_column_name: required_string(batch, 3, row)?,
+            fk_catalog_name: optional_string(batch, 4, row).unwrap_or_default(),
+            fk_db_schema_name: optional_string(batch, 5, row).unwrap_or_default(),
+            fk_table_name: required_string(batch, 6, row)?,
+            fk_column_name: required_string(batch, 7, row)?,
+            key_sequence: required_primitive::<Int32Type>(batch, 8, row)?,
+            fk_key_name: optional_string(batch, 9, row),
+            pk_key_name: optional_string(batch, 10, row),
+            update_rule: read_rule(batch, 11, row)?,
+            delete_rule: read_rule(batch, 12, row)?,
+        })
+    }
+}
+
+impl Iterator for ForeignKeysReader {
+    type Item = Result<ForeignKey>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.batch.num_rows() {
+            return None;
+        }
+        let row = self.row;
+        self.row += 1;
+        Some(self.read_row(row))
+    }
+}
+
+/// Reads an [`UpdateDeleteRules`] from its `uint8` encoding
+fn read_rule(batch: &RecordBatch, index: usize, row: usize) -> Result<UpdateDeleteRules> {
+    let value = required_primitive::<UInt8Type>(batch, index, row)?;
+    UpdateDeleteRules::try_from(i32::from(value)).map_err(|_| {
+        FlightError::DecodeError(format!(
+            "Invalid value {value} for {} at row {row}",
+            batch.schema().field(index).name()
+        ))
+    })
+}
+
+fn get_foreign_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_FOREIGN_KEYS_SCHEMA)
+}
+
+/// The schema for GetExportedKeys, GetImportedKeys and GetCrossReference
+static GET_FOREIGN_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("pk_catalog_name", DataType::Utf8, false),
+        Field::new("pk_db_schema_name", DataType::Utf8, false),
+        Field::new("pk_table_name", DataType::Utf8, false),
+        Field::new("pk_column_name", DataType::Utf8, false),
+        Field::new("fk_catalog_name", DataType::Utf8, false),
+        Field::new("fk_db_schema_name", DataType::Utf8, false),
+        Field::new("fk_table_name", DataType::Utf8, false),
+        Field::new("fk_column_name", DataType::Utf8, false),
+        Field::new("key_sequence", DataType::Int32, false),
+        Field::new("fk_key_name", DataType::Utf8, true),
+        Field::new("pk_key_name", DataType::Utf8, true),
+        Field::new("update_rule", DataType::UInt8, false),
+        Field::new("delete_rule", DataType::UInt8, false),
+    ]))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::metadata::tests::assert_batches_eq;
+
+    fn key(pk_table: &str, fk_table: &str, fk_key_name: &str, key_sequence: i32) -> ForeignKey {
+        ForeignKey {
+            pk_catalog_name: "catalog".into(),
+            pk_db_schema_name: "schema".into(),
+            pk_table_name: pk_table.into(),
+            pk_column_name: format!("pk_{key_sequence}"),
+            fk_catalog_name: "catalog".into(),
+            fk_db_schema_name: "schema".into(),
+            fk_table_name: fk_table.into(),
+            fk_column_name: format!("fk_{key_sequence}"),
+            key_sequence,
+            fk_key_name: Some(fk_key_name.into()),
+            pk_key_name: None,
+            update_rule: UpdateDeleteRules::Cascade,
+            delete_rule: UpdateDeleteRules::Restrict,
+        }
+    }
+
+    fn append_keys(builder: &mut GetForeignKeysBuilder) {
+        builder.append(key("a_table", "c_table", "c_fk", 2));
+        builder.append(key("b_table", "c_table", "c_fk_b", 1));
+        builder.append(key("a_table", "b_table", "b_fk", 1));
+        builder.append(key("a_table", "c_table", "c_fk", 1));
+    }
+
+    #[test]
+    fn test_exported_keys() {
+        let query = CommandGetExportedKeys {
+            catalog: None,
+            db_schema: Some("schema".into()),
+            table: "a_table".into(),
+        };
+        let mut builder = query.into_builder();
+        append_keys(&mut builder);
+        let batch = builder.build().unwrap();
+        assert_eq!(batch.schema(), get_foreign_keys_schema());
+        let batch = batch.project(&[2, 3, 6, 7, 8, 9, 11, 12]).unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+---------------+----------------+---------------+----------------+--------------+-------------+-------------+-------------+",
+                "| pk_table_name | pk_column_name | fk_table_name | fk_column_name | key_sequence | fk_key_name | update_rule | delete_rule |",
+                "+---------------+----------------+---------------+----------------+--------------+-------------+-------------+-------------+",
+                "| a_table       | pk_1           | b_table       | fk_1           | 1            | b_fk        | 0           | 1           |",
+                "| a_table       | pk_1           | c_table       | fk_1           | 1            | c_fk        | 0           | 1           |",
+                "| a_table       | pk_2           | c_table       | fk_2           | 2            | c_fk        | 0           | 1           |",
+                "+---------------+----------------+---------------+----------------+--------------+-------------+-------------+-------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_imported_keys() {
+        let query = CommandGetImportedKeys {
+            catalog: Some("catalog".into()),
+            db_schema: None,
+            table: "c_table".into(),
+        };
+        let mut builder = query.into_builder();
+        append_keys(&mut builder);
+        let batch = builder.build().unwrap();
+        let batch = batch.project(&[2, 6, 8, 9]).unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+---------------+---------------+--------------+-------------+",
+                "| pk_table_name | fk_table_name | key_sequence | fk_key_name |",
+                "+---------------+---------------+--------------+-------------+",
+                "| a_table       | c_table       | 1            | c_fk        |",
+                "| a_table       | c_table       | 2            | c_fk        |",
+                "| b_table       | c_table       | 1            | c_fk_b      |",
+                "+---------------+---------------+--------------+-------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_cross_reference() {
+        let query = CommandGetCrossReference {
+            pk_catalog: None,
+            pk_db_schema: None,
+            pk_table: "a_table".into(),
+            fk_catalog: None,
+            fk_db_schema: Some("other_schema".into()),
+            fk_table: "c_table".into(),
+        };
+        let mut builder = query.into_builder();
+        append_keys(&mut builder);
+        assert_eq!(builder.build().unwrap().num_rows(), 0);
+
+        let query = CommandGetCrossReference {
+            pk_catalog: None,
+            pk_db_schema: None,
+            pk_table: "a_table".into(),
+            fk_catalog: None,
+            fk_db_schema: None,
+            fk_table: "c_table".into(),
+        };
+        let mut builder = query.into_builder();
+        append_keys(&mut builder);
+        let batch = builder.build().unwrap();
+        let batch = batch.project(&[2, 6, 8]).unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+---------------+---------------+--------------+",
+                "| pk_table_name | fk_table_name | key_sequence |",
+                "+---------------+---------------+--------------+",
+                "| a_table       | c_table       | 1            |",
+                "| a_table       | c_table       | 2            |",
+                "+---------------+---------------+--------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_foreign_keys_reader() {
+        let query = CommandGetCrossReference {
+            pk_catalog: None,
+            pk_db_schema: None,
+            pk_table: "a_table".into(),
+            fk_catalog: None,
+            fk_db_schema: None,
+            fk_table: "c_table".into(),
+        };
+        let mut builder = query.into_builder();
+        append_keys(&mut builder);
+        let batch = builder.build().unwrap();
+
+        let keys = ForeignKeysReader::try_new(batch)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                key("a_table", "c_table", "c_fk", 1),
+                key("a_table", "c_table", "c_fk", 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_foreign_keys_reader_invalid_rule() {
+        let mut builder =
+            GetForeignKeysBuilder::new_exported(None::<String>, None::<String>, "a_table");
+        builder.append(key("a_table", "b_table", "b_fk", 1));
+        let batch = builder.build().unwrap();
+
+        let mut columns = batch.columns().to_vec();
+        columns[12] = Arc::new(arrow_array::UInt8Array::from(vec![42]));
+        let batch = RecordBatch::try_new(batch.schema(), columns).unwrap();
+
+        let mut reader = ForeignKeysReader::try_new(batch).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Decode error: Invalid value 42 for delete_rule at row 0"
+        );
+    }
+}
@@ -20,33 +20,59 @@
 //!
 //! - [`GetCatalogsBuilder`] for building responses to [`CommandGetCatalogs`] queries.
 //! - [`GetDbSchemasBuilder`] for building responses to [`CommandGetDbSchemas`] queries.
+//! - [`GetTableTypesBuilder`] for building responses to [`CommandGetTableTypes`] queries.
 //! - [`GetTablesBuilder`]for building responses to [`CommandGetTables`] queries.
 //! - [`SqlInfoDataBuilder`]for building responses to [`CommandGetSqlInfo`] queries.
 //! - [`XdbcTypeInfoDataBuilder`]for building responses to [`CommandGetXdbcTypeInfo`] queries.
+//! - [`GetPrimaryKeysBuilder`] for building responses to [`CommandGetPrimaryKeys`] queries.
+//! - [`GetForeignKeysBuilder`] for building responses to [`CommandGetExportedKeys`],
+//!   [`CommandGetImportedKeys`] and [`CommandGetCrossReference`] queries.
+//!
+//! The corresponding readers, such as [`TablesReader`], convert the
+//! `RecordBatch`es of these responses back into iterators of typed values,
+//! verifying that they conform to the schemas defined by the FlightSQL
+//! specification.
 //!
 //! [`CommandGetCatalogs`]: crate::sql::CommandGetCatalogs
 //! [`CommandGetDbSchemas`]: crate::sql::CommandGetDbSchemas
+//! [`CommandGetTableTypes`]: crate::sql::CommandGetTableTypes
 //! [`CommandGetTables`]: crate::sql::CommandGetTables
 //! [`CommandGetSqlInfo`]: crate::sql::CommandGetSqlInfo
 //! [`CommandGetXdbcTypeInfo`]: crate::sql::CommandGetXdbcTypeInfo
+//! [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+//! [`CommandGetExportedKeys`]: crate::sql::CommandGetExportedKeys
+//! [`CommandGetImportedKeys`]: crate::sql::CommandGetImportedKeys
+//! [`CommandGetCrossReference`]: crate::sql::CommandGetCrossReference
 
 mod catalogs;
 mod db_schemas;
+mod foreign_keys;
+mod primary_keys;
 mod sql_info;
 mod table_types;
 mod tables;
 mod xdbc_info;
 
-pub use catalogs::GetCatalogsBuilder;
-pub use db_schemas::GetDbSchemasBuilder;
+pub use catalogs::{CatalogsReader, GetCatalogsBuilder};
+pub use db_schemas::{DbSchemaInfo, DbSchemasReader, GetDbSchemasBuilder};
+pub use foreign_keys::{ForeignKey, ForeignKeysReader, GetForeignKeysBuilder};
+pub use primary_keys::{GetPrimaryKeysBuilder, PrimaryKey, PrimaryKeysReader};
 pub use sql_info::{SqlInfoData, SqlInfoDataBuilder};
-pub use tables::GetTablesBuilder;
+pub use table_types::{GetTableTypesBuilder, TableTypesReader};
+pub use tables::{GetTablesBuilder, TableInfo, TablesReader};
 pub use xdbc_info::{XdbcTypeInfo, XdbcTypeInfoData, XdbcTypeInfoDataBuilder};
 
+use arrow_array::cast::AsArray;
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::Array;
 use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
 use arrow_array::UInt32Array;
 use arrow_row::RowConverter;
 use arrow_row::SortField;
+use arrow_schema::Schema;
+
+use crate::error::{FlightError, Result};
 
 /// Helper function to sort all the columns in an array
 fn lexsort_to_indices(arrays: &[ArrayRef]) -> UInt32Array {
@@ -61,6 +87,72 @@ fn lexsort_to_indices(arrays: &[ArrayRef]) -> UInt32Array {
     UInt32Array::from_iter_values(sort.iter().map(|(i, _)| *i as u32))
 }
 
+/// Verifies that the columns of `batch` have the names and data types
+/// of `expected`, as defined by the FlightSQL specification
+fn check_schema(batch: &RecordBatch, expected: &Schema) -> Result<()> {
+    let actual = batch.schema();
+    let matches = actual.fields().len() == expected.fields().len()
+        && actual
+            .fields()
+            .iter()
+            .zip(expected.fields().iter())
+            .all(|(a, e)| a.name() == e.name() && a.data_type() == e.data_type());
+    if matches {
+        return Ok(());
+    }
+
+    let describe = |schema: &Schema| {
+        schema
+            .fields()
+            .iter()
+            .map(|f| format!("{}: {}", f.name(), f.data_type()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    Err(FlightError::DecodeError(format!(
+        "Expected FlightSQL metadata columns [{}] but got [{}]",
+        describe(expected),
+        describe(&actual)
+    )))
+}
+
+/// Returns an error if the value at `row` of the column at `index` is null
+fn check_not_null(batch: &RecordBatch, index: usize, row: usize) -> Result<()> {
+    if batch.column(index).is_null(row) {
+        return Err(FlightError::DecodeError(format!(
+            "Unexpected null in non-nullable column {} at row {row}",
+            batch.schema().field(index).name()
+        )));
+    }
+    Ok(())
+}
+
+/// Reads a utf8 value that may not be null
+fn required_string(batch: &RecordBatch, index: usize, row: usize) -> Result<String> {
+    check_not_null(batch, index, row)?;
+    Ok(batch
+        .column(index)
+        .as_string::<i32>()
+        .value(row)
+        .to_string())
+}
+
+/// Reads a utf8 value, returning `None` for nulls
+fn optional_string(batch: &RecordBatch, index: usize, row: usize) -> Option<String> {
+    let array = batch.column(index).as_string::<i32>();
+    array.is_valid(row).then(|| array.value(row).to_string())
+}
+
+/// Reads a primitive value that may not be null
+fn required_primitive<T: ArrowPrimitiveType>(
+    batch: &RecordBatch,
+    index: usize,
+    row: usize,
+) -> Result<T::Native> {
+    check_not_null(batch, index, row)?;
+    Ok(batch.column(index).as_primitive::<T>().value(row))
+}
+
 #[cfg(test)]
 mod tests {
     use arrow_array::RecordBatch;
@@ -0,0 +1,365 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`GetPrimaryKeysBuilder`] for building responses to [`CommandGetPrimaryKeys`] queries.
+//!
+//! [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+
+use std::sync::Arc;
+
+use arrow_arith::boolean::and;
+use arrow_array::builder::{Int32Builder, StringBuilder};
+use arrow_array::types::Int32Type;
+use arrow_array::{ArrayRef, RecordBatch, StringArray};
+use arrow_ord::cmp::eq;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow_select::{filter::filter_record_batch, take::take};
+use once_cell::sync::Lazy;
+
+use super::{
+    check_schema, lexsort_to_indices, optional_string, required_primitive, required_string,
+};
+use crate::error::*;
+use crate::sql::CommandGetPrimaryKeys;
+
+/// A builder for a [`CommandGetPrimaryKeys`] response.
+///
+/// Builds rows like this:
+///
+/// * catalog_name: utf8,
+/// * db_schema_name: utf8,
+/// * table_name: utf8 not null,
+/// * column_name: utf8 not null,
+/// * key_name: utf8,
+/// * key_sequence: int32 not null
+pub struct GetPrimaryKeysBuilder {
+    // Specifies the catalog of the table, if any
+    catalog_filter: Option<String>,
+    // Specifies the schema of the table, if any
+    db_schema_filter: Option<String>,
+    // Specifies the table to get the primary keys for
+    table_filter: String,
+    // array builder for catalog names
+    catalog_name: StringBuilder,
+    // array builder for db schema names
+    db_schema_name: StringBuilder,
+    // array builder for table names
+    table_name: StringBuilder,
+    // array builder for column names
+    column_name: StringBuilder,
+    // array builder for primary key names
+    key_name: StringBuilder,
+    // array builder for the positions of the columns in the keys
+    key_sequence: Int32Builder,
+}
+
+impl CommandGetPrimaryKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetPrimaryKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetPrimaryKeys> for GetPrimaryKeysBuilder {
+    fn from(value: CommandGetPrimaryKeys) -> Self {
+        Self::new(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl GetPrimaryKeysBuilder {
+    /// Create a new instance of [`GetPrimaryKeysBuilder`]
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`: Specifies the catalog of the table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema of the table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the table to get the primary keys for.
+    pub fn new(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            catalog_filter: catalog.map(|v| v.into()),
+            db_schema_filter: db_schema.map(|v| v.into()),
+            table_filter: table.into(),
+            catalog_name: StringBuilder::new(),
+            db_schema_name: StringBuilder::new(),
+            table_name: StringBuilder::new(),
+            column_name: StringBuilder::new(),
+            key_name: StringBuilder::new(),
+            key_sequence: Int32Builder::new(),
+        }
+    }
+
+    /// Append a row, for the column at position `key_sequence`, starting from 1, of
+    /// the primary key of a table
+    ///
+    /// In case the catalog or schema should be considered as empty, pass in an empty string '""'.
+    pub fn append(
+        &mut self,
+        catalog_name: impl AsRef<str>,
+        schema_name: impl AsRef<str>,
+        table_name: impl AsRef<str>,
+        column_name: impl AsRef<str>,
+        key_name: Option<&str>,
+        key_sequence: i32,
+    ) {
+        self.catalog_name.append_value(catalog_name);
+        self.db_schema_name.append_value(schema_name);
+        self.table_name.append_value(table_name);
+        self.column_name.append_value(column_name);
+        self.key_name.append_option(key_name);
+        self.key_sequence.append_value(key_sequence);
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetPrimaryKeys` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let schema = self.schema();
+        let Self {
+            catalog_filter,
+            db_schema_filter,
+            table_filter,
+            mut catalog_name,
+            mut db_schema_name,
+            mut table_name,
+            mut column_name,
+            mut key_name,
+            mut key_sequence,
+        } = self;
+
+        // Make the arrays
+        let catalog_name = catalog_name.finish();
+        let db_schema_name = db_schema_name.finish();
+        let table_name = table_name.finish();
+
+        let mut filter = eq(&table_name, &StringArray::new_scalar(table_filter))?;
+        if let Some(catalog_filter) = catalog_filter {
+            let scalar = StringArray::new_scalar(catalog_filter);
+            filter = and(&filter, &eq(&catalog_name, &scalar)?)?;
+        }
+        if let Some(db_schema_filter) = db_schema_filter {
+            let scalar = StringArray::new_scalar(db_schema_filter);
+            filter = and(&filter, &eq(&db_schema_name, &scalar)?)?;
+        }
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(catalog_name) as ArrayRef,
+                Arc::new(db_schema_name) as ArrayRef,
+                Arc::new(table_name) as ArrayRef,
+                Arc::new(column_name.finish()) as ArrayRef,
+                Arc::new(key_name.finish()) as ArrayRef,
+                Arc::new(key_sequence.finish()) as ArrayRef,
+            ],
+        )?;
+        let filtered_batch = filter_record_batch(&batch, &filter)?;
+
+        // Order filtered results by catalog_name, db_schema_name, table_name, key_name,
+        // then key_sequence
+        let sort_cols = filtered_batch.project(&[0, 1, 2, 4, 5])?;
+        let indices = lexsort_to_indices(sort_cols.columns());
+        let columns = filtered_batch
+            .columns()
+            .iter()
+            .map(|c| take(c, &indices, None))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(RecordBatch::try_new(filtered_batch.schema(), columns)?)
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetPrimaryKeys`]
+    pub fn schema(&self) -> SchemaRef {
+        get_primary_keys_schema()
+    }
+}
+
+/// One column of a primary key returned from a [`CommandGetPrimaryKeys`] query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimaryKey {
+    /// The catalog of the table, or an empty string if none
+    pub catalog_name: String,
+    /// The schema of the table, or an empty string if none
+    pub db_schema_name: String,
+    /// The name of the table
+    pub table_name: String,
+    /// The name of the column in the primary key
+    pub column_name: String,
+    /// The name of the primary key, if any
+    pub key_name: Option<String>,
+    /// The position of the column in the key, starting from 1
+    pub key_sequence: i32,
+}
+
+/// Reads [`PrimaryKey`]s from a [`CommandGetPrimaryKeys`] response
+///
+/// This is the inverse of [`GetPrimaryKeysBuilder`]
+#[derive(Debug, Clone)]
+pub struct PrimaryKeysReader {
+    batch: RecordBatch,
+    row: usize,
+}
+
+impl PrimaryKeysReader {
+    /// Create a new [`PrimaryKeysReader`], returning an error if `batch` does
+    /// not have the schema of a [`CommandGetPrimaryKeys`] response
+    pub fn try_new(batch: RecordBatch) -> Result<Self> {
+        check_schema(&batch, &get_primary_keys_schema())?;
+        Ok(Self { batch, row: 0 })
+    }
+
+    fn read_row(&self, row: usize) -> Result<PrimaryKey> {
+        Ok(PrimaryKey {
+            catalog_name: optional_string(&self.batch, 0, row).unwrap_or_default(),
+            db_schema_name: optional_string(&self.batch, 1, row).unwrap_or_default(),
+            table_name: required_string(&self.batch, 2, row)?,
+            column_name: required_string(&self.batch, 3, row)?,
+            key_name: optional_string(&self.batch, 4, row),
+            key_sequence: required_primitive::<Int32Type>(&self.batch, 5, row)?,
+        })
+    }
+}
+
+impl Iterator for PrimaryKeysReader {
+    type Item = Result<PrimaryKey>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.batch.num_rows() {
+            return None;
+        }
+        let row = self.row;
+        self.row += 1;
+        Some(self.read_row(row))
+    }
+}
+
+fn get_primary_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_PRIMARY_KEYS_SCHEMA)
+}
+
+/// The schema for GetPrimaryKeys
+static GET_PRIMARY_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, false),
+        Field::new("db_schema_name", DataType::Utf8, false),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("column_name", DataType::Utf8, false),
+        Field::new("key_name", DataType::Utf8, true),
+        Field::new("key_sequence", DataType::Int32, false),
+    ]))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::metadata::tests::assert_batches_eq;
+
+    fn append_keys(builder: &mut GetPrimaryKeysBuilder) {
+        builder.append("b_catalog", "a_schema", "a_table", "id", Some("pk"), 1);
+        builder.append("a_catalog", "a_schema", "a_table", "y", Some("pk"), 2);
+        builder.append("a_catalog", "a_schema", "b_table", "id", None, 1);
+        builder.append("a_catalog", "a_schema", "a_table", "x", Some("pk"), 1);
+        builder.append("a_catalog", "b_schema", "a_table", "id", Some("pk"), 1);
+    }
+
+    #[test]
+    fn test_primary_keys_are_filtered_and_sorted() {
+        let mut builder = GetPrimaryKeysBuilder::new(None::<String>, None::<String>, "a_table");
+        append_keys(&mut builder);
+        let batch = builder.build().unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| catalog_name | db_schema_name | table_name | column_name | key_name | key_sequence |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| a_catalog    | a_schema       | a_table    | x           | pk       | 1            |",
+                "| a_catalog    | a_schema       | a_table    | y           | pk       | 2            |",
+                "| a_catalog    | b_schema       | a_table    | id          | pk       | 1            |",
+                "| b_catalog    | a_schema       | a_table    | id          | pk       | 1            |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_builder_from_query() {
+        let query = CommandGetPrimaryKeys {
+            catalog: Some("a_catalog".into()),
+            db_schema: Some("a_schema".into()),
+            table: "b_table".into(),
+        };
+        let mut builder = query.into_builder();
+        append_keys(&mut builder);
+        let batch = builder.build().unwrap();
+        assert_eq!(batch.schema(), get_primary_keys_schema());
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| catalog_name | db_schema_name | table_name | column_name | key_name | key_sequence |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| a_catalog    | a_schema       | b_table    | id          |          | 1            |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_primary_keys_reader() {
+        let mut builder =
+            GetPrimaryKeysBuilder::new(Some("a_catalog"), Some("a_schema"), "a_table");
+        append_keys(&mut builder);
+        let batch = builder.build().unwrap();
+
+        let keys = PrimaryKeysReader::try_new(batch)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let key = |column_name: &str, key_sequence| PrimaryKey {
+            catalog_name: "a_catalog".into(),
+            db_schema_name: "a_schema".into(),
+            table_name: "a_table".into(),
+            column_name: column_name.into(),
+            key_name: Some("pk".into()),
+            key_sequence,
+        };
+        assert_eq!(keys, vec![key("x", 1), key("y", 2)]);
+    }
+
+    #[test]
+    fn test_primary_keys_reader_invalid_schema() {
+        let schema = Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("db_schema_name", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int64, false),
+        ]);
+        let batch = RecordBatch::new_empty(Arc::new(schema));
+
+        let err = PrimaryKeysReader::try_new(batch).unwrap_err();
+        assert!(err.to_string().contains("key_sequence: Int64"), "{err}");
+    }
+}
@@ -29,7 +29,7 @@ use once_cell::sync::Lazy;
 use crate::error::*;
 use crate::sql::CommandGetTableTypes;
 
-use super::lexsort_to_indices;
+use super::{check_schema, lexsort_to_indices, required_string};
 
 /// A builder for a [`CommandGetTableTypes`] response.
 ///
@@ -96,6 +96,37 @@ impl GetTableTypesBuilder {
     }
 }
 
+/// Reads the table types from a [`CommandGetTableTypes`] response
+///
+/// This is the inverse of [`GetTableTypesBuilder`]
+#[derive(Debug, Clone)]
+pub struct TableTypesReader {
+    batch: RecordBatch,
+    row: usize,
+}
+
+impl TableTypesReader {
+    /// Create a new [`TableTypesReader`], returning an error if `batch` does
+    /// not have the schema of a [`CommandGetTableTypes`] response
+    pub fn try_new(batch: RecordBatch) -> Result<Self> {
+        check_schema(&batch, &get_table_types_schema())?;
+        Ok(Self { batch, row: 0 })
+    }
+}
+
+impl Iterator for TableTypesReader {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.batch.num_rows() {
+            return None;
+        }
+        let row = self.row;
+        self.row += 1;
+        Some(required_string(&self.batch, 0, row))
+    }
+}
+
 fn get_table_types_schema() -> SchemaRef {
     Arc::clone(&GET_TABLE_TYPES_SCHEMA)
 }
@@ -155,4 +186,21 @@ mod tests {
 
         assert_eq!(schema_batch, ref_batch)
     }
+
+    #[test]
+    fn test_table_types_reader() {
+        let table_types = TableTypesReader::try_new(get_ref_batch())
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            table_types,
+            vec![
+                "a_table_type",
+                "b_table_type",
+                "c_table_type",
+                "d_table_type"
+            ]
+        );
+    }
 }
@@ -23,14 +23,16 @@ use std::sync::Arc;
 
 use arrow_arith::boolean::{and, or};
 use arrow_array::builder::{BinaryBuilder, StringBuilder};
+use arrow_array::cast::AsArray;
 use arrow_array::{ArrayRef, RecordBatch, StringArray};
+use arrow_ipc::convert::try_schema_from_ipc_buffer;
 use arrow_ord::cmp::eq;
 use arrow_schema::{DataType, Field, Schema, SchemaRef};
 use arrow_select::{filter::filter_record_batch, take::take};
 use arrow_string::like::like;
 use once_cell::sync::Lazy;
 
-use super::lexsort_to_indices;
+use super::{check_not_null, check_schema, lexsort_to_indices, optional_string, required_string};
 use crate::error::*;
 use crate::sql::CommandGetTables;
 use crate::{IpcMessage, IpcWriteOptions, SchemaAsIpc};
@@ -280,6 +282,77 @@ impl GetTablesBuilder {
     }
 }
 
+/// A table returned from a [`CommandGetTables`] query
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableInfo {
+    /// The catalog of the table, or an empty string if none
+    pub catalog_name: String,
+    /// The schema of the table, or an empty string if none
+    pub db_schema_name: String,
+    /// The name of the table
+    pub table_name: String,
+    /// The type of the table, such as "TABLE" or "VIEW"
+    pub table_type: String,
+    /// The schema of the table, if it was requested with `include_schema`
+    pub table_schema: Option<Schema>,
+}
+
+/// Reads [`TableInfo`]s from a [`CommandGetTables`] response
+///
+/// This is the inverse of [`GetTablesBuilder`], and accepts responses with
+/// or without the `table_schema` column.
+#[derive(Debug, Clone)]
+pub struct TablesReader {
+    batch: RecordBatch,
+    row: usize,
+}
+
+impl TablesReader {
+    /// Create a new [`TablesReader`], returning an error if `batch` does
+    /// not have the schema of a [`CommandGetTables`] response
+    pub fn try_new(batch: RecordBatch) -> Result<Self> {
+        let reader = Self { batch, row: 0 };
+        check_schema(&reader.batch, &get_tables_schema(reader.include_schema()))?;
+        Ok(reader)
+    }
+
+    /// Returns true if the response contains the schema of each table
+    pub fn include_schema(&self) -> bool {
+        self.batch.num_columns() == GET_TABLES_SCHEMA_WITH_TABLE_SCHEMA.fields().len()
+    }
+
+    fn read_row(&self, row: usize) -> Result<TableInfo> {
+        let table_schema = if self.include_schema() {
+            check_not_null(&self.batch, 4, row)?;
+            let bytes = self.batch.column(4).as_binary::<i32>().value(row);
+            Some(try_schema_from_ipc_buffer(bytes)?)
+        } else {
+            None
+        };
+
+        Ok(TableInfo {
+            catalog_name: optional_string(&self.batch, 0, row).unwrap_or_default(),
+            db_schema_name: optional_string(&self.batch, 1, row).unwrap_or_default(),
+            table_name: required_string(&self.batch, 2, row)?,
+            table_type: required_string(&self.batch, 3, row)?,
+            table_schema,
+        })
+    }
+}
+
+impl Iterator for TablesReader {
+    type Item = Result<TableInfo>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.batch.num_rows() {
+            return None;
+        }
+        let row = self.row;
+        self.row += 1;
+        Some(self.read_row(row))
+    }
+}
+
 fn get_tables_schema(include_schema: bool) -> SchemaRef {
     if include_schema {
         Arc::clone(&GET_TABLES_SCHEMA_WITH_TABLE_SCHEMA)
@@ -473,4 +546,54 @@ mod tests {
         let table_batch = builder.build().unwrap();
         assert_eq!(table_batch, ref_batch);
     }
+
+    #[test]
+    fn test_tables_reader() {
+        let table_schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let mut builder = GetTablesBuilder::new(
+            None::<String>,
+            None::<String>,
+            None::<String>,
+            None::<String>,
+            true,
+        );
+        builder
+            .append("", "a_schema", "a_table", "TABLE", &table_schema)
+            .unwrap();
+        let batch = builder.build().unwrap();
+
+        let reader = TablesReader::try_new(batch.clone()).unwrap();
+        assert!(reader.include_schema());
+        let tables = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(
+            tables,
+            vec![TableInfo {
+                catalog_name: "".into(),
+                db_schema_name: "a_schema".into(),
+                table_name: "a_table".into(),
+                table_type: "TABLE".into(),
+                table_schema: Some(table_schema),
+            }]
+        );
+
+        // without the table_schema column
+        let reader = TablesReader::try_new(batch.project(&[0, 1, 2, 3]).unwrap()).unwrap();
+        assert!(!reader.include_schema());
+        let tables = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(tables[0].table_name, "a_table");
+        assert_eq!(tables[0].table_schema, None);
+    }
+
+    #[test]
+    fn test_tables_reader_invalid_schema() {
+        let batch = get_ref_batch().project(&[0, 1, 3, 2]).unwrap();
+        let err = TablesReader::try_new(batch).unwrap_err();
+        assert!(
+            err.to_string().contains("but got [catalog_name: Utf8, db_schema_name: Utf8, table_type: Utf8, table_name: Utf8]"),
+            "{err}"
+        );
+    }
 }
@@ -18,6 +18,10 @@
 //! Helper trait [`FlightSqlService`] for implementing a [`FlightService`] that implements FlightSQL.
 
 use std::pin::Pin;
+use std::sync::Arc;
+
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::SchemaRef;
 
 use futures::{stream::Peekable, Stream, StreamExt};
 use prost::Message;
@@ -36,10 +40,13 @@ use super::{
     CommandStatementUpdate, DoPutPreparedStatementResult, DoPutUpdateResult, ProstMessageExt,
     SqlInfo, TicketStatementQuery,
 };
+use crate::sql::metadata::{SqlInfoDataBuilder, XdbcTypeInfoDataBuilder};
 use crate::{
-    flight_service_server::FlightService, gen::PollInfo, Action, ActionType, Criteria, Empty,
-    FlightData, FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse, PutResult,
-    SchemaResult, Ticket,
+    flight_service_server::FlightService, gen::PollInfo, Action, ActionType, CloseSessionRequest,
+    CloseSessionResult, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    GetSessionOptionsRequest, GetSessionOptionsResult, HandshakeRequest, HandshakeResponse,
+    PutResult, SchemaAsIpc, SchemaResult, SetSessionOptionsRequest, SetSessionOptionsResult,
+    Ticket,
 };
 
 pub(crate) static CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
@@ -50,6 +57,9 @@ pub(crate) static END_TRANSACTION: &str = "EndTransaction";
 pub(crate) static BEGIN_SAVEPOINT: &str = "BeginSavepoint";
 pub(crate) static END_SAVEPOINT: &str = "EndSavepoint";
 pub(crate) static CANCEL_QUERY: &str = "CancelQuery";
+pub(crate) static SET_SESSION_OPTIONS: &str = "SetSessionOptions";
+pub(crate) static GET_SESSION_OPTIONS: &str = "GetSessionOptions";
+pub(crate) static CLOSE_SESSION: &str = "CloseSession";
 
 /// Implements FlightSqlService to handle the flight sql protocol
 #[tonic::async_trait]
@@ -117,113 +127,123 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     }
 
     /// Get a FlightInfo for listing catalogs.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_catalogs`].
     async fn get_flight_info_catalogs(
         &self,
-        _query: CommandGetCatalogs,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetCatalogs,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_catalogs has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetCatalogs(query), request)
     }
 
     /// Get a FlightInfo for listing schemas.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_schemas`].
     async fn get_flight_info_schemas(
         &self,
-        _query: CommandGetDbSchemas,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetDbSchemas,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_schemas has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetDbSchemas(query), request)
     }
 
     /// Get a FlightInfo for listing tables.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_tables`].
     async fn get_flight_info_tables(
         &self,
-        _query: CommandGetTables,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetTables,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_tables has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetTables(query), request)
     }
 
     /// Get a FlightInfo to extract information about the table types.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_table_types`].
     async fn get_flight_info_table_types(
         &self,
-        _query: CommandGetTableTypes,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetTableTypes,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_table_types has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetTableTypes(query), request)
     }
 
     /// Get a FlightInfo for retrieving other information (See SqlInfo).
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_sql_info`].
     async fn get_flight_info_sql_info(
         &self,
-        _query: CommandGetSqlInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetSqlInfo,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_sql_info has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetSqlInfo(query), request)
     }
 
-    /// Get a FlightInfo to extract information about primary and foreign keys.
+    /// Get a FlightInfo to extract information about primary keys.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_primary_keys`].
     async fn get_flight_info_primary_keys(
         &self,
-        _query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetPrimaryKeys,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetPrimaryKeys(query), request)
     }
 
     /// Get a FlightInfo to extract information about exported keys.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_exported_keys`].
     async fn get_flight_info_exported_keys(
         &self,
-        _query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetExportedKeys,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetExportedKeys(query), request)
     }
 
     /// Get a FlightInfo to extract information about imported keys.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_imported_keys`].
     async fn get_flight_info_imported_keys(
         &self,
-        _query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetImportedKeys,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetImportedKeys(query), request)
     }
 
     /// Get a FlightInfo to extract information about cross reference.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_cross_reference`].
     async fn get_flight_info_cross_reference(
         &self,
-        _query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetCrossReference,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_cross_reference has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetCrossReference(query), request)
     }
 
     /// Get a FlightInfo to extract information about the supported XDBC types.
+    ///
+    /// By default, returns the result schema of the command and a single
+    /// endpoint whose ticket is passed to [`Self::do_get_xdbc_type_info`].
     async fn get_flight_info_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_xdbc_type_info has no default implementation",
-        ))
+        metadata_flight_info(Command::CommandGetXdbcTypeInfo(query), request)
     }
 
     /// Implementors may override to handle additional calls to get_flight_info()
@@ -238,6 +258,18 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
         )))
     }
 
+    /// Implementors may override to handle additional calls to get_schema()
+    async fn get_schema_fallback(
+        &self,
+        cmd: Command,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented(format!(
+            "get_schema: The defined request is invalid: {}",
+            cmd.type_url()
+        )))
+    }
+
     // do_get
 
     /// Get a FlightDataStream containing the query results.
@@ -551,6 +583,43 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
         ))
     }
 
+    /// Set options of the session of the request, creating a new session
+    /// if required.
+    ///
+    /// Sessions are typically tracked with a cookie, such as
+    /// `arrow_flight_session_id`, set in the response headers.
+    async fn do_action_set_session_options(
+        &self,
+        _query: SetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<SetSessionOptionsResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_set_session_options has no default implementation",
+        ))
+    }
+
+    /// Get the options of the session of the request
+    async fn do_action_get_session_options(
+        &self,
+        _query: GetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<GetSessionOptionsResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_get_session_options has no default implementation",
+        ))
+    }
+
+    /// Close the session of the request
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        _request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_close_session has no default implementation",
+        ))
+    }
+
     /// do_exchange
     /// Implementors may override to handle additional calls to do_exchange()
     async fn do_exchange_fallback(
@@ -656,9 +725,21 @@ where
 
     async fn get_schema(
         &self,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<SchemaResult>, Status> {
-        Err(Status::unimplemented("Not yet implemented"))
+        let message = Any::decode(&*request.get_ref().cmd).map_err(decode_error_to_status)?;
+        let cmd = Command::try_from(message).map_err(arrow_error_to_status)?;
+
+        match metadata_schema(&cmd) {
+            Some(schema) => {
+                let options = IpcWriteOptions::default();
+                let result = SchemaAsIpc::new(&schema, &options)
+                    .try_into()
+                    .map_err(arrow_error_to_status)?;
+                Ok(Response::new(result))
+            }
+            None => self.get_schema_fallback(cmd, request).await,
+        }
     }
 
     async fn do_get(
@@ -822,6 +903,27 @@ where
                 Response Message: ActionCancelQueryResult"
                 .into(),
         };
+        let set_session_options_action_type = ActionType {
+            r#type: SET_SESSION_OPTIONS.to_string(),
+            description: "Set options of the current session\n
+                Request Message: SetSessionOptionsRequest\n
+                Response Message: SetSessionOptionsResult"
+                .into(),
+        };
+        let get_session_options_action_type = ActionType {
+            r#type: GET_SESSION_OPTIONS.to_string(),
+            description: "Get the options of the current session\n
+                Request Message: GetSessionOptionsRequest\n
+                Response Message: GetSessionOptionsResult"
+                .into(),
+        };
+        let close_session_action_type = ActionType {
+            r#type: CLOSE_SESSION.to_string(),
+            description: "Close the current session\n
+                Request Message: CloseSessionRequest\n
+                Response Message: CloseSessionResult"
+                .into(),
+        };
         let mut actions: Vec<Result<ActionType, Status>> = vec![
             Ok(create_prepared_statement_action_type),
             Ok(close_prepared_statement_action_type),
@@ -831,6 +933,9 @@ where
             Ok(begin_savepoint_action_type),
             Ok(end_savepoint_action_type),
             Ok(cancel_query_action_type),
+            Ok(set_session_options_action_type),
+            Ok(get_session_options_action_type),
+            Ok(close_session_action_type),
         ];
 
         if let Some(mut custom_actions) = self.list_custom_actions().await {
@@ -955,6 +1060,31 @@ where
                 body: stmt.as_any().encode_to_vec().into(),
             })]);
             return Ok(Response::new(Box::pin(output)));
+        } else if request.get_ref().r#type == SET_SESSION_OPTIONS {
+            // The session actions are Flight actions, whose messages are not wrapped in `Any`
+            let cmd = SetSessionOptionsRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_set_session_options(cmd, request).await?;
+            let output = futures::stream::iter(vec![Ok(super::super::gen::Result {
+                body: result.encode_to_vec().into(),
+            })]);
+            return Ok(Response::new(Box::pin(output)));
+        } else if request.get_ref().r#type == GET_SESSION_OPTIONS {
+            let cmd = GetSessionOptionsRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_get_session_options(cmd, request).await?;
+            let output = futures::stream::iter(vec![Ok(super::super::gen::Result {
+                body: result.encode_to_vec().into(),
+            })]);
+            return Ok(Response::new(Box::pin(output)));
+        } else if request.get_ref().r#type == CLOSE_SESSION {
+            let cmd = CloseSessionRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_close_session(cmd, request).await?;
+            let output = futures::stream::iter(vec![Ok(super::super::gen::Result {
+                body: result.encode_to_vec().into(),
+            })]);
+            return Ok(Response::new(Box::pin(output)));
         }
 
         self.do_action_fallback(request).await
@@ -968,6 +1098,43 @@ where
     }
 }
 
+/// Returns a [`FlightInfo`] for a metadata command, describing the result
+/// schema of the command and a single endpoint whose ticket is the command
+fn metadata_flight_info(
+    command: Command,
+    request: Request<FlightDescriptor>,
+) -> Result<Response<FlightInfo>, Status> {
+    let schema = metadata_schema(&command).ok_or_else(|| {
+        Status::internal(format!("{} is not a metadata command", command.type_url()))
+    })?;
+    let ticket = Ticket::new(command.into_any().encode_to_vec());
+    let info = FlightInfo::new()
+        .try_with_schema(&schema)
+        .map_err(arrow_error_to_status)?
+        .with_endpoint(FlightEndpoint::new().with_ticket(ticket))
+        .with_descriptor(request.into_inner());
+    Ok(Response::new(info))
+}
+
+/// Returns the result schema defined by the Flight SQL specification for
+/// metadata commands, or `None` for any other command
+fn metadata_schema(command: &Command) -> Option<SchemaRef> {
+    let schema = match command {
+        Command::CommandGetCatalogs(cmd) => cmd.into_builder().schema(),
+        Command::CommandGetDbSchemas(cmd) => cmd.clone().into_builder().schema(),
+        Command::CommandGetTables(cmd) => cmd.clone().into_builder().schema(),
+        Command::CommandGetTableTypes(cmd) => cmd.into_builder().schema(),
+        Command::CommandGetSqlInfo(_) => Arc::new(SqlInfoDataBuilder::schema().clone()),
+        Command::CommandGetPrimaryKeys(cmd) => cmd.clone().into_builder().schema(),
+        Command::CommandGetExportedKeys(cmd) => cmd.clone().into_builder().schema(),
+        Command::CommandGetImportedKeys(cmd) => cmd.clone().into_builder().schema(),
+        Command::CommandGetCrossReference(cmd) => cmd.clone().into_builder().schema(),
+        Command::CommandGetXdbcTypeInfo(_) => XdbcTypeInfoDataBuilder::new().schema(),
+        _ => return None,
+    };
+    Some(schema)
+}
+
 fn decode_error_to_status(err: prost::DecodeError) -> Status {
     Status::invalid_argument(format!("{err:?}"))
 }
@@ -22,19 +22,30 @@ mod common;
 use crate::common::fixture::TestFixture;
 use arrow_array::{RecordBatch, UInt64Array};
 use arrow_flight::{
-    decode::FlightRecordBatchStream, encode::FlightDataEncoderBuilder, error::FlightError, Action,
-    ActionType, CancelFlightInfoRequest, CancelFlightInfoResult, CancelStatus, Criteria, Empty,
-    FlightClient, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
-    HandshakeResponse, PollInfo, PutResult, RenewFlightEndpointRequest, Ticket,
+    client::{CookieInterceptor, FlightClientInterceptor},
+    close_session_result,
+    decode::FlightRecordBatchStream,
+    encode::FlightDataEncoderBuilder,
+    error::FlightError,
+    put::{
+        decode_sequence_number, encode_sequence_number, ExponentialBackoff, NoRetry, ResumablePut,
+    },
+    set_session_options_result, Action, ActionType, CancelFlightInfoRequest,
+    CancelFlightInfoResult, CancelStatus, CloseSessionRequest, CloseSessionResult, Criteria, Empty,
+    FlightClient, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    GetSessionOptionsRequest, GetSessionOptionsResult, HandshakeRequest, HandshakeResponse,
+    PollInfo, PutResult, RenewFlightEndpointRequest, SessionOptionValue, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket,
 };
 use arrow_schema::{DataType, Field, Schema};
 use bytes::Bytes;
 use common::server::TestFlightServer;
 use futures::{Future, StreamExt, TryStreamExt};
 use prost::Message;
-use tonic::Status;
+use tonic::{metadata::MetadataMap, Status};
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 #[tokio::test]
 async fn test_handshake() {
@@ -178,6 +189,132 @@ async fn test_poll_flight_info_error() {
     .await;
 }
 
+#[tokio::test]
+async fn test_poll_until_complete() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+        let request = FlightDescriptor::new_cmd(b"My Command".to_vec());
+        let retry1 = FlightDescriptor::new_cmd(b"retry 1".to_vec());
+        let retry2 = FlightDescriptor::new_cmd(b"retry 2".to_vec());
+
+        let running = |retry: &FlightDescriptor, progress| PollInfo {
+            info: Some(test_flight_info(&request)),
+            flight_descriptor: Some(retry.clone()),
+            progress: Some(progress),
+            expiration_time: None,
+        };
+        let expected_response = test_poll_info(&request);
+        test_server.set_poll_flight_info_responses(vec![
+            Ok(running(&retry1, 0.2)),
+            Ok(running(&retry2, 0.6)),
+            Ok(expected_response.clone()),
+        ]);
+
+        let response = client
+            .poll_until_complete(
+                request.clone(),
+                Duration::from_millis(1),
+                futures::future::pending(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(Some(response), expected_response.info);
+        // each poll uses the retry descriptor of the previous response
+        assert_eq!(
+            test_server.take_poll_flight_info_requests(),
+            vec![request, retry1, retry2]
+        );
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_poll_until_complete_cancel() {
+    do_test(|test_server, mut client| async move {
+        let request = FlightDescriptor::new_cmd(b"My Command".to_vec());
+        let info = test_flight_info(&request);
+        test_server.set_poll_flight_info_response(Ok(PollInfo {
+            info: Some(info.clone()),
+            flight_descriptor: Some(request.clone()),
+            progress: None,
+            expiration_time: None,
+        }));
+        let response = CancelFlightInfoResult::new(CancelStatus::Cancelled).encode_to_vec();
+        test_server.set_do_action_response(vec![Ok(arrow_flight::Result::new(response))]);
+
+        let err = client
+            .poll_until_complete(request, Duration::from_secs(60), futures::future::ready(()))
+            .await
+            .unwrap_err();
+        expect_status(
+            err,
+            Status::cancelled("PollFlightInfo cancelled before the query completed"),
+        );
+
+        // the query is cancelled on the server
+        let expected_request = Action::new(
+            "CancelFlightInfo",
+            CancelFlightInfoRequest::new(info).encode_to_vec(),
+        );
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_poll_until_complete_expired() {
+    do_test(|test_server, mut client| async move {
+        let request = FlightDescriptor::new_cmd(b"My Command".to_vec());
+        test_server.set_poll_flight_info_response(Ok(PollInfo {
+            info: None,
+            flight_descriptor: Some(request.clone()),
+            progress: None,
+            expiration_time: Some(prost_types::Timestamp {
+                seconds: 1,
+                nanos: 0,
+            }),
+        }));
+
+        let err = client
+            .poll_until_complete(
+                request,
+                Duration::from_millis(1),
+                futures::future::pending(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: PollFlightInfo retry descriptor expired before the query completed"
+        );
+        assert_eq!(test_server.take_poll_flight_info_requests().len(), 1);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_poll_until_complete_error() {
+    do_test(|test_server, mut client| async move {
+        let request = FlightDescriptor::new_cmd(b"My Command".to_vec());
+
+        let e = Status::unauthenticated("DENIED");
+        test_server.set_poll_flight_info_response(Err(e.clone()));
+
+        let err = client
+            .poll_until_complete(
+                request,
+                Duration::from_millis(1),
+                futures::future::pending(),
+            )
+            .await
+            .unwrap_err();
+        expect_status(err, e);
+    })
+    .await;
+}
+
 // TODO more negative  tests (like if there are endpoints defined, etc)
 
 #[tokio::test]
@@ -329,6 +466,103 @@ async fn test_do_put() {
     .await;
 }
 
+#[tokio::test]
+async fn test_resumable_put() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let batches = test_put_batches();
+        let descriptor = FlightDescriptor::new_path(vec!["uploads".to_string()]);
+        let mut put = ResumablePut::new(descriptor.clone(), batches[0].schema(), batches.clone());
+
+        // the connection is lost after the first batch is acknowledged
+        test_server.set_do_put_responses(vec![
+            vec![Ok(put_ack(0)), Err(Status::unavailable("connection lost"))],
+            vec![Ok(put_ack(2))],
+        ]);
+
+        let err = put.send(&mut client).await.unwrap_err();
+        expect_status(err, Status::unavailable("connection lost"));
+        assert_eq!(put.last_acknowledged(), Some(0));
+        assert_eq!(put.pending(), 2);
+        assert!(!put.is_complete());
+
+        put.send(&mut client).await.unwrap();
+        assert_eq!(put.last_acknowledged(), Some(2));
+        assert_eq!(put.pending(), 0);
+        assert!(put.is_complete());
+        assert_eq!(put.results(), &[put_ack(0), put_ack(2)]);
+
+        // the second call only sends the batches that were not acknowledged
+        let requests = test_server.take_do_put_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(sequence_numbers(&requests[0]), vec![0, 1, 2]);
+        assert_eq!(sequence_numbers(&requests[1]), vec![1, 2]);
+        assert_eq!(requests[1][0].flight_descriptor, Some(descriptor));
+        assert_eq!(decode_put_request(&requests[1]).await, batches[1..]);
+
+        // a complete upload is not sent again
+        put.send(&mut client).await.unwrap();
+        assert!(test_server.take_do_put_requests().is_empty());
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_resumable_put_run() {
+    do_test(|test_server, mut client| async move {
+        let batches = test_put_batches();
+        let descriptor = FlightDescriptor::new_path(vec!["uploads".to_string()]);
+        let mut put = ResumablePut::new(descriptor, batches[0].schema(), batches.clone());
+
+        test_server.set_do_put_responses(vec![
+            vec![Err(Status::unavailable("connection lost"))],
+            vec![Ok(put_ack(1)), Err(Status::unavailable("connection lost"))],
+            vec![],
+        ]);
+
+        let retry_policy = ExponentialBackoff::new(2).with_initial_delay(Duration::from_millis(1));
+        put.run(&mut client, &retry_policy).await.unwrap();
+        assert!(put.is_complete());
+        assert_eq!(put.last_acknowledged(), Some(2));
+
+        let requests = test_server.take_do_put_requests();
+        let sequence_numbers: Vec<_> = requests.iter().map(|r| sequence_numbers(r)).collect();
+        assert_eq!(
+            sequence_numbers,
+            vec![vec![0, 1, 2], vec![0, 1, 2], vec![2]]
+        );
+        assert_eq!(decode_put_request(&requests[2]).await, batches[2..]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_resumable_put_run_error() {
+    do_test(|test_server, mut client| async move {
+        let batches = test_put_batches();
+        let descriptor = FlightDescriptor::new_path(vec!["uploads".to_string()]);
+        let mut put = ResumablePut::new(descriptor, batches[0].schema(), batches);
+
+        // errors are returned if the retry policy gives up
+        test_server.set_do_put_responses(vec![vec![Err(Status::unavailable("connection lost"))]]);
+        let err = put.run(&mut client, &NoRetry).await.unwrap_err();
+        expect_status(err, Status::unavailable("connection lost"));
+
+        // or if they are not transient
+        test_server.set_do_put_responses(vec![vec![Err(Status::invalid_argument("bad data"))]]);
+        let retry_policy = ExponentialBackoff::new(2).with_initial_delay(Duration::from_millis(1));
+        let err = put.run(&mut client, &retry_policy).await.unwrap_err();
+        expect_status(err, Status::invalid_argument("bad data"));
+
+        assert_eq!(test_server.take_do_put_requests().len(), 2);
+        assert_eq!(put.last_acknowledged(), None);
+        assert_eq!(put.pending(), 3);
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn test_do_put_error_server() {
     do_test(|test_server, mut client| async move {
@@ -513,6 +747,82 @@ async fn test_do_exchange() {
     .await;
 }
 
+#[tokio::test]
+async fn test_do_exchange_batches() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+        let output_flight_data = test_flight_data2().await;
+        test_server
+            .set_do_exchange_response(output_flight_data.clone().into_iter().map(Ok).collect());
+
+        let descriptor = FlightDescriptor::new_cmd(b"exchange".to_vec());
+        let response: Vec<_> = client
+            .do_exchange_batches(
+                descriptor.clone(),
+                batch.schema(),
+                futures::stream::iter(vec![Ok(batch.clone())]),
+            )
+            .await
+            .expect("error making request")
+            .try_collect()
+            .await
+            .expect("Error streaming data");
+
+        let expected_batches: Vec<_> = FlightRecordBatchStream::new_from_flight_data(
+            futures::stream::iter(output_flight_data).map(Ok),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(response, expected_batches);
+
+        // the server received the descriptor, schema and batch
+        let request = test_server.take_do_exchange_request().unwrap();
+        assert_eq!(request[0].flight_descriptor, Some(descriptor));
+        let decoder =
+            FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(request).map(Ok));
+        let sent: Vec<_> = decoder.try_collect().await.unwrap();
+        assert_eq!(sent, vec![batch]);
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_do_exchange_batches_client_error() {
+    do_test(|test_server, mut client| async move {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+        test_server.set_do_exchange_response(vec![]);
+
+        let descriptor = FlightDescriptor::new_cmd(b"exchange".to_vec());
+        let batches = vec![
+            Ok(batch.clone()),
+            Err(FlightError::protocol("client error")),
+        ];
+        let response = client
+            .do_exchange_batches(descriptor, batch.schema(), futures::stream::iter(batches))
+            .await
+            .expect("error making request")
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+
+        // the client error is returned by the response stream
+        assert_eq!(response.to_string(), "Protocol error: client error");
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn test_do_exchange_error() {
     do_test(|test_server, mut client| async move {
@@ -1004,6 +1314,152 @@ async fn test_cancel_flight_info() {
     .await;
 }
 
+#[tokio::test]
+async fn test_set_session_options() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = SetSessionOptionsResult::default().with_error(
+            "unknown",
+            set_session_options_result::ErrorValue::InvalidName,
+        );
+        let response = expected_response.encode_to_vec();
+        test_server.set_do_action_response(vec![Ok(arrow_flight::Result::new(response))]);
+
+        let request = SetSessionOptionsRequest::new([
+            ("catalog", SessionOptionValue::from("sales")),
+            ("unknown", SessionOptionValue::from(true)),
+        ]);
+        let actual_response = client
+            .set_session_options(request.clone())
+            .await
+            .expect("error making request");
+
+        let expected_request = Action::new("SetSessionOptions", request.encode_to_vec());
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_get_session_options() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = GetSessionOptionsResult::new([
+            ("catalog", SessionOptionValue::from("sales")),
+            ("timeout", SessionOptionValue::from(30_i64)),
+            (
+                "tags",
+                SessionOptionValue::from(vec!["a".to_string(), "b".to_string()]),
+            ),
+        ]);
+        let response = expected_response.encode_to_vec();
+        test_server.set_do_action_response(vec![Ok(arrow_flight::Result::new(response))]);
+
+        let actual_response = client
+            .get_session_options()
+            .await
+            .expect("error making request");
+
+        let expected_request = Action::new(
+            "GetSessionOptions",
+            GetSessionOptionsRequest {}.encode_to_vec(),
+        );
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_close_session() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = CloseSessionResult::new(close_session_result::Status::Closed);
+        let response = expected_response.encode_to_vec();
+        test_server.set_do_action_response(vec![Ok(arrow_flight::Result::new(response))]);
+
+        let actual_response = client.close_session().await.expect("error making request");
+
+        let expected_request = Action::new("CloseSession", CloseSessionRequest {}.encode_to_vec());
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_close_session_error_no_response() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        test_server.set_do_action_response(vec![]);
+
+        let err = client.close_session().await.unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: Received no response for close_session call"
+        );
+        // server still got the request
+        let expected_request = Action::new("CloseSession", CloseSessionRequest {}.encode_to_vec());
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[test]
+fn test_cookie_interceptor() {
+    let interceptor = CookieInterceptor::new();
+
+    // no cookie is sent before the server sets one
+    let mut request = MetadataMap::new();
+    interceptor.on_request("DoAction", &mut request).unwrap();
+    assert!(request.get("cookie").is_none());
+
+    let mut response = MetadataMap::new();
+    response.append(
+        "set-cookie",
+        "arrow_flight_session_id=abc; Path=/; HttpOnly"
+            .parse()
+            .unwrap(),
+    );
+    response.append("set-cookie", "other=1".parse().unwrap());
+    interceptor.on_response("DoAction", &response);
+    assert_eq!(
+        interceptor.cookie("arrow_flight_session_id").as_deref(),
+        Some("abc")
+    );
+
+    let mut request = MetadataMap::new();
+    interceptor.on_request("DoAction", &mut request).unwrap();
+    assert_eq!(
+        request.get("cookie").unwrap(),
+        "arrow_flight_session_id=abc; other=1"
+    );
+
+    // expired cookies are removed
+    let mut response = MetadataMap::new();
+    response.append("set-cookie", "other=; Max-Age=0".parse().unwrap());
+    interceptor.on_response("DoAction", &response);
+    let mut request = MetadataMap::new();
+    interceptor.on_request("DoAction", &mut request).unwrap();
+    assert_eq!(
+        request.get("cookie").unwrap(),
+        "arrow_flight_session_id=abc"
+    );
+
+    interceptor.clear();
+    assert_eq!(interceptor.cookie("arrow_flight_session_id"), None);
+}
+
 #[tokio::test]
 async fn test_cancel_flight_info_error_no_response() {
     do_test(|test_server, mut client| async move {
@@ -1079,6 +1535,90 @@ async fn test_renew_flight_endpoint_error_no_response() {
     .await;
 }
 
+/// Records the calls it intercepts and injects a header into each request
+#[derive(Debug, Default)]
+struct RecordingInterceptor {
+    requests: Mutex<Vec<String>>,
+    responses: Mutex<Vec<(String, Option<String>)>>,
+}
+
+impl FlightClientInterceptor for RecordingInterceptor {
+    fn on_request(&self, method: &str, metadata: &mut MetadataMap) -> Result<(), FlightError> {
+        metadata.insert("intercepted", method.parse().unwrap());
+        self.requests.lock().unwrap().push(method.to_string());
+        Ok(())
+    }
+
+    fn on_response(&self, method: &str, metadata: &MetadataMap) {
+        let header = metadata
+            .get("test-resp-header")
+            .map(|v| v.to_str().unwrap().to_string());
+        self.responses
+            .lock()
+            .unwrap()
+            .push((method.to_string(), header));
+    }
+}
+
+#[tokio::test]
+async fn test_interceptor() {
+    do_test(|test_server, mut client| async move {
+        let interceptor = Arc::new(RecordingInterceptor::default());
+        client.add_interceptor(interceptor.clone());
+
+        let ticket = Ticket {
+            ticket: Bytes::from("my awesome flight ticket"),
+        };
+        test_server.set_do_get_response(vec![]);
+        client.do_get(ticket).await.unwrap();
+        let metadata = test_server.take_last_request_metadata().unwrap();
+        assert_eq!(metadata.get("intercepted").unwrap(), "DoGet");
+
+        // errors are passed to the interceptor as well
+        let request = FlightDescriptor::new_cmd(b"foo".to_vec());
+        client.get_flight_info(request).await.unwrap_err();
+        let metadata = test_server.take_last_request_metadata().unwrap();
+        assert_eq!(metadata.get("intercepted").unwrap(), "GetFlightInfo");
+
+        assert_eq!(
+            *interceptor.requests.lock().unwrap(),
+            vec!["DoGet".to_string(), "GetFlightInfo".to_string()]
+        );
+        assert_eq!(
+            *interceptor.responses.lock().unwrap(),
+            vec![
+                ("DoGet".to_string(), Some("some_val".to_string())),
+                ("GetFlightInfo".to_string(), None),
+            ]
+        );
+    })
+    .await;
+}
+
+/// Rejects every request
+#[derive(Debug)]
+struct RejectingInterceptor;
+
+impl FlightClientInterceptor for RejectingInterceptor {
+    fn on_request(&self, _method: &str, _metadata: &mut MetadataMap) -> Result<(), FlightError> {
+        Err(FlightError::protocol("rejected"))
+    }
+}
+
+#[tokio::test]
+async fn test_interceptor_error() {
+    do_test(|test_server, mut client| async move {
+        client.add_interceptor(Arc::new(RejectingInterceptor));
+
+        let err = client.handshake("foo").await.unwrap_err();
+        assert_eq!(err.to_string(), "Protocol error: rejected");
+
+        // the server was never contacted
+        assert!(test_server.take_handshake_request().is_none());
+    })
+    .await;
+}
+
 async fn test_flight_data() -> Vec<FlightData> {
     let batch = RecordBatch::try_from_iter(vec![(
         "col",
@@ -1094,6 +1634,40 @@ async fn test_flight_data() -> Vec<FlightData> {
         .unwrap()
 }
 
+fn test_put_batches() -> Vec<RecordBatch> {
+    (0..3)
+        .map(|i| {
+            RecordBatch::try_from_iter(vec![(
+                "col",
+                Arc::new(UInt64Array::from_iter([i, i + 10])) as _,
+            )])
+            .unwrap()
+        })
+        .collect()
+}
+
+fn put_ack(sequence: u64) -> PutResult {
+    PutResult {
+        app_metadata: encode_sequence_number(sequence),
+    }
+}
+
+/// Return the sequence numbers of the messages of a `do_put` request
+fn sequence_numbers(request: &[FlightData]) -> Vec<u64> {
+    request
+        .iter()
+        .filter_map(|data| decode_sequence_number(&data.app_metadata))
+        .collect()
+}
+
+async fn decode_put_request(request: &[FlightData]) -> Vec<RecordBatch> {
+    let stream = futures::stream::iter(request.to_vec()).map(Ok);
+    FlightRecordBatchStream::new_from_flight_data(stream)
+        .try_collect()
+        .await
+        .unwrap()
+}
+
 async fn test_flight_data2() -> Vec<FlightData> {
     let batch = RecordBatch::try_from_iter(vec![(
         "col2",
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
 use arrow_array::RecordBatch;
@@ -90,18 +91,27 @@ impl TestFlightServer {
     /// Specify the response returned from the next call to poll_flight_info
     #[allow(dead_code)]
     pub fn set_poll_flight_info_response(&self, response: Result<PollInfo, Status>) {
+        self.set_poll_flight_info_responses(vec![response]);
+    }
+
+    /// Specify the responses returned from the next calls to poll_flight_info
+    #[allow(dead_code)]
+    pub fn set_poll_flight_info_responses(&self, responses: Vec<Result<PollInfo, Status>>) {
         let mut state = self.state.lock().expect("mutex not poisoned");
-        state.poll_flight_info_response.replace(response);
+        state.poll_flight_info_responses = responses.into();
     }
 
     /// Take and return last poll_flight_info request sent to the server,
     #[allow(dead_code)]
     pub fn take_poll_flight_info_request(&self) -> Option<FlightDescriptor> {
-        self.state
-            .lock()
-            .expect("mutex not poisoned")
-            .poll_flight_info_request
-            .take()
+        self.take_poll_flight_info_requests().pop()
+    }
+
+    /// Take and return all poll_flight_info requests sent to the server,
+    #[allow(dead_code)]
+    pub fn take_poll_flight_info_requests(&self) -> Vec<FlightDescriptor> {
+        let mut state = self.state.lock().expect("mutex not poisoned");
+        std::mem::take(&mut state.poll_flight_info_requests)
     }
 
     /// Specify the response returned from the next call to `do_get`
@@ -124,18 +134,27 @@ impl TestFlightServer {
     /// Specify the response returned from the next call to `do_put`
     #[allow(dead_code)]
     pub fn set_do_put_response(&self, response: Vec<Result<PutResult, Status>>) {
+        self.set_do_put_responses(vec![response]);
+    }
+
+    /// Specify the responses returned from the next calls to `do_put`
+    #[allow(dead_code)]
+    pub fn set_do_put_responses(&self, responses: Vec<Vec<Result<PutResult, Status>>>) {
         let mut state = self.state.lock().expect("mutex not poisoned");
-        state.do_put_response.replace(response);
+        state.do_put_responses = responses.into();
     }
 
     /// Take and return last do_put request sent to the server,
     #[allow(dead_code)]
     pub fn take_do_put_request(&self) -> Option<Vec<FlightData>> {
-        self.state
-            .lock()
-            .expect("mutex not poisoned")
-            .do_put_request
-            .take()
+        self.take_do_put_requests().pop()
+    }
+
+    /// Take and return all do_put requests sent to the server,
+    #[allow(dead_code)]
+    pub fn take_do_put_requests(&self) -> Vec<Vec<FlightData>> {
+        let mut state = self.state.lock().expect("mutex not poisoned");
+        std::mem::take(&mut state.do_put_requests)
     }
 
     /// Specify the response returned from the next call to `do_exchange`
@@ -252,18 +271,18 @@ struct State {
     pub get_flight_info_request: Option<FlightDescriptor>,
     /// The next response to return from `get_flight_info`
     pub get_flight_info_response: Option<Result<FlightInfo, Status>>,
-    /// The last `poll_flight_info` request received
-    pub poll_flight_info_request: Option<FlightDescriptor>,
-    /// The next response to return from `poll_flight_info`
-    pub poll_flight_info_response: Option<Result<PollInfo, Status>>,
+    /// The `poll_flight_info` requests received
+    pub poll_flight_info_requests: Vec<FlightDescriptor>,
+    /// The next responses to return from `poll_flight_info`
+    pub poll_flight_info_responses: VecDeque<Result<PollInfo, Status>>,
     /// The last do_get request received
     pub do_get_request: Option<Ticket>,
     /// The next response returned from `do_get`
     pub do_get_response: Option<Vec<Result<RecordBatch, Status>>>,
-    /// The last do_put request received
-    pub do_put_request: Option<Vec<FlightData>>,
-    /// The next response returned from `do_put`
-    pub do_put_response: Option<Vec<Result<PutResult, Status>>>,
+    /// The `do_put` requests received
+    pub do_put_requests: Vec<Vec<FlightData>>,
+    /// The next responses returned from `do_put`
+    pub do_put_responses: VecDeque<Vec<Result<PutResult, Status>>>,
     /// The last do_exchange request received
     pub do_exchange_request: Option<Vec<FlightData>>,
     /// The next response returned from `do_exchange`
@@ -364,10 +383,10 @@ impl FlightService for TestFlightServer {
     ) -> Result<Response<PollInfo>, Status> {
         self.save_metadata(&request);
         let mut state = self.state.lock().expect("mutex not poisoned");
-        state.poll_flight_info_request = Some(request.into_inner());
+        state.poll_flight_info_requests.push(request.into_inner());
         let response = state
-            .poll_flight_info_response
-            .take()
+            .poll_flight_info_responses
+            .pop_front()
             .unwrap_or_else(|| Err(Status::internal("No poll_flight_info response configured")))?;
         Ok(Response::new(response))
     }
@@ -429,11 +448,11 @@ impl FlightService for TestFlightServer {
 
         let mut state = self.state.lock().expect("mutex not poisoned");
 
-        state.do_put_request = Some(do_put_request);
+        state.do_put_requests.push(do_put_request);
 
         let response = state
-            .do_put_response
-            .take()
+            .do_put_responses
+            .pop_front()
             .ok_or_else(|| Status::internal("No do_put response configured"))?;
 
         let stream = futures::stream::iter(response).map_err(Into::into);
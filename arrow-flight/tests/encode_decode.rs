@@ -17,20 +17,28 @@
 
 //! Tests for round trip encoding / decoding
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use arrow_array::{ArrayRef, RecordBatch};
+use arrow_array::{builder::StringDictionaryBuilder, types::Int32Type, ArrayRef, RecordBatch};
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_descriptor::DescriptorType;
-use arrow_flight::FlightDescriptor;
 use arrow_flight::{
     decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream},
-    encode::FlightDataEncoderBuilder,
+    encode::{DictionaryHandling, FlightDataEncoderBuilder},
     error::FlightError,
+    metrics::FlightMetrics,
 };
+use arrow_flight::{FlightData, FlightDescriptor};
 use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
 use bytes::Bytes;
-use futures::{StreamExt, TryStreamExt};
+use futures::{Stream, StreamExt, TryStreamExt};
 
 mod common;
 use common::utils::{make_dictionary_batch, make_primitive_batch, make_view_batches};
@@ -414,6 +422,211 @@ async fn test_mismatched_schema_message() {
     .await;
 }
 
+#[tokio::test]
+async fn test_metrics() {
+    let encoder_metrics = Arc::new(CountingMetrics::default());
+    let decoder_metrics = Arc::new(CountingMetrics::default());
+
+    let input = vec![make_dictionary_batch(5), make_dictionary_batch(9)];
+    let encode_stream = FlightDataEncoderBuilder::default()
+        .with_dictionary_handling(DictionaryHandling::Resend)
+        .with_metrics(encoder_metrics.clone())
+        .build(futures::stream::iter(input.clone()).map(Ok));
+    let output: Vec<_> = FlightRecordBatchStream::new_from_flight_data(encode_stream)
+        .with_metrics(decoder_metrics.clone())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(input, output);
+
+    // schema message, then a dictionary and a record batch message per batch
+    assert_eq!(encoder_metrics.messages_sent.load(Ordering::Relaxed), 5);
+    assert_eq!(decoder_metrics.messages_received.load(Ordering::Relaxed), 5);
+    let bytes_sent = encoder_metrics.bytes_sent.load(Ordering::Relaxed);
+    assert!(bytes_sent > 0);
+    assert_eq!(
+        decoder_metrics.bytes_received.load(Ordering::Relaxed),
+        bytes_sent
+    );
+
+    // the dictionary of the second batch replaces the first
+    assert_eq!(encoder_metrics.dictionary_resets.load(Ordering::Relaxed), 1);
+    assert_eq!(decoder_metrics.dictionary_resets.load(Ordering::Relaxed), 1);
+
+    assert_eq!(encoder_metrics.batches_encoded.load(Ordering::Relaxed), 2);
+    assert_eq!(encoder_metrics.batches_decoded.load(Ordering::Relaxed), 0);
+    assert_eq!(decoder_metrics.batches_encoded.load(Ordering::Relaxed), 0);
+    assert_eq!(decoder_metrics.batches_decoded.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn test_metrics_delta_dictionary() {
+    let metrics = Arc::new(CountingMetrics::default());
+
+    let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+    builder.extend([Some("a"), Some("b"), Some("a")]);
+    let a1 = builder.finish_cloned();
+    builder.extend([Some("c"), Some("b")]);
+    let a2 = builder.finish_cloned();
+    let input = vec![
+        RecordBatch::try_from_iter(vec![("a", Arc::new(a1) as ArrayRef)]).unwrap(),
+        RecordBatch::try_from_iter(vec![("a", Arc::new(a2) as ArrayRef)]).unwrap(),
+    ];
+    let encode_stream = FlightDataEncoderBuilder::default()
+        .with_dictionary_handling(DictionaryHandling::Delta)
+        .build(futures::stream::iter(input.clone()).map(Ok));
+    let output: Vec<_> = FlightRecordBatchStream::new_from_flight_data(encode_stream)
+        .with_metrics(metrics.clone())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(input, output);
+
+    // the second dictionary is sent as a delta, which is not a reset
+    assert_eq!(metrics.messages_received.load(Ordering::Relaxed), 5);
+    assert_eq!(metrics.dictionary_resets.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn test_decoder_max_message_size() {
+    let input = vec![make_primitive_batch(5), make_primitive_batch(200)];
+    let flight_data = encode(input.clone()).await;
+    let max_size = flight_data.iter().map(message_size).max().unwrap();
+
+    let output: Vec<_> =
+        FlightRecordBatchStream::new_from_flight_data(flight_data_stream(flight_data.clone()))
+            .with_max_message_size(max_size)
+            .try_collect()
+            .await
+            .unwrap();
+    assert_eq!(input, output);
+
+    let err = FlightRecordBatchStream::new_from_flight_data(flight_data_stream(flight_data))
+        .with_max_message_size(max_size - 1)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Protocol error: Received FlightData message of {max_size} bytes, \
+             which exceeds the maximum message size of {} bytes",
+            max_size - 1
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_decoder_read_ahead() {
+    let input: Vec<_> = (0..5).map(|_| make_primitive_batch(10)).collect();
+    let flight_data = encode(input.clone()).await;
+    let batch_size = message_size(&flight_data[1]);
+
+    // by default, messages are only read when requested
+    let reads = Arc::new(AtomicUsize::new(0));
+    let mut decoder = FlightDataDecoder::new(counting_stream(flight_data.clone(), reads.clone()));
+    decoder.next().await.unwrap().unwrap();
+    assert_eq!(reads.load(Ordering::Relaxed), 1);
+    decoder.next().await.unwrap().unwrap();
+    assert_eq!(reads.load(Ordering::Relaxed), 2);
+
+    // messages are read ahead until the limits are reached
+    let reads = Arc::new(AtomicUsize::new(0));
+    let mut decoder = FlightDataDecoder::new(counting_stream(flight_data.clone(), reads.clone()))
+        .with_max_buffered_bytes(2 * batch_size)
+        .with_max_buffered_batches(2);
+    let schema = decoder.next().await.unwrap().unwrap();
+    assert!(matches!(schema.payload, DecodedPayload::Schema(_)));
+    // the schema and the first batch are decoded, the second is buffered
+    assert_eq!(reads.load(Ordering::Relaxed), 3);
+    decoder.next().await.unwrap().unwrap();
+    assert_eq!(reads.load(Ordering::Relaxed), 4);
+
+    let output: Vec<_> = FlightRecordBatchStream::new(decoder)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(input[1..], output);
+    assert_eq!(reads.load(Ordering::Relaxed), flight_data.len());
+
+    // errors are returned after the messages read before them
+    let mut flight_data: Vec<_> = flight_data.into_iter().take(3).map(Ok).collect();
+    flight_data.push(Err(FlightError::protocol("connection lost")));
+    let mut decoder =
+        FlightRecordBatchStream::new_from_flight_data(futures::stream::iter(flight_data))
+            .with_max_buffered_bytes(usize::MAX)
+            .with_max_buffered_batches(usize::MAX);
+    assert_eq!(decoder.next().await.unwrap().unwrap(), input[0]);
+    assert_eq!(decoder.next().await.unwrap().unwrap(), input[1]);
+    let err = decoder.next().await.unwrap().unwrap_err();
+    assert_eq!(err.to_string(), "Protocol error: connection lost");
+    assert!(decoder.next().await.is_none());
+}
+
+async fn encode(input: Vec<RecordBatch>) -> Vec<FlightData> {
+    FlightDataEncoderBuilder::default()
+        .build(futures::stream::iter(input).map(Ok))
+        .try_collect()
+        .await
+        .unwrap()
+}
+
+fn message_size(data: &FlightData) -> usize {
+    data.data_header.len() + data.data_body.len() + data.app_metadata.len()
+}
+
+fn flight_data_stream(
+    flight_data: Vec<FlightData>,
+) -> impl Stream<Item = Result<FlightData, FlightError>> {
+    futures::stream::iter(flight_data).map(Ok)
+}
+
+/// A stream of `flight_data` that counts the messages read in `reads`
+fn counting_stream(
+    flight_data: Vec<FlightData>,
+    reads: Arc<AtomicUsize>,
+) -> impl Stream<Item = Result<FlightData, FlightError>> {
+    flight_data_stream(flight_data).inspect(move |_| {
+        reads.fetch_add(1, Ordering::Relaxed);
+    })
+}
+
+/// [`FlightMetrics`] that counts what is reported
+#[derive(Debug, Default)]
+struct CountingMetrics {
+    messages_sent: AtomicUsize,
+    bytes_sent: AtomicUsize,
+    messages_received: AtomicUsize,
+    bytes_received: AtomicUsize,
+    dictionary_resets: AtomicUsize,
+    batches_encoded: AtomicUsize,
+    batches_decoded: AtomicUsize,
+}
+
+impl FlightMetrics for CountingMetrics {
+    fn message_sent(&self, bytes: usize) {
+        self.messages_sent.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn message_received(&self, bytes: usize) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn dictionary_reset(&self) {
+        self.dictionary_resets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn batch_encoded(&self, _elapsed: Duration) {
+        self.batches_encoded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn batch_decoded(&self, _elapsed: Duration) {
+        self.batches_decoded.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 /// Encodes input as a FlightData stream, and then decodes it using
 /// FlightRecordBatchStream and validates the decoded record batches
 /// match the input.
@@ -23,16 +23,23 @@ use crate::common::utils::make_primitive_batch;
 use arrow_array::RecordBatch;
 use arrow_flight::decode::FlightRecordBatchStream;
 use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_client::FlightServiceClient;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
 use arrow_flight::sql::server::{FlightSqlService, PeekableFlightDataStream};
 use arrow_flight::sql::{
-    ActionBeginTransactionRequest, ActionBeginTransactionResult, ActionEndTransactionRequest,
-    CommandStatementIngest, EndTransaction, SqlInfo, TableDefinitionOptions, TableExistsOption,
-    TableNotExistOption,
+    ActionBeginTransactionRequest, ActionBeginTransactionResult, ActionEndTransactionRequest, Any,
+    CommandGetPrimaryKeys, CommandStatementIngest, EndTransaction, ProstMessageExt, SqlInfo,
+    TableDefinitionOptions, TableExistsOption, TableNotExistOption,
 };
-use arrow_flight::Action;
+use arrow_flight::{
+    close_session_result, Action, CloseSessionRequest, CloseSessionResult, FlightClient,
+    FlightDescriptor, GetSessionOptionsRequest, GetSessionOptionsResult, SessionOptionValue,
+    SetSessionOptionsRequest, SetSessionOptionsResult,
+};
+use arrow_schema::Schema;
 use futures::{StreamExt, TryStreamExt};
+use prost::Message;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -68,6 +75,38 @@ pub async fn test_begin_end_transaction() {
         .is_err());
 }
 
+#[tokio::test]
+pub async fn test_session_options() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_client = FlightClient::new(channel);
+
+    let request = SetSessionOptionsRequest::new([
+        ("catalog", SessionOptionValue::from("sales")),
+        ("timeout", SessionOptionValue::from(30_i64)),
+    ]);
+    let result = flight_client.set_session_options(request).await.unwrap();
+    assert!(result.errors.is_empty());
+
+    let options = flight_client.get_session_options().await.unwrap();
+    assert_eq!(
+        options,
+        GetSessionOptionsResult::new([
+            ("catalog", SessionOptionValue::from("sales")),
+            ("timeout", SessionOptionValue::from(30_i64)),
+        ])
+    );
+
+    let result = flight_client.close_session().await.unwrap();
+    assert_eq!(
+        result,
+        CloseSessionResult::new(close_session_result::Status::Closed)
+    );
+    let options = flight_client.get_session_options().await.unwrap();
+    assert!(options.session_options.is_empty());
+}
+
 #[tokio::test]
 pub async fn test_execute_ingest() {
     let test_server = FlightSqlServiceImpl::new();
@@ -116,6 +155,49 @@ pub async fn test_execute_ingest_error() {
     );
 }
 
+#[tokio::test]
+pub async fn test_default_metadata_flight_info() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel.clone());
+
+    let cmd = CommandGetPrimaryKeys {
+        catalog: None,
+        db_schema: None,
+        table: "test".to_string(),
+    };
+    let expected_schema = cmd.clone().into_builder().schema();
+    let info = flight_sql_client
+        .get_primary_keys(cmd.clone())
+        .await
+        .unwrap();
+    assert_eq!(info.clone().try_decode_schema().unwrap(), *expected_schema);
+
+    // the ticket is the command itself, to be handled by do_get_primary_keys
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let any = Any::decode(ticket.ticket).unwrap();
+    assert_eq!(
+        any.unpack::<CommandGetPrimaryKeys>().unwrap(),
+        Some(cmd.clone())
+    );
+
+    // the same schema is returned by get_schema
+    let mut flight_client = FlightServiceClient::new(channel);
+    let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
+    let result = flight_client
+        .get_schema(descriptor)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(Schema::try_from(result).unwrap(), *expected_schema);
+
+    // non metadata commands are passed to get_schema_fallback
+    let descriptor = FlightDescriptor::new_cmd(make_ingest_command().as_any().encode_to_vec());
+    let err = flight_client.get_schema(descriptor).await.unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unimplemented);
+}
+
 fn make_ingest_command() -> CommandStatementIngest {
     CommandStatementIngest {
         table_definition_options: Some(TableDefinitionOptions {
@@ -135,6 +217,7 @@ fn make_ingest_command() -> CommandStatementIngest {
 pub struct FlightSqlServiceImpl {
     transactions: Arc<Mutex<HashMap<String, ()>>>,
     ingested_batches: Arc<Mutex<Vec<RecordBatch>>>,
+    session_options: Arc<Mutex<HashMap<String, SessionOptionValue>>>,
 }
 
 impl FlightSqlServiceImpl {
@@ -142,6 +225,7 @@ impl FlightSqlServiceImpl {
         Self {
             transactions: Arc::new(Mutex::new(HashMap::new())),
             ingested_batches: Arc::new(Mutex::new(Vec::new())),
+            session_options: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -197,6 +281,38 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Ok(())
     }
 
+    async fn do_action_set_session_options(
+        &self,
+        query: SetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<SetSessionOptionsResult, Status> {
+        self.session_options
+            .lock()
+            .await
+            .extend(query.session_options);
+        Ok(SetSessionOptionsResult::default())
+    }
+
+    async fn do_action_get_session_options(
+        &self,
+        _query: GetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<GetSessionOptionsResult, Status> {
+        let options = self.session_options.lock().await.clone();
+        Ok(GetSessionOptionsResult::new(options))
+    }
+
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        _request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        self.session_options.lock().await.clear();
+        Ok(CloseSessionResult::new(
+            close_session_result::Status::Closed,
+        ))
+    }
+
     async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
 
     async fn do_put_statement_ingest(
@@ -60,7 +60,11 @@ pub fn data_type_from_json(json: &serde_json::Value) -> Result<DataType> {
                     _ => 128, // Default bit width
                 };
 
-                if bit_width == 128 {
+                if bit_width == 32 {
+                    Ok(DataType::Decimal32(precision, scale))
+                } else if bit_width == 64 {
+                    Ok(DataType::Decimal64(precision, scale))
+                } else if bit_width == 128 {
                     Ok(DataType::Decimal128(precision, scale))
                 } else if bit_width == 256 {
                     Ok(DataType::Decimal256(precision, scale))
@@ -337,6 +341,12 @@ pub fn data_type_to_json(data_type: &DataType) -> serde_json::Value {
             TimeUnit::Nanosecond => "NANOSECOND",
         }}),
         DataType::Dictionary(_, _) => json!({ "name": "dictionary"}),
+        DataType::Decimal32(precision, scale) => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 32})
+        }
+        DataType::Decimal64(precision, scale) => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 64})
+        }
         DataType::Decimal128(precision, scale) => {
             json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 128})
         }
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::reader::{IpcReadOptions, ReadLimit};
+use crate::writer::CompressionOptions;
 use crate::CompressionType;
 use arrow_buffer::Buffer;
 use arrow_schema::ArrowError;
@@ -47,6 +49,8 @@ impl CompressionCodec {
     /// Compresses the data in `input` to `output` and appends the
     /// data using the specified compression mechanism.
     ///
+    /// Data smaller than [`CompressionOptions::min_compression_size`] is not compressed
+    ///
     /// returns the number of bytes written to the stream
     ///
     /// Writes this format to output:
@@ -58,16 +62,20 @@ impl CompressionCodec {
         &self,
         input: &[u8],
         output: &mut Vec<u8>,
+        options: &CompressionOptions,
     ) -> Result<usize, ArrowError> {
         let uncompressed_data_len = input.len();
         let original_output_len = output.len();
 
         if input.is_empty() {
             // empty input, nothing to do
+        } else if uncompressed_data_len < options.min_compression_size() {
+            output.extend_from_slice(&LENGTH_NO_COMPRESSED_DATA.to_le_bytes());
+            output.extend_from_slice(input);
         } else {
             // write compressed data directly into the output buffer
             output.extend_from_slice(&uncompressed_data_len.to_le_bytes());
-            self.compress(input, output)?;
+            self.compress(input, output, options)?;
 
             let compression_len = output.len() - original_output_len;
             if compression_len > uncompressed_data_len {
@@ -90,7 +98,13 @@ impl CompressionCodec {
     /// [8 bytes]:         uncompressed length
     /// [remaining bytes]: compressed data stream
     /// ```
-    pub(crate) fn decompress_to_buffer(&self, input: &Buffer) -> Result<Buffer, ArrowError> {
+    ///
+    /// Returns an error if the decompressed length exceeds the limit of `options`
+    pub(crate) fn decompress_to_buffer(
+        &self,
+        input: &Buffer,
+        options: &IpcReadOptions,
+    ) -> Result<Buffer, ArrowError> {
         // read the first 8 bytes to determine if the data is
         // compressed
         let decompressed_length = read_uncompressed_size(input);
@@ -101,6 +115,7 @@ impl CompressionCodec {
             // no compression
             input.slice(LENGTH_OF_PREFIX_DATA as usize)
         } else if let Ok(decompressed_length) = usize::try_from(decompressed_length) {
+            options.check(ReadLimit::DecompressedSize, decompressed_length)?;
             // decompress data using the codec
             let input_data = &input[(LENGTH_OF_PREFIX_DATA as usize)..];
             let v = self.decompress(input_data, decompressed_length as _)?;
@@ -115,10 +130,15 @@ impl CompressionCodec {
 
     /// Compress the data in input buffer and write to output buffer
     /// using the specified compression
-    fn compress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
+    fn compress(
+        &self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+        options: &CompressionOptions,
+    ) -> Result<(), ArrowError> {
         match self {
-            CompressionCodec::Lz4Frame => compress_lz4(input, output),
-            CompressionCodec::Zstd => compress_zstd(input, output),
+            CompressionCodec::Lz4Frame => compress_lz4(input, output, options),
+            CompressionCodec::Zstd => compress_zstd(input, output, options),
         }
     }
 
@@ -140,9 +160,25 @@ impl CompressionCodec {
 }
 
 #[cfg(feature = "lz4")]
-fn compress_lz4(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
+fn compress_lz4(
+    input: &[u8],
+    output: &mut Vec<u8>,
+    options: &CompressionOptions,
+) -> Result<(), ArrowError> {
+    use crate::writer::Lz4BlockSize;
+    use lz4_flex::frame::{BlockSize, FrameEncoder, FrameInfo};
     use std::io::Write;
-    let mut encoder = lz4_flex::frame::FrameEncoder::new(output);
+    let block_size = match options.lz4_block_size() {
+        Lz4BlockSize::Auto => BlockSize::Auto,
+        Lz4BlockSize::Max64KB => BlockSize::Max64KB,
+        Lz4BlockSize::Max256KB => BlockSize::Max256KB,
+        Lz4BlockSize::Max1MB => BlockSize::Max1MB,
+        Lz4BlockSize::Max4MB => BlockSize::Max4MB,
+    };
+    let frame_info = FrameInfo::new()
+        .block_size(block_size)
+        .content_checksum(options.lz4_content_checksum());
+    let mut encoder = FrameEncoder::with_frame_info(frame_info, output);
     encoder.write_all(input)?;
     encoder
         .finish()
@@ -152,7 +188,11 @@ fn compress_lz4(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
 
 #[cfg(not(feature = "lz4"))]
 #[allow(clippy::ptr_arg)]
-fn compress_lz4(_input: &[u8], _output: &mut Vec<u8>) -> Result<(), ArrowError> {
+fn compress_lz4(
+    _input: &[u8],
+    _output: &mut Vec<u8>,
+    _options: &CompressionOptions,
+) -> Result<(), ArrowError> {
     Err(ArrowError::InvalidArgumentError(
         "lz4 IPC compression requires the lz4 feature".to_string(),
     ))
@@ -175,9 +215,13 @@ fn decompress_lz4(_input: &[u8], _decompressed_size: usize) -> Result<Vec<u8>, A
 }
 
 #[cfg(feature = "zstd")]
-fn compress_zstd(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
+fn compress_zstd(
+    input: &[u8],
+    output: &mut Vec<u8>,
+    options: &CompressionOptions,
+) -> Result<(), ArrowError> {
     use std::io::Write;
-    let mut encoder = zstd::Encoder::new(output, 0)?;
+    let mut encoder = zstd::Encoder::new(output, options.zstd_level())?;
     encoder.write_all(input)?;
     encoder.finish()?;
     Ok(())
@@ -185,7 +229,11 @@ fn compress_zstd(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
 
 #[cfg(not(feature = "zstd"))]
 #[allow(clippy::ptr_arg)]
-fn compress_zstd(_input: &[u8], _output: &mut Vec<u8>) -> Result<(), ArrowError> {
+fn compress_zstd(
+    _input: &[u8],
+    _output: &mut Vec<u8>,
+    _options: &CompressionOptions,
+) -> Result<(), ArrowError> {
     Err(ArrowError::InvalidArgumentError(
         "zstd IPC compression requires the zstd feature".to_string(),
     ))
@@ -221,13 +269,19 @@ fn read_uncompressed_size(buffer: &[u8]) -> i64 {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(any(feature = "lz4", feature = "zstd"))]
+    use super::*;
+
     #[test]
     #[cfg(feature = "lz4")]
     fn test_lz4_compression() {
         let input_bytes = b"hello lz4";
         let codec = super::CompressionCodec::Lz4Frame;
         let mut output_bytes: Vec<u8> = Vec::new();
-        codec.compress(input_bytes, &mut output_bytes).unwrap();
+        let options = CompressionOptions::new();
+        codec
+            .compress(input_bytes, &mut output_bytes, &options)
+            .unwrap();
         let result = codec
             .decompress(output_bytes.as_slice(), input_bytes.len())
             .unwrap();
@@ -240,10 +294,56 @@ mod tests {
         let input_bytes = b"hello zstd";
         let codec = super::CompressionCodec::Zstd;
         let mut output_bytes: Vec<u8> = Vec::new();
-        codec.compress(input_bytes, &mut output_bytes).unwrap();
+        let options = CompressionOptions::new();
+        codec
+            .compress(input_bytes, &mut output_bytes, &options)
+            .unwrap();
         let result = codec
             .decompress(output_bytes.as_slice(), input_bytes.len())
             .unwrap();
         assert_eq!(input_bytes, result.as_slice());
     }
+
+    #[test]
+    #[cfg(all(feature = "lz4", feature = "zstd"))]
+    fn test_compression_options() {
+        use crate::writer::Lz4BlockSize;
+        let input: Vec<u8> = (0..10_000_u32)
+            .flat_map(|i| (i % 100).to_le_bytes())
+            .collect();
+        let round_trip = |codec: CompressionCodec, options: &CompressionOptions| {
+            let mut output = vec![];
+            let len = codec.compress_to_vec(&input, &mut output, options).unwrap();
+            assert_eq!(len, output.len());
+            let result = codec
+                .decompress_to_buffer(&output.into(), &Default::default())
+                .unwrap();
+            assert_eq!(result.as_slice(), input.as_slice());
+            len
+        };
+
+        let default = round_trip(CompressionCodec::Zstd, &CompressionOptions::new());
+        let options = CompressionOptions::new().with_zstd_level(19).unwrap();
+        assert_eq!(options.zstd_level(), 19);
+        assert!(round_trip(CompressionCodec::Zstd, &options) <= default);
+
+        let default = round_trip(CompressionCodec::Lz4Frame, &CompressionOptions::new());
+        let options = CompressionOptions::new()
+            .with_lz4_block_size(Lz4BlockSize::Max64KB)
+            .with_lz4_content_checksum(true);
+        assert_ne!(round_trip(CompressionCodec::Lz4Frame, &options), default);
+
+        // Below the threshold the data is written uncompressed
+        let options = CompressionOptions::new().with_min_compression_size(input.len() + 1);
+        let len = round_trip(CompressionCodec::Zstd, &options);
+        assert_eq!(len, input.len() + LENGTH_OF_PREFIX_DATA as usize);
+        let options = CompressionOptions::new().with_min_compression_size(input.len());
+        assert!(round_trip(CompressionCodec::Zstd, &options) < input.len());
+
+        let err = CompressionOptions::new().with_zstd_level(23).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: zstd compression level 23 must be between 1 and 22"
+        );
+    }
 }
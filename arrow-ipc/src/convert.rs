@@ -200,12 +200,6 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
     let len = c_fields.len();
     for i in 0..len {
         let c_field: crate::Field = c_fields.get(i);
-        match c_field.type_type() {
-            crate::Type::Decimal if fb.endianness() == crate::Endianness::Big => {
-                unimplemented!("Big Endian is not supported for Decimal!")
-            }
-            _ => (),
-        };
         fields.push(c_field.into());
     }
 
@@ -415,6 +409,20 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
             }
             DataType::LargeList(Arc::new(children.get(0).into()))
         }
+        crate::Type::ListView => {
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a list view to have one child")
+            }
+            DataType::ListView(Arc::new(children.get(0).into()))
+        }
+        crate::Type::LargeListView => {
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a large list view to have one child")
+            }
+            DataType::LargeListView(Arc::new(children.get(0).into()))
+        }
         crate::Type::FixedSizeList => {
             let children = field.children().unwrap();
             if children.len() != 1 {
@@ -453,7 +461,17 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
         crate::Type::Decimal => {
             let fsb = field.type_as_decimal().unwrap();
             let bit_width = fsb.bitWidth();
-            if bit_width == 128 {
+            if bit_width == 32 {
+                DataType::Decimal32(
+                    fsb.precision().try_into().unwrap(),
+                    fsb.scale().try_into().unwrap(),
+                )
+            } else if bit_width == 64 {
+                DataType::Decimal64(
+                    fsb.precision().try_into().unwrap(),
+                    fsb.scale().try_into().unwrap(),
+                )
+            } else if bit_width == 128 {
                 DataType::Decimal128(
                     fsb.precision().try_into().unwrap(),
                     fsb.scale().try_into().unwrap(),
@@ -506,6 +524,10 @@ pub(crate) fn build_field<'a>(
     dictionary_tracker: &mut Option<&mut DictionaryTracker>,
     field: &Field,
 ) -> WIPOffset<crate::Field<'a>> {
+    if let Some(tracker) = dictionary_tracker {
+        tracker.push_field(field.name());
+    }
+
     // Optional custom metadata.
     let mut fb_metadata = None;
     if !field.metadata().is_empty() {
@@ -557,6 +579,9 @@ pub(crate) fn build_field<'a>(
         field_builder.add_custom_metadata(fb_metadata);
     }
 
+    if let Some(tracker) = dictionary_tracker {
+        tracker.pop_field();
+    }
     field_builder.finish()
 }
 
@@ -771,7 +796,24 @@ pub(crate) fn get_fb_field_type<'a>(
                 children: Some(fbb.create_vector(&[child])),
             }
         }
-        ListView(_) | LargeListView(_) => unimplemented!("ListView/LargeListView not implemented"),
+        ListView(ref list_type) => {
+            let child = build_field(fbb, dictionary_tracker, list_type);
+            FBFieldType {
+                type_type: crate::Type::ListView,
+                type_: crate::ListViewBuilder::new(fbb).finish().as_union_value(),
+                children: Some(fbb.create_vector(&[child])),
+            }
+        }
+        LargeListView(ref list_type) => {
+            let child = build_field(fbb, dictionary_tracker, list_type);
+            FBFieldType {
+                type_type: crate::Type::LargeListView,
+                type_: crate::LargeListViewBuilder::new(fbb)
+                    .finish()
+                    .as_union_value(),
+                children: Some(fbb.create_vector(&[child])),
+            }
+        }
         LargeList(ref list_type) => {
             let child = build_field(fbb, dictionary_tracker, list_type);
             FBFieldType {
@@ -830,6 +872,28 @@ pub(crate) fn get_fb_field_type<'a>(
             // type in the DictionaryEncoding metadata in the parent field
             get_fb_field_type(value_type, dictionary_tracker, fbb)
         }
+        Decimal32(precision, scale) => {
+            let mut builder = crate::DecimalBuilder::new(fbb);
+            builder.add_precision(*precision as i32);
+            builder.add_scale(*scale as i32);
+            builder.add_bitWidth(32);
+            FBFieldType {
+                type_type: crate::Type::Decimal,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&empty_fields[..])),
+            }
+        }
+        Decimal64(precision, scale) => {
+            let mut builder = crate::DecimalBuilder::new(fbb);
+            builder.add_precision(*precision as i32);
+            builder.add_scale(*scale as i32);
+            builder.add_bitWidth(64);
+            FBFieldType {
+                type_type: crate::Type::Decimal,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&empty_fields[..])),
+            }
+        }
         Decimal128(precision, scale) => {
             let mut builder = crate::DecimalBuilder::new(fbb);
             builder.add_precision(*precision as i32);
@@ -1157,6 +1221,8 @@ mod tests {
                     123,
                     true,
                 ),
+                Field::new("decimal32<usize, usize>", DataType::Decimal32(9, 2), false),
+                Field::new("decimal64<usize, usize>", DataType::Decimal64(18, 4), false),
                 Field::new("decimal<usize, usize>", DataType::Decimal128(10, 6), false),
             ],
             md,
@@ -20,19 +20,37 @@
 //! The `FileReader` and `StreamReader` have similar interfaces,
 //! however the `FileReader` expects a reader that supports `Seek`ing
 
+mod message;
+mod options;
 mod stream;
 
+pub use message::*;
+pub use options::*;
 pub use stream::*;
 
+#[cfg(feature = "async")]
+mod async_stream;
+
+#[cfg(feature = "async")]
+pub use async_stream::*;
+
+#[cfg(feature = "mmap")]
+mod mmap;
+
+#[cfg(feature = "mmap")]
+pub use mmap::*;
+
 use flatbuffers::{VectorIter, VerifierOptions};
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::ops::Range;
 use std::sync::Arc;
 
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, ScalarBuffer};
-use arrow_data::ArrayData;
+use arrow_data::transform::MutableArrayData;
+use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::*;
 
 use crate::compression::CompressionCodec;
@@ -51,14 +69,19 @@ use DataType::*;
 fn read_buffer(
     buf: &crate::Buffer,
     a_data: &Buffer,
+    data_offset: usize,
     compression_codec: Option<CompressionCodec>,
+    options: &IpcReadOptions,
 ) -> Result<Buffer, ArrowError> {
-    let start_offset = buf.offset() as usize;
-    let buf_data = a_data.slice_with_length(start_offset, buf.length() as usize);
+    let buf_data = match buf.length() {
+        // empty buffers may not lie within `a_data`
+        0 => MutableBuffer::new(0).into(),
+        len => a_data.slice_with_length(buf.offset() as usize - data_offset, len as usize),
+    };
     // corner case: empty buffer
     match (buf_data.is_empty(), compression_codec) {
         (true, _) | (_, None) => Ok(buf_data),
-        (false, Some(decompressor)) => decompressor.decompress_to_buffer(&buf_data),
+        (false, Some(decompressor)) => decompressor.decompress_to_buffer(&buf_data, options),
     }
 }
 
@@ -78,66 +101,75 @@ fn create_array(
     reader: &mut ArrayReader,
     field: &Field,
     variadic_counts: &mut VecDeque<i64>,
-    require_alignment: bool,
 ) -> Result<ArrayRef, ArrowError> {
     let data_type = field.data_type();
     match data_type {
-        Utf8 | Binary | LargeBinary | LargeUtf8 => create_primitive_array(
-            reader.next_node(field)?,
-            data_type,
-            &[
-                reader.next_buffer()?,
-                reader.next_buffer()?,
-                reader.next_buffer()?,
-            ],
-            require_alignment,
-        ),
+        Utf8 | Binary | LargeBinary | LargeUtf8 => {
+            let offset_width = match data_type {
+                LargeBinary | LargeUtf8 => 8,
+                _ => 4,
+            };
+            create_primitive_array(
+                reader.next_node(field)?,
+                data_type,
+                &[
+                    reader.next_buffer()?,
+                    reader.next_swapped_buffer(&[offset_width])?,
+                    reader.next_buffer()?,
+                ],
+                reader,
+            )
+        }
         BinaryView | Utf8View => {
             let count = variadic_counts
                 .pop_front()
                 .ok_or(ArrowError::IpcError(format!(
                     "Missing variadic count for {data_type} column"
                 )))?;
-            let count = count + 2; // view and null buffer.
-            let buffers = (0..count)
-                .map(|_| reader.next_buffer())
-                .collect::<Result<Vec<_>, _>>()?;
-            create_primitive_array(
-                reader.next_node(field)?,
-                data_type,
-                &buffers,
-                require_alignment,
-            )
+            let mut buffers = vec![reader.next_buffer()?, reader.next_view_buffer()?];
+            for _ in 0..count {
+                buffers.push(reader.next_buffer()?);
+            }
+            create_primitive_array(reader.next_node(field)?, data_type, &buffers, reader)
         }
         FixedSizeBinary(_) => create_primitive_array(
             reader.next_node(field)?,
             data_type,
             &[reader.next_buffer()?, reader.next_buffer()?],
-            require_alignment,
+            reader,
         ),
         List(ref list_field) | LargeList(ref list_field) | Map(ref list_field, _) => {
             let list_node = reader.next_node(field)?;
-            let list_buffers = [reader.next_buffer()?, reader.next_buffer()?];
-            let values = create_array(reader, list_field, variadic_counts, require_alignment)?;
-            create_list_array(
-                list_node,
-                data_type,
-                &list_buffers,
-                values,
-                require_alignment,
-            )
+            let offset_width = match data_type {
+                LargeList(_) => 8,
+                _ => 4,
+            };
+            let list_buffers = [
+                reader.next_buffer()?,
+                reader.next_swapped_buffer(&[offset_width])?,
+            ];
+            let values = create_array(reader, list_field, variadic_counts)?;
+            create_list_array(list_node, data_type, &list_buffers, values, reader)
+        }
+        ListView(ref list_field) | LargeListView(ref list_field) => {
+            let list_node = reader.next_node(field)?;
+            let offset_width = match data_type {
+                LargeListView(_) => 8,
+                _ => 4,
+            };
+            let list_buffers = [
+                reader.next_buffer()?,
+                reader.next_swapped_buffer(&[offset_width])?,
+                reader.next_swapped_buffer(&[offset_width])?,
+            ];
+            let values = create_array(reader, list_field, variadic_counts)?;
+            create_list_array(list_node, data_type, &list_buffers, values, reader)
         }
         FixedSizeList(ref list_field, _) => {
             let list_node = reader.next_node(field)?;
             let list_buffers = [reader.next_buffer()?];
-            let values = create_array(reader, list_field, variadic_counts, require_alignment)?;
-            create_list_array(
-                list_node,
-                data_type,
-                &list_buffers,
-                values,
-                require_alignment,
-            )
+            let values = create_array(reader, list_field, variadic_counts)?;
+            create_list_array(list_node, data_type, &list_buffers, values, reader)
         }
         Struct(struct_fields) => {
             let struct_node = reader.next_node(field)?;
@@ -148,7 +180,7 @@ fn create_array(
             // TODO investigate whether just knowing the number of buffers could
             // still work
             for struct_field in struct_fields {
-                let child = create_array(reader, struct_field, variadic_counts, require_alignment)?;
+                let child = create_array(reader, struct_field, variadic_counts)?;
                 struct_arrays.push(child);
             }
             let null_count = struct_node.null_count() as usize;
@@ -172,9 +204,8 @@ fn create_array(
         }
         RunEndEncoded(run_ends_field, values_field) => {
             let run_node = reader.next_node(field)?;
-            let run_ends =
-                create_array(reader, run_ends_field, variadic_counts, require_alignment)?;
-            let values = create_array(reader, values_field, variadic_counts, require_alignment)?;
+            let run_ends = create_array(reader, run_ends_field, variadic_counts)?;
+            let values = create_array(reader, values_field, variadic_counts)?;
 
             let run_array_length = run_node.length() as usize;
             let builder = ArrayData::builder(data_type.clone())
@@ -183,18 +214,17 @@ fn create_array(
                 .add_child_data(run_ends.into_data())
                 .add_child_data(values.into_data());
 
-            let array_data = if require_alignment {
-                builder.build()?
-            } else {
-                builder.build_aligned()?
-            };
+            let array_data = reader.build(builder)?;
 
             Ok(make_array(array_data))
         }
         // Create dictionary array from RecordBatch
-        Dictionary(_, _) => {
+        Dictionary(ref key_type, _) => {
             let index_node = reader.next_node(field)?;
-            let index_buffers = [reader.next_buffer()?, reader.next_buffer()?];
+            let index_buffers = [
+                reader.next_buffer()?,
+                reader.next_swapped_buffer(swap_widths(key_type))?,
+            ];
 
             let dict_id = field.dict_id().ok_or_else(|| {
                 ArrowError::ParseError(format!("Field {field} does not have dict id"))
@@ -211,7 +241,7 @@ fn create_array(
                 data_type,
                 &index_buffers,
                 value_array.clone(),
-                require_alignment,
+                reader,
             )
         }
         Union(fields, mode) => {
@@ -228,8 +258,10 @@ fn create_array(
 
             let value_offsets = match mode {
                 UnionMode::Dense => {
-                    let offsets: ScalarBuffer<i32> =
-                        reader.next_buffer()?.slice_with_length(0, len * 4).into();
+                    let offsets: ScalarBuffer<i32> = reader
+                        .next_swapped_buffer(&[4])?
+                        .slice_with_length(0, len * 4)
+                        .into();
                     Some(offsets)
                 }
                 UnionMode::Sparse => None,
@@ -238,7 +270,7 @@ fn create_array(
             let mut children = Vec::with_capacity(fields.len());
 
             for (_id, field) in fields.iter() {
-                let child = create_array(reader, field, variadic_counts, require_alignment)?;
+                let child = create_array(reader, field, variadic_counts)?;
                 children.push(child);
             }
 
@@ -260,11 +292,7 @@ fn create_array(
                 .len(length as usize)
                 .offset(0);
 
-            let array_data = if require_alignment {
-                builder.build()?
-            } else {
-                builder.build_aligned()?
-            };
+            let array_data = reader.build(builder)?;
 
             // no buffer increases
             Ok(Arc::new(NullArray::from(array_data)))
@@ -272,19 +300,82 @@ fn create_array(
         _ => create_primitive_array(
             reader.next_node(field)?,
             data_type,
-            &[reader.next_buffer()?, reader.next_buffer()?],
-            require_alignment,
+            &[
+                reader.next_buffer()?,
+                reader.next_swapped_buffer(swap_widths(data_type))?,
+            ],
+            reader,
         ),
     }
 }
 
+/// Returns the widths of the values comprising each element of the fixed width
+/// `data_type`, whose byte order must be reversed when reading data written with a
+/// different endianness
+///
+/// Single byte, boolean and fixed size binary values are independent of the endianness
+fn swap_widths(data_type: &DataType) -> &'static [usize] {
+    match data_type {
+        Interval(IntervalUnit::DayTime) => &[4, 4],
+        Interval(IntervalUnit::MonthDayNano) => &[4, 4, 8],
+        _ => match data_type.primitive_width() {
+            Some(2) => &[2],
+            Some(4) => &[4],
+            Some(8) => &[8],
+            Some(16) => &[16],
+            Some(32) => &[32],
+            _ => &[],
+        },
+    }
+}
+
+/// Returns a copy of `buffer` with the byte order reversed for each of the consecutive
+/// values of `widths` bytes it is comprised of
+fn swap_bytes(buffer: &Buffer, widths: &[usize]) -> Buffer {
+    let element_width: usize = widths.iter().sum();
+    if element_width <= 1 {
+        return buffer.clone();
+    }
+    let mut swapped = MutableBuffer::new(buffer.len());
+    swapped.extend_from_slice(buffer.as_slice());
+    for element in swapped.as_slice_mut().chunks_exact_mut(element_width) {
+        let mut start = 0;
+        for width in widths {
+            element[start..start + width].reverse();
+            start += width;
+        }
+    }
+    swapped.into()
+}
+
+/// Returns a copy of the views `buffer` of a [`BinaryViewArray`] or [`StringViewArray`]
+/// with the byte order of the integers in each view reversed
+///
+/// The length of each view is swapped, along with the buffer index and offset of views
+/// longer than 12 bytes. The prefix and inlined data are independent of the endianness.
+///
+/// [`BinaryViewArray`]: arrow_array::BinaryViewArray
+/// [`StringViewArray`]: arrow_array::StringViewArray
+fn swap_view_bytes(buffer: &Buffer) -> Buffer {
+    let mut swapped = MutableBuffer::new(buffer.len());
+    swapped.extend_from_slice(buffer.as_slice());
+    for view in swapped.as_slice_mut().chunks_exact_mut(16) {
+        view[0..4].reverse();
+        if u32::from_le_bytes(view[0..4].try_into().unwrap()) > 12 {
+            view[8..12].reverse();
+            view[12..16].reverse();
+        }
+    }
+    swapped.into()
+}
+
 /// Reads the correct number of buffers based on data type and null_count, and creates a
 /// primitive array ref
 fn create_primitive_array(
     field_node: &FieldNode,
     data_type: &DataType,
     buffers: &[Buffer],
-    require_alignment: bool,
+    reader: &ArrayReader,
 ) -> Result<ArrayRef, ArrowError> {
     let length = field_node.length() as usize;
     let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
@@ -310,11 +401,7 @@ fn create_primitive_array(
         t => unreachable!("Data type {:?} either unsupported or not primitive", t),
     };
 
-    let array_data = if require_alignment {
-        builder.build()?
-    } else {
-        builder.build_aligned()?
-    };
+    let array_data = reader.build(builder)?;
 
     Ok(make_array(array_data))
 }
@@ -326,7 +413,7 @@ fn create_list_array(
     data_type: &DataType,
     buffers: &[Buffer],
     child_array: ArrayRef,
-    require_alignment: bool,
+    reader: &ArrayReader,
 ) -> Result<ArrayRef, ArrowError> {
     let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
     let length = field_node.length() as usize;
@@ -338,6 +425,13 @@ fn create_list_array(
             .add_child_data(child_data)
             .null_bit_buffer(null_buffer),
 
+        ListView(_) | LargeListView(_) => ArrayData::builder(data_type.clone())
+            .len(length)
+            .add_buffer(buffers[1].clone())
+            .add_buffer(buffers[2].clone())
+            .add_child_data(child_data)
+            .null_bit_buffer(null_buffer),
+
         FixedSizeList(_, _) => ArrayData::builder(data_type.clone())
             .len(length)
             .add_child_data(child_data)
@@ -346,11 +440,7 @@ fn create_list_array(
         _ => unreachable!("Cannot create list or map array from {:?}", data_type),
     };
 
-    let array_data = if require_alignment {
-        builder.build()?
-    } else {
-        builder.build_aligned()?
-    };
+    let array_data = reader.build(builder)?;
 
     Ok(make_array(array_data))
 }
@@ -362,7 +452,7 @@ fn create_dictionary_array(
     data_type: &DataType,
     buffers: &[Buffer],
     value_array: ArrayRef,
-    require_alignment: bool,
+    reader: &ArrayReader,
 ) -> Result<ArrayRef, ArrowError> {
     if let Dictionary(_, _) = *data_type {
         let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
@@ -372,11 +462,7 @@ fn create_dictionary_array(
             .add_child_data(value_array.into_data())
             .null_bit_buffer(null_buffer);
 
-        let array_data = if require_alignment {
-            builder.build()?
-        } else {
-            builder.build_aligned()?
-        };
+        let array_data = reader.build(builder)?;
 
         Ok(make_array(array_data))
     } else {
@@ -394,6 +480,14 @@ struct ArrayReader<'a> {
     version: MetadataVersion,
     /// The raw data buffer
     data: &'a Buffer,
+    /// The offset of `data` within the message body
+    data_offset: usize,
+    /// Whether `data` was written with a different endianness, and must be byte-swapped
+    swap_endianness: bool,
+    /// Whether array data is required to be aligned
+    require_alignment: bool,
+    /// Options limiting the data read
+    options: &'a IpcReadOptions,
     /// The fields comprising this array
     nodes: VectorIter<'a, FieldNode>,
     /// The buffers comprising this array
@@ -402,7 +496,55 @@ struct ArrayReader<'a> {
 
 impl<'a> ArrayReader<'a> {
     fn next_buffer(&mut self) -> Result<Buffer, ArrowError> {
-        read_buffer(self.buffers.next().unwrap(), self.data, self.compression)
+        let buf = self.buffers.next().unwrap();
+        read_buffer(
+            buf,
+            self.data,
+            self.data_offset,
+            self.compression,
+            self.options,
+        )
+    }
+
+    /// Builds the [`ArrayData`] of an array read from `data`
+    ///
+    /// If `require_alignment` is true, this will return an error if any buffer is not
+    /// properly aligned, otherwise buffers are copied to a new aligned allocation if
+    /// necessary.
+    ///
+    /// The data is not validated if requested with [`IpcReadOptions::with_skip_validation`],
+    /// in which case buffers are always aligned.
+    fn build(&self, builder: ArrayDataBuilder) -> Result<ArrayData, ArrowError> {
+        if self.options.skip_validation() {
+            // SAFETY: the data is trusted, as required by `with_skip_validation`
+            let mut data = unsafe { builder.build_unchecked() };
+            data.align_buffers();
+            return Ok(data);
+        }
+        match self.require_alignment {
+            true => builder.build(),
+            false => builder.build_aligned(),
+        }
+    }
+
+    /// Returns the next buffer, reversing the byte order of its values of `widths`
+    /// bytes if the data was written with a different endianness
+    fn next_swapped_buffer(&mut self, widths: &[usize]) -> Result<Buffer, ArrowError> {
+        let buffer = self.next_buffer()?;
+        Ok(match self.swap_endianness {
+            true => swap_bytes(&buffer, widths),
+            false => buffer,
+        })
+    }
+
+    /// Returns the next buffer as the views of a view array, byte-swapping it if the
+    /// data was written with a different endianness
+    fn next_view_buffer(&mut self) -> Result<Buffer, ArrowError> {
+        let buffer = self.next_buffer()?;
+        Ok(match self.swap_endianness {
+            true => swap_view_bytes(&buffer),
+            false => buffer,
+        })
     }
 
     fn skip_buffer(&mut self) {
@@ -452,6 +594,12 @@ impl<'a> ArrayReader<'a> {
                 self.skip_buffer();
                 self.skip_field(list_field, variadic_count)?;
             }
+            ListView(list_field) | LargeListView(list_field) => {
+                self.skip_buffer();
+                self.skip_buffer();
+                self.skip_buffer();
+                self.skip_field(list_field, variadic_count)?;
+            }
             FixedSizeList(list_field, _) => {
                 self.skip_buffer();
                 self.skip_field(list_field, variadic_count)?;
@@ -520,6 +668,8 @@ pub fn read_record_batch(
         projection,
         metadata,
         false,
+        false,
+        &IpcReadOptions::default(),
     )
 }
 
@@ -532,9 +682,19 @@ pub fn read_dictionary(
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &MetadataVersion,
 ) -> Result<(), ArrowError> {
-    read_dictionary_impl(buf, batch, schema, dictionaries_by_id, metadata, false)
+    read_dictionary_impl(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        metadata,
+        false,
+        false,
+        &IpcReadOptions::default(),
+    )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn read_record_batch_impl(
     buf: &Buffer,
     batch: crate::RecordBatch,
@@ -543,6 +703,8 @@ fn read_record_batch_impl(
     projection: Option<&[usize]>,
     metadata: &MetadataVersion,
     require_alignment: bool,
+    swap_endianness: bool,
+    options: &IpcReadOptions,
 ) -> Result<RecordBatch, ArrowError> {
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::IpcError("Unable to get buffers from IPC RecordBatch".to_string())
@@ -550,6 +712,8 @@ fn read_record_batch_impl(
     let field_nodes = batch.nodes().ok_or_else(|| {
         ArrowError::IpcError("Unable to get field nodes from IPC RecordBatch".to_string())
     })?;
+    options.check(ReadLimit::Buffers, buffers.len())?;
+    options.check(ReadLimit::Fields, field_nodes.len())?;
 
     let mut variadic_counts: VecDeque<i64> =
         batch.variadicBufferCounts().into_iter().flatten().collect();
@@ -564,6 +728,10 @@ fn read_record_batch_impl(
         compression,
         version: *metadata,
         data: buf,
+        data_offset: 0,
+        swap_endianness,
+        require_alignment,
+        options,
         nodes: field_nodes.iter(),
         buffers: buffers.iter(),
     };
@@ -576,8 +744,7 @@ fn read_record_batch_impl(
         for (idx, field) in schema.fields().iter().enumerate() {
             // Create array for projected field
             if let Some(proj_idx) = projection.iter().position(|p| p == &idx) {
-                let child =
-                    create_array(&mut reader, field, &mut variadic_counts, require_alignment)?;
+                let child = create_array(&mut reader, field, &mut variadic_counts)?;
                 arrays.push((proj_idx, child));
             } else {
                 reader.skip_field(field, &mut variadic_counts)?;
@@ -594,7 +761,7 @@ fn read_record_batch_impl(
         let mut children = vec![];
         // keep track of index as lists require more than one node
         for field in schema.fields() {
-            let child = create_array(&mut reader, field, &mut variadic_counts, require_alignment)?;
+            let child = create_array(&mut reader, field, &mut variadic_counts)?;
             children.push(child);
         }
         assert!(variadic_counts.is_empty());
@@ -602,6 +769,7 @@ fn read_record_batch_impl(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn read_dictionary_impl(
     buf: &Buffer,
     batch: crate::DictionaryBatch,
@@ -609,13 +777,9 @@ fn read_dictionary_impl(
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &MetadataVersion,
     require_alignment: bool,
+    swap_endianness: bool,
+    options: &IpcReadOptions,
 ) -> Result<(), ArrowError> {
-    if batch.isDelta() {
-        return Err(ArrowError::InvalidArgumentError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -639,6 +803,8 @@ fn read_dictionary_impl(
                 None,
                 metadata,
                 require_alignment,
+                swap_endianness,
+                options,
             )?;
             Some(record_batch.column(0).clone())
         }
@@ -648,20 +814,44 @@ fn read_dictionary_impl(
         ArrowError::InvalidArgumentError(format!("dictionary id {id} not found in schema"))
     })?;
 
+    // A delta dictionary batch appends its values to the existing dictionary
+    let dictionary_values = match batch.isDelta() {
+        true => {
+            let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "delta dictionary batch for dictionary id {id} without an existing dictionary"
+                ))
+            })?;
+            let (existing, delta) = (existing.to_data(), dictionary_values.to_data());
+            let len = existing.len() + delta.len();
+            let mut mutable = MutableArrayData::new(vec![&existing, &delta], false, len);
+            mutable.extend(0, 0, existing.len());
+            mutable.extend(1, 0, delta.len());
+            make_array(mutable.freeze())
+        }
+        false => dictionary_values,
+    };
+
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
     // Add (possibly multiple) array refs to the dictionaries array.
-    dictionaries_by_id.insert(id, dictionary_values.clone());
+    dictionaries_by_id.insert(id, dictionary_values);
 
     Ok(())
 }
 
-/// Read the data for a given block
-fn read_block<R: Read + Seek>(mut reader: R, block: &Block) -> Result<Buffer, ArrowError> {
+/// Read the data for a given block, returning an error if it exceeds the message size
+/// limit of `options`
+pub(crate) fn read_block<R: Read + Seek>(
+    mut reader: R,
+    block: &Block,
+    options: &IpcReadOptions,
+) -> Result<Buffer, ArrowError> {
     reader.seek(SeekFrom::Start(block.offset() as u64))?;
     let body_len = block.bodyLength().to_usize().unwrap();
     let metadata_len = block.metaDataLength().to_usize().unwrap();
     let total_len = body_len.checked_add(metadata_len).unwrap();
+    options.check(ReadLimit::MessageSize, total_len)?;
 
     let mut buf = MutableBuffer::from_len_zeroed(total_len);
     reader.read_exact(&mut buf)?;
@@ -764,6 +954,8 @@ pub struct FileDecoder {
     version: MetadataVersion,
     projection: Option<Vec<usize>>,
     require_alignment: bool,
+    swap_endianness: bool,
+    read_options: IpcReadOptions,
 }
 
 impl FileDecoder {
@@ -775,6 +967,8 @@ impl FileDecoder {
             dictionaries: Default::default(),
             projection: None,
             require_alignment: false,
+            swap_endianness: false,
+            read_options: IpcReadOptions::default(),
         }
     }
 
@@ -801,6 +995,21 @@ impl FileDecoder {
         self
     }
 
+    /// Specifies the endianness the data was written with, as recorded in the schema
+    /// of the file, defaulting to the native endianness.
+    ///
+    /// Data written with a different endianness is byte-swapped when it is read
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.swap_endianness = !endianness.equals_to_target_endianness();
+        self
+    }
+
+    /// Specifies the [`IpcReadOptions`] limiting the data read
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
     fn read_message<'a>(&self, buf: &'a [u8]) -> Result<Message<'a>, ArrowError> {
         let message = parse_message(buf)?;
 
@@ -826,6 +1035,8 @@ impl FileDecoder {
                     &mut self.dictionaries,
                     &message.version(),
                     self.require_alignment,
+                    self.swap_endianness,
+                    &self.read_options,
                 )
             }
             t => Err(ArrowError::ParseError(format!(
@@ -858,6 +1069,8 @@ impl FileDecoder {
                     self.projection.as_deref(),
                     &message.version(),
                     self.require_alignment,
+                    self.swap_endianness,
+                    &self.read_options,
                 )
                 .map(Some)
             }
@@ -867,6 +1080,144 @@ impl FileDecoder {
             ))),
         }
     }
+
+    /// Read the `rows` of the columns in `projection` of the RecordBatch with the
+    /// given message metadata, calling `read` to read the regions of the message body,
+    /// identified by their byte range, containing the data of each projected column
+    fn read_record_batch_columns<F>(
+        &self,
+        meta: &[u8],
+        projection: Option<&[usize]>,
+        rows: Option<Range<usize>>,
+        mut read: F,
+    ) -> Result<RecordBatch, ArrowError>
+    where
+        F: FnMut(Range<usize>) -> Result<Buffer, ArrowError>,
+    {
+        let message = self.read_message(meta)?;
+        let batch = match message.header_type() {
+            crate::MessageHeader::RecordBatch => message.header_as_record_batch().unwrap(),
+            t => {
+                return Err(ArrowError::IpcError(format!(
+                    "Expecting RecordBatch in record batch blocks, found {t:?}."
+                )))
+            }
+        };
+        let num_rows = batch.length() as usize;
+        if let Some(rows) = &rows {
+            if rows.start > rows.end || rows.end > num_rows {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot read rows {rows:?} from a record batch of {num_rows} rows"
+                )));
+            }
+        }
+
+        let fields = self.schema.fields();
+        let projection: Vec<usize> = match projection {
+            Some(projection) => {
+                if let Some(i) = projection.iter().find(|i| **i >= fields.len()) {
+                    return Err(ArrowError::SchemaError(format!(
+                        "project index {i} out of bounds, max field {}",
+                        fields.len()
+                    )));
+                }
+                projection.to_vec()
+            }
+            None => (0..fields.len()).collect(),
+        };
+
+        let buffers = batch.buffers().ok_or_else(|| {
+            ArrowError::IpcError("Unable to get buffers from IPC RecordBatch".to_string())
+        })?;
+        let field_nodes = batch.nodes().ok_or_else(|| {
+            ArrowError::IpcError("Unable to get field nodes from IPC RecordBatch".to_string())
+        })?;
+        self.read_options.check(ReadLimit::Buffers, buffers.len())?;
+        self.read_options
+            .check(ReadLimit::Fields, field_nodes.len())?;
+        let variadic_counts: VecDeque<i64> =
+            batch.variadicBufferCounts().into_iter().flatten().collect();
+        let compression = batch
+            .compression()
+            .map(|c| c.codec().try_into())
+            .transpose()?;
+
+        let empty = Buffer::from(MutableBuffer::new(0));
+        let new_reader = |data| ArrayReader {
+            dictionaries_by_id: &self.dictionaries,
+            compression,
+            version: message.version(),
+            data,
+            data_offset: 0,
+            swap_endianness: self.swap_endianness,
+            require_alignment: self.require_alignment,
+            options: &self.read_options,
+            nodes: field_nodes.iter(),
+            buffers: buffers.iter(),
+        };
+
+        // Find the region of the body containing the buffers of each projected field
+        let mut reader = new_reader(&empty);
+        let mut counts = variadic_counts.clone();
+        let mut ranges = vec![None; fields.len()];
+        for (idx, field) in fields.iter().enumerate() {
+            let start = buffers.len() - reader.buffers.len();
+            reader.skip_field(field, &mut counts)?;
+            if projection.contains(&idx) {
+                let end = buffers.len() - reader.buffers.len();
+                let range = (start..end)
+                    .map(|i| buffers.get(i))
+                    .filter(|b| b.length() > 0)
+                    .map(|b| b.offset() as usize..(b.offset() + b.length()) as usize)
+                    .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+                    .unwrap_or(0..0);
+                ranges[idx] = Some(range);
+            }
+        }
+
+        let data = ranges
+            .iter()
+            .map(|r| match r {
+                Some(r) if !r.is_empty() => read(r.clone()),
+                _ => Ok(empty.clone()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut reader = new_reader(&empty);
+        let mut counts = variadic_counts;
+        let mut arrays = Vec::with_capacity(projection.len());
+        for (idx, field) in fields.iter().enumerate() {
+            match &ranges[idx] {
+                Some(range) => {
+                    reader.data = &data[idx];
+                    reader.data_offset = range.start;
+                    let array = create_array(&mut reader, field, &mut counts)?;
+                    arrays.push((idx, array));
+                }
+                None => reader.skip_field(field, &mut counts)?,
+            }
+        }
+
+        let columns = projection
+            .iter()
+            .map(|p| {
+                let (_, array) = arrays.iter().find(|(idx, _)| idx == p).unwrap();
+                match &rows {
+                    Some(rows) => array.slice(rows.start, rows.end - rows.start),
+                    None => array.clone(),
+                }
+            })
+            .collect();
+        let num_rows = rows.map(|r| r.end - r.start).unwrap_or(num_rows);
+        let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+        let schema = Arc::new(self.schema.project(&projection)?);
+        RecordBatch::try_new_with_options(schema, columns, &options)
+    }
+
+    /// Returns the values of the dictionaries read, keyed by dictionary ID
+    pub(crate) fn dictionaries(&self) -> &HashMap<i64, ArrayRef> {
+        &self.dictionaries
+    }
 }
 
 /// Build an Arrow [`FileReader`] with custom options.
@@ -878,6 +1229,8 @@ pub struct FileReaderBuilder {
     max_footer_fb_tables: usize,
     /// Passed through to construct [`VerifierOptions`]
     max_footer_fb_depth: usize,
+    /// Options limiting the data read
+    read_options: IpcReadOptions,
 }
 
 impl Default for FileReaderBuilder {
@@ -887,6 +1240,7 @@ impl Default for FileReaderBuilder {
             max_footer_fb_tables: verifier_options.max_tables,
             max_footer_fb_depth: verifier_options.max_depth,
             projection: None,
+            read_options: IpcReadOptions::default(),
         }
     }
 }
@@ -939,6 +1293,13 @@ impl FileReaderBuilder {
         self
     }
 
+    /// Specifies the [`IpcReadOptions`] limiting the data read from the file, including
+    /// the size of its footer, which is limited by the maximum message size.
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
     /// Build [`FileReader`] with given reader.
     pub fn build<R: Read + Seek>(self, mut reader: R) -> Result<FileReader<R>, ArrowError> {
         // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
@@ -947,70 +1308,100 @@ impl FileReaderBuilder {
         reader.read_exact(&mut buffer)?;
 
         let footer_len = read_footer_length(buffer)?;
+        self.read_options
+            .check(ReadLimit::MessageSize, footer_len)?;
 
         // read footer
         let mut footer_data = vec![0; footer_len];
         reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
         reader.read_exact(&mut footer_data)?;
 
+        let mut footer = self.decode_footer(&footer_data)?;
+
+        // Create an array of optional dictionary value arrays, one per field.
+        for block in &footer.dictionaries {
+            let buf = read_block(&mut reader, block, &footer.decoder.read_options)?;
+            footer.decoder.read_dictionary(block, &buf)?;
+        }
+
+        Ok(FileReader {
+            reader,
+            total_blocks: footer.blocks.len(),
+            blocks: footer.blocks,
+            current_block: 0,
+            decoder: footer.decoder,
+            custom_metadata: footer.custom_metadata,
+        })
+    }
+
+    /// Decodes the footer of an Arrow file, returning a [`FileDecoder`] configured by
+    /// this builder, which has yet to read the dictionaries of the file
+    pub(crate) fn decode_footer(self, footer_data: &[u8]) -> Result<FileFooter, ArrowError> {
         let verifier_options = VerifierOptions {
             max_tables: self.max_footer_fb_tables,
             max_depth: self.max_footer_fb_depth,
             ..Default::default()
         };
-        let footer = crate::root_as_footer_with_opts(&verifier_options, &footer_data[..]).map_err(
-            |err| ArrowError::ParseError(format!("Unable to get root as footer: {err:?}")),
-        )?;
+        let footer =
+            crate::root_as_footer_with_opts(&verifier_options, footer_data).map_err(|err| {
+                ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+            })?;
 
         let blocks = footer.recordBatches().ok_or_else(|| {
             ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
         })?;
 
-        let total_blocks = blocks.len();
-
-        let ipc_schema = footer.schema().unwrap();
-        if !ipc_schema.endianness().equals_to_target_endianness() {
-            return Err(ArrowError::IpcError(
-                "the endianness of the source system does not match the endianness of the target system.".to_owned()
-            ));
-        }
-
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::ParseError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        let endianness = ipc_schema.endianness();
         let schema = crate::convert::fb_to_schema(ipc_schema);
+        self.read_options.check_schema(&schema)?;
 
         let mut custom_metadata = HashMap::new();
         if let Some(fb_custom_metadata) = footer.custom_metadata() {
             for kv in fb_custom_metadata.into_iter() {
-                custom_metadata.insert(
-                    kv.key().unwrap().to_string(),
-                    kv.value().unwrap().to_string(),
-                );
+                match (kv.key(), kv.value()) {
+                    (Some(key), Some(value)) => {
+                        custom_metadata.insert(key.to_string(), value.to_string());
+                    }
+                    _ => {
+                        return Err(ArrowError::ParseError(
+                            "Unable to get custom metadata from IPC Footer".to_string(),
+                        ))
+                    }
+                }
             }
         }
 
-        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
+        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version())
+            .with_endianness(endianness)
+            .with_read_options(self.read_options);
         if let Some(projection) = self.projection {
             decoder = decoder.with_projection(projection)
         }
 
-        // Create an array of optional dictionary value arrays, one per field.
-        if let Some(dictionaries) = footer.dictionaries() {
-            for block in dictionaries {
-                let buf = read_block(&mut reader, block)?;
-                decoder.read_dictionary(block, &buf)?;
-            }
-        }
-
-        Ok(FileReader {
-            reader,
-            blocks: blocks.iter().copied().collect(),
-            current_block: 0,
-            total_blocks,
+        Ok(FileFooter {
             decoder,
+            blocks: blocks.iter().copied().collect(),
+            dictionaries: footer.dictionaries().iter().flatten().copied().collect(),
             custom_metadata,
         })
     }
 }
 
+/// The contents of the footer of an Arrow file, decoded by [`FileReaderBuilder`]
+pub(crate) struct FileFooter {
+    /// The decoder, which has yet to read the dictionaries
+    pub(crate) decoder: FileDecoder,
+    /// The record batch blocks
+    pub(crate) blocks: Vec<Block>,
+    /// The dictionary blocks
+    pub(crate) dictionaries: Vec<Block>,
+    /// User defined metadata
+    pub(crate) custom_metadata: HashMap<String, String>,
+}
+
 /// Arrow File reader
 pub struct FileReader<R> {
     /// File reader that supports reading and seeking
@@ -1103,12 +1494,99 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
+    /// Read the record batch at `index`, decoding only the columns in `projection`
+    ///
+    /// Unlike reading the batch after [`Self::set_index`], only the metadata of the batch
+    /// and the regions of its body containing the data of the projected columns are read
+    /// from the underlying reader, allowing cheap random access into large files.
+    ///
+    /// `projection` contains the indices of the columns to read in [`Self::schema`], and
+    /// is independent of any projection this reader was created with. If `None`, all
+    /// columns are read.
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// # use arrow_ipc::reader::FileReader;
+    /// # use arrow_ipc::writer::FileWriter;
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+    /// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+    ///
+    /// let mut writer = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// let file = writer.into_inner().unwrap();
+    ///
+    /// let mut reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+    /// let read = reader.read_batch(1, Some(&[1])).unwrap();
+    /// assert_eq!(read, batch.project(&[1]).unwrap());
+    ///
+    /// let read = reader.read_batch_rows(0, None, 1..3).unwrap();
+    /// assert_eq!(read, batch.slice(1, 2));
+    /// ```
+    pub fn read_batch(
+        &mut self,
+        index: usize,
+        projection: Option<&[usize]>,
+    ) -> Result<RecordBatch, ArrowError> {
+        self.read_batch_impl(index, projection, None)
+    }
+
+    /// Read the `rows` of the record batch at `index`, decoding only the columns in
+    /// `projection`
+    ///
+    /// The projected columns are read as by [`Self::read_batch`], with the returned batch
+    /// being a zero-copy slice of these columns
+    pub fn read_batch_rows(
+        &mut self,
+        index: usize,
+        projection: Option<&[usize]>,
+        rows: Range<usize>,
+    ) -> Result<RecordBatch, ArrowError> {
+        self.read_batch_impl(index, projection, Some(rows))
+    }
+
+    fn read_batch_impl(
+        &mut self,
+        index: usize,
+        projection: Option<&[usize]>,
+        rows: Option<Range<usize>>,
+    ) -> Result<RecordBatch, ArrowError> {
+        let block = self.blocks.get(index).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot read batch at index {} from {} total batches",
+                index, self.total_blocks
+            ))
+        })?;
+
+        let meta_len = block.metaDataLength().to_usize().unwrap();
+        let body_len = block.bodyLength().to_usize().unwrap();
+        self.decoder
+            .read_options
+            .check(ReadLimit::MessageSize, meta_len + body_len)?;
+        let body_offset = block.offset() as u64 + meta_len as u64;
+        let mut meta = vec![0; meta_len];
+        self.reader.seek(SeekFrom::Start(block.offset() as u64))?;
+        self.reader.read_exact(&mut meta)?;
+
+        let reader = &mut self.reader;
+        self.decoder
+            .read_record_batch_columns(&meta, projection, rows, |range| {
+                let mut buf = MutableBuffer::from_len_zeroed(range.len());
+                reader.seek(SeekFrom::Start(body_offset + range.start as u64))?;
+                reader.read_exact(&mut buf)?;
+                Ok(buf.into())
+            })
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         let block = &self.blocks[self.current_block];
         self.current_block += 1;
 
         // read length
-        let buffer = read_block(&mut self.reader, block)?;
+        let buffer = read_block(&mut self.reader, block, &self.decoder.read_options)?;
         self.decoder.read_record_batch(block, &buffer)
     }
 
@@ -1166,6 +1644,12 @@ pub struct StreamReader<R> {
 
     /// Optional projection
     projection: Option<(Vec<usize>, Schema)>,
+
+    /// Whether the stream was written with a different endianness, and must be byte-swapped
+    swap_endianness: bool,
+
+    /// Options limiting the data read
+    read_options: IpcReadOptions,
 }
 
 impl<R> fmt::Debug for StreamReader<R> {
@@ -1202,8 +1686,22 @@ impl<R: Read> StreamReader<R> {
     /// An ['Err'](Result::Err) may be returned if the reader does not encounter a schema
     /// as the first message in the stream.
     pub fn try_new(
+        reader: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<StreamReader<R>, ArrowError> {
+        Self::try_new_with_options(reader, projection, IpcReadOptions::default())
+    }
+
+    /// Try to create a new stream reader, with [`IpcReadOptions`] limiting the data read
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if the reader does not encounter a schema
+    /// as the first message in the stream, or the schema exceeds the limits of `read_options`.
+    pub fn try_new_with_options(
         mut reader: R,
         projection: Option<Vec<usize>>,
+        read_options: IpcReadOptions,
     ) -> Result<StreamReader<R>, ArrowError> {
         // determine metadata length
         let mut meta_size: [u8; 4] = [0; 4];
@@ -1216,6 +1714,7 @@ impl<R: Read> StreamReader<R> {
             }
             i32::from_le_bytes(meta_size)
         };
+        read_options.check(ReadLimit::MessageSize, meta_len as usize)?;
 
         let mut meta_buffer = vec![0; meta_len as usize];
         reader.read_exact(&mut meta_buffer)?;
@@ -1227,7 +1726,9 @@ impl<R: Read> StreamReader<R> {
         let ipc_schema: crate::Schema = message.header_as_schema().ok_or_else(|| {
             ArrowError::ParseError("Unable to read IPC message as schema".to_string())
         })?;
+        let swap_endianness = !ipc_schema.endianness().equals_to_target_endianness();
         let schema = crate::convert::fb_to_schema(ipc_schema);
+        read_options.check_schema(&schema)?;
 
         // Create an array of optional dictionary value arrays, one per field.
         let dictionaries_by_id = HashMap::new();
@@ -1245,6 +1746,8 @@ impl<R: Read> StreamReader<R> {
             finished: false,
             dictionaries_by_id,
             projection,
+            swap_endianness,
+            read_options,
         })
     }
 
@@ -1304,6 +1807,8 @@ impl<R: Read> StreamReader<R> {
             return Ok(None);
         }
 
+        self.read_options
+            .check(ReadLimit::MessageSize, meta_len as usize)?;
         let mut meta_buffer = vec![0; meta_len as usize];
         self.reader.read_exact(&mut meta_buffer)?;
 
@@ -1311,6 +1816,9 @@ impl<R: Read> StreamReader<R> {
         let message = crate::root_as_message(vecs).map_err(|err| {
             ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
         })?;
+        let message_size = meta_len as usize + message.bodyLength() as usize;
+        self.read_options
+            .check(ReadLimit::MessageSize, message_size)?;
 
         match message.header_type() {
             crate::MessageHeader::Schema => Err(ArrowError::IpcError(
@@ -1332,6 +1840,8 @@ impl<R: Read> StreamReader<R> {
                     self.projection.as_ref().map(|x| x.0.as_ref()),
                     &message.version(),
                     false,
+                    self.swap_endianness,
+                    &self.read_options,
                 )
                 .map(Some)
             }
@@ -1352,6 +1862,8 @@ impl<R: Read> StreamReader<R> {
                     &mut self.dictionaries_by_id,
                     &message.version(),
                     false,
+                    self.swap_endianness,
+                    &self.read_options,
                 )?;
 
                 // read the next message until we encounter a RecordBatch
@@ -1403,7 +1915,7 @@ mod tests {
     use arrow_array::builder::{PrimitiveRunBuilder, UnionBuilder};
     use arrow_array::types::*;
     use arrow_buffer::NullBuffer;
-    use arrow_data::ArrayDataBuilder;
+    use arrow_data::{ArrayDataBuilder, ByteView};
 
     fn create_test_projection_schema() -> Schema {
         // define field types
@@ -1576,6 +2088,134 @@ mod tests {
         }
     }
 
+    /// A reader counting the number of bytes read
+    struct CountingReader<R> {
+        inner: R,
+        read: usize,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let read = self.inner.read(buf)?;
+            self.read += read;
+            Ok(read)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingReader<R> {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_read_batch() {
+        let schema = create_test_projection_schema();
+        let batch = create_test_projection_batch_data(&schema);
+        let view: ArrayRef = Arc::new(StringViewArray::from(vec![
+            "a string longer than twelve bytes",
+            "b",
+            "another string longer than twelve bytes",
+        ]));
+        let view_batch = RecordBatch::try_from_iter([
+            ("view", view.clone()),
+            ("int", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("view2", view),
+        ])
+        .unwrap();
+
+        for batch in [batch, view_batch] {
+            let mut writer = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            let buf = writer.into_inner().unwrap();
+
+            let mut reader = FileReader::try_new(std::io::Cursor::new(buf.clone()), None).unwrap();
+            assert_eq!(reader.read_batch(1, None).unwrap(), batch);
+            assert_eq!(reader.read_batch(0, None).unwrap(), batch);
+
+            for (index, field) in batch.schema().fields().iter().enumerate() {
+                let read = reader.read_batch(1, Some(&[index])).unwrap();
+                assert_eq!(read, batch.project(&[index]).unwrap());
+
+                // Sliced run arrays cannot be compared
+                if !matches!(field.data_type(), DataType::RunEndEncoded(_, _)) {
+                    let read = reader.read_batch_rows(1, Some(&[index]), 1..2).unwrap();
+                    assert_eq!(read, batch.project(&[index]).unwrap().slice(1, 1));
+                }
+            }
+
+            let projection = [2, 0, 1];
+            let read = reader.read_batch(1, Some(&projection)).unwrap();
+            assert_eq!(read, batch.project(&projection).unwrap());
+            let read = reader.read_batch_rows(0, Some(&[]), 0..2).unwrap();
+            assert_eq!(read.num_columns(), 0);
+            assert_eq!(read.num_rows(), 2);
+
+            // Only the metadata and the data of the projected column are read
+            let reader = CountingReader {
+                inner: std::io::Cursor::new(buf),
+                read: 0,
+            };
+            let mut reader = FileReader::try_new(reader, None).unwrap();
+            let read_footer = reader.get_ref().read;
+            reader.read_batch(1, Some(&[0])).unwrap();
+            let read_batch = reader.get_ref().read - read_footer;
+            reader.read_batch(1, None).unwrap();
+            assert!(read_batch < reader.get_ref().read - read_footer - read_batch);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "lz4")]
+    fn test_read_batch_compressed() {
+        let schema = create_test_projection_schema();
+        let batch = create_test_projection_batch_data(&schema);
+        let options = IpcWriteOptions::default()
+            .try_with_compression(Some(crate::CompressionType::LZ4_FRAME))
+            .unwrap();
+        let mut writer =
+            crate::writer::FileWriter::try_new_with_options(vec![], &schema, options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        let buf = writer.into_inner().unwrap();
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        assert_eq!(reader.read_batch(0, None).unwrap(), batch);
+        let projection = [13, 1, 6, 9];
+        let read = reader.read_batch(0, Some(&projection)).unwrap();
+        assert_eq!(read, batch.project(&projection).unwrap());
+    }
+
+    #[test]
+    fn test_read_batch_errors() {
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef)])
+                .unwrap();
+        let mut writer = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        let buf = writer.into_inner().unwrap();
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+
+        let err = reader.read_batch(1, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read batch at index 1 from 1 total batches"
+        );
+        let err = reader.read_batch(0, Some(&[1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: project index 1 out of bounds, max field 1"
+        );
+        let err = reader.read_batch_rows(0, None, 1..3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read rows 1..3 from a record batch of 2 rows"
+        );
+    }
+
     #[test]
     fn test_arrow_single_float_row() {
         let schema = Schema::new(vec![
@@ -1788,6 +2428,54 @@ mod tests {
         assert_eq!(rb, rb2);
     }
 
+    #[test]
+    fn test_roundtrip_decimal32_decimal64() {
+        let decimal32 = Decimal32Array::from(vec![Some(123), None, Some(-999_999_999)])
+            .with_precision_and_scale(9, 3)
+            .unwrap();
+        let decimal64 = Decimal64Array::from(vec![None, Some(10_i64.pow(15)), Some(-1)])
+            .with_precision_and_scale(16, -2)
+            .unwrap();
+        let rb = RecordBatch::try_from_iter([
+            ("decimal32", Arc::new(decimal32) as _),
+            ("decimal64", Arc::new(decimal64) as _),
+        ])
+        .unwrap();
+        assert_eq!(rb, roundtrip_ipc(&rb));
+        assert_eq!(rb, roundtrip_ipc_stream(&rb));
+    }
+
+    #[test]
+    fn test_roundtrip_extension_types() {
+        use arrow_array::extension::{Bool8Array, ExtensionArray, JsonArray, UuidArray};
+
+        let uuid: UuidArray = vec![Some([7; 16]), None].into_iter().collect();
+        let json = JsonArray::from(StringArray::from(vec![None, Some("{}")]));
+        let bool8: Bool8Array = vec![Some(true), Some(false)].into_iter().collect();
+        let schema = Arc::new(Schema::new(vec![
+            uuid.field("uuid"),
+            json.field("json"),
+            bool8.field("bool8"),
+        ]));
+        let columns = vec![
+            uuid.to_array_ref(),
+            json.to_array_ref(),
+            bool8.to_array_ref(),
+        ];
+        let rb = RecordBatch::try_new(schema, columns).unwrap();
+
+        for read in [roundtrip_ipc(&rb), roundtrip_ipc_stream(&rb)] {
+            assert_eq!(rb, read);
+            let schema = read.schema();
+            let uuid = UuidArray::try_from_field(schema.field(0), read.column(0)).unwrap();
+            assert_eq!(uuid.value(0), [7; 16]);
+            let json = JsonArray::try_from_field(schema.field(1), read.column(1)).unwrap();
+            assert_eq!(json.value(1), "{}");
+            let bool8 = Bool8Array::try_from_field(schema.field(2), read.column(2)).unwrap();
+            assert!(bool8.value(0));
+        }
+    }
+
     #[test]
     fn test_roundtrip_stream_run_array_sliced() {
         let run_array_1: Int32RunArray = vec!["a", "a", "a", "b", "b", "c", "c", "c"]
@@ -2173,6 +2861,8 @@ mod tests {
             None,
             &message.version(),
             false,
+            false,
+            &Default::default(),
         )
         .unwrap();
         assert_eq!(batch, roundtrip);
@@ -2210,6 +2900,8 @@ mod tests {
             None,
             &message.version(),
             true,
+            false,
+            &Default::default(),
         );
 
         let error = result.unwrap_err();
@@ -2373,4 +3065,332 @@ mod tests {
                 assert_eq!(decoded_batch.expect("Failed to read RecordBatch"), batch);
             });
     }
+
+    /// Encodes `batch` as the messages of an IPC stream written by a big-endian system,
+    /// reversing the byte order of the values of `widths` bytes of each buffer, listed
+    /// in the order the buffers are written with those of the dictionaries first
+    fn big_endian_messages(batch: &RecordBatch, widths: &[&[usize]]) -> Vec<Vec<u8>> {
+        let options = IpcWriteOptions::default();
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let fields = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| crate::convert::build_field(&mut fbb, &mut None, f))
+            .collect::<Vec<_>>();
+        let fields = fbb.create_vector(&fields);
+        let mut schema = crate::SchemaBuilder::new(&mut fbb);
+        schema.add_fields(fields);
+        schema.add_endianness(crate::Endianness::Big);
+        let schema = schema.finish();
+        let mut message = crate::MessageBuilder::new(&mut fbb);
+        message.add_version(MetadataVersion::V5);
+        message.add_header_type(crate::MessageHeader::Schema);
+        message.add_bodyLength(0);
+        message.add_header(schema.as_union_value());
+        let message = message.finish();
+        fbb.finish(message, None);
+        let mut encoded = vec![crate::writer::EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data: vec![],
+        }];
+
+        let mut tracker = DictionaryTracker::new_with_preserve_dict_id(false, true);
+        let (dictionaries, data) = IpcDataGenerator::default()
+            .encoded_batch(batch, &mut tracker, &options)
+            .unwrap();
+        let mut widths = widths.iter();
+        for mut data in dictionaries.into_iter().chain([data]) {
+            let message = root_as_message(&data.ipc_message).unwrap();
+            let ipc_batch = match message.header_as_dictionary_batch() {
+                Some(dictionary) => dictionary.data().unwrap(),
+                None => message.header_as_record_batch().unwrap(),
+            };
+            for buffer in ipc_batch.buffers().unwrap() {
+                let widths = widths.next().unwrap();
+                let start = buffer.offset() as usize;
+                let values = &mut data.arrow_data[start..start + buffer.length() as usize];
+                let width = widths.iter().sum::<usize>().max(1);
+                for value in values.chunks_exact_mut(width) {
+                    let mut start = 0;
+                    for width in widths.iter() {
+                        value[start..start + width].reverse();
+                        start += width;
+                    }
+                }
+            }
+            encoded.push(data);
+        }
+        assert!(widths.next().is_none());
+
+        encoded
+            .into_iter()
+            .map(|data| {
+                let mut buf = vec![];
+                crate::writer::write_message(&mut buf, data, &options).unwrap();
+                buf
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_read_big_endian() {
+        let list = LargeListArray::from_iter_primitive::<Int16Type, _, _>([
+            Some(vec![Some(1), None, Some(-300)]),
+            None,
+            Some(vec![Some(i16::MAX)]),
+        ]);
+        let dict: DictionaryArray<Int16Type> = [Some("a"), None, Some("bcd")].into_iter().collect();
+        let intervals = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(1, -2, 3),
+            IntervalMonthDayNano::new(i32::MAX, 5, i64::MIN),
+            IntervalMonthDayNano::new(0, 0, 0),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            (
+                "i32",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(-70000)])) as _,
+            ),
+            (
+                "str",
+                Arc::new(StringArray::from(vec!["foo", "", "barbaz"])) as _,
+            ),
+            ("list", Arc::new(list) as _),
+            (
+                "decimal",
+                Arc::new(
+                    Decimal128Array::from(vec![i128::MIN, 0, 12345678901234567890])
+                        .with_precision_and_scale(38, 2)
+                        .unwrap(),
+                ) as _,
+            ),
+            ("interval", Arc::new(intervals) as _),
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as _,
+            ),
+            ("dict", Arc::new(dict) as _),
+        ])
+        .unwrap();
+
+        let widths: &[&[usize]] = &[
+            // dictionary values
+            &[],
+            &[4],
+            &[],
+            // i32
+            &[],
+            &[4],
+            // str
+            &[],
+            &[4],
+            &[],
+            // list
+            &[],
+            &[8],
+            &[],
+            &[2],
+            // decimal
+            &[],
+            &[16],
+            // interval
+            &[],
+            &[4, 4, 8],
+            // bool
+            &[],
+            &[],
+            // dict
+            &[],
+            &[2],
+        ];
+        let messages = big_endian_messages(&batch, widths);
+        let mut stream = messages.concat();
+        stream.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+
+        let reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, vec![batch.clone()]);
+
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_vec(stream);
+        let read = decoder.decode(&mut buffer).unwrap();
+        assert_eq!(read, Some(batch.clone()));
+
+        // Read the messages as the blocks of a file
+        let mut decoder = FileDecoder::new(batch.schema(), MetadataVersion::V5)
+            .with_endianness(crate::Endianness::Big);
+        let block = |message: &[u8]| {
+            let meta_len = 8 + i32::from_le_bytes(message[4..8].try_into().unwrap());
+            let body_len = message.len() as i64 - meta_len as i64;
+            (Block::new(0, meta_len, body_len), Buffer::from(message))
+        };
+        let (dictionary, data) = block(&messages[1]);
+        decoder.read_dictionary(&dictionary, &data).unwrap();
+        let (record_batch, data) = block(&messages[2]);
+        let read = decoder.read_record_batch(&record_batch, &data).unwrap();
+        assert_eq!(read, Some(batch));
+    }
+
+    #[test]
+    fn test_swap_view_bytes() {
+        let mut big_endian = vec![];
+        // An inlined view of "hello"
+        big_endian.extend_from_slice(&[0, 0, 0, 5]);
+        big_endian.extend_from_slice(b"hello\0\0\0\0\0\0\0");
+        // A view of 20 bytes with prefix "abcd", at offset 3 of buffer 1
+        big_endian.extend_from_slice(&[0, 0, 0, 20]);
+        big_endian.extend_from_slice(b"abcd");
+        big_endian.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0, 3]);
+
+        let swapped = swap_view_bytes(&Buffer::from_vec(big_endian));
+        let views = ScalarBuffer::<u128>::new(swapped, 0, 2);
+        let inlined = ByteView::from(views[0]);
+        assert_eq!(inlined.length, 5);
+        assert_eq!(&views[0].to_le_bytes()[4..9], b"hello");
+        let view = ByteView::from(views[1]);
+        assert_eq!(view.length, 20);
+        assert_eq!(view.prefix, u32::from_le_bytes(*b"abcd"));
+        assert_eq!(view.buffer_index, 1);
+        assert_eq!(view.offset, 3);
+    }
+
+    fn read_limit_exceeded(e: ArrowError) -> ReadLimitExceeded {
+        match e {
+            ArrowError::ExternalError(e) => e.downcast_ref::<ReadLimitExceeded>().unwrap().clone(),
+            e => panic!("unexpected error {e}"),
+        }
+    }
+
+    /// A batch of 5 fields with a maximum depth of 2, and 10 buffers
+    fn read_options_batch() -> RecordBatch {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(
+            (0..1024).map(|i| Some(vec![Some(i)])),
+        );
+        let strings = Arc::new(StringArray::from_iter_values(
+            (0..1024).map(|i| i.to_string()),
+        ));
+        let nested =
+            StructArray::from(vec![(Arc::new(Field::new("s", Utf8, false)), strings as _)]);
+        RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from_iter_values(0..1024)) as _),
+            ("list", Arc::new(list) as _),
+            ("struct", Arc::new(nested) as _),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_read_options() {
+        let batch = read_options_batch();
+        let mut writer = crate::writer::StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        let stream = writer.into_inner().unwrap();
+        let mut writer = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        let file = writer.into_inner().unwrap();
+
+        let read_stream = |options: IpcReadOptions| {
+            let reader = StreamReader::try_new_with_options(stream.as_slice(), None, options)?;
+            reader.collect::<Result<Vec<_>, _>>()
+        };
+        let read_file = |options: IpcReadOptions| {
+            let reader = FileReaderBuilder::new()
+                .with_read_options(options)
+                .build(std::io::Cursor::new(&file))?;
+            reader.collect::<Result<Vec<_>, _>>()
+        };
+        let decode_stream = |options: IpcReadOptions| {
+            let mut decoder = StreamDecoder::new().with_read_options(options);
+            let mut buffer = Buffer::from(stream.as_slice());
+            let mut batches = vec![];
+            while let Some(batch) = decoder.decode(&mut buffer)? {
+                batches.push(batch);
+            }
+            decoder.finish().map(|_| batches)
+        };
+
+        let check = |options: IpcReadOptions, expected: Option<(ReadLimit, usize)>| {
+            let readers: [&dyn Fn(IpcReadOptions) -> Result<Vec<RecordBatch>, ArrowError>; 3] =
+                [&read_stream, &read_file, &decode_stream];
+            for read in readers {
+                match (read(options.clone()), expected) {
+                    (Ok(read), None) => assert_eq!(read, vec![batch.clone()]),
+                    (Err(e), Some((limit, value))) => {
+                        let e = read_limit_exceeded(e);
+                        assert_eq!(e.limit(), limit);
+                        assert!(e.value() >= value, "{e}");
+                    }
+                    (r, _) => panic!("unexpected result {r:?} for {options:?}"),
+                }
+            }
+        };
+
+        check(IpcReadOptions::new(), None);
+        check(
+            IpcReadOptions::new()
+                .with_max_fields(5)
+                .with_max_nesting_depth(2)
+                .with_max_buffers(10),
+            None,
+        );
+        check(
+            IpcReadOptions::new().with_max_fields(4),
+            Some((ReadLimit::Fields, 5)),
+        );
+        check(
+            IpcReadOptions::new().with_max_nesting_depth(1),
+            Some((ReadLimit::NestingDepth, 2)),
+        );
+        check(
+            IpcReadOptions::new().with_max_buffers(9),
+            Some((ReadLimit::Buffers, 10)),
+        );
+        // The batch exceeds the limit, but not the schema or footer
+        check(
+            IpcReadOptions::new().with_max_message_size(1024),
+            Some((ReadLimit::MessageSize, 4096)),
+        );
+        // SAFETY: the data was written by this process
+        let options = unsafe { IpcReadOptions::new().with_skip_validation(true) };
+        check(options, None);
+
+        let e = read_stream(IpcReadOptions::new().with_max_message_size(8)).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            format!(
+                "External error: IPC message size of {} exceeds the limit of 8",
+                { i32::from_le_bytes(stream[4..8].try_into().unwrap()) }
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lz4")]
+    fn test_read_options_decompressed_size() {
+        let array = Int32Array::from(vec![1; 1024]);
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as _)]).unwrap();
+        let options = IpcWriteOptions::default()
+            .try_with_compression(Some(crate::CompressionType::LZ4_FRAME))
+            .unwrap();
+        let mut writer =
+            crate::writer::FileWriter::try_new_with_options(vec![], &batch.schema(), options)
+                .unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        let file = writer.into_inner().unwrap();
+
+        let read = |options: IpcReadOptions| {
+            let reader = FileReaderBuilder::new()
+                .with_read_options(options)
+                .build(std::io::Cursor::new(&file))?;
+            reader.collect::<Result<Vec<_>, _>>()
+        };
+        let options = IpcReadOptions::new().with_max_decompressed_size(4096);
+        assert_eq!(read(options).unwrap(), vec![batch]);
+
+        let options = IpcReadOptions::new().with_max_decompressed_size(4095);
+        let e = read_limit_exceeded(read(options).unwrap_err());
+        assert_eq!(e.limit(), ReadLimit::DecompressedSize);
+        assert_eq!((e.value(), e.max()), (4096, 4095));
+    }
 }
@@ -0,0 +1,285 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::{ready, Stream};
+use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
+
+use crate::reader::StreamDecoder;
+
+/// The number of bytes requested from the reader by each read
+const READ_SIZE: usize = 64 * 1024;
+
+/// Asynchronous reader for an IPC stream, yielding [`RecordBatch`] from an [`AsyncRead`]
+///
+/// This is the asynchronous counterpart of [`StreamReader`](crate::reader::StreamReader),
+/// reading the stream incrementally with a [`StreamDecoder`] instead of blocking a thread
+/// or buffering the entire stream.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::AsyncStreamReader;
+/// # use arrow_ipc::writer::StreamWriter;
+/// # use futures::TryStreamExt;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+/// let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// let bytes = writer.into_inner().unwrap();
+///
+/// let reader = AsyncStreamReader::try_new(bytes.as_slice()).await.unwrap();
+/// assert_eq!(reader.schema(), batch.schema());
+/// let batches: Vec<_> = reader.try_collect().await.unwrap();
+/// assert_eq!(batches, vec![batch]);
+/// # }
+/// ```
+pub struct AsyncStreamReader<R> {
+    /// The object to read from
+    reader: R,
+    /// The schema that is read from the stream's first message
+    schema: SchemaRef,
+    /// Decodes the bytes read from `reader`
+    decoder: StreamDecoder,
+    /// Bytes read from `reader` that are yet to be decoded
+    buffer: Buffer,
+    /// A batch decoded while reading the schema, yet to be returned
+    pending: Option<RecordBatch>,
+    /// Whether the end of `reader` has been reached, or an error returned
+    finished: bool,
+}
+
+impl<R> Debug for AsyncStreamReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncStreamReader<R>")
+            .field("reader", &"R")
+            .field("schema", &self.schema)
+            .field("decoder", &self.decoder)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncStreamReader<R> {
+    /// Try to create a new stream reader, reading the schema from the start of the stream
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if the reader does not begin with a valid
+    /// schema message.
+    pub async fn try_new(reader: R) -> Result<Self, ArrowError> {
+        Self::try_new_with_decoder(reader, StreamDecoder::new()).await
+    }
+
+    /// Try to create a new stream reader decoding with `decoder`, reading the schema from
+    /// the start of the stream
+    ///
+    /// This allows configuring the [`StreamDecoder`], for example with
+    /// [`StreamDecoder::with_require_alignment`]
+    pub async fn try_new_with_decoder(
+        mut reader: R,
+        mut decoder: StreamDecoder,
+    ) -> Result<Self, ArrowError> {
+        let mut buffer = Buffer::from_vec(Vec::<u8>::new());
+        let mut pending = None;
+        while decoder.schema().is_none() {
+            if buffer.is_empty() {
+                let mut buf = Vec::with_capacity(READ_SIZE);
+                if reader.read_buf(&mut buf).await? == 0 {
+                    return Err(ArrowError::IpcError(
+                        "Expected schema message at the start of the stream".to_string(),
+                    ));
+                }
+                buffer = Buffer::from_vec(buf);
+            }
+            pending = decoder.decode(&mut buffer)?;
+        }
+
+        Ok(Self {
+            reader,
+            schema: decoder.schema().unwrap(),
+            decoder,
+            buffer,
+            pending,
+            finished: false,
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Consumes the [`AsyncStreamReader`], returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsyncStreamReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if let Some(batch) = this.pending.take() {
+            return Poll::Ready(Some(Ok(batch)));
+        }
+
+        while !this.finished {
+            if !this.buffer.is_empty() {
+                match this.decoder.decode(&mut this.buffer) {
+                    Ok(Some(batch)) => return Poll::Ready(Some(Ok(batch))),
+                    Ok(None) => continue,
+                    Err(e) => {
+                        this.finished = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+            }
+
+            // Decoded arrays may reference the bytes read, so each read needs a new allocation
+            let mut buf = vec![0; READ_SIZE];
+            let mut read_buf = ReadBuf::new(&mut buf);
+            if let Err(e) = ready!(Pin::new(&mut this.reader).poll_read(cx, &mut read_buf)) {
+                this.finished = true;
+                return Poll::Ready(Some(Err(e.into())));
+            }
+
+            let read = read_buf.filled().len();
+            if read == 0 {
+                this.finished = true;
+                return Poll::Ready(this.decoder.finish().err().map(Err));
+            }
+            buf.truncate(read);
+            this.buffer = Buffer::from_vec(buf);
+        }
+        Poll::Ready(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{IpcWriteOptions, StreamWriter};
+    use arrow_array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+    use futures::TryStreamExt;
+    use std::sync::Arc;
+
+    /// An [`AsyncRead`] returning `bytes` in chunks of `size`, pending before each chunk
+    struct ChunkedReader {
+        bytes: Vec<u8>,
+        size: usize,
+        pending: bool,
+    }
+
+    impl AsyncRead for ChunkedReader {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            self.pending = !self.pending;
+            if self.pending {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let len = self.size.min(self.bytes.len()).min(buf.remaining());
+            buf.put_slice(&self.bytes[..len]);
+            self.bytes.drain(..len);
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let values = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+                let dict = DictionaryArray::new(Int32Array::from(vec![i, 2 - i, i]), values);
+                RecordBatch::try_from_iter([
+                    ("int", Arc::new(Int32Array::from(vec![i; 3])) as ArrayRef),
+                    ("dict", Arc::new(dict) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn write_stream(batches: &[RecordBatch]) -> Vec<u8> {
+        let options = IpcWriteOptions::try_new(8, false, crate::MetadataVersion::V5).unwrap();
+        let schema = batches[0].schema();
+        let mut writer = StreamWriter::try_new_with_options(vec![], &schema, options).unwrap();
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        writer.finish().unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader() {
+        let batches = batches();
+        let bytes = write_stream(&batches);
+
+        for size in [1, 7, 64, bytes.len()] {
+            let reader = ChunkedReader {
+                bytes: bytes.clone(),
+                size,
+                pending: false,
+            };
+            let reader = AsyncStreamReader::try_new(reader).await.unwrap();
+            assert_eq!(reader.schema(), batches[0].schema());
+            let read: Vec<_> = reader.try_collect().await.unwrap();
+            assert_eq!(read, batches);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader_errors() {
+        let err = AsyncStreamReader::try_new([].as_slice()).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Expected schema message at the start of the stream"
+        );
+
+        let bytes = write_stream(&batches());
+        let truncated = &bytes[..bytes.len() - 20];
+        let mut reader = AsyncStreamReader::try_new(truncated).await.unwrap();
+        assert!(reader.try_next().await.unwrap().is_some());
+        assert!(reader.try_next().await.unwrap().is_some());
+        let err = reader.try_next().await.unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: Unexpected End of Stream");
+        assert!(reader.try_next().await.unwrap().is_none());
+    }
+}
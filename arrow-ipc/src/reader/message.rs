@@ -0,0 +1,419 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::ArrowError;
+
+use crate::convert::MessageBuffer;
+use crate::reader::{IpcReadOptions, ReadLimit};
+use crate::{Message, CONTINUATION_MARKER};
+
+/// An IPC message in the [encapsulated message format], consisting of the flatbuffer
+/// [`Message`] metadata and the message body containing the data of its buffers
+///
+/// [encapsulated message format]: https://arrow.apache.org/docs/format/Columnar.html#encapsulated-message-format
+#[derive(Debug, Clone)]
+pub struct EncapsulatedMessage {
+    message: MessageBuffer,
+    body: Buffer,
+}
+
+impl EncapsulatedMessage {
+    /// Create a new [`EncapsulatedMessage`] from the flatbuffer metadata and body
+    ///
+    /// Returns an error if `body` does not have the length recorded in `message`
+    pub fn try_new(message: MessageBuffer, body: Buffer) -> Result<Self, ArrowError> {
+        let body_length = message.as_ref().bodyLength();
+        if body_length != body.len() as i64 {
+            return Err(ArrowError::IpcError(format!(
+                "Message body of {} bytes does not match the body length of {body_length}",
+                body.len()
+            )));
+        }
+        Ok(Self { message, body })
+    }
+
+    /// Returns the flatbuffer [`Message`] metadata
+    pub fn message(&self) -> Message<'_> {
+        self.message.as_ref()
+    }
+
+    /// Returns the [`MessageBuffer`] containing the flatbuffer metadata
+    pub fn message_buffer(&self) -> &MessageBuffer {
+        &self.message
+    }
+
+    /// Returns the message body
+    pub fn body(&self) -> &Buffer {
+        &self.body
+    }
+
+    /// Returns the metadata and body of this message
+    pub fn into_parts(self) -> (MessageBuffer, Buffer) {
+        (self.message, self.body)
+    }
+}
+
+/// A low-level interface for reading [`EncapsulatedMessage`] from a stream of bytes
+///
+/// Each message is framed by an optional continuation marker, the length of the
+/// flatbuffer metadata, the metadata and the message body, as written by
+/// [`MessageEncoder`](crate::writer::MessageEncoder). A length of 0 marks the end of the
+/// stream.
+///
+/// This only frames messages, allowing custom transports to read messages individually,
+/// see [`StreamDecoder`](crate::reader::StreamDecoder) to decode them into record batches.
+///
+/// ```
+/// # use arrow_buffer::Buffer;
+/// # use arrow_ipc::MessageHeader;
+/// # use arrow_ipc::reader::MessageDecoder;
+/// # use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions, MessageEncoder};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+/// let options = IpcWriteOptions::default();
+/// let mut tracker = DictionaryTracker::new(false);
+/// let encoded = IpcDataGenerator::default()
+///     .schema_to_bytes_with_dictionary_tracker(&schema, &mut tracker, &options);
+///
+/// let encoder = MessageEncoder::new(options);
+/// let mut bytes = encoder.encode_to_vec(encoded).unwrap();
+/// encoder.encode_end_of_stream(&mut bytes).unwrap();
+///
+/// let mut decoder = MessageDecoder::new();
+/// let mut buffer = Buffer::from_vec(bytes);
+/// let message = decoder.decode(&mut buffer).unwrap().unwrap();
+/// assert_eq!(message.message().header_type(), MessageHeader::Schema);
+/// assert!(decoder.decode(&mut buffer).unwrap().is_none());
+/// assert!(decoder.is_finished());
+/// decoder.finish().unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct MessageDecoder {
+    /// The decoder state
+    state: DecoderState,
+    /// A scratch buffer when a read is split across multiple `Buffer`
+    buf: MutableBuffer,
+    /// Options limiting the data read
+    read_options: IpcReadOptions,
+}
+
+#[derive(Debug)]
+enum DecoderState {
+    /// Decoding the message header
+    Header {
+        /// Temporary buffer
+        buf: [u8; 4],
+        /// Number of bytes read into buf
+        read: u8,
+        /// If we have read a continuation token
+        continuation: bool,
+    },
+    /// Decoding the message flatbuffer
+    Message {
+        /// The size of the message flatbuffer
+        size: u32,
+    },
+    /// Decoding the message body
+    Body {
+        /// The message flatbuffer
+        message: MessageBuffer,
+    },
+    /// Reached the end of the stream
+    Finished,
+}
+
+impl Default for DecoderState {
+    fn default() -> Self {
+        Self::Header {
+            buf: [0; 4],
+            read: 0,
+            continuation: false,
+        }
+    }
+}
+
+impl MessageDecoder {
+    /// Create a new [`MessageDecoder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specifies the [`IpcReadOptions`] limiting the size of the messages read
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
+    /// Returns an error if the message, with metadata of `len` bytes, exceeds the message
+    /// size limit of the read options
+    fn check_message_size(&self, len: usize, message: &MessageBuffer) -> Result<(), ArrowError> {
+        let size = len + message.as_ref().bodyLength() as usize;
+        self.read_options.check(ReadLimit::MessageSize, size)
+    }
+
+    /// Try to read the next [`EncapsulatedMessage`] from the provided [`Buffer`]
+    ///
+    /// [`Buffer::advance`] will be called on `buffer` for any consumed bytes. Returns
+    /// `None` if `buffer` is consumed before a complete message is read, in which case
+    /// the partial message is retained until more data is provided.
+    pub fn decode(
+        &mut self,
+        buffer: &mut Buffer,
+    ) -> Result<Option<EncapsulatedMessage>, ArrowError> {
+        while !buffer.is_empty() || self.has_empty_body() {
+            match &mut self.state {
+                DecoderState::Header {
+                    buf,
+                    read,
+                    continuation,
+                } => {
+                    let offset_buf = &mut buf[*read as usize..];
+                    let to_read = buffer.len().min(offset_buf.len());
+                    offset_buf[..to_read].copy_from_slice(&buffer[..to_read]);
+                    *read += to_read as u8;
+                    buffer.advance(to_read);
+                    if *read == 4 {
+                        if !*continuation && buf == &CONTINUATION_MARKER {
+                            *continuation = true;
+                            *read = 0;
+                            continue;
+                        }
+                        let size = u32::from_le_bytes(*buf);
+
+                        if size == 0 {
+                            self.state = DecoderState::Finished;
+                            continue;
+                        }
+                        self.read_options
+                            .check(ReadLimit::MessageSize, size as usize)?;
+                        self.state = DecoderState::Message { size };
+                    }
+                }
+                DecoderState::Message { size } => {
+                    let len = *size as usize;
+                    if self.buf.is_empty() && buffer.len() > len {
+                        let message = MessageBuffer::try_new(buffer.slice_with_length(0, len))?;
+                        self.check_message_size(len, &message)?;
+                        self.state = DecoderState::Body { message };
+                        buffer.advance(len);
+                        continue;
+                    }
+
+                    let to_read = buffer.len().min(len - self.buf.len());
+                    self.buf.extend_from_slice(&buffer[..to_read]);
+                    buffer.advance(to_read);
+                    if self.buf.len() == len {
+                        let message = MessageBuffer::try_new(std::mem::take(&mut self.buf).into())?;
+                        self.check_message_size(len, &message)?;
+                        self.state = DecoderState::Body { message };
+                    }
+                }
+                DecoderState::Body { message } => {
+                    let body_length = message.as_ref().bodyLength() as usize;
+
+                    let body = if self.buf.is_empty() && buffer.len() >= body_length {
+                        let body = buffer.slice_with_length(0, body_length);
+                        buffer.advance(body_length);
+                        body
+                    } else {
+                        let to_read = buffer.len().min(body_length - self.buf.len());
+                        self.buf.extend_from_slice(&buffer[..to_read]);
+                        buffer.advance(to_read);
+
+                        if self.buf.len() != body_length {
+                            continue;
+                        }
+                        std::mem::take(&mut self.buf).into()
+                    };
+
+                    let message = match std::mem::take(&mut self.state) {
+                        DecoderState::Body { message } => message,
+                        _ => unreachable!(),
+                    };
+                    return Ok(Some(EncapsulatedMessage { message, body }));
+                }
+                DecoderState::Finished => {
+                    return Err(ArrowError::IpcError("Unexpected EOS".to_string()))
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns true if the metadata of a message without a body has been read, which can
+    /// be returned without consuming further bytes
+    fn has_empty_body(&self) -> bool {
+        matches!(&self.state, DecoderState::Body { message } if message.as_ref().bodyLength() == 0)
+    }
+
+    /// Returns true if the end of stream marker has been read
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, DecoderState::Finished)
+    }
+
+    /// Signal the end of stream
+    ///
+    /// Returns an error if any partial data remains in the stream
+    pub fn finish(&mut self) -> Result<(), ArrowError> {
+        match self.state {
+            DecoderState::Finished
+            | DecoderState::Header {
+                read: 0,
+                continuation: false,
+                ..
+            } => Ok(()),
+            _ => Err(ArrowError::IpcError("Unexpected End of Stream".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::StreamDecoder;
+    use crate::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions, MessageEncoder};
+    use crate::MessageHeader;
+    use arrow_array::{ArrayRef, DictionaryArray, Int32Array, RecordBatch, StringArray};
+    use std::sync::Arc;
+
+    fn encode_messages(batch: &RecordBatch, options: IpcWriteOptions) -> Vec<u8> {
+        let data_gen = IpcDataGenerator::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let encoder = MessageEncoder::new(options);
+
+        let schema = data_gen.schema_to_bytes_with_dictionary_tracker(
+            &batch.schema(),
+            &mut tracker,
+            encoder.write_options(),
+        );
+        let mut bytes = encoder.encode_to_vec(schema).unwrap();
+        let (dictionaries, batch) = data_gen
+            .encoded_batch(batch, &mut tracker, encoder.write_options())
+            .unwrap();
+        for encoded in dictionaries.into_iter().chain([batch]) {
+            encoder.encode(&mut bytes, encoded).unwrap();
+        }
+        encoder.encode_end_of_stream(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn batch() -> RecordBatch {
+        let keys = Int32Array::from(vec![0, 1, 0]);
+        let dict = DictionaryArray::new(keys, Arc::new(StringArray::from(vec!["a", "b"])));
+        RecordBatch::try_from_iter([
+            ("int", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("dict", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_message_decoder() {
+        let batch = batch();
+        let legacy = IpcWriteOptions::try_new(8, true, crate::MetadataVersion::V4).unwrap();
+        for options in [IpcWriteOptions::default(), legacy] {
+            let bytes = Buffer::from_vec(encode_messages(&batch, options));
+
+            // Decode one byte at a time
+            let mut decoder = MessageDecoder::new();
+            let mut messages = vec![];
+            for i in 0..bytes.len() {
+                let mut b = bytes.slice_with_length(i, 1);
+                if let Some(message) = decoder.decode(&mut b).unwrap() {
+                    messages.push(message);
+                }
+                assert!(b.is_empty());
+            }
+            assert!(decoder.is_finished());
+            decoder.finish().unwrap();
+
+            let header_types: Vec<_> = messages.iter().map(|m| m.message().header_type()).collect();
+            assert_eq!(
+                header_types,
+                [
+                    MessageHeader::Schema,
+                    MessageHeader::DictionaryBatch,
+                    MessageHeader::RecordBatch
+                ]
+            );
+
+            let mut stream_decoder = StreamDecoder::new();
+            let read: Vec<_> = messages
+                .into_iter()
+                .filter_map(|m| stream_decoder.decode_message(m).unwrap())
+                .collect();
+            assert_eq!(read, vec![batch.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_message_decoder_empty_body() {
+        let schema = batch().schema();
+        let options = IpcWriteOptions::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let encoded = IpcDataGenerator::default().schema_to_bytes_with_dictionary_tracker(
+            &schema,
+            &mut tracker,
+            &options,
+        );
+        let bytes = MessageEncoder::new(options).encode_to_vec(encoded).unwrap();
+
+        // A message without a body is returned once its metadata is read
+        let mut decoder = MessageDecoder::new();
+        let mut buffer = Buffer::from_vec(bytes);
+        let message = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(message.message().header_type(), MessageHeader::Schema);
+        assert!(message.body().is_empty());
+        assert!(!decoder.is_finished());
+        decoder.finish().unwrap();
+
+        // Partial header
+        let mut partial = Buffer::from_vec(vec![0xFF_u8; 6]);
+        assert!(decoder.decode(&mut partial).unwrap().is_none());
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: Unexpected End of Stream");
+    }
+
+    #[test]
+    fn test_message_decoder_errors() {
+        let bytes = Buffer::from_vec(encode_messages(&batch(), IpcWriteOptions::default()));
+
+        let options = IpcReadOptions::new().with_max_message_size(64);
+        let mut decoder = MessageDecoder::new().with_read_options(options);
+        let err = decoder.decode(&mut bytes.clone()).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit of 64"), "{err}");
+
+        let mut decoder = MessageDecoder::new();
+        let mut truncated = bytes.slice_with_length(0, bytes.len() - 20);
+        while decoder.decode(&mut truncated).unwrap().is_some() {}
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: Unexpected End of Stream");
+
+        let message = MessageDecoder::new()
+            .decode(&mut bytes.clone())
+            .unwrap()
+            .unwrap();
+        let (message, _) = message.into_parts();
+        let err =
+            EncapsulatedMessage::try_new(message, Buffer::from_vec(vec![0_u8; 8])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Message body of 8 bytes does not match the body length of 0"
+        );
+    }
+}
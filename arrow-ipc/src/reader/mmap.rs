@@ -0,0 +1,509 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, SchemaRef};
+
+use crate::reader::{
+    read_footer_length, FileDecoder, FileReaderBuilder, IpcReadOptions, ReadLimit,
+};
+use crate::Block;
+
+/// Arrow File reader constructing arrays as views over the file mapped into memory
+///
+/// Unlike [`FileReader`](crate::reader::FileReader), which copies each block of the file
+/// into a new allocation, the buffers of arrays read by this reader reference the file
+/// contents directly, provided they are uncompressed and sufficiently aligned. This allows
+/// large files to be read without copying, and the memory to be shared with other
+/// processes mapping the same file.
+///
+/// Files written with the default [`IpcWriteOptions`](crate::writer::IpcWriteOptions) align
+/// buffers to 64 bytes, which is sufficient for all data types. Buffers that are
+/// compressed or insufficiently aligned are copied to a new allocation.
+///
+/// A reader with custom options, such as [`IpcReadOptions`], can be created with
+/// [`FileReaderBuilder::build_mmap`] or [`FileReaderBuilder::build_from_buffer`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::MmapFileReader;
+/// # use arrow_ipc::writer::FileWriter;
+/// # #[cfg(unix)]
+/// # {
+/// let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+///
+/// let mut file = tempfile::tempfile().unwrap();
+/// let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.finish().unwrap();
+///
+/// // SAFETY: the file is not modified while it is mapped
+/// let reader = unsafe { MmapFileReader::try_new(&file, None) }.unwrap();
+/// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(batches, vec![batch]);
+/// # }
+/// ```
+pub struct MmapFileReader {
+    /// The contents of the file
+    buffer: Buffer,
+
+    /// The decoder
+    decoder: FileDecoder,
+
+    /// The record batch blocks of the file
+    blocks: Vec<Block>,
+
+    /// The index of the next block to read
+    current_block: usize,
+
+    /// User defined metadata
+    custom_metadata: HashMap<String, String>,
+}
+
+impl fmt::Debug for MmapFileReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MmapFileReader")
+            .field("len", &self.buffer.len())
+            .field("decoder", &self.decoder)
+            .field("blocks", &self.blocks)
+            .field("current_block", &self.current_block)
+            .field("custom_metadata", &self.custom_metadata)
+            .finish()
+    }
+}
+
+impl MmapFileReader {
+    /// Map `file` into memory, and create a reader of the record batches it contains,
+    /// with an optional projection of the columns to read
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, until
+    /// the reader and all arrays read from it are dropped, otherwise the behaviour is
+    /// undefined
+    #[cfg(unix)]
+    pub unsafe fn try_new(
+        file: &std::fs::File,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let builder = FileReaderBuilder {
+            projection,
+            ..Default::default()
+        };
+        builder.build_mmap(file)
+    }
+
+    /// Create a reader of the record batches of the Arrow file contained in `buffer`,
+    /// with an optional projection of the columns to read
+    ///
+    /// Arrays read reference `buffer`, which may be a memory mapping created by the caller
+    pub fn try_new_from_buffer(
+        buffer: Buffer,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let builder = FileReaderBuilder {
+            projection,
+            ..Default::default()
+        };
+        builder.build_from_buffer(buffer)
+    }
+
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+
+    /// Read the record batch at `index`, without changing the position of the reader
+    pub fn read_batch(&self, index: usize) -> Result<Option<RecordBatch>, ArrowError> {
+        let block = self.blocks.get(index).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot read batch at index {index} from {} total batches",
+                self.blocks.len()
+            ))
+        })?;
+        let buffer = block_buffer(&self.buffer, block, &self.decoder.read_options)?;
+        self.decoder.read_record_batch(block, &buffer)
+    }
+
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
+        if index >= self.blocks.len() {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index,
+                self.blocks.len()
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
+        }
+    }
+}
+
+impl FileReaderBuilder {
+    /// Map `file` into memory, and build a [`MmapFileReader`] of the record batches it
+    /// contains
+    ///
+    /// # Safety
+    ///
+    /// See [`MmapFileReader::try_new`]
+    #[cfg(unix)]
+    pub unsafe fn build_mmap(self, file: &std::fs::File) -> Result<MmapFileReader, ArrowError> {
+        self.build_from_buffer(map_file(file)?)
+    }
+
+    /// Build a [`MmapFileReader`] of the record batches of the Arrow file contained in
+    /// `buffer`, see [`MmapFileReader::try_new_from_buffer`]
+    pub fn build_from_buffer(self, buffer: Buffer) -> Result<MmapFileReader, ArrowError> {
+        let len = buffer.len();
+        if len < 10 {
+            return Err(ArrowError::ParseError(format!(
+                "Arrow file of {len} bytes is too small to contain a footer"
+            )));
+        }
+        let footer_len = read_footer_length(buffer[len - 10..].try_into().unwrap())?;
+        self.read_options
+            .check(ReadLimit::MessageSize, footer_len)?;
+        let footer_start = (len - 10).checked_sub(footer_len).ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Footer of {footer_len} bytes exceeds the Arrow file of {len} bytes"
+            ))
+        })?;
+
+        let mut footer = self.decode_footer(&buffer[footer_start..len - 10])?;
+        for block in &footer.dictionaries {
+            let buf = block_buffer(&buffer, block, &footer.decoder.read_options)?;
+            footer.decoder.read_dictionary(block, &buf)?;
+        }
+
+        Ok(MmapFileReader {
+            buffer,
+            decoder: footer.decoder,
+            blocks: footer.blocks,
+            current_block: 0,
+            custom_metadata: footer.custom_metadata,
+        })
+    }
+}
+
+impl Iterator for MmapFileReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_block < self.blocks.len() {
+            self.current_block += 1;
+            self.read_batch(self.current_block - 1).transpose()
+        } else {
+            None
+        }
+    }
+}
+
+impl RecordBatchReader for MmapFileReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema()
+    }
+}
+
+/// Returns the slice of `buffer` containing `block`, without copying
+fn block_buffer(
+    buffer: &Buffer,
+    block: &Block,
+    options: &IpcReadOptions,
+) -> Result<Buffer, ArrowError> {
+    let len = block.metaDataLength() as i64 + block.bodyLength();
+    let end = block.offset().checked_add(len);
+    match end {
+        Some(end) if block.offset() >= 0 && len >= 0 && end as u64 <= buffer.len() as u64 => {
+            options.check(ReadLimit::MessageSize, len as usize)?;
+            Ok(buffer.slice_with_length(block.offset() as usize, len as usize))
+        }
+        _ => Err(ArrowError::ParseError(format!(
+            "Block of {len} bytes at offset {} exceeds the Arrow file of {} bytes",
+            block.offset(),
+            buffer.len()
+        ))),
+    }
+}
+
+/// Maps the contents of `file` into memory, returning them as a [`Buffer`]
+///
+/// # Safety
+///
+/// The file must not be modified while the returned buffer, or any slice of it, exists
+#[cfg(unix)]
+unsafe fn map_file(file: &std::fs::File) -> Result<Buffer, ArrowError> {
+    use std::os::unix::io::AsRawFd;
+    use std::ptr::NonNull;
+
+    let len = usize::try_from(file.metadata()?.len()).map_err(|_| {
+        ArrowError::MemoryError("File is too large to be mapped into memory".to_string())
+    })?;
+    if len == 0 {
+        // Empty mappings are not permitted
+        return Ok(Buffer::from_vec(Vec::<u8>::new()));
+    }
+
+    let ptr = libc::mmap(
+        std::ptr::null_mut(),
+        len,
+        libc::PROT_READ,
+        libc::MAP_SHARED,
+        file.as_raw_fd(),
+        0,
+    );
+    if ptr == libc::MAP_FAILED {
+        return Err(std::io::Error::last_os_error().into());
+    }
+
+    let mapping = Mmap {
+        ptr: NonNull::new(ptr.cast()).unwrap(),
+        len,
+    };
+    Ok(Buffer::from_custom_allocation(
+        mapping.ptr,
+        len,
+        Arc::new(mapping),
+    ))
+}
+
+/// A read-only memory mapping, which is unmapped when dropped
+#[cfg(unix)]
+struct Mmap {
+    ptr: std::ptr::NonNull<u8>,
+    len: usize,
+}
+
+// SAFETY: the mapping is read-only, so may be shared between threads
+#[cfg(unix)]
+unsafe impl Send for Mmap {}
+#[cfg(unix)]
+unsafe impl Sync for Mmap {}
+
+#[cfg(unix)]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        // SAFETY: the mapping was created by `map_file` with this pointer and length
+        unsafe { libc::munmap(self.ptr.as_ptr().cast(), self.len) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{FileWriter, IpcWriteOptions};
+    use arrow_array::{Array, ArrayRef, Decimal128Array, DictionaryArray, Int32Array, StringArray};
+
+    fn batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let decimals =
+                    Decimal128Array::from_iter_values([i as i128, i as i128 * 100, -i as i128]);
+                let dict = DictionaryArray::new(
+                    Int32Array::from(vec![i, 0, 1]),
+                    Arc::new(StringArray::from(vec!["a", "b", "c"])),
+                );
+                RecordBatch::try_from_iter([
+                    ("int", Arc::new(Int32Array::from(vec![i; 3])) as ArrayRef),
+                    ("decimal", Arc::new(decimals) as ArrayRef),
+                    (
+                        "str",
+                        Arc::new(StringArray::from(vec!["x", "yy", "zzz"])) as ArrayRef,
+                    ),
+                    ("dict", Arc::new(dict) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn write_file(batches: &[RecordBatch], options: IpcWriteOptions) -> std::fs::File {
+        let mut file = tempfile::tempfile().unwrap();
+        let schema = batches[0].schema();
+        let mut writer = FileWriter::try_new_with_options(&mut file, &schema, options).unwrap();
+        writer.write_metadata("key", "value");
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        writer.finish().unwrap();
+        file
+    }
+
+    /// Returns whether all the buffers of `array` lie within `buffer`
+    fn is_zero_copy(array: &dyn Array, buffer: &Buffer) -> bool {
+        let range = buffer.as_ptr() as usize..buffer.as_ptr() as usize + buffer.len();
+        let data = array.to_data();
+        data.buffers()
+            .iter()
+            .all(|b| range.contains(&(b.as_ptr() as usize)))
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_mmap_file_reader() {
+        let batches = batches();
+        let file = write_file(&batches, IpcWriteOptions::default());
+
+        let mut reader = unsafe { MmapFileReader::try_new(&file, None) }.unwrap();
+        assert_eq!(reader.schema(), batches[0].schema());
+        assert_eq!(reader.num_batches(), 3);
+        assert_eq!(reader.custom_metadata()["key"], "value");
+
+        let read = reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+        for batch in &read {
+            for column in batch.columns() {
+                assert!(is_zero_copy(column.as_ref(), &reader.buffer));
+            }
+        }
+
+        reader.set_index(1).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batches[1]);
+        assert_eq!(reader.read_batch(0).unwrap().unwrap(), batches[0]);
+        let err = reader.read_batch(3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read batch at index 3 from 3 total batches"
+        );
+
+        // Arrays remain valid after the reader is dropped
+        let batch = reader.read_batch(2).unwrap().unwrap();
+        drop(reader);
+        assert_eq!(batch, batches[2]);
+
+        let reader = unsafe { MmapFileReader::try_new(&file, Some(vec![3, 0])) }.unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let projected: Vec<_> = batches
+            .iter()
+            .map(|b| b.project(&[3, 0]).unwrap())
+            .collect();
+        assert_eq!(read, projected);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_mmap_file_reader_unaligned() {
+        // Decimal128 buffers are copied when only aligned to 8 bytes
+        let batches = batches();
+        let options = IpcWriteOptions::default().try_with_alignment(8).unwrap();
+        let file = write_file(&batches, options);
+
+        let reader = unsafe { MmapFileReader::try_new(&file, None) }.unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[test]
+    fn test_mmap_file_reader_from_buffer() {
+        let batches = batches();
+        let mut bytes = vec![];
+        let mut writer = FileWriter::try_new(&mut bytes, &batches[0].schema()).unwrap();
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        writer.finish().unwrap();
+        drop(writer);
+
+        let buffer = Buffer::from_vec(bytes.clone());
+        let reader = MmapFileReader::try_new_from_buffer(buffer.clone(), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+        assert!(is_zero_copy(read[0].column(0).as_ref(), &buffer));
+
+        let err =
+            MmapFileReader::try_new_from_buffer(Buffer::from_vec(vec![0_u8; 4]), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Arrow file of 4 bytes is too small to contain a footer"
+        );
+
+        let options = IpcReadOptions::new();
+        let block = Block::new(8, 16, 8);
+        assert_eq!(
+            block_buffer(&buffer, &block, &options).unwrap().as_ptr(),
+            unsafe { buffer.as_ptr().add(8) }
+        );
+        let block = Block::new(bytes.len() as i64 - 8, 8, 8);
+        let err = block_buffer(&buffer, &block, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Parser error: Block of 16 bytes at offset {} exceeds the Arrow file of {} bytes",
+                bytes.len() - 8,
+                bytes.len()
+            )
+        );
+    }
+
+    #[test]
+    fn test_mmap_file_reader_read_options() {
+        let batches = batches();
+        let mut bytes = vec![];
+        let mut writer = FileWriter::try_new(&mut bytes, &batches[0].schema()).unwrap();
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        writer.finish().unwrap();
+        drop(writer);
+        let buffer = Buffer::from_vec(bytes);
+
+        let reader = FileReaderBuilder::new()
+            .with_projection(vec![1])
+            .with_read_options(IpcReadOptions::new().with_max_fields(4))
+            .build_from_buffer(buffer.clone())
+            .unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read[2], batches[2].project(&[1]).unwrap());
+
+        let err = FileReaderBuilder::new()
+            .with_read_options(IpcReadOptions::new().with_max_fields(3))
+            .build_from_buffer(buffer.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "External error: IPC number of fields of 4 exceeds the limit of 3"
+        );
+
+        // The footer exceeds the maximum message size
+        let err = FileReaderBuilder::new()
+            .with_read_options(IpcReadOptions::new().with_max_message_size(64))
+            .build_from_buffer(buffer.clone())
+            .unwrap_err();
+        assert!(err.to_string().contains("IPC message size of"), "{err}");
+
+        // Each block is checked against the maximum message size as it is read
+        let footer_len = read_footer_length(buffer[buffer.len() - 10..].try_into().unwrap());
+        let options = IpcReadOptions::new().with_max_message_size(footer_len.unwrap());
+        let mut reader = FileReaderBuilder::new()
+            .with_read_options(options)
+            .build_from_buffer(buffer)
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("IPC message size of"), "{err}");
+    }
+}
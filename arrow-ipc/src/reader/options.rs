@@ -0,0 +1,367 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+/// Options for reading IPC data, limiting the resources used to read untrusted input
+///
+/// IPC data declares the sizes of its messages and buffers ahead of their contents,
+/// so by default readers may allocate memory that is only found to be invalid after
+/// it has been read. Setting limits causes readers to instead return a
+/// [`ReadLimitExceeded`] error, wrapped in [`ArrowError::ExternalError`], before
+/// reading or allocating anything beyond them.
+///
+/// All limits default to unlimited.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::{IpcReadOptions, ReadLimit, ReadLimitExceeded, StreamReader};
+/// # use arrow_ipc::writer::StreamWriter;
+/// # use arrow_schema::ArrowError;
+/// let array: ArrayRef = Arc::new(Int32Array::from(vec![1; 1024]));
+/// let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+/// let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// let bytes = writer.into_inner().unwrap();
+///
+/// let options = IpcReadOptions::new().with_max_message_size(1024);
+/// let mut reader = StreamReader::try_new_with_options(bytes.as_slice(), None, options).unwrap();
+/// let e = match reader.next().unwrap().unwrap_err() {
+///     ArrowError::ExternalError(e) => e,
+///     _ => unreachable!(),
+/// };
+/// let e = e.downcast_ref::<ReadLimitExceeded>().unwrap();
+/// assert_eq!(e.limit(), ReadLimit::MessageSize);
+/// assert_eq!(e.max(), 1024);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpcReadOptions {
+    max_message_size: usize,
+    max_buffers: usize,
+    max_fields: usize,
+    max_decompressed_size: usize,
+    max_nesting_depth: usize,
+    skip_validation: bool,
+}
+
+impl Default for IpcReadOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IpcReadOptions {
+    /// Create options with no limits, validating the data read
+    pub const fn new() -> Self {
+        Self {
+            max_message_size: usize::MAX,
+            max_buffers: usize::MAX,
+            max_fields: usize::MAX,
+            max_decompressed_size: usize::MAX,
+            max_nesting_depth: usize::MAX,
+            skip_validation: false,
+        }
+    }
+
+    /// Limit the size in bytes of each message, comprising its metadata and body
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Limit the number of buffers of each record batch or dictionary batch
+    pub fn with_max_buffers(mut self, max_buffers: usize) -> Self {
+        self.max_buffers = max_buffers;
+        self
+    }
+
+    /// Limit the number of fields of the schema, including nested fields, and
+    /// therefore the number of field nodes of each record batch or dictionary batch
+    pub fn with_max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = max_fields;
+        self
+    }
+
+    /// Limit the size in bytes of each buffer once decompressed
+    pub fn with_max_decompressed_size(mut self, max_decompressed_size: usize) -> Self {
+        self.max_decompressed_size = max_decompressed_size;
+        self
+    }
+
+    /// Limit the depth of nested fields in the schema, where top-level fields have a
+    /// depth of 1
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// Specifies whether to skip validating the arrays read, defaulting to `false`
+    ///
+    /// Validation checks that the contents of the buffers, such as offsets and UTF-8
+    /// data, are consistent with the data type. Skipping it reduces the cost of reading
+    /// data known to be valid, such as data written by this process.
+    ///
+    /// # Safety
+    ///
+    /// Reading invalid data without validation results in undefined behaviour, the
+    /// data must therefore be trusted to have been written by a conforming writer
+    pub unsafe fn with_skip_validation(mut self, skip_validation: bool) -> Self {
+        self.skip_validation = skip_validation;
+        self
+    }
+
+    /// Returns the maximum size in bytes of each message
+    pub fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Returns the maximum number of buffers of each batch
+    pub fn max_buffers(&self) -> usize {
+        self.max_buffers
+    }
+
+    /// Returns the maximum number of fields of the schema
+    pub fn max_fields(&self) -> usize {
+        self.max_fields
+    }
+
+    /// Returns the maximum size in bytes of each decompressed buffer
+    pub fn max_decompressed_size(&self) -> usize {
+        self.max_decompressed_size
+    }
+
+    /// Returns the maximum depth of nested fields in the schema
+    pub fn max_nesting_depth(&self) -> usize {
+        self.max_nesting_depth
+    }
+
+    /// Returns whether the arrays read are not validated
+    pub fn skip_validation(&self) -> bool {
+        self.skip_validation
+    }
+
+    /// Returns an error if `value` exceeds the maximum of `limit`
+    pub(crate) fn check(&self, limit: ReadLimit, value: usize) -> Result<(), ArrowError> {
+        let max = match limit {
+            ReadLimit::MessageSize => self.max_message_size,
+            ReadLimit::Buffers => self.max_buffers,
+            ReadLimit::Fields => self.max_fields,
+            ReadLimit::DecompressedSize => self.max_decompressed_size,
+            ReadLimit::NestingDepth => self.max_nesting_depth,
+        };
+        match value > max {
+            true => Err(ReadLimitExceeded { limit, value, max }.into()),
+            false => Ok(()),
+        }
+    }
+
+    /// Returns an error if the fields of `schema` exceed the maximum number or depth
+    pub(crate) fn check_schema(&self, schema: &Schema) -> Result<(), ArrowError> {
+        let mut count = 0;
+        for field in schema.fields() {
+            self.check_field(field, 1, &mut count)?;
+        }
+        Ok(())
+    }
+
+    fn check_field(
+        &self,
+        field: &Field,
+        depth: usize,
+        count: &mut usize,
+    ) -> Result<(), ArrowError> {
+        *count += 1;
+        self.check(ReadLimit::Fields, *count)?;
+        self.check(ReadLimit::NestingDepth, depth)?;
+
+        let mut data_type = field.data_type();
+        while let DataType::Dictionary(_, values) = data_type {
+            data_type = values.as_ref();
+        }
+        match data_type {
+            DataType::List(child)
+            | DataType::LargeList(child)
+            | DataType::ListView(child)
+            | DataType::LargeListView(child)
+            | DataType::FixedSizeList(child, _)
+            | DataType::Map(child, _) => self.check_field(child, depth + 1, count),
+            DataType::Struct(children) => children
+                .iter()
+                .try_for_each(|child| self.check_field(child, depth + 1, count)),
+            DataType::Union(children, _) => children
+                .iter()
+                .try_for_each(|(_, child)| self.check_field(child, depth + 1, count)),
+            DataType::RunEndEncoded(run_ends, values) => {
+                self.check_field(run_ends, depth + 1, count)?;
+                self.check_field(values, depth + 1, count)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A limit of [`IpcReadOptions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadLimit {
+    /// See [`IpcReadOptions::with_max_message_size`]
+    MessageSize,
+    /// See [`IpcReadOptions::with_max_buffers`]
+    Buffers,
+    /// See [`IpcReadOptions::with_max_fields`]
+    Fields,
+    /// See [`IpcReadOptions::with_max_decompressed_size`]
+    DecompressedSize,
+    /// See [`IpcReadOptions::with_max_nesting_depth`]
+    NestingDepth,
+}
+
+impl Display for ReadLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MessageSize => write!(f, "message size"),
+            Self::Buffers => write!(f, "number of buffers"),
+            Self::Fields => write!(f, "number of fields"),
+            Self::DecompressedSize => write!(f, "decompressed buffer size"),
+            Self::NestingDepth => write!(f, "field nesting depth"),
+        }
+    }
+}
+
+/// The error returned when reading IPC data that exceeds a limit of [`IpcReadOptions`]
+///
+/// This is returned wrapped in [`ArrowError::ExternalError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadLimitExceeded {
+    limit: ReadLimit,
+    value: usize,
+    max: usize,
+}
+
+impl ReadLimitExceeded {
+    /// Returns the limit that was exceeded
+    pub fn limit(&self) -> ReadLimit {
+        self.limit
+    }
+
+    /// Returns the value declared by the data, which exceeds [`Self::max`]
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// Returns the configured maximum of the limit
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl Display for ReadLimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IPC {} of {} exceeds the limit of {}",
+            self.limit, self.value, self.max
+        )
+    }
+}
+
+impl Error for ReadLimitExceeded {}
+
+impl From<ReadLimitExceeded> for ArrowError {
+    fn from(e: ReadLimitExceeded) -> Self {
+        ArrowError::ExternalError(Box::new(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::UnionFields;
+    use std::sync::Arc;
+
+    fn limit_exceeded(e: ArrowError) -> ReadLimitExceeded {
+        match e {
+            ArrowError::ExternalError(e) => e.downcast_ref::<ReadLimitExceeded>().unwrap().clone(),
+            e => panic!("unexpected error {e}"),
+        }
+    }
+
+    #[test]
+    fn test_check_schema() {
+        let list = DataType::new_list(DataType::Int32, true);
+        let union = DataType::Union(
+            UnionFields::new(
+                vec![0, 1],
+                vec![
+                    Field::new("a", list, true),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+            ),
+            arrow_schema::UnionMode::Dense,
+        );
+        let dictionary = DataType::Dictionary(
+            Box::new(DataType::Int8),
+            Box::new(DataType::new_list(DataType::Int32, true)),
+        );
+        let schema = Schema::new(vec![
+            Field::new("union", union, true),
+            Field::new("dictionary", dictionary, true),
+            Field::new("int", DataType::Int64, true),
+        ]);
+
+        // union (1), a (2), item (3), b (2), dictionary (1), item (2), int (1)
+        let options = IpcReadOptions::new()
+            .with_max_fields(7)
+            .with_max_nesting_depth(3);
+        options.check_schema(&schema).unwrap();
+
+        let e = IpcReadOptions::new()
+            .with_max_fields(6)
+            .check_schema(&schema)
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "External error: IPC number of fields of 7 exceeds the limit of 6"
+        );
+
+        let e = IpcReadOptions::new()
+            .with_max_nesting_depth(2)
+            .check_schema(&schema)
+            .unwrap_err();
+        let e = limit_exceeded(e);
+        assert_eq!(e.limit(), ReadLimit::NestingDepth);
+        assert_eq!((e.value(), e.max()), (3, 2));
+
+        let struct_field = Arc::new(Field::new_struct(
+            "s",
+            vec![Field::new("c", DataType::Int8, true)],
+            true,
+        ));
+        let schema = Schema::new(vec![struct_field]);
+        IpcReadOptions::new()
+            .with_max_nesting_depth(2)
+            .check_schema(&schema)
+            .unwrap();
+        IpcReadOptions::new()
+            .with_max_nesting_depth(1)
+            .check_schema(&schema)
+            .unwrap_err();
+    }
+}
@@ -20,12 +20,14 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use arrow_array::{ArrayRef, RecordBatch};
-use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_buffer::Buffer;
 use arrow_schema::{ArrowError, SchemaRef};
 
-use crate::convert::MessageBuffer;
-use crate::reader::{read_dictionary_impl, read_record_batch_impl};
-use crate::{MessageHeader, CONTINUATION_MARKER};
+use crate::reader::{
+    read_dictionary_impl, read_record_batch_impl, EncapsulatedMessage, IpcReadOptions,
+    MessageDecoder,
+};
+use crate::MessageHeader;
 
 /// A low-level interface for reading [`RecordBatch`] data from a stream of bytes
 ///
@@ -36,47 +38,14 @@ pub struct StreamDecoder {
     schema: Option<SchemaRef>,
     /// Lookup table for dictionaries by ID
     dictionaries: HashMap<i64, ArrayRef>,
-    /// The decoder state
-    state: DecoderState,
-    /// A scratch buffer when a read is split across multiple `Buffer`
-    buf: MutableBuffer,
+    /// The decoder of the messages of the stream
+    decoder: MessageDecoder,
     /// Whether or not array data in input buffers are required to be aligned
     require_alignment: bool,
-}
-
-#[derive(Debug)]
-enum DecoderState {
-    /// Decoding the message header
-    Header {
-        /// Temporary buffer
-        buf: [u8; 4],
-        /// Number of bytes read into buf
-        read: u8,
-        /// If we have read a continuation token
-        continuation: bool,
-    },
-    /// Decoding the message flatbuffer
-    Message {
-        /// The size of the message flatbuffer
-        size: u32,
-    },
-    /// Decoding the message body
-    Body {
-        /// The message flatbuffer
-        message: MessageBuffer,
-    },
-    /// Reached the end of the stream
-    Finished,
-}
-
-impl Default for DecoderState {
-    fn default() -> Self {
-        Self::Header {
-            buf: [0; 4],
-            read: 0,
-            continuation: false,
-        }
-    }
+    /// Whether the stream was written with a different endianness, and must be byte-swapped
+    swap_endianness: bool,
+    /// Options limiting the data read
+    read_options: IpcReadOptions,
 }
 
 impl StreamDecoder {
@@ -102,6 +71,18 @@ impl StreamDecoder {
         self
     }
 
+    /// Specifies the [`IpcReadOptions`] limiting the data read
+    pub fn with_read_options(mut self, read_options: IpcReadOptions) -> Self {
+        self.decoder = self.decoder.with_read_options(read_options.clone());
+        self.read_options = read_options;
+        self
+    }
+
+    /// Returns the schema of the stream, if the schema message has been decoded
+    pub fn schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
     /// Try to read the next [`RecordBatch`] from the provided [`Buffer`]
     ///
     /// [`Buffer::advance`] will be called on `buffer` for any consumed bytes.
@@ -129,146 +110,91 @@ impl StreamDecoder {
     /// }
     /// ```
     pub fn decode(&mut self, buffer: &mut Buffer) -> Result<Option<RecordBatch>, ArrowError> {
-        while !buffer.is_empty() {
-            match &mut self.state {
-                DecoderState::Header {
-                    buf,
-                    read,
-                    continuation,
-                } => {
-                    let offset_buf = &mut buf[*read as usize..];
-                    let to_read = buffer.len().min(offset_buf.len());
-                    offset_buf[..to_read].copy_from_slice(&buffer[..to_read]);
-                    *read += to_read as u8;
-                    buffer.advance(to_read);
-                    if *read == 4 {
-                        if !*continuation && buf == &CONTINUATION_MARKER {
-                            *continuation = true;
-                            *read = 0;
-                            continue;
-                        }
-                        let size = u32::from_le_bytes(*buf);
-
-                        if size == 0 {
-                            self.state = DecoderState::Finished;
-                            continue;
-                        }
-                        self.state = DecoderState::Message { size };
-                    }
-                }
-                DecoderState::Message { size } => {
-                    let len = *size as usize;
-                    if self.buf.is_empty() && buffer.len() > len {
-                        let message = MessageBuffer::try_new(buffer.slice_with_length(0, len))?;
-                        self.state = DecoderState::Body { message };
-                        buffer.advance(len);
-                        continue;
-                    }
+        while let Some(message) = self.decoder.decode(buffer)? {
+            if let Some(batch) = self.decode_message(message)? {
+                return Ok(Some(batch));
+            }
+        }
+        Ok(None)
+    }
 
-                    let to_read = buffer.len().min(len - self.buf.len());
-                    self.buf.extend_from_slice(&buffer[..to_read]);
-                    buffer.advance(to_read);
-                    if self.buf.len() == len {
-                        let message = MessageBuffer::try_new(std::mem::take(&mut self.buf).into())?;
-                        self.state = DecoderState::Body { message };
-                    }
+    /// Decode an [`EncapsulatedMessage`] of the stream, returning the [`RecordBatch`]
+    /// if it contains one
+    ///
+    /// This allows messages framed by a custom transport, or read with a
+    /// [`MessageDecoder`], to be decoded. Messages must be provided in the order of the
+    /// stream, starting with the schema.
+    pub fn decode_message(
+        &mut self,
+        message: EncapsulatedMessage,
+    ) -> Result<Option<RecordBatch>, ArrowError> {
+        let body = message.body();
+        let message = message.message();
+        let version = message.version();
+        match message.header_type() {
+            MessageHeader::Schema => {
+                if self.schema.is_some() {
+                    return Err(ArrowError::IpcError(
+                        "Not expecting a schema when messages are read".to_string(),
+                    ));
                 }
-                DecoderState::Body { message } => {
-                    let message = message.as_ref();
-                    let body_length = message.bodyLength() as usize;
-
-                    let body = if self.buf.is_empty() && buffer.len() >= body_length {
-                        let body = buffer.slice_with_length(0, body_length);
-                        buffer.advance(body_length);
-                        body
-                    } else {
-                        let to_read = buffer.len().min(body_length - self.buf.len());
-                        self.buf.extend_from_slice(&buffer[..to_read]);
-                        buffer.advance(to_read);
-
-                        if self.buf.len() != body_length {
-                            continue;
-                        }
-                        std::mem::take(&mut self.buf).into()
-                    };
-
-                    let version = message.version();
-                    match message.header_type() {
-                        MessageHeader::Schema => {
-                            if self.schema.is_some() {
-                                return Err(ArrowError::IpcError(
-                                    "Not expecting a schema when messages are read".to_string(),
-                                ));
-                            }
 
-                            let ipc_schema = message.header_as_schema().unwrap();
-                            let schema = crate::convert::fb_to_schema(ipc_schema);
-                            self.state = DecoderState::default();
-                            self.schema = Some(Arc::new(schema));
-                        }
-                        MessageHeader::RecordBatch => {
-                            let batch = message.header_as_record_batch().unwrap();
-                            let schema = self.schema.clone().ok_or_else(|| {
-                                ArrowError::IpcError("Missing schema".to_string())
-                            })?;
-                            let batch = read_record_batch_impl(
-                                &body,
-                                batch,
-                                schema,
-                                &self.dictionaries,
-                                None,
-                                &version,
-                                self.require_alignment,
-                            )?;
-                            self.state = DecoderState::default();
-                            return Ok(Some(batch));
-                        }
-                        MessageHeader::DictionaryBatch => {
-                            let dictionary = message.header_as_dictionary_batch().unwrap();
-                            let schema = self.schema.as_deref().ok_or_else(|| {
-                                ArrowError::IpcError("Missing schema".to_string())
-                            })?;
-                            read_dictionary_impl(
-                                &body,
-                                dictionary,
-                                schema,
-                                &mut self.dictionaries,
-                                &version,
-                                self.require_alignment,
-                            )?;
-                            self.state = DecoderState::default();
-                        }
-                        MessageHeader::NONE => {
-                            self.state = DecoderState::default();
-                        }
-                        t => {
-                            return Err(ArrowError::IpcError(format!(
-                                "Message type unsupported by StreamDecoder: {t:?}"
-                            )))
-                        }
-                    }
-                }
-                DecoderState::Finished => {
-                    return Err(ArrowError::IpcError("Unexpected EOS".to_string()))
-                }
+                let ipc_schema = message.header_as_schema().unwrap();
+                self.swap_endianness = !ipc_schema.endianness().equals_to_target_endianness();
+                let schema = crate::convert::fb_to_schema(ipc_schema);
+                self.read_options.check_schema(&schema)?;
+                self.schema = Some(Arc::new(schema));
+                Ok(None)
+            }
+            MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().unwrap();
+                let schema = self
+                    .schema
+                    .clone()
+                    .ok_or_else(|| ArrowError::IpcError("Missing schema".to_string()))?;
+                let batch = read_record_batch_impl(
+                    body,
+                    batch,
+                    schema,
+                    &self.dictionaries,
+                    None,
+                    &version,
+                    self.require_alignment,
+                    self.swap_endianness,
+                    &self.read_options,
+                )?;
+                Ok(Some(batch))
             }
+            MessageHeader::DictionaryBatch => {
+                let dictionary = message.header_as_dictionary_batch().unwrap();
+                let schema = self
+                    .schema
+                    .as_deref()
+                    .ok_or_else(|| ArrowError::IpcError("Missing schema".to_string()))?;
+                read_dictionary_impl(
+                    body,
+                    dictionary,
+                    schema,
+                    &mut self.dictionaries,
+                    &version,
+                    self.require_alignment,
+                    self.swap_endianness,
+                    &self.read_options,
+                )?;
+                Ok(None)
+            }
+            MessageHeader::NONE => Ok(None),
+            t => Err(ArrowError::IpcError(format!(
+                "Message type unsupported by StreamDecoder: {t:?}"
+            ))),
         }
-        Ok(None)
     }
 
     /// Signal the end of stream
     ///
     /// Returns an error if any partial data remains in the stream
     pub fn finish(&mut self) -> Result<(), ArrowError> {
-        match self.state {
-            DecoderState::Finished
-            | DecoderState::Header {
-                read: 0,
-                continuation: false,
-                ..
-            } => Ok(()),
-            _ => Err(ArrowError::IpcError("Unexpected End of Stream".to_string())),
-        }
+        self.decoder.finish()
     }
 }
 
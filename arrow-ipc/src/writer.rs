@@ -22,7 +22,7 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
-use std::io::{BufWriter, Write};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
 use std::mem::size_of;
 use std::sync::Arc;
 
@@ -41,6 +41,12 @@ use crate::compression::CompressionCodec;
 use crate::convert::IpcSchemaEncoder;
 use crate::CONTINUATION_MARKER;
 
+#[cfg(feature = "async")]
+mod async_stream;
+
+#[cfg(feature = "async")]
+pub use async_stream::*;
+
 /// IPC write options used to control the behaviour of the [`IpcDataGenerator`]
 #[derive(Debug, Clone)]
 pub struct IpcWriteOptions {
@@ -66,6 +72,12 @@ pub struct IpcWriteOptions {
     ///
     /// Defaults to `false`
     preserve_dict_id: bool,
+    /// How dictionaries that change between batches are written
+    ///
+    /// Defaults to [`DictionaryHandling::Resend`]
+    dictionary_handling: DictionaryHandling,
+    /// The parameters of the compression codec, if compression is desired
+    compression_options: CompressionOptions,
 }
 
 impl IpcWriteOptions {
@@ -94,14 +106,7 @@ impl IpcWriteOptions {
         write_legacy_ipc_format: bool,
         metadata_version: crate::MetadataVersion,
     ) -> Result<Self, ArrowError> {
-        let is_alignment_valid =
-            alignment == 8 || alignment == 16 || alignment == 32 || alignment == 64;
-        if !is_alignment_valid {
-            return Err(ArrowError::InvalidArgumentError(
-                "Alignment should be 8, 16, 32, or 64.".to_string(),
-            ));
-        }
-        let alignment: u8 = u8::try_from(alignment).expect("range already checked");
+        let alignment = validate_alignment(alignment)?;
         match metadata_version {
             crate::MetadataVersion::V1
             | crate::MetadataVersion::V2
@@ -114,6 +119,8 @@ impl IpcWriteOptions {
                 metadata_version,
                 batch_compression_type: None,
                 preserve_dict_id: false,
+                dictionary_handling: DictionaryHandling::Resend,
+                compression_options: CompressionOptions::new(),
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -127,6 +134,8 @@ impl IpcWriteOptions {
                         metadata_version,
                         batch_compression_type: None,
                         preserve_dict_id: false,
+                        dictionary_handling: DictionaryHandling::Resend,
+                        compression_options: CompressionOptions::new(),
                     })
                 }
             }
@@ -153,6 +162,192 @@ impl IpcWriteOptions {
         self.preserve_dict_id = preserve_dict_id;
         self
     }
+
+    /// Return how the writer writes dictionaries that change between batches
+    pub fn dictionary_handling(&self) -> DictionaryHandling {
+        self.dictionary_handling
+    }
+
+    /// Set how the writer writes dictionaries that change between batches
+    /// (defaults to [`DictionaryHandling::Resend`])
+    pub fn with_dictionary_handling(mut self, dictionary_handling: DictionaryHandling) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
+
+    /// Return the alignment in bytes of the messages and buffers written
+    pub fn alignment(&self) -> usize {
+        usize::from(self.alignment)
+    }
+
+    /// Set the alignment in bytes of the messages and buffers written, which must be
+    /// 8, 16, 32, or 64 (defaults to 64)
+    ///
+    /// [`FileWriter`] aligns messages relative to the start of the file, so with an
+    /// alignment of 64 every buffer of a file mapped into memory at a page boundary
+    /// is sufficiently aligned for its data type, allowing it to be read without copying.
+    pub fn try_with_alignment(mut self, alignment: usize) -> Result<Self, ArrowError> {
+        self.alignment = validate_alignment(alignment)?;
+        Ok(self)
+    }
+
+    /// Return the parameters of the compression codec
+    pub fn compression_options(&self) -> &CompressionOptions {
+        &self.compression_options
+    }
+
+    /// Set the parameters of the compression codec configured with
+    /// [`Self::try_with_compression`]
+    pub fn with_compression_options(mut self, compression_options: CompressionOptions) -> Self {
+        self.compression_options = compression_options;
+        self
+    }
+}
+
+/// Returns `alignment` as a `u8` if it is a valid alignment of [`IpcWriteOptions`]
+fn validate_alignment(alignment: usize) -> Result<u8, ArrowError> {
+    match alignment {
+        8 | 16 | 32 | 64 => Ok(alignment as u8),
+        _ => Err(ArrowError::InvalidArgumentError(
+            "Alignment should be 8, 16, 32, or 64.".to_string(),
+        )),
+    }
+}
+
+/// The parameters of the compression codecs used when writing IPC buffers, see
+/// [`IpcWriteOptions::with_compression_options`]
+///
+/// ```
+/// # use arrow_ipc::CompressionType;
+/// # use arrow_ipc::writer::{CompressionOptions, IpcWriteOptions, Lz4BlockSize};
+/// let compression = CompressionOptions::new()
+///     .with_zstd_level(9)
+///     .unwrap()
+///     .with_lz4_block_size(Lz4BlockSize::Max256KB)
+///     .with_min_compression_size(1024);
+/// let options = IpcWriteOptions::default()
+///     .try_with_compression(Some(CompressionType::ZSTD))
+///     .unwrap()
+///     .with_compression_options(compression);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionOptions {
+    zstd_level: i32,
+    lz4_block_size: Lz4BlockSize,
+    lz4_content_checksum: bool,
+    min_compression_size: usize,
+}
+
+impl Default for CompressionOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompressionOptions {
+    /// The minimum zstd compression level
+    pub const MIN_ZSTD_LEVEL: i32 = 1;
+    /// The maximum zstd compression level
+    pub const MAX_ZSTD_LEVEL: i32 = 22;
+
+    /// Create a new [`CompressionOptions`] with the default parameters of each codec
+    pub const fn new() -> Self {
+        Self {
+            zstd_level: 0,
+            lz4_block_size: Lz4BlockSize::Auto,
+            lz4_content_checksum: false,
+            min_compression_size: 0,
+        }
+    }
+
+    /// Return the zstd compression level, or `0` for the default zstd level
+    pub fn zstd_level(&self) -> i32 {
+        self.zstd_level
+    }
+
+    /// Set the zstd compression level, between [`Self::MIN_ZSTD_LEVEL`] and
+    /// [`Self::MAX_ZSTD_LEVEL`], where higher levels compress better but more slowly
+    pub fn with_zstd_level(mut self, level: i32) -> Result<Self, ArrowError> {
+        if !(Self::MIN_ZSTD_LEVEL..=Self::MAX_ZSTD_LEVEL).contains(&level) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "zstd compression level {level} must be between {} and {}",
+                Self::MIN_ZSTD_LEVEL,
+                Self::MAX_ZSTD_LEVEL
+            )));
+        }
+        self.zstd_level = level;
+        Ok(self)
+    }
+
+    /// Return the maximum size of the blocks of an lz4 frame
+    pub fn lz4_block_size(&self) -> Lz4BlockSize {
+        self.lz4_block_size
+    }
+
+    /// Set the maximum size of the blocks of an lz4 frame (defaults to [`Lz4BlockSize::Auto`])
+    pub fn with_lz4_block_size(mut self, block_size: Lz4BlockSize) -> Self {
+        self.lz4_block_size = block_size;
+        self
+    }
+
+    /// Return whether lz4 frames include a checksum of their content
+    pub fn lz4_content_checksum(&self) -> bool {
+        self.lz4_content_checksum
+    }
+
+    /// Set whether lz4 frames include a checksum of their content (defaults to `false`)
+    pub fn with_lz4_content_checksum(mut self, content_checksum: bool) -> Self {
+        self.lz4_content_checksum = content_checksum;
+        self
+    }
+
+    /// Return the size in bytes below which buffers are written uncompressed
+    pub fn min_compression_size(&self) -> usize {
+        self.min_compression_size
+    }
+
+    /// Set the size in bytes below which buffers are written uncompressed (defaults to `0`)
+    ///
+    /// Compressing small buffers rarely reduces their size, as the compressed data includes
+    /// the headers of the codec, but still incurs the cost of compression
+    pub fn with_min_compression_size(mut self, min_compression_size: usize) -> Self {
+        self.min_compression_size = min_compression_size;
+        self
+    }
+}
+
+/// The maximum size of the blocks of an lz4 frame, see [`CompressionOptions::with_lz4_block_size`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Lz4BlockSize {
+    /// Select the block size based on the size of the data to compress
+    #[default]
+    Auto,
+    /// 64KB blocks
+    Max64KB,
+    /// 256KB blocks
+    Max256KB,
+    /// 1MB blocks
+    Max1MB,
+    /// 4MB blocks
+    Max4MB,
+}
+
+/// Controls how a dictionary that differs from the one previously written for the
+/// same dictionary ID is written, see [`IpcWriteOptions::with_dictionary_handling`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DictionaryHandling {
+    /// Write the new dictionary in full, replacing the previous dictionary
+    ///
+    /// This is not supported by the [`FileWriter`], which returns an error
+    #[default]
+    Resend,
+    /// If the new dictionary starts with the values of the previous dictionary, write
+    /// only the values appended to it as a delta dictionary batch, otherwise write the
+    /// new dictionary in full as with [`DictionaryHandling::Resend`]
+    ///
+    /// This allows dictionaries built incrementally, such as by a dictionary builder
+    /// that is not reset between batches, to be written without resending values
+    Delta,
 }
 
 impl Default for IpcWriteOptions {
@@ -163,6 +358,8 @@ impl Default for IpcWriteOptions {
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
             preserve_dict_id: false,
+            dictionary_handling: DictionaryHandling::Resend,
+            compression_options: CompressionOptions::new(),
         }
     }
 }
@@ -331,6 +528,28 @@ impl IpcDataGenerator {
                     dict_id,
                 )?;
             }
+            DataType::ListView(field) => {
+                let list = column.as_list_view::<i32>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                    dict_id,
+                )?;
+            }
+            DataType::LargeListView(field) => {
+                let list = column.as_list_view::<i64>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                    dict_id,
+                )?;
+            }
             DataType::FixedSizeList(field, _) => {
                 let list = column
                     .as_any()
@@ -427,14 +646,30 @@ impl IpcDataGenerator {
                         ArrowError::IpcError(format!("no dict id for field {}", field.name()))
                     })?;
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
+                let update = dictionary_tracker.insert_column(
+                    dict_id,
+                    column,
+                    write_options.dictionary_handling,
+                )?;
 
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    )?);
+                match update {
+                    DictionaryUpdate::None => {}
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            write_options,
+                            false,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            write_options,
+                            true,
+                        )?);
+                    }
                 }
             }
             _ => self._encode_dictionaries(
@@ -576,6 +811,7 @@ impl IpcDataGenerator {
         dict_id: i64,
         array_data: &ArrayData,
         write_options: &IpcWriteOptions,
+        is_delta: bool,
     ) -> Result<EncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
@@ -644,6 +880,7 @@ impl IpcDataGenerator {
             let mut batch_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -757,15 +994,34 @@ fn into_zero_offset_run_array<R: RunEndIndexType>(
     Ok(array_data.into())
 }
 
+/// The change to a dictionary recorded by [`DictionaryTracker::insert_column`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary has already been written, and need not be written again
+    None,
+    /// No dictionary has been written for this ID, and the dictionary must be written in full
+    New,
+    /// The dictionary replaces a different dictionary, and must be written in full
+    Replaced,
+    /// The dictionary appends values to the previously written dictionary, and only
+    /// these appended values need be written as a delta dictionary batch
+    Delta(ArrayData),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times.
 ///
 /// Can optionally error if an update to an existing dictionary is attempted, which
-/// isn't allowed in the `FileWriter`.
+/// isn't allowed in the `FileWriter`. Appending values to an existing dictionary, written
+/// as a delta dictionary batch with [`DictionaryHandling::Delta`], is allowed.
 #[derive(Debug)]
 pub struct DictionaryTracker {
     written: HashMap<i64, ArrayData>,
     dict_ids: Vec<i64>,
+    /// The path of the field each ID in `dict_ids` was assigned to
+    dict_paths: Vec<Vec<String>>,
+    /// The names of the fields enclosing the field being encoded
+    path: Vec<String>,
     error_on_replacement: bool,
     preserve_dict_id: bool,
 }
@@ -777,14 +1033,15 @@ impl DictionaryTracker {
     /// is true, an error will be generated if an update to an
     /// existing dictionary is attempted.
     ///
-    /// If `preserve_dict_id` is true, the dictionary ID defined in the schema
-    /// is used, otherwise a unique dictionary ID will be assigned by incrementing
+    /// A unique dictionary ID will be assigned to each dictionary field by incrementing
     /// the last seen dictionary ID (or using `0` if no other dictionary IDs have been
-    /// seen)
+    /// seen), see [`Self::new_with_preserve_dict_id`] to use the IDs defined in the schema
     pub fn new(error_on_replacement: bool) -> Self {
         Self {
             written: HashMap::new(),
             dict_ids: Vec::new(),
+            dict_paths: Vec::new(),
+            path: Vec::new(),
             error_on_replacement,
             preserve_dict_id: false,
         }
@@ -795,10 +1052,15 @@ impl DictionaryTracker {
     /// If `error_on_replacement`
     /// is true, an error will be generated if an update to an
     /// existing dictionary is attempted.
+    ///
+    /// If `preserve_dict_id` is true, the dictionary ID defined in the schema is used,
+    /// unless it has already been assigned to another field, see [`Self::set_dict_id`]
     pub fn new_with_preserve_dict_id(error_on_replacement: bool, preserve_dict_id: bool) -> Self {
         Self {
             written: HashMap::new(),
             dict_ids: Vec::new(),
+            dict_paths: Vec::new(),
+            path: Vec::new(),
             error_on_replacement,
             preserve_dict_id,
         }
@@ -806,26 +1068,47 @@ impl DictionaryTracker {
 
     /// Set the dictionary ID for `field`.
     ///
-    /// If `preserve_dict_id` is true, this will return the `dict_id` in `field` (or panic if `field` does
-    /// not have a `dict_id` defined).
+    /// If `preserve_dict_id` is true, this will return the `dict_id` in `field`, provided it
+    /// has not already been assigned to another field.
     ///
-    /// If `preserve_dict_id` is false, this will return the value of the last `dict_id` assigned incremented by 1
-    /// or 0 in the case where no dictionary IDs have yet been assigned
+    /// Otherwise, this will return the largest `dict_id` assigned incremented by 1 or 0 in the
+    /// case where no dictionary IDs have yet been assigned. Fields of different sources that
+    /// share a `dict_id`, such as the default of 0, are thereby remapped to unique IDs, so
+    /// their dictionaries do not replace one another.
     pub fn set_dict_id(&mut self, field: &Field) -> i64 {
-        let next = if self.preserve_dict_id {
-            field.dict_id().expect("no dict_id in field")
-        } else {
-            self.dict_ids
-                .last()
-                .copied()
-                .map(|i| i + 1)
-                .unwrap_or_default()
-        };
+        let next = field
+            .dict_id()
+            .filter(|id| self.preserve_dict_id && !self.dict_ids.contains(id))
+            .unwrap_or_else(|| {
+                self.dict_ids
+                    .iter()
+                    .max()
+                    .map(|i| i + 1)
+                    .unwrap_or_default()
+            });
 
         self.dict_ids.push(next);
+        self.dict_paths.push(self.path.clone());
         next
     }
 
+    /// Return the dictionary ID assigned to the dictionary field at `path`, the names of
+    /// the fields from the root of the schema, if any
+    pub fn dict_id_for_path(&self, path: &[&str]) -> Option<i64> {
+        let idx = self.dict_paths.iter().position(|p| p.iter().eq(path))?;
+        Some(self.dict_ids[idx])
+    }
+
+    /// Enter the field with `name` when traversing the schema
+    pub(crate) fn push_field(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    /// Leave the field entered by the last call to [`Self::push_field`]
+    pub(crate) fn pop_field(&mut self) {
+        self.path.pop();
+    }
+
     /// Return the sequence of dictionary IDs in the order they should be observed while
     /// traversing the schema
     pub fn dict_id(&mut self) -> &[i64] {
@@ -842,32 +1125,73 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, column: &ArrayRef) -> Result<bool, ArrowError> {
+        let update = self.insert_column(dict_id, column, DictionaryHandling::Resend)?;
+        Ok(update != DictionaryUpdate::None)
+    }
+
+    /// Keep track of the dictionary with the given ID and values, returning the
+    /// [`DictionaryUpdate`] that must be written. Behavior:
+    ///
+    /// * If this ID has been written already and has the same data, return
+    ///   [`DictionaryUpdate::None`].
+    /// * If `dict_handling` is [`DictionaryHandling::Delta`] and the values of the dictionary
+    ///   written for this ID are a prefix of the new values, return [`DictionaryUpdate::Delta`]
+    ///   with the appended values.
+    /// * If this ID has been written already but with different data, and this tracker is
+    ///   configured to return an error, return an error.
+    /// * Otherwise return [`DictionaryUpdate::Replaced`] or, if this dictionary has never
+    ///   been seen before, [`DictionaryUpdate::New`].
+    pub fn insert_column(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        dict_handling: DictionaryHandling,
+    ) -> Result<DictionaryUpdate, ArrowError> {
         let dict_data = column.to_data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
-            if ArrayData::ptr_eq(&last.child_data()[0], dict_values) {
-                // Same dictionary values => no need to emit it again
-                return Ok(false);
+        let last = match self.written.get(&dict_id) {
+            Some(last) => &last.child_data()[0],
+            None => {
+                self.written.insert(dict_id, dict_data);
+                return Ok(DictionaryUpdate::New);
             }
-            if self.error_on_replacement {
-                // If error on replacement perform a logical comparison
-                if last.child_data()[0] == *dict_values {
-                    // Same dictionary values => no need to emit it again
-                    return Ok(false);
-                }
-                return Err(ArrowError::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+        };
+
+        if ArrayData::ptr_eq(last, dict_values) {
+            // Same dictionary values => no need to emit it again
+            return Ok(DictionaryUpdate::None);
+        }
+
+        if dict_handling == DictionaryHandling::Delta
+            && last.len() <= dict_values.len()
+            && dict_values.slice(0, last.len()) == *last
+        {
+            let delta = dict_values.slice(last.len(), dict_values.len() - last.len());
+            self.written.insert(dict_id, dict_data);
+            return match delta.is_empty() {
+                true => Ok(DictionaryUpdate::None),
+                false => Ok(DictionaryUpdate::Delta(delta)),
+            };
+        }
+
+        if self.error_on_replacement {
+            // If error on replacement perform a logical comparison
+            if *last == *dict_values {
+                // Same dictionary values => no need to emit it again
+                return Ok(DictionaryUpdate::None);
             }
+            return Err(ArrowError::InvalidArgumentError(
+                "Dictionary replacement detected when writing IPC file format. \
+                 Arrow IPC files only support a single dictionary for a given field \
+                 across all batches."
+                    .to_string(),
+            ));
         }
 
         self.written.insert(dict_id, dict_data);
-        Ok(true)
+        Ok(DictionaryUpdate::Replaced)
     }
 }
 
@@ -1087,6 +1411,150 @@ impl<W: Write> FileWriter<W> {
     }
 }
 
+impl<W: Read + Write + Seek> FileWriter<W> {
+    /// Try to create a writer appending record batches to the existing IPC file in `writer`
+    ///
+    /// See [`FileWriter::try_new_append_with_options`] for details.
+    pub fn try_new_append(writer: W) -> Result<Self, ArrowError> {
+        Self::try_new_append_with_options(writer, IpcWriteOptions::default())
+    }
+
+    /// Try to create a writer appending record batches to the existing IPC file in `writer`,
+    /// with [`IpcWriteOptions`]
+    ///
+    /// The footer of the file is read, and then overwritten by the appended dictionaries and
+    /// record batches, followed by an updated footer listing both the existing and appended
+    /// batches when the writer is finished. The schema, dictionary IDs and custom metadata of
+    /// the file are preserved, and record batches written must have the schema of the file.
+    ///
+    /// As in [`FileWriter::write`], the dictionaries of appended batches must match those
+    /// already in the file, unless [`DictionaryHandling::Delta`] is used and they only append
+    /// values to them.
+    ///
+    /// Note the file is not truncated, and appending no record batches to a file written by
+    /// another implementation may write a shorter footer than the original, in which case the
+    /// file should be truncated to the position of `writer` after [`FileWriter::finish`].
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) is returned if `writer` does not contain a valid IPC file, or
+    /// its metadata version or endianness differ from those written.
+    pub fn try_new_append_with_options(
+        mut writer: W,
+        mut write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let mut trailer = [0; 10];
+        let trailer_start = writer
+            .seek(SeekFrom::End(0))?
+            .checked_sub(10)
+            .ok_or_else(|| {
+                ArrowError::ParseError("Arrow file is too small to contain a footer".to_string())
+            })?;
+        writer.seek(SeekFrom::Start(trailer_start))?;
+        writer.read_exact(&mut trailer)?;
+
+        let footer_len = crate::reader::read_footer_length(trailer)?;
+        let footer_start = trailer_start
+            .checked_sub(footer_len as u64)
+            .ok_or_else(|| {
+                ArrowError::ParseError(format!("Invalid footer length: {footer_len}"))
+            })?;
+        let mut footer_data = vec![0; footer_len];
+        writer.seek(SeekFrom::Start(footer_start))?;
+        writer.read_exact(&mut footer_data)?;
+        let footer = crate::root_as_footer(&footer_data[..]).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+        })?;
+
+        if footer.version() != write_options.metadata_version {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot append metadata version {:?} to an Arrow file of version {:?}",
+                write_options.metadata_version,
+                footer.version()
+            )));
+        }
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::ParseError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        if !ipc_schema.endianness().equals_to_target_endianness() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot append to an Arrow file of non-native endianness".to_string(),
+            ));
+        }
+        let schema = Arc::new(crate::convert::fb_to_schema(ipc_schema));
+
+        let mut custom_metadata = HashMap::new();
+        if let Some(fb_custom_metadata) = footer.custom_metadata() {
+            for kv in fb_custom_metadata.into_iter() {
+                custom_metadata.insert(
+                    kv.key().unwrap().to_string(),
+                    kv.value().unwrap().to_string(),
+                );
+            }
+        }
+
+        // Read the dictionaries of the file, against which those appended are compared
+        let dictionary_blocks: Vec<_> = footer.dictionaries().iter().flatten().copied().collect();
+        let record_blocks: Vec<_> = footer.recordBatches().iter().flatten().copied().collect();
+        let mut decoder = crate::reader::FileDecoder::new(schema.clone(), footer.version());
+        for block in &dictionary_blocks {
+            let buf = crate::reader::read_block(&mut writer, block, &Default::default())?;
+            decoder.read_dictionary(block, &buf)?;
+        }
+
+        // The dictionary IDs of the file are preserved by assigning them from its schema
+        write_options.preserve_dict_id = true;
+        let mut dictionary_tracker = DictionaryTracker::new_with_preserve_dict_id(true, true);
+        IpcSchemaEncoder::new()
+            .with_dictionary_tracker(&mut dictionary_tracker)
+            .schema_to_fb_offset(&mut FlatBufferBuilder::new(), &schema);
+        for (dict_id, values) in decoder.dictionaries() {
+            let keys = PrimitiveArray::<Int32Type>::from(Vec::<i32>::new());
+            let dict = DictionaryArray::new(keys, values.clone());
+            dictionary_tracker
+                .written
+                .insert(*dict_id, dict.into_data());
+        }
+
+        // Overwrite the end of stream marker if present, otherwise the footer
+        let eos_len = match write_options.write_legacy_ipc_format {
+            true => 4,
+            false => 8,
+        };
+        let mut eos = [0xFF; 8];
+        let mut block_offsets = footer_start;
+        if let Some(eos_start) = footer_start.checked_sub(eos_len as u64) {
+            writer.seek(SeekFrom::Start(eos_start))?;
+            writer.read_exact(&mut eos[..eos_len])?;
+            let mut expected = vec![];
+            write_continuation(&mut expected, &write_options, 0)?;
+            if eos[..eos_len] == expected[..] {
+                block_offsets = eos_start;
+            }
+        }
+        writer.seek(SeekFrom::Start(block_offsets))?;
+        let mut block_offsets = block_offsets as usize;
+
+        // Pad the position to the alignment, which it already is if the marker was found
+        let pad_len = pad_to_alignment(write_options.alignment, block_offsets);
+        writer.write_all(&PADDING[..pad_len])?;
+        block_offsets += pad_len;
+
+        Ok(Self {
+            writer,
+            write_options,
+            schema,
+            block_offsets,
+            dictionary_blocks,
+            record_blocks,
+            finished: false,
+            dictionary_tracker,
+            custom_metadata,
+            data_gen: IpcDataGenerator::default(),
+        })
+    }
+}
+
 impl<W: Write> RecordBatchWriter for FileWriter<W> {
     fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
         self.write(batch)
@@ -1283,6 +1751,60 @@ pub struct EncodedData {
     /// Arrow buffers to be written, should be an empty vec for schema messages
     pub arrow_data: Vec<u8>,
 }
+
+/// A low-level interface for framing [`EncodedData`] as IPC messages, in the
+/// [encapsulated message format] of a continuation marker, the length of the flatbuffer
+/// metadata, the padded metadata and the message body
+///
+/// This allows custom transports to frame individual messages, generated by
+/// [`IpcDataGenerator`], without writing a complete stream with [`StreamWriter`]. The
+/// messages can be read with [`MessageDecoder`](crate::reader::MessageDecoder).
+///
+/// [encapsulated message format]: https://arrow.apache.org/docs/format/Columnar.html#encapsulated-message-format
+#[derive(Debug, Clone, Default)]
+pub struct MessageEncoder {
+    write_options: IpcWriteOptions,
+}
+
+impl MessageEncoder {
+    /// Create a new [`MessageEncoder`] framing messages with [`IpcWriteOptions`]
+    ///
+    /// The options determine the alignment of messages, and whether the continuation
+    /// marker of the legacy format is omitted, and should be those the messages were
+    /// generated with.
+    pub fn new(write_options: IpcWriteOptions) -> Self {
+        Self { write_options }
+    }
+
+    /// Return the [`IpcWriteOptions`] of this encoder
+    pub fn write_options(&self) -> &IpcWriteOptions {
+        &self.write_options
+    }
+
+    /// Write `encoded` framed as a message to `writer`, returning the number of bytes of the
+    /// framed metadata and of the body written
+    pub fn encode<W: Write>(
+        &self,
+        writer: W,
+        encoded: EncodedData,
+    ) -> Result<(usize, usize), ArrowError> {
+        write_message(writer, encoded, &self.write_options)
+    }
+
+    /// Return `encoded` framed as a message
+    pub fn encode_to_vec(&self, encoded: EncodedData) -> Result<Vec<u8>, ArrowError> {
+        let mut buf = Vec::with_capacity(encoded.ipc_message.len() + encoded.arrow_data.len() + 8);
+        self.encode(&mut buf, encoded)?;
+        Ok(buf)
+    }
+
+    /// Write the marker signalling the end of a stream of messages to `writer`, returning
+    /// the number of bytes written
+    pub fn encode_end_of_stream<W: Write>(&self, writer: W) -> Result<usize, ArrowError> {
+        write_continuation(writer, &self.write_options, 0)
+    }
+}
+
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(
     mut writer: W,
@@ -1477,6 +1999,18 @@ fn get_list_array_buffers<O: OffsetSizeTrait>(data: &ArrayData) -> (Buffer, Arra
     (offsets, child_data)
 }
 
+/// Returns the offsets and sizes buffers of a list view, truncated to the sliced range
+///
+/// Unlike a list, the views may reference any range of the child data, which is
+/// therefore written in full
+fn get_list_view_array_buffers<O: OffsetSizeTrait>(data: &ArrayData) -> (Buffer, Buffer) {
+    let byte_offset = data.offset() * O::get_byte_width();
+    let byte_len = data.len() * O::get_byte_width();
+    let offsets = data.buffers()[0].slice_with_length(byte_offset, byte_len);
+    let sizes = data.buffers()[1].slice_with_length(byte_offset, byte_len);
+    (offsets, sizes)
+}
+
 /// Write array data to a vector of bytes
 #[allow(clippy::too_many_arguments)]
 fn write_array_data(
@@ -1517,7 +2051,7 @@ fn write_array_data(
             arrow_data,
             offset,
             compression_codec,
-            write_options.alignment,
+            write_options,
         )?;
     }
 
@@ -1531,7 +2065,7 @@ fn write_array_data(
                 arrow_data,
                 offset,
                 compression_codec,
-                write_options.alignment,
+                write_options,
             )?;
         }
     } else if matches!(data_type, DataType::BinaryView | DataType::Utf8View) {
@@ -1548,7 +2082,7 @@ fn write_array_data(
                 arrow_data,
                 offset,
                 compression_codec,
-                write_options.alignment,
+                write_options,
             )?;
         }
     } else if matches!(data_type, DataType::LargeBinary | DataType::LargeUtf8) {
@@ -1560,7 +2094,7 @@ fn write_array_data(
                 arrow_data,
                 offset,
                 compression_codec,
-                write_options.alignment,
+                write_options,
             )?;
         }
     } else if DataType::is_numeric(data_type)
@@ -1592,7 +2126,7 @@ fn write_array_data(
             arrow_data,
             offset,
             compression_codec,
-            write_options.alignment,
+            write_options,
         )?;
     } else if matches!(data_type, DataType::Boolean) {
         // Bools are special because the payload (= 1 bit) is smaller than the physical container elements (= bytes).
@@ -1607,7 +2141,7 @@ fn write_array_data(
             arrow_data,
             offset,
             compression_codec,
-            write_options.alignment,
+            write_options,
         )?;
     } else if matches!(
         data_type,
@@ -1629,7 +2163,7 @@ fn write_array_data(
             arrow_data,
             offset,
             compression_codec,
-            write_options.alignment,
+            write_options,
         )?;
         offset = write_array_data(
             &sliced_child_data,
@@ -1643,6 +2177,28 @@ fn write_array_data(
             write_options,
         )?;
         return Ok(offset);
+    } else if matches!(
+        data_type,
+        DataType::ListView(_) | DataType::LargeListView(_)
+    ) {
+        assert_eq!(array_data.buffers().len(), 2);
+        assert_eq!(array_data.child_data().len(), 1);
+
+        let (offsets, sizes) = match data_type {
+            DataType::ListView(_) => get_list_view_array_buffers::<i32>(array_data),
+            DataType::LargeListView(_) => get_list_view_array_buffers::<i64>(array_data),
+            _ => unreachable!(),
+        };
+        for buffer in [offsets, sizes] {
+            offset = write_buffer(
+                buffer.as_slice(),
+                buffers,
+                arrow_data,
+                offset,
+                compression_codec,
+                write_options,
+            )?;
+        }
     } else {
         for buffer in array_data.buffers() {
             offset = write_buffer(
@@ -1651,7 +2207,7 @@ fn write_array_data(
                 arrow_data,
                 offset,
                 compression_codec,
-                write_options.alignment,
+                write_options,
             )?;
         }
     }
@@ -1716,10 +2272,12 @@ fn write_buffer(
     arrow_data: &mut Vec<u8>,         // output stream
     offset: i64,                      // current output stream offset
     compression_codec: Option<CompressionCodec>,
-    alignment: u8,
+    write_options: &IpcWriteOptions,
 ) -> Result<i64, ArrowError> {
     let len: i64 = match compression_codec {
-        Some(compressor) => compressor.compress_to_vec(buffer, arrow_data)?,
+        Some(compressor) => {
+            compressor.compress_to_vec(buffer, arrow_data, &write_options.compression_options)?
+        }
         None => {
             arrow_data.extend_from_slice(buffer);
             buffer.len()
@@ -1733,7 +2291,7 @@ fn write_buffer(
     // make new index entry
     buffers.push(crate::Buffer::new(offset, len));
     // padding and make offset aligned
-    let pad_len = pad_to_alignment(alignment, len as usize);
+    let pad_len = pad_to_alignment(write_options.alignment, len as usize);
     arrow_data.extend_from_slice(&PADDING[..pad_len]);
 
     Ok(offset + len + (pad_len as i64))
@@ -1758,7 +2316,7 @@ mod tests {
     use arrow_array::builder::UnionBuilder;
     use arrow_array::builder::{PrimitiveRunBuilder, UInt32Builder};
     use arrow_array::types::*;
-    use arrow_buffer::ScalarBuffer;
+    use arrow_buffer::{NullBuffer, ScalarBuffer};
 
     use crate::convert::fb_to_schema;
     use crate::reader::*;
@@ -1921,6 +2479,43 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn test_write_file_with_compression_options() {
+        let array = Int32Array::from_iter_values((0..4096).map(|i| i % 10));
+        let strings = StringArray::from(vec!["a"; 4096]);
+        let batch = RecordBatch::try_from_iter([
+            ("int", Arc::new(array) as ArrayRef),
+            ("string", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap();
+
+        let write = |compression: CompressionOptions| {
+            let options = IpcWriteOptions::default()
+                .try_with_compression(Some(crate::CompressionType::ZSTD))
+                .unwrap()
+                .with_compression_options(compression);
+            let mut writer =
+                FileWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            let file = writer.into_inner().unwrap();
+
+            let mut reader = FileReader::try_new(Cursor::new(file.as_slice()), None).unwrap();
+            assert_eq!(reader.next().unwrap().unwrap(), batch);
+            file.len()
+        };
+
+        let default = write(CompressionOptions::new());
+        let level = write(CompressionOptions::new().with_zstd_level(19).unwrap());
+        assert!(level <= default);
+
+        // The offsets and values buffers of the strings are below the threshold
+        let threshold = write(CompressionOptions::new().with_min_compression_size(16 * 1024));
+        let uncompressed = write(CompressionOptions::new().with_min_compression_size(usize::MAX));
+        assert!(default < threshold && threshold < uncompressed);
+    }
+
     #[test]
     fn test_write_file() {
         let schema = Schema::new(vec![Field::new("field1", DataType::UInt32, true)]);
@@ -2025,6 +2620,100 @@ mod tests {
         write_null_file(IpcWriteOptions::try_new(64, false, MetadataVersion::V5).unwrap());
     }
 
+    fn dictionary_batch(keys: Vec<i32>, values: Vec<&str>) -> RecordBatch {
+        let values = Arc::new(StringArray::from(values));
+        let array = DictionaryArray::new(Int32Array::from(keys), values);
+        RecordBatch::try_from_iter([("dict", Arc::new(array) as ArrayRef)]).unwrap()
+    }
+
+    #[test]
+    fn test_dictionary_tracker_delta() {
+        let mut tracker = DictionaryTracker::new(false);
+        let delta = DictionaryHandling::Delta;
+        let update = |tracker: &mut DictionaryTracker, batch: RecordBatch, handling| {
+            tracker.insert_column(0, batch.column(0), handling).unwrap()
+        };
+
+        let a = dictionary_batch(vec![0, 1], vec!["a", "b"]);
+        assert_eq!(update(&mut tracker, a, delta), DictionaryUpdate::New);
+
+        let b = dictionary_batch(vec![2], vec!["a", "b", "c", "d"]);
+        let expected = StringArray::from(vec!["c", "d"]).into_data();
+        match update(&mut tracker, b, delta) {
+            DictionaryUpdate::Delta(d) => assert_eq!(d, expected),
+            u => panic!("expected delta, got {u:?}"),
+        }
+
+        let c = dictionary_batch(vec![0], vec!["a", "b", "c", "d"]);
+        assert_eq!(update(&mut tracker, c, delta), DictionaryUpdate::None);
+
+        let d = dictionary_batch(vec![0], vec!["a", "b", "c", "d", "e"]);
+        let resend = DictionaryHandling::Resend;
+        assert_eq!(update(&mut tracker, d, resend), DictionaryUpdate::Replaced);
+
+        let e = dictionary_batch(vec![0], vec!["x", "b", "c", "d", "e", "f"]);
+        assert_eq!(update(&mut tracker, e, delta), DictionaryUpdate::Replaced);
+
+        let mut tracker = DictionaryTracker::new(true);
+        let a = dictionary_batch(vec![0, 1], vec!["a", "b"]);
+        assert_eq!(update(&mut tracker, a, delta), DictionaryUpdate::New);
+        let b = dictionary_batch(vec![2], vec!["a", "b", "c"]);
+        assert!(matches!(
+            update(&mut tracker, b, delta),
+            DictionaryUpdate::Delta(_)
+        ));
+        let c = dictionary_batch(vec![2], vec!["a", "b", "c", "d"]);
+        let err = tracker.insert_column(0, c.column(0), resend).unwrap_err();
+        assert!(err.to_string().contains("Dictionary replacement detected"));
+    }
+
+    #[test]
+    fn test_delta_dictionary_round_trip() {
+        let batches = [
+            dictionary_batch(vec![0, 1, 0], vec!["a", "b"]),
+            dictionary_batch(vec![2, 0], vec!["a", "b", "c"]),
+            dictionary_batch(vec![1], vec!["a", "b", "c"]),
+            dictionary_batch(vec![3, 4, 2], vec!["a", "b", "c", "d", "e"]),
+        ];
+        let schema = batches[0].schema();
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+        assert_eq!(options.dictionary_handling(), DictionaryHandling::Delta);
+
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &schema, options.clone()).unwrap();
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        writer.finish().unwrap();
+        let stream = writer.into_inner().unwrap();
+
+        let reader = StreamReader::try_new(Cursor::new(stream.as_slice()), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        // Only the appended values are written
+        let mut writer = StreamWriter::try_new(vec![], &schema).unwrap();
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        writer.finish().unwrap();
+        assert!(stream.len() < writer.into_inner().unwrap().len());
+
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &schema, options.clone()).unwrap();
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        writer.finish().unwrap();
+        let file = writer.into_inner().unwrap();
+
+        let reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        // A dictionary that is not an extension is still a replacement
+        let mut writer = FileWriter::try_new_with_options(vec![], &schema, options).unwrap();
+        writer.write(&batches[1]).unwrap();
+        let replaced = dictionary_batch(vec![0], vec!["x"]);
+        let err = writer.write(&replaced).unwrap_err();
+        assert!(err.to_string().contains("Dictionary replacement detected"));
+    }
+
     #[test]
     fn track_union_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
@@ -2358,6 +3047,36 @@ mod tests {
         assert_eq!(record_batch_slice, deserialized_batch);
     }
 
+    #[test]
+    fn test_list_view_round_trip() {
+        // Views are out of order and overlapping
+        let field = Arc::new(Field::new_list_field(DataType::Utf8, true));
+        let values = Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"]));
+        let offsets = ScalarBuffer::from(vec![3, 0, 1, 0, 4]);
+        let sizes = ScalarBuffer::from(vec![2, 2, 3, 0, 1]);
+        let nulls = NullBuffer::from(vec![true, true, true, false, true]);
+        let list = ListViewArray::new(field.clone(), offsets, sizes, values.clone(), Some(nulls));
+
+        let offsets = ScalarBuffer::from(vec![4, 0, 2, 0, 2]);
+        let sizes = ScalarBuffer::from(vec![1, 5, 0, 5, 0]);
+        let large = LargeListViewArray::new(field, offsets, sizes, values, None);
+
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(list) as ArrayRef),
+            ("b", Arc::new(large) as ArrayRef),
+        ])
+        .unwrap();
+
+        let round_trip = deserialize_file(serialize_file(&batch));
+        assert_eq!(round_trip, batch);
+
+        for (offset, len) in [(0, 5), (1, 3), (4, 1), (2, 0)] {
+            let sliced = batch.slice(offset, len);
+            let round_trip = deserialize_stream(serialize_stream(&sliced));
+            assert_eq!(round_trip, sliced);
+        }
+    }
+
     #[test]
     fn test_stream_writer_writes_array_slice() {
         let array = UInt32Array::from(vec![Some(1), Some(2), Some(3)]);
@@ -2787,6 +3506,200 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_with_alignment() {
+        let options = IpcWriteOptions::default();
+        assert_eq!(options.alignment(), 64);
+        let options = options.try_with_alignment(16).unwrap();
+        assert_eq!(options.alignment(), 16);
+
+        for alignment in [0, 4, 24, 128] {
+            let err = IpcWriteOptions::default()
+                .try_with_alignment(alignment)
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid argument error: Alignment should be 8, 16, 32, or 64."
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_writer_remaps_dict_ids() {
+        // Batches of different sources, whose fields share the default dictionary ID of 0
+        let batches: Vec<_> = [(["a", "b"], ["x", "y"]), (["c", "d"], ["a", "b"])]
+            .into_iter()
+            .map(|(a, b)| {
+                let keys = Int32Array::from(vec![0, 1, 1]);
+                let a = DictionaryArray::new(keys.clone(), Arc::new(StringArray::from(a.to_vec())));
+                let b = DictionaryArray::new(keys, Arc::new(StringArray::from(b.to_vec())));
+                let schema = Schema::new(vec![
+                    Field::new("a", a.data_type().clone(), false),
+                    Field::new("b", b.data_type().clone(), false),
+                ]);
+                let columns = vec![Arc::new(a) as ArrayRef, Arc::new(b) as ArrayRef];
+                RecordBatch::try_new(Arc::new(schema), columns).unwrap()
+            })
+            .collect();
+        assert_eq!(batches[0].schema().field(1).dict_id(), Some(0));
+
+        let options = IpcWriteOptions::default().with_preserve_dict_id(true);
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batches[0].schema(), options).unwrap();
+        assert_eq!(writer.dictionary_tracker.dict_id(), &[0, 1]);
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        let bytes = writer.into_inner().unwrap();
+
+        let reader = StreamReader::try_new(bytes.as_slice(), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[test]
+    fn test_dict_id_for_path() {
+        let dict_type =
+            || DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let list = Field::new_list("list", Field::new("item", dict_type(), true), true);
+        let fields = vec![Field::new("dict", dict_type(), true), list.clone()];
+        let schema = Schema::new(vec![
+            Field::new_dict("a", dict_type(), true, 5, false),
+            Field::new_struct("b", fields, true),
+            list,
+        ]);
+
+        for (preserve_dict_id, expected) in [(false, [0, 1, 2, 3]), (true, [5, 0, 6, 7])] {
+            let mut tracker = DictionaryTracker::new_with_preserve_dict_id(false, preserve_dict_id);
+            IpcSchemaEncoder::new()
+                .with_dictionary_tracker(&mut tracker)
+                .schema_to_fb(&schema);
+
+            assert_eq!(tracker.dict_id(), &expected);
+            assert_eq!(tracker.dict_id_for_path(&["a"]), Some(expected[0]));
+            assert_eq!(tracker.dict_id_for_path(&["b", "dict"]), Some(expected[1]));
+            assert_eq!(
+                tracker.dict_id_for_path(&["b", "list", "item"]),
+                Some(expected[2])
+            );
+            assert_eq!(
+                tracker.dict_id_for_path(&["list", "item"]),
+                Some(expected[3])
+            );
+            assert_eq!(tracker.dict_id_for_path(&["b"]), None);
+        }
+    }
+
+    fn append_batches(values: &[&[&str]]) -> Vec<RecordBatch> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, values)| {
+                let keys = Int32Array::from(vec![0, values.len() as i32 - 1]);
+                let dict = DictionaryArray::new(keys, Arc::new(StringArray::from(values.to_vec())));
+                RecordBatch::try_from_iter([
+                    (
+                        "int",
+                        Arc::new(Int32Array::from(vec![i as i32; 2])) as ArrayRef,
+                    ),
+                    ("dict", Arc::new(dict) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn write_append_file(batches: &[RecordBatch]) -> Cursor<Vec<u8>> {
+        let mut writer = FileWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        writer.write_metadata("key", "value");
+        batches.iter().for_each(|b| writer.write(b).unwrap());
+        Cursor::new(writer.into_inner().unwrap())
+    }
+
+    #[test]
+    fn test_file_writer_append() {
+        let batches = append_batches(&[&["a", "b"], &["a", "b"], &["a", "b"], &["a", "b"]]);
+        let mut file = write_append_file(&batches[..2]);
+        let original = file.get_ref().clone();
+
+        // Appending no batches rewrites the same footer
+        let writer = FileWriter::try_new_append(&mut file).unwrap();
+        writer.into_inner().unwrap();
+        assert_eq!(file.get_ref(), &original);
+
+        let mut writer = FileWriter::try_new_append(&mut file).unwrap();
+        assert_eq!(writer.schema().as_ref(), batches[0].schema().as_ref());
+        writer.write(&batches[2]).unwrap();
+        writer.write_metadata("appended", "true");
+        writer.finish().unwrap();
+
+        let mut writer = FileWriter::try_new_append(&mut file).unwrap();
+        writer.write(&batches[3]).unwrap();
+        writer.finish().unwrap();
+
+        let reader = FileReader::try_new(Cursor::new(file.into_inner()), None).unwrap();
+        assert_eq!(reader.num_batches(), 4);
+        assert_eq!(reader.custom_metadata()["key"], "value");
+        assert_eq!(reader.custom_metadata()["appended"], "true");
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[test]
+    fn test_file_writer_append_dictionaries() {
+        let batches = append_batches(&[&["a", "b"], &["a", "b", "c"], &["x"]]);
+        let mut file = write_append_file(&batches[..1]);
+
+        // Dictionaries of the file cannot be replaced
+        let mut writer = FileWriter::try_new_append(&mut file).unwrap();
+        let err = writer.write(&batches[1]).unwrap_err();
+        assert!(
+            err.to_string().contains("Dictionary replacement detected"),
+            "{err}"
+        );
+
+        // But can be appended to as delta dictionaries
+        let mut file = write_append_file(&batches[..1]);
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+        let mut writer = FileWriter::try_new_append_with_options(&mut file, options).unwrap();
+        writer.write(&batches[1]).unwrap();
+        writer.finish().unwrap();
+
+        let reader = FileReader::try_new(Cursor::new(file.into_inner()), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, &batches[..2]);
+    }
+
+    #[test]
+    fn test_file_writer_append_errors() {
+        let err = FileWriter::try_new_append(Cursor::new(vec![0; 4]))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Arrow file is too small to contain a footer"
+        );
+
+        let err = FileWriter::try_new_append(Cursor::new(vec![0; 16]))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Arrow file does not contain correct footer"
+        );
+
+        let batches = append_batches(&[&["a"]]);
+        let file = write_append_file(&batches);
+        let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V4).unwrap();
+        let err = FileWriter::try_new_append_with_options(file, options)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append metadata version V4 \
+             to an Arrow file of version V5"
+        );
+    }
+
     #[test]
     fn test_flush() {
         // We write a schema which is small enough to fit into a buffer and not get flushed,
@@ -0,0 +1,248 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::writer::{
+    write_continuation, write_message, DictionaryTracker, IpcDataGenerator, IpcWriteOptions,
+};
+
+/// Asynchronous writer for an IPC stream, writing [`RecordBatch`] to an [`AsyncWrite`]
+///
+/// This is the asynchronous counterpart of [`StreamWriter`](crate::writer::StreamWriter).
+/// Each message is encoded in memory and then written to the underlying writer, which
+/// should be buffered if it performs a system call for each write.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::StreamReader;
+/// # use arrow_ipc::writer::AsyncStreamWriter;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+///
+/// let mut writer = AsyncStreamWriter::try_new(vec![], &batch.schema()).await.unwrap();
+/// writer.write(&batch).await.unwrap();
+/// writer.finish().await.unwrap();
+/// let bytes = writer.into_inner();
+///
+/// let reader = StreamReader::try_new(bytes.as_slice(), None).unwrap();
+/// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(batches, vec![batch]);
+/// # }
+/// ```
+pub struct AsyncStreamWriter<W> {
+    /// The object to write to
+    writer: W,
+    /// IPC write options
+    write_options: IpcWriteOptions,
+    /// Whether the end of stream marker has been written, and the writer is finished
+    finished: bool,
+    /// Keeps track of dictionaries that have been written
+    dictionary_tracker: DictionaryTracker,
+    /// A scratch buffer the messages are encoded into
+    buf: Vec<u8>,
+
+    data_gen: IpcDataGenerator,
+}
+
+impl<W> Debug for AsyncStreamWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncStreamWriter<W>")
+            .field("writer", &"W")
+            .field("write_options", &self.write_options)
+            .field("finished", &self.finished)
+            .field("dictionary_tracker", &self.dictionary_tracker)
+            .finish()
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncStreamWriter<W> {
+    /// Try to create a new writer, with the schema written as part of the header.
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if writing the header to the writer fails.
+    pub async fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_new_with_options(writer, schema, IpcWriteOptions::default()).await
+    }
+
+    /// Try to create a new writer with [`IpcWriteOptions`].
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if writing the header to the writer fails.
+    pub async fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let data_gen = IpcDataGenerator::default();
+        let preserve_dict_id = write_options.preserve_dict_id;
+        let mut dictionary_tracker =
+            DictionaryTracker::new_with_preserve_dict_id(false, preserve_dict_id);
+
+        let encoded_message = data_gen.schema_to_bytes_with_dictionary_tracker(
+            schema,
+            &mut dictionary_tracker,
+            &write_options,
+        );
+        let mut buf = vec![];
+        write_message(&mut buf, encoded_message, &write_options)?;
+
+        let mut this = Self {
+            writer,
+            write_options,
+            finished: false,
+            dictionary_tracker,
+            buf,
+            data_gen,
+        };
+        this.flush_buf().await?;
+        Ok(this)
+    }
+
+    /// Write a record batch to the stream
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        if self.finished {
+            return Err(ArrowError::IpcError(
+                "Cannot write record batch to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        let (encoded_dictionaries, encoded_message) = self
+            .data_gen
+            .encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)
+            .expect("AsyncStreamWriter is configured to not error on dictionary replacement");
+
+        for encoded_dictionary in encoded_dictionaries {
+            write_message(&mut self.buf, encoded_dictionary, &self.write_options)?;
+        }
+        write_message(&mut self.buf, encoded_message, &self.write_options)?;
+        self.flush_buf().await
+    }
+
+    /// Write continuation bytes, mark the stream as done, and flush the underlying writer
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        if self.finished {
+            return Err(ArrowError::IpcError(
+                "Cannot write footer to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        write_continuation(&mut self.buf, &self.write_options, 0)?;
+        self.flush_buf().await?;
+        self.writer.flush().await?;
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Flush the underlying writer.
+    pub async fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwraps the underlying writer.
+    ///
+    /// Unlike [`StreamWriter::into_inner`](crate::writer::StreamWriter::into_inner), this
+    /// does not write the end of stream marker, which should first be written with
+    /// [`Self::finish`].
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Writes the encoded messages to the underlying writer
+    async fn flush_buf(&mut self) -> Result<(), ArrowError> {
+        self.writer.write_all(&self.buf).await?;
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::StreamReader;
+    use crate::writer::{DictionaryHandling, StreamWriter};
+    use arrow_array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+    use std::sync::Arc;
+
+    fn batches() -> Vec<RecordBatch> {
+        [vec!["a", "b"], vec!["a", "b", "c"], vec!["x"]]
+            .into_iter()
+            .map(|values| {
+                let keys = Int32Array::from(vec![0; 2]);
+                let dict = DictionaryArray::new(keys, Arc::new(StringArray::from(values)));
+                RecordBatch::try_from_iter([("dict", Arc::new(dict) as ArrayRef)]).unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_writer() {
+        let batches = batches();
+        let schema = batches[0].schema();
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+
+        let mut writer = AsyncStreamWriter::try_new_with_options(vec![], &schema, options.clone())
+            .await
+            .unwrap();
+        for batch in &batches {
+            writer.write(batch).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+        let bytes = writer.into_inner();
+
+        // Identical to the output of the synchronous writer
+        let mut sync_writer = StreamWriter::try_new_with_options(vec![], &schema, options).unwrap();
+        batches.iter().for_each(|b| sync_writer.write(b).unwrap());
+        sync_writer.finish().unwrap();
+        assert_eq!(bytes, sync_writer.into_inner().unwrap());
+
+        let reader = StreamReader::try_new(bytes.as_slice(), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        let mut writer = AsyncStreamWriter::try_new(vec![], &schema).await.unwrap();
+        writer.finish().await.unwrap();
+        let err = writer.write(&batches[0]).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Cannot write record batch to stream writer as it is closed"
+        );
+    }
+}
@@ -19,29 +19,65 @@ use std::marker::PhantomData;
 
 use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::types::DecimalType;
-use arrow_array::Array;
+use arrow_array::{Array, ArrowNativeTypeOp};
+use arrow_buffer::ArrowNativeType;
 use arrow_cast::parse::parse_decimal;
 use arrow_data::ArrayData;
 use arrow_schema::ArrowError;
 
+use crate::reader::primitive_array::is_number;
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::ArrayDecoder;
+use crate::reader::{ArrayDecoder, DecimalRoundingMode, DecoderOptions};
 
 pub struct DecimalArrayDecoder<D: DecimalType> {
     precision: u8,
     scale: i8,
+    rounding: DecimalRoundingMode,
+    null_on_overflow: bool,
     // Invariant and Send
     phantom: PhantomData<fn(D) -> D>,
 }
 
 impl<D: DecimalType> DecimalArrayDecoder<D> {
-    pub fn new(precision: u8, scale: i8) -> Self {
+    pub fn new(precision: u8, scale: i8, options: &DecoderOptions) -> Self {
         Self {
             precision,
             scale,
+            rounding: options.decimal_rounding,
+            null_on_overflow: options.null_on_overflow,
             phantom: PhantomData,
         }
     }
+
+    /// Parses `s` returning `None` if it overflows and `null_on_overflow` is set
+    fn parse(&self, s: &str) -> Result<Option<D::Native>, ArrowError> {
+        let result = match self.rounding {
+            DecimalRoundingMode::Truncate => parse_decimal::<D>(s, self.precision, self.scale),
+            DecimalRoundingMode::HalfUp => self.parse_half_up(s),
+        };
+        match result {
+            Ok(v) => Ok(Some(v)),
+            Err(_) if self.null_on_overflow && is_number(s.as_bytes()) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses `s` with an additional digit of scale, and rounds based on that digit
+    fn parse_half_up(&self, s: &str) -> Result<D::Native, ArrowError> {
+        let v = parse_decimal::<D>(s, D::MAX_PRECISION, self.scale + 1)?;
+        let ten = D::Native::usize_as(10);
+        let five = D::Native::usize_as(5);
+        let (truncated, digit) = (v.div_wrapping(ten), v.mod_wrapping(ten));
+        let rounded = if digit.is_ge(five) {
+            truncated.add_wrapping(D::Native::ONE)
+        } else if digit.is_le(five.neg_wrapping()) {
+            truncated.sub_wrapping(D::Native::ONE)
+        } else {
+            truncated
+        };
+        D::validate_decimal_precision(rounded, self.precision)?;
+        Ok(rounded)
+    }
 }
 
 impl<D> ArrayDecoder for DecimalArrayDecoder<D>
@@ -52,46 +88,29 @@ where
         let mut builder = PrimitiveBuilder::<D>::with_capacity(pos.len());
 
         for p in pos {
-            match tape.get(*p) {
-                TapeElement::Null => builder.append_null(),
-                TapeElement::String(idx) => {
-                    let s = tape.get_string(idx);
-                    let value = parse_decimal::<D>(s, self.precision, self.scale)?;
-                    builder.append_value(value)
-                }
-                TapeElement::Number(idx) => {
-                    let s = tape.get_string(idx);
-                    let value = parse_decimal::<D>(s, self.precision, self.scale)?;
-                    builder.append_value(value)
-                }
+            let value = match tape.get(*p) {
+                TapeElement::Null => None,
+                TapeElement::String(idx) => self.parse(tape.get_string(idx))?,
+                TapeElement::Number(idx) => self.parse(tape.get_string(idx))?,
                 TapeElement::I64(high) => match tape.get(*p + 1) {
                     TapeElement::I32(low) => {
                         let val = ((high as i64) << 32 | (low as u32) as i64).to_string();
-                        let value = parse_decimal::<D>(&val, self.precision, self.scale)?;
-                        builder.append_value(value)
+                        self.parse(&val)?
                     }
                     _ => unreachable!(),
                 },
-                TapeElement::I32(val) => {
-                    let s = val.to_string();
-                    let value = parse_decimal::<D>(&s, self.precision, self.scale)?;
-                    builder.append_value(value)
-                }
+                TapeElement::I32(val) => self.parse(&val.to_string())?,
                 TapeElement::F64(high) => match tape.get(*p + 1) {
                     TapeElement::F32(low) => {
                         let val = f64::from_bits((high as u64) << 32 | low as u64).to_string();
-                        let value = parse_decimal::<D>(&val, self.precision, self.scale)?;
-                        builder.append_value(value)
+                        self.parse(&val)?
                     }
                     _ => unreachable!(),
                 },
-                TapeElement::F32(val) => {
-                    let s = f32::from_bits(val).to_string();
-                    let value = parse_decimal::<D>(&s, self.precision, self.scale)?;
-                    builder.append_value(value)
-                }
+                TapeElement::F32(val) => self.parse(&f32::from_bits(val).to_string())?,
                 _ => return Err(tape.error(*p, "decimal")),
-            }
+            };
+            builder.append_option(value)
         }
 
         Ok(builder
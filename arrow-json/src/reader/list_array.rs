@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::{make_decoder, ArrayDecoder};
+use crate::reader::{make_decoder, ArrayDecoder, DecoderOptions};
 use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::buffer::NullBuffer;
@@ -34,8 +34,7 @@ pub struct ListArrayDecoder<O> {
 impl<O: OffsetSizeTrait> ListArrayDecoder<O> {
     pub fn new(
         data_type: DataType,
-        coerce_primitive: bool,
-        strict_mode: bool,
+        options: &DecoderOptions,
         is_nullable: bool,
     ) -> Result<Self, ArrowError> {
         let field = match &data_type {
@@ -43,12 +42,7 @@ impl<O: OffsetSizeTrait> ListArrayDecoder<O> {
             DataType::LargeList(f) if O::IS_LARGE => f,
             _ => unreachable!(),
         };
-        let decoder = make_decoder(
-            field.data_type().clone(),
-            coerce_primitive,
-            strict_mode,
-            field.is_nullable(),
-        )?;
+        let decoder = make_decoder(field.data_type().clone(), options, field.is_nullable())?;
 
         Ok(Self {
             data_type,
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::{make_decoder, ArrayDecoder};
+use crate::reader::{make_decoder, ArrayDecoder, DecoderOptions};
 use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
 use arrow_buffer::buffer::NullBuffer;
 use arrow_buffer::ArrowNativeType;
@@ -33,8 +33,7 @@ pub struct MapArrayDecoder {
 impl MapArrayDecoder {
     pub fn new(
         data_type: DataType,
-        coerce_primitive: bool,
-        strict_mode: bool,
+        options: &DecoderOptions,
         is_nullable: bool,
     ) -> Result<Self, ArrowError> {
         let fields = match &data_type {
@@ -56,14 +55,12 @@ impl MapArrayDecoder {
 
         let keys = make_decoder(
             fields[0].data_type().clone(),
-            coerce_primitive,
-            strict_mode,
+            options,
             fields[0].is_nullable(),
         )?;
         let values = make_decoder(
             fields[1].data_type().clone(),
-            coerce_primitive,
-            strict_mode,
+            options,
             fields[1].is_nullable(),
         )?;
 
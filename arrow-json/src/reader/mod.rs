@@ -153,6 +153,7 @@ use crate::reader::map_array::MapArrayDecoder;
 use crate::reader::null_array::NullArrayDecoder;
 use crate::reader::primitive_array::PrimitiveArrayDecoder;
 use crate::reader::string_array::StringArrayDecoder;
+use crate::reader::string_view_array::StringViewArrayDecoder;
 use crate::reader::struct_array::StructArrayDecoder;
 use crate::reader::tape::{Tape, TapeDecoder};
 use crate::reader::timestamp_array::TimestampArrayDecoder;
@@ -166,6 +167,7 @@ mod primitive_array;
 mod schema;
 mod serializer;
 mod string_array;
+mod string_view_array;
 mod struct_array;
 mod tape;
 mod timestamp_array;
@@ -173,9 +175,9 @@ mod timestamp_array;
 /// A builder for [`Reader`] and [`Decoder`]
 pub struct ReaderBuilder {
     batch_size: usize,
-    coerce_primitive: bool,
-    strict_mode: bool,
+    options: DecoderOptions,
     is_field: bool,
+    lenient: bool,
 
     schema: SchemaRef,
 }
@@ -192,9 +194,9 @@ impl ReaderBuilder {
     pub fn new(schema: SchemaRef) -> Self {
         Self {
             batch_size: 1024,
-            coerce_primitive: false,
-            strict_mode: false,
+            options: DecoderOptions::default(),
             is_field: false,
+            lenient: false,
             schema,
         }
     }
@@ -232,9 +234,9 @@ impl ReaderBuilder {
     pub fn new_with_field(field: impl Into<FieldRef>) -> Self {
         Self {
             batch_size: 1024,
-            coerce_primitive: false,
-            strict_mode: false,
+            options: DecoderOptions::default(),
             is_field: true,
+            lenient: false,
             schema: Arc::new(Schema::new([field.into()])),
         }
     }
@@ -245,21 +247,120 @@ impl ReaderBuilder {
     }
 
     /// Sets if the decoder should coerce primitive values (bool and number) into string
-    /// when the Schema's column is Utf8 or LargeUtf8.
-    pub fn with_coerce_primitive(self, coerce_primitive: bool) -> Self {
-        Self {
-            coerce_primitive,
-            ..self
-        }
+    /// when the Schema's column is Utf8, LargeUtf8, Utf8View or BinaryView.
+    pub fn with_coerce_primitive(mut self, coerce_primitive: bool) -> Self {
+        self.options.coerce_primitive = coerce_primitive;
+        self
     }
 
     /// Sets if the decoder should return an error if it encounters a column not present
     /// in `schema`
-    pub fn with_strict_mode(self, strict_mode: bool) -> Self {
-        Self {
-            strict_mode,
-            ..self
-        }
+    pub fn with_strict_mode(mut self, strict_mode: bool) -> Self {
+        self.options.strict_mode = strict_mode;
+        self
+    }
+
+    /// Sets a [chrono format string] used to parse string values of timestamp columns,
+    /// instead of the default RFC 3339-like parsing of [`string_to_datetime`]
+    ///
+    /// Formats without a UTC offset are interpreted in the timezone of the column, or
+    /// UTC if none, and formats without a time are interpreted as midnight
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::TimestampSecondType;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema, TimeUnit};
+    /// let data = r#"{"ts": "01/02/2024 10:30:00"}"#;
+    /// let ts = DataType::Timestamp(TimeUnit::Second, None);
+    /// let schema = Arc::new(Schema::new(vec![Field::new("ts", ts, true)]));
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_timestamp_format("%d/%m/%Y %H:%M:%S")
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let ts = batch.column(0).as_primitive::<TimestampSecondType>();
+    /// assert_eq!(ts.value(0), 1706783400);
+    /// ```
+    ///
+    /// [chrono format string]: chrono::format::strftime
+    /// [`string_to_datetime`]: arrow_cast::parse::string_to_datetime
+    pub fn with_timestamp_format(mut self, format: impl Into<String>) -> Self {
+        self.options.timestamp_format = Some(format.into());
+        self
+    }
+
+    /// Sets how values with more fractional digits than the scale of a decimal column
+    /// are rounded, defaults to [`DecimalRoundingMode::Truncate`]
+    ///
+    /// This applies to decimals parsed from both JSON numbers and strings
+    pub fn with_decimal_rounding(mut self, rounding: DecimalRoundingMode) -> Self {
+        self.options.decimal_rounding = rounding;
+        self
+    }
+
+    /// Sets if numeric values that cannot be represented by the type of their column,
+    /// such as `300` for an [`DataType::Int8`] column, should be decoded as null instead
+    /// of returning an error, defaults to `false`
+    ///
+    /// This applies to integer, floating point, decimal and timestamp columns
+    pub fn with_null_on_overflow(mut self, null_on_overflow: bool) -> Self {
+        self.options.null_on_overflow = null_on_overflow;
+        self
+    }
+
+    /// Sets if strings containing JSON numbers, such as `"1e3"` or `" 42 "`, should be
+    /// decoded as numbers for numeric and timestamp columns, defaults to `false`
+    ///
+    /// Regardless of this setting, string values of numeric columns that can be parsed
+    /// by [`Parser`], such as `"42"`, are accepted. This additionally accepts surrounding
+    /// whitespace, scientific notation and fractional values for integer columns, with
+    /// the same semantics as unquoted numbers, and numeric strings for timestamp columns,
+    /// which are interpreted as a number of units since the epoch
+    ///
+    /// [`Parser`]: arrow_cast::parse::Parser
+    pub fn with_coerce_quoted_numbers(mut self, coerce_quoted_numbers: bool) -> Self {
+        self.options.coerce_quoted_numbers = coerce_quoted_numbers;
+        self
+    }
+
+    /// Sets if the decoder should skip records that cannot be decoded, instead of
+    /// returning an error, defaults to `false`
+    ///
+    /// This includes records that are not valid JSON, and records whose values do not
+    /// match the schema. The skipped records can be retrieved with
+    /// [`Decoder::take_bad_records`] or [`Reader::take_bad_records`]
+    ///
+    /// As the end of an invalid JSON record cannot be determined, the input is skipped
+    /// until the next newline, and so lenient decoding requires newline-delimited JSON
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let data = "{\"a\": 1}\n{\"a\": \"foo\"}\n{\"a\": 2]\n{\"a\": 3}\n";
+    /// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_lenient(true)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).as_primitive::<Int32Type>().values(), &[1, 3]);
+    ///
+    /// let mut bad = reader.take_bad_records();
+    /// bad.sort_unstable_by_key(|r| r.row);
+    /// assert_eq!(bad[0].row, 1);
+    /// assert_eq!(bad[0].raw, "{\"a\": \"foo\"}");
+    /// assert_eq!(bad[1].row, 2);
+    /// assert_eq!(bad[1].raw, "{\"a\": 2]");
+    /// ```
+    pub fn with_lenient(self, lenient: bool) -> Self {
+        Self { lenient, ..self }
     }
 
     /// Create a [`Reader`] with the provided [`BufRead`]
@@ -280,20 +381,67 @@ impl ReaderBuilder {
             }
         };
 
-        let decoder = make_decoder(data_type, self.coerce_primitive, self.strict_mode, nullable)?;
+        let decoder = make_decoder(data_type, &self.options, nullable)?;
 
         let num_fields = self.schema.flattened_fields().len();
 
+        let tape_decoder = TapeDecoder::new(self.batch_size, num_fields).with_lenient(self.lenient);
+
         Ok(Decoder {
             decoder,
             is_field: self.is_field,
-            tape_decoder: TapeDecoder::new(self.batch_size, num_fields),
+            tape_decoder,
             batch_size: self.batch_size,
+            lenient: self.lenient,
+            bad_records: vec![],
             schema: self.schema,
         })
     }
 }
 
+/// How to round values with more fractional digits than the scale of a decimal column,
+/// see [`ReaderBuilder::with_decimal_rounding`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DecimalRoundingMode {
+    /// Discard any excess fractional digits, rounding towards zero
+    #[default]
+    Truncate,
+    /// Round to the nearest value, rounding halfway values away from zero
+    HalfUp,
+}
+
+/// Options used by [`make_decoder`] to construct an [`ArrayDecoder`]
+#[derive(Debug, Clone, Default)]
+struct DecoderOptions {
+    coerce_primitive: bool,
+    strict_mode: bool,
+    timestamp_format: Option<String>,
+    decimal_rounding: DecimalRoundingMode,
+    null_on_overflow: bool,
+    coerce_quoted_numbers: bool,
+}
+
+/// A record skipped by a lenient [`Decoder`], see [`ReaderBuilder::with_lenient`]
+#[derive(Debug)]
+pub struct BadRecord {
+    /// The zero-based index of the record in the input, including any skipped records
+    pub row: usize,
+    /// The error encountered decoding the record
+    pub error: ArrowError,
+    /// The raw text of the record, excluding surrounding whitespace
+    pub raw: String,
+}
+
+impl BadRecord {
+    fn new(row: usize, error: ArrowError, raw: &[u8]) -> Self {
+        Self {
+            row,
+            error,
+            raw: String::from_utf8_lossy(raw).trim().to_string(),
+        }
+    }
+}
+
 /// Reads JSON data with a known schema directly into arrow [`RecordBatch`]
 ///
 /// Lines consisting solely of ASCII whitespace are ignored
@@ -310,23 +458,36 @@ impl<R> std::fmt::Debug for Reader<R> {
     }
 }
 
+impl<R> Reader<R> {
+    /// Returns the records skipped since the last call, see [`ReaderBuilder::with_lenient`]
+    pub fn take_bad_records(&mut self) -> Vec<BadRecord> {
+        self.decoder.take_bad_records()
+    }
+}
+
 impl<R: BufRead> Reader<R> {
     /// Reads the next [`RecordBatch`] returning `Ok(None)` if EOF
     fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         loop {
-            let buf = self.reader.fill_buf()?;
-            if buf.is_empty() {
-                break;
+            loop {
+                let buf = self.reader.fill_buf()?;
+                if buf.is_empty() {
+                    break;
+                }
+                let read = buf.len();
+
+                let decoded = self.decoder.decode(buf)?;
+                self.reader.consume(decoded);
+                if decoded != read {
+                    break;
+                }
             }
-            let read = buf.len();
-
-            let decoded = self.decoder.decode(buf)?;
-            self.reader.consume(decoded);
-            if decoded != read {
-                break;
+            match self.decoder.flush()? {
+                // Every record in the batch was skipped
+                Some(batch) if batch.num_rows() == 0 => continue,
+                batch => return Ok(batch),
             }
         }
-        self.decoder.flush()
     }
 }
 
@@ -389,6 +550,8 @@ pub struct Decoder {
     decoder: Box<dyn ArrayDecoder>,
     batch_size: usize,
     is_field: bool,
+    lenient: bool,
+    bad_records: Vec<BadRecord>,
     schema: SchemaRef,
 }
 
@@ -592,12 +755,26 @@ impl Decoder {
         self.tape_decoder.serialize(rows)
     }
 
+    /// Returns the records skipped since the last call, see [`ReaderBuilder::with_lenient`]
+    ///
+    /// Records that are not valid JSON are skipped by [`Self::decode`], whereas records
+    /// whose values do not match the schema are skipped by [`Self::flush`], and so the
+    /// returned records are not necessarily ordered by [`BadRecord::row`]
+    pub fn take_bad_records(&mut self) -> Vec<BadRecord> {
+        let mut bad_records = self.tape_decoder.take_bad_records();
+        bad_records.append(&mut self.bad_records);
+        bad_records
+    }
+
     /// Flushes the currently buffered data to a [`RecordBatch`]
     ///
     /// Returns `Ok(None)` if no buffered data
     ///
-    /// Note: if called part way through decoding a record, this will return an error
+    /// Note: if called part way through decoding a record, this will return an error,
+    /// unless lenient, in which case the partial record is skipped. Similarly if lenient
+    /// and every buffered record is skipped, this will return an empty [`RecordBatch`]
     pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        self.tape_decoder.skip_partial();
         let tape = self.tape_decoder.finish()?;
 
         if tape.num_rows() == 0 {
@@ -613,7 +790,24 @@ impl Decoder {
             })
             .collect();
 
-        let decoded = self.decoder.decode(&tape, &pos)?;
+        let decoded = match self.decoder.decode(&tape, &pos) {
+            Ok(decoded) => decoded,
+            Err(e) if !self.lenient => return Err(e),
+            Err(_) => {
+                // Decode each row individually to find those that cannot be decoded
+                let mut valid = Vec::with_capacity(pos.len());
+                for (idx, p) in pos.iter().enumerate() {
+                    match self.decoder.decode(&tape, std::slice::from_ref(p)) {
+                        Ok(_) => valid.push(*p),
+                        Err(e) => {
+                            let (row, raw) = self.tape_decoder.raw_row(idx).unwrap_or((idx, &[]));
+                            self.bad_records.push(BadRecord::new(row, e, raw));
+                        }
+                    }
+                }
+                self.decoder.decode(&tape, &valid)?
+            }
+        };
         self.tape_decoder.clear();
 
         let batch = match self.is_field {
@@ -633,73 +827,78 @@ trait ArrayDecoder: Send {
 }
 
 macro_rules! primitive_decoder {
-    ($t:ty, $data_type:expr) => {
-        Ok(Box::new(PrimitiveArrayDecoder::<$t>::new($data_type)))
+    ($t:ty, $data_type:expr, $options:expr) => {
+        Ok(Box::new(PrimitiveArrayDecoder::<$t>::new(
+            $data_type, $options,
+        )))
     };
 }
 
 fn make_decoder(
     data_type: DataType,
-    coerce_primitive: bool,
-    strict_mode: bool,
+    options: &DecoderOptions,
     is_nullable: bool,
 ) -> Result<Box<dyn ArrayDecoder>, ArrowError> {
     downcast_integer! {
-        data_type => (primitive_decoder, data_type),
+        data_type => (primitive_decoder, data_type, options),
         DataType::Null => Ok(Box::<NullArrayDecoder>::default()),
-        DataType::Float16 => primitive_decoder!(Float16Type, data_type),
-        DataType::Float32 => primitive_decoder!(Float32Type, data_type),
-        DataType::Float64 => primitive_decoder!(Float64Type, data_type),
+        DataType::Float16 => primitive_decoder!(Float16Type, data_type, options),
+        DataType::Float32 => primitive_decoder!(Float32Type, data_type, options),
+        DataType::Float64 => primitive_decoder!(Float64Type, data_type, options),
         DataType::Timestamp(TimeUnit::Second, None) => {
-            Ok(Box::new(TimestampArrayDecoder::<TimestampSecondType, _>::new(data_type, Utc)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampSecondType, _>::new(data_type, Utc, options)))
         },
         DataType::Timestamp(TimeUnit::Millisecond, None) => {
-            Ok(Box::new(TimestampArrayDecoder::<TimestampMillisecondType, _>::new(data_type, Utc)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampMillisecondType, _>::new(data_type, Utc, options)))
         },
         DataType::Timestamp(TimeUnit::Microsecond, None) => {
-            Ok(Box::new(TimestampArrayDecoder::<TimestampMicrosecondType, _>::new(data_type, Utc)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampMicrosecondType, _>::new(data_type, Utc, options)))
         },
         DataType::Timestamp(TimeUnit::Nanosecond, None) => {
-            Ok(Box::new(TimestampArrayDecoder::<TimestampNanosecondType, _>::new(data_type, Utc)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampNanosecondType, _>::new(data_type, Utc, options)))
         },
         DataType::Timestamp(TimeUnit::Second, Some(ref tz)) => {
             let tz: Tz = tz.parse()?;
-            Ok(Box::new(TimestampArrayDecoder::<TimestampSecondType, _>::new(data_type, tz)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampSecondType, _>::new(data_type, tz, options)))
         },
         DataType::Timestamp(TimeUnit::Millisecond, Some(ref tz)) => {
             let tz: Tz = tz.parse()?;
-            Ok(Box::new(TimestampArrayDecoder::<TimestampMillisecondType, _>::new(data_type, tz)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampMillisecondType, _>::new(data_type, tz, options)))
         },
         DataType::Timestamp(TimeUnit::Microsecond, Some(ref tz)) => {
             let tz: Tz = tz.parse()?;
-            Ok(Box::new(TimestampArrayDecoder::<TimestampMicrosecondType, _>::new(data_type, tz)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampMicrosecondType, _>::new(data_type, tz, options)))
         },
         DataType::Timestamp(TimeUnit::Nanosecond, Some(ref tz)) => {
             let tz: Tz = tz.parse()?;
-            Ok(Box::new(TimestampArrayDecoder::<TimestampNanosecondType, _>::new(data_type, tz)))
+            Ok(Box::new(TimestampArrayDecoder::<TimestampNanosecondType, _>::new(data_type, tz, options)))
         },
-        DataType::Date32 => primitive_decoder!(Date32Type, data_type),
-        DataType::Date64 => primitive_decoder!(Date64Type, data_type),
-        DataType::Time32(TimeUnit::Second) => primitive_decoder!(Time32SecondType, data_type),
-        DataType::Time32(TimeUnit::Millisecond) => primitive_decoder!(Time32MillisecondType, data_type),
-        DataType::Time64(TimeUnit::Microsecond) => primitive_decoder!(Time64MicrosecondType, data_type),
-        DataType::Time64(TimeUnit::Nanosecond) => primitive_decoder!(Time64NanosecondType, data_type),
-        DataType::Duration(TimeUnit::Nanosecond) => primitive_decoder!(DurationNanosecondType, data_type),
-        DataType::Duration(TimeUnit::Microsecond) => primitive_decoder!(DurationMicrosecondType, data_type),
-        DataType::Duration(TimeUnit::Millisecond) => primitive_decoder!(DurationMillisecondType, data_type),
-        DataType::Duration(TimeUnit::Second) => primitive_decoder!(DurationSecondType, data_type),
-        DataType::Decimal128(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal128Type>::new(p, s))),
-        DataType::Decimal256(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal256Type>::new(p, s))),
+        DataType::Date32 => primitive_decoder!(Date32Type, data_type, options),
+        DataType::Date64 => primitive_decoder!(Date64Type, data_type, options),
+        DataType::Time32(TimeUnit::Second) => primitive_decoder!(Time32SecondType, data_type, options),
+        DataType::Time32(TimeUnit::Millisecond) => primitive_decoder!(Time32MillisecondType, data_type, options),
+        DataType::Time64(TimeUnit::Microsecond) => primitive_decoder!(Time64MicrosecondType, data_type, options),
+        DataType::Time64(TimeUnit::Nanosecond) => primitive_decoder!(Time64NanosecondType, data_type, options),
+        DataType::Duration(TimeUnit::Nanosecond) => primitive_decoder!(DurationNanosecondType, data_type, options),
+        DataType::Duration(TimeUnit::Microsecond) => primitive_decoder!(DurationMicrosecondType, data_type, options),
+        DataType::Duration(TimeUnit::Millisecond) => primitive_decoder!(DurationMillisecondType, data_type, options),
+        DataType::Duration(TimeUnit::Second) => primitive_decoder!(DurationSecondType, data_type, options),
+        DataType::Decimal32(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal32Type>::new(p, s, options))),
+        DataType::Decimal64(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal64Type>::new(p, s, options))),
+        DataType::Decimal128(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal128Type>::new(p, s, options))),
+        DataType::Decimal256(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal256Type>::new(p, s, options))),
         DataType::Boolean => Ok(Box::<BooleanArrayDecoder>::default()),
-        DataType::Utf8 => Ok(Box::new(StringArrayDecoder::<i32>::new(coerce_primitive))),
-        DataType::LargeUtf8 => Ok(Box::new(StringArrayDecoder::<i64>::new(coerce_primitive))),
-        DataType::List(_) => Ok(Box::new(ListArrayDecoder::<i32>::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
-        DataType::LargeList(_) => Ok(Box::new(ListArrayDecoder::<i64>::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
-        DataType::Struct(_) => Ok(Box::new(StructArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
+        DataType::Utf8 => Ok(Box::new(StringArrayDecoder::<i32>::new(options.coerce_primitive))),
+        DataType::LargeUtf8 => Ok(Box::new(StringArrayDecoder::<i64>::new(options.coerce_primitive))),
+        DataType::Utf8View => Ok(Box::new(StringViewArrayDecoder::<StringViewType>::new(options.coerce_primitive))),
+        DataType::BinaryView => Ok(Box::new(StringViewArrayDecoder::<BinaryViewType>::new(options.coerce_primitive))),
+        DataType::List(_) => Ok(Box::new(ListArrayDecoder::<i32>::new(data_type, options, is_nullable)?)),
+        DataType::LargeList(_) => Ok(Box::new(ListArrayDecoder::<i64>::new(data_type, options, is_nullable)?)),
+        DataType::Struct(_) => Ok(Box::new(StructArrayDecoder::new(data_type, options, is_nullable)?)),
         DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => {
             Err(ArrowError::JsonError(format!("{data_type} is not supported by JSON")))
         }
-        DataType::Map(_, _) => Ok(Box::new(MapArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
+        DataType::Map(_, _) => Ok(Box::new(MapArrayDecoder::new(data_type, options, is_nullable)?)),
         d => Err(ArrowError::NotYetImplemented(format!("Support for {d} in JSON reader")))
     }
 }
@@ -711,7 +910,7 @@ mod tests {
     use std::io::{BufReader, Cursor, Seek};
 
     use arrow_array::cast::AsArray;
-    use arrow_array::{Array, BooleanArray, Float64Array, ListArray, StringArray};
+    use arrow_array::{Array, ArrayRef, BooleanArray, Float64Array, ListArray, StringArray};
     use arrow_buffer::{ArrowNativeType, Buffer};
     use arrow_cast::display::{ArrayFormatter, FormatOptions};
     use arrow_data::ArrayDataBuilder;
@@ -848,6 +1047,125 @@ mod tests {
         assert_eq!(col2.value(4), "");
     }
 
+    #[test]
+    fn test_string_view() {
+        let buf = r#"
+        {"a": "1", "b": "a string longer than twelve bytes"}
+        {"a": "hello", "b": "shoo"}
+        {"b": "\t😁foo", "a": "\nfoobar\ud83d\ude00\u0061\u0073\u0066\u0067\u00FF"}
+
+        {"b": null}
+        {"b": "", "a": null}
+
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8View, true),
+            Field::new("b", DataType::BinaryView, true),
+        ]));
+
+        let batches = do_read(buf, 1024, false, false, schema);
+        assert_eq!(batches.len(), 1);
+
+        let col1 = batches[0].column(0).as_string_view();
+        assert_eq!(col1.null_count(), 2);
+        assert_eq!(col1.value(0), "1");
+        assert_eq!(col1.value(1), "hello");
+        assert_eq!(col1.value(2), "\nfoobar😀asfgÿ");
+        assert!(col1.is_null(3));
+        assert!(col1.is_null(4));
+
+        let col2 = batches[0].column(1).as_binary_view();
+        assert_eq!(col2.null_count(), 1);
+        assert_eq!(col2.value(0), b"a string longer than twelve bytes");
+        assert_eq!(col2.value(1), b"shoo");
+        assert_eq!(col2.value(2), "\t😁foo".as_bytes());
+        assert!(col2.is_null(3));
+        assert_eq!(col2.value(4), b"");
+    }
+
+    #[test]
+    fn test_string_view_coerce_primitive() {
+        let buf = r#"
+        {"a": 1, "b": [true, false, 1.5]}
+        {"a": "a string longer than twelve bytes", "b": [null, 12345678901234, "x"]}
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8View, true),
+            Field::new_list("b", Field::new("item", DataType::Utf8View, true), true),
+        ]));
+
+        let batches = do_read(buf, 1024, true, false, schema);
+        let col1 = batches[0].column(0).as_string_view();
+        assert_eq!(col1.value(0), "1");
+        assert_eq!(col1.value(1), "a string longer than twelve bytes");
+
+        let col2 = batches[0].column(1).as_list::<i32>();
+        let values = col2.values().as_string_view();
+        let values: Vec<_> = values.iter().collect();
+        assert_eq!(
+            values,
+            [
+                Some("true"),
+                Some("false"),
+                Some("1.5"),
+                None,
+                Some("12345678901234"),
+                Some("x")
+            ]
+        );
+
+        let buf = r#"{"a": 1}"#;
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8View, true)]));
+        let err = ReaderBuilder::new(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': expected string got 1"
+        );
+    }
+
+    #[test]
+    fn test_string_view_buffers() {
+        let long = "a".repeat(20);
+        let longer = "b".repeat(200);
+        let buf: String = (0..10)
+            .map(|i| format!(r#"{{"a": "{long}{i}", "b": "{longer}", "c": "short"}}"#))
+            .collect();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8View, false),
+            Field::new("b", DataType::Utf8View, false),
+            Field::new("c", DataType::Utf8View, false),
+        ]));
+        let batch = ReaderBuilder::new(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        // The strings of "a" are interleaved with the longer strings of "b",
+        // and so are copied individually
+        let a = batch.column(0).as_string_view();
+        assert_eq!(a.value(3), format!("{long}3"));
+        let len: usize = a.data_buffers().iter().map(|b| b.len()).sum();
+        assert_eq!(len, 10 * 21);
+
+        // The strings of "b" reference a single copy of the containing string data
+        let b = batch.column(1).as_string_view();
+        assert_eq!(b.data_buffers().len(), 1);
+        assert!(b.data_buffers()[0].len() < 10 * 250);
+        assert!(b.iter().all(|v| v == Some(longer.as_str())));
+
+        // Short strings are stored inline
+        let c = batch.column(2).as_string_view();
+        assert!(c.data_buffers().is_empty());
+        assert!(c.iter().all(|v| v == Some("short")));
+    }
+
     #[test]
     fn test_complex() {
         let buf = r#"
@@ -1153,6 +1471,8 @@ mod tests {
 
     #[test]
     fn test_decimals() {
+        test_decimal::<Decimal32Type>(DataType::Decimal32(8, 2));
+        test_decimal::<Decimal64Type>(DataType::Decimal64(10, 2));
         test_decimal::<Decimal128Type>(DataType::Decimal128(10, 2));
         test_decimal::<Decimal256Type>(DataType::Decimal256(10, 2));
     }
@@ -2343,4 +2663,389 @@ mod tests {
             .unwrap()
         );
     }
+
+    fn read_lenient(buf: &str, schema: SchemaRef) -> (RecordBatch, Vec<BadRecord>) {
+        let format = |batches: &[RecordBatch]| {
+            let options = FormatOptions::default();
+            let mut rows = vec![];
+            for batch in batches {
+                let array = StructArray::from(batch.clone());
+                let formatter = ArrayFormatter::try_new(&array, &options).unwrap();
+                rows.extend((0..array.len()).map(|i| formatter.value(i).to_string()));
+            }
+            rows
+        };
+
+        let mut expected = None;
+        // Test with different batch and buffer sizes to test for boundary conditions
+        for batch_size in [1, 2, 100] {
+            for capacity in [1, 3, 1024] {
+                let mut reader = ReaderBuilder::new(schema.clone())
+                    .with_batch_size(batch_size)
+                    .with_lenient(true)
+                    .build(BufReader::with_capacity(capacity, buf.as_bytes()))
+                    .unwrap();
+
+                let mut batches = vec![];
+                let mut bad = vec![];
+                while let Some(b) = reader.next() {
+                    batches.push(b.unwrap());
+                    bad.extend(reader.take_bad_records());
+                }
+                bad.extend(reader.take_bad_records());
+                bad.sort_unstable_by_key(|r| r.row);
+
+                let rows = format(&batches);
+                let summary: Vec<_> = bad
+                    .iter()
+                    .map(|r| (r.row, r.raw.clone(), r.error.to_string()))
+                    .collect();
+
+                match &expected {
+                    Some(e) => assert_eq!(e, &(rows, summary)),
+                    None => expected = Some((rows, summary)),
+                }
+                if batch_size == 100 && capacity == 1024 {
+                    assert_eq!(batches.len(), 1);
+                    return (batches.pop().unwrap(), bad);
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test_lenient_invalid_json() {
+        let buf = r#"
+        {"a": 1, "b": "foo"}
+        {"a": 2, "b": "bar"]
+        {"a": 3, "b" "baz"}
+        {"a": 4, "b": null}
+        not json
+        {"a": 5, "b": tru}
+        {"a": 6}
+        {"a": 7, "b": "trunc"#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+
+        let (batch, bad) = read_lenient(buf, schema);
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.values(), &[1, 4, 6]);
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(b.value(0), "foo");
+        assert!(b.is_null(1));
+        assert!(b.is_null(2));
+
+        let rows: Vec<_> = bad.iter().map(|r| r.row).collect();
+        assert_eq!(rows, &[1, 2, 4, 5, 7]);
+        assert_eq!(bad[0].raw, r#"{"a": 2, "b": "bar"]"#);
+        assert_eq!(
+            bad[0].error.to_string(),
+            "Json error: Encountered unexpected ']' whilst parsing object"
+        );
+        assert_eq!(bad[1].raw, r#"{"a": 3, "b" "baz"}"#);
+        assert_eq!(bad[2].raw, "not json");
+        assert_eq!(bad[3].raw, r#"{"a": 5, "b": tru}"#);
+        assert_eq!(bad[4].raw, r#"{"a": 7, "b": "trunc"#);
+        assert_eq!(
+            bad[4].error.to_string(),
+            "Json error: Truncated record whilst reading string"
+        );
+    }
+
+    #[test]
+    fn test_lenient_type_mismatch() {
+        let buf = r#"
+        {"a": 1, "b": {"c": [1, 2]}}
+        {"a": "foo", "b": {"c": [3]}}
+        {"a": 3, "b": {"c": [4, "bar"]}}
+        {"a": 4, "b": {"c": null}}
+        {"a": 5, "b": {"c": {}}}
+        {"a": 6, "b": null}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new(
+                "b",
+                DataType::Struct(
+                    vec![Field::new_list(
+                        "c",
+                        Field::new("item", DataType::Int64, true),
+                        true,
+                    )]
+                    .into(),
+                ),
+                true,
+            ),
+        ]));
+
+        let (batch, bad) = read_lenient(buf, schema);
+        let a = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(a.values(), &[1, 4, 6]);
+        let b = batch.column(1).as_struct();
+        assert!(b.is_valid(1));
+        assert!(b.is_null(2));
+        let c = b.column(0).as_list::<i32>();
+        assert_eq!(c.value_offsets(), &[0, 2, 2, 2]);
+
+        let rows: Vec<_> = bad.iter().map(|r| r.row).collect();
+        assert_eq!(rows, &[1, 2, 4]);
+        assert_eq!(bad[0].raw, r#"{"a": "foo", "b": {"c": [3]}}"#);
+        assert_eq!(
+            bad[0].error.to_string(),
+            "Json error: whilst decoding field 'a': failed to parse \"foo\" as Int32"
+        );
+        assert_eq!(bad[1].raw, r#"{"a": 3, "b": {"c": [4, "bar"]}}"#);
+        assert_eq!(bad[2].raw, r#"{"a": 5, "b": {"c": {}}}"#);
+    }
+
+    #[test]
+    fn test_lenient_decoder() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let mut decoder = ReaderBuilder::new(schema)
+            .with_lenient(true)
+            .build_decoder()
+            .unwrap();
+
+        // Invalid record is skipped once the newline is reached
+        decoder.decode(b"{\"a\": 1}\n{\"a\": }").unwrap();
+        assert!(decoder.take_bad_records().is_empty());
+        decoder.decode(b" more\n{\"a\": \"x\"}\n").unwrap();
+        let bad = decoder.take_bad_records();
+        assert_eq!(bad.len(), 1);
+        assert_eq!(bad[0].row, 1);
+        assert_eq!(bad[0].raw, r#"{"a": } more"#);
+
+        // Type mismatches are found when flushed
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        let bad = decoder.take_bad_records();
+        assert_eq!(bad.len(), 1);
+        assert_eq!(bad[0].row, 2);
+        assert_eq!(bad[0].raw, r#"{"a": "x"}"#);
+
+        // A batch containing only invalid records is empty
+        decoder.decode(b"{\"a\": false}").unwrap();
+        assert_eq!(decoder.flush().unwrap().unwrap().num_rows(), 0);
+        assert_eq!(decoder.take_bad_records()[0].row, 3);
+        assert!(decoder.flush().unwrap().is_none());
+
+        // Not lenient by default
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let mut decoder = ReaderBuilder::new(schema).build_decoder().unwrap();
+        decoder.decode(b"{\"a\": false}").unwrap();
+        decoder.flush().unwrap_err();
+    }
+
+    fn read_with(
+        buf: &str,
+        field: Field,
+        f: impl FnOnce(ReaderBuilder) -> ReaderBuilder,
+    ) -> Result<ArrayRef, ArrowError> {
+        let builder = ReaderBuilder::new(Arc::new(Schema::new(vec![field])));
+        let mut reader = f(builder).build(buf.as_bytes())?;
+        Ok(reader.next().unwrap()?.column(0).clone())
+    }
+
+    #[test]
+    fn test_timestamp_format() {
+        let buf = r#"
+        {"a": "01/02/2024 10:30:00"}
+        {"a": "02/02/2024 23:59:59"}
+        {"a": null}
+        "#;
+
+        let field = Field::new("a", DataType::Timestamp(TimeUnit::Second, None), true);
+        let col = read_with(buf, field.clone(), |b| {
+            b.with_timestamp_format("%d/%m/%Y %H:%M:%S")
+        })
+        .unwrap();
+        let col = col.as_primitive::<TimestampSecondType>();
+        assert_eq!(col.value(0), 1706783400);
+        assert_eq!(col.value(1), 1706918399);
+        assert!(col.is_null(2));
+
+        // Default parsing does not support the format
+        let err = read_with(buf, field, |b| b).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("failed to parse \"01/02/2024 10:30:00\""));
+
+        // Formats without an offset use the timezone of the column
+        let tz = DataType::Timestamp(TimeUnit::Millisecond, Some("+02:00".into()));
+        let field = Field::new("a", tz, true);
+        let col = read_with(buf, field.clone(), |b| {
+            b.with_timestamp_format("%d/%m/%Y %H:%M:%S")
+        })
+        .unwrap();
+        let col = col.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(col.value(0), (1706783400 - 7200) * 1000);
+
+        // Formats with an offset and without a time
+        let buf = r#"{"a": "2024-02-01 10:30 +0100"}"#;
+        let col = read_with(buf, field.clone(), |b| {
+            b.with_timestamp_format("%Y-%m-%d %H:%M %z")
+        })
+        .unwrap();
+        let col = col.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(col.value(0), (1706783400 - 3600) * 1000);
+
+        let buf = r#"{"a": "20240201"}"#;
+        let col = read_with(buf, field.clone(), |b| b.with_timestamp_format("%Y%m%d")).unwrap();
+        let col = col.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(col.value(0), (1706745600 - 7200) * 1000);
+
+        let err = read_with(buf, field, |b| b.with_timestamp_format("%d/%m/%Y")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': failed to parse \"20240201\" as Timestamp(Millisecond, Some(\"+02:00\")): \
+            Parser error: input contains invalid characters using format \"%d/%m/%Y\""
+        );
+    }
+
+    #[test]
+    fn test_decimal_rounding() {
+        let buf = r#"
+        {"a": 1.234}
+        {"a": 1.235}
+        {"a": "-1.235"}
+        {"a": -1.2349}
+        {"a": 9.995}
+        {"a": 12}
+        "#;
+        let field = Field::new("a", DataType::Decimal128(5, 2), true);
+
+        let col = read_with(buf, field.clone(), |b| b).unwrap();
+        let col = col.as_primitive::<Decimal128Type>();
+        assert_eq!(col.values(), &[123, 123, -123, -123, 999, 1200]);
+
+        let col = read_with(buf, field.clone(), |b| {
+            b.with_decimal_rounding(DecimalRoundingMode::HalfUp)
+        })
+        .unwrap();
+        let col = col.as_primitive::<Decimal128Type>();
+        assert_eq!(col.values(), &[123, 124, -124, -123, 1000, 1200]);
+
+        // Rounding can overflow the precision
+        let field = Field::new("a", DataType::Decimal128(3, 2), true);
+        let err = read_with(buf, field, |b| {
+            b.with_decimal_rounding(DecimalRoundingMode::HalfUp)
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: 1000 is too large to store in a Decimal128 of precision 3. Max is 999"
+        );
+    }
+
+    #[test]
+    fn test_null_on_overflow() {
+        let buf = r#"
+        {"a": 1}
+        {"a": 300}
+        {"a": "-300"}
+        {"a": 1e10}
+        "#;
+        let field = Field::new("a", DataType::Int8, true);
+        let err = read_with(buf, field.clone(), |b| b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': failed to parse 300 as Int8"
+        );
+
+        // Strings are only treated as numbers if coercing quoted numbers
+        let err = read_with(buf, field.clone(), |b| b.with_null_on_overflow(true)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': failed to parse \"-300\" as Int8"
+        );
+
+        let col = read_with(buf, field.clone(), |b| {
+            b.with_null_on_overflow(true)
+                .with_coerce_quoted_numbers(true)
+        })
+        .unwrap();
+        let col = col.as_primitive::<Int8Type>();
+        assert_eq!(col.iter().collect::<Vec<_>>(), &[Some(1), None, None, None]);
+
+        // Strings that are not numbers are still an error
+        let buf = r#"{"a": "foo"}"#;
+        let err = read_with(buf, field, |b| {
+            b.with_null_on_overflow(true)
+                .with_coerce_quoted_numbers(true)
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': failed to parse \"foo\" as Int8"
+        );
+
+        let buf = r#"
+        {"a": 1.5}
+        {"a": "1000.5"}
+        {"a": 99999}
+        "#;
+        let field = Field::new("a", DataType::Decimal128(4, 1), true);
+        read_with(buf, field.clone(), |b| b).unwrap_err();
+        let col = read_with(buf, field.clone(), |b| b.with_null_on_overflow(true)).unwrap();
+        let col = col.as_primitive::<Decimal128Type>();
+        assert_eq!(col.iter().collect::<Vec<_>>(), &[Some(15), None, None]);
+
+        let buf = r#"{"a": "abc"}"#;
+        read_with(buf, field, |b| b.with_null_on_overflow(true)).unwrap_err();
+
+        let buf = r#"
+        {"a": "2020-01-01T00:00:00Z"}
+        {"a": "3000-01-01T00:00:00Z"}
+        "#;
+        let ts = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let field = Field::new("a", ts, true);
+        read_with(buf, field.clone(), |b| b).unwrap_err();
+        let col = read_with(buf, field, |b| b.with_null_on_overflow(true)).unwrap();
+        let col = col.as_primitive::<TimestampNanosecondType>();
+        assert_eq!(
+            col.iter().collect::<Vec<_>>(),
+            &[Some(1577836800000000000), None]
+        );
+    }
+
+    #[test]
+    fn test_coerce_quoted_numbers() {
+        let buf = r#"
+        {"a": "42"}
+        {"a": " 42 "}
+        {"a": "1e3"}
+        {"a": "1.5"}
+        "#;
+        let field = Field::new("a", DataType::Int32, true);
+        let err = read_with(buf, field.clone(), |b| b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': failed to parse \" 42 \" as Int32"
+        );
+
+        let col = read_with(buf, field, |b| b.with_coerce_quoted_numbers(true)).unwrap();
+        let col = col.as_primitive::<Int32Type>();
+        assert_eq!(col.values(), &[42, 42, 1000, 1]);
+
+        let field = Field::new("a", DataType::Float64, true);
+        let col = read_with(buf, field, |b| b.with_coerce_quoted_numbers(true)).unwrap();
+        let col = col.as_primitive::<Float64Type>();
+        assert_eq!(col.values(), &[42., 42., 1000., 1.5]);
+
+        let buf = r#"
+        {"a": "1700000000"}
+        {"a": "2023-11-14T22:13:20Z"}
+        "#;
+        let ts = DataType::Timestamp(TimeUnit::Second, None);
+        let field = Field::new("a", ts, true);
+        read_with(buf, field.clone(), |b| b).unwrap_err();
+        let col = read_with(buf, field, |b| b.with_coerce_quoted_numbers(true)).unwrap();
+        let col = col.as_primitive::<TimestampSecondType>();
+        assert_eq!(col.values(), &[1700000000, 1700000000]);
+    }
 }
@@ -26,7 +26,7 @@ use arrow_schema::{ArrowError, DataType};
 use half::f16;
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::ArrayDecoder;
+use crate::reader::{ArrayDecoder, DecoderOptions};
 
 /// A trait for JSON-specific primitive parsing logic
 ///
@@ -73,16 +73,25 @@ impl ParseJsonNumber for f64 {
     }
 }
 
+/// Returns true if `s` is a valid number, regardless of whether it fits in any type
+pub(crate) fn is_number(s: &[u8]) -> bool {
+    lexical_core::parse::<f64>(s).is_ok()
+}
+
 pub struct PrimitiveArrayDecoder<P: ArrowPrimitiveType> {
     data_type: DataType,
+    null_on_overflow: bool,
+    coerce_quoted_numbers: bool,
     // Invariant and Send
     phantom: PhantomData<fn(P) -> P>,
 }
 
 impl<P: ArrowPrimitiveType> PrimitiveArrayDecoder<P> {
-    pub fn new(data_type: DataType) -> Self {
+    pub fn new(data_type: DataType, options: &DecoderOptions) -> Self {
         Self {
             data_type,
+            null_on_overflow: options.null_on_overflow,
+            coerce_quoted_numbers: options.coerce_quoted_numbers,
             phantom: Default::default(),
         }
     }
@@ -99,58 +108,56 @@ where
         let d = &self.data_type;
 
         for p in pos {
-            match tape.get(*p) {
-                TapeElement::Null => builder.append_null(),
+            // On error returns if the value is a number that overflows, and a description
+            let value = match tape.get(*p) {
+                TapeElement::Null => {
+                    builder.append_null();
+                    continue;
+                }
                 TapeElement::String(idx) => {
                     let s = tape.get_string(idx);
-                    let value = P::parse(s).ok_or_else(|| {
-                        ArrowError::JsonError(format!("failed to parse \"{s}\" as {d}",))
-                    })?;
-
-                    builder.append_value(value)
+                    let trimmed = s.trim().as_bytes();
+                    match P::parse(s) {
+                        Some(v) => Ok(v),
+                        None if self.coerce_quoted_numbers && is_number(trimmed) => {
+                            ParseJsonNumber::parse(trimmed).ok_or(true)
+                        }
+                        None => Err(false),
+                    }
+                    .map_err(|overflow| (overflow, format!("\"{s}\"")))
                 }
                 TapeElement::Number(idx) => {
                     let s = tape.get_string(idx);
-                    let value = ParseJsonNumber::parse(s.as_bytes()).ok_or_else(|| {
-                        ArrowError::JsonError(format!("failed to parse {s} as {d}",))
-                    })?;
-
-                    builder.append_value(value)
+                    ParseJsonNumber::parse(s.as_bytes()).ok_or_else(|| (true, s.to_string()))
                 }
                 TapeElement::F32(v) => {
                     let v = f32::from_bits(v);
-                    let value = NumCast::from(v).ok_or_else(|| {
-                        ArrowError::JsonError(format!("failed to parse {v} as {d}",))
-                    })?;
-                    builder.append_value(value)
-                }
-                TapeElement::I32(v) => {
-                    let value = NumCast::from(v).ok_or_else(|| {
-                        ArrowError::JsonError(format!("failed to parse {v} as {d}",))
-                    })?;
-                    builder.append_value(value)
+                    NumCast::from(v).ok_or_else(|| (true, v.to_string()))
                 }
+                TapeElement::I32(v) => NumCast::from(v).ok_or_else(|| (true, v.to_string())),
                 TapeElement::F64(high) => match tape.get(p + 1) {
                     TapeElement::F32(low) => {
                         let v = f64::from_bits((high as u64) << 32 | low as u64);
-                        let value = NumCast::from(v).ok_or_else(|| {
-                            ArrowError::JsonError(format!("failed to parse {v} as {d}",))
-                        })?;
-                        builder.append_value(value)
+                        NumCast::from(v).ok_or_else(|| (true, v.to_string()))
                     }
                     _ => unreachable!(),
                 },
                 TapeElement::I64(high) => match tape.get(p + 1) {
                     TapeElement::I32(low) => {
                         let v = (high as i64) << 32 | (low as u32) as i64;
-                        let value = NumCast::from(v).ok_or_else(|| {
-                            ArrowError::JsonError(format!("failed to parse {v} as {d}",))
-                        })?;
-                        builder.append_value(value)
+                        NumCast::from(v).ok_or_else(|| (true, v.to_string()))
                     }
                     _ => unreachable!(),
                 },
                 _ => return Err(tape.error(*p, "primitive")),
+            };
+
+            match value {
+                Ok(v) => builder.append_value(v),
+                Err((true, _)) if self.null_on_overflow => builder.append_null(),
+                Err((_, s)) => {
+                    return Err(ArrowError::JsonError(format!("failed to parse {s} as {d}")))
+                }
             }
         }
 
@@ -23,6 +23,85 @@ use std::borrow::Borrow;
 use std::io::{BufRead, Seek};
 use std::sync::Arc;
 
+/// How schema inference resolves values of different types appearing in the same field
+///
+/// Values that are `null` or missing from a record never conflict with other values
+/// and are not affected by this setting.
+///
+/// Used by [`InferenceOptions::with_conflict_resolution`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TypeConflictResolution {
+    /// Widen to a type able to represent all observed values
+    ///
+    /// * `Int64` and `Float64` are widened to `Float64`
+    /// * Lists and scalars are widened to a list of a compatible scalar
+    /// * All other combinations, including objects mixed with scalars or lists,
+    ///   are coerced to `Utf8`
+    #[default]
+    Widen,
+    /// Infer any field whose values have differing types as `Utf8`, without attempting
+    /// to widen
+    Stringify,
+    /// Widen `Int64` with `Float64` and scalars with lists as for [`Self::Widen`], returning
+    /// an error identifying the offending field for any other combination
+    Error,
+}
+
+/// Options for JSON schema inference
+///
+/// ```
+/// # use std::io::Cursor;
+/// # use arrow_json::reader::{infer_json_schema_with_options, InferenceOptions, TypeConflictResolution};
+/// let data = r#"{"a": {"b": 1}}
+/// {"a": {"b": true}}"#;
+///
+/// let options = InferenceOptions::new().with_conflict_resolution(TypeConflictResolution::Error);
+/// let err = infer_json_schema_with_options(Cursor::new(data), None, &options).unwrap_err();
+/// assert!(err.to_string().contains("'a.b'"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InferenceOptions {
+    conflict_resolution: TypeConflictResolution,
+}
+
+impl InferenceOptions {
+    /// Create a new [`InferenceOptions`] with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how values of different types in the same field are resolved,
+    /// defaults to [`TypeConflictResolution::Widen`]
+    pub fn with_conflict_resolution(mut self, conflict_resolution: TypeConflictResolution) -> Self {
+        self.conflict_resolution = conflict_resolution;
+        self
+    }
+
+    /// Returns the configured [`TypeConflictResolution`]
+    pub fn conflict_resolution(&self) -> TypeConflictResolution {
+        self.conflict_resolution
+    }
+}
+
+/// The path of a field within a JSON record, used for error reporting
+#[derive(Debug, Clone, Copy)]
+enum FieldPath<'a> {
+    Root,
+    Field(&'a FieldPath<'a>, &'a str),
+    Element(&'a FieldPath<'a>),
+}
+
+impl std::fmt::Display for FieldPath<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldPath::Root => Ok(()),
+            FieldPath::Field(FieldPath::Root, name) => write!(f, "{name}"),
+            FieldPath::Field(parent, name) => write!(f, "{parent}.{name}"),
+            FieldPath::Element(parent) => write!(f, "{parent}[]"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 enum InferredType {
     Scalar(HashSet<DataType>),
@@ -32,49 +111,119 @@ enum InferredType {
 }
 
 impl InferredType {
-    fn merge(&mut self, other: InferredType) -> Result<(), ArrowError> {
-        match (self, other) {
-            (InferredType::Array(s), InferredType::Array(o)) => {
-                s.merge(*o)?;
-            }
-            (InferredType::Scalar(self_hs), InferredType::Scalar(other_hs)) => {
-                other_hs.into_iter().for_each(|v| {
-                    self_hs.insert(v);
-                });
+    /// Merge the type of `value`, found at `path`, into this type
+    fn merge_value(
+        &mut self,
+        value: &Value,
+        path: &FieldPath<'_>,
+        resolution: TypeConflictResolution,
+    ) -> Result<(), ArrowError> {
+        match value {
+            // we treat json as nullable by default when inferring, so nulls never
+            // conflict with other values
+            Value::Null => Ok(()),
+            Value::Bool(_) => self.merge_scalar(DataType::Boolean, path, resolution),
+            Value::Number(n) if n.is_i64() => self.merge_scalar(DataType::Int64, path, resolution),
+            Value::Number(_) => self.merge_scalar(DataType::Float64, path, resolution),
+            Value::String(_) => self.merge_scalar(DataType::Utf8, path, resolution),
+            Value::Array(array) => {
+                match self {
+                    InferredType::Any => *self = InferredType::Array(Box::new(InferredType::Any)),
+                    InferredType::Array(_) => {}
+                    // in case of column contains both scalar type and scalar array type, we
+                    // convert type of this column to scalar array.
+                    InferredType::Scalar(_) if resolution != TypeConflictResolution::Stringify => {
+                        let scalar = std::mem::replace(self, InferredType::Any);
+                        *self = InferredType::Array(Box::new(scalar));
+                    }
+                    _ => return self.resolve_conflict("list", path, resolution),
+                }
+
+                if let InferredType::Array(element) = self {
+                    let element_path = FieldPath::Element(path);
+                    for v in array {
+                        element.merge_value(v, &element_path, resolution)?;
+                    }
+                }
+                Ok(())
             }
-            (InferredType::Object(self_map), InferredType::Object(other_map)) => {
-                for (k, v) in other_map {
-                    self_map.entry(k).or_insert(InferredType::Any).merge(v)?;
+            Value::Object(map) => {
+                match self {
+                    InferredType::Any => *self = InferredType::Object(HashMap::new()),
+                    InferredType::Object(_) => {}
+                    _ => return self.resolve_conflict("object", path, resolution),
                 }
+
+                if let InferredType::Object(field_types) = self {
+                    collect_field_types_from_object(field_types, map, path, resolution)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn merge_scalar(
+        &mut self,
+        data_type: DataType,
+        path: &FieldPath<'_>,
+        resolution: TypeConflictResolution,
+    ) -> Result<(), ArrowError> {
+        match self {
+            InferredType::Any => {
+                *self = InferredType::Scalar(HashSet::from_iter([data_type]));
+                Ok(())
             }
-            (s @ InferredType::Any, v) => {
-                *s = v;
+            InferredType::Scalar(hs) => {
+                hs.insert(data_type);
+                Ok(())
             }
-            (_, InferredType::Any) => {}
             // convert a scalar type to a single-item scalar array type.
-            (InferredType::Array(self_inner_type), other_scalar @ InferredType::Scalar(_)) => {
-                self_inner_type.merge(other_scalar)?;
+            InferredType::Array(element) if resolution != TypeConflictResolution::Stringify => {
+                element.merge_scalar(data_type, &FieldPath::Element(path), resolution)
             }
-            (s @ InferredType::Scalar(_), InferredType::Array(mut other_inner_type)) => {
-                other_inner_type.merge(s.clone())?;
-                *s = InferredType::Array(other_inner_type);
+            _ => self.resolve_conflict(&data_type.to_string(), path, resolution),
+        }
+    }
+
+    /// Resolve a value of type `found` that cannot be merged into this type
+    fn resolve_conflict(
+        &mut self,
+        found: &str,
+        path: &FieldPath<'_>,
+        resolution: TypeConflictResolution,
+    ) -> Result<(), ArrowError> {
+        match resolution {
+            TypeConflictResolution::Error => {
+                let types = format!("{} v.s. {found}", self.describe());
+                Err(conflict_error(path, &types))
             }
-            // incompatible types
-            (s, o) => {
-                return Err(ArrowError::JsonError(format!(
-                    "Incompatible type found during schema inference: {s:?} v.s. {o:?}",
-                )));
+            TypeConflictResolution::Widen | TypeConflictResolution::Stringify => {
+                *self = InferredType::Scalar(HashSet::from_iter([DataType::Utf8]));
+                Ok(())
             }
         }
-
-        Ok(())
     }
 
-    fn is_none_or_any(ty: Option<&Self>) -> bool {
-        matches!(ty, Some(Self::Any) | None)
+    fn describe(&self) -> String {
+        match self {
+            InferredType::Scalar(hs) => hs
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(" v.s. "),
+            InferredType::Array(_) => "list".to_string(),
+            InferredType::Object(_) => "object".to_string(),
+            InferredType::Any => "null".to_string(),
+        }
     }
 }
 
+fn conflict_error(path: &FieldPath<'_>, types: &str) -> ArrowError {
+    ArrowError::JsonError(format!(
+        "Incompatible type found during schema inference for field '{path}': {types}",
+    ))
+}
+
 /// Shorthand for building list data type of `ty`
 fn list_type_of(ty: DataType) -> DataType {
     DataType::List(Arc::new(Field::new_list_field(ty, true)))
@@ -107,24 +256,60 @@ fn coerce_data_type(dt: Vec<&DataType>) -> DataType {
     })
 }
 
-fn generate_datatype(t: &InferredType) -> Result<DataType, ArrowError> {
+fn generate_datatype(
+    t: &InferredType,
+    path: &FieldPath<'_>,
+    resolution: TypeConflictResolution,
+) -> Result<DataType, ArrowError> {
     Ok(match t {
+        InferredType::Scalar(hs) if hs.len() > 1 => match resolution {
+            TypeConflictResolution::Widen => coerce_data_type(hs.iter().collect()),
+            TypeConflictResolution::Stringify => DataType::Utf8,
+            TypeConflictResolution::Error => {
+                if !hs
+                    .iter()
+                    .all(|d| matches!(d, DataType::Int64 | DataType::Float64))
+                {
+                    return Err(conflict_error(path, &t.describe()));
+                }
+                DataType::Float64
+            }
+        },
         InferredType::Scalar(hs) => coerce_data_type(hs.iter().collect()),
-        InferredType::Object(spec) => DataType::Struct(generate_fields(spec)?),
-        InferredType::Array(ele_type) => list_type_of(generate_datatype(ele_type)?),
+        InferredType::Object(spec) => DataType::Struct(generate_fields(spec, path, resolution)?),
+        InferredType::Array(ele_type) => list_type_of(generate_datatype(
+            ele_type,
+            &FieldPath::Element(path),
+            resolution,
+        )?),
         InferredType::Any => DataType::Null,
     })
 }
 
-fn generate_fields(spec: &HashMap<String, InferredType>) -> Result<Fields, ArrowError> {
+fn generate_fields(
+    spec: &HashMap<String, InferredType>,
+    path: &FieldPath<'_>,
+    resolution: TypeConflictResolution,
+) -> Result<Fields, ArrowError> {
     spec.iter()
-        .map(|(k, types)| Ok(Field::new(k, generate_datatype(types)?, true)))
+        .map(|(k, types)| {
+            let field_path = FieldPath::Field(path, k);
+            let data_type = generate_datatype(types, &field_path, resolution)?;
+            Ok(Field::new(k, data_type, true))
+        })
         .collect()
 }
 
 /// Generate schema from JSON field names and inferred data types
-fn generate_schema(spec: HashMap<String, InferredType>) -> Result<Schema, ArrowError> {
-    Ok(Schema::new(generate_fields(&spec)?))
+fn generate_schema(
+    spec: HashMap<String, InferredType>,
+    resolution: TypeConflictResolution,
+) -> Result<Schema, ArrowError> {
+    Ok(Schema::new(generate_fields(
+        &spec,
+        &FieldPath::Root,
+        resolution,
+    )?))
 }
 
 /// JSON file reader that produces a serde_json::Value iterator from a Read trait
@@ -276,198 +461,38 @@ pub fn infer_json_schema<R: BufRead>(
     Ok((schema, values.record_count))
 }
 
-fn set_object_scalar_field_type(
-    field_types: &mut HashMap<String, InferredType>,
-    key: &str,
-    ftype: DataType,
-) -> Result<(), ArrowError> {
-    if InferredType::is_none_or_any(field_types.get(key)) {
-        field_types.insert(key.to_string(), InferredType::Scalar(HashSet::new()));
-    }
-
-    match field_types.get_mut(key).unwrap() {
-        InferredType::Scalar(hs) => {
-            hs.insert(ftype);
-            Ok(())
-        }
-        // in case of column contains both scalar type and scalar array type, we convert type of
-        // this column to scalar array.
-        scalar_array @ InferredType::Array(_) => {
-            let mut hs = HashSet::new();
-            hs.insert(ftype);
-            scalar_array.merge(InferredType::Scalar(hs))?;
-            Ok(())
-        }
-        t => Err(ArrowError::JsonError(format!(
-            "Expected scalar or scalar array JSON type, found: {t:?}",
-        ))),
-    }
-}
-
-fn infer_scalar_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
-    let mut hs = HashSet::new();
-
-    for v in array {
-        match v {
-            Value::Null => {}
-            Value::Number(n) => {
-                if n.is_i64() {
-                    hs.insert(DataType::Int64);
-                } else {
-                    hs.insert(DataType::Float64);
-                }
-            }
-            Value::Bool(_) => {
-                hs.insert(DataType::Boolean);
-            }
-            Value::String(_) => {
-                hs.insert(DataType::Utf8);
-            }
-            Value::Array(_) | Value::Object(_) => {
-                return Err(ArrowError::JsonError(format!(
-                    "Expected scalar value for scalar array, got: {v:?}"
-                )));
-            }
-        }
-    }
-
-    Ok(InferredType::Scalar(hs))
-}
-
-fn infer_nested_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
-    let mut inner_ele_type = InferredType::Any;
-
-    for v in array {
-        match v {
-            Value::Array(inner_array) => {
-                inner_ele_type.merge(infer_array_element_type(inner_array)?)?;
-            }
-            x => {
-                return Err(ArrowError::JsonError(format!(
-                    "Got non array element in nested array: {x:?}"
-                )));
-            }
-        }
-    }
-
-    Ok(InferredType::Array(Box::new(inner_ele_type)))
-}
-
-fn infer_struct_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
-    let mut field_types = HashMap::new();
-
-    for v in array {
-        match v {
-            Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, map)?;
-            }
-            _ => {
-                return Err(ArrowError::JsonError(format!(
-                    "Expected struct value for struct array, got: {v:?}"
-                )));
-            }
-        }
-    }
-
-    Ok(InferredType::Object(field_types))
-}
-
-fn infer_array_element_type(array: &[Value]) -> Result<InferredType, ArrowError> {
-    match array.iter().take(1).next() {
-        None => Ok(InferredType::Any), // empty array, return any type that can be updated later
-        Some(a) => match a {
-            Value::Array(_) => infer_nested_array_type(array),
-            Value::Object(_) => infer_struct_array_type(array),
-            _ => infer_scalar_array_type(array),
-        },
-    }
+/// Infer the fields of a JSON file by reading the first n records of the buffer, with
+/// `max_read_records` controlling the maximum number of records to read, resolving
+/// conflicting types according to `options`.
+///
+/// Returns inferred schema and number of records read.
+///
+/// See [`infer_json_schema`] and [`InferenceOptions`]
+pub fn infer_json_schema_with_options<R: BufRead>(
+    reader: R,
+    max_read_records: Option<usize>,
+    options: &InferenceOptions,
+) -> Result<(Schema, usize), ArrowError> {
+    let mut values = ValueIter::new(reader, max_read_records);
+    let schema = infer_json_schema_from_iterator_with_options(&mut values, options)?;
+    Ok((schema, values.record_count))
 }
 
 fn collect_field_types_from_object(
     field_types: &mut HashMap<String, InferredType>,
     map: &serde_json::map::Map<String, Value>,
+    path: &FieldPath<'_>,
+    resolution: TypeConflictResolution,
 ) -> Result<(), ArrowError> {
     for (k, v) in map {
-        match v {
-            Value::Array(array) => {
-                let ele_type = infer_array_element_type(array)?;
-
-                if InferredType::is_none_or_any(field_types.get(k)) {
-                    match ele_type {
-                        InferredType::Scalar(_) => {
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Scalar(HashSet::new()))),
-                            );
-                        }
-                        InferredType::Object(_) => {
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Object(HashMap::new()))),
-                            );
-                        }
-                        InferredType::Any | InferredType::Array(_) => {
-                            // set inner type to any for nested array as well
-                            // so it can be updated properly from subsequent type merges
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Any)),
-                            );
-                        }
-                    }
-                }
-
-                match field_types.get_mut(k).unwrap() {
-                    InferredType::Array(inner_type) => {
-                        inner_type.merge(ele_type)?;
-                    }
-                    // in case of column contains both scalar type and scalar array type, we
-                    // convert type of this column to scalar array.
-                    field_type @ InferredType::Scalar(_) => {
-                        field_type.merge(ele_type)?;
-                        *field_type = InferredType::Array(Box::new(field_type.clone()));
-                    }
-                    t => {
-                        return Err(ArrowError::JsonError(format!(
-                            "Expected array json type, found: {t:?}",
-                        )));
-                    }
-                }
-            }
-            Value::Bool(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Boolean)?;
-            }
-            Value::Null => {
-                // we treat json as nullable by default when inferring, so just
-                // mark existence of a field if it wasn't known before
-                if !field_types.contains_key(k) {
-                    field_types.insert(k.to_string(), InferredType::Any);
-                }
-            }
-            Value::Number(n) => {
-                if n.is_i64() {
-                    set_object_scalar_field_type(field_types, k, DataType::Int64)?;
-                } else {
-                    set_object_scalar_field_type(field_types, k, DataType::Float64)?;
-                }
-            }
-            Value::String(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Utf8)?;
-            }
-            Value::Object(inner_map) => {
-                if let InferredType::Any = field_types.get(k).unwrap_or(&InferredType::Any) {
-                    field_types.insert(k.to_string(), InferredType::Object(HashMap::new()));
-                }
-                match field_types.get_mut(k).unwrap() {
-                    InferredType::Object(inner_field_types) => {
-                        collect_field_types_from_object(inner_field_types, inner_map)?;
-                    }
-                    t => {
-                        return Err(ArrowError::JsonError(format!(
-                            "Expected object json type, found: {t:?}",
-                        )));
-                    }
-                }
+        let field_path = FieldPath::Field(path, k);
+        match field_types.get_mut(k) {
+            Some(field_type) => field_type.merge_value(v, &field_path, resolution)?,
+            None => {
+                // mark existence of the field even if it is null
+                let mut field_type = InferredType::Any;
+                field_type.merge_value(v, &field_path, resolution)?;
+                field_types.insert(k.to_string(), field_type);
             }
         }
     }
@@ -480,7 +505,9 @@ fn collect_field_types_from_object(
 /// The following type coercion logic is implemented:
 /// * `Int64` and `Float64` are converted to `Float64`
 /// * Lists and scalars are coerced to a list of a compatible scalar
-/// * All other cases are coerced to `Utf8` (String)
+/// * All other cases, including objects mixed with scalars or lists, are coerced to `Utf8` (String)
+///
+/// Use [`infer_json_schema_from_iterator_with_options`] to configure this behaviour.
 ///
 /// Note that the above coercion logic is different from what Spark has, where it would default to
 /// String type in case of List and Scalar values appeared in the same field.
@@ -493,12 +520,36 @@ where
     I: Iterator<Item = Result<V, ArrowError>>,
     V: Borrow<Value>,
 {
+    infer_json_schema_from_iterator_with_options(value_iter, &InferenceOptions::default())
+}
+
+/// Infer the fields of a JSON file by reading all items from the JSON Value Iterator,
+/// resolving conflicting types according to `options`.
+///
+/// Fields are unioned across all records, with fields missing from some records
+/// inferred as nullable.
+///
+/// See [`infer_json_schema_from_iterator`] and [`InferenceOptions`]
+pub fn infer_json_schema_from_iterator_with_options<I, V>(
+    value_iter: I,
+    options: &InferenceOptions,
+) -> Result<Schema, ArrowError>
+where
+    I: Iterator<Item = Result<V, ArrowError>>,
+    V: Borrow<Value>,
+{
+    let resolution = options.conflict_resolution;
     let mut field_types: HashMap<String, InferredType> = HashMap::new();
 
     for record in value_iter {
         match record?.borrow() {
             Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, map)?;
+                collect_field_types_from_object(
+                    &mut field_types,
+                    map,
+                    &FieldPath::Root,
+                    resolution,
+                )?;
             }
             value => {
                 return Err(ArrowError::JsonError(format!(
@@ -508,7 +559,7 @@ where
         };
     }
 
-    generate_schema(field_types)
+    generate_schema(field_types, resolution)
 }
 
 #[cfg(test)]
@@ -739,4 +790,113 @@ mod tests {
         )]);
         assert_eq!(inferred_schema, schema);
     }
+
+    fn infer_with(data: &str, resolution: TypeConflictResolution) -> Result<Schema, ArrowError> {
+        let options = InferenceOptions::new().with_conflict_resolution(resolution);
+        infer_json_schema_with_options(Cursor::new(data), None, &options).map(|(s, _)| s)
+    }
+
+    const CONFLICTING: &str = r#"
+        {"a": 1,   "b": 1,     "c": {"x": 1},   "d": [1],  "e": 1}
+        {"a": 1.5, "b": true,  "c": "foo",      "d": 2.5}
+        {"a": 2,   "b": null,  "c": {"x": 2}}
+    "#;
+
+    #[test]
+    fn test_infer_conflict_widen() {
+        let schema = infer_with(CONFLICTING, TypeConflictResolution::Widen).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, true),
+            Field::new("d", list_type_of(DataType::Float64), true),
+            Field::new("e", DataType::Int64, true),
+        ]);
+        assert_eq!(schema, expected);
+
+        // Matches the behaviour of infer_json_schema
+        let (inferred, _) = infer_json_schema(Cursor::new(CONFLICTING), None).unwrap();
+        assert_eq!(inferred, expected);
+    }
+
+    #[test]
+    fn test_infer_conflict_stringify() {
+        let schema = infer_with(CONFLICTING, TypeConflictResolution::Stringify).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, true),
+            Field::new("d", DataType::Utf8, true),
+            Field::new("e", DataType::Int64, true),
+        ]);
+        assert_eq!(schema, expected);
+
+        // Conflicts are resolved where they occur
+        let data = r#"
+            {"a": {"b": [1, "x"], "c": 1}}
+            {"a": {"b": [true], "c": 2}}
+        "#;
+        let schema = infer_with(data, TypeConflictResolution::Stringify).unwrap();
+        let expected = Schema::new(vec![Field::new(
+            "a",
+            DataType::Struct(Fields::from(vec![
+                Field::new("b", list_type_of(DataType::Utf8), true),
+                Field::new("c", DataType::Int64, true),
+            ])),
+            true,
+        )]);
+        assert_eq!(schema, expected);
+    }
+
+    #[test]
+    fn test_infer_conflict_error() {
+        let err = |data: &str| {
+            infer_with(data, TypeConflictResolution::Error)
+                .unwrap_err()
+                .to_string()
+        };
+
+        // Numeric and scalar to list widening are still permitted
+        let data = r#"
+            {"a": 1, "b": 1}
+            {"a": 1.5, "b": [2, 3.5]}
+        "#;
+        let schema = infer_with(data, TypeConflictResolution::Error).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", list_type_of(DataType::Float64), true),
+        ]);
+        assert_eq!(schema, expected);
+
+        assert_eq!(
+            err(CONFLICTING),
+            "Json error: Incompatible type found during schema inference for field 'c': object v.s. Utf8"
+        );
+        assert_eq!(
+            err(r#"{"a": {"b": 1}}
+            {"a": {"b": true}}"#),
+            "Json error: Incompatible type found during schema inference for field 'a.b': Int64 v.s. Boolean"
+        );
+        assert_eq!(
+            err(r#"{"a": [{"b": 1}, {"b": {"c": 1}}]}"#),
+            "Json error: Incompatible type found during schema inference for field 'a[].b': Int64 v.s. object"
+        );
+        assert_eq!(
+            err(r#"{"a": [1, 2]}
+            {"a": {"b": 1}}"#),
+            "Json error: Incompatible type found during schema inference for field 'a': list v.s. object"
+        );
+    }
+
+    #[test]
+    fn test_infer_mixed_array_elements() {
+        // Previously rejected as mixing scalar and non-scalar array elements
+        let data = r#"{"a": [1, [2]], "b": [{"c": 1}, 2]}"#;
+        let schema = infer_with(data, TypeConflictResolution::Widen).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", list_type_of(list_type_of(DataType::Int64)), true),
+            Field::new("b", list_type_of(DataType::Utf8), true),
+        ]);
+        assert_eq!(schema, expected);
+    }
 }
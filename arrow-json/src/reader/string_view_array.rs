@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::builder::GenericByteViewBuilder;
+use arrow_array::types::ByteViewType;
+use arrow_array::Array;
+use arrow_buffer::Buffer;
+use arrow_data::ArrayData;
+use arrow_schema::ArrowError;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::reader::tape::{Tape, TapeElement};
+use crate::reader::ArrayDecoder;
+
+const TRUE: &str = "true";
+const FALSE: &str = "false";
+
+/// The maximum length of a string stored inline within a view
+const MAX_INLINE_LEN: usize = 12;
+
+/// Decodes JSON strings to a [`GenericByteViewArray`](arrow_array::GenericByteViewArray),
+/// such as a `StringViewArray` or `BinaryViewArray`
+///
+/// Where the strings of this column are densely packed in the [`Tape`] string data,
+/// the views reference a single copy of the containing range of the string data,
+/// avoiding copying each string individually
+pub struct StringViewArrayDecoder<T: ByteViewType> {
+    coerce_primitive: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ByteViewType> StringViewArrayDecoder<T> {
+    pub fn new(coerce_primitive: bool) -> Self {
+        Self {
+            coerce_primitive,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: ByteViewType> ArrayDecoder for StringViewArrayDecoder<T>
+where
+    str: AsRef<T::Native>,
+{
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
+        let coerce_primitive = self.coerce_primitive;
+
+        // The total length, and containing range, of the strings that cannot be inlined
+        let mut long_len = 0;
+        let mut long_range: Option<Range<usize>> = None;
+        for p in pos {
+            match tape.get(*p) {
+                TapeElement::String(idx) => {
+                    let range = tape.get_string_range(idx);
+                    if range.len() > MAX_INLINE_LEN {
+                        long_len += range.len();
+                        long_range = Some(match long_range {
+                            Some(r) => r.start.min(range.start)..r.end.max(range.end),
+                            None => range,
+                        });
+                    }
+                }
+                TapeElement::Null => {}
+                TapeElement::True
+                | TapeElement::False
+                | TapeElement::Number(_)
+                | TapeElement::I64(_)
+                | TapeElement::I32(_)
+                | TapeElement::F64(_)
+                | TapeElement::F32(_)
+                    if coerce_primitive => {}
+                _ => return Err(tape.error(*p, "string")),
+            }
+        }
+
+        let mut builder = GenericByteViewBuilder::<T>::with_capacity(pos.len());
+
+        // Reference the string data directly if at least half of it belongs to this column
+        let block = long_range
+            .filter(|r| r.len() <= long_len * 2 && u32::try_from(r.len()).is_ok())
+            .map(|r| {
+                let data = &tape.string_data().as_bytes()[r.clone()];
+                (builder.append_block(Buffer::from_slice_ref(data)), r.start)
+            });
+
+        for p in pos {
+            match tape.get(*p) {
+                TapeElement::String(idx) => {
+                    let range = tape.get_string_range(idx);
+                    match block {
+                        Some((block, start)) if range.len() > MAX_INLINE_LEN => {
+                            let offset = (range.start - start) as u32;
+                            // SAFETY:
+                            // The range is within the block, and contains a valid UTF-8 string
+                            unsafe {
+                                builder.append_view_unchecked(block, offset, range.len() as u32)
+                            }
+                        }
+                        _ => builder.append_value(tape.get_string(idx)),
+                    }
+                }
+                TapeElement::Null => builder.append_null(),
+                TapeElement::True if coerce_primitive => builder.append_value(TRUE),
+                TapeElement::False if coerce_primitive => builder.append_value(FALSE),
+                TapeElement::Number(idx) if coerce_primitive => {
+                    builder.append_value(tape.get_string(idx))
+                }
+                TapeElement::I64(high) if coerce_primitive => match tape.get(p + 1) {
+                    TapeElement::I32(low) => {
+                        let val = (high as i64) << 32 | (low as u32) as i64;
+                        builder.append_value(val.to_string().as_str());
+                    }
+                    _ => unreachable!(),
+                },
+                TapeElement::I32(n) if coerce_primitive => {
+                    builder.append_value(n.to_string().as_str())
+                }
+                TapeElement::F32(n) if coerce_primitive => {
+                    builder.append_value(n.to_string().as_str())
+                }
+                TapeElement::F64(high) if coerce_primitive => match tape.get(p + 1) {
+                    TapeElement::F32(low) => {
+                        let val = f64::from_bits((high as u64) << 32 | low as u64);
+                        builder.append_value(val.to_string().as_str());
+                    }
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(builder.finish().into_data())
+    }
+}
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::{make_decoder, ArrayDecoder};
+use crate::reader::{make_decoder, ArrayDecoder, DecoderOptions};
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_buffer::buffer::NullBuffer;
 use arrow_data::{ArrayData, ArrayDataBuilder};
@@ -32,8 +32,7 @@ pub struct StructArrayDecoder {
 impl StructArrayDecoder {
     pub fn new(
         data_type: DataType,
-        coerce_primitive: bool,
-        strict_mode: bool,
+        options: &DecoderOptions,
         is_nullable: bool,
     ) -> Result<Self, ArrowError> {
         let decoders = struct_fields(&data_type)
@@ -43,19 +42,14 @@ impl StructArrayDecoder {
                 // StructArrayDecoder::decode verifies that if the child is not nullable
                 // it doesn't contain any nulls not masked by its parent
                 let nullable = f.is_nullable() || is_nullable;
-                make_decoder(
-                    f.data_type().clone(),
-                    coerce_primitive,
-                    strict_mode,
-                    nullable,
-                )
+                make_decoder(f.data_type().clone(), options, nullable)
             })
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
         Ok(Self {
             data_type,
             decoders,
-            strict_mode,
+            strict_mode: options.strict_mode,
             is_nullable,
         })
     }
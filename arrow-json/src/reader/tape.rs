@@ -16,9 +16,11 @@
 // under the License.
 
 use crate::reader::serializer::TapeSerializer;
+use crate::reader::BadRecord;
 use arrow_schema::ArrowError;
 use serde::Serialize;
 use std::fmt::Write;
+use std::ops::Range;
 
 /// We decode JSON to a flattened tape representation,
 /// allowing for efficient traversal of the JSON data
@@ -110,6 +112,18 @@ impl<'a> Tape<'a> {
         unsafe { self.strings.get_unchecked(start_offset..end_offset) }
     }
 
+    /// Returns the byte range within [`Self::string_data`] of the string for the given string index
+    #[inline]
+    pub fn get_string_range(&self, idx: u32) -> Range<usize> {
+        self.string_offsets[idx as usize]..self.string_offsets[idx as usize + 1]
+    }
+
+    /// Returns the string data of all the strings in this tape
+    #[inline]
+    pub fn string_data(&self) -> &'a str {
+        self.strings
+    }
+
     /// Returns the tape element at `idx`
     pub fn get(&self, idx: u32) -> TapeElement {
         self.elements[idx as usize]
@@ -314,6 +328,59 @@ pub struct TapeDecoder {
 
     /// A stack of [`DecoderState`]
     stack: Vec<DecoderState>,
+
+    /// If records that fail to parse should be skipped, see [`LenientState`]
+    lenient: Option<LenientState>,
+}
+
+/// The state used by a lenient [`TapeDecoder`] to skip records that fail to parse
+///
+/// Records that fail to parse are rolled back, and the input skipped until the
+/// next newline, as the end of an invalid record cannot otherwise be determined
+#[derive(Debug, Default)]
+struct LenientState {
+    /// The raw bytes of the records in this tape, and any that failed to parse
+    raw: Vec<u8>,
+    /// The record number and range within `raw` of each row in this tape
+    rows: Vec<(usize, Range<usize>)>,
+    /// The number of records started, including those that failed to parse
+    records: usize,
+    /// The lengths of `elements`, `bytes` and `offsets` at the start of the current row
+    checkpoint: (usize, usize, usize),
+    /// The record number, error and start within `raw` of a record being skipped
+    skipping: Option<(usize, ArrowError, usize)>,
+    /// Records that have been skipped, but whose raw bytes may not yet be in `raw`
+    skipped: Vec<(usize, ArrowError, Range<usize>)>,
+    /// Records that failed to parse
+    bad_records: Vec<BadRecord>,
+}
+
+impl LenientState {
+    fn start_row(&mut self, start: usize, checkpoint: (usize, usize, usize)) {
+        self.rows.push((self.records, start..usize::MAX));
+        self.records += 1;
+        self.checkpoint = checkpoint;
+    }
+
+    /// Records `end` as the end of the last row, or of the record being skipped
+    fn end_row(&mut self, end: usize) {
+        match self.skipping.take() {
+            Some((record, error, start)) => self.skipped.push((record, error, start..end)),
+            None => {
+                if let Some((_, range)) = self.rows.last_mut() {
+                    range.end = range.end.min(end);
+                }
+            }
+        }
+    }
+
+    /// Converts skipped records, whose raw bytes are now in `raw`, to [`BadRecord`]
+    fn flush_skipped(&mut self) {
+        for (record, error, range) in self.skipped.drain(..) {
+            let raw = &self.raw[range];
+            self.bad_records.push(BadRecord::new(record, error, raw));
+        }
+    }
 }
 
 impl TapeDecoder {
@@ -334,23 +401,80 @@ impl TapeDecoder {
             cur_row: 0,
             bytes: Vec::with_capacity(num_fields * 2 * 8),
             stack: Vec::with_capacity(10),
+            lenient: None,
+        }
+    }
+
+    /// Skip records that fail to parse, instead of returning an error
+    ///
+    /// The skipped records can be retrieved with [`Self::take_bad_records`]
+    pub fn with_lenient(self, lenient: bool) -> Self {
+        Self {
+            lenient: lenient.then(LenientState::default),
+            ..self
         }
     }
 
     pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
         let mut iter = BufIter::new(buf);
+        while let Err(e) = self.decode_iter(&mut iter, buf.len()) {
+            match self.lenient.is_some() {
+                true => self.skip_row(e),
+                false => return Err(e),
+            }
+        }
+
+        let read = buf.len() - iter.len();
+        if let Some(lenient) = self.lenient.as_mut() {
+            lenient.raw.extend_from_slice(&buf[..read]);
+            lenient.flush_skipped();
+        }
+        Ok(read)
+    }
 
-        while !iter.is_empty() {
+    /// Rolls back the partially decoded current row, which failed with `error`,
+    /// and starts skipping input until the next newline
+    fn skip_row(&mut self, error: ArrowError) {
+        let lenient = self.lenient.as_mut().unwrap();
+        let (elements, bytes, offsets) = lenient.checkpoint;
+        self.elements.truncate(elements);
+        self.bytes.truncate(bytes);
+        self.offsets.truncate(offsets);
+        self.stack.clear();
+        self.cur_row -= 1;
+
+        let (record, range) = lenient.rows.pop().unwrap();
+        lenient.skipping = Some((record, error, range.start));
+    }
+
+    fn decode_iter(&mut self, iter: &mut BufIter<'_>, buf_len: usize) -> Result<(), ArrowError> {
+        while !iter.as_slice().is_empty() {
             let state = match self.stack.last_mut() {
                 Some(l) => l,
                 None => {
+                    if let Some(lenient) = self.lenient.as_mut() {
+                        if lenient.skipping.is_some() {
+                            iter.advance_until(|b| b == b'\n');
+                            if iter.as_slice().is_empty() {
+                                break;
+                            }
+                        }
+                        lenient.end_row(lenient.raw.len() + buf_len - iter.len());
+                    }
+
                     iter.skip_whitespace();
-                    if iter.is_empty() || self.cur_row >= self.batch_size {
+                    if iter.as_slice().is_empty() || self.cur_row >= self.batch_size {
                         break;
                     }
 
                     // Start of row
                     self.cur_row += 1;
+                    if let Some(lenient) = self.lenient.as_mut() {
+                        let start = lenient.raw.len() + buf_len - iter.len();
+                        let checkpoint =
+                            (self.elements.len(), self.bytes.len(), self.offsets.len());
+                        lenient.start_row(start, checkpoint);
+                    }
                     self.stack.push(DecoderState::Value);
                     self.stack.last_mut().unwrap()
                 }
@@ -438,7 +562,7 @@ impl TapeDecoder {
                     });
                     self.bytes.extend_from_slice(s);
 
-                    if !iter.is_empty() {
+                    if !iter.as_slice().is_empty() {
                         self.stack.pop();
                         let idx = self.offsets.len() - 1;
                         self.elements.push(TapeElement::Number(idx as _));
@@ -455,7 +579,7 @@ impl TapeDecoder {
                 DecoderState::Literal(literal, idx) => {
                     let bytes = literal.bytes();
                     let expected = bytes.iter().skip(*idx as usize).copied();
-                    for (expected, b) in expected.zip(&mut iter) {
+                    for (expected, b) in expected.zip(&mut *iter) {
                         match b == expected {
                             true => *idx += 1,
                             false => return Err(err(b, "parsing literal")),
@@ -521,7 +645,7 @@ impl TapeDecoder {
             }
         }
 
-        Ok(buf.len() - iter.len())
+        Ok(())
     }
 
     /// Writes any type that implements [`Serialize`] into this [`TapeDecoder`]
@@ -541,17 +665,54 @@ impl TapeDecoder {
             .map_err(|e| ArrowError::JsonError(e.to_string()))?;
 
         self.cur_row += rows.len();
+        if let Some(lenient) = self.lenient.as_mut() {
+            let end = lenient.raw.len();
+            let checkpoint = (self.elements.len(), self.bytes.len(), self.offsets.len());
+            for _ in rows {
+                lenient.start_row(end, checkpoint);
+                lenient.end_row(end);
+            }
+        }
 
         Ok(())
     }
 
+    /// Skips any partially decoded row, or record being skipped, if lenient
+    ///
+    /// This should be called before [`Self::finish`] once all input has been provided
+    pub fn skip_partial(&mut self) {
+        if self.lenient.is_none() {
+            return;
+        }
+        if let Some(b) = self.stack.last() {
+            let e = truncated_error(b);
+            self.skip_row(e);
+        }
+        let lenient = self.lenient.as_mut().unwrap();
+        lenient.end_row(lenient.raw.len());
+        lenient.flush_skipped();
+    }
+
+    /// Returns the record number and raw bytes of `row` within the current tape, if lenient
+    pub fn raw_row(&self, row: usize) -> Option<(usize, &[u8])> {
+        let lenient = self.lenient.as_ref()?;
+        let (record, range) = lenient.rows.get(row)?;
+        let end = range.end.min(lenient.raw.len());
+        Some((*record, &lenient.raw[range.start..end]))
+    }
+
+    /// Returns the records that failed to parse since the last call, if lenient
+    pub fn take_bad_records(&mut self) -> Vec<BadRecord> {
+        match self.lenient.as_mut() {
+            Some(lenient) => std::mem::take(&mut lenient.bad_records),
+            None => vec![],
+        }
+    }
+
     /// Finishes the current [`Tape`]
     pub fn finish(&self) -> Result<Tape<'_>, ArrowError> {
         if let Some(b) = self.stack.last() {
-            return Err(ArrowError::JsonError(format!(
-                "Truncated record whilst reading {}",
-                b.as_str()
-            )));
+            return Err(truncated_error(b));
         }
 
         if self.offsets.len() >= u32::MAX as usize {
@@ -600,6 +761,10 @@ impl TapeDecoder {
         self.elements.push(TapeElement::Null);
         self.offsets.clear();
         self.offsets.push(0);
+        if let Some(lenient) = self.lenient.as_mut() {
+            lenient.raw.clear();
+            lenient.rows.clear();
+        }
     }
 }
 
@@ -615,10 +780,6 @@ impl<'a> BufIter<'a> {
         self.0.as_slice()
     }
 
-    fn is_empty(&self) -> bool {
-        self.0.len() == 0
-    }
-
     fn peek(&self) -> Option<u8> {
         self.0.as_slice().first().copied()
     }
@@ -662,6 +823,14 @@ impl Iterator for BufIter<'_> {
 
 impl ExactSizeIterator for BufIter<'_> {}
 
+/// Returns an error for a record truncated whilst in `state`
+fn truncated_error(state: &DecoderState) -> ArrowError {
+    ArrowError::JsonError(format!(
+        "Truncated record whilst reading {}",
+        state.as_str()
+    ))
+}
+
 /// Returns an error for a given byte `b` and context `ctx`
 fn err(b: u8, ctx: &str) -> ArrowError {
     ArrowError::JsonError(format!(
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use chrono::TimeZone;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone};
 use std::marker::PhantomData;
 
 use arrow_array::builder::PrimitiveBuilder;
@@ -25,25 +25,69 @@ use arrow_cast::parse::string_to_datetime;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, TimeUnit};
 
+use crate::reader::primitive_array::is_number;
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::ArrayDecoder;
+use crate::reader::{ArrayDecoder, DecoderOptions};
 
 /// A specialized [`ArrayDecoder`] for timestamps
 pub struct TimestampArrayDecoder<P: ArrowTimestampType, Tz: TimeZone> {
     data_type: DataType,
     timezone: Tz,
+    format: Option<String>,
+    null_on_overflow: bool,
+    coerce_quoted_numbers: bool,
     // Invariant and Send
     phantom: PhantomData<fn(P) -> P>,
 }
 
 impl<P: ArrowTimestampType, Tz: TimeZone> TimestampArrayDecoder<P, Tz> {
-    pub fn new(data_type: DataType, timezone: Tz) -> Self {
+    pub fn new(data_type: DataType, timezone: Tz, options: &DecoderOptions) -> Self {
         Self {
             data_type,
             timezone,
+            format: options.timestamp_format.clone(),
+            null_on_overflow: options.null_on_overflow,
+            coerce_quoted_numbers: options.coerce_quoted_numbers,
             phantom: Default::default(),
         }
     }
+
+    fn parse_number(&self, s: &str) -> Result<i64, ArrowError> {
+        let b = s.as_bytes();
+        lexical_core::parse::<i64>(b)
+            .or_else(|_| lexical_core::parse::<f64>(b).map(|x| x as i64))
+            .map_err(|_| {
+                ArrowError::JsonError(format!("failed to parse {s} as {}", self.data_type))
+            })
+    }
+}
+
+/// Parses `s` with the chrono format string `format`, interpreting it in `timezone`
+/// if it does not contain a UTC offset, and as midnight if it does not contain a time
+fn parse_formatted<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    format: &str,
+) -> Result<DateTime<T>, ArrowError> {
+    if let Ok(date) = DateTime::parse_from_str(s, format) {
+        return Ok(date.with_timezone(timezone));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(s, format)
+        .or_else(|e| match NaiveDate::parse_from_str(s, format) {
+            Ok(date) => Ok(date.and_hms_opt(0, 0, 0).unwrap()),
+            Err(_) => Err(e),
+        })
+        .map_err(|e| ArrowError::ParseError(format!("{e} using format \"{format}\"")))?;
+
+    timezone
+        .from_local_datetime(&naive)
+        .single()
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "{naive} is ambiguous or does not exist in timezone"
+            ))
+        })
 }
 
 impl<P, Tz> ArrayDecoder for TimestampArrayDecoder<P, Tz>
@@ -60,7 +104,17 @@ where
                 TapeElement::Null => builder.append_null(),
                 TapeElement::String(idx) => {
                     let s = tape.get_string(idx);
-                    let date = string_to_datetime(&self.timezone, s).map_err(|e| {
+                    let trimmed = s.trim();
+                    if self.coerce_quoted_numbers && is_number(trimmed.as_bytes()) {
+                        builder.append_value(self.parse_number(trimmed)?);
+                        continue;
+                    }
+
+                    let date = match &self.format {
+                        Some(format) => parse_formatted(&self.timezone, s, format),
+                        None => string_to_datetime(&self.timezone, s),
+                    }
+                    .map_err(|e| {
                         ArrowError::JsonError(format!(
                             "failed to parse \"{s}\" as {}: {}",
                             self.data_type, e
@@ -68,31 +122,25 @@ where
                     })?;
 
                     let value = match P::UNIT {
-                        TimeUnit::Second => date.timestamp(),
-                        TimeUnit::Millisecond => date.timestamp_millis(),
-                        TimeUnit::Microsecond => date.timestamp_micros(),
-                        TimeUnit::Nanosecond => date.timestamp_nanos_opt().ok_or_else(|| {
-                            ArrowError::ParseError(format!(
+                        TimeUnit::Second => Some(date.timestamp()),
+                        TimeUnit::Millisecond => Some(date.timestamp_millis()),
+                        TimeUnit::Microsecond => Some(date.timestamp_micros()),
+                        TimeUnit::Nanosecond => date.timestamp_nanos_opt(),
+                    };
+                    match value {
+                        Some(v) => builder.append_value(v),
+                        None if self.null_on_overflow => builder.append_null(),
+                        None => {
+                            return Err(ArrowError::ParseError(format!(
                                 "{} would overflow 64-bit signed nanoseconds",
                                 date.to_rfc3339(),
-                            ))
-                        })?,
-                    };
-                    builder.append_value(value)
+                            )))
+                        }
+                    }
                 }
                 TapeElement::Number(idx) => {
                     let s = tape.get_string(idx);
-                    let b = s.as_bytes();
-                    let value = lexical_core::parse::<i64>(b)
-                        .or_else(|_| lexical_core::parse::<f64>(b).map(|x| x as i64))
-                        .map_err(|_| {
-                            ArrowError::JsonError(format!(
-                                "failed to parse {s} as {}",
-                                self.data_type
-                            ))
-                        })?;
-
-                    builder.append_value(value)
+                    builder.append_value(self.parse_number(s)?)
                 }
                 TapeElement::I32(v) => builder.append_value(v as i64),
                 TapeElement::I64(high) => match tape.get(p + 1) {
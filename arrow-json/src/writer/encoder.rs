@@ -19,8 +19,9 @@ use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_cast::base64::{Engine, BASE64_STANDARD};
 use arrow_cast::display::{ArrayFormatter, FormatOptions};
-use arrow_schema::{ArrowError, DataType, FieldRef};
+use arrow_schema::{ArrowError, DataType, FieldRef, IntervalUnit, TimeUnit};
 use half::f16;
 use lexical_core::FormattedSize;
 use serde::Serializer;
@@ -29,6 +30,49 @@ use std::io::Write;
 #[derive(Debug, Clone, Default)]
 pub struct EncoderOptions {
     pub explicit_nulls: bool,
+    pub nested_explicit_nulls: Option<bool>,
+    pub binary_encoding: BinaryEncoding,
+    pub interval_encoding: IntervalEncoding,
+}
+
+impl EncoderOptions {
+    /// Returns the options for values nested within a struct, list or map
+    fn nested(&self) -> Self {
+        Self {
+            explicit_nulls: self.nested_explicit_nulls.unwrap_or(self.explicit_nulls),
+            ..self.clone()
+        }
+    }
+}
+
+/// How `Binary`, `LargeBinary` and `FixedSizeBinary` values are encoded
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum BinaryEncoding {
+    /// A string of lowercase hexadecimal digits, e.g. `"0aff"`
+    #[default]
+    Hex,
+    /// A standard, padded, base64 string, e.g. `"Cv8="`
+    Base64,
+    /// An array of integer byte values, e.g. `[10,255]`
+    Array,
+}
+
+/// How `Interval` and `Duration` values are encoded
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum IntervalEncoding {
+    /// A string formatted by [`ArrayFormatter`], e.g. `"PT120S"` for a `Duration`
+    /// and `"1 mons 2 days"` for an `Interval`
+    #[default]
+    Display,
+    /// An ISO 8601 duration string, e.g. `"PT120S"` or `"P1M2D"`
+    ///
+    /// As ISO 8601 does not define negative durations, negative components are
+    /// written with a leading `-`, e.g. `"P-1M2D"` or `"PT-0.5S"`
+    Iso8601,
+    /// An object of the components of the value, e.g. `{"seconds":120}` for a
+    /// `Duration(Second)` or `{"months":1,"days":2,"nanoseconds":0}` for an
+    /// `Interval(MonthDayNano)`
+    Object,
 }
 
 /// A trait to format array values as JSON values
@@ -86,15 +130,23 @@ fn make_encoder_impl<'a>(
         }
         DataType::List(_) => {
             let array = array.as_list::<i32>();
-            (Box::new(ListEncoder::try_new(array, options)?) as _, array.nulls().cloned())
+            (Box::new(ListEncoder::try_new(array, &options.nested())?) as _, array.nulls().cloned())
         }
         DataType::LargeList(_) => {
             let array = array.as_list::<i64>();
-            (Box::new(ListEncoder::try_new(array, options)?) as _, array.nulls().cloned())
+            (Box::new(ListEncoder::try_new(array, &options.nested())?) as _, array.nulls().cloned())
+        }
+        DataType::ListView(_) => {
+            let array = array.as_list_view::<i32>();
+            (Box::new(ListViewEncoder::try_new(array, &options.nested())?) as _, array.nulls().cloned())
+        }
+        DataType::LargeListView(_) => {
+            let array = array.as_list_view::<i64>();
+            (Box::new(ListViewEncoder::try_new(array, &options.nested())?) as _, array.nulls().cloned())
         }
         DataType::FixedSizeList(_, _) => {
             let array = array.as_fixed_size_list();
-            (Box::new(FixedSizeListEncoder::try_new(array, options)?) as _, array.nulls().cloned())
+            (Box::new(FixedSizeListEncoder::try_new(array, &options.nested())?) as _, array.nulls().cloned())
         }
 
         DataType::Dictionary(_, _) => downcast_dictionary_array! {
@@ -104,28 +156,29 @@ fn make_encoder_impl<'a>(
 
         DataType::Map(_, _) => {
             let array = array.as_map();
-            (Box::new(MapEncoder::try_new(array, options)?) as _,  array.nulls().cloned())
+            (Box::new(MapEncoder::try_new(array, &options.nested())?) as _,  array.nulls().cloned())
         }
 
         DataType::FixedSizeBinary(_) => {
             let array = array.as_fixed_size_binary();
-            (Box::new(BinaryEncoder::new(array)) as _, array.nulls().cloned())
+            (Box::new(BinaryEncoder::new(array, options.binary_encoding)) as _, array.nulls().cloned())
         }
 
         DataType::Binary => {
             let array: &BinaryArray = array.as_binary();
-            (Box::new(BinaryEncoder::new(array)) as _, array.nulls().cloned())
+            (Box::new(BinaryEncoder::new(array, options.binary_encoding)) as _, array.nulls().cloned())
         }
 
         DataType::LargeBinary => {
             let array: &LargeBinaryArray = array.as_binary();
-            (Box::new(BinaryEncoder::new(array)) as _, array.nulls().cloned())
+            (Box::new(BinaryEncoder::new(array, options.binary_encoding)) as _, array.nulls().cloned())
         }
 
         DataType::Struct(fields) => {
             let array = array.as_struct();
+            let nested = options.nested();
             let encoders = fields.iter().zip(array.columns()).map(|(field, array)| {
-                let (encoder, nulls) = make_encoder_impl(array, options)?;
+                let (encoder, nulls) = make_encoder_impl(array, &nested)?;
                 Ok(FieldEncoder{
                     field: field.clone(),
                     encoder, nulls
@@ -138,12 +191,19 @@ fn make_encoder_impl<'a>(
             };
             (Box::new(encoder) as _, array.nulls().cloned())
         }
-        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+        DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => {
             let options = FormatOptions::new().with_display_error(true);
             let formatter = ArrayFormatter::try_new(array, &options)?;
             (Box::new(RawArrayFormatter(formatter)) as _, array.nulls().cloned())
         }
         d => match d.is_temporal() {
+            true if is_interval(d) && options.interval_encoding != IntervalEncoding::Display => {
+                let encoder = make_interval_encoder(array, options.interval_encoding);
+                (encoder, array.nulls().cloned())
+            }
             true => {
                 // Note: the implementation of Encoder for ArrayFormatter assumes it does not produce
                 // characters that would need to be escaped within a JSON string, e.g. `'"'`.
@@ -369,6 +429,54 @@ impl<O: OffsetSizeTrait> Encoder for ListEncoder<'_, O> {
     }
 }
 
+struct ListViewEncoder<'a, O: OffsetSizeTrait> {
+    offsets: ScalarBuffer<O>,
+    sizes: ScalarBuffer<O>,
+    nulls: Option<NullBuffer>,
+    encoder: Box<dyn Encoder + 'a>,
+}
+
+impl<'a, O: OffsetSizeTrait> ListViewEncoder<'a, O> {
+    fn try_new(
+        array: &'a GenericListViewArray<O>,
+        options: &EncoderOptions,
+    ) -> Result<Self, ArrowError> {
+        let (encoder, nulls) = make_encoder_impl(array.values().as_ref(), options)?;
+        Ok(Self {
+            offsets: array.offsets().clone(),
+            sizes: array.sizes().clone(),
+            encoder,
+            nulls,
+        })
+    }
+}
+
+impl<O: OffsetSizeTrait> Encoder for ListViewEncoder<'_, O> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let start = self.offsets[idx].as_usize();
+        let end = start + self.sizes[idx].as_usize();
+        out.push(b'[');
+        match self.nulls.as_ref() {
+            Some(n) => (start..end).for_each(|idx| {
+                if idx != start {
+                    out.push(b',')
+                }
+                match n.is_null(idx) {
+                    true => out.extend_from_slice(b"null"),
+                    false => self.encoder.encode(idx, out),
+                }
+            }),
+            None => (start..end).for_each(|idx| {
+                if idx != start {
+                    out.push(b',')
+                }
+                self.encoder.encode(idx, out);
+            }),
+        }
+        out.push(b']');
+    }
+}
+
 struct FixedSizeListEncoder<'a> {
     value_length: usize,
     nulls: Option<NullBuffer>,
@@ -545,16 +653,24 @@ impl Encoder for MapEncoder<'_> {
     }
 }
 
-/// New-type wrapper for encoding the binary types in arrow: `Binary`, `LargeBinary`
-/// and `FixedSizeBinary` as hex strings in JSON.
-struct BinaryEncoder<B>(B);
+/// Encoder for the binary types in arrow: `Binary`, `LargeBinary` and `FixedSizeBinary`,
+/// see [`BinaryEncoding`]
+struct BinaryEncoder<B> {
+    array: B,
+    encoding: BinaryEncoding,
+    buffer: String,
+}
 
 impl<'a, B> BinaryEncoder<B>
 where
     B: ArrayAccessor<Item = &'a [u8]>,
 {
-    fn new(array: B) -> Self {
-        Self(array)
+    fn new(array: B, encoding: BinaryEncoding) -> Self {
+        Self {
+            array,
+            encoding,
+            buffer: String::new(),
+        }
     }
 }
 
@@ -563,11 +679,184 @@ where
     B: ArrayAccessor<Item = &'a [u8]>,
 {
     fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
-        out.push(b'"');
-        for byte in self.0.value(idx) {
+        let value = self.array.value(idx);
+        match self.encoding {
+            BinaryEncoding::Hex => {
+                out.push(b'"');
+                for byte in value {
+                    // this write is infallible
+                    write!(out, "{byte:02x}").unwrap();
+                }
+                out.push(b'"');
+            }
+            BinaryEncoding::Base64 => {
+                self.buffer.clear();
+                BASE64_STANDARD.encode_string(value, &mut self.buffer);
+                out.push(b'"');
+                out.extend_from_slice(self.buffer.as_bytes());
+                out.push(b'"');
+            }
+            BinaryEncoding::Array => {
+                out.push(b'[');
+                for (i, byte) in value.iter().enumerate() {
+                    if i != 0 {
+                        out.push(b',');
+                    }
+                    // this write is infallible
+                    write!(out, "{byte}").unwrap();
+                }
+                out.push(b']');
+            }
+        }
+    }
+}
+
+fn is_interval(d: &DataType) -> bool {
+    matches!(d, DataType::Interval(_) | DataType::Duration(_))
+}
+
+/// Returns an [`Encoder`] for an `Interval` or `Duration` array, see [`IntervalEncoding`]
+fn make_interval_encoder<'a>(
+    array: &'a dyn Array,
+    encoding: IntervalEncoding,
+) -> Box<dyn Encoder + 'a> {
+    let object = encoding == IntervalEncoding::Object;
+    match array.data_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => Box::new(IntervalEncoder {
+            values: array
+                .as_primitive::<IntervalYearMonthType>()
+                .values()
+                .clone(),
+            encode: match object {
+                true => |v, out| write!(out, "{{\"months\":{v}}}").unwrap(),
+                false => |v, out| write_iso8601(v, 0, 0, out),
+            },
+        }),
+        DataType::Interval(IntervalUnit::DayTime) => Box::new(IntervalEncoder {
+            values: array.as_primitive::<IntervalDayTimeType>().values().clone(),
+            encode: match object {
+                true => |v: IntervalDayTime, out| {
+                    let (d, ms) = (v.days, v.milliseconds);
+                    write!(out, "{{\"days\":{d},\"milliseconds\":{ms}}}").unwrap()
+                },
+                false => |v: IntervalDayTime, out| {
+                    write_iso8601(0, v.days, v.milliseconds as i128 * 1_000_000, out)
+                },
+            },
+        }),
+        DataType::Interval(IntervalUnit::MonthDayNano) => Box::new(IntervalEncoder {
+            values: array
+                .as_primitive::<IntervalMonthDayNanoType>()
+                .values()
+                .clone(),
+            encode: match object {
+                true => |v: IntervalMonthDayNano, out| {
+                    let (m, d, ns) = (v.months, v.days, v.nanoseconds);
+                    write!(out, "{{\"months\":{m},\"days\":{d},\"nanoseconds\":{ns}}}").unwrap()
+                },
+                false => |v: IntervalMonthDayNano, out| {
+                    write_iso8601(v.months, v.days, v.nanoseconds as i128, out)
+                },
+            },
+        }),
+        DataType::Duration(unit) => {
+            let (values, unit, nanos_per_unit) = match unit {
+                TimeUnit::Second => {
+                    let values = array.as_primitive::<DurationSecondType>().values();
+                    (values, "seconds", 1_000_000_000)
+                }
+                TimeUnit::Millisecond => {
+                    let values = array.as_primitive::<DurationMillisecondType>().values();
+                    (values, "milliseconds", 1_000_000)
+                }
+                TimeUnit::Microsecond => {
+                    let values = array.as_primitive::<DurationMicrosecondType>().values();
+                    (values, "microseconds", 1_000)
+                }
+                TimeUnit::Nanosecond => {
+                    let values = array.as_primitive::<DurationNanosecondType>().values();
+                    (values, "nanoseconds", 1)
+                }
+            };
+            Box::new(DurationEncoder {
+                values: values.clone(),
+                unit,
+                nanos_per_unit,
+                object,
+            })
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Encodes interval values with a function, see [`make_interval_encoder`]
+struct IntervalEncoder<N: ArrowNativeType> {
+    values: ScalarBuffer<N>,
+    encode: fn(N, &mut Vec<u8>),
+}
+
+impl<N: ArrowNativeType> Encoder for IntervalEncoder<N> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        (self.encode)(self.values[idx], out)
+    }
+}
+
+/// Encodes duration values as an object, e.g. `{"seconds":120}`, or ISO 8601 string
+struct DurationEncoder {
+    values: ScalarBuffer<i64>,
+    unit: &'static str,
+    nanos_per_unit: i128,
+    object: bool,
+}
+
+impl Encoder for DurationEncoder {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let value = self.values[idx];
+        match self.object {
             // this write is infallible
-            write!(out, "{byte:02x}").unwrap();
+            true => write!(out, "{{\"{}\":{}}}", self.unit, value).unwrap(),
+            false => write_iso8601(0, 0, value as i128 * self.nanos_per_unit, out),
         }
-        out.push(b'"');
     }
 }
+
+/// Writes an ISO 8601 duration string, e.g. `"P1Y2M3DT4H5M6.5S"`, to `out`
+fn write_iso8601(months: i32, days: i32, nanos: i128, out: &mut Vec<u8>) {
+    const NANOS_PER_SECOND: i128 = 1_000_000_000;
+    const NANOS_PER_MINUTE: i128 = 60 * NANOS_PER_SECOND;
+    const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MINUTE;
+
+    // The writes below are infallible
+    out.extend_from_slice(b"\"P");
+    let (years, months) = (months / 12, months % 12);
+    for (value, unit) in [(years, 'Y'), (months, 'M'), (days, 'D')] {
+        if value != 0 {
+            write!(out, "{value}{unit}").unwrap();
+        }
+    }
+
+    if nanos != 0 {
+        out.push(b'T');
+        let (hours, nanos) = (nanos / NANOS_PER_HOUR, nanos % NANOS_PER_HOUR);
+        let (minutes, nanos) = (nanos / NANOS_PER_MINUTE, nanos % NANOS_PER_MINUTE);
+        let (seconds, nanos) = (nanos / NANOS_PER_SECOND, nanos % NANOS_PER_SECOND);
+        for (value, unit) in [(hours, 'H'), (minutes, 'M')] {
+            if value != 0 {
+                write!(out, "{value}{unit}").unwrap();
+            }
+        }
+        match nanos {
+            0 if seconds == 0 => {}
+            0 => write!(out, "{seconds}S").unwrap(),
+            _ => {
+                let sign = if nanos < 0 { "-" } else { "" };
+                let fraction = format!("{:09}", nanos.unsigned_abs());
+                let fraction = fraction.trim_end_matches('0');
+                write!(out, "{sign}{}.{fraction}S", seconds.unsigned_abs()).unwrap()
+            }
+        }
+    } else if months == 0 && years == 0 && days == 0 {
+        out.extend_from_slice(b"0D");
+    }
+    out.push(b'"');
+}
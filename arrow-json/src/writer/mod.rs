@@ -112,6 +112,7 @@ use arrow_array::*;
 use arrow_schema::*;
 
 use encoder::{make_encoder, EncoderOptions};
+pub use encoder::{BinaryEncoding, IntervalEncoding};
 
 /// This trait defines how to format a sequence of JSON objects to a
 /// byte stream.
@@ -253,6 +254,58 @@ impl WriterBuilder {
         self
     }
 
+    /// Returns `true` if this writer is configured to keep keys with null values
+    /// within nested structs and maps, see [`Self::with_nested_explicit_nulls`]
+    pub fn nested_explicit_nulls(&self) -> bool {
+        self.0
+            .nested_explicit_nulls
+            .unwrap_or(self.0.explicit_nulls)
+    }
+
+    /// Set whether to keep keys with null values within nested structs and maps,
+    /// i.e. those within a struct, list or map column, or to omit writing them.
+    ///
+    /// For example, keeping top-level nulls but skipping nested nulls:
+    ///
+    /// ```json
+    /// {"foo":{"a":1},"bar":null}
+    /// {"foo":{},"bar":[{"b":2},{}]}
+    /// ```
+    ///
+    /// Note: null elements of lists are always written, as they cannot be omitted
+    ///
+    /// Default is the value of [`Self::with_explicit_nulls`].
+    pub fn with_nested_explicit_nulls(mut self, nested_explicit_nulls: bool) -> Self {
+        self.0.nested_explicit_nulls = Some(nested_explicit_nulls);
+        self
+    }
+
+    /// Returns how binary values are encoded, see [`Self::with_binary_encoding`]
+    pub fn binary_encoding(&self) -> BinaryEncoding {
+        self.0.binary_encoding
+    }
+
+    /// Set how `Binary`, `LargeBinary` and `FixedSizeBinary` values are encoded.
+    ///
+    /// Default is [`BinaryEncoding::Hex`].
+    pub fn with_binary_encoding(mut self, binary_encoding: BinaryEncoding) -> Self {
+        self.0.binary_encoding = binary_encoding;
+        self
+    }
+
+    /// Returns how interval and duration values are encoded, see [`Self::with_interval_encoding`]
+    pub fn interval_encoding(&self) -> IntervalEncoding {
+        self.0.interval_encoding
+    }
+
+    /// Set how `Interval` and `Duration` values are encoded.
+    ///
+    /// Default is [`IntervalEncoding::Display`].
+    pub fn with_interval_encoding(mut self, interval_encoding: IntervalEncoding) -> Self {
+        self.0.interval_encoding = interval_encoding;
+        self
+    }
+
     /// Create a new `Writer` with specified `JsonFormat` and builder options.
     pub fn build<W, F>(self, writer: W) -> Writer<W, F>
     where
@@ -410,7 +463,7 @@ mod tests {
 
     use arrow_array::builder::*;
     use arrow_array::types::*;
-    use arrow_buffer::{i256, Buffer, NullBuffer, OffsetBuffer, ToByteSlice};
+    use arrow_buffer::{i256, Buffer, NullBuffer, OffsetBuffer, ScalarBuffer, ToByteSlice};
     use arrow_data::ArrayData;
 
     use crate::reader::*;
@@ -1850,6 +1903,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_writer_list_view() {
+        // Views are out of order and overlapping
+        let field = FieldRef::new(Field::new_list_field(DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+        let offsets = ScalarBuffer::from(vec![2, 0, 1, 0, 3]);
+        let sizes = ScalarBuffer::from(vec![2, 3, 0, 0, 1]);
+        let nulls = NullBuffer::from(vec![true, true, true, false, true]);
+        let list = ListViewArray::new(field.clone(), offsets, sizes, values.clone(), Some(nulls));
+
+        let offsets = ScalarBuffer::from(vec![0, 1, 2, 3, 0]);
+        let sizes = ScalarBuffer::from(vec![1, 1, 1, 1, 4]);
+        let large = LargeListViewArray::new(field, offsets, sizes, values, None);
+
+        let batch = RecordBatch::try_from_iter([
+            ("list", Arc::new(list) as ArrayRef),
+            ("large", Arc::new(large) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = LineDelimitedWriter::new(&mut buf);
+        writer.write_batches(&[&batch]).unwrap();
+        writer.finish().unwrap();
+
+        assert_json_eq(
+            &buf,
+            r#"{"list":[3,4],"large":[1]}
+{"list":[1,null,3],"large":[null]}
+{"list":[],"large":[3]}
+{"large":[4]}
+{"list":[4],"large":[1,null,3,4]}
+"#,
+        );
+    }
+
     #[test]
     fn test_writer_null_dict() {
         let keys = Int32Array::from_iter(vec![Some(0), None, Some(1)]);
@@ -1953,4 +2042,155 @@ mod tests {
 "#,
         );
     }
+
+    fn write_with(batch: &RecordBatch, builder: WriterBuilder) -> String {
+        let mut buf = Vec::new();
+        let mut writer = builder.build::<_, LineDelimited>(&mut buf);
+        writer.write(batch).unwrap();
+        writer.finish().unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_writer_binary_encoding() {
+        let binary = BinaryArray::from_iter([Some(b"\x0a\xff".as_slice()), None, Some(b"")]);
+        let fixed = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            [Some(b"ab".as_slice()), None, Some(b"\x00\x01")].into_iter(),
+            2,
+        )
+        .unwrap();
+        let batch = RecordBatch::try_from_iter([
+            ("b", Arc::new(binary) as ArrayRef),
+            ("f", Arc::new(fixed) as ArrayRef),
+        ])
+        .unwrap();
+
+        let out = write_with(&batch, WriterBuilder::new());
+        assert_eq!(
+            out,
+            "{\"b\":\"0aff\",\"f\":\"6162\"}\n{}\n{\"b\":\"\",\"f\":\"0001\"}\n"
+        );
+
+        let builder = WriterBuilder::new().with_binary_encoding(BinaryEncoding::Base64);
+        assert_eq!(builder.binary_encoding(), BinaryEncoding::Base64);
+        let out = write_with(&batch, builder);
+        assert_eq!(
+            out,
+            "{\"b\":\"Cv8=\",\"f\":\"YWI=\"}\n{}\n{\"b\":\"\",\"f\":\"AAE=\"}\n"
+        );
+
+        let builder = WriterBuilder::new().with_binary_encoding(BinaryEncoding::Array);
+        let out = write_with(&batch, builder);
+        assert_eq!(
+            out,
+            "{\"b\":[10,255],\"f\":[97,98]}\n{}\n{\"b\":[],\"f\":[0,1]}\n"
+        );
+    }
+
+    #[test]
+    fn test_writer_interval_encoding() {
+        let year_month = IntervalYearMonthArray::from(vec![Some(14), Some(-1), Some(0), None]);
+        let day_time = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTime::new(1, 1500)),
+            Some(IntervalDayTime::new(0, -500)),
+            Some(IntervalDayTime::new(0, 0)),
+            None,
+        ]);
+        let month_day_nano = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, 2, 3_723_000_000_001)),
+            Some(IntervalMonthDayNano::new(-13, 0, -60_000_000_000)),
+            Some(IntervalMonthDayNano::new(0, 3, 0)),
+            None,
+        ]);
+        let duration = DurationMillisecondArray::from(vec![Some(120), Some(-1), Some(0), None]);
+        let batch = RecordBatch::try_from_iter([
+            ("ym", Arc::new(year_month) as ArrayRef),
+            ("dt", Arc::new(day_time) as ArrayRef),
+            ("mdn", Arc::new(month_day_nano) as ArrayRef),
+            ("d", Arc::new(duration) as ArrayRef),
+        ])
+        .unwrap();
+
+        let out = write_with(&batch, WriterBuilder::new());
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            r#"{"ym":"1 years 2 mons","dt":"1 days 1.500 secs","mdn":"1 mons 2 days 1 hours 2 mins 3.000000001 secs","d":"PT0.12S"}"#
+        );
+
+        let builder = WriterBuilder::new().with_interval_encoding(IntervalEncoding::Iso8601);
+        assert_eq!(builder.interval_encoding(), IntervalEncoding::Iso8601);
+        let out = write_with(&batch, builder);
+        assert_eq!(
+            out,
+            r#"{"ym":"P1Y2M","dt":"P1DT1.5S","mdn":"P1M2DT1H2M3.000000001S","d":"PT0.12S"}
+{"ym":"P-1M","dt":"PT-0.5S","mdn":"P-1Y-1MT-1M","d":"PT-0.001S"}
+{"ym":"P0D","dt":"P0D","mdn":"P3D","d":"P0D"}
+{}
+"#
+        );
+
+        let builder = WriterBuilder::new().with_interval_encoding(IntervalEncoding::Object);
+        let out = write_with(&batch, builder);
+        assert_eq!(
+            out,
+            r#"{"ym":{"months":14},"dt":{"days":1,"milliseconds":1500},"mdn":{"months":1,"days":2,"nanoseconds":3723000000001},"d":{"milliseconds":120}}
+{"ym":{"months":-1},"dt":{"days":0,"milliseconds":-500},"mdn":{"months":-13,"days":0,"nanoseconds":-60000000000},"d":{"milliseconds":-1}}
+{"ym":{"months":0},"dt":{"days":0,"milliseconds":0},"mdn":{"months":0,"days":3,"nanoseconds":0},"d":{"milliseconds":0}}
+{}
+"#
+        );
+    }
+
+    #[test]
+    fn test_writer_nested_explicit_nulls() {
+        let child = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef,
+        )]);
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(child.data_type().clone(), true)),
+            OffsetBuffer::from_lengths([2, 0]),
+            Arc::new(child.clone()),
+            None,
+        );
+        let b = Int32Array::from(vec![None, Some(2)]);
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(child) as ArrayRef),
+            ("l", Arc::new(list) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let builder = WriterBuilder::new().with_explicit_nulls(true);
+        assert!(builder.nested_explicit_nulls());
+        let out = write_with(&batch, builder);
+        assert_eq!(
+            out,
+            r#"{"s":{"a":1},"l":[{"a":1},{"a":null}],"b":null}
+{"s":{"a":null},"l":[],"b":2}
+"#
+        );
+
+        let builder = WriterBuilder::new()
+            .with_explicit_nulls(true)
+            .with_nested_explicit_nulls(false);
+        assert!(!builder.nested_explicit_nulls());
+        let out = write_with(&batch, builder);
+        assert_eq!(
+            out,
+            r#"{"s":{"a":1},"l":[{"a":1},{}],"b":null}
+{"s":{},"l":[],"b":2}
+"#
+        );
+
+        let builder = WriterBuilder::new().with_nested_explicit_nulls(true);
+        let out = write_with(&batch, builder);
+        assert_eq!(
+            out,
+            r#"{"s":{"a":1},"l":[{"a":1},{"a":null}]}
+{"s":{"a":null},"l":[],"b":2}
+"#
+        );
+    }
 }
@@ -0,0 +1,220 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the `clamp` kernel, limiting values to a range
+
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use std::sync::Arc;
+
+/// Limits each value of `array` to the range `min..=max`
+///
+/// Values less than `min` are replaced by `min`, and values greater than `max` by `max`.
+/// A null `min` or `max` leaves the corresponding side of the range unbounded, and null
+/// values of `array` remain null. `min` and `max` may be scalars or arrays of the same
+/// length as `array`.
+///
+/// Supports numeric, including decimal, and temporal arrays, other than intervals, with
+/// `min` and `max` of the same type as `array`. The result has the same type as `array`.
+/// Floating point values are ordered as by [`f64::total_cmp`], and so a NaN value is
+/// clamped to `max`.
+///
+/// Returns an error if `min` is greater than `max` for any row.
+///
+/// ```
+/// # use arrow_array::{Int32Array, Scalar};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::clamp::clamp;
+/// let array = Int32Array::from(vec![Some(-5), Some(3), None, Some(12)]);
+/// let min = Scalar::new(Int32Array::from(vec![0]));
+/// let max = Int32Array::from(vec![Some(10), Some(2), Some(10), None]);
+/// let result = clamp(&array, &min, &max).unwrap();
+/// assert_eq!(
+///     result.as_primitive::<Int32Type>(),
+///     &Int32Array::from(vec![Some(0), Some(2), None, Some(12)])
+/// );
+/// ```
+pub fn clamp(array: &dyn Array, min: &dyn Datum, max: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let data_type = array.data_type();
+    let supported = data_type.is_numeric() || data_type.is_temporal();
+    if !supported || matches!(data_type, DataType::Interval(_)) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "clamp not supported for {data_type}"
+        )));
+    }
+
+    let (min, min_s) = min.get();
+    let (max, max_s) = max.get();
+    for (bound, is_scalar) in [(min, min_s), (max, max_s)] {
+        if bound.data_type() != data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "clamp bounds must have the same type as the array, got {} vs {data_type}",
+                bound.data_type()
+            )));
+        }
+        if !is_scalar && bound.len() != array.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot perform clamp on arrays of different length, got {} vs {}",
+                array.len(),
+                bound.len()
+            )));
+        }
+    }
+
+    downcast_primitive_array!(
+        array => clamp_impl(array, (min, min_s), (max, max_s)),
+        _ => unreachable!()
+    )
+}
+
+fn clamp_impl<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    min: (&dyn Array, bool),
+    max: (&dyn Array, bool),
+) -> Result<ArrayRef, ArrowError> {
+    let min = (min.0.as_primitive::<T>(), min.1);
+    let max = (max.0.as_primitive::<T>(), max.1);
+    let bound = |(bound, is_scalar): (&PrimitiveArray<T>, bool), idx: usize| {
+        let idx = if is_scalar { 0 } else { idx };
+        bound.is_valid(idx).then(|| bound.value(idx))
+    };
+
+    let values = array
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(idx, v)| match (bound(min, idx), bound(max, idx)) {
+            (Some(min), Some(max)) if min.is_gt(max) => Err(ArrowError::InvalidArgumentError(
+                format!("clamp minimum {min:?} is greater than maximum {max:?}"),
+            )),
+            (min, max) => {
+                let v = min.filter(|min| v.is_lt(*min)).unwrap_or(*v);
+                Ok(max.filter(|max| v.is_gt(*max)).unwrap_or(v))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let array = PrimitiveArray::<T>::new(values.into(), array.nulls().cloned())
+        .with_data_type(array.data_type().clone());
+    Ok(Arc::new(array))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::*;
+
+    #[test]
+    fn test_clamp() {
+        let array = Int64Array::from(vec![
+            Some(i64::MIN),
+            Some(-1),
+            Some(5),
+            None,
+            Some(i64::MAX),
+        ]);
+        let min = Scalar::new(Int64Array::from(vec![0]));
+        let max = Scalar::new(Int64Array::from(vec![10]));
+        let result = clamp(&array, &min, &max).unwrap();
+        let expected = Int64Array::from(vec![Some(0), Some(0), Some(5), None, Some(10)]);
+        assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+
+        let min = Int64Array::from(vec![Some(1), None, Some(6), Some(0), None]);
+        let max = Int64Array::from(vec![Some(2), Some(-2), None, Some(0), None]);
+        let result = clamp(&array, &min, &max).unwrap();
+        let expected = Int64Array::from(vec![Some(1), Some(-2), Some(6), None, Some(i64::MAX)]);
+        assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+
+        let array = UInt8Array::from(vec![1, 2, 3]).slice(1, 2);
+        let min = Scalar::new(UInt8Array::from(vec![3]));
+        let result = clamp(&array, &min, &min).unwrap();
+        assert_eq!(
+            result.as_primitive::<UInt8Type>(),
+            &UInt8Array::from(vec![3, 3])
+        );
+    }
+
+    #[test]
+    fn test_clamp_types() {
+        let array = Float64Array::from(vec![f64::NEG_INFINITY, -0.0, 0.5, f64::NAN]);
+        let min = Scalar::new(Float64Array::from(vec![0.0]));
+        let max = Scalar::new(Float64Array::from(vec![1.0]));
+        let result = clamp(&array, &min, &max).unwrap();
+        let expected = Float64Array::from(vec![0.0, 0.0, 0.5, 1.0]);
+        assert_eq!(result.as_primitive::<Float64Type>(), &expected);
+
+        let array = Decimal128Array::from(vec![-150, 250, 999])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let min = Decimal128Array::from(vec![0])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let max = Decimal128Array::from(vec![200])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let result = clamp(&array, &Scalar::new(min), &Scalar::new(max)).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(5, 2));
+        let expected = Decimal128Array::from(vec![0, 200, 200]);
+        assert_eq!(
+            result.as_primitive::<Decimal128Type>().values(),
+            expected.values()
+        );
+
+        let array = TimestampSecondArray::from(vec![0, 3_600, 7_200]).with_timezone("+01:00");
+        let min = TimestampSecondArray::from(vec![1_000]).with_timezone("+01:00");
+        let max = TimestampSecondArray::from(vec![3_000]).with_timezone("+01:00");
+        let result = clamp(&array, &Scalar::new(min), &Scalar::new(max)).unwrap();
+        let expected =
+            TimestampSecondArray::from(vec![1_000, 3_000, 3_000]).with_timezone("+01:00");
+        assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+    }
+
+    #[test]
+    fn test_clamp_invalid() {
+        let array = Int32Array::from(vec![1, 2]);
+        let min = Int32Array::from(vec![0, 3]);
+        let max = Scalar::new(Int32Array::from(vec![2]));
+        let err = clamp(&array, &min, &max).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: clamp minimum 3 is greater than maximum 2"
+        );
+
+        let min = Int32Array::from(vec![0]);
+        let err = clamp(&array, &min, &max).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot perform clamp on arrays of different length, got 2 vs 1"
+        );
+
+        let min = Scalar::new(Int64Array::from(vec![0]));
+        let err = clamp(&array, &min, &max).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: clamp bounds must have the same type as the array, got Int64 vs Int32"
+        );
+
+        let array = StringArray::from(vec!["a"]);
+        let err = clamp(&array, &array, &array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: clamp not supported for Utf8"
+        );
+    }
+}
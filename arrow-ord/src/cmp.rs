@@ -23,15 +23,17 @@
 //! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
 //!
 
+use crate::ord::make_comparator;
 use arrow_array::cast::AsArray;
 use arrow_array::types::{ByteArrayType, ByteViewType};
 use arrow_array::{
-    downcast_primitive_array, AnyDictionaryArray, Array, ArrowNativeTypeOp, BooleanArray, Datum,
-    FixedSizeBinaryArray, GenericByteArray, GenericByteViewArray,
+    downcast_primitive_array, AnyDictionaryArray, Array, ArrowNativeTypeOp, ArrowPrimitiveType,
+    BooleanArray, Datum, FixedSizeBinaryArray, GenericByteArray, GenericByteViewArray,
+    PrimitiveArray,
 };
 use arrow_buffer::bit_util::ceil;
 use arrow_buffer::{BooleanBuffer, MutableBuffer, NullBuffer};
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, SortOptions};
 use arrow_select::take::take;
 use std::ops::Not;
 
@@ -176,8 +178,9 @@ pub fn gt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowErro
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are supported as the null semantics are
+/// well-defined, with null values nested within them considered equal to each other.
+/// These are compared using [`crate::ord::make_comparator`]
 pub fn distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::Distinct, lhs, rhs)
 }
@@ -194,12 +197,219 @@ pub fn distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowE
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are supported as the null semantics are
+/// well-defined, with null values nested within them considered equal to each other.
+/// These are compared using [`crate::ord::make_comparator`]
 pub fn not_distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::NotDistinct, lhs, rhs)
 }
 
+/// Options for [`between`]
+///
+/// The default includes both bounds, as in SQL `BETWEEN`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BetweenOptions {
+    /// Whether values equal to the lower bound are between the bounds
+    pub low_inclusive: bool,
+    /// Whether values equal to the upper bound are between the bounds
+    pub high_inclusive: bool,
+}
+
+impl Default for BetweenOptions {
+    fn default() -> Self {
+        Self {
+            low_inclusive: true,
+            high_inclusive: true,
+        }
+    }
+}
+
+/// Perform `low <= array <= high` operation on three [`Datum`], with the bounds
+/// included or excluded according to `options`
+///
+/// This is equivalent to combining [`gt_eq`] and [`lt_eq`] (or [`gt`] and [`lt`]) with
+/// a logical AND, but compares each value against both bounds in a single pass, without
+/// materializing the intermediate results.
+///
+/// Comparing null values in any of the arguments will yield a null in the corresponding
+/// slot of the resulting [`BooleanArray`]. A `low` bound greater than `high` yields `false`.
+///
+/// Values are ordered as in [`lt`], in particular floating point values are ordered by
+/// their totalOrder. Nested types are not supported.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array, Scalar};
+/// # use arrow_ord::cmp::{between, BetweenOptions};
+/// let array = Int32Array::from(vec![Some(1), Some(2), None, Some(4), Some(5)]);
+/// let low = Scalar::new(Int32Array::from(vec![2]));
+/// let high = Scalar::new(Int32Array::from(vec![4]));
+///
+/// let result = between(&array, &low, &high, BetweenOptions::default()).unwrap();
+/// let expected = BooleanArray::from(vec![Some(false), Some(true), None, Some(true), Some(false)]);
+/// assert_eq!(result, expected);
+///
+/// let options = BetweenOptions { low_inclusive: true, high_inclusive: false };
+/// let result = between(&array, &low, &high, options).unwrap();
+/// let expected = BooleanArray::from(vec![Some(false), Some(true), None, Some(false), Some(false)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn between(
+    array: &dyn Datum,
+    low: &dyn Datum,
+    high: &dyn Datum,
+    options: BetweenOptions,
+) -> Result<BooleanArray, ArrowError> {
+    use arrow_schema::DataType::*;
+    let operands = [array, low, high].map(|d| {
+        let (array, scalar) = d.get();
+        let dictionary = array.as_any_dictionary_opt();
+        BetweenOperand {
+            array,
+            values: dictionary.map(|x| x.values().as_ref()).unwrap_or(array),
+            dictionary,
+            scalar,
+        }
+    });
+
+    let mut len = None;
+    for o in operands.iter().filter(|o| !o.scalar) {
+        match len {
+            Some(len) if len != o.array.len() => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot compare arrays of different lengths, got {len} vs {}",
+                    o.array.len()
+                )))
+            }
+            _ => len = Some(o.array.len()),
+        }
+    }
+    let len = len.unwrap_or(1);
+
+    let [a_t, l_t, h_t] = operands.map(|o| o.values.data_type());
+    if a_t.is_nested() || l_t.is_nested() || h_t.is_nested() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Nested comparison: {a_t} BETWEEN {l_t} AND {h_t} (hint: use make_comparator instead)"
+        )));
+    } else if a_t != l_t || a_t != h_t {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid comparison operation: {a_t} BETWEEN {l_t} AND {h_t}"
+        )));
+    }
+
+    let mut nulls = None;
+    for o in &operands {
+        let o_nulls = o.array.logical_nulls();
+        match o.scalar {
+            true if o_nulls.as_ref().map(|n| n.is_null(0)).unwrap_or_default() => {
+                return Ok(BooleanArray::new_null(len))
+            }
+            true => {}
+            false => nulls = NullBuffer::union(nulls.as_ref(), o_nulls.as_ref()),
+        }
+    }
+
+    let a = operands[0].values;
+    let values = downcast_primitive_array! {
+        a => between_primitive(a, &operands, len, options),
+        Boolean => apply_between(operands.map(|o| o.values.as_boolean()), &operands, len, options),
+        Utf8 => apply_between(operands.map(|o| o.values.as_string::<i32>()), &operands, len, options),
+        Utf8View => apply_between(operands.map(|o| o.values.as_string_view()), &operands, len, options),
+        LargeUtf8 => apply_between(operands.map(|o| o.values.as_string::<i64>()), &operands, len, options),
+        Binary => apply_between(operands.map(|o| o.values.as_binary::<i32>()), &operands, len, options),
+        BinaryView => apply_between(operands.map(|o| o.values.as_binary_view()), &operands, len, options),
+        LargeBinary => apply_between(operands.map(|o| o.values.as_binary::<i64>()), &operands, len, options),
+        FixedSizeBinary(_) => apply_between(operands.map(|o| o.values.as_fixed_size_binary()), &operands, len, options),
+        Null => BooleanBuffer::new_unset(len),
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "between not supported for {t}"
+            )))
+        }
+    };
+    Ok(BooleanArray::new(values, nulls))
+}
+
+/// An argument of [`between`]
+#[derive(Clone, Copy)]
+struct BetweenOperand<'a> {
+    array: &'a dyn Array,
+    /// The values of `array`, or of its dictionary
+    values: &'a dyn Array,
+    dictionary: Option<&'a dyn AnyDictionaryArray>,
+    scalar: bool,
+}
+
+impl BetweenOperand<'_> {
+    /// Returns the [`BetweenIndices`] of the values of this operand
+    ///
+    /// Panics if this is a dictionary with no values
+    fn indices(&self) -> BetweenIndices {
+        match (self.scalar, self.dictionary) {
+            (true, Some(d)) => BetweenIndices::Scalar(d.normalized_keys()[0]),
+            (true, None) => BetweenIndices::Scalar(0),
+            (false, Some(d)) => BetweenIndices::Keys(d.normalized_keys()),
+            (false, None) => BetweenIndices::Identity,
+        }
+    }
+}
+
+/// Maps the rows of a [`BetweenOperand`] to the index of their value
+enum BetweenIndices {
+    Scalar(usize),
+    Keys(Vec<usize>),
+    Identity,
+}
+
+impl BetweenIndices {
+    #[inline]
+    fn get(&self, idx: usize) -> usize {
+        match self {
+            Self::Scalar(v) => *v,
+            Self::Keys(keys) => keys[idx],
+            Self::Identity => idx,
+        }
+    }
+}
+
+fn between_primitive<T: ArrowPrimitiveType>(
+    a: &PrimitiveArray<T>,
+    operands: &[BetweenOperand<'_>; 3],
+    len: usize,
+    options: BetweenOptions,
+) -> BooleanBuffer {
+    let [_, l, h] = operands.map(|o| o.values.as_primitive::<T>().values().as_ref());
+    apply_between([a.values().as_ref(), l, h], operands, len, options)
+}
+
+/// Evaluates [`between`] on the values of `operands`, ignoring nulls
+fn apply_between<T: ArrayOrd>(
+    values: [T; 3],
+    operands: &[BetweenOperand<'_>; 3],
+    len: usize,
+    options: BetweenOptions,
+) -> BooleanBuffer {
+    if values.iter().any(|v| v.len() == 0) {
+        return BooleanBuffer::new_unset(len); // Handle empty dictionaries
+    }
+
+    let [a_i, l_i, h_i] = operands.map(|o| o.indices());
+    let [a, l, h] = values;
+    collect_bool(len, false, |idx| {
+        let v = a.value(a_i.get(idx));
+        let low = l.value(l_i.get(idx));
+        let high = h.value(h_i.get(idx));
+        let above = match options.low_inclusive {
+            true => !T::is_lt(v, low),
+            false => T::is_lt(low, v),
+        };
+        let below = match options.high_inclusive {
+            true => !T::is_lt(high, v),
+            false => T::is_lt(v, high),
+        };
+        above && below
+    })
+}
+
 /// Perform `op` on the provided `Datum`
 #[inline(never)]
 fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
@@ -221,6 +431,12 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
         false => l_len,
     };
 
+    if matches!(op, Op::Distinct | Op::NotDistinct)
+        && (l.data_type().is_nested() || r.data_type().is_nested())
+    {
+        return distinct_nested(op, l, l_s, r, r_s, len);
+    }
+
     let l_nulls = l.logical_nulls();
     let r_nulls = r.logical_nulls();
 
@@ -325,6 +541,31 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
     })
 }
 
+/// Perform [`Op::Distinct`] or [`Op::NotDistinct`] on nested arrays using [`make_comparator`]
+fn distinct_nested(
+    op: Op,
+    l: &dyn Array,
+    l_s: bool,
+    r: &dyn Array,
+    r_s: bool,
+    len: usize,
+) -> Result<BooleanArray, ArrowError> {
+    let cmp = make_comparator(l, r, SortOptions::default()).map_err(|_| {
+        ArrowError::InvalidArgumentError(format!(
+            "Invalid comparison operation: {} {op} {}",
+            l.data_type(),
+            r.data_type()
+        ))
+    })?;
+    let neg = matches!(op, Op::Distinct);
+    let values = collect_bool(len, neg, |idx| {
+        let l_idx = if l_s { 0 } else { idx };
+        let r_idx = if r_s { 0 } else { idx };
+        cmp(l_idx, r_idx).is_eq()
+    });
+    Ok(BooleanArray::new(values, None))
+}
+
 /// Perform a potentially vectored `op` on the provided `ArrayOrd`
 fn apply<T: ArrayOrd>(
     op: Op,
@@ -834,6 +1075,20 @@ mod tests {
         assert!(!r.value(0))
     }
 
+    #[test]
+    fn test_scalar_from_value() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let r = lt(&a, &Scalar::from(2)).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(true), None, Some(false)]));
+
+        let r = eq(&a, &Scalar::null(&arrow_schema::DataType::Int32)).unwrap();
+        assert_eq!(r, BooleanArray::new_null(3));
+
+        let a = StringArray::from(vec!["a", "b"]);
+        let r = eq(&Scalar::utf8("b"), &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true]));
+    }
+
     #[test]
     fn test_scalar_empty() {
         let a = Int32Array::new_null(0);
@@ -855,4 +1110,221 @@ mod tests {
 
         neq(&col.slice(0, col.len() - 1), &col.slice(1, col.len() - 1)).unwrap();
     }
+
+    #[test]
+    fn test_distinct_nested() {
+        use arrow_array::builder::{Int32Builder, ListBuilder};
+        use arrow_array::ListArray;
+
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.append_value([Some(1), None]);
+        builder.append_value([Some(1), None]);
+        builder.append_null();
+        builder.append_null();
+        builder.append_value([Some(2)]);
+        let a = builder.finish();
+
+        builder.append_value([Some(1), None]);
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_null();
+        builder.append_value([]);
+        builder.append_value([Some(2)]);
+        let b = builder.finish();
+
+        let expected = BooleanArray::from(vec![false, true, false, true, false]);
+        assert_eq!(distinct(&a, &b).unwrap(), expected);
+        assert_eq!(distinct(&b, &a).unwrap(), expected);
+        let expected = BooleanArray::from(vec![true, false, true, false, true]);
+        assert_eq!(not_distinct(&a, &b).unwrap(), expected);
+
+        let scalar = Scalar::new(a.slice(0, 1));
+        let expected = BooleanArray::from(vec![true, true, false, false, false]);
+        assert_eq!(not_distinct(&a, &scalar).unwrap(), expected);
+        assert_eq!(not_distinct(&scalar, &a).unwrap(), expected);
+
+        let null = Scalar::new(ListArray::new_null(
+            Arc::new(arrow_schema::Field::new_list_field(
+                arrow_schema::DataType::Int32,
+                true,
+            )),
+            1,
+        ));
+        let expected = BooleanArray::from(vec![true, true, false, false, true]);
+        assert_eq!(distinct(&a, &null).unwrap(), expected);
+
+        // Other comparisons of nested types are not supported
+        let err = eq(&a, &b).unwrap_err();
+        assert!(err.to_string().contains("Nested comparison"));
+
+        let c = Int32Array::from(vec![1, 2, 3, 4, 5]);
+        let err = distinct(&a, &c).unwrap_err();
+        assert!(err.to_string().contains("IS DISTINCT FROM Int32"));
+    }
+
+    #[test]
+    fn test_between() {
+        let a = Int32Array::from(vec![Some(1), Some(2), Some(3), None, Some(4), Some(5)]);
+        let low = Int32Array::from(vec![Some(0), Some(2), Some(2), Some(0), None, Some(6)]);
+        let high = Scalar::new(Int32Array::from(vec![4]));
+
+        let cases = [
+            (true, true, [true, true, true, false, false, false]),
+            (false, true, [true, false, true, false, false, false]),
+            (true, false, [true, true, true, false, false, false]),
+            (false, false, [true, false, true, false, false, false]),
+        ];
+        for (low_inclusive, high_inclusive, expected) in cases {
+            let options = BetweenOptions {
+                low_inclusive,
+                high_inclusive,
+            };
+            let r = between(&a, &low, &high, options).unwrap();
+            let expected = BooleanArray::new(
+                expected.into_iter().collect(),
+                NullBuffer::union(a.nulls(), low.nulls()),
+            );
+            assert_eq!(r, expected);
+
+            // Equivalent to combining two comparisons
+            let l = match low_inclusive {
+                true => gt_eq(&a, &low).unwrap(),
+                false => gt(&a, &low).unwrap(),
+            };
+            let h = match high_inclusive {
+                true => lt_eq(&a, &high).unwrap(),
+                false => lt(&a, &high).unwrap(),
+            };
+            let l = l.iter().zip(h.iter());
+            let combined: BooleanArray = l.map(|(l, h)| Some(l? && h?)).collect();
+            assert_eq!(r, combined);
+        }
+
+        let options = BetweenOptions {
+            low_inclusive: true,
+            high_inclusive: false,
+        };
+        let r = between(&a, &high, &low, options).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+            None,
+            Some(true),
+        ]);
+        assert_eq!(r, expected);
+
+        let null = Scalar::new(Int32Array::new_null(1));
+        let r = between(&a, &null, &high, options).unwrap();
+        assert_eq!(r, BooleanArray::new_null(6));
+
+        let r = between(&Scalar::new(&a.slice(2, 1)), &low, &high, options).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+        ]);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    fn test_between_types() {
+        use arrow_array::{Float64Array, NullArray, StringViewArray};
+
+        let a = Float64Array::from(vec![-0.0, 0.0, f64::NAN, 1.0, f64::INFINITY]);
+        let low = Scalar::new(Float64Array::from(vec![0.0]));
+        let high = Scalar::new(Float64Array::from(vec![f64::INFINITY]));
+        let r = between(&a, &low, &high, BetweenOptions::default()).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, false, true, true]));
+
+        let a = StringViewArray::from(vec![
+            "apple",
+            "banana",
+            "a string that is too long to be inlined",
+            "cherry",
+        ]);
+        let low = Scalar::new(StringViewArray::from(vec!["a"]));
+        let high = Scalar::new(StringViewArray::from(vec!["b"]));
+        let options = BetweenOptions {
+            low_inclusive: false,
+            high_inclusive: true,
+        };
+        let r = between(&a, &low, &high, options).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true, false]));
+
+        let a = BooleanArray::from(vec![Some(false), None, Some(true)]);
+        let low = Scalar::new(BooleanArray::from(vec![true]));
+        let high = Scalar::new(BooleanArray::from(vec![true]));
+        let r = between(&a, &low, &high, BetweenOptions::default()).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), None, Some(true)]));
+
+        let a = NullArray::new(2);
+        let r = between(&a, &a, &a, BetweenOptions::default()).unwrap();
+        assert_eq!(r, BooleanArray::new_null(2));
+    }
+
+    #[test]
+    fn test_between_dictionary() {
+        use arrow_array::types::Int32Type;
+
+        let a: DictionaryArray<Int32Type> = vec![Some("b"), Some("d"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let low: DictionaryArray<Int32Type> = vec!["a", "c", "a", "c", "b"].into_iter().collect();
+        let high = Scalar::new(StringArray::from(vec!["c"]));
+        let r = between(&a, &low, &high, BetweenOptions::default()).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+        ]);
+        assert_eq!(r, expected);
+
+        let keys = Int32Array::from(vec![Some(1), None]);
+        let values = Arc::new(StringArray::from(vec![Some("a"), None]));
+        let null = Scalar::new(DictionaryArray::new(keys.slice(1, 1), values.clone()));
+        let r = between(&a, &null, &high, BetweenOptions::default()).unwrap();
+        assert_eq!(r, BooleanArray::new_null(5));
+
+        let low = DictionaryArray::new(keys, values);
+        let a = StringArray::from(vec!["b", "b"]);
+        let r = between(&a, &low, &high, BetweenOptions::default()).unwrap();
+        assert_eq!(r, BooleanArray::new_null(2));
+
+        let empty =
+            DictionaryArray::new(Int32Array::new_null(2), Arc::new(StringArray::new_null(0)));
+        let r = between(&a, &empty, &high, BetweenOptions::default()).unwrap();
+        assert_eq!(r, BooleanArray::new_null(2));
+    }
+
+    #[test]
+    fn test_between_invalid() {
+        use arrow_array::builder::{Int32Builder, ListBuilder};
+
+        let a = Int32Array::from(vec![1, 2]);
+        let low = Int32Array::from(vec![1]);
+        let err = between(&a, &low, &a, BetweenOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare arrays of different lengths, got 2 vs 1"
+        );
+
+        let low = Scalar::new(arrow_array::Int64Array::from(vec![1]));
+        let err = between(&a, &low, &a, BetweenOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid comparison operation: Int32 BETWEEN Int64 AND Int32"
+        );
+
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.append_value([Some(1)]);
+        let list = builder.finish();
+        let err = between(&list, &list, &list, BetweenOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("Nested comparison"), "{err}");
+    }
 }
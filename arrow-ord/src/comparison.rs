@@ -3163,6 +3163,29 @@ mod tests {
         assert_eq!(crate::cmp::gt_eq(&array1, &array2).unwrap(), expected);
     }
 
+    #[test]
+    fn test_decimal32_decimal64() {
+        let a = Decimal32Array::from(vec![Some(1), Some(-2), None, Some(5)]);
+        let b = Decimal32Array::new_scalar(1);
+        let e = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+        let r = crate::cmp::eq(&a, &b).unwrap();
+        assert_eq!(e, r);
+
+        let e = BooleanArray::from(vec![Some(false), Some(true), None, Some(false)]);
+        let r = crate::cmp::lt(&a, &b).unwrap();
+        assert_eq!(e, r);
+
+        let a = Decimal64Array::from_iter_values([1, 2, 4, i64::MIN]);
+        let b = Decimal64Array::from_iter_values([7, -3, 4, i64::MAX]);
+        let e = BooleanArray::from(vec![true, false, true, true]);
+        let r = crate::cmp::lt_eq(&a, &b).unwrap();
+        assert_eq!(e, r);
+
+        let e = BooleanArray::from(vec![false, true, false, false]);
+        let r = crate::cmp::gt(&a, &b).unwrap();
+        assert_eq!(e, r);
+    }
+
     #[test]
     fn test_decimal128() {
         let a = Decimal128Array::from_iter_values([1, 2, 4, 5]);
@@ -0,0 +1,384 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Set membership kernels, equivalent to SQL `value IN (a, b, c)`
+
+use ahash::RandomState;
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, NullBuffer, ToByteSlice};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+/// A set of values, prepared for repeated membership tests with [`in_list`]
+///
+/// Building an [`InList`] hashes each value of the list once, allowing it to be
+/// probed with any number of arrays in time proportional to their length,
+/// independent of the length of the list
+#[derive(Debug)]
+pub struct InList {
+    data_type: DataType,
+    set: Box<dyn ValueSet>,
+    contains_null: bool,
+}
+
+impl InList {
+    /// Creates a new [`InList`] from the values of `list`
+    ///
+    /// `list` may be a dictionary array, in which case its logical values are used
+    ///
+    /// Returns an error if `list` is of an unsupported type
+    pub fn try_new(list: &dyn Array) -> Result<Self, ArrowError> {
+        let contains_null = list.logical_null_count() != 0;
+        let list = match list.as_any_dictionary_opt() {
+            Some(d) => take(d.values().as_ref(), d.keys(), None)?,
+            None => make_array(list.to_data()),
+        };
+        let set = make_set(list.as_ref())?;
+        Ok(Self {
+            data_type: list.data_type().clone(),
+            set,
+            contains_null,
+        })
+    }
+
+    /// Returns the data type of the values of this list
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns true if this list contains a null value
+    pub fn contains_null(&self) -> bool {
+        self.contains_null
+    }
+}
+
+/// Returns whether each value of `values` is contained within `list`
+///
+/// This follows the semantics of SQL `IN`, in particular for each row:
+///
+/// * If the value is null, the result is null
+/// * If the value is contained in `list`, the result is `true`
+/// * If `list` contains a null value, the result is null
+/// * Otherwise the result is `false`
+///
+/// `values` may be a dictionary array, in which case each dictionary value is only
+/// probed once. Floating point values are compared using their total order, as in
+/// [`crate::cmp::eq`]
+///
+/// Returns an error if the value type of `values` does not match that of `list`
+///
+/// ```
+/// # use arrow_array::{BooleanArray, StringArray};
+/// # use arrow_ord::in_list::{in_list, InList};
+/// let list = InList::try_new(&StringArray::from(vec!["a", "c"])).unwrap();
+///
+/// let values = StringArray::from(vec![Some("a"), Some("b"), None, Some("c")]);
+/// let result = in_list(&values, &list).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]));
+/// ```
+pub fn in_list(values: &dyn Array, list: &InList) -> Result<BooleanArray, ArrowError> {
+    let nulls = values.logical_nulls();
+    let dictionary = values.as_any_dictionary_opt();
+    let flat = dictionary.map(|d| d.values().as_ref()).unwrap_or(values);
+
+    if flat.data_type() != &list.data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot test membership of {} in list of {}",
+            flat.data_type(),
+            list.data_type
+        )));
+    }
+
+    let contains = list.set.contains(flat);
+    let contains = match dictionary {
+        Some(d) => {
+            let contains = BooleanArray::new(contains, None);
+            take(&contains, d.keys(), None)?
+                .as_boolean()
+                .values()
+                .clone()
+        }
+        None => contains,
+    };
+
+    let nulls = match list.contains_null {
+        // Values not contained in a list containing null are null
+        true => {
+            let found = NullBuffer::new(contains.clone());
+            NullBuffer::union(nulls.as_ref(), Some(&found))
+        }
+        false => nulls,
+    };
+    Ok(BooleanArray::new(contains, nulls))
+}
+
+/// A hash set of the non-null values of a list
+trait ValueSet: std::fmt::Debug + Send + Sync {
+    /// Returns whether each value of `values` is contained in this set, ignoring nulls
+    ///
+    /// `values` is guaranteed to be of the same type as the list
+    fn contains(&self, values: &dyn Array) -> BooleanBuffer;
+}
+
+fn make_set(list: &dyn Array) -> Result<Box<dyn ValueSet>, ArrowError> {
+    fn bytes<'a>(iter: impl Iterator<Item = Option<&'a [u8]>>) -> Box<dyn ValueSet> {
+        Box::new(BytesSet(iter.flatten().map(Box::from).collect()))
+    }
+
+    Ok(downcast_primitive_array!(
+        list => Box::new(PrimitiveSet::new(list)),
+        DataType::Null => Box::new(BooleanSet([false; 2])),
+        DataType::Boolean => {
+            let mut set = [false; 2];
+            list.as_boolean().iter().flatten().for_each(|v| set[v as usize] = true);
+            Box::new(BooleanSet(set))
+        }
+        DataType::Utf8 => bytes(list.as_string::<i32>().iter().map(|v| v.map(str::as_bytes))),
+        DataType::LargeUtf8 => bytes(list.as_string::<i64>().iter().map(|v| v.map(str::as_bytes))),
+        DataType::Utf8View => bytes(list.as_string_view().iter().map(|v| v.map(str::as_bytes))),
+        DataType::Binary => bytes(list.as_binary::<i32>().iter()),
+        DataType::LargeBinary => bytes(list.as_binary::<i64>().iter()),
+        DataType::BinaryView => bytes(list.as_binary_view().iter()),
+        DataType::FixedSizeBinary(_) => bytes(list.as_fixed_size_binary().iter()),
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "in_list not supported for {d}"
+            )))
+        }
+    ))
+}
+
+/// A native value, hashed and compared by its total order
+#[derive(Debug, Clone, Copy)]
+struct NativeKey<T>(T);
+
+impl<T: ArrowNativeTypeOp> PartialEq for NativeKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.is_eq(other.0)
+    }
+}
+
+impl<T: ArrowNativeTypeOp> Eq for NativeKey<T> {}
+
+impl<T: ArrowNativeType> Hash for NativeKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_byte_slice().hash(state)
+    }
+}
+
+struct PrimitiveSet<T: ArrowPrimitiveType>(HashSet<NativeKey<T::Native>, RandomState>);
+
+impl<T: ArrowPrimitiveType> std::fmt::Debug for PrimitiveSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PrimitiveSet").field(&self.0).finish()
+    }
+}
+
+impl<T: ArrowPrimitiveType> PrimitiveSet<T> {
+    fn new(list: &PrimitiveArray<T>) -> Self {
+        Self(list.iter().flatten().map(NativeKey).collect())
+    }
+}
+
+impl<T: ArrowPrimitiveType> ValueSet for PrimitiveSet<T> {
+    fn contains(&self, values: &dyn Array) -> BooleanBuffer {
+        let values = values.as_primitive::<T>().values();
+        BooleanBuffer::collect_bool(values.len(), |i| self.0.contains(&NativeKey(values[i])))
+    }
+}
+
+#[derive(Debug)]
+struct BooleanSet([bool; 2]);
+
+impl ValueSet for BooleanSet {
+    fn contains(&self, values: &dyn Array) -> BooleanBuffer {
+        match values.data_type() {
+            DataType::Boolean => {
+                let values = values.as_boolean().values();
+                BooleanBuffer::collect_bool(values.len(), |i| self.0[values.value(i) as usize])
+            }
+            _ => BooleanBuffer::new_unset(values.len()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BytesSet(HashSet<Box<[u8]>, RandomState>);
+
+impl BytesSet {
+    fn probe<'a>(&self, len: usize, value: impl Fn(usize) -> &'a [u8]) -> BooleanBuffer {
+        BooleanBuffer::collect_bool(len, |i| self.0.contains(value(i)))
+    }
+}
+
+impl ValueSet for BytesSet {
+    fn contains(&self, values: &dyn Array) -> BooleanBuffer {
+        let len = values.len();
+        match values.data_type() {
+            DataType::Utf8 => {
+                let values = values.as_string::<i32>();
+                self.probe(len, |i| values.value(i).as_bytes())
+            }
+            DataType::LargeUtf8 => {
+                let values = values.as_string::<i64>();
+                self.probe(len, |i| values.value(i).as_bytes())
+            }
+            DataType::Utf8View => {
+                let values = values.as_string_view();
+                self.probe(len, |i| values.value(i).as_bytes())
+            }
+            DataType::Binary => {
+                let values = values.as_binary::<i32>();
+                self.probe(len, |i| values.value(i))
+            }
+            DataType::LargeBinary => {
+                let values = values.as_binary::<i64>();
+                self.probe(len, |i| values.value(i))
+            }
+            DataType::BinaryView => {
+                let values = values.as_binary_view();
+                self.probe(len, |i| values.value(i))
+            }
+            DataType::FixedSizeBinary(_) => {
+                let values = values.as_fixed_size_binary();
+                self.probe(len, |i| values.value(i))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::{Int32Type, Int8Type};
+    use arrow_buffer::i256;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_in_list_primitive() {
+        let list = InList::try_new(&Int32Array::from(vec![1, 3, 5])).unwrap();
+        assert!(!list.contains_null());
+        assert_eq!(list.data_type(), &DataType::Int32);
+
+        let values = Int32Array::from(vec![Some(1), Some(2), None, Some(5)]);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+
+        // Sliced values
+        let expected = BooleanArray::from(vec![Some(false), None]);
+        assert_eq!(in_list(&values.slice(1, 2), &list).unwrap(), expected);
+
+        let list = InList::try_new(&Float64Array::from(vec![0.0, f64::NAN])).unwrap();
+        let values = Float64Array::from(vec![0.0, -0.0, f64::NAN, 1.0]);
+        let expected = BooleanArray::from(vec![true, false, true, false]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_in_list_decimal() {
+        let list = Decimal128Array::from(vec![100, 250])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let list = InList::try_new(&list).unwrap();
+        let values = Decimal128Array::from(vec![250, 251])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let expected = BooleanArray::from(vec![true, false]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+
+        // Different scale is a different type
+        let values = Decimal128Array::from(vec![250])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+        let err = in_list(&values, &list).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot test membership of Decimal128(10, 3) in list of Decimal128(10, 2)"
+        );
+
+        let list = Decimal256Array::from(vec![i256::from(5), i256::MAX]);
+        let list = InList::try_new(&list).unwrap();
+        let values = Decimal256Array::from(vec![i256::MAX, i256::from(4)]);
+        let expected = BooleanArray::from(vec![true, false]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_in_list_strings() {
+        let list = StringArray::from(vec![Some("foo"), None, Some("a long string value")]);
+        let list = InList::try_new(&list).unwrap();
+        assert!(list.contains_null());
+
+        let values = StringArray::from(vec![Some("foo"), Some("bar"), None]);
+        let expected = BooleanArray::from(vec![Some(true), None, None]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+
+        let list = StringViewArray::from(vec!["foo", "a long string value"]);
+        let list = InList::try_new(&list).unwrap();
+        let values = StringViewArray::from(vec!["a long string value", "foo", "bar"]);
+        let expected = BooleanArray::from(vec![true, true, false]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+
+        let list = InList::try_new(&BinaryArray::from(vec![b"a".as_slice()])).unwrap();
+        let values = BinaryArray::from(vec![b"a".as_slice(), b"b".as_slice()]);
+        let expected = BooleanArray::from(vec![true, false]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+
+        let values = LargeStringArray::from(vec!["a"]);
+        let err = in_list(&values, &list).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot test membership of LargeUtf8 in list of Binary"
+        );
+    }
+
+    #[test]
+    fn test_in_list_dictionary() {
+        let list: DictionaryArray<Int8Type> = vec!["b", "c", "b"].into_iter().collect();
+        let list = InList::try_new(&list).unwrap();
+        assert_eq!(list.data_type(), &DataType::Utf8);
+
+        let dict_values = StringArray::from(vec![Some("a"), Some("b"), None]);
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(2), Some(1)]);
+        let values = DictionaryArray::<Int32Type>::new(keys, Arc::new(dict_values));
+        let expected = BooleanArray::from(vec![Some(false), Some(true), None, None, Some(true)]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+
+        let values = StringArray::from(vec!["c", "d"]);
+        let expected = BooleanArray::from(vec![true, false]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_in_list_boolean() {
+        let list = InList::try_new(&BooleanArray::from(vec![true])).unwrap();
+        let values = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None]);
+        assert_eq!(in_list(&values, &list).unwrap(), expected);
+
+        let err = InList::try_new(&ListArray::new_null(
+            Arc::new(arrow_schema::Field::new_list_field(DataType::Int32, true)),
+            1,
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("in_list not supported for List"));
+    }
+}
@@ -0,0 +1,345 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Comparison, sorting and min/max of interval arrays by their normalized length
+//!
+//! The other kernels of this crate order intervals by their binary representation,
+//! comparing each field in turn, so that `1 month` is greater than `100 days`, see
+//! [`IntervalMonthDayNano`](arrow_buffer::IntervalMonthDayNano).
+//!
+//! The kernels in this module instead order intervals by their approximate length,
+//! treating a month as 30 days and a day as 24 hours, and order intervals of the
+//! same length by their binary representation. This is a total order consistent with
+//! equality, in which `1 month` is less than `100 days` and greater than `30 days`.
+//!
+//! ```
+//! # use arrow_array::IntervalMonthDayNanoArray;
+//! # use arrow_buffer::IntervalMonthDayNano;
+//! # use arrow_ord::interval::{max_normalized, sort_to_indices_normalized};
+//! let array = IntervalMonthDayNanoArray::from(vec![
+//!     IntervalMonthDayNano::new(0, 100, 0),
+//!     IntervalMonthDayNano::new(1, 0, 0),
+//!     IntervalMonthDayNano::new(0, 30, 0),
+//! ]);
+//! let indices = sort_to_indices_normalized(&array, None, None).unwrap();
+//! assert_eq!(indices.values(), &[2, 1, 0]);
+//! assert_eq!(max_normalized(&array), Some(IntervalMonthDayNano::new(0, 100, 0)));
+//! ```
+
+use crate::ord::{compare, DynComparator};
+use crate::sort::partial_sort;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType};
+use arrow_array::{Array, ArrowPrimitiveType, Datum, Int8Array, PrimitiveArray, UInt32Array};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType, IntervalUnit, SortOptions};
+use std::cmp::Ordering;
+
+/// An interval [`ArrowPrimitiveType`] that can be ordered by its normalized length
+pub trait NormalizedIntervalType: ArrowPrimitiveType {
+    /// Compares `a` and `b` by their normalized length, see the [module docs](self)
+    fn cmp_normalized(a: &Self::Native, b: &Self::Native) -> Ordering;
+}
+
+impl NormalizedIntervalType for IntervalYearMonthType {
+    fn cmp_normalized(a: &i32, b: &i32) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl NormalizedIntervalType for IntervalDayTimeType {
+    fn cmp_normalized(a: &Self::Native, b: &Self::Native) -> Ordering {
+        a.cmp_normalized(b)
+    }
+}
+
+impl NormalizedIntervalType for IntervalMonthDayNanoType {
+    fn cmp_normalized(a: &Self::Native, b: &Self::Native) -> Ordering {
+        a.cmp_normalized(b)
+    }
+}
+
+/// Returns a [`DynComparator`] of the values of the interval arrays `left` and `right`
+/// by their normalized length, ordering nulls according to `opts`
+///
+/// Returns an error if `left` and `right` are not intervals of the same [`IntervalUnit`]
+pub fn make_normalized_comparator(
+    left: &dyn Array,
+    right: &dyn Array,
+    opts: SortOptions,
+) -> Result<DynComparator, ArrowError> {
+    use DataType::Interval;
+    use IntervalUnit::*;
+    match (left.data_type(), right.data_type()) {
+        (Interval(YearMonth), Interval(YearMonth)) => {
+            Ok(comparator::<IntervalYearMonthType>(left, right, opts))
+        }
+        (Interval(DayTime), Interval(DayTime)) => {
+            Ok(comparator::<IntervalDayTimeType>(left, right, opts))
+        }
+        (Interval(MonthDayNano), Interval(MonthDayNano)) => {
+            Ok(comparator::<IntervalMonthDayNanoType>(left, right, opts))
+        }
+        (l, r) => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare {l} with {r} by normalized interval length"
+        ))),
+    }
+}
+
+fn comparator<T: NormalizedIntervalType>(
+    left: &dyn Array,
+    right: &dyn Array,
+    opts: SortOptions,
+) -> DynComparator {
+    let left = left.as_primitive::<T>();
+    let right = right.as_primitive::<T>();
+    let l_values = left.values().clone();
+    let r_values = right.values().clone();
+    compare(left, right, opts, move |i, j| {
+        T::cmp_normalized(&l_values[i], &r_values[j])
+    })
+}
+
+/// Compares the intervals of `lhs` and `rhs` by their normalized length, returning
+/// `-1`, `0` or `1` if the value of `lhs` is less than, equal to or greater than
+/// that of `rhs`, or null if either is null
+///
+/// ```
+/// # use arrow_array::{Int8Array, IntervalDayTimeArray, Scalar};
+/// # use arrow_buffer::IntervalDayTime;
+/// # use arrow_ord::interval::cmp_normalized;
+/// let array = IntervalDayTimeArray::from(vec![
+///     Some(IntervalDayTime::new(0, 90_000_000)),
+///     Some(IntervalDayTime::new(1, 0)),
+///     None,
+/// ]);
+/// let day = Scalar::new(IntervalDayTimeArray::from(vec![IntervalDayTime::new(1, 0)]));
+/// let result = cmp_normalized(&array, &day).unwrap();
+/// assert_eq!(result, Int8Array::from(vec![Some(1), Some(0), None]));
+/// ```
+pub fn cmp_normalized(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<Int8Array, ArrowError> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+
+    let l_len = l.len();
+    let r_len = r.len();
+    if l_len != r_len && !l_s && !r_s {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare arrays of different lengths, got {l_len} vs {r_len}"
+        )));
+    }
+    let len = match l_s {
+        true => r_len,
+        false => l_len,
+    };
+
+    let cmp = make_normalized_comparator(l, r, SortOptions::default())?;
+    let values = (0..len).map(|i| {
+        let l_idx = if l_s { 0 } else { i };
+        let r_idx = if r_s { 0 } else { i };
+        cmp(l_idx, r_idx) as i8
+    });
+
+    let nulls = |array: &dyn Array, scalar: bool| match scalar {
+        true => array.is_null(0).then(|| NullBuffer::new_null(len)),
+        false => array.logical_nulls(),
+    };
+    let nulls = NullBuffer::union(nulls(l, l_s).as_ref(), nulls(r, r_s).as_ref());
+    Ok(Int8Array::new(values.collect(), nulls))
+}
+
+/// Sorts the interval array `values` by their normalized length, returning the
+/// sorted indices
+///
+/// Equal values are ordered by their index. If `limit` is specified, only the
+/// first `limit` indices are returned.
+///
+/// See [`sort_to_indices`](crate::sort::sort_to_indices) to sort by binary representation
+pub fn sort_to_indices_normalized(
+    values: &dyn Array,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+) -> Result<UInt32Array, ArrowError> {
+    let cmp = make_normalized_comparator(values, values, options.unwrap_or_default())?;
+    let len = values.len();
+    let limit = limit.unwrap_or(len).min(len);
+
+    let mut indices: Vec<u32> = (0..len as u32).collect();
+    partial_sort(&mut indices, limit, |a, b| {
+        cmp(*a as usize, *b as usize).then(a.cmp(b))
+    });
+    indices.truncate(limit);
+    Ok(UInt32Array::from(indices))
+}
+
+/// Returns the shortest interval in `array` by normalized length, ignoring nulls
+pub fn min_normalized<T: NormalizedIntervalType>(array: &PrimitiveArray<T>) -> Option<T::Native> {
+    array
+        .iter()
+        .flatten()
+        .reduce(|a, b| match T::cmp_normalized(&b, &a) {
+            Ordering::Less => b,
+            _ => a,
+        })
+}
+
+/// Returns the longest interval in `array` by normalized length, ignoring nulls
+pub fn max_normalized<T: NormalizedIntervalType>(array: &PrimitiveArray<T>) -> Option<T::Native> {
+    array
+        .iter()
+        .flatten()
+        .reduce(|a, b| match T::cmp_normalized(&b, &a) {
+            Ordering::Greater => b,
+            _ => a,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{
+        DictionaryArray, IntervalDayTimeArray, IntervalMonthDayNanoArray, IntervalYearMonthArray,
+        Scalar,
+    };
+    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
+
+    #[test]
+    fn test_cmp_normalized() {
+        let month = IntervalMonthDayNano::new(1, 0, 0);
+        let l = IntervalMonthDayNanoArray::from(vec![
+            Some(month),
+            Some(month),
+            Some(month),
+            Some(IntervalMonthDayNano::new(0, 1, -1)),
+            None,
+            Some(IntervalMonthDayNano::new(-1, 31, 0)),
+        ]);
+        let r = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(0, 100, 0)),
+            Some(IntervalMonthDayNano::new(0, 30, 0)),
+            Some(month),
+            Some(IntervalMonthDayNano::new(0, 0, 86_400_000_000_000)),
+            Some(month),
+            None,
+        ]);
+        let result = cmp_normalized(&l, &r).unwrap();
+        let expected = Int8Array::from(vec![Some(-1), Some(1), Some(0), Some(-1), None, None]);
+        assert_eq!(result, expected);
+
+        let result = cmp_normalized(&r, &l).unwrap();
+        let expected = Int8Array::from(vec![Some(1), Some(-1), Some(0), Some(1), None, None]);
+        assert_eq!(result, expected);
+
+        // Scalars
+        let scalar = Scalar::new(IntervalMonthDayNanoArray::from(vec![month]));
+        let result = cmp_normalized(&scalar, &r).unwrap();
+        let expected = Int8Array::from(vec![Some(-1), Some(1), Some(0), Some(1), Some(0), None]);
+        assert_eq!(result, expected);
+
+        let null = Scalar::new(IntervalMonthDayNanoArray::new_null(1));
+        let result = cmp_normalized(&l, &null).unwrap();
+        assert_eq!(result, Int8Array::new_null(6));
+
+        // Errors
+        let err = cmp_normalized(&l, &r.slice(0, 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare arrays of different lengths, got 6 vs 2"
+        );
+        let year_month = IntervalYearMonthArray::from(vec![1; 6]);
+        let err = cmp_normalized(&l, &year_month).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare Interval(MonthDayNano) with Interval(YearMonth) by normalized interval length"
+        );
+        let dictionary = DictionaryArray::new(Int8Array::from(vec![0; 6]), std::sync::Arc::new(l));
+        assert!(cmp_normalized(&dictionary, &r).is_err());
+    }
+
+    #[test]
+    fn test_sort_normalized() {
+        let array = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTime::new(1, 0)),
+            None,
+            Some(IntervalDayTime::new(0, 90_000_000)),
+            Some(IntervalDayTime::new(0, 86_400_000)),
+            Some(IntervalDayTime::new(-1, 0)),
+            Some(IntervalDayTime::new(1, 0)),
+        ]);
+
+        let indices = sort_to_indices_normalized(&array, None, None).unwrap();
+        assert_eq!(indices.values(), &[1, 4, 3, 0, 5, 2]);
+
+        let options = SortOptions::default().desc().nulls_last();
+        let indices = sort_to_indices_normalized(&array, Some(options), None).unwrap();
+        assert_eq!(indices.values(), &[2, 0, 5, 3, 4, 1]);
+
+        let indices = sort_to_indices_normalized(&array, Some(options), Some(2)).unwrap();
+        assert_eq!(indices.values(), &[2, 0]);
+
+        let indices = sort_to_indices_normalized(&array, None, Some(10)).unwrap();
+        assert_eq!(indices.len(), 6);
+
+        let array = IntervalYearMonthArray::from(vec![Some(13), None, Some(-1), Some(12)]);
+        let indices = sort_to_indices_normalized(&array, None, None).unwrap();
+        assert_eq!(indices.values(), &[1, 2, 3, 0]);
+
+        let err = sort_to_indices_normalized(&Int8Array::from(vec![1]), None, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare Int8 with Int8 by normalized interval length"
+        );
+    }
+
+    #[test]
+    fn test_min_max_normalized() {
+        let array = IntervalMonthDayNanoArray::from(vec![
+            None,
+            Some(IntervalMonthDayNano::new(1, 0, 0)),
+            Some(IntervalMonthDayNano::new(0, 45, 0)),
+            Some(IntervalMonthDayNano::new(0, 30, 0)),
+            Some(IntervalMonthDayNano::new(1, -1, 10)),
+        ]);
+        assert_eq!(
+            min_normalized(&array),
+            Some(IntervalMonthDayNano::new(1, -1, 10))
+        );
+        assert_eq!(
+            max_normalized(&array),
+            Some(IntervalMonthDayNano::new(0, 45, 0))
+        );
+
+        let array = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTime::new(1, 0)),
+            Some(IntervalDayTime::new(0, 90_000_000)),
+            Some(IntervalDayTime::new(0, -1)),
+        ]);
+        assert_eq!(min_normalized(&array), Some(IntervalDayTime::new(0, -1)));
+        assert_eq!(
+            max_normalized(&array),
+            Some(IntervalDayTime::new(0, 90_000_000))
+        );
+
+        let array = IntervalYearMonthArray::from(vec![Some(3), None, Some(-2)]);
+        assert_eq!(min_normalized(&array), Some(-2));
+        assert_eq!(max_normalized(&array), Some(3));
+
+        let array = IntervalYearMonthArray::new_null(2);
+        assert_eq!(min_normalized(&array), None);
+        assert_eq!(max_normalized(&array), None);
+    }
+}
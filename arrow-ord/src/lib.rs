@@ -44,10 +44,14 @@
 //!
 
 #![warn(missing_docs)]
+pub mod clamp;
 pub mod cmp;
 #[doc(hidden)]
 pub mod comparison;
+pub mod in_list;
+pub mod interval;
 pub mod ord;
 pub mod partition;
 pub mod rank;
 pub mod sort;
+pub mod top_k;
@@ -36,7 +36,7 @@ fn child_opts(opts: SortOptions) -> SortOptions {
     }
 }
 
-fn compare<A, F>(l: &A, r: &A, opts: SortOptions, cmp: F) -> DynComparator
+pub(crate) fn compare<A, F>(l: &A, r: &A, opts: SortOptions, cmp: F) -> DynComparator
 where
     A: Array + Clone,
     F: Fn(usize, usize) -> Ordering + Send + Sync + 'static,
@@ -202,6 +202,39 @@ fn compare_list<O: OffsetSizeTrait>(
     Ok(f)
 }
 
+fn compare_list_view<O: OffsetSizeTrait>(
+    left: &dyn Array,
+    right: &dyn Array,
+    opts: SortOptions,
+) -> Result<DynComparator, ArrowError> {
+    let left = left.as_list_view::<O>();
+    let right = right.as_list_view::<O>();
+
+    let c_opts = child_opts(opts);
+    let cmp = make_comparator(left.values().as_ref(), right.values().as_ref(), c_opts)?;
+
+    let l_o = left.offsets().clone();
+    let l_s = left.sizes().clone();
+    let r_o = right.offsets().clone();
+    let r_s = right.sizes().clone();
+    let f = compare(left, right, opts, move |i, j| {
+        let l_start = l_o[i].as_usize();
+        let l_end = l_start + l_s[i].as_usize();
+
+        let r_start = r_o[j].as_usize();
+        let r_end = r_start + r_s[j].as_usize();
+
+        for (i, j) in (l_start..l_end).zip(r_start..r_end) {
+            match cmp(i, j) {
+                Ordering::Equal => continue,
+                r => return r,
+            }
+        }
+        (l_end - l_start).cmp(&(r_end - r_start))
+    });
+    Ok(f)
+}
+
 fn compare_fixed_list(
     left: &dyn Array,
     right: &dyn Array,
@@ -373,6 +406,8 @@ pub fn make_comparator(
         },
         (List(_), List(_)) => compare_list::<i32>(left, right, opts),
         (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right, opts),
+        (ListView(_), ListView(_)) => compare_list_view::<i32>(left, right, opts),
+        (LargeListView(_), LargeListView(_)) => compare_list_view::<i64>(left, right, opts),
         (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_list(left, right, opts),
         (Struct(_), Struct(_)) => compare_struct(left, right, opts),
         (Dictionary(l_key, _), Dictionary(r_key, _)) => {
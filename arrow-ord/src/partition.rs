@@ -19,11 +19,16 @@
 
 use std::ops::Range;
 
-use arrow_array::{Array, ArrayRef};
-use arrow_buffer::BooleanBuffer;
-use arrow_schema::ArrowError;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{BinaryViewType, ByteViewType, RunEndIndexType, StringViewType};
+use arrow_array::{
+    downcast_run_array, AnyDictionaryArray, Array, ArrayRef, GenericByteViewArray, RunArray,
+};
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, BooleanBufferBuilder};
+use arrow_schema::{ArrowError, DataType, SortOptions};
 
 use crate::cmp::distinct;
+use crate::ord::make_comparator;
 
 /// A computed set of partitions, see [`partition`]
 #[derive(Debug, Clone)]
@@ -153,12 +158,111 @@ pub fn partition(columns: &[ArrayRef]) -> Result<Partitions, ArrowError> {
 
 /// Returns a mask with bits set whenever the value or nullability changes
 fn find_boundaries(v: &dyn Array) -> Result<BooleanBuffer, ArrowError> {
+    if let Some(d) = v.as_any_dictionary_opt() {
+        return dictionary_boundaries(d);
+    }
+    match v.data_type() {
+        DataType::Utf8View => return Ok(view_boundaries(v.as_byte_view::<StringViewType>())),
+        DataType::BinaryView => return Ok(view_boundaries(v.as_byte_view::<BinaryViewType>())),
+        DataType::RunEndEncoded(_, _) => {
+            return downcast_run_array!(
+                v => run_boundaries(v),
+                d => unreachable!("unexpected run end type {d}")
+            )
+        }
+        _ => {}
+    }
     let slice_len = v.len() - 1;
     let v1 = v.slice(0, slice_len);
     let v2 = v.slice(1, slice_len);
     Ok(distinct(&v1, &v2)?.values().clone())
 }
 
+/// Finds the boundaries of a dictionary array, comparing the keys of consecutive
+/// rows, and only comparing their values where the keys differ
+fn dictionary_boundaries(d: &dyn AnyDictionaryArray) -> Result<BooleanBuffer, ArrowError> {
+    let slice_len = d.len() - 1;
+    let values = d.values().as_ref();
+    if values.is_empty() {
+        // All keys are null
+        return Ok(BooleanBuffer::new_unset(slice_len));
+    }
+
+    let keys = d.keys();
+    let keys_distinct = distinct(&keys.slice(0, slice_len), &keys.slice(1, slice_len))?;
+    let nulls = d.logical_nulls();
+    let normalized = d.normalized_keys();
+    let cmp = make_comparator(values, values, SortOptions::default())?;
+
+    let mut builder = BooleanBufferBuilder::new(slice_len);
+    builder.append_n(slice_len, false);
+    for idx in keys_distinct.values().set_indices() {
+        let is_distinct = match nulls
+            .as_ref()
+            .map(|n| (n.is_valid(idx), n.is_valid(idx + 1)))
+        {
+            Some((true, true)) | None => cmp(normalized[idx], normalized[idx + 1]).is_ne(),
+            Some((l, r)) => l != r,
+        };
+        if is_distinct {
+            builder.set_bit(idx, true);
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Finds the boundaries of a view array, treating rows with identical views as equal
+/// without comparing their data
+fn view_boundaries<T: ByteViewType + ?Sized>(v: &GenericByteViewArray<T>) -> BooleanBuffer {
+    let views = v.views();
+    let is_distinct = |idx: usize| {
+        let (l, r) = (views[idx], views[idx + 1]);
+        if l == r {
+            return false;
+        }
+        // Views of different lengths, or of short values, differ in their data
+        let (l_len, r_len) = (l as u32, r as u32);
+        if l_len != r_len || l_len <= 12 {
+            return true;
+        }
+        // SAFETY: idx + 1 < v.len()
+        unsafe { GenericByteViewArray::compare_unchecked(v, idx, v, idx + 1).is_ne() }
+    };
+
+    let slice_len = v.len() - 1;
+    match v.nulls().filter(|n| n.null_count() > 0) {
+        Some(n) => BooleanBuffer::collect_bool(slice_len, |idx| {
+            match (n.is_valid(idx), n.is_valid(idx + 1)) {
+                (true, true) => is_distinct(idx),
+                (l, r) => l != r,
+            }
+        }),
+        None => BooleanBuffer::collect_bool(slice_len, is_distinct),
+    }
+}
+
+/// Finds the boundaries of a run end encoded array, which can only occur at the
+/// end of a run, comparing the values of consecutive runs
+fn run_boundaries<R: RunEndIndexType>(v: &RunArray<R>) -> Result<BooleanBuffer, ArrowError> {
+    let slice_len = v.len() - 1;
+    let start = v.get_start_physical_index();
+    let end = v.get_end_physical_index();
+    let mut builder = BooleanBufferBuilder::new(slice_len);
+    builder.append_n(slice_len, false);
+    if start == end {
+        return Ok(builder.finish());
+    }
+
+    let values = v.values().slice(start, end - start + 1);
+    let run_ends = v.run_ends();
+    for idx in find_boundaries(values.as_ref())?.set_indices() {
+        // The last row of the run
+        let row = run_ends.values()[start + idx].as_usize() - run_ends.offset() - 1;
+        builder.set_bit(row, true);
+    }
+    Ok(builder.finish())
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -298,4 +402,100 @@ mod tests {
             vec![(0..1), (1..2), (2..4), (4..5), (5..7), (7..8), (8..9)],
         );
     }
+
+    #[test]
+    fn test_partition_dictionary() {
+        // Keys 0 and 2 reference equal values, as do keys 3 and 4
+        let values = StringArray::from(vec![Some("a"), Some("b"), Some("a"), None, None]);
+        let keys = Int32Array::from(vec![
+            Some(0),
+            Some(2),
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(3),
+            None,
+            Some(4),
+            Some(0),
+        ]);
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let expected = vec![(0..3), (3..5), (5..8), (8..9)];
+        assert_eq!(
+            partition(&[Arc::new(dict.clone())]).unwrap().ranges(),
+            expected
+        );
+
+        let sliced = dict.slice(2, 5);
+        let expected = vec![(0..1), (1..3), (3..5)];
+        assert_eq!(partition(&[Arc::new(sliced)]).unwrap().ranges(), expected);
+
+        let dict =
+            DictionaryArray::new(Int32Array::new_null(3), Arc::new(StringArray::new_null(0)));
+        assert_eq!(partition(&[Arc::new(dict)]).unwrap().ranges(), vec![(0..3)]);
+    }
+
+    #[test]
+    fn test_partition_view() {
+        let long = "a string that is too long to be inlined";
+        let array = StringViewArray::from(vec![
+            Some("foo"),
+            Some("foo"),
+            None,
+            None,
+            Some(long),
+            Some(long),
+            Some("a string that is too long to be inlined!"),
+        ]);
+        // Equal long values in different buffers
+        let other = StringViewArray::from(vec![long]);
+        let array = arrow_select::concat::concat(&[&array, &other]).unwrap();
+        let expected = vec![(0..2), (2..4), (4..6), (6..7), (7..8)];
+        assert_eq!(
+            partition(std::slice::from_ref(&array)).unwrap().ranges(),
+            expected
+        );
+
+        let array = arrow_select::concat::concat(&[&array.slice(0, 6), &other]).unwrap();
+        let array = array.as_string_view();
+        assert_ne!(array.views()[5], array.views()[6]);
+        let expected = vec![(0..2), (2..4), (4..7)];
+        assert_eq!(
+            partition(&[Arc::new(array.clone())]).unwrap().ranges(),
+            expected
+        );
+
+        let array = BinaryViewArray::from(vec![b"a".as_ref(), b"a", b"b"]);
+        let expected = vec![(0..2), (2..3)];
+        assert_eq!(partition(&[Arc::new(array)]).unwrap().ranges(), expected);
+    }
+
+    #[test]
+    fn test_partition_run_end_encoded() {
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7, 9]);
+        let values = Int64Array::from(vec![Some(1), Some(1), Some(2), None, None]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let expected = vec![(0..3), (3..6), (6..9)];
+        assert_eq!(
+            partition(&[Arc::new(array.clone())]).unwrap().ranges(),
+            expected
+        );
+
+        let sliced = array.slice(1, 4);
+        let expected = vec![(0..2), (2..4)];
+        assert_eq!(partition(&[Arc::new(sliced)]).unwrap().ranges(), expected);
+
+        let sliced = array.slice(3, 3);
+        assert_eq!(
+            partition(&[Arc::new(sliced)]).unwrap().ranges(),
+            vec![(0..3)]
+        );
+
+        // Combined with other columns
+        let other = Arc::new(Int64Array::from(vec![1, 1, 1, 2, 2, 2, 2, 2, 3])) as _;
+        let expected = vec![(0..3), (3..6), (6..8), (8..9)];
+        assert_eq!(
+            partition(&[Arc::new(array), other]).unwrap().ranges(),
+            expected
+        );
+    }
 }
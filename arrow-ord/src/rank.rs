@@ -17,13 +17,48 @@
 
 //! Provides `rank` function to assign a rank to each value in an array
 
+use crate::ord::make_comparator;
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
-use arrow_array::{downcast_primitive_array, Array, ArrowNativeTypeOp, GenericByteArray};
+use arrow_array::{
+    downcast_primitive_array, Array, ArrowNativeTypeOp, Float64Array, GenericByteArray, UInt64Array,
+};
 use arrow_buffer::NullBuffer;
 use arrow_schema::{ArrowError, DataType, SortOptions};
 use std::cmp::Ordering;
 
+/// How equal values are ranked by [`rank_with_options`] and [`percent_rank`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RankTies {
+    /// Equal values are assigned the lowest of their ranks, as in SQL `RANK`
+    #[default]
+    Min,
+    /// Equal values are assigned the highest of their ranks, as in [`rank`]
+    Max,
+}
+
+/// How null values are ranked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NullRanking {
+    /// Nulls are ranked as equal values, ordered according to [`SortOptions::nulls_first`]
+    #[default]
+    Ranked,
+    /// Nulls are assigned a null rank, and are excluded from the ranking of other values
+    Null,
+}
+
+/// Options for the ranking kernels [`rank_with_options`], [`dense_rank`],
+/// [`percent_rank`] and [`row_number`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RankOptions {
+    /// The order in which values are ranked
+    pub sort_options: SortOptions,
+    /// How equal values are ranked
+    pub ties: RankTies,
+    /// How null values are ranked
+    pub nulls: NullRanking,
+}
+
 /// Assigns a rank to each value in `array` based on its position in the sorted order
 ///
 /// Where values are equal, they will be assigned the highest of their ranks,
@@ -49,6 +84,176 @@ pub fn rank(array: &dyn Array, options: Option<SortOptions>) -> Result<Vec<u32>,
     Ok(ranks)
 }
 
+/// Assigns a 1-based rank to each value in `array` based on its position in the sorted order
+///
+/// Unlike [`rank`] this supports any type supported by [`make_comparator`], and equal
+/// values are assigned a rank according to [`RankOptions::ties`]
+///
+/// ```
+/// # use arrow_array::{Int32Array, UInt64Array};
+/// # use arrow_ord::rank::{rank_with_options, NullRanking, RankOptions};
+/// let array = Int32Array::from(vec![Some(10), None, Some(5), Some(10)]);
+/// let ranks = rank_with_options(&array, None).unwrap();
+/// assert_eq!(ranks.values(), &[3, 1, 2, 3]);
+///
+/// let options = RankOptions {
+///     nulls: NullRanking::Null,
+///     ..Default::default()
+/// };
+/// let ranks = rank_with_options(&array, Some(options)).unwrap();
+/// assert_eq!(ranks, UInt64Array::from(vec![Some(2), None, Some(1), Some(2)]));
+/// ```
+pub fn rank_with_options(
+    array: &dyn Array,
+    options: Option<RankOptions>,
+) -> Result<UInt64Array, ArrowError> {
+    let options = options.unwrap_or_default();
+    let (groups, nulls) = peer_groups(array, options)?;
+    let mut out = vec![0; array.len()];
+    for group in groups.iter() {
+        let rank = match options.ties {
+            RankTies::Min => group.start + 1,
+            RankTies::Max => group.end,
+        };
+        group.indices.iter().for_each(|idx| out[*idx] = rank as u64);
+    }
+    Ok(UInt64Array::new(out.into(), nulls))
+}
+
+/// Assigns a 1-based rank to each value in `array` based on its position in the sorted
+/// order, where equal values have the same rank and ranks have no gaps, as in SQL `DENSE_RANK`
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_ord::rank::dense_rank;
+/// let array = StringArray::from(vec!["b", "a", "c", "a", "b"]);
+/// let ranks = dense_rank(&array, None).unwrap();
+/// assert_eq!(ranks.values(), &[2, 1, 3, 1, 2]);
+/// ```
+pub fn dense_rank(
+    array: &dyn Array,
+    options: Option<RankOptions>,
+) -> Result<UInt64Array, ArrowError> {
+    let options = options.unwrap_or_default();
+    let (groups, nulls) = peer_groups(array, options)?;
+    let mut out = vec![0; array.len()];
+    for (rank, group) in groups.iter().enumerate() {
+        group
+            .indices
+            .iter()
+            .for_each(|idx| out[*idx] = rank as u64 + 1);
+    }
+    Ok(UInt64Array::new(out.into(), nulls))
+}
+
+/// Assigns the relative rank of each value in `array`, computed as
+/// `(rank - 1) / (number of ranked values - 1)`, as in SQL `PERCENT_RANK`
+///
+/// The rank of equal values is determined by [`RankOptions::ties`]
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::rank::percent_rank;
+/// let array = Int32Array::from(vec![3, 1, 2, 1, 5]);
+/// let ranks = percent_rank(&array, None).unwrap();
+/// assert_eq!(ranks.values(), &[0.75, 0.0, 0.5, 0.0, 1.0]);
+/// ```
+pub fn percent_rank(
+    array: &dyn Array,
+    options: Option<RankOptions>,
+) -> Result<Float64Array, ArrowError> {
+    let ranks = rank_with_options(array, options)?;
+    let ranked = (ranks.len() - ranks.null_count()) as f64;
+    Ok(ranks.unary(|rank| match ranked > 1. {
+        // Null ranks have a value of 0
+        true => rank.saturating_sub(1) as f64 / (ranked - 1.),
+        false => 0.,
+    }))
+}
+
+/// Assigns a unique 1-based number to each value in `array` based on its position in the
+/// sorted order, where equal values are numbered in the order they appear, as in SQL `ROW_NUMBER`
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::rank::row_number;
+/// let array = Int32Array::from(vec![2, 1, 2, 1]);
+/// let numbers = row_number(&array, None).unwrap();
+/// assert_eq!(numbers.values(), &[3, 1, 4, 2]);
+/// ```
+pub fn row_number(
+    array: &dyn Array,
+    options: Option<RankOptions>,
+) -> Result<UInt64Array, ArrowError> {
+    let options = options.unwrap_or_default();
+    let (groups, nulls) = peer_groups(array, options)?;
+    let mut out = vec![0; array.len()];
+    for group in groups.iter() {
+        for (offset, idx) in group.indices.iter().enumerate() {
+            out[*idx] = (group.start + offset) as u64 + 1;
+        }
+    }
+    Ok(UInt64Array::new(out.into(), nulls))
+}
+
+/// A group of equal values within the sorted order of an array
+struct PeerGroup<'a> {
+    /// The indices of the values in the group, in ascending order
+    indices: &'a [usize],
+    /// The 0-based position of the first value of the group in the sorted order
+    start: usize,
+    /// The 0-based position after the last value of the group in the sorted order
+    end: usize,
+}
+
+/// The indices of the ranked values of an array in sorted order, split into [`PeerGroup`]s
+struct PeerGroups {
+    order: Vec<usize>,
+    boundaries: Vec<usize>,
+}
+
+impl PeerGroups {
+    fn iter(&self) -> impl Iterator<Item = PeerGroup<'_>> {
+        self.boundaries.windows(2).map(|w| PeerGroup {
+            indices: &self.order[w[0]..w[1]],
+            start: w[0],
+            end: w[1],
+        })
+    }
+}
+
+/// Sorts the values of `array` and splits them into groups of equal values
+///
+/// Input that is already sorted is detected and not re-sorted
+fn peer_groups(
+    array: &dyn Array,
+    options: RankOptions,
+) -> Result<(PeerGroups, Option<NullBuffer>), ArrowError> {
+    let cmp = make_comparator(array, array, options.sort_options)?;
+    let (mut order, nulls) = match (options.nulls, array.logical_nulls()) {
+        (NullRanking::Null, Some(n)) if n.null_count() > 0 => {
+            (n.valid_indices().collect(), Some(n))
+        }
+        _ => ((0..array.len()).collect::<Vec<_>>(), None),
+    };
+
+    if !order.windows(2).all(|w| cmp(w[0], w[1]).is_le()) {
+        // A stable sort ensures equal values are in ascending index order
+        order.sort_by(|a, b| cmp(*a, *b));
+    }
+
+    let mut boundaries = vec![0];
+    for i in 1..order.len() {
+        if cmp(order[i - 1], order[i]).is_ne() {
+            boundaries.push(i);
+        }
+    }
+    if !order.is_empty() {
+        boundaries.push(order.len());
+    }
+    Ok((PeerGroups { order, boundaries }, nulls))
+}
+
 #[inline(never)]
 fn primitive_rank<T: ArrowNativeTypeOp>(
     values: &[T],
@@ -188,4 +393,93 @@ mod tests {
         let res = rank(&values, None).unwrap();
         assert_eq!(res, &[3, 1, 4, 3]);
     }
+
+    #[test]
+    fn test_ranking_kernels() {
+        let a = Int32Array::from(vec![Some(3), None, Some(1), Some(3), None, Some(2)]);
+
+        let res = rank_with_options(&a, None).unwrap();
+        assert_eq!(res.values(), &[5, 1, 3, 5, 1, 4]);
+        assert_eq!(res.null_count(), 0);
+
+        let max = RankOptions {
+            ties: RankTies::Max,
+            ..Default::default()
+        };
+        let res = rank_with_options(&a, Some(max)).unwrap();
+        assert_eq!(
+            res.values(),
+            rank(&a, None)
+                .unwrap()
+                .iter()
+                .map(|x| *x as u64)
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+
+        let res = dense_rank(&a, None).unwrap();
+        assert_eq!(res.values(), &[4, 1, 2, 4, 1, 3]);
+
+        let res = row_number(&a, None).unwrap();
+        assert_eq!(res.values(), &[5, 1, 3, 6, 2, 4]);
+
+        let res = percent_rank(&a, None).unwrap();
+        assert_eq!(res.values(), &[0.8, 0.0, 0.4, 0.8, 0.0, 0.6]);
+
+        let options = RankOptions {
+            sort_options: SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+            nulls: NullRanking::Null,
+            ..Default::default()
+        };
+        let res = rank_with_options(&a, Some(options)).unwrap();
+        assert_eq!(
+            res,
+            UInt64Array::from(vec![Some(1), None, Some(4), Some(1), None, Some(3)])
+        );
+
+        let res = dense_rank(&a, Some(options)).unwrap();
+        assert_eq!(
+            res,
+            UInt64Array::from(vec![Some(1), None, Some(3), Some(1), None, Some(2)])
+        );
+
+        let res = row_number(&a, Some(options)).unwrap();
+        assert_eq!(
+            res,
+            UInt64Array::from(vec![Some(1), None, Some(4), Some(2), None, Some(3)])
+        );
+
+        let res = percent_rank(&a, Some(options)).unwrap();
+        assert_eq!(
+            res,
+            Float64Array::from(vec![
+                Some(0.0),
+                None,
+                Some(1.0),
+                Some(0.0),
+                None,
+                Some(2.0 / 3.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_ranking_kernels_sorted_input() {
+        let a = StringArray::from(vec!["a", "a", "b", "c", "c", "c"]);
+        assert_eq!(
+            rank_with_options(&a, None).unwrap().values(),
+            &[1, 1, 3, 4, 4, 4]
+        );
+        assert_eq!(dense_rank(&a, None).unwrap().values(), &[1, 1, 2, 3, 3, 3]);
+        assert_eq!(row_number(&a, None).unwrap().values(), &[1, 2, 3, 4, 5, 6]);
+
+        let a = Int32Array::from(vec![7]);
+        assert_eq!(percent_rank(&a, None).unwrap().values(), &[0.0]);
+
+        let a = Int32Array::from(Vec::<i32>::new());
+        assert!(row_number(&a, None).unwrap().is_empty());
+    }
 }
@@ -25,8 +25,9 @@ use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
 use arrow_buffer::BooleanBufferBuilder;
 use arrow_data::ArrayDataBuilder;
-use arrow_schema::{ArrowError, DataType};
-use arrow_select::take::take;
+use arrow_row::{RowConverter, SortField};
+use arrow_schema::{ArrowError, DataType, Schema};
+use arrow_select::take::{take, take_record_batch};
 use std::cmp::Ordering;
 use std::sync::Arc;
 
@@ -199,6 +200,29 @@ fn can_rank(data_type: &DataType) -> bool {
         )
 }
 
+/// Whether [`make_comparator`] can compare arrays of given data type.
+fn can_compare(data_type: &DataType) -> bool {
+    data_type.is_primitive()
+        || match data_type {
+            DataType::Boolean
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::BinaryView
+            | DataType::FixedSizeBinary(_) => true,
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::ListView(f)
+            | DataType::LargeListView(f)
+            | DataType::FixedSizeList(f, _) => can_compare(f.data_type()),
+            DataType::Struct(fields) => fields.iter().all(|f| can_compare(f.data_type())),
+            DataType::Dictionary(_, values) => can_compare(values.as_ref()),
+            _ => false,
+        }
+}
+
 /// Whether `sort_to_indices` can sort an array of given data type.
 fn can_sort_to_indices(data_type: &DataType) -> bool {
     data_type.is_primitive()
@@ -214,9 +238,12 @@ fn can_sort_to_indices(data_type: &DataType) -> bool {
                 | DataType::FixedSizeBinary(_)
         )
         || match data_type {
-            DataType::List(f) if can_rank(f.data_type()) => true,
-            DataType::LargeList(f) if can_rank(f.data_type()) => true,
-            DataType::FixedSizeList(f, _) if can_rank(f.data_type()) => true,
+            DataType::List(f) if can_compare(f.data_type()) => true,
+            DataType::LargeList(f) if can_compare(f.data_type()) => true,
+            DataType::ListView(f) if can_compare(f.data_type()) => true,
+            DataType::LargeListView(f) if can_compare(f.data_type()) => true,
+            DataType::FixedSizeList(f, _) if can_compare(f.data_type()) => true,
+            DataType::Struct(_) => can_compare(data_type),
             DataType::Dictionary(_, values) if can_rank(values.as_ref()) => true,
             DataType::RunEndEncoded(_, f) if can_sort_to_indices(f.data_type()) => true,
             _ => false,
@@ -246,7 +273,10 @@ pub fn sort_to_indices(
         DataType::FixedSizeBinary(_) => sort_fixed_size_binary(array.as_fixed_size_binary(), v, n, options, limit),
         DataType::List(_) => sort_list(array.as_list::<i32>(), v, n, options, limit)?,
         DataType::LargeList(_) => sort_list(array.as_list::<i64>(), v, n, options, limit)?,
+        DataType::ListView(_) => sort_list_view(array.as_list_view::<i32>(), v, n, options, limit)?,
+        DataType::LargeListView(_) => sort_list_view(array.as_list_view::<i64>(), v, n, options, limit)?,
         DataType::FixedSizeList(_, _) => sort_fixed_size_list(array.as_fixed_size_list(), v, n, options, limit)?,
+        DataType::Struct(_) => sort_by_comparator(array, v, n, options, limit)?,
         DataType::Dictionary(_, _) => downcast_dictionary_array!{
             array => sort_dictionary(array, v, n, options, limit)?,
             _ => unreachable!()
@@ -370,6 +400,9 @@ fn sort_list<O: OffsetSizeTrait>(
     options: SortOptions,
     limit: Option<usize>,
 ) -> Result<UInt32Array, ArrowError> {
+    if !can_rank(array.values().data_type()) {
+        return sort_by_comparator(array, value_indices, null_indices, options, limit);
+    }
     let rank = child_rank(array.values().as_ref(), options)?;
     let offsets = array.value_offsets();
     let mut valids = value_indices
@@ -383,6 +416,30 @@ fn sort_list<O: OffsetSizeTrait>(
     Ok(sort_impl(options, &mut valids, &null_indices, limit, Ord::cmp).into())
 }
 
+fn sort_list_view<O: OffsetSizeTrait>(
+    array: &GenericListViewArray<O>,
+    value_indices: Vec<u32>,
+    null_indices: Vec<u32>,
+    options: SortOptions,
+    limit: Option<usize>,
+) -> Result<UInt32Array, ArrowError> {
+    if !can_rank(array.values().data_type()) {
+        return sort_by_comparator(array, value_indices, null_indices, options, limit);
+    }
+    let rank = child_rank(array.values().as_ref(), options)?;
+    let offsets = array.value_offsets();
+    let sizes = array.value_sizes();
+    let mut valids = value_indices
+        .into_iter()
+        .map(|index| {
+            let start = offsets[index as usize].as_usize();
+            let end = start + sizes[index as usize].as_usize();
+            (index, &rank[start..end])
+        })
+        .collect::<Vec<(u32, &[u32])>>();
+    Ok(sort_impl(options, &mut valids, &null_indices, limit, Ord::cmp).into())
+}
+
 fn sort_fixed_size_list(
     array: &FixedSizeListArray,
     value_indices: Vec<u32>,
@@ -390,6 +447,9 @@ fn sort_fixed_size_list(
     options: SortOptions,
     limit: Option<usize>,
 ) -> Result<UInt32Array, ArrowError> {
+    if !can_rank(array.values().data_type()) {
+        return sort_by_comparator(array, value_indices, null_indices, options, limit);
+    }
     let rank = child_rank(array.values().as_ref(), options)?;
     let size = array.value_length() as usize;
     let mut valids = value_indices
@@ -402,6 +462,30 @@ fn sort_fixed_size_list(
     Ok(sort_impl(options, &mut valids, &null_indices, limit, Ord::cmp).into())
 }
 
+/// Sorts nested arrays, such as [`StructArray`], using [`make_comparator`]
+///
+/// Children are compared lexicographically, with shorter lists ordered before
+/// longer lists with the same prefix, consistent with the row format
+fn sort_by_comparator(
+    array: &dyn Array,
+    value_indices: Vec<u32>,
+    null_indices: Vec<u32>,
+    options: SortOptions,
+    limit: Option<usize>,
+) -> Result<UInt32Array, ArrowError> {
+    // Compare in ascending order as sort_impl handles the direction of the sort
+    let value_options = SortOptions {
+        descending: false,
+        nulls_first: options.nulls_first != options.descending,
+    };
+    let cmp = make_comparator(array, array, value_options)?;
+    let mut valids = value_indices
+        .into_iter()
+        .map(|index| (index, index as usize))
+        .collect::<Vec<(u32, usize)>>();
+    Ok(sort_impl(options, &mut valids, &null_indices, limit, cmp).into())
+}
+
 #[inline(never)]
 fn sort_impl<T: Copy>(
     options: SortOptions,
@@ -744,6 +828,238 @@ pub fn lexsort_to_indices(
     ))
 }
 
+/// A column of a [`RecordBatch`] to be used in [`sort_record_batch`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortColumnSpec {
+    /// The index of the column to sort by
+    pub column: usize,
+    /// Sort options for this column
+    pub options: Option<SortOptions>,
+}
+
+impl SortColumnSpec {
+    /// Creates a new [`SortColumnSpec`] sorting by the column at `column` with default options
+    pub fn new(column: usize) -> Self {
+        Self {
+            column,
+            options: None,
+        }
+    }
+
+    /// Creates a new [`SortColumnSpec`] sorting by the column named `name` in `schema`
+    pub fn try_new_with_name(schema: &Schema, name: &str) -> Result<Self, ArrowError> {
+        Ok(Self::new(schema.index_of(name)?))
+    }
+
+    /// Sets the sort options for this column
+    pub fn with_options(self, options: SortOptions) -> Self {
+        Self {
+            options: Some(options),
+            ..self
+        }
+    }
+}
+
+/// The minimum number of sort columns for which [`sort_record_batch_to_indices`]
+/// always compares rows using the [row format](arrow_row)
+const ROW_FORMAT_MIN_COLUMNS: usize = 3;
+
+/// The number of rows sampled to estimate the cardinality of the leading sort column
+const CARDINALITY_SAMPLE_SIZE: usize = 1024;
+
+/// The leading sort column is considered low cardinality if a sample of its values
+/// contains fewer than `1 / LOW_CARDINALITY_RATIO` distinct values per row
+const LOW_CARDINALITY_RATIO: usize = 8;
+
+/// The row format is not used for sorts with a limit of fewer than
+/// `1 / ROW_FORMAT_MIN_LIMIT_RATIO` of the rows, as converting every row
+/// outweighs the cost of comparing the few rows that are fully sorted
+const ROW_FORMAT_MIN_LIMIT_RATIO: usize = 16;
+
+/// Sort the rows of a [`RecordBatch`] lexicographically by the columns in `columns`
+///
+/// Performs a stable sort, see [`sort_record_batch_to_indices`]
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::sort::{sort_record_batch, SortColumnSpec, SortOptions};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 1, 2]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z", "w"]));
+/// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+///
+/// let columns = [
+///     SortColumnSpec::new(0).with_options(SortOptions::default().desc()),
+///     SortColumnSpec::new(1),
+/// ];
+/// let sorted = sort_record_batch(&batch, &columns).unwrap();
+///
+/// assert_eq!(sorted.column(0).as_primitive::<Int32Type>().values(), &[2, 2, 1, 1]);
+/// let b: Vec<_> = sorted.column(1).as_string::<i32>().iter().flatten().collect();
+/// assert_eq!(b, &["w", "y", "x", "z"]);
+/// ```
+pub fn sort_record_batch(
+    batch: &RecordBatch,
+    columns: &[SortColumnSpec],
+) -> Result<RecordBatch, ArrowError> {
+    sort_record_batch_limit(batch, columns, None)
+}
+
+/// Sort the rows of a [`RecordBatch`] lexicographically by the columns in `columns`,
+/// returning at most the first `limit` rows
+///
+/// Performs a stable sort, see [`sort_record_batch_to_indices_limit`]
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::sort::{sort_record_batch_limit, SortColumnSpec, SortOptions};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(2)]));
+/// let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+///
+/// // Nulls are ordered first by default
+/// let columns = [SortColumnSpec::new(0)];
+/// let sorted = sort_record_batch_limit(&batch, &columns, Some(2)).unwrap();
+/// let a = sorted.column(0).as_primitive::<Int32Type>();
+/// assert_eq!(a, &Int32Array::from(vec![None, Some(1)]));
+///
+/// let columns = [SortColumnSpec::new(0).with_options(SortOptions::default().nulls_last())];
+/// let sorted = sort_record_batch_limit(&batch, &columns, Some(2)).unwrap();
+/// let a = sorted.column(0).as_primitive::<Int32Type>();
+/// assert_eq!(a, &Int32Array::from(vec![1, 2]));
+/// ```
+pub fn sort_record_batch_limit(
+    batch: &RecordBatch,
+    columns: &[SortColumnSpec],
+    limit: Option<usize>,
+) -> Result<RecordBatch, ArrowError> {
+    let indices = sort_record_batch_to_indices_limit(batch, columns, limit)?;
+    take_record_batch(batch, &indices)
+}
+
+/// Sort the rows of a [`RecordBatch`] lexicographically by the columns in `columns`,
+/// returning an unsigned integer (`UInt32Array`) of indices
+///
+/// See [`sort_record_batch_to_indices_limit`]
+pub fn sort_record_batch_to_indices(
+    batch: &RecordBatch,
+    columns: &[SortColumnSpec],
+) -> Result<UInt32Array, ArrowError> {
+    sort_record_batch_to_indices_limit(batch, columns, None)
+}
+
+/// Sort the rows of a [`RecordBatch`] lexicographically by the columns in `columns`,
+/// returning an unsigned integer (`UInt32Array`) of the indices of at most the first
+/// `limit` rows
+///
+/// Performs a stable sort, rows that compare equal retain their relative order.
+/// The [`SortOptions`] of each column, including the ordering of its nulls, are
+/// applied independently.
+///
+/// Rows are compared using the [row format](arrow_row), if it supports the column
+/// types, when sorting by many columns, or by several columns with a low cardinality
+/// leading column, where comparisons frequently fall through to the later columns.
+/// Otherwise, and for a `limit` selecting only a small fraction of the rows, rows are
+/// compared using a [`LexicographicalComparator`]
+pub fn sort_record_batch_to_indices_limit(
+    batch: &RecordBatch,
+    columns: &[SortColumnSpec],
+    limit: Option<usize>,
+) -> Result<UInt32Array, ArrowError> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Sort requires at least one column".to_string(),
+        ));
+    }
+    let sort_columns = columns
+        .iter()
+        .map(|spec| match spec.column < batch.num_columns() {
+            true => Ok(SortColumn {
+                values: batch.column(spec.column).clone(),
+                options: spec.options,
+            }),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "Sort column index {} out of bounds for batch with {} columns",
+                spec.column,
+                batch.num_columns()
+            ))),
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let num_rows = batch.num_rows();
+    let mut indices: Vec<u32> = (0..num_rows as u32).collect();
+    let limit = limit.unwrap_or(num_rows).min(num_rows);
+
+    if use_row_format(&sort_columns, num_rows, limit)? {
+        let fields: Vec<_> = sort_columns
+            .iter()
+            .map(|c| {
+                let options = c.options.unwrap_or_default();
+                SortField::new_with_options(c.values.data_type().clone(), options)
+            })
+            .collect();
+        if RowConverter::supports_fields(&fields) {
+            let converter = RowConverter::new(fields)?;
+            let values: Vec<_> = sort_columns.into_iter().map(|c| c.values).collect();
+            let rows = converter.convert_columns(&values)?;
+            stable_sort_limit(&mut indices, limit, |a, b| {
+                rows.row(a as usize).cmp(&rows.row(b as usize))
+            });
+            return Ok(UInt32Array::from(indices));
+        }
+    }
+
+    let comparator = LexicographicalComparator::try_new(&sort_columns)?;
+    stable_sort_limit(&mut indices, limit, |a, b| {
+        comparator.compare(a as usize, b as usize)
+    });
+    Ok(UInt32Array::from(indices))
+}
+
+/// Returns true if sorting by `columns` should compare rows using the row format
+fn use_row_format(
+    columns: &[SortColumn],
+    num_rows: usize,
+    limit: usize,
+) -> Result<bool, ArrowError> {
+    if columns.len() < 2 || limit.saturating_mul(ROW_FORMAT_MIN_LIMIT_RATIO) < num_rows {
+        return Ok(false);
+    }
+    if columns.len() >= ROW_FORMAT_MIN_COLUMNS {
+        return Ok(true);
+    }
+    is_low_cardinality(columns[0].values.as_ref())
+}
+
+/// Estimates whether `values` has a low cardinality from a sample of its values
+fn is_low_cardinality(values: &dyn Array) -> Result<bool, ArrowError> {
+    let step = (values.len() / CARDINALITY_SAMPLE_SIZE).max(1);
+    let mut sample: Vec<usize> = (0..values.len()).step_by(step).collect();
+    let cmp = make_comparator(values, values, SortOptions::default())?;
+    sample.sort_unstable_by(|a, b| cmp(*a, *b));
+    let distinct = 1 + sample
+        .windows(2)
+        .filter(|w| cmp(w[0], w[1]).is_ne())
+        .count();
+    Ok(distinct.saturating_mul(LOW_CARDINALITY_RATIO) <= sample.len())
+}
+
+/// Sorts `indices` by `cmp`, truncating them to the first `limit`, preserving the
+/// relative order of indices that compare equal
+fn stable_sort_limit(indices: &mut Vec<u32>, limit: usize, cmp: impl Fn(u32, u32) -> Ordering) {
+    if limit < indices.len() {
+        // Break ties by index, so an unstable partial sort is stable
+        partial_sort(indices, limit, |a, b| cmp(*a, *b).then(a.cmp(b)));
+        indices.truncate(limit);
+    } else {
+        indices.sort_by(|a, b| cmp(*a, *b));
+    }
+}
+
 /// It's unstable_sort, may not preserve the order of equal elements
 pub fn partial_sort<T, F>(v: &mut [T], limit: usize, mut is_less: F)
 where
@@ -796,8 +1112,8 @@ mod tests {
     use arrow_array::builder::{
         FixedSizeListBuilder, Int64Builder, ListBuilder, PrimitiveRunBuilder,
     };
-    use arrow_buffer::{i256, NullBuffer};
-    use arrow_schema::Field;
+    use arrow_buffer::{i256, NullBuffer, OffsetBuffer, ScalarBuffer};
+    use arrow_schema::{Field, Fields};
     use half::f16;
     use rand::rngs::StdRng;
     use rand::{Rng, RngCore, SeedableRng};
@@ -4256,12 +4572,9 @@ mod tests {
             ),
         ]);
 
-        assert!(!can_sort_to_indices(struct_array.data_type()));
-        assert!(sort_to_indices(&struct_array, None, None)
-            .err()
-            .unwrap()
-            .to_string()
-            .contains("Sort not supported for data type"));
+        assert!(can_sort_to_indices(struct_array.data_type()));
+        let indices = sort_to_indices(&struct_array, None, None).unwrap();
+        assert_eq!(indices.values(), &[1, 3, 0, 2]);
 
         let sort_columns = vec![SortColumn {
             values: Arc::new(struct_array.clone()) as ArrayRef,
@@ -4282,4 +4595,228 @@ mod tests {
 
         assert_eq!(&sorted[0], &expected_struct_array);
     }
+
+    /// Asserts that `indices` sorts `array` consistently with the row format
+    fn assert_sorted_as_rows(array: &ArrayRef, options: SortOptions, indices: &UInt32Array) {
+        let converter = RowConverter::new(vec![SortField::new_with_options(
+            array.data_type().clone(),
+            options,
+        )])
+        .unwrap();
+        let rows = converter
+            .convert_columns(std::slice::from_ref(array))
+            .unwrap();
+        for w in indices.values().windows(2) {
+            let (a, b) = (rows.row(w[0] as usize), rows.row(w[1] as usize));
+            assert!(a <= b, "{options:?} {w:?}");
+        }
+    }
+
+    #[test]
+    fn test_sort_nested() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = 200;
+
+        let mut list = ListBuilder::new(Int64Builder::new());
+        for _ in 0..len {
+            for _ in 0..rng.gen_range(0..3) {
+                list.values()
+                    .append_option(rng.gen_bool(0.8).then(|| rng.gen_range(0..3)));
+            }
+            list.append(rng.gen_bool(0.9));
+        }
+        let list = Arc::new(list.finish()) as ArrayRef;
+        let ints: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..3)))
+            .collect();
+        let nulls = NullBuffer::from_iter((0..len).map(|_| rng.gen_bool(0.9)));
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", list.data_type().clone(), true),
+        ]);
+        let structs = StructArray::new(fields.clone(), vec![Arc::new(ints), list], Some(nulls));
+        let structs = Arc::new(structs) as ArrayRef;
+
+        // List of struct, where the struct cannot be ranked
+        let offsets = OffsetBuffer::from_lengths((0..len / 4).map(|_| 4));
+        let field = Arc::new(Field::new_struct("item", fields, true));
+        let lists = Arc::new(ListArray::new(field, offsets, structs.clone(), None)) as ArrayRef;
+
+        for array in [structs, lists.slice(1, 40)] {
+            assert!(can_sort_to_indices(array.data_type()));
+            for (descending, nulls_first) in
+                [(false, false), (false, true), (true, false), (true, true)]
+            {
+                let options = SortOptions {
+                    descending,
+                    nulls_first,
+                };
+                let indices = sort_to_indices(&array, Some(options), None).unwrap();
+                assert_eq!(indices.len(), array.len());
+                assert_sorted_as_rows(&array, options, &indices);
+
+                let limited = sort_to_indices(&array, Some(options), Some(10)).unwrap();
+                assert_eq!(limited.len(), 10);
+                assert_sorted_as_rows(&array, options, &limited);
+                let sorted = take(array.as_ref(), &indices, None).unwrap();
+                let limited = take(array.as_ref(), &limited, None).unwrap();
+                assert_eq!(limited.as_ref(), sorted.slice(0, 10).as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_list_shorter_first() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            Some(vec![]),
+            Some(vec![Some(1), Some(2), Some(0)]),
+            Some(vec![Some(0), Some(5)]),
+        ]);
+        let fields = Fields::from(vec![Field::new("a", a.data_type().clone(), true)]);
+        let a = StructArray::new(fields, vec![Arc::new(a)], None);
+
+        let indices = sort_to_indices(&a, None, None).unwrap();
+        assert_eq!(indices.values(), &[2, 4, 1, 0, 3]);
+
+        let options = SortOptions::default().desc();
+        let indices = sort_to_indices(&a, Some(options), None).unwrap();
+        assert_eq!(indices.values(), &[3, 0, 1, 4, 2]);
+    }
+
+    #[test]
+    fn test_sort_list_view() {
+        // Views are out of order and overlapping: [[1, 2], [1], [], null, [1, 2, 0], [0, 5]]
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![0, 5, 1, 2, 0]));
+        let offsets = ScalarBuffer::from(vec![2, 2, 0, 0, 2, 0]);
+        let sizes = ScalarBuffer::from(vec![2, 1, 0, 3, 3, 2]);
+        let nulls = NullBuffer::from(vec![true, true, true, false, true, true]);
+        let a = ListViewArray::new(field.clone(), offsets, sizes, values, Some(nulls));
+
+        let indices = sort_to_indices(&a, None, None).unwrap();
+        assert_eq!(indices.values(), &[3, 2, 5, 1, 0, 4]);
+
+        let options = SortOptions::default().desc();
+        let indices = sort_to_indices(&a, Some(options), Some(3)).unwrap();
+        assert_eq!(indices.values(), &[3, 4, 0]);
+
+        let sorted = sort(&a, None).unwrap();
+        let offsets = ScalarBuffer::from(vec![0, 0, 0, 2, 2, 2]);
+        let sizes = ScalarBuffer::from(vec![0, 0, 2, 1, 2, 3]);
+        let nulls = NullBuffer::from(vec![false, true, true, true, true, true]);
+        let values = a.values().clone();
+        let expected = ListViewArray::new(field, offsets, sizes, values, Some(nulls));
+        assert_eq!(sorted.as_ref(), &expected);
+
+        // Struct children use the comparator
+        let field = Arc::new(Field::new_list_field(DataType::Utf8, true));
+        let values = Arc::new(StringArray::from(vec!["0", "5", "1", "2", "0"]));
+        let offsets = ScalarBuffer::from(vec![2, 2, 0, 0, 2, 0]);
+        let sizes = ScalarBuffer::from(vec![2, 1, 0, 3, 3, 2]);
+        let nulls = NullBuffer::from(vec![true, true, true, false, true, true]);
+        let large = LargeListViewArray::new(field, offsets, sizes, values, Some(nulls));
+        let fields = Fields::from(vec![Field::new("a", large.data_type().clone(), true)]);
+        let s = StructArray::new(fields, vec![Arc::new(large)], None);
+        let indices = sort_to_indices(&s, None, None).unwrap();
+        assert_eq!(indices.values(), &[3, 2, 5, 1, 0, 4]);
+    }
+
+    #[test]
+    fn test_is_low_cardinality() {
+        let a = Int32Array::from_iter_values((0..10_000).map(|x| x % 10));
+        assert!(is_low_cardinality(&a).unwrap());
+        let a = Int32Array::from_iter_values((0..10_000).map(|x| x % 1000));
+        assert!(!is_low_cardinality(&a).unwrap());
+        let a = StringArray::from(vec!["a", "b", "c"]);
+        assert!(!is_low_cardinality(&a).unwrap());
+        assert!(is_low_cardinality(&new_null_array(&DataType::Utf8, 100)).unwrap());
+    }
+
+    #[test]
+    fn test_sort_record_batch() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = 100;
+        let a: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..3)))
+            .collect();
+        let b: StringArray = (0..len)
+            .map(|_| Some(["x", "y"][rng.gen_range(0..2)]))
+            .collect();
+        let c: Float64Array = (0..len)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..2) as f64))
+            .collect();
+        let d = Int32Array::from_iter_values(0..len);
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c) as ArrayRef),
+            ("d", Arc::new(d) as ArrayRef),
+        ])
+        .unwrap();
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        for specs in [
+            vec![SortColumnSpec::new(0)],
+            vec![SortColumnSpec::new(2).with_options(options)],
+            vec![SortColumnSpec::new(1), SortColumnSpec::new(0)],
+            vec![
+                SortColumnSpec::new(0).with_options(options),
+                SortColumnSpec::new(1),
+                SortColumnSpec::new(2),
+            ],
+        ] {
+            let indices = sort_record_batch_to_indices(&batch, &specs).unwrap();
+
+            // stable, so equal rows are ordered by the unique column d
+            let mut sort_columns: Vec<_> = specs
+                .iter()
+                .map(|spec| SortColumn {
+                    values: batch.column(spec.column).clone(),
+                    options: spec.options,
+                })
+                .collect();
+            sort_columns.push(SortColumn {
+                values: batch.column(3).clone(),
+                options: None,
+            });
+            let expected = lexsort_to_indices(&sort_columns, None).unwrap();
+            assert_eq!(indices, expected);
+
+            let sorted = sort_record_batch(&batch, &specs).unwrap();
+            assert_eq!(sorted, take_record_batch(&batch, &expected).unwrap());
+
+            for limit in [0, 1, 5, 99, 100, 200] {
+                let indices = sort_record_batch_to_indices_limit(&batch, &specs, Some(limit));
+                let expected = expected.slice(0, limit.min(len as usize));
+                assert_eq!(indices.unwrap(), expected);
+
+                let sorted = sort_record_batch_limit(&batch, &specs, Some(limit)).unwrap();
+                assert_eq!(sorted, take_record_batch(&batch, &expected).unwrap());
+            }
+        }
+
+        // Two columns with a high cardinality leading column
+        let specs = [SortColumnSpec::new(3), SortColumnSpec::new(0)];
+        let indices = sort_record_batch_to_indices_limit(&batch, &specs, Some(3)).unwrap();
+        assert_eq!(indices.values(), &[0, 1, 2]);
+
+        let spec = SortColumnSpec::try_new_with_name(&batch.schema(), "c").unwrap();
+        assert_eq!(spec, SortColumnSpec::new(2));
+
+        let err = sort_record_batch(&batch, &[SortColumnSpec::new(4)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Sort column index 4 out of bounds for batch with 4 columns"
+        );
+        let err = sort_record_batch(&batch, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Sort requires at least one column"
+        );
+    }
 }
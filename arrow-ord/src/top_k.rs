@@ -0,0 +1,254 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Provides `top_k` functions to select the first `k` values of an array in sorted order
+
+use crate::ord::{make_comparator, DynComparator};
+use crate::sort::{LexicographicalComparator, SortColumn};
+use arrow_array::{Array, RecordBatch, UInt32Array};
+use arrow_schema::{ArrowError, SortOptions};
+use arrow_select::take::take_record_batch;
+use std::cmp::Ordering;
+
+/// Returns the indices of the first `k` values of `values` in the order given by `options`
+///
+/// With the default [`SortOptions`] this selects the `k` smallest values, and with
+/// [`SortOptions::descending`] the `k` largest values. The returned indices are sorted
+/// according to `options`, with equal values ordered by their index.
+///
+/// Unlike [`sort_to_indices`](crate::sort::sort_to_indices) with a limit, this uses a bounded heap, requiring `O(k)`
+/// additional memory and `O(n log k)` time
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::top_k::top_k;
+/// # use arrow_schema::SortOptions;
+/// let array = Int32Array::from(vec![Some(5), None, Some(1), Some(8), Some(3)]);
+///
+/// // nulls are ordered first by default
+/// let indices = top_k(&array, 2, None).unwrap();
+/// assert_eq!(indices.values(), &[1, 2]);
+///
+/// let options = SortOptions::default().desc().nulls_last();
+/// let indices = top_k(&array, 2, Some(options)).unwrap();
+/// assert_eq!(indices.values(), &[3, 0]);
+/// ```
+pub fn top_k(
+    values: &dyn Array,
+    k: usize,
+    options: Option<SortOptions>,
+) -> Result<UInt32Array, ArrowError> {
+    let cmp = make_comparator(values, values, options.unwrap_or_default())?;
+    Ok(select(values.len(), k, cmp))
+}
+
+/// Returns the indices of the first `k` rows of `columns` in lexicographical order
+///
+/// See [`top_k`] for more information
+pub fn lex_top_k(columns: &[SortColumn], k: usize) -> Result<UInt32Array, ArrowError> {
+    let Some(first) = columns.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Top-k requires at least one column".to_string(),
+        ));
+    };
+    let row_count = first.values.len();
+    if columns.iter().any(|c| c.values.len() != row_count) {
+        return Err(ArrowError::ComputeError(
+            "lexical top-k columns have different row counts".to_string(),
+        ));
+    }
+    if columns.len() == 1 {
+        return top_k(first.values.as_ref(), k, first.options);
+    }
+
+    let k = k.min(row_count);
+    let comparator = LexicographicalComparator::try_new(columns)?;
+    Ok(select(
+        row_count,
+        k,
+        Box::new(move |a, b| comparator.compare(a, b)),
+    ))
+}
+
+/// Returns the first `k` rows of `batch` in the lexicographical order of `columns`
+///
+/// `columns` must have the same number of rows as `batch`, see [`lex_top_k`]
+pub fn top_k_batch(
+    batch: &RecordBatch,
+    columns: &[SortColumn],
+    k: usize,
+) -> Result<RecordBatch, ArrowError> {
+    if columns.iter().any(|c| c.values.len() != batch.num_rows()) {
+        return Err(ArrowError::ComputeError(
+            "top-k columns must have the same number of rows as the batch".to_string(),
+        ));
+    }
+    let indices = lex_top_k(columns, k)?;
+    take_record_batch(batch, &indices)
+}
+
+/// Selects the first `k` of `len` indices ordered by `cmp`, breaking ties by index
+fn select(len: usize, k: usize, cmp: DynComparator) -> UInt32Array {
+    let cmp = |a: &u32, b: &u32| cmp(*a as usize, *b as usize).then(a.cmp(b));
+
+    if k >= len {
+        let mut indices: Vec<u32> = (0..len as u32).collect();
+        indices.sort_unstable_by(cmp);
+        return UInt32Array::from(indices);
+    }
+
+    // A max-heap of the best `k` indices seen so far, the root is the worst of these
+    let mut heap: Vec<u32> = Vec::with_capacity(k);
+    if k > 0 {
+        for idx in 0..len as u32 {
+            if heap.len() < k {
+                heap.push(idx);
+                sift_up(&mut heap, cmp);
+            } else if cmp(&idx, &heap[0]) == Ordering::Less {
+                heap[0] = idx;
+                sift_down(&mut heap, cmp);
+            }
+        }
+    }
+    heap.sort_unstable_by(cmp);
+    UInt32Array::from(heap)
+}
+
+fn sift_up(heap: &mut [u32], cmp: impl Fn(&u32, &u32) -> Ordering) {
+    let mut child = heap.len() - 1;
+    while child > 0 {
+        let parent = (child - 1) / 2;
+        if cmp(&heap[child], &heap[parent]) != Ordering::Greater {
+            break;
+        }
+        heap.swap(child, parent);
+        child = parent;
+    }
+}
+
+fn sift_down(heap: &mut [u32], cmp: impl Fn(&u32, &u32) -> Ordering) {
+    let mut parent = 0;
+    loop {
+        let left = 2 * parent + 1;
+        if left >= heap.len() {
+            break;
+        }
+        let right = left + 1;
+        let largest = match right < heap.len() && cmp(&heap[right], &heap[left]).is_gt() {
+            true => right,
+            false => left,
+        };
+        if cmp(&heap[largest], &heap[parent]) != Ordering::Greater {
+            break;
+        }
+        heap.swap(largest, parent);
+        parent = largest;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{ArrayRef, Float64Array, Int32Array, StringArray};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_top_k() {
+        let array = Int32Array::from(vec![Some(3), None, Some(1), Some(3), Some(-4), None]);
+
+        let indices = top_k(&array, 3, None).unwrap();
+        assert_eq!(indices.values(), &[1, 5, 4]);
+
+        let options = SortOptions::default().nulls_last();
+        let indices = top_k(&array, 3, Some(options)).unwrap();
+        assert_eq!(indices.values(), &[4, 2, 0]);
+
+        let options = SortOptions::default().desc().nulls_last();
+        let indices = top_k(&array, 2, Some(options)).unwrap();
+        assert_eq!(indices.values(), &[0, 3]);
+
+        assert_eq!(top_k(&array, 0, None).unwrap().len(), 0);
+        assert_eq!(top_k(&array, 10, None).unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_top_k_ties() {
+        let array = Int32Array::from(vec![1; 100]);
+        let expected: Vec<u32> = (0..100).collect();
+        for k in [50, 99, 100, 200] {
+            let indices = top_k(&array, k, None).unwrap();
+            assert_eq!(indices.values(), &expected[..k.min(100)]);
+        }
+    }
+
+    #[test]
+    fn test_top_k_matches_sort() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let len = rng.gen_range(0..200);
+            let array: Float64Array = (0..len)
+                .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..20) as f64))
+                .collect();
+            let k = rng.gen_range(0..len + 5);
+            let options = SortOptions {
+                descending: rng.gen_bool(0.5),
+                nulls_first: rng.gen_bool(0.5),
+            };
+
+            let expected = crate::sort::sort(&array, Some(options)).unwrap();
+            let indices = top_k(&array, k, Some(options)).unwrap();
+            let actual = arrow_select::take::take(&array, &indices, None).unwrap();
+            assert_eq!(actual.as_ref(), &expected.slice(0, k.min(len)));
+        }
+    }
+
+    #[test]
+    fn test_lex_top_k() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 2, 1, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z", "w", "v"]));
+        let batch = RecordBatch::try_from_iter([("a", a.clone()), ("b", b.clone())]).unwrap();
+        let columns = [
+            SortColumn {
+                values: a,
+                options: Some(SortOptions::default().desc()),
+            },
+            SortColumn {
+                values: b,
+                options: None,
+            },
+        ];
+
+        let indices = lex_top_k(&columns, 3).unwrap();
+        assert_eq!(indices.values(), &[4, 0, 2]);
+
+        let top = top_k_batch(&batch, &columns, 2).unwrap();
+        assert_eq!(top.num_rows(), 2);
+        assert_eq!(top.schema(), batch.schema());
+        assert_eq!(
+            top.column(1).as_ref(),
+            &StringArray::from(vec!["v", "x"]) as &dyn Array
+        );
+
+        let err = lex_top_k(&[], 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Top-k requires at least one column"
+        );
+    }
+}
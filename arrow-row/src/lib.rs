@@ -117,6 +117,33 @@
 //! }
 //! ```
 //!
+//! # Versioned Format
+//!
+//! The byte layout of rows created with [`RowConverter::new`] is an implementation detail,
+//! and may change between releases. [`RowConverter::new_versioned`] instead pins the encoding
+//! to a [`RowFormatVersion`], allowing [`Rows`] to be written with [`RowConverter::write_rows`],
+//! for example when spilling to disk, and read back with [`RowConverter::read_rows`] by a
+//! different process or release of this crate
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_row::{RowConverter, RowFormatVersion, SortField};
+//! # use arrow_array::{ArrayRef, Int32Array};
+//! # use arrow_schema::DataType;
+//! let fields = vec![SortField::new(DataType::Int32)];
+//! let converter = RowConverter::new_versioned(fields.clone(), RowFormatVersion::V1).unwrap();
+//! let array = Arc::new(Int32Array::from(vec![3, 1, 2])) as ArrayRef;
+//! let rows = converter.convert_columns(&[array.clone()]).unwrap();
+//!
+//! let mut spilled = vec![];
+//! converter.write_rows(&rows, &mut spilled).unwrap();
+//!
+//! // A converter with the same fields and version can read the rows back
+//! let converter = RowConverter::new_versioned(fields, RowFormatVersion::V1).unwrap();
+//! let read = converter.read_rows(&spilled).unwrap();
+//! assert_eq!(converter.convert_rows(&read).unwrap(), vec![array]);
+//! ```
+//!
 //! [non-comparison sorts]: https://en.wikipedia.org/wiki/Sorting_algorithm#Non-comparison_sorts
 //! [radix sort]: https://en.wikipedia.org/wiki/Radix_sort
 //! [normalized for sorting]: http://wwwlgis.informatik.uni-kl.de/archiv/wwwdvs.informatik.uni-kl.de/courses/DBSREAL/SS2005/Vorlesungsunterlagen/Implementing_Sorting.pdf
@@ -131,7 +158,7 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use arrow_array::cast::*;
-use arrow_array::types::ArrowDictionaryKeyType;
+use arrow_array::types::{ArrowDictionaryKeyType, Int16Type, Int32Type, Int64Type};
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, Buffer, OffsetBuffer, ScalarBuffer};
 use arrow_data::ArrayDataBuilder;
@@ -143,6 +170,7 @@ use crate::variable::{decode_binary, decode_string};
 
 mod fixed;
 mod list;
+mod run;
 mod variable;
 
 /// Converts [`ArrayRef`] columns into a [row-oriented](self) format.
@@ -362,6 +390,8 @@ pub struct RowConverter {
     fields: Arc<[SortField]>,
     /// State for codecs
     codecs: Vec<Codec>,
+    /// The pinned format version, if any
+    version: Option<RowFormatVersion>,
 }
 
 #[derive(Debug)]
@@ -376,6 +406,8 @@ enum Codec {
     Struct(RowConverter, OwnedRow),
     /// A row converter for the child field
     List(RowConverter),
+    /// A row converter for the run values
+    RunEndEncoded(RowConverter),
 }
 
 impl Codec {
@@ -395,6 +427,13 @@ impl Codec {
                 };
                 Ok(Self::Dictionary(converter, owned))
             }
+            DataType::RunEndEncoded(_, values) => {
+                let sort_field =
+                    SortField::new_with_options(values.data_type().clone(), sort_field.options);
+
+                let converter = RowConverter::new(vec![sort_field])?;
+                Ok(Self::RunEndEncoded(converter))
+            }
             d if !d.is_nested() => Ok(Self::Stateless),
             DataType::List(f) | DataType::LargeList(f) => {
                 // The encoded contents will be inverted if descending is set to true
@@ -455,6 +494,14 @@ impl Codec {
                 let rows = converter.convert_columns(&[values.clone()])?;
                 Ok(Encoder::List(rows))
             }
+            Codec::RunEndEncoded(converter) => {
+                let values = downcast_run_array! {
+                    array => array.values(),
+                    _ => unreachable!()
+                };
+                let rows = converter.convert_columns(std::slice::from_ref(values))?;
+                Ok(Encoder::RunEndEncoded(rows))
+            }
         }
     }
 
@@ -464,6 +511,7 @@ impl Codec {
             Codec::Dictionary(converter, nulls) => converter.size() + nulls.data.len(),
             Codec::Struct(converter, nulls) => converter.size() + nulls.data.len(),
             Codec::List(converter) => converter.size(),
+            Codec::RunEndEncoded(converter) => converter.size(),
         }
     }
 }
@@ -482,6 +530,8 @@ enum Encoder<'a> {
     Struct(Rows, Row<'a>),
     /// The row encoding of the child array
     List(Rows),
+    /// The row encoding of the run values
+    RunEndEncoded(Rows),
 }
 
 /// Configure the data type and sort order for a given column
@@ -512,6 +562,37 @@ impl SortField {
     }
 }
 
+/// A version of the row format with a stable byte layout
+///
+/// Rows encoded with a given version by any release of this crate will compare, and
+/// decode, identically to rows encoded with the same version and [`SortField`]s by any
+/// later release. Changes to the encoding will only ever be introduced as a new version.
+///
+/// See [`RowConverter::new_versioned`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RowFormatVersion {
+    /// The initial versioned format, as documented on [`RowConverter`]
+    V1,
+}
+
+impl RowFormatVersion {
+    /// Returns the identifier of this version in the serialized form
+    fn id(&self) -> u8 {
+        match self {
+            Self::V1 => 1,
+        }
+    }
+}
+
+/// Magic bytes at the start of rows serialized by [`RowConverter::write_rows`]
+const ROWS_MAGIC: &[u8; 8] = b"ARROWROW";
+
+/// Returns the flags describing `options` in rows serialized by [`RowConverter::write_rows`]
+fn sort_options_flags(options: SortOptions) -> u8 {
+    options.descending as u8 | (options.nulls_first as u8) << 1
+}
+
 impl RowConverter {
     /// Create a new [`RowConverter`] with the provided schema
     pub fn new(fields: Vec<SortField>) -> Result<Self, ArrowError> {
@@ -525,9 +606,30 @@ impl RowConverter {
         Ok(Self {
             fields: fields.into(),
             codecs,
+            version: None,
         })
     }
 
+    /// Create a new [`RowConverter`] with the provided schema, whose encoding is
+    /// pinned to `version`
+    ///
+    /// Unlike [`RowConverter::new`], the byte layout of the produced [`Rows`] is stable
+    /// across releases, and so they can be persisted with [`RowConverter::write_rows`]
+    pub fn new_versioned(
+        fields: Vec<SortField>,
+        version: RowFormatVersion,
+    ) -> Result<Self, ArrowError> {
+        let mut converter = Self::new(fields)?;
+        converter.version = Some(version);
+        Ok(converter)
+    }
+
+    /// Returns the [`RowFormatVersion`] of this [`RowConverter`], if created
+    /// with [`RowConverter::new_versioned`]
+    pub fn version(&self) -> Option<RowFormatVersion> {
+        self.version
+    }
+
     /// Check if the given fields are supported by the row format.
     pub fn supports_fields(fields: &[SortField]) -> bool {
         fields.iter().all(|x| Self::supports_datatype(&x.data_type))
@@ -535,6 +637,7 @@ impl RowConverter {
 
     fn supports_datatype(d: &DataType) -> bool {
         match d {
+            DataType::RunEndEncoded(_, values) => Self::supports_datatype(values.data_type()),
             _ if !d.is_nested() => true,
             DataType::List(f) | DataType::LargeList(f) | DataType::Map(f, _) => {
                 Self::supports_datatype(f.data_type())
@@ -675,11 +778,97 @@ impl RowConverter {
     ///
     /// Panics if the rows were not produced by this [`RowConverter`]
     pub fn convert_rows<'a, I>(&self, rows: I) -> Result<Vec<ArrayRef>, ArrowError>
+    where
+        I: IntoIterator<Item = Row<'a>>,
+    {
+        let (mut rows, validate_utf8) = self.row_data(rows);
+
+        // SAFETY
+        // We have validated that the rows came from this [`RowConverter`]
+        // and therefore must be valid
+        unsafe { self.convert_raw(&mut rows, validate_utf8) }
+    }
+
+    /// Convert only the fields at the indices in `projection` of [`Row`]s to [`ArrayRef`],
+    /// returning them in the order of `projection`
+    ///
+    /// The encoding of fields not in `projection` is skipped over, without decoding it
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// let converter = RowConverter::new(vec![
+    ///     SortField::new(DataType::Utf8),
+    ///     SortField::new(DataType::Int32),
+    /// ]).unwrap();
+    /// let payload = Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef;
+    /// let keys = Arc::new(Int32Array::from(vec![2, 1])) as ArrayRef;
+    /// let rows = converter.convert_columns(&[payload, keys.clone()]).unwrap();
+    ///
+    /// let decoded = converter.convert_rows_projected(&rows, &[1]).unwrap();
+    /// assert_eq!(decoded, vec![keys]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an index in `projection` is out of bounds
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows were not produced by this [`RowConverter`]
+    pub fn convert_rows_projected<'a, I>(
+        &self,
+        rows: I,
+        projection: &[usize],
+    ) -> Result<Vec<ArrayRef>, ArrowError>
+    where
+        I: IntoIterator<Item = Row<'a>>,
+    {
+        if let Some(idx) = projection.iter().find(|x| **x >= self.fields.len()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Projection index {idx} out of bounds for {} fields",
+                self.fields.len()
+            )));
+        }
+
+        let (mut rows, validate_utf8) = self.row_data(rows);
+
+        // Fields after the last projected field need not be skipped
+        let end = projection.iter().max().map(|x| x + 1).unwrap_or_default();
+        let mut decoded = Vec::with_capacity(end);
+        for (idx, (field, codec)) in self.fields.iter().zip(&self.codecs).enumerate().take(end) {
+            if projection.contains(&idx) {
+                // SAFETY
+                // We have validated that the rows came from this [`RowConverter`]
+                // and therefore must be valid
+                let array = unsafe { decode_column(field, &mut rows, codec, validate_utf8)? };
+                decoded.push(Some(array));
+            } else {
+                rows.iter_mut()
+                    .for_each(|row| *row = &row[encoded_field_len(field, codec, row)..]);
+                decoded.push(None);
+            }
+        }
+
+        Ok(projection
+            .iter()
+            .map(|idx| decoded[*idx].clone().unwrap())
+            .collect())
+    }
+
+    /// Returns the data of `rows`, and whether it requires UTF-8 validation
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows were not produced by this [`RowConverter`]
+    fn row_data<'a, I>(&self, rows: I) -> (Vec<&'a [u8]>, bool)
     where
         I: IntoIterator<Item = Row<'a>>,
     {
         let mut validate_utf8 = false;
-        let mut rows: Vec<_> = rows
+        let rows = rows
             .into_iter()
             .map(|row| {
                 assert!(
@@ -690,11 +879,17 @@ impl RowConverter {
                 row.data
             })
             .collect();
+        (rows, validate_utf8)
+    }
 
-        // SAFETY
-        // We have validated that the rows came from this [`RowConverter`]
-        // and therefore must be valid
-        unsafe { self.convert_raw(&mut rows, validate_utf8) }
+    /// Returns the number of bytes occupied by the encoded fields at the start of `row`
+    fn encoded_len(&self, row: &[u8]) -> usize {
+        self.fields
+            .iter()
+            .zip(&self.codecs)
+            .fold(0, |offset, (field, codec)| {
+                offset + encoded_field_len(field, codec, &row[offset..])
+            })
     }
 
     /// Returns an empty [`Rows`] with capacity for `row_capacity` rows with
@@ -781,6 +976,163 @@ impl RowConverter {
         }
     }
 
+    /// Serializes `rows` to `writer`
+    ///
+    /// The output consists of, with all integers little endian
+    ///
+    /// * the magic bytes `ARROWROW`
+    /// * the [`RowFormatVersion`] as a `u8`
+    /// * the number of fields as a `u32`
+    /// * for each field, a descriptor of its [`SortField`], consisting of
+    ///     * its [`SortOptions`] as a `u8`, with bit 0 set if descending, and bit 1 set if
+    ///       nulls first
+    ///     * the length of its [`DataType`], formatted with [`Display`](std::fmt::Display),
+    ///       as a `u32`, followed by the UTF-8 formatted data type
+    /// * the number of rows as a `u64`
+    /// * the length of each row as a `u64`
+    /// * the concatenated row data
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this [`RowConverter`] was not created with
+    /// [`RowConverter::new_versioned`], or if writing to `writer` fails
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` were not produced by this [`RowConverter`]
+    pub fn write_rows<W: std::io::Write>(
+        &self,
+        rows: &Rows,
+        mut writer: W,
+    ) -> Result<(), ArrowError> {
+        assert!(
+            Arc::ptr_eq(&rows.config.fields, &self.fields),
+            "rows were not produced by this RowConverter"
+        );
+        let version = self.version.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "Only rows from a versioned RowConverter can be serialized".to_string(),
+            )
+        })?;
+
+        writer.write_all(ROWS_MAGIC)?;
+        writer.write_all(&[version.id()])?;
+        writer.write_all(&(self.fields.len() as u32).to_le_bytes())?;
+        for field in self.fields.iter() {
+            let data_type = field.data_type.to_string();
+            writer.write_all(&[sort_options_flags(field.options)])?;
+            writer.write_all(&(data_type.len() as u32).to_le_bytes())?;
+            writer.write_all(data_type.as_bytes())?;
+        }
+        writer.write_all(&(rows.num_rows() as u64).to_le_bytes())?;
+        for w in rows.offsets.windows(2) {
+            writer.write_all(&((w[1] - w[0]) as u64).to_le_bytes())?;
+        }
+        writer.write_all(&rows.buffer)?;
+        Ok(())
+    }
+
+    /// Deserializes [`Rows`] written by [`RowConverter::write_rows`]
+    ///
+    /// The rows must have been written by a [`RowConverter`] with the same [`SortField`]s
+    /// and [`RowFormatVersion`] as this one, which may have been from a different release
+    /// of this crate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this [`RowConverter`] was not created with
+    /// [`RowConverter::new_versioned`], if the data was written with a different version,
+    /// number of fields, or field data types or sort options, or is truncated.
+    ///
+    /// Operations on the returned [`Rows`] may panic if the row data is otherwise malformed
+    pub fn read_rows(&self, data: &[u8]) -> Result<Rows, ArrowError> {
+        let version = self.version.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "Only a versioned RowConverter can deserialize rows".to_string(),
+            )
+        })?;
+
+        let mut remaining = data;
+        let mut take = |len: usize| {
+            if remaining.len() < len {
+                return Err(ArrowError::ParseError(
+                    "Serialized rows are truncated".to_string(),
+                ));
+            }
+            let (head, tail) = remaining.split_at(len);
+            remaining = tail;
+            Ok(head)
+        };
+
+        if take(ROWS_MAGIC.len())? != ROWS_MAGIC {
+            return Err(ArrowError::ParseError(
+                "Serialized rows are missing the expected magic bytes".to_string(),
+            ));
+        }
+        let id = take(1)?[0];
+        if id != version.id() {
+            return Err(ArrowError::ParseError(format!(
+                "Serialized rows have format version {id}, expected {}",
+                version.id()
+            )));
+        }
+        let num_fields = u32::from_le_bytes(take(4)?.try_into().unwrap()) as usize;
+        if num_fields != self.fields.len() {
+            return Err(ArrowError::ParseError(format!(
+                "Serialized rows have {num_fields} fields, expected {}",
+                self.fields.len()
+            )));
+        }
+        for (idx, field) in self.fields.iter().enumerate() {
+            let flags = take(1)?[0];
+            if flags != sort_options_flags(field.options) {
+                let options = SortOptions {
+                    descending: flags & 1 != 0,
+                    nulls_first: flags & 2 != 0,
+                };
+                return Err(ArrowError::ParseError(format!(
+                    "Serialized rows have sort options {options} for field {idx}, expected {}",
+                    field.options
+                )));
+            }
+            let len = u32::from_le_bytes(take(4)?.try_into().unwrap()) as usize;
+            let data_type = take(len)?;
+            let expected = field.data_type.to_string();
+            if data_type != expected.as_bytes() {
+                return Err(ArrowError::ParseError(format!(
+                    "Serialized rows have data type {} for field {idx}, expected {expected}",
+                    String::from_utf8_lossy(data_type)
+                )));
+            }
+        }
+        let num_rows = u64::from_le_bytes(take(8)?.try_into().unwrap()) as usize;
+        let lengths =
+            take(num_rows.checked_mul(8).ok_or_else(|| {
+                ArrowError::ParseError("Serialized rows are truncated".to_string())
+            })?)?;
+
+        let mut offsets = Vec::with_capacity(num_rows + 1);
+        offsets.push(0_usize);
+        let mut end = 0_usize;
+        for len in lengths.chunks_exact(8) {
+            let len = u64::from_le_bytes(len.try_into().unwrap()) as usize;
+            end = end.checked_add(len).ok_or_else(|| {
+                ArrowError::ParseError("Serialized rows are truncated".to_string())
+            })?;
+            offsets.push(end);
+        }
+        let buffer = take(end)?.to_vec();
+
+        Ok(Rows {
+            buffer,
+            offsets,
+            config: RowConfig {
+                fields: Arc::clone(&self.fields),
+                validate_utf8: true,
+            },
+        })
+    }
+
     /// Convert raw bytes into [`ArrayRef`]
     ///
     /// # Safety
@@ -1190,9 +1542,7 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
                         .iter()
                         .zip(lengths.iter_mut())
                         .for_each(|(slice, length)| *length += variable::encoded_len(slice)),
-                    DataType::BinaryView => array.as_binary_view().iter().zip(lengths.iter_mut()).for_each(|(slice, length)| {
-                            *length += variable::encoded_len(slice)
-                        }),
+                    DataType::BinaryView => variable::compute_lengths_view(&mut lengths, array.as_binary_view()),
                     DataType::Utf8 => array.as_string::<i32>()
                         .iter()
                         .zip(lengths.iter_mut())
@@ -1205,9 +1555,7 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
                         .for_each(|(slice, length)| {
                             *length += variable::encoded_len(slice.map(|x| x.as_bytes()))
                         }),
-                    DataType::Utf8View => array.as_string_view().iter().zip(lengths.iter_mut()).for_each(|(slice, length)| {
-                        *length += variable::encoded_len(slice.map(|x| x.as_bytes()))
-                    }),
+                    DataType::Utf8View => variable::compute_lengths_view(&mut lengths, array.as_string_view()),
                     DataType::FixedSizeBinary(len) => {
                         let len = len.to_usize().unwrap();
                         lengths.iter_mut().for_each(|x| *x += 1 + len)
@@ -1246,6 +1594,10 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
                 }
                 _ => unreachable!(),
             },
+            Encoder::RunEndEncoded(rows) => downcast_run_array! {
+                array => run::compute_lengths(&mut lengths, rows, array),
+                _ => unreachable!()
+            },
         }
     }
 
@@ -1282,7 +1634,7 @@ fn encode_column(
                     variable::encode(data, offsets, as_generic_binary_array::<i32>(column).iter(), opts)
                 }
                 DataType::BinaryView => {
-                    variable::encode(data, offsets, column.as_binary_view().iter(), opts)
+                    variable::encode_view(data, offsets, column.as_binary_view(), opts)
                 }
                 DataType::LargeBinary => {
                     variable::encode(data, offsets, as_generic_binary_array::<i64>(column).iter(), opts)
@@ -1299,11 +1651,9 @@ fn encode_column(
                         .map(|x| x.map(|x| x.as_bytes())),
                     opts,
                 ),
-                DataType::Utf8View => variable::encode(
-                    data, offsets,
-                    column.as_string_view().iter().map(|x| x.map(|x| x.as_bytes())),
-                    opts,
-                ),
+                DataType::Utf8View => {
+                    variable::encode_view(data, offsets, column.as_string_view(), opts)
+                }
                 DataType::FixedSizeBinary(_) => {
                     let array = column.as_any().downcast_ref().unwrap();
                     fixed::encode_fixed_size_binary(data, offsets, array, opts)
@@ -1342,6 +1692,10 @@ fn encode_column(
             }
             _ => unreachable!(),
         },
+        Encoder::RunEndEncoded(rows) => downcast_run_array! {
+            column => run::encode(data, offsets, rows, column),
+            _ => unreachable!()
+        },
     }
 }
 
@@ -1427,10 +1781,72 @@ unsafe fn decode_column(
             }
             _ => unreachable!(),
         },
+        Codec::RunEndEncoded(converter) => match &field.data_type {
+            DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+                DataType::Int16 => Arc::new(run::decode::<Int16Type>(
+                    converter,
+                    rows,
+                    field,
+                    validate_utf8,
+                )?),
+                DataType::Int32 => Arc::new(run::decode::<Int32Type>(
+                    converter,
+                    rows,
+                    field,
+                    validate_utf8,
+                )?),
+                DataType::Int64 => Arc::new(run::decode::<Int64Type>(
+                    converter,
+                    rows,
+                    field,
+                    validate_utf8,
+                )?),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
     };
     Ok(array)
 }
 
+macro_rules! encoded_len_helper {
+    ($t:ty) => {
+        <<$t as ArrowPrimitiveType>::Native as fixed::FixedLengthEncoding>::ENCODED_LEN
+    };
+}
+
+/// Returns the number of bytes occupied by the encoding of `field` at the start of `row`
+fn encoded_field_len(field: &SortField, codec: &Codec, row: &[u8]) -> usize {
+    use fixed::FixedLengthEncoding;
+
+    match codec {
+        Codec::Stateless => {
+            let data_type = &field.data_type;
+            downcast_primitive! {
+                data_type => (encoded_len_helper),
+                DataType::Null => 0,
+                DataType::Boolean => bool::ENCODED_LEN,
+                DataType::FixedSizeBinary(size) => *size as usize + 1,
+                _ => variable::decode_blocks(row, field.options, |_| {}),
+            }
+        }
+        Codec::Dictionary(converter, _) | Codec::RunEndEncoded(converter) => {
+            converter.encoded_len(row)
+        }
+        Codec::Struct(converter, _) => 1 + converter.encoded_len(&row[1..]),
+        Codec::List(_) => {
+            let mut offset = 0;
+            loop {
+                let decoded = variable::decode_blocks(&row[offset..], field.options, |_| {});
+                offset += decoded;
+                if decoded <= 1 {
+                    return offset;
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rand::distributions::uniform::SampleUniform;
@@ -2190,6 +2606,176 @@ mod tests {
         test_nested_list::<i64>();
     }
 
+    fn test_run_end_encoded<R: RunEndIndexType>() {
+        let values = StringArray::from(vec![Some("b"), None, Some("a"), Some("c"), Some("a")]);
+        let run_ends = PrimitiveArray::<R>::from_iter_values(
+            [2, 3, 6, 7, 9].map(|x| R::Native::from_usize(x).unwrap()),
+        );
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+
+        let plain: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("b"),
+            Some("b"),
+            None,
+            Some("a"),
+            Some("a"),
+            Some("a"),
+            Some("c"),
+            Some("a"),
+            Some("a"),
+        ]));
+        let options = [
+            SortOptions::default(),
+            SortOptions::default().desc(),
+            SortOptions::default().with_nulls_first(false),
+        ];
+        for options in options {
+            let field = SortField::new_with_options(array.data_type().clone(), options);
+            let converter = RowConverter::new(vec![field]).unwrap();
+            let rows = converter
+                .convert_columns(std::slice::from_ref(&array))
+                .unwrap();
+
+            let plain_field = SortField::new_with_options(DataType::Utf8, options);
+            let plain_converter = RowConverter::new(vec![plain_field]).unwrap();
+            let plain_rows = plain_converter
+                .convert_columns(std::slice::from_ref(&plain))
+                .unwrap();
+
+            // Ordering matches that of the logical values
+            for i in 0..rows.num_rows() {
+                for j in 0..rows.num_rows() {
+                    assert_eq!(
+                        rows.row(i).cmp(&rows.row(j)),
+                        plain_rows.row(i).cmp(&plain_rows.row(j)),
+                        "{i} {j}"
+                    );
+                }
+            }
+
+            let back = converter.convert_rows(&rows).unwrap();
+            assert_eq!(back[0].data_type(), array.data_type());
+            let back = as_run_array::<R>(&back[0]);
+            assert_eq!(back.run_ends().values(), run_ends.values());
+            let back_values = back.values().as_string::<i32>();
+            assert_eq!(back_values, &values);
+
+            // Sliced arrays encode only their logical values
+            let sliced = array.slice(1, 5);
+            let rows = converter
+                .convert_columns(std::slice::from_ref(&sliced))
+                .unwrap();
+            let back = converter.convert_rows(&rows).unwrap();
+            let back = as_run_array::<R>(&back[0]);
+            assert_eq!(back.len(), 5);
+            let expected: Vec<_> = [1, 2, 5].map(|x| R::Native::from_usize(x).unwrap()).into();
+            assert_eq!(back.run_ends().values(), &expected);
+            let back_values = back.values().as_string::<i32>();
+            let expected = StringArray::from(vec![Some("b"), None, Some("a")]);
+            assert_eq!(back_values, &expected);
+        }
+    }
+
+    #[test]
+    fn test_run_end_encoded_int16() {
+        test_run_end_encoded::<Int16Type>();
+    }
+
+    #[test]
+    fn test_run_end_encoded_int32() {
+        test_run_end_encoded::<Int32Type>();
+    }
+
+    #[test]
+    fn test_run_end_encoded_int64() {
+        test_run_end_encoded::<Int64Type>();
+    }
+
+    #[test]
+    fn test_run_end_encoded_merges_runs() {
+        let values = Int32Array::from(vec![1, 1, 2]);
+        let run_ends = Int32Array::from(vec![2, 3, 4]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+
+        let converter = RowConverter::new(vec![SortField::new(array.data_type().clone())]).unwrap();
+        let rows = converter.convert_columns(&[array]).unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        let back = as_run_array::<Int32Type>(&back[0]);
+        assert_eq!(back.run_ends().values(), &[3, 4]);
+        assert_eq!(back.values().as_primitive::<Int32Type>().values(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_run_end_encoded_overflow() {
+        let values = Int32Array::from(vec![1]);
+        let run_ends = Int16Array::from(vec![30000]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+
+        let converter = RowConverter::new(vec![SortField::new(array.data_type().clone())]).unwrap();
+        let mut rows = converter.empty_rows(60000, 0);
+        converter
+            .append(&mut rows, std::slice::from_ref(&array))
+            .unwrap();
+        converter.append(&mut rows, &[array]).unwrap();
+
+        let err = converter.convert_rows(&rows).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: 60000 rows exceed the maximum run end for Int16"
+        );
+    }
+
+    #[test]
+    fn test_view_arrays() {
+        let long = "a string that is longer than twelve bytes";
+        let strings = StringViewArray::from(vec![
+            Some("foo"),
+            None,
+            Some(long),
+            Some(""),
+            Some("a string that is longer than twelve bytez"),
+            Some("foo"),
+            None,
+            Some("twelve bytes"),
+        ]);
+        let binary = BinaryViewArray::from_iter(strings.iter().map(|x| x.map(|x| x.as_bytes())));
+
+        let columns = [Arc::new(strings) as ArrayRef, Arc::new(binary) as ArrayRef];
+        for column in columns {
+            for options in [SortOptions::default(), SortOptions::default().desc()] {
+                let field = SortField::new_with_options(column.data_type().clone(), options);
+                let converter = RowConverter::new(vec![field]).unwrap();
+
+                let sliced = column.slice(1, 6);
+                for column in [column.clone(), sliced] {
+                    let rows = converter
+                        .convert_columns(std::slice::from_ref(&column))
+                        .unwrap();
+                    let back = converter.convert_rows(&rows).unwrap();
+                    assert_eq!(&back[0], &column);
+
+                    let sort_columns = [SortColumn {
+                        values: column.clone(),
+                        options: Some(options),
+                    }];
+                    let comparator = LexicographicalComparator::try_new(&sort_columns).unwrap();
+                    for i in 0..column.len() {
+                        for j in 0..column.len() {
+                            assert_eq!(
+                                rows.row(i).cmp(&rows.row(j)),
+                                comparator.compare(i, j),
+                                "{i} {j}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn generate_primitive_array<K>(len: usize, valid_percent: f64) -> PrimitiveArray<K>
     where
         K: ArrowPrimitiveType,
@@ -2447,6 +3033,13 @@ mod tests {
                 dictionary_eq(actual, expected)
             }
 
+            // Check that each column can be decoded in isolation
+            for (idx, expected) in arrays.iter().enumerate() {
+                let back = converter.convert_rows_projected(&rows, &[idx]).unwrap();
+                back[0].to_data().validate_full().unwrap();
+                dictionary_eq(&back[0], expected)
+            }
+
             // Check that we can convert
             let rows = rows.try_into_binary().expect("reasonable size");
             let parser = converter.parser();
@@ -2534,4 +3127,250 @@ mod tests {
         let rows = converter.convert_columns(&[Arc::new(a) as _]).unwrap();
         assert_eq!(rows.row(0).cmp(&rows.row(1)), Ordering::Less);
     }
+
+    #[test]
+    fn test_convert_rows_projected() {
+        let mut list = ListBuilder::new(Int8Builder::new());
+        list.append_value([Some(1), None]);
+        list.append_null();
+        list.append_value([]);
+        let list = Arc::new(list.finish()) as ArrayRef;
+
+        let strings = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("long string value"),
+        ]));
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("x"), None, Some("y")].into_iter().collect();
+        let run_ends = Int32Array::from(vec![2, 3]);
+        let run = RunArray::try_new(&run_ends, &Int64Array::from(vec![Some(1), None])).unwrap();
+        let structs = StructArray::from(vec![(
+            Arc::new(Field::new("s", DataType::Utf8, true)),
+            strings.clone() as ArrayRef,
+        )]);
+
+        let columns = vec![
+            Arc::new(NullArray::new(3)) as ArrayRef,
+            Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])) as ArrayRef,
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(-1)])) as ArrayRef,
+            strings,
+            Arc::new(FixedSizeBinaryArray::from(vec![
+                Some(b"ab".as_slice()),
+                None,
+                Some(b"cd"),
+            ])) as ArrayRef,
+            Arc::new(BinaryViewArray::from(vec![
+                Some(b"a".as_slice()),
+                Some(b""),
+                None,
+            ])) as ArrayRef,
+            Arc::new(dict) as ArrayRef,
+            Arc::new(structs) as ArrayRef,
+            list,
+            Arc::new(run) as ArrayRef,
+        ];
+
+        for options in [SortOptions::default(), SortOptions::default().desc()] {
+            let fields = columns
+                .iter()
+                .map(|x| SortField::new_with_options(x.data_type().clone(), options))
+                .collect();
+            let converter = RowConverter::new(fields).unwrap();
+            let rows = converter.convert_columns(&columns).unwrap();
+
+            for (idx, column) in columns.iter().enumerate() {
+                let back = converter.convert_rows_projected(&rows, &[idx]).unwrap();
+                assert_eq!(back.len(), 1);
+                dictionary_eq(&back[0], column);
+            }
+
+            let back = converter
+                .convert_rows_projected(&rows, &[9, 3, 0, 3])
+                .unwrap();
+            assert_eq!(back.len(), 4);
+            for (actual, idx) in back.iter().zip([9, 3, 0, 3]) {
+                dictionary_eq(actual, &columns[idx]);
+            }
+
+            let back = converter.convert_rows_projected(&rows, &[]).unwrap();
+            assert!(back.is_empty());
+
+            let err = converter.convert_rows_projected(&rows, &[10]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid argument error: Projection index 10 out of bounds for 10 fields"
+            );
+        }
+    }
+
+    #[test]
+    fn test_versioned_round_trip() {
+        let a = Arc::new(Int32Array::from(vec![Some(3), None, Some(-1), Some(3)])) as ArrayRef;
+        let b = Arc::new(StringArray::from(vec![
+            Some("b"),
+            Some("a"),
+            None,
+            Some(""),
+        ])) as ArrayRef;
+        let mut c = ListBuilder::new(Int8Builder::new());
+        c.append_value([Some(1), None]);
+        c.append_null();
+        c.append_value([]);
+        c.append_value([Some(2)]);
+        let c = Arc::new(c.finish()) as ArrayRef;
+        let columns = vec![a, b, c];
+
+        let fields: Vec<_> = columns
+            .iter()
+            .map(|x| {
+                SortField::new_with_options(x.data_type().clone(), SortOptions::default().desc())
+            })
+            .collect();
+        let converter = RowConverter::new_versioned(fields.clone(), RowFormatVersion::V1).unwrap();
+        assert_eq!(converter.version(), Some(RowFormatVersion::V1));
+        let rows = converter.convert_columns(&columns).unwrap();
+
+        let mut spilled = vec![];
+        converter.write_rows(&rows, &mut spilled).unwrap();
+
+        let other = RowConverter::new_versioned(fields, RowFormatVersion::V1).unwrap();
+        let read = other.read_rows(&spilled).unwrap();
+        assert_eq!(read.num_rows(), rows.num_rows());
+        for (a, b) in rows.iter().zip(read.iter()) {
+            assert_eq!(a.data(), b.data());
+        }
+        let back = other.convert_rows(&read).unwrap();
+        assert_eq!(back, columns);
+
+        // Rows read back are comparable with those freshly converted
+        let fresh = other.convert_columns(&columns).unwrap();
+        for (a, b) in fresh.iter().zip(read.iter()) {
+            assert_eq!(a, b);
+        }
+
+        // Empty rows
+        let empty = converter.empty_rows(0, 0);
+        let mut spilled = vec![];
+        converter.write_rows(&empty, &mut spilled).unwrap();
+        assert_eq!(converter.read_rows(&spilled).unwrap().num_rows(), 0);
+    }
+
+    #[test]
+    fn test_versioned_layout() {
+        // The V1 layout must never change
+        let fields = vec![
+            SortField::new(DataType::Int32),
+            SortField::new_with_options(DataType::Utf8, SortOptions::default().desc()),
+        ];
+        let converter = RowConverter::new_versioned(fields, RowFormatVersion::V1).unwrap();
+        let columns = [
+            Arc::new(Int32Array::from(vec![Some(5), None])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some("a"), Some("")])) as ArrayRef,
+        ];
+        let rows = converter.convert_columns(&columns).unwrap();
+        let mut spilled = vec![];
+        converter.write_rows(&rows, &mut spilled).unwrap();
+
+        let mut expected = b"ARROWROW".to_vec();
+        expected.push(1);
+        expected.extend_from_slice(&2_u32.to_le_bytes());
+        // Int32, ASC NULLS FIRST
+        expected.push(2);
+        expected.extend_from_slice(&5_u32.to_le_bytes());
+        expected.extend_from_slice(b"Int32");
+        // Utf8, DESC NULLS FIRST
+        expected.push(3);
+        expected.extend_from_slice(&4_u32.to_le_bytes());
+        expected.extend_from_slice(b"Utf8");
+        expected.extend_from_slice(&2_u64.to_le_bytes());
+        expected.extend_from_slice(&15_u64.to_le_bytes());
+        expected.extend_from_slice(&6_u64.to_le_bytes());
+        // 5, "a"
+        expected.extend_from_slice(&[1, 0x80, 0, 0, 5, !2, !b'a']);
+        expected.extend_from_slice(&[!0; 7]);
+        expected.push(!1);
+        // null, ""
+        expected.extend_from_slice(&[0, 0, 0, 0, 0, !1]);
+        assert_eq!(spilled, expected);
+    }
+
+    #[test]
+    fn test_versioned_errors() {
+        let fields = vec![SortField::new(DataType::Int32)];
+        let array = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+
+        let unversioned = RowConverter::new(fields.clone()).unwrap();
+        assert_eq!(unversioned.version(), None);
+        let rows = unversioned
+            .convert_columns(std::slice::from_ref(&array))
+            .unwrap();
+        let err = unversioned.write_rows(&rows, vec![]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Only rows from a versioned RowConverter can be serialized"
+        );
+        let err = unversioned.read_rows(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Only a versioned RowConverter can deserialize rows"
+        );
+
+        let converter = RowConverter::new_versioned(fields, RowFormatVersion::V1).unwrap();
+        let rows = converter.convert_columns(&[array]).unwrap();
+        let mut spilled = vec![];
+        converter.write_rows(&rows, &mut spilled).unwrap();
+
+        let err = converter.read_rows(&spilled[1..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Serialized rows are missing the expected magic bytes"
+        );
+
+        let mut data = spilled.clone();
+        data[8] = 2;
+        let err = converter.read_rows(&data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Serialized rows have format version 2, expected 1"
+        );
+
+        let two = vec![
+            SortField::new(DataType::Int32),
+            SortField::new(DataType::Int32),
+        ];
+        let two = RowConverter::new_versioned(two, RowFormatVersion::V1).unwrap();
+        let err = two.read_rows(&spilled).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Serialized rows have 1 fields, expected 2"
+        );
+
+        let int64 = vec![SortField::new(DataType::Int64)];
+        let int64 = RowConverter::new_versioned(int64, RowFormatVersion::V1).unwrap();
+        let err = int64.read_rows(&spilled).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Serialized rows have data type Int32 for field 0, expected Int64"
+        );
+
+        let desc = vec![SortField::new_with_options(
+            DataType::Int32,
+            SortOptions::default().desc(),
+        )];
+        let desc = RowConverter::new_versioned(desc, RowFormatVersion::V1).unwrap();
+        let err = desc.read_rows(&spilled).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Serialized rows have sort options ASC NULLS FIRST for field 0, expected DESC NULLS FIRST"
+        );
+
+        for len in [4, 12, 15, 20, 25, 40, spilled.len() - 1] {
+            let err = converter.read_rows(&spilled[..len]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Parser error: Serialized rows are truncated"
+            );
+        }
+    }
 }
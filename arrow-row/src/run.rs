@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::{RowConverter, Rows, SortField};
+use arrow_array::types::RunEndIndexType;
+use arrow_array::{Array, PrimitiveArray, RunArray};
+use arrow_buffer::{ArrowNativeType, ScalarBuffer};
+use arrow_data::transform::MutableArrayData;
+use arrow_data::ArrayDataBuilder;
+use arrow_schema::ArrowError;
+
+/// Returns the physical index and logical length of each run of `array`
+fn runs<R: RunEndIndexType>(array: &RunArray<R>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let run_ends = array.run_ends();
+    let (offset, len) = (run_ends.offset(), run_ends.len());
+    let physical = match len {
+        0 => 0..0,
+        _ => run_ends.get_start_physical_index()..run_ends.get_end_physical_index() + 1,
+    };
+
+    let mut start = 0;
+    physical.map(move |idx| {
+        let end = (run_ends.values()[idx].as_usize() - offset).min(len);
+        let run_len = end - start;
+        start = end;
+        (idx, run_len)
+    })
+}
+
+/// Adds the length of the encoded run value to each logical index of `array`
+pub fn compute_lengths<R: RunEndIndexType>(
+    lengths: &mut [usize],
+    rows: &Rows,
+    array: &RunArray<R>,
+) {
+    let mut lengths = lengths.iter_mut();
+    for (idx, run_len) in runs(array) {
+        let len = rows.row(idx).data.len();
+        lengths.by_ref().take(run_len).for_each(|x| *x += len);
+    }
+}
+
+/// Encodes each logical value of `array` using the encoding of its run in `rows`
+pub fn encode<R: RunEndIndexType>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &Rows,
+    array: &RunArray<R>,
+) {
+    let mut offsets = offsets.iter_mut().skip(1);
+    for (idx, run_len) in runs(array) {
+        let row = rows.row(idx).data;
+        for offset in offsets.by_ref().take(run_len) {
+            let end_offset = *offset + row.len();
+            data[*offset..end_offset].copy_from_slice(row);
+            *offset = end_offset;
+        }
+    }
+}
+
+/// Decodes a [`RunArray`] from `rows`, merging consecutive equal values into a single run
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `converter`
+pub unsafe fn decode<R: RunEndIndexType>(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+    validate_utf8: bool,
+) -> Result<RunArray<R>, ArrowError> {
+    let len = R::Native::from_usize(rows.len()).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "{} rows exceed the maximum run end for {}",
+            rows.len(),
+            R::DATA_TYPE
+        ))
+    })?;
+
+    let encoded: Vec<_> = rows.to_vec();
+    let values = converter.convert_raw(rows, validate_utf8)?;
+    let values = values[0].to_data();
+
+    // The row format is canonical, so equal values have identical encodings
+    let mut run_starts = vec![];
+    let mut run_ends = vec![];
+    let mut previous = None;
+    for (idx, (encoded, remaining)) in encoded.iter().zip(rows.iter()).enumerate() {
+        let value = &encoded[..encoded.len() - remaining.len()];
+        if previous != Some(value) {
+            if idx != 0 {
+                run_ends.push(R::Native::usize_as(idx));
+            }
+            run_starts.push(idx);
+            previous = Some(value);
+        }
+    }
+    if !rows.is_empty() {
+        run_ends.push(len);
+    }
+
+    let mut run_values = MutableArrayData::new(vec![&values], false, run_starts.len());
+    for start in run_starts {
+        run_values.extend(0, start, start + 1);
+    }
+    let run_ends = PrimitiveArray::<R>::new(ScalarBuffer::from(run_ends), None);
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(rows.len())
+        .add_child_data(run_ends.into_data())
+        .add_child_data(run_values.freeze());
+
+    Ok(RunArray::from(unsafe { builder.build_unchecked() }))
+}
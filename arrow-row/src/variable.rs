@@ -17,6 +17,7 @@
 
 use crate::null_sentinel;
 use arrow_array::builder::BufferBuilder;
+use arrow_array::types::ByteViewType;
 use arrow_array::*;
 use arrow_buffer::bit_util::ceil;
 use arrow_buffer::MutableBuffer;
@@ -84,6 +85,48 @@ pub fn encode<'a, I: Iterator<Item = Option<&'a [u8]>>>(
     }
 }
 
+/// Adds the encoded length of each value of `array` to `lengths`, computing
+/// the lengths from the views without accessing the data buffers
+pub fn compute_lengths_view<T: ByteViewType + ?Sized>(
+    lengths: &mut [usize],
+    array: &GenericByteViewArray<T>,
+) {
+    let views = array.views().iter();
+    match array.nulls().filter(|n| n.null_count() > 0) {
+        Some(nulls) => {
+            lengths
+                .iter_mut()
+                .zip(views.zip(nulls.iter()))
+                .for_each(|(length, (view, valid))| {
+                    *length += padded_length(valid.then_some(*view as u32 as usize))
+                })
+        }
+        None => lengths
+            .iter_mut()
+            .zip(views)
+            .for_each(|(length, view)| *length += padded_length(Some(*view as u32 as usize))),
+    }
+}
+
+/// Encodes the values of `array`, reading them directly from the views and data buffers
+pub fn encode_view<T: ByteViewType + ?Sized>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    array: &GenericByteViewArray<T>,
+    opts: SortOptions,
+) {
+    let values = array.bytes_iter();
+    match array.nulls().filter(|n| n.null_count() > 0) {
+        Some(nulls) => {
+            let values = values
+                .zip(nulls.iter())
+                .map(|(v, valid)| valid.then_some(v));
+            encode(data, offsets, values, opts)
+        }
+        None => encode(data, offsets, values.map(Some), opts),
+    }
+}
+
 pub fn encode_null(out: &mut [u8], opts: SortOptions) -> usize {
     out[0] = null_sentinel(opts);
     1
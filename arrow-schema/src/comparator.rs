@@ -0,0 +1,471 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{ArrowError, DataType, Field, Fields, Schema};
+
+/// Compares [`Schema`], [`Field`] and [`DataType`] for equality, optionally ignoring
+/// some of their properties
+///
+/// By default, the comparison is the same as [`PartialEq`], except that the
+/// `dict_id` and `dict_is_ordered` of fields are ignored.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use arrow_schema::{DataType, Field, Schema, SchemaComparator};
+/// let expected = Schema::new(vec![
+///     Field::new("a", DataType::Int32, false),
+///     Field::new("b", DataType::Utf8, false),
+/// ]);
+/// let actual = Schema::new(vec![
+///     Field::new("b", DataType::Utf8, true),
+///     Field::new("a", DataType::Int32, true)
+///         .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+/// ]);
+///
+/// let comparator = SchemaComparator::new();
+/// assert!(!comparator.equals_schema(&expected, &actual));
+///
+/// let comparator = comparator
+///     .with_match_by_name(true)
+///     .with_ignore_nullability(true)
+///     .with_ignore_metadata(true);
+/// assert!(comparator.equals_schema(&expected, &actual));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SchemaComparator {
+    ignore_metadata: bool,
+    ignore_nullability: bool,
+    ignore_names: bool,
+    ignore_dictionary_key_types: bool,
+    match_by_name: bool,
+}
+
+impl SchemaComparator {
+    /// Create a new [`SchemaComparator`] that compares all properties
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignore the metadata of schemas and fields, including nested fields
+    pub fn with_ignore_metadata(mut self, ignore_metadata: bool) -> Self {
+        self.ignore_metadata = ignore_metadata;
+        self
+    }
+
+    /// Ignore the nullability of fields, including nested fields
+    pub fn with_ignore_nullability(mut self, ignore_nullability: bool) -> Self {
+        self.ignore_nullability = ignore_nullability;
+        self
+    }
+
+    /// Ignore the names of fields matched by position, including nested fields
+    /// such as the item field of a [`DataType::List`]
+    pub fn with_ignore_names(mut self, ignore_names: bool) -> Self {
+        self.ignore_names = ignore_names;
+        self
+    }
+
+    /// Ignore the key type of [`DataType::Dictionary`], comparing only the value type
+    pub fn with_ignore_dictionary_key_types(mut self, ignore_dictionary_key_types: bool) -> Self {
+        self.ignore_dictionary_key_types = ignore_dictionary_key_types;
+        self
+    }
+
+    /// Match the fields of schemas and [`DataType::Struct`] by name instead of by
+    /// position, ignoring their order
+    pub fn with_match_by_name(mut self, match_by_name: bool) -> Self {
+        self.match_by_name = match_by_name;
+        self
+    }
+
+    /// Returns `true` if `left` and `right` are equal
+    pub fn equals_schema(&self, left: &Schema, right: &Schema) -> bool {
+        self.validate(left, right).is_ok()
+    }
+
+    /// Returns `true` if `left` and `right` are equal
+    pub fn equals_field(&self, left: &Field, right: &Field) -> bool {
+        self.check_field("", left, right).is_ok()
+    }
+
+    /// Returns `true` if `left` and `right` are equal
+    pub fn equals_data_type(&self, left: &DataType, right: &DataType) -> bool {
+        self.check_data_type("", left, right).is_ok()
+    }
+
+    /// Returns an error describing the first difference between `expected` and `actual`,
+    /// if any
+    ///
+    /// ```
+    /// # use arrow_schema::{DataType, Field, Schema, SchemaComparator};
+    /// let expected = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+    /// let actual = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+    /// let err = SchemaComparator::new().validate(&expected, &actual).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Schema error: Expected field 'a' to have data type Int32, got Int64"
+    /// );
+    /// ```
+    pub fn validate(&self, expected: &Schema, actual: &Schema) -> Result<(), ArrowError> {
+        if !self.ignore_metadata && expected.metadata != actual.metadata {
+            return Err(ArrowError::SchemaError(format!(
+                "Expected schema metadata {:?}, got {:?}",
+                sorted(&expected.metadata),
+                sorted(&actual.metadata)
+            )));
+        }
+        self.check_fields("", &expected.fields, &actual.fields)
+    }
+
+    fn check_fields(
+        &self,
+        path: &str,
+        expected: &Fields,
+        actual: &Fields,
+    ) -> Result<(), ArrowError> {
+        if self.match_by_name {
+            for e in expected {
+                match actual.find(e.name()) {
+                    Some((_, a)) => self.check_field(path, e, a)?,
+                    None => {
+                        return Err(ArrowError::SchemaError(format!(
+                            "Expected field '{}' not found",
+                            child_path(path, e.name())
+                        )))
+                    }
+                }
+            }
+            if let Some(a) = actual.iter().find(|a| expected.find(a.name()).is_none()) {
+                return Err(ArrowError::SchemaError(format!(
+                    "Unexpected field '{}'",
+                    child_path(path, a.name())
+                )));
+            }
+            return Ok(());
+        }
+
+        if expected.len() != actual.len() {
+            return Err(ArrowError::SchemaError(match path.is_empty() {
+                true => format!("Expected {} fields, got {}", expected.len(), actual.len()),
+                false => format!(
+                    "Expected field '{path}' to have {} children, got {}",
+                    expected.len(),
+                    actual.len()
+                ),
+            }));
+        }
+        expected
+            .iter()
+            .zip(actual)
+            .try_for_each(|(e, a)| self.check_field(path, e, a))
+    }
+
+    fn check_field(
+        &self,
+        parent: &str,
+        expected: &Field,
+        actual: &Field,
+    ) -> Result<(), ArrowError> {
+        let path = child_path(parent, expected.name());
+        if !self.ignore_names && expected.name() != actual.name() {
+            return Err(ArrowError::SchemaError(format!(
+                "Expected field '{path}', got '{}'",
+                child_path(parent, actual.name())
+            )));
+        }
+        if !self.ignore_nullability && expected.is_nullable() != actual.is_nullable() {
+            let nullability = |nullable| match nullable {
+                true => "nullable",
+                false => "non-nullable",
+            };
+            return Err(ArrowError::SchemaError(format!(
+                "Expected field '{path}' to be {}, got {}",
+                nullability(expected.is_nullable()),
+                nullability(actual.is_nullable())
+            )));
+        }
+        if !self.ignore_metadata && expected.metadata() != actual.metadata() {
+            return Err(ArrowError::SchemaError(format!(
+                "Expected field '{path}' to have metadata {:?}, got {:?}",
+                sorted(expected.metadata()),
+                sorted(actual.metadata())
+            )));
+        }
+        self.check_data_type(&path, expected.data_type(), actual.data_type())
+    }
+
+    fn check_data_type(
+        &self,
+        path: &str,
+        expected: &DataType,
+        actual: &DataType,
+    ) -> Result<(), ArrowError> {
+        match (expected, actual) {
+            (DataType::List(e), DataType::List(a))
+            | (DataType::LargeList(e), DataType::LargeList(a))
+            | (DataType::ListView(e), DataType::ListView(a))
+            | (DataType::LargeListView(e), DataType::LargeListView(a)) => {
+                self.check_field(path, e, a)
+            }
+            (DataType::FixedSizeList(e, e_size), DataType::FixedSizeList(a, a_size))
+                if e_size == a_size =>
+            {
+                self.check_field(path, e, a)
+            }
+            (DataType::Map(e, e_sorted), DataType::Map(a, a_sorted)) if e_sorted == a_sorted => {
+                self.check_field(path, e, a)
+            }
+            (DataType::Struct(e), DataType::Struct(a)) => self.check_fields(path, e, a),
+            (DataType::Union(e, e_mode), DataType::Union(a, a_mode))
+                if e_mode == a_mode
+                    && e.len() == a.len()
+                    && e.iter().zip(a.iter()).all(|(e, a)| e.0 == a.0) =>
+            {
+                e.iter()
+                    .zip(a.iter())
+                    .try_for_each(|((_, e), (_, a))| self.check_field(path, e, a))
+            }
+            (DataType::Dictionary(e_key, e_value), DataType::Dictionary(a_key, a_value))
+                if self.ignore_dictionary_key_types || e_key == a_key =>
+            {
+                self.check_data_type(path, e_value, a_value)
+            }
+            (
+                DataType::RunEndEncoded(e_ends, e_values),
+                DataType::RunEndEncoded(a_ends, a_values),
+            ) => {
+                self.check_field(path, e_ends, a_ends)?;
+                self.check_field(path, e_values, a_values)
+            }
+            _ if expected == actual => Ok(()),
+            _ => Err(ArrowError::SchemaError(match path.is_empty() {
+                true => format!("Expected data type {expected}, got {actual}"),
+                false => {
+                    format!("Expected field '{path}' to have data type {expected}, got {actual}")
+                }
+            })),
+        }
+    }
+}
+
+/// Returns the path of the field `name` within the field at `parent`
+fn child_path(parent: &str, name: &str) -> String {
+    match parent.is_empty() {
+        true => name.to_string(),
+        false => format!("{parent}.{name}"),
+    }
+}
+
+/// Returns `metadata` sorted by key, for deterministic error messages
+fn sorted(metadata: &HashMap<String, String>) -> BTreeMap<&str, &str> {
+    metadata
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnionFields;
+    use std::sync::Arc;
+
+    fn err(result: Result<(), ArrowError>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_compare_schema() {
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_struct(
+                "b",
+                vec![
+                    Field::new("c", DataType::Utf8, true),
+                    Field::new_list("d", Field::new_list_field(DataType::Int64, true), true),
+                ],
+                false,
+            ),
+        ]);
+        let comparator = SchemaComparator::new();
+        comparator.validate(&expected, &expected).unwrap();
+
+        let actual = expected.clone().with_metadata(metadata.clone());
+        assert_eq!(
+            err(comparator.validate(&expected, &actual)),
+            "Schema error: Expected schema metadata {}, got {\"k\": \"v\"}"
+        );
+        let comparator_ignore_metadata = comparator.with_ignore_metadata(true);
+        assert!(comparator_ignore_metadata.equals_schema(&expected, &actual));
+
+        let actual = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_struct(
+                "b",
+                vec![
+                    Field::new("c", DataType::Utf8, true).with_metadata(metadata),
+                    Field::new_list("d", Field::new("element", DataType::Int64, false), true),
+                ],
+                false,
+            ),
+        ]);
+        assert_eq!(
+            err(comparator.validate(&expected, &actual)),
+            "Schema error: Expected field 'b.c' to have metadata {}, got {\"k\": \"v\"}"
+        );
+        assert_eq!(
+            err(comparator_ignore_metadata.validate(&expected, &actual)),
+            "Schema error: Expected field 'b.d.item', got 'b.d.element'"
+        );
+        let comparator = comparator_ignore_metadata.with_ignore_names(true);
+        assert_eq!(
+            err(comparator.validate(&expected, &actual)),
+            "Schema error: Expected field 'b.d.item' to be nullable, got non-nullable"
+        );
+        let comparator = comparator.with_ignore_nullability(true);
+        assert!(comparator.equals_schema(&expected, &actual));
+
+        let actual = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        assert_eq!(
+            err(comparator.validate(&expected, &actual)),
+            "Schema error: Expected 2 fields, got 1"
+        );
+    }
+
+    #[test]
+    fn test_compare_by_name() {
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_struct(
+                "b",
+                vec![
+                    Field::new("c", DataType::Utf8, true),
+                    Field::new("d", DataType::Int64, true),
+                ],
+                false,
+            ),
+        ]);
+        let actual = Schema::new(vec![
+            Field::new_struct(
+                "b",
+                vec![
+                    Field::new("d", DataType::Int64, true),
+                    Field::new("c", DataType::Utf8, true),
+                ],
+                false,
+            ),
+            Field::new("a", DataType::Int32, false),
+        ]);
+        let comparator = SchemaComparator::new();
+        assert_eq!(
+            err(comparator.validate(&expected, &actual)),
+            "Schema error: Expected field 'a', got 'b'"
+        );
+        let comparator = comparator.with_ignore_names(true);
+        assert!(err(comparator.validate(&expected, &actual))
+            .starts_with("Schema error: Expected field 'a' to have data type Int32, got Struct("));
+
+        let comparator = SchemaComparator::new().with_match_by_name(true);
+        comparator.validate(&expected, &actual).unwrap();
+
+        let actual = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_struct("b", vec![Field::new("d", DataType::Int64, true)], false),
+        ]);
+        assert_eq!(
+            err(comparator.validate(&expected, &actual)),
+            "Schema error: Expected field 'b.c' not found"
+        );
+        assert_eq!(
+            err(comparator.validate(&actual, &expected)),
+            "Schema error: Unexpected field 'b.c'"
+        );
+    }
+
+    #[test]
+    fn test_compare_data_type() {
+        let dictionary =
+            |key: DataType| DataType::Dictionary(Box::new(key), Box::new(DataType::Utf8));
+        let comparator = SchemaComparator::new();
+        assert!(
+            comparator.equals_data_type(&dictionary(DataType::Int32), &dictionary(DataType::Int32))
+        );
+        assert!(
+            !comparator.equals_data_type(&dictionary(DataType::Int32), &dictionary(DataType::Int8))
+        );
+        assert_eq!(
+            err(comparator.check_data_type("", &DataType::Utf8, &DataType::LargeUtf8)),
+            "Schema error: Expected data type Utf8, got LargeUtf8"
+        );
+
+        let comparator = comparator.with_ignore_dictionary_key_types(true);
+        assert!(
+            comparator.equals_data_type(&dictionary(DataType::Int32), &dictionary(DataType::Int8))
+        );
+        assert!(!comparator.equals_data_type(
+            &dictionary(DataType::Int32),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::LargeUtf8))
+        ));
+        assert!(!comparator.equals_data_type(
+            &DataType::new_fixed_size_list(DataType::Int32, 2, true),
+            &DataType::new_fixed_size_list(DataType::Int32, 3, true)
+        ));
+
+        let union = |name: &str, nullable: bool| {
+            DataType::Union(
+                UnionFields::new(
+                    vec![0, 1],
+                    vec![
+                        Field::new("a", DataType::Int32, true),
+                        Field::new(name, DataType::Utf8, nullable),
+                    ],
+                ),
+                crate::UnionMode::Dense,
+            )
+        };
+        assert!(comparator.equals_data_type(&union("b", true), &union("b", true)));
+        assert!(!comparator.equals_data_type(&union("b", true), &union("c", false)));
+        let comparator = comparator
+            .with_ignore_names(true)
+            .with_ignore_nullability(true);
+        assert!(comparator.equals_data_type(&union("b", true), &union("c", false)));
+
+        let ree = |nullable: bool| {
+            DataType::RunEndEncoded(
+                Arc::new(Field::new("run_ends", DataType::Int32, false)),
+                Arc::new(Field::new("values", DataType::Utf8, nullable)),
+            )
+        };
+        assert!(comparator.equals_data_type(&ree(true), &ree(false)));
+        assert!(!SchemaComparator::new().equals_data_type(&ree(true), &ree(false)));
+    }
+
+    #[test]
+    fn test_compare_field() {
+        let dictionary = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let field = Field::new_dict("a", dictionary.clone(), true, 1, false);
+        let comparator = SchemaComparator::new();
+        assert!(comparator.equals_field(&field, &Field::new_dict("a", dictionary, true, 2, true)));
+        assert!(!comparator.equals_field(&field, &field.clone().with_name("b")));
+        assert!(comparator
+            .with_ignore_names(true)
+            .equals_field(&field, &field.clone().with_name("b")));
+    }
+}
@@ -0,0 +1,634 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Write;
+
+use crate::extension::ExtensionType;
+use crate::{ArrowError, DataType};
+
+/// The [`arrow.fixed_shape_tensor`] extension type
+///
+/// A multidimensional array of a fixed shape per value, stored as a
+/// [`DataType::FixedSizeList`] of the tensor elements in row-major order
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_schema::{DataType, Field};
+/// # use arrow_schema::extension::{ExtensionType, FixedShapeTensor, FixedShapeTensorMetadata};
+/// let metadata = FixedShapeTensorMetadata::try_new(vec![2, 3], None, None).unwrap();
+/// let item = Arc::new(Field::new("item", DataType::Float32, false));
+/// let data_type = DataType::FixedSizeList(item, metadata.list_size() as i32);
+/// let tensor = FixedShapeTensor::try_new(&data_type, metadata).unwrap();
+/// assert_eq!(tensor.value_type(), &DataType::Float32);
+/// assert_eq!(tensor.dimensions(), 2);
+///
+/// let field = Field::new("tensor", data_type, true)
+///     .try_with_extension_type(tensor)
+///     .unwrap();
+/// assert_eq!(field.extension_type_metadata(), Some(r#"{"shape":[2,3]}"#));
+/// ```
+///
+/// [`arrow.fixed_shape_tensor`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#fixed-shape-tensor
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedShapeTensor {
+    value_type: DataType,
+    metadata: FixedShapeTensorMetadata,
+}
+
+impl FixedShapeTensor {
+    /// Returns the data type of the tensor elements
+    pub fn value_type(&self) -> &DataType {
+        &self.value_type
+    }
+
+    /// Returns the physical shape of the tensors, that of their elements in row-major order
+    pub fn shape(&self) -> &[usize] {
+        &self.metadata.shape
+    }
+
+    /// Returns the number of elements of each tensor, the product of its shape
+    pub fn list_size(&self) -> usize {
+        self.metadata.list_size()
+    }
+
+    /// Returns the logical shape of the tensors
+    ///
+    /// This is the [`Self::shape`] with its dimensions reordered by the
+    /// [`Self::permutation`], if any, such that logical dimension `i` is physical
+    /// dimension `permutation[i]`
+    pub fn logical_shape(&self) -> Vec<usize> {
+        match self.permutation() {
+            Some(p) => p.iter().map(|&d| self.metadata.shape[d]).collect(),
+            None => self.metadata.shape.clone(),
+        }
+    }
+
+    /// Returns the strides of the [`Self::logical_shape`], the number of elements
+    /// between consecutive indices of each logical dimension in the row-major storage
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_schema::{DataType, Field};
+    /// # use arrow_schema::extension::{ExtensionType, FixedShapeTensor, FixedShapeTensorMetadata};
+    /// let metadata = FixedShapeTensorMetadata::try_new(vec![2, 3, 4], None, Some(vec![2, 0, 1]))
+    ///     .unwrap();
+    /// let item = Arc::new(Field::new("item", DataType::Float32, false));
+    /// let data_type = DataType::FixedSizeList(item, 24);
+    /// let tensor = FixedShapeTensor::try_new(&data_type, metadata).unwrap();
+    /// assert_eq!(tensor.logical_shape(), vec![4, 2, 3]);
+    /// assert_eq!(tensor.strides(), vec![1, 12, 4]);
+    /// ```
+    pub fn strides(&self) -> Vec<usize> {
+        let shape = &self.metadata.shape;
+        let mut physical = vec![1; shape.len()];
+        for i in (1..shape.len()).rev() {
+            physical[i - 1] = physical[i] * shape[i];
+        }
+        match self.permutation() {
+            Some(p) => p.iter().map(|&d| physical[d]).collect(),
+            None => physical,
+        }
+    }
+
+    /// Returns the number of dimensions of the tensors
+    pub fn dimensions(&self) -> usize {
+        self.metadata.shape.len()
+    }
+
+    /// Returns the names of the dimensions of the tensors, if any
+    pub fn dim_names(&self) -> Option<&[String]> {
+        self.metadata.dim_names.as_deref()
+    }
+
+    /// Returns the permutation of the dimensions of the tensors, if any
+    pub fn permutation(&self) -> Option<&[usize]> {
+        self.metadata.permutation.as_deref()
+    }
+}
+
+impl ExtensionType for FixedShapeTensor {
+    const NAME: &'static str = "arrow.fixed_shape_tensor";
+
+    type Metadata = FixedShapeTensorMetadata;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &self.metadata
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        Some(self.metadata.to_json())
+    }
+
+    fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+        let metadata = metadata.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "{} extension type requires metadata",
+                Self::NAME
+            ))
+        })?;
+        let metadata = FixedShapeTensorMetadata::from_json(metadata).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "{} extension type metadata is invalid: {metadata}",
+                Self::NAME
+            ))
+        })?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::FixedSizeList(f, size)
+                if f.data_type() == &self.value_type
+                    && usize::try_from(*size).ok() == Some(self.metadata.list_size()) =>
+            {
+                Ok(())
+            }
+            d => Err(ArrowError::InvalidArgumentError(format!(
+                "{} extension type not supported for {d}",
+                Self::NAME
+            ))),
+        }
+    }
+
+    fn try_new(data_type: &DataType, metadata: Self::Metadata) -> Result<Self, ArrowError> {
+        metadata.validate()?;
+        let value_type = match data_type {
+            DataType::FixedSizeList(f, _) => f.data_type().clone(),
+            d => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{} extension type not supported for {d}",
+                    Self::NAME
+                )))
+            }
+        };
+        let tensor = Self {
+            value_type,
+            metadata,
+        };
+        tensor.supports_data_type(data_type)?;
+        Ok(tensor)
+    }
+}
+
+/// The metadata of the [`FixedShapeTensor`] extension type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedShapeTensorMetadata {
+    shape: Vec<usize>,
+    dim_names: Option<Vec<String>>,
+    permutation: Option<Vec<usize>>,
+}
+
+impl FixedShapeTensorMetadata {
+    /// Creates [`FixedShapeTensorMetadata`] for tensors of `shape`
+    ///
+    /// Returns an error if `dim_names` does not have a name for every dimension, or if
+    /// `permutation` is not a permutation of the dimensions
+    pub fn try_new(
+        shape: Vec<usize>,
+        dim_names: Option<Vec<String>>,
+        permutation: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let metadata = Self {
+            shape,
+            dim_names,
+            permutation,
+        };
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    /// Returns the number of elements of each tensor, the product of its shape
+    pub fn list_size(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Serializes this metadata as a JSON object, omitting absent fields
+    fn to_json(&self) -> String {
+        fn write_array<T>(out: &mut String, key: &str, values: &[T], f: fn(&mut String, &T)) {
+            write!(out, "\"{key}\":[").unwrap();
+            for (idx, value) in values.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                f(out, value);
+            }
+            out.push(']');
+        }
+
+        let write_index = |out: &mut String, d: &usize| write!(out, "{d}").unwrap();
+        let mut out = String::from("{");
+        write_array(&mut out, "shape", &self.shape, write_index);
+        if let Some(dim_names) = &self.dim_names {
+            out.push(',');
+            write_array(&mut out, "dim_names", dim_names, |out, s| {
+                out.push('"');
+                for c in s.chars() {
+                    match c {
+                        '"' => out.push_str("\\\""),
+                        '\\' => out.push_str("\\\\"),
+                        c if c < ' ' => write!(out, "\\u{:04x}", c as u32).unwrap(),
+                        c => out.push(c),
+                    }
+                }
+                out.push('"');
+            });
+        }
+        if let Some(permutation) = &self.permutation {
+            out.push(',');
+            write_array(&mut out, "permutation", permutation, write_index);
+        }
+        out.push('}');
+        out
+    }
+
+    /// Parses metadata serialized as a JSON object, ignoring unknown fields
+    ///
+    /// Returns `None` if `json` is not valid JSON of the expected structure
+    fn from_json(json: &str) -> Option<Self> {
+        let mut parser = JsonParser {
+            input: json,
+            pos: 0,
+        };
+        let mut shape = None;
+        let mut dim_names = None;
+        let mut permutation = None;
+
+        parser.expect(b'{')?;
+        if !parser.consume(b'}') {
+            loop {
+                let key = parser.string()?;
+                parser.expect(b':')?;
+                match key.as_str() {
+                    "shape" => shape = Some(parser.array(JsonParser::index)?),
+                    "dim_names" => dim_names = parser.nullable(|p| p.array(JsonParser::string))?,
+                    "permutation" => {
+                        permutation = parser.nullable(|p| p.array(JsonParser::index))?
+                    }
+                    _ => parser.skip_value()?,
+                }
+                if parser.consume(b'}') {
+                    break;
+                }
+                parser.expect(b',')?;
+            }
+        }
+        parser.skip_whitespace();
+        if parser.pos != json.len() {
+            return None;
+        }
+
+        Some(Self {
+            shape: shape?,
+            dim_names,
+            permutation,
+        })
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        let dimensions = self.shape.len();
+        if let Some(dim_names) = &self.dim_names {
+            if dim_names.len() != dimensions {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{} extension type expects {dimensions} dimension names, got {}",
+                    FixedShapeTensor::NAME,
+                    dim_names.len()
+                )));
+            }
+        }
+        if let Some(permutation) = &self.permutation {
+            let mut seen = vec![false; dimensions];
+            let valid = permutation.len() == dimensions
+                && permutation
+                    .iter()
+                    .all(|&d| d < dimensions && !std::mem::replace(&mut seen[d], true));
+            if !valid {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{} extension type expects a permutation of {dimensions} dimensions, got {permutation:?}",
+                    FixedShapeTensor::NAME
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A minimal parser for the JSON [`FixedShapeTensorMetadata`] is serialized as
+struct JsonParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl JsonParser<'_> {
+    fn skip_whitespace(&mut self) {
+        let remaining = &self.input[self.pos..];
+        self.pos += remaining.len() - remaining.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    /// Consumes `b` if it is the next non-whitespace byte
+    fn consume(&mut self, b: u8) -> bool {
+        let found = self.peek() == Some(b);
+        self.pos += found as usize;
+        found
+    }
+
+    fn expect(&mut self, b: u8) -> Option<()> {
+        self.consume(b).then_some(())
+    }
+
+    /// Consumes `literal` if the remaining input starts with it
+    fn literal(&mut self, literal: &str) -> bool {
+        self.skip_whitespace();
+        let found = self.input[self.pos..].starts_with(literal);
+        if found {
+            self.pos += literal.len();
+        }
+        found
+    }
+
+    /// Parses `null` as `Some(None)`, or a value with `f`
+    fn nullable<T>(&mut self, f: impl FnOnce(&mut Self) -> Option<T>) -> Option<Option<T>> {
+        match self.literal("null") {
+            true => Some(None),
+            false => f(self).map(Some),
+        }
+    }
+
+    fn array<T>(&mut self, f: impl Fn(&mut Self) -> Option<T>) -> Option<Vec<T>> {
+        self.expect(b'[')?;
+        let mut values = vec![];
+        if self.consume(b']') {
+            return Some(values);
+        }
+        loop {
+            values.push(f(self)?);
+            if self.consume(b']') {
+                return Some(values);
+            }
+            self.expect(b',')?;
+        }
+    }
+
+    /// Parses a non-negative integer
+    fn index(&mut self) -> Option<usize> {
+        self.skip_whitespace();
+        let remaining = &self.input[self.pos..];
+        let len = remaining.len()
+            - remaining
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        let digits = &remaining[..len];
+        if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+            return None;
+        }
+        self.pos += len;
+        digits.parse().ok()
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+        loop {
+            let (idx, c) = chars.next()?;
+            match c {
+                '"' => {
+                    self.pos += idx + 1;
+                    return Some(out);
+                }
+                '\\' => match chars.next()?.1 {
+                    '"' => out.push('"'),
+                    '\\' => out.push('\\'),
+                    '/' => out.push('/'),
+                    'b' => out.push('\u{8}'),
+                    'f' => out.push('\u{c}'),
+                    'n' => out.push('\n'),
+                    'r' => out.push('\r'),
+                    't' => out.push('\t'),
+                    'u' => {
+                        fn hex(chars: &mut std::str::CharIndices<'_>) -> Option<u32> {
+                            let digits: String = chars.take(4).map(|(_, c)| c).collect();
+                            match digits.len() {
+                                4 => u32::from_str_radix(&digits, 16).ok(),
+                                _ => None,
+                            }
+                        }
+                        let high = hex(&mut chars)?;
+                        let code = match high {
+                            0xD800..=0xDBFF => {
+                                let (_, backslash) = chars.next()?;
+                                let (_, u) = chars.next()?;
+                                let low = hex(&mut chars)?;
+                                if backslash != '\\'
+                                    || u != 'u'
+                                    || !(0xDC00..=0xDFFF).contains(&low)
+                                {
+                                    return None;
+                                }
+                                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                            }
+                            code => code,
+                        };
+                        out.push(char::from_u32(code)?);
+                    }
+                    _ => return None,
+                },
+                c if c < ' ' => return None,
+                c => out.push(c),
+            }
+        }
+    }
+
+    /// Skips over any JSON value
+    fn skip_value(&mut self) -> Option<()> {
+        match self.peek()? {
+            b'"' => self.string().map(|_| ()),
+            b'[' => self.array(Self::skip_value).map(|_| ()),
+            b'{' => {
+                self.pos += 1;
+                if self.consume(b'}') {
+                    return Some(());
+                }
+                loop {
+                    self.string()?;
+                    self.expect(b':')?;
+                    self.skip_value()?;
+                    if self.consume(b'}') {
+                        return Some(());
+                    }
+                    self.expect(b',')?;
+                }
+            }
+            _ if self.literal("null") || self.literal("true") || self.literal("false") => Some(()),
+            _ => {
+                let remaining = &self.input[self.pos..];
+                let len = remaining.len()
+                    - remaining
+                        .trim_start_matches(|c: char| {
+                            matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E')
+                        })
+                        .len();
+                remaining[..len].parse::<f64>().ok()?;
+                self.pos += len;
+                Some(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Field;
+    use std::sync::Arc;
+
+    fn storage_type(value_type: DataType, size: i32) -> DataType {
+        DataType::FixedSizeList(Arc::new(Field::new("item", value_type, false)), size)
+    }
+
+    #[test]
+    fn test_fixed_shape_tensor() {
+        let metadata = FixedShapeTensorMetadata::try_new(
+            vec![2, 5, 3],
+            Some(vec!["C".to_string(), "H".to_string(), "W".to_string()]),
+            Some(vec![2, 0, 1]),
+        )
+        .unwrap();
+        let data_type = storage_type(DataType::Int64, 30);
+        let tensor = FixedShapeTensor::try_new(&data_type, metadata).unwrap();
+        assert_eq!(tensor.shape(), &[2, 5, 3]);
+        assert_eq!(tensor.dim_names().unwrap(), &["C", "H", "W"]);
+        assert_eq!(tensor.permutation(), Some(&[2, 0, 1][..]));
+
+        let field = Field::new("t", data_type.clone(), true)
+            .try_with_extension_type(tensor.clone())
+            .unwrap();
+        assert_eq!(
+            field.extension_type_metadata(),
+            Some(r#"{"shape":[2,5,3],"dim_names":["C","H","W"],"permutation":[2,0,1]}"#)
+        );
+        assert_eq!(
+            field.try_extension_type::<FixedShapeTensor>().unwrap(),
+            tensor
+        );
+
+        let metadata =
+            FixedShapeTensor::deserialize_metadata(Some(r#"{"shape":[2,5,3]}"#)).unwrap();
+        let err =
+            FixedShapeTensor::try_new(&storage_type(DataType::Int64, 10), metadata).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.fixed_shape_tensor extension type not supported for FixedSizeList(Field { name: \"item\", data_type: Int64, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: {} }, 10)"
+        );
+
+        let err =
+            FixedShapeTensorMetadata::try_new(vec![2, 2], None, Some(vec![0, 0])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.fixed_shape_tensor extension type expects a permutation of 2 dimensions, got [0, 0]"
+        );
+
+        let err = FixedShapeTensorMetadata::try_new(vec![2, 2], Some(vec!["x".to_string()]), None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.fixed_shape_tensor extension type expects 2 dimension names, got 1"
+        );
+
+        let metadata = FixedShapeTensorMetadata::try_new(vec![2, 5, 3], None, None).unwrap();
+        let tensor = FixedShapeTensor::try_new(&data_type, metadata).unwrap();
+        assert_eq!(tensor.list_size(), 30);
+        assert_eq!(tensor.logical_shape(), vec![2, 5, 3]);
+        assert_eq!(tensor.strides(), vec![15, 3, 1]);
+
+        let metadata =
+            FixedShapeTensorMetadata::try_new(vec![2, 5, 3], None, Some(vec![2, 0, 1])).unwrap();
+        let tensor = FixedShapeTensor::try_new(&data_type, metadata).unwrap();
+        assert_eq!(tensor.logical_shape(), vec![3, 2, 5]);
+        assert_eq!(tensor.strides(), vec![1, 15, 3]);
+
+        let metadata = FixedShapeTensorMetadata::try_new(vec![], None, None).unwrap();
+        let tensor =
+            FixedShapeTensor::try_new(&storage_type(DataType::Int64, 1), metadata).unwrap();
+        assert_eq!(tensor.list_size(), 1);
+        assert!(tensor.strides().is_empty());
+
+        let err = FixedShapeTensor::deserialize_metadata(None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.fixed_shape_tensor extension type requires metadata"
+        );
+    }
+
+    #[test]
+    fn test_fixed_shape_tensor_metadata_json() {
+        let dim_names = vec!["a \"b\"\\".to_string(), "\u{e9}\n\u{1f600}".to_string()];
+        let metadata =
+            FixedShapeTensorMetadata::try_new(vec![10, 0], Some(dim_names), Some(vec![1, 0]))
+                .unwrap();
+        let json = metadata.to_json();
+        assert_eq!(
+            json,
+            "{\"shape\":[10,0],\"dim_names\":[\"a \\\"b\\\"\\\\\",\"\u{e9}\\u000a\u{1f600}\"],\"permutation\":[1,0]}"
+        );
+        assert_eq!(
+            FixedShapeTensorMetadata::from_json(&json).unwrap(),
+            metadata
+        );
+
+        let json = r#" { "extra" : [ {"x": -1.5e3, "y": [true, false, null]}, "}" ],
+            "dim_names": ["\u00e9\ud83d\ude00", "\/"], "shape" : [ 2 , 3 ],
+            "permutation": null } "#;
+        let metadata = FixedShapeTensorMetadata::from_json(json).unwrap();
+        let expected = FixedShapeTensorMetadata::try_new(
+            vec![2, 3],
+            Some(vec!["\u{e9}\u{1f600}".to_string(), "/".to_string()]),
+            None,
+        )
+        .unwrap();
+        assert_eq!(metadata, expected);
+
+        for json in [
+            "",
+            "{}",
+            "[]",
+            r#"{"shape":[1,2]"#,
+            r#"{"shape":[1,2]} x"#,
+            r#"{"shape":[1,2],}"#,
+            r#"{"shape":[-1]}"#,
+            r#"{"shape":[01]}"#,
+            r#"{"shape":[1.5]}"#,
+            r#"{"shape":[1],"dim_names":["\ud83d"]}"#,
+            r#"{"shape":[1],"dim_names":["\q"]}"#,
+            r#"{"shape":[1],"dim_names":[1]}"#,
+            r#"{"shape":[1],"extra":nul}"#,
+        ] {
+            assert_eq!(FixedShapeTensorMetadata::from_json(json), None, "{json}");
+        }
+
+        let err = FixedShapeTensor::deserialize_metadata(Some(r#"{"shape":[2,"#)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Invalid argument error: arrow.fixed_shape_tensor extension type metadata is invalid: {"shape":[2,"#
+        );
+    }
+}
@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::extension::ExtensionType;
+use crate::{ArrowError, DataType};
+
+/// The [`arrow.json`] extension type
+///
+/// A JSON document, stored as a [`DataType::Utf8`], [`DataType::LargeUtf8`] or
+/// [`DataType::Utf8View`]
+///
+/// [`arrow.json`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#json
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Json(());
+
+impl ExtensionType for Json {
+    const NAME: &'static str = "arrow.json";
+
+    type Metadata = ();
+
+    fn metadata(&self) -> &Self::Metadata {
+        &self.0
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        None
+    }
+
+    /// The specification allows an empty JSON object as metadata, which is accepted
+    /// along with no metadata at all
+    fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+        match metadata.map(str::trim) {
+            None | Some("") | Some("{}") => Ok(()),
+            Some(metadata) => Err(ArrowError::InvalidArgumentError(format!(
+                "{} extension type expects an empty object as metadata, got {metadata:?}",
+                Self::NAME
+            ))),
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Ok(()),
+            d => Err(ArrowError::InvalidArgumentError(format!(
+                "{} extension type not supported for {d}",
+                Self::NAME
+            ))),
+        }
+    }
+
+    fn try_new(data_type: &DataType, metadata: Self::Metadata) -> Result<Self, ArrowError> {
+        let json = Self(metadata);
+        json.supports_data_type(data_type)?;
+        Ok(json)
+    }
+}
@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The [canonical extension types](https://arrow.apache.org/docs/format/CanonicalExtensions.html)
+
+mod bool8;
+pub use bool8::Bool8;
+mod fixed_shape_tensor;
+pub use fixed_shape_tensor::{FixedShapeTensor, FixedShapeTensorMetadata};
+mod json;
+pub use json::Json;
+mod uuid;
+pub use uuid::Uuid;
+
+use crate::extension::ExtensionType;
+use crate::{ArrowError, Field};
+
+/// A canonical extension type
+///
+/// Identifies the canonical extension type of a [`Field`] from its metadata, validating
+/// the extension type metadata and the storage type of the [`Field`]
+///
+/// ```
+/// # use arrow_schema::{DataType, Field};
+/// # use arrow_schema::extension::{CanonicalExtensionType, Uuid};
+/// let field = Field::new("id", DataType::FixedSizeBinary(16), false)
+///     .try_with_extension_type(Uuid)
+///     .unwrap();
+/// let extension_type = CanonicalExtensionType::try_from(&field).unwrap();
+/// assert_eq!(extension_type, CanonicalExtensionType::Uuid(Uuid));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CanonicalExtensionType {
+    /// The `arrow.fixed_shape_tensor` extension type, see [`FixedShapeTensor`]
+    FixedShapeTensor(FixedShapeTensor),
+    /// The `arrow.json` extension type, see [`Json`]
+    Json(Json),
+    /// The `arrow.uuid` extension type, see [`Uuid`]
+    Uuid(Uuid),
+    /// The `arrow.bool8` extension type, see [`Bool8`]
+    Bool8(Bool8),
+}
+
+impl CanonicalExtensionType {
+    /// Returns the name of this extension type
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FixedShapeTensor(_) => FixedShapeTensor::NAME,
+            Self::Json(_) => Json::NAME,
+            Self::Uuid(_) => Uuid::NAME,
+            Self::Bool8(_) => Bool8::NAME,
+        }
+    }
+}
+
+impl TryFrom<&Field> for CanonicalExtensionType {
+    type Error = ArrowError;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        match field.extension_type_name() {
+            Some(FixedShapeTensor::NAME) => field.try_extension_type().map(Self::FixedShapeTensor),
+            Some(Json::NAME) => field.try_extension_type().map(Self::Json),
+            Some(Uuid::NAME) => field.try_extension_type().map(Self::Uuid),
+            Some(Bool8::NAME) => field.try_extension_type().map(Self::Bool8),
+            Some(name) => Err(ArrowError::InvalidArgumentError(format!(
+                "Field \"{}\" has unsupported extension type {name}",
+                field.name()
+            ))),
+            None => Err(ArrowError::InvalidArgumentError(format!(
+                "Field \"{}\" does not have an extension type",
+                field.name()
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+    use crate::DataType;
+    use std::collections::HashMap;
+
+    fn field_with_metadata(data_type: DataType, name: &str, metadata: Option<&str>) -> Field {
+        let mut m = HashMap::from([(EXTENSION_TYPE_NAME_KEY.to_string(), name.to_string())]);
+        if let Some(metadata) = metadata {
+            m.insert(
+                EXTENSION_TYPE_METADATA_KEY.to_string(),
+                metadata.to_string(),
+            );
+        }
+        Field::new("c", data_type, true).with_metadata(m)
+    }
+
+    #[test]
+    fn test_canonical_extension_type() {
+        let field = field_with_metadata(DataType::Int8, "arrow.bool8", None);
+        let extension_type = CanonicalExtensionType::try_from(&field).unwrap();
+        assert_eq!(extension_type, CanonicalExtensionType::Bool8(Bool8));
+        assert_eq!(extension_type.name(), "arrow.bool8");
+
+        let field = field_with_metadata(DataType::LargeUtf8, "arrow.json", Some("{}"));
+        let extension_type = CanonicalExtensionType::try_from(&field).unwrap();
+        assert_eq!(
+            extension_type,
+            CanonicalExtensionType::Json(Json::default())
+        );
+
+        let field = field_with_metadata(DataType::FixedSizeBinary(16), "arrow.uuid", None);
+        let extension_type = CanonicalExtensionType::try_from(&field).unwrap();
+        assert_eq!(extension_type, CanonicalExtensionType::Uuid(Uuid));
+
+        let field = field_with_metadata(DataType::FixedSizeBinary(8), "arrow.uuid", None);
+        let err = CanonicalExtensionType::try_from(&field).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.uuid extension type not supported for FixedSizeBinary(8)"
+        );
+
+        let field = field_with_metadata(DataType::Int8, "arrow.bool8", Some("x"));
+        let err = CanonicalExtensionType::try_from(&field).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.bool8 extension type expects no metadata, got \"x\""
+        );
+
+        let field = field_with_metadata(DataType::Int8, "example.unknown", None);
+        let err = CanonicalExtensionType::try_from(&field).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field \"c\" has unsupported extension type example.unknown"
+        );
+
+        let field = Field::new("c", DataType::Int8, true);
+        let err = CanonicalExtensionType::try_from(&field).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field \"c\" does not have an extension type"
+        );
+    }
+}
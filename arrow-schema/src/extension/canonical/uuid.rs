@@ -0,0 +1,60 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::extension::{deserialize_empty_metadata, ExtensionType};
+use crate::{ArrowError, DataType};
+
+/// The [`arrow.uuid`] extension type
+///
+/// A UUID, stored as a 16 byte [`DataType::FixedSizeBinary`] in big-endian byte order
+///
+/// [`arrow.uuid`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#uuid
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Uuid;
+
+impl ExtensionType for Uuid {
+    const NAME: &'static str = "arrow.uuid";
+
+    type Metadata = ();
+
+    fn metadata(&self) -> &Self::Metadata {
+        &()
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        None
+    }
+
+    fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+        deserialize_empty_metadata(Self::NAME, metadata)
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::FixedSizeBinary(16) => Ok(()),
+            d => Err(ArrowError::InvalidArgumentError(format!(
+                "{} extension type not supported for {d}",
+                Self::NAME
+            ))),
+        }
+    }
+
+    fn try_new(data_type: &DataType, _metadata: Self::Metadata) -> Result<Self, ArrowError> {
+        Self.supports_data_type(data_type)?;
+        Ok(Self)
+    }
+}
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Extension types
+//!
+//! An [extension type] is a user-defined type that is stored using a standard Arrow
+//! [`DataType`], the storage type, and identified by the [`EXTENSION_TYPE_NAME_KEY`] and
+//! [`EXTENSION_TYPE_METADATA_KEY`] entries of the metadata of its [`Field`]. As this
+//! metadata is preserved by IPC and Parquet, so is the extension type.
+//!
+//! The [`ExtensionType`] trait defines how an extension type is attached to, and read
+//! from, a [`Field`], with [`Field::try_with_extension_type`] and
+//! [`Field::try_extension_type`]. The [canonical extension types] are provided by
+//! [`CanonicalExtensionType`], which can identify the canonical extension type of any
+//! [`Field`].
+//!
+//! [extension type]: https://arrow.apache.org/docs/format/Columnar.html#format-metadata-extension-types
+//! [canonical extension types]: https://arrow.apache.org/docs/format/CanonicalExtensions.html
+//! [`Field`]: crate::Field
+//! [`Field::try_with_extension_type`]: crate::Field::try_with_extension_type
+//! [`Field::try_extension_type`]: crate::Field::try_extension_type
+
+mod canonical;
+pub use canonical::*;
+
+#[cfg(test)]
+use crate::Field;
+use crate::{ArrowError, DataType};
+
+/// The field metadata key of the name of an extension type
+pub const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The field metadata key of the serialized metadata of an extension type
+pub const EXTENSION_TYPE_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// An extension type, stored as a standard Arrow [`DataType`]
+///
+/// ```
+/// # use arrow_schema::{ArrowError, DataType, Field};
+/// # use arrow_schema::extension::ExtensionType;
+/// /// A temperature in degrees, stored as a `Float64`
+/// #[derive(Debug, PartialEq)]
+/// struct Temperature {
+///     unit: String,
+/// }
+///
+/// impl ExtensionType for Temperature {
+///     const NAME: &'static str = "example.temperature";
+///     type Metadata = String;
+///
+///     fn metadata(&self) -> &Self::Metadata {
+///         &self.unit
+///     }
+///
+///     fn serialize_metadata(&self) -> Option<String> {
+///         Some(self.unit.clone())
+///     }
+///
+///     fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+///         metadata.map(str::to_string).ok_or_else(|| {
+///             ArrowError::InvalidArgumentError("Temperature requires a unit".to_string())
+///         })
+///     }
+///
+///     fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+///         match data_type {
+///             DataType::Float64 => Ok(()),
+///             d => Err(ArrowError::InvalidArgumentError(format!(
+///                 "Temperature not supported for {d}"
+///             ))),
+///         }
+///     }
+///
+///     fn try_new(data_type: &DataType, unit: Self::Metadata) -> Result<Self, ArrowError> {
+///         let temperature = Self { unit };
+///         temperature.supports_data_type(data_type)?;
+///         Ok(temperature)
+///     }
+/// }
+///
+/// let temperature = Temperature { unit: "Celsius".to_string() };
+/// let field = Field::new("t", DataType::Float64, true)
+///     .try_with_extension_type(temperature)
+///     .unwrap();
+/// assert_eq!(field.extension_type_name(), Some("example.temperature"));
+/// assert_eq!(field.extension_type_metadata(), Some("Celsius"));
+///
+/// let temperature = field.try_extension_type::<Temperature>().unwrap();
+/// assert_eq!(temperature.metadata(), "Celsius");
+/// ```
+pub trait ExtensionType: Sized {
+    /// The name of this extension type, stored as [`EXTENSION_TYPE_NAME_KEY`]
+    const NAME: &'static str;
+
+    /// The parameters of this extension type
+    type Metadata;
+
+    /// Returns the parameters of this extension type
+    fn metadata(&self) -> &Self::Metadata;
+
+    /// Returns the metadata of this extension type to store as
+    /// [`EXTENSION_TYPE_METADATA_KEY`], or `None` if it has no metadata
+    fn serialize_metadata(&self) -> Option<String>;
+
+    /// Parses the metadata stored as [`EXTENSION_TYPE_METADATA_KEY`], if any
+    fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError>;
+
+    /// Returns an error if this extension type cannot be stored as `data_type`
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError>;
+
+    /// Creates this extension type from its parameters, returning an error if it
+    /// cannot be stored as `data_type`
+    fn try_new(data_type: &DataType, metadata: Self::Metadata) -> Result<Self, ArrowError>;
+}
+
+/// Returns an error if `metadata` is not empty, for extension types without parameters
+fn deserialize_empty_metadata(name: &str, metadata: Option<&str>) -> Result<(), ArrowError> {
+    match metadata {
+        None | Some("") => Ok(()),
+        Some(metadata) => Err(ArrowError::InvalidArgumentError(format!(
+            "{name} extension type expects no metadata, got {metadata:?}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_extension_type() {
+        let field = Field::new("uuid", DataType::FixedSizeBinary(16), true);
+        assert_eq!(field.extension_type_name(), None);
+        let err = field.try_extension_type::<Uuid>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field \"uuid\" does not have an extension type, expected arrow.uuid"
+        );
+
+        let field = field.try_with_extension_type(Uuid).unwrap();
+        assert_eq!(field.extension_type_name(), Some("arrow.uuid"));
+        assert_eq!(field.extension_type_metadata(), None);
+        field.try_extension_type::<Uuid>().unwrap();
+
+        let err = field.try_extension_type::<Bool8>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field \"uuid\" has extension type arrow.uuid, expected arrow.bool8"
+        );
+
+        // Replacing the extension type removes stale metadata
+        let field = Field::new("json", DataType::Utf8, true)
+            .with_metadata(
+                [(EXTENSION_TYPE_METADATA_KEY.to_string(), "x".to_string())]
+                    .into_iter()
+                    .collect(),
+            )
+            .try_with_extension_type(Json::default())
+            .unwrap();
+        assert_eq!(field.extension_type_name(), Some("arrow.json"));
+        assert_eq!(field.extension_type_metadata(), None);
+
+        let err = Field::new("bool8", DataType::Boolean, true)
+            .try_with_extension_type(Bool8)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: arrow.bool8 extension type not supported for Boolean"
+        );
+    }
+}
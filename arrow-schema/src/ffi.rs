@@ -509,8 +509,8 @@ impl TryFrom<&FFI_ArrowSchema> for DataType {
                                 DataType::Decimal128(parsed_precision, parsed_scale)
                             },
                             [precision, scale, bits] => {
-                                if *bits != "128" && *bits != "256" {
-                                    return Err(ArrowError::CDataInterface("Only 128/256 bit wide decimal is supported in the Rust implementation".to_string()));
+                                if !matches!(*bits, "32" | "64" | "128" | "256") {
+                                    return Err(ArrowError::CDataInterface("Only 32/64/128/256 bit wide decimal is supported in the Rust implementation".to_string()));
                                 }
                                 let parsed_precision = precision.parse::<u8>().map_err(|_| {
                                     ArrowError::CDataInterface(
@@ -522,10 +522,11 @@ impl TryFrom<&FFI_ArrowSchema> for DataType {
                                         "The decimal type requires an integer scale".to_string(),
                                     )
                                 })?;
-                                if *bits == "128" {
-                                    DataType::Decimal128(parsed_precision, parsed_scale)
-                                } else {
-                                    DataType::Decimal256(parsed_precision, parsed_scale)
+                                match *bits {
+                                    "32" => DataType::Decimal32(parsed_precision, parsed_scale),
+                                    "64" => DataType::Decimal64(parsed_precision, parsed_scale),
+                                    "128" => DataType::Decimal128(parsed_precision, parsed_scale),
+                                    _ => DataType::Decimal256(parsed_precision, parsed_scale),
                                 }
                             }
                             _ => {
@@ -708,6 +709,8 @@ fn get_format_string(dtype: &DataType) -> Result<String, ArrowError> {
         DataType::LargeUtf8 => Ok("U".to_string()),
         DataType::FixedSizeBinary(num_bytes) => Ok(format!("w:{num_bytes}")),
         DataType::FixedSizeList(_, num_elems) => Ok(format!("+w:{num_elems}")),
+        DataType::Decimal32(precision, scale) => Ok(format!("d:{precision},{scale},32")),
+        DataType::Decimal64(precision, scale) => Ok(format!("d:{precision},{scale},64")),
         DataType::Decimal128(precision, scale) => Ok(format!("d:{precision},{scale}")),
         DataType::Decimal256(precision, scale) => Ok(format!("d:{precision},{scale},256")),
         DataType::Date32 => Ok("tdD".to_string()),
@@ -847,6 +850,10 @@ mod tests {
         round_trip_type(DataType::Date64);
         round_trip_type(DataType::Time64(TimeUnit::Nanosecond));
         round_trip_type(DataType::FixedSizeBinary(12));
+        round_trip_type(DataType::Decimal32(9, 2));
+        round_trip_type(DataType::Decimal64(18, -2));
+        round_trip_type(DataType::Decimal128(38, 10));
+        round_trip_type(DataType::Decimal256(76, 10));
         round_trip_type(DataType::FixedSizeList(
             Arc::new(Field::new("a", DataType::Int64, false)),
             5,
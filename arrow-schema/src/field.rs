@@ -21,7 +21,12 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use crate::datatype::DataType;
+use crate::datatype::{
+    DataType, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION,
+    DECIMAL256_MAX_SCALE, DECIMAL32_MAX_PRECISION, DECIMAL32_MAX_SCALE, DECIMAL64_MAX_PRECISION,
+    DECIMAL64_MAX_SCALE,
+};
+use crate::extension::{ExtensionType, EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
 use crate::schema::SchemaBuilder;
 use crate::{Fields, UnionFields, UnionMode};
 
@@ -360,6 +365,66 @@ impl Field {
         self
     }
 
+    /// Returns the name of the extension type of this [`Field`], if any
+    pub fn extension_type_name(&self) -> Option<&str> {
+        self.metadata()
+            .get(EXTENSION_TYPE_NAME_KEY)
+            .map(String::as_str)
+    }
+
+    /// Returns the serialized metadata of the extension type of this [`Field`], if any
+    pub fn extension_type_metadata(&self) -> Option<&str> {
+        self.metadata()
+            .get(EXTENSION_TYPE_METADATA_KEY)
+            .map(String::as_str)
+    }
+
+    /// Returns the extension type `E` of this [`Field`]
+    ///
+    /// Returns an error if this [`Field`] does not have the extension type `E`, or if its
+    /// metadata or data type are not valid for `E`
+    pub fn try_extension_type<E: ExtensionType>(&self) -> Result<E, ArrowError> {
+        match self.extension_type_name() {
+            Some(name) if name == E::NAME => {}
+            Some(name) => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Field \"{}\" has extension type {name}, expected {}",
+                    self.name(),
+                    E::NAME
+                )))
+            }
+            None => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Field \"{}\" does not have an extension type, expected {}",
+                    self.name(),
+                    E::NAME
+                )))
+            }
+        }
+        let metadata = E::deserialize_metadata(self.extension_type_metadata())?;
+        E::try_new(self.data_type(), metadata)
+    }
+
+    /// Sets the extension type of this [`Field`] to `extension_type` and returns self
+    ///
+    /// Any previous extension type is replaced. Returns an error if `extension_type`
+    /// cannot be stored as the data type of this [`Field`]
+    pub fn try_with_extension_type<E: ExtensionType>(
+        mut self,
+        extension_type: E,
+    ) -> Result<Self, ArrowError> {
+        extension_type.supports_data_type(self.data_type())?;
+        self.metadata
+            .insert(EXTENSION_TYPE_NAME_KEY.to_string(), E::NAME.to_string());
+        match extension_type.serialize_metadata() {
+            Some(metadata) => self
+                .metadata
+                .insert(EXTENSION_TYPE_METADATA_KEY.to_string(), metadata),
+            None => self.metadata.remove(EXTENSION_TYPE_METADATA_KEY),
+        };
+        Ok(self)
+    }
+
     /// Returns a (flattened) [`Vec`] containing all child [`Field`]s
     /// within `self` contained within this field (including `self`)
     pub(crate) fn fields(&self) -> Vec<&Field> {
@@ -375,6 +440,8 @@ impl Field {
             DataType::Union(fields, _) => fields.iter().flat_map(|(_, f)| f.fields()).collect(),
             DataType::List(field)
             | DataType::LargeList(field)
+            | DataType::ListView(field)
+            | DataType::LargeListView(field)
             | DataType::FixedSizeList(field, _)
             | DataType::Map(field, _) => field.fields(),
             DataType::Dictionary(_, value_field) => Field::_fields(value_field.as_ref()),
@@ -428,6 +495,27 @@ impl Field {
     /// assert!(field.is_nullable());
     /// ```
     pub fn try_merge(&mut self, from: &Field) -> Result<(), ArrowError> {
+        self.try_merge_with_mode(from, MergeMode::Strict)
+    }
+
+    /// Merge this field into self if it is compatible, promoting data types according
+    /// to `mode`.
+    ///
+    /// Struct, list and large list fields are merged recursively with the same `mode`.
+    ///
+    /// NOTE: `self` may be updated to a partial / unexpected state in case of merge failure.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    /// let mut field = Field::new("c1", DataType::Int32, false);
+    /// let from = Field::new("c1", DataType::Float32, true);
+    /// assert!(field.try_merge(&from).is_err());
+    /// field.try_merge_with_mode(&from, MergeMode::Promote).unwrap();
+    /// assert_eq!(field, Field::new("c1", DataType::Float64, true));
+    /// ```
+    pub fn try_merge_with_mode(&mut self, from: &Field, mode: MergeMode) -> Result<(), ArrowError> {
         if from.dict_id != self.dict_id {
             return Err(ArrowError::SchemaError(format!(
                 "Fail to merge schema field '{}' because from dict_id = {} does not match {}",
@@ -467,7 +555,7 @@ impl Field {
             DataType::Struct(nested_fields) => match &from.data_type {
                 DataType::Struct(from_nested_fields) => {
                     let mut builder = SchemaBuilder::new();
-                    nested_fields.iter().chain(from_nested_fields).try_for_each(|f| builder.try_merge(f))?;
+                    nested_fields.iter().chain(from_nested_fields).try_for_each(|f| builder.try_merge_with_mode(f, mode))?;
                     *nested_fields = builder.finish().fields;
                 }
                 _ => {
@@ -490,7 +578,7 @@ impl Field {
             DataType::List(field) => match &from.data_type {
                 DataType::List(from_field) => {
                     let mut f = (**field).clone();
-                    f.try_merge(from_field)?;
+                    f.try_merge_with_mode(from_field, mode)?;
                     (*field) = Arc::new(f);
                 },
                 _ => {
@@ -502,7 +590,7 @@ impl Field {
             DataType::LargeList(field) => match &from.data_type {
                 DataType::LargeList(from_field) => {
                     let mut f = (**field).clone();
-                    f.try_merge(from_field)?;
+                    f.try_merge_with_mode(from_field, mode)?;
                     (*field) = Arc::new(f);
                 },
                 _ => {
@@ -547,15 +635,24 @@ impl Field {
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Utf8View
+            | DataType::Decimal32(_, _)
+            | DataType::Decimal64(_, _)
             | DataType::Decimal128(_, _)
             | DataType::Decimal256(_, _) => {
                 if from.data_type == DataType::Null {
                     self.nullable = true;
                 } else if self.data_type != from.data_type {
-                    return Err(ArrowError::SchemaError(
-                        format!("Fail to merge schema field '{}' because the from data_type = {} does not equal {}",
-                            self.name, from.data_type, self.data_type)
-                    ));
+                    let promoted = match mode {
+                        MergeMode::Strict => None,
+                        MergeMode::Promote => promote_data_type(&self.data_type, &from.data_type),
+                    };
+                    match promoted {
+                        Some(data_type) => self.data_type = data_type,
+                        None => return Err(ArrowError::SchemaError(
+                            format!("Fail to merge schema field '{}' because the from data_type = {} does not equal {}",
+                                self.name, from.data_type, self.data_type)
+                        )),
+                    }
                 }
             }
         }
@@ -598,6 +695,96 @@ impl Field {
     }
 }
 
+/// How [`Field::try_merge_with_mode`] merges fields with different data types
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MergeMode {
+    /// Data types must be equal, except [`DataType::Null`] which merges with any data type
+    #[default]
+    Strict,
+    /// As [`MergeMode::Strict`], but data types are promoted to a common data type
+    /// that can represent the values of both:
+    ///
+    /// * Integers are widened, with a signed and an unsigned integer promoted to a
+    ///   signed integer wide enough for both, e.g. `Int32` + `Int64` -> `Int64` and
+    ///   `UInt8` + `Int8` -> `Int16`
+    /// * Floats are widened, and a float with an integer is promoted to `Float64`
+    /// * `Utf8` + `LargeUtf8` -> `LargeUtf8` and `Binary` + `LargeBinary` -> `LargeBinary`
+    /// * Decimals are widened to the largest scale and number of integral digits,
+    ///   switching to a wider decimal type if the precision requires it
+    Promote,
+}
+
+/// Returns the data type `a` and `b` are promoted to by [`MergeMode::Promote`]
+fn promote_data_type(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match (a, b) {
+        (a, b) if a.is_integer() && b.is_integer() => {
+            let (a_width, b_width) = (a.primitive_width()?, b.primitive_width()?);
+            match (a.is_signed_integer(), b.is_signed_integer()) {
+                (true, true) | (false, false) => {
+                    Some(if a_width >= b_width { a } else { b }.clone())
+                }
+                (signed, _) => {
+                    let (s_width, u_width) = match signed {
+                        true => (a_width, b_width),
+                        false => (b_width, a_width),
+                    };
+                    match s_width.max(u_width * 2) {
+                        2 => Some(Int16),
+                        4 => Some(Int32),
+                        8 => Some(Int64),
+                        _ => None,
+                    }
+                }
+            }
+        }
+        (a, b) if a.is_floating() && b.is_floating() => {
+            let a_wider = a.primitive_width()? >= b.primitive_width()?;
+            Some(if a_wider { a } else { b }.clone())
+        }
+        (a, b) if a.is_floating() && b.is_integer() || a.is_integer() && b.is_floating() => {
+            Some(Float64)
+        }
+        (Utf8 | LargeUtf8, Utf8 | LargeUtf8) => Some(LargeUtf8),
+        (Binary | LargeBinary, Binary | LargeBinary) => Some(LargeBinary),
+        _ => {
+            let (a_rank, a_precision, a_scale) = decimal_parts(a)?;
+            let (b_rank, b_precision, b_scale) = decimal_parts(b)?;
+            let scale = a_scale.max(b_scale);
+            let digits =
+                (a_precision as i16 - a_scale as i16).max(b_precision as i16 - b_scale as i16);
+            let precision = u8::try_from(digits + scale as i16).ok()?;
+            let types = [
+                (DECIMAL32_MAX_PRECISION, DECIMAL32_MAX_SCALE),
+                (DECIMAL64_MAX_PRECISION, DECIMAL64_MAX_SCALE),
+                (DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE),
+                (DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE),
+            ];
+            let rank = (a_rank.max(b_rank)..types.len()).find(|r| {
+                let (max_precision, max_scale) = types[*r];
+                precision <= max_precision && scale <= max_scale
+            })?;
+            match rank {
+                0 => Some(Decimal32(precision, scale)),
+                1 => Some(Decimal64(precision, scale)),
+                2 => Some(Decimal128(precision, scale)),
+                _ => Some(Decimal256(precision, scale)),
+            }
+        }
+    }
+}
+
+/// Returns the rank by width, precision and scale of a decimal data type
+fn decimal_parts(data_type: &DataType) -> Option<(usize, u8, i8)> {
+    match data_type {
+        DataType::Decimal32(p, s) => Some((0, *p, *s)),
+        DataType::Decimal64(p, s) => Some((1, *p, *s)),
+        DataType::Decimal128(p, s) => Some((2, *p, *s)),
+        DataType::Decimal256(p, s) => Some((3, *p, *s)),
+        _ => None,
+    }
+}
+
 // TODO: improve display with crate https://crates.io/crates/derive_more ?
 impl std::fmt::Display for Field {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -649,6 +836,71 @@ mod test {
         assert_eq!(Field::new("c2", DataType::Utf8, true), field2);
     }
 
+    #[test]
+    fn test_merge_with_promotion() {
+        use DataType::*;
+        let cases = [
+            (Int32, Int64, Some(Int64)),
+            (Int8, UInt8, Some(Int16)),
+            (UInt32, Int16, Some(Int64)),
+            (UInt16, UInt64, Some(UInt64)),
+            (UInt64, Int8, None),
+            (Float16, Float32, Some(Float32)),
+            (Float32, Int32, Some(Float64)),
+            (UInt64, Float16, Some(Float64)),
+            (Utf8, LargeUtf8, Some(LargeUtf8)),
+            (LargeBinary, Binary, Some(LargeBinary)),
+            (Utf8, Binary, None),
+            (Int64, Decimal128(10, 2), None),
+            (
+                Decimal128(10, 2),
+                Decimal128(12, 4),
+                Some(Decimal128(12, 4)),
+            ),
+            (Decimal128(10, 0), Decimal128(5, 5), Some(Decimal128(15, 5))),
+            (Decimal32(9, 2), Decimal64(4, 4), Some(Decimal64(11, 4))),
+            (Decimal32(9, 0), Decimal32(9, 9), Some(Decimal64(18, 9))),
+            (
+                Decimal128(38, 0),
+                Decimal128(38, 10),
+                Some(Decimal256(48, 10)),
+            ),
+            (Decimal256(76, 0), Decimal32(2, 1), None),
+            (Decimal128(5, -2), Decimal128(3, 1), Some(Decimal128(8, 1))),
+        ];
+        for (a, b, expected) in cases {
+            for (a, b) in [(&a, &b), (&b, &a)] {
+                let mut field = Field::new("c1", a.clone(), false);
+                let from = Field::new("c1", b.clone(), false);
+                let result = field.try_merge_with_mode(&from, MergeMode::Promote);
+                match &expected {
+                    Some(expected) => {
+                        result.unwrap();
+                        assert_eq!(field.data_type(), expected, "{a} + {b}");
+                    }
+                    None => {
+                        let err = result.unwrap_err().to_string();
+                        assert!(err.contains("does not equal"), "{a} + {b}: {err}");
+                    }
+                }
+                assert!(Field::new("c1", a.clone(), false).try_merge(&from).is_err());
+            }
+        }
+
+        let mut field = Field::new("c1", Int32, false);
+        field
+            .try_merge_with_mode(&Field::new("c1", Int64, true), MergeMode::Promote)
+            .unwrap();
+        assert_eq!(field, Field::new("c1", Int64, true));
+
+        let mut field = Field::new_list("l", Field::new_list_field(Int32, false), false);
+        let from = Field::new_list("l", Field::new_list_field(Float64, true), false);
+        field
+            .try_merge_with_mode(&from, MergeMode::Promote)
+            .unwrap();
+        assert_eq!(field, from);
+    }
+
     #[test]
     fn test_merge_with_nested_null() {
         let mut struct1 = Field::new(
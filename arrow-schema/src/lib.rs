@@ -18,6 +18,8 @@
 #![warn(missing_docs)]
 //! Arrow logical types
 
+mod comparator;
+pub use comparator::*;
 mod datatype;
 
 pub use datatype::*;
@@ -25,6 +27,7 @@ use std::fmt::Display;
 mod datatype_parse;
 mod error;
 pub use error::*;
+pub mod extension;
 mod field;
 pub use field::*;
 mod fields;
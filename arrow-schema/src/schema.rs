@@ -21,8 +21,8 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use crate::error::ArrowError;
-use crate::field::Field;
-use crate::{FieldRef, Fields};
+use crate::field::{Field, MergeMode};
+use crate::{DataType, FieldRef, Fields};
 
 /// A builder to facilitate building a [`Schema`] from iteratively from [`FieldRef`]
 #[derive(Debug, Default)]
@@ -96,15 +96,26 @@ impl SchemaBuilder {
     ///
     /// If an existing field exists with the same name, calls [`Field::try_merge`]
     pub fn try_merge(&mut self, field: &FieldRef) -> Result<(), ArrowError> {
+        self.try_merge_with_mode(field, MergeMode::Strict)
+    }
+
+    /// Appends a [`FieldRef`] to this [`SchemaBuilder`] checking for collision
+    ///
+    /// If an existing field exists with the same name, calls [`Field::try_merge_with_mode`]
+    pub fn try_merge_with_mode(
+        &mut self,
+        field: &FieldRef,
+        mode: MergeMode,
+    ) -> Result<(), ArrowError> {
         // This could potentially be sped up with a HashMap or similar
         let existing = self.fields.iter_mut().find(|f| f.name() == field.name());
         match existing {
             Some(e) if Arc::ptr_eq(e, field) => {} // Nothing to do
             Some(e) => match Arc::get_mut(e) {
-                Some(e) => e.try_merge(field.as_ref())?,
+                Some(e) => e.try_merge_with_mode(field.as_ref(), mode)?,
                 None => {
                     let mut t = e.as_ref().clone();
-                    t.try_merge(field)?;
+                    t.try_merge_with_mode(field, mode)?;
                     *e = Arc::new(t)
                 }
             },
@@ -246,6 +257,43 @@ impl Schema {
         self
     }
 
+    /// Returns a new schema with the fields of [`DataType::Struct`] fields flattened into
+    /// top-level fields, named by joining the names of the parent and child fields
+    /// with `separator`
+    ///
+    /// Nested structs are flattened recursively, up to `max_depth` levels if specified.
+    /// A flattened field is nullable if it or any of its parents is nullable. Structs
+    /// without fields are not flattened. The metadata of the schema is preserved
+    ///
+    /// ```
+    /// # use arrow_schema::{DataType, Field, Fields, Schema};
+    /// let point = Fields::from(vec![
+    ///     Field::new("x", DataType::Float64, false),
+    ///     Field::new("y", DataType::Float64, false),
+    /// ]);
+    /// let schema = Schema::new(vec![
+    ///     Field::new("id", DataType::Int32, false),
+    ///     Field::new("point", DataType::Struct(point), true),
+    /// ]);
+    ///
+    /// let normalized = schema.normalize(".", None);
+    /// assert_eq!(
+    ///     normalized,
+    ///     Schema::new(vec![
+    ///         Field::new("id", DataType::Int32, false),
+    ///         Field::new("point.x", DataType::Float64, true),
+    ///         Field::new("point.y", DataType::Float64, true),
+    ///     ])
+    /// );
+    /// ```
+    pub fn normalize(&self, separator: &str, max_depth: Option<usize>) -> Self {
+        let mut fields = Vec::with_capacity(self.fields.len());
+        for field in self.fields.iter() {
+            normalize_field(field, None, separator, max_depth, false, &mut fields);
+        }
+        Self::new_with_metadata(fields, self.metadata.clone())
+    }
+
     /// Returns a new schema with only the specified columns in the new schema
     /// This carries metadata from the parent schema over as well
     pub fn project(&self, indices: &[usize]) -> Result<Schema, ArrowError> {
@@ -293,6 +341,49 @@ impl Schema {
     /// );
     /// ```
     pub fn try_merge(schemas: impl IntoIterator<Item = Self>) -> Result<Self, ArrowError> {
+        Self::try_merge_with_mode(schemas, MergeMode::Strict)
+    }
+
+    /// Merge schemas if they are compatible, promoting data types according to `mode`.
+    /// Struct fields will be merged recursively.
+    ///
+    /// See [`MergeMode`] for the supported promotions, and [`Schema::try_merge`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// # use arrow_schema::*;
+    ///
+    /// let merged = Schema::try_merge_with_mode(
+    ///     vec![
+    ///         Schema::new(vec![
+    ///             Field::new("c1", DataType::Int32, false),
+    ///             Field::new("c2", DataType::Utf8, false),
+    ///             Field::new("c3", DataType::Decimal128(10, 2), false),
+    ///         ]),
+    ///         Schema::new(vec![
+    ///             Field::new("c1", DataType::Int64, false),
+    ///             Field::new("c2", DataType::LargeUtf8, true),
+    ///             Field::new("c3", DataType::Decimal128(12, 4), false),
+    ///         ]),
+    ///     ],
+    ///     MergeMode::Promote,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     merged,
+    ///     Schema::new(vec![
+    ///         Field::new("c1", DataType::Int64, false),
+    ///         Field::new("c2", DataType::LargeUtf8, true),
+    ///         Field::new("c3", DataType::Decimal128(12, 4), false),
+    ///     ]),
+    /// );
+    /// ```
+    pub fn try_merge_with_mode(
+        schemas: impl IntoIterator<Item = Self>,
+        mode: MergeMode,
+    ) -> Result<Self, ArrowError> {
         let mut out_meta = HashMap::new();
         let mut out_fields = SchemaBuilder::new();
         for schema in schemas {
@@ -312,7 +403,9 @@ impl Schema {
             }
 
             // merge fields
-            fields.iter().try_for_each(|x| out_fields.try_merge(x))?
+            fields
+                .iter()
+                .try_for_each(|x| out_fields.try_merge_with_mode(x, mode))?
         }
 
         Ok(out_fields.finish().with_metadata(out_meta))
@@ -492,6 +585,39 @@ impl Hash for Schema {
     }
 }
 
+/// Appends `field` to `fields`, flattening it into its children if it is a struct and
+/// `max_depth` allows
+fn normalize_field(
+    field: &FieldRef,
+    prefix: Option<&str>,
+    separator: &str,
+    max_depth: Option<usize>,
+    parent_nullable: bool,
+    fields: &mut Vec<FieldRef>,
+) {
+    let name = match prefix {
+        Some(prefix) => format!("{prefix}{separator}{}", field.name()),
+        None => field.name().clone(),
+    };
+    let nullable = parent_nullable || field.is_nullable();
+    match field.data_type() {
+        DataType::Struct(children) if !children.is_empty() && max_depth != Some(0) => {
+            for child in children.iter() {
+                let max_depth = max_depth.map(|d| d - 1);
+                normalize_field(child, Some(&name), separator, max_depth, nullable, fields);
+            }
+        }
+        _ if prefix.is_none() => fields.push(field.clone()),
+        _ => fields.push(Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_name(name)
+                .with_nullable(nullable),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::datatype::DataType;
@@ -967,6 +1093,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_schema_merge_with_promotion() {
+        let schemas = vec![
+            Schema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new(
+                    "address",
+                    DataType::Struct(vec![Field::new("zip", DataType::UInt16, false)].into()),
+                    false,
+                ),
+            ]),
+            Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new(
+                    "address",
+                    DataType::Struct(
+                        vec![
+                            Field::new("zip", DataType::Int32, true),
+                            Field::new("street", DataType::Utf8, false),
+                        ]
+                        .into(),
+                    ),
+                    false,
+                ),
+            ]),
+            Schema::new(vec![Field::new("score", DataType::Float32, true)]),
+        ];
+
+        let err = Schema::try_merge(schemas.clone()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Fail to merge schema field 'id' because the from data_type = Int64 does not equal Int32"
+        );
+
+        let merged = Schema::try_merge_with_mode(schemas, MergeMode::Promote).unwrap();
+        assert_eq!(
+            merged,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new(
+                    "address",
+                    DataType::Struct(
+                        vec![
+                            Field::new("zip", DataType::Int32, true),
+                            Field::new("street", DataType::Utf8, false),
+                        ]
+                        .into(),
+                    ),
+                    false,
+                ),
+                Field::new("score", DataType::Float32, true),
+            ])
+        );
+    }
+
     #[test]
     fn test_schema_builder_change_field() {
         let mut builder = SchemaBuilder::new();
@@ -997,6 +1178,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_schema_normalize() {
+        let inner = Fields::from(vec![
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Utf8, true)
+                .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+        ]);
+        let outer = Fields::from(vec![
+            Field::new("b", DataType::Struct(inner.clone()), false),
+            Field::new("e", DataType::Struct(Fields::empty()), false),
+        ]);
+        let metadata = HashMap::from([("foo".to_string(), "bar".to_string())]);
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("a", DataType::Struct(outer.clone()), false),
+            Field::new("f", DataType::Struct(inner.clone()), true),
+        ])
+        .with_metadata(metadata.clone());
+
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("a.b.c", DataType::Int32, false),
+            Field::new("a.b.d", DataType::Utf8, true)
+                .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+            Field::new("a.e", DataType::Struct(Fields::empty()), false),
+            Field::new("f.c", DataType::Int32, true),
+            Field::new("f.d", DataType::Utf8, true)
+                .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+        ])
+        .with_metadata(metadata.clone());
+        assert_eq!(schema.normalize(".", None), expected);
+
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("a/b", DataType::Struct(inner.clone()), false),
+            Field::new("a/e", DataType::Struct(Fields::empty()), false),
+            Field::new("f/c", DataType::Int32, true),
+            Field::new("f/d", DataType::Utf8, true)
+                .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+        ])
+        .with_metadata(metadata);
+        assert_eq!(schema.normalize("/", Some(1)), expected);
+
+        assert_eq!(schema.normalize(".", Some(0)), schema);
+    }
+
     #[test]
     fn test_schema_builder_metadata() {
         let mut metadata = HashMap::with_capacity(1);
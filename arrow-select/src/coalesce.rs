@@ -0,0 +1,220 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Implements the `coalesce` function for Arrow arrays.
+
+use arrow_array::{make_array, Array, ArrayRef};
+use arrow_buffer::BooleanBuffer;
+use arrow_data::transform::MutableArrayData;
+use arrow_schema::ArrowError;
+
+/// Returns a new array containing the first non-null value of each row across `arrays`,
+/// or null if the row is null in all `arrays`
+///
+/// This can be used to implement SQL `COALESCE`
+///
+/// Nulls are determined using [`Array::logical_nulls`], and so a dictionary
+/// key referencing a null value is considered null
+///
+/// Returns an error if `arrays` is empty, or the arrays do not all have the same
+/// data type and length
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_select::coalesce::coalesce;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, None]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![Some(10), Some(20), None]));
+/// let result = coalesce(&[a, b]).unwrap();
+/// assert_eq!(result.as_primitive(), &Int32Array::from(vec![Some(1), Some(20), None]));
+/// ```
+pub fn coalesce(arrays: &[ArrayRef]) -> Result<ArrayRef, ArrowError> {
+    let Some(first) = arrays.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "coalesce requires at least one array".to_string(),
+        ));
+    };
+    let len = first.len();
+    for array in arrays {
+        if array.data_type() != first.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "coalesce requires arrays of the same data type, got {} and {}",
+                first.data_type(),
+                array.data_type()
+            )));
+        }
+        if array.len() != len {
+            return Err(ArrowError::InvalidArgumentError(
+                "all arrays should have the same length".to_string(),
+            ));
+        }
+    }
+
+    // Fast path: the first array has no nulls
+    let Some(first_nulls) = first.logical_nulls().filter(|n| n.null_count() != 0) else {
+        return Ok(first.clone());
+    };
+
+    // Find the index of the array providing the value of each row, with
+    // `arrays.len()` denoting a row that is null in all arrays
+    let null_idx = arrays.len();
+    let mut selected = vec![null_idx; len];
+    first_nulls
+        .valid_indices()
+        .for_each(|row| selected[row] = 0);
+    let mut unmatched = !first_nulls.inner();
+    for (idx, array) in arrays.iter().enumerate().skip(1) {
+        match array.logical_nulls().filter(|n| n.null_count() != 0) {
+            Some(nulls) => {
+                let matched = &unmatched & nulls.inner();
+                matched.set_indices().for_each(|row| selected[row] = idx);
+                unmatched = &unmatched & &!&matched;
+                if unmatched.count_set_bits() == 0 {
+                    break;
+                }
+            }
+            None => {
+                // All remaining rows are provided by this array
+                unmatched.set_indices().for_each(|row| selected[row] = idx);
+                unmatched = BooleanBuffer::new_unset(len);
+                break;
+            }
+        }
+    }
+
+    let data: Vec<_> = arrays.iter().map(|a| a.to_data()).collect();
+    let use_nulls = unmatched.count_set_bits() != 0;
+    let mut mutable = MutableArrayData::new(data.iter().collect(), use_nulls, len);
+
+    // Copy contiguous runs of rows selected from the same array
+    let mut start = 0;
+    while start < len {
+        let source = selected[start];
+        let end = selected[start..]
+            .iter()
+            .position(|s| *s != source)
+            .map_or(len, |x| start + x);
+        if source == null_idx {
+            mutable.extend_nulls(end - start)
+        } else {
+            mutable.extend(source, start, end)
+        }
+        start = end;
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_coalesce() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, None, None]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![Some(10), None, Some(30), None]));
+        let c: ArrayRef = Arc::new(Int32Array::from(vec![None, Some(200), Some(300), None]));
+
+        let out = coalesce(&[a.clone(), b.clone(), c.clone()]).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(200), Some(30), None]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+
+        let out = coalesce(&[c.clone(), b.clone(), a.clone()]).unwrap();
+        let expected = Int32Array::from(vec![Some(10), Some(200), Some(300), None]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+
+        // Later arrays without nulls
+        let d: ArrayRef = Arc::new(Int32Array::from(vec![-1, -2, -3, -4]));
+        let out = coalesce(&[a.clone(), d.clone(), c]).unwrap();
+        let expected = Int32Array::from(vec![1, -2, -3, -4]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+        assert_eq!(out.null_count(), 0);
+
+        // Fast path returns the first array
+        let out = coalesce(&[d.clone(), a]).unwrap();
+        assert_eq!(out.as_ref(), d.as_ref());
+
+        let out = coalesce(std::slice::from_ref(&b)).unwrap();
+        assert_eq!(out.as_ref(), b.as_ref());
+    }
+
+    #[test]
+    fn test_coalesce_sliced() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), None]).slice(1, 3);
+        let b = Int32Array::from(vec![Some(10), Some(20), None, None]).slice(1, 3);
+        let out = coalesce(&[Arc::new(a), Arc::new(b)]).unwrap();
+        let expected = Int32Array::from(vec![Some(20), Some(3), None]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_coalesce_dictionary() {
+        // Key referencing a null value is treated as null
+        let values = StringArray::from(vec![Some("a"), None, Some("b")]);
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(2)]);
+        let a = DictionaryArray::new(keys, Arc::new(values));
+        let b: DictionaryArray<Int32Type> = vec!["x", "y", "z", "w"].into_iter().collect();
+
+        let out = coalesce(&[Arc::new(a), Arc::new(b)]).unwrap();
+        let out = out.as_dictionary::<Int32Type>();
+        let out: Vec<_> = out
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(out, vec![Some("a"), Some("y"), Some("z"), Some("b")]);
+    }
+
+    #[test]
+    fn test_coalesce_view() {
+        let long = "a string that is too long to be inlined";
+        let a = StringViewArray::from(vec![None, Some("short"), None]);
+        let b = StringViewArray::from(vec![Some(long), Some(long), None]);
+        let out = coalesce(&[Arc::new(a), Arc::new(b)]).unwrap();
+        let expected = StringViewArray::from(vec![Some(long), Some("short"), None]);
+        assert_eq!(out.as_string_view(), &expected);
+    }
+
+    #[test]
+    fn test_coalesce_invalid() {
+        let err = coalesce(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: coalesce requires at least one array"
+        );
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let b: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+        let err = coalesce(&[a.clone(), b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: coalesce requires arrays of the same data type, got Int32 and Int64"
+        );
+
+        let err = coalesce(&[a.clone(), a.slice(0, 1)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length"
+        );
+    }
+}
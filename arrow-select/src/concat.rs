@@ -34,9 +34,9 @@ use crate::dictionary::{merge_dictionary_values, should_merge_dictionary_values}
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, NullBuffer};
+use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, NullBuffer, OffsetBuffer};
 use arrow_data::transform::{Capacities, MutableArrayData};
-use arrow_schema::{ArrowError, DataType, SchemaRef};
+use arrow_schema::{ArrowError, DataType, FieldRef, Fields, SchemaRef};
 use std::sync::Arc;
 
 fn binary_capacity<T: ByteArrayType>(arrays: &[&dyn Array]) -> Capacities {
@@ -93,6 +93,12 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
         .collect();
 
     if !should_merge_dictionary_values::<K>(&dictionaries, output_len) {
+        // The naive approach concatenates the dictionary values, and so requires
+        // the total number of values to be representable by the key type
+        let values_len: usize = dictionaries.iter().map(|d| d.values().len()).sum();
+        if K::Native::from_usize(values_len).is_none() && !single_dictionary(&dictionaries) {
+            return Err(ArrowError::DictionaryKeyOverflowError);
+        }
         return concat_fallback(arrays, Capacities::Array(output_len));
     }
 
@@ -101,27 +107,14 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
     // Recompute keys
     let mut key_values = Vec::with_capacity(output_len);
 
-    let mut has_nulls = false;
     for (d, mapping) in dictionaries.iter().zip(merged.key_mappings) {
-        has_nulls |= d.null_count() != 0;
         for key in d.keys().values() {
             // Use get to safely handle nulls
             key_values.push(mapping.get(key.as_usize()).copied().unwrap_or_default())
         }
     }
 
-    let nulls = has_nulls.then(|| {
-        let mut nulls = BooleanBufferBuilder::new(output_len);
-        for d in &dictionaries {
-            match d.nulls() {
-                Some(n) => nulls.append_buffer(n.inner()),
-                None => nulls.append_n(d.len(), true),
-            }
-        }
-        NullBuffer::new(nulls.finish())
-    });
-
-    let keys = PrimitiveArray::<K>::new(key_values.into(), nulls);
+    let keys = PrimitiveArray::<K>::new(key_values.into(), concat_nulls(arrays));
     // Sanity check
     assert_eq!(keys.len(), output_len);
 
@@ -129,6 +122,86 @@ fn concat_dictionaries<K: ArrowDictionaryKeyType>(
     Ok(Arc::new(array))
 }
 
+/// Returns true if all `dictionaries` share the same values array
+fn single_dictionary<K: ArrowDictionaryKeyType>(dictionaries: &[&DictionaryArray<K>]) -> bool {
+    let first = dictionaries[0].values().to_data();
+    dictionaries
+        .iter()
+        .skip(1)
+        .all(|d| d.values().to_data().ptr_eq(&first))
+}
+
+/// Concatenates the null buffers of `arrays`, returning `None` if none of the arrays
+/// contain nulls
+fn concat_nulls(arrays: &[&dyn Array]) -> Option<NullBuffer> {
+    if arrays.iter().all(|a| a.null_count() == 0) {
+        return None;
+    }
+    let len = arrays.iter().map(|a| a.len()).sum();
+    let mut nulls = BooleanBufferBuilder::new(len);
+    for a in arrays {
+        match a.nulls() {
+            Some(n) => nulls.append_buffer(n.inner()),
+            None => nulls.append_n(a.len(), true),
+        }
+    }
+    Some(NullBuffer::new(nulls.finish()))
+}
+
+/// Returns true if `data_type` is a dictionary, or a struct or list
+/// containing a dictionary
+fn contains_dictionary(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Dictionary(_, _) => true,
+        DataType::Struct(fields) => fields.iter().any(|f| contains_dictionary(f.data_type())),
+        DataType::List(f) | DataType::LargeList(f) => contains_dictionary(f.data_type()),
+        _ => false,
+    }
+}
+
+/// Concatenates [`StructArray`] by concatenating each of their children with
+/// [`concat`], allowing any child dictionaries to be merged
+fn concat_structs(arrays: &[&dyn Array], fields: &Fields) -> Result<ArrayRef, ArrowError> {
+    let structs: Vec<_> = arrays.iter().map(|a| a.as_struct()).collect();
+    let columns = (0..fields.len())
+        .map(|i| {
+            let children: Vec<_> = structs.iter().map(|s| s.column(i).as_ref()).collect();
+            concat(&children)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let array = StructArray::try_new(fields.clone(), columns, concat_nulls(arrays))?;
+    Ok(Arc::new(array))
+}
+
+/// Concatenates [`GenericListArray`] by concatenating their child values with
+/// [`concat`], allowing any child dictionaries to be merged
+fn concat_lists<O: OffsetSizeTrait>(
+    arrays: &[&dyn Array],
+    field: &FieldRef,
+) -> Result<ArrayRef, ArrowError> {
+    let lists: Vec<_> = arrays.iter().map(|a| a.as_list::<O>()).collect();
+    let values: Vec<_> = lists
+        .iter()
+        .map(|l| {
+            let offsets = l.value_offsets();
+            let start = offsets[0].as_usize();
+            let end = offsets[offsets.len() - 1].as_usize();
+            l.values().slice(start, end - start)
+        })
+        .collect();
+    let values: Vec<_> = values.iter().map(|v| v.as_ref()).collect();
+    let values = concat(&values)?;
+
+    let offsets = OffsetBuffer::from_lengths(
+        lists
+            .iter()
+            .flat_map(|l| l.offsets().windows(2).map(|w| (w[1] - w[0]).as_usize())),
+    );
+    let array =
+        GenericListArray::<O>::try_new(field.clone(), offsets, values, concat_nulls(arrays))?;
+    Ok(Arc::new(array))
+}
+
 macro_rules! dict_helper {
     ($t:ty, $arrays:expr) => {
         return Ok(Arc::new(concat_dictionaries::<$t>($arrays)?) as _)
@@ -147,6 +220,11 @@ fn get_capacity(arrays: &[&dyn Array], data_type: &DataType) -> Capacities {
 }
 
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
+///
+/// Dictionaries, including those nested within [`StructArray`] and [`GenericListArray`],
+/// will have their values merged where this is likely to produce a smaller output, or is
+/// necessary to avoid overflowing the key type. Returns [`ArrowError::DictionaryKeyOverflowError`]
+/// if the concatenated dictionary values cannot be represented by the key type.
 pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     if arrays.is_empty() {
         return Err(ArrowError::ComputeError(
@@ -163,14 +241,71 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
             "It is not possible to concatenate arrays of different data types.".to_string(),
         ));
     }
-    if let DataType::Dictionary(k, _) = d {
-        downcast_integer! {
-            k.as_ref() => (dict_helper, arrays),
-            _ => unreachable!("illegal dictionary key type {k}")
-        };
-    } else {
-        let capacity = get_capacity(arrays, d);
-        concat_fallback(arrays, capacity)
+    match d {
+        DataType::Dictionary(k, _) => {
+            downcast_integer! {
+                k.as_ref() => (dict_helper, arrays),
+                _ => unreachable!("illegal dictionary key type {k}")
+            };
+        }
+        DataType::Struct(fields) if contains_dictionary(d) => concat_structs(arrays, fields),
+        DataType::List(f) if contains_dictionary(d) => concat_lists::<i32>(arrays, f),
+        DataType::LargeList(f) if contains_dictionary(d) => concat_lists::<i64>(arrays, f),
+        _ => {
+            let capacity = get_capacity(arrays, d);
+            concat_fallback(arrays, capacity)
+        }
+    }
+}
+
+/// Concatenate multiple [Array] of the same type into a single [ArrayRef], compacting a
+/// concatenated byte view array, see [`concat()`]
+///
+/// Concatenating byte view arrays, such as [`StringViewArray`], shares the data buffers
+/// of `arrays`, which may retain much more memory than the concatenated values need. The
+/// result is compacted when the fraction of its data buffer bytes not referenced by its
+/// values exceeds `view_gc_threshold`, see [`GenericByteViewArray::compact`]
+///
+/// Returns an error if `view_gc_threshold` is not within `0.0..=1.0`
+///
+/// ```
+/// # use arrow_array::{Array, StringViewArray, cast::AsArray};
+/// # use arrow_select::concat::concat_compacted;
+/// let long = "a string longer than 12 bytes";
+/// let array = StringViewArray::from(vec![long, long, long, long]);
+/// let a = array.slice(0, 1);
+/// let b = array.slice(3, 1);
+///
+/// let concatenated = concat_compacted(&[&a, &b], 0.5).unwrap();
+/// let concatenated = concatenated.as_string_view();
+/// assert_eq!(concatenated.total_data_buffer_bytes(), 2 * long.len());
+/// ```
+pub fn concat_compacted(
+    arrays: &[&dyn Array],
+    view_gc_threshold: f64,
+) -> Result<ArrayRef, ArrowError> {
+    validate_view_gc_threshold(view_gc_threshold)?;
+    let array = concat(arrays)?;
+    Ok(compact_byte_views(array, view_gc_threshold))
+}
+
+/// Returns an error if `threshold` is not a valid fraction of data buffer bytes
+pub(crate) fn validate_view_gc_threshold(threshold: f64) -> Result<(), ArrowError> {
+    match (0.0..=1.0).contains(&threshold) {
+        true => Ok(()),
+        false => Err(ArrowError::InvalidArgumentError(format!(
+            "View GC threshold must be between 0 and 1, got {threshold}"
+        ))),
+    }
+}
+
+/// Compacts `array` if it is a byte view array with more than `threshold` of its data
+/// buffer bytes unreferenced, see [`GenericByteViewArray::compact`]
+pub(crate) fn compact_byte_views(array: ArrayRef, threshold: f64) -> ArrayRef {
+    match array.data_type() {
+        DataType::Utf8View => Arc::new(array.as_string_view().compact(threshold)),
+        DataType::BinaryView => Arc::new(array.as_binary_view().compact(threshold)),
+        _ => array,
     }
 }
 
@@ -229,14 +364,96 @@ pub fn concat_batches<'a>(
 mod tests {
     use super::*;
     use arrow_array::builder::StringDictionaryBuilder;
+    use arrow_buffer::{NullBuffer, ScalarBuffer};
     use arrow_schema::{Field, Schema};
 
+    #[test]
+    fn test_concat_compacted() {
+        let long = "a string longer than 12 bytes";
+        let array = BinaryViewArray::from_iter_values([long, long, long, long]);
+        let a = array.slice(0, 2);
+        let b = array.slice(2, 1);
+        let expected = BinaryViewArray::from_iter_values([long, long, long]);
+
+        // Both inputs retain the whole data buffer, of which 5 / 8 is unreferenced
+        // after concatenation
+        let concatenated = concat(&[&a, &b]).unwrap();
+        assert_eq!(concatenated.as_binary_view(), &expected);
+        assert_eq!(
+            concatenated.as_binary_view().total_data_buffer_bytes(),
+            8 * long.len()
+        );
+
+        let concatenated = concat_compacted(&[&a, &b], 0.7).unwrap();
+        assert_eq!(
+            concatenated.as_binary_view().total_data_buffer_bytes(),
+            8 * long.len()
+        );
+
+        let concatenated = concat_compacted(&[&a, &b], 0.6).unwrap();
+        assert_eq!(concatenated.as_binary_view(), &expected);
+        assert_eq!(
+            concatenated.as_binary_view().total_data_buffer_bytes(),
+            3 * long.len()
+        );
+
+        let values = Int32Array::from(vec![1, 2]);
+        let concatenated = concat_compacted(&[&values, &values], 0.6).unwrap();
+        assert_eq!(
+            concatenated.as_primitive::<Int32Type>().values(),
+            &[1, 2, 1, 2]
+        );
+
+        for threshold in [-0.1, 1.5, f64::NAN] {
+            let err = concat_compacted(&[&a, &b], threshold).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid argument error: View GC threshold must be between 0 and 1, got {threshold}")
+            );
+        }
+    }
+
     #[test]
     fn test_concat_empty_vec() {
         let re = concat(&[]);
         assert!(re.is_err());
     }
 
+    #[test]
+    fn test_concat_list_view() {
+        // Views are out of order and overlapping: [[4, 5], [1, 2], [2, 3, 4], null]
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
+        let offsets = ScalarBuffer::from(vec![3, 0, 1, 2]);
+        let sizes = ScalarBuffer::from(vec![2, 2, 3, 1]);
+        let nulls = NullBuffer::from(vec![true, true, true, false]);
+        let a = ListViewArray::new(field.clone(), offsets, sizes, values, Some(nulls));
+
+        let values = Arc::new(Int32Array::from(vec![6, 7]));
+        let offsets = ScalarBuffer::from(vec![1, 0]);
+        let sizes = ScalarBuffer::from(vec![1, 2]);
+        let b = ListViewArray::new(field, offsets, sizes, values, None);
+
+        let c = concat(&[&a, &b.slice(1, 1), &a.slice(2, 2), &b]).unwrap();
+        let c = c.as_list_view::<i32>();
+        let actual: Vec<_> = c
+            .iter()
+            .map(|x| x.map(|x| x.as_primitive::<Int32Type>().values().to_vec()))
+            .collect();
+        let expected = vec![
+            Some(vec![4, 5]),
+            Some(vec![1, 2]),
+            Some(vec![2, 3, 4]),
+            None,
+            Some(vec![6, 7]),
+            Some(vec![2, 3, 4]),
+            None,
+            Some(vec![7]),
+            Some(vec![6, 7]),
+        ];
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_concat_batches_no_columns() {
         // Test concat using empty schema / batches without columns
@@ -555,7 +772,9 @@ mod tests {
         assert_eq!(actual_output, &expected_output);
     }
 
-    fn collect_string_dictionary(array: &DictionaryArray<Int32Type>) -> Vec<Option<&str>> {
+    fn collect_string_dictionary<K: ArrowDictionaryKeyType>(
+        array: &DictionaryArray<K>,
+    ) -> Vec<Option<&str>> {
         let concrete = array.downcast_dict::<StringArray>().unwrap();
         concrete.into_iter().collect()
     }
@@ -851,4 +1070,151 @@ mod tests {
         assert_eq!(array.null_count(), 10);
         assert_eq!(array.logical_null_count(), 10);
     }
+
+    #[test]
+    fn test_fixed_size_binary_dictionary_merge() {
+        let values = (0..100u32).map(|x| x.to_le_bytes());
+        let values = FixedSizeBinaryArray::try_from_iter(values).unwrap();
+        let keys = Int8Array::from_iter_values(0..100);
+        let a = DictionaryArray::new(keys, Arc::new(values.clone()));
+        // Copy values so that dictionaries are not pointer equal
+        let values = FixedSizeBinaryArray::try_from_iter(values.iter().flatten()).unwrap();
+        let keys = Int8Array::from_iter(vec![Some(99), None, Some(0)]);
+        let b = DictionaryArray::new(keys, Arc::new(values));
+
+        // Naively concatenating the 200 values would overflow Int8 keys
+        let array = concat(&[&a, &b]).unwrap();
+        let dictionary = array.as_dictionary::<Int8Type>();
+        assert_eq!(dictionary.len(), 103);
+        assert_eq!(dictionary.null_count(), 1);
+        assert!(dictionary.values().len() <= 127);
+
+        let typed = dictionary.downcast_dict::<FixedSizeBinaryArray>().unwrap();
+        let actual: Vec<_> = typed.into_iter().collect();
+        let expected: Vec<_> = (0..100u32)
+            .map(Some)
+            .chain([Some(99), None, Some(0)])
+            .map(|x| x.map(|x| x.to_le_bytes()))
+            .collect();
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|x| x.as_ref().map(|x| x.as_slice()))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_dictionary_key_overflow() {
+        let a = DictionaryArray::new(
+            Int8Array::from_iter_values(0..100),
+            Arc::new(Int32Array::from_iter_values(0..100)),
+        );
+        let b = DictionaryArray::new(
+            Int8Array::from_iter_values(0..100),
+            Arc::new(Int32Array::from_iter_values(100..200)),
+        );
+        let err = concat(&[&a, &b]).unwrap_err();
+        assert!(
+            matches!(err, ArrowError::DictionaryKeyOverflowError),
+            "{err}"
+        );
+
+        // Does not overflow when concatenating the same dictionary
+        let array = concat(&[&a, &a]).unwrap();
+        let dictionary = array.as_dictionary::<Int8Type>();
+        assert_eq!(dictionary.len(), 200);
+        assert_eq!(dictionary.values().len(), 100);
+    }
+
+    fn string_dictionary(len: usize) -> DictionaryArray<Int8Type> {
+        let mut builder = StringDictionaryBuilder::<Int8Type>::new();
+        for i in 0..len {
+            builder.append(i.to_string()).unwrap();
+        }
+        builder.finish()
+    }
+
+    #[test]
+    fn test_struct_dictionary_merge() {
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new_dictionary("b", DataType::Int8, DataType::Utf8, true),
+        ]);
+        let a = StructArray::new(
+            fields.clone(),
+            vec![
+                Arc::new(Int32Array::from_iter_values(0..100)),
+                Arc::new(string_dictionary(100)),
+            ],
+            None,
+        );
+        let nulls = NullBuffer::from_iter((0..100).map(|x| x % 2 == 0));
+        let b = StructArray::new(
+            fields.clone(),
+            vec![
+                Arc::new(Int32Array::from_iter_values(100..200)),
+                Arc::new(string_dictionary(100)),
+            ],
+            Some(nulls),
+        );
+
+        let array = concat(&[&a, &b.slice(10, 50)]).unwrap();
+        let array = array.as_struct();
+        assert_eq!(array.len(), 150);
+        assert_eq!(array.null_count(), 25);
+        assert_eq!(array.fields(), &fields);
+
+        let ints = array.column(0).as_primitive::<Int32Type>();
+        let expected = Int32Array::from_iter_values((0..100).chain(110..160));
+        assert_eq!(ints, &expected);
+
+        let dictionary = array.column(1).as_dictionary::<Int8Type>();
+        // Merged on a best-effort basis
+        let values_len = dictionary.values().len();
+        assert!((100..128).contains(&values_len), "{values_len}");
+        let actual = collect_string_dictionary(dictionary);
+        let expected: Vec<_> = (0..100).chain(10..60).map(|x| x.to_string()).collect();
+        let expected: Vec<_> = expected.iter().map(|x| Some(x.as_str())).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_list_dictionary_merge() {
+        let field = Arc::new(Field::new_dictionary(
+            "item",
+            DataType::Int8,
+            DataType::Utf8,
+            true,
+        ));
+        let offsets = OffsetBuffer::from_lengths([10, 0, 90]);
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let a = ListArray::new(
+            field.clone(),
+            offsets,
+            Arc::new(string_dictionary(100)),
+            Some(nulls),
+        );
+        let offsets = OffsetBuffer::from_lengths([50, 30, 20]);
+        let b = ListArray::new(
+            field.clone(),
+            offsets,
+            Arc::new(string_dictionary(100)),
+            None,
+        );
+
+        let array = concat(&[&a, &b.slice(1, 2)]).unwrap();
+        let array = array.as_list::<i32>();
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.value_offsets(), &[0, 10, 10, 100, 130, 150]);
+
+        let dictionary = array.values().as_dictionary::<Int8Type>();
+        // Merged on a best-effort basis
+        let values_len = dictionary.values().len();
+        assert!((100..128).contains(&values_len), "{values_len}");
+        let actual = collect_string_dictionary(dictionary);
+        let expected: Vec<_> = (0..100).chain(50..100).map(|x| x.to_string()).collect();
+        let expected: Vec<_> = expected.iter().map(|x| Some(x.as_str())).collect();
+        assert_eq!(actual, expected);
+    }
 }
@@ -22,7 +22,7 @@ use arrow_array::cast::AsArray;
 use arrow_array::types::{
     ArrowDictionaryKeyType, BinaryType, ByteArrayType, LargeBinaryType, LargeUtf8Type, Utf8Type,
 };
-use arrow_array::{Array, ArrayRef, DictionaryArray, GenericByteArray};
+use arrow_array::{Array, ArrayRef, DictionaryArray, FixedSizeBinaryArray, GenericByteArray};
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, ScalarBuffer};
 use arrow_schema::{ArrowError, DataType};
 
@@ -94,6 +94,23 @@ fn bytes_ptr_eq<T: ByteArrayType>(a: &dyn Array, b: &dyn Array) -> bool {
     }
 }
 
+/// Performs a cheap, pointer-based comparison of two fixed size binary arrays
+///
+/// See [`Buffer::ptr_eq`](arrow_buffer::Buffer::ptr_eq)
+fn fixed_size_binary_ptr_eq(a: &dyn Array, b: &dyn Array) -> bool {
+    match (a.as_fixed_size_binary_opt(), b.as_fixed_size_binary_opt()) {
+        (Some(a), Some(b)) => {
+            let values_eq = a.values().ptr_eq(b.values());
+            match (a.nulls(), b.nulls()) {
+                (Some(a), Some(b)) => values_eq && a.inner().ptr_eq(b.inner()),
+                (None, None) => values_eq,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
 /// A type-erased function that compares two array for pointer equality
 type PtrEq = dyn Fn(&dyn Array, &dyn Array) -> bool;
 
@@ -113,6 +130,7 @@ pub fn should_merge_dictionary_values<K: ArrowDictionaryKeyType>(
         LargeUtf8 => Box::new(bytes_ptr_eq::<LargeUtf8Type>),
         Binary => Box::new(bytes_ptr_eq::<BinaryType>),
         LargeBinary => Box::new(bytes_ptr_eq::<LargeBinaryType>),
+        FixedSizeBinary(_) => Box::new(fixed_size_binary_ptr_eq),
         _ => return false,
     };
 
@@ -229,10 +247,25 @@ fn get_masked_values<'a>(array: &'a dyn Array, mask: &BooleanBuffer) -> Vec<(usi
         DataType::LargeUtf8 => masked_bytes(array.as_string::<i64>(), mask),
         DataType::Binary => masked_bytes(array.as_binary::<i32>(), mask),
         DataType::LargeBinary => masked_bytes(array.as_binary::<i64>(), mask),
+        DataType::FixedSizeBinary(_) => {
+            masked_fixed_size_binary(array.as_fixed_size_binary(), mask)
+        }
         _ => unimplemented!(),
     }
 }
 
+/// Compute [`get_masked_values`] for a [`FixedSizeBinaryArray`]
+///
+/// Note: this does not check the null mask and will return values contained in null slots
+fn masked_fixed_size_binary<'a>(
+    array: &'a FixedSizeBinaryArray,
+    mask: &BooleanBuffer,
+) -> Vec<(usize, &'a [u8])> {
+    mask.set_indices()
+        .map(|idx| (idx, array.value(idx)))
+        .collect()
+}
+
 /// Compute [`get_masked_values`] for a [`GenericByteArray`]
 ///
 /// Note: this does not check the null mask and will return values contained in null slots
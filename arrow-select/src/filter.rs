@@ -17,7 +17,6 @@
 
 //! Defines filter kernels
 
-use std::ops::AddAssign;
 use std::sync::Arc;
 
 use arrow_array::builder::BooleanBufferBuilder;
@@ -26,13 +25,15 @@ use arrow_array::types::{
     ArrowDictionaryKeyType, ArrowPrimitiveType, ByteArrayType, ByteViewType, RunEndIndexType,
 };
 use arrow_array::*;
-use arrow_buffer::{bit_util, ArrowNativeType, BooleanBuffer, NullBuffer, RunEndBuffer};
+use arrow_buffer::{bit_util, ArrowNativeType, BooleanBuffer, NullBuffer, ScalarBuffer};
 use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_data::bit_iterator::{BitIndexIterator, BitSliceIterator};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::*;
 
+use crate::take::merge_runs;
+
 /// If the filter selects more than this fraction of rows, use
 /// [`SlicesIterator`] to copy ranges of values. Otherwise iterate
 /// over individual rows using [`IndexIterator`]
@@ -195,6 +196,53 @@ fn multiple_arrays(data_type: &DataType) -> bool {
     }
 }
 
+/// Returns a filtered [`ChunkedArray`] where the corresponding elements of
+/// `predicate` are `true`.
+///
+/// This is the equivalent of calling [filter] on each chunk with the corresponding
+/// slice of `predicate`, chunks without any selected values are omitted.
+///
+/// # Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, ChunkedArray, Int32Array};
+/// # use arrow_schema::DataType;
+/// # use arrow_select::filter::filter_chunked;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![5, 6, 7]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![8, 9]));
+/// let array = ChunkedArray::try_new(DataType::Int32, vec![a, b]).unwrap();
+/// let filter_array = BooleanArray::from(vec![true, false, false, true, false]);
+/// let c = filter_chunked(&array, &filter_array).unwrap();
+/// assert_eq!(c.num_chunks(), 2);
+/// assert_eq!(c.chunk(1).as_ref(), &Int32Array::from(vec![8]));
+/// ```
+pub fn filter_chunked(
+    values: &ChunkedArray,
+    predicate: &BooleanArray,
+) -> Result<ChunkedArray, ArrowError> {
+    if predicate.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Filter predicate of length {} does not match ChunkedArray of length {}",
+            predicate.len(),
+            values.len()
+        )));
+    }
+
+    let chunks = values
+        .chunks()
+        .iter()
+        .zip(values.chunk_offsets())
+        .filter_map(|(chunk, offset)| {
+            let predicate = predicate.slice(*offset, chunk.len());
+            match predicate.true_count() {
+                0 => None,
+                _ => Some(filter(chunk, &predicate)),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    ChunkedArray::try_new(values.data_type().clone(), chunks)
+}
+
 /// Returns a filtered [RecordBatch] where the corresponding elements of
 /// `predicate` are true.
 ///
@@ -274,6 +322,184 @@ impl FilterBuilder {
             strategy: self.strategy,
         }
     }
+
+    /// Construct a [`FilterSelection`], deferring the materialization of filtered arrays
+    ///
+    /// Selective filters are represented as a list of indices, otherwise the filter mask
+    /// is retained and optimized for repeated application
+    pub fn build_selection(self) -> FilterSelection {
+        let len = self.filter.len();
+        let kind = match self.strategy {
+            IterationStrategy::IndexIterator | IterationStrategy::Indices(_)
+                if len <= u32::MAX as usize =>
+            {
+                let indices = IndexIterator::new(&self.filter, self.count).map(|x| x as u32);
+                SelectionKind::Indices(UInt32Array::from_iter_values(indices))
+            }
+            _ => SelectionKind::Predicate(self.optimize().build()),
+        };
+        FilterSelection { len, kind }
+    }
+}
+
+/// A selection of rows computed by a [`FilterBuilder`], that can be cheaply applied
+/// to any number of arrays with [`FilterSelection::apply`]
+///
+/// This allows engines evaluating multiple predicates to defer materializing filtered
+/// columns until they are needed, using [`FilterSelection::refine`] to combine the
+/// selections of subsequent predicates
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Int32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::filter::FilterBuilder;
+/// let a = Int32Array::from(vec![1, 2, 3, 4, 5, 6]);
+/// let b = Int32Array::from(vec![10, 20, 30, 40, 50, 60]);
+///
+/// // a > 2
+/// let selection = FilterBuilder::new(&BooleanArray::from(vec![false, false, true, true, true, true]))
+///     .build_selection();
+///
+/// // b != 50, evaluated only on the rows selected by the first predicate
+/// let selected_b = selection.apply(&b).unwrap();
+/// let predicate = BooleanArray::from_unary(selected_b.as_primitive::<Int32Type>(), |x| x != 50);
+/// let selection = selection.refine(&predicate).unwrap();
+///
+/// assert_eq!(selection.count(), 3);
+/// let a = selection.apply(&a).unwrap();
+/// assert_eq!(a.as_primitive::<Int32Type>().values(), &[3, 4, 6]);
+/// ```
+#[derive(Debug)]
+pub struct FilterSelection {
+    len: usize,
+    kind: SelectionKind,
+}
+
+/// The representation of a [`FilterSelection`]
+#[derive(Debug)]
+enum SelectionKind {
+    /// The indices of the selected rows
+    Indices(UInt32Array),
+    /// A filter mask of the selected rows
+    Predicate(FilterPredicate),
+}
+
+impl FilterSelection {
+    /// The number of rows this selection applies to
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if this selection applies to no rows
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of rows selected
+    pub fn count(&self) -> usize {
+        match &self.kind {
+            SelectionKind::Indices(indices) => indices.len(),
+            SelectionKind::Predicate(predicate) => predicate.count(),
+        }
+    }
+
+    /// Returns the indices of the selected rows
+    pub fn to_indices(&self) -> UInt64Array {
+        match &self.kind {
+            SelectionKind::Indices(indices) => indices.unary(|x| x as u64),
+            SelectionKind::Predicate(predicate) => {
+                let indices = IndexIterator::new(&predicate.filter, predicate.count);
+                UInt64Array::from_iter_values(indices.map(|x| x as u64))
+            }
+        }
+    }
+
+    /// Returns a bitmap of length [`Self::len`], with the bits of the selected rows set
+    pub fn to_bitmap(&self) -> BooleanBuffer {
+        match &self.kind {
+            SelectionKind::Indices(indices) => {
+                let mut builder = BooleanBufferBuilder::new(self.len);
+                builder.append_n(self.len, false);
+                indices
+                    .values()
+                    .iter()
+                    .for_each(|x| builder.set_bit(*x as usize, true));
+                builder.finish()
+            }
+            SelectionKind::Predicate(predicate) => predicate.filter.values().clone(),
+        }
+    }
+
+    /// Selects rows from `values` based on this [`FilterSelection`]
+    ///
+    /// Returns an error if `values` is not of length [`Self::len`]
+    pub fn apply(&self, values: &dyn Array) -> Result<ArrayRef, ArrowError> {
+        if values.len() != self.len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Filter selection of length {} cannot be applied to array of length {}",
+                self.len,
+                values.len()
+            )));
+        }
+        match &self.kind {
+            SelectionKind::Indices(indices) => crate::take::take(values, indices, None),
+            SelectionKind::Predicate(predicate) => predicate.filter(values),
+        }
+    }
+
+    /// Selects rows from `record_batch` based on this [`FilterSelection`]
+    pub fn apply_record_batch(
+        &self,
+        record_batch: &RecordBatch,
+    ) -> Result<RecordBatch, ArrowError> {
+        let columns = record_batch
+            .columns()
+            .iter()
+            .map(|a| self.apply(a))
+            .collect::<Result<Vec<_>, _>>()?;
+        let options = RecordBatchOptions::default().with_row_count(Some(self.count()));
+        RecordBatch::try_new_with_options(record_batch.schema(), columns, &options)
+    }
+
+    /// Returns a new [`FilterSelection`] further restricting this selection by `predicate`
+    ///
+    /// `predicate` is relative to the rows selected by this selection, as returned by
+    /// [`Self::apply`], and so must be of length [`Self::count`]. Null values of
+    /// `predicate` are treated as `false`
+    pub fn refine(&self, predicate: &BooleanArray) -> Result<Self, ArrowError> {
+        if predicate.len() != self.count() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Predicate of length {} cannot refine a selection of {} rows",
+                predicate.len(),
+                self.count()
+            )));
+        }
+        let predicate = match predicate.null_count() {
+            0 => predicate.clone(),
+            _ => prep_null_mask_filter(predicate),
+        };
+
+        let mut builder = BooleanBufferBuilder::new(self.len);
+        builder.append_n(self.len, false);
+        let mut set = predicate.values().iter();
+        let mut select = |idx: usize| {
+            if set.next().unwrap_or_default() {
+                builder.set_bit(idx, true)
+            }
+        };
+        match &self.kind {
+            SelectionKind::Indices(indices) => {
+                indices.values().iter().for_each(|x| select(*x as usize))
+            }
+            SelectionKind::Predicate(predicate) => {
+                IndexIterator::new(&predicate.filter, predicate.count).for_each(select)
+            }
+        }
+        let filter = BooleanArray::new(builder.finish(), None);
+        Ok(FilterBuilder::new(&filter).build_selection())
+    }
 }
 
 /// The iteration strategy used to evaluate [`FilterPredicate`]
@@ -390,6 +616,12 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
             DataType::Struct(_) => {
                 Ok(Arc::new(filter_struct(values.as_struct(), predicate)?))
             }
+            DataType::ListView(_) => {
+                Ok(Arc::new(filter_list_view::<i32>(values.as_list_view(), predicate)?))
+            }
+            DataType::LargeListView(_) => {
+                Ok(Arc::new(filter_list_view::<i64>(values.as_list_view(), predicate)?))
+            }
             DataType::Union(_, UnionMode::Sparse) => {
                 Ok(Arc::new(filter_sparse_union(values.as_union(), predicate)?))
             }
@@ -422,50 +654,40 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
 }
 
 /// Filter any supported [`RunArray`] based on a [`FilterPredicate`]
+///
+/// The output is run-end encoded, with adjacent runs of equal values merged
 fn filter_run_end_array<R: RunEndIndexType>(
     array: &RunArray<R>,
     predicate: &FilterPredicate,
-) -> Result<RunArray<R>, ArrowError>
-where
-    R::Native: Into<i64> + From<bool>,
-    R::Native: AddAssign,
-{
-    let run_ends: &RunEndBuffer<R::Native> = array.run_ends();
-    let mut new_run_ends = vec![R::default_value(); run_ends.len()];
-
-    let mut start = 0u64;
-    let mut j = 0;
-    let mut count = R::default_value();
+) -> Result<RunArray<R>, ArrowError> {
+    let run_ends = array.run_ends();
     let filter_values = predicate.filter.values();
-    let run_ends = run_ends.inner();
-
-    let pred: BooleanArray = BooleanBuffer::collect_bool(run_ends.len(), |i| {
-        let mut keep = false;
-        let mut end = run_ends[i].into() as u64;
-        let difference = end.saturating_sub(filter_values.len() as u64);
-        end -= difference;
-
-        // Safety: we subtract the difference off `end` so we are always within bounds
-        for pred in (start..end).map(|i| unsafe { filter_values.value_unchecked(i as usize) }) {
-            count += R::Native::from(pred);
-            keep |= pred
+    let len = filter_values.len();
+
+    // The end of each output run, and the physical index of its value
+    let mut new_run_ends = vec![];
+    let mut physical_indices = vec![];
+    if !run_ends.is_empty() {
+        let mut start = 0;
+        let mut count = 0;
+        for physical in run_ends.get_start_physical_index()..=run_ends.get_end_physical_index() {
+            let end = (run_ends.values()[physical].as_usize() - run_ends.offset()).min(len);
+            let selected = filter_values.slice(start, end - start).count_set_bits();
+            if selected != 0 {
+                count += selected;
+                new_run_ends.push(count);
+                physical_indices.push(physical as u32);
+            }
+            start = end;
+            if start == len {
+                break;
+            }
         }
-        // this is to avoid branching
-        new_run_ends[j] = count;
-        j += keep as usize;
-
-        start = end;
-        keep
-    })
-    .into();
-
-    new_run_ends.truncate(j);
-
-    let values = array.values();
-    let values = filter(&values, &pred)?;
+    }
 
-    let run_ends = PrimitiveArray::<R>::new(new_run_ends.into(), None);
-    RunArray::try_new(&run_ends, &values)
+    let indices = UInt32Array::from(physical_indices);
+    let values = crate::take::take(array.values(), &indices, None)?;
+    merge_runs(new_run_ends, values)
 }
 
 /// Computes a new null mask for `data` based on `predicate`
@@ -838,6 +1060,35 @@ fn filter_struct(
     Ok(unsafe { StructArray::new_unchecked(array.fields().clone(), columns, nulls) })
 }
 
+/// `filter` implementation for list views
+///
+/// As the views of the output may reference any values of the input, only the
+/// offsets, sizes and nulls are filtered, with the values shared with the input
+fn filter_list_view<O: OffsetSizeTrait>(
+    array: &GenericListViewArray<O>,
+    predicate: &FilterPredicate,
+) -> Result<GenericListViewArray<O>, ArrowError> {
+    let field = match array.data_type() {
+        DataType::ListView(f) | DataType::LargeListView(f) => f.clone(),
+        _ => unreachable!(),
+    };
+    let offsets = filter_native(array.offsets(), predicate);
+    let sizes = filter_native(array.sizes(), predicate);
+
+    let nulls = filter_null_mask(array.nulls(), predicate).map(|(null_count, nulls)| {
+        let buffer = BooleanBuffer::new(nulls, 0, predicate.count);
+        unsafe { NullBuffer::new_unchecked(buffer, null_count) }
+    });
+
+    GenericListViewArray::try_new(
+        field,
+        ScalarBuffer::new(offsets, 0, predicate.count),
+        ScalarBuffer::new(sizes, 0, predicate.count),
+        array.values().clone(),
+        nulls,
+    )
+}
+
 /// `filter` implementation for sparse unions
 fn filter_sparse_union(
     array: &UnionArray,
@@ -1279,6 +1530,23 @@ mod tests {
         assert_eq!(0, actual.len());
     }
 
+    #[test]
+    fn test_filter_run_end_encoding_array_merge_runs() {
+        let run_ends = Int32Array::from(vec![2, 3, 5, 6, 8]);
+        let values = StringArray::from(vec![Some("a"), Some("b"), Some("a"), None, None]);
+        let a = RunArray::try_new(&run_ends, &values).unwrap();
+        let a = a.slice(1, 6);
+
+        // Removing the run of "b" merges the adjacent runs of "a"
+        let b = BooleanArray::from(vec![true, false, true, true, true, false]);
+        let c = filter(&a, &b).unwrap();
+        let actual: &RunArray<Int32Type> = as_run_array(&c);
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual.run_ends().values(), &[3, 4]);
+        let values = actual.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec![Some("a"), None]));
+    }
+
     #[test]
     fn test_filter_run_end_encoding_array_max_value_gt_predicate_len() {
         let run_ends = Int64Array::from(vec![2, 3, 8, 10]);
@@ -1320,6 +1588,85 @@ mod tests {
         assert_eq!("world", values.value(d.keys().value(1) as usize));
     }
 
+    fn test_filter_list_view_generic<O: OffsetSizeTrait>() {
+        // Views are out of order and overlapping: [[4, 5], [1, 2], [2, 3, 4], null, [5]]
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
+        let offsets = ScalarBuffer::from_iter([3, 0, 1, 2, 4].map(O::usize_as));
+        let sizes = ScalarBuffer::from_iter([2, 2, 3, 1, 1].map(O::usize_as));
+        let nulls = NullBuffer::from(vec![true, true, true, false, true]);
+        let list = GenericListViewArray::<O>::new(field, offsets, sizes, values, Some(nulls));
+
+        let predicate = BooleanArray::from(vec![true, false, true, true, false]);
+        let filtered = filter(&list, &predicate).unwrap();
+        let filtered = filtered.as_list_view::<O>();
+        assert!(Arc::ptr_eq(filtered.values(), list.values()));
+
+        let actual: Vec<_> = filtered
+            .iter()
+            .map(|x| x.map(|x| x.as_primitive::<Int32Type>().values().to_vec()))
+            .collect();
+        assert_eq!(actual, vec![Some(vec![4, 5]), Some(vec![2, 3, 4]), None]);
+
+        // Sliced input
+        let sliced = list.slice(1, 4);
+        let predicate = BooleanArray::from(vec![true, false, false, true]);
+        let filtered = filter(&sliced, &predicate).unwrap();
+        let filtered = filtered.as_list_view::<O>();
+        let actual: Vec<_> = filtered
+            .iter()
+            .map(|x| x.map(|x| x.as_primitive::<Int32Type>().values().to_vec()))
+            .collect();
+        assert_eq!(actual, vec![Some(vec![1, 2]), Some(vec![5])]);
+    }
+
+    #[test]
+    fn test_filter_list_view() {
+        test_filter_list_view_generic::<i32>();
+        test_filter_list_view_generic::<i64>();
+    }
+
+    #[test]
+    fn test_filter_chunked() {
+        let chunks: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(Int32Array::from(Vec::<i32>::new())),
+            Arc::new(Int32Array::from(vec![4, 5])),
+            Arc::new(Int32Array::from(vec![6])),
+        ];
+        let values = ChunkedArray::try_new(DataType::Int32, chunks).unwrap();
+
+        let predicate = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+        ]);
+        let filtered = filter_chunked(&values, &predicate).unwrap();
+        assert_eq!(filtered.num_chunks(), 2);
+        assert_eq!(
+            filtered.chunk(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![None, Some(3)])
+        );
+        assert_eq!(
+            filtered.chunk(1).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![6])
+        );
+
+        let predicate = BooleanArray::from(vec![true, false, true]);
+        let filtered = filter_chunked(&values.slice(2, 3), &predicate).unwrap();
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![3, 5]));
+        assert_eq!(filtered, ChunkedArray::from(expected));
+
+        let err = filter_chunked(&values, &predicate).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter predicate of length 3 does not match ChunkedArray of length 6"
+        );
+    }
+
     #[test]
     fn test_filter_list_array() {
         let value_data = ArrayData::builder(DataType::Int32)
@@ -2039,4 +2386,67 @@ mod tests {
 
         assert_eq!(result.to_data(), expected.to_data());
     }
+
+    #[test]
+    fn test_filter_selection() {
+        let a = Int32Array::from_iter_values(0..100);
+        let b = StringArray::from_iter_values((0..100).map(|x| format!("v{x}")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a.clone()) as ArrayRef),
+            ("b", Arc::new(b.clone()) as ArrayRef),
+        ])
+        .unwrap();
+
+        // Selective predicates use indices, others use the filter mask
+        for step in [1, 2, 10] {
+            let mask = BooleanArray::from_iter((0..100).map(|x| Some(x % step == 0)));
+            let selection = FilterBuilder::new(&mask).build_selection();
+            assert_eq!(selection.len(), 100);
+            assert_eq!(selection.count(), 100 / step);
+            assert_eq!(selection.to_bitmap(), mask.values().clone());
+            let indices: Vec<_> = (0..100).step_by(step).map(|x| x as u64).collect();
+            assert_eq!(selection.to_indices().values(), &indices);
+
+            let expected = filter_record_batch(&batch, &mask).unwrap();
+            assert_eq!(selection.apply_record_batch(&batch).unwrap(), expected);
+            assert_eq!(
+                selection.apply(&b).unwrap().as_ref(),
+                filter(&b, &mask).unwrap().as_ref()
+            );
+
+            // Refine by selecting every third of the selected rows, null is false
+            let predicate = BooleanArray::from_iter(
+                (0..selection.count()).map(|x| (x % 6 != 3).then_some(x % 3 == 0)),
+            );
+            let refined = selection.refine(&predicate).unwrap();
+            let expected: Vec<_> = (0..100)
+                .step_by(step)
+                .enumerate()
+                .filter(|(i, _)| i % 6 == 0)
+                .map(|(_, x)| x)
+                .collect();
+            let result = refined.apply(&a).unwrap();
+            assert_eq!(result.as_primitive::<Int32Type>().values(), &expected);
+        }
+
+        let selection =
+            FilterBuilder::new(&BooleanArray::from(vec![false, true])).build_selection();
+        let err = selection.apply(&a).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter selection of length 2 cannot be applied to array of length 100"
+        );
+        let err = selection
+            .refine(&BooleanArray::from(vec![true, true]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Predicate of length 2 cannot refine a selection of 1 rows"
+        );
+
+        let empty = FilterBuilder::new(&BooleanArray::from(vec![false; 3])).build_selection();
+        assert_eq!(empty.count(), 0);
+        assert!(!empty.is_empty());
+        assert_eq!(empty.apply(&a.slice(0, 3)).unwrap().len(), 0);
+    }
 }
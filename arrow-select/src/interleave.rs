@@ -269,6 +269,9 @@ fn interleave_fallback(
 ///
 /// This function will call [`interleave`] on each array of the [`RecordBatch`] instances and assemble a new [`RecordBatch`].
 ///
+/// Returns an error if `record_batches` is empty, or the [`RecordBatch`] instances do not
+/// all have the same fields. The schema of the first [`RecordBatch`] is used for the output.
+///
 /// # Example
 /// ```
 /// # use std::sync::Arc;
@@ -313,7 +316,23 @@ pub fn interleave_record_batch(
     record_batches: &[&RecordBatch],
     indices: &[(usize, usize)],
 ) -> Result<RecordBatch, ArrowError> {
-    let schema = record_batches[0].schema();
+    let Some(first) = record_batches.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "interleave_record_batch requires input of at least one record batch".to_string(),
+        ));
+    };
+    let schema = first.schema();
+    if let Some(batch) = record_batches
+        .iter()
+        .find(|b| b.schema_ref().fields() != schema.fields())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "It is not possible to interleave record batches with different schemas ({} and {})",
+            schema,
+            batch.schema_ref()
+        )));
+    }
+
     let columns = (0..schema.fields().len())
         .map(|i| {
             let column_values: Vec<&dyn Array> = record_batches
@@ -323,13 +342,15 @@ pub fn interleave_record_batch(
             interleave(&column_values, indices)
         })
         .collect::<Result<Vec<_>, _>>()?;
-    RecordBatch::try_new(schema, columns)
+    let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+    RecordBatch::try_new_with_options(schema, columns, &options)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use arrow_array::builder::{Int32Builder, ListBuilder};
+    use arrow_schema::{Field, Schema};
 
     #[test]
     fn test_primitive() {
@@ -461,4 +482,72 @@ mod tests {
             DictionaryArray::<Int32Type>::from_iter(vec![Some("0"), Some("1"), Some("2"), None]);
         assert_eq!(array.as_ref(), &expected)
     }
+
+    #[test]
+    fn test_interleave_record_batch() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let a = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )
+        .unwrap();
+        let b = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![3, 4, 5])),
+                Arc::new(StringArray::from(vec![Some("c"), None, Some("e")])),
+            ],
+        )
+        .unwrap();
+
+        let batch = interleave_record_batch(&[&a, &b], &[(1, 2), (0, 1), (1, 1)]).unwrap();
+        let expected = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(5), None, Some(4)])),
+                Arc::new(StringArray::from(vec![Some("e"), Some("b"), None])),
+            ],
+        )
+        .unwrap();
+        assert_eq!(batch, expected);
+
+        let batch = interleave_record_batch(&[&a, &b], &[]).unwrap();
+        assert_eq!(batch, RecordBatch::new_empty(schema));
+
+        // No columns
+        let options = RecordBatchOptions::new().with_row_count(Some(2));
+        let empty =
+            RecordBatch::try_new_with_options(Arc::new(Schema::empty()), vec![], &options).unwrap();
+        let batch = interleave_record_batch(&[&empty], &[(0, 1), (0, 0), (0, 1)]).unwrap();
+        assert_eq!(batch.num_columns(), 0);
+        assert_eq!(batch.num_rows(), 3);
+    }
+
+    #[test]
+    fn test_interleave_record_batch_invalid() {
+        let err = interleave_record_batch(&[], &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: interleave_record_batch requires input of at least one record batch"
+        );
+
+        let a =
+            RecordBatch::try_from_iter([("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef)])
+                .unwrap();
+        let b =
+            RecordBatch::try_from_iter([("b", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef)])
+                .unwrap();
+        let err = interleave_record_batch(&[&a, &b], &[(0, 0), (1, 0)]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("It is not possible to interleave record batches with different schemas"),
+            "{err}"
+        );
+    }
 }
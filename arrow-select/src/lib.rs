@@ -18,6 +18,7 @@
 #![warn(missing_docs)]
 //! Arrow selection kernels
 
+pub mod coalesce;
 pub mod concat;
 mod dictionary;
 pub mod filter;
@@ -19,7 +19,7 @@
 
 use std::sync::Arc;
 
-use arrow_array::builder::{BufferBuilder, UInt32Builder};
+use arrow_array::builder::UInt32Builder;
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
@@ -29,6 +29,8 @@ use arrow_buffer::{
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, FieldRef, UnionMode};
 
+use crate::concat::{compact_byte_views, validate_view_gc_threshold};
+
 use num::{One, Zero};
 
 /// Take elements by index from [Array], creating a new [Array] from those indexes.
@@ -98,6 +100,36 @@ pub fn take(
     }
 }
 
+/// Take elements by index from [Array], compacting a taken byte view array, see [`take`]
+///
+/// Taking from a byte view array, such as a [`StringViewArray`], shares the data buffers
+/// of `values`, which may retain much more memory than the taken values need. The result
+/// is compacted when the fraction of its data buffer bytes not referenced by its values
+/// exceeds `view_gc_threshold`, see [`GenericByteViewArray::compact`]
+///
+/// Returns an error if `view_gc_threshold` is not within `0.0..=1.0`
+///
+/// ```
+/// # use arrow_array::{StringViewArray, UInt32Array, cast::AsArray};
+/// # use arrow_select::take::take_compacted;
+/// let long = "a string longer than 12 bytes";
+/// let values = StringViewArray::from(vec!["a", long, "b", long]);
+/// let indices = UInt32Array::from(vec![0, 3]);
+///
+/// let taken = take_compacted(&values, &indices, None, 0.4).unwrap();
+/// assert_eq!(taken.as_string_view().total_data_buffer_bytes(), long.len());
+/// ```
+pub fn take_compacted(
+    values: &dyn Array,
+    indices: &dyn Array,
+    options: Option<TakeOptions>,
+    view_gc_threshold: f64,
+) -> Result<ArrayRef, ArrowError> {
+    validate_view_gc_threshold(view_gc_threshold)?;
+    let taken = take(values, indices, options)?;
+    Ok(compact_byte_views(taken, view_gc_threshold))
+}
+
 /// For each [ArrayRef] in the [`Vec<ArrayRef>`], take elements by index and create a new
 /// [`Vec<ArrayRef>`] from those indices.
 ///
@@ -157,6 +189,75 @@ pub fn take_arrays(
         .collect()
 }
 
+/// Take elements by logical index from a [`ChunkedArray`], creating a new [Array] from
+/// those indexes, without first concatenating the chunks of `values`
+///
+/// Null indices produce null values. Unlike [`take`], the indices are always checked to
+/// be in bounds.
+///
+/// # Examples
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, ChunkedArray, Int32Array, UInt32Array};
+/// # use arrow_schema::DataType;
+/// # use arrow_select::take::take_chunked;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 2]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![3, 4]));
+/// let values = ChunkedArray::try_new(DataType::Int32, vec![a, b]).unwrap();
+///
+/// let indices = UInt32Array::from(vec![Some(4), None, Some(1)]);
+/// let taken = take_chunked(&values, &indices).unwrap();
+/// assert_eq!(taken.as_ref(), &Int32Array::from(vec![Some(4), None, Some(1)]));
+/// ```
+pub fn take_chunked(values: &ChunkedArray, indices: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    macro_rules! helper {
+        ($t:ty, $values:expr, $indices:expr) => {{
+            take_chunked_impl($values, $indices.as_primitive::<$t>())
+        }};
+    }
+    downcast_integer! {
+        indices.data_type() => (helper, values, indices),
+        d => Err(ArrowError::InvalidArgumentError(format!("Take only supported for integers, got {d:?}")))
+    }
+}
+
+fn take_chunked_impl<I: ArrowPrimitiveType>(
+    values: &ChunkedArray,
+    indices: &PrimitiveArray<I>,
+) -> Result<ArrayRef, ArrowError> {
+    if let [chunk] = values.chunks() {
+        let options = TakeOptions { check_bounds: true };
+        return take(chunk, indices, Some(options));
+    }
+
+    // Null indices take from an additional array of a single null
+    let nulls = new_null_array(values.data_type(), 1);
+    let null_index = (values.num_chunks(), 0);
+
+    let len = values.len();
+    let indices = indices
+        .iter()
+        .map(|index| match index {
+            Some(index) => {
+                let ix = index
+                    .to_usize()
+                    .ok_or_else(|| ArrowError::ComputeError("Cast to usize failed".to_string()))?;
+                if ix >= len {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Array index out of bounds, cannot get item at index {ix} from {len} entries"
+                    )));
+                }
+                Ok(values.chunk_index(ix))
+            }
+            None => Ok(null_index),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut arrays: Vec<&dyn Array> = values.chunks().iter().map(|c| c.as_ref()).collect();
+    arrays.push(nulls.as_ref());
+    crate::interleave::interleave(&arrays, &indices)
+}
+
 /// Verifies that the non-null values of `indices` are all `< len`
 fn check_bounds<T: ArrowPrimitiveType>(
     len: usize,
@@ -215,6 +316,12 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
         DataType::LargeList(_) => {
             Ok(Arc::new(take_list::<_, Int64Type>(values.as_list(), indices)?))
         }
+        DataType::ListView(_) => {
+            Ok(Arc::new(take_list_view::<_, i32>(values.as_list_view(), indices)?))
+        }
+        DataType::LargeListView(_) => {
+            Ok(Arc::new(take_list_view::<_, i64>(values.as_list_view(), indices)?))
+        }
         DataType::FixedSizeList(_, length) => {
             let values = values
                 .as_any()
@@ -591,6 +698,28 @@ where
     Ok(GenericListArray::<OffsetType::Native>::from(list_data))
 }
 
+/// `take` implementation for list view arrays
+///
+/// As the views of the output may reference any values of the input, only the
+/// offsets, sizes and nulls are taken, with the values shared with the input
+fn take_list_view<IndexType, OffsetType>(
+    values: &GenericListViewArray<OffsetType>,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<GenericListViewArray<OffsetType>, ArrowError>
+where
+    IndexType: ArrowPrimitiveType,
+    OffsetType: OffsetSizeTrait,
+{
+    let field = match values.data_type() {
+        DataType::ListView(f) | DataType::LargeListView(f) => f.clone(),
+        _ => unreachable!(),
+    };
+    let offsets = take_native(values.offsets(), indices);
+    let sizes = take_native(values.sizes(), indices);
+    let nulls = take_nulls(values.nulls(), indices);
+    GenericListViewArray::try_new(field, offsets, sizes, values.values().clone(), nulls)
+}
+
 /// `take` implementation for `FixedSizeListArray`
 ///
 /// Calculates the index and indexed offset for the inner array,
@@ -668,67 +797,127 @@ fn take_dict<T: ArrowDictionaryKeyType, I: ArrowPrimitiveType>(
 /// `take` implementation for run arrays
 ///
 /// Finds physical indices for the given logical indices and builds output run array
-/// by taking values in the input run_array.values at the physical indices.
-/// The output run array will be run encoded on the physical indices and not on output values.
+/// by taking values in the input run_array.values at the physical indices, merging
+/// adjacent runs with equal values. Null indices produce null values.
 /// For e.g. an input `RunArray{ run_ends = [2,4,6,8], values=[1,2,1,2] }` and `logical_indices=[2,3,6,7]`
 /// would be converted to `physical_indices=[1,1,3,3]` which will be used to build
-/// output `RunArray{ run_ends=[2,4], values=[2,2] }`.
+/// output `RunArray{ run_ends=[4], values=[2] }`.
 fn take_run<T: RunEndIndexType, I: ArrowPrimitiveType>(
     run_array: &RunArray<T>,
     logical_indices: &PrimitiveArray<I>,
 ) -> Result<RunArray<T>, ArrowError> {
-    // get physical indices for the input logical indices
-    let physical_indices = run_array.get_physical_indices(logical_indices.values())?;
-
-    // Run encode the physical indices into new_run_ends_builder
-    // Keep track of the physical indices to take in take_value_indices
-    // `unwrap` is used in this function because the unwrapped values are bounded by the corresponding `::Native`.
-    let mut new_run_ends_builder = BufferBuilder::<T::Native>::new(1);
-    let mut take_value_indices = BufferBuilder::<I::Native>::new(1);
-    let mut new_physical_len = 1;
-    for ix in 1..physical_indices.len() {
-        if physical_indices[ix] != physical_indices[ix - 1] {
-            take_value_indices.append(I::Native::from_usize(physical_indices[ix - 1]).unwrap());
-            new_run_ends_builder.append(T::Native::from_usize(ix).unwrap());
-            new_physical_len += 1;
+    // get physical indices for the valid input logical indices
+    let valid: Vec<_> = logical_indices.iter().flatten().collect();
+    let mut physical_indices = run_array.get_physical_indices(&valid)?.into_iter();
+
+    // Run encode the physical indices, keeping track of the physical index of each run
+    let mut run_ends: Vec<usize> = vec![];
+    let mut take_value_indices: Vec<Option<u64>> = vec![];
+    for (idx, logical) in logical_indices.iter().enumerate() {
+        let physical = logical.and_then(|_| physical_indices.next().map(|x| x as u64));
+        match take_value_indices.last() {
+            Some(last) if *last == physical => *run_ends.last_mut().unwrap() = idx + 1,
+            _ => {
+                run_ends.push(idx + 1);
+                take_value_indices.push(physical);
+            }
         }
     }
-    take_value_indices
-        .append(I::Native::from_usize(physical_indices[physical_indices.len() - 1]).unwrap());
-    new_run_ends_builder.append(T::Native::from_usize(physical_indices.len()).unwrap());
-    let new_run_ends = unsafe {
-        // Safety:
-        // The function builds a valid run_ends array and hence need not be validated.
-        ArrayDataBuilder::new(T::DATA_TYPE)
-            .len(new_physical_len)
-            .null_count(0)
-            .add_buffer(new_run_ends_builder.finish())
-            .build_unchecked()
-    };
 
-    let take_value_indices: PrimitiveArray<I> = unsafe {
-        // Safety:
-        // The function builds a valid take_value_indices array and hence need not be validated.
-        ArrayDataBuilder::new(I::DATA_TYPE)
-            .len(new_physical_len)
-            .null_count(0)
-            .add_buffer(take_value_indices.finish())
-            .build_unchecked()
-            .into()
+    let take_value_indices = UInt64Array::from(take_value_indices);
+    let new_values = take(run_array.values(), &take_value_indices, None)?;
+    merge_runs(run_ends, new_values)
+}
+
+/// Builds a [`RunArray`] from the logical end of each run and the corresponding
+/// `values`, merging adjacent runs with equal values
+pub(crate) fn merge_runs<R: RunEndIndexType>(
+    run_ends: Vec<usize>,
+    values: ArrayRef,
+) -> Result<RunArray<R>, ArrowError> {
+    let equal = equal_to_next(values.as_ref());
+    let (run_ends, values) = match equal.iter().any(|x| *x) {
+        true => {
+            // Keep only the last of each sequence of equal runs
+            let keep: Vec<_> = (0..values.len())
+                .map(|i| !equal.get(i).copied().unwrap_or_default())
+                .collect();
+            let run_ends = run_ends
+                .into_iter()
+                .zip(&keep)
+                .filter_map(|(end, keep)| keep.then_some(end))
+                .collect();
+            let values = crate::filter::filter(&values, &BooleanArray::from(keep))?;
+            (run_ends, values)
+        }
+        false => (run_ends, values),
     };
 
-    let new_values = take(run_array.values(), &take_value_indices, None)?;
+    let run_ends = run_ends
+        .into_iter()
+        .map(|end| {
+            R::Native::from_usize(end).ok_or_else(|| {
+                ArrowError::ComputeError(format!(
+                    "Run end {end} overflows run end type {}",
+                    R::DATA_TYPE
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    RunArray::try_new(&PrimitiveArray::<R>::new(run_ends.into(), None), &values)
+}
 
-    let builder = ArrayDataBuilder::new(run_array.data_type().clone())
-        .len(physical_indices.len())
-        .add_child_data(new_run_ends)
-        .add_child_data(new_values.into_data());
-    let array_data = unsafe {
-        // Safety:
-        //  This function builds a valid run array and hence can skip validation.
-        builder.build_unchecked()
+/// Returns whether each value of `values` is equal to the next value, with
+/// nulls considered equal to each other
+fn equal_to_next(values: &dyn Array) -> Vec<bool> {
+    let len = values.len().saturating_sub(1);
+    let nulls = values.logical_nulls();
+    let is_valid = |i: usize| nulls.as_ref().map(|n| n.is_valid(i)).unwrap_or(true);
+    let compare = |eq: &dyn Fn(usize) -> bool| {
+        (0..len)
+            .map(|i| match (is_valid(i), is_valid(i + 1)) {
+                (true, true) => eq(i),
+                (false, false) => true,
+                _ => false,
+            })
+            .collect()
     };
-    Ok(array_data.into())
+
+    downcast_primitive_array!(
+        values => compare(&|i| values.value(i).is_eq(values.value(i + 1))),
+        DataType::Boolean => {
+            let values = values.as_boolean();
+            compare(&|i| values.value(i) == values.value(i + 1))
+        }
+        DataType::Utf8 => {
+            let values = values.as_string::<i32>();
+            compare(&|i| values.value(i) == values.value(i + 1))
+        }
+        DataType::LargeUtf8 => {
+            let values = values.as_string::<i64>();
+            compare(&|i| values.value(i) == values.value(i + 1))
+        }
+        DataType::Utf8View => {
+            let values = values.as_string_view();
+            compare(&|i| values.value(i) == values.value(i + 1))
+        }
+        DataType::Binary => {
+            let values = values.as_binary::<i32>();
+            compare(&|i| values.value(i) == values.value(i + 1))
+        }
+        DataType::LargeBinary => {
+            let values = values.as_binary::<i64>();
+            compare(&|i| values.value(i) == values.value(i + 1))
+        }
+        DataType::BinaryView => {
+            let values = values.as_binary_view();
+            compare(&|i| values.value(i) == values.value(i + 1))
+        }
+        _ => {
+            let data = values.to_data();
+            (0..len).map(|i| data.slice(i, 1) == data.slice(i + 1, 1)).collect()
+        }
+    )
 }
 
 /// Takes/filters a list array's inner data using the offsets of the list array.
@@ -1597,6 +1786,40 @@ mod tests {
         _test_byte_view::<BinaryViewType>()
     }
 
+    #[test]
+    fn test_take_view_gc_threshold() {
+        let long = "a string longer than 12 bytes";
+        let values = StringViewArray::from(vec![Some(long), None, Some("short"), Some(long)]);
+        let indices = UInt32Array::from(vec![2, 3, 1]);
+        let expected = StringViewArray::from(vec![Some("short"), Some(long), None]);
+
+        let taken = take(&values, &indices, None).unwrap();
+        assert_eq!(taken.as_string_view(), &expected);
+        assert_eq!(
+            taken.as_string_view().total_data_buffer_bytes(),
+            2 * long.len()
+        );
+
+        let taken = take_compacted(&values, &indices, None, 0.5).unwrap();
+        assert_eq!(
+            taken.as_string_view().total_data_buffer_bytes(),
+            2 * long.len()
+        );
+
+        let options = TakeOptions { check_bounds: true };
+        let taken = take_compacted(&values, &indices, Some(options), 0.4).unwrap();
+        assert_eq!(taken.as_string_view(), &expected);
+        assert_eq!(taken.as_string_view().total_data_buffer_bytes(), long.len());
+
+        for threshold in [-0.1, 1.5, f64::NAN] {
+            let err = take_compacted(&values, &indices, None, threshold).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid argument error: View GC threshold must be between 0 and 1, got {threshold}")
+            );
+        }
+    }
+
     macro_rules! test_take_list {
         ($offset_type:ty, $list_data_type:ident, $list_array_type:ident) => {{
             // Construct a value array, [[0,0,0], [-1,-2,-1], [], [2,3]]
@@ -1810,6 +2033,81 @@ mod tests {
         assert_eq!(&output, &expected)
     }
 
+    fn test_take_list_view_generic<O: OffsetSizeTrait>() {
+        // Views are out of order and overlapping: [[4, 5], [1, 2], [2, 3, 4], null, [5]]
+        let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
+        let offsets = ScalarBuffer::from_iter([3, 0, 1, 2, 4].map(O::usize_as));
+        let sizes = ScalarBuffer::from_iter([2, 2, 3, 1, 1].map(O::usize_as));
+        let nulls = NullBuffer::from(vec![true, true, true, false, true]);
+        let list = GenericListViewArray::<O>::new(field, offsets, sizes, values, Some(nulls));
+
+        let indices = UInt32Array::from(vec![Some(4), None, Some(0), Some(3), Some(2), Some(0)]);
+        let taken = take(&list, &indices, None).unwrap();
+        let taken = taken.as_list_view::<O>();
+
+        // Values are shared with the input
+        assert!(Arc::ptr_eq(taken.values(), list.values()));
+        let actual: Vec<_> = taken
+            .iter()
+            .map(|x| x.map(|x| x.as_primitive::<Int32Type>().values().to_vec()))
+            .collect();
+        let expected = vec![
+            Some(vec![5]),
+            None,
+            Some(vec![4, 5]),
+            None,
+            Some(vec![2, 3, 4]),
+            Some(vec![4, 5]),
+        ];
+        assert_eq!(actual, expected);
+
+        let options = Some(TakeOptions { check_bounds: true });
+        let err = take(&list, &UInt32Array::from(vec![5]), options).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{err}");
+    }
+
+    #[test]
+    fn test_take_list_view() {
+        test_take_list_view_generic::<i32>();
+        test_take_list_view_generic::<i64>();
+    }
+
+    #[test]
+    fn test_take_chunked() {
+        let chunks: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+            Arc::new(StringArray::from(Vec::<&str>::new())),
+            Arc::new(StringArray::from(vec!["b", "c", "d"])),
+        ];
+        let values = ChunkedArray::try_new(DataType::Utf8, chunks).unwrap();
+
+        let indices = Int64Array::from(vec![Some(4), None, Some(0), Some(1), Some(2), Some(4)]);
+        let taken = take_chunked(&values, &indices).unwrap();
+        let expected =
+            StringArray::from(vec![Some("d"), None, Some("a"), None, Some("b"), Some("d")]);
+        assert_eq!(taken.as_string::<i32>(), &expected);
+
+        let taken = take_chunked(&values.slice(2, 2), &UInt8Array::from(vec![1, 0])).unwrap();
+        assert_eq!(taken.as_string::<i32>(), &StringArray::from(vec!["c", "b"]));
+
+        let err = take_chunked(&values, &UInt32Array::from(vec![5])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 5 from 5 entries"
+        );
+        let err = take_chunked(&values.slice(2, 1), &UInt32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 1 from 1 entries"
+        );
+
+        let empty = ChunkedArray::new_empty(DataType::Utf8);
+        let taken = take_chunked(&empty, &UInt32Array::from(vec![None, None])).unwrap();
+        assert_eq!(taken.data_type(), &DataType::Utf8);
+        assert_eq!(taken.null_count(), 2);
+    }
+
     #[test]
     fn test_take_list() {
         test_take_list!(i32, List, ListArray);
@@ -2171,10 +2469,41 @@ mod tests {
 
         assert_eq!(take_out.len(), 7);
         assert_eq!(take_out.run_ends().len(), 7);
-        assert_eq!(take_out.run_ends().values(), &[1_i32, 3, 4, 5, 7]);
+        assert_eq!(take_out.run_ends().values(), &[5_i32, 7]);
 
         let take_out_values = take_out.values().as_primitive::<Int32Type>();
-        assert_eq!(take_out_values.values(), &[2, 2, 2, 2, 1]);
+        assert_eq!(take_out_values.values(), &[2, 1]);
+    }
+
+    #[test]
+    fn test_take_runs_nulls() {
+        let mut builder = StringRunBuilder::<Int16Type>::new();
+        builder.extend([Some("a"), Some("a"), None, Some("b"), Some("a"), None]);
+        let run_array = builder.finish();
+        let sliced = run_array.slice(1, 5);
+
+        let take_indices =
+            UInt32Array::from(vec![Some(0), None, Some(1), Some(3), Some(0), Some(3)]);
+        let take_out = take(&sliced, &take_indices, None).unwrap();
+        let take_out = arrow_array::cast::as_run_array::<Int16Type>(&take_out);
+
+        assert_eq!(take_out.len(), 6);
+        assert_eq!(take_out.run_ends().values(), &[1_i16, 3, 6]);
+        let values = take_out.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec![Some("a"), None, Some("a")]));
+
+        let take_out = take(&sliced, &UInt32Array::new_null(3), None).unwrap();
+        let take_out = arrow_array::cast::as_run_array::<Int16Type>(&take_out);
+        assert_eq!(take_out.run_ends().values(), &[3_i16]);
+        assert_eq!(take_out.values().null_count(), 1);
+
+        let take_out = take(&sliced, &UInt32Array::from(Vec::<u32>::new()), None).unwrap();
+        assert_eq!(take_out.len(), 0);
+
+        let err = take(&sliced, &UInt32Array::from(vec![5]), None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The logical index cannot be converted is 5"));
     }
 
     #[test]
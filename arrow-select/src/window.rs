@@ -18,7 +18,8 @@
 //! Defines windowing functions, like `shift`ing
 
 use crate::concat::concat;
-use arrow_array::{make_array, new_null_array, Array, ArrayRef};
+use crate::take::take;
+use arrow_array::{make_array, new_null_array, Array, ArrayRef, Datum, UInt32Array};
 use arrow_schema::ArrowError;
 use num::abs;
 
@@ -53,28 +54,84 @@ use num::abs;
 /// assert_eq!(res.as_ref(), &expected);
 /// ```
 pub fn shift(array: &dyn Array, offset: i64) -> Result<ArrayRef, ArrowError> {
+    shift_with_fill(array, offset, None)
+}
+
+/// Shifts array by defined number of items (to left or right), filling the
+/// vacated positions with `fill`, or null if `fill` is `None`
+///
+/// `fill` must be a scalar, such as a [`Scalar`](arrow_array::Scalar), of the same
+/// type as `array`. This supports arrays of any type, including nested types, and
+/// can be used to implement the SQL `LEAD` and `LAG` window functions with a default
+/// value.
+///
+/// See [`shift`] for more details
+///
+/// # Examples
+/// ```
+/// # use arrow_array::{Int32Array, Scalar};
+/// # use arrow_select::window::shift_with_fill;
+/// let a = Int32Array::from(vec![Some(1), None, Some(4)]);
+/// let fill = Scalar::new(Int32Array::from(vec![0]));
+///
+/// // LAG(a, 1, 0)
+/// let res = shift_with_fill(&a, 1, Some(&fill)).unwrap();
+/// let expected = Int32Array::from(vec![Some(0), Some(1), None]);
+/// assert_eq!(res.as_ref(), &expected);
+///
+/// // LEAD(a, 2, 0)
+/// let res = shift_with_fill(&a, -2, Some(&fill)).unwrap();
+/// let expected = Int32Array::from(vec![Some(4), Some(0), Some(0)]);
+/// assert_eq!(res.as_ref(), &expected);
+/// ```
+pub fn shift_with_fill(
+    array: &dyn Array,
+    offset: i64,
+    fill: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    let fill = match fill {
+        Some(fill) => {
+            let (fill, is_scalar) = fill.get();
+            if !is_scalar {
+                return Err(ArrowError::InvalidArgumentError(
+                    "shift fill value must be a scalar".to_string(),
+                ));
+            }
+            if fill.data_type() != array.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "shift fill value must have the same type as the array, got {} vs {}",
+                    fill.data_type(),
+                    array.data_type()
+                )));
+            }
+            Some(fill)
+        }
+        None => None,
+    };
+    // Generate array with `len` fill items
+    let fill_array = |len: usize| match fill {
+        Some(fill) => take(fill, &UInt32Array::from(vec![0; len]), None),
+        None => Ok(new_null_array(array.data_type(), len)),
+    };
+
     let value_len = array.len() as i64;
     if offset == 0 {
         Ok(make_array(array.to_data()))
     } else if offset == i64::MIN || abs(offset) >= value_len {
-        Ok(new_null_array(array.data_type(), array.len()))
+        fill_array(array.len())
     } else {
-        // Concatenate both arrays, add nulls after if shift > 0 else before
+        // Concatenate both arrays, add fill items after if shift > 0 else before
         if offset > 0 {
             let length = array.len() - offset as usize;
             let slice = array.slice(0, length);
-
-            // Generate array with remaining `null` items
-            let null_arr = new_null_array(array.data_type(), offset as usize);
-            concat(&[null_arr.as_ref(), slice.as_ref()])
+            let fill_arr = fill_array(offset as usize)?;
+            concat(&[fill_arr.as_ref(), slice.as_ref()])
         } else {
             let offset = -offset as usize;
             let length = array.len() - offset;
             let slice = array.slice(offset, length);
-
-            // Generate array with remaining `null` items
-            let null_arr = new_null_array(array.data_type(), offset);
-            concat(&[slice.as_ref(), null_arr.as_ref()])
+            let fill_arr = fill_array(offset)?;
+            concat(&[slice.as_ref(), fill_arr.as_ref()])
         }
     }
 }
@@ -82,7 +139,11 @@ pub fn shift(array: &dyn Array, offset: i64) -> Result<ArrayRef, ArrowError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_array::{Float64Array, Int32Array, Int32DictionaryArray};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{
+        Float64Array, Int32Array, Int32DictionaryArray, ListArray, Scalar, StringArray,
+    };
 
     #[test]
     fn test_shift_neg() {
@@ -191,4 +252,77 @@ mod tests {
         let expected: Int32Array = vec![None, None, None].into();
         assert_eq!(res.as_ref(), &expected);
     }
+
+    #[test]
+    fn test_shift_with_fill() {
+        let a: Int32Array = vec![Some(1), None, Some(4)].into();
+        let fill = Scalar::new(Int32Array::from(vec![7]));
+        let res = shift_with_fill(&a, 1, Some(&fill)).unwrap();
+        let expected: Int32Array = vec![Some(7), Some(1), None].into();
+        assert_eq!(res.as_ref(), &expected);
+
+        let res = shift_with_fill(&a, -1, Some(&fill)).unwrap();
+        let expected: Int32Array = vec![None, Some(4), Some(7)].into();
+        assert_eq!(res.as_ref(), &expected);
+
+        let res = shift_with_fill(&a, i64::MIN, Some(&fill)).unwrap();
+        let expected: Int32Array = vec![Some(7); 3].into();
+        assert_eq!(res.as_ref(), &expected);
+
+        let res = shift_with_fill(&a, 0, Some(&fill)).unwrap();
+        assert_eq!(res.as_ref(), &a);
+
+        // A null scalar behaves the same as no fill value
+        let fill = Scalar::new(Int32Array::new_null(1));
+        let res = shift_with_fill(&a, 2, Some(&fill)).unwrap();
+        let expected: Int32Array = vec![None, None, Some(1)].into();
+        assert_eq!(res.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_shift_with_fill_nested() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+        ]);
+        let fill = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![None])]);
+        let fill = Scalar::new(fill);
+        let res = shift_with_fill(&a, -2, Some(&fill)).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![]),
+            Some(vec![None]),
+            Some(vec![None]),
+        ]);
+        assert_eq!(res.as_ref(), &expected);
+
+        let a: Int32DictionaryArray = [Some("alpha"), None, Some("beta")].into_iter().collect();
+        let fill: Int32DictionaryArray = [Some("gamma")].into_iter().collect();
+        let res = shift_with_fill(&a, 1, Some(&Scalar::new(fill))).unwrap();
+        let res = res.as_dictionary::<Int32Type>();
+        let values: Vec<_> = res
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("gamma"), Some("alpha"), None]);
+    }
+
+    #[test]
+    fn test_shift_with_fill_invalid() {
+        let a: Int32Array = vec![Some(1), None, Some(4)].into();
+        let fill = Int32Array::from(vec![0]);
+        let err = shift_with_fill(&a, 1, Some(&fill)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: shift fill value must be a scalar"
+        );
+
+        let fill = Scalar::new(Float64Array::from(vec![0.]));
+        let err = shift_with_fill(&a, 1, Some(&fill)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: shift fill value must have the same type as the array, got Float64 vs Int32"
+        );
+    }
 }
@@ -16,9 +16,12 @@
 // under the License.
 
 //! Zip two arrays by some boolean mask. Where the mask evaluates `true` values of `truthy`
+//!
+//! [`case_when`] generalises this to multiple conditions, evaluated in order of priority
 
 use crate::filter::SlicesIterator;
 use arrow_array::*;
+use arrow_buffer::BooleanBufferBuilder;
 use arrow_data::transform::MutableArrayData;
 use arrow_schema::ArrowError;
 
@@ -114,9 +117,131 @@ pub fn zip(
     Ok(make_array(data))
 }
 
+/// Evaluates a multi-branch conditional, equivalent to SQL's
+/// `CASE WHEN c1 THEN v1 WHEN c2 THEN v2 ... ELSE e END`
+///
+/// For each row, the value is taken from the first branch whose condition is `true`,
+/// a null condition is treated as `false`. Rows where no condition is `true` take
+/// the value of `else_value`, or are null if `else_value` is `None`
+///
+/// # Arguments
+/// * `branches` - The `(condition, value)` pairs, evaluated in order of priority
+/// * `else_value` - The value of rows not matched by any branch, may be a [`Scalar`]
+///
+/// Returns an error if `branches` is empty, the conditions and values do not all
+/// have the same length, or the values and `else_value` do not all have the same type
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Int32Array, Scalar};
+/// # use arrow_select::zip::case_when;
+/// let values = Int32Array::from(vec![1, 5, 10, -1]);
+/// let branches = [
+///     (BooleanArray::from(vec![false, false, true, false]), Arc::new(Int32Array::from(vec![100; 4])) as ArrayRef),
+///     (BooleanArray::from(vec![true, true, true, false]), Arc::new(values.clone()) as ArrayRef),
+/// ];
+/// let else_value = Scalar::new(Int32Array::from(vec![0]));
+/// let result = case_when(&branches, Some(&else_value)).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 5, 100, 0]));
+/// ```
+pub fn case_when(
+    branches: &[(BooleanArray, ArrayRef)],
+    else_value: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    let Some((_, first)) = branches.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "case_when requires at least one branch".into(),
+        ));
+    };
+    let len = first.len();
+    let data_type = first.data_type();
+
+    for (condition, value) in branches {
+        if condition.len() != len || value.len() != len {
+            return Err(ArrowError::InvalidArgumentError(
+                "all arrays should have the same length".into(),
+            ));
+        }
+        if value.data_type() != data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "case_when values must have the same data type, got {data_type} and {}",
+                value.data_type()
+            )));
+        }
+    }
+
+    let else_value = else_value.map(|e| e.get());
+    if let Some((else_value, is_scalar)) = &else_value {
+        if else_value.data_type() != data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "case_when else value must have the same data type as the branches, got {data_type} and {}",
+                else_value.data_type()
+            )));
+        }
+        let expected = if *is_scalar { 1 } else { len };
+        if else_value.len() != expected {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "case_when else value must have length {expected}, got {}",
+                else_value.len()
+            )));
+        }
+    }
+
+    // Find the index of the branch selected by each row, with
+    // `branches.len()` denoting the else value
+    let else_idx = branches.len();
+    let mut selected = vec![else_idx; len];
+    let mut unmatched = BooleanBufferBuilder::new(len);
+    unmatched.append_n(len, true);
+    let mut unmatched = unmatched.finish();
+    for (idx, (condition, _)) in branches.iter().enumerate() {
+        if unmatched.count_set_bits() == 0 {
+            break;
+        }
+        let mut matched = condition.values() & &unmatched;
+        if let Some(nulls) = condition.nulls() {
+            matched = &matched & nulls.inner();
+        }
+        matched.set_indices().for_each(|row| selected[row] = idx);
+        unmatched = &unmatched & &!&matched;
+    }
+
+    let mut data: Vec<_> = branches.iter().map(|(_, v)| v.to_data()).collect();
+    let else_scalar = match &else_value {
+        Some((e, is_scalar)) => {
+            data.push(e.to_data());
+            *is_scalar
+        }
+        None => false,
+    };
+    let use_nulls = else_value.is_none();
+    let mut mutable = MutableArrayData::new(data.iter().collect(), use_nulls, len);
+
+    // Copy contiguous runs of rows selected from the same source
+    let mut start = 0;
+    while start < len {
+        let source = selected[start];
+        let end = selected[start..]
+            .iter()
+            .position(|s| *s != source)
+            .map_or(len, |x| start + x);
+        match (source == else_idx, else_value.is_some()) {
+            (true, false) => mutable.extend_nulls(end - start),
+            (true, true) if else_scalar => (start..end).for_each(|_| mutable.extend(source, 0, 1)),
+            _ => mutable.extend(source, start, end),
+        }
+        start = end;
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use std::sync::Arc;
 
     #[test]
     fn test_zip_kernel_one() {
@@ -227,4 +352,94 @@ mod test {
         let expected = Int32Array::from(vec![None, None, Some(42), Some(42), None]);
         assert_eq!(actual, &expected);
     }
+    #[test]
+    fn test_case_when() {
+        let c1 = BooleanArray::from(vec![Some(true), Some(false), None, Some(false), Some(true)]);
+        let c2 = BooleanArray::from(vec![Some(true), Some(true), Some(true), None, Some(false)]);
+        let v1: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a1"),
+            Some("b1"),
+            Some("c1"),
+            Some("d1"),
+            None,
+        ]));
+        let v2: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a2"),
+            Some("b2"),
+            None,
+            Some("d2"),
+            Some("e2"),
+        ]));
+        let branches = [(c1, v1), (c2, v2)];
+
+        let out = case_when(&branches, None).unwrap();
+        let expected = StringArray::from(vec![Some("a1"), Some("b2"), None, None, None]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let else_scalar = Scalar::new(StringArray::from(vec!["else"]));
+        let out = case_when(&branches, Some(&else_scalar)).unwrap();
+        let expected = StringArray::from(vec![Some("a1"), Some("b2"), None, Some("else"), None]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let else_array = StringArray::from(vec!["e0", "e1", "e2", "e3", "e4"]);
+        let out = case_when(&branches, Some(&else_array)).unwrap();
+        let expected = StringArray::from(vec![Some("a1"), Some("b2"), None, Some("e3"), None]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_case_when_sliced() {
+        let condition = BooleanArray::from(vec![true, false, true, false, false]).slice(1, 4);
+        let values = Int32Array::from(vec![1, 2, 3, 4, 5]).slice(1, 4);
+        let else_value = Int32Array::from(vec![10, 20, 30, 40, 50]).slice(1, 4);
+        let branches = [(condition, Arc::new(values) as ArrayRef)];
+        let out = case_when(&branches, Some(&else_value)).unwrap();
+        assert_eq!(
+            out.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![20, 3, 40, 50])
+        );
+    }
+
+    #[test]
+    fn test_case_when_invalid() {
+        let err = case_when(&[], None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when requires at least one branch"
+        );
+
+        let condition = BooleanArray::from(vec![true, false]);
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let longs: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+
+        let branches = [
+            (condition.clone(), ints.clone()),
+            (condition.clone(), longs.clone()),
+        ];
+        let err = case_when(&branches, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when values must have the same data type, got Int32 and Int64"
+        );
+
+        let branches = [(condition.clone(), ints.clone())];
+        let err = case_when(&branches, Some(&longs)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when else value must have the same data type as the branches, got Int32 and Int64"
+        );
+
+        let err = case_when(&branches, Some(&ints.slice(0, 1))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: case_when else value must have length 2, got 1"
+        );
+
+        let branches = [(condition.slice(0, 1), ints)];
+        let err = case_when(&branches, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays should have the same length"
+        );
+    }
 }
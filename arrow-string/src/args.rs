@@ -0,0 +1,186 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for kernels taking several [`Datum`] arguments, each of which may be an array
+//! or a scalar broadcast to every row
+
+use arrow_array::builder::{GenericStringBuilder, StringViewBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy)]
+enum Strings<'a> {
+    Utf8(&'a StringArray),
+    LargeUtf8(&'a LargeStringArray),
+    Utf8View(&'a StringViewArray),
+}
+
+/// A string argument of a kernel
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StringArg<'a> {
+    strings: Strings<'a>,
+    data_type: &'a DataType,
+    is_scalar: bool,
+    len: usize,
+}
+
+impl<'a> StringArg<'a> {
+    /// Creates a new [`StringArg`] for the argument `arg` of kernel `name`
+    pub(crate) fn try_new(name: &str, arg: &'a dyn Datum) -> Result<Self, ArrowError> {
+        let (array, is_scalar) = arg.get();
+        let strings = match array.data_type() {
+            DataType::Utf8 => Strings::Utf8(array.as_string()),
+            DataType::LargeUtf8 => Strings::LargeUtf8(array.as_string()),
+            DataType::Utf8View => Strings::Utf8View(array.as_string_view()),
+            d => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{name} not supported for {d}"
+                )))
+            }
+        };
+        Ok(Self {
+            strings,
+            data_type: array.data_type(),
+            is_scalar,
+            len: array.len(),
+        })
+    }
+
+    /// Returns the [`DataType`] of this argument
+    pub(crate) fn data_type(&self) -> &'a DataType {
+        self.data_type
+    }
+
+    /// Returns the value of this argument for row `idx`, or `None` if it is null
+    pub(crate) fn value(&self, idx: usize) -> Option<&'a str> {
+        let idx = if self.is_scalar { 0 } else { idx };
+        match self.strings {
+            Strings::Utf8(a) => a.is_valid(idx).then(|| a.value(idx)),
+            Strings::LargeUtf8(a) => a.is_valid(idx).then(|| a.value(idx)),
+            Strings::Utf8View(a) => a.is_valid(idx).then(|| a.value(idx)),
+        }
+    }
+
+    /// Returns the number of rows of this argument, or `None` if it is a scalar
+    pub(crate) fn len(&self) -> Option<usize> {
+        (!self.is_scalar).then_some(self.len)
+    }
+}
+
+/// An [`Int64Array`] argument of a kernel
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Int64Arg<'a> {
+    array: &'a Int64Array,
+    is_scalar: bool,
+}
+
+impl<'a> Int64Arg<'a> {
+    /// Creates a new [`Int64Arg`] for the argument `arg` of kernel `name`
+    pub(crate) fn try_new(name: &str, arg: &'a dyn Datum) -> Result<Self, ArrowError> {
+        let (array, is_scalar) = arg.get();
+        match array.data_type() {
+            DataType::Int64 => Ok(Self {
+                array: array.as_primitive::<Int64Type>(),
+                is_scalar,
+            }),
+            d => Err(ArrowError::InvalidArgumentError(format!(
+                "{name} not supported for {d}"
+            ))),
+        }
+    }
+
+    /// Returns the value of this argument for row `idx`, or `None` if it is null
+    pub(crate) fn value(&self, idx: usize) -> Option<i64> {
+        let idx = if self.is_scalar { 0 } else { idx };
+        self.array.is_valid(idx).then(|| self.array.value(idx))
+    }
+
+    /// Returns the number of rows of this argument, or `None` if it is a scalar
+    pub(crate) fn len(&self) -> Option<usize> {
+        (!self.is_scalar).then_some(self.array.len())
+    }
+}
+
+/// Returns the number of rows output by kernel `name` given the number of rows of each
+/// of its arguments, as returned by [`StringArg::len`] and [`Int64Arg::len`]
+pub(crate) fn output_len(
+    name: &str,
+    lengths: impl IntoIterator<Item = Option<usize>>,
+) -> Result<usize, ArrowError> {
+    let mut len = None;
+    for l in lengths.into_iter().flatten() {
+        match len {
+            Some(len) if len != l => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot perform {name} on arrays of different length, got {len} vs {l}"
+                )))
+            }
+            _ => len = Some(l),
+        }
+    }
+    Ok(len.unwrap_or(1))
+}
+
+/// Builds a string array of `data_type` with `len` rows, where `f` writes the value of
+/// each row to the provided buffer, returning `false` if the row is null
+pub(crate) fn build_strings<F>(
+    data_type: &DataType,
+    len: usize,
+    mut f: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: FnMut(usize, &mut String) -> Result<bool, ArrowError>,
+{
+    Ok(match data_type {
+        DataType::Utf8 => {
+            let mut builder = GenericStringBuilder::<i32>::with_capacity(len, 0);
+            for_each_string(len, &mut f, |v| builder.append_option(v))?;
+            Arc::new(builder.finish())
+        }
+        DataType::LargeUtf8 => {
+            let mut builder = GenericStringBuilder::<i64>::with_capacity(len, 0);
+            for_each_string(len, &mut f, |v| builder.append_option(v))?;
+            Arc::new(builder.finish())
+        }
+        DataType::Utf8View => {
+            let mut builder = StringViewBuilder::with_capacity(len);
+            for_each_string(len, &mut f, |v| builder.append_option(v))?;
+            Arc::new(builder.finish())
+        }
+        d => unreachable!("unexpected string type {d}"),
+    })
+}
+
+fn for_each_string<F>(
+    len: usize,
+    mut f: F,
+    mut append: impl FnMut(Option<&str>),
+) -> Result<(), ArrowError>
+where
+    F: FnMut(usize, &mut String) -> Result<bool, ArrowError>,
+{
+    let mut buf = String::new();
+    for idx in 0..len {
+        buf.clear();
+        let valid = f(idx, &mut buf)?;
+        append(valid.then_some(buf.as_str()));
+    }
+    Ok(())
+}
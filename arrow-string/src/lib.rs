@@ -18,9 +18,15 @@
 #![warn(missing_docs)]
 //! Arrow string kernels
 
+mod args;
 pub mod concat_elements;
 pub mod length;
 pub mod like;
+#[cfg(feature = "unicode")]
+pub mod normalize;
+pub mod pad;
 mod predicate;
 pub mod regexp;
+pub mod similarity;
+pub mod split;
 pub mod substring;
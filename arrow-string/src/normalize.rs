@@ -0,0 +1,302 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines Unicode normalization and case folding kernels for string arrays
+//!
+//! Requires the `unicode` feature
+
+use arrow_array::builder::{GenericStringBuilder, StringViewBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, DataType};
+use caseless::Caseless;
+use std::sync::Arc;
+use unicode_normalization::UnicodeNormalization;
+
+/// A Unicode normalization form, as defined by
+/// [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition
+    NFC,
+    /// Canonical decomposition
+    NFD,
+    /// Compatibility decomposition, followed by canonical composition
+    NFKC,
+    /// Compatibility decomposition
+    NFKD,
+}
+
+/// Returns an array of the same type as `array` with each value converted to the
+/// Unicode normalization `form`
+///
+/// Normalizing strings to a common form ensures that canonically equivalent strings, such
+/// as a precomposed `"é"` and `"e"` followed by a combining acute accent, compare equal.
+/// The compatibility forms additionally fold formatting distinctions, such as ligatures
+/// and full-width characters.
+///
+/// Supports [`StringArray`], [`LargeStringArray`] and [`StringViewArray`], or a
+/// [`DictionaryArray`] with these as values
+///
+/// # Example
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::normalize::{normalize, NormalizationForm};
+/// let array = StringArray::from(vec![Some("e\u{301}"), Some("\u{fb01}"), None]);
+/// let result = normalize(&array, NormalizationForm::NFKC).unwrap();
+/// assert_eq!(
+///     result.as_string::<i32>(),
+///     &StringArray::from(vec![Some("\u{e9}"), Some("fi"), None])
+/// );
+/// ```
+pub fn normalize(array: &dyn Array, form: NormalizationForm) -> Result<ArrayRef, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let values = normalize(d.values().as_ref(), form)?;
+        return Ok(d.with_values(values));
+    }
+
+    let f = |s: &str, out: &mut String| match form {
+        NormalizationForm::NFC => out.extend(s.nfc()),
+        NormalizationForm::NFD => out.extend(s.nfd()),
+        NormalizationForm::NFKC => out.extend(s.nfkc()),
+        NormalizationForm::NFKD => out.extend(s.nfkd()),
+    };
+
+    match array.data_type() {
+        // ASCII strings are unchanged by normalization
+        DataType::Utf8 if array.as_string::<i32>().is_ascii() => Ok(array.slice(0, array.len())),
+        DataType::LargeUtf8 if array.as_string::<i64>().is_ascii() => {
+            Ok(array.slice(0, array.len()))
+        }
+        DataType::Utf8 => Ok(Arc::new(map_string(array.as_string::<i32>(), f))),
+        DataType::LargeUtf8 => Ok(Arc::new(map_string(array.as_string::<i64>(), f))),
+        DataType::Utf8View => Ok(Arc::new(map_string_view(array.as_string_view(), f))),
+        other => Err(ArrowError::ComputeError(format!(
+            "normalize not supported for {other:?}"
+        ))),
+    }
+}
+
+/// Returns an array of the same type as `array` with each value converted using Unicode
+/// default case folding
+///
+/// Case folding maps strings that differ only in case to the same string, and so can be
+/// used for case-insensitive comparisons. Unlike lowercasing, it handles characters
+/// whose case mapping is not one to one, for example folding both `"ß"` and `"SS"` to
+/// `"ss"`, and the Greek final sigma `"ς"` to `"σ"`.
+///
+/// Case folding does not normalize strings, and so for accent-insensitive comparisons
+/// should be combined with [`normalize`].
+///
+/// Supports [`StringArray`], [`LargeStringArray`] and [`StringViewArray`], or a
+/// [`DictionaryArray`] with these as values
+///
+/// # Example
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::normalize::casefold;
+/// let array = StringArray::from(vec![Some("Straße"), Some("STRASSE"), None]);
+/// let result = casefold(&array).unwrap();
+/// assert_eq!(
+///     result.as_string::<i32>(),
+///     &StringArray::from(vec![Some("strasse"), Some("strasse"), None])
+/// );
+/// ```
+pub fn casefold(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let values = casefold(d.values().as_ref())?;
+        return Ok(d.with_values(values));
+    }
+
+    let f = |s: &str, out: &mut String| out.extend(s.chars().default_case_fold());
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(casefold_string(array.as_string::<i32>(), f))),
+        DataType::LargeUtf8 => Ok(Arc::new(casefold_string(array.as_string::<i64>(), f))),
+        DataType::Utf8View => Ok(Arc::new(map_string_view(array.as_string_view(), f))),
+        other => Err(ArrowError::ComputeError(format!(
+            "casefold not supported for {other:?}"
+        ))),
+    }
+}
+
+/// Case folds `array`, lowercasing the values buffer in place of per-value folding if
+/// the array only contains ASCII
+fn casefold_string<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    f: impl Fn(&str, &mut String),
+) -> GenericStringArray<O> {
+    if !array.is_ascii() {
+        return map_string(array, f);
+    }
+    let values = Buffer::from_vec(array.value_data().to_ascii_lowercase());
+    // SAFETY: lowercasing ASCII preserves the offsets and UTF-8 validity of the values
+    unsafe {
+        GenericStringArray::new_unchecked(array.offsets().clone(), values, array.nulls().cloned())
+    }
+}
+
+fn map_string<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    f: impl Fn(&str, &mut String),
+) -> GenericStringArray<O> {
+    let mut builder =
+        GenericStringBuilder::<O>::with_capacity(array.len(), array.value_data().len());
+    let mut buf = String::new();
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                buf.clear();
+                f(value, &mut buf);
+                builder.append_value(&buf);
+            }
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+fn map_string_view(array: &StringViewArray, f: impl Fn(&str, &mut String)) -> StringViewArray {
+    let mut builder = StringViewBuilder::with_capacity(array.len());
+    let mut buf = String::new();
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                buf.clear();
+                f(value, &mut buf);
+                builder.append_value(&buf);
+            }
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_normalize() {
+        // "é" precomposed, decomposed, and the "ﬁ" ligature
+        let array = StringArray::from(vec![
+            Some("\u{e9}"),
+            Some("e\u{301}"),
+            Some("\u{fb01}"),
+            None,
+            Some("plain"),
+        ]);
+
+        let cases = [
+            (NormalizationForm::NFC, ["\u{e9}", "\u{e9}", "\u{fb01}"]),
+            (NormalizationForm::NFD, ["e\u{301}", "e\u{301}", "\u{fb01}"]),
+            (NormalizationForm::NFKC, ["\u{e9}", "\u{e9}", "fi"]),
+            (NormalizationForm::NFKD, ["e\u{301}", "e\u{301}", "fi"]),
+        ];
+        for (form, [a, b, c]) in cases {
+            let expected = StringArray::from(vec![Some(a), Some(b), Some(c), None, Some("plain")]);
+            let result = normalize(&array, form).unwrap();
+            assert_eq!(result.as_string::<i32>(), &expected, "{form:?}");
+
+            let large = LargeStringArray::from_iter(array.iter());
+            let result = normalize(&large, form).unwrap();
+            let expected = LargeStringArray::from_iter(expected.iter());
+            assert_eq!(result.as_string::<i64>(), &expected, "{form:?}");
+
+            let view = StringViewArray::from_iter(array.iter());
+            let result = normalize(&view, form).unwrap();
+            let expected = StringViewArray::from_iter(expected.iter());
+            assert_eq!(result.as_string_view(), &expected, "{form:?}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_ascii() {
+        let array = StringArray::from(vec![Some("a"), None, Some("bc"), Some("def")]).slice(1, 3);
+        let result = normalize(&array, NormalizationForm::NFKD).unwrap();
+        assert_eq!(result.as_string::<i32>(), &array);
+    }
+
+    #[test]
+    fn test_casefold() {
+        let array = StringArray::from(vec![
+            Some("Straße"),
+            Some("STRASSE"),
+            Some("ΣΊΣΥΦΟΣ"),
+            Some("σίσυφος"),
+            None,
+            Some("ǅ"),
+        ]);
+        let expected = StringArray::from(vec![
+            Some("strasse"),
+            Some("strasse"),
+            Some("σίσυφοσ"),
+            Some("σίσυφοσ"),
+            None,
+            Some("ǆ"),
+        ]);
+        let result = casefold(&array).unwrap();
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let view = StringViewArray::from_iter(array.iter());
+        let result = casefold(&view).unwrap();
+        assert_eq!(
+            result.as_string_view(),
+            &StringViewArray::from_iter(expected.iter())
+        );
+
+        // ASCII fast path
+        let array = LargeStringArray::from(vec![Some("Hello"), None, Some("WORLD")]).slice(1, 2);
+        let result = casefold(&array).unwrap();
+        let expected = LargeStringArray::from(vec![None, Some("world")]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+    }
+
+    #[test]
+    fn test_normalize_dictionary() {
+        let array: DictionaryArray<Int32Type> =
+            vec!["ÉCOLE", "e\u{301}cole", "ÉCOLE"].into_iter().collect();
+        let folded = casefold(&array).unwrap();
+        let result = normalize(&folded, NormalizationForm::NFC).unwrap();
+        let result = result.as_dictionary::<Int32Type>();
+        let values: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("\u{e9}cole"); 3]);
+    }
+
+    #[test]
+    fn test_normalize_unsupported() {
+        let array = Int32Array::from(vec![1]);
+        let err = normalize(&array, NormalizationForm::NFC).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: normalize not supported for Int32"
+        );
+        let err = casefold(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: casefold not supported for Int32"
+        );
+    }
+}
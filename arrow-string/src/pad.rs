@@ -0,0 +1,252 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the SQL `lpad` and `rpad` kernels for string arrays
+
+use crate::args::{build_strings, output_len, Int64Arg, StringArg};
+use arrow_array::{ArrayRef, Datum};
+use arrow_schema::ArrowError;
+
+/// Pads each string in `array` on the left to `length` characters, by prepending
+/// the characters of `fill` repeated as necessary
+///
+/// Modelled after the Postgres [lpad]:
+///
+/// * Lengths are measured in Unicode scalar values (`char`), not bytes
+/// * A string longer than `length` is truncated to its first `length` characters
+/// * A string is returned unchanged, or truncated, if `fill` is empty
+/// * A `length` less than or equal to zero returns an empty string
+/// * The result is null if any argument is null
+///
+/// `array` and `fill` may be any of [`StringArray`], [`LargeStringArray`] or
+/// [`StringViewArray`], and `length` an [`Int64Array`]. Any argument may be a scalar,
+/// and the result has the same type as `array`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int64Array, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::pad::lpad;
+/// let array = StringArray::from(vec![Some("hi"), Some("héllo"), None]);
+/// let length = Scalar::new(Int64Array::from(vec![4]));
+/// let fill = Scalar::new(StringArray::from(vec!["xy"]));
+/// let result = lpad(&array, &length, &fill).unwrap();
+/// assert_eq!(
+///     result.as_string::<i32>(),
+///     &StringArray::from(vec![Some("xyhi"), Some("héll"), None])
+/// );
+/// ```
+///
+/// [lpad]: https://www.postgresql.org/docs/current/functions-string.html
+/// [`StringArray`]: arrow_array::StringArray
+/// [`LargeStringArray`]: arrow_array::LargeStringArray
+/// [`StringViewArray`]: arrow_array::StringViewArray
+/// [`Int64Array`]: arrow_array::Int64Array
+pub fn lpad(
+    array: &dyn Datum,
+    length: &dyn Datum,
+    fill: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    pad("lpad", array, length, fill, true)
+}
+
+/// Pads each string in `array` on the right to `length` characters, by appending
+/// the characters of `fill` repeated as necessary
+///
+/// See [`lpad`] for more details
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int64Array, Scalar, StringViewArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::pad::rpad;
+/// let array = StringViewArray::from(vec!["a", "bb", "ccc"]);
+/// let length = Int64Array::from(vec![3, 4, 2]);
+/// let fill = Scalar::new(StringViewArray::from(vec!["."]));
+/// let result = rpad(&array, &length, &fill).unwrap();
+/// assert_eq!(
+///     result.as_string_view(),
+///     &StringViewArray::from(vec!["a..", "bb..", "cc"])
+/// );
+/// ```
+pub fn rpad(
+    array: &dyn Datum,
+    length: &dyn Datum,
+    fill: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    pad("rpad", array, length, fill, false)
+}
+
+fn pad(
+    name: &str,
+    array: &dyn Datum,
+    length: &dyn Datum,
+    fill: &dyn Datum,
+    left: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let array = StringArg::try_new(name, array)?;
+    let length = Int64Arg::try_new(name, length)?;
+    let fill = StringArg::try_new(name, fill)?;
+    let len = output_len(name, [array.len(), length.len(), fill.len()])?;
+
+    build_strings(array.data_type(), len, |idx, out| {
+        let (Some(value), Some(length), Some(fill)) =
+            (array.value(idx), length.value(idx), fill.value(idx))
+        else {
+            return Ok(false);
+        };
+        let length = match usize::try_from(length) {
+            Ok(length) if length <= i32::MAX as usize => length,
+            Ok(_) => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "{name} requested length {length} too large"
+                )))
+            }
+            Err(_) => 0,
+        };
+
+        // Byte offset of the end of the first `length` characters, or the number of
+        // characters to pad if `value` is shorter
+        let (end, count) = match value.char_indices().nth(length) {
+            Some((end, _)) => (end, 0),
+            None => (value.len(), length - value.chars().count()),
+        };
+        if left {
+            out.extend(fill.chars().cycle().take(count));
+            out.push_str(&value[..end]);
+        } else {
+            out.push_str(&value[..end]);
+            out.extend(fill.chars().cycle().take(count));
+        }
+        Ok(true)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::*;
+
+    #[test]
+    fn test_lpad_rpad() {
+        let array = StringArray::from(vec![
+            Some("hi"),
+            Some("hello"),
+            Some(""),
+            None,
+            Some("héllo"),
+            Some("abc"),
+        ]);
+        let length = Int64Array::from(vec![Some(5), Some(3), Some(2), Some(4), Some(7), None]);
+        let fill = Scalar::new(StringArray::from(vec!["xy"]));
+
+        let result = lpad(&array, &length, &fill).unwrap();
+        let expected = StringArray::from(vec![
+            Some("xyxhi"),
+            Some("hel"),
+            Some("xy"),
+            None,
+            Some("xyhéllo"),
+            None,
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let result = rpad(&array, &length, &fill).unwrap();
+        let expected = StringArray::from(vec![
+            Some("hixyx"),
+            Some("hel"),
+            Some("xy"),
+            None,
+            Some("hélloxy"),
+            None,
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_pad_edge_cases() {
+        let array = LargeStringArray::from(vec!["abc", "abc", "abc", "ab"]);
+        let length = Int64Array::from(vec![-1, 0, 5, 5]);
+        let fill = LargeStringArray::from(vec!["x", "x", "", "\u{1f600}-"]);
+
+        let result = lpad(&array, &length, &fill).unwrap();
+        let expected = LargeStringArray::from(vec!["", "", "abc", "\u{1f600}-\u{1f600}ab"]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        let result = rpad(&array, &length, &fill).unwrap();
+        let expected = LargeStringArray::from(vec!["", "", "abc", "ab\u{1f600}-\u{1f600}"]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+    }
+
+    #[test]
+    fn test_pad_scalars() {
+        // Mixed string types, with the output matching the type of the padded array
+        let long = "a string that is too long to be inlined";
+        let array = StringViewArray::from(vec![Some("short"), Some(long), None]);
+        let length = Scalar::new(Int64Array::from(vec![8]));
+        let fill = Scalar::new(StringArray::from(vec!["-"]));
+        let result = lpad(&array, &length, &fill).unwrap();
+        let expected = StringViewArray::from(vec![Some("---short"), Some("a string"), None]);
+        assert_eq!(result.as_string_view(), &expected);
+
+        let array = Scalar::new(StringArray::from(vec!["7"]));
+        let length = Int64Array::from(vec![1, 2, 3]);
+        let fill = Scalar::new(StringArray::from(vec!["0"]));
+        let result = lpad(&array, &length, &fill).unwrap();
+        let expected = StringArray::from(vec!["7", "07", "007"]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let length = Scalar::new(Int64Array::from(vec![3]));
+        let result = rpad(&array, &length, &fill).unwrap();
+        assert_eq!(result.as_string::<i32>(), &StringArray::from(vec!["700"]));
+
+        let fill = Scalar::new(StringArray::new_null(1));
+        let result = rpad(&array, &length, &fill).unwrap();
+        assert_eq!(result.as_string::<i32>(), &StringArray::new_null(1));
+    }
+
+    #[test]
+    fn test_pad_invalid() {
+        let array = StringArray::from(vec!["a", "b"]);
+        let fill = StringArray::from(vec!["x"]);
+        let length = Scalar::new(Int64Array::from(vec![3]));
+        let err = lpad(&array, &length, &fill).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot perform lpad on arrays of different length, got 2 vs 1"
+        );
+
+        let length = Scalar::new(Int32Array::from(vec![3]));
+        let err = rpad(&array, &length, &fill).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: rpad not supported for Int32"
+        );
+
+        let length = Scalar::new(Int64Array::from(vec![i64::MAX]));
+        let fill = Scalar::new(StringArray::from(vec!["x"]));
+        let err = rpad(&array, &length, &fill).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Invalid argument error: rpad requested length {} too large",
+                i64::MAX
+            )
+        );
+    }
+}
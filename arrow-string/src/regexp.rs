@@ -28,6 +28,7 @@ use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, Field};
 use regex::Regex;
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -395,6 +396,7 @@ fn regexp_scalar_match<OffsetSize: OffsetSizeTrait>(
 ///
 /// # See Also
 /// * [`regexp_is_match`] for matching (rather than extracting) a regular expression against an array of strings
+/// * [`regexp_extract`] for extracting a single group from a string array
 ///
 /// [regexp_match]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
 pub fn regexp_match(
@@ -493,6 +495,274 @@ pub fn regexp_match(
     }
 }
 
+fn compile_regex(pattern: &str) -> Result<Regex, ArrowError> {
+    Regex::new(pattern)
+        .map_err(|e| ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}")))
+}
+
+/// Invokes `f` with each value of `array` and the compiled regular expression of the
+/// corresponding pattern in `regex_array`, or `None` if either is null
+///
+/// A scalar pattern is compiled once, and an array of patterns compiles each distinct
+/// pattern once
+fn for_each_regexp<'a, O: OffsetSizeTrait>(
+    name: &str,
+    array: &'a GenericStringArray<O>,
+    regex_array: &'a dyn Datum,
+    mut f: impl FnMut(Option<(&'a str, &Regex)>) -> Result<(), ArrowError>,
+) -> Result<(), ArrowError> {
+    let (patterns, is_scalar) = regex_array.get();
+    if patterns.data_type() != array.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "{name}() requires both array and pattern to be either Utf8 or LargeUtf8"
+        )));
+    }
+    let patterns = patterns.as_string::<O>();
+
+    if is_scalar {
+        let re = match patterns.is_valid(0) {
+            true => Some(compile_regex(patterns.value(0))?),
+            false => None,
+        };
+        return array.iter().try_for_each(|value| f(value.zip(re.as_ref())));
+    }
+
+    if patterns.len() != array.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot perform {name}() on arrays of different length"
+        )));
+    }
+    let mut cache: HashMap<&str, Regex> = HashMap::new();
+    array
+        .iter()
+        .zip(patterns.iter())
+        .try_for_each(|(value, pattern)| {
+            let re = match (value, pattern) {
+                (Some(value), Some(pattern)) => {
+                    let re = match cache.entry(pattern) {
+                        Entry::Occupied(e) => e.into_mut(),
+                        Entry::Vacant(e) => e.insert(compile_regex(pattern)?),
+                    };
+                    Some((value, &*re))
+                }
+                _ => None,
+            };
+            f(re)
+        })
+}
+
+fn check_group_index(name: &str, re: &Regex, group_idx: usize) -> Result<(), ArrowError> {
+    match group_idx < re.captures_len() {
+        true => Ok(()),
+        false => Err(ArrowError::InvalidArgumentError(format!(
+            "{name}() group index {group_idx} out of bounds for pattern '{}' with {} groups",
+            re.as_str(),
+            re.captures_len() - 1
+        ))),
+    }
+}
+
+fn regexp_extract_impl<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    regex_array: &dyn Datum,
+    group_idx: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let mut builder = GenericStringBuilder::<O>::with_capacity(array.len(), 0);
+    for_each_regexp("regexp_extract", array, regex_array, |row| {
+        match row {
+            Some((value, re)) => {
+                check_group_index("regexp_extract", re, group_idx)?;
+                let group = re.captures(value).and_then(|caps| caps.get(group_idx));
+                builder.append_option(group.map(|m| m.as_str()));
+            }
+            None => builder.append_null(),
+        }
+        Ok(())
+    })?;
+    Ok(Arc::new(builder.finish()))
+}
+
+fn regexp_extract_all_impl<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    regex_array: &dyn Datum,
+    group_idx: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let mut list_builder = ListBuilder::new(GenericStringBuilder::<O>::new());
+    for_each_regexp("regexp_extract_all", array, regex_array, |row| {
+        match row {
+            Some((value, re)) => {
+                check_group_index("regexp_extract_all", re, group_idx)?;
+                for caps in re.captures_iter(value) {
+                    let group = caps.get(group_idx).map(|m| m.as_str());
+                    list_builder.values().append_option(group);
+                }
+                list_builder.append(true);
+            }
+            None => list_builder.append(false),
+        }
+        Ok(())
+    })?;
+    Ok(Arc::new(list_builder.finish()))
+}
+
+fn regexp_split_to_list_impl<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    regex_array: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let mut list_builder = ListBuilder::new(GenericStringBuilder::<O>::new());
+    for_each_regexp("regexp_split_to_list", array, regex_array, |row| {
+        match row {
+            Some((value, re)) => {
+                let mut start = 0;
+                for m in re.find_iter(value) {
+                    // Empty matches at the start or end of the string do not split
+                    if m.is_empty() && (m.start() == 0 || m.start() == value.len()) {
+                        continue;
+                    }
+                    list_builder.values().append_value(&value[start..m.start()]);
+                    start = m.end();
+                }
+                list_builder.values().append_value(&value[start..]);
+                list_builder.append(true);
+            }
+            None => list_builder.append(false),
+        }
+        Ok(())
+    })?;
+    Ok(Arc::new(list_builder.finish()))
+}
+
+/// Extract the substring matched by capture group `group_idx` of a regular expression
+/// for each string in a String array.
+///
+/// Returns an array of the same type as `array`, either [`StringArray`] or
+/// [`LargeStringArray`]. Group `0` refers to the substring matching the whole pattern,
+/// and group `n` to the n'th capturing parenthesized subexpression.
+///
+/// The result is NULL if the string or pattern is NULL, if the pattern does not match,
+/// or if the requested group does not participate in the leftmost-first match.
+///
+/// `regex_array` may be a scalar, in which case the pattern is compiled once, or an array
+/// of the same length as `array`. Flags can be specified inline, e.g. `(?i)`.
+///
+/// Returns an error if `group_idx` exceeds the number of groups in a pattern.
+///
+/// # See Also
+/// * [`regexp_extract_all`] for extracting all matches of a group
+/// * [`regexp_match`] for extracting all groups of the first match
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::regexp_extract;
+/// let array = StringArray::from(vec![Some("abc-005-def"), Some("X545"), None]);
+/// let pattern = Scalar::new(StringArray::from(vec![r"(\w+)-(\d+)"]));
+/// let result = regexp_extract(&array, &pattern, 2).unwrap();
+/// assert_eq!(
+///     result.as_string::<i32>(),
+///     &StringArray::from(vec![Some("005"), None, None])
+/// );
+/// ```
+pub fn regexp_extract(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    group_idx: usize,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => regexp_extract_impl(array.as_string::<i32>(), regex_array, group_idx),
+        DataType::LargeUtf8 => {
+            regexp_extract_impl(array.as_string::<i64>(), regex_array, group_idx)
+        }
+        _ => Err(ArrowError::ComputeError(
+            "regexp_extract() requires array to be either Utf8 or LargeUtf8".to_string(),
+        )),
+    }
+}
+
+/// Extract the substrings matched by capture group `group_idx` of every non-overlapping
+/// match of a regular expression for each string in a String array.
+///
+/// Returns a [`ListArray`] of [`GenericStringArray`], where each element contains the
+/// group of each match in order, or an empty list if there is no match. A group that does
+/// not participate in a match is a NULL entry of the list.
+///
+/// The list element is NULL if the string or pattern is NULL.
+///
+/// See [`regexp_extract`] for details on `regex_array` and `group_idx`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::regexp_extract_all;
+/// let array = StringArray::from(vec!["a1b22c333", "abc"]);
+/// let pattern = Scalar::new(StringArray::from(vec![r"\d+"]));
+/// let result = regexp_extract_all(&array, &pattern, 0).unwrap();
+/// let result = result.as_list::<i32>();
+/// assert_eq!(
+///     result.value(0).as_string::<i32>(),
+///     &StringArray::from(vec!["1", "22", "333"])
+/// );
+/// assert!(result.value(1).is_empty());
+/// ```
+pub fn regexp_extract_all(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    group_idx: usize,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => regexp_extract_all_impl(array.as_string::<i32>(), regex_array, group_idx),
+        DataType::LargeUtf8 => {
+            regexp_extract_all_impl(array.as_string::<i64>(), regex_array, group_idx)
+        }
+        _ => Err(ArrowError::ComputeError(
+            "regexp_extract_all() requires array to be either Utf8 or LargeUtf8".to_string(),
+        )),
+    }
+}
+
+/// Split each string in a String array using a regular expression as the delimiter.
+///
+/// Modelled after the Postgres [regexp_split_to_array].
+///
+/// Returns a [`ListArray`] of [`GenericStringArray`] containing the text between
+/// matches of the pattern. A string without any match yields a single-element list
+/// containing the whole string, and empty matches at the start or end of a string are
+/// ignored, so an empty pattern splits a string into its characters.
+///
+/// The list element is NULL if the string or pattern is NULL.
+///
+/// See [`regexp_extract`] for details on `regex_array`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::regexp_split_to_list;
+/// let array = StringArray::from(vec!["a, b,c"]);
+/// let pattern = Scalar::new(StringArray::from(vec![r",\s*"]));
+/// let result = regexp_split_to_list(&array, &pattern).unwrap();
+/// assert_eq!(
+///     result.as_list::<i32>().value(0).as_string::<i32>(),
+///     &StringArray::from(vec!["a", "b", "c"])
+/// );
+/// ```
+///
+/// [regexp_split_to_array]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
+pub fn regexp_split_to_list(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => regexp_split_to_list_impl(array.as_string::<i32>(), regex_array),
+        DataType::LargeUtf8 => regexp_split_to_list_impl(array.as_string::<i64>(), regex_array),
+        _ => Err(ArrowError::ComputeError(
+            "regexp_split_to_list() requires array to be either Utf8 or LargeUtf8".to_string(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -804,4 +1074,144 @@ mod tests {
         regexp_is_match_scalar::<StringViewArray>,
         [true, true, false, false]
     );
+
+    #[test]
+    fn test_regexp_extract() {
+        let array = StringArray::from(vec![
+            Some("abc-005-def"),
+            Some("X-7-5"),
+            Some("X545"),
+            None,
+            Some("foo-"),
+        ]);
+        let pattern = Scalar::new(StringArray::from(vec![r"(\w+)-(\d+)?"]));
+        let actual = regexp_extract(&array, &pattern, 0).unwrap();
+        let expected =
+            StringArray::from(vec![Some("abc-005"), Some("X-7"), None, None, Some("foo-")]);
+        assert_eq!(actual.as_string::<i32>(), &expected);
+
+        let actual = regexp_extract(&array, &pattern, 2).unwrap();
+        let expected = StringArray::from(vec![Some("005"), Some("7"), None, None, None]);
+        assert_eq!(actual.as_string::<i32>(), &expected);
+
+        let array = LargeStringArray::from(vec![Some("ab12"), Some("cd"), Some("34"), Some("x")]);
+        let pattern =
+            LargeStringArray::from(vec![Some(r"[a-z]+"), Some(r"[a-z]+"), Some(r"\d+"), None]);
+        let actual = regexp_extract(&array, &pattern, 0).unwrap();
+        let expected = LargeStringArray::from(vec![Some("ab"), Some("cd"), Some("34"), None]);
+        assert_eq!(actual.as_string::<i64>(), &expected);
+
+        let pattern = Scalar::new(new_null_array(&DataType::LargeUtf8, 1));
+        let actual = regexp_extract(&array, &pattern, 0).unwrap();
+        assert_eq!(actual.null_count(), 4);
+    }
+
+    #[test]
+    fn test_regexp_extract_all() {
+        let array = StringArray::from(vec![Some("k1=v1, k2=v2"), Some("none"), None, Some("k3=")]);
+        let pattern = Scalar::new(StringArray::from(vec![r"(\w+)=(\w+)?"]));
+        let actual = regexp_extract_all(&array, &pattern, 2).unwrap();
+
+        let mut expected = ListBuilder::new(GenericStringBuilder::<i32>::new());
+        expected.values().append_value("v1");
+        expected.values().append_value("v2");
+        expected.append(true);
+        expected.append(true);
+        expected.append(false);
+        expected.values().append_null();
+        expected.append(true);
+        assert_eq!(actual.as_list::<i32>(), &expected.finish());
+
+        let actual = regexp_extract_all(&array, &pattern, 1).unwrap();
+        let mut expected = ListBuilder::new(GenericStringBuilder::<i32>::new());
+        expected.values().append_value("k1");
+        expected.values().append_value("k2");
+        expected.append(true);
+        expected.append(true);
+        expected.append(false);
+        expected.values().append_value("k3");
+        expected.append(true);
+        assert_eq!(actual.as_list::<i32>(), &expected.finish());
+    }
+
+    #[test]
+    fn test_regexp_split_to_list() {
+        let array = LargeStringArray::from(vec![
+            Some("a, b,c"),
+            Some("abc"),
+            Some(""),
+            None,
+            Some(",a,"),
+        ]);
+        let pattern = Scalar::new(LargeStringArray::from(vec![r",\s*"]));
+        let actual = regexp_split_to_list(&array, &pattern).unwrap();
+
+        let mut expected = ListBuilder::new(GenericStringBuilder::<i64>::new());
+        expected.values().append_value("a");
+        expected.values().append_value("b");
+        expected.values().append_value("c");
+        expected.append(true);
+        expected.values().append_value("abc");
+        expected.append(true);
+        expected.values().append_value("");
+        expected.append(true);
+        expected.append(false);
+        expected.values().append_value("");
+        expected.values().append_value("a");
+        expected.values().append_value("");
+        expected.append(true);
+        assert_eq!(actual.as_list::<i32>(), &expected.finish());
+
+        // Empty pattern splits into characters
+        let array = StringArray::from(vec!["héllo"]);
+        let pattern = Scalar::new(StringArray::from(vec![""]));
+        let actual = regexp_split_to_list(&array, &pattern).unwrap();
+        let actual = actual.as_list::<i32>().value(0);
+        let expected = StringArray::from(vec!["h", "é", "l", "l", "o"]);
+        assert_eq!(actual.as_string::<i32>(), &expected);
+
+        let pattern = StringArray::from(vec![r"l+"]);
+        let actual = regexp_split_to_list(&array, &pattern).unwrap();
+        let actual = actual.as_list::<i32>().value(0);
+        let expected = StringArray::from(vec!["hé", "o"]);
+        assert_eq!(actual.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_extract_invalid() {
+        let array = StringArray::from(vec!["abc"]);
+        let pattern = Scalar::new(StringArray::from(vec![r"(a)(b)"]));
+        let err = regexp_extract(&array, &pattern, 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: regexp_extract() group index 3 out of bounds for pattern '(a)(b)' with 2 groups"
+        );
+
+        let pattern = Scalar::new(StringArray::from(vec![r"(a"]));
+        let err = regexp_extract_all(&array, &pattern, 0).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Regular expression did not compile"));
+
+        let pattern = Scalar::new(LargeStringArray::from(vec!["a"]));
+        let err = regexp_split_to_list(&array, &pattern).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_split_to_list() requires both array and pattern to be either Utf8 or LargeUtf8"
+        );
+
+        let pattern = StringArray::from(vec!["a", "b"]);
+        let err = regexp_extract(&array, &pattern, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot perform regexp_extract() on arrays of different length"
+        );
+
+        let array = Int32Array::from(vec![1]);
+        let err = regexp_extract(&array, &pattern, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_extract() requires array to be either Utf8 or LargeUtf8"
+        );
+    }
 }
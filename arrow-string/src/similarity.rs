@@ -0,0 +1,436 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines string similarity kernels for fuzzy matching, such as [`levenshtein`]
+//! and [`jaro_winkler`]
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int32Type};
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The unit in which strings are compared by the similarity kernels
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StringUnit {
+    /// Compare the UTF-8 encoded bytes of the strings
+    Byte,
+    /// Compare the Unicode scalar values of the strings
+    #[default]
+    Char,
+    /// Compare the extended grapheme clusters of the strings, as defined by
+    /// [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/)
+    ///
+    /// This treats a base character followed by combining marks, or a multi-codepoint
+    /// emoji, as a single user-perceived character
+    ///
+    /// Requires the `unicode` feature, without which this compares Unicode scalar
+    /// values as [`StringUnit::Char`]
+    Grapheme,
+}
+
+/// Returns the Levenshtein edit distance between each pair of strings in `a` and `b`,
+/// comparing [`StringUnit::Char`]
+///
+/// See [`levenshtein_with_unit`] for more details
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int32Array, Scalar, StringArray};
+/// # use arrow_string::similarity::levenshtein;
+/// let a = StringArray::from(vec![Some("kitten"), Some("flaw"), None]);
+/// let b = Scalar::new(StringArray::from(vec!["sitting"]));
+/// let result = levenshtein(&a, &b).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![Some(3), Some(7), None]));
+/// ```
+pub fn levenshtein(a: &dyn Datum, b: &dyn Datum) -> Result<Int32Array, ArrowError> {
+    levenshtein_with_unit(a, b, StringUnit::Char)
+}
+
+/// Returns the Levenshtein edit distance between each pair of strings in `a` and `b`
+///
+/// The distance is the minimum number of single `unit` insertions, deletions or
+/// substitutions required to change one string into the other.
+///
+/// `a` and `b` must have the same data type, one of [`StringArray`], [`LargeStringArray`]
+/// or [`StringViewArray`], and either may be a scalar. The result is null where either
+/// input is null.
+pub fn levenshtein_with_unit(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    unit: StringUnit,
+) -> Result<Int32Array, ArrowError> {
+    similarity_op::<Int32Type, _>("levenshtein", a, b, |a, b| {
+        let distance = match unit {
+            StringUnit::Byte => levenshtein_units(a.as_bytes(), b.as_bytes()),
+            StringUnit::Char => levenshtein_units(&chars(a), &chars(b)),
+            StringUnit::Grapheme => levenshtein_units(&graphemes(a), &graphemes(b)),
+        };
+        i32::try_from(distance).map_err(|_| {
+            ArrowError::ComputeError(format!("levenshtein distance {distance} overflows Int32"))
+        })
+    })
+}
+
+/// Returns the Jaro-Winkler similarity between each pair of strings in `a` and `b`,
+/// comparing [`StringUnit::Char`]
+///
+/// See [`jaro_winkler_with_unit`] for more details
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_string::similarity::jaro_winkler;
+/// let a = StringArray::from(vec![Some("martha"), Some("martha"), None]);
+/// let b = StringArray::from(vec![Some("marhta"), Some("martha"), Some("marhta")]);
+/// let result = jaro_winkler(&a, &b).unwrap();
+/// assert!((result.value(0) - 0.9611).abs() < 1e-4);
+/// assert_eq!(result.value(1), 1.0);
+/// assert!(result.is_null(2));
+/// ```
+pub fn jaro_winkler(a: &dyn Datum, b: &dyn Datum) -> Result<Float64Array, ArrowError> {
+    jaro_winkler_with_unit(a, b, StringUnit::Char)
+}
+
+/// Returns the Jaro-Winkler similarity between each pair of strings in `a` and `b`
+///
+/// The similarity ranges from `0.0` for strings with no `unit` in common, to `1.0` for
+/// equal strings. Two empty strings are considered equal. Following Winkler, strings
+/// with a Jaro similarity above `0.7` are boosted by `0.1` for each unit of common
+/// prefix, up to a prefix of 4.
+///
+/// `a` and `b` must have the same data type, one of [`StringArray`], [`LargeStringArray`]
+/// or [`StringViewArray`], and either may be a scalar. The result is null where either
+/// input is null.
+pub fn jaro_winkler_with_unit(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    unit: StringUnit,
+) -> Result<Float64Array, ArrowError> {
+    similarity_op::<Float64Type, _>("jaro_winkler", a, b, |a, b| {
+        Ok(match unit {
+            StringUnit::Byte => jaro_winkler_units(a.as_bytes(), b.as_bytes()),
+            StringUnit::Char => jaro_winkler_units(&chars(a), &chars(b)),
+            StringUnit::Grapheme => jaro_winkler_units(&graphemes(a), &graphemes(b)),
+        })
+    })
+}
+
+fn chars(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+#[cfg(feature = "unicode")]
+fn graphemes(s: &str) -> Vec<&str> {
+    s.graphemes(true).collect()
+}
+
+#[cfg(not(feature = "unicode"))]
+fn graphemes(s: &str) -> Vec<&str> {
+    s.char_indices()
+        .map(|(idx, c)| &s[idx..idx + c.len_utf8()])
+        .collect()
+}
+
+fn similarity_op<O, F>(
+    name: &str,
+    a: &dyn Datum,
+    b: &dyn Datum,
+    op: F,
+) -> Result<PrimitiveArray<O>, ArrowError>
+where
+    O: ArrowPrimitiveType,
+    F: Fn(&str, &str) -> Result<O::Native, ArrowError>,
+{
+    let (l, l_s) = a.get();
+    let (r, r_s) = b.get();
+
+    if l.len() != r.len() && !l_s && !r_s {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare arrays of different lengths, got {} vs {}",
+            l.len(),
+            r.len()
+        )));
+    }
+
+    match (l.data_type(), r.data_type()) {
+        (DataType::Utf8, DataType::Utf8) => {
+            apply(l.as_string::<i32>(), l_s, r.as_string::<i32>(), r_s, op)
+        }
+        (DataType::LargeUtf8, DataType::LargeUtf8) => {
+            apply(l.as_string::<i64>(), l_s, r.as_string::<i64>(), r_s, op)
+        }
+        (DataType::Utf8View, DataType::Utf8View) => {
+            apply(l.as_string_view(), l_s, r.as_string_view(), r_s, op)
+        }
+        (l_t, r_t) => Err(ArrowError::InvalidArgumentError(format!(
+            "{name} not supported for {l_t} and {r_t}"
+        ))),
+    }
+}
+
+fn apply<'a, T, O, F>(
+    l: T,
+    l_s: bool,
+    r: T,
+    r_s: bool,
+    op: F,
+) -> Result<PrimitiveArray<O>, ArrowError>
+where
+    T: StringArrayType<'a>,
+    O: ArrowPrimitiveType,
+    F: Fn(&str, &str) -> Result<O::Native, ArrowError>,
+{
+    let len = match (l_s, r_s) {
+        (true, true) => 1,
+        (true, false) => r.len(),
+        (false, _) => l.len(),
+    };
+    let value = |a: &T, is_scalar: bool, idx: usize| {
+        let idx = if is_scalar { 0 } else { idx };
+        a.is_valid(idx).then(|| a.value(idx))
+    };
+    (0..len)
+        .map(|idx| match (value(&l, l_s, idx), value(&r, r_s, idx)) {
+            (Some(l), Some(r)) => op(l, r).map(Some),
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+/// Computes the Levenshtein distance between `a` and `b` using a single row of the
+/// dynamic programming matrix
+fn levenshtein_units<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = match x == y {
+                true => diagonal,
+                false => 1 + diagonal.min(above).min(row[j]),
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+fn jaro_units<T: PartialEq>(a: &[T], b: &[T]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Units only match if they are no further apart than the match window
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len().min(b.len()));
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *x {
+                b_matched[j] = true;
+                a_matches.push(x);
+                break;
+            }
+        }
+    }
+
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    // Half the number of matching units that are out of order
+    let b_matches = b.iter().zip(&b_matched).filter_map(|(y, m)| m.then_some(y));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, y)| **x != *y)
+        .count()
+        / 2;
+
+    let m = a_matches.len() as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+fn jaro_winkler_units<T: PartialEq>(a: &[T], b: &[T]) -> f64 {
+    let similarity = jaro_units(a, b);
+    if similarity <= 0.7 {
+        return similarity;
+    }
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    similarity + 0.1 * prefix as f64 * (1.0 - similarity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &Float64Array, expected: &[Option<f64>]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            match (a, e) {
+                (Some(a), Some(e)) => assert!((a - e).abs() < 1e-4, "{a} != {e}"),
+                _ => assert_eq!(a, *e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        let a = StringArray::from(vec![
+            Some("kitten"),
+            Some(""),
+            Some("abc"),
+            None,
+            Some("flaw"),
+            Some("same"),
+        ]);
+        let b = StringArray::from(vec![
+            Some("sitting"),
+            Some("abc"),
+            Some(""),
+            Some("abc"),
+            None,
+            Some("same"),
+        ]);
+        let expected = Int32Array::from(vec![Some(3), Some(3), Some(3), None, None, Some(0)]);
+        assert_eq!(levenshtein(&a, &b).unwrap(), expected);
+        assert_eq!(levenshtein(&b, &a).unwrap(), expected);
+
+        let a = LargeStringArray::from(vec!["lawn", "flaw", "law"]);
+        let b = Scalar::new(LargeStringArray::from(vec!["flaw"]));
+        let expected = Int32Array::from(vec![2, 0, 1]);
+        assert_eq!(levenshtein(&a, &b).unwrap(), expected);
+        assert_eq!(levenshtein(&b, &a).unwrap(), expected);
+
+        let a = StringViewArray::from(vec![Some("a string that is too long to be inlined"), None]);
+        let b = Scalar::new(StringViewArray::from(vec![
+            "a string that is too long to inline",
+        ]));
+        let expected = Int32Array::from(vec![Some(4), None]);
+        assert_eq!(levenshtein(&a, &b).unwrap(), expected);
+
+        // Both scalars
+        let a = Scalar::new(StringArray::from(vec!["abc"]));
+        let b = Scalar::new(StringArray::from(vec!["abd"]));
+        assert_eq!(levenshtein(&a, &b).unwrap(), Int32Array::from(vec![1]));
+
+        let b = Scalar::new(StringArray::new_null(1));
+        let a = StringArray::from(vec!["a", "b"]);
+        assert_eq!(levenshtein(&a, &b).unwrap(), Int32Array::new_null(2));
+    }
+
+    #[test]
+    fn test_levenshtein_unit() {
+        // "é" composed and decomposed into "e" and a combining acute accent
+        let a = StringArray::from(vec!["\u{e9}", "e\u{301}", "🇩🇪", "a"]);
+        let b = StringArray::from(vec!["e", "e\u{301}x", "🇫🇷", "🇫🇷"]);
+
+        let result = levenshtein_with_unit(&a, &b, StringUnit::Byte).unwrap();
+        assert_eq!(result, Int32Array::from(vec![2, 1, 2, 8]));
+
+        let result = levenshtein_with_unit(&a, &b, StringUnit::Char).unwrap();
+        assert_eq!(result, Int32Array::from(vec![1, 1, 2, 2]));
+
+        let result = levenshtein_with_unit(&a, &b, StringUnit::Grapheme).unwrap();
+        #[cfg(feature = "unicode")]
+        assert_eq!(result, Int32Array::from(vec![1, 1, 1, 1]));
+        #[cfg(not(feature = "unicode"))]
+        assert_eq!(result, Int32Array::from(vec![1, 1, 2, 2]));
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let a = StringArray::from(vec![
+            Some("martha"),
+            Some("dixon"),
+            Some("jellyfish"),
+            Some(""),
+            Some(""),
+            Some("abc"),
+            None,
+        ]);
+        let b = StringArray::from(vec![
+            Some("marhta"),
+            Some("dicksonx"),
+            Some("smellyfish"),
+            Some(""),
+            Some("abc"),
+            Some("xyz"),
+            Some("abc"),
+        ]);
+        let expected = [
+            Some(0.9611),
+            Some(0.8133),
+            Some(0.8963),
+            Some(1.0),
+            Some(0.0),
+            Some(0.0),
+            None,
+        ];
+        assert_close(&jaro_winkler(&a, &b).unwrap(), &expected);
+        assert_close(&jaro_winkler(&b, &a).unwrap(), &expected);
+
+        let a = StringViewArray::from(vec!["dwayne", "duane"]);
+        let b = Scalar::new(StringViewArray::from(vec!["duane"]));
+        let result = jaro_winkler(&a, &b).unwrap();
+        assert_close(&result, &[Some(0.84), Some(1.0)]);
+    }
+
+    #[test]
+    fn test_jaro_winkler_unit() {
+        let a = LargeStringArray::from(vec!["e\u{301}t\u{e9}"]);
+        let b = LargeStringArray::from(vec!["e\u{301}te\u{301}"]);
+
+        let result = jaro_winkler_with_unit(&a, &b, StringUnit::Grapheme).unwrap();
+        #[cfg(feature = "unicode")]
+        assert_close(&result, &[Some(0.8222)]);
+        #[cfg(not(feature = "unicode"))]
+        assert_close(&result, &[Some(0.8483)]);
+
+        let result = jaro_winkler_with_unit(&a, &b, StringUnit::Char).unwrap();
+        assert_close(&result, &[Some(0.8483)]);
+
+        let result = jaro_winkler_with_unit(&a, &b, StringUnit::Byte).unwrap();
+        assert_close(&result, &[Some(0.8476)]);
+    }
+
+    #[test]
+    fn test_similarity_invalid() {
+        let a = StringArray::from(vec!["a", "b"]);
+        let b = StringArray::from(vec!["a"]);
+        let err = levenshtein(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare arrays of different lengths, got 2 vs 1"
+        );
+
+        let b = LargeStringArray::from(vec!["a", "b"]);
+        let err = jaro_winkler(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: jaro_winkler not supported for Utf8 and LargeUtf8"
+        );
+    }
+}
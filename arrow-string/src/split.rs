@@ -0,0 +1,306 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the SQL `split_part` and `substring_index` kernels for string arrays
+
+use crate::args::{build_strings, output_len, Int64Arg, StringArg};
+use arrow_array::{ArrayRef, Datum};
+use arrow_schema::ArrowError;
+
+/// Splits each string in `array` on `delimiter` and returns the field at position `n`
+///
+/// Modelled after the Postgres [split_part]:
+///
+/// * Fields are numbered from 1, with a negative `n` counting from the last field
+/// * An empty string is returned if there are fewer than `|n|` fields
+/// * An empty `delimiter` does not split the string, returning it as the only field
+/// * The result is null if any argument is null
+///
+/// Returns an error if `n` is zero.
+///
+/// `array` and `delimiter` may be any of [`StringArray`], [`LargeStringArray`] or
+/// [`StringViewArray`], and `n` an [`Int64Array`]. Any argument may be a scalar,
+/// and the result has the same type as `array`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int64Array, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::split_part;
+/// let array = StringArray::from(vec![Some("a,b,c"), Some("a"), None]);
+/// let delimiter = Scalar::new(StringArray::from(vec![","]));
+/// let n = Int64Array::from(vec![2, 2, 1]);
+/// let result = split_part(&array, &delimiter, &n).unwrap();
+/// assert_eq!(
+///     result.as_string::<i32>(),
+///     &StringArray::from(vec![Some("b"), Some(""), None])
+/// );
+/// ```
+///
+/// [split_part]: https://www.postgresql.org/docs/current/functions-string.html
+/// [`StringArray`]: arrow_array::StringArray
+/// [`LargeStringArray`]: arrow_array::LargeStringArray
+/// [`StringViewArray`]: arrow_array::StringViewArray
+/// [`Int64Array`]: arrow_array::Int64Array
+pub fn split_part(
+    array: &dyn Datum,
+    delimiter: &dyn Datum,
+    n: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let array = StringArg::try_new("split_part", array)?;
+    let delimiter = StringArg::try_new("split_part", delimiter)?;
+    let n = Int64Arg::try_new("split_part", n)?;
+    let len = output_len("split_part", [array.len(), delimiter.len(), n.len()])?;
+
+    build_strings(array.data_type(), len, |idx, out| {
+        let (Some(value), Some(delimiter), Some(n)) =
+            (array.value(idx), delimiter.value(idx), n.value(idx))
+        else {
+            return Ok(false);
+        };
+        let field = match n {
+            0 => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "split_part field position must not be zero".to_string(),
+                ))
+            }
+            _ if delimiter.is_empty() => (n == 1 || n == -1).then_some(value),
+            1.. => value.split(delimiter).nth(n as usize - 1),
+            _ => {
+                let fields = value.matches(delimiter).count() + 1;
+                let skip = fields.checked_sub(n.unsigned_abs() as usize);
+                skip.and_then(|skip| value.split(delimiter).nth(skip))
+            }
+        };
+        out.push_str(field.unwrap_or_default());
+        Ok(true)
+    })
+}
+
+/// Returns the substring of each string in `array` before `count` occurrences of
+/// `delimiter`
+///
+/// Modelled after the Spark and MySQL `substring_index`:
+///
+/// * If `count` is positive, returns everything to the left of the `count`'th
+///   occurrence of `delimiter`, counting from the left
+/// * If `count` is negative, returns everything to the right of the `|count|`'th
+///   occurrence of `delimiter`, counting from the right
+/// * The whole string is returned if there are fewer than `|count|` occurrences
+/// * An empty string is returned if `count` is zero or `delimiter` is empty
+/// * The result is null if any argument is null
+///
+/// `array` and `delimiter` may be any of [`StringArray`], [`LargeStringArray`] or
+/// [`StringViewArray`], and `count` an [`Int64Array`]. Any argument may be a scalar,
+/// and the result has the same type as `array`.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int64Array, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::substring_index;
+/// let array = Scalar::new(StringArray::from(vec!["www.apache.org"]));
+/// let delimiter = Scalar::new(StringArray::from(vec!["."]));
+/// let count = Int64Array::from(vec![1, 2, -2, 5]);
+/// let result = substring_index(&array, &delimiter, &count).unwrap();
+/// assert_eq!(
+///     result.as_string::<i32>(),
+///     &StringArray::from(vec!["www", "www.apache", "apache.org", "www.apache.org"])
+/// );
+/// ```
+///
+/// [`StringArray`]: arrow_array::StringArray
+/// [`LargeStringArray`]: arrow_array::LargeStringArray
+/// [`StringViewArray`]: arrow_array::StringViewArray
+/// [`Int64Array`]: arrow_array::Int64Array
+pub fn substring_index(
+    array: &dyn Datum,
+    delimiter: &dyn Datum,
+    count: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let array = StringArg::try_new("substring_index", array)?;
+    let delimiter = StringArg::try_new("substring_index", delimiter)?;
+    let count = Int64Arg::try_new("substring_index", count)?;
+    let len = output_len(
+        "substring_index",
+        [array.len(), delimiter.len(), count.len()],
+    )?;
+
+    build_strings(array.data_type(), len, |idx, out| {
+        let (Some(value), Some(delimiter), Some(count)) =
+            (array.value(idx), delimiter.value(idx), count.value(idx))
+        else {
+            return Ok(false);
+        };
+        let substring = match count {
+            _ if delimiter.is_empty() => "",
+            0 => "",
+            1.. => match value.match_indices(delimiter).nth(count as usize - 1) {
+                Some((end, _)) => &value[..end],
+                None => value,
+            },
+            _ => {
+                let count = count.unsigned_abs() as usize;
+                match value.rmatch_indices(delimiter).nth(count - 1) {
+                    Some((start, _)) => &value[start + delimiter.len()..],
+                    None => value,
+                }
+            }
+        };
+        out.push_str(substring);
+        Ok(true)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::*;
+
+    #[test]
+    fn test_split_part() {
+        let array = StringArray::from(vec!["a,b,c"; 8]);
+        let delimiter = Scalar::new(StringArray::from(vec![","]));
+        let n = Int64Array::from(vec![1, 2, 3, 4, -1, -3, -4, i64::MIN]);
+        let result = split_part(&array, &delimiter, &n).unwrap();
+        let expected = StringArray::from(vec!["a", "b", "c", "", "c", "a", "", ""]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let array = LargeStringArray::from(vec![
+            Some("abc~@~def~@~ghi"),
+            Some("~@~"),
+            Some("abc"),
+            Some("abc"),
+            None,
+            Some("abc"),
+        ]);
+        let delimiter = LargeStringArray::from(vec![
+            Some("~@~"),
+            Some("~@~"),
+            Some(""),
+            Some(""),
+            Some(","),
+            None,
+        ]);
+        let n = Scalar::new(Int64Array::from(vec![-2]));
+        let result = split_part(&array, &delimiter, &n).unwrap();
+        let expected =
+            LargeStringArray::from(vec![Some("def"), Some(""), Some(""), Some(""), None, None]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        let n = Scalar::new(Int64Array::from(vec![1]));
+        let result = split_part(&array, &delimiter, &n).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("abc"),
+            Some(""),
+            Some("abc"),
+            Some("abc"),
+            None,
+            None,
+        ]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        let array =
+            StringViewArray::from(vec![Some("a string that is too long to be inlined"), None]);
+        let delimiter = Scalar::new(StringViewArray::from(vec![" "]));
+        let n = Scalar::new(Int64Array::from(vec![-3]));
+        let result = split_part(&array, &delimiter, &n).unwrap();
+        let expected = StringViewArray::from(vec![Some("to"), None]);
+        assert_eq!(result.as_string_view(), &expected);
+
+        let n = Scalar::new(Int64Array::from(vec![0]));
+        let err = split_part(&array, &delimiter, &n).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: split_part field position must not be zero"
+        );
+    }
+
+    #[test]
+    fn test_substring_index() {
+        let array = StringArray::from(vec![
+            Some("www.apache.org"),
+            Some("www.apache.org"),
+            Some("www.apache.org"),
+            Some("www.apache.org"),
+            Some("www.apache.org"),
+            Some("www.apache.org"),
+            Some(""),
+            None,
+        ]);
+        let delimiter = Scalar::new(StringArray::from(vec!["."]));
+        let count = Int64Array::from(vec![
+            Some(1),
+            Some(3),
+            Some(-1),
+            Some(-3),
+            Some(0),
+            None,
+            Some(1),
+            Some(1),
+        ]);
+        let result = substring_index(&array, &delimiter, &count).unwrap();
+        let expected = StringArray::from(vec![
+            Some("www"),
+            Some("www.apache.org"),
+            Some("org"),
+            Some("www.apache.org"),
+            Some(""),
+            None,
+            Some(""),
+            None,
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        // Multi-character and empty delimiters
+        let array = LargeStringArray::from(vec!["a::b::c", "a::b::c", "a::b::c"]);
+        let delimiter = LargeStringArray::from(vec!["::", "::", ""]);
+        let count = Int64Array::from(vec![2, -2, 1]);
+        let result = substring_index(&array, &delimiter, &count).unwrap();
+        let expected = LargeStringArray::from(vec!["a::b", "b::c", ""]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        let array = StringViewArray::from(vec!["x/y/z"]);
+        let delimiter = Scalar::new(StringArray::from(vec!["/"]));
+        let count = Scalar::new(Int64Array::from(vec![i64::MIN]));
+        let result = substring_index(&array, &delimiter, &count).unwrap();
+        assert_eq!(
+            result.as_string_view(),
+            &StringViewArray::from(vec!["x/y/z"])
+        );
+    }
+
+    #[test]
+    fn test_split_invalid() {
+        let array = StringArray::from(vec!["a"]);
+        let delimiter = Scalar::new(Int64Array::from(vec![1]));
+        let n = Scalar::new(Int64Array::from(vec![1]));
+        let err = substring_index(&array, &delimiter, &n).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: substring_index not supported for Int64"
+        );
+
+        let delimiter = StringArray::from(vec![",", ","]);
+        let err = split_part(&array, &delimiter, &n).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot perform split_part on arrays of different length, got 1 vs 2"
+        );
+    }
+}
@@ -17,12 +17,19 @@
 
 //! Computation kernels on Arrow Arrays
 
-pub use arrow_arith::{aggregate, arithmetic, arity, bitwise, boolean, numeric, temporal};
+pub use arrow_arith::{
+    aggregate, arithmetic, arity, bitwise, boolean, cumulative, hash, math, numeric, temporal,
+};
 pub use arrow_cast::cast;
 pub use arrow_cast::parse as cast_utils;
-pub use arrow_ord::{cmp, partition, rank, sort};
-pub use arrow_select::{concat, filter, interleave, nullif, take, union_extract, window, zip};
-pub use arrow_string::{concat_elements, length, regexp, substring};
+pub use arrow_ord::{clamp, cmp, in_list, interval, partition, rank, sort, top_k};
+pub use arrow_select::{
+    coalesce, concat, filter, interleave, nullif, take, union_extract, window, zip,
+};
+pub use arrow_string::{concat_elements, length, pad, regexp, similarity, split, substring};
+
+#[cfg(feature = "unicode")]
+pub use arrow_string::normalize;
 
 /// Comparison kernels for `Array`s.
 pub mod comparison {
@@ -27,6 +27,8 @@ pub use self::kernels::cast::*;
 pub use self::kernels::comparison::*;
 pub use self::kernels::concat::*;
 pub use self::kernels::filter::*;
+pub use self::kernels::hash::*;
+pub use self::kernels::in_list::InList;
 pub use self::kernels::interleave::*;
 pub use self::kernels::nullif::*;
 pub use self::kernels::partition::*;
@@ -35,5 +37,6 @@ pub use self::kernels::regexp::*;
 pub use self::kernels::sort::*;
 pub use self::kernels::take::*;
 pub use self::kernels::temporal::*;
+pub use self::kernels::top_k::*;
 pub use self::kernels::union_extract::*;
 pub use self::kernels::window::*;
@@ -382,6 +382,8 @@ pub mod error;
 #[cfg(feature = "ffi")]
 pub use arrow_array::ffi;
 #[cfg(feature = "ffi")]
+pub use arrow_array::ffi_device;
+#[cfg(feature = "ffi")]
 pub use arrow_array::ffi_stream;
 #[cfg(feature = "ipc")]
 pub use arrow_ipc as ipc;
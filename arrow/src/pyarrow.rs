@@ -44,32 +44,39 @@
 //! | `pyarrow.Array`             | [ArrayData]                                                        |
 //! | `pyarrow.RecordBatch`       | [RecordBatch]                                                      |
 //! | `pyarrow.RecordBatchReader` | [ArrowArrayStreamReader] / `Box<dyn RecordBatchReader + Send>` (1) |
+//! | `pyarrow.Table`             | [Table] (2)                                                        |
+//! | `pyarrow.ChunkedArray`      | [ChunkedArray] (2)                                                 |
 //!
 //! (1) `pyarrow.RecordBatchReader` can be imported as [ArrowArrayStreamReader]. Either
 //! [ArrowArrayStreamReader] or `Box<dyn RecordBatchReader + Send>` can be exported
 //! as `pyarrow.RecordBatchReader`. (`Box<dyn RecordBatchReader + Send>` is typically
 //! easier to create.)
 //!
-//! PyArrow has the notion of chunked arrays and tables, but arrow-rs doesn't
-//! have these same concepts. A chunked table is instead represented with
-//! `Vec<RecordBatch>`. A `pyarrow.Table` can be imported to Rust by calling
-//! [pyarrow.Table.to_reader()](https://arrow.apache.org/docs/python/generated/pyarrow.Table.html#pyarrow.Table.to_reader)
-//! and then importing the reader as a [ArrowArrayStreamReader].
+//! (2) Tables and chunked arrays are transferred with the
+//! [C Stream Interface](https://arrow.apache.org/docs/format/CStreamInterface.html),
+//! so the underlying buffers are never copied. arrow-rs has no table type of its own,
+//! so [Table] is a thin wrapper around a `Vec<RecordBatch>` and the [SchemaRef]
+//! they share, which preserves schema metadata that would otherwise be lost for
+//! a table without any batches.
 
 use std::convert::{From, TryFrom};
 use std::ptr::{addr_of, addr_of_mut};
 use std::sync::Arc;
 
-use arrow_array::{RecordBatchIterator, RecordBatchOptions, RecordBatchReader, StructArray};
+use std::ffi::CStr;
+
+use arrow_array::{
+    ChunkedArray, RecordBatchIterator, RecordBatchOptions, RecordBatchReader, StructArray,
+};
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::import_exception;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
-use pyo3::types::{PyCapsule, PyList, PyTuple};
+use pyo3::types::{PyCapsule, PyDict, PyList, PyTuple};
 
 use crate::array::{make_array, ArrayData};
-use crate::datatypes::{DataType, Field, Schema};
+use crate::datatypes::{DataType, Field, Schema, SchemaRef};
 use crate::error::ArrowError;
 use crate::ffi;
 use crate::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
@@ -486,6 +493,183 @@ impl IntoPyArrow for ArrowArrayStreamReader {
     }
 }
 
+/// A `pyarrow.Table` represented as a list of [RecordBatch] sharing a common schema.
+///
+/// Keeping the schema alongside the batches preserves its metadata, and allows
+/// tables without any batches to be converted to and from PyArrow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    record_batches: Vec<RecordBatch>,
+    schema: SchemaRef,
+}
+
+impl Table {
+    /// Create a new [Table], returning an error if any of `record_batches`
+    /// does not match `schema`
+    pub fn try_new(
+        record_batches: Vec<RecordBatch>,
+        schema: SchemaRef,
+    ) -> Result<Self, ArrowError> {
+        for batch in &record_batches {
+            if batch.schema_ref() != &schema {
+                return Err(ArrowError::SchemaError(format!(
+                    "All record batches must have the table schema {}, found {}",
+                    schema,
+                    batch.schema()
+                )));
+            }
+        }
+        Ok(Self {
+            record_batches,
+            schema,
+        })
+    }
+
+    /// Returns the record batches of this table
+    pub fn record_batches(&self) -> &[RecordBatch] {
+        &self.record_batches
+    }
+
+    /// Returns the schema of this table
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Consumes this table, returning its record batches and schema
+    pub fn into_inner(self) -> (Vec<RecordBatch>, SchemaRef) {
+        (self.record_batches, self.schema)
+    }
+}
+
+/// Supports conversion from `pyarrow.Table`, or any object implementing
+/// `__arrow_c_stream__`, to [Table].
+impl FromPyArrow for Table {
+    fn from_pyarrow_bound(value: &Bound<PyAny>) -> PyResult<Self> {
+        let reader = if value.hasattr("__arrow_c_stream__")? {
+            ArrowArrayStreamReader::from_pyarrow_bound(value)?
+        } else {
+            validate_class("Table", value)?;
+            ArrowArrayStreamReader::from_pyarrow_bound(&value.call_method0("to_reader")?)?
+        };
+
+        // Batches imported from a stream do not carry the schema metadata,
+        // so reattach the stream schema to each of them
+        let schema = reader.schema();
+        let record_batches = reader
+            .map(|batch| batch.and_then(|b| b.with_schema(schema.clone())))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(to_py_err)?;
+
+        Table::try_new(record_batches, schema).map_err(to_py_err)
+    }
+}
+
+/// Convert a [Table] into a `pyarrow.Table`.
+impl ToPyArrow for Table {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let batches = self.record_batches.clone().into_iter().map(Ok);
+        let reader = RecordBatchIterator::new(batches, self.schema.clone());
+        let reader: Box<dyn RecordBatchReader + Send> = Box::new(reader);
+        let py_reader = reader.into_pyarrow(py)?;
+        py_reader.call_method0(py, "read_all")
+    }
+}
+
+/// Reads all the arrays of `stream`, which need not be of struct type
+fn chunked_array_from_stream(mut stream: FFI_ArrowArrayStream) -> Result<ChunkedArray, ArrowError> {
+    fn last_error(stream: &mut FFI_ArrowArrayStream, code: i32) -> ArrowError {
+        let message = stream
+            .get_last_error
+            .map(|get_last_error| unsafe { get_last_error(stream) })
+            .filter(|error| !error.is_null())
+            .map(|error| {
+                unsafe { CStr::from_ptr(error) }
+                    .to_string_lossy()
+                    .to_string()
+            });
+        ArrowError::CDataInterface(message.unwrap_or_else(|| format!("error code {code}")))
+    }
+
+    let (get_schema, get_next) = match (stream.release, stream.get_schema, stream.get_next) {
+        (Some(_), Some(get_schema), Some(get_next)) => (get_schema, get_next),
+        _ => {
+            return Err(ArrowError::CDataInterface(
+                "input stream is already released".to_string(),
+            ))
+        }
+    };
+
+    let mut schema = FFI_ArrowSchema::empty();
+    let code = unsafe { get_schema(&mut stream, &mut schema) };
+    if code != 0 {
+        return Err(last_error(&mut stream, code));
+    }
+    let data_type = DataType::try_from(&schema)?;
+
+    let mut chunks = vec![];
+    loop {
+        let mut array = FFI_ArrowArray::empty();
+        let code = unsafe { get_next(&mut stream, &mut array) };
+        if code != 0 {
+            return Err(last_error(&mut stream, code));
+        }
+        if array.is_released() {
+            break;
+        }
+        let data = unsafe { ffi::from_ffi_and_data_type(array, data_type.clone()) }?;
+        chunks.push(make_array(data));
+    }
+    ChunkedArray::try_new(data_type, chunks)
+}
+
+/// Supports conversion from `pyarrow.ChunkedArray`, or any object implementing
+/// `__arrow_c_stream__` for a stream of arrays, to [ChunkedArray].
+impl FromPyArrow for ChunkedArray {
+    fn from_pyarrow_bound(value: &Bound<PyAny>) -> PyResult<Self> {
+        // Newer versions of PyArrow as well as other libraries with Arrow data implement this
+        // method, so prefer it over reading the chunks individually.
+        // See https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html
+        if value.hasattr("__arrow_c_stream__")? {
+            let capsule = value.getattr("__arrow_c_stream__")?.call0()?;
+            let capsule = capsule.downcast::<PyCapsule>()?;
+            validate_pycapsule(capsule, "arrow_array_stream")?;
+
+            let stream = unsafe { FFI_ArrowArrayStream::from_raw(capsule.pointer() as _) };
+            return chunked_array_from_stream(stream).map_err(to_py_err);
+        }
+
+        validate_class("ChunkedArray", value)?;
+
+        let data_type = DataType::from_pyarrow_bound(&value.getattr("type")?)?;
+        let chunks = value
+            .getattr("chunks")?
+            .downcast::<PyList>()?
+            .iter()
+            .map(|a| Ok(make_array(ArrayData::from_pyarrow_bound(&a)?)))
+            .collect::<PyResult<_>>()?;
+
+        ChunkedArray::try_new(data_type, chunks).map_err(to_py_err)
+    }
+}
+
+/// Convert a [ChunkedArray] into a `pyarrow.ChunkedArray`.
+impl ToPyArrow for ChunkedArray {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let chunks = self
+            .chunks()
+            .iter()
+            .map(|chunk| chunk.to_data().to_pyarrow(py))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let module = py.import("pyarrow")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("type", self.data_type().to_pyarrow(py)?)?;
+        let chunked =
+            module.call_method("chunked_array", (PyList::new(py, chunks)?,), Some(&kwargs))?;
+        Ok(chunked.unbind())
+    }
+}
+
 /// A newtype wrapper for types implementing [`FromPyArrow`] or [`IntoPyArrow`].
 ///
 /// When wrapped around a type `T: FromPyArrow`, it
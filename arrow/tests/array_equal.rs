@@ -23,8 +23,8 @@ use arrow::array::{
 };
 use arrow::datatypes::{Int16Type, Int32Type};
 use arrow_array::builder::{StringBuilder, StringViewBuilder, StructBuilder};
-use arrow_array::{DictionaryArray, FixedSizeListArray, StringViewArray};
-use arrow_buffer::{Buffer, ToByteSlice};
+use arrow_array::{DictionaryArray, FixedSizeListArray, ListViewArray, StringViewArray};
+use arrow_buffer::{Buffer, NullBuffer, ScalarBuffer, ToByteSlice};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{DataType, Field, Fields};
 use std::sync::Arc;
@@ -1292,3 +1292,65 @@ fn test_list_excess_children_equal() {
     assert_eq!(b.value_offsets(), &[0, 0, 2]);
     assert_eq!(a, b);
 }
+
+#[test]
+fn test_list_view_equal() {
+    let field = Arc::new(Field::new_list_field(DataType::Int32, true));
+
+    // [[1, 2], null, [], [3, null, 4]]
+    let values = Arc::new(Int32Array::from(vec![
+        Some(1),
+        Some(2),
+        Some(3),
+        None,
+        Some(4),
+    ]));
+    let offsets = ScalarBuffer::from(vec![0, 2, 2, 2]);
+    let sizes = ScalarBuffer::from(vec![2, 1, 0, 3]);
+    let nulls = NullBuffer::from(vec![true, false, true, true]);
+    let a = ListViewArray::new(field.clone(), offsets, sizes, values, Some(nulls));
+
+    // Same logical values, with views out of order over a different child layout
+    let values = Arc::new(Int32Array::from(vec![
+        Some(3),
+        None,
+        Some(4),
+        Some(1),
+        Some(2),
+    ]));
+    let offsets = ScalarBuffer::from(vec![3, 0, 5, 0]);
+    let sizes = ScalarBuffer::from(vec![2, 0, 0, 3]);
+    let nulls = NullBuffer::from(vec![true, false, true, true]);
+    let b = ListViewArray::new(field.clone(), offsets, sizes, values, Some(nulls));
+    test_equal(&a, &b, true);
+    test_equal(&a.slice(1, 3), &b.slice(1, 3), true);
+
+    // Differing sizes
+    let values = Arc::new(Int32Array::from(vec![
+        Some(1),
+        Some(2),
+        Some(3),
+        None,
+        Some(4),
+    ]));
+    let offsets = ScalarBuffer::from(vec![0, 2, 2, 2]);
+    let sizes = ScalarBuffer::from(vec![2, 1, 0, 2]);
+    let c = ListViewArray::new(field.clone(), offsets, sizes, values, None);
+    test_equal(&a, &c, false);
+    test_equal(&a.slice(0, 1), &c.slice(0, 1), true);
+
+    // Differing values
+    let values = Arc::new(Int32Array::from(vec![
+        Some(1),
+        Some(2),
+        Some(3),
+        Some(5),
+        Some(4),
+    ]));
+    let offsets = ScalarBuffer::from(vec![0, 4, 2, 2]);
+    let sizes = ScalarBuffer::from(vec![2, 1, 0, 3]);
+    let nulls = NullBuffer::from(vec![true, false, true, true]);
+    let d = ListViewArray::new(field, offsets, sizes, values, Some(nulls));
+    test_equal(&a, &d, false);
+    test_equal(&a.slice(0, 3), &d.slice(0, 3), true);
+}
@@ -18,12 +18,12 @@
 use arrow::array::{
     Array, ArrayRef, BooleanArray, Decimal128Array, DictionaryArray, FixedSizeBinaryArray,
     FixedSizeListBuilder, Int16Array, Int32Array, Int64Array, Int64Builder, ListArray, ListBuilder,
-    MapBuilder, NullArray, StringArray, StringBuilder, StringDictionaryBuilder, StructArray,
-    UInt16Array, UInt16Builder, UInt8Array, UnionArray,
+    ListViewArray, MapBuilder, NullArray, StringArray, StringBuilder, StringDictionaryBuilder,
+    StructArray, UInt16Array, UInt16Builder, UInt8Array, UnionArray,
 };
 use arrow::datatypes::Int16Type;
 use arrow_array::StringViewArray;
-use arrow_buffer::{Buffer, ScalarBuffer};
+use arrow_buffer::{Buffer, NullBuffer, ScalarBuffer};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::{DataType, Field, Fields, UnionFields};
@@ -547,6 +547,55 @@ fn test_binary_fixed_sized_offsets() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_list_view_append() {
+    // [[4, 5], [1, 2], null, [2, 3, 4]]
+    let field = Arc::new(Field::new_list_field(DataType::Int64, true));
+    let values = Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5]));
+    let offsets = ScalarBuffer::from(vec![3, 0, 4, 1]);
+    let sizes = ScalarBuffer::from(vec![2, 2, 1, 3]);
+    let nulls = NullBuffer::from(vec![true, true, false, true]);
+    let a = ListViewArray::new(field, offsets, sizes, values, Some(nulls)).into_data();
+    let b = a.slice(1, 3);
+
+    let mut mutable = MutableArrayData::new(vec![&a, &b], true, 8);
+    mutable.extend(0, 0, 2);
+    mutable.extend_nulls(1);
+    mutable.extend(1, 1, 3);
+    mutable.extend(0, 3, 4);
+
+    let result = ListViewArray::from(mutable.freeze());
+    assert_eq!(result.len(), 6);
+    assert_eq!(result.value_offsets(), &[0, 2, 4, 4, 5, 8]);
+    assert_eq!(result.value_sizes(), &[2, 2, 0, 1, 3, 3]);
+
+    // Each view is copied, including those masked by a null
+    let values = result.values().as_any().downcast_ref::<Int64Array>();
+    assert_eq!(values.unwrap().values(), &[4, 5, 1, 2, 5, 2, 3, 4, 2, 3, 4]);
+
+    let actual: Vec<_> = result
+        .iter()
+        .map(|x| {
+            x.map(|x| {
+                x.as_any()
+                    .downcast_ref::<Int64Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+        })
+        .collect();
+    let expected = vec![
+        Some(vec![4, 5]),
+        Some(vec![1, 2]),
+        None,
+        None,
+        Some(vec![2, 3, 4]),
+        Some(vec![2, 3, 4]),
+    ];
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn test_list_append() {
     let mut builder = ListBuilder::<Int64Builder>::new(Int64Builder::with_capacity(24));
@@ -386,6 +386,43 @@ fn test_validate_large_list_view_offsets_sizes() {
     );
 }
 
+#[test]
+#[cfg(not(feature = "force_validate"))]
+fn test_validate_list_view_len() {
+    // Fewer views than values
+    let field_type = Arc::new(Field::new("f", DataType::Int32, true));
+    let values: Int32Array = [Some(1), Some(2), Some(3), Some(4)].into_iter().collect();
+    let offsets = Buffer::from_slice_ref([2_i32, 0]);
+    let sizes = Buffer::from_slice_ref([2_i32, 4]);
+    ArrayData::try_new(
+        DataType::ListView(field_type.clone()),
+        2,
+        None,
+        0,
+        vec![offsets, sizes],
+        vec![values.to_data()],
+    )
+    .unwrap();
+
+    // More views than values, with the last view out of bounds
+    let offsets = Buffer::from_slice_ref([0_i32, 0, 1, 2, 3, 3]);
+    let sizes = Buffer::from_slice_ref([0_i32, 4, 3, 1, 1, 2]);
+    let err = ArrayData::try_new(
+        DataType::ListView(field_type),
+        6,
+        None,
+        0,
+        vec![offsets, sizes],
+        vec![values.into_data()],
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Size 2 at index 5 is larger than the remaining values"),
+        "{err}"
+    );
+}
+
 #[test]
 #[should_panic(expected = "Error converting offset[1] (-1) to usize for ListView")]
 fn test_validate_list_view_negative_offsets() {
@@ -1099,3 +1136,120 @@ fn test_sliced_array_child() {
     let err = data.validate_values().unwrap_err();
     assert_eq!(err.to_string(), "Invalid argument error: Offset invariant failure: offset at position 1 out of bounds: 3 > 2");
 }
+
+#[test]
+#[cfg(not(feature = "force_validate"))]
+fn test_validate_with_level() {
+    use arrow_data::ValidationLevel;
+
+    // 5 is an invalid offset into a list of only four values
+    let values = Int32Array::from(vec![1, 2, 3, 4]);
+    let list_type = DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+    let list = unsafe {
+        ArrayData::new_unchecked(
+            list_type.clone(),
+            3,
+            None,
+            None,
+            0,
+            vec![Buffer::from_slice_ref([0_i32, 2, 5, 4])],
+            vec![values.into_data()],
+        )
+    };
+
+    // Invalid UTF-8
+    let strings = unsafe {
+        ArrayData::new_unchecked(
+            DataType::Utf8,
+            3,
+            None,
+            None,
+            0,
+            vec![
+                Buffer::from_slice_ref([0_i32, 1, 2, 3]),
+                Buffer::from_slice_ref([b'a', 0xFF, b'c']),
+            ],
+            vec![],
+        )
+    };
+
+    let b = Field::new("b", list_type, true);
+    let inner_type = DataType::Struct(vec![b].into());
+    let inner = ArrayData::try_new(inner_type.clone(), 3, None, 0, vec![], vec![list]).unwrap();
+    let fields = vec![
+        Field::new("a", inner_type, true),
+        Field::new("c", DataType::Utf8, true),
+    ];
+    let data = ArrayData::try_new(
+        DataType::Struct(fields.clone().into()),
+        3,
+        None,
+        0,
+        vec![],
+        vec![inner, strings.clone()],
+    )
+    .unwrap();
+
+    data.validate_with_level(ValidationLevel::Structure)
+        .unwrap();
+    let err = data
+        .validate_with_level(ValidationLevel::Offsets)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid argument error: Invalid child 'a'.'b': Offset invariant failure: offset at position 2 out of bounds: 5 > 4"
+    );
+    let err = data.validate_full().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid argument error: Invalid child 'a'.'b': Offset invariant failure: offset at position 2 out of bounds: 5 > 4"
+    );
+
+    let valid = Int32Array::from(vec![1, 2, 3]).into_data();
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("c", DataType::Utf8, true),
+    ];
+    let data = ArrayData::try_new(
+        DataType::Struct(fields.into()),
+        3,
+        None,
+        0,
+        vec![],
+        vec![valid, strings],
+    )
+    .unwrap();
+    data.validate_with_level(ValidationLevel::Offsets).unwrap();
+    let err = data.validate_with_level(ValidationLevel::Full).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid argument error: Invalid child 'c': Invalid UTF8 sequence at string index 1 (1..2): invalid utf-8 sequence of 1 bytes from index 0"
+    );
+
+    // Structural errors of children are also reported with their path
+    let dictionary_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int64));
+    let values = unsafe {
+        ArrayData::new_unchecked(
+            DataType::Int64,
+            2,
+            None,
+            None,
+            0,
+            vec![make_i32_buffer(2)],
+            vec![],
+        )
+    };
+    let err = ArrayData::try_new(
+        dictionary_type,
+        1,
+        None,
+        0,
+        vec![Buffer::from_slice_ref([0_i8])],
+        vec![values],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid argument error: Invalid child #0: Need at least 16 bytes in buffers[0] in array of type Int64, but got 8"
+    );
+}
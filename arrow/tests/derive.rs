@@ -0,0 +1,237 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::record::{ArrowRecord, Decimal128, RecordIterator};
+use arrow::array::{Array, ArrayRef, Int32Array, RecordBatchIterator, StringArray, StructArray};
+use arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, ArrowRecord)]
+struct Address {
+    street: String,
+    number: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, ArrowRecord)]
+struct Person {
+    id: i64,
+    name: String,
+    nickname: Option<String>,
+    active: bool,
+    score: f64,
+    tags: Vec<String>,
+    scores: Option<Vec<Option<i32>>>,
+    address: Address,
+    previous: Option<Address>,
+    history: Vec<Address>,
+    born: NaiveDate,
+    last_seen: NaiveDateTime,
+    updated: DateTime<Utc>,
+    balance: Decimal128<12, 2>,
+}
+
+fn people() -> Vec<Person> {
+    let born = NaiveDate::from_ymd_opt(1990, 5, 17).unwrap();
+    let last_seen = born.and_hms_micro_opt(12, 30, 0, 250).unwrap();
+    vec![
+        Person {
+            id: 1,
+            name: "alice".to_string(),
+            nickname: Some("al".to_string()),
+            active: true,
+            score: 1.5,
+            tags: vec!["a".to_string(), "b".to_string()],
+            scores: Some(vec![Some(1), None, Some(3)]),
+            address: Address {
+                street: "Main".to_string(),
+                number: Some(12),
+            },
+            previous: None,
+            history: vec![],
+            born,
+            last_seen,
+            updated: Utc.from_utc_datetime(&last_seen),
+            balance: Decimal128(12345),
+        },
+        Person {
+            id: 2,
+            name: "bob".to_string(),
+            nickname: None,
+            active: false,
+            score: -0.25,
+            tags: vec![],
+            scores: None,
+            address: Address {
+                street: "High".to_string(),
+                number: None,
+            },
+            previous: Some(Address {
+                street: "Low".to_string(),
+                number: Some(3),
+            }),
+            history: vec![
+                Address {
+                    street: "Old".to_string(),
+                    number: None,
+                },
+                Address {
+                    street: "Older".to_string(),
+                    number: Some(7),
+                },
+            ],
+            born,
+            last_seen,
+            updated: Utc.from_utc_datetime(&last_seen),
+            balance: Decimal128(-1),
+        },
+    ]
+}
+
+#[test]
+fn test_schema() {
+    let address = Fields::from(vec![
+        Field::new("street", DataType::Utf8, false),
+        Field::new("number", DataType::UInt32, true),
+    ]);
+    assert_eq!(Address::schema(), Schema::new(address.clone()));
+
+    let schema = Person::schema();
+    let expected = [
+        ("id", DataType::Int64, false),
+        ("name", DataType::Utf8, false),
+        ("nickname", DataType::Utf8, true),
+        ("active", DataType::Boolean, false),
+        ("score", DataType::Float64, false),
+        (
+            "tags",
+            DataType::List(Arc::new(Field::new_list_field(DataType::Utf8, false))),
+            false,
+        ),
+        (
+            "scores",
+            DataType::List(Arc::new(Field::new_list_field(DataType::Int32, true))),
+            true,
+        ),
+        ("address", DataType::Struct(address.clone()), false),
+        ("previous", DataType::Struct(address.clone()), true),
+        (
+            "history",
+            DataType::List(Arc::new(Field::new_list_field(
+                DataType::Struct(address),
+                false,
+            ))),
+            false,
+        ),
+        ("born", DataType::Date32, false),
+        (
+            "last_seen",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
+        (
+            "updated",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into())),
+            false,
+        ),
+        ("balance", DataType::Decimal128(12, 2), false),
+    ];
+    assert_eq!(schema.fields().len(), expected.len());
+    for (field, (name, data_type, nullable)) in schema.fields().iter().zip(expected) {
+        assert_eq!(field.name(), name);
+        assert_eq!(field.data_type(), &data_type);
+        assert_eq!(field.is_nullable(), nullable, "{name}");
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let people = people();
+    let batch = RecordBatch::try_from(people.as_slice()).unwrap();
+    assert_eq!(batch.num_rows(), 2);
+    assert_eq!(batch.schema().as_ref(), &Person::schema());
+
+    let names = batch.column(1).as_any().downcast_ref::<StringArray>();
+    assert_eq!(names.unwrap(), &StringArray::from(vec!["alice", "bob"]));
+
+    let previous = batch.column(8).as_any().downcast_ref::<StructArray>();
+    assert_eq!(previous.unwrap().null_count(), 1);
+
+    let decoded: Vec<Person> = Vec::try_from(&batch).unwrap();
+    assert_eq!(decoded, people);
+
+    let empty = RecordBatch::try_from(&people[..0]).unwrap();
+    assert_eq!(empty.num_rows(), 0);
+    assert!(Person::from_record_batch(&empty).unwrap().is_empty());
+}
+
+#[test]
+fn test_record_iterator() {
+    let people = people();
+    let batch = RecordBatch::try_from(people.as_slice()).unwrap();
+    let batches = vec![Ok(batch.clone()), Ok(batch.slice(1, 1))];
+    let reader = RecordBatchIterator::new(batches, batch.schema());
+
+    let decoded = RecordIterator::<Person, _>::new(reader)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(decoded, [people.clone(), people[1..].to_vec()].concat());
+}
+
+#[test]
+fn test_decode_errors() {
+    let street: ArrayRef = Arc::new(StringArray::from(vec![Some("Main"), None]));
+    let number: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+
+    let batch = RecordBatch::try_from_iter(vec![("street", street.clone())]).unwrap();
+    let err = Address::from_record_batch(&batch).unwrap_err();
+    assert_eq!(err.to_string(), "Schema error: Column number not found");
+
+    let batch =
+        RecordBatch::try_from_iter(vec![("street", street.clone()), ("number", number)]).unwrap();
+    let err = Address::from_record_batch(&batch).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cast error: Expected array of UInt32, got Int32"
+    );
+
+    let number: ArrayRef = Arc::new(arrow::array::UInt32Array::from(vec![1, 2]));
+    let batch = RecordBatch::try_from_iter(vec![("number", number), ("street", street)]).unwrap();
+    let err = Address::from_record_batch(&batch).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid argument error: Found null value for non-nullable street"
+    );
+}
+
+mod custom_path {
+    use arrow_array::record::ArrowRecord;
+
+    #[derive(Debug, PartialEq, ArrowRecord)]
+    #[arrow(crate = "arrow_array")]
+    struct Row {
+        value: i32,
+    }
+
+    #[test]
+    fn test_crate_path() {
+        let rows = vec![Row { value: 1 }, Row { value: 2 }];
+        let batch = Row::to_record_batch(&rows).unwrap();
+        assert_eq!(Row::from_record_batch(&batch).unwrap(), rows);
+    }
+}
@@ -16,11 +16,13 @@
 // under the License.
 
 use arrow::array::{ArrayRef, Int32Array, StringArray};
-use arrow::pyarrow::{FromPyArrow, ToPyArrow};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::pyarrow::{FromPyArrow, Table, ToPyArrow};
 use arrow::record_batch::RecordBatch;
 use arrow_array::builder::{BinaryViewBuilder, StringViewBuilder};
-use arrow_array::{Array, BinaryViewArray, StringViewArray};
+use arrow_array::{Array, BinaryViewArray, ChunkedArray, StringViewArray};
 use pyo3::Python;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[test]
@@ -72,6 +74,79 @@ fn test_to_pyarrow_byte_view() {
     }
 }
 
+#[test]
+fn test_table_to_pyarrow() {
+    pyo3::prepare_freethreaded_python();
+
+    let metadata = HashMap::from([("key".to_string(), "value".to_string())]);
+    let schema = Arc::new(
+        Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ])
+        .with_metadata(metadata),
+    );
+    let batches = vec![
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )
+        .unwrap(),
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(3), None])),
+                Arc::new(StringArray::from(vec![None, Some("d")])),
+            ],
+        )
+        .unwrap(),
+    ];
+
+    for input in [
+        Table::try_new(batches, schema.clone()).unwrap(),
+        Table::try_new(vec![], schema.clone()).unwrap(),
+    ] {
+        let res = Python::with_gil(|py| {
+            let py_input = input.to_pyarrow(py)?;
+            let table = Table::from_pyarrow_bound(py_input.bind(py))?;
+            let py_table = table.to_pyarrow(py)?;
+            Table::from_pyarrow_bound(py_table.bind(py))
+        })
+        .unwrap();
+
+        assert_eq!(input, res);
+        assert_eq!(res.schema().metadata(), schema.metadata());
+    }
+}
+
+#[test]
+fn test_chunked_array_to_pyarrow() {
+    pyo3::prepare_freethreaded_python();
+
+    let input = ChunkedArray::try_new(
+        DataType::Int32,
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(Int32Array::from(vec![Some(3), None, Some(5)])),
+        ],
+    )
+    .unwrap();
+
+    let res = Python::with_gil(|py| {
+        let py_input = input.to_pyarrow(py)?;
+        let chunked = ChunkedArray::from_pyarrow_bound(py_input.bind(py))?;
+        let py_chunked = chunked.to_pyarrow(py)?;
+        ChunkedArray::from_pyarrow_bound(py_chunked.bind(py))
+    })
+    .unwrap();
+
+    assert_eq!(input, res);
+    assert_eq!(res.num_chunks(), 2);
+}
+
 fn binary_view_column(num_variadic_buffers: usize) -> BinaryViewArray {
     let long_scalar = b"but soft what light through yonder window breaks".as_slice();
     let mut builder = BinaryViewBuilder::new().with_fixed_block_size(long_scalar.len() as u32);
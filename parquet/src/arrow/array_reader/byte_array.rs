@@ -29,7 +29,8 @@ use crate::encodings::decoding::{Decoder, DeltaBitPackDecoder};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 use arrow_array::{
-    Array, ArrayRef, BinaryArray, Decimal128Array, Decimal256Array, OffsetSizeTrait,
+    Array, ArrayRef, BinaryArray, Decimal128Array, Decimal256Array, Decimal32Array, Decimal64Array,
+    OffsetSizeTrait,
 };
 use arrow_buffer::i256;
 use arrow_schema::DataType as ArrowType;
@@ -54,6 +55,8 @@ pub fn make_byte_array_reader(
     match data_type {
         ArrowType::Binary
         | ArrowType::Utf8
+        | ArrowType::Decimal32(_, _)
+        | ArrowType::Decimal64(_, _)
         | ArrowType::Decimal128(_, _)
         | ArrowType::Decimal256(_, _) => {
             let reader = GenericRecordReader::new(column_desc);
@@ -123,6 +126,30 @@ impl<I: OffsetSizeTrait> ArrayReader for ByteArrayReader<I> {
             // Apply conversion to all elements regardless of null slots as the conversions
             // are infallible. This improves performance by avoiding a branch in the inner
             // loop (see docs for `PrimitiveArray::from_unary`).
+            ArrowType::Decimal32(p, s) => {
+                let array = buffer.into_array(null_buffer, ArrowType::Binary);
+                let binary = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+                // Null slots will have 0 length, so we need to check for that in the lambda
+                // or sign_extend_be will panic.
+                let decimal = Decimal32Array::from_unary(binary, |x| match x.len() {
+                    0 => i32::default(),
+                    _ => i32::from_be_bytes(sign_extend_be(x)),
+                })
+                .with_precision_and_scale(p, s)?;
+                Arc::new(decimal)
+            }
+            ArrowType::Decimal64(p, s) => {
+                let array = buffer.into_array(null_buffer, ArrowType::Binary);
+                let binary = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+                // Null slots will have 0 length, so we need to check for that in the lambda
+                // or sign_extend_be will panic.
+                let decimal = Decimal64Array::from_unary(binary, |x| match x.len() {
+                    0 => i64::default(),
+                    _ => i64::from_be_bytes(sign_extend_be(x)),
+                })
+                .with_precision_and_scale(p, s)?;
+                Arc::new(decimal)
+            }
             ArrowType::Decimal128(p, s) => {
                 let array = buffer.into_array(null_buffer, ArrowType::Binary);
                 let binary = array.as_any().downcast_ref::<BinaryArray>().unwrap();
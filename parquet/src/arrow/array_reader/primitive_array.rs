@@ -26,6 +26,7 @@ use crate::schema::types::ColumnDescPtr;
 use arrow_array::Decimal256Array;
 use arrow_array::{
     builder::TimestampNanosecondBufferBuilder, ArrayRef, BooleanArray, Decimal128Array,
+    Decimal32Array, Decimal64Array,
     Float32Array, Float64Array, Int32Array, Int64Array, TimestampNanosecondArray, UInt32Array,
     UInt64Array,
 };
@@ -216,6 +217,59 @@ where
                 let a = arrow_cast::cast(&array, &ArrowType::Date32)?;
                 arrow_cast::cast(&a, target_type)?
             }
+            ArrowType::Decimal32(p, s) => {
+                // A decimal with a precision of at most 9 fits an `i32`, and so narrowing
+                // an `i64` is lossless for values within the precision
+                let array = match array.data_type() {
+                    ArrowType::Int32 => array
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap()
+                        .unary(|i| i)
+                        as Decimal32Array,
+                    ArrowType::Int64 => array
+                        .as_any()
+                        .downcast_ref::<Int64Array>()
+                        .unwrap()
+                        .unary(|i| i as i32)
+                        as Decimal32Array,
+                    _ => {
+                        return Err(arrow_err!(
+                            "Cannot convert {:?} to decimal",
+                            array.data_type()
+                        ));
+                    }
+                }
+                .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(array) as ArrayRef
+            }
+            ArrowType::Decimal64(p, s) => {
+                // See above comment. Conversion to `i64` is likewise lossless.
+                let array = match array.data_type() {
+                    ArrowType::Int32 => array
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap()
+                        .unary(|i| i as i64)
+                        as Decimal64Array,
+                    ArrowType::Int64 => array
+                        .as_any()
+                        .downcast_ref::<Int64Array>()
+                        .unwrap()
+                        .unary(|i| i)
+                        as Decimal64Array,
+                    _ => {
+                        return Err(arrow_err!(
+                            "Cannot convert {:?} to decimal",
+                            array.data_type()
+                        ));
+                    }
+                }
+                .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(array) as ArrayRef
+            }
             ArrowType::Decimal128(p, s) => {
                 // Apply conversion to all elements regardless of null slots as the conversion
                 // to `i128` is infallible. This improves performance by avoiding a branch in
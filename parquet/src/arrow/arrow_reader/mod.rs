@@ -4009,6 +4009,98 @@ mod tests {
         test_decimal_roundtrip::<Decimal256Type>();
     }
 
+    #[test]
+    fn test_decimal32_decimal64_roundtrip() {
+        let d1 = Decimal32Array::from(vec![Some(1), None, Some(999_999_999)])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let d2 = Decimal32Array::from(vec![Some(1), Some(-9), None])
+            .with_precision_and_scale(1, 0)
+            .unwrap();
+        let d3 = Decimal64Array::from(vec![Some(1), None, Some(-99_999)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let d4 = Decimal64Array::from(vec![Some(1), Some(10_i64.pow(18) - 1), None])
+            .with_precision_and_scale(18, 4)
+            .unwrap();
+
+        let batch = RecordBatch::try_from_iter([
+            ("d1", Arc::new(d1) as ArrayRef),
+            ("d2", Arc::new(d2) as ArrayRef),
+            ("d3", Arc::new(d3) as ArrayRef),
+            ("d4", Arc::new(d4) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+        let physical_types: Vec<_> = builder
+            .parquet_schema()
+            .columns()
+            .iter()
+            .map(|c| c.physical_type())
+            .collect();
+        assert_eq!(
+            physical_types,
+            [
+                PhysicalType::INT32,
+                PhysicalType::INT64,
+                PhysicalType::INT32,
+                PhysicalType::INT64
+            ]
+        );
+
+        let mut reader = builder.build().unwrap();
+        assert_eq!(batch.schema(), reader.schema());
+
+        let out = reader.next().unwrap().unwrap();
+        assert_eq!(batch, out);
+    }
+
+    #[test]
+    fn test_extension_types_roundtrip() {
+        use arrow_array::extension::{Bool8Array, ExtensionArray, JsonArray, UuidArray};
+
+        let uuid: UuidArray = vec![None, Some([1; 16])].into_iter().collect();
+        let json = JsonArray::from(StringArray::from(vec![Some("[1]"), None]));
+        let bool8: Bool8Array = vec![Some(false), Some(true)].into_iter().collect();
+        let schema = Arc::new(Schema::new(vec![
+            uuid.field("uuid"),
+            json.field("json"),
+            bool8.field("bool8"),
+        ]));
+        let columns = vec![
+            uuid.to_array_ref(),
+            json.to_array_ref(),
+            bool8.to_array_ref(),
+        ];
+        let batch = RecordBatch::try_new(schema, columns).unwrap();
+
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let read = ParquetRecordBatchReader::try_new(Bytes::from(buffer), 1024)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+
+        let schema = read.schema();
+        let uuid = UuidArray::try_from_field(schema.field(0), read.column(0)).unwrap();
+        assert_eq!(uuid.value(1), [1; 16]);
+        let json = JsonArray::try_from_field(schema.field(1), read.column(1)).unwrap();
+        assert_eq!(json.value(0), "[1]");
+        let bool8 = Bool8Array::try_from_field(schema.field(2), read.column(2)).unwrap();
+        assert!(bool8.value(1));
+    }
+
     #[test]
     fn test_list_selection() {
         let schema = Arc::new(Schema::new(vec![Field::new_list(
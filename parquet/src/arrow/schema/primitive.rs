@@ -69,8 +69,11 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
         // Determine interval time unit (#1666)
         (DataType::Interval(_), DataType::Interval(_)) => hint,
 
-        // Promote to Decimal256
-        (DataType::Decimal128(_, _), DataType::Decimal256(_, _)) => hint,
+        // Promote to Decimal256, or narrow to Decimal32 or Decimal64
+        (
+            DataType::Decimal128(_, _),
+            DataType::Decimal32(_, _) | DataType::Decimal64(_, _) | DataType::Decimal256(_, _),
+        ) => hint,
 
         // Potentially preserve dictionary encoding
         (_, DataType::Dictionary(_, value)) => {